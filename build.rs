@@ -0,0 +1,14 @@
+//! Compiles the gRPC API's protobuf definitions (`proto/julie.proto`) when the
+//! `grpc` feature is enabled. Needs `protoc` on the PATH for that build only.
+
+fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
+
+    #[cfg(feature = "grpc")]
+    {
+        println!("cargo:rerun-if-changed=proto/julie.proto");
+        tonic_prost_build::configure()
+            .compile_protos(&["proto/julie.proto"], &["proto"])
+            .expect("failed to compile proto/julie.proto (is protoc installed?)");
+    }
+}
@@ -0,0 +1,320 @@
+//! Process-wide registry of background work, for "what is julie doing right now".
+//!
+//! Long-running work (indexing, embedding, maintenance commands, the file
+//! watcher's queue) registers a [`BackgroundTask`] and reports progress on it.
+//! The registry only holds weak references: a task disappears from status
+//! output as soon as its owner drops the last `Arc`, so an aborted or panicked
+//! task cannot leave a stale entry behind.
+//!
+//! Pausing is cooperative. Owners of pausable tasks check [`BackgroundTask::is_paused`]
+//! (or block in [`BackgroundTask::wait_while_paused`]) at points where stopping
+//! holds no locks; tasks without such a point register as not pausable.
+
+use std::fmt;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{Arc, Mutex, MutexGuard, OnceLock, Weak};
+use std::time::{Duration, Instant};
+
+/// Poll interval of [`BackgroundTask::wait_while_paused`].
+const PAUSE_POLL_INTERVAL: Duration = Duration::from_millis(200);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BackgroundTaskKind {
+    Indexing,
+    Embedding,
+    Maintenance,
+    Watcher,
+}
+
+impl BackgroundTaskKind {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Indexing => "indexing",
+            Self::Embedding => "embedding",
+            Self::Maintenance => "maintenance",
+            Self::Watcher => "watcher",
+        }
+    }
+}
+
+impl fmt::Display for BackgroundTaskKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// Progress since the current measurement window started. The window is the
+/// task's lifetime, except for the watcher, which restarts it whenever its
+/// backlog drains so the rate reflects the current burst of events.
+#[derive(Debug)]
+struct Progress {
+    window_start: Instant,
+    completed: u64,
+    total: Option<u64>,
+    detail: Option<String>,
+    paused_since: Option<Instant>,
+}
+
+/// One unit of background work, shared between its owner and the registry.
+#[derive(Debug)]
+pub struct BackgroundTask {
+    id: u64,
+    kind: BackgroundTaskKind,
+    workspace_id: String,
+    label: String,
+    pausable: bool,
+    started_at: Instant,
+    progress: Mutex<Progress>,
+}
+
+/// Point-in-time view of a task for status reporting.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BackgroundTaskSnapshot {
+    pub id: u64,
+    pub kind: BackgroundTaskKind,
+    pub workspace_id: String,
+    pub label: String,
+    pub detail: Option<String>,
+    pub completed: u64,
+    pub total: Option<u64>,
+    pub elapsed: Duration,
+    /// Remaining time at the rate observed in the current window. `None`
+    /// while paused, before any progress, or when the total is unknown.
+    pub eta: Option<Duration>,
+    pub paused: bool,
+    pub pausable: bool,
+}
+
+impl BackgroundTaskSnapshot {
+    /// Completion percentage, when the total is known and non-zero.
+    pub fn percent(&self) -> Option<f64> {
+        self.total
+            .filter(|total| *total > 0)
+            .map(|total| (self.completed.min(total) as f64 / total as f64) * 100.0)
+    }
+}
+
+impl BackgroundTask {
+    pub fn id(&self) -> u64 {
+        self.id
+    }
+
+    pub fn kind(&self) -> BackgroundTaskKind {
+        self.kind
+    }
+
+    pub fn workspace_id(&self) -> &str {
+        &self.workspace_id
+    }
+
+    pub fn is_pausable(&self) -> bool {
+        self.pausable
+    }
+
+    fn progress(&self) -> MutexGuard<'_, Progress> {
+        self.progress
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    /// Set the number of work items. Unknown until the owner has scanned its input.
+    pub fn set_total(&self, total: u64) {
+        self.progress().total = Some(total);
+    }
+
+    /// Add `count` work items discovered after the total was set, e.g. a
+    /// follow-up phase. An unknown total starts from the items already done.
+    pub fn extend_total(&self, count: u64) {
+        let mut progress = self.progress();
+        progress.total = Some(progress.total.unwrap_or(progress.completed) + count);
+    }
+
+    /// Record `count` more finished work items.
+    pub fn advance(&self, count: u64) {
+        let mut progress = self.progress();
+        progress.completed = progress.completed.saturating_add(count);
+    }
+
+    /// Short description of the current step, e.g. an indexing stage.
+    pub fn set_detail(&self, detail: impl Into<String>) {
+        self.progress().detail = Some(detail.into());
+    }
+
+    /// Report the depth of a queue the task drains. Extends the total by the
+    /// new backlog while a drain is in progress; once everything counted so
+    /// far is done, starts a fresh measurement window at `depth`.
+    pub fn track_backlog(&self, depth: u64) {
+        let mut progress = self.progress();
+        let drained = progress
+            .total
+            .is_none_or(|total| progress.completed >= total);
+        if drained {
+            progress.window_start = Instant::now();
+            progress.completed = 0;
+            progress.total = Some(depth);
+        } else {
+            progress.total = Some(progress.completed + depth);
+        }
+    }
+
+    pub fn is_paused(&self) -> bool {
+        self.progress().paused_since.is_some()
+    }
+
+    /// Pause or resume. Time spent paused is excluded from the rate behind the ETA.
+    fn set_paused(&self, paused: bool) {
+        let mut progress = self.progress();
+        match (paused, progress.paused_since) {
+            (true, None) => progress.paused_since = Some(Instant::now()),
+            (false, Some(since)) => {
+                progress.window_start += since.elapsed();
+                progress.paused_since = None;
+            }
+            _ => {}
+        }
+    }
+
+    /// Block the calling thread while the task is paused. Returns early when
+    /// `cancel` is set, so a paused task can still be cancelled.
+    pub fn wait_while_paused(&self, cancel: Option<&AtomicBool>) {
+        while self.is_paused() {
+            if cancel.is_some_and(|flag| flag.load(Ordering::Acquire)) {
+                return;
+            }
+            std::thread::sleep(PAUSE_POLL_INTERVAL);
+        }
+    }
+
+    pub fn snapshot(&self) -> BackgroundTaskSnapshot {
+        let progress = self.progress();
+        let eta = match (progress.paused_since, progress.total) {
+            (None, Some(total)) if progress.completed > 0 => {
+                let remaining = total.saturating_sub(progress.completed);
+                let per_item =
+                    progress.window_start.elapsed().as_secs_f64() / progress.completed as f64;
+                Some(Duration::from_secs_f64(per_item * remaining as f64))
+            }
+            _ => None,
+        };
+        BackgroundTaskSnapshot {
+            id: self.id,
+            kind: self.kind,
+            workspace_id: self.workspace_id.clone(),
+            label: self.label.clone(),
+            detail: progress.detail.clone(),
+            completed: progress.completed,
+            total: progress.total,
+            elapsed: self.started_at.elapsed(),
+            eta,
+            paused: progress.paused_since.is_some(),
+            pausable: self.pausable,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TaskControlError {
+    NotFound(u64),
+    NotPausable(u64),
+}
+
+impl fmt::Display for TaskControlError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NotFound(id) => write!(f, "no active background task with id {id}"),
+            Self::NotPausable(id) => write!(f, "background task {id} cannot be paused"),
+        }
+    }
+}
+
+impl std::error::Error for TaskControlError {}
+
+/// Registry of live background tasks.
+///
+/// Production code uses the process-wide [`BackgroundTaskRegistry::global`];
+/// tests create their own instance for isolation.
+#[derive(Debug, Default)]
+pub struct BackgroundTaskRegistry {
+    next_id: AtomicU64,
+    tasks: Mutex<Vec<Weak<BackgroundTask>>>,
+}
+
+impl BackgroundTaskRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn global() -> &'static BackgroundTaskRegistry {
+        static GLOBAL: OnceLock<BackgroundTaskRegistry> = OnceLock::new();
+        GLOBAL.get_or_init(BackgroundTaskRegistry::new)
+    }
+
+    /// Register a task. It stays listed until the returned `Arc` and all its
+    /// clones are dropped.
+    pub fn register(
+        &self,
+        kind: BackgroundTaskKind,
+        workspace_id: impl Into<String>,
+        label: impl Into<String>,
+        pausable: bool,
+    ) -> Arc<BackgroundTask> {
+        let now = Instant::now();
+        let task = Arc::new(BackgroundTask {
+            id: self.next_id.fetch_add(1, Ordering::Relaxed) + 1,
+            kind,
+            workspace_id: workspace_id.into(),
+            label: label.into(),
+            pausable,
+            started_at: now,
+            progress: Mutex::new(Progress {
+                window_start: now,
+                completed: 0,
+                total: None,
+                detail: None,
+                paused_since: None,
+            }),
+        });
+        let mut tasks = self.tasks();
+        tasks.retain(|task| task.strong_count() > 0);
+        tasks.push(Arc::downgrade(&task));
+        task
+    }
+
+    fn tasks(&self) -> MutexGuard<'_, Vec<Weak<BackgroundTask>>> {
+        self.tasks
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    fn live_tasks(&self) -> Vec<Arc<BackgroundTask>> {
+        let mut tasks = self.tasks();
+        tasks.retain(|task| task.strong_count() > 0);
+        tasks.iter().filter_map(Weak::upgrade).collect()
+    }
+
+    /// Snapshots of every live task, oldest first.
+    pub fn snapshot(&self) -> Vec<BackgroundTaskSnapshot> {
+        self.live_tasks()
+            .iter()
+            .map(|task| task.snapshot())
+            .collect()
+    }
+
+    /// Pause or resume the task with `id`, returning its updated snapshot.
+    pub fn set_paused(
+        &self,
+        id: u64,
+        paused: bool,
+    ) -> Result<BackgroundTaskSnapshot, TaskControlError> {
+        let task = self
+            .live_tasks()
+            .into_iter()
+            .find(|task| task.id == id)
+            .ok_or(TaskControlError::NotFound(id))?;
+        if !task.pausable {
+            return Err(TaskControlError::NotPausable(id));
+        }
+        task.set_paused(paused);
+        Ok(task.snapshot())
+    }
+}
@@ -335,6 +335,9 @@ fn open_connection(db_path: &Path) -> Result<Connection> {
     let conn = Connection::open(db_path)
         .map_err(|e| anyhow!("Failed to open connection to {}: {}", db_path.display(), e))?;
 
+    // Index key — must come before anything reads the database.
+    crate::database::encryption::apply_index_key(&conn)?;
+
     // WAL mode — must be set before any other operations.
     conn.query_row("PRAGMA journal_mode = WAL", [], |_| Ok(()))
         .map_err(|e| anyhow!("Failed to set WAL mode: {e}"))?;
@@ -3,6 +3,7 @@
 use anyhow::Result;
 use rusqlite::params;
 use serde::Serialize;
+use std::collections::HashMap;
 
 /// A high-centrality symbol returned by get_top_symbols_by_centrality.
 #[derive(Debug, Clone, Serialize)]
@@ -23,6 +24,22 @@ pub struct FileHotspot {
     pub line_count: i32,
     pub size: i64,
     pub symbol_count: i64,
+    /// Commits touching the file in the churn window, when ranked by
+    /// [`SymbolDatabase::get_file_hotspots_with_churn`](super::SymbolDatabase::get_file_hotspots_with_churn).
+    pub commit_count: Option<i64>,
+}
+
+impl FileHotspot {
+    /// `line_count + symbol_count * 10`, the static ranking score.
+    pub fn complexity_score(&self) -> f64 {
+        self.line_count as f64 + self.symbol_count as f64 * 10.0
+    }
+
+    /// Complexity multiplied by commit count; the complexity score alone when
+    /// no churn was gathered.
+    pub fn risk_score(&self) -> f64 {
+        self.complexity_score() * self.commit_count.unwrap_or(1) as f64
+    }
 }
 
 /// Workspace-wide aggregate counts.
@@ -63,6 +80,11 @@ const DOCUMENTABLE_KINDS: &str =
 /// Symbol kinds checked for dead code (callable things, not type definitions).
 const DEAD_CODE_KINDS: &str = "'function','method'";
 
+/// Symbol kinds that make up a public API surface (declarations callers can
+/// name, not variables or members of private types).
+const API_SURFACE_KINDS: &str = "'function','method','constructor','class','struct','interface','trait',\
+     'enum','type','module','namespace','constant','delegate'";
+
 /// SQL fragment for excluding fixture/example/doc directories.
 /// These contain sample code that's intentionally unreferenced.
 const NON_SOURCE_EXCLUSION: &str = "
@@ -102,6 +124,50 @@ pub struct UndocumentedSymbol {
     pub reference_score: f64,
 }
 
+/// A public declaration on the workspace's API surface.
+#[derive(Debug, Clone, Serialize)]
+pub struct PublicApiSymbol {
+    pub name: String,
+    pub kind: String,
+    pub language: String,
+    pub file_path: String,
+    pub start_line: u32,
+    pub signature: Option<String>,
+    /// Enclosing declaration (e.g. the class of a public method).
+    pub parent_name: Option<String>,
+}
+
+/// A callable with a stored embedding, considered by the naming drift report.
+#[derive(Debug, Clone, Serialize)]
+pub struct NamingDriftCandidate {
+    pub id: String,
+    pub name: String,
+    pub kind: String,
+    pub language: String,
+    pub file_path: String,
+    pub start_line: u32,
+}
+
+/// A declaration with a stored embedding, considered by the topic map.
+#[derive(Debug, Clone, Serialize)]
+pub struct TopicCandidate {
+    pub id: String,
+    pub name: String,
+    pub kind: String,
+    pub language: String,
+    pub file_path: String,
+    pub start_line: u32,
+}
+
+/// Relationships from symbols in one file to symbols in another, used by
+/// the module dependency matrix.
+#[derive(Debug, Clone, Serialize)]
+pub struct FileDependency {
+    pub from_file: String,
+    pub to_file: String,
+    pub relationships: i64,
+}
+
 /// A public symbol with zero incoming references (potential dead code).
 #[derive(Debug, Clone, Serialize)]
 pub struct DeadCodeCandidate {
@@ -169,6 +235,7 @@ impl super::SymbolDatabase {
                 line_count: row.get(2)?,
                 size: row.get(3)?,
                 symbol_count: row.get(4)?,
+                commit_count: None,
             })
         })?;
 
@@ -179,6 +246,36 @@ impl super::SymbolDatabase {
         Ok(results)
     }
 
+    /// Return the top `limit` files ranked by risk: the composite score of
+    /// [`Self::get_file_hotspots`] multiplied by how often the file changed,
+    /// from `commit_counts` (path -> commits, e.g.
+    /// [`crate::git_history::commit_counts_since`]).
+    ///
+    /// Files with no commits in the window are left out: complex code nobody
+    /// touches is not where changes break things.
+    pub fn get_file_hotspots_with_churn(
+        &self,
+        limit: usize,
+        commit_counts: &HashMap<String, usize>,
+    ) -> Result<Vec<FileHotspot>> {
+        // `usize::MAX` binds as -1, which SQLite reads as "no limit".
+        let mut hotspots = self.get_file_hotspots(usize::MAX)?;
+        hotspots.retain_mut(|hotspot| match commit_counts.get(&hotspot.path) {
+            Some(&commits) if commits > 0 => {
+                hotspot.commit_count = Some(commits as i64);
+                true
+            }
+            _ => false,
+        });
+        hotspots.sort_by(|a, b| {
+            b.risk_score()
+                .total_cmp(&a.risk_score())
+                .then_with(|| a.path.cmp(&b.path))
+        });
+        hotspots.truncate(limit);
+        Ok(hotspots)
+    }
+
     /// Return aggregate workspace statistics:
     /// total files, symbols, lines, relationships, and distinct language count.
     ///
@@ -357,4 +454,176 @@ impl super::SymbolDatabase {
             .collect::<Result<Vec<_>, _>>()?;
         Ok(results)
     }
+
+    /// Count incoming usages of deprecated symbols: relationships (calls,
+    /// imports, type uses, ...) whose target carries a deprecation annotation
+    /// (`@Deprecated`, `#[deprecated]`, `[Obsolete]`) or an `@deprecated` doc
+    /// tag. Self-edges and structural `contains` edges are not usages.
+    pub fn count_deprecated_usages(&self) -> Result<i64> {
+        Ok(self.conn.query_row(
+            "SELECT COUNT(*)
+             FROM relationships r
+             JOIN symbols s ON s.id = r.to_symbol_id
+             WHERE r.from_symbol_id != r.to_symbol_id
+               AND r.kind != 'contains'
+               AND (EXISTS (SELECT 1 FROM symbol_annotations a
+                            WHERE a.symbol_id = s.id
+                              AND a.annotation_key IN ('deprecated', 'obsolete'))
+                    OR s.doc_comment LIKE '%@deprecated%')",
+            [],
+            |row| row.get(0),
+        )?)
+    }
+
+    /// Return the public/exported declarations under `focus_path` (a file or
+    /// directory, workspace-relative; `None` for the whole workspace), in
+    /// file and line order.
+    ///
+    /// Members whose enclosing declaration is known to be non-public are
+    /// skipped: a public method on a private class is not reachable API.
+    /// Excludes test files and fixture/example/doc directories.
+    pub fn get_public_api_symbols(&self, focus_path: Option<&str>) -> Result<Vec<PublicApiSymbol>> {
+        let focus_path = focus_path.map(|path| path.trim_end_matches('/'));
+        let sql = format!(
+            "SELECT name, kind, language, file_path, start_line, signature,
+                    (SELECT p.name FROM symbols p WHERE p.id = symbols.parent_id)
+             FROM symbols
+             WHERE visibility = 'public'
+               AND kind IN ({API_SURFACE_KINDS})
+               AND content_type IS NULL
+               AND (parent_id IS NULL OR parent_id NOT IN (
+                   SELECT id FROM symbols
+                   WHERE visibility IS NOT NULL AND visibility != 'public'))
+               AND (?1 IS NULL OR file_path = ?1
+                    OR substr(file_path, 1, length(?1) + 1) = ?1 || '/')
+               {TEST_PATH_EXCLUSION}
+               {NON_SOURCE_EXCLUSION}
+             ORDER BY file_path, start_line, name"
+        );
+        let mut stmt = self.conn.prepare(&sql)?;
+        let results = stmt
+            .query_map(params![focus_path], |row| {
+                Ok(PublicApiSymbol {
+                    name: row.get(0)?,
+                    kind: row.get(1)?,
+                    language: row.get(2)?,
+                    file_path: row.get(3)?,
+                    start_line: row.get(4)?,
+                    signature: row.get(5)?,
+                    parent_name: row.get(6)?,
+                })
+            })?
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(results)
+    }
+
+    /// Return the functions and methods under `focus_path` (workspace-relative
+    /// file or directory; `None` for the whole workspace) that have a stored
+    /// embedding, in file and line order.
+    ///
+    /// Excludes test files and fixture/example/doc directories.
+    pub fn get_naming_drift_candidates(
+        &self,
+        focus_path: Option<&str>,
+    ) -> Result<Vec<NamingDriftCandidate>> {
+        let focus_path = focus_path.map(|path| path.trim_end_matches('/'));
+        let sql = format!(
+            "SELECT id, name, kind, language, file_path, start_line
+             FROM symbols
+             WHERE kind IN ({DEAD_CODE_KINDS})
+               AND content_type IS NULL
+               AND id IN (SELECT symbol_id FROM symbol_vectors)
+               AND (?1 IS NULL OR file_path = ?1
+                    OR substr(file_path, 1, length(?1) + 1) = ?1 || '/')
+               {TEST_PATH_EXCLUSION}
+               {NON_SOURCE_EXCLUSION}
+             ORDER BY file_path, start_line, name"
+        );
+        let mut stmt = self.conn.prepare(&sql)?;
+        let results = stmt
+            .query_map(params![focus_path], |row| {
+                Ok(NamingDriftCandidate {
+                    id: row.get(0)?,
+                    name: row.get(1)?,
+                    kind: row.get(2)?,
+                    language: row.get(3)?,
+                    file_path: row.get(4)?,
+                    start_line: row.get(5)?,
+                })
+            })?
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(results)
+    }
+
+    /// Return the declarations (API surface kinds) under `focus_path`
+    /// (workspace-relative file or directory; `None` for the whole
+    /// workspace) that have a stored embedding, in file and line order.
+    ///
+    /// Excludes test files and fixture/example/doc directories.
+    pub fn get_topic_candidates(&self, focus_path: Option<&str>) -> Result<Vec<TopicCandidate>> {
+        let focus_path = focus_path.map(|path| path.trim_end_matches('/'));
+        let sql = format!(
+            "SELECT id, name, kind, language, file_path, start_line
+             FROM symbols
+             WHERE kind IN ({API_SURFACE_KINDS})
+               AND content_type IS NULL
+               AND id IN (SELECT symbol_id FROM symbol_vectors)
+               AND (?1 IS NULL OR file_path = ?1
+                    OR substr(file_path, 1, length(?1) + 1) = ?1 || '/')
+               {TEST_PATH_EXCLUSION}
+               {NON_SOURCE_EXCLUSION}
+             ORDER BY file_path, start_line, name"
+        );
+        let mut stmt = self.conn.prepare(&sql)?;
+        let results = stmt
+            .query_map(params![focus_path], |row| {
+                Ok(TopicCandidate {
+                    id: row.get(0)?,
+                    name: row.get(1)?,
+                    kind: row.get(2)?,
+                    language: row.get(3)?,
+                    file_path: row.get(4)?,
+                    start_line: row.get(5)?,
+                })
+            })?
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(results)
+    }
+
+    /// Count cross-file relationships between files under `focus_path`
+    /// (workspace-relative file or directory; `None` for the whole
+    /// workspace), one row per (source file, target file) pair.
+    ///
+    /// Structural `contains` edges are not dependencies. Test files and
+    /// fixture/example/doc directories are excluded on both ends.
+    pub fn get_file_dependencies(&self, focus_path: Option<&str>) -> Result<Vec<FileDependency>> {
+        let focus_path = focus_path.map(|path| path.trim_end_matches('/'));
+        let sql = format!(
+            "WITH scoped AS (
+                SELECT id, file_path FROM symbols
+                WHERE (?1 IS NULL OR file_path = ?1
+                       OR substr(file_path, 1, length(?1) + 1) = ?1 || '/')
+                  {TEST_PATH_EXCLUSION}
+                  {NON_SOURCE_EXCLUSION}
+             )
+             SELECT f.file_path, t.file_path, COUNT(*)
+             FROM relationships r
+             JOIN scoped f ON f.id = r.from_symbol_id
+             JOIN scoped t ON t.id = r.to_symbol_id
+             WHERE r.kind != 'contains' AND f.file_path != t.file_path
+             GROUP BY f.file_path, t.file_path
+             ORDER BY f.file_path, t.file_path"
+        );
+        let mut stmt = self.conn.prepare(&sql)?;
+        let results = stmt
+            .query_map(params![focus_path], |row| {
+                Ok(FileDependency {
+                    from_file: row.get(0)?,
+                    to_file: row.get(1)?,
+                    relationships: row.get(2)?,
+                })
+            })?
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(results)
+    }
 }
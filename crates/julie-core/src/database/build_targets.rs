@@ -0,0 +1,105 @@
+//! Derived build target membership of indexed files.
+//!
+//! Build target symbols (from Makefiles, CMake scripts and Bazel packages)
+//! list the files they compile. The post-index analysis pass in julie-index
+//! keeps the entries that name indexed files and records them here, so tools
+//! can say which targets build a given file.
+
+use anyhow::Result;
+use rusqlite::{params, params_from_iter};
+use std::collections::HashMap;
+use tracing::debug;
+
+use super::SymbolDatabase;
+
+/// One indexed file compiled by one build target.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BuildTargetFile {
+    pub file_path: String,
+    /// Symbol id of the build target.
+    pub target_id: String,
+    /// Target label (`//src/net:http` for Bazel, the target name otherwise).
+    pub label: String,
+    /// Build file that declares the target.
+    pub build_file: String,
+}
+
+impl SymbolDatabase {
+    /// Replace the entire `build_target_files` table. The table is derived
+    /// data, so the analysis pass wipes and recomputes it on each run.
+    pub fn replace_all_build_target_files(&self, files: &[BuildTargetFile]) -> Result<()> {
+        self.conn.execute_batch("BEGIN")?;
+        let result = (|| -> Result<()> {
+            self.conn.execute("DELETE FROM build_target_files", [])?;
+            let mut stmt = self.conn.prepare(
+                "INSERT OR REPLACE INTO build_target_files (file_path, target_id, label, build_file)
+                 VALUES (?1, ?2, ?3, ?4)",
+            )?;
+            for file in files {
+                stmt.execute(params![
+                    file.file_path,
+                    file.target_id,
+                    file.label,
+                    file.build_file
+                ])?;
+            }
+            Ok(())
+        })();
+
+        match result {
+            Ok(()) => self.conn.execute_batch("COMMIT")?,
+            Err(e) => {
+                let _ = self.conn.execute_batch("ROLLBACK");
+                return Err(e);
+            }
+        }
+        debug!(
+            "Replaced build_target_files table: {} rows inserted",
+            files.len()
+        );
+        Ok(())
+    }
+
+    /// Build targets compiling each of `file_paths`, keyed by file path and
+    /// sorted by label. Files no target compiles are absent.
+    pub fn build_targets_for_files(
+        &self,
+        file_paths: &[String],
+    ) -> Result<HashMap<String, Vec<BuildTargetFile>>> {
+        if file_paths.is_empty() {
+            return Ok(HashMap::new());
+        }
+
+        const CHUNK_SIZE: usize = 500;
+        let mut unique_paths = file_paths.to_vec();
+        unique_paths.sort();
+        unique_paths.dedup();
+        let mut targets: HashMap<String, Vec<BuildTargetFile>> = HashMap::new();
+
+        for chunk in unique_paths.chunks(CHUNK_SIZE) {
+            let placeholders = (1..=chunk.len())
+                .map(|i| format!("?{i}"))
+                .collect::<Vec<_>>()
+                .join(",");
+            let sql = format!(
+                "SELECT file_path, target_id, label, build_file FROM build_target_files
+                 WHERE file_path IN ({placeholders})
+                 ORDER BY label, build_file"
+            );
+            let mut stmt = self.conn.prepare(&sql)?;
+            let rows = stmt.query_map(params_from_iter(chunk.iter()), |row| {
+                Ok(BuildTargetFile {
+                    file_path: row.get(0)?,
+                    target_id: row.get(1)?,
+                    label: row.get(2)?,
+                    build_file: row.get(3)?,
+                })
+            })?;
+            for row in rows {
+                let row = row?;
+                targets.entry(row.file_path.clone()).or_default().push(row);
+            }
+        }
+        Ok(targets)
+    }
+}
@@ -1,16 +1,25 @@
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 use std::ptr::NonNull;
+use std::time::Instant;
 
 use anyhow::Result;
 use rusqlite::{Connection, Transaction, params};
 
+use crate::database::context_store::{
+    context_hashes_for_files, delete_unreferenced_contexts, store_code_context,
+};
 use crate::database::revision_changes::{
     RevisionChangeKind, RevisionFileChange, record_revision_file_changes_tx,
     snapshot_file_hashes_tx,
 };
+use crate::database::revision_symbol_changes::{
+    SymbolFingerprint, record_revision_symbol_changes_tx, snapshot_symbols_tx,
+};
 use crate::database::revisions::record_canonical_revision_tx;
 use crate::database::symbols::annotations::replace_annotations_batch;
 use crate::database::{CanonicalRevisionKind, FileInfo, SymbolDatabase};
+use crate::paths::normalize_stored_path;
+use crate::telemetry::Telemetry;
 use julie_extractors::{Relationship, Symbol};
 
 use super::cleanup::{
@@ -30,21 +39,21 @@ pub use super::write_set::{AtomicPersistenceMetadata, CanonicalWriteSet};
 use super::{collect_referenced_symbol_ids, load_existing_symbol_ids_tx};
 
 #[derive(Default)]
-struct InsertCounts {
-    files: i64,
-    symbols: i64,
-    relationships: i64,
-    identifiers: i64,
-    types: i64,
-    type_arguments: i64,
-    literals: i64,
-    source_regions: i64,
-    structural_facts: i64,
-    complexity_metrics: i64,
+pub(super) struct InsertCounts {
+    pub(super) files: i64,
+    pub(super) symbols: i64,
+    pub(super) relationships: i64,
+    pub(super) identifiers: i64,
+    pub(super) types: i64,
+    pub(super) type_arguments: i64,
+    pub(super) literals: i64,
+    pub(super) source_regions: i64,
+    pub(super) structural_facts: i64,
+    pub(super) complexity_metrics: i64,
 }
 
 impl InsertCounts {
-    fn has_changes(&self, cleaned_files: usize) -> bool {
+    pub(super) fn has_changes(&self, cleaned_files: usize) -> bool {
         cleaned_files > 0
             || self.files > 0
             || self.symbols > 0
@@ -57,6 +66,19 @@ impl InsertCounts {
             || self.structural_facts > 0
             || self.complexity_metrics > 0
     }
+
+    pub(super) fn add(&mut self, other: &InsertCounts) {
+        self.files += other.files;
+        self.symbols += other.symbols;
+        self.relationships += other.relationships;
+        self.identifiers += other.identifiers;
+        self.types += other.types;
+        self.type_arguments += other.type_arguments;
+        self.literals += other.literals;
+        self.source_regions += other.source_regions;
+        self.structural_facts += other.structural_facts;
+        self.complexity_metrics += other.complexity_metrics;
+    }
 }
 
 impl SymbolDatabase {
@@ -99,17 +121,28 @@ impl SymbolDatabase {
         metadata: AtomicPersistenceMetadata<'_>,
     ) -> Result<Option<i64>> {
         require_workspace_id(workspace_id)?;
+        let started = Instant::now();
         let now = unix_timestamp()?;
         let fk_guard = ForeignKeyGuard::disable(&self.conn)?;
 
+        let files_to_clean: Vec<String> = files_to_clean
+            .iter()
+            .map(|path| normalize_stored_path(path).into_owned())
+            .collect();
+        let files_to_clean = files_to_clean.as_slice();
+
         let result = (|| {
             let tx = self.conn.transaction()?;
             let existing_hashes = snapshot_file_hashes_tx(&tx, files_to_clean)?;
+            let existing_symbols = snapshot_write_set_symbols_tx(&tx, files_to_clean, write_set)?;
+            let replaced_contexts = context_hashes_for_files(&tx, files_to_clean)?;
             for file_path in files_to_clean {
                 delete_file_rows_tx(&tx, file_path)?;
             }
 
             let counts = insert_batch_tx(&tx, write_set, now, false)?;
+            // After the insert, so contexts the new symbols share are kept.
+            delete_unreferenced_contexts(&tx, &replaced_contexts)?;
             let revision = if counts.has_changes(files_to_clean.len()) {
                 let revision = record_canonical_revision_tx(
                     &tx,
@@ -130,6 +163,13 @@ impl SymbolDatabase {
                     write_set.files,
                     &existing_hashes,
                 )?;
+                record_revision_symbol_changes_tx(
+                    &tx,
+                    revision,
+                    workspace_id,
+                    &existing_symbols,
+                    write_set.symbols,
+                )?;
                 Some(revision)
             } else {
                 None
@@ -147,6 +187,7 @@ impl SymbolDatabase {
         if result.is_ok() {
             checkpoint_wal_best_effort(self);
         }
+        Telemetry::global().record_db_write("incremental_update", started.elapsed());
         result
     }
 
@@ -204,6 +245,9 @@ impl SymbolDatabase {
         metadata: AtomicPersistenceMetadata<'_>,
     ) -> Result<Option<i64>> {
         require_workspace_id(workspace_id)?;
+        let file_path = normalize_stored_path(file_path);
+        let file_path = file_path.as_ref();
+        let started = Instant::now();
         let now = unix_timestamp()?;
         let fk_guard = ForeignKeyGuard::disable(&self.conn)?;
         let result = (|| {
@@ -215,7 +259,10 @@ impl SymbolDatabase {
                 return Ok(None);
             }
 
+            let existing_symbols = snapshot_symbols_tx(&tx, Some(&paths))?;
+            let replaced_contexts = context_hashes_for_files(&tx, &paths)?;
             delete_file_rows_tx(&tx, file_path)?;
+            delete_unreferenced_contexts(&tx, &replaced_contexts)?;
             let revision = record_canonical_revision_tx(
                 &tx,
                 workspace_id,
@@ -236,6 +283,7 @@ impl SymbolDatabase {
                 new_hash: None,
             }];
             record_revision_file_changes_tx(&tx, revision, workspace_id, &changes)?;
+            record_revision_symbol_changes_tx(&tx, revision, workspace_id, &existing_symbols, &[])?;
             if metadata.mark_external_analysis_stale {
                 mark_external_analysis_stale_tx(&tx, now)?;
             }
@@ -243,6 +291,7 @@ impl SymbolDatabase {
             Ok(Some(revision))
         })();
         fk_guard.restore()?;
+        Telemetry::global().record_db_write("delete_file", started.elapsed());
         result
     }
 }
@@ -255,13 +304,18 @@ fn fresh_insert_atomic(
     replace_existing: bool,
 ) -> Result<Option<i64>> {
     require_workspace_id(workspace_id)?;
+    let started = Instant::now();
     let now = unix_timestamp()?;
     let fk_guard = ForeignKeyGuard::disable(&db.conn)?;
     let result = (|| {
         let tx = db.conn.transaction()?;
-        if replace_existing {
+        let existing_symbols = if replace_existing {
+            let existing_symbols = snapshot_symbols_tx(&tx, None)?;
             delete_all_indexed_rows_tx(&tx)?;
-        }
+            existing_symbols
+        } else {
+            snapshot_write_set_symbols_tx(&tx, &[], write_set)?
+        };
 
         let counts = insert_batch_tx(&tx, write_set, now, true)?;
         let revision = if counts.has_changes(0) {
@@ -282,13 +336,20 @@ fn fresh_insert_atomic(
                 .map(|file| RevisionFileChange {
                     revision,
                     workspace_id: workspace_id.to_string(),
-                    file_path: file.path.clone(),
+                    file_path: normalize_stored_path(&file.path).into_owned(),
                     change_kind: RevisionChangeKind::Added,
                     old_hash: None,
                     new_hash: Some(file.hash.clone()),
                 })
                 .collect();
             record_revision_file_changes_tx(&tx, revision, workspace_id, &changes)?;
+            record_revision_symbol_changes_tx(
+                &tx,
+                revision,
+                workspace_id,
+                &existing_symbols,
+                write_set.symbols,
+            )?;
             Some(revision)
         } else {
             None
@@ -305,10 +366,34 @@ fn fresh_insert_atomic(
     if result.is_ok() {
         checkpoint_wal_best_effort(db);
     }
+    let operation = if replace_existing {
+        "replace_workspace"
+    } else {
+        "bulk_store_fresh"
+    };
+    Telemetry::global().record_db_write(operation, started.elapsed());
     result
 }
 
-fn insert_batch_tx(
+/// Symbols about to be replaced by `write_set`: those of the cleaned files
+/// plus any already stored for the files it writes.
+fn snapshot_write_set_symbols_tx(
+    tx: &Transaction<'_>,
+    files_to_clean: &[String],
+    write_set: &CanonicalWriteSet<'_>,
+) -> Result<HashMap<String, SymbolFingerprint>> {
+    let mut seen: HashSet<String> = files_to_clean.iter().cloned().collect();
+    let mut paths = files_to_clean.to_vec();
+    for file in write_set.files {
+        let path = normalize_stored_path(&file.path).into_owned();
+        if seen.insert(path.clone()) {
+            paths.push(path);
+        }
+    }
+    snapshot_symbols_tx(tx, Some(&paths))
+}
+
+pub(super) fn insert_batch_tx(
     tx: &Transaction<'_>,
     write_set: &CanonicalWriteSet<'_>,
     now: i64,
@@ -366,7 +451,7 @@ fn insert_files_tx(tx: &Transaction<'_>, files: &[FileInfo], now: i64) -> Result
     )?;
     for file in files {
         stmt.execute(params![
-            file.path,
+            normalize_stored_path(&file.path),
             file.language,
             file.hash,
             file.size,
@@ -398,6 +483,7 @@ fn insert_symbols_tx(tx: &Transaction<'_>, symbols: &[Symbol]) -> Result<i64> {
             .map(serde_json::to_string)
             .transpose()?;
         let visibility_str = symbol.visibility.as_ref().map(|v| v.as_storage_str());
+        let context_hash = store_code_context(tx, symbol.code_context.as_deref())?;
         let parent_id = symbol.parent_id.as_deref().filter(|parent_id| {
             batch_symbol_ids.contains(*parent_id) || existing_parent_ids.contains(*parent_id)
         });
@@ -406,7 +492,7 @@ fn insert_symbols_tx(tx: &Transaction<'_>, symbols: &[Symbol]) -> Result<i64> {
             symbol.name,
             symbol.kind.to_string(),
             symbol.language,
-            symbol.file_path,
+            normalize_stored_path(&symbol.file_path),
             symbol.signature,
             symbol.start_line,
             symbol.start_column,
@@ -416,7 +502,7 @@ fn insert_symbols_tx(tx: &Transaction<'_>, symbols: &[Symbol]) -> Result<i64> {
             symbol.end_byte,
             symbol.doc_comment,
             visibility_str,
-            symbol.code_context,
+            context_hash,
             parent_id,
             metadata_json,
             symbol.semantic_group,
@@ -436,7 +522,7 @@ fn insert_symbols_tx(tx: &Transaction<'_>, symbols: &[Symbol]) -> Result<i64> {
     Ok(symbols.len() as i64)
 }
 
-fn mark_external_analysis_stale_tx(tx: &Transaction<'_>, now: i64) -> Result<()> {
+pub(super) fn mark_external_analysis_stale_tx(tx: &Transaction<'_>, now: i64) -> Result<()> {
     tx.execute(
         "INSERT INTO external_extract_metadata (key, value, updated_at)
          VALUES ('analysis_state', 'stale', ?1)
@@ -462,13 +548,13 @@ fn mark_external_analysis_stale_tx(tx: &Transaction<'_>, now: i64) -> Result<()>
     Ok(())
 }
 
-struct ForeignKeyGuard {
+pub(super) struct ForeignKeyGuard {
     conn: NonNull<Connection>,
     active: bool,
 }
 
 impl ForeignKeyGuard {
-    fn disable(conn: &Connection) -> Result<Self> {
+    pub(super) fn disable(conn: &Connection) -> Result<Self> {
         conn.execute("PRAGMA foreign_keys = OFF", [])?;
         Ok(Self {
             conn: NonNull::from(conn),
@@ -476,7 +562,7 @@ impl ForeignKeyGuard {
         })
     }
 
-    fn restore(mut self) -> Result<()> {
+    pub(super) fn restore(mut self) -> Result<()> {
         // SAFETY: The guard is created from a live Connection reference and is
         // dropped before the surrounding function returns.
         unsafe {
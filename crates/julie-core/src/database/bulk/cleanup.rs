@@ -1,17 +1,27 @@
+use std::collections::HashSet;
+
 use anyhow::{Result, bail};
 use rusqlite::{Transaction, params};
 use tracing::warn;
 
 use crate::database::bulk::atomic::AtomicPersistenceMetadata;
+use crate::database::bulk::identifiers::recount_identifier_references_tx;
+use crate::database::chunk_vectors::delete_chunks_for_symbols;
 use crate::database::revision_changes::{
     RevisionChangeKind, RevisionFileChange, record_revision_file_changes_tx,
 };
 use crate::database::symbols::annotations::delete_annotations_for_file;
-use crate::database::{FileInfo, SymbolDatabase};
+use crate::database::{FileInfo, PARSE_SKIPPED_REASON, SymbolDatabase};
+use crate::paths::normalize_stored_path;
 
 const EXTRACTOR_FAILURE_REASON: &str = "extractor_failure";
 
 pub(super) fn delete_file_rows_tx(tx: &Transaction<'_>, file_path: &str) -> Result<()> {
+    delete_chunks_for_symbols(
+        tx,
+        "SELECT id FROM symbols WHERE file_path = ?1",
+        params![file_path],
+    )?;
     tx.execute(
         "DELETE FROM symbol_vectors WHERE symbol_id IN (SELECT id FROM symbols WHERE file_path = ?1)",
         params![file_path],
@@ -86,12 +96,48 @@ pub(super) fn delete_file_rows_tx(tx: &Transaction<'_>, file_path: &str) -> Resu
             OR to_symbol_id IN (SELECT id FROM symbols WHERE file_path = ?1)",
         params![file_path],
     )?;
+    tx.execute(
+        "DELETE FROM cross_language_links
+         WHERE from_symbol_id IN (SELECT id FROM symbols WHERE file_path = ?1)
+            OR to_symbol_id IN (SELECT id FROM symbols WHERE file_path = ?1)",
+        params![file_path],
+    )?;
+    tx.execute(
+        "DELETE FROM symbol_aliases
+         WHERE symbol_id IN (SELECT id FROM symbols WHERE file_path = ?1)",
+        params![file_path],
+    )?;
+    tx.execute(
+        "DELETE FROM swift_module_files WHERE file_path = ?1",
+        params![file_path],
+    )?;
+    tx.execute(
+        "DELETE FROM identifier_external_modules
+         WHERE identifier_id IN (
+            SELECT id FROM identifiers
+            WHERE file_path = ?1
+               OR containing_symbol_id IN (SELECT id FROM symbols WHERE file_path = ?1)
+         )",
+        params![file_path],
+    )?;
+    // The symbols these identifiers resolve to lose them from their
+    // identifier_reference_count; recount them once the rows are gone.
+    let recount = tx
+        .prepare(
+            "SELECT DISTINCT target_symbol_id FROM identifiers
+             WHERE target_symbol_id IS NOT NULL
+               AND (file_path = ?1
+                    OR containing_symbol_id IN (SELECT id FROM symbols WHERE file_path = ?1))",
+        )?
+        .query_map(params![file_path], |row| row.get::<_, String>(0))?
+        .collect::<rusqlite::Result<HashSet<String>>>()?;
     tx.execute(
         "DELETE FROM identifiers
          WHERE file_path = ?1
             OR containing_symbol_id IN (SELECT id FROM symbols WHERE file_path = ?1)",
         params![file_path],
     )?;
+    recount_identifier_references_tx(tx, &recount)?;
     tx.execute(
         "DELETE FROM types WHERE symbol_id IN (SELECT id FROM symbols WHERE file_path = ?1)",
         params![file_path],
@@ -112,10 +158,16 @@ pub(super) fn delete_file_rows_tx(tx: &Transaction<'_>, file_path: &str) -> Resu
 pub(super) fn delete_all_indexed_rows_tx(tx: &Transaction<'_>) -> Result<()> {
     for sql in [
         "DELETE FROM symbol_vectors",
+        "DELETE FROM symbol_chunk_vectors",
+        "DELETE FROM symbol_chunks",
         "DELETE FROM source_regions",
         "DELETE FROM structural_facts",
         "DELETE FROM complexity_metrics",
         "DELETE FROM web_edges",
+        "DELETE FROM cross_language_links",
+        "DELETE FROM identifier_external_modules",
+        "DELETE FROM symbol_aliases",
+        "DELETE FROM swift_module_files",
         "DELETE FROM literals",
         "DELETE FROM type_arguments",
         "DELETE FROM identifiers",
@@ -123,6 +175,7 @@ pub(super) fn delete_all_indexed_rows_tx(tx: &Transaction<'_>) -> Result<()> {
         "DELETE FROM relationships",
         "DELETE FROM symbol_annotations",
         "DELETE FROM symbols",
+        "DELETE FROM context_blobs",
         "DELETE FROM files",
         "DELETE FROM indexing_repairs",
     ] {
@@ -140,22 +193,26 @@ pub(super) fn record_incremental_file_changes(
     existing_hashes: &std::collections::HashMap<String, String>,
 ) -> Result<()> {
     let mut changes = Vec::new();
-    for file in new_files {
-        let (change_kind, old_hash) = match existing_hashes.get(&file.path) {
+    let new_paths: Vec<String> = new_files
+        .iter()
+        .map(|file| normalize_stored_path(&file.path).into_owned())
+        .collect();
+    for (file, path) in new_files.iter().zip(&new_paths) {
+        let (change_kind, old_hash) = match existing_hashes.get(path) {
             Some(old_hash) => (RevisionChangeKind::Modified, Some(old_hash.clone())),
             None => (RevisionChangeKind::Added, None),
         };
         changes.push(RevisionFileChange {
             revision,
             workspace_id: workspace_id.to_string(),
-            file_path: file.path.clone(),
+            file_path: path.clone(),
             change_kind,
             old_hash,
             new_hash: Some(file.hash.clone()),
         });
     }
     for file_path in files_to_clean {
-        if new_files.iter().any(|file| file.path == *file_path) {
+        if new_paths.contains(file_path) {
             continue;
         }
         if let Some(old_hash) = existing_hashes.get(file_path) {
@@ -180,7 +237,7 @@ pub(super) fn persist_batch_metadata_tx(
     for file in successful_files {
         tx.execute(
             "DELETE FROM indexing_repairs WHERE path = ?1",
-            params![file.path],
+            params![normalize_stored_path(&file.path)],
         )?;
     }
     for (path, diagnostics) in metadata.parse_diagnostics_by_file {
@@ -191,14 +248,31 @@ pub(super) fn persist_batch_metadata_tx(
         };
         tx.execute(
             "UPDATE files SET parse_cache = ?2 WHERE path = ?1",
-            params![path, payload],
+            params![normalize_stored_path(path), payload],
         )?;
     }
     for (path, detail) in metadata.repair_entries {
         tx.execute(
             "INSERT OR REPLACE INTO indexing_repairs (path, reason, detail, updated_at)
              VALUES (?1, ?2, ?3, ?4)",
-            params![path, EXTRACTOR_FAILURE_REASON, detail, unix_timestamp()?],
+            params![
+                normalize_stored_path(path),
+                EXTRACTOR_FAILURE_REASON,
+                detail,
+                unix_timestamp()?
+            ],
+        )?;
+    }
+    for (path, reason) in metadata.parse_skips {
+        tx.execute(
+            "INSERT OR REPLACE INTO indexing_repairs (path, reason, detail, updated_at)
+             VALUES (?1, ?2, ?3, ?4)",
+            params![
+                normalize_stored_path(path),
+                PARSE_SKIPPED_REASON,
+                reason,
+                unix_timestamp()?
+            ],
         )?;
     }
     Ok(())
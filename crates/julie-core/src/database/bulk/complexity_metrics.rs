@@ -4,6 +4,8 @@ use anyhow::Result;
 use julie_extractors::base::ComplexityMetric;
 use rusqlite::{Transaction, params};
 
+use crate::paths::normalize_stored_path;
+
 pub(crate) fn insert_complexity_metrics_tx(
     tx: &Transaction<'_>,
     metrics: &[ComplexityMetric],
@@ -30,7 +32,7 @@ pub(crate) fn insert_complexity_metrics_tx(
             .transpose()?;
         stmt.execute(params![
             metric.id,
-            metric.file_path,
+            normalize_stored_path(&metric.file_path),
             metric.language,
             metric.scope,
             symbol_id,
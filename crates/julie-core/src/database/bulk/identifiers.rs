@@ -5,6 +5,7 @@ use rusqlite::{Transaction, params};
 use tracing::{debug, info};
 
 use crate::database::SymbolDatabase;
+use crate::paths::normalize_stored_path;
 
 const IDENTIFIER_SECONDARY_INDEXES: &[(&str, &str)] = &[
     ("idx_identifiers_name", "identifiers(name)"),
@@ -30,6 +31,8 @@ const IDENTIFIER_SECONDARY_INDEXES: &[(&str, &str)] = &[
     ),
 ];
 
+const RECOUNT_CHUNK_SIZE: usize = 500;
+
 pub(crate) fn insert_identifiers_tx(
     tx: &Transaction<'_>,
     identifiers: &[julie_extractors::Identifier],
@@ -39,6 +42,19 @@ pub(crate) fn insert_identifiers_tx(
         return Ok(0);
     }
 
+    let (inserted, recount) = write_identifiers_tx(tx, identifiers, valid_symbol_ids)?;
+    recount_identifier_references_tx(tx, &recount)?;
+    Ok(inserted)
+}
+
+/// Write `identifiers` and return how many were written along with the
+/// symbols whose identifier_reference_count may have changed: the targets of
+/// the rows replaced and of the rows written.
+fn write_identifiers_tx(
+    tx: &Transaction<'_>,
+    identifiers: &[julie_extractors::Identifier],
+    valid_symbol_ids: Option<&HashSet<String>>,
+) -> Result<(i64, HashSet<String>)> {
     let mut stmt = tx.prepare(
         "INSERT OR REPLACE INTO identifiers
          (id, name, kind, language, file_path, start_line, start_col,
@@ -47,6 +63,10 @@ pub(crate) fn insert_identifiers_tx(
          VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15)",
     )?;
 
+    let mut recount = stored_identifier_targets_tx(
+        tx,
+        identifiers.iter().map(|identifier| identifier.id.as_str()),
+    )?;
     let mut inserted = 0;
     for identifier in identifiers {
         let containing_symbol_id = normalize_symbol_ref(
@@ -61,13 +81,16 @@ pub(crate) fn insert_identifiers_tx(
             &identifier.id,
             "target_symbol_id",
         );
+        if let Some(target) = &target_symbol_id {
+            recount.insert(target.clone());
+        }
 
         stmt.execute(params![
             identifier.id,
             identifier.name,
             identifier.kind.to_string(),
             identifier.language,
-            identifier.file_path,
+            normalize_stored_path(&identifier.file_path),
             identifier.start_line,
             identifier.start_column,
             identifier.end_line,
@@ -82,7 +105,56 @@ pub(crate) fn insert_identifiers_tx(
         inserted += 1;
     }
 
-    Ok(inserted)
+    Ok((inserted, recount))
+}
+
+/// Targets of the stored identifiers among `identifier_ids`.
+fn stored_identifier_targets_tx<'a>(
+    tx: &Transaction<'_>,
+    identifier_ids: impl Iterator<Item = &'a str>,
+) -> Result<HashSet<String>> {
+    let ids: Vec<&str> = identifier_ids.collect();
+    let mut targets = HashSet::new();
+    for chunk in ids.chunks(RECOUNT_CHUNK_SIZE) {
+        let placeholders = vec!["?"; chunk.len()].join(",");
+        let mut stmt = tx.prepare(&format!(
+            "SELECT DISTINCT target_symbol_id FROM identifiers
+             WHERE target_symbol_id IS NOT NULL AND id IN ({placeholders})"
+        ))?;
+        let rows = stmt.query_map(rusqlite::params_from_iter(chunk), |row| {
+            row.get::<_, String>(0)
+        })?;
+        for row in rows {
+            targets.insert(row?);
+        }
+    }
+    Ok(targets)
+}
+
+/// Recount `symbols.identifier_reference_count` for `symbol_ids` from the
+/// identifiers resolving to them, skipping identifiers inside the symbol they
+/// name (recursion). Writers call it once per batch for the symbols the batch
+/// touched, instead of a trigger adjusting the count row by row.
+pub(crate) fn recount_identifier_references_tx(
+    tx: &Transaction<'_>,
+    symbol_ids: &HashSet<String>,
+) -> Result<()> {
+    let ids: Vec<&String> = symbol_ids.iter().collect();
+    for chunk in ids.chunks(RECOUNT_CHUNK_SIZE) {
+        let placeholders = vec!["?"; chunk.len()].join(",");
+        tx.execute(
+            &format!(
+                "UPDATE symbols SET identifier_reference_count = (
+                    SELECT COUNT(*) FROM identifiers i
+                    WHERE i.target_symbol_id = symbols.id
+                      AND i.containing_symbol_id IS NOT i.target_symbol_id
+                 )
+                 WHERE id IN ({placeholders})"
+            ),
+            rusqlite::params_from_iter(chunk),
+        )?;
+    }
+    Ok(())
 }
 
 pub(crate) fn insert_identifiers_with_deferred_indexes_tx(
@@ -95,8 +167,10 @@ pub(crate) fn insert_identifiers_with_deferred_indexes_tx(
     }
 
     drop_identifier_secondary_indexes_tx(tx)?;
-    let inserted = insert_identifiers_tx(tx, identifiers, valid_symbol_ids)?;
+    let (inserted, recount) = write_identifiers_tx(tx, identifiers, valid_symbol_ids)?;
     create_identifier_secondary_indexes_tx(tx)?;
+    // After the indexes are back: the recount looks identifiers up by target.
+    recount_identifier_references_tx(tx, &recount)?;
     Ok(inserted)
 }
 
@@ -14,6 +14,7 @@ use rusqlite::{Transaction, params};
 use tracing::{debug, info};
 
 use crate::database::SymbolDatabase;
+use crate::paths::normalize_stored_path;
 use julie_extractors::Literal;
 
 pub(crate) fn insert_literals_tx(
@@ -48,7 +49,7 @@ pub(crate) fn insert_literals_tx(
             literal.carrier,
             literal.arg_position,
             literal.language,
-            literal.file_path,
+            normalize_stored_path(&literal.file_path),
             literal.start_line,
             literal.start_column,
             literal.end_line,
@@ -10,6 +10,7 @@ pub mod cleanup;
 pub mod complexity_metrics;
 pub mod identifiers;
 pub mod literals;
+pub mod rebuild;
 pub mod relationships;
 pub mod source_regions;
 pub mod structural_facts;
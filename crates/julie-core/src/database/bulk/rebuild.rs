@@ -0,0 +1,153 @@
+//! Workspace rebuilds written in parts.
+//!
+//! `replace_workspace_data_atomic` takes the whole write set at once, which a
+//! distributed merge of a large tree cannot hold in memory. A streamed rebuild
+//! clears the indexed rows and inserts each part as it arrives, all inside one
+//! transaction: nothing is visible until every part is written, and an error
+//! from any part leaves the previous index untouched.
+
+use std::time::Instant;
+
+use anyhow::Result;
+use rusqlite::Transaction;
+
+use crate::database::revision_changes::{
+    RevisionChangeKind, RevisionFileChange, record_revision_file_changes_tx,
+};
+use crate::database::revision_symbol_changes::{
+    record_revision_snapshot_changes_tx, snapshot_symbols_tx,
+};
+use crate::database::revisions::record_canonical_revision_tx;
+use crate::database::{CanonicalRevisionKind, SymbolDatabase};
+use crate::paths::normalize_stored_path;
+use crate::telemetry::Telemetry;
+
+use super::atomic::{
+    ForeignKeyGuard, InsertCounts, insert_batch_tx, mark_external_analysis_stale_tx,
+};
+use super::cleanup::{
+    checkpoint_wal_best_effort, delete_all_indexed_rows_tx, persist_batch_metadata_tx,
+    require_workspace_id, unix_timestamp,
+};
+use super::write_set::{AtomicPersistenceMetadata, CanonicalWriteSet};
+
+/// Inserts the parts of a streamed rebuild into its transaction.
+pub struct WorkspaceRebuildWriter<'tx, 'conn> {
+    tx: &'tx Transaction<'conn>,
+    now: i64,
+    counts: InsertCounts,
+    /// `(path, hash)` of every file written so far.
+    files: Vec<(String, String)>,
+    mark_external_analysis_stale: bool,
+}
+
+impl WorkspaceRebuildWriter<'_, '_> {
+    /// Insert one part. A part must hold whole files: rows referencing a
+    /// symbol that is not stored yet are dropped, as in any bulk insert.
+    pub fn write(
+        &mut self,
+        write_set: &CanonicalWriteSet<'_>,
+        metadata: AtomicPersistenceMetadata<'_>,
+    ) -> Result<()> {
+        let counts = insert_batch_tx(self.tx, write_set, self.now, false)?;
+        self.counts.add(&counts);
+        self.files.extend(write_set.files.iter().map(|file| {
+            (
+                normalize_stored_path(&file.path).into_owned(),
+                file.hash.clone(),
+            )
+        }));
+        persist_batch_metadata_tx(self.tx, write_set.files, metadata)?;
+        self.mark_external_analysis_stale |= metadata.mark_external_analysis_stale;
+        Ok(())
+    }
+}
+
+impl SymbolDatabase {
+    /// Replace every indexed row with the parts `write_parts` hands to the
+    /// writer. The revision and its file and symbol changes are recorded once,
+    /// after the last part.
+    pub fn replace_workspace_data_streamed<F>(
+        &mut self,
+        workspace_id: &str,
+        write_parts: F,
+    ) -> Result<Option<i64>>
+    where
+        F: FnOnce(&mut WorkspaceRebuildWriter<'_, '_>) -> Result<()>,
+    {
+        require_workspace_id(workspace_id)?;
+        let started = Instant::now();
+        let now = unix_timestamp()?;
+        let fk_guard = ForeignKeyGuard::disable(&self.conn)?;
+        let result = (|| {
+            let tx = self.conn.transaction()?;
+            let existing_symbols = snapshot_symbols_tx(&tx, None)?;
+            delete_all_indexed_rows_tx(&tx)?;
+
+            let mut writer = WorkspaceRebuildWriter {
+                tx: &tx,
+                now,
+                counts: InsertCounts::default(),
+                files: Vec::new(),
+                mark_external_analysis_stale: false,
+            };
+            write_parts(&mut writer)?;
+            let WorkspaceRebuildWriter {
+                counts,
+                files,
+                mark_external_analysis_stale,
+                ..
+            } = writer;
+
+            let revision = if counts.has_changes(0) {
+                let revision = record_canonical_revision_tx(
+                    &tx,
+                    workspace_id,
+                    CanonicalRevisionKind::Fresh,
+                    0,
+                    counts.files,
+                    counts.symbols,
+                    counts.relationships,
+                    counts.identifiers,
+                    counts.types,
+                )?;
+                let changes: Vec<_> = files
+                    .into_iter()
+                    .map(|(file_path, hash)| RevisionFileChange {
+                        revision,
+                        workspace_id: workspace_id.to_string(),
+                        file_path,
+                        change_kind: RevisionChangeKind::Added,
+                        old_hash: None,
+                        new_hash: Some(hash),
+                    })
+                    .collect();
+                record_revision_file_changes_tx(&tx, revision, workspace_id, &changes)?;
+                // The parts are gone by now; the stored rows are what was written.
+                let written_symbols = snapshot_symbols_tx(&tx, None)?;
+                record_revision_snapshot_changes_tx(
+                    &tx,
+                    revision,
+                    workspace_id,
+                    &existing_symbols,
+                    &written_symbols,
+                )?;
+                Some(revision)
+            } else {
+                None
+            };
+
+            if revision.is_some() && mark_external_analysis_stale {
+                mark_external_analysis_stale_tx(&tx, now)?;
+            }
+            tx.commit()?;
+            Ok(revision)
+        })();
+        fk_guard.restore()?;
+        if result.is_ok() {
+            checkpoint_wal_best_effort(self);
+        }
+        Telemetry::global().record_db_write("replace_workspace", started.elapsed());
+        result
+    }
+}
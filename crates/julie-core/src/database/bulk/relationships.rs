@@ -5,6 +5,7 @@ use rusqlite::{Transaction, params};
 use tracing::{debug, info};
 
 use crate::database::SymbolDatabase;
+use crate::paths::normalize_stored_path;
 use julie_extractors::Relationship;
 
 pub(crate) fn insert_relationships_tx(
@@ -45,7 +46,7 @@ pub(crate) fn insert_relationships_tx(
             rel.from_symbol_id,
             rel.to_symbol_id,
             rel.kind.to_string(),
-            rel.file_path,
+            normalize_stored_path(&rel.file_path),
             rel.line_number,
             rel.confidence,
             metadata_json
@@ -4,6 +4,8 @@ use anyhow::Result;
 use julie_extractors::base::SourceRegion;
 use rusqlite::{Transaction, params};
 
+use crate::paths::normalize_stored_path;
+
 pub(crate) fn insert_source_regions_tx(
     tx: &Transaction<'_>,
     regions: &[SourceRegion],
@@ -27,7 +29,7 @@ pub(crate) fn insert_source_regions_tx(
             .transpose()?;
         stmt.execute(params![
             region.id,
-            region.file_path,
+            normalize_stored_path(&region.file_path),
             region.language,
             region.kind.as_str(),
             containing_symbol_id,
@@ -4,6 +4,8 @@ use anyhow::Result;
 use julie_extractors::base::StructuralFact;
 use rusqlite::{Transaction, params};
 
+use crate::paths::normalize_stored_path;
+
 pub(crate) fn insert_structural_facts_tx(
     tx: &Transaction<'_>,
     facts: &[StructuralFact],
@@ -28,7 +30,7 @@ pub(crate) fn insert_structural_facts_tx(
             .transpose()?;
         stmt.execute(params![
             fact.id,
-            fact.file_path,
+            normalize_stored_path(&fact.file_path),
             fact.language,
             fact.pattern_id,
             fact.capture_name,
@@ -12,9 +12,12 @@
 
 use anyhow::Result;
 use rusqlite::{Transaction, params};
+use serde::{Deserialize, Serialize};
 
 use julie_extractors::base::{TypeArgument, TypeArgumentUsage};
 
+use crate::paths::normalize_stored_path;
+
 /// One flattened `type_arguments` row, ready to insert.
 ///
 /// `parent_arg_id` is `None` for a top-level argument and the parent row's
@@ -22,7 +25,7 @@ use julie_extractors::base::{TypeArgument, TypeArgumentUsage};
 /// tree. `target_symbol_id` is intentionally write-once-NULL today: nothing
 /// resolves it yet (see `cleanup.rs` for the forward-safe NULL-on-symbol-delete
 /// guard kept in lockstep).
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct TypeArgumentRow {
     pub id: String,
     pub identifier_id: String,
@@ -105,7 +108,7 @@ pub(crate) fn insert_type_arguments_tx(
             row.parent_arg_id,
             row.ordinal,
             row.type_name,
-            row.file_path,
+            normalize_stored_path(&row.file_path),
             row.language,
             now,
         ])?;
@@ -5,6 +5,7 @@ use rusqlite::{Transaction, params};
 use tracing::debug;
 
 use crate::database::web_edges::WebEdge;
+use crate::paths::normalize_stored_path;
 
 /// Deterministic primary key for a derived web edge. Re-deriving the same
 /// edge from the same facts yields the same id, so `INSERT OR REPLACE` is
@@ -89,7 +90,7 @@ pub(crate) fn insert_web_edges_tx(
             edge.method,
             edge.path,
             edge.table,
-            edge.file_path,
+            normalize_stored_path(&edge.file_path),
             edge.line_number,
             edge.confidence,
             metadata_json
@@ -7,6 +7,9 @@ use super::type_arguments::TypeArgumentRow;
 pub struct AtomicPersistenceMetadata<'a> {
     pub parse_diagnostics_by_file: &'a [(String, Vec<julie_extractors::base::ParseDiagnostic>)],
     pub repair_entries: &'a [(String, String)],
+    /// `(path, reason)` for files indexed text-only because parsing was
+    /// skipped; recorded under `PARSE_SKIPPED_REASON`.
+    pub parse_skips: &'a [(String, String)],
     pub mark_external_analysis_stale: bool,
 }
 
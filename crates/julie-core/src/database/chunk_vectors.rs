@@ -0,0 +1,347 @@
+//! Snippet-level vectors for long symbol bodies.
+//!
+//! A function whose source is too long for a single embedding is split into
+//! overlapping chunks by the embedding pipeline. Each chunk gets its own vector
+//! in the `symbol_chunk_vectors` virtual table (keyed by `chunk_id`) and a row in
+//! the plain `symbol_chunks` table mapping it back to its symbol and line range
+//! (both created by migration 032).
+//!
+//! Chunk vectors follow their symbol's vector lifecycle: the deletes in
+//! `vectors.rs` remove a symbol's chunks along with its metadata vector.
+
+use std::collections::{HashMap, HashSet};
+
+use anyhow::{Context, Result};
+use rusqlite::Connection;
+use rusqlite::types::ToSql;
+use tracing::debug;
+use zerocopy::AsBytes;
+
+use super::SymbolDatabase;
+
+/// Chunk candidates fetched per requested result in `knn_search_with_chunks`.
+/// A long function can own several of the nearest chunks, so over-fetch before
+/// collapsing chunks onto their symbols.
+const CHUNK_KNN_OVERFETCH: usize = 4;
+
+const MAX_SQLITE_BIND_PARAMS: usize = 900;
+
+/// One embedded chunk of a symbol body.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChunkEmbedding {
+    pub chunk_id: String,
+    pub symbol_id: String,
+    pub start_line: u32,
+    pub end_line: u32,
+    pub vector: Vec<f32>,
+}
+
+impl SymbolDatabase {
+    /// Create the chunk tables if missing. The vector table uses cosine
+    /// distance, matching `recreate_vectors_table`.
+    pub(crate) fn create_symbol_chunk_tables(&self, dimensions: usize) -> Result<()> {
+        self.conn.execute_batch(
+            "CREATE TABLE IF NOT EXISTS symbol_chunks (
+                chunk_id TEXT PRIMARY KEY,
+                symbol_id TEXT NOT NULL,
+                start_line INTEGER NOT NULL,
+                end_line INTEGER NOT NULL
+            );
+            CREATE INDEX IF NOT EXISTS idx_symbol_chunks_symbol ON symbol_chunks(symbol_id);",
+        )?;
+        self.conn.execute(
+            &format!(
+                "CREATE VIRTUAL TABLE IF NOT EXISTS symbol_chunk_vectors USING vec0(
+                    chunk_id TEXT PRIMARY KEY,
+                    embedding float[{dimensions}] distance_metric=cosine
+                )"
+            ),
+            [],
+        )?;
+        Ok(())
+    }
+
+    /// Store chunk embeddings, replacing rows with the same `chunk_id`.
+    ///
+    /// Callers re-chunking a symbol should first clear its old chunks with
+    /// `delete_chunk_embeddings_for_symbol_ids`: a shorter body produces fewer
+    /// chunks, and the leftovers would otherwise keep matching.
+    pub fn store_chunk_embeddings(&mut self, chunks: &[ChunkEmbedding]) -> Result<usize> {
+        if chunks.is_empty() {
+            return Ok(0);
+        }
+
+        let tx = self.conn.transaction()?;
+        {
+            let mut del_stmt = tx.prepare("DELETE FROM symbol_chunk_vectors WHERE chunk_id = ?")?;
+            let mut ins_vec_stmt =
+                tx.prepare("INSERT INTO symbol_chunk_vectors(chunk_id, embedding) VALUES (?, ?)")?;
+            let mut ins_row_stmt = tx.prepare(
+                "INSERT OR REPLACE INTO symbol_chunks(chunk_id, symbol_id, start_line, end_line)
+                 VALUES (?, ?, ?, ?)",
+            )?;
+
+            for chunk in chunks {
+                del_stmt.execute([&chunk.chunk_id])?;
+                ins_vec_stmt.execute(rusqlite::params![chunk.chunk_id, chunk.vector.as_bytes()])?;
+                ins_row_stmt.execute(rusqlite::params![
+                    chunk.chunk_id,
+                    chunk.symbol_id,
+                    chunk.start_line,
+                    chunk.end_line
+                ])?;
+            }
+        }
+        tx.commit()?;
+
+        debug!("Stored {} chunk embeddings", chunks.len());
+        Ok(chunks.len())
+    }
+
+    /// Delete all chunks belonging to the given symbols. Returns the number of
+    /// chunk vectors removed.
+    pub fn delete_chunk_embeddings_for_symbol_ids(
+        &mut self,
+        symbol_ids: &[String],
+    ) -> Result<usize> {
+        let mut total_deleted = 0;
+        for chunk in symbol_ids.chunks(MAX_SQLITE_BIND_PARAMS) {
+            let placeholders: Vec<&str> = chunk.iter().map(|_| "?").collect();
+            let params: Vec<&dyn ToSql> = chunk.iter().map(|id| id as &dyn ToSql).collect();
+            total_deleted +=
+                delete_chunks_for_symbols(&self.conn, &placeholders.join(", "), params.as_slice())?;
+        }
+        Ok(total_deleted)
+    }
+
+    /// Symbols that currently have at least one chunk vector.
+    pub fn get_chunked_symbol_ids(&self) -> Result<HashSet<String>> {
+        let mut stmt = self
+            .conn
+            .prepare("SELECT DISTINCT symbol_id FROM symbol_chunks")
+            .context("Failed to prepare chunked symbol IDs query")?;
+        let ids = stmt
+            .query_map([], |row| row.get::<_, String>(0))?
+            .collect::<Result<HashSet<String>, _>>()
+            .context("Failed to collect chunked symbol IDs")?;
+        Ok(ids)
+    }
+
+    /// Count the stored chunk vectors.
+    pub fn chunk_embedding_count(&self) -> Result<i64> {
+        let count: i64 = self
+            .conn
+            .query_row("SELECT COUNT(*) FROM symbol_chunk_vectors", [], |row| {
+                row.get(0)
+            })
+            .context("Failed to count chunk embeddings")?;
+        Ok(count)
+    }
+
+    /// KNN search over symbol vectors and chunk vectors, aggregated per symbol.
+    ///
+    /// A symbol's distance is the smallest distance among its metadata vector
+    /// and its chunks (max-similarity aggregation), so a long function matches
+    /// on any part of its body. Returns `(symbol_id, distance)` pairs ordered by
+    /// ascending distance, like `knn_search`.
+    pub fn knn_search_with_chunks(
+        &self,
+        query_vector: &[f32],
+        limit: usize,
+    ) -> Result<Vec<(String, f64)>> {
+        let mut best: HashMap<String, f64> =
+            self.knn_search(query_vector, limit)?.into_iter().collect();
+
+        let chunk_hits =
+            self.knn_chunk_search(query_vector, limit.saturating_mul(CHUNK_KNN_OVERFETCH))?;
+        if !chunk_hits.is_empty() {
+            let chunk_ids: Vec<String> = chunk_hits.iter().map(|(id, _)| id.clone()).collect();
+            let owners = self.chunk_owners(&chunk_ids)?;
+            for (chunk_id, distance) in chunk_hits {
+                let Some(symbol_id) = owners.get(&chunk_id) else {
+                    continue;
+                };
+                keep_min_distance(&mut best, symbol_id.clone(), distance);
+            }
+        }
+
+        Ok(rank_by_distance(best, limit))
+    }
+
+    /// `knn_search_with_chunks` restricted to symbols written in `languages`.
+    ///
+    /// The language filter is part of the vector query: only vectors whose
+    /// symbol has one of the languages are scored, so a narrow filter still
+    /// fills `limit` instead of keeping whatever survived a global top-k.
+    /// Matching is exact against `symbols.language` (lowercase names such as
+    /// `"go"`). An empty `languages` searches every vector.
+    pub fn knn_search_with_chunks_in_languages(
+        &self,
+        query_vector: &[f32],
+        limit: usize,
+        languages: &[String],
+    ) -> Result<Vec<(String, f64)>> {
+        if languages.is_empty() {
+            return self.knn_search_with_chunks(query_vector, limit);
+        }
+
+        let placeholders: Vec<&str> = languages.iter().map(|_| "?").collect();
+        let placeholders = placeholders.join(", ");
+        let query_blob = query_vector.as_bytes();
+        let limit_param = limit as i64;
+        let mut params: Vec<&dyn ToSql> = vec![&query_blob];
+        params.extend(languages.iter().map(|language| language as &dyn ToSql));
+        params.push(&limit_param);
+
+        let symbol_sql = format!(
+            "SELECT s.id, {distance}(v.embedding, ?) AS distance
+             FROM symbols s
+             JOIN symbol_vectors v ON v.symbol_id = s.id
+             WHERE s.language IN ({placeholders})
+             ORDER BY distance
+             LIMIT ?",
+            distance = self.symbol_vector_distance_fn()?
+        );
+        let mut best: HashMap<String, f64> = self
+            .query_symbol_distances(&symbol_sql, &params)
+            .context("Failed to execute language-filtered KNN query")?
+            .into_iter()
+            .collect();
+
+        // Chunk vectors are always cosine (see `create_symbol_chunk_tables`).
+        let chunk_sql = format!(
+            "SELECT c.symbol_id, MIN(vec_distance_cosine(cv.embedding, ?)) AS distance
+             FROM symbols s
+             JOIN symbol_chunks c ON c.symbol_id = s.id
+             JOIN symbol_chunk_vectors cv ON cv.chunk_id = c.chunk_id
+             WHERE s.language IN ({placeholders})
+             GROUP BY c.symbol_id
+             ORDER BY distance
+             LIMIT ?"
+        );
+        for (symbol_id, distance) in self
+            .query_symbol_distances(&chunk_sql, &params)
+            .context("Failed to execute language-filtered chunk KNN query")?
+        {
+            keep_min_distance(&mut best, symbol_id, distance);
+        }
+
+        Ok(rank_by_distance(best, limit))
+    }
+
+    /// The scalar distance function matching `symbol_vectors`' metric, so
+    /// filtered scans score like the table's own KNN. Tables created by
+    /// migration 010 use the vec0 default (L2); `recreate_vectors_table`
+    /// creates cosine tables.
+    fn symbol_vector_distance_fn(&self) -> Result<&'static str> {
+        let sql: String = self
+            .conn
+            .query_row(
+                "SELECT sql FROM sqlite_master WHERE name = 'symbol_vectors'",
+                [],
+                |row| row.get(0),
+            )
+            .context("Failed to read symbol_vectors definition")?;
+        if sql.contains("distance_metric=cosine") {
+            Ok("vec_distance_cosine")
+        } else {
+            Ok("vec_distance_l2")
+        }
+    }
+
+    fn query_symbol_distances(
+        &self,
+        sql: &str,
+        params: &[&dyn ToSql],
+    ) -> Result<Vec<(String, f64)>> {
+        let mut stmt = self.conn.prepare(sql)?;
+        let rows = stmt
+            .query_map(params, |row| {
+                Ok((row.get::<_, String>(0)?, row.get::<_, f64>(1)?))
+            })?
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(rows)
+    }
+
+    fn knn_chunk_search(&self, query_vector: &[f32], limit: usize) -> Result<Vec<(String, f64)>> {
+        let mut stmt = self
+            .conn
+            .prepare(
+                "SELECT chunk_id, distance
+                 FROM symbol_chunk_vectors
+                 WHERE embedding MATCH ?
+                 AND k = ?
+                 ORDER BY distance",
+            )
+            .context("Failed to prepare chunk KNN query")?;
+
+        let results = stmt
+            .query_map(
+                rusqlite::params![query_vector.as_bytes(), limit as i64],
+                |row| Ok((row.get::<_, String>(0)?, row.get::<_, f64>(1)?)),
+            )?
+            .collect::<Result<Vec<_>, _>>()
+            .context("Failed to execute chunk KNN query")?;
+        Ok(results)
+    }
+
+    /// Map chunk ids to their owning symbol ids.
+    fn chunk_owners(&self, chunk_ids: &[String]) -> Result<HashMap<String, String>> {
+        let mut owners = HashMap::new();
+        for chunk in chunk_ids.chunks(MAX_SQLITE_BIND_PARAMS) {
+            let placeholders: Vec<&str> = chunk.iter().map(|_| "?").collect();
+            let sql = format!(
+                "SELECT chunk_id, symbol_id FROM symbol_chunks WHERE chunk_id IN ({})",
+                placeholders.join(", ")
+            );
+            let params: Vec<&dyn ToSql> = chunk.iter().map(|id| id as &dyn ToSql).collect();
+            let mut stmt = self.conn.prepare(&sql)?;
+            let rows = stmt.query_map(params.as_slice(), |row| {
+                Ok((row.get::<_, String>(0)?, row.get::<_, String>(1)?))
+            })?;
+            for row in rows {
+                let (chunk_id, symbol_id) = row?;
+                owners.insert(chunk_id, symbol_id);
+            }
+        }
+        Ok(owners)
+    }
+}
+
+fn keep_min_distance(best: &mut HashMap<String, f64>, symbol_id: String, distance: f64) {
+    best.entry(symbol_id)
+        .and_modify(|current| *current = current.min(distance))
+        .or_insert(distance);
+}
+
+/// Order by ascending distance (ties by id) and keep the first `limit`.
+fn rank_by_distance(best: HashMap<String, f64>, limit: usize) -> Vec<(String, f64)> {
+    let mut results: Vec<(String, f64)> = best.into_iter().collect();
+    results.sort_by(|(a_id, a), (b_id, b)| a.total_cmp(b).then_with(|| a_id.cmp(b_id)));
+    results.truncate(limit);
+    results
+}
+
+/// Delete the chunk vectors and chunk rows of every symbol id returned by
+/// `symbol_ids_sql`. Returns the number of chunk vectors removed.
+///
+/// Takes a plain connection so the bulk-cleanup transactions can share it.
+pub(crate) fn delete_chunks_for_symbols(
+    conn: &Connection,
+    symbol_ids_sql: &str,
+    params: &[&dyn ToSql],
+) -> Result<usize> {
+    let deleted = conn.execute(
+        &format!(
+            "DELETE FROM symbol_chunk_vectors WHERE chunk_id IN (
+                SELECT chunk_id FROM symbol_chunks WHERE symbol_id IN ({symbol_ids_sql})
+            )"
+        ),
+        params,
+    )?;
+    conn.execute(
+        &format!("DELETE FROM symbol_chunks WHERE symbol_id IN ({symbol_ids_sql})"),
+        params,
+    )?;
+    Ok(deleted)
+}
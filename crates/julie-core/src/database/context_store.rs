@@ -0,0 +1,170 @@
+//! Compressed storage for symbol code context.
+//!
+//! `code_context` holds each symbol's source text and used to be stored
+//! inline on the `symbols` row, which made it most of the database's size.
+//! Contexts now live in `context_blobs`, zstd-compressed and keyed by a
+//! content hash, so identical bodies (vendored copies, generated code) are
+//! stored once. `symbols.context_hash` points at the blob and
+//! `symbols.code_context` stays NULL for rows written this way.
+//!
+//! Reads decompress lazily: only the full-row mapper (`row_to_symbol`)
+//! resolves the hash, so lightweight queries never touch the blobs.
+//! Blobs no longer referenced by any symbol are pruned by the atomic write
+//! paths after they replace a file's symbols.
+
+use anyhow::Result;
+use rusqlite::{Connection, OptionalExtension, params};
+use serde::Serialize;
+
+use super::SymbolDatabase;
+
+/// zstd's default level: most of the ratio of higher levels at a fraction of
+/// the cost, which matters because every indexed symbol goes through it.
+pub(super) const COMPRESSION_LEVEL: i32 = 3;
+
+/// Storage used by symbol code context, for workspace stats.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+pub struct ContextStorageStats {
+    /// Symbols whose context is stored as a blob.
+    pub symbols: i64,
+    /// Distinct blobs after deduplication.
+    pub blobs: i64,
+    /// Bytes the same contexts would take stored inline, one copy per symbol.
+    pub raw_bytes: i64,
+    /// Compressed bytes actually stored.
+    pub stored_bytes: i64,
+}
+
+impl ContextStorageStats {
+    pub fn saved_bytes(&self) -> i64 {
+        (self.raw_bytes - self.stored_bytes).max(0)
+    }
+
+    /// Share of `raw_bytes` saved, 0-100.
+    pub fn saved_percent(&self) -> f64 {
+        if self.raw_bytes == 0 {
+            return 0.0;
+        }
+        self.saved_bytes() as f64 * 100.0 / self.raw_bytes as f64
+    }
+}
+
+/// Store `context` as a blob and return its hash, the value for
+/// `symbols.context_hash`. Contexts that are already stored are not
+/// recompressed.
+pub(crate) fn store_code_context(
+    conn: &Connection,
+    context: Option<&str>,
+) -> Result<Option<String>> {
+    let Some(context) = context else {
+        return Ok(None);
+    };
+    let hash = blake3::hash(context.as_bytes()).to_hex()[..32].to_string();
+    let exists: bool = conn
+        .prepare_cached("SELECT EXISTS(SELECT 1 FROM context_blobs WHERE hash = ?1)")?
+        .query_row([&hash], |row| row.get(0))?;
+    if !exists {
+        let data = zstd::encode_all(context.as_bytes(), COMPRESSION_LEVEL)?;
+        conn.prepare_cached(
+            "INSERT INTO context_blobs (hash, raw_size, data) VALUES (?1, ?2, ?3)",
+        )?
+        .execute(params![hash, context.len() as i64, data])?;
+    }
+    Ok(Some(hash))
+}
+
+/// Decompress the blob stored under `hash`; `None` if it is missing.
+pub(crate) fn read_code_context(conn: &Connection, hash: &str) -> rusqlite::Result<Option<String>> {
+    let data: Option<Vec<u8>> = conn
+        .prepare_cached("SELECT data FROM context_blobs WHERE hash = ?1")?
+        .query_row([hash], |row| row.get(0))
+        .optional()?;
+    let Some(data) = data else {
+        return Ok(None);
+    };
+    let decoded = zstd::decode_all(data.as_slice()).map_err(corrupt_blob)?;
+    String::from_utf8(decoded).map(Some).map_err(corrupt_blob)
+}
+
+fn corrupt_blob(error: impl std::error::Error + Send + Sync + 'static) -> rusqlite::Error {
+    rusqlite::Error::FromSqlConversionFailure(0, rusqlite::types::Type::Blob, Box::new(error))
+}
+
+/// Context hashes referenced by the symbols of `file_paths`. Collect these
+/// before deleting the symbols, then pass them to
+/// [`delete_unreferenced_contexts`] once the replacement rows are written.
+pub(crate) fn context_hashes_for_files(
+    conn: &Connection,
+    file_paths: &[String],
+) -> Result<Vec<String>> {
+    let mut stmt = conn.prepare_cached(
+        "SELECT DISTINCT context_hash FROM symbols
+         WHERE file_path = ?1 AND context_hash IS NOT NULL",
+    )?;
+    let mut hashes = Vec::new();
+    for file_path in file_paths {
+        let rows = stmt.query_map([file_path], |row| row.get::<_, String>(0))?;
+        for hash in rows {
+            hashes.push(hash?);
+        }
+    }
+    hashes.sort_unstable();
+    hashes.dedup();
+    Ok(hashes)
+}
+
+/// Delete the blobs among `candidates` that no symbol references any more.
+pub(crate) fn delete_unreferenced_contexts(
+    conn: &Connection,
+    candidates: &[String],
+) -> Result<usize> {
+    let mut stmt = conn.prepare_cached(
+        "DELETE FROM context_blobs
+         WHERE hash = ?1
+           AND NOT EXISTS (SELECT 1 FROM symbols WHERE context_hash = ?1)",
+    )?;
+    let mut deleted = 0;
+    for hash in candidates {
+        deleted += stmt.execute([hash])?;
+    }
+    Ok(deleted)
+}
+
+impl SymbolDatabase {
+    /// Decompressed code context stored under `context_hash`.
+    pub fn get_code_context(&self, context_hash: &str) -> Result<Option<String>> {
+        Ok(read_code_context(&self.conn, context_hash)?)
+    }
+
+    /// Delete blobs that no symbol references.
+    pub fn delete_orphan_contexts(&self) -> Result<usize> {
+        Ok(self.conn.execute(
+            "DELETE FROM context_blobs
+             WHERE NOT EXISTS (
+                SELECT 1 FROM symbols WHERE symbols.context_hash = context_blobs.hash
+             )",
+            [],
+        )?)
+    }
+
+    pub fn get_context_storage_stats(&self) -> Result<ContextStorageStats> {
+        let (symbols, raw_bytes): (i64, i64) = self.conn.query_row(
+            "SELECT COUNT(*), COALESCE(SUM(b.raw_size), 0)
+             FROM symbols s
+             JOIN context_blobs b ON b.hash = s.context_hash",
+            [],
+            |row| Ok((row.get(0)?, row.get(1)?)),
+        )?;
+        let (blobs, stored_bytes): (i64, i64) = self.conn.query_row(
+            "SELECT COUNT(*), COALESCE(SUM(length(data)), 0) FROM context_blobs",
+            [],
+            |row| Ok((row.get(0)?, row.get(1)?)),
+        )?;
+        Ok(ContextStorageStats {
+            symbols,
+            blobs,
+            raw_bytes,
+            stored_bytes,
+        })
+    }
+}
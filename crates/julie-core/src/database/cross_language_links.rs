@@ -0,0 +1,123 @@
+//! Derived cross-language counterpart links.
+//!
+//! A counterpart link says "this TypeScript interface, that C# DTO and this
+//! SQL table describe the same entity". Links are computed by the post-index
+//! analysis pass in julie-index and stored here so `fast_refs` (and anything
+//! else that wants them) reads them instead of re-deriving them per query.
+
+use anyhow::Result;
+use rusqlite::{params, params_from_iter};
+use tracing::debug;
+
+use super::SymbolDatabase;
+
+/// A directed counterpart link. Every pair is stored in both directions.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CrossLanguageLink {
+    pub from_symbol_id: String,
+    pub to_symbol_id: String,
+    /// Link confidence in `0.0..=1.0`.
+    pub confidence: f32,
+    /// Short explanation of the evidence (e.g. `"same name, 3/4 fields"`).
+    pub reason: String,
+}
+
+impl SymbolDatabase {
+    /// Replace the entire `cross_language_links` table with `links`. The table
+    /// is derived data, so the analysis pass wipes and recomputes it on each
+    /// run.
+    ///
+    /// Takes `&self` (explicit `BEGIN`/`COMMIT`) so it can run alongside the
+    /// other post-index analyses, which all share a `&SymbolDatabase`.
+    pub fn replace_all_cross_language_links(&self, links: &[CrossLanguageLink]) -> Result<()> {
+        self.conn.execute_batch("BEGIN")?;
+        let result = (|| -> Result<()> {
+            self.conn.execute("DELETE FROM cross_language_links", [])?;
+            let mut stmt = self.conn.prepare(
+                "INSERT OR REPLACE INTO cross_language_links
+                    (from_symbol_id, to_symbol_id, confidence, reason)
+                 VALUES (?1, ?2, ?3, ?4)",
+            )?;
+            for link in links {
+                stmt.execute(params![
+                    link.from_symbol_id,
+                    link.to_symbol_id,
+                    f64::from(link.confidence),
+                    link.reason,
+                ])?;
+            }
+            Ok(())
+        })();
+
+        match result {
+            Ok(()) => self.conn.execute_batch("COMMIT")?,
+            Err(e) => {
+                let _ = self.conn.execute_batch("ROLLBACK");
+                return Err(e);
+            }
+        }
+        debug!(
+            "Replaced cross_language_links table: {} links inserted",
+            links.len()
+        );
+        Ok(())
+    }
+
+    /// Counterparts of any of `symbol_ids`, highest confidence first.
+    /// Chunked like `web_edges_from_symbols`.
+    pub fn cross_language_links_for_symbols(
+        &self,
+        symbol_ids: &[String],
+    ) -> Result<Vec<CrossLanguageLink>> {
+        if symbol_ids.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        const CHUNK_SIZE: usize = 500;
+        let mut unique_ids = symbol_ids.to_vec();
+        unique_ids.sort();
+        unique_ids.dedup();
+        let mut links = Vec::new();
+
+        for chunk in unique_ids.chunks(CHUNK_SIZE) {
+            let placeholders = (1..=chunk.len())
+                .map(|i| format!("?{i}"))
+                .collect::<Vec<_>>()
+                .join(",");
+            let sql = format!(
+                "SELECT from_symbol_id, to_symbol_id, confidence, reason
+                 FROM cross_language_links
+                 WHERE from_symbol_id IN ({placeholders})"
+            );
+            let mut stmt = self.conn.prepare(&sql)?;
+            let rows = stmt
+                .query_map(params_from_iter(chunk.iter()), |row| {
+                    Ok(CrossLanguageLink {
+                        from_symbol_id: row.get(0)?,
+                        to_symbol_id: row.get(1)?,
+                        confidence: row.get::<_, f64>(2)? as f32,
+                        reason: row.get(3)?,
+                    })
+                })?
+                .collect::<rusqlite::Result<Vec<_>>>()?;
+            links.extend(rows);
+        }
+
+        links.sort_by(|a, b| {
+            b.confidence
+                .total_cmp(&a.confidence)
+                .then_with(|| a.from_symbol_id.cmp(&b.from_symbol_id))
+                .then_with(|| a.to_symbol_id.cmp(&b.to_symbol_id))
+        });
+        Ok(links)
+    }
+
+    /// Count of stored links, both directions (lightweight health signal).
+    pub fn cross_language_link_count(&self) -> Result<i64> {
+        Ok(self
+            .conn
+            .query_row("SELECT COUNT(*) FROM cross_language_links", [], |row| {
+                row.get(0)
+            })?)
+    }
+}
@@ -0,0 +1,445 @@
+//! Two-phase commit of embeddings against index generations.
+//!
+//! The embedding pipeline reads the symbol set, spends minutes embedding it,
+//! and only then writes vectors. An index write landing in between deletes the
+//! vectors of the files it replaces, and without coordination the pipeline
+//! would write vectors computed from the old symbols back under their IDs; a
+//! process dying mid-run leaves a half-replaced vector set. Either way
+//! semantic search returns ghosts: hits whose vector describes code that is
+//! no longer there.
+//!
+//! A pipeline run is therefore an *embedding generation*:
+//!
+//! 1. [`SymbolDatabase::begin_embedding_generation`] records a pending
+//!    generation stamped with the canonical revision its symbols were read at;
+//! 2. vectors are staged in plain tables (`pending_symbol_vectors`,
+//!    `pending_symbol_chunks`) that search never reads;
+//! 3. [`SymbolDatabase::commit_embedding_generation`] moves them into the
+//!    vector tables in one transaction, dropping any whose symbol changed or
+//!    disappeared after the snapshot (per `revision_symbol_changes`), flips the
+//!    active generation, and garbage-collects vectors of symbols that no longer
+//!    exist.
+//!
+//! A run that dies before committing leaves only staged rows, which the next
+//! `begin_embedding_generation` discards.
+
+use super::*;
+use crate::database::chunk_vectors::delete_chunks_for_symbols;
+use crate::database::embedding_jobs::record_job_progress_tx;
+use anyhow::{Result, anyhow};
+use rusqlite::{OptionalExtension, Transaction, params};
+use std::collections::HashSet;
+use zerocopy::AsBytes;
+
+/// A pending embedding generation, returned by
+/// [`SymbolDatabase::begin_embedding_generation`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EmbeddingGeneration {
+    pub generation: i64,
+    /// Newest canonical revision when the generation began: the symbols its
+    /// vectors were computed from. 0 for an empty index.
+    pub source_revision: i64,
+}
+
+/// Outcome of [`SymbolDatabase::commit_embedding_generation`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct EmbeddingCommit {
+    pub generation: i64,
+    pub vectors_committed: usize,
+    pub chunks_committed: usize,
+    /// Staged vectors (symbol and chunk) dropped because their symbol changed
+    /// or disappeared after the generation began.
+    pub stale_discarded: usize,
+    /// Committed vectors removed because their symbol no longer exists.
+    pub orphans_collected: usize,
+}
+
+fn get_unix_timestamp() -> Result<i64> {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs() as i64)
+        .map_err(|e| anyhow!("System time error: {}", e))
+}
+
+/// Fail unless `generation` is still the pending one. A newer run, a cleared
+/// vector store, or a commit by another caller supersedes it.
+fn ensure_pending_tx(tx: &Transaction<'_>, generation: &EmbeddingGeneration) -> Result<()> {
+    let pending: Option<i64> = tx.query_row(
+        "SELECT pending_generation FROM embedding_generation_state WHERE id = 1",
+        [],
+        |row| row.get(0),
+    )?;
+    if pending != Some(generation.generation) {
+        return Err(anyhow!(
+            "embedding generation {} is no longer pending (superseded or cleared)",
+            generation.generation
+        ));
+    }
+    Ok(())
+}
+
+/// Drop staged vectors and forget the pending generation. Returns the number
+/// of staged rows removed.
+pub(crate) fn discard_pending_embeddings(conn: &Connection) -> Result<usize> {
+    let discarded = conn.execute("DELETE FROM pending_symbol_vectors", [])?
+        + conn.execute("DELETE FROM pending_symbol_chunks", [])?;
+    conn.execute(
+        "UPDATE embedding_generation_state
+         SET pending_generation = NULL, pending_source_revision = NULL
+         WHERE id = 1",
+        [],
+    )?;
+    Ok(discarded)
+}
+
+/// Move a generation's staged vectors into the vector tables, skipping those
+/// whose symbol is in `changed` (or all of them when `changed` is `None`).
+/// The staged rows themselves are left for the caller to remove.
+fn publish_staged_tx(
+    tx: &Transaction<'_>,
+    generation: &EmbeddingGeneration,
+    changed: Option<&HashSet<String>>,
+) -> Result<EmbeddingCommit> {
+    let mut commit = EmbeddingCommit {
+        generation: generation.generation,
+        ..Default::default()
+    };
+
+    if let Some(changed) = changed {
+        let mut staged = tx.prepare(
+            "SELECT p.symbol_id, p.embedding
+             FROM pending_symbol_vectors p
+             JOIN symbols s ON s.id = p.symbol_id
+             WHERE p.generation = ?1",
+        )?;
+        let mut del_stmt = tx.prepare("DELETE FROM symbol_vectors WHERE symbol_id = ?1")?;
+        let mut ins_stmt =
+            tx.prepare("INSERT INTO symbol_vectors(symbol_id, embedding) VALUES (?1, ?2)")?;
+        let rows = staged.query_map(params![generation.generation], |row| {
+            Ok((row.get::<_, String>(0)?, row.get::<_, Vec<u8>>(1)?))
+        })?;
+        for row in rows {
+            let (symbol_id, embedding) = row?;
+            if changed.contains(&symbol_id) {
+                continue;
+            }
+            del_stmt.execute(params![symbol_id])?;
+            ins_stmt.execute(params![symbol_id, embedding])?;
+            commit.vectors_committed += 1;
+        }
+
+        let mut staged_chunks = tx.prepare(
+            "SELECT p.chunk_id, p.symbol_id, p.start_line, p.end_line, p.embedding
+             FROM pending_symbol_chunks p
+             JOIN symbols s ON s.id = p.symbol_id
+             WHERE p.generation = ?1",
+        )?;
+        let mut del_chunk_stmt =
+            tx.prepare("DELETE FROM symbol_chunk_vectors WHERE chunk_id = ?1")?;
+        let mut ins_chunk_stmt =
+            tx.prepare("INSERT INTO symbol_chunk_vectors(chunk_id, embedding) VALUES (?1, ?2)")?;
+        let mut ins_chunk_row_stmt = tx.prepare(
+            "INSERT OR REPLACE INTO symbol_chunks(chunk_id, symbol_id, start_line, end_line)
+             VALUES (?1, ?2, ?3, ?4)",
+        )?;
+        let rows = staged_chunks.query_map(params![generation.generation], |row| {
+            Ok((
+                row.get::<_, String>(0)?,
+                row.get::<_, String>(1)?,
+                row.get::<_, i64>(2)?,
+                row.get::<_, i64>(3)?,
+                row.get::<_, Vec<u8>>(4)?,
+            ))
+        })?;
+        for row in rows {
+            let (chunk_id, symbol_id, start_line, end_line, embedding) = row?;
+            if changed.contains(&symbol_id) {
+                continue;
+            }
+            del_chunk_stmt.execute(params![chunk_id])?;
+            ins_chunk_stmt.execute(params![chunk_id, embedding])?;
+            ins_chunk_row_stmt.execute(params![chunk_id, symbol_id, start_line, end_line])?;
+            commit.chunks_committed += 1;
+        }
+    }
+    let staged_total: usize = tx.query_row(
+        "SELECT (SELECT COUNT(*) FROM pending_symbol_vectors WHERE generation = ?1)
+              + (SELECT COUNT(*) FROM pending_symbol_chunks WHERE generation = ?1)",
+        params![generation.generation],
+        |row| row.get::<_, i64>(0),
+    )? as usize;
+    commit.stale_discarded = staged_total - commit.vectors_committed - commit.chunks_committed;
+    Ok(commit)
+}
+
+impl SymbolDatabase {
+    pub(crate) fn create_embedding_generation_tables(&self) -> Result<()> {
+        self.conn.execute_batch(
+            "CREATE TABLE IF NOT EXISTS embedding_generation_state (
+                id INTEGER PRIMARY KEY CHECK (id = 1),
+                active_generation INTEGER NOT NULL DEFAULT 0,
+                pending_generation INTEGER,
+                pending_source_revision INTEGER,
+                committed_at INTEGER
+            );
+            INSERT OR IGNORE INTO embedding_generation_state (id) VALUES (1);
+            CREATE TABLE IF NOT EXISTS pending_symbol_vectors (
+                symbol_id TEXT PRIMARY KEY,
+                generation INTEGER NOT NULL,
+                embedding BLOB NOT NULL
+            );
+            CREATE TABLE IF NOT EXISTS pending_symbol_chunks (
+                chunk_id TEXT PRIMARY KEY,
+                symbol_id TEXT NOT NULL,
+                generation INTEGER NOT NULL,
+                start_line INTEGER NOT NULL,
+                end_line INTEGER NOT NULL,
+                embedding BLOB NOT NULL
+            );",
+        )?;
+        debug!("Created embedding generation tables");
+        Ok(())
+    }
+
+    /// The last committed embedding generation; 0 before the first commit.
+    pub fn active_embedding_generation(&self) -> Result<i64> {
+        Ok(self.conn.query_row(
+            "SELECT active_generation FROM embedding_generation_state WHERE id = 1",
+            [],
+            |row| row.get(0),
+        )?)
+    }
+
+    /// The generation currently staging vectors, if any.
+    pub fn pending_embedding_generation(&self) -> Result<Option<EmbeddingGeneration>> {
+        let pending: Option<(Option<i64>, Option<i64>)> = self
+            .conn
+            .query_row(
+                "SELECT pending_generation, pending_source_revision
+                 FROM embedding_generation_state WHERE id = 1",
+                [],
+                |row| Ok((row.get(0)?, row.get(1)?)),
+            )
+            .optional()?;
+        Ok(match pending {
+            Some((Some(generation), source_revision)) => Some(EmbeddingGeneration {
+                generation,
+                source_revision: source_revision.unwrap_or(0),
+            }),
+            _ => None,
+        })
+    }
+
+    /// Phase one: start a pending generation at the current canonical
+    /// revision. Read the symbols to embed under the same lock, so the vectors
+    /// match `source_revision`. An interrupted generation's staged vectors are
+    /// discarded first.
+    pub fn begin_embedding_generation(&mut self) -> Result<EmbeddingGeneration> {
+        let tx = self.conn.transaction()?;
+        let (active, interrupted): (i64, Option<i64>) = tx.query_row(
+            "SELECT active_generation, pending_generation
+             FROM embedding_generation_state WHERE id = 1",
+            [],
+            |row| Ok((row.get(0)?, row.get(1)?)),
+        )?;
+        if let Some(interrupted) = interrupted {
+            let discarded = discard_pending_embeddings(&tx)?;
+            warn!(
+                "Discarded {discarded} staged vectors of uncommitted embedding generation {interrupted}"
+            );
+        }
+        let source_revision: i64 = tx.query_row(
+            "SELECT COALESCE(MAX(revision), 0) FROM canonical_revisions",
+            [],
+            |row| row.get(0),
+        )?;
+        let generation = active.max(interrupted.unwrap_or(0)) + 1;
+        tx.execute(
+            "UPDATE embedding_generation_state
+             SET pending_generation = ?1, pending_source_revision = ?2
+             WHERE id = 1",
+            params![generation, source_revision],
+        )?;
+        tx.commit()?;
+
+        debug!("Began embedding generation {generation} at revision {source_revision}");
+        Ok(EmbeddingGeneration {
+            generation,
+            source_revision,
+        })
+    }
+
+    /// Stage symbol embeddings for a pending generation. They stay invisible
+    /// to search until [`Self::commit_embedding_generation`].
+    pub fn stage_embeddings(
+        &mut self,
+        generation: &EmbeddingGeneration,
+        embeddings: &[(String, Vec<f32>)],
+    ) -> Result<usize> {
+        if embeddings.is_empty() {
+            return Ok(0);
+        }
+        let tx = self.conn.transaction()?;
+        ensure_pending_tx(&tx, generation)?;
+        {
+            let mut stmt = tx.prepare(
+                "INSERT OR REPLACE INTO pending_symbol_vectors (symbol_id, generation, embedding)
+                 VALUES (?1, ?2, ?3)",
+            )?;
+            for (symbol_id, vector) in embeddings {
+                stmt.execute(params![symbol_id, generation.generation, vector.as_bytes()])?;
+            }
+        }
+        tx.commit()?;
+        Ok(embeddings.len())
+    }
+
+    /// Stage chunk embeddings for a pending generation; see
+    /// [`Self::stage_embeddings`].
+    pub fn stage_chunk_embeddings(
+        &mut self,
+        generation: &EmbeddingGeneration,
+        chunks: &[ChunkEmbedding],
+    ) -> Result<usize> {
+        if chunks.is_empty() {
+            return Ok(0);
+        }
+        let tx = self.conn.transaction()?;
+        ensure_pending_tx(&tx, generation)?;
+        {
+            let mut stmt = tx.prepare(
+                "INSERT OR REPLACE INTO pending_symbol_chunks
+                 (chunk_id, symbol_id, generation, start_line, end_line, embedding)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+            )?;
+            for chunk in chunks {
+                stmt.execute(params![
+                    chunk.chunk_id,
+                    chunk.symbol_id,
+                    generation.generation,
+                    chunk.start_line,
+                    chunk.end_line,
+                    chunk.vector.as_bytes()
+                ])?;
+            }
+        }
+        tx.commit()?;
+        Ok(chunks.len())
+    }
+
+    /// Phase two: publish a pending generation's staged vectors atomically.
+    ///
+    /// Staged vectors whose symbol was added, removed or modified after
+    /// `source_revision` are dropped (the next run embeds the new symbols).
+    /// When the retained symbol deltas no longer reach back to
+    /// `source_revision`, nothing can be validated and the whole generation is
+    /// discarded.
+    pub fn commit_embedding_generation(
+        &mut self,
+        generation: &EmbeddingGeneration,
+    ) -> Result<EmbeddingCommit> {
+        let changed = self.symbols_changed_since(generation.source_revision)?;
+        let now = get_unix_timestamp()?;
+
+        let tx = self.conn.transaction()?;
+        ensure_pending_tx(&tx, generation)?;
+        let mut commit = publish_staged_tx(&tx, generation, changed.as_ref())?;
+
+        // The previous generation's leftovers: vectors whose symbol is gone.
+        commit.orphans_collected = delete_chunks_for_symbols(
+            &tx,
+            "SELECT symbol_id FROM symbol_chunks WHERE symbol_id NOT IN (SELECT id FROM symbols)",
+            &[],
+        )? + tx.execute(
+            "DELETE FROM symbol_vectors WHERE symbol_id NOT IN (SELECT id FROM symbols)",
+            [],
+        )?;
+
+        discard_pending_embeddings(&tx)?;
+        tx.execute(
+            "UPDATE embedding_generation_state
+             SET active_generation = ?1, committed_at = ?2
+             WHERE id = 1",
+            params![generation.generation, now],
+        )?;
+        tx.commit()?;
+
+        if changed.is_none() {
+            warn!(
+                "Embedding generation {} outlived the retained symbol history; \
+                 discarded all {} staged vectors",
+                generation.generation, commit.stale_discarded
+            );
+        }
+        debug!("Committed embedding generation: {commit:?}");
+        Ok(commit)
+    }
+
+    /// Publish what a pending generation has staged so far and keep it
+    /// pending, recording `done_symbol_ids` as finished by the running
+    /// embedding job (see `embedding_jobs`).
+    ///
+    /// Staged vectors are validated against `source_revision` exactly as in
+    /// [`Self::commit_embedding_generation`], so a checkpoint never publishes
+    /// a vector the final commit would have dropped. The active generation
+    /// only moves on the final commit.
+    pub fn checkpoint_embedding_generation(
+        &mut self,
+        generation: &EmbeddingGeneration,
+        done_symbol_ids: &[String],
+        completed: u64,
+    ) -> Result<EmbeddingCommit> {
+        let changed = self.symbols_changed_since(generation.source_revision)?;
+
+        let tx = self.conn.transaction()?;
+        ensure_pending_tx(&tx, generation)?;
+        let commit = publish_staged_tx(&tx, generation, changed.as_ref())?;
+        tx.execute(
+            "DELETE FROM pending_symbol_vectors WHERE generation = ?1",
+            params![generation.generation],
+        )?;
+        tx.execute(
+            "DELETE FROM pending_symbol_chunks WHERE generation = ?1",
+            params![generation.generation],
+        )?;
+        if let Some(changed) = &changed {
+            let published: Vec<&str> = done_symbol_ids
+                .iter()
+                .filter(|id| !changed.contains(*id))
+                .map(String::as_str)
+                .collect();
+            record_job_progress_tx(&tx, &published, completed)?;
+        }
+        tx.commit()?;
+
+        debug!("Checkpointed embedding generation: {commit:?}");
+        Ok(commit)
+    }
+
+    /// IDs of symbols added, removed or modified after `revision`, or `None`
+    /// when the retained deltas of some workspace no longer reach back that
+    /// far.
+    fn symbols_changed_since(&self, revision: i64) -> Result<Option<HashSet<String>>> {
+        let workspace_ids: Vec<String> = {
+            let mut stmt = self.conn.prepare(
+                "SELECT DISTINCT workspace_id FROM canonical_revisions WHERE revision > ?1",
+            )?;
+            stmt.query_map(params![revision], |row| row.get(0))?
+                .collect::<rusqlite::Result<_>>()?
+        };
+        for workspace_id in &workspace_ids {
+            let oldest = self.oldest_comparable_generation(workspace_id)?;
+            if oldest.is_none_or(|oldest| oldest > revision) {
+                return Ok(None);
+            }
+        }
+
+        let mut stmt = self.conn.prepare(
+            "SELECT DISTINCT symbol_id FROM revision_symbol_changes WHERE revision > ?1",
+        )?;
+        let changed = stmt
+            .query_map(params![revision], |row| row.get::<_, String>(0))?
+            .collect::<rusqlite::Result<HashSet<String>>>()?;
+        Ok(Some(changed))
+    }
+}
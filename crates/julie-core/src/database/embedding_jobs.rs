@@ -0,0 +1,189 @@
+//! Progress of the workspace embedding job, kept so a cancelled, failed or
+//! killed run resumes instead of starting over.
+//!
+//! Every run re-embeds the symbols whose enrichment (callees, children,
+//! implementors) may have changed, so "already has a vector" alone would redo
+//! most of a huge workspace after an interruption. The pipeline therefore
+//! checkpoints every few batches (see
+//! [`SymbolDatabase::checkpoint_embedding_generation`]), recording the
+//! symbols it finished in `embedding_job_done`. The next run skips those that
+//! still have a vector, and a run that completes clears the list.
+
+use std::collections::HashSet;
+use std::fmt;
+
+use anyhow::{Result, anyhow};
+use rusqlite::{OptionalExtension, Transaction, params};
+
+use super::SymbolDatabase;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EmbeddingJobStatus {
+    /// In progress, or the process died during it.
+    Running,
+    Cancelled,
+    Failed,
+    Complete,
+}
+
+impl EmbeddingJobStatus {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Running => "running",
+            Self::Cancelled => "cancelled",
+            Self::Failed => "failed",
+            Self::Complete => "complete",
+        }
+    }
+
+    fn parse(value: &str) -> Result<Self> {
+        match value {
+            "running" => Ok(Self::Running),
+            "cancelled" => Ok(Self::Cancelled),
+            "failed" => Ok(Self::Failed),
+            "complete" => Ok(Self::Complete),
+            other => Err(anyhow!("unknown embedding job status '{other}'")),
+        }
+    }
+}
+
+impl fmt::Display for EmbeddingJobStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// The last embedding job of a workspace.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EmbeddingJobRecord {
+    pub status: EmbeddingJobStatus,
+    /// Symbols the job set out to embed, including those a resumed job
+    /// carried over.
+    pub total: u64,
+    /// Symbols embedded as of the last checkpoint.
+    pub completed: u64,
+    pub started_at: i64,
+    pub updated_at: i64,
+}
+
+fn unix_now() -> i64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs() as i64)
+        .unwrap_or(0)
+}
+
+/// Record symbols finished since the last checkpoint. Runs inside the
+/// checkpoint's transaction so the list never claims an unpublished vector.
+pub(crate) fn record_job_progress_tx(
+    tx: &Transaction<'_>,
+    done_symbol_ids: &[&str],
+    completed: u64,
+) -> Result<()> {
+    {
+        let mut stmt =
+            tx.prepare("INSERT OR IGNORE INTO embedding_job_done (symbol_id) VALUES (?1)")?;
+        for symbol_id in done_symbol_ids {
+            stmt.execute([symbol_id])?;
+        }
+    }
+    tx.execute(
+        "UPDATE embedding_job_state SET completed = ?1, updated_at = ?2 WHERE id = 1",
+        params![completed as i64, unix_now()],
+    )?;
+    Ok(())
+}
+
+impl SymbolDatabase {
+    /// `pub(crate)` so migration 050 can call it; the `IF NOT EXISTS` DDL
+    /// serves both fresh DBs and upgrades.
+    pub(crate) fn create_embedding_job_tables(&self) -> Result<()> {
+        self.conn.execute_batch(
+            "CREATE TABLE IF NOT EXISTS embedding_job_state (
+                id INTEGER PRIMARY KEY CHECK (id = 1),
+                status TEXT NOT NULL,
+                total INTEGER NOT NULL,
+                completed INTEGER NOT NULL,
+                started_at INTEGER NOT NULL,
+                updated_at INTEGER NOT NULL
+            );
+            CREATE TABLE IF NOT EXISTS embedding_job_done (
+                symbol_id TEXT PRIMARY KEY
+            );",
+        )?;
+        Ok(())
+    }
+
+    /// The last embedding job, if one ever ran.
+    pub fn embedding_job(&self) -> Result<Option<EmbeddingJobRecord>> {
+        let row = self
+            .conn
+            .query_row(
+                "SELECT status, total, completed, started_at, updated_at
+                 FROM embedding_job_state WHERE id = 1",
+                [],
+                |row| {
+                    Ok((
+                        row.get::<_, String>(0)?,
+                        row.get::<_, i64>(1)?,
+                        row.get::<_, i64>(2)?,
+                        row.get::<_, i64>(3)?,
+                        row.get::<_, i64>(4)?,
+                    ))
+                },
+            )
+            .optional()?;
+        row.map(|(status, total, completed, started_at, updated_at)| {
+            Ok(EmbeddingJobRecord {
+                status: EmbeddingJobStatus::parse(&status)?,
+                total: total as u64,
+                completed: completed as u64,
+                started_at,
+                updated_at,
+            })
+        })
+        .transpose()
+    }
+
+    /// Symbols an unfinished job already embedded; empty after a job
+    /// completes.
+    pub fn unfinished_embedding_job_ids(&self) -> Result<HashSet<String>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT d.symbol_id FROM embedding_job_done d
+             JOIN embedding_job_state j ON j.id = 1
+             WHERE j.status != 'complete'",
+        )?;
+        let ids = stmt
+            .query_map([], |row| row.get(0))?
+            .collect::<rusqlite::Result<HashSet<String>>>()?;
+        Ok(ids)
+    }
+
+    /// Start a job of `total` symbols, `completed` of which a resumed job
+    /// already finished. Keeps the done list of the job being resumed.
+    pub fn start_embedding_job(&self, total: u64, completed: u64) -> Result<()> {
+        let now = unix_now();
+        if completed == 0 {
+            self.conn.execute("DELETE FROM embedding_job_done", [])?;
+        }
+        self.conn.execute(
+            "INSERT OR REPLACE INTO embedding_job_state
+             (id, status, total, completed, started_at, updated_at)
+             VALUES (1, 'running', ?1, ?2, ?3, ?3)",
+            params![total as i64, completed as i64, now],
+        )?;
+        Ok(())
+    }
+
+    /// Record how the job ended. A completed job forgets its done list.
+    pub fn finish_embedding_job(&self, status: EmbeddingJobStatus) -> Result<()> {
+        self.conn.execute(
+            "UPDATE embedding_job_state SET status = ?1, updated_at = ?2 WHERE id = 1",
+            params![status.as_str(), unix_now()],
+        )?;
+        if status == EmbeddingJobStatus::Complete {
+            self.conn.execute("DELETE FROM embedding_job_done", [])?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,141 @@
+//! Encryption at rest for workspace databases.
+//!
+//! A workspace database holds extracted source: symbol signatures, compressed
+//! context blobs and, through sqlite-vec, the embedding vectors. Builds with
+//! the `encryption` feature link SQLCipher instead of plain SQLite, and when an
+//! index key is configured every workspace connection is keyed before its
+//! first statement, so all of it is encrypted on disk.
+//!
+//! The key comes from [`INDEX_KEY_ENV`], or else from the standard output of
+//! the command in [`INDEX_KEY_COMMAND_ENV`]: that is how an OS keychain is
+//! used (`security find-generic-password -w -s julie` on macOS,
+//! `secret-tool lookup service julie` with the Secret Service on Linux). It is
+//! resolved once per process. With no key configured nothing changes; a key
+//! configured for a build without SQLCipher is an error, never a silent
+//! fallback to plaintext.
+//!
+//! Extracted source also ends up outside the database, in the Tantivy index
+//! and in extraction checkpoints. Their owners ask [`index_key_configured`]
+//! and keep nothing on disk while it holds: the Tantivy index lives in memory
+//! and is rebuilt from the database on open, checkpoints are not written, and
+//! backups copy the database alone.
+
+use std::process::Command;
+use std::sync::OnceLock;
+
+use anyhow::{Result, anyhow, bail};
+use rusqlite::Connection;
+use tracing::debug;
+
+/// Passphrase for workspace databases.
+pub const INDEX_KEY_ENV: &str = "JULIE_INDEX_KEY";
+
+/// Shell command that prints the passphrase, used when [`INDEX_KEY_ENV`] is unset.
+pub const INDEX_KEY_COMMAND_ENV: &str = "JULIE_INDEX_KEY_COMMAND";
+
+/// Whether this build links SQLCipher.
+pub const ENCRYPTION_SUPPORTED: bool = cfg!(feature = "encryption");
+
+static INDEX_KEY: OnceLock<std::result::Result<Option<String>, String>> = OnceLock::new();
+
+/// The configured index key, resolved from the environment on first use.
+pub fn index_key() -> Result<Option<&'static str>> {
+    INDEX_KEY
+        .get_or_init(|| {
+            resolve_index_key(|name| std::env::var(name).ok()).map_err(|err| format!("{err:#}"))
+        })
+        .as_ref()
+        .map(Option::as_deref)
+        .map_err(|err| anyhow!("{err}"))
+}
+
+/// Whether workspace data must stay encrypted on disk. A key that failed to
+/// resolve counts as configured: the database will refuse to open, and
+/// nothing else may fall back to plaintext in the meantime.
+pub fn index_key_configured() -> bool {
+    !matches!(index_key(), Ok(None))
+}
+
+/// Resolve the index key through `lookup` (the environment, in production).
+/// An empty variable counts as unset; a key command that fails or prints
+/// nothing is an error.
+pub fn resolve_index_key(lookup: impl Fn(&str) -> Option<String>) -> Result<Option<String>> {
+    let non_empty = |name: &str| lookup(name).filter(|value: &String| !value.trim().is_empty());
+    if let Some(key) = non_empty(INDEX_KEY_ENV) {
+        return Ok(Some(key));
+    }
+    let Some(command) = non_empty(INDEX_KEY_COMMAND_ENV) else {
+        return Ok(None);
+    };
+
+    let output = shell(&command)
+        .output()
+        .map_err(|err| anyhow!("{INDEX_KEY_COMMAND_ENV} could not be run: {err}"))?;
+    if !output.status.success() {
+        bail!(
+            "{INDEX_KEY_COMMAND_ENV} exited with {}: {}",
+            output.status,
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+    }
+    let key = String::from_utf8(output.stdout)
+        .map_err(|_| anyhow!("{INDEX_KEY_COMMAND_ENV} printed a key that is not UTF-8"))?;
+    let key = key.trim_end_matches(['\r', '\n']);
+    if key.is_empty() {
+        bail!("{INDEX_KEY_COMMAND_ENV} printed an empty key");
+    }
+    Ok(Some(key.to_string()))
+}
+
+fn shell(command: &str) -> Command {
+    let mut cmd = if cfg!(windows) {
+        let mut cmd = Command::new("cmd");
+        cmd.arg("/C");
+        cmd
+    } else {
+        let mut cmd = Command::new("sh");
+        cmd.arg("-c");
+        cmd
+    };
+    cmd.arg(command);
+    cmd
+}
+
+/// Key `conn` with the configured index key. Must run before any other
+/// statement on the connection; a no-op when no key is configured.
+pub fn apply_index_key(conn: &Connection) -> Result<()> {
+    match index_key()? {
+        Some(key) => key_connection(conn, key),
+        None => Ok(()),
+    }
+}
+
+/// Key `conn` with `key` and check that the database opens with it.
+pub fn key_connection(conn: &Connection, key: &str) -> Result<()> {
+    if !ENCRYPTION_SUPPORTED {
+        bail!(
+            "an index key is set ({INDEX_KEY_ENV} or {INDEX_KEY_COMMAND_ENV}) but this build \
+             has no encryption support; rebuild with `--features encryption` or unset the key"
+        );
+    }
+    conn.pragma_update(None, "key", key)
+        .map_err(|err| anyhow!("Failed to set the index key: {err}"))?;
+
+    // Plain SQLite accepts `PRAGMA key` and ignores it; only SQLCipher
+    // reports a cipher version.
+    let Ok(cipher_version) =
+        conn.query_row("PRAGMA cipher_version", [], |row| row.get::<_, String>(0))
+    else {
+        bail!("this build was expected to link SQLCipher but does not");
+    };
+    conn.query_row("SELECT count(*) FROM sqlite_master", [], |_| Ok(()))
+        .map_err(|err| {
+            anyhow!(
+                "Cannot open the index with the configured key ({err}). Either the key is \
+                 wrong or the index was written unencrypted; delete the workspace's index \
+                 directory to rebuild it encrypted"
+            )
+        })?;
+    debug!("Workspace database keyed (SQLCipher {cipher_version})");
+    Ok(())
+}
@@ -0,0 +1,163 @@
+//! Derived external-module attribution for unresolved call identifiers.
+//!
+//! A call like `_.debounce(...)` or `requests.get(...)` has no target in the
+//! workspace, but the file's imports say which library it comes from. The
+//! post-index analysis pass in julie-index records that library here, one row
+//! per call identifier, so `fast_refs` can group external usage by library and
+//! dependency analyses can count real call-level usage of each dependency.
+
+use anyhow::Result;
+use rusqlite::{params, params_from_iter};
+use tracing::debug;
+
+use super::SymbolDatabase;
+use super::identifiers::escape_sql_like;
+
+/// The library an unresolved call identifier most likely comes from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IdentifierExternalModule {
+    pub identifier_id: String,
+    /// Library name as imported (`lodash`, `requests`, `github.com/gin-gonic/gin`).
+    pub module: String,
+}
+
+/// One call site attributed to an external library.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExternalModuleUsage {
+    pub module: String,
+    /// Call identifier name as extracted (`get`, `_.debounce`, `Client::new`).
+    pub name: String,
+    pub file_path: String,
+    pub start_line: u32,
+    pub containing_symbol_id: Option<String>,
+}
+
+/// Call-level usage totals for one external library.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExternalModuleCallCount {
+    pub module: String,
+    pub calls: usize,
+    pub files: usize,
+}
+
+impl SymbolDatabase {
+    /// Replace the entire `identifier_external_modules` table. The table is
+    /// derived data, so the analysis pass wipes and recomputes it on each run.
+    pub fn replace_all_identifier_external_modules(
+        &self,
+        modules: &[IdentifierExternalModule],
+    ) -> Result<()> {
+        self.conn.execute_batch("BEGIN")?;
+        let result = (|| -> Result<()> {
+            self.conn
+                .execute("DELETE FROM identifier_external_modules", [])?;
+            let mut stmt = self.conn.prepare(
+                "INSERT OR REPLACE INTO identifier_external_modules (identifier_id, module)
+                 VALUES (?1, ?2)",
+            )?;
+            for entry in modules {
+                stmt.execute(params![entry.identifier_id, entry.module])?;
+            }
+            Ok(())
+        })();
+
+        match result {
+            Ok(()) => self.conn.execute_batch("COMMIT")?,
+            Err(e) => {
+                let _ = self.conn.execute_batch("ROLLBACK");
+                return Err(e);
+            }
+        }
+        debug!(
+            "Replaced identifier_external_modules table: {} rows inserted",
+            modules.len()
+        );
+        Ok(())
+    }
+
+    /// External call sites whose identifier name or library matches any of
+    /// `names`, ordered by library, file and line. A name matches the bare
+    /// call name as well as qualified forms (`_.debounce` for `debounce`).
+    pub fn external_module_usages_for_names(
+        &self,
+        names: &[String],
+    ) -> Result<Vec<ExternalModuleUsage>> {
+        if names.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        // Each name binds 4 params (exact name, two qualified suffixes, module).
+        const MAX_NAMES_PER_CHUNK: usize = 200;
+        let mut usages = Vec::new();
+        for chunk in names.chunks(MAX_NAMES_PER_CHUNK) {
+            let mut conditions = Vec::with_capacity(chunk.len());
+            let mut values: Vec<String> = Vec::with_capacity(chunk.len() * 4);
+            for name in chunk {
+                let base = values.len();
+                conditions.push(format!(
+                    "i.name = ?{} OR i.name LIKE ?{} ESCAPE '\\' OR i.name LIKE ?{} ESCAPE '\\' OR m.module = ?{}",
+                    base + 1,
+                    base + 2,
+                    base + 3,
+                    base + 4
+                ));
+                let escaped = escape_sql_like(name);
+                values.push(name.clone());
+                values.push(format!("%.{escaped}"));
+                values.push(format!("%::{escaped}"));
+                values.push(name.clone());
+            }
+            let sql = format!(
+                "SELECT m.module, i.name, i.file_path, i.start_line, i.containing_symbol_id
+                 FROM identifier_external_modules m
+                 JOIN identifiers i ON i.id = m.identifier_id
+                 WHERE {}",
+                conditions.join(" OR ")
+            );
+            let mut stmt = self.conn.prepare(&sql)?;
+            let rows = stmt
+                .query_map(params_from_iter(values.iter()), |row| {
+                    Ok(ExternalModuleUsage {
+                        module: row.get(0)?,
+                        name: row.get(1)?,
+                        file_path: row.get(2)?,
+                        start_line: row.get(3)?,
+                        containing_symbol_id: row.get(4)?,
+                    })
+                })?
+                .collect::<rusqlite::Result<Vec<_>>>()?;
+            usages.extend(rows);
+        }
+
+        usages.sort_by(|a, b| {
+            a.module
+                .cmp(&b.module)
+                .then_with(|| a.file_path.cmp(&b.file_path))
+                .then_with(|| a.start_line.cmp(&b.start_line))
+        });
+        usages.dedup();
+        Ok(usages)
+    }
+
+    /// Call count and distinct calling files per external library, most
+    /// used first.
+    pub fn external_module_call_counts(&self) -> Result<Vec<ExternalModuleCallCount>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT m.module, COUNT(*), COUNT(DISTINCT i.file_path)
+             FROM identifier_external_modules m
+             JOIN identifiers i ON i.id = m.identifier_id
+             GROUP BY m.module
+             ORDER BY COUNT(*) DESC, m.module",
+        )?;
+        let counts = stmt
+            .query_map([], |row| {
+                Ok(ExternalModuleCallCount {
+                    module: row.get(0)?,
+                    calls: row.get::<_, i64>(1)? as usize,
+                    files: row.get::<_, i64>(2)? as usize,
+                })
+            })?
+            .collect::<rusqlite::Result<Vec<_>>>()?;
+        Ok(counts)
+    }
+}
@@ -0,0 +1,110 @@
+//! Everything an editor needs to decorate one file, in one query.
+//!
+//! Editor extensions show symbol outlines, reference counts and staleness
+//! markers for the open file. Building that from `get_symbols` plus one
+//! `fast_refs` per symbol costs hundreds of round trips; this reads the file
+//! row, its symbols and their incoming reference counts in a single joined
+//! statement.
+
+use anyhow::Result;
+use rusqlite::{Row, params};
+use serde::Serialize;
+
+use super::SymbolDatabase;
+
+/// One file's symbols with ranges, kinds and reference counts.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct FileAnnotations {
+    pub file_path: String,
+    pub language: String,
+    pub hash: String,
+    pub line_count: i64,
+    /// File modification time recorded at indexing, in Unix seconds.
+    pub last_modified: i64,
+    /// When the file was last indexed, in Unix seconds.
+    pub last_indexed: i64,
+    /// Ordered by start position.
+    pub symbols: Vec<SymbolAnnotation>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct SymbolAnnotation {
+    pub id: String,
+    pub name: String,
+    pub kind: String,
+    pub parent_id: Option<String>,
+    pub signature: Option<String>,
+    pub visibility: Option<String>,
+    pub start_line: u32,
+    pub start_column: u32,
+    pub end_line: u32,
+    pub end_column: u32,
+    /// Incoming relationships (calls, type uses, implementations) from other
+    /// symbols; self-recursion is not counted.
+    pub reference_count: u32,
+    /// Graph centrality, as used for search ranking.
+    pub reference_score: f64,
+}
+
+impl SymbolDatabase {
+    /// Annotations for `file_path` (workspace-relative), or `None` when the
+    /// file is not indexed. An indexed file without symbols returns an empty
+    /// `symbols` list.
+    pub fn get_file_annotations(&self, file_path: &str) -> Result<Option<FileAnnotations>> {
+        // LEFT JOIN keeps the file row when it has no symbols; the counts are
+        // correlated subqueries over idx_rel_to.
+        let mut stmt = self.conn.prepare(
+            "SELECT f.path, f.language, f.hash, f.line_count, f.last_modified, f.last_indexed,
+                    s.id, s.name, s.kind, s.parent_id, s.signature, s.visibility,
+                    s.start_line, s.start_col, s.end_line, s.end_col,
+                    (SELECT COUNT(*) FROM relationships r
+                     WHERE r.to_symbol_id = s.id AND r.from_symbol_id != s.id),
+                    s.reference_score
+             FROM files f
+             LEFT JOIN symbols s ON s.file_path = f.path
+             WHERE f.path = ?1
+             ORDER BY s.start_line, s.start_col, s.id",
+        )?;
+        let mut rows = stmt.query(params![file_path])?;
+
+        let Some(row) = rows.next()? else {
+            return Ok(None);
+        };
+        let mut annotations = FileAnnotations {
+            file_path: row.get(0)?,
+            language: row.get(1)?,
+            hash: row.get(2)?,
+            line_count: row.get::<_, Option<i64>>(3)?.unwrap_or(0),
+            last_modified: row.get(4)?,
+            last_indexed: row.get::<_, Option<i64>>(5)?.unwrap_or(0),
+            symbols: Vec::new(),
+        };
+        annotations.symbols.extend(symbol_annotation(row)?);
+        while let Some(row) = rows.next()? {
+            annotations.symbols.extend(symbol_annotation(row)?);
+        }
+        Ok(Some(annotations))
+    }
+}
+
+/// The symbol half of a `get_file_annotations` row; `None` for the single
+/// row of a file without symbols.
+fn symbol_annotation(row: &Row<'_>) -> rusqlite::Result<Option<SymbolAnnotation>> {
+    let Some(id) = row.get::<_, Option<String>>(6)? else {
+        return Ok(None);
+    };
+    Ok(Some(SymbolAnnotation {
+        id,
+        name: row.get(7)?,
+        kind: row.get(8)?,
+        parent_id: row.get(9)?,
+        signature: row.get(10)?,
+        visibility: row.get(11)?,
+        start_line: row.get::<_, Option<u32>>(12)?.unwrap_or(0),
+        start_column: row.get::<_, Option<u32>>(13)?.unwrap_or(0),
+        end_line: row.get::<_, Option<u32>>(14)?.unwrap_or(0),
+        end_column: row.get::<_, Option<u32>>(15)?.unwrap_or(0),
+        reference_count: row.get(16)?,
+        reference_score: row.get::<_, Option<f64>>(17)?.unwrap_or(0.0),
+    }))
+}
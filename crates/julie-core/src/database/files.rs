@@ -1,6 +1,7 @@
 // File operations
 
 use super::*;
+use crate::paths::normalize_stored_path;
 use anyhow::{Result, anyhow};
 use blake3;
 use rusqlite::params;
@@ -25,7 +26,7 @@ impl SymbolDatabase {
              (path, language, hash, size, last_modified, last_indexed, symbol_count, content, line_count)
              VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)",
             params![
-                file_info.path,
+                normalize_stored_path(&file_info.path),
                 file_info.language,
                 file_info.hash,
                 file_info.size,
@@ -80,6 +81,26 @@ impl SymbolDatabase {
         Ok(serde_json::from_slice(&payload)?)
     }
 
+    /// Files stored with parse diagnostics, by path: indexed from the
+    /// well-formed code around their syntax errors.
+    pub fn list_files_with_parse_diagnostics(
+        &self,
+    ) -> Result<Vec<(String, Vec<julie_extractors::base::ParseDiagnostic>)>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT path, parse_cache FROM files WHERE parse_cache IS NOT NULL ORDER BY path",
+        )?;
+        let rows = stmt.query_map([], |row| {
+            Ok((row.get::<_, String>(0)?, row.get::<_, Vec<u8>>(1)?))
+        })?;
+
+        let mut files = Vec::new();
+        for row in rows {
+            let (path, payload) = row?;
+            files.push((path, serde_json::from_slice(&payload)?));
+        }
+        Ok(files)
+    }
+
     /// Bulk file storage for initial indexing
     ///
     /// Uses optimized bulk insert pattern:
@@ -144,7 +165,7 @@ impl SymbolDatabase {
 
             for file in files {
                 stmt.execute(params![
-                    file.path,
+                    normalize_stored_path(&file.path),
                     file.language,
                     file.hash,
                     file.size,
@@ -250,7 +271,7 @@ impl SymbolDatabase {
              (path, language, hash, size, last_modified, last_indexed, symbol_count, content)
              VALUES (?1, ?2, ?3, ?4, ?5, ?6, 0, ?7)",
             params![
-                path,
+                normalize_stored_path(path),
                 language,
                 hash,
                 size as i64,
@@ -319,6 +340,28 @@ impl SymbolDatabase {
         Ok(results)
     }
 
+    /// Stream `(path, language, content)` for every file with stored content,
+    /// in path order, without loading the whole table. `visit` returns
+    /// `false` to stop early.
+    pub fn for_each_file_content(
+        &self,
+        mut visit: impl FnMut(&str, &str, &str) -> bool,
+    ) -> Result<()> {
+        let mut stmt = self.conn.prepare(
+            "SELECT path, language, content FROM files WHERE content IS NOT NULL ORDER BY path",
+        )?;
+        let mut rows = stmt.query([])?;
+        while let Some(row) = rows.next()? {
+            let path = row.get_ref(0)?.as_str()?;
+            let language = row.get_ref(1)?.as_str()?;
+            let content = row.get_ref(2)?.as_str()?;
+            if !visit(path, language, content) {
+                break;
+            }
+        }
+        Ok(())
+    }
+
     /// Get stored languages for a bounded set of file paths.
     pub fn get_file_languages_by_paths(&self, paths: &[&str]) -> Result<HashMap<String, String>> {
         if paths.is_empty() {
@@ -0,0 +1,146 @@
+//! Derived framework usage of projects and framework roles of symbols.
+//!
+//! The post-index analysis pass in julie-index detects the application
+//! frameworks each project uses (React, Angular, Spring, Rails, ASP.NET,
+//! Flutter) from its manifests and imports, then labels the symbols that
+//! play a framework role: React components, Spring beans, Rails controller
+//! actions and so on. Both land here so tools can show and filter on them.
+
+use anyhow::Result;
+use rusqlite::{params, params_from_iter};
+use std::collections::HashMap;
+use tracing::debug;
+
+use super::SymbolDatabase;
+
+/// A framework one project uses.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProjectFramework {
+    /// Workspace-relative directory the detection covers (`""` for the root).
+    pub root: String,
+    /// Project owning `root`, when the project map knows it.
+    pub project: Option<String>,
+    /// Framework key (`react`, `spring`, `aspnet`, ...).
+    pub framework: String,
+    /// What the detection was based on, e.g. `package.json dependency react`.
+    pub evidence: String,
+}
+
+/// The framework role one symbol plays.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FrameworkSymbol {
+    pub symbol_id: String,
+    /// Framework key (`react`, `spring`, ...).
+    pub framework: String,
+    /// Role key (`react_component`, `spring_bean`, ...).
+    pub role: String,
+}
+
+impl SymbolDatabase {
+    /// Replace the entire `project_frameworks` and `framework_symbols`
+    /// tables. Both are derived data, so the analysis pass wipes and
+    /// recomputes them together on each run.
+    pub fn replace_all_frameworks(
+        &self,
+        frameworks: &[ProjectFramework],
+        symbols: &[FrameworkSymbol],
+    ) -> Result<()> {
+        self.conn.execute_batch("BEGIN")?;
+        let result = (|| -> Result<()> {
+            self.conn.execute("DELETE FROM project_frameworks", [])?;
+            self.conn.execute("DELETE FROM framework_symbols", [])?;
+            let mut stmt = self.conn.prepare(
+                "INSERT OR REPLACE INTO project_frameworks (root, project, framework, evidence)
+                 VALUES (?1, ?2, ?3, ?4)",
+            )?;
+            for framework in frameworks {
+                stmt.execute(params![
+                    framework.root,
+                    framework.project,
+                    framework.framework,
+                    framework.evidence
+                ])?;
+            }
+            let mut stmt = self.conn.prepare(
+                "INSERT OR REPLACE INTO framework_symbols (symbol_id, framework, role)
+                 VALUES (?1, ?2, ?3)",
+            )?;
+            for symbol in symbols {
+                stmt.execute(params![symbol.symbol_id, symbol.framework, symbol.role])?;
+            }
+            Ok(())
+        })();
+
+        match result {
+            Ok(()) => self.conn.execute_batch("COMMIT")?,
+            Err(e) => {
+                let _ = self.conn.execute_batch("ROLLBACK");
+                return Err(e);
+            }
+        }
+        debug!(
+            "Replaced framework tables: {} project frameworks, {} framework symbols",
+            frameworks.len(),
+            symbols.len()
+        );
+        Ok(())
+    }
+
+    /// Every detected project framework, ordered by root then framework.
+    pub fn project_frameworks(&self) -> Result<Vec<ProjectFramework>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT root, project, framework, evidence FROM project_frameworks
+             ORDER BY root, framework",
+        )?;
+        let rows = stmt.query_map([], |row| {
+            Ok(ProjectFramework {
+                root: row.get(0)?,
+                project: row.get(1)?,
+                framework: row.get(2)?,
+                evidence: row.get(3)?,
+            })
+        })?;
+        Ok(rows.collect::<rusqlite::Result<_>>()?)
+    }
+
+    /// Framework role of each of `symbol_ids` that plays one, keyed by
+    /// symbol id. Symbols without a role are absent.
+    pub fn framework_roles_for_symbols(
+        &self,
+        symbol_ids: &[String],
+    ) -> Result<HashMap<String, FrameworkSymbol>> {
+        if symbol_ids.is_empty() {
+            return Ok(HashMap::new());
+        }
+
+        const CHUNK_SIZE: usize = 500;
+        let mut unique_ids = symbol_ids.to_vec();
+        unique_ids.sort();
+        unique_ids.dedup();
+        let mut roles = HashMap::new();
+
+        for chunk in unique_ids.chunks(CHUNK_SIZE) {
+            let placeholders = (1..=chunk.len())
+                .map(|i| format!("?{i}"))
+                .collect::<Vec<_>>()
+                .join(",");
+            let sql = format!(
+                "SELECT symbol_id, framework, role FROM framework_symbols
+                 WHERE symbol_id IN ({placeholders})"
+            );
+            let mut stmt = self.conn.prepare(&sql)?;
+            let rows = stmt.query_map(params_from_iter(chunk.iter()), |row| {
+                Ok(FrameworkSymbol {
+                    symbol_id: row.get(0)?,
+                    framework: row.get(1)?,
+                    role: row.get(2)?,
+                })
+            })?;
+            for row in rows {
+                let row = row?;
+                roles.insert(row.symbol_id.clone(), row);
+            }
+        }
+        Ok(roles)
+    }
+}
@@ -1,5 +1,6 @@
 // Helper functions and utilities
 
+use super::context_store::read_code_context;
 use super::*;
 use anyhow::Result;
 
@@ -10,7 +11,7 @@ use anyhow::Result;
 /// 2. row_to_symbol() (add row.get() call)
 pub(crate) const SYMBOL_COLUMNS: &str = "id, name, kind, language, file_path, signature, \
      start_line, start_col, end_line, end_col, start_byte, end_byte, \
-     doc_comment, visibility, code_context, parent_id, \
+     doc_comment, visibility, code_context, context_hash, parent_id, \
      metadata, semantic_group, confidence, content_type, \
      body_start_line, body_start_col, body_end_line, body_end_col, \
      body_start_byte, body_end_byte, body_hash";
@@ -27,7 +28,7 @@ pub(crate) const SYMBOL_COLUMNS_LIGHTWEIGHT: &str = "id, name, kind, language, f
 
 pub(crate) const SYMBOL_UPSERT_SQL: &str = "INSERT INTO symbols
      (id, name, kind, language, file_path, signature, start_line, start_col,
-      end_line, end_col, start_byte, end_byte, doc_comment, visibility, code_context,
+      end_line, end_col, start_byte, end_byte, doc_comment, visibility, context_hash,
       parent_id, metadata, semantic_group, confidence, content_type,
       body_start_line, body_start_col, body_end_line, body_end_col,
       body_start_byte, body_end_byte, body_hash)
@@ -46,7 +47,8 @@ pub(crate) const SYMBOL_UPSERT_SQL: &str = "INSERT INTO symbols
       end_byte = excluded.end_byte,
       doc_comment = excluded.doc_comment,
       visibility = excluded.visibility,
-      code_context = excluded.code_context,
+      code_context = NULL,
+      context_hash = excluded.context_hash,
       parent_id = excluded.parent_id,
       metadata = excluded.metadata,
       semantic_group = excluded.semantic_group,
@@ -204,6 +206,21 @@ impl SymbolDatabase {
         Ok(result)
     }
 
+    /// Count relationships grouped by kind, sorted by count descending.
+    pub fn count_relationships_by_kind(&self) -> Result<Vec<(String, i64)>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT kind, COUNT(*) as cnt FROM relationships GROUP BY kind ORDER BY cnt DESC",
+        )?;
+        let rows = stmt.query_map([], |row| {
+            Ok((row.get::<_, String>(0)?, row.get::<_, i64>(1)?))
+        })?;
+        let mut result = Vec::new();
+        for row in rows {
+            result.push(row?);
+        }
+        Ok(result)
+    }
+
     /// Helper to convert database row to Symbol
     pub(crate) fn row_to_symbol(&self, row: &Row) -> rusqlite::Result<Symbol> {
         let kind_str: String = row.get("kind")?;
@@ -238,7 +255,7 @@ impl SymbolDatabase {
             metadata,
             semantic_group: row.get("semantic_group")?,
             confidence: row.get("confidence")?,
-            code_context: row.get("code_context")?,
+            code_context: self.row_code_context(row)?,
             content_type: row.get("content_type")?,
             body_span: row_to_body_span(row)?,
             body_hash: row.get("body_hash")?,
@@ -246,6 +263,18 @@ impl SymbolDatabase {
         })
     }
 
+    /// Inline `code_context` for legacy rows, otherwise the decompressed blob
+    /// behind `context_hash`.
+    fn row_code_context(&self, row: &Row) -> rusqlite::Result<Option<String>> {
+        if let Some(inline) = row.get::<_, Option<String>>("code_context")? {
+            return Ok(Some(inline));
+        }
+        match row.get::<_, Option<String>>("context_hash")? {
+            Some(hash) => read_code_context(&self.conn, &hash),
+            None => Ok(None),
+        }
+    }
+
     /// Lightweight row mapper — skips expensive columns not in SYMBOL_COLUMNS_LIGHTWEIGHT.
     /// Sets code_context, metadata, semantic_group, confidence, content_type to None.
     pub(crate) fn row_to_symbol_lightweight(&self, row: &Row) -> rusqlite::Result<Symbol> {
@@ -27,6 +27,16 @@ pub struct IdentifierRef {
     pub confidence: f32,
 }
 
+/// A call identifier with its language, for per-language call-site scans.
+#[derive(Debug, Clone)]
+pub struct CallSite {
+    pub name: String,
+    pub language: String,
+    pub file_path: String,
+    pub start_line: u32,
+    pub containing_symbol_id: Option<String>,
+}
+
 /// Column list for IdentifierRef queries
 const IDENTIFIER_REF_COLUMNS: &str =
     "name, kind, file_path, start_line, containing_symbol_id, target_symbol_id, confidence";
@@ -50,7 +60,7 @@ fn refill_temp_values(
 /// Escape SQL LIKE wildcard characters so they match literally.
 /// `_` (any single char) and `%` (any sequence) are escaped with `\`.
 /// The backslash itself is also escaped.
-fn escape_sql_like(s: &str) -> String {
+pub(super) fn escape_sql_like(s: &str) -> String {
     let mut out = String::with_capacity(s.len());
     for ch in s.chars() {
         match ch {
@@ -608,4 +618,65 @@ impl SymbolDatabase {
 
         Ok(results)
     }
+
+    /// Find call identifiers whose callee is one of `names`, either bare
+    /// ("eval") or as the last segment of a qualified call ("os.system",
+    /// "Runtime::exec"). The suffix match cannot use the name index, so this
+    /// is meant for whole-workspace scans such as the security audit, not for
+    /// interactive lookups.
+    /// Chunked in batches of 166 names (3 bind params per name).
+    pub fn get_call_sites_by_callee_names(&self, names: &[String]) -> Result<Vec<CallSite>> {
+        if names.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        const MAX_NAMES_PER_CHUNK: usize = 166;
+        let mut results = Vec::new();
+
+        for chunk in names.chunks(MAX_NAMES_PER_CHUNK) {
+            let mut conditions = Vec::with_capacity(chunk.len());
+            let mut params: Vec<String> = Vec::with_capacity(chunk.len() * 3);
+            for name in chunk {
+                let idx = params.len();
+                conditions.push(format!(
+                    "name = ?{} OR name LIKE ?{} ESCAPE '\\' OR name LIKE ?{} ESCAPE '\\'",
+                    idx + 1,
+                    idx + 2,
+                    idx + 3
+                ));
+                params.push(name.clone());
+                params.push(format!("%.{}", escape_sql_like(name)));
+                params.push(format!("%::{}", escape_sql_like(name)));
+            }
+
+            let query = format!(
+                "SELECT name, language, file_path, start_line, containing_symbol_id
+                 FROM identifiers
+                 WHERE kind = 'call' AND ({})",
+                conditions.join(" OR ")
+            );
+            let mut stmt = self.conn.prepare(&query)?;
+            let param_refs: Vec<&dyn rusqlite::ToSql> =
+                params.iter().map(|p| p as &dyn rusqlite::ToSql).collect();
+            let rows = stmt.query_map(&param_refs[..], |row| {
+                Ok(CallSite {
+                    name: row.get(0)?,
+                    language: row.get(1)?,
+                    file_path: row.get(2)?,
+                    start_line: row.get(3)?,
+                    containing_symbol_id: row.get(4)?,
+                })
+            })?;
+            for row in rows {
+                results.push(row?);
+            }
+        }
+
+        debug!(
+            "Found {} call sites for {} callee names",
+            results.len(),
+            names.len()
+        );
+        Ok(results)
+    }
 }
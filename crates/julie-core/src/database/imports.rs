@@ -0,0 +1,64 @@
+//! Import statements as extracted: one row per import symbol, with the
+//! statement text the language-specific import parsers need.
+//!
+//! Dependency-manifest entries (`semantic_group = 'dependency'`) are import
+//! symbols too but are not statements in source, so they are left out.
+
+use anyhow::Result;
+
+use super::SymbolDatabase;
+
+/// One import symbol and the statement it came from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ImportStatement {
+    pub symbol_id: String,
+    pub file_path: String,
+    pub language: String,
+    /// Symbol name the extractor gave the import (usually a bound name).
+    pub name: String,
+    /// The signature, else the stored code context, else the name.
+    pub statement: String,
+    pub start_line: u32,
+}
+
+impl SymbolDatabase {
+    /// Every import statement in the workspace, by file and line.
+    pub fn get_import_statements(&self) -> Result<Vec<ImportStatement>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT id, file_path, language, name, COALESCE(signature, code_context),
+                    context_hash, start_line
+             FROM symbols
+             WHERE kind = 'import' AND (semantic_group IS NULL OR semantic_group != 'dependency')
+             ORDER BY file_path, start_line",
+        )?;
+        let rows = stmt.query_map([], |row| {
+            Ok((
+                ImportStatement {
+                    symbol_id: row.get(0)?,
+                    file_path: row.get(1)?,
+                    language: row.get(2)?,
+                    name: row.get(3)?,
+                    statement: row.get::<_, Option<String>>(4)?.unwrap_or_default(),
+                    start_line: row.get(6)?,
+                },
+                row.get::<_, Option<String>>(5)?,
+            ))
+        })?;
+
+        let mut imports = Vec::new();
+        for row in rows {
+            let (mut import, context_hash) = row?;
+            // Imports almost always carry a signature; the compressed context
+            // is only read when they do not.
+            if import.statement.is_empty() {
+                import.statement = match context_hash {
+                    Some(hash) => self.get_code_context(&hash)?,
+                    None => None,
+                }
+                .unwrap_or_else(|| import.name.clone());
+            }
+            imports.push(import);
+        }
+        Ok(imports)
+    }
+}
@@ -43,6 +43,25 @@ impl SymbolDatabase {
             .map_err(Into::into)
     }
 
+    /// Recorded version of `component` and when it was recorded, in Unix
+    /// seconds.
+    pub fn get_index_engine_state(
+        &self,
+        workspace_id: &str,
+        component: &str,
+    ) -> Result<Option<(String, i64)>> {
+        self.conn
+            .query_row(
+                "SELECT version, updated_at
+                 FROM index_engine_state
+                 WHERE workspace_id = ?1 AND component = ?2",
+                params![workspace_id, component],
+                |row| Ok((row.get(0)?, row.get(1)?)),
+            )
+            .optional()
+            .map_err(Into::into)
+    }
+
     pub fn index_engine_version_matches(
         &self,
         workspace_id: &str,
@@ -1,6 +1,9 @@
 // Schema migration system for database versioning
 
+use super::context_store::store_code_context;
+use super::stored_paths::StoredPathScan;
 use super::*;
+use crate::symbol_ids::{SymbolIdKey, stable_symbol_ids};
 use anyhow::{Result, anyhow};
 use rusqlite::params;
 use tracing::{debug, info, warn};
@@ -13,7 +16,30 @@ fn get_unix_timestamp() -> Result<i64> {
 }
 
 /// Current schema version - increment when adding migrations
-pub const LATEST_SCHEMA_VERSION: i32 = 30;
+pub const LATEST_SCHEMA_VERSION: i32 = 50;
+
+/// Every `(table, column)` holding a symbol ID, rewritten by migration 036.
+const SYMBOL_ID_COLUMNS: &[(&str, &str)] = &[
+    ("symbols", "id"),
+    ("symbols", "parent_id"),
+    ("symbol_annotations", "symbol_id"),
+    ("identifiers", "containing_symbol_id"),
+    ("identifiers", "target_symbol_id"),
+    ("types", "symbol_id"),
+    ("relationships", "from_symbol_id"),
+    ("relationships", "to_symbol_id"),
+    ("literals", "containing_symbol_id"),
+    ("source_regions", "containing_symbol_id"),
+    ("structural_facts", "containing_symbol_id"),
+    ("complexity_metrics", "symbol_id"),
+    ("web_edges", "from_symbol_id"),
+    ("web_edges", "to_symbol_id"),
+    ("cross_language_links", "from_symbol_id"),
+    ("cross_language_links", "to_symbol_id"),
+    ("symbol_aliases", "symbol_id"),
+    ("symbol_chunks", "symbol_id"),
+    ("type_arguments", "target_symbol_id"),
+];
 
 impl SymbolDatabase {
     // ============================================================
@@ -126,6 +152,26 @@ impl SymbolDatabase {
             28 => self.migration_028_add_literals()?,
             29 => self.migration_029_add_extractor_enrichments()?,
             30 => self.migration_030_add_web_edges()?,
+            31 => self.migration_031_add_cross_language_links()?,
+            32 => self.migration_032_add_symbol_chunk_vectors()?,
+            33 => self.migration_033_add_identifier_external_modules()?,
+            34 => self.migration_034_add_symbol_aliases()?,
+            35 => self.migration_035_add_symbol_body_hash_index()?,
+            36 => self.migration_036_stable_symbol_ids()?,
+            37 => self.migration_037_compress_symbol_contexts()?,
+            38 => self.migration_038_add_operation_journal()?,
+            39 => self.migration_039_add_swift_module_files()?,
+            40 => self.migration_040_add_revision_symbol_changes()?,
+            41 => self.migration_041_add_embedding_generations()?,
+            42 => self.migration_042_add_reference_count()?,
+            43 => self.migration_043_add_project_files()?,
+            44 => self.migration_044_add_build_target_files()?,
+            45 => self.migration_045_add_framework_tables()?,
+            46 => self.migration_046_add_identifier_reference_count()?,
+            47 => self.migration_047_add_nested_repositories()?,
+            48 => self.migration_048_add_soft_deleted_files()?,
+            49 => self.migration_049_normalize_stored_paths()?,
+            50 => self.migration_050_add_embedding_jobs()?,
             _ => return Err(anyhow!("Unknown migration version: {}", version)),
         }
         Ok(())
@@ -164,6 +210,26 @@ impl SymbolDatabase {
             28 => "Add literals table",
             29 => "Add extractor enrichment tables",
             30 => "Add web_edges table for derived web navigation edges",
+            31 => "Add cross_language_links table for derived counterpart links",
+            32 => "Add symbol chunk tables for snippet-level embeddings",
+            33 => "Add identifier_external_modules table for external call attribution",
+            34 => "Add symbol_aliases table for build-time renamed symbols",
+            35 => "Add body_hash index for duplicate symbol grouping",
+            36 => "Re-key symbols onto stable IDs",
+            37 => "Move symbol code_context into compressed context_blobs",
+            38 => "Add operation_journal tables for undoing file edits",
+            39 => "Add swift_module_files table for Swift module membership",
+            40 => "Add revision_symbol_changes table for generation comparison",
+            41 => "Add embedding generation tables for two-phase vector commits",
+            42 => "Add trigger-maintained reference_count column for popularity ranking",
+            43 => "Add project_files table for monorepo project membership",
+            44 => "Add build_target_files table for build target membership",
+            45 => "Add project_frameworks and framework_symbols tables for framework detection",
+            46 => "Add identifier_reference_count column for usage badges",
+            47 => "Add nested_repositories table for submodules and nested git repositories",
+            48 => "Add soft_deleted_files table for the file deletion grace period",
+            49 => "Normalize stored file paths to forward slashes without Windows prefixes",
+            50 => "Add embedding_job_state and embedding_job_done tables for resumable embedding",
             _ => "Unknown migration",
         };
 
@@ -197,7 +263,7 @@ impl SymbolDatabase {
         Ok(columns.contains(&column.to_string()))
     }
 
-    fn table_exists(&self, table: &str) -> Result<bool> {
+    pub(super) fn table_exists(&self, table: &str) -> Result<bool> {
         self.conn
             .query_row(
                 "SELECT EXISTS(
@@ -936,6 +1002,391 @@ impl SymbolDatabase {
         Ok(())
     }
 
+    fn migration_031_add_cross_language_links(&self) -> Result<()> {
+        info!("Running migration 031: Add cross_language_links table");
+        self.create_cross_language_links_table()?;
+        info!("Migration 031 complete: cross_language_links table added");
+        Ok(())
+    }
+
+    fn migration_032_add_symbol_chunk_vectors(&self) -> Result<()> {
+        info!("Running migration 032: Add symbol_chunks and symbol_chunk_vectors tables");
+        let dims: i64 = self
+            .conn
+            .query_row(
+                "SELECT dimensions FROM embedding_config WHERE id = 1",
+                [],
+                |row| row.get(0),
+            )
+            .unwrap_or(384);
+        let dims = usize::try_from(dims)
+            .map_err(|_| anyhow!("Invalid embedding dimensions in embedding_config: {dims}"))?;
+        self.create_symbol_chunk_tables(dims)?;
+        info!("Migration 032 complete: symbol chunk tables added ({dims}-dim vectors)");
+        Ok(())
+    }
+
+    fn migration_033_add_identifier_external_modules(&self) -> Result<()> {
+        info!("Running migration 033: Add identifier_external_modules table");
+        self.create_identifier_external_modules_table()?;
+        info!("Migration 033 complete: identifier_external_modules table added");
+        Ok(())
+    }
+
+    fn migration_034_add_symbol_aliases(&self) -> Result<()> {
+        info!("Running migration 034: Add symbol_aliases table");
+        self.create_symbol_aliases_table()?;
+        info!("Migration 034 complete: symbol_aliases table added");
+        Ok(())
+    }
+
+    fn migration_035_add_symbol_body_hash_index(&self) -> Result<()> {
+        info!("Running migration 035: Add body_hash index on symbols");
+        if !self.table_exists("symbols")? {
+            debug!("symbols table does not exist, skipping migration 035");
+            return Ok(());
+        }
+        self.create_symbol_body_hash_index()?;
+        info!("Migration 035 complete: body_hash index added");
+        Ok(())
+    }
+
+    /// Migration 036: Re-key existing symbols onto stable IDs.
+    ///
+    /// Rewrites `symbols.id` and every column that points at a symbol, so
+    /// embeddings and relationships carry over instead of being orphaned by the
+    /// next re-index. Search projections are marked stale because Tantivy
+    /// documents are keyed by symbol ID.
+    fn migration_036_stable_symbol_ids(&self) -> Result<()> {
+        info!("Running migration 036: Re-key symbols onto stable IDs");
+        if !self.table_exists("symbols")? {
+            debug!("symbols table does not exist, skipping migration 036");
+            return Ok(());
+        }
+
+        type StoredKey = (
+            String,
+            Option<String>,
+            String,
+            String,
+            Option<String>,
+            String,
+            u32,
+        );
+        let rows: Vec<StoredKey> = {
+            let mut stmt = self.conn.prepare(
+                "SELECT id, parent_id, name, kind, signature, file_path, start_byte FROM symbols",
+            )?;
+            stmt.query_map([], |row| {
+                Ok((
+                    row.get(0)?,
+                    row.get(1)?,
+                    row.get(2)?,
+                    row.get(3)?,
+                    row.get(4)?,
+                    row.get(5)?,
+                    row.get::<_, Option<u32>>(6)?.unwrap_or(0),
+                ))
+            })?
+            .collect::<rusqlite::Result<_>>()?
+        };
+        let keys: Vec<SymbolIdKey<'_>> = rows
+            .iter()
+            .map(
+                |(id, parent_id, name, kind, signature, file_path, start_byte)| SymbolIdKey {
+                    id,
+                    parent_id: parent_id.as_deref(),
+                    name,
+                    kind,
+                    signature: signature.as_deref(),
+                    file_path,
+                    start_byte: *start_byte,
+                },
+            )
+            .collect();
+        let changed: Vec<(String, String)> = stable_symbol_ids(&keys)
+            .into_iter()
+            .filter(|(old, new)| old != new)
+            .collect();
+        if changed.is_empty() {
+            info!("Migration 036 complete: symbol IDs already stable");
+            return Ok(());
+        }
+
+        let tx = self.conn.unchecked_transaction()?;
+        tx.execute_batch(
+            "PRAGMA defer_foreign_keys = ON;
+             CREATE TEMP TABLE symbol_id_map (old_id TEXT PRIMARY KEY, new_id TEXT NOT NULL);",
+        )?;
+        {
+            let mut insert =
+                tx.prepare("INSERT INTO temp.symbol_id_map (old_id, new_id) VALUES (?1, ?2)")?;
+            for (old, new) in &changed {
+                insert.execute(params![old, new])?;
+            }
+        }
+
+        for (table, column) in SYMBOL_ID_COLUMNS {
+            if !self.table_exists(table)? || !self.has_column(table, column)? {
+                continue;
+            }
+            tx.execute(
+                &format!(
+                    "UPDATE {table}
+                     SET {column} = (SELECT new_id FROM temp.symbol_id_map WHERE old_id = {table}.{column})
+                     WHERE {column} IN (SELECT old_id FROM temp.symbol_id_map)"
+                ),
+                [],
+            )?;
+        }
+
+        // vec0 tables cannot update their primary key; move each row instead.
+        if self.table_exists("symbol_vectors")? {
+            let moved: Vec<(String, String, Vec<u8>)> = {
+                let mut stmt = tx.prepare(
+                    "SELECT v.symbol_id, m.new_id, v.embedding
+                     FROM symbol_vectors v
+                     JOIN temp.symbol_id_map m ON m.old_id = v.symbol_id",
+                )?;
+                stmt.query_map([], |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)))?
+                    .collect::<rusqlite::Result<_>>()?
+            };
+            for (old, new, embedding) in &moved {
+                tx.execute("DELETE FROM symbol_vectors WHERE symbol_id = ?1", [old])?;
+                tx.execute(
+                    "INSERT INTO symbol_vectors (symbol_id, embedding) VALUES (?1, ?2)",
+                    params![new, embedding],
+                )?;
+            }
+            debug!("Migration 036 moved {} symbol embeddings", moved.len());
+        }
+
+        if self.table_exists("projection_states")? {
+            tx.execute(
+                "UPDATE projection_states
+                 SET status = 'stale', detail = 'symbol ids re-keyed', updated_at = ?1",
+                [get_unix_timestamp()?],
+            )?;
+        }
+
+        tx.execute_batch("DROP TABLE temp.symbol_id_map;")?;
+        tx.commit()?;
+
+        info!(
+            "Migration 036 complete: {} symbol IDs re-keyed",
+            changed.len()
+        );
+        Ok(())
+    }
+
+    /// Migration 037: Move inline `code_context` into `context_blobs`.
+    ///
+    /// Converts in batches so large databases never hold every context in
+    /// memory. Freed pages are reused by later writes; the file itself only
+    /// shrinks on the next VACUUM.
+    fn migration_037_compress_symbol_contexts(&self) -> Result<()> {
+        info!("Running migration 037: Compress symbol code_context");
+        if !self.table_exists("symbols")? {
+            debug!("symbols table does not exist, skipping migration 037");
+            return Ok(());
+        }
+        if !self.has_column("symbols", "context_hash")? {
+            self.conn
+                .execute("ALTER TABLE symbols ADD COLUMN context_hash TEXT", [])?;
+        }
+        self.create_context_blobs_table()?;
+
+        const BATCH_SIZE: i64 = 5000;
+        let tx = self.conn.unchecked_transaction()?;
+        let mut converted = 0usize;
+        loop {
+            // Converted rows drop out of the WHERE clause, so each pass
+            // picks up where the last one stopped.
+            let batch: Vec<(i64, String)> = {
+                let mut stmt = tx.prepare(
+                    "SELECT rowid, code_context FROM symbols
+                     WHERE code_context IS NOT NULL
+                     LIMIT ?1",
+                )?;
+                stmt.query_map([BATCH_SIZE], |row| Ok((row.get(0)?, row.get(1)?)))?
+                    .collect::<rusqlite::Result<_>>()?
+            };
+            if batch.is_empty() {
+                break;
+            }
+            let mut update = tx.prepare(
+                "UPDATE symbols SET code_context = NULL, context_hash = ?1 WHERE rowid = ?2",
+            )?;
+            for (rowid, context) in &batch {
+                let hash = store_code_context(&tx, Some(context))?;
+                update.execute(params![hash, rowid])?;
+            }
+            converted += batch.len();
+        }
+        tx.commit()?;
+
+        info!("Migration 037 complete: {converted} symbol contexts compressed");
+        Ok(())
+    }
+
+    fn migration_038_add_operation_journal(&self) -> Result<()> {
+        info!("Running migration 038: Add operation_journal tables");
+        self.create_operation_journal_tables()?;
+        info!("Migration 038 complete: operation_journal tables added");
+        Ok(())
+    }
+
+    fn migration_039_add_swift_module_files(&self) -> Result<()> {
+        info!("Running migration 039: Add swift_module_files table");
+        self.create_swift_module_files_table()?;
+        info!("Migration 039 complete: swift_module_files table added");
+        Ok(())
+    }
+
+    fn migration_040_add_revision_symbol_changes(&self) -> Result<()> {
+        info!("Running migration 040: Add revision_symbol_changes table");
+        self.create_revision_symbol_changes_table()?;
+        info!("Migration 040 complete: revision_symbol_changes table added");
+        Ok(())
+    }
+
+    fn migration_041_add_embedding_generations(&self) -> Result<()> {
+        info!("Running migration 041: Add embedding generation tables");
+        self.create_embedding_generation_tables()?;
+        info!("Migration 041 complete: embedding generation tables added");
+        Ok(())
+    }
+
+    /// Migration 042: Add `symbols.reference_count` and the triggers that keep
+    /// it current, then backfill it from the existing relationships.
+    fn migration_042_add_reference_count(&self) -> Result<()> {
+        info!("Running migration 042: Add reference_count column");
+
+        let table_exists: bool = self.conn.query_row(
+            "SELECT COUNT(*) FROM sqlite_master WHERE type='table' AND name='symbols'",
+            [],
+            |row| row.get::<_, i32>(0).map(|c| c > 0),
+        )?;
+        if !table_exists {
+            debug!("Symbols table doesn't exist yet (fresh database), skipping migration");
+            return Ok(());
+        }
+
+        if !self.has_column("symbols", "reference_count")? {
+            self.conn.execute(
+                "ALTER TABLE symbols ADD COLUMN reference_count INTEGER NOT NULL DEFAULT 0",
+                [],
+            )?;
+        }
+
+        let relationships_exist: bool = self.conn.query_row(
+            "SELECT COUNT(*) FROM sqlite_master WHERE type='table' AND name='relationships'",
+            [],
+            |row| row.get::<_, i32>(0).map(|c| c > 0),
+        )?;
+        if relationships_exist {
+            self.create_reference_count_triggers()?;
+            self.recompute_reference_counts()?;
+        }
+
+        info!("Migration 042 complete: reference_count column added and backfilled");
+        Ok(())
+    }
+
+    fn migration_043_add_project_files(&self) -> Result<()> {
+        info!("Running migration 043: Add project_files table");
+        self.create_project_files_table()?;
+        info!("Migration 043 complete: project_files table added");
+        Ok(())
+    }
+
+    fn migration_044_add_build_target_files(&self) -> Result<()> {
+        info!("Running migration 044: Add build_target_files table");
+        self.create_build_target_files_table()?;
+        info!("Migration 044 complete: build_target_files table added");
+        Ok(())
+    }
+
+    fn migration_045_add_framework_tables(&self) -> Result<()> {
+        info!("Running migration 045: Add framework tables");
+        self.create_framework_tables()?;
+        info!("Migration 045 complete: project_frameworks and framework_symbols tables added");
+        Ok(())
+    }
+
+    /// Migration 046: Add `symbols.identifier_reference_count` and backfill it
+    /// from the existing identifiers. The identifier writers keep it current
+    /// from then on.
+    fn migration_046_add_identifier_reference_count(&self) -> Result<()> {
+        info!("Running migration 046: Add identifier_reference_count column");
+
+        let table_exists: bool = self.conn.query_row(
+            "SELECT COUNT(*) FROM sqlite_master WHERE type='table' AND name='symbols'",
+            [],
+            |row| row.get::<_, i32>(0).map(|c| c > 0),
+        )?;
+        if !table_exists {
+            debug!("Symbols table doesn't exist yet (fresh database), skipping migration");
+            return Ok(());
+        }
+
+        if !self.has_column("symbols", "identifier_reference_count")? {
+            self.conn.execute(
+                "ALTER TABLE symbols ADD COLUMN identifier_reference_count INTEGER NOT NULL DEFAULT 0",
+                [],
+            )?;
+        }
+
+        let identifiers_exist: bool = self.conn.query_row(
+            "SELECT COUNT(*) FROM sqlite_master WHERE type='table' AND name='identifiers'",
+            [],
+            |row| row.get::<_, i32>(0).map(|c| c > 0),
+        )?;
+        if identifiers_exist {
+            self.recompute_identifier_reference_counts()?;
+        }
+
+        info!("Migration 046 complete: identifier_reference_count column added and backfilled");
+        Ok(())
+    }
+
+    fn migration_047_add_nested_repositories(&self) -> Result<()> {
+        info!("Running migration 047: Add nested_repositories table");
+        self.create_nested_repositories_table()?;
+        info!("Migration 047 complete: nested_repositories table added");
+        Ok(())
+    }
+
+    fn migration_048_add_soft_deleted_files(&self) -> Result<()> {
+        info!("Running migration 048: Add soft_deleted_files table");
+        self.create_soft_deleted_files_table()?;
+        info!("Migration 048 complete: soft_deleted_files table added");
+        Ok(())
+    }
+
+    /// Migration 049: Rewrite stored paths into the form
+    /// [`normalize_stored_path`](crate::paths::normalize_stored_path) produces.
+    ///
+    /// Symbol IDs derived from the old spelling are left alone; the next
+    /// re-extraction of the file replaces them.
+    fn migration_049_normalize_stored_paths(&self) -> Result<()> {
+        info!("Running migration 049: Normalize stored file paths");
+        let changed =
+            self.rewrite_stored_paths(StoredPathScan::All, crate::paths::normalize_stored_path)?;
+        info!(
+            "Migration 049 complete: {} stored paths normalized",
+            changed
+        );
+        Ok(())
+    }
+
+    fn migration_050_add_embedding_jobs(&self) -> Result<()> {
+        info!("Running migration 050: Add embedding job tables");
+        self.create_embedding_job_tables()?;
+        info!("Migration 050 complete: embedding job tables added");
+        Ok(())
+    }
+
     fn migration_016_add_canonical_revisions(&self) -> Result<()> {
         info!("Running migration 016: Add canonical_revisions table");
 
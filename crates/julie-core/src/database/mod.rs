@@ -17,25 +17,44 @@ use julie_extractors::{Relationship, RelationshipKind, Symbol, SymbolKind};
 
 // Module declarations
 pub mod analytics;
+mod build_targets;
 pub mod bulk;
 mod bulk_operations;
+mod chunk_vectors;
 mod complexity_metrics;
+mod context_store;
+mod cross_language_links;
+mod embedding_generations;
+mod embedding_jobs;
+pub mod encryption;
+mod external_modules;
+mod file_annotations;
 mod files;
+mod frameworks;
 mod helpers;
 mod identifiers;
 pub mod impact_graph;
+mod imports;
 mod index_engine;
 mod memory_vectors;
 mod migrations;
+mod operation_journal;
 mod projections;
+mod projects;
 mod relationships;
 mod repairs;
 mod revision_changes;
+mod revision_symbol_changes;
 mod revisions;
 mod schema;
 mod schema_enrichments;
+mod soft_deletes;
 mod source_regions;
+mod stored_paths;
 mod structural_facts;
+mod swift_modules;
+mod symbol_aliases;
+mod symbol_copies;
 mod symbols;
 mod tool_calls;
 mod type_queries;
@@ -44,10 +63,31 @@ pub mod vectors;
 mod web_edges;
 mod workspace;
 pub use analytics::*;
+pub use build_targets::BuildTargetFile;
+pub use chunk_vectors::ChunkEmbedding;
+pub use context_store::ContextStorageStats;
+pub use cross_language_links::CrossLanguageLink;
+pub use embedding_generations::{EmbeddingCommit, EmbeddingGeneration};
+pub use embedding_jobs::{EmbeddingJobRecord, EmbeddingJobStatus};
+pub use external_modules::{
+    ExternalModuleCallCount, ExternalModuleUsage, IdentifierExternalModule,
+};
+pub use file_annotations::{FileAnnotations, SymbolAnnotation};
+pub use frameworks::{FrameworkSymbol, ProjectFramework};
+pub use imports::ImportStatement;
+pub use operation_journal::{JournalFile, JournalOperation, MAX_JOURNAL_OPERATIONS};
 pub use projections::{ProjectionState, ProjectionStatus};
+pub use projects::{NestedRepository, ProjectFile};
+pub use repairs::{IndexingRepairRecord, PARSE_SKIPPED_REASON};
 pub use revision_changes::{RevisionChangeKind, RevisionFileChange};
+pub use revision_symbol_changes::{
+    GenerationDiff, RevisionSymbolChange, SYMBOL_GENERATION_RETENTION, SymbolChangeKind,
+};
 pub use revisions::{CanonicalRevision, CanonicalRevisionKind};
 pub use structural_facts::*;
+pub use swift_modules::SwiftModuleFile;
+pub use symbol_aliases::SymbolAlias;
+pub use symbol_copies::SymbolCopy;
 pub use tool_calls::{HistorySummary, ToolCallSummary};
 pub use web_edges::*;
 
@@ -67,7 +107,7 @@ fn register_sqlite_vec() {
 
 // Re-export public types
 pub use files::{calculate_file_hash, create_file_info};
-pub use identifiers::IdentifierRef;
+pub use identifiers::{CallSite, IdentifierRef};
 pub use migrations::LATEST_SCHEMA_VERSION;
 pub use types::*;
 
@@ -126,6 +166,10 @@ impl SymbolDatabase {
         let conn =
             Connection::open(&file_path).map_err(|e| anyhow!("Failed to open database: {}", e))?;
 
+        // An encrypted database must be keyed before anything reads it,
+        // including the journal mode switch below.
+        encryption::apply_index_key(&conn)?;
+
         // 🚨 CRITICAL: Set WAL mode IMMEDIATELY after connection open
         // This MUST happen before ANY other database operations (including migrations)
         // to prevent corruption when multiple processes access the same database.
@@ -260,6 +304,46 @@ impl SymbolDatabase {
         Ok((busy, log, checkpointed))
     }
 
+    /// Write a consistent copy of this database to `dest` with `VACUUM INTO`.
+    ///
+    /// The copy runs inside a single read transaction, so under WAL it sees one
+    /// committed state and does not block writers. Embeddings live in the
+    /// sqlite-vec shadow tables and are copied with everything else. With an
+    /// index key set the copy is written by `sqlcipher_export` into a
+    /// database keyed the same way, so it is never plaintext.
+    ///
+    /// `dest` must not exist. Returns the size of the copy in bytes.
+    pub fn backup_to(&self, dest: &Path) -> Result<u64> {
+        if dest.exists() {
+            return Err(anyhow!("Backup target already exists: {}", dest.display()));
+        }
+        let dest_str = dest
+            .to_str()
+            .ok_or_else(|| anyhow!("Backup path is not valid UTF-8: {}", dest.display()))?;
+
+        debug!("Backing up {} to {}", self.file_path.display(), dest_str);
+        match encryption::index_key()? {
+            Some(key) => self
+                .conn
+                .execute("ATTACH DATABASE ?1 AS backup KEY ?2", [dest_str, key])
+                .and_then(|_| {
+                    let exported =
+                        self.conn
+                            .query_row("SELECT sqlcipher_export('backup')", [], |_| Ok(()));
+                    let detached = self.conn.execute_batch("DETACH DATABASE backup");
+                    exported.and(detached)
+                })
+                .map_err(|e| anyhow!("Failed to back up encrypted database: {}", e))?,
+            None => self
+                .conn
+                .execute("VACUUM INTO ?1", [dest_str])
+                .map(|_| ())
+                .map_err(|e| anyhow!("Failed to back up database: {}", e))?,
+        }
+
+        Ok(std::fs::metadata(dest)?.len())
+    }
+
     /// Wrap a pooled connection in a `SymbolDatabase`.
     ///
     /// The caller is responsible for schema state — this constructor assumes
@@ -0,0 +1,179 @@
+//! Undo log for operations that modify workspace files.
+//!
+//! Each applied `edit_file`, `rewrite_symbol` or `rename_symbol` records one
+//! journal entry holding every file it changed, with the content before and
+//! after the operation (zstd-compressed). `manage_workspace
+//! rollback_last_operation` restores the "before" state of the newest entry
+//! that has not been rolled back, so repeated rollbacks walk back through
+//! the log.
+//!
+//! Only file content is journaled. Symbol, relationship and identifier rows
+//! are derived from it, so the rollback reindexes the restored files before it
+//! returns, and the canonical revision deltas (see `revision_symbol_changes`)
+//! record what that did to the rows. A reindex on its own changes no file and
+//! re-extracting reproduces its rows, so it has nothing to journal. Only the
+//! newest [`MAX_JOURNAL_OPERATIONS`] entries are kept.
+
+use anyhow::Result;
+use rusqlite::{OptionalExtension, params};
+
+use super::SymbolDatabase;
+use super::context_store::COMPRESSION_LEVEL;
+
+/// Journal entries kept per workspace; older ones are pruned on insert.
+pub const MAX_JOURNAL_OPERATIONS: i64 = 50;
+
+/// One file changed by a journaled operation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JournalFile {
+    /// Path as the operation addressed it, usually workspace-relative.
+    pub file_path: String,
+    pub before: String,
+    pub after: String,
+}
+
+/// A recorded operation and the files it changed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JournalOperation {
+    pub id: i64,
+    /// Tool that performed it (`edit_file`, `rename_symbol`, ...).
+    pub kind: String,
+    /// One-line description for listings and rollback reports.
+    pub summary: String,
+    pub created_at: i64,
+    pub files: Vec<JournalFile>,
+}
+
+impl SymbolDatabase {
+    /// `pub(crate)` so migration 038 can call it; the `IF NOT EXISTS` DDL
+    /// serves both fresh DBs and upgrades.
+    pub(crate) fn create_operation_journal_tables(&self) -> Result<()> {
+        self.conn.execute_batch(
+            "CREATE TABLE IF NOT EXISTS operation_journal (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                kind TEXT NOT NULL,
+                summary TEXT NOT NULL,
+                created_at INTEGER NOT NULL,
+                rolled_back_at INTEGER
+            );
+            CREATE TABLE IF NOT EXISTS operation_journal_files (
+                operation_id INTEGER NOT NULL
+                    REFERENCES operation_journal(id) ON DELETE CASCADE,
+                file_path TEXT NOT NULL,
+                before_content BLOB NOT NULL,
+                after_content BLOB NOT NULL,
+                PRIMARY KEY (operation_id, file_path)
+            );",
+        )?;
+        Ok(())
+    }
+
+    /// Record an applied operation and prune entries beyond
+    /// [`MAX_JOURNAL_OPERATIONS`]. Returns the new entry's ID.
+    pub fn record_operation(
+        &self,
+        kind: &str,
+        summary: &str,
+        files: &[JournalFile],
+    ) -> Result<i64> {
+        let now = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)?
+            .as_secs() as i64;
+        let tx = self.conn.unchecked_transaction()?;
+        tx.execute(
+            "INSERT INTO operation_journal (kind, summary, created_at) VALUES (?1, ?2, ?3)",
+            params![kind, summary, now],
+        )?;
+        let id = tx.last_insert_rowid();
+        {
+            let mut stmt = tx.prepare(
+                "INSERT OR REPLACE INTO operation_journal_files
+                 (operation_id, file_path, before_content, after_content)
+                 VALUES (?1, ?2, ?3, ?4)",
+            )?;
+            for file in files {
+                stmt.execute(params![
+                    id,
+                    file.file_path,
+                    zstd::encode_all(file.before.as_bytes(), COMPRESSION_LEVEL)?,
+                    zstd::encode_all(file.after.as_bytes(), COMPRESSION_LEVEL)?,
+                ])?;
+            }
+        }
+        // Explicit child delete: the cascade only fires with foreign keys on.
+        tx.execute(
+            "DELETE FROM operation_journal_files WHERE operation_id <= ?1",
+            [id - MAX_JOURNAL_OPERATIONS],
+        )?;
+        tx.execute(
+            "DELETE FROM operation_journal WHERE id <= ?1",
+            [id - MAX_JOURNAL_OPERATIONS],
+        )?;
+        tx.commit()?;
+        Ok(id)
+    }
+
+    /// The newest operation that has not been rolled back.
+    pub fn last_operation(&self) -> Result<Option<JournalOperation>> {
+        let operation = self
+            .conn
+            .query_row(
+                "SELECT id, kind, summary, created_at FROM operation_journal
+                 WHERE rolled_back_at IS NULL
+                 ORDER BY id DESC
+                 LIMIT 1",
+                [],
+                |row| {
+                    Ok(JournalOperation {
+                        id: row.get(0)?,
+                        kind: row.get(1)?,
+                        summary: row.get(2)?,
+                        created_at: row.get(3)?,
+                        files: Vec::new(),
+                    })
+                },
+            )
+            .optional()?;
+        let Some(mut operation) = operation else {
+            return Ok(None);
+        };
+
+        let mut stmt = self.conn.prepare(
+            "SELECT file_path, before_content, after_content FROM operation_journal_files
+             WHERE operation_id = ?1
+             ORDER BY file_path",
+        )?;
+        let rows = stmt.query_map([operation.id], |row| {
+            Ok((
+                row.get::<_, String>(0)?,
+                row.get::<_, Vec<u8>>(1)?,
+                row.get::<_, Vec<u8>>(2)?,
+            ))
+        })?;
+        for row in rows {
+            let (file_path, before, after) = row?;
+            operation.files.push(JournalFile {
+                file_path,
+                before: decompress(&before)?,
+                after: decompress(&after)?,
+            });
+        }
+        Ok(Some(operation))
+    }
+
+    /// Mark an operation rolled back so the next rollback moves past it.
+    pub fn mark_operation_rolled_back(&self, id: i64) -> Result<()> {
+        let now = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)?
+            .as_secs() as i64;
+        self.conn.execute(
+            "UPDATE operation_journal SET rolled_back_at = ?1 WHERE id = ?2",
+            params![now, id],
+        )?;
+        Ok(())
+    }
+}
+
+fn decompress(data: &[u8]) -> Result<String> {
+    Ok(String::from_utf8(zstd::decode_all(data)?)?)
+}
@@ -0,0 +1,156 @@
+//! Derived project (package) membership of indexed files.
+//!
+//! In a monorepo the same symbol name is often defined by several packages.
+//! The post-index analysis pass in julie-index reads the package manifests
+//! (`Cargo.toml`, `package.json`, `go.mod`, `.csproj` and `.sln`) and records
+//! the innermost project of every file here, so tools can label results with
+//! their project and filter on it.
+//!
+//! Git repositories nested in the workspace (submodules and plain clones) are
+//! recorded here too. Indexing finds them on disk, since their `.git` is
+//! never indexed, and the analysis pass treats each one as a project.
+
+use anyhow::Result;
+use rusqlite::{params, params_from_iter};
+use std::collections::HashMap;
+use tracing::debug;
+
+use super::SymbolDatabase;
+
+/// The project an indexed file belongs to.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProjectFile {
+    pub file_path: String,
+    /// Package name as declared by the manifest (`name`, `module`, assembly).
+    pub project: String,
+    /// Manifest that declares the project.
+    pub manifest_path: String,
+}
+
+/// A git repository nested inside the workspace.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NestedRepository {
+    /// Workspace-relative directory holding the repository's `.git`.
+    pub root: String,
+    /// True for a submodule (or worktree), whose `.git` is a file pointing
+    /// into another repository; false for a plain clone.
+    pub submodule: bool,
+}
+
+impl SymbolDatabase {
+    /// Replace the entire `project_files` table. The table is derived data,
+    /// so the analysis pass wipes and recomputes it on each run.
+    pub fn replace_all_project_files(&self, files: &[ProjectFile]) -> Result<()> {
+        self.conn.execute_batch("BEGIN")?;
+        let result = (|| -> Result<()> {
+            self.conn.execute("DELETE FROM project_files", [])?;
+            let mut stmt = self.conn.prepare(
+                "INSERT OR REPLACE INTO project_files (file_path, project, manifest_path)
+                 VALUES (?1, ?2, ?3)",
+            )?;
+            for file in files {
+                stmt.execute(params![file.file_path, file.project, file.manifest_path])?;
+            }
+            Ok(())
+        })();
+
+        match result {
+            Ok(()) => self.conn.execute_batch("COMMIT")?,
+            Err(e) => {
+                let _ = self.conn.execute_batch("ROLLBACK");
+                return Err(e);
+            }
+        }
+        debug!(
+            "Replaced project_files table: {} files inserted",
+            files.len()
+        );
+        Ok(())
+    }
+
+    /// Project of each of `file_paths` that belongs to a known project, keyed
+    /// by file path. Files outside every project are absent.
+    pub fn projects_for_files(&self, file_paths: &[String]) -> Result<HashMap<String, String>> {
+        if file_paths.is_empty() {
+            return Ok(HashMap::new());
+        }
+
+        const CHUNK_SIZE: usize = 500;
+        let mut unique_paths = file_paths.to_vec();
+        unique_paths.sort();
+        unique_paths.dedup();
+        let mut projects = HashMap::new();
+
+        for chunk in unique_paths.chunks(CHUNK_SIZE) {
+            let placeholders = (1..=chunk.len())
+                .map(|i| format!("?{i}"))
+                .collect::<Vec<_>>()
+                .join(",");
+            let sql = format!(
+                "SELECT file_path, project FROM project_files
+                 WHERE file_path IN ({placeholders})"
+            );
+            let mut stmt = self.conn.prepare(&sql)?;
+            let rows = stmt.query_map(params_from_iter(chunk.iter()), |row| {
+                Ok((row.get::<_, String>(0)?, row.get::<_, String>(1)?))
+            })?;
+            for row in rows {
+                let (file_path, project) = row?;
+                projects.insert(file_path, project);
+            }
+        }
+        Ok(projects)
+    }
+
+    /// Every known project with its file count, ordered by name.
+    pub fn project_file_counts(&self) -> Result<Vec<(String, i64)>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT project, COUNT(*) FROM project_files
+             GROUP BY project ORDER BY project",
+        )?;
+        let rows = stmt.query_map([], |row| Ok((row.get(0)?, row.get(1)?)))?;
+        Ok(rows.collect::<rusqlite::Result<_>>()?)
+    }
+
+    /// Replace the recorded nested repositories with `repositories`.
+    pub fn replace_nested_repositories(&self, repositories: &[NestedRepository]) -> Result<()> {
+        self.conn.execute_batch("BEGIN")?;
+        let result = (|| -> Result<()> {
+            self.conn.execute("DELETE FROM nested_repositories", [])?;
+            let mut stmt = self.conn.prepare(
+                "INSERT OR REPLACE INTO nested_repositories (root, submodule) VALUES (?1, ?2)",
+            )?;
+            for repository in repositories {
+                stmt.execute(params![repository.root, repository.submodule])?;
+            }
+            Ok(())
+        })();
+
+        match result {
+            Ok(()) => self.conn.execute_batch("COMMIT")?,
+            Err(e) => {
+                let _ = self.conn.execute_batch("ROLLBACK");
+                return Err(e);
+            }
+        }
+        debug!(
+            "Replaced nested_repositories table: {} repositories inserted",
+            repositories.len()
+        );
+        Ok(())
+    }
+
+    /// Every recorded nested repository, ordered by root.
+    pub fn nested_repositories(&self) -> Result<Vec<NestedRepository>> {
+        let mut stmt = self
+            .conn
+            .prepare("SELECT root, submodule FROM nested_repositories ORDER BY root")?;
+        let rows = stmt.query_map([], |row| {
+            Ok(NestedRepository {
+                root: row.get(0)?,
+                submodule: row.get(1)?,
+            })
+        })?;
+        Ok(rows.collect::<rusqlite::Result<_>>()?)
+    }
+}
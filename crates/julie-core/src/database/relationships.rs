@@ -23,6 +23,37 @@ impl SymbolDatabase {
         Ok(())
     }
 
+    /// Count relationships whose source or target symbol no longer exists.
+    ///
+    /// The foreign keys cascade symbol deletes, but bulk writes run with
+    /// foreign keys off, so an interrupted write can leave such rows behind.
+    pub fn count_orphaned_relationships(&self) -> Result<usize> {
+        let count: i64 = self.conn.query_row(
+            "SELECT COUNT(*) FROM relationships
+             WHERE from_symbol_id NOT IN (SELECT id FROM symbols)
+                OR to_symbol_id NOT IN (SELECT id FROM symbols)",
+            [],
+            |row| row.get(0),
+        )?;
+        Ok(count as usize)
+    }
+
+    /// Delete the relationships counted by [`Self::count_orphaned_relationships`]
+    /// and rebuild `reference_count` from what remains.
+    pub fn delete_orphaned_relationships(&self) -> Result<usize> {
+        let deleted = self.conn.execute(
+            "DELETE FROM relationships
+             WHERE from_symbol_id NOT IN (SELECT id FROM symbols)
+                OR to_symbol_id NOT IN (SELECT id FROM symbols)",
+            [],
+        )?;
+        if deleted > 0 {
+            self.recompute_reference_counts()?;
+            debug!("Deleted {deleted} orphaned relationships");
+        }
+        Ok(deleted)
+    }
+
     pub fn get_outgoing_relationships(&self, symbol_id: &str) -> Result<Vec<Relationship>> {
         let mut stmt = self.conn.prepare(
             "SELECT id, from_symbol_id, to_symbol_id, kind, file_path, line_number, confidence, metadata
@@ -513,6 +544,117 @@ impl SymbolDatabase {
         Ok(scores)
     }
 
+    /// Replace every relationship a post-indexing pass derived, identified by
+    /// the `{prefix}:` its ids start with. Extraction never produces such ids,
+    /// so extracted relationships are left alone.
+    pub fn replace_derived_relationships(
+        &self,
+        prefix: &str,
+        relationships: &[Relationship],
+    ) -> Result<usize> {
+        let tx = self.conn.unchecked_transaction()?;
+        tx.execute(
+            "DELETE FROM relationships WHERE substr(id, 1, ?2) = ?1",
+            params![format!("{prefix}:"), prefix.len() as i64 + 1],
+        )?;
+        let inserted = crate::database::bulk::relationships::insert_relationships_tx(
+            &tx,
+            relationships,
+            None,
+        )?;
+        tx.commit()?;
+        debug!(
+            "Replaced {} derived relationships with prefix '{}'",
+            inserted, prefix
+        );
+        Ok(inserted as usize)
+    }
+
+    /// Rebuild `reference_count` for every symbol from the relationships table.
+    ///
+    /// The relationship triggers keep the column current on their own; this is
+    /// for backfilling and for repairing a database written without them.
+    pub fn recompute_reference_counts(&self) -> Result<()> {
+        self.conn.execute(
+            "UPDATE symbols SET reference_count = (
+                SELECT COUNT(*) FROM relationships r
+                WHERE r.to_symbol_id = symbols.id
+                  AND r.from_symbol_id != r.to_symbol_id
+                  AND r.kind != 'contains'
+            )",
+            [],
+        )?;
+        Ok(())
+    }
+
+    /// Rebuild `identifier_reference_count` for every symbol from the
+    /// identifiers table. Only needed for backfilling; the identifier writers
+    /// recount the symbols each batch touches.
+    pub fn recompute_identifier_reference_counts(&self) -> Result<()> {
+        self.conn.execute(
+            "UPDATE symbols SET identifier_reference_count = (
+                SELECT COUNT(*) FROM identifiers i
+                WHERE i.target_symbol_id = symbols.id
+                  AND i.containing_symbol_id IS NOT i.target_symbol_id
+            )",
+            [],
+        )?;
+        Ok(())
+    }
+
+    /// Get reference_count (incoming usage edges) for a batch of symbol IDs.
+    /// Search uses it to order hits whose relevance scores tie.
+    pub fn get_reference_counts(&self, ids: &[&str]) -> Result<HashMap<String, i64>> {
+        const MAX_BIND_PARAMS: usize = 900;
+
+        let mut counts = HashMap::new();
+        for chunk in ids.chunks(MAX_BIND_PARAMS) {
+            let placeholders: Vec<&str> = chunk.iter().map(|_| "?").collect();
+            let sql = format!(
+                "SELECT id, reference_count FROM symbols WHERE id IN ({})",
+                placeholders.join(", ")
+            );
+
+            let mut stmt = self.conn.prepare(&sql)?;
+            let rows = stmt.query_map(rusqlite::params_from_iter(chunk.iter()), |row| {
+                Ok((row.get::<_, String>(0)?, row.get::<_, i64>(1)?))
+            })?;
+            for row in rows {
+                let (id, count) = row?;
+                counts.insert(id, count);
+            }
+        }
+        Ok(counts)
+    }
+
+    /// Usage count for a batch of symbol IDs: the larger of the incoming usage
+    /// edges and the incoming resolved identifiers. A call site usually
+    /// produces both an edge and an identifier, so the two are not summed.
+    /// Search and outline show it as a badge next to each symbol.
+    pub fn get_usage_counts(&self, ids: &[&str]) -> Result<HashMap<String, i64>> {
+        const MAX_BIND_PARAMS: usize = 900;
+
+        let mut counts = HashMap::new();
+        for chunk in ids.chunks(MAX_BIND_PARAMS) {
+            let placeholders: Vec<&str> = chunk.iter().map(|_| "?").collect();
+            let sql = format!(
+                "SELECT id, MAX(reference_count, identifier_reference_count)
+                 FROM symbols WHERE id IN ({})",
+                placeholders.join(", ")
+            );
+
+            let mut stmt = self.conn.prepare(&sql)?;
+            let rows = stmt.query_map(rusqlite::params_from_iter(chunk.iter()), |row| {
+                Ok((row.get::<_, String>(0)?, row.get::<_, i64>(1)?))
+            })?;
+            for row in rows {
+                let (id, count) = row?;
+                counts.insert(id, count);
+            }
+        }
+        Ok(counts)
+    }
+
     /// Get relationship type statistics using SQL aggregation (avoids loading all relationships into memory)
     /// Returns HashMap<relationship_kind, count> grouped by relationship type
     /// Used by FastExploreTool's intelligent_dependencies mode
@@ -11,6 +11,12 @@ fn get_unix_timestamp() -> Result<i64> {
         .map_err(|e| anyhow!("System time error: {}", e))
 }
 
+/// `indexing_repairs.reason` for files indexed text-only because the parser
+/// was skipped (too large, minified, timed out, or crashed). Informational:
+/// unlike `extractor_failure`, these rows are not retried and don't trigger
+/// startup repair; they clear when the file next parses normally.
+pub const PARSE_SKIPPED_REASON: &str = "parse_skipped";
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct IndexingRepairRecord {
     pub path: String,
@@ -50,6 +56,15 @@ impl SymbolDatabase {
         Ok(())
     }
 
+    /// Files whose parser was skipped, with the reason in `detail`.
+    pub fn list_parse_skips(&self) -> Result<Vec<IndexingRepairRecord>> {
+        Ok(self
+            .list_indexing_repairs()?
+            .into_iter()
+            .filter(|record| record.reason == PARSE_SKIPPED_REASON)
+            .collect())
+    }
+
     pub fn list_indexing_repairs(&self) -> Result<Vec<IndexingRepairRecord>> {
         let mut stmt = self.conn.prepare(
             "SELECT path, reason, detail, updated_at
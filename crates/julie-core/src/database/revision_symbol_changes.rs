@@ -0,0 +1,330 @@
+//! Symbol-level changes per canonical revision ("index generation").
+//!
+//! Every atomic write that records a canonical revision also records which
+//! symbols it added, removed or modified, keyed by stable symbol ID. Walking
+//! those deltas between two revisions reconstructs what changed in the symbol
+//! set without keeping full snapshots, which is what `manage_workspace
+//! compare_generations` reports. Only the newest
+//! [`SYMBOL_GENERATION_RETENTION`] generations keep their deltas; older ones
+//! are pruned on write.
+
+use super::*;
+use anyhow::{Result, anyhow};
+use rusqlite::{Row, Transaction, params};
+use std::collections::{BTreeMap, HashMap};
+use tracing::debug;
+
+/// Generations whose symbol deltas are kept per workspace.
+pub const SYMBOL_GENERATION_RETENTION: i64 = 20;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SymbolChangeKind {
+    Added,
+    Removed,
+    Modified,
+}
+
+impl SymbolChangeKind {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Added => "added",
+            Self::Removed => "removed",
+            Self::Modified => "modified",
+        }
+    }
+
+    pub fn from_str(value: &str) -> Option<Self> {
+        match value {
+            "added" => Some(Self::Added),
+            "removed" => Some(Self::Removed),
+            "modified" => Some(Self::Modified),
+            _ => None,
+        }
+    }
+}
+
+/// One symbol's change between two generations.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RevisionSymbolChange {
+    pub symbol_id: String,
+    pub name: String,
+    pub kind: String,
+    pub file_path: String,
+    pub change_kind: SymbolChangeKind,
+}
+
+/// Net symbol changes from one generation to a later one.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct GenerationDiff {
+    pub from_revision: i64,
+    pub to_revision: i64,
+    pub added: Vec<RevisionSymbolChange>,
+    pub removed: Vec<RevisionSymbolChange>,
+    pub modified: Vec<RevisionSymbolChange>,
+}
+
+/// What identifies a change to a persisted symbol.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct SymbolFingerprint {
+    name: String,
+    kind: String,
+    file_path: String,
+    signature: Option<String>,
+    body_hash: Option<String>,
+}
+
+/// Fingerprints of the symbols currently stored for `file_paths`, or for
+/// every file when `file_paths` is `None`. Taken before the rows are deleted.
+pub(crate) fn snapshot_symbols_tx(
+    tx: &Transaction<'_>,
+    file_paths: Option<&[String]>,
+) -> Result<HashMap<String, SymbolFingerprint>> {
+    let row_to_entry = |row: &Row<'_>| {
+        Ok((
+            row.get::<_, String>(0)?,
+            SymbolFingerprint {
+                name: row.get(1)?,
+                kind: row.get(2)?,
+                file_path: row.get(3)?,
+                signature: row.get(4)?,
+                body_hash: row.get(5)?,
+            },
+        ))
+    };
+    const COLUMNS: &str = "id, name, kind, file_path, signature, body_hash";
+
+    let mut snapshot = HashMap::new();
+    match file_paths {
+        None => {
+            let mut stmt = tx.prepare(&format!("SELECT {COLUMNS} FROM symbols"))?;
+            for entry in stmt.query_map([], row_to_entry)? {
+                let (id, fingerprint) = entry?;
+                snapshot.insert(id, fingerprint);
+            }
+        }
+        Some(file_paths) => {
+            let mut stmt = tx.prepare(&format!(
+                "SELECT {COLUMNS} FROM symbols WHERE file_path = ?1"
+            ))?;
+            for file_path in file_paths {
+                for entry in stmt.query_map(params![file_path], row_to_entry)? {
+                    let (id, fingerprint) = entry?;
+                    snapshot.insert(id, fingerprint);
+                }
+            }
+        }
+    }
+    Ok(snapshot)
+}
+
+/// Record how `revision` changed the symbol set, from the `before` snapshot
+/// of the replaced rows and the `after` symbols written in their place, then
+/// prune generations beyond [`SYMBOL_GENERATION_RETENTION`].
+pub(crate) fn record_revision_symbol_changes_tx(
+    tx: &Transaction<'_>,
+    revision: i64,
+    workspace_id: &str,
+    before: &HashMap<String, SymbolFingerprint>,
+    after: &[Symbol],
+) -> Result<()> {
+    let mut written = HashMap::with_capacity(after.len());
+    for symbol in after {
+        written
+            .entry(symbol.id.clone())
+            .or_insert_with(|| SymbolFingerprint {
+                name: symbol.name.clone(),
+                kind: symbol.kind.to_string(),
+                file_path: symbol.file_path.clone(),
+                signature: symbol.signature.clone(),
+                body_hash: symbol.body_hash.clone(),
+            });
+    }
+    record_revision_snapshot_changes_tx(tx, revision, workspace_id, before, &written)
+}
+
+/// [`record_revision_symbol_changes_tx`] for a write whose symbols are no
+/// longer in memory: `after` is a snapshot of the rows once written.
+pub(crate) fn record_revision_snapshot_changes_tx(
+    tx: &Transaction<'_>,
+    revision: i64,
+    workspace_id: &str,
+    before: &HashMap<String, SymbolFingerprint>,
+    after: &HashMap<String, SymbolFingerprint>,
+) -> Result<()> {
+    let mut stmt = tx.prepare(
+        "INSERT OR REPLACE INTO revision_symbol_changes
+         (revision, workspace_id, symbol_id, name, kind, file_path, change_kind)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)",
+    )?;
+
+    for (id, new) in after {
+        let change_kind = match before.get(id) {
+            None => SymbolChangeKind::Added,
+            Some(old) if old != new => SymbolChangeKind::Modified,
+            Some(_) => continue,
+        };
+        stmt.execute(params![
+            revision,
+            workspace_id,
+            id,
+            new.name,
+            new.kind,
+            new.file_path,
+            change_kind.as_str()
+        ])?;
+    }
+    for (id, old) in before {
+        if after.contains_key(id) {
+            continue;
+        }
+        stmt.execute(params![
+            revision,
+            workspace_id,
+            id,
+            old.name,
+            old.kind,
+            old.file_path,
+            SymbolChangeKind::Removed.as_str()
+        ])?;
+    }
+
+    tx.execute(
+        "DELETE FROM revision_symbol_changes
+         WHERE workspace_id = ?1 AND revision <= ?2",
+        params![workspace_id, revision - SYMBOL_GENERATION_RETENTION],
+    )?;
+    Ok(())
+}
+
+impl SymbolDatabase {
+    pub(crate) fn create_revision_symbol_changes_table(&self) -> Result<()> {
+        self.conn.execute_batch(
+            "CREATE TABLE IF NOT EXISTS revision_symbol_changes (
+                revision INTEGER NOT NULL,
+                workspace_id TEXT NOT NULL,
+                symbol_id TEXT NOT NULL,
+                name TEXT NOT NULL,
+                kind TEXT NOT NULL,
+                file_path TEXT NOT NULL,
+                change_kind TEXT NOT NULL CHECK(change_kind IN ('added', 'removed', 'modified')),
+                PRIMARY KEY (revision, workspace_id, symbol_id)
+            );
+            CREATE INDEX IF NOT EXISTS idx_revision_symbol_changes_workspace_revision
+                ON revision_symbol_changes(workspace_id, revision);",
+        )?;
+        debug!("Created revision_symbol_changes table and indexes");
+        Ok(())
+    }
+
+    /// Oldest generation that can still be the `from` side of
+    /// [`Self::compare_generations`]: every generation after it has its
+    /// deltas retained. Generation 0 is the empty index before the first
+    /// write. `None` when the workspace has no recorded generation.
+    pub fn oldest_comparable_generation(&self, workspace_id: &str) -> Result<Option<i64>> {
+        let Some(latest) = self.get_current_canonical_revision(workspace_id)? else {
+            return Ok(None);
+        };
+        // Indexes built before deltas were recorded have none for their
+        // early generations, so history starts just before the first one.
+        let first_tracked: Option<i64> = self.conn.query_row(
+            "SELECT MIN(revision) FROM revision_symbol_changes WHERE workspace_id = ?1",
+            params![workspace_id],
+            |row| row.get(0),
+        )?;
+        Ok(Some(first_tracked.map_or(latest, |first| {
+            (first - 1).max(latest - SYMBOL_GENERATION_RETENTION).max(0)
+        })))
+    }
+
+    /// Net symbol changes between generations `from_revision` and
+    /// `to_revision` (exclusive / inclusive). A symbol added and removed in
+    /// between is not reported; one removed and re-added counts as modified.
+    pub fn compare_generations(
+        &self,
+        workspace_id: &str,
+        from_revision: i64,
+        to_revision: i64,
+    ) -> Result<GenerationDiff> {
+        if from_revision > to_revision {
+            return Err(anyhow!(
+                "from generation {from_revision} is newer than to generation {to_revision}"
+            ));
+        }
+        let latest = self
+            .get_current_canonical_revision(workspace_id)?
+            .ok_or_else(|| anyhow!("workspace {workspace_id} has no index generations"))?;
+        if to_revision > latest {
+            return Err(anyhow!(
+                "generation {to_revision} does not exist (latest is {latest})"
+            ));
+        }
+        let oldest = self
+            .oldest_comparable_generation(workspace_id)?
+            .unwrap_or(latest);
+        if from_revision < oldest {
+            return Err(anyhow!(
+                "generation {from_revision} is older than the retained history; \
+                 the oldest comparable generation is {oldest}"
+            ));
+        }
+
+        let mut stmt = self.conn.prepare(
+            "SELECT symbol_id, name, kind, file_path, change_kind
+             FROM revision_symbol_changes
+             WHERE workspace_id = ?1 AND revision > ?2 AND revision <= ?3
+             ORDER BY revision",
+        )?;
+        let rows = stmt.query_map(params![workspace_id, from_revision, to_revision], |row| {
+            Ok((
+                row.get::<_, String>(0)?,
+                row.get::<_, String>(1)?,
+                row.get::<_, String>(2)?,
+                row.get::<_, String>(3)?,
+                row.get::<_, String>(4)?,
+            ))
+        })?;
+
+        // First and last change per symbol: the first says whether it existed
+        // at `from`, the last whether it exists at `to`.
+        let mut spans: BTreeMap<String, (SymbolChangeKind, RevisionSymbolChange)> = BTreeMap::new();
+        for row in rows {
+            let (symbol_id, name, kind, file_path, change_kind) = row?;
+            let change_kind = SymbolChangeKind::from_str(&change_kind)
+                .ok_or_else(|| anyhow!("Unknown symbol change kind: {change_kind}"))?;
+            let change = RevisionSymbolChange {
+                symbol_id: symbol_id.clone(),
+                name,
+                kind,
+                file_path,
+                change_kind,
+            };
+            spans
+                .entry(symbol_id)
+                .and_modify(|(_, last)| *last = change.clone())
+                .or_insert((change_kind, change));
+        }
+
+        let mut diff = GenerationDiff {
+            from_revision,
+            to_revision,
+            ..Default::default()
+        };
+        for (first, mut last) in spans.into_values() {
+            let existed = first != SymbolChangeKind::Added;
+            let exists = last.change_kind != SymbolChangeKind::Removed;
+            match (existed, exists) {
+                (false, true) => {
+                    last.change_kind = SymbolChangeKind::Added;
+                    diff.added.push(last);
+                }
+                (true, false) => diff.removed.push(last),
+                (true, true) => {
+                    last.change_kind = SymbolChangeKind::Modified;
+                    diff.modified.push(last);
+                }
+                (false, false) => {}
+            }
+        }
+        Ok(diff)
+    }
+}
@@ -19,6 +19,9 @@ impl SymbolDatabase {
         self.create_workspaces_table()?;
         self.create_canonical_revisions_table()?;
         self.create_revision_file_changes_table()?;
+        self.create_revision_symbol_changes_table()?; // Per-generation symbol deltas
+        self.create_embedding_generation_tables()?; // Two-phase embedding commits
+        self.create_embedding_job_tables()?; // Resumable embedding job progress
         self.create_projection_states_table()?;
         self.create_index_engine_state_table()?;
         self.create_files_table()?;
@@ -34,8 +37,19 @@ impl SymbolDatabase {
         self.create_structural_facts_table()?;
         self.create_complexity_metrics_table()?;
         self.create_web_edges_table()?; // Derived web navigation edges
+        self.create_cross_language_links_table()?; // Derived cross-language counterparts
+        self.create_identifier_external_modules_table()?; // Derived external call attribution
+        self.create_symbol_aliases_table()?; // Derived build-time symbol aliases
+        self.create_swift_module_files_table()?; // Derived Swift module membership
+        self.create_project_files_table()?; // Derived monorepo project membership
+        self.create_nested_repositories_table()?; // Submodules and nested clones
+        self.create_build_target_files_table()?; // Derived build target membership
+        self.create_framework_tables()?; // Derived framework usage and symbol roles
         self.create_types_table()?; // Type intelligence
         self.create_relationships_table()?;
+        self.create_reference_count_triggers()?; // Needs symbols and relationships
+        self.create_operation_journal_tables()?; // Undo log for file edits
+        self.create_soft_deleted_files_table()?; // Deleted files in their grace period
 
         debug!("Database schema created successfully");
         Ok(())
@@ -146,7 +160,7 @@ impl SymbolDatabase {
                 end_byte INTEGER,
                 doc_comment TEXT,
                 visibility TEXT,
-                code_context TEXT,
+                code_context TEXT,  -- Legacy inline context; new rows use context_hash
                 parent_id TEXT REFERENCES symbols(id),
                 metadata TEXT,  -- JSON blob
 
@@ -172,7 +186,16 @@ impl SymbolDatabase {
                 body_hash TEXT,
 
                 -- Graph centrality: weighted incoming reference count
-                reference_score REAL NOT NULL DEFAULT 0.0
+                reference_score REAL NOT NULL DEFAULT 0.0,
+
+                -- Popularity: incoming usage edges, maintained by relationship triggers
+                reference_count INTEGER NOT NULL DEFAULT 0,
+
+                -- Popularity: incoming resolved identifiers, maintained by identifier triggers
+                identifier_reference_count INTEGER NOT NULL DEFAULT 0,
+
+                -- Compressed code context in context_blobs
+                context_hash TEXT
             )",
             [],
         )?;
@@ -215,11 +238,44 @@ impl SymbolDatabase {
             [],
         )?;
 
+        self.create_symbol_body_hash_index()?;
+        self.create_context_blobs_table()?;
+
         debug!("Created symbols table and indexes");
 
         Ok(())
     }
 
+    /// Partial index backing duplicate grouping by content hash
+    /// (`symbol_copies`). Symbols without a body never group, so they stay
+    /// out of the index.
+    pub(super) fn create_symbol_body_hash_index(&self) -> Result<()> {
+        self.conn.execute(
+            "CREATE INDEX IF NOT EXISTS idx_symbols_body_hash
+             ON symbols(body_hash, name, kind)
+             WHERE body_hash IS NOT NULL",
+            [],
+        )?;
+        Ok(())
+    }
+
+    /// Compressed code context shared by symbols with identical source
+    /// (see `context_store`), plus the index that lets pruning find the
+    /// blobs no symbol references.
+    pub(super) fn create_context_blobs_table(&self) -> Result<()> {
+        self.conn.execute_batch(
+            "CREATE TABLE IF NOT EXISTS context_blobs (
+                hash TEXT PRIMARY KEY,
+                raw_size INTEGER NOT NULL,
+                data BLOB NOT NULL
+            );
+            CREATE INDEX IF NOT EXISTS idx_symbols_context_hash
+            ON symbols(context_hash)
+            WHERE context_hash IS NOT NULL;",
+        )?;
+        Ok(())
+    }
+
     pub fn create_symbol_annotations_table(&self) -> Result<()> {
         self.conn.execute_batch(
             "CREATE TABLE IF NOT EXISTS symbol_annotations (
@@ -456,4 +512,65 @@ impl SymbolDatabase {
         debug!("Created relationships table and indexes");
         Ok(())
     }
+
+    /// Keep `symbols.reference_count` in step with the relationships table.
+    ///
+    /// Only usage edges count: self-references and `contains` edges are
+    /// skipped, matching the zero weight they get in `reference_score`.
+    /// `INSERT OR REPLACE` does not fire delete triggers, so the insert path
+    /// first retracts whatever row the new one is about to replace. Symbols
+    /// inserted after their incoming edges (foreign keys are off during
+    /// atomic rewrites) recount on insert.
+    pub fn create_reference_count_triggers(&self) -> Result<()> {
+        self.conn.execute_batch(
+            "CREATE TRIGGER IF NOT EXISTS relationships_refcount_bi
+             BEFORE INSERT ON relationships BEGIN
+                UPDATE symbols SET reference_count = MAX(reference_count - 1, 0)
+                WHERE id = (SELECT to_symbol_id FROM relationships
+                            WHERE id = NEW.id
+                              AND from_symbol_id != to_symbol_id
+                              AND kind != 'contains');
+             END;
+
+             CREATE TRIGGER IF NOT EXISTS relationships_refcount_ai
+             AFTER INSERT ON relationships
+             WHEN NEW.from_symbol_id != NEW.to_symbol_id AND NEW.kind != 'contains' BEGIN
+                UPDATE symbols SET reference_count = reference_count + 1
+                WHERE id = NEW.to_symbol_id;
+             END;
+
+             CREATE TRIGGER IF NOT EXISTS relationships_refcount_ad
+             AFTER DELETE ON relationships
+             WHEN OLD.from_symbol_id != OLD.to_symbol_id AND OLD.kind != 'contains' BEGIN
+                UPDATE symbols SET reference_count = MAX(reference_count - 1, 0)
+                WHERE id = OLD.to_symbol_id;
+             END;
+
+             CREATE TRIGGER IF NOT EXISTS relationships_refcount_au
+             AFTER UPDATE OF from_symbol_id, to_symbol_id, kind ON relationships BEGIN
+                UPDATE symbols SET reference_count = MAX(reference_count - 1, 0)
+                WHERE id = OLD.to_symbol_id
+                  AND OLD.from_symbol_id != OLD.to_symbol_id
+                  AND OLD.kind != 'contains';
+                UPDATE symbols SET reference_count = reference_count + 1
+                WHERE id = NEW.to_symbol_id
+                  AND NEW.from_symbol_id != NEW.to_symbol_id
+                  AND NEW.kind != 'contains';
+             END;
+
+             CREATE TRIGGER IF NOT EXISTS symbols_refcount_ai
+             AFTER INSERT ON symbols BEGIN
+                UPDATE symbols SET reference_count = (
+                    SELECT COUNT(*) FROM relationships r
+                    WHERE r.to_symbol_id = NEW.id
+                      AND r.from_symbol_id != r.to_symbol_id
+                      AND r.kind != 'contains'
+                )
+                WHERE id = NEW.id;
+             END;",
+        )?;
+
+        debug!("Created reference_count triggers");
+        Ok(())
+    }
 }
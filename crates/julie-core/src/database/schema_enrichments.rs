@@ -264,4 +264,185 @@ impl SymbolDatabase {
         debug!("Created web_edges table and indexes");
         Ok(())
     }
+
+    /// Create the `cross_language_links` table: *derived* counterpart links
+    /// between type-like symbols in different languages that model the same
+    /// entity (TS interface <-> C# DTO <-> SQL table). Computed by the
+    /// post-index analysis pass and wiped/recomputed on each run, so readers
+    /// get the links without re-deriving them per query.
+    ///
+    /// Links are stored in both directions so a lookup by `from_symbol_id` is
+    /// a single indexed scan. `confidence` is in `0.0..=1.0`; `reason` is a
+    /// short human-readable explanation of the evidence.
+    ///
+    /// `pub(crate)` so `migration_031_add_cross_language_links` can call it;
+    /// the `CREATE ... IF NOT EXISTS` DDL is the single source of truth for
+    /// both fresh DBs (via `initialize_schema`) and upgrades (via migration 031).
+    pub(crate) fn create_cross_language_links_table(&self) -> Result<()> {
+        self.conn.execute_batch(
+            "CREATE TABLE IF NOT EXISTS cross_language_links (
+                from_symbol_id   TEXT NOT NULL REFERENCES symbols(id) ON DELETE CASCADE,
+                to_symbol_id     TEXT NOT NULL REFERENCES symbols(id) ON DELETE CASCADE,
+                confidence       REAL NOT NULL,
+                reason           TEXT NOT NULL,
+                PRIMARY KEY (from_symbol_id, to_symbol_id)
+            );
+            CREATE INDEX IF NOT EXISTS idx_cross_language_links_to
+                ON cross_language_links(to_symbol_id);",
+        )?;
+        debug!("Created cross_language_links table and indexes");
+        Ok(())
+    }
+
+    /// Create the `identifier_external_modules` table: *derived* attribution
+    /// of unresolved call identifiers to the external library their file
+    /// imports them from (`lodash`, `requests`). Computed by the post-index
+    /// analysis pass and wiped/recomputed on each run.
+    ///
+    /// `pub(crate)` so `migration_033_add_identifier_external_modules` can
+    /// call it; the DDL is shared by fresh DBs and upgrades.
+    pub(crate) fn create_identifier_external_modules_table(&self) -> Result<()> {
+        self.conn.execute_batch(
+            "CREATE TABLE IF NOT EXISTS identifier_external_modules (
+                identifier_id TEXT PRIMARY KEY REFERENCES identifiers(id) ON DELETE CASCADE,
+                module        TEXT NOT NULL
+            );
+            CREATE INDEX IF NOT EXISTS idx_identifier_external_modules_module
+                ON identifier_external_modules(module);",
+        )?;
+        debug!("Created identifier_external_modules table and indexes");
+        Ok(())
+    }
+
+    /// Create the `symbol_aliases` table: *derived* names under which a
+    /// symbol is reachable after macro expansion or code generation
+    /// (`get_user_name` -> `getUserName` via napi). Computed by the
+    /// post-index analysis pass and wiped/recomputed on each run.
+    ///
+    /// `pub(crate)` so `migration_034_add_symbol_aliases` can call it; the
+    /// DDL is shared by fresh DBs and upgrades.
+    pub(crate) fn create_symbol_aliases_table(&self) -> Result<()> {
+        self.conn.execute_batch(
+            "CREATE TABLE IF NOT EXISTS symbol_aliases (
+                alias      TEXT NOT NULL,
+                symbol_id  TEXT NOT NULL REFERENCES symbols(id) ON DELETE CASCADE,
+                source     TEXT NOT NULL,
+                PRIMARY KEY (alias, symbol_id)
+            );
+            CREATE INDEX IF NOT EXISTS idx_symbol_aliases_symbol
+                ON symbol_aliases(symbol_id);",
+        )?;
+        debug!("Created symbol_aliases table and indexes");
+        Ok(())
+    }
+
+    /// Create the `swift_module_files` table: *derived* Swift module (SwiftPM
+    /// or Xcode target) of each Swift source file, parsed from `Package.swift`
+    /// and `project.pbxproj`. Computed by the post-index analysis pass and
+    /// wiped/recomputed on each run.
+    ///
+    /// `pub(crate)` so `migration_039_add_swift_module_files` can call it; the
+    /// DDL is shared by fresh DBs and upgrades.
+    pub(crate) fn create_swift_module_files_table(&self) -> Result<()> {
+        self.conn.execute_batch(
+            "CREATE TABLE IF NOT EXISTS swift_module_files (
+                file_path     TEXT PRIMARY KEY,
+                module        TEXT NOT NULL,
+                manifest_path TEXT NOT NULL
+            );
+            CREATE INDEX IF NOT EXISTS idx_swift_module_files_module
+                ON swift_module_files(module);",
+        )?;
+        debug!("Created swift_module_files table and indexes");
+        Ok(())
+    }
+
+    /// Create the `project_files` table: *derived* project (package) of each
+    /// indexed file, parsed from `Cargo.toml`, `package.json`, `go.mod`,
+    /// `.csproj` and `.sln` manifests. Computed by the post-index analysis
+    /// pass and wiped/recomputed on each run.
+    ///
+    /// `pub(crate)` so `migration_043_add_project_files` can call it; the DDL
+    /// is shared by fresh DBs and upgrades.
+    pub(crate) fn create_project_files_table(&self) -> Result<()> {
+        self.conn.execute_batch(
+            "CREATE TABLE IF NOT EXISTS project_files (
+                file_path     TEXT PRIMARY KEY,
+                project       TEXT NOT NULL,
+                manifest_path TEXT NOT NULL
+            );
+            CREATE INDEX IF NOT EXISTS idx_project_files_project
+                ON project_files(project);",
+        )?;
+        debug!("Created project_files table and indexes");
+        Ok(())
+    }
+
+    /// Create the `nested_repositories` table: git repositories (submodules
+    /// and plain clones) nested in the workspace. Found on disk by indexing
+    /// and replaced on each run; the project map treats each as a project.
+    ///
+    /// `pub(crate)` so `migration_047_add_nested_repositories` can call it;
+    /// the DDL is shared by fresh DBs and upgrades.
+    pub(crate) fn create_nested_repositories_table(&self) -> Result<()> {
+        self.conn.execute_batch(
+            "CREATE TABLE IF NOT EXISTS nested_repositories (
+                root      TEXT PRIMARY KEY,
+                submodule INTEGER NOT NULL DEFAULT 0
+            );",
+        )?;
+        debug!("Created nested_repositories table");
+        Ok(())
+    }
+
+    /// Create the `build_target_files` table: *derived* build targets (from
+    /// Makefiles, CMake scripts and Bazel packages) compiling each indexed
+    /// file. Computed by the post-index analysis pass and wiped/recomputed on
+    /// each run.
+    ///
+    /// `pub(crate)` so `migration_044_add_build_target_files` can call it; the
+    /// DDL is shared by fresh DBs and upgrades.
+    pub(crate) fn create_build_target_files_table(&self) -> Result<()> {
+        self.conn.execute_batch(
+            "CREATE TABLE IF NOT EXISTS build_target_files (
+                file_path  TEXT NOT NULL,
+                target_id  TEXT NOT NULL,
+                label      TEXT NOT NULL,
+                build_file TEXT NOT NULL,
+                PRIMARY KEY (file_path, target_id)
+            );
+            CREATE INDEX IF NOT EXISTS idx_build_target_files_target
+                ON build_target_files(target_id);",
+        )?;
+        debug!("Created build_target_files table and indexes");
+        Ok(())
+    }
+
+    /// Create the `project_frameworks` and `framework_symbols` tables:
+    /// *derived* frameworks each project uses (from manifests and imports)
+    /// and the framework role of each symbol that plays one. Computed by the
+    /// post-index analysis pass and wiped/recomputed on each run.
+    ///
+    /// `pub(crate)` so `migration_045_add_framework_tables` can call it; the
+    /// DDL is shared by fresh DBs and upgrades.
+    pub(crate) fn create_framework_tables(&self) -> Result<()> {
+        self.conn.execute_batch(
+            "CREATE TABLE IF NOT EXISTS project_frameworks (
+                root      TEXT NOT NULL,
+                project   TEXT,
+                framework TEXT NOT NULL,
+                evidence  TEXT NOT NULL,
+                PRIMARY KEY (root, framework)
+            );
+            CREATE TABLE IF NOT EXISTS framework_symbols (
+                symbol_id TEXT PRIMARY KEY,
+                framework TEXT NOT NULL,
+                role      TEXT NOT NULL
+            );
+            CREATE INDEX IF NOT EXISTS idx_framework_symbols_role
+                ON framework_symbols(role);",
+        )?;
+        debug!("Created project_frameworks and framework_symbols tables");
+        Ok(())
+    }
 }
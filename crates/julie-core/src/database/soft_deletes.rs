@@ -0,0 +1,76 @@
+//! Files deleted from disk whose index rows are kept for a grace period.
+//!
+//! A branch switch or a stray `git clean` removes files that come back a
+//! moment later. Dropping their symbols on the delete event breaks every
+//! reference an agent is following mid-task, so the watcher records the
+//! deletion here instead and keeps the rows queryable. Results from these
+//! files are flagged as deleted; once the grace period passes with the file
+//! still absent, the watcher removes them for good. A create or modify event
+//! for the path clears the mark.
+
+use std::collections::HashMap;
+
+use anyhow::Result;
+use rusqlite::params;
+
+use super::SymbolDatabase;
+use crate::paths::normalize_stored_path;
+
+impl SymbolDatabase {
+    /// `pub(crate)` so migration 048 can call it; the `IF NOT EXISTS` DDL
+    /// serves both fresh DBs and upgrades.
+    pub(crate) fn create_soft_deleted_files_table(&self) -> Result<()> {
+        self.conn.execute_batch(
+            "CREATE TABLE IF NOT EXISTS soft_deleted_files (
+                path TEXT PRIMARY KEY,
+                deleted_at INTEGER NOT NULL
+            );
+            CREATE INDEX IF NOT EXISTS idx_soft_deleted_files_deleted_at
+                ON soft_deleted_files(deleted_at);",
+        )?;
+        Ok(())
+    }
+
+    /// Record that `path` was deleted from disk at `deleted_at` (unix
+    /// seconds). A repeated delete event keeps the first timestamp, so the
+    /// grace period is not extended by duplicate events.
+    pub fn mark_file_soft_deleted(&self, path: &str, deleted_at: i64) -> Result<()> {
+        self.conn.execute(
+            "INSERT OR IGNORE INTO soft_deleted_files (path, deleted_at) VALUES (?1, ?2)",
+            params![normalize_stored_path(path), deleted_at],
+        )?;
+        Ok(())
+    }
+
+    /// Forget the deletion mark for `path`. Returns whether one existed.
+    pub fn clear_soft_delete(&self, path: &str) -> Result<bool> {
+        let removed = self
+            .conn
+            .execute("DELETE FROM soft_deleted_files WHERE path = ?1", [path])?;
+        Ok(removed > 0)
+    }
+
+    /// Every soft-deleted path with its deletion time.
+    pub fn soft_deleted_files(&self) -> Result<HashMap<String, i64>> {
+        let mut stmt = self
+            .conn
+            .prepare("SELECT path, deleted_at FROM soft_deleted_files")?;
+        let rows = stmt
+            .query_map([], |row| Ok((row.get(0)?, row.get(1)?)))?
+            .collect::<rusqlite::Result<HashMap<_, _>>>()?;
+        Ok(rows)
+    }
+
+    /// Paths deleted at or before `cutoff`, oldest first.
+    pub fn soft_deletes_older_than(&self, cutoff: i64) -> Result<Vec<String>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT path FROM soft_deleted_files
+             WHERE deleted_at <= ?1
+             ORDER BY deleted_at, path",
+        )?;
+        let paths = stmt
+            .query_map([cutoff], |row| row.get(0))?
+            .collect::<rusqlite::Result<Vec<String>>>()?;
+        Ok(paths)
+    }
+}
@@ -0,0 +1,157 @@
+//! Rewriting the file paths stored across the index tables.
+//!
+//! Every table tied to a file keeps its own copy of the path, so a change of
+//! spelling has to reach all of them in one transaction. Migration 049 brings
+//! legacy rows into the form
+//! [`normalize_stored_path`](crate::paths::normalize_stored_path) produces;
+//! once a workspace knows its root,
+//! [`SymbolDatabase::relativize_stored_paths`] rewrites the absolute paths
+//! that lie under it.
+
+use std::borrow::Cow;
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use anyhow::{Result, anyhow};
+use rusqlite::params;
+
+use super::SymbolDatabase;
+use crate::paths::normalize_stored_path_within;
+
+fn get_unix_timestamp() -> Result<i64> {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs() as i64)
+        .map_err(|e| anyhow!("System time error: {}", e))
+}
+
+/// Every `(table, column)` holding a stored file path.
+pub(super) const STORED_PATH_COLUMNS: &[(&str, &str)] = &[
+    ("files", "path"),
+    ("symbols", "file_path"),
+    ("identifiers", "file_path"),
+    ("relationships", "file_path"),
+    ("type_arguments", "file_path"),
+    ("literals", "file_path"),
+    ("source_regions", "file_path"),
+    ("structural_facts", "file_path"),
+    ("complexity_metrics", "file_path"),
+    ("web_edges", "file_path"),
+    ("swift_module_files", "file_path"),
+    ("project_files", "file_path"),
+    ("build_target_files", "file_path"),
+    ("indexing_repairs", "path"),
+    ("revision_file_changes", "file_path"),
+    ("revision_symbol_changes", "file_path"),
+    ("soft_deleted_files", "path"),
+];
+
+/// Which stored paths a rewrite has to look at.
+#[derive(Clone, Copy)]
+pub(super) enum StoredPathScan {
+    /// Every distinct value.
+    All,
+    /// Only values that start like an absolute path; relative rows are
+    /// skipped without being read back.
+    Absolute,
+}
+
+impl SymbolDatabase {
+    /// Rewrite the absolute stored paths under `workspace_root` into
+    /// workspace-relative form. Returns the number of distinct paths changed.
+    pub fn relativize_stored_paths(&self, workspace_root: &Path) -> Result<usize> {
+        self.rewrite_stored_paths(StoredPathScan::Absolute, |path| {
+            normalize_stored_path_within(path, workspace_root)
+        })
+    }
+
+    /// Apply `rewrite` to every stored path in [`STORED_PATH_COLUMNS`] and
+    /// return the number of distinct paths it changed.
+    ///
+    /// The rewrite map is built from every column, so a path only left in a
+    /// revision or soft-delete row is rewritten too. A row whose new path is
+    /// already taken (the file was stored under both spellings) is dropped in
+    /// favour of the existing one, and the search projection is marked stale
+    /// because its documents still carry the old paths.
+    pub(super) fn rewrite_stored_paths<F>(&self, scan: StoredPathScan, rewrite: F) -> Result<usize>
+    where
+        F: for<'p> Fn(&'p str) -> Cow<'p, str>,
+    {
+        let mut columns = Vec::new();
+        for &(table, column) in STORED_PATH_COLUMNS {
+            if self.table_exists(table)? && self.has_column(table, column)? {
+                columns.push((table, column));
+            }
+        }
+
+        let mut changed: BTreeMap<String, String> = BTreeMap::new();
+        for &(table, column) in &columns {
+            let filter = match scan {
+                StoredPathScan::All => String::new(),
+                StoredPathScan::Absolute => {
+                    format!(" WHERE {column} LIKE '/%' OR {column} LIKE '_:%'")
+                }
+            };
+            let mut stmt = self
+                .conn
+                .prepare(&format!("SELECT DISTINCT {column} FROM {table}{filter}"))?;
+            let paths = stmt.query_map([], |row| row.get::<_, String>(0))?;
+            for path in paths {
+                let path = path?;
+                if changed.contains_key(&path) {
+                    continue;
+                }
+                if let Cow::Owned(new_path) = rewrite(&path) {
+                    changed.insert(path, new_path);
+                }
+            }
+        }
+        if changed.is_empty() {
+            return Ok(0);
+        }
+
+        let tx = self.conn.unchecked_transaction()?;
+        tx.execute_batch(
+            "PRAGMA defer_foreign_keys = ON;
+             CREATE TEMP TABLE stored_path_map (old_path TEXT PRIMARY KEY, new_path TEXT NOT NULL);",
+        )?;
+        {
+            let mut insert = tx
+                .prepare("INSERT INTO temp.stored_path_map (old_path, new_path) VALUES (?1, ?2)")?;
+            for (old, new) in &changed {
+                insert.execute(params![old, new])?;
+            }
+        }
+
+        for &(table, column) in &columns {
+            tx.execute(
+                &format!(
+                    "UPDATE OR IGNORE {table}
+                     SET {column} = (SELECT new_path FROM temp.stored_path_map WHERE old_path = {table}.{column})
+                     WHERE {column} IN (SELECT old_path FROM temp.stored_path_map)"
+                ),
+                [],
+            )?;
+            // Rows left behind collided with an already-rewritten row.
+            tx.execute(
+                &format!(
+                    "DELETE FROM {table}
+                     WHERE {column} IN (SELECT old_path FROM temp.stored_path_map)"
+                ),
+                [],
+            )?;
+        }
+
+        if self.table_exists("projection_states")? {
+            tx.execute(
+                "UPDATE projection_states
+                 SET status = 'stale', detail = 'stored paths normalized', updated_at = ?1",
+                [get_unix_timestamp()?],
+            )?;
+        }
+
+        tx.execute_batch("DROP TABLE temp.stored_path_map;")?;
+        tx.commit()?;
+        Ok(changed.len())
+    }
+}
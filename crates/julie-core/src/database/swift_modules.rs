@@ -0,0 +1,103 @@
+//! Derived Swift module membership of source files.
+//!
+//! Swift has no module declaration in source: a file's module is the SwiftPM
+//! target or Xcode target that compiles it, which only the build manifests
+//! (`Package.swift`, `*.xcodeproj/project.pbxproj`) say. The post-index
+//! analysis pass in julie-index parses those manifests and records one row
+//! per Swift file here, so navigation can resolve `Module.Symbol` and tell
+//! same-named types in different modules apart.
+
+use anyhow::Result;
+use rusqlite::{params, params_from_iter};
+use std::collections::HashMap;
+use tracing::debug;
+
+use super::SymbolDatabase;
+
+/// The module a Swift source file is compiled into.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SwiftModuleFile {
+    pub file_path: String,
+    /// Module name as imported (`import Networking`).
+    pub module: String,
+    /// `Package.swift` or `project.pbxproj` that declares the target.
+    pub manifest_path: String,
+}
+
+impl SymbolDatabase {
+    /// Replace the entire `swift_module_files` table. The table is derived
+    /// data, so the analysis pass wipes and recomputes it on each run.
+    pub fn replace_all_swift_module_files(&self, files: &[SwiftModuleFile]) -> Result<()> {
+        self.conn.execute_batch("BEGIN")?;
+        let result = (|| -> Result<()> {
+            self.conn.execute("DELETE FROM swift_module_files", [])?;
+            let mut stmt = self.conn.prepare(
+                "INSERT OR REPLACE INTO swift_module_files (file_path, module, manifest_path)
+                 VALUES (?1, ?2, ?3)",
+            )?;
+            for file in files {
+                stmt.execute(params![file.file_path, file.module, file.manifest_path])?;
+            }
+            Ok(())
+        })();
+
+        match result {
+            Ok(()) => self.conn.execute_batch("COMMIT")?,
+            Err(e) => {
+                let _ = self.conn.execute_batch("ROLLBACK");
+                return Err(e);
+            }
+        }
+        debug!(
+            "Replaced swift_module_files table: {} files inserted",
+            files.len()
+        );
+        Ok(())
+    }
+
+    /// Module of each of `file_paths` that belongs to a known Swift target,
+    /// keyed by file path. Files outside every target are absent.
+    pub fn swift_modules_for_files(
+        &self,
+        file_paths: &[String],
+    ) -> Result<HashMap<String, String>> {
+        if file_paths.is_empty() {
+            return Ok(HashMap::new());
+        }
+
+        const CHUNK_SIZE: usize = 500;
+        let mut unique_paths = file_paths.to_vec();
+        unique_paths.sort();
+        unique_paths.dedup();
+        let mut modules = HashMap::new();
+
+        for chunk in unique_paths.chunks(CHUNK_SIZE) {
+            let placeholders = (1..=chunk.len())
+                .map(|i| format!("?{i}"))
+                .collect::<Vec<_>>()
+                .join(",");
+            let sql = format!(
+                "SELECT file_path, module FROM swift_module_files
+                 WHERE file_path IN ({placeholders})"
+            );
+            let mut stmt = self.conn.prepare(&sql)?;
+            let rows = stmt.query_map(params_from_iter(chunk.iter()), |row| {
+                Ok((row.get::<_, String>(0)?, row.get::<_, String>(1)?))
+            })?;
+            for row in rows {
+                let (file_path, module) = row?;
+                modules.insert(file_path, module);
+            }
+        }
+        Ok(modules)
+    }
+
+    /// Count of Swift files with a known module (lightweight health signal).
+    pub fn swift_module_file_count(&self) -> Result<i64> {
+        Ok(self
+            .conn
+            .query_row("SELECT COUNT(*) FROM swift_module_files", [], |row| {
+                row.get(0)
+            })?)
+    }
+}
@@ -0,0 +1,119 @@
+//! Derived aliases for symbols renamed at build time.
+//!
+//! Macros and code generators publish a definition under a name that never
+//! appears in its source: `#[napi] fn get_user_name` is called as
+//! `getUserName` from JavaScript, `[ObservableProperty] string _userName`
+//! becomes the `UserName` property, `#[derive(Builder)] struct Config` adds a
+//! `ConfigBuilder`. The post-index analysis pass in julie-index infers those
+//! names per language and records them here so `fast_refs` can connect call
+//! sites to the definition they were generated from.
+
+use anyhow::Result;
+use rusqlite::{params, params_from_iter};
+use tracing::debug;
+
+use super::SymbolDatabase;
+
+/// A name under which a symbol is reachable after macro expansion or codegen.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SymbolAlias {
+    /// The symbol whose source declaration produces the alias.
+    pub symbol_id: String,
+    /// Name as it appears at call sites (`getUserName`, `ConfigBuilder`).
+    pub alias: String,
+    /// What produced the alias (`napi`, `wasm_bindgen js_name`, `lombok getter`).
+    pub source: String,
+}
+
+impl SymbolDatabase {
+    /// Replace the entire `symbol_aliases` table. The table is derived data,
+    /// so the analysis pass wipes and recomputes it on each run.
+    pub fn replace_all_symbol_aliases(&self, aliases: &[SymbolAlias]) -> Result<()> {
+        self.conn.execute_batch("BEGIN")?;
+        let result = (|| -> Result<()> {
+            self.conn.execute("DELETE FROM symbol_aliases", [])?;
+            let mut stmt = self.conn.prepare(
+                "INSERT OR REPLACE INTO symbol_aliases (alias, symbol_id, source)
+                 VALUES (?1, ?2, ?3)",
+            )?;
+            for alias in aliases {
+                stmt.execute(params![alias.alias, alias.symbol_id, alias.source])?;
+            }
+            Ok(())
+        })();
+
+        match result {
+            Ok(()) => self.conn.execute_batch("COMMIT")?,
+            Err(e) => {
+                let _ = self.conn.execute_batch("ROLLBACK");
+                return Err(e);
+            }
+        }
+        debug!(
+            "Replaced symbol_aliases table: {} aliases inserted",
+            aliases.len()
+        );
+        Ok(())
+    }
+
+    /// Aliases whose name is one of `names`, i.e. the generated definitions a
+    /// call site spelled that way can refer to.
+    pub fn symbol_aliases_by_names(&self, names: &[String]) -> Result<Vec<SymbolAlias>> {
+        self.query_symbol_aliases("alias", names)
+    }
+
+    /// Aliases of any of `symbol_ids`.
+    pub fn symbol_aliases_for_symbols(&self, symbol_ids: &[String]) -> Result<Vec<SymbolAlias>> {
+        self.query_symbol_aliases("symbol_id", symbol_ids)
+    }
+
+    /// Count of stored aliases (lightweight health signal).
+    pub fn symbol_alias_count(&self) -> Result<i64> {
+        Ok(self
+            .conn
+            .query_row("SELECT COUNT(*) FROM symbol_aliases", [], |row| row.get(0))?)
+    }
+
+    /// Chunked `IN` lookup on `column`, ordered by alias then symbol id.
+    fn query_symbol_aliases(&self, column: &str, values: &[String]) -> Result<Vec<SymbolAlias>> {
+        if values.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        const CHUNK_SIZE: usize = 500;
+        let mut unique_values = values.to_vec();
+        unique_values.sort();
+        unique_values.dedup();
+        let mut aliases = Vec::new();
+
+        for chunk in unique_values.chunks(CHUNK_SIZE) {
+            let placeholders = (1..=chunk.len())
+                .map(|i| format!("?{i}"))
+                .collect::<Vec<_>>()
+                .join(",");
+            let sql = format!(
+                "SELECT symbol_id, alias, source
+                 FROM symbol_aliases
+                 WHERE {column} IN ({placeholders})"
+            );
+            let mut stmt = self.conn.prepare(&sql)?;
+            let rows = stmt
+                .query_map(params_from_iter(chunk.iter()), |row| {
+                    Ok(SymbolAlias {
+                        symbol_id: row.get(0)?,
+                        alias: row.get(1)?,
+                        source: row.get(2)?,
+                    })
+                })?
+                .collect::<rusqlite::Result<Vec<_>>>()?;
+            aliases.extend(rows);
+        }
+
+        aliases.sort_by(|a, b| {
+            a.alias
+                .cmp(&b.alias)
+                .then_with(|| a.symbol_id.cmp(&b.symbol_id))
+        });
+        Ok(aliases)
+    }
+}
@@ -0,0 +1,72 @@
+//! Identical copies of a symbol across the workspace.
+//!
+//! Vendored libraries, generated clients and copy-pasted helpers put the same
+//! definition in many files. Two symbols are copies when they share a name,
+//! a kind and a `body_hash`, the content hash the extractors record for
+//! body-bearing symbols. Search uses the groups to collapse copies into one
+//! result; symbols without a body hash never group.
+
+use std::collections::HashMap;
+
+use anyhow::Result;
+use rusqlite::params_from_iter;
+
+use super::SymbolDatabase;
+
+/// One location of a duplicated symbol.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SymbolCopy {
+    pub symbol_id: String,
+    pub file_path: String,
+    pub start_line: u32,
+}
+
+impl SymbolDatabase {
+    /// Copy groups for `symbol_ids`, keyed by the requested id. Each group
+    /// lists every copy, the requested symbol included, ordered by file and
+    /// line. Ids without another copy are absent from the map.
+    pub fn symbol_copies(&self, symbol_ids: &[String]) -> Result<HashMap<String, Vec<SymbolCopy>>> {
+        let mut groups: HashMap<String, Vec<SymbolCopy>> = HashMap::new();
+        if symbol_ids.is_empty() {
+            return Ok(groups);
+        }
+
+        const CHUNK_SIZE: usize = 500;
+        let mut unique_ids = symbol_ids.to_vec();
+        unique_ids.sort();
+        unique_ids.dedup();
+
+        for chunk in unique_ids.chunks(CHUNK_SIZE) {
+            let placeholders = (1..=chunk.len())
+                .map(|i| format!("?{i}"))
+                .collect::<Vec<_>>()
+                .join(",");
+            let sql = format!(
+                "SELECT s.id, c.id, c.file_path, c.start_line
+                 FROM symbols s
+                 JOIN symbols c
+                   ON c.body_hash = s.body_hash AND c.name = s.name AND c.kind = s.kind
+                 WHERE s.id IN ({placeholders}) AND s.body_hash IS NOT NULL
+                 ORDER BY s.id, c.file_path, c.start_line, c.id"
+            );
+            let mut stmt = self.conn.prepare(&sql)?;
+            let rows = stmt.query_map(params_from_iter(chunk.iter()), |row| {
+                Ok((
+                    row.get::<_, String>(0)?,
+                    SymbolCopy {
+                        symbol_id: row.get(1)?,
+                        file_path: row.get(2)?,
+                        start_line: row.get(3)?,
+                    },
+                ))
+            })?;
+            for row in rows {
+                let (requested_id, copy) = row?;
+                groups.entry(requested_id).or_default().push(copy);
+            }
+        }
+
+        groups.retain(|_, copies| copies.len() > 1);
+        Ok(groups)
+    }
+}
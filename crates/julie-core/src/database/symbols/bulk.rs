@@ -1,8 +1,10 @@
 // Bulk symbol storage operations with index optimization
 
+use super::super::context_store::store_code_context;
 use super::super::helpers::SYMBOL_UPSERT_SQL;
 use super::super::*;
 use super::annotations::replace_annotations_batch;
+use crate::paths::normalize_stored_path;
 use anyhow::{Result, anyhow};
 use rusqlite::params;
 use tracing::{debug, info, warn};
@@ -188,13 +190,14 @@ impl SymbolDatabase {
                         .transpose()?;
 
                     let visibility_str = symbol.visibility.as_ref().map(|v| v.as_storage_str());
+                    let context_hash = store_code_context(&tx, symbol.code_context.as_deref())?;
 
                     match stmt.execute(params![
                         symbol.id,
                         symbol.name,
                         symbol.kind.to_string(),
                         symbol.language,
-                        symbol.file_path,
+                        normalize_stored_path(&symbol.file_path),
                         symbol.signature,
                         symbol.start_line,
                         symbol.start_column,
@@ -204,7 +207,7 @@ impl SymbolDatabase {
                         symbol.end_byte,
                         symbol.doc_comment,
                         visibility_str,
-                        symbol.code_context,
+                        context_hash,
                         symbol.parent_id,
                         metadata_json,
                         symbol.semantic_group,
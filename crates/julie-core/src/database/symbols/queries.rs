@@ -76,6 +76,29 @@ impl SymbolDatabase {
         Ok(symbols)
     }
 
+    /// Find every declaration site of a partial type (C# `partial class`).
+    ///
+    /// Declarations share the `partial_group` metadata key stamped at
+    /// extraction time. Ordered by file path and line so the result is stable.
+    pub fn find_partial_declarations(&self, partial_group: &str) -> Result<Vec<Symbol>> {
+        let query = format!(
+            "SELECT {} FROM symbols WHERE json_extract(metadata, '$.partial_group') = ?1 \
+             ORDER BY file_path, start_line",
+            SYMBOL_COLUMNS
+        );
+        let mut stmt = self.conn.prepare(&query)?;
+
+        let symbol_iter = stmt.query_map(params![partial_group], |row| self.row_to_symbol(row))?;
+
+        let mut symbols = Vec::new();
+        for symbol_result in symbol_iter {
+            symbols.push(symbol_result?);
+        }
+
+        hydrate_annotations_for_symbols(self, &mut symbols)?;
+        Ok(symbols)
+    }
+
     /// Find symbols matching any of the given names in a single batch.
     ///
     /// Groups results by name. Chunks queries to stay within SQLite's parameter limit.
@@ -284,4 +307,35 @@ impl SymbolDatabase {
         );
         Ok(symbols)
     }
+
+    /// The C/C++ preprocessor condition recorded on each of `symbol_ids`,
+    /// keyed by id. Symbols compiled unconditionally are absent.
+    pub fn preprocessor_conditions(
+        &self,
+        symbol_ids: &[String],
+    ) -> Result<HashMap<String, String>> {
+        let mut conditions = HashMap::new();
+        const CHUNK_SIZE: usize = 500;
+        for chunk in symbol_ids.chunks(CHUNK_SIZE) {
+            let placeholders = (1..=chunk.len())
+                .map(|i| format!("?{i}"))
+                .collect::<Vec<_>>()
+                .join(",");
+            let sql = format!(
+                "SELECT id, json_extract(metadata, '$.preprocessor.condition')
+                 FROM symbols
+                 WHERE id IN ({placeholders})
+                   AND json_extract(metadata, '$.preprocessor.condition') IS NOT NULL"
+            );
+            let mut stmt = self.conn.prepare(&sql)?;
+            let rows = stmt.query_map(rusqlite::params_from_iter(chunk.iter()), |row| {
+                Ok((row.get::<_, String>(0)?, row.get::<_, String>(1)?))
+            })?;
+            for row in rows {
+                let (id, condition) = row?;
+                conditions.insert(id, condition);
+            }
+        }
+        Ok(conditions)
+    }
 }
@@ -210,41 +210,44 @@ impl SymbolDatabase {
         Ok(self.get_symbol_count_for_workspace()? as usize)
     }
 
-    /// Query symbols by name pattern (LIKE search) with optional filters
-    /// Uses idx_symbols_name, idx_symbols_language for fast lookup
+    /// Query symbols whose name or code context contains `pattern`
+    /// (case-insensitive), with an optional language filter.
+    ///
+    /// Contexts are stored compressed, so SQL can only filter by language;
+    /// the substring match runs on the decompressed rows. Most-used symbols
+    /// come first, so the cap keeps the popular matches.
     pub fn query_symbols_by_name_pattern(
         &self,
         pattern: &str,
         language: Option<&str>,
     ) -> Result<Vec<Symbol>> {
-        let pattern_like = format!("%{}%", pattern);
+        const LIMIT: usize = 1000;
+        let needle = pattern.to_lowercase();
+        let matches = |symbol: &Symbol| {
+            symbol.name.to_lowercase().contains(&needle)
+                || symbol
+                    .code_context
+                    .as_deref()
+                    .is_some_and(|context| context.to_lowercase().contains(&needle))
+        };
 
-        let mut symbols = Vec::new();
+        let query = format!(
+            "SELECT {} FROM symbols
+             WHERE ?1 IS NULL OR language = ?1
+             ORDER BY reference_count DESC, name, file_path",
+            SYMBOL_COLUMNS
+        );
+        let mut stmt = self.conn.prepare(&query)?;
+        let rows = stmt.query_map([language], |row| self.row_to_symbol(row))?;
 
-        if let Some(lang) = language {
-            let query = format!(
-                "SELECT {} FROM symbols
-                 WHERE (name LIKE ?1 OR code_context LIKE ?1) AND language = ?2
-                 ORDER BY name, file_path LIMIT 1000",
-                SYMBOL_COLUMNS
-            );
-            let mut stmt = self.conn.prepare(&query)?;
-            let rows =
-                stmt.query_map([&pattern_like as &str, lang], |row| self.row_to_symbol(row))?;
-            for row in rows {
-                symbols.push(row?);
-            }
-        } else {
-            let query = format!(
-                "SELECT {} FROM symbols
-                 WHERE (name LIKE ?1 OR code_context LIKE ?1)
-                 ORDER BY name, file_path LIMIT 1000",
-                SYMBOL_COLUMNS
-            );
-            let mut stmt = self.conn.prepare(&query)?;
-            let rows = stmt.query_map([&pattern_like], |row| self.row_to_symbol(row))?;
-            for row in rows {
-                symbols.push(row?);
+        let mut symbols = Vec::new();
+        for row in rows {
+            let symbol = row?;
+            if matches(&symbol) {
+                symbols.push(symbol);
+                if symbols.len() == LIMIT {
+                    break;
+                }
             }
         }
 
@@ -252,6 +255,30 @@ impl SymbolDatabase {
         Ok(symbols)
     }
 
+    /// Distinct symbol names, most-referenced first, capped at `limit`.
+    /// Used as the dictionary for zero-hit "did you mean" suggestions.
+    pub fn symbol_name_dictionary(
+        &self,
+        language: Option<&str>,
+        limit: usize,
+    ) -> Result<Vec<String>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT name FROM symbols
+             WHERE ?1 IS NULL OR language = ?1
+             GROUP BY name
+             ORDER BY MAX(reference_score) DESC, name
+             LIMIT ?2",
+        )?;
+        let rows = stmt.query_map(rusqlite::params![language, limit as i64], |row| {
+            row.get::<_, String>(0)
+        })?;
+        let mut names = Vec::new();
+        for row in rows {
+            names.push(row?);
+        }
+        Ok(names)
+    }
+
     /// Find definition symbols whose name matches a query as a component.
     /// Matches exact name OR qualified names ending with `.query` (component boundary).
     /// Only returns definition kinds (module, class, trait, function, etc.).
@@ -281,7 +308,7 @@ impl SymbolDatabase {
                    AND name != ?2
                    AND kind IN {}
                    AND language = ?3
-                 ORDER BY reference_score DESC, name, file_path
+                 ORDER BY reference_score DESC, reference_count DESC, name, file_path
                  LIMIT ?4",
                 SYMBOL_COLUMNS, definition_kinds
             );
@@ -299,7 +326,7 @@ impl SymbolDatabase {
                  WHERE name LIKE ?1
                    AND name != ?2
                    AND kind IN {}
-                 ORDER BY reference_score DESC, name, file_path
+                 ORDER BY reference_score DESC, reference_count DESC, name, file_path
                  LIMIT ?3",
                 SYMBOL_COLUMNS, definition_kinds
             );
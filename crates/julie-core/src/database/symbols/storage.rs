@@ -1,5 +1,8 @@
 // Symbol storage and deletion operations
 
+use super::super::context_store::{
+    context_hashes_for_files, delete_unreferenced_contexts, store_code_context,
+};
 use super::super::helpers::SYMBOL_UPSERT_SQL;
 use super::super::*;
 use super::annotations::{delete_annotations_for_file, replace_annotations_batch};
@@ -7,6 +10,8 @@ use anyhow::Result;
 use rusqlite::params;
 use tracing::debug;
 
+use crate::paths::normalize_stored_path;
+
 impl SymbolDatabase {
     /// Store symbols and annotation rows atomically.
     pub fn store_symbols(&mut self, symbols: &[Symbol]) -> Result<()> {
@@ -26,6 +31,7 @@ impl SymbolDatabase {
 
             // Serialize visibility enum to string
             let visibility_str = symbol.visibility.as_ref().map(|v| v.as_storage_str());
+            let context_hash = store_code_context(&tx, symbol.code_context.as_deref())?;
 
             // Debug log for markdown symbols
             if symbol.language == "markdown" {
@@ -42,7 +48,7 @@ impl SymbolDatabase {
                     symbol.name,
                     symbol.kind.to_string(),
                     symbol.language,
-                    symbol.file_path,
+                    normalize_stored_path(&symbol.file_path),
                     symbol.signature,
                     symbol.start_line,
                     symbol.start_column,
@@ -52,7 +58,7 @@ impl SymbolDatabase {
                     symbol.end_byte,
                     symbol.doc_comment,
                     visibility_str,
-                    symbol.code_context,
+                    context_hash,
                     symbol.parent_id,
                     metadata_json,
                     symbol.semantic_group,
@@ -94,6 +100,7 @@ impl SymbolDatabase {
                 .transpose()?;
 
             let visibility_str = symbol.visibility.as_ref().map(|v| v.as_storage_str());
+            let context_hash = store_code_context(&tx, symbol.code_context.as_deref())?;
 
             tx.execute(
                 SYMBOL_UPSERT_SQL,
@@ -102,7 +109,7 @@ impl SymbolDatabase {
                     symbol.name,
                     symbol.kind.to_string(),
                     symbol.language,
-                    symbol.file_path,
+                    normalize_stored_path(&symbol.file_path),
                     symbol.signature,
                     symbol.start_line,
                     symbol.start_column,
@@ -112,7 +119,7 @@ impl SymbolDatabase {
                     symbol.end_byte,
                     symbol.doc_comment,
                     visibility_str,
-                    symbol.code_context,
+                    context_hash,
                     symbol.parent_id,
                     metadata_json,
                     symbol.semantic_group,
@@ -139,20 +146,24 @@ impl SymbolDatabase {
     }
 
     pub fn delete_symbols_for_file(&self, file_path: &str) -> Result<()> {
+        let contexts = context_hashes_for_files(&self.conn, &[file_path.to_string()])?;
         delete_annotations_for_file(&*self.conn, file_path)?;
         self.conn.execute(
             "DELETE FROM symbols WHERE file_path = ?1",
             params![file_path],
         )?;
+        delete_unreferenced_contexts(&self.conn, &contexts)?;
         Ok(())
     }
 
     pub fn delete_symbols_for_file_in_workspace(&self, file_path: &str) -> Result<()> {
+        let contexts = context_hashes_for_files(&self.conn, &[file_path.to_string()])?;
         delete_annotations_for_file(&*self.conn, file_path)?;
         let count = self.conn.execute(
             "DELETE FROM symbols WHERE file_path = ?1",
             params![file_path],
         )?;
+        delete_unreferenced_contexts(&self.conn, &contexts)?;
         debug!("Deleted {} symbols for file '{}'", count, file_path);
         Ok(())
     }
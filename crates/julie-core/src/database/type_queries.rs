@@ -5,15 +5,19 @@
 //! - Finding functions returning specific types
 //! - Finding functions accepting specific types as parameters
 //! - Type hierarchy exploration
+//! - Full type rows for display next to definitions
+
+use std::collections::HashMap;
 
 use anyhow::Result;
-use rusqlite::OptionalExtension;
+use rusqlite::{OptionalExtension, params_from_iter};
 use tracing::debug;
 
 use crate::database::SymbolDatabase;
 use crate::database::helpers::SYMBOL_COLUMNS;
 use crate::database::symbols::annotations::hydrate_annotations_for_symbols;
 use julie_extractors::Symbol;
+use julie_extractors::base::TypeInfo;
 
 impl SymbolDatabase {
     /// Find all symbols that implement a given interface/trait
@@ -333,4 +337,51 @@ impl SymbolDatabase {
         let type_result = stmt.query_row([symbol_id], |row| row.get(0)).optional()?;
         Ok(type_result)
     }
+
+    /// Full type rows of `symbol_ids`, keyed by symbol id. Symbols without
+    /// type information are absent. Chunked like `web_edges_from_symbols`.
+    pub fn get_type_infos_for_symbols(
+        &self,
+        symbol_ids: &[String],
+    ) -> Result<HashMap<String, TypeInfo>> {
+        const CHUNK_SIZE: usize = 500;
+        let mut types = HashMap::new();
+
+        for chunk in symbol_ids.chunks(CHUNK_SIZE) {
+            let placeholders = (1..=chunk.len())
+                .map(|i| format!("?{i}"))
+                .collect::<Vec<_>>()
+                .join(",");
+            let sql = format!(
+                "SELECT symbol_id, resolved_type, generic_params, constraints, is_inferred,
+                        language, metadata
+                 FROM types
+                 WHERE symbol_id IN ({placeholders})"
+            );
+            let mut stmt = self.conn.prepare(&sql)?;
+            let rows = stmt
+                .query_map(params_from_iter(chunk.iter()), |row| {
+                    let json_column = |index: usize| -> rusqlite::Result<Option<String>> {
+                        row.get::<_, Option<String>>(index)
+                    };
+                    Ok(TypeInfo {
+                        symbol_id: row.get(0)?,
+                        resolved_type: row.get(1)?,
+                        generic_params: json_column(2)?
+                            .and_then(|json| serde_json::from_str(&json).ok()),
+                        constraints: json_column(3)?
+                            .and_then(|json| serde_json::from_str(&json).ok()),
+                        is_inferred: row.get(4)?,
+                        language: row.get(5)?,
+                        metadata: json_column(6)?.and_then(|json| serde_json::from_str(&json).ok()),
+                    })
+                })?
+                .collect::<rusqlite::Result<Vec<_>>>()?;
+            for info in rows {
+                types.insert(info.symbol_id.clone(), info);
+            }
+        }
+
+        Ok(types)
+    }
 }
@@ -14,6 +14,8 @@ use tracing::{debug, info};
 use zerocopy::AsBytes;
 
 use super::SymbolDatabase;
+use super::chunk_vectors::delete_chunks_for_symbols;
+use super::embedding_generations::discard_pending_embeddings;
 
 impl SymbolDatabase {
     /// Store embeddings for a batch of symbols.
@@ -49,11 +51,16 @@ impl SymbolDatabase {
     /// Delete all embeddings for symbols belonging to a given file path.
     ///
     /// Joins on the `symbols` table to find symbol_ids for the file, then deletes
-    /// matching rows from `symbol_vectors`.
+    /// matching rows from `symbol_vectors` and the symbols' chunk vectors.
     ///
     /// **Important:** Call this BEFORE deleting symbols from the `symbols` table,
     /// because the join requires symbol records to still exist.
     pub fn delete_embeddings_for_file(&mut self, file_path: &str) -> Result<usize> {
+        delete_chunks_for_symbols(
+            &self.conn,
+            "SELECT id FROM symbols WHERE file_path = ?",
+            rusqlite::params![file_path],
+        )?;
         let deleted = self.conn.execute(
             "DELETE FROM symbol_vectors WHERE symbol_id IN (
                 SELECT id FROM symbols WHERE file_path = ?
@@ -66,9 +73,9 @@ impl SymbolDatabase {
         Ok(deleted)
     }
 
-    /// Delete embedding rows for specific symbol IDs.
+    /// Delete embedding rows (and chunk vectors) for specific symbol IDs.
     ///
-    /// Returns the number of deleted rows.
+    /// Returns the number of deleted symbol vectors.
     pub fn delete_embeddings_for_symbol_ids(&mut self, symbol_ids: &[String]) -> Result<usize> {
         const MAX_SQLITE_BIND_PARAMS_PER_DELETE: usize = 900;
 
@@ -88,6 +95,7 @@ impl SymbolDatabase {
                 .map(|id| id as &dyn rusqlite::types::ToSql)
                 .collect();
             total_deleted += self.conn.execute(&sql, params.as_slice())?;
+            delete_chunks_for_symbols(&self.conn, &placeholders.join(", "), params.as_slice())?;
         }
 
         if total_deleted > 0 {
@@ -97,8 +105,24 @@ impl SymbolDatabase {
         Ok(total_deleted)
     }
 
-    /// Delete embedding rows that no longer have a matching symbol.
+    /// Count embedding rows that no longer have a matching symbol.
+    pub fn count_orphan_embeddings(&self) -> Result<usize> {
+        let count: i64 = self.conn.query_row(
+            "SELECT COUNT(*) FROM symbol_vectors
+             WHERE symbol_id NOT IN (SELECT id FROM symbols)",
+            [],
+            |row| row.get(0),
+        )?;
+        Ok(count as usize)
+    }
+
+    /// Delete embedding rows (and chunk vectors) that no longer have a matching symbol.
     pub fn delete_orphan_embeddings(&mut self) -> Result<usize> {
+        delete_chunks_for_symbols(
+            &self.conn,
+            "SELECT symbol_id FROM symbol_chunks WHERE symbol_id NOT IN (SELECT id FROM symbols)",
+            &[],
+        )?;
         let deleted = self.conn.execute(
             "DELETE FROM symbol_vectors
              WHERE symbol_id NOT IN (SELECT id FROM symbols)",
@@ -226,6 +250,14 @@ impl SymbolDatabase {
             .map(|l| l as &dyn rusqlite::types::ToSql)
             .collect();
         let deleted = self.conn.execute(&sql, params.as_slice())?;
+        delete_chunks_for_symbols(
+            &self.conn,
+            &format!(
+                "SELECT id FROM symbols WHERE language IN ({})",
+                placeholders.join(", ")
+            ),
+            params.as_slice(),
+        )?;
         if deleted > 0 {
             debug!("Purged {deleted} embeddings for non-code languages: {languages:?}");
         }
@@ -233,8 +265,14 @@ impl SymbolDatabase {
     }
 
     /// Delete all embeddings (used during re-indexing).
+    ///
+    /// Also drops any pending embedding generation, so a pipeline still
+    /// running against the old vectors cannot commit into the cleared store.
     pub fn clear_all_embeddings(&mut self) -> Result<()> {
         self.conn.execute("DELETE FROM symbol_vectors", [])?;
+        self.conn.execute("DELETE FROM symbol_chunk_vectors", [])?;
+        self.conn.execute("DELETE FROM symbol_chunks", [])?;
+        discard_pending_embeddings(&self.conn)?;
         debug!("Cleared all embeddings from symbol_vectors");
         Ok(())
     }
@@ -276,7 +314,8 @@ impl SymbolDatabase {
         Ok(())
     }
 
-    /// Drop and recreate the symbol_vectors table with new dimensions.
+    /// Drop and recreate the symbol_vectors and symbol_chunk_vectors tables with
+    /// new dimensions.
     ///
     /// This clears ALL existing embeddings — the embedding pipeline will
     /// regenerate them on next run. Call this when the provider's dimensions
@@ -298,6 +337,13 @@ impl SymbolDatabase {
         );
         self.conn.execute(&create_sql, [])?;
 
+        self.conn
+            .execute("DROP TABLE IF EXISTS symbol_chunk_vectors", [])?;
+        self.create_symbol_chunk_tables(dimensions)?;
+        self.conn.execute("DELETE FROM symbol_chunks", [])?;
+        // Staged vectors have the old dimensions.
+        discard_pending_embeddings(&self.conn)?;
+
         info!(
             "✅ symbol_vectors table recreated with {}-dim float vectors",
             dimensions
@@ -39,19 +39,26 @@ impl SymbolDatabase {
         // Explicit deletes for every workspace-owned table — don't trust FK cascade alone
         // because foreign_keys pragma state is per-connection. Order is dependent-first.
         tx.execute("DELETE FROM symbol_vectors", [])?;
+        tx.execute("DELETE FROM symbol_chunk_vectors", [])?;
+        tx.execute("DELETE FROM symbol_chunks", [])?;
         tx.execute("DELETE FROM source_regions", [])?;
         tx.execute("DELETE FROM structural_facts", [])?;
         tx.execute("DELETE FROM complexity_metrics", [])?;
         tx.execute("DELETE FROM literals", [])?;
         tx.execute("DELETE FROM type_arguments", [])?;
+        tx.execute("DELETE FROM identifier_external_modules", [])?;
+        tx.execute("DELETE FROM symbol_aliases", [])?;
+        tx.execute("DELETE FROM swift_module_files", [])?;
         tx.execute("DELETE FROM identifiers", [])?;
         tx.execute("DELETE FROM types", [])?;
         tx.execute("DELETE FROM relationships", [])?;
         tx.execute("DELETE FROM symbols", [])?;
+        tx.execute("DELETE FROM context_blobs", [])?;
         tx.execute("DELETE FROM files", [])?;
         tx.execute("DELETE FROM indexing_repairs", [])?;
         tx.execute("DELETE FROM canonical_revisions", [])?;
         tx.execute("DELETE FROM revision_file_changes", [])?;
+        tx.execute("DELETE FROM revision_symbol_changes", [])?;
         tx.execute("DELETE FROM projection_states", [])?;
 
         tx.commit()?;
@@ -3,13 +3,176 @@ use std::collections::HashSet;
 use std::ffi::OsStr;
 use std::path::Path;
 use std::sync::OnceLock;
+use std::time::Duration;
 
 const HARD_SIZE_CAP: usize = 5_000_000; // 5 MiB absolute safety rail
+/// Default wall-clock budget for extracting one file with a tree-sitter parser.
+const DEFAULT_PARSE_TIMEOUT_SECS: u64 = 30;
 const MINIFIED_AVG_LINE_LEN: usize = 200;
 const MINIFIED_MAX_LINE_LEN: usize = 20_000;
 const MINIFIED_LONG_LINE_RATIO: f64 = 0.20;
 const LONG_LINE_THRESHOLD: usize = 500;
 
+/// Language id assigned to Jupyter notebooks (`.ipynb`).
+pub const JUPYTER_LANGUAGE: &str = "jupyter";
+/// Language id assigned to R Markdown documents (`.Rmd`).
+pub const RMARKDOWN_LANGUAGE: &str = "rmarkdown";
+
+/// Notebook formats have no grammar of their own, but their code cells are
+/// parsed with the cell language's extractor, so they are parser-backed.
+pub fn is_notebook_language(language: &str) -> bool {
+    language == JUPYTER_LANGUAGE || language == RMARKDOWN_LANGUAGE
+}
+
+/// Language id assigned to Verilog (`.v`, `.vh`).
+pub const VERILOG_LANGUAGE: &str = "verilog";
+/// Language id assigned to SystemVerilog (`.sv`, `.svh`).
+pub const SYSTEMVERILOG_LANGUAGE: &str = "systemverilog";
+
+/// HDL sources have no tree-sitter grammar; julie-pipeline's verilog pass
+/// parses them, so they are parser-backed.
+pub fn is_verilog_language(language: &str) -> bool {
+    language == VERILOG_LANGUAGE || language == SYSTEMVERILOG_LANGUAGE
+}
+
+/// Language id assigned to Groovy sources, Gradle scripts and Jenkinsfiles.
+pub const GROOVY_LANGUAGE: &str = "groovy";
+
+/// Groovy has no tree-sitter grammar; julie-pipeline's groovy pass parses it,
+/// so it is parser-backed.
+pub fn is_groovy_language(language: &str) -> bool {
+    language == GROOVY_LANGUAGE
+}
+
+/// Language id assigned to free-form Fortran (`.f90`, `.f95`, `.f03`, `.f08`).
+pub const FORTRAN_LANGUAGE: &str = "fortran";
+
+/// Fortran has no tree-sitter grammar; julie-pipeline's fortran pass parses
+/// it, so it is parser-backed.
+pub fn is_fortran_language(language: &str) -> bool {
+    language == FORTRAN_LANGUAGE
+}
+
+/// Language id assigned to Ada and SPARK (`.ads`, `.adb`, `.ada`).
+pub const ADA_LANGUAGE: &str = "ada";
+
+/// Ada has no tree-sitter grammar; julie-pipeline's ada pass parses it, so it
+/// is parser-backed.
+pub fn is_ada_language(language: &str) -> bool {
+    language == ADA_LANGUAGE
+}
+
+/// Language id assigned to comma-separated data files (`.csv`).
+pub const CSV_LANGUAGE: &str = "csv";
+/// Language id assigned to tab-separated data files (`.tsv`).
+pub const TSV_LANGUAGE: &str = "tsv";
+
+/// Data files are indexed as text; julie-pipeline's delimited data pass adds
+/// symbols for their header row.
+pub fn is_delimited_data_language(language: &str) -> bool {
+    language == CSV_LANGUAGE || language == TSV_LANGUAGE
+}
+
+/// Language id assigned to Makefiles (`Makefile`, `GNUmakefile`, `*.mk`).
+pub const MAKEFILE_LANGUAGE: &str = "makefile";
+/// Language id assigned to CMake scripts (`CMakeLists.txt`, `*.cmake`).
+pub const CMAKE_LANGUAGE: &str = "cmake";
+/// Language id assigned to Bazel package files (`BUILD`, `BUILD.bazel`).
+pub const BAZEL_LANGUAGE: &str = "bazel";
+
+/// Build files have no tree-sitter grammar; julie-pipeline's build target
+/// pass parses them, so they are parser-backed.
+pub fn is_build_file_language(language: &str) -> bool {
+    language == MAKEFILE_LANGUAGE || language == CMAKE_LANGUAGE || language == BAZEL_LANGUAGE
+}
+
+/// Language id assigned to Godot text scenes (`.tscn`).
+pub const GODOT_SCENE_LANGUAGE: &str = "godot_scene";
+
+/// Godot scenes have no tree-sitter grammar; julie-pipeline's Godot scene
+/// pass parses them, so they are parser-backed.
+pub fn is_godot_scene_language(language: &str) -> bool {
+    language == GODOT_SCENE_LANGUAGE
+}
+
+/// Language id assigned to Terraform and OpenTofu configuration (`.tf`,
+/// `.tofu`).
+pub const TERRAFORM_LANGUAGE: &str = "terraform";
+
+/// HCL has no tree-sitter grammar; julie-pipeline's terraform pass parses it,
+/// so it is parser-backed.
+pub fn is_terraform_language(language: &str) -> bool {
+    language == TERRAFORM_LANGUAGE
+}
+
+/// Language id of SQL sources, whatever the dialect.
+pub const SQL_LANGUAGE: &str = "sql";
+
+/// Procedural SQL dialect whose routines julie-pipeline's SQL dialect pass
+/// extracts on top of the SQL grammar.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SqlDialect {
+    /// Oracle PL/SQL: packages, procedures, functions and triggers.
+    PlSql,
+    /// Microsoft T-SQL: procedures, functions and triggers in `GO` batches.
+    TSql,
+}
+
+/// Extensions only PL/SQL sources use.
+const PLSQL_EXTENSIONS: &[&str] = &[
+    "pks", "pkb", "pck", "pls", "plb", "plsql", "prc", "fnc", "trg",
+];
+/// Extensions only T-SQL sources use.
+const TSQL_EXTENSIONS: &[&str] = &["tsql"];
+
+impl SqlDialect {
+    /// Parse a `[sql] dialect` value; vendor names are accepted too.
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "plsql" | "pl/sql" | "oracle" => Some(Self::PlSql),
+            "tsql" | "t-sql" | "mssql" | "sqlserver" => Some(Self::TSql),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::PlSql => "plsql",
+            Self::TSql => "tsql",
+        }
+    }
+
+    /// The dialect a file's extension names, if any. Plain `.sql` names none.
+    pub fn from_extension(path: &Path) -> Option<Self> {
+        let ext = path.extension()?.to_str()?;
+        if PLSQL_EXTENSIONS
+            .iter()
+            .any(|plsql| ext.eq_ignore_ascii_case(plsql))
+        {
+            Some(Self::PlSql)
+        } else if TSQL_EXTENSIONS
+            .iter()
+            .any(|tsql| ext.eq_ignore_ascii_case(tsql))
+        {
+            Some(Self::TSql)
+        } else {
+            None
+        }
+    }
+}
+
+fn has_extractor(language: &str) -> bool {
+    is_notebook_language(language)
+        || is_verilog_language(language)
+        || is_groovy_language(language)
+        || is_fortran_language(language)
+        || is_ada_language(language)
+        || is_build_file_language(language)
+        || is_godot_scene_language(language)
+        || is_terraform_language(language)
+        || julie_extractors::language::get_tree_sitter_language(language).is_ok()
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ExtractionMode {
     ParserBacked,
@@ -17,22 +180,99 @@ pub enum ExtractionMode {
 }
 
 pub fn detect_language_for_indexing(path: &Path) -> String {
+    let file_name = path
+        .file_name()
+        .and_then(|name| name.to_str())
+        .unwrap_or("");
+    // Build files are recognized by name before `.txt` and friends are.
+    // julie-pipeline's build target pass extracts their targets. Bazel's
+    // names are case-sensitive: a `build` script is not a package.
+    if file_name.eq_ignore_ascii_case("CMakeLists.txt") {
+        return CMAKE_LANGUAGE.to_string();
+    }
+    if file_name == "BUILD" || file_name == "BUILD.bazel" {
+        return BAZEL_LANGUAGE.to_string();
+    }
+
     if let Some(ext) = path.extension().and_then(|ext| ext.to_str()) {
         if let Some(lang) = julie_extractors::language::detect_language_from_extension(ext) {
             return lang.to_string();
         }
+        // No tree-sitter grammar; julie-pipeline's protobuf pass extracts symbols.
+        if ext.eq_ignore_ascii_case("proto") {
+            return "protobuf".to_string();
+        }
+        // Notebooks: julie-pipeline's notebook pass extracts each cell.
+        if ext.eq_ignore_ascii_case("ipynb") {
+            return JUPYTER_LANGUAGE.to_string();
+        }
+        if ext.eq_ignore_ascii_case("rmd") {
+            return RMARKDOWN_LANGUAGE.to_string();
+        }
+        // No tree-sitter grammar; julie-pipeline's verilog pass extracts symbols.
+        if ext.eq_ignore_ascii_case("v") || ext.eq_ignore_ascii_case("vh") {
+            return VERILOG_LANGUAGE.to_string();
+        }
+        if ext.eq_ignore_ascii_case("sv") || ext.eq_ignore_ascii_case("svh") {
+            return SYSTEMVERILOG_LANGUAGE.to_string();
+        }
+        // No tree-sitter grammar; julie-pipeline's groovy pass extracts symbols.
+        if ["groovy", "gvy", "gy", "gsh", "gradle"]
+            .iter()
+            .any(|groovy| ext.eq_ignore_ascii_case(groovy))
+        {
+            return GROOVY_LANGUAGE.to_string();
+        }
+        // No tree-sitter grammar; julie-pipeline's fortran pass extracts symbols.
+        if ["f90", "f95", "f03", "f08"]
+            .iter()
+            .any(|fortran| ext.eq_ignore_ascii_case(fortran))
+        {
+            return FORTRAN_LANGUAGE.to_string();
+        }
+        // No tree-sitter grammar; julie-pipeline's ada pass extracts symbols.
+        if ["ads", "adb", "ada"]
+            .iter()
+            .any(|ada| ext.eq_ignore_ascii_case(ada))
+        {
+            return ADA_LANGUAGE.to_string();
+        }
+        // PL/SQL and T-SQL sources; julie-pipeline's SQL dialect pass
+        // extracts their routines.
+        if SqlDialect::from_extension(path).is_some() {
+            return SQL_LANGUAGE.to_string();
+        }
+        // Data files; julie-pipeline's delimited data pass extracts header columns.
+        if ext.eq_ignore_ascii_case("csv") {
+            return CSV_LANGUAGE.to_string();
+        }
+        if ext.eq_ignore_ascii_case("tsv") {
+            return TSV_LANGUAGE.to_string();
+        }
+        // No tree-sitter grammar; julie-pipeline's build target pass extracts targets.
+        if ext.eq_ignore_ascii_case("mk") {
+            return MAKEFILE_LANGUAGE.to_string();
+        }
+        if ext.eq_ignore_ascii_case("cmake") {
+            return CMAKE_LANGUAGE.to_string();
+        }
+        // No tree-sitter grammar; julie-pipeline's Godot scene pass extracts nodes.
+        if ext.eq_ignore_ascii_case("tscn") {
+            return GODOT_SCENE_LANGUAGE.to_string();
+        }
+        // No tree-sitter grammar; julie-pipeline's terraform pass extracts blocks.
+        if ext.eq_ignore_ascii_case("tf") || ext.eq_ignore_ascii_case("tofu") {
+            return TERRAFORM_LANGUAGE.to_string();
+        }
     }
 
-    let file_name = path
-        .file_name()
-        .and_then(|name| name.to_str())
-        .unwrap_or("");
-
     match file_name.to_lowercase().as_str() {
         "dockerfile" | "containerfile" => "dockerfile".to_string(),
-        "makefile" | "gnumakefile" => "makefile".to_string(),
+        "makefile" | "gnumakefile" => MAKEFILE_LANGUAGE.to_string(),
         "cargo.toml" | "cargo.lock" => "toml".to_string(),
         "package.json" | "tsconfig.json" | "jsconfig.json" => "json".to_string(),
+        "jenkinsfile" => GROOVY_LANGUAGE.to_string(),
+        name if name.starts_with("jenkinsfile.") => GROOVY_LANGUAGE.to_string(),
         name if name.starts_with("bash") || name.contains("bashrc") || name.contains("bash_") => {
             "bash".to_string()
         }
@@ -63,13 +303,35 @@ pub fn allows_blacklisted_extension(file_name: &str) -> bool {
 
 pub fn determine_extraction_mode(language: &str, content: &str) -> ExtractionMode {
     if content.trim().is_empty()
-        || julie_extractors::language::get_tree_sitter_language(language).is_err()
+        || !has_extractor(language)
+        || parser_skip_reason(language, content).is_some()
     {
-        return ExtractionMode::TextOnly;
+        ExtractionMode::TextOnly
+    } else {
+        ExtractionMode::ParserBacked
+    }
+}
+
+/// Why a file in a parser-backed language is indexed text-only instead of
+/// parsed: too large, or minified/generated. `None` when the content is fine
+/// to hand to tree-sitter, and for empty files or languages without a
+/// grammar, which are text-only by design rather than skipped.
+///
+/// Indexing records the reason so skipped files show up in health
+/// diagnostics rather than silently losing their symbols.
+pub fn parser_skip_reason(language: &str, content: &str) -> Option<String> {
+    if content.trim().is_empty() || !has_extractor(language) {
+        return None;
+    }
+    if content.len() > HARD_SIZE_CAP {
+        return Some(format!(
+            "file too large to parse ({} bytes, limit {HARD_SIZE_CAP})",
+            content.len()
+        ));
     }
 
-    let skip_minified_check = matches!(language, "markdown");
-    let too_large = content.len() > HARD_SIZE_CAP;
+    // Notebook outputs embed long base64 lines that are not code.
+    let skip_minified_check = matches!(language, "markdown") || is_notebook_language(language);
     let minified = !skip_minified_check
         && is_likely_minified_or_generated(
             content,
@@ -78,12 +340,20 @@ pub fn determine_extraction_mode(language: &str, content: &str) -> ExtractionMod
             MINIFIED_LONG_LINE_RATIO,
             LONG_LINE_THRESHOLD,
         );
+    minified.then(|| "minified or generated content".to_string())
+}
 
-    if too_large || minified {
-        ExtractionMode::TextOnly
-    } else {
-        ExtractionMode::ParserBacked
-    }
+/// Wall-clock budget for extracting a single file. A file that exceeds it is
+/// indexed text-only and recorded as skipped instead of stalling the batch.
+///
+/// Override with `JULIE_PARSE_TIMEOUT_SECS`.
+pub fn parse_timeout() -> Duration {
+    let secs = std::env::var("JULIE_PARSE_TIMEOUT_SECS")
+        .ok()
+        .and_then(|value| value.trim().parse::<u64>().ok())
+        .filter(|secs| *secs > 0)
+        .unwrap_or(DEFAULT_PARSE_TIMEOUT_SECS);
+    Duration::from_secs(secs)
 }
 
 pub fn should_index_path_candidate(path: &Path, supported_extensions: &HashSet<String>) -> bool {
@@ -49,21 +49,22 @@ pub fn is_likely_text_file(file_path: &Path) -> bool {
 /// Secure path resolution that prevents directory traversal attacks.
 ///
 /// Resolves a file path relative to a workspace root and ensures the final
-/// resolved path is within the workspace boundaries.
+/// resolved path is within the workspace boundaries. `file_path` may be a
+/// stored path; non-UTF-8 bytes escaped in the index are decoded first.
 pub fn secure_path_resolution(
     file_path: &str,
     workspace_root: &Path,
 ) -> Result<std::path::PathBuf> {
     use std::path::{Component, PathBuf};
 
-    let candidate = Path::new(file_path);
+    let candidate = crate::paths::decode_stored_path(file_path);
 
     let canonical_workspace_root = workspace_root
         .canonicalize()
         .map_err(|e| anyhow::anyhow!("Workspace root does not exist: {}", e))?;
 
     let resolved = if candidate.is_absolute() {
-        candidate.to_path_buf()
+        candidate
     } else {
         canonical_workspace_root.join(candidate)
     };
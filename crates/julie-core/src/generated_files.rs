@@ -0,0 +1,352 @@
+//! Generator outputs declared by `[[generated]]` in `.julie/config.toml`.
+//!
+//! Generated sources (protobuf and gRPC stubs, ORM models) live in build
+//! output directories that discovery skips, so references to generated
+//! symbols dangle. Each `[[generated]]` table names one output directory to
+//! index anyway. Its files are read-only to the editing tools and map back to
+//! the source (IDL) file they were generated from, where changes belong.
+//!
+//! Outputs must resolve inside the workspace: a symlinked output tree such as
+//! Bazel's `bazel-bin`, which points into Bazel's cache, is skipped.
+//!
+//! Generated code that is checked in next to hand-written code (`*_pb2.py`,
+//! `*.g.dart`, ORM migrations, files with a "DO NOT EDIT" header) is found by
+//! [`detect_generated_code`] instead. It stays indexed but is ranked below
+//! hand-written code, which it otherwise drowns out in search results.
+
+use std::path::{Path, PathBuf};
+
+use globset::{Glob, GlobSet, GlobSetBuilder};
+use ignore::WalkBuilder;
+use tracing::debug;
+
+use crate::file_policy::{should_index_path_candidate, supported_extensions_for_indexing};
+use crate::paths::to_relative_unix_style;
+use crate::workspace_settings::{GeneratedOutputs, WorkspaceSettings, WorkspaceSettingsRegistry};
+
+/// Suffixes generators append to the source's base name
+/// (`user_pb2_grpc.py`, `user_grpc_pb.js`, `UserGrpc.java`).
+const GENERATOR_SUFFIXES: &[&str] = &[
+    "_pb2_grpc",
+    "_grpc_pb",
+    "_pb2",
+    "_grpc",
+    "_pb",
+    "Grpc",
+    "OuterClass",
+];
+
+/// File-name endings of common generator outputs, matched case-insensitively.
+const GENERATED_FILE_SUFFIXES: &[&str] = &[
+    ".pb.go",
+    ".pb.gw.go",
+    ".pb.cc",
+    ".pb.h",
+    ".pb.swift",
+    "_pb2.py",
+    "_pb2.pyi",
+    "_pb2_grpc.py",
+    "_pb.js",
+    "_pb.d.ts",
+    ".g.dart",
+    ".freezed.dart",
+    ".gr.dart",
+    ".mocks.dart",
+    ".g.cs",
+    ".g.i.cs",
+    ".designer.cs",
+    ".designer.vb",
+    "modelsnapshot.cs",
+];
+
+/// Lines at the top of a file searched for a generator header. Long enough
+/// to get past a license block.
+const HEADER_SCAN_LINES: usize = 25;
+
+/// Why a file counts as generated code.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GeneratedCodeReason {
+    /// Under a `[[generated]]` output directory.
+    Output,
+    /// Matches an `[index] generated` glob.
+    Configured,
+    /// Its name follows a generator convention, or it is an ORM migration.
+    FileName,
+    /// Its header says it is generated ("Code generated ... DO NOT EDIT").
+    Header,
+}
+
+impl GeneratedCodeReason {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Output => "output",
+            Self::Configured => "config",
+            Self::FileName => "file_name",
+            Self::Header => "header",
+        }
+    }
+}
+
+/// Why the file at `relative_path` with `content` is generated code, if it
+/// is. Declared outputs and configured globs are checked before the name and
+/// header heuristics.
+pub fn detect_generated_code(
+    workspace_root: &Path,
+    relative_path: &str,
+    content: &str,
+) -> Option<GeneratedCodeReason> {
+    let settings = WorkspaceSettingsRegistry::global().get(workspace_root);
+    let relative_path = relative_path.replace('\\', "/");
+    if !settings.generated.is_empty()
+        && GeneratedOutputMatcher::new(&settings)
+            .generated_file(&relative_path)
+            .is_some()
+    {
+        return Some(GeneratedCodeReason::Output);
+    }
+    if settings
+        .generated_code_matcher()
+        .is_some_and(|globs| globs.is_match(&relative_path))
+    {
+        return Some(GeneratedCodeReason::Configured);
+    }
+    if is_generated_file_name(&relative_path) {
+        return Some(GeneratedCodeReason::FileName);
+    }
+    has_generated_header(content).then_some(GeneratedCodeReason::Header)
+}
+
+/// Whether `path` is named like generator output: a protobuf, Dart
+/// `build_runner` or .NET designer file, a `*.generated.*` or
+/// `zz_generated.*` file, or an ORM migration.
+pub fn is_generated_file_name(path: &str) -> bool {
+    let path = path.replace('\\', "/");
+    let file_name = path
+        .rsplit('/')
+        .next()
+        .unwrap_or(&path)
+        .to_ascii_lowercase();
+    GENERATED_FILE_SUFFIXES
+        .iter()
+        .any(|suffix| file_name.ends_with(suffix))
+        || file_name.contains(".generated.")
+        || file_name.starts_with("zz_generated")
+        || is_orm_migration(&path, &file_name)
+}
+
+/// Django, EF Core, TypeORM and Knex name migrations after a sequence
+/// number or timestamp inside a `migrations` directory. Rails keeps them in
+/// `db/migrate` and Alembic in `alembic/versions`, under any name.
+fn is_orm_migration(path: &str, file_name: &str) -> bool {
+    let segments: Vec<&str> = path.split('/').collect();
+    let dirs = &segments[..segments.len().saturating_sub(1)];
+    let numbered = file_name.starts_with(|c: char| c.is_ascii_digit());
+    let in_migrations = dirs
+        .iter()
+        .any(|dir| dir.eq_ignore_ascii_case("migrations"));
+    let rails = dirs.windows(2).any(|pair| pair == ["db", "migrate"]);
+    let alembic = dirs.windows(2).any(|pair| pair == ["alembic", "versions"]);
+    (in_migrations && numbered)
+        || (rails && file_name.ends_with(".rb"))
+        || (alembic && file_name.ends_with(".py"))
+}
+
+/// Whether the first lines of `content` carry a generator header, such as
+/// Go's `// Code generated by protoc-gen-go. DO NOT EDIT.`, `@generated`, or
+/// .NET's `<auto-generated>`.
+pub fn has_generated_header(content: &str) -> bool {
+    content
+        .lines()
+        .take(HEADER_SCAN_LINES)
+        .any(|line| is_generated_marker(&line.to_ascii_lowercase()))
+}
+
+fn is_generated_marker(line: &str) -> bool {
+    if line.contains("@generated")
+        || line.contains("<auto-generated")
+        || line.contains("<autogenerated")
+    {
+        return true;
+    }
+    line.contains("generated")
+        && [
+            "do not edit",
+            "do not modify",
+            "don't edit",
+            "this file",
+            "protocol buffer compiler",
+        ]
+        .iter()
+        .any(|phrase| line.contains(phrase))
+}
+
+/// A workspace file that is generator output.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GeneratedFile {
+    /// The `[[generated]]` root the file is under.
+    pub root: String,
+    /// Workspace-relative source file, when the table sets `source`.
+    pub source: Option<String>,
+}
+
+impl GeneratedFile {
+    /// Why an edit of `file_path` is refused.
+    pub fn edit_refusal(&self, file_path: &str) -> String {
+        match &self.source {
+            Some(source) => format!(
+                "'{file_path}' is generated from '{source}' (`[[generated]]` root '{}'); edit the source and regenerate",
+                self.root
+            ),
+            None => format!(
+                "'{file_path}' is generated (`[[generated]]` root '{}'); edit its source and regenerate",
+                self.root
+            ),
+        }
+    }
+}
+
+/// The `[[generated]]` tables of one workspace, with globs compiled.
+#[derive(Debug, Default)]
+pub struct GeneratedOutputMatcher {
+    outputs: Vec<CompiledOutputs>,
+}
+
+#[derive(Debug)]
+struct CompiledOutputs {
+    root: String,
+    /// `None` includes every file under `root`.
+    include: Option<GlobSet>,
+    source: Option<String>,
+}
+
+impl GeneratedOutputMatcher {
+    pub fn new(settings: &WorkspaceSettings) -> Self {
+        Self {
+            outputs: settings.generated.iter().map(compile).collect(),
+        }
+    }
+
+    /// Matcher for the current settings of `workspace_root`.
+    pub fn for_workspace(workspace_root: &Path) -> Self {
+        Self::new(&WorkspaceSettingsRegistry::global().get(workspace_root))
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.outputs.is_empty()
+    }
+
+    /// Output directories, workspace-relative.
+    pub fn roots(&self) -> impl Iterator<Item = &str> {
+        self.outputs.iter().map(|outputs| outputs.root.as_str())
+    }
+
+    /// The generated file at workspace-relative `relative_path`, if it is one.
+    /// The first table whose root and globs match wins.
+    pub fn generated_file(&self, relative_path: &str) -> Option<GeneratedFile> {
+        let relative_path = relative_path.replace('\\', "/");
+        self.outputs.iter().find_map(|outputs| {
+            let output = relative_path
+                .strip_prefix(outputs.root.as_str())?
+                .strip_prefix('/')?;
+            if let Some(include) = &outputs.include {
+                if !include.is_match(output) {
+                    return None;
+                }
+            }
+            Some(GeneratedFile {
+                root: outputs.root.clone(),
+                source: outputs
+                    .source
+                    .as_deref()
+                    .map(|template| source_path(template, output)),
+            })
+        })
+    }
+}
+
+/// The generated file at `relative_path` in `workspace_root`, if it is one.
+pub fn generated_file(workspace_root: &Path, relative_path: &str) -> Option<GeneratedFile> {
+    let matcher = GeneratedOutputMatcher::for_workspace(workspace_root);
+    if matcher.is_empty() {
+        return None;
+    }
+    matcher.generated_file(relative_path)
+}
+
+/// Every indexable generator output of `workspace_root`, as absolute
+/// canonical paths. Ignore files and blacklisted directories do not apply:
+/// the roots are named explicitly.
+pub fn discover_generated_files(workspace_root: &Path) -> Vec<PathBuf> {
+    let matcher = GeneratedOutputMatcher::for_workspace(workspace_root);
+    let mut files = Vec::new();
+    for root in matcher.roots() {
+        let walk = WalkBuilder::new(workspace_root.join(root))
+            .standard_filters(false)
+            .follow_links(false)
+            .build();
+        for entry in walk.filter_map(Result::ok) {
+            if !entry.file_type().is_some_and(|ft| ft.is_file()) {
+                continue;
+            }
+            let path = entry.path();
+            if !should_index_path_candidate(path, supported_extensions_for_indexing()) {
+                continue;
+            }
+            let Ok(relative_path) = to_relative_unix_style(path, workspace_root) else {
+                debug!(
+                    "⏭️  Skipping generated file outside the workspace: {}",
+                    path.display()
+                );
+                continue;
+            };
+            if matcher.generated_file(&relative_path).is_some() {
+                files.push(path.canonicalize().unwrap_or_else(|_| path.to_path_buf()));
+            }
+        }
+    }
+    files.sort();
+    files.dedup();
+    files
+}
+
+/// `source` with `{dir}` and `{stem}` filled in for `output` (relative to
+/// its root). Empty segments left by an empty `{dir}` are dropped.
+pub fn source_path(template: &str, output: &str) -> String {
+    let (dir, file_name) = output.rsplit_once('/').unwrap_or(("", output));
+    let filled = template
+        .replace("{dir}", dir)
+        .replace("{stem}", output_stem(file_name));
+    filled
+        .split('/')
+        .filter(|segment| !segment.is_empty())
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+/// Base name a generator derived `file_name` from: the name up to its first
+/// `.`, minus one generator suffix (`user.pb.go`, `user_pb2_grpc.py` → `user`).
+pub fn output_stem(file_name: &str) -> &str {
+    let base = file_name.split('.').next().unwrap_or(file_name);
+    GENERATOR_SUFFIXES
+        .iter()
+        .find_map(|suffix| base.strip_suffix(suffix).filter(|stem| !stem.is_empty()))
+        .unwrap_or(base)
+}
+
+fn compile(outputs: &GeneratedOutputs) -> CompiledOutputs {
+    let include = (!outputs.include.is_empty()).then(|| {
+        let mut builder = GlobSetBuilder::new();
+        for pattern in &outputs.include {
+            // Validated on load.
+            if let Ok(glob) = Glob::new(pattern) {
+                builder.add(glob);
+            }
+        }
+        builder.build().unwrap_or_else(|_| GlobSet::empty())
+    });
+    CompiledOutputs {
+        root: outputs.root.trim().trim_end_matches('/').replace('\\', "/"),
+        include,
+        source: outputs.source.clone(),
+    }
+}
@@ -0,0 +1,263 @@
+//! File churn and branch switches from git history.
+//!
+//! Counts how many commits touched each file over a recent window, by running
+//! `git log` in the workspace root, lists the files that differ from an
+//! earlier commit so a branch switch can be reindexed from the diff, and lists
+//! the files with uncommitted work so they can be indexed first, and reads who
+//! last changed individual lines (`git blame`). Paths come
+//! back relative to that root with forward slashes, the same form the index
+//! stores, so they can be joined against `files.path` directly. Changes to
+//! files outside the root are ignored, which keeps a workspace nested in a
+//! larger repository scoped to itself.
+
+use anyhow::{Context, Result, bail};
+use std::collections::HashMap;
+use std::path::Path;
+use std::process::Command;
+
+/// Number of commits in the last `months` months touching each file under
+/// `root`. Merge commits are skipped: they repeat their parents' changes.
+///
+/// Fails when git is not installed or `root` is not inside a repository.
+pub fn commit_counts_since(root: &Path, months: u32) -> Result<HashMap<String, usize>> {
+    let output = Command::new("git")
+        .arg("-C")
+        .arg(root)
+        .args([
+            "-c",
+            "core.quotePath=false",
+            "log",
+            "--no-merges",
+            "--no-renames",
+            "--relative",
+            "--name-only",
+            "--format=",
+        ])
+        .arg(format!("--since={months} months ago"))
+        .output()
+        .context("Failed to run git")?;
+    if !output.status.success() {
+        bail!(
+            "git log failed in {}: {}",
+            root.display(),
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+    }
+    Ok(parse_commit_file_counts(&String::from_utf8_lossy(
+        &output.stdout,
+    )))
+}
+
+/// Count file occurrences in `git log --name-only --format=` output: one path
+/// per line for every commit that touched it, commits separated by blank
+/// lines.
+pub fn parse_commit_file_counts(output: &str) -> HashMap<String, usize> {
+    let mut counts = HashMap::new();
+    for line in output.lines() {
+        let path = line.trim();
+        if path.is_empty() {
+            continue;
+        }
+        *counts.entry(path.replace('\\', "/")).or_insert(0) += 1;
+    }
+    counts
+}
+
+/// Commit id checked out at `root` (`git rev-parse HEAD`).
+///
+/// Fails when git is not installed, `root` is not inside a repository, or the
+/// repository has no commits yet.
+pub fn head_commit(root: &Path) -> Result<String> {
+    let output = Command::new("git")
+        .arg("-C")
+        .arg(root)
+        .args(["rev-parse", "--verify", "HEAD"])
+        .output()
+        .context("Failed to run git")?;
+    if !output.status.success() {
+        bail!(
+            "git rev-parse failed in {}: {}",
+            root.display(),
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+    }
+    let head = String::from_utf8_lossy(&output.stdout).trim().to_string();
+    if !is_commit_id(&head) {
+        bail!("git rev-parse returned an unexpected commit id: {head}");
+    }
+    Ok(head)
+}
+
+/// Files under `root` whose working-tree content differs from `commit`:
+/// everything a checkout changed since then, plus uncommitted edits to
+/// tracked files. Renames are listed as a deletion and an addition, so both
+/// paths appear. Untracked files are not listed.
+pub fn changed_files_since(root: &Path, commit: &str) -> Result<Vec<String>> {
+    if !is_commit_id(commit) {
+        bail!("Not a commit id: {commit}");
+    }
+    let output = Command::new("git")
+        .arg("-C")
+        .arg(root)
+        .args([
+            "-c",
+            "core.quotePath=false",
+            "diff",
+            "--no-renames",
+            "--relative",
+            "--name-only",
+            commit,
+            "--",
+        ])
+        .output()
+        .context("Failed to run git")?;
+    if !output.status.success() {
+        bail!(
+            "git diff failed in {}: {}",
+            root.display(),
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+    }
+    Ok(parse_changed_paths(&String::from_utf8_lossy(
+        &output.stdout,
+    )))
+}
+
+/// Files under `root` with uncommitted work: staged or unstaged changes
+/// against HEAD, then untracked files that are not ignored. A repository
+/// without commits yet reports only its untracked files.
+///
+/// Fails when git is not installed or `root` is not inside a repository.
+pub fn uncommitted_files(root: &Path) -> Result<Vec<String>> {
+    let run = |args: &[&str]| -> Result<std::process::Output> {
+        Command::new("git")
+            .arg("-C")
+            .arg(root)
+            .args(["-c", "core.quotePath=false"])
+            .args(args)
+            .output()
+            .context("Failed to run git")
+    };
+    let untracked = run(&["ls-files", "--others", "--exclude-standard"])?;
+    if !untracked.status.success() {
+        bail!(
+            "git ls-files failed in {}: {}",
+            root.display(),
+            String::from_utf8_lossy(&untracked.stderr).trim()
+        );
+    }
+    let changed = run(&[
+        "diff",
+        "--no-renames",
+        "--relative",
+        "--name-only",
+        "HEAD",
+        "--",
+    ])?;
+    let mut output = String::new();
+    if changed.status.success() {
+        output.push_str(&String::from_utf8_lossy(&changed.stdout));
+        output.push('\n');
+    }
+    output.push_str(&String::from_utf8_lossy(&untracked.stdout));
+    Ok(parse_changed_paths(&output))
+}
+
+/// Paths in `git diff --name-only` output, one per line, deduplicated in
+/// their original order.
+pub fn parse_changed_paths(output: &str) -> Vec<String> {
+    let mut seen = std::collections::HashSet::new();
+    output
+        .lines()
+        .map(str::trim)
+        .filter(|path| !path.is_empty())
+        .map(|path| path.replace('\\', "/"))
+        .filter(|path| seen.insert(path.clone()))
+        .collect()
+}
+
+/// Who last changed one line, from `git blame`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LineBlame {
+    pub author: String,
+    /// Unix time, in seconds, of the commit that last changed the line.
+    pub author_time: i64,
+}
+
+/// Who last changed each of `lines` (1-based) of `file`, a path relative to
+/// `root`. Lines with uncommitted changes are left out.
+///
+/// Fails when git is not installed, the file is not tracked, or a line is
+/// past the end of the file.
+pub fn blame_lines(root: &Path, file: &str, lines: &[u32]) -> Result<HashMap<u32, LineBlame>> {
+    if lines.is_empty() {
+        return Ok(HashMap::new());
+    }
+    let mut command = Command::new("git");
+    command
+        .arg("-C")
+        .arg(root)
+        .args(["blame", "--line-porcelain"]);
+    for line in lines {
+        command.arg(format!("-L{line},{line}"));
+    }
+    let output = command
+        .arg("--")
+        .arg(file)
+        .output()
+        .context("Failed to run git")?;
+    if !output.status.success() {
+        bail!(
+            "git blame failed for {file}: {}",
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+    }
+    Ok(parse_blame_porcelain(&String::from_utf8_lossy(
+        &output.stdout,
+    )))
+}
+
+/// Blame per final line number in `git blame --line-porcelain` output. Each
+/// entry is a `<commit> <original line> <final line> [<count>]` header, then
+/// `key value` lines, then the line's content after a tab. Uncommitted lines
+/// (all-zero commit id) are skipped.
+pub fn parse_blame_porcelain(output: &str) -> HashMap<u32, LineBlame> {
+    let mut blame = HashMap::new();
+    let mut entry: Option<(bool, u32)> = None;
+    let mut author = None;
+    let mut author_time = None;
+    for line in output.lines() {
+        if line.starts_with('\t') {
+            if let (Some((true, line_number)), Some(author), Some(author_time)) =
+                (entry, author.take(), author_time.take())
+            {
+                blame.insert(
+                    line_number,
+                    LineBlame {
+                        author,
+                        author_time,
+                    },
+                );
+            }
+            entry = None;
+        } else if entry.is_none() {
+            let mut fields = line.split(' ');
+            let commit = fields.next().unwrap_or_default();
+            entry = fields
+                .nth(1)
+                .and_then(|final_line| final_line.parse().ok())
+                .map(|final_line| (commit.bytes().any(|b| b != b'0'), final_line));
+        } else if let Some(name) = line.strip_prefix("author ") {
+            author = Some(name.to_string());
+        } else if let Some(time) = line.strip_prefix("author-time ") {
+            author_time = time.parse().ok();
+        }
+    }
+    blame
+}
+
+/// A full or abbreviated hexadecimal object id. Anything else could be read
+/// by git as an option or a revision expression.
+fn is_commit_id(value: &str) -> bool {
+    (4..=64).contains(&value.len()) && value.bytes().all(|b| b.is_ascii_hexdigit())
+}
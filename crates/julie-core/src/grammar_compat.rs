@@ -0,0 +1,270 @@
+//! Tree-sitter grammar compatibility checks.
+//!
+//! Extractors match on node kind names (`function_item`, `class_declaration`,
+//! ...). A grammar upgrade that renames or drops one of them does not fail to
+//! compile or to parse — extraction just quietly finds nothing. This module
+//! pins, per language, the node kinds extraction depends on and verifies them
+//! against the grammar actually linked into the binary, together with the
+//! grammar's ABI version.
+//!
+//! [`grammar_compatibility`] runs the check once per process (at server
+//! startup), logs every problem, and the result is surfaced in the health
+//! report. The `grammar_compat` tests run the same check, so a grammar bump
+//! that breaks an expectation fails CI instead of shipping.
+
+use std::fmt;
+use std::sync::OnceLock;
+
+use tracing::{debug, warn};
+
+/// Node kinds one language's extractor relies on.
+#[derive(Debug, Clone, Copy)]
+pub struct GrammarExpectation {
+    pub language: &'static str,
+    /// Named node kinds that must exist in the grammar.
+    pub node_kinds: &'static [&'static str],
+}
+
+/// Pinned node kinds per language. Only kinds whose disappearance would
+/// silently drop symbols or relationships belong here; update the list in the
+/// same change that upgrades a grammar.
+pub const GRAMMAR_EXPECTATIONS: &[GrammarExpectation] = &[
+    GrammarExpectation {
+        language: "rust",
+        node_kinds: &[
+            "function_item",
+            "struct_item",
+            "enum_item",
+            "trait_item",
+            "impl_item",
+            "mod_item",
+            "use_declaration",
+            "call_expression",
+            "macro_invocation",
+        ],
+    },
+    GrammarExpectation {
+        language: "typescript",
+        node_kinds: &[
+            "function_declaration",
+            "class_declaration",
+            "interface_declaration",
+            "type_alias_declaration",
+            "method_definition",
+            "arrow_function",
+            "import_statement",
+            "call_expression",
+        ],
+    },
+    GrammarExpectation {
+        language: "javascript",
+        node_kinds: &[
+            "function_declaration",
+            "class_declaration",
+            "method_definition",
+            "arrow_function",
+            "import_statement",
+            "call_expression",
+        ],
+    },
+    GrammarExpectation {
+        language: "python",
+        node_kinds: &[
+            "function_definition",
+            "class_definition",
+            "decorated_definition",
+            "import_statement",
+            "import_from_statement",
+            "call",
+        ],
+    },
+    GrammarExpectation {
+        language: "java",
+        node_kinds: &[
+            "class_declaration",
+            "interface_declaration",
+            "enum_declaration",
+            "method_declaration",
+            "import_declaration",
+            "method_invocation",
+        ],
+    },
+    GrammarExpectation {
+        language: "csharp",
+        node_kinds: &[
+            "class_declaration",
+            "interface_declaration",
+            "method_declaration",
+            "property_declaration",
+            "namespace_declaration",
+            "using_directive",
+            "invocation_expression",
+        ],
+    },
+    GrammarExpectation {
+        language: "go",
+        node_kinds: &[
+            "function_declaration",
+            "method_declaration",
+            "type_declaration",
+            "type_spec",
+            "import_declaration",
+            "call_expression",
+        ],
+    },
+    GrammarExpectation {
+        language: "c",
+        node_kinds: &[
+            "function_definition",
+            "struct_specifier",
+            "declaration",
+            "preproc_include",
+            "call_expression",
+        ],
+    },
+    GrammarExpectation {
+        language: "cpp",
+        node_kinds: &[
+            "function_definition",
+            "class_specifier",
+            "namespace_definition",
+            "template_declaration",
+            "call_expression",
+        ],
+    },
+    GrammarExpectation {
+        language: "php",
+        node_kinds: &[
+            "function_definition",
+            "class_declaration",
+            "method_declaration",
+            "namespace_definition",
+        ],
+    },
+    GrammarExpectation {
+        language: "ruby",
+        node_kinds: &["method", "class", "module", "call"],
+    },
+];
+
+/// What is wrong with one language's grammar.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GrammarProblem {
+    /// The grammar could not be loaded at all.
+    Unavailable(String),
+    /// The grammar was generated for an ABI this tree-sitter cannot load.
+    IncompatibleAbi { version: usize },
+    /// Pinned node kinds the grammar no longer defines.
+    MissingNodeKinds(Vec<String>),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GrammarIssue {
+    pub language: String,
+    pub problem: GrammarProblem,
+}
+
+impl fmt::Display for GrammarIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.problem {
+            GrammarProblem::Unavailable(error) => {
+                write!(f, "{} grammar unavailable: {error}", self.language)
+            }
+            GrammarProblem::IncompatibleAbi { version } => write!(
+                f,
+                "{} grammar ABI {version} is outside the supported range {}-{}",
+                self.language,
+                tree_sitter::MIN_COMPATIBLE_LANGUAGE_VERSION,
+                tree_sitter::LANGUAGE_VERSION
+            ),
+            GrammarProblem::MissingNodeKinds(kinds) => write!(
+                f,
+                "{} grammar no longer defines node kinds {}; symbols using them will not be extracted",
+                self.language,
+                kinds.join(", ")
+            ),
+        }
+    }
+}
+
+/// Result of checking every pinned grammar.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct GrammarCompatReport {
+    /// Languages checked.
+    pub checked: usize,
+    pub issues: Vec<GrammarIssue>,
+}
+
+impl GrammarCompatReport {
+    pub fn is_compatible(&self) -> bool {
+        self.issues.is_empty()
+    }
+}
+
+/// Check `language` against the pinned `node_kinds`.
+pub fn check_grammar(
+    language_name: &str,
+    language: &tree_sitter::Language,
+    node_kinds: &[&str],
+) -> Option<GrammarIssue> {
+    let issue = |problem| {
+        Some(GrammarIssue {
+            language: language_name.to_string(),
+            problem,
+        })
+    };
+
+    let version = language.abi_version();
+    if !(tree_sitter::MIN_COMPATIBLE_LANGUAGE_VERSION..=tree_sitter::LANGUAGE_VERSION)
+        .contains(&version)
+    {
+        return issue(GrammarProblem::IncompatibleAbi { version });
+    }
+
+    // `id_for_node_kind` returns 0 (the reserved "end" symbol) for unknown kinds.
+    let missing: Vec<String> = node_kinds
+        .iter()
+        .filter(|kind| language.id_for_node_kind(kind, true) == 0)
+        .map(|kind| kind.to_string())
+        .collect();
+    if missing.is_empty() {
+        None
+    } else {
+        issue(GrammarProblem::MissingNodeKinds(missing))
+    }
+}
+
+/// Check every language in [`GRAMMAR_EXPECTATIONS`].
+pub fn check_all_grammars() -> GrammarCompatReport {
+    let mut report = GrammarCompatReport::default();
+    for expectation in GRAMMAR_EXPECTATIONS {
+        report.checked += 1;
+        let issue = match julie_extractors::language::get_tree_sitter_language(expectation.language)
+        {
+            Ok(language) => check_grammar(expectation.language, &language, expectation.node_kinds),
+            Err(error) => Some(GrammarIssue {
+                language: expectation.language.to_string(),
+                problem: GrammarProblem::Unavailable(error.to_string()),
+            }),
+        };
+        report.issues.extend(issue);
+    }
+    report
+}
+
+/// Process-wide check result, computed (and logged) on first use.
+pub fn grammar_compatibility() -> &'static GrammarCompatReport {
+    static REPORT: OnceLock<GrammarCompatReport> = OnceLock::new();
+    REPORT.get_or_init(|| {
+        let report = check_all_grammars();
+        for issue in &report.issues {
+            warn!("Grammar compatibility: {issue}");
+        }
+        debug!(
+            "Grammar compatibility: {} languages checked, {} issues",
+            report.checked,
+            report.issues.len()
+        );
+        report
+    })
+}
@@ -55,6 +55,10 @@ pub enum IndexingRepairReason {
     /// Triggers `spawn_workspace_embedding` without a full symbol
     /// re-extraction.
     MissingEmbeddings,
+    /// HEAD moved since the last index run. The repair run re-checks only
+    /// the files `git diff` lists against the indexed commit, plus files
+    /// modified since, instead of hashing the whole workspace.
+    BranchSwitched,
 }
 
 impl IndexingRepairReason {
@@ -70,6 +74,7 @@ impl IndexingRepairReason {
             Self::TantivyDirty => "tantivy_dirty",
             Self::SemanticVersionChanged => "semantic_version_changed",
             Self::MissingEmbeddings => "missing_embeddings",
+            Self::BranchSwitched => "branch_switched",
         }
     }
 
@@ -85,6 +90,7 @@ impl IndexingRepairReason {
             "tantivy_dirty" => Some(Self::TantivyDirty),
             "semantic_version_changed" => Some(Self::SemanticVersionChanged),
             "missing_embeddings" => Some(Self::MissingEmbeddings),
+            "branch_switched" => Some(Self::BranchSwitched),
             _ => None,
         }
     }
@@ -384,6 +390,18 @@ impl IndexingBatchState {
         &self.repair_issues
     }
 
+    /// Fold an earlier batch of the same run (a warm-up batch) into this one:
+    /// its files and repair issues count as this batch's.
+    pub fn absorb_earlier(&mut self, earlier: IndexingBatchState) {
+        let mut file_states = earlier.file_states;
+        file_states.append(&mut self.file_states);
+        self.file_states = file_states;
+        self.repair_needed |= earlier.repair_needed;
+        let mut repair_issues = earlier.repair_issues;
+        repair_issues.append(&mut self.repair_issues);
+        self.repair_issues = repair_issues;
+    }
+
     pub fn parsed_file_count(&self) -> usize {
         self.count_files(IndexedFileDisposition::Parsed)
     }
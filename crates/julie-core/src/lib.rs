@@ -4,6 +4,7 @@
 //! crate (and any future sibling crates) depend on. It must remain a true leaf:
 //! no references to `crate::handler`, `crate::tools`, or `crate::daemon`.
 
+pub mod background_tasks;
 pub mod connection_pool;
 pub mod cross_language_intelligence;
 pub mod database;
@@ -11,20 +12,31 @@ pub mod embeddings_contract;
 pub mod external_extract_paths;
 pub mod file_policy;
 pub mod file_utils;
+pub mod generated_files;
+pub mod git_history;
 pub mod glob;
+pub mod grammar_compat;
 pub mod health_types;
 pub mod indexing_state;
 pub mod language;
 pub mod mcp_compat;
+pub mod memory_budget;
+pub mod parser_pool;
 pub mod paths;
+pub mod preprocessor;
+pub mod response_budget;
 pub mod serde_lenient;
 pub mod shared;
 pub mod string_similarity;
+pub mod supervisor;
+pub mod symbol_ids;
+pub mod telemetry;
 pub mod token_estimation;
 pub mod walk;
 pub mod workspace;
 pub mod workspace_errors;
 pub mod workspace_scan;
+pub mod workspace_settings;
 
 #[cfg(any(test, feature = "test-support"))]
 pub mod test_support;
@@ -0,0 +1,235 @@
+//! Process-wide memory budget for indexing and embedding.
+//!
+//! The first index of a large repository holds every extracted row in memory
+//! until it is persisted, next to a pool of tree-sitter parsers and the text
+//! of the embedding batch in flight. On small CI machines that got the server
+//! OOM-killed. The owners of those buffers now report their approximate size
+//! to [`MemoryBudget::global`], and the code that decides how much work to
+//! take on next asks [`MemoryBudget::batch_size`], which shrinks the batch as
+//! usage nears the budget: extraction runs fewer files at once, embedding
+//! sends smaller batches, and the parser pool stops keeping idle parsers.
+//!
+//! Sizes are estimates (row counts times row sizes plus string payloads),
+//! not allocator statistics. Set the budget with `JULIE_MEMORY_BUDGET_MB`;
+//! without one usage is still tracked for health diagnostics, but nothing
+//! shrinks.
+
+use std::fmt;
+use std::sync::OnceLock;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+/// Environment variable holding the budget in MiB.
+pub const MEMORY_BUDGET_ENV: &str = "JULIE_MEMORY_BUDGET_MB";
+
+/// Batches keep their full size until usage reaches this share of the
+/// budget, then shrink linearly to their minimum at the full budget.
+const SHRINK_START_PERCENT: u64 = 50;
+
+const MIB: u64 = 1024 * 1024;
+
+/// The buffers whose size counts toward the budget.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MemoryComponent {
+    /// Tree-sitter parsers, checked out or idle in the parser pool.
+    ParserPool,
+    /// Extracted rows waiting to be persisted.
+    BatchBuffers,
+    /// Texts of embedding batches in flight.
+    EmbeddingBatches,
+}
+
+impl MemoryComponent {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::ParserPool => "parser pool",
+            Self::BatchBuffers => "batch buffers",
+            Self::EmbeddingBatches => "embedding batches",
+        }
+    }
+
+    fn index(self) -> usize {
+        match self {
+            Self::ParserPool => 0,
+            Self::BatchBuffers => 1,
+            Self::EmbeddingBatches => 2,
+        }
+    }
+}
+
+impl fmt::Display for MemoryComponent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// Point-in-time usage, for health diagnostics.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct MemoryUsage {
+    pub limit_bytes: Option<u64>,
+    pub parser_pool_bytes: u64,
+    pub batch_buffer_bytes: u64,
+    pub embedding_batch_bytes: u64,
+    /// Highest total seen since startup.
+    pub peak_bytes: u64,
+    /// Batches handed out smaller than requested because of the budget.
+    pub shrunk_batches: u64,
+}
+
+impl MemoryUsage {
+    pub fn used_bytes(&self) -> u64 {
+        self.parser_pool_bytes + self.batch_buffer_bytes + self.embedding_batch_bytes
+    }
+
+    pub fn over_budget(&self) -> bool {
+        self.limit_bytes
+            .is_some_and(|limit| self.used_bytes() >= limit)
+    }
+}
+
+/// Approximate memory held by the tracked buffers, against an optional limit.
+///
+/// Production code uses the process-wide [`MemoryBudget::global`]; tests
+/// create their own instance for isolation.
+pub struct MemoryBudget {
+    /// Zero when unlimited.
+    limit_bytes: u64,
+    used: [AtomicU64; 3],
+    peak: AtomicU64,
+    shrunk_batches: AtomicU64,
+}
+
+impl Default for MemoryBudget {
+    fn default() -> Self {
+        Self::new(None)
+    }
+}
+
+impl MemoryBudget {
+    pub fn new(limit_bytes: Option<u64>) -> Self {
+        Self {
+            limit_bytes: limit_bytes.unwrap_or(0),
+            used: Default::default(),
+            peak: AtomicU64::new(0),
+            shrunk_batches: AtomicU64::new(0),
+        }
+    }
+
+    /// The budget from [`MEMORY_BUDGET_ENV`]; unlimited when unset, zero or
+    /// not a number.
+    pub fn from_env() -> Self {
+        let limit = std::env::var(MEMORY_BUDGET_ENV)
+            .ok()
+            .and_then(|value| value.trim().parse::<u64>().ok())
+            .filter(|mib| *mib > 0)
+            .map(|mib| mib.saturating_mul(MIB));
+        Self::new(limit)
+    }
+
+    pub fn global() -> &'static MemoryBudget {
+        static GLOBAL: OnceLock<MemoryBudget> = OnceLock::new();
+        GLOBAL.get_or_init(MemoryBudget::from_env)
+    }
+
+    pub fn limit_bytes(&self) -> Option<u64> {
+        (self.limit_bytes > 0).then_some(self.limit_bytes)
+    }
+
+    pub fn add(&self, component: MemoryComponent, bytes: u64) {
+        self.used[component.index()].fetch_add(bytes, Ordering::Relaxed);
+        self.peak.fetch_max(self.used_bytes(), Ordering::Relaxed);
+    }
+
+    pub fn release(&self, component: MemoryComponent, bytes: u64) {
+        // `fetch_update` with a closure that never fails cannot error.
+        let _ = self.used[component.index()].fetch_update(
+            Ordering::Relaxed,
+            Ordering::Relaxed,
+            |used| Some(used.saturating_sub(bytes)),
+        );
+    }
+
+    /// Count `bytes` against `component` until the returned charge is
+    /// dropped.
+    pub fn charge(&self, component: MemoryComponent, bytes: u64) -> MemoryCharge<'_> {
+        self.add(component, bytes);
+        MemoryCharge {
+            budget: self,
+            component,
+            bytes,
+        }
+    }
+
+    pub fn used_bytes(&self) -> u64 {
+        self.used
+            .iter()
+            .map(|used| used.load(Ordering::Relaxed))
+            .sum()
+    }
+
+    pub fn over_budget(&self) -> bool {
+        self.limit_bytes()
+            .is_some_and(|limit| self.used_bytes() >= limit)
+    }
+
+    /// How many items to take on next, out of a normal batch of `default`:
+    /// all of them while usage is below half the budget, fewer as it nears
+    /// the budget, and `min` (at least one) once it is reached.
+    pub fn batch_size(&self, default: usize, min: usize) -> usize {
+        let min = min.clamp(1, default.max(1));
+        let Some(limit) = self.limit_bytes() else {
+            return default;
+        };
+        let used = self.used_bytes();
+        let shrink_start = limit / 100 * SHRINK_START_PERCENT;
+        if used <= shrink_start {
+            return default;
+        }
+        let headroom = limit.saturating_sub(used);
+        let span = (limit - shrink_start).max(1);
+        let scaled = (default as u128 * headroom as u128 / span as u128) as usize;
+        let size = scaled.clamp(min, default);
+        if size < default {
+            self.shrunk_batches.fetch_add(1, Ordering::Relaxed);
+        }
+        size
+    }
+
+    pub fn usage(&self) -> MemoryUsage {
+        let used =
+            |component: MemoryComponent| self.used[component.index()].load(Ordering::Relaxed);
+        MemoryUsage {
+            limit_bytes: self.limit_bytes(),
+            parser_pool_bytes: used(MemoryComponent::ParserPool),
+            batch_buffer_bytes: used(MemoryComponent::BatchBuffers),
+            embedding_batch_bytes: used(MemoryComponent::EmbeddingBatches),
+            peak_bytes: self.peak.load(Ordering::Relaxed),
+            shrunk_batches: self.shrunk_batches.load(Ordering::Relaxed),
+        }
+    }
+}
+
+/// Bytes counted against a [`MemoryBudget`]; released when dropped.
+pub struct MemoryCharge<'a> {
+    budget: &'a MemoryBudget,
+    component: MemoryComponent,
+    bytes: u64,
+}
+
+impl MemoryCharge<'_> {
+    /// Change the charged amount to `bytes`, for a buffer that grew or
+    /// shrank.
+    pub fn resize(&mut self, bytes: u64) {
+        if bytes > self.bytes {
+            self.budget.add(self.component, bytes - self.bytes);
+        } else {
+            self.budget.release(self.component, self.bytes - bytes);
+        }
+        self.bytes = bytes;
+    }
+}
+
+impl Drop for MemoryCharge<'_> {
+    fn drop(&mut self) {
+        self.budget.release(self.component, self.bytes);
+    }
+}
@@ -0,0 +1,220 @@
+//! Process-wide pool of tree-sitter parsers, keyed by language.
+//!
+//! Creating a parser, loading its grammar and growing its parse stack showed
+//! up in indexing profiles: every file paid for all three. Callers now check
+//! a parser out with [`ParserPool::checkout`]; when the [`PooledParser`] is
+//! dropped the parser is reset and returned, keeping the grammar and the
+//! stack and lexer buffers it has already grown for the next file.
+//!
+//! The extraction pipeline (syntax-error masking, identifier fill-in), the
+//! watcher's re-extraction of the same passes, and the tools that parse
+//! source on demand share [`ParserPool::global`]. Extractors inside
+//! `julie_extractors` manage their own parsers and are not pooled.
+//!
+//! Every parser the pool builds counts [`PARSER_ESTIMATE_BYTES`] against the
+//! [`MemoryBudget`] until it is dropped, and a returned parser is dropped
+//! instead of kept idle while the budget is exhausted.
+
+use std::collections::HashMap;
+use std::ops::{Deref, DerefMut};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Mutex, MutexGuard, OnceLock};
+
+use anyhow::Result;
+use tree_sitter::{Language, Parser};
+
+use crate::memory_budget::{MemoryBudget, MemoryComponent};
+
+/// Idle parsers kept per language; more are dropped when returned.
+pub const DEFAULT_MAX_IDLE_PER_LANGUAGE: usize = 8;
+
+/// Rough memory of one parser once its parse stack and lexer buffers have
+/// grown on typical source files.
+pub const PARSER_ESTIMATE_BYTES: u64 = 256 * 1024;
+
+/// Counters for one pool.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ParserPoolStats {
+    /// Parsers built because none was idle.
+    pub created: u64,
+    /// Checkouts served by an idle parser.
+    pub reused: u64,
+    /// Parsers idle right now, across languages.
+    pub idle: usize,
+}
+
+#[derive(Default)]
+struct PoolState {
+    grammars: HashMap<String, Language>,
+    idle: HashMap<String, Vec<Parser>>,
+}
+
+/// Idle parsers per language.
+///
+/// Production code uses the process-wide [`ParserPool::global`]; tests create
+/// their own instance for isolation.
+pub struct ParserPool {
+    max_idle_per_language: usize,
+    budget: Option<&'static MemoryBudget>,
+    state: Mutex<PoolState>,
+    created: AtomicU64,
+    reused: AtomicU64,
+}
+
+impl Default for ParserPool {
+    fn default() -> Self {
+        Self::new(DEFAULT_MAX_IDLE_PER_LANGUAGE)
+    }
+}
+
+impl ParserPool {
+    pub fn new(max_idle_per_language: usize) -> Self {
+        Self::with_budget(max_idle_per_language, None)
+    }
+
+    /// A pool whose parsers count against `budget`.
+    pub fn with_budget(
+        max_idle_per_language: usize,
+        budget: Option<&'static MemoryBudget>,
+    ) -> Self {
+        Self {
+            max_idle_per_language,
+            budget,
+            state: Mutex::new(PoolState::default()),
+            created: AtomicU64::new(0),
+            reused: AtomicU64::new(0),
+        }
+    }
+
+    pub fn global() -> &'static ParserPool {
+        static GLOBAL: OnceLock<ParserPool> = OnceLock::new();
+        GLOBAL.get_or_init(|| {
+            ParserPool::with_budget(DEFAULT_MAX_IDLE_PER_LANGUAGE, Some(MemoryBudget::global()))
+        })
+    }
+
+    /// A parser set to `language`'s grammar, idle or new. Fails when no
+    /// tree-sitter grammar is registered for the language.
+    pub fn checkout(&self, language: &str) -> Result<PooledParser<'_>> {
+        let idle = self.lock().idle.get_mut(language).and_then(Vec::pop);
+        let parser = match idle {
+            Some(parser) => {
+                self.reused.fetch_add(1, Ordering::Relaxed);
+                parser
+            }
+            None => {
+                let grammar = grammar_for(&mut self.lock(), language)?;
+                self.build(&grammar)?
+            }
+        };
+        Ok(PooledParser {
+            pool: self,
+            language: language.to_string(),
+            parser: Some(parser),
+        })
+    }
+
+    /// Build parsers for `language` until `count` are idle (capped by the
+    /// pool's limit), so the first files of a batch skip grammar loading.
+    pub fn warm(&self, language: &str, count: usize) -> Result<()> {
+        let target = count.min(self.max_idle_per_language);
+        let (missing, grammar) = {
+            let mut state = self.lock();
+            let idle = state.idle.get(language).map_or(0, Vec::len);
+            if idle >= target {
+                return Ok(());
+            }
+            (target - idle, grammar_for(&mut state, language)?)
+        };
+        let parsers = (0..missing)
+            .map(|_| self.build(&grammar))
+            .collect::<Result<Vec<_>>>()?;
+        for parser in parsers {
+            self.give_back(language, parser);
+        }
+        Ok(())
+    }
+
+    pub fn stats(&self) -> ParserPoolStats {
+        ParserPoolStats {
+            created: self.created.load(Ordering::Relaxed),
+            reused: self.reused.load(Ordering::Relaxed),
+            idle: self.lock().idle.values().map(Vec::len).sum(),
+        }
+    }
+
+    fn build(&self, grammar: &Language) -> Result<Parser> {
+        let mut parser = Parser::new();
+        parser.set_language(grammar)?;
+        self.created.fetch_add(1, Ordering::Relaxed);
+        if let Some(budget) = self.budget {
+            budget.add(MemoryComponent::ParserPool, PARSER_ESTIMATE_BYTES);
+        }
+        Ok(parser)
+    }
+
+    fn give_back(&self, language: &str, mut parser: Parser) {
+        parser.reset();
+        let over_budget = self.budget.is_some_and(MemoryBudget::over_budget);
+        {
+            let mut state = self.lock();
+            let idle = state.idle.entry(language.to_string()).or_default();
+            if idle.len() < self.max_idle_per_language && !over_budget {
+                idle.push(parser);
+                return;
+            }
+        }
+        drop(parser);
+        if let Some(budget) = self.budget {
+            budget.release(MemoryComponent::ParserPool, PARSER_ESTIMATE_BYTES);
+        }
+    }
+
+    fn lock(&self) -> MutexGuard<'_, PoolState> {
+        // A panic while holding the lock leaves the maps consistent: every
+        // update is a single insert or pop.
+        self.state
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+fn grammar_for(state: &mut PoolState, language: &str) -> Result<Language> {
+    if let Some(grammar) = state.grammars.get(language) {
+        return Ok(grammar.clone());
+    }
+    let grammar = julie_extractors::language::get_tree_sitter_language(language)?;
+    state.grammars.insert(language.to_string(), grammar.clone());
+    Ok(grammar)
+}
+
+/// A checked-out parser; returned to its pool when dropped.
+pub struct PooledParser<'a> {
+    pool: &'a ParserPool,
+    language: String,
+    parser: Option<Parser>,
+}
+
+impl Deref for PooledParser<'_> {
+    type Target = Parser;
+
+    fn deref(&self) -> &Parser {
+        self.parser.as_ref().expect("parser is present until drop")
+    }
+}
+
+impl DerefMut for PooledParser<'_> {
+    fn deref_mut(&mut self) -> &mut Parser {
+        self.parser.as_mut().expect("parser is present until drop")
+    }
+}
+
+impl Drop for PooledParser<'_> {
+    fn drop(&mut self) {
+        // A parser dropped by a panic may be mid-parse; `reset` in
+        // `give_back` clears that state, so it is still safe to reuse.
+        if let Some(parser) = self.parser.take() {
+            self.pool.give_back(&self.language, parser);
+        }
+    }
+}
@@ -6,6 +6,8 @@
 //! existing `crate::utils::paths::*` call sites compile unchanged.
 
 use anyhow::{Context, Result};
+use std::borrow::Cow;
+use std::ffi::OsStr;
 use std::path::{MAIN_SEPARATOR, Path, PathBuf};
 
 use crate::workspace_errors::{WorkspaceResolutionFailure, WorkspaceResolutionFailureKind};
@@ -96,8 +98,10 @@ pub fn to_relative_unix_style(absolute: &Path, workspace_root: &Path) -> Result<
         }
     };
 
-    // Convert to string and normalize separators to Unix-style
-    let path_str = relative.to_str().context("Path contains invalid UTF-8")?;
+    // Convert to string and normalize separators to Unix-style. Non-UTF-8
+    // bytes are escaped losslessly (see `encode_path_lossless`) rather than
+    // rejected, so such files still round-trip through the database.
+    let path_str = encode_path_lossless(relative.as_os_str());
 
     // Replace platform-specific separators with Unix-style /
     // On Unix, MAIN_SEPARATOR is already '/', so this is a no-op
@@ -105,15 +109,15 @@ pub fn to_relative_unix_style(absolute: &Path, workspace_root: &Path) -> Result<
     let unix_style = if MAIN_SEPARATOR == '\\' {
         path_str.replace('\\', "/")
     } else {
-        path_str.to_string()
+        path_str
     };
 
     Ok(unix_style)
 }
 
 fn relative_by_normalized_string(path: &Path, root: &Path) -> Option<String> {
-    let path = path.to_string_lossy().replace('\\', "/");
-    let root = root.to_string_lossy().replace('\\', "/");
+    let path = encode_path_lossless(path.as_os_str()).replace('\\', "/");
+    let root = encode_path_lossless(root.as_os_str()).replace('\\', "/");
     let root = root.trim_end_matches('/');
 
     if root.is_empty() {
@@ -147,6 +151,218 @@ fn strip_normalized_prefix<'a>(path: &'a str, root: &str) -> Option<&'a str> {
     path.strip_prefix(&format!("{root}/"))
 }
 
+// ──────────────────────────────────────────────────────────────────────────────
+// normalize_stored_path
+// ──────────────────────────────────────────────────────────────────────────────
+
+/// Bring a path about to be written to (or looked up in) the database into
+/// the stored form: forward slashes, no Windows extended-length prefix.
+///
+/// Paths normally arrive workspace-relative from [`to_relative_unix_style`],
+/// but fallbacks for files outside the root used to store `\\?\C:\...`
+/// verbatim, which leaked into tool output and made a database built on
+/// Windows unreadable elsewhere. The `\\?\` and `\\?\UNC\` prefixes are
+/// removed (also in their already slash-converted `//?/` form), and
+/// backslashes become `/` when the path is Windows-shaped (a drive letter or
+/// a `\\server` share) or on Windows builds. On other platforms a backslash
+/// in a relative path is a legal file name character and is kept.
+///
+/// Already-normalized paths are returned borrowed.
+pub fn normalize_stored_path(path: &str) -> Cow<'_, str> {
+    let (rest, prefix) = if let Some(rest) = path
+        .strip_prefix(r"\\?\UNC\")
+        .or_else(|| path.strip_prefix("//?/UNC/"))
+    {
+        (rest, "//")
+    } else if let Some(rest) = path
+        .strip_prefix(r"\\?\")
+        .or_else(|| path.strip_prefix("//?/"))
+    {
+        (rest, "")
+    } else {
+        (path, "")
+    };
+    let stripped = rest.len() != path.len();
+
+    let windows_shaped =
+        stripped || cfg!(windows) || has_drive_letter(rest) || rest.starts_with(r"\\");
+    if !windows_shaped || (!stripped && !rest.contains('\\')) {
+        return Cow::Borrowed(path);
+    }
+    Cow::Owned(format!("{prefix}{}", rest.replace('\\', "/")))
+}
+
+/// [`normalize_stored_path`], then made workspace-relative when the path is
+/// absolute and lies under `workspace_root`.
+///
+/// A file reached through a spelling of the root that canonicalization did
+/// not line up (a `\\?\` prefix on one side only, a different drive-letter
+/// case) used to be stored by its full path next to the relative rows of the
+/// same tree. Paths outside the root keep their absolute form.
+pub fn normalize_stored_path_within<'a>(path: &'a str, workspace_root: &Path) -> Cow<'a, str> {
+    let normalized = normalize_stored_path(path);
+    if !(normalized.starts_with('/') || has_drive_letter(&normalized)) {
+        return normalized;
+    }
+    let root = encode_path_lossless(workspace_root.as_os_str()).replace('\\', "/");
+    let root = normalize_stored_path(&root);
+    let root = root.trim_end_matches('/');
+    if root.is_empty() {
+        return normalized;
+    }
+    let relative = strip_stored_root(&normalized, root)
+        .filter(|relative| !relative.is_empty())
+        .map(str::to_string);
+    relative.map_or(normalized, Cow::Owned)
+}
+
+/// The part of `path` below `root`. Windows-shaped paths compare without
+/// regard to ASCII case, as the file system does.
+fn strip_stored_root<'a>(path: &'a str, root: &str) -> Option<&'a str> {
+    let rest = if cfg!(windows) || has_drive_letter(path) || path.starts_with("//") {
+        let head = path.get(..root.len())?;
+        head.eq_ignore_ascii_case(root)
+            .then(|| &path[root.len()..])?
+    } else {
+        path.strip_prefix(root)?
+    };
+    rest.strip_prefix('/')
+}
+
+fn has_drive_letter(path: &str) -> bool {
+    let bytes = path.as_bytes();
+    bytes.len() >= 3
+        && bytes[0].is_ascii_alphabetic()
+        && bytes[1] == b':'
+        && (bytes[2] == b'\\' || bytes[2] == b'/')
+}
+
+// ──────────────────────────────────────────────────────────────────────────────
+// Lossless path encoding
+// ──────────────────────────────────────────────────────────────────────────────
+
+/// First code point of the escape range used for raw (non-UTF-8) path bytes.
+///
+/// Byte `b` is stored as `U+10FF00 + b`, the last 256 code points of plane 16
+/// (Supplementary Private Use Area-B). Real file names essentially never use
+/// them, and a literal occurrence is itself escaped byte-by-byte, so the
+/// encoding stays lossless in both directions.
+const RAW_BYTE_ESCAPE_BASE: u32 = 0x10FF00;
+
+fn is_raw_byte_escape(c: char) -> bool {
+    (c as u32) >= RAW_BYTE_ESCAPE_BASE
+}
+
+fn raw_byte_escape(byte: u8) -> char {
+    char::from_u32(RAW_BYTE_ESCAPE_BASE + byte as u32).expect("escape range is valid")
+}
+
+/// Encode an OS path as a `String` suitable for database storage without
+/// losing information.
+///
+/// Valid UTF-8 passes through unchanged, so every path that was storable
+/// before keeps its exact representation (no migration needed). Bytes that are
+/// not valid UTF-8 — Latin-1 or Shift-JIS file names on Linux, for example —
+/// are mapped into a private-use escape range instead of being replaced with
+/// `U+FFFD`, which would collapse distinct files onto one key.
+///
+/// [`decode_stored_path`] reverses the mapping. On Windows, paths are always
+/// valid Unicode once unpaired surrogates are ruled out, so the lossy
+/// conversion is exact there in practice.
+pub fn encode_path_lossless(path: &OsStr) -> String {
+    #[cfg(unix)]
+    {
+        use std::os::unix::ffi::OsStrExt;
+        encode_bytes_lossless(path.as_bytes())
+    }
+    #[cfg(not(unix))]
+    {
+        encode_bytes_lossless(path.to_string_lossy().as_bytes())
+    }
+}
+
+fn encode_bytes_lossless(bytes: &[u8]) -> String {
+    let mut encoded = String::with_capacity(bytes.len());
+    for chunk in bytes.utf8_chunks() {
+        for c in chunk.valid().chars() {
+            if is_raw_byte_escape(c) {
+                let mut buf = [0u8; 4];
+                for byte in c.encode_utf8(&mut buf).bytes() {
+                    encoded.push(raw_byte_escape(byte));
+                }
+            } else {
+                encoded.push(c);
+            }
+        }
+        encoded.extend(chunk.invalid().iter().copied().map(raw_byte_escape));
+    }
+    encoded
+}
+
+/// Decode a path stored by [`encode_path_lossless`] back into the exact OS
+/// path, byte for byte.
+pub fn decode_stored_path(stored: &str) -> PathBuf {
+    if !stored.chars().any(is_raw_byte_escape) {
+        return PathBuf::from(stored);
+    }
+
+    let mut bytes = Vec::with_capacity(stored.len());
+    for c in stored.chars() {
+        if is_raw_byte_escape(c) {
+            bytes.push((c as u32 - RAW_BYTE_ESCAPE_BASE) as u8);
+        } else {
+            let mut buf = [0u8; 4];
+            bytes.extend_from_slice(c.encode_utf8(&mut buf).as_bytes());
+        }
+    }
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::ffi::OsStringExt;
+        PathBuf::from(std::ffi::OsString::from_vec(bytes))
+    }
+    #[cfg(not(unix))]
+    {
+        PathBuf::from(String::from_utf8_lossy(&bytes).into_owned())
+    }
+}
+
+/// Resolve a stored workspace-relative path to an absolute filesystem path.
+pub fn stored_path_to_absolute(workspace_root: &Path, stored: &str) -> PathBuf {
+    workspace_root.join(decode_stored_path(stored))
+}
+
+/// Render a stored path for humans: escaped raw bytes are shown as `\xNN`
+/// instead of private-use glyphs. Display-only — never feed the result back
+/// into a database query.
+pub fn display_stored_path(stored: &str) -> String {
+    if !stored.chars().any(is_raw_byte_escape) {
+        return stored.to_string();
+    }
+
+    let mut bytes = Vec::with_capacity(stored.len());
+    let mut display = String::with_capacity(stored.len());
+    let flush = |bytes: &mut Vec<u8>, display: &mut String| {
+        for chunk in bytes.utf8_chunks() {
+            display.push_str(chunk.valid());
+            for byte in chunk.invalid() {
+                display.push_str(&format!("\\x{byte:02X}"));
+            }
+        }
+        bytes.clear();
+    };
+    for c in stored.chars() {
+        if is_raw_byte_escape(c) {
+            bytes.push((c as u32 - RAW_BYTE_ESCAPE_BASE) as u8);
+        } else {
+            flush(&mut bytes, &mut display);
+            display.push(c);
+        }
+    }
+    flush(&mut bytes, &mut display);
+    display
+}
+
 // ──────────────────────────────────────────────────────────────────────────────
 // resolve_workspace_file_input
 // ──────────────────────────────────────────────────────────────────────────────
@@ -0,0 +1,327 @@
+//! C/C++ preprocessor conditions: define sets and `#if` evaluation.
+//!
+//! A [`DefineSet`] is a configuration: the macros assumed defined, with their
+//! values. [`evaluate_condition`] decides an `#if`/`#elif` expression under it
+//! the way the preprocessor would for the simple cases (`defined`, integer
+//! literals, object-like macros, logical, comparison and arithmetic
+//! operators). Anything it cannot decide (function-like macros,
+//! `__has_include`, macros defined as non-numeric text) is unknown rather than
+//! guessed, so callers can keep those branches instead of dropping them.
+//!
+//! Indexing tags C/C++ symbols with the condition guarding them; search
+//! re-evaluates the stored condition against a requested define set.
+
+use std::collections::BTreeMap;
+
+/// How deep macro values are followed (`#define A B`, `#define B 1`).
+const MAX_EXPANSION_DEPTH: usize = 8;
+
+/// Macros assumed defined for one configuration. Macros not in the set are
+/// undefined, which the preprocessor treats as `0` in expressions.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DefineSet {
+    macros: BTreeMap<String, String>,
+}
+
+impl DefineSet {
+    /// Parse `NAME` or `NAME=VALUE` entries, as given to a compiler's `-D`.
+    /// A bare `NAME` is defined as `1`.
+    pub fn parse<I, S>(entries: I) -> Result<Self, String>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let mut defines = Self::default();
+        for entry in entries {
+            let entry = entry.as_ref().trim();
+            let (name, value) = match entry.split_once('=') {
+                Some((name, value)) => (name.trim(), value.trim()),
+                None => (entry, "1"),
+            };
+            if identifier_len(name) == 0 || identifier_len(name) != name.len() {
+                return Err(format!(
+                    "{entry:?} is not a macro definition (expected NAME or NAME=VALUE)"
+                ));
+            }
+            defines.define(name, value);
+        }
+        Ok(defines)
+    }
+
+    pub fn define(&mut self, name: &str, value: &str) {
+        self.macros.insert(name.to_string(), value.to_string());
+    }
+
+    pub fn undefine(&mut self, name: &str) {
+        self.macros.remove(name);
+    }
+
+    pub fn is_defined(&self, name: &str) -> bool {
+        self.macros.contains_key(name)
+    }
+
+    pub fn value(&self, name: &str) -> Option<&str> {
+        self.macros.get(name).map(String::as_str)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.macros.is_empty()
+    }
+}
+
+/// Evaluate an `#if` expression under `defines`. `None` when the result
+/// cannot be decided or the expression is not understood.
+pub fn evaluate_condition(expression: &str, defines: &DefineSet) -> Option<bool> {
+    evaluate(expression, defines, 0).map(|value| value != 0)
+}
+
+fn evaluate(expression: &str, defines: &DefineSet, depth: usize) -> Option<i64> {
+    let tokens = tokenize(expression)?;
+    let mut parser = Parser {
+        tokens: &tokens,
+        pos: 0,
+        defines,
+        depth,
+    };
+    let value = parser.or().ok()?;
+    if parser.pos != tokens.len() {
+        return None;
+    }
+    value
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Ident(String),
+    Number(i64),
+    Op(&'static str),
+}
+
+const OPERATORS: &[&str] = &[
+    "&&", "||", "==", "!=", "<=", ">=", "!", "<", ">", "+", "-", "*", "/", "%", "(", ")",
+];
+
+fn tokenize(expression: &str) -> Option<Vec<Token>> {
+    let mut tokens = Vec::new();
+    let mut rest = expression;
+    while let Some(c) = rest.chars().next() {
+        if c.is_whitespace() {
+            rest = &rest[c.len_utf8()..];
+        } else if rest.starts_with("//") {
+            break;
+        } else if let Some(comment) = rest.strip_prefix("/*") {
+            rest = &comment[comment.find("*/")? + 2..];
+        } else if c.is_ascii_digit() {
+            let len = rest
+                .find(|c: char| !c.is_ascii_alphanumeric())
+                .unwrap_or(rest.len());
+            tokens.push(Token::Number(parse_integer(&rest[..len])?));
+            rest = &rest[len..];
+        } else if identifier_len(rest) > 0 {
+            let len = identifier_len(rest);
+            tokens.push(Token::Ident(rest[..len].to_string()));
+            rest = &rest[len..];
+        } else {
+            let op = OPERATORS.iter().find(|op| rest.starts_with(**op))?;
+            tokens.push(Token::Op(op));
+            rest = &rest[op.len()..];
+        }
+    }
+    Some(tokens)
+}
+
+/// Decimal, hex or octal literal with an optional `u`/`l` suffix.
+fn parse_integer(literal: &str) -> Option<i64> {
+    let digits = literal.trim_end_matches(['u', 'U', 'l', 'L']);
+    if let Some(hex) = digits
+        .strip_prefix("0x")
+        .or_else(|| digits.strip_prefix("0X"))
+    {
+        i64::from_str_radix(hex, 16).ok()
+    } else if digits.len() > 1 && digits.starts_with('0') {
+        i64::from_str_radix(&digits[1..], 8).ok()
+    } else {
+        digits.parse().ok()
+    }
+}
+
+fn identifier_len(text: &str) -> usize {
+    let mut chars = text.char_indices();
+    match chars.next() {
+        Some((_, c)) if c == '_' || c.is_ascii_alphabetic() => {}
+        _ => return 0,
+    }
+    chars
+        .find(|(_, c)| !(*c == '_' || c.is_ascii_alphanumeric()))
+        .map(|(i, _)| i)
+        .unwrap_or(text.len())
+}
+
+/// `Err` is a syntax error; `Ok(None)` a well-formed but undecidable value.
+type Value = Result<Option<i64>, ()>;
+
+struct Parser<'a> {
+    tokens: &'a [Token],
+    pos: usize,
+    defines: &'a DefineSet,
+    depth: usize,
+}
+
+impl Parser<'_> {
+    fn peek_op(&self) -> Option<&'static str> {
+        match self.tokens.get(self.pos) {
+            Some(Token::Op(op)) => Some(op),
+            _ => None,
+        }
+    }
+
+    fn eat(&mut self, op: &str) -> bool {
+        let matched = self.peek_op() == Some(op);
+        if matched {
+            self.pos += 1;
+        }
+        matched
+    }
+
+    fn or(&mut self) -> Value {
+        let mut left = self.and()?;
+        while self.eat("||") {
+            let right = self.and()?;
+            left = match (left, right) {
+                (Some(l), _) if l != 0 => Some(1),
+                (_, Some(r)) if r != 0 => Some(1),
+                (Some(_), Some(_)) => Some(0),
+                _ => None,
+            };
+        }
+        Ok(left)
+    }
+
+    fn and(&mut self) -> Value {
+        let mut left = self.comparison()?;
+        while self.eat("&&") {
+            let right = self.comparison()?;
+            left = match (left, right) {
+                (Some(0), _) | (_, Some(0)) => Some(0),
+                (Some(_), Some(_)) => Some(1),
+                _ => None,
+            };
+        }
+        Ok(left)
+    }
+
+    fn comparison(&mut self) -> Value {
+        let mut left = self.additive()?;
+        while let Some(op @ ("==" | "!=" | "<" | ">" | "<=" | ">=")) = self.peek_op() {
+            self.pos += 1;
+            let right = self.additive()?;
+            left = left.zip(right).map(|(l, r)| {
+                i64::from(match op {
+                    "==" => l == r,
+                    "!=" => l != r,
+                    "<" => l < r,
+                    ">" => l > r,
+                    "<=" => l <= r,
+                    _ => l >= r,
+                })
+            });
+        }
+        Ok(left)
+    }
+
+    fn additive(&mut self) -> Value {
+        let mut left = self.multiplicative()?;
+        while let Some(op @ ("+" | "-")) = self.peek_op() {
+            self.pos += 1;
+            let right = self.multiplicative()?;
+            left = left.zip(right).and_then(|(l, r)| match op {
+                "+" => l.checked_add(r),
+                _ => l.checked_sub(r),
+            });
+        }
+        Ok(left)
+    }
+
+    fn multiplicative(&mut self) -> Value {
+        let mut left = self.unary()?;
+        while let Some(op @ ("*" | "/" | "%")) = self.peek_op() {
+            self.pos += 1;
+            let right = self.unary()?;
+            left = left.zip(right).and_then(|(l, r)| match op {
+                "*" => l.checked_mul(r),
+                "/" => l.checked_div(r),
+                _ => l.checked_rem(r),
+            });
+        }
+        Ok(left)
+    }
+
+    fn unary(&mut self) -> Value {
+        if self.eat("!") {
+            return Ok(self.unary()?.map(|v| i64::from(v == 0)));
+        }
+        if self.eat("-") {
+            return Ok(self.unary()?.and_then(i64::checked_neg));
+        }
+        if self.eat("+") {
+            return self.unary();
+        }
+        self.primary()
+    }
+
+    fn primary(&mut self) -> Value {
+        let token = self.tokens.get(self.pos).ok_or(())?.clone();
+        self.pos += 1;
+        match token {
+            Token::Number(n) => Ok(Some(n)),
+            Token::Op("(") => {
+                let value = self.or()?;
+                if !self.eat(")") {
+                    return Err(());
+                }
+                Ok(value)
+            }
+            Token::Op(_) => Err(()),
+            Token::Ident(name) if name == "defined" => {
+                let parenthesized = self.eat("(");
+                let Some(Token::Ident(name)) = self.tokens.get(self.pos) else {
+                    return Err(());
+                };
+                self.pos += 1;
+                if parenthesized && !self.eat(")") {
+                    return Err(());
+                }
+                Ok(Some(i64::from(self.defines.is_defined(name))))
+            }
+            Token::Ident(_) if self.peek_op() == Some("(") => {
+                // Function-like macro or `__has_include(...)`: skip its
+                // arguments, the result is unknown.
+                let mut depth = 0;
+                loop {
+                    match self.tokens.get(self.pos) {
+                        Some(Token::Op("(")) => depth += 1,
+                        Some(Token::Op(")")) => depth -= 1,
+                        Some(_) => {}
+                        None => return Err(()),
+                    }
+                    self.pos += 1;
+                    if depth == 0 {
+                        return Ok(None);
+                    }
+                }
+            }
+            Token::Ident(name) => Ok(self.macro_value(&name)),
+        }
+    }
+
+    fn macro_value(&self, name: &str) -> Option<i64> {
+        match (name, self.defines.value(name)) {
+            (_, Some(value)) if self.depth < MAX_EXPANSION_DEPTH => {
+                evaluate(value, self.defines, self.depth + 1)
+            }
+            (_, Some(_)) => None,
+            ("true", None) => Some(1),
+            _ => Some(0),
+        }
+    }
+}
@@ -0,0 +1,294 @@
+//! Token-budget-aware response shaping.
+//!
+//! Tools that accept a `max_tokens` parameter render their result rows through
+//! [`BudgetedResponse`]: `fast_search` (one entry per hit), `fast_refs` (per
+//! definition, reference and textual mention) and `get_symbols` (per symbol,
+//! grouped under its top-level symbol rather than its file). When the full
+//! rendering exceeds the budget, the builder degrades in fixed stages:
+//!
+//! 1. drop context lines (code snippets around a match),
+//! 2. drop doc comment lines,
+//! 3. collapse every file down to a single `path (N results)` line,
+//! 4. drop trailing files until the output fits.
+//!
+//! Every stage past the first appends a footer describing what was omitted,
+//! so the agent knows to narrow the query instead of trusting a silently
+//! truncated answer.
+//!
+//! `deep_dive` takes `max_tokens` as well, but as the budget of its own
+//! per-definition truncation, which keeps the similar-symbols section.
+
+use crate::token_estimation::TokenEstimator;
+
+/// One result row: a header (location + one-line summary) plus optional doc
+/// comment and context lines that can be shed under budget pressure.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct BudgetedEntry {
+    /// File the entry belongs to, or another grouping key. Used to collapse
+    /// entries by file.
+    pub file: String,
+    /// Always-kept header. May span multiple lines (e.g. location + signature).
+    pub header: String,
+    /// Doc comment lines, dropped at [`ShapingStage::DroppedDocs`].
+    pub doc_lines: Vec<String>,
+    /// Context/snippet lines, dropped first at [`ShapingStage::DroppedContext`].
+    pub context_lines: Vec<String>,
+}
+
+impl BudgetedEntry {
+    pub fn new(file: impl Into<String>, header: impl Into<String>) -> Self {
+        Self {
+            file: file.into(),
+            header: header.into(),
+            doc_lines: Vec::new(),
+            context_lines: Vec::new(),
+        }
+    }
+
+    pub fn with_doc(mut self, doc: Option<&str>) -> Self {
+        if let Some(doc) = doc {
+            self.doc_lines = non_blank_lines(doc);
+        }
+        self
+    }
+
+    pub fn with_context(mut self, context: Option<&str>) -> Self {
+        if let Some(context) = context {
+            self.context_lines = non_blank_lines(context);
+        }
+        self
+    }
+}
+
+fn non_blank_lines(text: &str) -> Vec<String> {
+    text.lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| line.trim_end().to_string())
+        .collect()
+}
+
+/// The most aggressive shaping stage that was applied to fit the budget.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ShapingStage {
+    /// Everything fit; nothing was omitted.
+    Full,
+    /// Context lines were dropped.
+    DroppedContext,
+    /// Context and doc comment lines were dropped.
+    DroppedDocs,
+    /// Entries were collapsed to one line per file.
+    CollapsedByFile,
+    /// Collapsed, and trailing files were dropped as well.
+    Truncated,
+}
+
+/// Counts of what shaping removed from the response.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct OmissionReport {
+    pub context_lines: usize,
+    pub doc_lines: usize,
+    /// Entries folded into per-file summary lines.
+    pub collapsed_entries: usize,
+    /// Files removed entirely (only at [`ShapingStage::Truncated`]).
+    pub dropped_files: usize,
+}
+
+impl OmissionReport {
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// Human-readable footer line, or `None` when nothing was omitted.
+    pub fn summary(&self, max_tokens: usize) -> Option<String> {
+        if self.is_empty() {
+            return None;
+        }
+        let mut parts = Vec::new();
+        if self.context_lines > 0 {
+            parts.push(format!("{} context lines", self.context_lines));
+        }
+        if self.doc_lines > 0 {
+            parts.push(format!("{} doc comment lines", self.doc_lines));
+        }
+        if self.collapsed_entries > 0 {
+            parts.push(format!(
+                "{} results collapsed by file",
+                self.collapsed_entries
+            ));
+        }
+        if self.dropped_files > 0 {
+            parts.push(format!("{} files dropped", self.dropped_files));
+        }
+        Some(format!(
+            "[budget] Omitted to fit max_tokens={}: {}. Narrow the query or raise max_tokens for more detail.",
+            max_tokens,
+            parts.join(", ")
+        ))
+    }
+}
+
+/// Result of [`BudgetedResponse::render`].
+#[derive(Debug, Clone)]
+pub struct ShapedResponse {
+    pub text: String,
+    pub stage: ShapingStage,
+    pub omitted: OmissionReport,
+}
+
+/// Builder for tool responses that must respect an optional token budget.
+#[derive(Debug, Clone, Default)]
+pub struct BudgetedResponse {
+    heading: String,
+    entries: Vec<BudgetedEntry>,
+    max_tokens: Option<usize>,
+}
+
+impl BudgetedResponse {
+    pub fn new(heading: impl Into<String>) -> Self {
+        Self {
+            heading: heading.into(),
+            entries: Vec::new(),
+            max_tokens: None,
+        }
+    }
+
+    /// Set the token budget. `None` (or zero) disables shaping.
+    pub fn with_max_tokens(mut self, max_tokens: Option<u32>) -> Self {
+        self.max_tokens = max_tokens.filter(|budget| *budget > 0).map(|b| b as usize);
+        self
+    }
+
+    pub fn push(&mut self, entry: BudgetedEntry) {
+        self.entries.push(entry);
+    }
+
+    pub fn entries(&self) -> &[BudgetedEntry] {
+        &self.entries
+    }
+
+    pub fn render(&self) -> ShapedResponse {
+        let full = self.render_entries(true, true);
+        let Some(budget) = self.max_tokens else {
+            return ShapedResponse {
+                text: full,
+                stage: ShapingStage::Full,
+                omitted: OmissionReport::default(),
+            };
+        };
+
+        let estimator = TokenEstimator::new();
+        let fits = |text: &str| estimator.estimate_string(text) <= budget;
+        if fits(&full) {
+            return ShapedResponse {
+                text: full,
+                stage: ShapingStage::Full,
+                omitted: OmissionReport::default(),
+            };
+        }
+
+        let mut omitted = OmissionReport {
+            context_lines: self.entries.iter().map(|e| e.context_lines.len()).sum(),
+            ..OmissionReport::default()
+        };
+        let text = with_footer(self.render_entries(false, true), &omitted, budget);
+        if fits(&text) {
+            return ShapedResponse {
+                text,
+                stage: ShapingStage::DroppedContext,
+                omitted,
+            };
+        }
+
+        omitted.doc_lines = self.entries.iter().map(|e| e.doc_lines.len()).sum();
+        let text = with_footer(self.render_entries(false, false), &omitted, budget);
+        if fits(&text) {
+            return ShapedResponse {
+                text,
+                stage: ShapingStage::DroppedDocs,
+                omitted,
+            };
+        }
+
+        let groups = self.file_groups();
+        omitted.collapsed_entries = self.entries.len();
+        let text = with_footer(self.render_collapsed(&groups), &omitted, budget);
+        if fits(&text) || groups.len() <= 1 {
+            return ShapedResponse {
+                text,
+                stage: ShapingStage::CollapsedByFile,
+                omitted,
+            };
+        }
+
+        // Keep as many leading (highest-ranked) files as fit; always keep one.
+        let mut kept = groups.len();
+        let mut text = text;
+        while kept > 1 {
+            kept -= 1;
+            omitted.dropped_files = groups.len() - kept;
+            text = with_footer(self.render_collapsed(&groups[..kept]), &omitted, budget);
+            if fits(&text) {
+                break;
+            }
+        }
+        ShapedResponse {
+            text,
+            stage: ShapingStage::Truncated,
+            omitted,
+        }
+    }
+
+    fn render_entries(&self, include_context: bool, include_docs: bool) -> String {
+        let mut output = self.heading.clone();
+        if !output.is_empty() {
+            output.push_str("\n\n");
+        }
+        for entry in &self.entries {
+            output.push_str(&entry.header);
+            output.push('\n');
+            if include_docs {
+                for line in &entry.doc_lines {
+                    output.push_str(&format!("  {}\n", line.trim()));
+                }
+            }
+            if include_context {
+                for line in &entry.context_lines {
+                    output.push_str(&format!("  {}\n", line));
+                }
+            }
+            output.push('\n');
+        }
+        output.trim_end().to_string()
+    }
+
+    /// Group entries by file preserving the order of first appearance.
+    fn file_groups(&self) -> Vec<(&str, usize)> {
+        let mut groups: Vec<(&str, usize)> = Vec::new();
+        for entry in &self.entries {
+            match groups.iter_mut().find(|(file, _)| *file == entry.file) {
+                Some(group) => group.1 += 1,
+                None => groups.push((entry.file.as_str(), 1)),
+            }
+        }
+        groups
+    }
+
+    fn render_collapsed(&self, groups: &[(&str, usize)]) -> String {
+        let mut output = self.heading.clone();
+        if !output.is_empty() {
+            output.push_str("\n\n");
+        }
+        for (file, count) in groups {
+            let noun = if *count == 1 { "result" } else { "results" };
+            output.push_str(&format!("{} ({} {})\n", file, count, noun));
+        }
+        output.trim_end().to_string()
+    }
+}
+
+fn with_footer(text: String, omitted: &OmissionReport, budget: usize) -> String {
+    match omitted.summary(budget) {
+        Some(footer) => format!("{text}\n\n{footer}"),
+        None => text,
+    }
+}
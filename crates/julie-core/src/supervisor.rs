@@ -0,0 +1,239 @@
+//! Process-wide watchdog for background subsystems.
+//!
+//! A panic in background work (the embedding engine, a parser thread, the
+//! file watcher's loops) used to be logged and forgotten, leaving the server
+//! half working: the watcher stopped updating, or a workspace never finished
+//! embedding. Owners now run that work through [`Supervisor::supervise`],
+//! which catches the panic, counts it, and restarts the work with backoff
+//! until its [`RestartPolicy`] runs out. Code that recovers on its own (a
+//! crashed parser degrades the file to text-only) only reports the panic
+//! through [`Supervisor::record_panic`].
+//!
+//! The counters never reset while the process lives; health diagnostics
+//! report them per subsystem.
+
+use std::any::Any;
+use std::fmt;
+use std::future::Future;
+use std::sync::{Mutex, MutexGuard, OnceLock};
+use std::time::{Duration, SystemTime};
+
+use tokio::task::JoinError;
+use tracing::{error, warn};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Subsystem {
+    Embeddings,
+    Parser,
+    Watcher,
+}
+
+impl Subsystem {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Embeddings => "embeddings",
+            Self::Parser => "parser",
+            Self::Watcher => "watcher",
+        }
+    }
+}
+
+impl fmt::Display for Subsystem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// How often a supervised task is restarted after panicking.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RestartPolicy {
+    /// Restarts before the supervisor gives up.
+    pub max_restarts: u32,
+    /// Delay before the first restart; doubles for each further one.
+    pub backoff: Duration,
+}
+
+impl RestartPolicy {
+    pub const fn new(max_restarts: u32, backoff: Duration) -> Self {
+        Self {
+            max_restarts,
+            backoff,
+        }
+    }
+
+    fn delay(&self, restart: u32) -> Duration {
+        self.backoff
+            .saturating_mul(1 << restart.saturating_sub(1).min(16))
+    }
+}
+
+/// Why a supervised task did not produce a result.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SupervisorExit {
+    /// The task was aborted (shutdown), not crashed.
+    Cancelled,
+    /// The task panicked more often than its policy allows. Carries the last
+    /// panic message.
+    GaveUp(String),
+}
+
+impl fmt::Display for SupervisorExit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Cancelled => write!(f, "cancelled"),
+            Self::GaveUp(message) => write!(f, "kept panicking: {message}"),
+        }
+    }
+}
+
+impl std::error::Error for SupervisorExit {}
+
+/// The most recent panic of a subsystem.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PanicEvent {
+    /// The task that panicked, e.g. a workspace id or file path.
+    pub task: String,
+    pub message: String,
+    pub at: SystemTime,
+    /// Whether the subsystem was restarted (or otherwise recovered) afterwards.
+    pub recovered: bool,
+}
+
+/// Panic and restart counts of one subsystem.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SubsystemRecovery {
+    pub subsystem: Subsystem,
+    pub panics: u64,
+    pub restarts: u64,
+    /// Panics after which the subsystem stayed down.
+    pub unrecovered: u64,
+    pub last_panic: PanicEvent,
+}
+
+/// Registry of background panics and restarts.
+///
+/// Production code uses the process-wide [`Supervisor::global`]; tests create
+/// their own instance for isolation.
+#[derive(Debug, Default)]
+pub struct Supervisor {
+    subsystems: Mutex<Vec<SubsystemRecovery>>,
+}
+
+impl Supervisor {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn global() -> &'static Supervisor {
+        static GLOBAL: OnceLock<Supervisor> = OnceLock::new();
+        GLOBAL.get_or_init(Supervisor::new)
+    }
+
+    fn subsystems(&self) -> MutexGuard<'_, Vec<SubsystemRecovery>> {
+        self.subsystems
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    /// Count a panic in `subsystem`. `recovered` says whether the subsystem
+    /// keeps running (restarted, or degraded gracefully) after it.
+    pub fn record_panic(&self, subsystem: Subsystem, task: &str, message: &str, recovered: bool) {
+        error!(
+            subsystem = subsystem.as_str(),
+            task, message, recovered, "Background task panicked"
+        );
+        let event = PanicEvent {
+            task: task.to_string(),
+            message: message.to_string(),
+            at: SystemTime::now(),
+            recovered,
+        };
+        let mut subsystems = self.subsystems();
+        let index = match subsystems
+            .iter()
+            .position(|entry| entry.subsystem == subsystem)
+        {
+            Some(index) => index,
+            None => {
+                subsystems.push(SubsystemRecovery {
+                    subsystem,
+                    panics: 0,
+                    restarts: 0,
+                    unrecovered: 0,
+                    last_panic: event.clone(),
+                });
+                subsystems.sort_by_key(|entry| entry.subsystem);
+                subsystems
+                    .iter()
+                    .position(|entry| entry.subsystem == subsystem)
+                    .expect("just inserted")
+            }
+        };
+        let entry = &mut subsystems[index];
+        entry.panics += 1;
+        if recovered {
+            entry.restarts += 1;
+        } else {
+            entry.unrecovered += 1;
+        }
+        entry.last_panic = event;
+    }
+
+    /// Subsystems that have panicked at least once, in [`Subsystem`] order.
+    pub fn snapshot(&self) -> Vec<SubsystemRecovery> {
+        self.subsystems().clone()
+    }
+
+    /// Run the task `start` spawns, restarting it per `policy` when it
+    /// panics. `start` is called once per attempt and returns the task's
+    /// `JoinHandle` (from `tokio::spawn` or `spawn_blocking`), so each restart
+    /// begins from fresh state.
+    pub async fn supervise<T, F, Fut>(
+        &self,
+        subsystem: Subsystem,
+        task: &str,
+        policy: RestartPolicy,
+        mut start: F,
+    ) -> Result<T, SupervisorExit>
+    where
+        F: FnMut() -> Fut,
+        Fut: Future<Output = Result<T, JoinError>>,
+    {
+        let mut restarts = 0;
+        loop {
+            match start().await {
+                Ok(value) => return Ok(value),
+                Err(err) if err.is_cancelled() => return Err(SupervisorExit::Cancelled),
+                Err(err) => {
+                    let message = panic_message(err.into_panic());
+                    let restart = restarts < policy.max_restarts;
+                    self.record_panic(subsystem, task, &message, restart);
+                    if !restart {
+                        return Err(SupervisorExit::GaveUp(message));
+                    }
+                    restarts += 1;
+                    let delay = policy.delay(restarts);
+                    warn!(
+                        subsystem = subsystem.as_str(),
+                        task,
+                        restart = restarts,
+                        delay_ms = delay.as_millis() as u64,
+                        "Restarting background task after panic"
+                    );
+                    tokio::time::sleep(delay).await;
+                }
+            }
+        }
+    }
+}
+
+/// The message a panic was raised with, when it is a string.
+pub fn panic_message(payload: Box<dyn Any + Send>) -> String {
+    match payload.downcast::<String>() {
+        Ok(message) => *message,
+        Err(payload) => payload
+            .downcast_ref::<&str>()
+            .map(|message| message.to_string())
+            .unwrap_or_else(|| "non-string panic payload".to_string()),
+    }
+}
@@ -0,0 +1,124 @@
+//! Deterministic symbol IDs that survive re-indexing.
+//!
+//! Extractors derive IDs from source positions, so inserting a line above a
+//! function gave it a new ID on the next index and orphaned anything stored
+//! against the old one (embeddings, relationships held by external tools).
+//! Stable IDs hash what identifies a symbol instead of where it sits:
+//!
+//! - the file path (relative to the workspace root),
+//! - the qualified name, built from the parent chain (`Widget::render`),
+//! - the kind,
+//! - the whitespace-normalized signature, so overloads stay distinct.
+//!
+//! Symbols that collide on all four (two anonymous closures in one function)
+//! are numbered in source order. The workspace itself is not hashed: every
+//! database holds one workspace, and workspace IDs change when a directory
+//! moves, which is exactly when IDs should not.
+
+use std::collections::HashMap;
+
+use julie_extractors::Symbol;
+
+/// The identifying fields of one symbol, as stored or extracted.
+#[derive(Debug, Clone, Copy)]
+pub struct SymbolIdKey<'a> {
+    pub id: &'a str,
+    pub parent_id: Option<&'a str>,
+    pub name: &'a str,
+    pub kind: &'a str,
+    pub signature: Option<&'a str>,
+    pub file_path: &'a str,
+    pub start_byte: u32,
+}
+
+/// Stable IDs for `keys`, keyed by current ID. Parents must be in `keys` to
+/// contribute to the qualified name; missing parents are skipped.
+pub fn stable_symbol_ids(keys: &[SymbolIdKey<'_>]) -> HashMap<String, String> {
+    let by_id: HashMap<&str, &SymbolIdKey<'_>> = keys.iter().map(|key| (key.id, key)).collect();
+
+    let mut sources: Vec<(String, u32, &str)> = keys
+        .iter()
+        .map(|key| {
+            let signature = key
+                .signature
+                .map(|signature| signature.split_whitespace().collect::<Vec<_>>().join(" "))
+                .unwrap_or_default();
+            let source = format!(
+                "{}\u{0}{}\u{0}{}\u{0}{}",
+                key.file_path,
+                qualified_name(key, &by_id),
+                key.kind,
+                signature
+            );
+            (source, key.start_byte, key.id)
+        })
+        .collect();
+    // Source order decides which duplicate keeps the plain ID.
+    sources.sort();
+
+    let mut seen: HashMap<String, usize> = HashMap::new();
+    sources
+        .into_iter()
+        .map(|(source, _, id)| {
+            let count = seen.entry(source.clone()).or_insert(0);
+            *count += 1;
+            let source = if *count == 1 {
+                source
+            } else {
+                format!("{source}#{count}")
+            };
+            let stable = blake3::hash(source.as_bytes()).to_hex()[..32].to_string();
+            (id.to_string(), stable)
+        })
+        .collect()
+}
+
+/// Replace the IDs of `symbols` (and their `parent_id` links) with stable
+/// ones. Returns the old → new mapping so callers can rewrite other rows
+/// that reference the symbols.
+pub fn assign_stable_symbol_ids(symbols: &mut [Symbol]) -> HashMap<String, String> {
+    let kinds: Vec<String> = symbols
+        .iter()
+        .map(|symbol| symbol.kind.to_string())
+        .collect();
+    let keys: Vec<SymbolIdKey<'_>> = symbols
+        .iter()
+        .zip(&kinds)
+        .map(|(symbol, kind)| SymbolIdKey {
+            id: &symbol.id,
+            parent_id: symbol.parent_id.as_deref(),
+            name: &symbol.name,
+            kind,
+            signature: symbol.signature.as_deref(),
+            file_path: &symbol.file_path,
+            start_byte: symbol.start_byte,
+        })
+        .collect();
+    let ids = stable_symbol_ids(&keys);
+
+    for symbol in symbols.iter_mut() {
+        if let Some(stable) = ids.get(&symbol.id) {
+            symbol.id = stable.clone();
+        }
+        if let Some(stable) = symbol.parent_id.as_ref().and_then(|id| ids.get(id)) {
+            symbol.parent_id = Some(stable.clone());
+        }
+    }
+    ids
+}
+
+/// Names from the outermost ancestor down to `key`, joined with `::`.
+fn qualified_name(key: &SymbolIdKey<'_>, by_id: &HashMap<&str, &SymbolIdKey<'_>>) -> String {
+    let mut names = vec![key.name];
+    let mut parent_id = key.parent_id;
+    while let Some(parent) = parent_id.and_then(|id| by_id.get(id)) {
+        // Guard against malformed parent cycles.
+        if names.len() > by_id.len() {
+            break;
+        }
+        names.push(parent.name);
+        parent_id = parent.parent_id;
+    }
+    names.reverse();
+    names.join("::")
+}
@@ -0,0 +1,374 @@
+//! Opt-in local telemetry: counters and latency histograms for tool calls,
+//! extraction per language, and database writes.
+//!
+//! Nothing is recorded until a workspace sets `[telemetry] enabled = true` in
+//! `.julie/config.toml`. The process-wide [`Telemetry::global`] then writes
+//! its snapshot to `.julie/metrics.prom` (Prometheus text exposition) or
+//! `.julie/metrics.json` in every opted-in workspace, at most once per
+//! [`FLUSH_INTERVAL`]. Nothing leaves the machine: point a local scraper or
+//! `cat` at the file to see where a slow workspace spends its time.
+
+use std::collections::{BTreeMap, HashMap};
+use std::fmt::Write as _;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Mutex, MutexGuard, OnceLock};
+use std::time::{Duration, Instant};
+
+use anyhow::Result;
+use serde::Serialize;
+use tracing::warn;
+
+/// Upper bounds, in milliseconds, of the latency histogram buckets. A final
+/// `+Inf` bucket catches everything slower.
+pub const LATENCY_BUCKETS_MS: &[f64] = &[
+    1.0, 5.0, 10.0, 25.0, 50.0, 100.0, 250.0, 500.0, 1000.0, 2500.0, 5000.0, 10000.0,
+];
+
+/// Minimum time between two writes of the metrics file.
+pub const FLUSH_INTERVAL: Duration = Duration::from_secs(10);
+
+/// File format of the metrics snapshot.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TelemetryFormat {
+    Prometheus,
+    Json,
+}
+
+impl TelemetryFormat {
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "prometheus" => Some(Self::Prometheus),
+            "json" => Some(Self::Json),
+            _ => None,
+        }
+    }
+
+    /// File name of the snapshot inside `.julie/`.
+    pub fn file_name(self) -> &'static str {
+        match self {
+            Self::Prometheus => "metrics.prom",
+            Self::Json => "metrics.json",
+        }
+    }
+}
+
+/// Where and how one workspace wants the snapshot written.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TelemetrySink {
+    pub path: PathBuf,
+    pub format: TelemetryFormat,
+}
+
+/// Count, failures and latency distribution of one labelled operation.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct LatencySeries {
+    pub count: u64,
+    pub errors: u64,
+    pub total_ms: f64,
+    pub max_ms: f64,
+    /// Observations per bucket of [`LATENCY_BUCKETS_MS`], plus the `+Inf`
+    /// bucket last. Not cumulative.
+    pub buckets: Vec<u64>,
+}
+
+impl Default for LatencySeries {
+    fn default() -> Self {
+        Self {
+            count: 0,
+            errors: 0,
+            total_ms: 0.0,
+            max_ms: 0.0,
+            buckets: vec![0; LATENCY_BUCKETS_MS.len() + 1],
+        }
+    }
+}
+
+impl LatencySeries {
+    fn observe(&mut self, duration: Duration, success: bool) {
+        let ms = duration.as_secs_f64() * 1000.0;
+        self.count += 1;
+        if !success {
+            self.errors += 1;
+        }
+        self.total_ms += ms;
+        self.max_ms = self.max_ms.max(ms);
+        let bucket = LATENCY_BUCKETS_MS
+            .iter()
+            .position(|bound| ms <= *bound)
+            .unwrap_or(LATENCY_BUCKETS_MS.len());
+        self.buckets[bucket] += 1;
+    }
+}
+
+/// Everything recorded so far, keyed by tool name, language and database
+/// operation.
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct TelemetrySnapshot {
+    pub uptime_secs: u64,
+    pub tools: BTreeMap<String, LatencySeries>,
+    pub extraction: BTreeMap<String, LatencySeries>,
+    pub database: BTreeMap<String, LatencySeries>,
+}
+
+impl TelemetrySnapshot {
+    pub fn to_json(&self) -> Result<String> {
+        Ok(serde_json::to_string_pretty(self)?)
+    }
+
+    /// Prometheus text exposition format (version 0.0.4).
+    pub fn to_prometheus(&self) -> String {
+        let mut out = String::new();
+        let _ = writeln!(
+            out,
+            "# HELP julie_uptime_seconds Seconds since telemetry started.\n\
+             # TYPE julie_uptime_seconds gauge\n\
+             julie_uptime_seconds {}",
+            self.uptime_secs
+        );
+        write_family(
+            &mut out,
+            "julie_tool_call",
+            "Tool call latency in milliseconds.",
+            "tool",
+            &self.tools,
+            true,
+        );
+        write_family(
+            &mut out,
+            "julie_extraction",
+            "Per-file extraction time in milliseconds.",
+            "language",
+            &self.extraction,
+            false,
+        );
+        write_family(
+            &mut out,
+            "julie_db_write",
+            "Database write transaction time in milliseconds.",
+            "operation",
+            &self.database,
+            false,
+        );
+        out
+    }
+}
+
+fn write_family(
+    out: &mut String,
+    name: &str,
+    help: &str,
+    label: &str,
+    series: &BTreeMap<String, LatencySeries>,
+    with_errors: bool,
+) {
+    if series.is_empty() {
+        return;
+    }
+    let _ = writeln!(out, "# HELP {name}_ms {help}");
+    let _ = writeln!(out, "# TYPE {name}_ms histogram");
+    for (key, stats) in series {
+        let key = escape_label(key);
+        let mut cumulative = 0;
+        for (bound, count) in LATENCY_BUCKETS_MS.iter().zip(&stats.buckets) {
+            cumulative += count;
+            let _ = writeln!(
+                out,
+                "{name}_ms_bucket{{{label}=\"{key}\",le=\"{bound}\"}} {cumulative}"
+            );
+        }
+        let _ = writeln!(
+            out,
+            "{name}_ms_bucket{{{label}=\"{key}\",le=\"+Inf\"}} {}",
+            stats.count
+        );
+        let _ = writeln!(out, "{name}_ms_sum{{{label}=\"{key}\"}} {}", stats.total_ms);
+        let _ = writeln!(out, "{name}_ms_count{{{label}=\"{key}\"}} {}", stats.count);
+    }
+    if with_errors {
+        let _ = writeln!(out, "# HELP {name}_errors_total Failed calls.");
+        let _ = writeln!(out, "# TYPE {name}_errors_total counter");
+        for (key, stats) in series {
+            let _ = writeln!(
+                out,
+                "{name}_errors_total{{{label}=\"{}\"}} {}",
+                escape_label(key),
+                stats.errors
+            );
+        }
+    }
+}
+
+fn escape_label(value: &str) -> String {
+    value
+        .replace('\\', r"\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
+
+#[derive(Clone, Copy)]
+enum Family {
+    Tools,
+    Extraction,
+    Database,
+}
+
+#[derive(Default)]
+struct TelemetryState {
+    tools: BTreeMap<String, LatencySeries>,
+    extraction: BTreeMap<String, LatencySeries>,
+    database: BTreeMap<String, LatencySeries>,
+    sinks: HashMap<PathBuf, TelemetrySink>,
+    last_flush: Option<Instant>,
+}
+
+impl TelemetryState {
+    fn series(&mut self, family: Family) -> &mut BTreeMap<String, LatencySeries> {
+        match family {
+            Family::Tools => &mut self.tools,
+            Family::Extraction => &mut self.extraction,
+            Family::Database => &mut self.database,
+        }
+    }
+}
+
+/// Process-wide metrics registry.
+///
+/// Production code uses [`Telemetry::global`]; tests create their own
+/// instance for isolation.
+pub struct Telemetry {
+    enabled: AtomicBool,
+    started: Instant,
+    state: Mutex<TelemetryState>,
+}
+
+impl Default for Telemetry {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Telemetry {
+    pub fn new() -> Self {
+        Self {
+            enabled: AtomicBool::new(false),
+            started: Instant::now(),
+            state: Mutex::new(TelemetryState::default()),
+        }
+    }
+
+    pub fn global() -> &'static Telemetry {
+        static GLOBAL: OnceLock<Telemetry> = OnceLock::new();
+        GLOBAL.get_or_init(Telemetry::new)
+    }
+
+    /// Set or clear the sink of `workspace_root`. Recording is on while at
+    /// least one workspace has a sink.
+    pub fn configure_workspace(&self, workspace_root: &Path, sink: Option<TelemetrySink>) {
+        let mut state = self.lock();
+        match sink {
+            Some(sink) => {
+                state.sinks.insert(workspace_root.to_path_buf(), sink);
+            }
+            None => {
+                state.sinks.remove(workspace_root);
+            }
+        }
+        self.enabled
+            .store(!state.sinks.is_empty(), Ordering::Relaxed);
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.enabled.load(Ordering::Relaxed)
+    }
+
+    pub fn record_tool_call(&self, tool: &str, duration: Duration, success: bool) {
+        self.record(Family::Tools, tool, duration, success);
+    }
+
+    pub fn record_extraction(&self, language: &str, duration: Duration) {
+        self.record(Family::Extraction, language, duration, true);
+    }
+
+    pub fn record_db_write(&self, operation: &str, duration: Duration) {
+        self.record(Family::Database, operation, duration, true);
+    }
+
+    fn record(&self, family: Family, key: &str, duration: Duration, success: bool) {
+        if !self.is_enabled() {
+            return;
+        }
+        let flush_due = {
+            let mut state = self.lock();
+            let series = state.series(family);
+            match series.get_mut(key) {
+                Some(stats) => stats.observe(duration, success),
+                None => {
+                    let mut stats = LatencySeries::default();
+                    stats.observe(duration, success);
+                    series.insert(key.to_string(), stats);
+                }
+            }
+            state
+                .last_flush
+                .is_none_or(|last| last.elapsed() >= FLUSH_INTERVAL)
+        };
+        if flush_due {
+            self.flush();
+        }
+    }
+
+    pub fn snapshot(&self) -> TelemetrySnapshot {
+        let state = self.lock();
+        TelemetrySnapshot {
+            uptime_secs: self.started.elapsed().as_secs(),
+            tools: state.tools.clone(),
+            extraction: state.extraction.clone(),
+            database: state.database.clone(),
+        }
+    }
+
+    /// Write the snapshot to every configured sink now. Failures are logged;
+    /// telemetry never fails the operation it measures.
+    pub fn flush(&self) {
+        let sinks: Vec<TelemetrySink> = {
+            let mut state = self.lock();
+            state.last_flush = Some(Instant::now());
+            state.sinks.values().cloned().collect()
+        };
+        if sinks.is_empty() {
+            return;
+        }
+        let snapshot = self.snapshot();
+        for sink in sinks {
+            if let Err(err) = write_snapshot(&snapshot, &sink) {
+                warn!(
+                    "Failed to write metrics to {}: {}",
+                    sink.path.display(),
+                    err
+                );
+            }
+        }
+    }
+
+    fn lock(&self) -> MutexGuard<'_, TelemetryState> {
+        self.state
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+/// Write through a temporary file so a scraper never reads half a snapshot.
+fn write_snapshot(snapshot: &TelemetrySnapshot, sink: &TelemetrySink) -> Result<()> {
+    let text = match sink.format {
+        TelemetryFormat::Prometheus => snapshot.to_prometheus(),
+        TelemetryFormat::Json => snapshot.to_json()?,
+    };
+    if let Some(parent) = sink.path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    let tmp = sink.path.with_extension("tmp");
+    std::fs::write(&tmp, text)?;
+    std::fs::rename(&tmp, &sink.path)?;
+    Ok(())
+}
@@ -448,6 +448,11 @@ impl IdentifierBuilder {
         self
     }
 
+    pub fn code_context(mut self, code_context: impl Into<String>) -> Self {
+        self.code_context = Some(code_context.into());
+        self
+    }
+
     pub fn confidence(mut self, confidence: f32) -> Self {
         self.confidence = confidence;
         self
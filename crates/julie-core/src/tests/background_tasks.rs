@@ -0,0 +1,120 @@
+//! Tests for the background task registry (`background_tasks`).
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use crate::background_tasks::{BackgroundTaskKind, BackgroundTaskRegistry, TaskControlError};
+
+#[test]
+fn dropped_tasks_disappear_from_snapshot() {
+    let registry = BackgroundTaskRegistry::new();
+    let indexing = registry.register(BackgroundTaskKind::Indexing, "ws_a", "indexing", false);
+    let embedding = registry.register(BackgroundTaskKind::Embedding, "ws_a", "embedding", true);
+
+    let ids: Vec<u64> = registry.snapshot().iter().map(|task| task.id).collect();
+    assert_eq!(ids, vec![indexing.id(), embedding.id()]);
+
+    drop(indexing);
+    let remaining = registry.snapshot();
+    assert_eq!(remaining.len(), 1);
+    assert_eq!(remaining[0].kind, BackgroundTaskKind::Embedding);
+}
+
+#[test]
+fn progress_reports_percent_and_eta() {
+    let registry = BackgroundTaskRegistry::new();
+    let task = registry.register(BackgroundTaskKind::Embedding, "ws", "embedding", true);
+
+    let snapshot = task.snapshot();
+    assert_eq!(snapshot.total, None);
+    assert_eq!(snapshot.eta, None, "no ETA before the total is known");
+
+    task.set_total(200);
+    assert_eq!(task.snapshot().eta, None, "no ETA before any progress");
+
+    std::thread::sleep(Duration::from_millis(20));
+    task.advance(50);
+    let snapshot = task.snapshot();
+    assert_eq!(snapshot.completed, 50);
+    assert_eq!(snapshot.percent(), Some(25.0));
+    let eta = snapshot.eta.expect("ETA once progress is made");
+    assert!(
+        eta >= Duration::from_millis(50),
+        "150 remaining at >= 0.4ms per item, got {eta:?}"
+    );
+}
+
+#[test]
+fn pause_and_resume_pausable_task() {
+    let registry = BackgroundTaskRegistry::new();
+    let task = registry.register(BackgroundTaskKind::Embedding, "ws", "embedding", true);
+    task.set_total(10);
+    task.advance(5);
+
+    let paused = registry.set_paused(task.id(), true).unwrap();
+    assert!(paused.paused);
+    assert!(task.is_paused());
+    assert_eq!(paused.eta, None, "paused tasks have no ETA");
+
+    let resumed = registry.set_paused(task.id(), false).unwrap();
+    assert!(!resumed.paused);
+    assert!(resumed.eta.is_some());
+}
+
+#[test]
+fn pause_rejects_unknown_and_unpausable_tasks() {
+    let registry = BackgroundTaskRegistry::new();
+    let task = registry.register(BackgroundTaskKind::Indexing, "ws", "indexing", false);
+
+    assert_eq!(
+        registry.set_paused(task.id(), true),
+        Err(TaskControlError::NotPausable(task.id()))
+    );
+    assert!(!task.is_paused());
+    assert_eq!(
+        registry.set_paused(999, true),
+        Err(TaskControlError::NotFound(999))
+    );
+}
+
+#[test]
+fn wait_while_paused_returns_on_resume_and_on_cancel() {
+    let registry = Arc::new(BackgroundTaskRegistry::new());
+    let task = registry.register(BackgroundTaskKind::Embedding, "ws", "embedding", true);
+    registry.set_paused(task.id(), true).unwrap();
+
+    let waiter = {
+        let task = Arc::clone(&task);
+        std::thread::spawn(move || task.wait_while_paused(None))
+    };
+    std::thread::sleep(Duration::from_millis(50));
+    assert!(!waiter.is_finished(), "waiter must block while paused");
+    registry.set_paused(task.id(), false).unwrap();
+    waiter.join().unwrap();
+
+    registry.set_paused(task.id(), true).unwrap();
+    let cancel = std::sync::atomic::AtomicBool::new(true);
+    task.wait_while_paused(Some(&cancel));
+    assert!(task.is_paused(), "cancel returns without resuming");
+}
+
+#[test]
+fn track_backlog_restarts_window_after_drain() {
+    let registry = BackgroundTaskRegistry::new();
+    let task = registry.register(BackgroundTaskKind::Watcher, "ws", "file watcher", true);
+
+    task.track_backlog(4);
+    task.advance(1);
+    task.track_backlog(5);
+    let snapshot = task.snapshot();
+    assert_eq!((snapshot.completed, snapshot.total), (1, Some(6)));
+
+    task.advance(5);
+    task.track_backlog(2);
+    let snapshot = task.snapshot();
+    assert_eq!(
+        (snapshot.completed, snapshot.total),
+        (0, Some(2)),
+        "a drained backlog starts a new window"
+    );
+}
@@ -156,6 +156,22 @@ fn test_bulk_store_types() {
     // Verify generic_params JSON
     let params: Vec<String> = serde_json::from_str(&generic_params.unwrap()).unwrap();
     assert_eq!(params, vec!["T"]);
+
+    // Read back as TypeInfo, JSON columns decoded
+    let infos = db
+        .get_type_infos_for_symbols(&["symbol2".to_string(), "missing".to_string()])
+        .unwrap();
+    assert_eq!(infos.len(), 1, "symbols without a type row are absent");
+    let info = &infos["symbol2"];
+    assert_eq!(info.resolved_type, "Promise<T>");
+    assert_eq!(info.generic_params, Some(vec!["T".to_string()]));
+    assert_eq!(info.constraints, None);
+    assert!(info.is_inferred);
+    assert_eq!(info.language, "typescript");
+    assert_eq!(
+        info.metadata.as_ref().unwrap()["async"],
+        serde_json::json!(true)
+    );
 }
 
 #[test]
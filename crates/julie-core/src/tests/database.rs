@@ -16,14 +16,23 @@ use tree_sitter::Parser;
 
 mod basic_storage;
 mod concurrency_wal;
+mod context_store;
 mod deweighting;
+mod embedding_generations;
 mod embeddings;
+mod encryption;
 mod extractor_symbols;
+mod file_annotations;
 mod file_queries;
+mod generation_diffs;
 mod identifier_centrality;
 mod identifier_queries;
 mod migrations;
+mod operation_journal;
 mod reference_scores_basic;
 mod reference_scores_propagation;
 mod relationships;
+mod soft_deletes;
+mod stored_paths;
+mod symbol_copies;
 mod symbol_lookup;
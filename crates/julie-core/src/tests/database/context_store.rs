@@ -0,0 +1,142 @@
+use super::*;
+
+const RENDER_BODY: &str = "fn render(&self) -> String {\n    self.title.clone()\n}";
+
+fn blob_count(db: &SymbolDatabase) -> i64 {
+    db.conn
+        .query_row("SELECT COUNT(*) FROM context_blobs", [], |row| row.get(0))
+        .unwrap()
+}
+
+fn inline_context(db: &SymbolDatabase, id: &str) -> Option<String> {
+    db.conn
+        .query_row(
+            "SELECT code_context FROM symbols WHERE id = ?1",
+            [id],
+            |row| row.get(0),
+        )
+        .unwrap()
+}
+
+#[test]
+fn test_code_context_round_trips_through_deduplicated_blobs() {
+    let temp_dir = TempDir::new().unwrap();
+    let mut db = SymbolDatabase::new(temp_dir.path().join("test.db")).unwrap();
+    let files = [
+        file_info_builder("src/a.rs").build(),
+        file_info_builder("vendor/a.rs").build(),
+    ];
+    let symbols = [
+        symbol_builder("a", "render", "src/a.rs")
+            .code_context(RENDER_BODY)
+            .build(),
+        // A vendored copy shares the blob.
+        symbol_builder("b", "render", "vendor/a.rs")
+            .code_context(RENDER_BODY)
+            .build(),
+        symbol_builder("c", "bare", "src/a.rs").build(),
+    ];
+    db.bulk_store_fresh_atomic(&files, &symbols, &[], &[], &[], "primary")
+        .unwrap();
+
+    assert_eq!(blob_count(&db), 1);
+    assert_eq!(inline_context(&db, "a"), None);
+    for id in ["a", "b"] {
+        let symbol = db.get_symbol_by_id(id).unwrap().unwrap();
+        assert_eq!(symbol.code_context.as_deref(), Some(RENDER_BODY));
+    }
+    let bare = db.get_symbol_by_id("c").unwrap().unwrap();
+    assert_eq!(bare.code_context, None);
+}
+
+#[test]
+fn test_incremental_update_prunes_unreferenced_blobs() {
+    let temp_dir = TempDir::new().unwrap();
+    let mut db = SymbolDatabase::new(temp_dir.path().join("test.db")).unwrap();
+    let file = file_info_builder("src/a.rs").build();
+    let old = symbol_builder("a", "render", "src/a.rs")
+        .code_context(RENDER_BODY)
+        .build();
+    db.bulk_store_fresh_atomic(&[file.clone()], &[old], &[], &[], &[], "primary")
+        .unwrap();
+
+    let new = symbol_builder("a", "render", "src/a.rs")
+        .code_context("fn render(&self) -> String { String::new() }")
+        .build();
+    db.incremental_update_atomic(
+        &["src/a.rs".to_string()],
+        &[file],
+        &[new],
+        &[],
+        &[],
+        &[],
+        "primary",
+    )
+    .unwrap();
+
+    assert_eq!(blob_count(&db), 1);
+    let symbol = db.get_symbol_by_id("a").unwrap().unwrap();
+    assert_eq!(
+        symbol.code_context.as_deref(),
+        Some("fn render(&self) -> String { String::new() }")
+    );
+
+    db.delete_symbols_for_file("src/a.rs").unwrap();
+    assert_eq!(blob_count(&db), 0);
+}
+
+#[test]
+fn test_context_storage_stats_count_each_symbol_once() {
+    let temp_dir = TempDir::new().unwrap();
+    let mut db = SymbolDatabase::new(temp_dir.path().join("test.db")).unwrap();
+    let body = RENDER_BODY.repeat(50);
+    db.store_file_info(&file_info_builder("src/a.rs").build())
+        .unwrap();
+    db.store_symbols(&[
+        symbol_builder("a", "render", "src/a.rs")
+            .code_context(body.clone())
+            .build(),
+        symbol_builder("b", "render_again", "src/a.rs")
+            .code_context(body.clone())
+            .build(),
+    ])
+    .unwrap();
+
+    let stats = db.get_context_storage_stats().unwrap();
+    assert_eq!(stats.symbols, 2);
+    assert_eq!(stats.blobs, 1);
+    assert_eq!(stats.raw_bytes, 2 * body.len() as i64);
+    assert!(stats.stored_bytes < body.len() as i64);
+    assert!(stats.saved_percent() > 50.0);
+
+    assert_eq!(db.delete_orphan_contexts().unwrap(), 0);
+}
+
+#[test]
+fn test_migration_037_compresses_inline_contexts() {
+    let temp_dir = TempDir::new().unwrap();
+    let db_path = temp_dir.path().join("v36.db");
+    {
+        let mut db = SymbolDatabase::new(&db_path).unwrap();
+        db.store_file_info(&file_info_builder("src/a.rs").build())
+            .unwrap();
+        db.store_symbols(&[symbol_builder("a", "render", "src/a.rs").build()])
+            .unwrap();
+    }
+    let conn = open_test_connection(&db_path).unwrap();
+    conn.execute(
+        "UPDATE symbols SET code_context = ?1, context_hash = NULL WHERE id = 'a'",
+        [RENDER_BODY],
+    )
+    .unwrap();
+    conn.execute("DELETE FROM schema_version WHERE version >= 37", [])
+        .unwrap();
+    drop(conn);
+
+    let db = SymbolDatabase::new(&db_path).unwrap();
+    assert_eq!(db.get_schema_version().unwrap(), LATEST_SCHEMA_VERSION);
+    assert_eq!(inline_context(&db, "a"), None);
+    assert_eq!(blob_count(&db), 1);
+    let symbol = db.get_symbol_by_id("a").unwrap().unwrap();
+    assert_eq!(symbol.code_context.as_deref(), Some(RENDER_BODY));
+}
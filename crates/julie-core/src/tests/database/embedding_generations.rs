@@ -0,0 +1,159 @@
+use super::*;
+
+fn update(db: &mut SymbolDatabase, file_path: &str, symbols: &[Symbol]) {
+    db.incremental_update_atomic(
+        &[file_path.to_string()],
+        &[file_info_builder(file_path).build()],
+        symbols,
+        &[],
+        &[],
+        &[],
+        "primary",
+    )
+    .unwrap();
+}
+
+fn vectors(ids: &[&str]) -> Vec<(String, Vec<f32>)> {
+    ids.iter()
+        .map(|id| (id.to_string(), vec![0.1_f32; 384]))
+        .collect()
+}
+
+#[test]
+fn test_staged_embeddings_are_invisible_until_commit() {
+    let temp_dir = TempDir::new().unwrap();
+    let mut db = SymbolDatabase::new(temp_dir.path().join("test.db")).unwrap();
+    let files = [file_info_builder("src/a.rs").build()];
+    let symbols = [
+        symbol_builder("a", "a", "src/a.rs").build(),
+        symbol_builder("b", "b", "src/a.rs").build(),
+    ];
+    db.bulk_store_fresh_atomic(&files, &symbols, &[], &[], &[], "primary")
+        .unwrap();
+    assert_eq!(db.active_embedding_generation().unwrap(), 0);
+
+    let generation = db.begin_embedding_generation().unwrap();
+    assert_eq!(generation.generation, 1);
+    assert_eq!(db.pending_embedding_generation().unwrap(), Some(generation));
+    db.stage_embeddings(&generation, &vectors(&["a", "b"]))
+        .unwrap();
+    assert_eq!(db.embedding_count().unwrap(), 0);
+
+    let commit = db.commit_embedding_generation(&generation).unwrap();
+    assert_eq!(commit.vectors_committed, 2);
+    assert_eq!(commit.stale_discarded, 0);
+    assert_eq!(db.embedding_count().unwrap(), 2);
+    assert_eq!(db.active_embedding_generation().unwrap(), 1);
+    assert_eq!(db.pending_embedding_generation().unwrap(), None);
+}
+
+#[test]
+fn test_commit_drops_vectors_of_symbols_changed_mid_generation() {
+    let temp_dir = TempDir::new().unwrap();
+    let mut db = SymbolDatabase::new(temp_dir.path().join("test.db")).unwrap();
+    let files = [file_info_builder("src/a.rs").build()];
+    let symbols = [
+        symbol_builder("keep", "keep", "src/a.rs").build(),
+        symbol_builder("edit", "edit", "src/a.rs")
+            .signature("fn edit()")
+            .build(),
+        symbol_builder("drop", "drop", "src/a.rs").build(),
+    ];
+    db.bulk_store_fresh_atomic(&files, &symbols, &[], &[], &[], "primary")
+        .unwrap();
+
+    let generation = db.begin_embedding_generation().unwrap();
+    db.stage_embeddings(&generation, &vectors(&["keep", "edit", "drop"]))
+        .unwrap();
+
+    // An index write lands while the pipeline is still embedding.
+    update(
+        &mut db,
+        "src/a.rs",
+        &[
+            symbol_builder("keep", "keep", "src/a.rs").build(),
+            symbol_builder("edit", "edit", "src/a.rs")
+                .signature("fn edit(x: u32)")
+                .build(),
+        ],
+    );
+
+    let commit = db.commit_embedding_generation(&generation).unwrap();
+    assert_eq!(commit.vectors_committed, 1);
+    assert_eq!(commit.stale_discarded, 2);
+    // `edit` is left for the next run to embed from its new body.
+    let embedded = db.get_embedded_symbol_ids().unwrap();
+    assert_eq!(embedded, ["keep".to_string()].into_iter().collect());
+}
+
+#[test]
+fn test_begin_discards_an_interrupted_generation() {
+    let temp_dir = TempDir::new().unwrap();
+    let mut db = SymbolDatabase::new(temp_dir.path().join("test.db")).unwrap();
+    let files = [file_info_builder("src/a.rs").build()];
+    let symbols = [symbol_builder("a", "a", "src/a.rs").build()];
+    db.bulk_store_fresh_atomic(&files, &symbols, &[], &[], &[], "primary")
+        .unwrap();
+
+    let interrupted = db.begin_embedding_generation().unwrap();
+    db.stage_embeddings(&interrupted, &vectors(&["a"])).unwrap();
+
+    let generation = db.begin_embedding_generation().unwrap();
+    assert_eq!(generation.generation, interrupted.generation + 1);
+    assert!(db.stage_embeddings(&interrupted, &vectors(&["a"])).is_err());
+    assert!(db.commit_embedding_generation(&interrupted).is_err());
+
+    let commit = db.commit_embedding_generation(&generation).unwrap();
+    assert_eq!(commit.vectors_committed, 0);
+    assert_eq!(db.embedding_count().unwrap(), 0);
+
+    // Clearing the store supersedes a pending generation too.
+    let generation = db.begin_embedding_generation().unwrap();
+    db.clear_all_embeddings().unwrap();
+    assert!(db.commit_embedding_generation(&generation).is_err());
+}
+
+#[test]
+fn test_checkpoint_publishes_vectors_and_records_job_progress() {
+    let temp_dir = TempDir::new().unwrap();
+    let mut db = SymbolDatabase::new(temp_dir.path().join("test.db")).unwrap();
+    let files = [file_info_builder("src/a.rs").build()];
+    let symbols = [
+        symbol_builder("a", "a", "src/a.rs").build(),
+        symbol_builder("b", "b", "src/a.rs").build(),
+        symbol_builder("c", "c", "src/a.rs").build(),
+    ];
+    db.bulk_store_fresh_atomic(&files, &symbols, &[], &[], &[], "primary")
+        .unwrap();
+    assert_eq!(db.embedding_job().unwrap(), None);
+
+    let generation = db.begin_embedding_generation().unwrap();
+    db.start_embedding_job(3, 0).unwrap();
+    db.stage_embeddings(&generation, &vectors(&["a", "b"]))
+        .unwrap();
+    let ids = ["a".to_string(), "b".to_string()];
+    let commit = db
+        .checkpoint_embedding_generation(&generation, &ids, 2)
+        .unwrap();
+    assert_eq!(commit.vectors_committed, 2);
+
+    // Published, but the generation stays pending until the final commit.
+    assert_eq!(db.embedding_count().unwrap(), 2);
+    assert_eq!(db.pending_embedding_generation().unwrap(), Some(generation));
+    assert_eq!(db.active_embedding_generation().unwrap(), 0);
+
+    db.finish_embedding_job(EmbeddingJobStatus::Cancelled)
+        .unwrap();
+    let job = db.embedding_job().unwrap().unwrap();
+    assert_eq!(job.status, EmbeddingJobStatus::Cancelled);
+    assert_eq!((job.completed, job.total), (2, 3));
+    let done = db.unfinished_embedding_job_ids().unwrap();
+    assert_eq!(done, ids.iter().cloned().collect());
+
+    // A resumed job keeps the list; a completed one forgets it.
+    db.start_embedding_job(3, 2).unwrap();
+    assert_eq!(db.unfinished_embedding_job_ids().unwrap().len(), 2);
+    db.finish_embedding_job(EmbeddingJobStatus::Complete)
+        .unwrap();
+    assert!(db.unfinished_embedding_job_ids().unwrap().is_empty());
+}
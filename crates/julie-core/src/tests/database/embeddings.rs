@@ -56,6 +56,31 @@ fn test_delete_embeddings_for_symbol_ids_only_removes_requested_rows() {
     assert!(!remaining.contains("sym_c"));
 }
 
+#[test]
+fn test_orphan_embeddings_are_counted_before_deletion() {
+    let temp_dir = TempDir::new().unwrap();
+    let db_path = temp_dir.path().join("test.db");
+    let mut db = SymbolDatabase::new(&db_path).unwrap();
+
+    db.store_file_info(&file_info_builder("src/lib.rs").language("rust").build())
+        .unwrap();
+    db.store_symbols(&[symbol_builder("sym_a", "a", "src/lib.rs")
+        .kind(SymbolKind::Function)
+        .language("rust")
+        .build()])
+        .unwrap();
+    db.store_embeddings(&[
+        ("sym_a".to_string(), vec![0.1_f32; 384]),
+        ("sym_gone".to_string(), vec![0.2_f32; 384]),
+    ])
+    .unwrap();
+
+    assert_eq!(db.count_orphan_embeddings().unwrap(), 1);
+    assert_eq!(db.delete_orphan_embeddings().unwrap(), 1);
+    assert_eq!(db.count_orphan_embeddings().unwrap(), 0);
+    assert_eq!(db.embedding_count().unwrap(), 1);
+}
+
 #[test]
 fn test_delete_embeddings_for_symbol_ids_batches_large_inputs() {
     let temp_dir = TempDir::new().unwrap();
@@ -81,3 +106,182 @@ fn test_delete_embeddings_for_symbol_ids_batches_large_inputs() {
     let remaining_ids = db.get_embedded_symbol_ids().unwrap();
     assert!(remaining_ids.is_empty());
 }
+
+#[test]
+fn test_backup_to_copies_embeddings_while_source_stays_open() {
+    let temp_dir = TempDir::new().unwrap();
+    let mut db = SymbolDatabase::new(temp_dir.path().join("live.db")).unwrap();
+    db.store_embeddings(&[
+        ("sym_a".to_string(), vec![0.1_f32; 384]),
+        ("sym_b".to_string(), vec![0.2_f32; 384]),
+    ])
+    .unwrap();
+
+    let backup_path = temp_dir.path().join("backup.db");
+    let bytes = db.backup_to(&backup_path).unwrap();
+    assert!(bytes > 0);
+
+    // The live connection keeps working after the backup.
+    db.store_embeddings(&[("sym_c".to_string(), vec![0.3_f32; 384])])
+        .unwrap();
+    assert_eq!(db.embedding_count().unwrap(), 3);
+
+    let restored = SymbolDatabase::new(&backup_path).unwrap();
+    assert_eq!(restored.embedding_count().unwrap(), 2);
+    assert!(restored.get_embedding("sym_b").unwrap().is_some());
+
+    let err = db.backup_to(&backup_path).unwrap_err();
+    assert!(err.to_string().contains("already exists"), "{err}");
+}
+
+fn chunk(chunk_id: &str, symbol_id: &str, value: f32) -> ChunkEmbedding {
+    ChunkEmbedding {
+        chunk_id: chunk_id.to_string(),
+        symbol_id: symbol_id.to_string(),
+        start_line: 1,
+        end_line: 10,
+        vector: unit_vector(value),
+    }
+}
+
+/// 384-dim vector pointing mostly along axis 0 or 1, so cosine distances
+/// between `unit_vector(a)` and `unit_vector(b)` grow with `|a - b|`.
+fn unit_vector(angle: f32) -> Vec<f32> {
+    let mut v = vec![0.0_f32; 384];
+    v[0] = angle.cos();
+    v[1] = angle.sin();
+    v
+}
+
+#[test]
+fn test_knn_search_with_chunks_ranks_symbol_by_best_chunk() {
+    let temp_dir = TempDir::new().unwrap();
+    let mut db = SymbolDatabase::new(temp_dir.path().join("test.db")).unwrap();
+
+    // sym_long's metadata vector is far from the query; one body chunk is close.
+    db.store_embeddings(&[
+        ("sym_long".to_string(), unit_vector(1.4)),
+        ("sym_short".to_string(), unit_vector(0.5)),
+    ])
+    .unwrap();
+    db.store_chunk_embeddings(&[
+        chunk("sym_long#chunk0", "sym_long", 1.2),
+        chunk("sym_long#chunk1", "sym_long", 0.1),
+    ])
+    .unwrap();
+
+    let plain = db.knn_search(&unit_vector(0.0), 2).unwrap();
+    assert_eq!(plain[0].0, "sym_short");
+
+    let hits = db.knn_search_with_chunks(&unit_vector(0.0), 2).unwrap();
+    let ids: Vec<&str> = hits.iter().map(|(id, _)| id.as_str()).collect();
+    assert_eq!(
+        ids,
+        vec!["sym_long", "sym_short"],
+        "chunks collapse onto their symbol"
+    );
+    assert!(
+        hits[0].1 < plain[1].1,
+        "best chunk distance wins over metadata distance"
+    );
+}
+
+#[test]
+fn test_chunk_embeddings_are_deleted_with_symbol_embeddings() {
+    let temp_dir = TempDir::new().unwrap();
+    let mut db = SymbolDatabase::new(temp_dir.path().join("test.db")).unwrap();
+
+    db.store_embeddings(&[
+        ("sym_a".to_string(), unit_vector(0.1)),
+        ("sym_b".to_string(), unit_vector(0.2)),
+    ])
+    .unwrap();
+    db.store_chunk_embeddings(&[
+        chunk("sym_a#chunk0", "sym_a", 0.1),
+        chunk("sym_a#chunk1", "sym_a", 0.2),
+        chunk("sym_b#chunk0", "sym_b", 0.3),
+    ])
+    .unwrap();
+    assert_eq!(db.chunk_embedding_count().unwrap(), 3);
+
+    db.delete_embeddings_for_symbol_ids(&["sym_a".to_string()])
+        .unwrap();
+    assert_eq!(db.chunk_embedding_count().unwrap(), 1);
+    let chunked = db.get_chunked_symbol_ids().unwrap();
+    assert!(chunked.contains("sym_b"));
+    assert!(!chunked.contains("sym_a"));
+
+    db.clear_all_embeddings().unwrap();
+    assert_eq!(db.chunk_embedding_count().unwrap(), 0);
+    assert!(db.get_chunked_symbol_ids().unwrap().is_empty());
+}
+
+#[test]
+fn test_knn_search_with_chunks_in_languages_filters_inside_the_query() {
+    let temp_dir = TempDir::new().unwrap();
+    let mut db = SymbolDatabase::new(temp_dir.path().join("test.db")).unwrap();
+
+    let symbols = [
+        ("sym_rs", "src/lib.rs", "rust"),
+        ("sym_py", "app/main.py", "python"),
+        ("sym_go_a", "svc/handler.go", "go"),
+        ("sym_go_b", "svc/store.go", "go"),
+    ];
+    for (id, file_path, language) in symbols {
+        db.store_file_info(&file_info_builder(file_path).language(language).build())
+            .unwrap();
+        db.store_symbols(&[symbol_builder(id, id, file_path)
+            .kind(SymbolKind::Function)
+            .language(language)
+            .span(1, 0, 10, 1)
+            .build()])
+            .unwrap();
+    }
+
+    // Rust and Python are the nearest vectors; Go is further away, except
+    // for one body chunk of sym_go_b.
+    db.store_embeddings(&[
+        ("sym_rs".to_string(), unit_vector(0.05)),
+        ("sym_py".to_string(), unit_vector(0.1)),
+        ("sym_go_a".to_string(), unit_vector(0.9)),
+        ("sym_go_b".to_string(), unit_vector(1.3)),
+    ])
+    .unwrap();
+    db.store_chunk_embeddings(&[chunk("sym_go_b#chunk0", "sym_go_b", 0.2)])
+        .unwrap();
+
+    let query = unit_vector(0.0);
+    let unfiltered = db.knn_search_with_chunks(&query, 2).unwrap();
+    assert!(unfiltered.iter().all(|(id, _)| !id.starts_with("sym_go")));
+
+    let go = db
+        .knn_search_with_chunks_in_languages(&query, 2, &["go".to_string()])
+        .unwrap();
+    let ids: Vec<&str> = go.iter().map(|(id, _)| id.as_str()).collect();
+    assert_eq!(
+        ids,
+        ["sym_go_b", "sym_go_a"],
+        "filtered top-k is still full"
+    );
+
+    // Filtered scans score with the table's own metric.
+    let all = db.knn_search(&query, 4).unwrap();
+    let expected = all.iter().find(|(id, _)| id == "sym_go_a").unwrap().1;
+    assert!(
+        (go[1].1 - expected).abs() < 1e-4,
+        "{} vs {expected}",
+        go[1].1
+    );
+
+    let mixed = db
+        .knn_search_with_chunks_in_languages(&query, 10, &["go".to_string(), "python".to_string()])
+        .unwrap();
+    assert_eq!(mixed.len(), 3);
+    assert_eq!(mixed[0].0, "sym_py");
+
+    assert_eq!(
+        db.knn_search_with_chunks_in_languages(&query, 2, &[])
+            .unwrap(),
+        unfiltered
+    );
+}
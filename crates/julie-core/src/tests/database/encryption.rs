@@ -0,0 +1,95 @@
+use super::*;
+use crate::database::encryption::{
+    INDEX_KEY_COMMAND_ENV, INDEX_KEY_ENV, key_connection, resolve_index_key,
+};
+use rusqlite::Connection;
+
+fn env(vars: &[(&str, &str)]) -> impl Fn(&str) -> Option<String> {
+    let vars: HashMap<String, String> = vars
+        .iter()
+        .map(|(name, value)| (name.to_string(), value.to_string()))
+        .collect();
+    move |name| vars.get(name).cloned()
+}
+
+#[test]
+fn test_no_index_key_configured() {
+    assert_eq!(resolve_index_key(env(&[])).unwrap(), None);
+    assert_eq!(
+        resolve_index_key(env(&[(INDEX_KEY_ENV, "  "), (INDEX_KEY_COMMAND_ENV, "")])).unwrap(),
+        None
+    );
+}
+
+#[test]
+fn test_index_key_env_wins_over_command() {
+    let key = resolve_index_key(env(&[
+        (INDEX_KEY_ENV, "s3cret"),
+        (INDEX_KEY_COMMAND_ENV, "exit 1"),
+    ]))
+    .unwrap();
+    assert_eq!(key.as_deref(), Some("s3cret"));
+}
+
+#[cfg(unix)]
+#[test]
+fn test_index_key_command_output_is_the_key() {
+    let key = resolve_index_key(env(&[(INDEX_KEY_COMMAND_ENV, "echo ' from keychain '")])).unwrap();
+    // Only the trailing newline is stripped; the key's own spaces are kept.
+    assert_eq!(key.as_deref(), Some(" from keychain "));
+}
+
+#[cfg(unix)]
+#[test]
+fn test_index_key_command_failures_are_errors() {
+    let err =
+        resolve_index_key(env(&[(INDEX_KEY_COMMAND_ENV, "echo locked >&2; exit 3")])).unwrap_err();
+    assert!(err.to_string().contains("locked"), "{err}");
+
+    let err = resolve_index_key(env(&[(INDEX_KEY_COMMAND_ENV, "printf '\\n'")])).unwrap_err();
+    assert!(err.to_string().contains("empty key"), "{err}");
+}
+
+#[cfg(not(feature = "encryption"))]
+#[test]
+fn test_index_key_without_encryption_support_is_refused() {
+    let conn = Connection::open_in_memory().unwrap();
+    let err = key_connection(&conn, "s3cret").unwrap_err();
+    assert!(err.to_string().contains("--features encryption"), "{err}");
+}
+
+#[cfg(feature = "encryption")]
+#[test]
+fn test_keyed_database_cannot_be_read_without_the_key() {
+    let temp_dir = TempDir::new().unwrap();
+    let path = temp_dir.path().join("encrypted.db");
+    {
+        let conn = Connection::open(&path).unwrap();
+        key_connection(&conn, "s3cret").unwrap();
+        conn.execute_batch("CREATE TABLE t (body TEXT); INSERT INTO t VALUES ('fn secret()');")
+            .unwrap();
+    }
+
+    let plain = Connection::open(&path).unwrap();
+    assert!(
+        plain
+            .query_row("SELECT count(*) FROM sqlite_master", [], |_| Ok(()))
+            .is_err()
+    );
+
+    let wrong = Connection::open(&path).unwrap();
+    assert!(key_connection(&wrong, "guess").is_err());
+
+    let keyed = Connection::open(&path).unwrap();
+    key_connection(&keyed, "s3cret").unwrap();
+    let body: String = keyed
+        .query_row("SELECT body FROM t", [], |row| row.get(0))
+        .unwrap();
+    assert_eq!(body, "fn secret()");
+    assert!(
+        !std::fs::read(&path)
+            .unwrap()
+            .windows(11)
+            .any(|w| w == b"fn secret()")
+    );
+}
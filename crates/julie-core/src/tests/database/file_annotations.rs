@@ -0,0 +1,107 @@
+use super::*;
+
+fn annotated_db(temp_dir: &TempDir) -> SymbolDatabase {
+    let mut db = SymbolDatabase::new(temp_dir.path().join("test.db")).unwrap();
+    db.store_file_info(
+        &file_info_builder("src/widget.rs")
+            .hash("widget-hash")
+            .line_count(40)
+            .last_modified(1_700_000_000)
+            .last_indexed(1_700_000_100)
+            .build(),
+    )
+    .unwrap();
+    db.store_file_info(&file_info_builder("src/main.rs").build())
+        .unwrap();
+    db.store_file_info(&file_info_builder("README.md").language("markdown").build())
+        .unwrap();
+
+    db.store_symbols(&[
+        symbol_builder("render", "render", "src/widget.rs")
+            .kind(SymbolKind::Method)
+            .span(12, 4, 20, 5)
+            .signature("fn render(&self)")
+            .visibility(Visibility::Public)
+            .parent_id("widget")
+            .build(),
+        symbol_builder("widget", "Widget", "src/widget.rs")
+            .kind(SymbolKind::Struct)
+            .span(3, 0, 30, 1)
+            .build(),
+        symbol_builder("main", "main", "src/main.rs")
+            .kind(SymbolKind::Function)
+            .span(1, 0, 5, 1)
+            .build(),
+    ])
+    .unwrap();
+    db.store_relationships(&[
+        relationship_builder("r1", "main", "widget")
+            .kind(RelationshipKind::Uses)
+            .file_path("src/main.rs")
+            .build(),
+        relationship_builder("r2", "main", "render")
+            .file_path("src/main.rs")
+            .build(),
+        // Recursion is not a reference.
+        relationship_builder("r3", "render", "render")
+            .file_path("src/widget.rs")
+            .build(),
+    ])
+    .unwrap();
+    set_symbol_reference_scores(&db, &[("widget", 2.5)]).unwrap();
+    db
+}
+
+#[test]
+fn test_get_file_annotations_returns_symbols_in_position_order() {
+    let temp_dir = TempDir::new().unwrap();
+    let db = annotated_db(&temp_dir);
+
+    let annotations = db.get_file_annotations("src/widget.rs").unwrap().unwrap();
+    assert_eq!(annotations.file_path, "src/widget.rs");
+    assert_eq!(annotations.language, "rust");
+    assert_eq!(annotations.hash, "widget-hash");
+    assert_eq!(annotations.line_count, 40);
+    assert_eq!(annotations.last_modified, 1_700_000_000);
+    assert_eq!(annotations.last_indexed, 1_700_000_100);
+
+    let names: Vec<&str> = annotations
+        .symbols
+        .iter()
+        .map(|symbol| symbol.name.as_str())
+        .collect();
+    assert_eq!(names, vec!["Widget", "render"]);
+
+    let widget = &annotations.symbols[0];
+    assert_eq!(widget.kind, "struct");
+    assert_eq!(widget.reference_count, 1);
+    assert_eq!(widget.reference_score, 2.5);
+
+    let render = &annotations.symbols[1];
+    assert_eq!(render.kind, "method");
+    assert_eq!(render.parent_id.as_deref(), Some("widget"));
+    assert_eq!(render.signature.as_deref(), Some("fn render(&self)"));
+    assert_eq!(render.visibility.as_deref(), Some("public"));
+    assert_eq!(
+        (
+            render.start_line,
+            render.start_column,
+            render.end_line,
+            render.end_column
+        ),
+        (12, 4, 20, 5)
+    );
+    assert_eq!(render.reference_count, 1);
+}
+
+#[test]
+fn test_get_file_annotations_distinguishes_empty_and_unknown_files() {
+    let temp_dir = TempDir::new().unwrap();
+    let db = annotated_db(&temp_dir);
+
+    let readme = db.get_file_annotations("README.md").unwrap().unwrap();
+    assert_eq!(readme.language, "markdown");
+    assert!(readme.symbols.is_empty());
+
+    assert_eq!(db.get_file_annotations("src/missing.rs").unwrap(), None);
+}
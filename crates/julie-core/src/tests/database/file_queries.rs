@@ -73,6 +73,46 @@ fn test_get_file_contents_by_paths_returns_requested_content_map() {
     assert!(!contents.contains_key("src/missing.rs"));
 }
 
+#[test]
+fn test_for_each_file_content_streams_in_path_order_and_stops_early() {
+    let temp_dir = TempDir::new().unwrap();
+    let db_path = temp_dir.path().join("test.db");
+    #[allow(unused_mut)]
+    let mut db = SymbolDatabase::new(&db_path).unwrap();
+
+    for (path, content) in [
+        ("src/b.rs", "beta"),
+        ("src/a.rs", "alpha"),
+        ("src/c.rs", "gamma"),
+    ] {
+        db.store_file_with_content(path, "rust", path, 10, 1, content, "test_workspace")
+            .unwrap();
+    }
+
+    let mut seen = Vec::new();
+    db.for_each_file_content(|path, language, content| {
+        seen.push((path.to_string(), language.to_string(), content.to_string()));
+        seen.len() < 2
+    })
+    .unwrap();
+
+    assert_eq!(
+        seen,
+        [
+            (
+                "src/a.rs".to_string(),
+                "rust".to_string(),
+                "alpha".to_string()
+            ),
+            (
+                "src/b.rs".to_string(),
+                "rust".to_string(),
+                "beta".to_string()
+            ),
+        ]
+    );
+}
+
 #[test]
 fn test_get_file_languages_by_paths_returns_requested_languages() {
     let temp_dir = TempDir::new().unwrap();
@@ -194,3 +234,15 @@ fn test_get_identifiers_by_names_kinds_excluding_containers_filters_rows() {
         std::collections::HashSet::from(["sym_included"])
     );
 }
+
+#[test]
+fn test_store_file_info_normalizes_windows_prefixed_paths() {
+    let temp_dir = TempDir::new().unwrap();
+    let db = SymbolDatabase::new(temp_dir.path().join("test.db")).unwrap();
+
+    let file = file_info_builder(r"\\?\C:\ws\src\a.rs").build();
+    db.store_file_info(&file).unwrap();
+
+    assert!(db.get_file_hash("C:/ws/src/a.rs").unwrap().is_some());
+    assert!(db.get_file_hash(r"\\?\C:\ws\src\a.rs").unwrap().is_none());
+}
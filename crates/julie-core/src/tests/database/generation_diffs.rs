@@ -0,0 +1,129 @@
+use super::*;
+
+fn names(changes: &[RevisionSymbolChange]) -> Vec<&str> {
+    let mut names: Vec<&str> = changes.iter().map(|change| change.name.as_str()).collect();
+    names.sort_unstable();
+    names
+}
+
+fn update(db: &mut SymbolDatabase, file_path: &str, symbols: &[Symbol]) {
+    db.incremental_update_atomic(
+        &[file_path.to_string()],
+        &[file_info_builder(file_path).build()],
+        symbols,
+        &[],
+        &[],
+        &[],
+        "primary",
+    )
+    .unwrap();
+}
+
+#[test]
+fn test_compare_generations_reports_net_symbol_changes() {
+    let temp_dir = TempDir::new().unwrap();
+    let mut db = SymbolDatabase::new(temp_dir.path().join("test.db")).unwrap();
+    let files = [file_info_builder("src/a.rs").build()];
+    let symbols = [
+        symbol_builder("keep", "keep", "src/a.rs").build(),
+        symbol_builder("edit", "edit", "src/a.rs")
+            .signature("fn edit()")
+            .build(),
+        symbol_builder("drop", "drop", "src/a.rs").build(),
+    ];
+    db.bulk_store_fresh_atomic(&files, &symbols, &[], &[], &[], "primary")
+        .unwrap();
+    let base = db
+        .get_current_canonical_revision("primary")
+        .unwrap()
+        .unwrap();
+
+    // Generation 2: `edit` changes signature, `drop` goes away, `temp` and
+    // `new` appear.
+    update(
+        &mut db,
+        "src/a.rs",
+        &[
+            symbol_builder("keep", "keep", "src/a.rs").build(),
+            symbol_builder("edit", "edit", "src/a.rs")
+                .signature("fn edit(x: u32)")
+                .build(),
+            symbol_builder("temp", "temp", "src/a.rs").build(),
+            symbol_builder("new", "new", "src/a.rs").build(),
+        ],
+    );
+    // Generation 3: `temp` was short-lived.
+    update(
+        &mut db,
+        "src/a.rs",
+        &[
+            symbol_builder("keep", "keep", "src/a.rs").build(),
+            symbol_builder("edit", "edit", "src/a.rs")
+                .signature("fn edit(x: u32)")
+                .build(),
+            symbol_builder("new", "new", "src/a.rs").build(),
+        ],
+    );
+    let latest = db
+        .get_current_canonical_revision("primary")
+        .unwrap()
+        .unwrap();
+    assert_eq!(latest, base + 2);
+
+    let diff = db.compare_generations("primary", base, latest).unwrap();
+    assert_eq!(names(&diff.added), ["new"]);
+    assert_eq!(names(&diff.removed), ["drop"]);
+    assert_eq!(names(&diff.modified), ["edit"]);
+
+    let last_step = db
+        .compare_generations("primary", latest - 1, latest)
+        .unwrap();
+    assert!(last_step.added.is_empty());
+    assert_eq!(names(&last_step.removed), ["temp"]);
+    assert!(last_step.modified.is_empty());
+
+    // Generation 0 is the empty index, so everything present is "added".
+    assert_eq!(db.oldest_comparable_generation("primary").unwrap(), Some(0));
+    let from_empty = db.compare_generations("primary", 0, latest).unwrap();
+    assert_eq!(names(&from_empty.added), ["edit", "keep", "new"]);
+    assert!(from_empty.removed.is_empty());
+}
+
+#[test]
+fn test_compare_generations_rejects_pruned_and_future_generations() {
+    let temp_dir = TempDir::new().unwrap();
+    let mut db = SymbolDatabase::new(temp_dir.path().join("test.db")).unwrap();
+    db.bulk_store_fresh_atomic(
+        &[file_info_builder("src/a.rs").build()],
+        &[symbol_builder("s0", "v0", "src/a.rs").build()],
+        &[],
+        &[],
+        &[],
+        "primary",
+    )
+    .unwrap();
+    for step in 1..=SYMBOL_GENERATION_RETENTION + 2 {
+        let symbol = symbol_builder("s0", format!("v{step}"), "src/a.rs").build();
+        update(&mut db, "src/a.rs", &[symbol]);
+    }
+    let latest = db
+        .get_current_canonical_revision("primary")
+        .unwrap()
+        .unwrap();
+    let oldest = db.oldest_comparable_generation("primary").unwrap().unwrap();
+    assert_eq!(oldest, latest - SYMBOL_GENERATION_RETENTION);
+
+    assert!(db.compare_generations("primary", oldest, latest).is_ok());
+    assert!(
+        db.compare_generations("primary", oldest - 1, latest)
+            .is_err()
+    );
+    assert!(
+        db.compare_generations("primary", oldest, latest + 1)
+            .is_err()
+    );
+    assert!(
+        db.compare_generations("primary", latest, latest - 1)
+            .is_err()
+    );
+}
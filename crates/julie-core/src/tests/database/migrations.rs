@@ -267,6 +267,49 @@ fn test_reference_score_defaults_to_zero() {
         score
     );
 }
+/// Migration 042: reference_count column and its triggers exist on fresh databases
+#[test]
+fn test_migration_042_reference_count_column_and_triggers_exist() {
+    let temp_dir = TempDir::new().unwrap();
+    let db_path = temp_dir.path().join("test.db");
+    let db = SymbolDatabase::new(&db_path).unwrap();
+
+    assert!(db.has_column("symbols", "reference_count").unwrap());
+    let triggers: i64 = db
+        .conn
+        .query_row(
+            "SELECT COUNT(*) FROM sqlite_master
+             WHERE type = 'trigger' AND name LIKE '%_refcount_%'",
+            [],
+            |row| row.get(0),
+        )
+        .unwrap();
+    assert_eq!(triggers, 5);
+}
+
+/// Migration 046: identifier_reference_count column exists on fresh databases,
+/// kept current by the identifier writers rather than per-row triggers
+#[test]
+fn test_migration_046_identifier_reference_count_column_without_triggers() {
+    let temp_dir = TempDir::new().unwrap();
+    let db_path = temp_dir.path().join("test.db");
+    let db = SymbolDatabase::new(&db_path).unwrap();
+
+    assert!(
+        db.has_column("symbols", "identifier_reference_count")
+            .unwrap()
+    );
+    let triggers: i64 = db
+        .conn
+        .query_row(
+            "SELECT COUNT(*) FROM sqlite_master
+             WHERE type = 'trigger' AND name LIKE '%_usecount_%'",
+            [],
+            |row| row.get(0),
+        )
+        .unwrap();
+    assert_eq!(triggers, 0);
+}
 // ============================================================================
 // Migration 011: Embedding Config (Phase 5, Task 1)
 // ============================================================================
@@ -456,7 +499,8 @@ fn test_migration_027_upgrades_v26_database_preserving_data() {
     let symbol_count: i64 = db
         .conn
         .query_row(
-            "SELECT COUNT(*) FROM symbols WHERE id = 'sym-legacy'",
+            // Migration 036 re-keys the row onto its stable ID.
+            "SELECT COUNT(*) FROM symbols WHERE name = 'Legacy'",
             [],
             |row| row.get(0),
         )
@@ -611,7 +655,8 @@ fn test_migration_028_upgrades_v27_database_preserving_data() {
     let symbol_count: i64 = db
         .conn
         .query_row(
-            "SELECT COUNT(*) FROM symbols WHERE id = 'sym-legacy'",
+            // Migration 036 re-keys the row onto its stable ID.
+            "SELECT COUNT(*) FROM symbols WHERE name = 'Legacy'",
             [],
             |row| row.get(0),
         )
@@ -707,3 +752,106 @@ fn test_migration_029_adds_extractor_enrichment_tables() {
         );
     }
 }
+
+#[test]
+fn test_migration_036_rekeys_symbols_and_dependents() {
+    let temp_dir = TempDir::new().unwrap();
+    let db_path = temp_dir.path().join("v35.db");
+    {
+        let mut db = SymbolDatabase::new(&db_path).unwrap();
+        let file = file_info_builder("widget.rs").build();
+        let parent = symbol_builder("pos-widget", "Widget", "widget.rs")
+            .kind(SymbolKind::Struct)
+            .bytes(0, 200)
+            .build();
+        let child = symbol_builder("pos-render", "render", "widget.rs")
+            .kind(SymbolKind::Method)
+            .bytes(40, 120)
+            .parent_id("pos-widget")
+            .build();
+        let call = relationship_builder("rel-1", "pos-render", "pos-widget")
+            .file_path("widget.rs")
+            .build();
+        db.bulk_store_fresh_atomic(&[file], &[parent, child], &[call], &[], &[], "primary")
+            .unwrap();
+        db.store_embeddings(&[("pos-render".to_string(), vec![0.5_f32; 384])])
+            .unwrap();
+    }
+    let conn = open_test_connection(&db_path).unwrap();
+    conn.execute("DELETE FROM schema_version WHERE version >= 36", [])
+        .unwrap();
+    drop(conn);
+
+    let db = SymbolDatabase::new(&db_path).unwrap();
+    assert_eq!(db.get_schema_version().unwrap(), LATEST_SCHEMA_VERSION);
+
+    let widget = db.get_symbols_for_file("widget.rs").unwrap();
+    let parent = widget.iter().find(|s| s.name == "Widget").unwrap();
+    let child = widget.iter().find(|s| s.name == "render").unwrap();
+    assert_ne!(parent.id, "pos-widget");
+    assert_ne!(child.id, "pos-render");
+    assert_eq!(child.parent_id.as_deref(), Some(parent.id.as_str()));
+
+    let outgoing = db.get_outgoing_relationships(&child.id).unwrap();
+    assert_eq!(outgoing.len(), 1);
+    assert_eq!(outgoing[0].to_symbol_id, parent.id);
+
+    assert!(db.get_embedding(&child.id).unwrap().is_some());
+    assert!(db.get_embedding("pos-render").unwrap().is_none());
+}
+
+#[test]
+fn test_migration_049_normalizes_windows_prefixed_paths() {
+    let temp_dir = TempDir::new().unwrap();
+    let db_path = temp_dir.path().join("v48.db");
+    {
+        let mut db = SymbolDatabase::new(&db_path).unwrap();
+        let file = file_info_builder("C:/ws/src/a.rs").build();
+        let symbol = symbol_builder("sym-a", "alpha", "C:/ws/src/a.rs").build();
+        db.bulk_store_fresh_atomic(&[file], &[symbol], &[], &[], &[], "primary")
+            .unwrap();
+    }
+    let conn = open_test_connection(&db_path).unwrap();
+    conn.execute_batch(
+        r"PRAGMA foreign_keys = OFF;
+          UPDATE files SET path = '\\?\C:\ws\src\a.rs';
+          UPDATE symbols SET file_path = '\\?\C:\ws\src\a.rs';
+          INSERT INTO soft_deleted_files (path, deleted_at) VALUES ('\\?\C:\ws\src\gone.rs', 7);
+          DELETE FROM schema_version WHERE version >= 49;",
+    )
+    .unwrap();
+    drop(conn);
+
+    let db = SymbolDatabase::new(&db_path).unwrap();
+    assert_eq!(db.get_schema_version().unwrap(), LATEST_SCHEMA_VERSION);
+    assert!(db.get_file_hash("C:/ws/src/a.rs").unwrap().is_some());
+    let symbols = db.get_symbols_for_file("C:/ws/src/a.rs").unwrap();
+    assert_eq!(symbols.len(), 1);
+    assert_eq!(symbols[0].file_path, "C:/ws/src/a.rs");
+    assert_eq!(
+        db.soft_deleted_files().unwrap(),
+        HashMap::from([("C:/ws/src/gone.rs".to_string(), 7)]),
+        "a path with no files row is rewritten too"
+    );
+}
+
+#[test]
+fn test_migration_050_adds_embedding_job_tables() {
+    let temp_dir = TempDir::new().unwrap();
+    let db_path = temp_dir.path().join("v49.db");
+    drop(SymbolDatabase::new(&db_path).unwrap());
+    let conn = open_test_connection(&db_path).unwrap();
+    conn.execute_batch(
+        "DROP TABLE embedding_job_state;
+         DROP TABLE embedding_job_done;
+         DELETE FROM schema_version WHERE version >= 50;",
+    )
+    .unwrap();
+    drop(conn);
+
+    let db = SymbolDatabase::new(&db_path).unwrap();
+    assert_eq!(db.get_schema_version().unwrap(), LATEST_SCHEMA_VERSION);
+    assert_eq!(db.embedding_job().unwrap(), None);
+    db.start_embedding_job(10, 0).unwrap();
+    assert_eq!(db.embedding_job().unwrap().unwrap().total, 10);
+}
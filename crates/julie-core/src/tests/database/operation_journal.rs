@@ -0,0 +1,76 @@
+use super::*;
+
+fn change(file_path: &str, before: &str, after: &str) -> JournalFile {
+    JournalFile {
+        file_path: file_path.to_string(),
+        before: before.to_string(),
+        after: after.to_string(),
+    }
+}
+
+#[test]
+fn test_last_operation_walks_back_through_rollbacks() {
+    let temp_dir = TempDir::new().unwrap();
+    let db = SymbolDatabase::new(temp_dir.path().join("test.db")).unwrap();
+    assert_eq!(db.last_operation().unwrap(), None);
+
+    let first = db
+        .record_operation(
+            "edit_file",
+            "edit_file src/a.rs",
+            &[change("src/a.rs", "fn a() {}\n", "fn a() { b() }\n")],
+        )
+        .unwrap();
+    let second = db
+        .record_operation(
+            "rename_symbol",
+            "rename_symbol 'b' → 'c'",
+            &[
+                change("src/b.rs", "fn b() {}\n", "fn c() {}\n"),
+                change("src/a.rs", "fn a() { b() }\n", "fn a() { c() }\n"),
+            ],
+        )
+        .unwrap();
+
+    let last = db.last_operation().unwrap().unwrap();
+    assert_eq!(last.id, second);
+    assert_eq!(last.kind, "rename_symbol");
+    assert_eq!(
+        last.files,
+        [
+            change("src/a.rs", "fn a() { b() }\n", "fn a() { c() }\n"),
+            change("src/b.rs", "fn b() {}\n", "fn c() {}\n"),
+        ]
+    );
+
+    db.mark_operation_rolled_back(second).unwrap();
+    assert_eq!(db.last_operation().unwrap().unwrap().id, first);
+    db.mark_operation_rolled_back(first).unwrap();
+    assert_eq!(db.last_operation().unwrap(), None);
+}
+
+#[test]
+fn test_record_operation_prunes_oldest_entries() {
+    let temp_dir = TempDir::new().unwrap();
+    let db = SymbolDatabase::new(temp_dir.path().join("test.db")).unwrap();
+    for n in 0..MAX_JOURNAL_OPERATIONS + 5 {
+        db.record_operation(
+            "edit_file",
+            &format!("edit {n}"),
+            &[change("src/a.rs", &n.to_string(), &(n + 1).to_string())],
+        )
+        .unwrap();
+    }
+
+    let (operations, files): (i64, i64) = db
+        .conn
+        .query_row(
+            "SELECT (SELECT COUNT(*) FROM operation_journal),
+                    (SELECT COUNT(*) FROM operation_journal_files)",
+            [],
+            |row| Ok((row.get(0)?, row.get(1)?)),
+        )
+        .unwrap();
+    assert_eq!(operations, MAX_JOURNAL_OPERATIONS);
+    assert_eq!(files, MAX_JOURNAL_OPERATIONS);
+}
@@ -373,3 +373,247 @@ fn test_get_reference_scores_large_batch() {
     assert_eq!(mixed_scores.len(), count);
     assert!(!mixed_scores.contains_key("nonexistent_0"));
 }
+
+/// reference_count follows relationship inserts, replacements and deletes
+/// without a recompute pass, skipping self-references and containment.
+#[test]
+fn test_reference_count_maintained_incrementally() {
+    let temp_dir = TempDir::new().unwrap();
+    let db_path = temp_dir.path().join("test.db");
+    let mut db = SymbolDatabase::new(&db_path).unwrap();
+
+    db.store_file_info(&FileInfo {
+        path: "test.rs".to_string(),
+        language: "rust".to_string(),
+        hash: "abc123".to_string(),
+        size: 100,
+        last_modified: 1234567890,
+        last_indexed: 0,
+        symbol_count: 3,
+        line_count: 0,
+        content: None,
+    })
+    .unwrap();
+
+    let symbols: Vec<_> = ["target", "user_a", "user_b"]
+        .iter()
+        .map(|id| {
+            symbol_builder(*id, *id, "test.rs")
+                .kind(SymbolKind::Function)
+                .language("rust")
+                .span(1, 0, 10, 1)
+                .bytes(0, 100)
+                .build()
+        })
+        .collect();
+    db.store_symbols(&symbols).unwrap();
+
+    let counts = |db: &SymbolDatabase| db.get_reference_counts(&["target", "user_a", "user_b"]);
+
+    db.store_relationships(&[
+        relationship_builder("r1", "user_a", "target")
+            .kind(RelationshipKind::Calls)
+            .build(),
+        relationship_builder("r2", "user_b", "target")
+            .kind(RelationshipKind::Uses)
+            .build(),
+        relationship_builder("r3", "user_a", "target")
+            .kind(RelationshipKind::Contains)
+            .build(),
+        relationship_builder("r4", "target", "target")
+            .kind(RelationshipKind::Calls)
+            .build(),
+    ])
+    .unwrap();
+    let after_insert = counts(&db).unwrap();
+    assert_eq!(
+        after_insert["target"], 2,
+        "contains and self-calls don't count"
+    );
+    assert_eq!(after_insert["user_a"], 0);
+
+    // Re-storing the same edge replaces the row instead of counting it twice.
+    db.store_relationships(&[relationship_builder("r1", "user_a", "target")
+        .kind(RelationshipKind::Calls)
+        .build()])
+        .unwrap();
+    assert_eq!(counts(&db).unwrap()["target"], 2);
+
+    // Replacing an edge with one to a different target moves the count.
+    db.store_relationships(&[relationship_builder("r1", "user_b", "user_a")
+        .kind(RelationshipKind::Calls)
+        .build()])
+        .unwrap();
+    let after_retarget = counts(&db).unwrap();
+    assert_eq!(after_retarget["target"], 1);
+    assert_eq!(after_retarget["user_a"], 1);
+
+    db.delete_relationships_for_file("test.rs").unwrap();
+    let after_delete = counts(&db).unwrap();
+    assert!(after_delete.values().all(|count| *count == 0));
+}
+
+/// The recompute pass agrees with the trigger-maintained counts.
+#[test]
+fn test_recompute_reference_counts_matches_triggers() {
+    let temp_dir = TempDir::new().unwrap();
+    let db_path = temp_dir.path().join("test.db");
+    let mut db = SymbolDatabase::new(&db_path).unwrap();
+
+    db.store_file_info(&FileInfo {
+        path: "test.rs".to_string(),
+        language: "rust".to_string(),
+        hash: "abc123".to_string(),
+        size: 100,
+        last_modified: 1234567890,
+        last_indexed: 0,
+        symbol_count: 3,
+        line_count: 0,
+        content: None,
+    })
+    .unwrap();
+    let symbols: Vec<_> = ["target", "caller1", "caller2"]
+        .iter()
+        .map(|id| {
+            symbol_builder(*id, *id, "test.rs")
+                .kind(SymbolKind::Function)
+                .language("rust")
+                .span(1, 0, 10, 1)
+                .bytes(0, 100)
+                .build()
+        })
+        .collect();
+    db.store_symbols(&symbols).unwrap();
+    db.store_relationships(&[
+        relationship_builder("r1", "caller1", "target")
+            .kind(RelationshipKind::Calls)
+            .build(),
+        relationship_builder("r2", "caller2", "target")
+            .kind(RelationshipKind::Calls)
+            .build(),
+    ])
+    .unwrap();
+
+    db.conn
+        .execute("UPDATE symbols SET reference_count = 0", [])
+        .unwrap();
+    db.recompute_reference_counts().unwrap();
+
+    let counts = db.get_reference_counts(&["target", "caller1"]).unwrap();
+    assert_eq!(counts["target"], 2);
+    assert_eq!(counts["caller1"], 0);
+}
+
+/// identifier_reference_count follows resolved identifiers as batches insert,
+/// retarget and delete them, and usage counts take the larger of the two
+/// counts rather than summing a call site's edge and identifier.
+#[test]
+fn test_identifier_reference_count_maintained_incrementally() {
+    let temp_dir = TempDir::new().unwrap();
+    let db_path = temp_dir.path().join("test.db");
+    let mut db = SymbolDatabase::new(&db_path).unwrap();
+
+    db.store_file_info(
+        &file_info_builder("test.rs")
+            .language("rust")
+            .hash("abc123")
+            .size(100)
+            .last_modified(1234567890)
+            .last_indexed(0)
+            .symbol_count(3)
+            .line_count(0)
+            .build(),
+    )
+    .unwrap();
+    db.store_file_info(
+        &file_info_builder("callers.rs")
+            .language("rust")
+            .hash("def456")
+            .size(100)
+            .last_modified(1234567890)
+            .last_indexed(0)
+            .symbol_count(0)
+            .line_count(0)
+            .build(),
+    )
+    .unwrap();
+    let symbols: Vec<_> = ["target", "user_a", "user_b"]
+        .iter()
+        .map(|id| {
+            symbol_builder(*id, *id, "test.rs")
+                .kind(SymbolKind::Function)
+                .language("rust")
+                .span(1, 0, 10, 1)
+                .bytes(0, 100)
+                .build()
+        })
+        .collect();
+    db.store_symbols(&symbols).unwrap();
+
+    let call = |id: &str, from: &str, to: &str, line: u32| {
+        identifier_builder(id, to, "callers.rs")
+            .kind(IdentifierKind::Call)
+            .language("rust")
+            .line(line)
+            .column(0, 6)
+            .containing_symbol_id(from)
+            .target_symbol_id(to)
+            .build()
+    };
+    let unresolved = identifier_builder("i4", "target", "callers.rs")
+        .kind(IdentifierKind::Call)
+        .language("rust")
+        .line(5)
+        .column(0, 6)
+        .containing_symbol_id("user_b")
+        .build();
+    db.bulk_store_identifiers(
+        &[
+            call("i1", "user_a", "target", 2),
+            call("i2", "user_b", "target", 3),
+            call("i3", "target", "target", 4),
+            unresolved,
+        ],
+        "",
+    )
+    .unwrap();
+    let usage = |db: &SymbolDatabase| db.get_usage_counts(&["target", "user_a", "user_b"]);
+    assert_eq!(
+        usage(&db).unwrap()["target"],
+        2,
+        "recursive and unresolved identifiers don't count"
+    );
+
+    // Re-storing the same identifier replaces the row instead of counting it twice.
+    db.bulk_store_identifiers(&[call("i1", "user_a", "target", 2)], "")
+        .unwrap();
+    assert_eq!(usage(&db).unwrap()["target"], 2);
+
+    // An edge for the same call site doesn't add to the usage count.
+    db.store_relationships(&[relationship_builder("r1", "user_a", "target")
+        .kind(RelationshipKind::Calls)
+        .build()])
+        .unwrap();
+    assert_eq!(usage(&db).unwrap()["target"], 2);
+
+    // Re-storing an identifier with a new target moves its count.
+    db.bulk_store_identifiers(&[call("i2", "user_b", "user_a", 3)], "")
+        .unwrap();
+    let after_retarget = usage(&db).unwrap();
+    assert_eq!(after_retarget["target"], 1);
+    assert_eq!(after_retarget["user_a"], 1);
+
+    db.conn
+        .execute("UPDATE symbols SET identifier_reference_count = 0", [])
+        .unwrap();
+    db.recompute_identifier_reference_counts().unwrap();
+    assert_eq!(usage(&db).unwrap(), after_retarget);
+
+    // Deleting the file holding the identifiers recounts what they targeted.
+    db.delete_single_file_atomic("test_ws", "callers.rs", Default::default())
+        .unwrap();
+    db.delete_relationships_for_file("test.rs").unwrap();
+    let after_delete = usage(&db).unwrap();
+    assert_eq!(after_delete.len(), 3);
+    assert!(after_delete.values().all(|count| *count == 0));
+}
@@ -300,3 +300,48 @@ async fn test_get_outgoing_relationships_for_symbols_batch() {
         "expected relationship from caller_b"
     );
 }
+
+#[test]
+fn test_orphaned_relationships_are_counted_and_purged() {
+    let temp_dir = TempDir::new().unwrap();
+    let db_path = temp_dir.path().join("test.db");
+    let mut db = SymbolDatabase::new(&db_path).unwrap();
+
+    db.store_file_info(&file_info_builder("main.rs").language("rust").build())
+        .unwrap();
+    let symbols: Vec<_> = ["caller", "callee", "gone"]
+        .iter()
+        .map(|id| {
+            symbol_builder(*id, *id, "main.rs")
+                .kind(SymbolKind::Function)
+                .language("rust")
+                .build()
+        })
+        .collect();
+    db.store_symbols(&symbols).unwrap();
+    db.store_relationships(&[
+        relationship_builder("rel_live", "caller", "callee")
+            .file_path("main.rs")
+            .build(),
+        relationship_builder("rel_orphan", "caller", "gone")
+            .file_path("main.rs")
+            .build(),
+    ])
+    .unwrap();
+    assert_eq!(db.count_orphaned_relationships().unwrap(), 0);
+
+    // Bulk writes run with foreign keys off; simulate one that died after
+    // removing a symbol but before its relationships.
+    db.conn.execute_batch("PRAGMA foreign_keys = OFF").unwrap();
+    db.conn
+        .execute("DELETE FROM symbols WHERE id = 'gone'", [])
+        .unwrap();
+    db.conn.execute_batch("PRAGMA foreign_keys = ON").unwrap();
+
+    assert_eq!(db.count_orphaned_relationships().unwrap(), 1);
+    assert_eq!(db.delete_orphaned_relationships().unwrap(), 1);
+    assert_eq!(db.count_orphaned_relationships().unwrap(), 0);
+    let remaining = db.get_outgoing_relationships("caller").unwrap();
+    assert_eq!(remaining.len(), 1);
+    assert_eq!(remaining[0].id, "rel_live");
+}
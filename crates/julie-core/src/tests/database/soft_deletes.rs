@@ -0,0 +1,37 @@
+use super::*;
+
+#[test]
+fn test_soft_delete_marks_keep_the_first_deletion_time() {
+    let temp_dir = TempDir::new().unwrap();
+    let db = SymbolDatabase::new(temp_dir.path().join("test.db")).unwrap();
+
+    db.mark_file_soft_deleted("src/a.rs", 100).unwrap();
+    db.mark_file_soft_deleted("src/a.rs", 250).unwrap();
+    db.mark_file_soft_deleted("src/b.rs", 200).unwrap();
+
+    assert_eq!(
+        db.soft_deleted_files().unwrap(),
+        HashMap::from([("src/a.rs".to_string(), 100), ("src/b.rs".to_string(), 200)]),
+        "a duplicate delete event must not extend the grace period"
+    );
+    assert_eq!(
+        db.soft_deletes_older_than(99).unwrap(),
+        Vec::<String>::new()
+    );
+    assert_eq!(db.soft_deletes_older_than(150).unwrap(), ["src/a.rs"]);
+    assert_eq!(
+        db.soft_deletes_older_than(200).unwrap(),
+        ["src/a.rs", "src/b.rs"]
+    );
+}
+
+#[test]
+fn test_clear_soft_delete_reports_whether_a_mark_existed() {
+    let temp_dir = TempDir::new().unwrap();
+    let db = SymbolDatabase::new(temp_dir.path().join("test.db")).unwrap();
+
+    db.mark_file_soft_deleted("src/a.rs", 100).unwrap();
+    assert!(db.clear_soft_delete("src/a.rs").unwrap());
+    assert!(!db.clear_soft_delete("src/a.rs").unwrap());
+    assert!(db.soft_deleted_files().unwrap().is_empty());
+}
@@ -0,0 +1,43 @@
+use super::*;
+use std::path::Path;
+
+#[test]
+fn test_relativize_stored_paths_rewrites_absolute_rows_under_the_root() {
+    let temp_dir = TempDir::new().unwrap();
+    let mut db = SymbolDatabase::new(temp_dir.path().join("test.db")).unwrap();
+    let inside = file_info_builder("/ws/src/a.rs").build();
+    let outside = file_info_builder("/elsewhere/b.rs").build();
+    let relative = file_info_builder("src/c.rs").build();
+    let symbol = symbol_builder("sym-a", "alpha", "/ws/src/a.rs").build();
+    db.bulk_store_fresh_atomic(
+        &[inside, outside, relative],
+        &[symbol],
+        &[],
+        &[],
+        &[],
+        "primary",
+    )
+    .unwrap();
+    db.mark_file_soft_deleted("/ws/src/gone.rs", 7).unwrap();
+
+    let changed = db.relativize_stored_paths(Path::new("/ws/")).unwrap();
+    assert_eq!(changed, 2, "a.rs and gone.rs lie under the root");
+
+    assert!(db.get_file_hash("src/a.rs").unwrap().is_some());
+    assert!(db.get_file_hash("/ws/src/a.rs").unwrap().is_none());
+    assert_eq!(
+        db.get_symbols_for_file("src/a.rs").unwrap()[0].file_path,
+        "src/a.rs"
+    );
+    assert!(
+        db.get_file_hash("/elsewhere/b.rs").unwrap().is_some(),
+        "files outside the root keep their absolute path"
+    );
+    assert!(db.get_file_hash("src/c.rs").unwrap().is_some());
+    assert_eq!(
+        db.soft_deleted_files().unwrap(),
+        HashMap::from([("src/gone.rs".to_string(), 7)])
+    );
+
+    assert_eq!(db.relativize_stored_paths(Path::new("/ws")).unwrap(), 0);
+}
@@ -0,0 +1,102 @@
+use super::*;
+
+fn hashed_symbol(id: &str, name: &str, file_path: &str, line: u32, hash: Option<&str>) -> Symbol {
+    Symbol {
+        body_hash: hash.map(str::to_string),
+        ..symbol_builder(id, name, file_path)
+            .span(line, 0, line + 5, 1)
+            .build()
+    }
+}
+
+#[test]
+fn test_symbol_copies_groups_by_body_hash_name_and_kind() {
+    let temp_dir = TempDir::new().unwrap();
+    let db_path = temp_dir.path().join("copies.db");
+    let mut db = SymbolDatabase::new(&db_path).unwrap();
+
+    for path in [
+        "src/util.rs",
+        "vendor/a/util.rs",
+        "vendor/b/util.rs",
+        "src/other.rs",
+    ] {
+        db.store_file_info(&file_info_builder(path).build())
+            .unwrap();
+    }
+
+    let symbols = [
+        hashed_symbol(
+            "main-copy",
+            "parse",
+            "src/util.rs",
+            10,
+            Some("sha256:parse"),
+        ),
+        hashed_symbol(
+            "vendor-b",
+            "parse",
+            "vendor/b/util.rs",
+            3,
+            Some("sha256:parse"),
+        ),
+        hashed_symbol(
+            "vendor-a",
+            "parse",
+            "vendor/a/util.rs",
+            7,
+            Some("sha256:parse"),
+        ),
+        // Same body under another name is a different symbol.
+        hashed_symbol(
+            "renamed",
+            "parse_str",
+            "src/other.rs",
+            1,
+            Some("sha256:parse"),
+        ),
+        hashed_symbol("edited", "parse", "src/other.rs", 20, Some("sha256:edited")),
+        hashed_symbol("bodiless", "parse", "src/other.rs", 40, None),
+    ];
+    db.store_symbols(&symbols).unwrap();
+
+    let groups = db
+        .symbol_copies(&[
+            "vendor-b".to_string(),
+            "renamed".to_string(),
+            "edited".to_string(),
+            "bodiless".to_string(),
+        ])
+        .unwrap();
+
+    assert_eq!(groups.len(), 1, "only vendor-b has copies: {groups:?}");
+    let locations: Vec<_> = groups["vendor-b"]
+        .iter()
+        .map(|copy| {
+            (
+                copy.symbol_id.as_str(),
+                copy.file_path.as_str(),
+                copy.start_line,
+            )
+        })
+        .collect();
+    assert_eq!(
+        locations,
+        [
+            ("main-copy", "src/util.rs", 10),
+            ("vendor-a", "vendor/a/util.rs", 7),
+            ("vendor-b", "vendor/b/util.rs", 3),
+        ]
+    );
+
+    let index_exists: bool = db
+        .conn
+        .query_row(
+            "SELECT EXISTS(SELECT 1 FROM sqlite_master
+             WHERE type = 'index' AND name = 'idx_symbols_body_hash')",
+            [],
+            |row| row.get(0),
+        )
+        .unwrap();
+    assert!(index_exists, "copy lookups need the body_hash index");
+}
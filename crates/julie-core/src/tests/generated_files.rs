@@ -0,0 +1,170 @@
+//! Tests for `[[generated]]` output matching, source mapping and generated-code
+//! detection (`generated_files`).
+
+use std::fs;
+use std::path::Path;
+
+use crate::generated_files::{
+    GeneratedOutputMatcher, discover_generated_files, has_generated_header, is_generated_file_name,
+    output_stem, source_path,
+};
+use crate::workspace_settings::WorkspaceSettings;
+
+fn settings(text: &str) -> WorkspaceSettings {
+    WorkspaceSettings::parse(text, Path::new("config.toml")).unwrap()
+}
+
+#[test]
+fn output_stem_strips_extensions_and_generator_suffixes() {
+    assert_eq!(output_stem("user.pb.go"), "user");
+    assert_eq!(output_stem("user_grpc.pb.go"), "user");
+    assert_eq!(output_stem("user_pb2.py"), "user");
+    assert_eq!(output_stem("user_pb2_grpc.py"), "user");
+    assert_eq!(output_stem("user_grpc_pb.js"), "user");
+    assert_eq!(output_stem("UserGrpc.java"), "User");
+    // A suffix alone is the name itself.
+    assert_eq!(output_stem("_pb.ts"), "_pb");
+}
+
+#[test]
+fn source_path_fills_dir_and_stem() {
+    let template = "proto/{dir}/{stem}.proto";
+    assert_eq!(
+        source_path(template, "acme/user/user_grpc.pb.go"),
+        "proto/acme/user/user.proto"
+    );
+    assert_eq!(source_path(template, "user_pb2.py"), "proto/user.proto");
+    assert_eq!(
+        source_path("schema.prisma", "models/user.ts"),
+        "schema.prisma"
+    );
+}
+
+#[test]
+fn matcher_requires_root_and_include_globs() {
+    let matcher = GeneratedOutputMatcher::new(&settings(
+        r#"
+[[generated]]
+root = "gen/go/"
+include = ["**/*.pb.go"]
+source = "proto/{dir}/{stem}.proto"
+
+[[generated]]
+root = "gen/py"
+"#,
+    ));
+
+    let file = matcher.generated_file("gen/go/user/user.pb.go").unwrap();
+    assert_eq!(file.root, "gen/go");
+    assert_eq!(file.source.as_deref(), Some("proto/user/user.proto"));
+    assert!(matcher.generated_file("gen/go/user/helpers.go").is_none());
+    assert!(matcher.generated_file("gen/gopher/user.pb.go").is_none());
+
+    let python = matcher.generated_file("gen/py/user_pb2.py").unwrap();
+    assert_eq!(python.source, None);
+    assert!(
+        python
+            .edit_refusal("gen/py/user_pb2.py")
+            .contains("regenerate")
+    );
+}
+
+#[test]
+fn invalid_generated_tables_are_rejected() {
+    for (text, key) in [
+        ("[[generated]]\nroot = \"\"", "generated.root"),
+        ("[[generated]]\nroot = \"../gen\"", "generated.root"),
+        ("[[generated]]\nroot = \"/abs/gen\"", "generated.root"),
+        (
+            "[[generated]]\nroot = \"gen\"\ninclude = [\"[\"]",
+            "generated.include",
+        ),
+        (
+            "[[generated]]\nroot = \"gen\"\nsource = \"proto/{name}.proto\"",
+            "generated.source",
+        ),
+    ] {
+        let err = WorkspaceSettings::parse(text, Path::new("config.toml"))
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains(key), "{text}: {err}");
+    }
+}
+
+#[test]
+fn discovery_indexes_ignored_output_directories() {
+    let dir = tempfile::tempdir().unwrap();
+    let root = dir.path().canonicalize().unwrap();
+    fs::create_dir_all(root.join(".julie")).unwrap();
+    fs::write(
+        root.join(".julie/config.toml"),
+        "[[generated]]\nroot = \"build/gen\"\ninclude = [\"*.pb.go\"]\n",
+    )
+    .unwrap();
+    fs::write(root.join(".gitignore"), "build/\n").unwrap();
+    fs::create_dir_all(root.join("build/gen/user")).unwrap();
+    fs::write(root.join("build/gen/user/user.pb.go"), "package user\n").unwrap();
+    fs::write(root.join("build/gen/user/notes.go"), "package user\n").unwrap();
+
+    let files = discover_generated_files(&root);
+    assert_eq!(files, vec![root.join("build/gen/user/user.pb.go")]);
+}
+
+#[test]
+fn generator_file_names_are_recognized() {
+    for path in [
+        "api/user_pb2.py",
+        "api/user_pb2_grpc.py",
+        "gen/user.pb.go",
+        "lib/models/user.g.dart",
+        "lib/models/user.freezed.dart",
+        "Forms/MainForm.Designer.cs",
+        "src/client.generated.ts",
+        "pkg/apis/v1/zz_generated.deepcopy.go",
+        "app/migrations/0001_initial.py",
+        "Data/Migrations/20240101120000_Init.cs",
+        "Data/Migrations/AppDbContextModelSnapshot.cs",
+        "db/migrate/20240101120000_create_users.rb",
+        "alembic/versions/ae1027a6acf_add_users.py",
+    ] {
+        assert!(is_generated_file_name(path), "{path} should be generated");
+    }
+    for path in [
+        "api/user.py",
+        "lib/models/user.dart",
+        "app/migrations/__init__.py",
+        "src/migrations.rs",
+        "api/versions/1_0.py",
+        "db/seeds.rb",
+    ] {
+        assert!(
+            !is_generated_file_name(path),
+            "{path} should not be generated"
+        );
+    }
+}
+
+#[test]
+fn generator_headers_are_recognized_past_a_license_block() {
+    let go = "// Copyright 2024 Acme\n// SPDX-License-Identifier: MIT\n\n\
+              // Code generated by protoc-gen-go. DO NOT EDIT.\npackage user\n";
+    assert!(has_generated_header(go));
+    assert!(has_generated_header(
+        "/**\n * @generated SignedSource<<abc>>\n */\n"
+    ));
+    assert!(has_generated_header(
+        "//------\n// <auto-generated>\n//     This code was generated by a tool.\n"
+    ));
+    assert!(has_generated_header(
+        "# Generated by the protocol buffer compiler.  DO NOT EDIT!\n"
+    ));
+
+    assert!(!has_generated_header(
+        "/// Returns a generated id.\nfn id() {}\n"
+    ));
+    let late = format!(
+        "{}// Code generated. DO NOT EDIT.\n",
+        "fn f() {}\n".repeat(40)
+    );
+    assert!(!has_generated_header(&late));
+}
@@ -0,0 +1,144 @@
+//! Tests for parsing `git log --name-only` output into per-file commit counts
+//! and `git diff --name-only` output into changed paths, and for `git blame`.
+
+use crate::git_history::{changed_files_since, parse_changed_paths, parse_commit_file_counts};
+
+#[test]
+fn counts_one_commit_per_file_occurrence() {
+    let output = "src/lib.rs\nsrc/main.rs\n\nsrc/lib.rs\n\n\nsrc\\win\\path.rs\n";
+    let counts = parse_commit_file_counts(output);
+    assert_eq!(counts.len(), 3);
+    assert_eq!(counts["src/lib.rs"], 2);
+    assert_eq!(counts["src/main.rs"], 1);
+    assert_eq!(counts["src/win/path.rs"], 1);
+    assert!(parse_commit_file_counts("").is_empty());
+}
+
+#[test]
+fn changed_paths_are_normalized_and_deduplicated() {
+    let output = "src/lib.rs\n\nsrc\\win\\path.rs\nsrc/lib.rs\n  docs/guide.md  \n";
+    assert_eq!(
+        parse_changed_paths(output),
+        ["src/lib.rs", "src/win/path.rs", "docs/guide.md"]
+    );
+    assert!(parse_changed_paths("").is_empty());
+}
+
+#[test]
+fn changed_files_since_rejects_revision_expressions() {
+    let root = std::env::temp_dir();
+    for commit in ["--output=/tmp/x", "HEAD~1", "main", ""] {
+        let err = changed_files_since(&root, commit).unwrap_err().to_string();
+        assert!(err.contains("Not a commit id"), "{commit}: {err}");
+    }
+}
+
+#[test]
+fn uncommitted_files_lists_changed_then_untracked_files() {
+    use crate::git_history::uncommitted_files;
+    use std::process::Command;
+
+    let dir = tempfile::tempdir().unwrap();
+    let root = dir.path();
+    let git = |args: &[&str]| {
+        let status = Command::new("git")
+            .arg("-C")
+            .arg(root)
+            .args(["-c", "user.name=Test", "-c", "user.email=test@example.com"])
+            .args(args)
+            .status()
+            .expect("git must be installed to run this test");
+        assert!(status.success(), "git {args:?} failed");
+    };
+
+    git(&["init", "-q"]);
+    std::fs::write(root.join(".gitignore"), "*.log\n").unwrap();
+    std::fs::write(root.join("untracked_first.rs"), "fn a() {}\n").unwrap();
+    assert_eq!(
+        uncommitted_files(root).unwrap(),
+        [".gitignore", "untracked_first.rs"],
+        "a repository without commits still reports untracked files"
+    );
+
+    std::fs::create_dir(root.join("src")).unwrap();
+    std::fs::write(root.join("src/edited.rs"), "fn a() {}\n").unwrap();
+    std::fs::write(root.join("src/clean.rs"), "fn b() {}\n").unwrap();
+    git(&["add", "."]);
+    git(&["commit", "-q", "-m", "initial"]);
+
+    std::fs::write(root.join("src/edited.rs"), "fn a() { todo!() }\n").unwrap();
+    std::fs::write(root.join("src/new.rs"), "fn c() {}\n").unwrap();
+    std::fs::write(root.join("debug.log"), "ignored\n").unwrap();
+    assert_eq!(
+        uncommitted_files(root).unwrap(),
+        ["src/edited.rs", "src/new.rs"]
+    );
+}
+
+#[test]
+fn blame_porcelain_keeps_committed_lines_by_final_line() {
+    use crate::git_history::{LineBlame, parse_blame_porcelain};
+
+    let output = "\
+4f1c2d3e4f1c2d3e4f1c2d3e4f1c2d3e4f1c2d3e 2 3 1
+author Ada Lovelace
+author-mail <ada@example.com>
+author-time 1700000000
+author-tz +0000
+summary initial
+filename src/lib.rs
+\t// TODO: retry
+0000000000000000000000000000000000000000 7 9 1
+author Not Committed Yet
+author-time 1800000000
+filename src/lib.rs
+\t// FIXME: wip
+";
+    let blame = parse_blame_porcelain(output);
+    assert_eq!(blame.len(), 1, "uncommitted lines are skipped");
+    assert_eq!(
+        blame[&3],
+        LineBlame {
+            author: "Ada Lovelace".to_string(),
+            author_time: 1_700_000_000,
+        }
+    );
+    assert!(parse_blame_porcelain("").is_empty());
+}
+
+#[test]
+fn blame_lines_reads_authors_of_requested_lines() {
+    use crate::git_history::blame_lines;
+    use std::process::Command;
+
+    let dir = tempfile::tempdir().unwrap();
+    let root = dir.path();
+    let git = |args: &[&str]| {
+        let status = Command::new("git")
+            .arg("-C")
+            .arg(root)
+            .args([
+                "-c",
+                "user.name=Grace",
+                "-c",
+                "user.email=grace@example.com",
+            ])
+            .args(args)
+            .status()
+            .expect("git must be installed to run this test");
+        assert!(status.success(), "git {args:?} failed");
+    };
+
+    git(&["init", "-q"]);
+    std::fs::write(root.join("lib.rs"), "fn a() {}\n// TODO: b\n").unwrap();
+    git(&["add", "."]);
+    git(&["commit", "-q", "-m", "initial"]);
+    std::fs::write(root.join("lib.rs"), "fn a() {}\n// TODO: b\n// HACK: c\n").unwrap();
+
+    let blame = blame_lines(root, "lib.rs", &[2, 3]).unwrap();
+    assert_eq!(blame.len(), 1, "the uncommitted line has no owner yet");
+    assert_eq!(blame[&2].author, "Grace");
+    assert!(blame[&2].author_time > 0);
+    assert!(blame_lines(root, "lib.rs", &[]).unwrap().is_empty());
+    assert!(blame_lines(root, "missing.rs", &[1]).is_err());
+}
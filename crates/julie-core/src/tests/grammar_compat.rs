@@ -0,0 +1,66 @@
+//! Tests for tree-sitter grammar compatibility checks (`grammar_compat`).
+//!
+//! `every_pinned_grammar_is_compatible` is the upgrade harness: it fails when
+//! a grammar bump drops or renames a node kind the extractors depend on.
+
+use crate::grammar_compat::{
+    GRAMMAR_EXPECTATIONS, GrammarProblem, check_all_grammars, check_grammar, grammar_compatibility,
+};
+
+#[test]
+fn every_pinned_grammar_is_compatible() {
+    let report = check_all_grammars();
+    assert_eq!(report.checked, GRAMMAR_EXPECTATIONS.len());
+    let issues: Vec<String> = report.issues.iter().map(ToString::to_string).collect();
+    assert!(
+        report.is_compatible(),
+        "grammar issues:\n{}",
+        issues.join("\n")
+    );
+}
+
+#[test]
+fn missing_node_kinds_are_reported() {
+    let rust = julie_extractors::language::get_tree_sitter_language("rust").unwrap();
+    let issue = check_grammar(
+        "rust",
+        &rust,
+        &[
+            "function_item",
+            "function_definition_renamed",
+            "struct_item",
+        ],
+    )
+    .expect("an unknown kind is an issue");
+
+    assert_eq!(issue.language, "rust");
+    assert_eq!(
+        issue.problem,
+        GrammarProblem::MissingNodeKinds(vec!["function_definition_renamed".to_string()])
+    );
+    assert!(issue.to_string().contains("function_definition_renamed"));
+}
+
+#[test]
+fn pinned_languages_are_unique_and_non_empty() {
+    let mut languages: Vec<&str> = GRAMMAR_EXPECTATIONS
+        .iter()
+        .map(|expectation| expectation.language)
+        .collect();
+    assert!(
+        GRAMMAR_EXPECTATIONS
+            .iter()
+            .all(|expectation| !expectation.node_kinds.is_empty())
+    );
+    languages.sort_unstable();
+    languages.dedup();
+    assert_eq!(languages.len(), GRAMMAR_EXPECTATIONS.len());
+}
+
+#[test]
+fn process_wide_report_is_cached() {
+    let first = grammar_compatibility();
+    let second = grammar_compatibility();
+    assert!(std::ptr::eq(first, second));
+    assert_eq!(first.checked, GRAMMAR_EXPECTATIONS.len());
+}
@@ -0,0 +1,61 @@
+//! Tests for the process memory budget (`memory_budget`).
+
+use crate::memory_budget::{MemoryBudget, MemoryComponent, MemoryUsage};
+
+#[test]
+fn charges_are_tracked_per_component_and_released_on_drop() {
+    let budget = MemoryBudget::new(Some(1_000));
+
+    budget.add(MemoryComponent::ParserPool, 100);
+    let mut batch = budget.charge(MemoryComponent::BatchBuffers, 300);
+    {
+        let _embedding = budget.charge(MemoryComponent::EmbeddingBatches, 50);
+        assert_eq!(budget.used_bytes(), 450);
+    }
+    batch.resize(500);
+    assert_eq!(
+        budget.usage(),
+        MemoryUsage {
+            limit_bytes: Some(1_000),
+            parser_pool_bytes: 100,
+            batch_buffer_bytes: 500,
+            embedding_batch_bytes: 0,
+            peak_bytes: 600,
+            shrunk_batches: 0,
+        }
+    );
+    drop(batch);
+    budget.release(MemoryComponent::ParserPool, 500);
+    assert_eq!(budget.used_bytes(), 0, "releases never underflow");
+    assert_eq!(budget.usage().peak_bytes, 600);
+}
+
+#[test]
+fn batches_shrink_as_usage_nears_the_budget() {
+    let budget = MemoryBudget::new(Some(1_000));
+    assert_eq!(budget.batch_size(100, 10), 100);
+
+    let mut batch = budget.charge(MemoryComponent::BatchBuffers, 500);
+    assert_eq!(budget.batch_size(100, 10), 100, "full size up to half");
+    batch.resize(750);
+    assert_eq!(budget.batch_size(100, 10), 50);
+    batch.resize(980);
+    assert_eq!(budget.batch_size(100, 10), 10, "never below the minimum");
+    batch.resize(2_000);
+    assert!(budget.over_budget());
+    assert_eq!(budget.batch_size(100, 0), 1, "always at least one");
+    assert_eq!(budget.usage().shrunk_batches, 3);
+
+    drop(batch);
+    assert!(!budget.over_budget());
+    assert_eq!(budget.batch_size(100, 10), 100);
+}
+
+#[test]
+fn without_a_limit_nothing_shrinks() {
+    let budget = MemoryBudget::new(None);
+    let _batch = budget.charge(MemoryComponent::BatchBuffers, u64::MAX / 2);
+    assert_eq!(budget.batch_size(100, 10), 100);
+    assert!(!budget.over_budget());
+    assert_eq!(budget.usage().limit_bytes, None);
+}
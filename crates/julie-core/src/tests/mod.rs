@@ -1,9 +1,21 @@
+mod background_tasks;
 mod bulk_store_types_tdd;
 mod bulk_store_types_tests;
 mod database;
 mod database_init_race;
 mod database_lightweight_query;
 mod database_row_mapping;
+mod generated_files;
+mod git_history;
+mod grammar_compat;
+mod memory_budget;
 mod memory_vectors;
+mod parser_pool;
 mod paths;
+mod preprocessor;
+mod response_budget;
+mod supervisor;
+mod symbol_ids;
+mod telemetry;
 mod vector_storage;
+mod workspace_settings;
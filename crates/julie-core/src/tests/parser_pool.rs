@@ -0,0 +1,106 @@
+//! Tests for the per-language tree-sitter parser pool (`parser_pool`).
+
+use crate::memory_budget::MemoryBudget;
+use crate::parser_pool::{PARSER_ESTIMATE_BYTES, ParserPool, ParserPoolStats};
+
+#[test]
+fn returned_parsers_are_reused_per_language() {
+    let pool = ParserPool::new(4);
+
+    for source in ["fn a() {}", "fn b() { let x = 1; }"] {
+        let mut parser = pool.checkout("rust").unwrap();
+        let tree = parser.parse(source, None).unwrap();
+        assert_eq!(tree.root_node().kind(), "source_file");
+        assert!(!tree.root_node().has_error());
+    }
+    assert_eq!(
+        pool.stats(),
+        ParserPoolStats {
+            created: 1,
+            reused: 1,
+            idle: 1
+        }
+    );
+
+    let mut python = pool.checkout("python").unwrap();
+    let tree = python.parse("def a():\n    pass\n", None).unwrap();
+    assert_eq!(tree.root_node().kind(), "module");
+    assert_eq!(pool.stats().created, 2, "each language has its own parsers");
+}
+
+#[test]
+fn concurrent_checkouts_get_separate_parsers_and_idle_is_capped() {
+    let pool = ParserPool::new(2);
+
+    let parsers: Vec<_> = (0..3).map(|_| pool.checkout("rust").unwrap()).collect();
+    assert_eq!(pool.stats().created, 3);
+    drop(parsers);
+    assert_eq!(pool.stats().idle, 2, "the third parser is dropped");
+}
+
+#[test]
+fn warm_builds_idle_parsers_up_to_the_cap() {
+    let pool = ParserPool::new(3);
+
+    pool.warm("rust", 5).unwrap();
+    assert_eq!(
+        pool.stats(),
+        ParserPoolStats {
+            created: 3,
+            reused: 0,
+            idle: 3
+        }
+    );
+    pool.warm("rust", 2).unwrap();
+    assert_eq!(pool.stats().created, 3, "already warm");
+
+    let _parser = pool.checkout("rust").unwrap();
+    assert_eq!(pool.stats().reused, 1);
+}
+
+#[test]
+fn unknown_languages_fail_without_touching_the_pool() {
+    let pool = ParserPool::new(2);
+
+    assert!(pool.checkout("not-a-language").is_err());
+    assert!(pool.warm("not-a-language", 1).is_err());
+    assert_eq!(pool.stats(), ParserPoolStats::default());
+}
+
+#[test]
+fn a_parser_dropped_mid_use_is_reset_before_reuse() {
+    let pool = ParserPool::new(2);
+
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        let mut parser = pool.checkout("rust").unwrap();
+        parser.parse("fn broken(", None).unwrap();
+        panic!("extractor crashed");
+    }));
+    assert!(result.is_err());
+
+    let mut parser = pool.checkout("rust").unwrap();
+    let tree = parser.parse("struct S;", None).unwrap();
+    assert!(!tree.root_node().has_error());
+    assert_eq!(pool.stats().reused, 1);
+}
+
+#[test]
+fn parsers_count_against_the_budget_and_are_not_kept_over_it() {
+    let budget: &'static MemoryBudget =
+        Box::leak(Box::new(MemoryBudget::new(Some(2 * PARSER_ESTIMATE_BYTES))));
+    let pool = ParserPool::with_budget(4, Some(budget));
+
+    drop(pool.checkout("rust").unwrap());
+    assert_eq!(pool.stats().idle, 1, "idle parsers keep their charge");
+    assert_eq!(budget.used_bytes(), PARSER_ESTIMATE_BYTES);
+
+    let parsers: Vec<_> = (0..2).map(|_| pool.checkout("rust").unwrap()).collect();
+    assert!(budget.over_budget());
+    drop(parsers);
+    assert_eq!(
+        pool.stats().idle,
+        1,
+        "only the parser returned under budget"
+    );
+    assert_eq!(budget.used_bytes(), PARSER_ESTIMATE_BYTES);
+}
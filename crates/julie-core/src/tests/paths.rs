@@ -1,7 +1,14 @@
-//! Tests for `RegistryPaths` path helpers (Phase 3b + 3c).
+//! Tests for `RegistryPaths` path helpers (Phase 3b + 3c) and lossless
+//! storage encoding of non-UTF-8 paths.
 
-use crate::paths::RegistryPaths;
-use std::path::PathBuf;
+use crate::paths::{
+    RegistryPaths, decode_stored_path, display_stored_path, encode_path_lossless,
+    normalize_stored_path, normalize_stored_path_within, stored_path_to_absolute,
+    to_relative_unix_style,
+};
+use std::borrow::Cow;
+use std::ffi::OsStr;
+use std::path::{Path, PathBuf};
 
 fn fixed_home() -> RegistryPaths {
     RegistryPaths::with_home(PathBuf::from("/tmp/julie-home-3b"))
@@ -89,3 +96,142 @@ fn workspace_leader_lock_is_inside_workspace_index_dir_and_distinct_from_rebuild
         "workspace_leader_lock must not alias the Tantivy rebuild lock"
     );
 }
+
+#[test]
+fn utf8_paths_are_stored_verbatim() {
+    for path in ["src/main.rs", "docs/résumé.md", "日本語/ファイル.ts", ""] {
+        let encoded = encode_path_lossless(OsStr::new(path));
+        assert_eq!(encoded, path, "valid UTF-8 must not change representation");
+        assert_eq!(decode_stored_path(&encoded), PathBuf::from(path));
+        assert_eq!(display_stored_path(&encoded), path);
+    }
+}
+
+#[test]
+fn escape_range_characters_round_trip() {
+    let path = "odd/\u{10FF41}\u{10FFFF}.rs";
+    let encoded = encode_path_lossless(OsStr::new(path));
+    assert_ne!(encoded, path, "escape-range characters must be escaped");
+    assert_eq!(decode_stored_path(&encoded), PathBuf::from(path));
+    assert_eq!(display_stored_path(&encoded), path);
+}
+
+#[test]
+fn normalize_stored_path_strips_windows_prefixes() {
+    let cases = [
+        (r"\\?\C:\ws\src\main.rs", "C:/ws/src/main.rs"),
+        ("//?/C:/ws/src/main.rs", "C:/ws/src/main.rs"),
+        (r"\\?\UNC\server\share\lib.rs", "//server/share/lib.rs"),
+        ("//?/UNC/server/share/lib.rs", "//server/share/lib.rs"),
+        (r"C:\ws\src\main.rs", "C:/ws/src/main.rs"),
+        (r"\\server\share\lib.rs", "//server/share/lib.rs"),
+    ];
+    for (stored, expected) in cases {
+        assert_eq!(normalize_stored_path(stored), expected, "input: {stored}");
+    }
+}
+
+#[test]
+fn normalize_stored_path_borrows_already_normalized_paths() {
+    for path in [
+        "src/main.rs",
+        "C:/ws/src/main.rs",
+        "//server/share/lib.rs",
+        "",
+    ] {
+        assert!(
+            matches!(normalize_stored_path(path), Cow::Borrowed(p) if p == path),
+            "{path} must be returned unchanged"
+        );
+    }
+}
+
+#[test]
+fn normalize_stored_path_within_relativizes_paths_under_the_root() {
+    let cases = [
+        (r"\\?\C:\ws\src\main.rs", r"C:\ws", "src/main.rs"),
+        ("c:/WS/src/main.rs", "C:/ws/", "src/main.rs"),
+        ("/home/dev/ws/lib.rs", "/home/dev/ws", "lib.rs"),
+        (
+            "/home/dev/wsx/lib.rs",
+            "/home/dev/ws",
+            "/home/dev/wsx/lib.rs",
+        ),
+        ("/home/dev/ws", "/home/dev/ws", "/home/dev/ws"),
+        ("src/main.rs", "/home/dev/ws", "src/main.rs"),
+    ];
+    for (stored, root, expected) in cases {
+        assert_eq!(
+            normalize_stored_path_within(stored, Path::new(root)),
+            expected,
+            "input: {stored} under {root}"
+        );
+    }
+}
+
+#[cfg(not(windows))]
+#[test]
+fn normalize_stored_path_keeps_backslashes_in_unix_file_names() {
+    assert_eq!(
+        normalize_stored_path(r"src/odd\name.rs"),
+        r"src/odd\name.rs"
+    );
+}
+
+#[cfg(unix)]
+mod non_utf8 {
+    use super::*;
+    use std::os::unix::ffi::{OsStrExt, OsStringExt};
+
+    /// `café.rs` in Latin-1: `0xE9` is not valid UTF-8 on its own.
+    fn latin1_name() -> std::ffi::OsString {
+        std::ffi::OsString::from_vec(b"caf\xE9.rs".to_vec())
+    }
+
+    #[test]
+    fn non_utf8_bytes_round_trip_exactly() {
+        let original = Path::new("src").join(latin1_name());
+        let encoded = encode_path_lossless(original.as_os_str());
+        let decoded = decode_stored_path(&encoded);
+        assert_eq!(
+            decoded.as_os_str().as_bytes(),
+            original.as_os_str().as_bytes()
+        );
+    }
+
+    #[test]
+    fn distinct_non_utf8_names_stay_distinct() {
+        // Lossy conversion maps both to "caf\u{FFFD}.rs"; storage keys must not collide.
+        let latin1 = encode_path_lossless(&latin1_name());
+        let other = encode_path_lossless(OsStr::from_bytes(b"caf\xE8.rs"));
+        assert_ne!(latin1, other);
+    }
+
+    #[test]
+    fn display_shows_raw_bytes_as_hex() {
+        let encoded = encode_path_lossless(&latin1_name());
+        assert_eq!(display_stored_path(&encoded), "caf\\xE9.rs");
+    }
+
+    #[test]
+    fn to_relative_unix_style_accepts_non_utf8_files() {
+        let workspace = tempfile::tempdir().unwrap();
+        let dir = workspace.path().join("src");
+        std::fs::create_dir_all(&dir).unwrap();
+        let file = dir.join(latin1_name());
+        if std::fs::write(&file, "fn main() {}").is_err() {
+            // Some filesystems (e.g. strict UTF-8 ones) reject such names.
+            return;
+        }
+
+        let stored = to_relative_unix_style(&file, workspace.path()).unwrap();
+        assert!(stored.starts_with("src/caf"));
+
+        let resolved = stored_path_to_absolute(workspace.path(), &stored);
+        assert_eq!(
+            std::fs::read_to_string(resolved).unwrap(),
+            "fn main() {}",
+            "stored path must resolve back to the same file"
+        );
+    }
+}
@@ -0,0 +1,49 @@
+//! Tests for `#if` evaluation under a define set (`preprocessor`).
+
+use crate::preprocessor::{DefineSet, evaluate_condition};
+
+fn defines(entries: &[&str]) -> DefineSet {
+    DefineSet::parse(entries).unwrap()
+}
+
+#[test]
+fn parses_compiler_style_definitions() {
+    let set = defines(&["DEBUG", "LEVEL=3", " NAME = widget "]);
+    assert_eq!(set.value("DEBUG"), Some("1"));
+    assert_eq!(set.value("LEVEL"), Some("3"));
+    assert_eq!(set.value("NAME"), Some("widget"));
+    assert!(!set.is_defined("RELEASE"));
+
+    assert!(DefineSet::parse(["=1"]).is_err());
+    assert!(DefineSet::parse(["A B"]).is_err());
+}
+
+#[test]
+fn evaluates_defined_and_macro_values() {
+    let set = defines(&["DEBUG", "LEVEL=3", "ALIAS=LEVEL", "EMPTY="]);
+    let eval = |expression| evaluate_condition(expression, &set);
+
+    assert_eq!(eval("defined(DEBUG)"), Some(true));
+    assert_eq!(eval("defined RELEASE"), Some(false));
+    assert_eq!(eval("!defined(DEBUG) || LEVEL >= 2"), Some(true));
+    assert_eq!(eval("LEVEL == 3 && ALIAS * 2 == 6"), Some(true));
+    // Undefined macros are 0, as in the preprocessor.
+    assert_eq!(eval("RELEASE"), Some(false));
+    assert_eq!(eval("0x10 > 010 /* octal */"), Some(true));
+    assert_eq!(eval("(LEVEL - 3) % 2"), Some(false));
+}
+
+#[test]
+fn leaves_undecidable_conditions_unknown() {
+    let set = defines(&["EMPTY=", "CALL=f(1)"]);
+    let eval = |expression| evaluate_condition(expression, &set);
+
+    assert_eq!(eval("__has_include(<stdio.h>)"), None);
+    assert_eq!(eval("VERSION_AT_LEAST(2, 1)"), None);
+    assert_eq!(eval("EMPTY"), None);
+    assert_eq!(eval("CALL"), None);
+    assert_eq!(eval("LEVEL >"), None);
+    // Short-circuiting still decides around an unknown operand.
+    assert_eq!(eval("0 && __has_include(<stdio.h>)"), Some(false));
+    assert_eq!(eval("1 || CALL"), Some(true));
+}
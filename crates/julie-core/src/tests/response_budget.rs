@@ -0,0 +1,87 @@
+//! Tests for token-budget-aware response shaping (`response_budget`).
+
+use crate::response_budget::{BudgetedEntry, BudgetedResponse, ShapingStage};
+
+fn entry(file: &str, line: u32) -> BudgetedEntry {
+    BudgetedEntry::new(file, format!("{file}:{line} (function)"))
+        .with_doc(Some(
+            "/// Computes the thing.\n/// Handles every edge case.",
+        ))
+        .with_context(Some(
+            "fn compute_the_thing(input: &str) -> usize {\n    input.len()\n}",
+        ))
+}
+
+fn response(max_tokens: Option<u32>) -> BudgetedResponse {
+    let mut response =
+        BudgetedResponse::new("6 matches for \"compute\":").with_max_tokens(max_tokens);
+    for (file, line) in [
+        ("src/a.rs", 10),
+        ("src/a.rs", 40),
+        ("src/b.rs", 5),
+        ("src/b.rs", 90),
+        ("src/c.rs", 1),
+        ("src/d.rs", 7),
+    ] {
+        response.push(entry(file, line));
+    }
+    response
+}
+
+#[test]
+fn no_budget_renders_everything() {
+    let shaped = response(None).render();
+    assert_eq!(shaped.stage, ShapingStage::Full);
+    assert!(shaped.omitted.is_empty());
+    assert!(shaped.text.contains("input.len()"));
+    assert!(shaped.text.contains("Handles every edge case"));
+    assert!(!shaped.text.contains("[budget]"));
+}
+
+#[test]
+fn generous_budget_is_untouched() {
+    let unbounded = response(None).render();
+    let shaped = response(Some(10_000)).render();
+    assert_eq!(shaped.stage, ShapingStage::Full);
+    assert_eq!(shaped.text, unbounded.text);
+}
+
+#[test]
+fn context_lines_are_dropped_first() {
+    let full_tokens = response(None).render().text.len() / 4;
+    let shaped = response(Some((full_tokens * 3 / 4) as u32)).render();
+    assert_eq!(shaped.stage, ShapingStage::DroppedContext);
+    assert!(!shaped.text.contains("input.len()"));
+    assert!(shaped.text.contains("Handles every edge case"));
+    assert_eq!(shaped.omitted.context_lines, 18);
+    assert_eq!(shaped.omitted.doc_lines, 0);
+    assert!(shaped.text.contains("[budget] Omitted to fit max_tokens="));
+    assert!(shaped.text.contains("18 context lines"));
+}
+
+#[test]
+fn tight_budget_collapses_by_file_and_reports_omissions() {
+    let shaped = response(Some(70)).render();
+    assert_eq!(shaped.stage, ShapingStage::CollapsedByFile);
+    assert!(shaped.text.contains("src/a.rs (2 results)"));
+    assert!(shaped.text.contains("src/c.rs (1 result)"));
+    assert_eq!(shaped.omitted.collapsed_entries, 6);
+    assert_eq!(shaped.omitted.doc_lines, 12);
+    assert!(shaped.text.contains("6 results collapsed by file"));
+}
+
+#[test]
+fn tiny_budget_keeps_highest_ranked_file() {
+    let shaped = response(Some(1)).render();
+    assert_eq!(shaped.stage, ShapingStage::Truncated);
+    assert!(shaped.text.contains("src/a.rs (2 results)"));
+    assert!(!shaped.text.contains("src/d.rs"));
+    assert_eq!(shaped.omitted.dropped_files, 3);
+    assert!(shaped.text.contains("3 files dropped"));
+}
+
+#[test]
+fn zero_budget_disables_shaping() {
+    let shaped = response(Some(0)).render();
+    assert_eq!(shaped.stage, ShapingStage::Full);
+}
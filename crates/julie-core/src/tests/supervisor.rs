@@ -0,0 +1,114 @@
+//! Tests for the background task watchdog (`supervisor`).
+
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::time::Duration;
+
+use crate::supervisor::{RestartPolicy, Subsystem, Supervisor, SupervisorExit, panic_message};
+
+const FAST: RestartPolicy = RestartPolicy::new(2, Duration::from_millis(1));
+
+#[tokio::test]
+async fn panicking_task_is_restarted_until_it_succeeds() {
+    let supervisor = Supervisor::new();
+    let attempts = Arc::new(AtomicU32::new(0));
+
+    let result = supervisor
+        .supervise(Subsystem::Embeddings, "ws_a", FAST, || {
+            let attempts = attempts.clone();
+            tokio::task::spawn_blocking(move || {
+                if attempts.fetch_add(1, Ordering::SeqCst) == 0 {
+                    panic!("model crashed");
+                }
+                42
+            })
+        })
+        .await;
+
+    assert_eq!(result, Ok(42));
+    assert_eq!(attempts.load(Ordering::SeqCst), 2);
+    let snapshot = supervisor.snapshot();
+    assert_eq!(snapshot.len(), 1);
+    assert_eq!(snapshot[0].subsystem, Subsystem::Embeddings);
+    assert_eq!(
+        (
+            snapshot[0].panics,
+            snapshot[0].restarts,
+            snapshot[0].unrecovered
+        ),
+        (1, 1, 0)
+    );
+    assert_eq!(snapshot[0].last_panic.task, "ws_a");
+    assert_eq!(snapshot[0].last_panic.message, "model crashed");
+    assert!(snapshot[0].last_panic.recovered);
+}
+
+#[tokio::test]
+async fn supervisor_gives_up_after_max_restarts() {
+    let supervisor = Supervisor::new();
+    let attempts = Arc::new(AtomicU32::new(0));
+
+    let result: Result<(), _> = supervisor
+        .supervise(Subsystem::Watcher, "events", FAST, || {
+            let attempts = attempts.clone();
+            tokio::spawn(async move {
+                let attempt = attempts.fetch_add(1, Ordering::SeqCst);
+                panic!("attempt {attempt}");
+            })
+        })
+        .await;
+
+    assert_eq!(result, Err(SupervisorExit::GaveUp("attempt 2".to_string())));
+    assert_eq!(
+        attempts.load(Ordering::SeqCst),
+        3,
+        "first run plus two restarts"
+    );
+    let watcher = &supervisor.snapshot()[0];
+    assert_eq!(
+        (watcher.panics, watcher.restarts, watcher.unrecovered),
+        (3, 2, 1)
+    );
+    assert!(!watcher.last_panic.recovered);
+}
+
+#[tokio::test]
+async fn cancelled_task_is_not_counted_as_panic() {
+    let supervisor = Supervisor::new();
+
+    let result: Result<(), _> = supervisor
+        .supervise(Subsystem::Embeddings, "ws_a", FAST, || {
+            let handle = tokio::spawn(std::future::pending::<()>());
+            handle.abort();
+            handle
+        })
+        .await;
+
+    assert_eq!(result, Err(SupervisorExit::Cancelled));
+    assert!(supervisor.snapshot().is_empty());
+}
+
+#[test]
+fn recorded_panics_are_kept_in_subsystem_order() {
+    let supervisor = Supervisor::new();
+    supervisor.record_panic(Subsystem::Watcher, "events", "boom", true);
+    supervisor.record_panic(Subsystem::Parser, "src/lib.rs", "bad node", true);
+    supervisor.record_panic(Subsystem::Parser, "src/main.rs", "bad node", true);
+
+    let snapshot = supervisor.snapshot();
+    let subsystems: Vec<Subsystem> = snapshot.iter().map(|entry| entry.subsystem).collect();
+    assert_eq!(subsystems, [Subsystem::Parser, Subsystem::Watcher]);
+    assert_eq!(snapshot[0].panics, 2);
+    assert_eq!(snapshot[0].last_panic.task, "src/main.rs");
+}
+
+#[test]
+fn panic_message_reads_string_payloads() {
+    let owned = std::panic::catch_unwind(|| panic!("owned {}", 1)).unwrap_err();
+    let literal = std::panic::catch_unwind(|| panic!("literal")).unwrap_err();
+    let other = std::panic::catch_unwind(|| std::panic::panic_any(7_u8)).unwrap_err();
+
+    assert_eq!(panic_message(owned), "owned 1");
+    assert_eq!(panic_message(literal), "literal");
+    assert_eq!(panic_message(other), "non-string panic payload");
+}
@@ -0,0 +1,89 @@
+//! Tests for deterministic symbol IDs (`symbol_ids`).
+
+use julie_extractors::{Symbol, SymbolKind};
+
+use crate::symbol_ids::assign_stable_symbol_ids;
+use crate::test_support::symbol_builder;
+
+/// `struct Widget` with a `render` method, the method starting at `offset`.
+fn widget(offset: u32) -> Vec<Symbol> {
+    vec![
+        symbol_builder("pos-widget", "Widget", "src/widget.rs")
+            .kind(SymbolKind::Struct)
+            .bytes(offset, offset + 200)
+            .build(),
+        symbol_builder(format!("pos-render-{offset}"), "render", "src/widget.rs")
+            .kind(SymbolKind::Method)
+            .bytes(offset + 40, offset + 120)
+            .signature("fn render(&self)")
+            .parent_id("pos-widget")
+            .build(),
+    ]
+}
+
+#[test]
+fn ids_survive_a_shifted_position() {
+    let mut before = widget(0);
+    let mut after = widget(512);
+    assign_stable_symbol_ids(&mut before);
+    assign_stable_symbol_ids(&mut after);
+
+    assert_eq!(before[0].id, after[0].id);
+    assert_eq!(before[1].id, after[1].id);
+    assert_eq!(after[1].parent_id.as_deref(), Some(after[0].id.as_str()));
+    assert_eq!(before[1].id.len(), 32);
+}
+
+#[test]
+fn qualified_name_kind_and_path_distinguish_symbols() {
+    let mut free = widget(0);
+    free[1].parent_id = None;
+    let mut nested = widget(0);
+    let mut other_file = widget(0);
+    for symbol in &mut other_file {
+        symbol.file_path = "src/other.rs".to_string();
+    }
+    assign_stable_symbol_ids(&mut free);
+    assign_stable_symbol_ids(&mut nested);
+    assign_stable_symbol_ids(&mut other_file);
+
+    assert_ne!(free[1].id, nested[1].id);
+    assert_ne!(nested[1].id, other_file[1].id);
+}
+
+#[test]
+fn overloads_and_duplicates_get_distinct_ids() {
+    let overload = |id: &str, signature: &str, start: u32| {
+        symbol_builder(id, "parse", "src/parse.cs")
+            .kind(SymbolKind::Method)
+            .bytes(start, start + 10)
+            .signature(signature)
+            .build()
+    };
+    let mut symbols = vec![
+        overload("a", "int parse(string s)", 0),
+        overload("b", "int parse(string s, int radix)", 20),
+        overload("c", "int parse(string s)", 40),
+    ];
+    assign_stable_symbol_ids(&mut symbols);
+
+    assert_ne!(symbols[0].id, symbols[1].id);
+    assert_ne!(symbols[0].id, symbols[2].id);
+
+    // The first duplicate in source order keeps the plain ID.
+    let mut alone = vec![overload("a", "int parse(string s)", 0)];
+    assign_stable_symbol_ids(&mut alone);
+    assert_eq!(alone[0].id, symbols[0].id);
+}
+
+#[test]
+fn signature_whitespace_does_not_change_the_id() {
+    let mut compact = widget(0);
+    let mut spaced = widget(0);
+    spaced[1].signature = Some("fn  render(\n    &self\n)".to_string());
+    compact[1].signature = Some("fn render( &self )".to_string());
+    assign_stable_symbol_ids(&mut compact);
+    assign_stable_symbol_ids(&mut spaced);
+
+    assert_eq!(compact[1].id, spaced[1].id);
+}
@@ -0,0 +1,107 @@
+//! Tests for opt-in local metrics (`telemetry`).
+
+use std::path::Path;
+use std::time::Duration;
+
+use crate::telemetry::{LATENCY_BUCKETS_MS, Telemetry, TelemetryFormat, TelemetrySink};
+
+fn sink(dir: &Path, format: TelemetryFormat) -> TelemetrySink {
+    TelemetrySink {
+        path: dir.join(".julie").join(format.file_name()),
+        format,
+    }
+}
+
+#[test]
+fn nothing_is_recorded_until_a_workspace_opts_in() {
+    let telemetry = Telemetry::new();
+    telemetry.record_tool_call("fast_search", Duration::from_millis(3), true);
+    assert!(!telemetry.is_enabled());
+    assert!(telemetry.snapshot().tools.is_empty());
+}
+
+#[test]
+fn series_count_errors_and_bucket_latencies() {
+    let dir = tempfile::tempdir().unwrap();
+    let telemetry = Telemetry::new();
+    telemetry.configure_workspace(dir.path(), Some(sink(dir.path(), TelemetryFormat::Json)));
+
+    telemetry.record_tool_call("fast_search", Duration::from_millis(3), true);
+    telemetry.record_tool_call("fast_search", Duration::from_millis(700), false);
+    telemetry.record_extraction("rust", Duration::from_secs(20));
+    telemetry.record_db_write("incremental_update", Duration::from_micros(500));
+
+    let snapshot = telemetry.snapshot();
+    let search = &snapshot.tools["fast_search"];
+    assert_eq!((search.count, search.errors), (2, 1));
+    assert_eq!(search.buckets[1], 1, "3ms lands in the <=5ms bucket");
+    assert_eq!(search.buckets[8], 1, "700ms lands in the <=1000ms bucket");
+    assert_eq!(
+        snapshot.extraction["rust"].buckets[LATENCY_BUCKETS_MS.len()],
+        1,
+        "slower than every bound lands in +Inf"
+    );
+    assert_eq!(snapshot.database["incremental_update"].buckets[0], 1);
+}
+
+#[test]
+fn prometheus_output_has_cumulative_buckets() {
+    let dir = tempfile::tempdir().unwrap();
+    let telemetry = Telemetry::new();
+    telemetry.configure_workspace(dir.path(), Some(sink(dir.path(), TelemetryFormat::Json)));
+    telemetry.record_tool_call("deep_dive", Duration::from_millis(3), true);
+    telemetry.record_tool_call("deep_dive", Duration::from_millis(30), false);
+
+    let text = telemetry.snapshot().to_prometheus();
+    assert!(
+        text.contains("# TYPE julie_tool_call_ms histogram"),
+        "{text}"
+    );
+    assert!(
+        text.contains("julie_tool_call_ms_bucket{tool=\"deep_dive\",le=\"5\"} 1"),
+        "{text}"
+    );
+    assert!(
+        text.contains("julie_tool_call_ms_bucket{tool=\"deep_dive\",le=\"50\"} 2"),
+        "{text}"
+    );
+    assert!(
+        text.contains("julie_tool_call_ms_count{tool=\"deep_dive\"} 2"),
+        "{text}"
+    );
+    assert!(
+        text.contains("julie_tool_call_errors_total{tool=\"deep_dive\"} 1"),
+        "{text}"
+    );
+    assert!(
+        !text.contains("julie_extraction_ms"),
+        "empty families are omitted"
+    );
+}
+
+#[test]
+fn flush_writes_every_sink_and_opting_out_stops_recording() {
+    let prom_dir = tempfile::tempdir().unwrap();
+    let json_dir = tempfile::tempdir().unwrap();
+    let prom = sink(prom_dir.path(), TelemetryFormat::Prometheus);
+    let json = sink(json_dir.path(), TelemetryFormat::Json);
+    let telemetry = Telemetry::new();
+    telemetry.configure_workspace(prom_dir.path(), Some(prom.clone()));
+    telemetry.configure_workspace(json_dir.path(), Some(json.clone()));
+
+    telemetry.record_extraction("python", Duration::from_millis(12));
+    telemetry.flush();
+
+    let text = std::fs::read_to_string(&prom.path).unwrap();
+    assert!(text.contains("julie_extraction_ms_count{language=\"python\"} 1"));
+    let value: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(&json.path).unwrap()).unwrap();
+    assert_eq!(value["extraction"]["python"]["count"], 1);
+
+    telemetry.configure_workspace(prom_dir.path(), None);
+    assert!(telemetry.is_enabled(), "the JSON workspace still opts in");
+    telemetry.configure_workspace(json_dir.path(), None);
+    assert!(!telemetry.is_enabled());
+    telemetry.record_extraction("python", Duration::from_millis(12));
+    assert_eq!(telemetry.snapshot().extraction["python"].count, 1);
+}
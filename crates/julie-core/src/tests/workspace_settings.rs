@@ -0,0 +1,318 @@
+//! Tests for `.julie/config.toml` parsing, validation and reloading (`workspace_settings`).
+
+use std::fs;
+use std::path::Path;
+
+use crate::file_policy::SqlDialect;
+use crate::telemetry::{TelemetryFormat, TelemetrySink};
+use crate::workspace_settings::{SettingsError, WorkspaceSettings, WorkspaceSettingsRegistry};
+
+fn write_settings(root: &Path, text: &str) {
+    fs::create_dir_all(root.join(".julie")).unwrap();
+    fs::write(root.join(".julie/config.toml"), text).unwrap();
+}
+
+fn parse_error(text: &str) -> String {
+    WorkspaceSettings::parse(text, Path::new("/ws/.julie/config.toml"))
+        .unwrap_err()
+        .to_string()
+}
+
+#[test]
+fn missing_file_yields_defaults() {
+    let dir = tempfile::tempdir().unwrap();
+    assert_eq!(
+        WorkspaceSettings::load(dir.path()).unwrap(),
+        WorkspaceSettings::default()
+    );
+}
+
+#[test]
+fn parses_all_sections() {
+    let settings = WorkspaceSettings::parse(
+        r#"
+[index]
+ignore = ["generated/**", "*.pb.go"]
+
+[embeddings]
+provider = "sidecar"
+model = "nomic-ai/CodeRankEmbed"
+
+[context]
+max_tokens = 6000
+
+[watcher]
+backend = "poll"
+poll_interval_ms = 2000
+"#,
+        Path::new("config.toml"),
+    )
+    .unwrap();
+
+    assert_eq!(settings.index.ignore, vec!["generated/**", "*.pb.go"]);
+    assert_eq!(settings.embeddings.provider.as_deref(), Some("sidecar"));
+    assert_eq!(
+        settings.embeddings.model.as_deref(),
+        Some("nomic-ai/CodeRankEmbed")
+    );
+    assert_eq!(settings.context.max_tokens, Some(6000));
+    assert_eq!(settings.watcher.backend.as_deref(), Some("poll"));
+    assert_eq!(settings.watcher.poll_interval_ms, Some(2000));
+}
+
+#[test]
+fn errors_name_the_file_and_key() {
+    let unknown = parse_error("[context]\nmax_token = 6000\n");
+    assert!(unknown.contains("/ws/.julie/config.toml"), "{unknown}");
+    assert!(unknown.contains("max_token"), "{unknown}");
+
+    let wrong_type = parse_error("[index]\nignore = \"generated/\"\n");
+    assert!(
+        wrong_type.contains("invalid /ws/.julie/config.toml"),
+        "{wrong_type}"
+    );
+
+    let out_of_range = parse_error("[context]\nmax_tokens = 10\n");
+    assert!(
+        out_of_range.contains("`context.max_tokens`") && out_of_range.contains("out of range"),
+        "{out_of_range}"
+    );
+
+    let provider = parse_error("[embeddings]\nprovider = \"ort\"\n");
+    assert!(provider.contains("`embeddings.provider`"), "{provider}");
+
+    let negated = parse_error("[index]\nignore = [\"!keep.rs\"]\n");
+    assert!(negated.contains("`index.ignore`"), "{negated}");
+
+    let backend = parse_error("[watcher]\nbackend = \"inotify\"\n");
+    assert!(backend.contains("`watcher.backend`"), "{backend}");
+
+    let interval = parse_error("[watcher]\npoll_interval_ms = 10\n");
+    assert!(
+        interval.contains("`watcher.poll_interval_ms`") && interval.contains("out of range"),
+        "{interval}"
+    );
+
+    let language = parse_error("[symbols.Rust]\ninclude_locals = false\n");
+    assert!(language.contains("`symbols`"), "{language}");
+
+    let option = parse_error("[symbols.rust]\ninclude_macros = false\n");
+    assert!(option.contains("include_macros"), "{option}");
+
+    let define = parse_error("[preprocessor]\ndefines = [\"2FAST=1\"]\n");
+    assert!(define.contains("`preprocessor.defines`"), "{define}");
+
+    let mode = parse_error("[semantic]\nmode = \"off\"\n");
+    assert!(mode.contains("`semantic.mode`"), "{mode}");
+
+    let dialect = parse_error("[sql]\ndialect = \"mysql\"\n");
+    assert!(dialect.contains("`sql.dialect`"), "{dialect}");
+
+    let format = parse_error("[telemetry]\nformat = \"otlp\"\n");
+    assert!(format.contains("`telemetry.format`"), "{format}");
+
+    let generated = parse_error("[index]\ngenerated = [\"api/[**\"]\n");
+    assert!(generated.contains("`index.generated`"), "{generated}");
+
+    let tool = parse_error("[tools.deep_dive]\nmax_tokens = 2000\n");
+    assert!(tool.contains("`tools`"), "{tool}");
+
+    let budget = parse_error("[tools.fast_refs]\nmax_tokens = 50\n");
+    assert!(
+        budget.contains("`tools.max_tokens`") && budget.contains("out of range"),
+        "{budget}"
+    );
+}
+
+#[test]
+fn semantic_mode_defaults_to_enabled() {
+    assert!(!WorkspaceSettings::default().semantic_disabled());
+
+    let enabled =
+        WorkspaceSettings::parse("[semantic]\nmode = \"enabled\"\n", Path::new("config.toml"))
+            .unwrap();
+    assert!(!enabled.semantic_disabled());
+
+    let disabled = WorkspaceSettings::parse(
+        "[semantic]\nmode = \"Disabled\"\n",
+        Path::new("config.toml"),
+    )
+    .unwrap();
+    assert!(disabled.semantic_disabled());
+}
+
+#[test]
+fn telemetry_is_opt_in_and_writes_under_the_julie_dir() {
+    let root = Path::new("/ws");
+    assert_eq!(WorkspaceSettings::default().telemetry_sink(root), None);
+
+    let enabled =
+        WorkspaceSettings::parse("[telemetry]\nenabled = true\n", Path::new("config.toml"))
+            .unwrap();
+    assert_eq!(
+        enabled.telemetry_sink(root),
+        Some(TelemetrySink {
+            path: root.join(".julie/metrics.prom"),
+            format: TelemetryFormat::Prometheus,
+        })
+    );
+
+    let json = WorkspaceSettings::parse(
+        "[telemetry]\nenabled = true\nformat = \"JSON\"\n",
+        Path::new("config.toml"),
+    )
+    .unwrap();
+    assert_eq!(
+        json.telemetry_sink(root).map(|sink| sink.path),
+        Some(root.join(".julie/metrics.json"))
+    );
+}
+
+#[test]
+fn nested_repositories_are_indexed_by_default() {
+    assert!(WorkspaceSettings::default().indexes_nested_repositories());
+
+    let excluded = WorkspaceSettings::parse(
+        "[index]\nnested_repositories = false\n",
+        Path::new("config.toml"),
+    )
+    .unwrap();
+    assert!(!excluded.indexes_nested_repositories());
+}
+
+#[test]
+fn preprocessor_defines_are_unset_by_default() {
+    assert_eq!(WorkspaceSettings::default().preprocessor_defines(), None);
+
+    let settings = WorkspaceSettings::parse(
+        "[preprocessor]\ndefines = [\"DEBUG\", \"PLATFORM = 2\"]\n",
+        Path::new("config.toml"),
+    )
+    .unwrap();
+    let defines = settings.preprocessor_defines().unwrap();
+    assert_eq!(defines.value("DEBUG"), Some("1"));
+    assert_eq!(defines.value("PLATFORM"), Some("2"));
+}
+
+#[test]
+fn sql_dialect_accepts_vendor_names() {
+    assert_eq!(WorkspaceSettings::default().sql_dialect(), None);
+
+    let settings =
+        WorkspaceSettings::parse("[sql]\ndialect = \"Oracle\"\n", Path::new("config.toml"))
+            .unwrap();
+    assert_eq!(settings.sql_dialect(), Some(SqlDialect::PlSql));
+    let settings =
+        WorkspaceSettings::parse("[sql]\ndialect = \"tsql\"\n", Path::new("config.toml")).unwrap();
+    assert_eq!(settings.sql_dialect(), Some(SqlDialect::TSql));
+}
+
+#[test]
+fn symbol_options_override_the_default_table_per_key() {
+    let settings = WorkspaceSettings::parse(
+        r#"
+[symbols.default]
+include_imports = false
+include_locals = false
+
+[symbols.rust]
+include_private = false
+include_locals = true
+"#,
+        Path::new("config.toml"),
+    )
+    .unwrap();
+
+    let rust = settings.symbol_options("rust");
+    assert!(rust.includes_locals());
+    assert!(!rust.includes_private());
+    assert!(!rust.includes_imports());
+
+    let python = settings.symbol_options("python");
+    assert!(!python.includes_locals());
+    assert!(python.includes_private());
+    assert!(!python.includes_imports());
+
+    assert!(
+        WorkspaceSettings::default()
+            .symbol_options("rust")
+            .keeps_everything()
+    );
+}
+
+#[test]
+fn tool_limits_fall_back_to_the_default_table() {
+    let settings = WorkspaceSettings::parse(
+        r#"
+[tools.default]
+max_tokens = 4000
+
+[tools.fast_refs]
+max_tokens = 2000
+"#,
+        Path::new("config.toml"),
+    )
+    .unwrap();
+
+    assert_eq!(settings.tool_limits("fast_refs").max_tokens, Some(2000));
+    assert_eq!(settings.tool_limits("fast_search").max_tokens, Some(4000));
+    assert_eq!(
+        WorkspaceSettings::default()
+            .tool_limits("get_symbols")
+            .max_tokens,
+        None
+    );
+}
+
+#[test]
+fn ignore_matcher_is_rooted_at_the_workspace() {
+    let dir = tempfile::tempdir().unwrap();
+    let settings =
+        WorkspaceSettings::parse("[index]\nignore = [\"generated/\"]\n", Path::new("c")).unwrap();
+    let matcher = settings.ignore_matcher(dir.path()).unwrap();
+
+    assert!(
+        matcher
+            .matched(dir.path().join("generated"), true)
+            .is_ignore()
+    );
+    assert!(!matcher.matched(dir.path().join("src"), true).is_ignore());
+    assert!(
+        WorkspaceSettings::default()
+            .ignore_matcher(dir.path())
+            .is_none()
+    );
+}
+
+#[test]
+fn reload_keeps_previous_settings_on_error() {
+    let dir = tempfile::tempdir().unwrap();
+    let registry = WorkspaceSettingsRegistry::new();
+    write_settings(dir.path(), "[context]\nmax_tokens = 6000\n");
+    assert_eq!(registry.get(dir.path()).context.max_tokens, Some(6000));
+
+    write_settings(dir.path(), "[context]\nmax_tokens = 8000\n");
+    assert_eq!(
+        registry.get(dir.path()).context.max_tokens,
+        Some(6000),
+        "get serves the cached settings until a reload"
+    );
+    registry.reload(dir.path()).unwrap();
+    assert_eq!(registry.get(dir.path()).context.max_tokens, Some(8000));
+
+    write_settings(dir.path(), "[context\n");
+    assert!(matches!(
+        registry.reload(dir.path()),
+        Err(SettingsError::Parse { .. })
+    ));
+    assert_eq!(registry.get(dir.path()).context.max_tokens, Some(8000));
+}
+
+#[test]
+fn invalid_file_on_first_load_falls_back_to_defaults() {
+    let dir = tempfile::tempdir().unwrap();
+    let registry = WorkspaceSettingsRegistry::new();
+    write_settings(dir.path(), "[context]\nmax_tokens = 1\n");
+
+    assert_eq!(*registry.get(dir.path()), WorkspaceSettings::default());
+}
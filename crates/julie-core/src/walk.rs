@@ -1,4 +1,6 @@
+use crate::database::NestedRepository;
 use crate::shared::BLACKLISTED_DIRECTORIES;
+use crate::workspace_settings::WorkspaceSettingsRegistry;
 use anyhow::{Context, Result};
 use ignore::WalkBuilder;
 use ignore::gitignore::{Gitignore, GitignoreBuilder};
@@ -53,6 +55,8 @@ impl WalkConfig {
 /// - `hidden(false)` — include dotfiles; let .gitignore + blacklist handle exclusion
 /// - `git_ignore(true)` — respect .gitignore (including nested, global, .git/info/exclude)
 /// - `.julieignore` — if `config.use_julieignore`, added as custom ignore filename
+/// - `index.ignore` and `index.nested_repositories = false` from
+///   `.julie/config.toml` — also gated on `config.use_julieignore`
 /// - `filter_entry` — always excludes `.git` and `.julie`; optionally excludes BLACKLISTED_DIRECTORIES
 pub fn build_walker(workspace_path: &Path, config: &WalkConfig) -> ignore::Walk {
     try_build_walker(workspace_path, config).expect("walker configuration should be valid")
@@ -118,6 +122,20 @@ fn configure_walker_builder(
     let extra_ignore_matcher =
         build_extra_ignore_matcher(workspace_path, &config.extra_ignore_files)?;
 
+    // `index.ignore` and `index.nested_repositories` from `.julie/config.toml`
+    // follow the same switch as `.julieignore`: all are the user's
+    // per-workspace exclusions.
+    let settings = config
+        .use_julieignore
+        .then(|| WorkspaceSettingsRegistry::global().get(workspace_path));
+    let settings_ignore_matcher = settings
+        .as_ref()
+        .and_then(|settings| settings.ignore_matcher(workspace_path));
+    let skip_nested_repositories = settings
+        .as_ref()
+        .is_some_and(|settings| !settings.indexes_nested_repositories());
+    let walk_workspace = workspace_path.to_path_buf();
+
     // Apply .gitignore files from ancestor directories between the git root and
     // workspace_path (exclusive). This compensates for parents(false) which prevents
     // the ignore crate from reading parent-dir gitignore files on its own.
@@ -158,6 +176,12 @@ fn configure_walker_builder(
             }
         }
 
+        if let Some(matcher) = &settings_ignore_matcher {
+            if matcher.matched(entry.path(), is_dir).is_ignore() {
+                return false;
+            }
+        }
+
         // Always exclude internal state directories — hidden(false) would otherwise include them.
         // See: https://github.com/BurntSushi/ripgrep/issues/3099
         if matches!(file_name, ".git" | ".julie") {
@@ -169,12 +193,66 @@ fn configure_walker_builder(
             return false;
         }
 
+        // A single-path walk starts below the workspace root, possibly inside
+        // a nested repository, so its root checks every ancestor.
+        if skip_nested_repositories {
+            let nested = if entry.depth() == 0 {
+                within_nested_repository(&walk_workspace, entry.path())
+            } else {
+                is_dir && is_repository_root(entry.path())
+            };
+            if nested {
+                return false;
+            }
+        }
+
         true
     });
 
     Ok(())
 }
 
+/// Git repositories nested below `workspace_path`: directories holding a
+/// `.git` directory (a plain clone) or `.git` file (a submodule or worktree).
+/// Gitignored and blacklisted directories are not searched. Sorted by root.
+pub fn find_nested_repositories(workspace_path: &Path) -> Vec<NestedRepository> {
+    let config = WalkConfig {
+        use_julieignore: false,
+        use_blacklisted_dirs: true,
+        extra_ignore_files: Vec::new(),
+    };
+    let mut repositories = Vec::new();
+    for entry in build_walker(workspace_path, &config).filter_map(Result::ok) {
+        if entry.depth() == 0 || !entry.file_type().is_some_and(|ft| ft.is_dir()) {
+            continue;
+        }
+        let Ok(git) = fs::symlink_metadata(entry.path().join(".git")) else {
+            continue;
+        };
+        let Ok(relative) = entry.path().strip_prefix(workspace_path) else {
+            continue;
+        };
+        repositories.push(NestedRepository {
+            root: relative.to_string_lossy().replace('\\', "/"),
+            submodule: git.is_file(),
+        });
+    }
+    repositories.sort_by(|a, b| a.root.cmp(&b.root));
+    repositories
+}
+
+fn is_repository_root(dir: &Path) -> bool {
+    dir.join(".git").exists()
+}
+
+/// True when `path`, or a directory between it and `workspace_path`, is the
+/// root of a nested repository.
+fn within_nested_repository(workspace_path: &Path, path: &Path) -> bool {
+    path.ancestors()
+        .take_while(|dir| *dir != workspace_path && dir.starts_with(workspace_path))
+        .any(is_repository_root)
+}
+
 /// Walk up from `start` looking for a directory containing `.git`.
 /// Returns the git root directory, or `None` if not inside a git repo.
 fn find_git_root(start: &Path) -> Option<PathBuf> {
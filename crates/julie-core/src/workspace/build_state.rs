@@ -0,0 +1,241 @@
+//! Build and dependency artifacts present in a workspace checkout.
+//!
+//! Julie indexes everything it can derive from source, so a bare clone — no
+//! build, no dependency install — is a supported "no-build" mode rather than
+//! a degraded one. [`probe_build_state`] records, per ecosystem found at the
+//! workspace root, whether the dependency install and build outputs exist, so
+//! the capabilities report can say which relationship types depend on them
+//! and what to run to get them.
+//!
+//! Only the workspace root is probed: nested packages of a monorepo share the
+//! root's verdict. Artifacts that live outside the checkout (the Cargo
+//! registry, Go's module cache, `~/.m2`) are reported as
+//! [`ArtifactState::NotLocal`] rather than guessed at.
+
+use std::path::Path;
+
+/// Whether an ecosystem's dependency install or build output exists.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ArtifactState {
+    Present,
+    Missing,
+    /// Lives outside the workspace (global cache) or does not apply.
+    NotLocal,
+}
+
+impl ArtifactState {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Present => "present",
+            Self::Missing => "missing",
+            Self::NotLocal => "not local",
+        }
+    }
+}
+
+/// Build state of one ecosystem detected at the workspace root.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EcosystemBuildState {
+    pub ecosystem: &'static str,
+    /// Manifest file that identified the ecosystem.
+    pub manifest: String,
+    pub dependencies: ArtifactState,
+    pub build_outputs: ArtifactState,
+    /// Command that produces the missing artifacts.
+    pub setup_hint: &'static str,
+}
+
+impl EcosystemBuildState {
+    /// Something this ecosystem installs or builds in the checkout is
+    /// missing, and nothing it produces is present.
+    pub fn is_bare(&self) -> bool {
+        !self.has_local_artifacts()
+            && (self.dependencies == ArtifactState::Missing
+                || self.build_outputs == ArtifactState::Missing)
+    }
+
+    /// A dependency install or build output exists in the checkout.
+    pub fn has_local_artifacts(&self) -> bool {
+        self.dependencies == ArtifactState::Present || self.build_outputs == ArtifactState::Present
+    }
+}
+
+struct EcosystemProbe {
+    ecosystem: &'static str,
+    manifests: &'static [&'static str],
+    manifest_extensions: &'static [&'static str],
+    /// Empty when dependencies live outside the workspace.
+    dependency_paths: &'static [&'static str],
+    /// Empty when the ecosystem has no build output directory.
+    build_paths: &'static [&'static str],
+    setup_hint: &'static str,
+}
+
+const PROBES: &[EcosystemProbe] = &[
+    EcosystemProbe {
+        ecosystem: "rust",
+        manifests: &["Cargo.toml"],
+        manifest_extensions: &[],
+        dependency_paths: &[],
+        build_paths: &["target"],
+        setup_hint: "cargo build",
+    },
+    EcosystemProbe {
+        ecosystem: "node",
+        manifests: &["package.json"],
+        manifest_extensions: &[],
+        dependency_paths: &["node_modules"],
+        build_paths: &["dist", "build"],
+        setup_hint: "npm install (or pnpm/yarn install), then the package's build script",
+    },
+    EcosystemProbe {
+        ecosystem: "python",
+        manifests: &["pyproject.toml", "requirements.txt", "setup.py", "Pipfile"],
+        manifest_extensions: &[],
+        dependency_paths: &[".venv", "venv"],
+        build_paths: &[],
+        setup_hint: "create a virtualenv and install the project's requirements",
+    },
+    EcosystemProbe {
+        ecosystem: "go",
+        manifests: &["go.mod"],
+        manifest_extensions: &[],
+        dependency_paths: &[],
+        build_paths: &[],
+        setup_hint: "go generate ./... && go build ./...",
+    },
+    EcosystemProbe {
+        ecosystem: "dotnet",
+        manifests: &[],
+        manifest_extensions: &["sln", "csproj", "fsproj", "vbproj"],
+        dependency_paths: &["obj/project.assets.json"],
+        build_paths: &["bin"],
+        setup_hint: "dotnet restore && dotnet build",
+    },
+    EcosystemProbe {
+        ecosystem: "maven",
+        manifests: &["pom.xml"],
+        manifest_extensions: &[],
+        dependency_paths: &[],
+        build_paths: &["target"],
+        setup_hint: "mvn compile",
+    },
+    EcosystemProbe {
+        ecosystem: "gradle",
+        manifests: &["build.gradle", "build.gradle.kts"],
+        manifest_extensions: &[],
+        dependency_paths: &[],
+        build_paths: &["build"],
+        setup_hint: "gradle build",
+    },
+];
+
+/// Probe `workspace_root` for each known ecosystem's manifest and, where one
+/// is found, its dependency install and build outputs.
+pub fn probe_build_state(workspace_root: &Path) -> Vec<EcosystemBuildState> {
+    let root_files: Vec<String> = std::fs::read_dir(workspace_root)
+        .map(|entries| {
+            entries
+                .filter_map(|entry| entry.ok())
+                .filter(|entry| entry.file_type().is_ok_and(|kind| kind.is_file()))
+                .filter_map(|entry| entry.file_name().to_str().map(str::to_string))
+                .collect()
+        })
+        .unwrap_or_default();
+
+    PROBES
+        .iter()
+        .filter_map(|probe| {
+            let manifest = root_files
+                .iter()
+                .filter(|name| {
+                    probe.manifests.contains(&name.as_str())
+                        || Path::new(name.as_str())
+                            .extension()
+                            .and_then(|ext| ext.to_str())
+                            .is_some_and(|ext| probe.manifest_extensions.contains(&ext))
+                })
+                .min()?;
+            Some(EcosystemBuildState {
+                ecosystem: probe.ecosystem,
+                manifest: manifest.clone(),
+                dependencies: artifact_state(workspace_root, probe.dependency_paths),
+                build_outputs: artifact_state(workspace_root, probe.build_paths),
+                setup_hint: probe.setup_hint,
+            })
+        })
+        .collect()
+}
+
+/// A checkout is a bare clone when some ecosystem is bare and none has local
+/// artifacts. A workspace with no recognized manifest, or whose ecosystems
+/// keep everything in global caches, is not called bare: there is nothing in
+/// the checkout to check.
+pub fn is_bare_clone(states: &[EcosystemBuildState]) -> bool {
+    states.iter().any(EcosystemBuildState::is_bare)
+        && !states.iter().any(EcosystemBuildState::has_local_artifacts)
+}
+
+fn artifact_state(workspace_root: &Path, paths: &[&str]) -> ArtifactState {
+    if paths.is_empty() {
+        ArtifactState::NotLocal
+    } else if paths.iter().any(|path| workspace_root.join(path).exists()) {
+        ArtifactState::Present
+    } else {
+        ArtifactState::Missing
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_bare_clone_reports_missing_artifacts_per_ecosystem() {
+        let dir = TempDir::new().unwrap();
+        fs::write(dir.path().join("Cargo.toml"), "[package]\n").unwrap();
+        fs::write(dir.path().join("package.json"), "{}").unwrap();
+
+        let states = probe_build_state(dir.path());
+        let ecosystems: Vec<_> = states.iter().map(|state| state.ecosystem).collect();
+        assert_eq!(ecosystems, vec!["rust", "node"]);
+        assert_eq!(states[0].dependencies, ArtifactState::NotLocal);
+        assert_eq!(states[0].build_outputs, ArtifactState::Missing);
+        assert_eq!(states[1].dependencies, ArtifactState::Missing);
+        assert!(is_bare_clone(&states));
+    }
+
+    #[test]
+    fn test_installed_dependencies_end_bare_clone_mode() {
+        let dir = TempDir::new().unwrap();
+        fs::write(dir.path().join("package.json"), "{}").unwrap();
+        fs::create_dir(dir.path().join("node_modules")).unwrap();
+        fs::write(dir.path().join("App.sln"), "").unwrap();
+
+        let states = probe_build_state(dir.path());
+        let node = states.iter().find(|s| s.ecosystem == "node").unwrap();
+        assert_eq!(node.dependencies, ArtifactState::Present);
+        let dotnet = states.iter().find(|s| s.ecosystem == "dotnet").unwrap();
+        assert_eq!(dotnet.manifest, "App.sln");
+        assert!(dotnet.is_bare());
+        assert!(!is_bare_clone(&states), "one built ecosystem is enough");
+    }
+
+    #[test]
+    fn test_workspace_without_local_artifacts_to_check_is_not_bare() {
+        let dir = TempDir::new().unwrap();
+        fs::write(dir.path().join("notes.md"), "# notes").unwrap();
+        assert!(probe_build_state(dir.path()).is_empty());
+        assert!(!is_bare_clone(&[]));
+
+        fs::write(dir.path().join("go.mod"), "module example.com/app\n").unwrap();
+        let states = probe_build_state(dir.path());
+        assert_eq!(states[0].dependencies, ArtifactState::NotLocal);
+        assert!(
+            !is_bare_clone(&states),
+            "Go keeps modules in a global cache"
+        );
+    }
+}
@@ -0,0 +1,332 @@
+//! Single-flight workspace initialization (leader/follower).
+//!
+//! Several MCP connections can reach startup indexing for the same workspace
+//! at nearly the same moment. The mutation gate serializes their writes, but
+//! it only exists once a workspace identity is bound, and even when it does
+//! each caller still runs its own full pipeline after the previous one
+//! finishes. [`InitializationLock`] collapses concurrent runs instead: the
+//! first caller for a key becomes the *leader* and runs the initialization;
+//! callers arriving while it is in flight become *followers* and await the
+//! leader's outcome rather than starting a second pipeline.
+//!
+//! # Failure and cancellation
+//!
+//! A leader that returns an error hands the (stringified) error to every
+//! follower. A leader that is dropped without finishing — its task was
+//! cancelled or panicked — releases the flight, and each waiting follower
+//! retries: one of them becomes the next leader.
+//!
+//! # Re-entrancy
+//!
+//! A leader that reaches [`InitializationLock::run`] again for the same key
+//! from inside its own initialization (same task) runs the nested call
+//! directly instead of waiting on itself. Work the leader spawns onto other
+//! tasks is not recognized as the leader and must not re-enter the same key.
+
+use std::collections::HashMap;
+use std::future::Future;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex as StdMutex};
+
+use tokio::sync::watch;
+
+/// Outcome shared with followers: the leader's value or its error message.
+pub type FlightOutcome<T> = Result<T, String>;
+
+type FlightSlot<T> = watch::Receiver<Option<FlightOutcome<T>>>;
+type FlightMap<T> = Arc<StdMutex<HashMap<String, (u64, FlightSlot<T>)>>>;
+
+/// Distinguishes lock instances in the task-local re-entrancy marker.
+static NEXT_LOCK_ID: AtomicU64 = AtomicU64::new(1);
+/// Distinguishes successive flights for the same key.
+static NEXT_FLIGHT_ID: AtomicU64 = AtomicU64::new(1);
+
+tokio::task_local! {
+    /// `(lock id, key)` pairs the current task is leading.
+    static LEADING: Vec<(u64, String)>;
+}
+
+/// Per-key single-flight lock for workspace initialization.
+pub struct InitializationLock<T> {
+    id: u64,
+    flights: FlightMap<T>,
+}
+
+/// What a caller became when it claimed a key.
+pub enum InitRole<T> {
+    /// Run the initialization, then [`InitLeader::finish`].
+    Leader(InitLeader<T>),
+    /// Another caller is running it; [`InitFollower::wait`] for the outcome.
+    Follower(InitFollower<T>),
+}
+
+/// The caller running the initialization for a key.
+///
+/// Dropping a leader without calling [`finish`](Self::finish) abandons the
+/// flight; its followers retry.
+pub struct InitLeader<T> {
+    key: String,
+    flight_id: u64,
+    flights: FlightMap<T>,
+    sender: watch::Sender<Option<FlightOutcome<T>>>,
+}
+
+/// A caller waiting on another caller's initialization.
+pub struct InitFollower<T> {
+    receiver: FlightSlot<T>,
+}
+
+impl<T: Clone> InitializationLock<T> {
+    pub fn new() -> Self {
+        Self {
+            id: NEXT_LOCK_ID.fetch_add(1, Ordering::Relaxed),
+            flights: Arc::new(StdMutex::new(HashMap::new())),
+        }
+    }
+
+    /// Become the leader for `key`, or a follower of the flight in progress.
+    pub fn claim(&self, key: &str) -> InitRole<T> {
+        let mut flights = lock_flights(&self.flights);
+        if let Some((_, receiver)) = flights.get(key) {
+            return InitRole::Follower(InitFollower {
+                receiver: receiver.clone(),
+            });
+        }
+        let (sender, receiver) = watch::channel(None);
+        let flight_id = NEXT_FLIGHT_ID.fetch_add(1, Ordering::Relaxed);
+        flights.insert(key.to_string(), (flight_id, receiver));
+        InitRole::Leader(InitLeader {
+            key: key.to_string(),
+            flight_id,
+            flights: Arc::clone(&self.flights),
+            sender,
+        })
+    }
+
+    /// Whether a flight for `key` is currently in progress.
+    pub fn is_in_flight(&self, key: &str) -> bool {
+        lock_flights(&self.flights).contains_key(key)
+    }
+
+    /// Run `init` for `key` unless a run is already in flight, in which case
+    /// wait for it and return its outcome. Errors from the leader reach
+    /// followers as `anyhow` errors carrying the leader's message.
+    pub async fn run<F, Fut>(&self, key: &str, init: F) -> anyhow::Result<T>
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = anyhow::Result<T>>,
+    {
+        let reentrant = LEADING
+            .try_with(|leading| {
+                leading
+                    .iter()
+                    .any(|(lock_id, leading_key)| *lock_id == self.id && leading_key == key)
+            })
+            .unwrap_or(false);
+        if reentrant {
+            return init().await;
+        }
+
+        let mut init = Some(init);
+        loop {
+            match self.claim(key) {
+                InitRole::Leader(leader) => {
+                    let init = init.take().expect("initialization runs at most once");
+                    let mut leading = LEADING.try_with(Clone::clone).unwrap_or_default();
+                    leading.push((self.id, key.to_string()));
+                    let result = LEADING.scope(leading, init()).await;
+                    leader.finish(
+                        result
+                            .as_ref()
+                            .map(Clone::clone)
+                            .map_err(|err| format!("{err:#}")),
+                    );
+                    return result;
+                }
+                InitRole::Follower(follower) => {
+                    tracing::debug!(key, "Awaiting in-flight workspace initialization");
+                    match follower.wait().await {
+                        Some(outcome) => {
+                            return outcome.map_err(|message| {
+                                anyhow::anyhow!("workspace initialization failed: {message}")
+                            });
+                        }
+                        // Leader abandoned the flight: claim again.
+                        None => continue,
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl<T: Clone> Default for InitializationLock<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> InitLeader<T> {
+    /// Publish the outcome to every follower and release the key.
+    pub fn finish(self, outcome: FlightOutcome<T>) {
+        // Release first so a caller arriving after completion starts a fresh
+        // run instead of reusing this result.
+        self.release();
+        self.sender.send_replace(Some(outcome));
+    }
+
+    fn release(&self) {
+        let mut flights = lock_flights(&self.flights);
+        if flights
+            .get(&self.key)
+            .is_some_and(|(flight_id, _)| *flight_id == self.flight_id)
+        {
+            flights.remove(&self.key);
+        }
+    }
+}
+
+impl<T> Drop for InitLeader<T> {
+    fn drop(&mut self) {
+        // No-op after `finish`; otherwise abandons the flight, and dropping
+        // the sender wakes followers with `None`.
+        self.release();
+    }
+}
+
+impl<T: Clone> InitFollower<T> {
+    /// Wait for the leader's outcome; `None` if the leader abandoned the
+    /// flight without finishing.
+    pub async fn wait(mut self) -> Option<FlightOutcome<T>> {
+        match self.receiver.wait_for(Option::is_some).await {
+            Ok(outcome) => outcome.clone(),
+            Err(_) => None,
+        }
+    }
+}
+
+fn lock_flights<T>(
+    flights: &FlightMap<T>,
+) -> std::sync::MutexGuard<'_, HashMap<String, (u64, FlightSlot<T>)>> {
+    flights.lock().unwrap_or_else(|poisoned| {
+        tracing::warn!("Initialization flight map poisoned, recovering");
+        poisoned.into_inner()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::AtomicUsize;
+    use std::time::Duration;
+    use tokio::sync::Notify;
+    use tokio::time::timeout;
+
+    /// Concurrent callers for one key run the initialization once and all
+    /// observe the leader's value.
+    #[tokio::test]
+    async fn test_concurrent_callers_share_one_run() {
+        let lock = Arc::new(InitializationLock::<u32>::new());
+        let runs = Arc::new(AtomicUsize::new(0));
+        let release = Arc::new(Notify::new());
+
+        let mut handles = Vec::new();
+        for _ in 0..4 {
+            let lock = Arc::clone(&lock);
+            let runs = Arc::clone(&runs);
+            let release = Arc::clone(&release);
+            handles.push(tokio::spawn(async move {
+                lock.run("/ws", || async move {
+                    runs.fetch_add(1, Ordering::SeqCst);
+                    release.notified().await;
+                    Ok(7)
+                })
+                .await
+                .unwrap()
+            }));
+        }
+
+        // Let every task claim the key before the leader finishes.
+        while !lock.is_in_flight("/ws") {
+            tokio::task::yield_now().await;
+        }
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        release.notify_one();
+
+        for handle in handles {
+            assert_eq!(handle.await.unwrap(), 7);
+        }
+        assert_eq!(runs.load(Ordering::SeqCst), 1, "only the leader runs");
+        assert!(!lock.is_in_flight("/ws"));
+    }
+
+    /// A leader's error reaches its followers.
+    #[tokio::test]
+    async fn test_leader_error_reaches_followers() {
+        let lock = InitializationLock::<u32>::new();
+        let InitRole::Leader(leader) = lock.claim("/ws") else {
+            panic!("first claim leads");
+        };
+        let InitRole::Follower(follower) = lock.claim("/ws") else {
+            panic!("second claim follows");
+        };
+        leader.finish(Err("registry write failed".to_string()));
+        assert_eq!(
+            follower.wait().await,
+            Some(Err("registry write failed".to_string()))
+        );
+    }
+
+    /// Dropping a leader abandons the flight: followers see `None` and the
+    /// next claim leads again.
+    #[tokio::test]
+    async fn test_abandoned_flight_lets_a_follower_lead() {
+        let lock = InitializationLock::<u32>::new();
+        let InitRole::Leader(leader) = lock.claim("/ws") else {
+            panic!("first claim leads");
+        };
+        let InitRole::Follower(follower) = lock.claim("/ws") else {
+            panic!("second claim follows");
+        };
+        drop(leader);
+        assert_eq!(follower.wait().await, None);
+        assert!(matches!(lock.claim("/ws"), InitRole::Leader(_)));
+    }
+
+    /// After a flight finishes, the next caller runs a fresh initialization
+    /// rather than reusing the old result; other keys never wait.
+    #[tokio::test]
+    async fn test_finished_flight_and_other_keys_do_not_block() {
+        let lock = InitializationLock::<u32>::new();
+        assert_eq!(lock.run("/a", || async { Ok(1) }).await.unwrap(), 1);
+        assert_eq!(lock.run("/a", || async { Ok(2) }).await.unwrap(), 2);
+
+        let InitRole::Leader(_held) = lock.claim("/a") else {
+            panic!("first claim leads");
+        };
+        let other = timeout(
+            Duration::from_millis(200),
+            lock.run("/b", || async { Ok(3) }),
+        )
+        .await
+        .expect("a different key must not wait");
+        assert_eq!(other.unwrap(), 3);
+    }
+
+    /// A leader re-entering its own key runs the nested call directly
+    /// instead of deadlocking on itself.
+    #[tokio::test]
+    async fn test_reentrant_call_runs_inline() {
+        let lock = &InitializationLock::<u32>::new();
+        let result = timeout(
+            Duration::from_millis(500),
+            lock.run("/ws", move || async move {
+                let nested = lock.run("/ws", || async { Ok(20) }).await?;
+                Ok(nested + 1)
+            }),
+        )
+        .await
+        .expect("re-entrant run must not deadlock");
+        assert_eq!(result.unwrap(), 21);
+    }
+}
@@ -2,6 +2,8 @@
 //!
 //! These are zero-dependency utilities shared across the workspace stack.
 
+pub mod build_state;
+pub mod init_flight;
 pub mod leader_lock;
 pub mod mutation_gate;
 pub mod registry;
@@ -25,8 +25,17 @@
 //! is private and there is no public constructor.  Any function that accepts
 //! `_guard: &MutationGuard<'_>` therefore cannot be called without first
 //! calling [`acquire_gate`], which is a compile-time guarantee.
+//!
+//! # Index generation
+//!
+//! Because every writer passes through the gate, the registry also keeps a
+//! per-workspace generation counter for read-side caches. It is bumped when a
+//! guard is acquired and again when it is dropped, seqlock-style: an odd value
+//! means a write is in flight, and any result computed under one even value is
+//! valid until the counter moves. See [`Registry::generation`].
 
 use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::{Mutex as StdMutex, OnceLock};
 use tokio::sync::{Mutex as AsyncMutex, OwnedMutexGuard};
 
@@ -40,6 +49,7 @@ use std::sync::Arc;
 /// using the process-wide singleton via [`Registry::global`].
 pub struct Registry {
     cache: StdMutex<HashMap<String, Arc<AsyncMutex<()>>>>,
+    generations: StdMutex<HashMap<String, Arc<AtomicU64>>>,
 }
 
 impl Registry {
@@ -47,6 +57,7 @@ impl Registry {
     pub fn new() -> Self {
         Self {
             cache: StdMutex::new(HashMap::new()),
+            generations: StdMutex::new(HashMap::new()),
         }
     }
 
@@ -78,15 +89,47 @@ impl Registry {
             .clone()
     }
 
+    fn generation_for(&self, workspace_id: &str) -> Arc<AtomicU64> {
+        let mut map = match self.generations.lock() {
+            Ok(g) => g,
+            Err(poisoned) => {
+                tracing::warn!(
+                    "Mutation gate generation mutex poisoned, recovering: {}",
+                    poisoned
+                );
+                poisoned.into_inner()
+            }
+        };
+        map.entry(workspace_id.to_owned())
+            .or_insert_with(|| Arc::new(AtomicU64::new(0)))
+            .clone()
+    }
+
+    /// Current index generation for `workspace_id`.
+    ///
+    /// Odd while a [`MutationGuard`] is held, even otherwise. A cached read
+    /// result is reusable only while the generation still equals the even
+    /// value observed *before* the read ran.
+    pub fn generation(&self, workspace_id: &str) -> u64 {
+        self.generation_for(workspace_id).load(Ordering::Acquire)
+    }
+
+    fn guard(&self, workspace_id: &str, guard: OwnedMutexGuard<()>) -> MutationGuard<'static> {
+        let generation = self.generation_for(workspace_id);
+        generation.fetch_add(1, Ordering::AcqRel);
+        MutationGuard {
+            _guard: guard,
+            generation,
+            _lifetime: std::marker::PhantomData,
+        }
+    }
+
     /// Acquire the mutation gate for `workspace_id`, blocking until any other
     /// writer using this registry releases it.
     pub async fn acquire(&self, workspace_id: &str) -> MutationGuard<'static> {
         let arc = self.arc_for(workspace_id);
         let guard = arc.lock_owned().await;
-        MutationGuard {
-            _guard: guard,
-            _lifetime: std::marker::PhantomData,
-        }
+        self.guard(workspace_id, guard)
     }
 
     /// Try to acquire the mutation gate without waiting.
@@ -96,10 +139,7 @@ impl Registry {
     pub fn try_acquire(&self, workspace_id: &str) -> Option<MutationGuard<'static>> {
         let arc = self.arc_for(workspace_id);
         let guard = arc.try_lock_owned().ok()?;
-        Some(MutationGuard {
-            _guard: guard,
-            _lifetime: std::marker::PhantomData,
-        })
+        Some(self.guard(workspace_id, guard))
     }
 }
 
@@ -116,15 +156,26 @@ impl Default for Registry {
 /// has no public constructor, callers must go through [`acquire_gate`] — making
 /// it impossible to invoke a gated function without holding the lock.
 ///
-/// Dropping a `MutationGuard` releases the lock immediately.
+/// Dropping a `MutationGuard` releases the lock immediately, after advancing
+/// the workspace's index generation.
 pub struct MutationGuard<'a> {
     /// Private — no public constructor means external code cannot fabricate a
     /// guard without going through `acquire_gate`.
     _guard: OwnedMutexGuard<()>,
+    /// Workspace generation counter; bumped back to even on drop.
+    generation: Arc<AtomicU64>,
     /// Phantom lifetime so the guard can be borrowed by mutation functions.
     _lifetime: std::marker::PhantomData<&'a ()>,
 }
 
+impl Drop for MutationGuard<'_> {
+    fn drop(&mut self) {
+        // Runs before the lock field is dropped, so the next writer always
+        // observes an even generation on entry.
+        self.generation.fetch_add(1, Ordering::AcqRel);
+    }
+}
+
 /// Acquire the workspace mutation gate for `workspace_id`, blocking until any
 /// other writer releases it.
 ///
@@ -258,6 +309,30 @@ mod tests {
         drop(_guard_a);
     }
 
+    /// The generation is odd while a guard is held and moves on every write.
+    #[tokio::test]
+    async fn test_generation_tracks_guard_lifetime() {
+        let reg = Registry::new();
+        assert_eq!(reg.generation("ws_gen"), 0);
+
+        let guard = reg.acquire("ws_gen").await;
+        assert_eq!(
+            reg.generation("ws_gen"),
+            1,
+            "odd while a write is in flight"
+        );
+        assert_eq!(
+            reg.generation("ws_other"),
+            0,
+            "generations are per workspace"
+        );
+        drop(guard);
+        assert_eq!(reg.generation("ws_gen"), 2);
+
+        drop(reg.try_acquire("ws_gen").expect("gate is free"));
+        assert_eq!(reg.generation("ws_gen"), 4);
+    }
+
     /// Registry::global() must return the same Arc on every call.
     #[tokio::test]
     async fn test_global_registry_is_singleton() {
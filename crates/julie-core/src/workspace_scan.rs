@@ -1,4 +1,5 @@
 use crate::file_policy::{should_index_path_candidate, supported_extensions_for_indexing};
+use crate::generated_files::discover_generated_files;
 use crate::paths::to_relative_unix_style;
 use crate::walk::{WalkConfig, build_walker};
 use anyhow::Result;
@@ -36,6 +37,13 @@ pub fn scan_workspace_files(workspace_root: &Path) -> Result<HashSet<String>> {
         }
     }
 
+    // `[[generated]]` output directories are indexed even when ignored.
+    for path in discover_generated_files(workspace_root) {
+        if let Ok(relative_path) = to_relative_unix_style(&path, workspace_root) {
+            files.insert(relative_path);
+        }
+    }
+
     Ok(files)
 }
 
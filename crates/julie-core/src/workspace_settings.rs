@@ -0,0 +1,287 @@
+//! User-editable per-workspace settings, read from `.julie/config.toml`.
+//!
+//! ```toml
+//! [index]
+//! ignore = ["generated/**", "*.pb.go"]   # gitignore syntax, on top of .gitignore/.julieignore
+//! nested_repositories = false            # skip submodules and nested git clones
+//! generated = ["web/src/api/**"]         # generated code (indexed, ranked low)
+//!
+//! [embeddings]
+//! provider = "sidecar"                  # auto | sidecar | none
+//! model = "nomic-ai/CodeRankEmbed"      # sidecar model id
+//!
+//! [semantic]
+//! mode = "disabled"                     # enabled | disabled (no embeddings at all)
+//!
+//! [context]
+//! max_tokens = 6000                     # get_context budget when the call sets none
+//!
+//! [watcher]
+//! backend = "poll"                      # auto | native | poll
+//! poll_interval_ms = 2000               # scan interval of the polling backend
+//!
+//! [symbols.default]                     # every language
+//! include_imports = false
+//!
+//! [symbols.rust]                        # overrides `default` per key
+//! include_private = false
+//! include_locals = true
+//!
+//! [preprocessor]
+//! defines = ["DEBUG", "PLATFORM=2"]     # C/C++ macros assumed defined
+//!
+//! [sql]
+//! dialect = "plsql"                     # plsql | tsql, for `.sql` files
+//!
+//! [tools.default]                      # every budgeted tool
+//! max_tokens = 4000                     # output budget when the call sets none
+//!
+//! [tools.fast_refs]                     # overrides `default` per key
+//! max_tokens = 2000
+//!
+//! [telemetry]
+//! enabled = true                        # write local metrics to .julie/
+//! format = "json"                       # prometheus | json
+//!
+//! [[generated]]                         # generator outputs, indexed read-only
+//! root = "gen/go"                       # output directory, even if ignored
+//! include = ["**/*.pb.go"]              # globs relative to `root` (default: all)
+//! source = "proto/{dir}/{stem}.proto"   # IDL each output is generated from
+//! ```
+//!
+//! Every key is optional; a missing file means defaults. Unknown keys and
+//! out-of-range values are rejected with an error naming the file and key, so
+//! a typo never silently falls back to a default. The tables themselves are
+//! in `sections`; loading and validation are in `validation`.
+//!
+//! The process-wide [`WorkspaceSettingsRegistry`] caches the parsed file per
+//! workspace root. The file watcher calls [`WorkspaceSettingsRegistry::reload`]
+//! when the file changes; an invalid edit is reported and the previous settings
+//! stay in effect.
+
+use std::collections::{BTreeMap, HashMap};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex, MutexGuard, OnceLock};
+
+use ignore::gitignore::{Gitignore, GitignoreBuilder};
+use serde::Deserialize;
+use tracing::warn;
+
+use crate::file_policy::SqlDialect;
+use crate::preprocessor::DefineSet;
+use crate::telemetry::{TelemetryFormat, TelemetrySink};
+
+mod sections;
+mod validation;
+
+pub use sections::{
+    ContextSettings, EmbeddingSettings, GeneratedOutputs, IndexSettings, PreprocessorSettings,
+    SemanticSettings, SqlSettings, SymbolOptions, TelemetrySettings, ToolLimits, WatcherSettings,
+};
+pub use validation::SettingsError;
+
+/// Location of the settings file, relative to the workspace root.
+pub const SETTINGS_RELATIVE_PATH: &str = ".julie/config.toml";
+
+/// Accepted range for `context.max_tokens`.
+pub const CONTEXT_MAX_TOKENS_RANGE: std::ops::RangeInclusive<u32> = 500..=50_000;
+
+/// Accepted range for `watcher.poll_interval_ms`.
+pub const WATCHER_POLL_INTERVAL_MS_RANGE: std::ops::RangeInclusive<u64> = 250..=600_000;
+
+/// Accepted range for `tools.<name>.max_tokens`.
+pub const TOOL_MAX_TOKENS_RANGE: std::ops::RangeInclusive<u32> = 200..=100_000;
+
+/// Why embeddings are unavailable in a workspace with `semantic.mode = "disabled"`.
+pub const SEMANTIC_DISABLED_REASON: &str =
+    "semantic search is disabled by `[semantic] mode = \"disabled\"` in .julie/config.toml";
+
+/// `[symbols.<key>]` table that applies to every language.
+pub const SYMBOL_OPTIONS_DEFAULT_KEY: &str = "default";
+
+/// `[tools.<key>]` table that applies to every tool in [`BUDGETED_TOOLS`].
+pub const TOOL_LIMITS_DEFAULT_KEY: &str = "default";
+
+/// Tools a `[tools.<name>]` table can configure: those that shape their
+/// output to a `max_tokens` budget.
+pub const BUDGETED_TOOLS: &[&str] = &["fast_search", "fast_refs", "get_symbols"];
+
+/// Absolute path of the settings file for `workspace_root`.
+pub fn settings_path(workspace_root: &Path) -> PathBuf {
+    workspace_root.join(SETTINGS_RELATIVE_PATH)
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct WorkspaceSettings {
+    pub index: IndexSettings,
+    pub embeddings: EmbeddingSettings,
+    pub semantic: SemanticSettings,
+    pub context: ContextSettings,
+    pub watcher: WatcherSettings,
+    /// Extraction options keyed by language name, plus
+    /// [`SYMBOL_OPTIONS_DEFAULT_KEY`] for every language.
+    pub symbols: BTreeMap<String, SymbolOptions>,
+    pub preprocessor: PreprocessorSettings,
+    pub sql: SqlSettings,
+    pub telemetry: TelemetrySettings,
+    /// Output limits keyed by tool name, plus [`TOOL_LIMITS_DEFAULT_KEY`]
+    /// for every tool.
+    pub tools: BTreeMap<String, ToolLimits>,
+    /// Generator output directories; see [`crate::generated_files`].
+    pub generated: Vec<GeneratedOutputs>,
+}
+
+impl WorkspaceSettings {
+    /// True unless `index.nested_repositories = false`.
+    pub fn indexes_nested_repositories(&self) -> bool {
+        self.index.nested_repositories.unwrap_or(true)
+    }
+
+    /// True when `semantic.mode = "disabled"`: embeddings must never be
+    /// initialized for this workspace.
+    pub fn semantic_disabled(&self) -> bool {
+        self.semantic
+            .mode
+            .as_deref()
+            .is_some_and(|mode| mode.trim().eq_ignore_ascii_case("disabled"))
+    }
+
+    /// The configured C/C++ define set, or `None` when `preprocessor.defines`
+    /// is not set.
+    pub fn preprocessor_defines(&self) -> Option<DefineSet> {
+        // Validated on load.
+        DefineSet::parse(self.preprocessor.defines.as_ref()?).ok()
+    }
+
+    /// The configured dialect of `.sql` files, or `None` when `sql.dialect`
+    /// is not set.
+    pub fn sql_dialect(&self) -> Option<SqlDialect> {
+        SqlDialect::parse(self.sql.dialect.as_deref()?)
+    }
+
+    /// Where `[telemetry]` wants metrics written for `workspace_root`, or
+    /// `None` when telemetry is off.
+    pub fn telemetry_sink(&self, workspace_root: &Path) -> Option<TelemetrySink> {
+        if self.telemetry.enabled != Some(true) {
+            return None;
+        }
+        let format = self
+            .telemetry
+            .format
+            .as_deref()
+            .and_then(TelemetryFormat::parse)
+            .unwrap_or(TelemetryFormat::Prometheus);
+        let path = settings_path(workspace_root).with_file_name(format.file_name());
+        Some(TelemetrySink { path, format })
+    }
+
+    /// Extraction options for `language`: its own table over `default`.
+    pub fn symbol_options(&self, language: &str) -> SymbolOptions {
+        let fallback = self
+            .symbols
+            .get(SYMBOL_OPTIONS_DEFAULT_KEY)
+            .copied()
+            .unwrap_or_default();
+        match self.symbols.get(language) {
+            Some(options) => options.or(fallback),
+            None => fallback,
+        }
+    }
+
+    /// Output limits for `tool`: its own table over `default`.
+    pub fn tool_limits(&self, tool: &str) -> ToolLimits {
+        let fallback = self
+            .tools
+            .get(TOOL_LIMITS_DEFAULT_KEY)
+            .copied()
+            .unwrap_or_default();
+        match self.tools.get(tool) {
+            Some(limits) => limits.or(fallback),
+            None => fallback,
+        }
+    }
+
+    /// Matcher for `index.generated`. `None` when no globs are configured.
+    pub fn generated_code_matcher(&self) -> Option<globset::GlobSet> {
+        if self.index.generated.is_empty() {
+            return None;
+        }
+        let mut builder = globset::GlobSetBuilder::new();
+        for pattern in &self.index.generated {
+            // Validated on load.
+            if let Ok(glob) = globset::Glob::new(pattern) {
+                builder.add(glob);
+            }
+        }
+        builder.build().ok()
+    }
+
+    /// Matcher for `index.ignore`, rooted at `workspace_root`. `None` when no
+    /// patterns are configured.
+    pub fn ignore_matcher(&self, workspace_root: &Path) -> Option<Gitignore> {
+        if self.index.ignore.is_empty() {
+            return None;
+        }
+        let mut builder = GitignoreBuilder::new(workspace_root);
+        for pattern in &self.index.ignore {
+            // Validated on load.
+            let _ = builder.add_line(None, pattern);
+        }
+        builder.build().ok()
+    }
+}
+
+/// Cache of loaded settings keyed by workspace root.
+///
+/// Production code uses the process-wide [`WorkspaceSettingsRegistry::global`];
+/// tests create their own instance for isolation.
+#[derive(Debug, Default)]
+pub struct WorkspaceSettingsRegistry {
+    entries: Mutex<HashMap<PathBuf, Arc<WorkspaceSettings>>>,
+}
+
+impl WorkspaceSettingsRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn global() -> &'static WorkspaceSettingsRegistry {
+        static GLOBAL: OnceLock<WorkspaceSettingsRegistry> = OnceLock::new();
+        GLOBAL.get_or_init(WorkspaceSettingsRegistry::new)
+    }
+
+    fn entries(&self) -> MutexGuard<'_, HashMap<PathBuf, Arc<WorkspaceSettings>>> {
+        self.entries
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    /// Current settings of `workspace_root`, loading them on first use. An
+    /// invalid file is logged once and replaced by defaults until it is fixed.
+    pub fn get(&self, workspace_root: &Path) -> Arc<WorkspaceSettings> {
+        if let Some(settings) = self.entries().get(workspace_root) {
+            return Arc::clone(settings);
+        }
+        let settings = Arc::new(
+            WorkspaceSettings::load(workspace_root).unwrap_or_else(|err| {
+                warn!("{err}; using default workspace settings");
+                WorkspaceSettings::default()
+            }),
+        );
+        Arc::clone(
+            self.entries()
+                .entry(workspace_root.to_path_buf())
+                .or_insert(settings),
+        )
+    }
+
+    /// Re-read the file of `workspace_root`. On error the previously loaded
+    /// settings stay in effect.
+    pub fn reload(&self, workspace_root: &Path) -> Result<Arc<WorkspaceSettings>, SettingsError> {
+        let settings = Arc::new(WorkspaceSettings::load(workspace_root)?);
+        self.entries()
+            .insert(workspace_root.to_path_buf(), Arc::clone(&settings));
+        Ok(settings)
+    }
+}
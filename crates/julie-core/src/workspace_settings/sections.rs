@@ -0,0 +1,152 @@
+//! The per-feature tables of `.julie/config.toml`.
+
+use serde::Deserialize;
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct IndexSettings {
+    /// Extra ignore globs in gitignore syntax, relative to the workspace root.
+    pub ignore: Vec<String>,
+    /// Index git repositories nested in the workspace (submodules and
+    /// plain clones) as projects of their own. Default: true.
+    pub nested_repositories: Option<bool>,
+    /// Globs, relative to the workspace root, of generated code the built-in
+    /// heuristics miss; see [`crate::generated_files::detect_generated_code`].
+    pub generated: Vec<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct EmbeddingSettings {
+    /// Backend preference. `JULIE_EMBEDDING_PROVIDER` takes precedence.
+    pub provider: Option<String>,
+    /// Sidecar model id. `JULIE_EMBEDDING_SIDECAR_MODEL_ID` takes precedence.
+    pub model: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct SemanticSettings {
+    /// `disabled` never starts the embedding subsystem: no model download,
+    /// no ONNX runtime, and semantic/hybrid search report a capability error.
+    /// Lexical search and relationships are unaffected.
+    pub mode: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ContextSettings {
+    /// Default `get_context` token budget, replacing the adaptive one.
+    pub max_tokens: Option<u32>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct WatcherSettings {
+    /// File watcher backend. `JULIE_WATCHER_BACKEND` takes precedence.
+    pub backend: Option<String>,
+    /// Polling backend scan interval. `JULIE_WATCHER_POLL_INTERVAL_MS` takes
+    /// precedence.
+    pub poll_interval_ms: Option<u64>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct PreprocessorSettings {
+    /// `NAME` or `NAME=VALUE` macros the C/C++ code is built with; every
+    /// other macro is undefined. Unset means conditions are recorded but not
+    /// evaluated.
+    pub defines: Option<Vec<String>>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct SqlSettings {
+    /// Dialect of `.sql` files whose content doesn't make it obvious.
+    /// Dialect-specific extensions (`.pkb`, `.tsql`, ...) take precedence.
+    pub dialect: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct TelemetrySettings {
+    /// Record tool, extraction and database timings and write them to
+    /// `.julie/metrics.prom` (or `.json`). Default: false.
+    pub enabled: Option<bool>,
+    /// `prometheus` (text exposition, the default) or `json`.
+    pub format: Option<String>,
+}
+
+/// One `[[generated]]` table: a directory of generator output.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct GeneratedOutputs {
+    /// Output directory, relative to the workspace root. It is indexed even
+    /// when ignored or blacklisted (`build/`, `target/`).
+    pub root: String,
+    /// Globs relative to `root` selecting the outputs to index. Empty
+    /// indexes every supported file under `root`.
+    pub include: Vec<String>,
+    /// Source file of each output, relative to the workspace root, with
+    /// `{dir}` (the output's directory below `root`) and `{stem}` (its file
+    /// name without extensions or generator suffixes) filled in.
+    pub source: Option<String>,
+}
+
+/// Which symbols extraction keeps. Unset keys keep everything.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct SymbolOptions {
+    /// Variables and constants declared inside functions and methods.
+    pub include_locals: Option<bool>,
+    /// Symbols the extractor marked `private`, and everything nested in them.
+    pub include_private: Option<bool>,
+    /// Import symbols (`use`, `import`, `#include`, ...).
+    pub include_imports: Option<bool>,
+}
+
+impl SymbolOptions {
+    /// Keys set here win; unset keys fall back to `fallback`.
+    pub fn or(self, fallback: SymbolOptions) -> SymbolOptions {
+        SymbolOptions {
+            include_locals: self.include_locals.or(fallback.include_locals),
+            include_private: self.include_private.or(fallback.include_private),
+            include_imports: self.include_imports.or(fallback.include_imports),
+        }
+    }
+
+    pub fn includes_locals(&self) -> bool {
+        self.include_locals.unwrap_or(true)
+    }
+
+    pub fn includes_private(&self) -> bool {
+        self.include_private.unwrap_or(true)
+    }
+
+    pub fn includes_imports(&self) -> bool {
+        self.include_imports.unwrap_or(true)
+    }
+
+    /// True when nothing would be filtered out.
+    pub fn keeps_everything(&self) -> bool {
+        self.includes_locals() && self.includes_private() && self.includes_imports()
+    }
+}
+
+/// One `[tools.<name>]` table: limits on a tool's output when the call sets
+/// none of its own.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ToolLimits {
+    /// Default `max_tokens` output budget.
+    pub max_tokens: Option<u32>,
+}
+
+impl ToolLimits {
+    /// Keys set here win; unset keys fall back to `fallback`.
+    pub fn or(self, fallback: ToolLimits) -> ToolLimits {
+        ToolLimits {
+            max_tokens: self.max_tokens.or(fallback.max_tokens),
+        }
+    }
+}
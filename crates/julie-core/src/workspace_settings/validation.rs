@@ -0,0 +1,291 @@
+//! Loading, parsing and validating `.julie/config.toml`.
+//!
+//! Every check names the offending key, so a typo or an out-of-range value
+//! is reported instead of silently falling back to a default.
+
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+use ignore::gitignore::GitignoreBuilder;
+
+use super::{
+    BUDGETED_TOOLS, CONTEXT_MAX_TOKENS_RANGE, SYMBOL_OPTIONS_DEFAULT_KEY, TOOL_LIMITS_DEFAULT_KEY,
+    TOOL_MAX_TOKENS_RANGE, WATCHER_POLL_INTERVAL_MS_RANGE, WorkspaceSettings, settings_path,
+};
+use crate::file_policy::SqlDialect;
+use crate::preprocessor::DefineSet;
+use crate::telemetry::TelemetryFormat;
+
+const EMBEDDING_PROVIDERS: &[&str] = &["auto", "sidecar", "none", "disabled", "off"];
+const WATCHER_BACKENDS: &[&str] = &["auto", "native", "poll"];
+const SEMANTIC_MODES: &[&str] = &["enabled", "disabled"];
+
+#[derive(Debug)]
+pub enum SettingsError {
+    Read {
+        path: PathBuf,
+        source: std::io::Error,
+    },
+    Parse {
+        path: PathBuf,
+        message: String,
+    },
+    Invalid {
+        path: PathBuf,
+        key: &'static str,
+        message: String,
+    },
+}
+
+impl fmt::Display for SettingsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Read { path, source } => {
+                write!(f, "failed to read {}: {source}", path.display())
+            }
+            Self::Parse { path, message } => {
+                write!(f, "invalid {}: {}", path.display(), message.trim_end())
+            }
+            Self::Invalid { path, key, message } => {
+                write!(f, "invalid `{key}` in {}: {message}", path.display())
+            }
+        }
+    }
+}
+
+impl std::error::Error for SettingsError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Read { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+impl WorkspaceSettings {
+    /// Load the settings of `workspace_root`. A missing file yields defaults.
+    pub fn load(workspace_root: &Path) -> Result<Self, SettingsError> {
+        let path = settings_path(workspace_root);
+        match std::fs::read_to_string(&path) {
+            Ok(text) => Self::parse(&text, &path),
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(source) => Err(SettingsError::Read { path, source }),
+        }
+    }
+
+    /// Parse and validate settings text. `path` is only used in error messages.
+    pub fn parse(text: &str, path: &Path) -> Result<Self, SettingsError> {
+        let settings: Self = toml::from_str(text).map_err(|err| SettingsError::Parse {
+            path: path.to_path_buf(),
+            message: err.to_string(),
+        })?;
+        settings.validate(path)?;
+        Ok(settings)
+    }
+
+    fn validate(&self, path: &Path) -> Result<(), SettingsError> {
+        let invalid = |key, message: String| SettingsError::Invalid {
+            path: path.to_path_buf(),
+            key,
+            message,
+        };
+
+        let mut builder = GitignoreBuilder::new("/");
+        for pattern in &self.index.ignore {
+            if pattern.trim().is_empty() {
+                return Err(invalid("index.ignore", "patterns must not be empty".into()));
+            }
+            if pattern.trim_start().starts_with('!') {
+                return Err(invalid(
+                    "index.ignore",
+                    format!(
+                        "negated pattern {pattern:?} is not supported; list only paths to skip"
+                    ),
+                ));
+            }
+            if let Err(err) = builder.add_line(None, pattern) {
+                return Err(invalid(
+                    "index.ignore",
+                    format!("bad pattern {pattern:?}: {err}"),
+                ));
+            }
+        }
+
+        for pattern in &self.index.generated {
+            if let Err(err) = globset::Glob::new(pattern) {
+                return Err(invalid(
+                    "index.generated",
+                    format!("bad pattern {pattern:?}: {err}"),
+                ));
+            }
+        }
+
+        if let Some(provider) = &self.embeddings.provider {
+            let normalized = provider.trim().to_ascii_lowercase();
+            if !EMBEDDING_PROVIDERS.contains(&normalized.as_str()) {
+                return Err(invalid(
+                    "embeddings.provider",
+                    format!("unknown provider {provider:?} (expected auto, sidecar or none)"),
+                ));
+            }
+        }
+
+        if let Some(model) = &self.embeddings.model {
+            if model.trim().is_empty() || model.chars().any(char::is_whitespace) {
+                return Err(invalid(
+                    "embeddings.model",
+                    format!("{model:?} is not a model id (e.g. \"nomic-ai/CodeRankEmbed\")"),
+                ));
+            }
+        }
+
+        if let Some(mode) = &self.semantic.mode {
+            let normalized = mode.trim().to_ascii_lowercase();
+            if !SEMANTIC_MODES.contains(&normalized.as_str()) {
+                return Err(invalid(
+                    "semantic.mode",
+                    format!("unknown mode {mode:?} (expected enabled or disabled)"),
+                ));
+            }
+        }
+
+        if let Some(max_tokens) = self.context.max_tokens {
+            if !CONTEXT_MAX_TOKENS_RANGE.contains(&max_tokens) {
+                return Err(invalid(
+                    "context.max_tokens",
+                    format!(
+                        "{max_tokens} is out of range ({}-{})",
+                        CONTEXT_MAX_TOKENS_RANGE.start(),
+                        CONTEXT_MAX_TOKENS_RANGE.end()
+                    ),
+                ));
+            }
+        }
+
+        if let Some(backend) = &self.watcher.backend {
+            let normalized = backend.trim().to_ascii_lowercase();
+            if !WATCHER_BACKENDS.contains(&normalized.as_str()) {
+                return Err(invalid(
+                    "watcher.backend",
+                    format!("unknown backend {backend:?} (expected auto, native or poll)"),
+                ));
+            }
+        }
+
+        if let Some(interval) = self.watcher.poll_interval_ms {
+            if !WATCHER_POLL_INTERVAL_MS_RANGE.contains(&interval) {
+                return Err(invalid(
+                    "watcher.poll_interval_ms",
+                    format!(
+                        "{interval} is out of range ({}-{})",
+                        WATCHER_POLL_INTERVAL_MS_RANGE.start(),
+                        WATCHER_POLL_INTERVAL_MS_RANGE.end()
+                    ),
+                ));
+            }
+        }
+
+        for language in self.symbols.keys() {
+            let well_formed = !language.is_empty()
+                && language
+                    .chars()
+                    .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_' || c == '-');
+            if !well_formed {
+                return Err(invalid(
+                    "symbols",
+                    format!(
+                        "{language:?} is not a language name (lowercase, e.g. \"rust\" or \"{SYMBOL_OPTIONS_DEFAULT_KEY}\")"
+                    ),
+                ));
+            }
+        }
+
+        if let Some(defines) = &self.preprocessor.defines {
+            if let Err(message) = DefineSet::parse(defines) {
+                return Err(invalid("preprocessor.defines", message));
+            }
+        }
+
+        for (tool, limits) in &self.tools {
+            if tool != TOOL_LIMITS_DEFAULT_KEY && !BUDGETED_TOOLS.contains(&tool.as_str()) {
+                return Err(invalid(
+                    "tools",
+                    format!(
+                        "{tool:?} has no limits to set (expected {TOOL_LIMITS_DEFAULT_KEY:?} or one of {})",
+                        BUDGETED_TOOLS.join(", ")
+                    ),
+                ));
+            }
+            if let Some(max_tokens) = limits.max_tokens {
+                if !TOOL_MAX_TOKENS_RANGE.contains(&max_tokens) {
+                    return Err(invalid(
+                        "tools.max_tokens",
+                        format!(
+                            "{max_tokens} for {tool:?} is out of range ({}-{})",
+                            TOOL_MAX_TOKENS_RANGE.start(),
+                            TOOL_MAX_TOKENS_RANGE.end()
+                        ),
+                    ));
+                }
+            }
+        }
+
+        if let Some(dialect) = &self.sql.dialect {
+            if SqlDialect::parse(dialect).is_none() {
+                return Err(invalid(
+                    "sql.dialect",
+                    format!("unknown dialect {dialect:?} (expected plsql or tsql)"),
+                ));
+            }
+        }
+
+        if let Some(format) = &self.telemetry.format {
+            if TelemetryFormat::parse(format).is_none() {
+                return Err(invalid(
+                    "telemetry.format",
+                    format!("unknown format {format:?} (expected prometheus or json)"),
+                ));
+            }
+        }
+
+        for outputs in &self.generated {
+            let root = outputs.root.trim().trim_end_matches('/');
+            let escapes = Path::new(root)
+                .components()
+                .any(|component| !matches!(component, std::path::Component::Normal(_)));
+            if root.is_empty() || escapes {
+                return Err(invalid(
+                    "generated.root",
+                    format!(
+                        "{:?} is not a directory inside the workspace (e.g. \"gen/go\")",
+                        outputs.root
+                    ),
+                ));
+            }
+            for pattern in &outputs.include {
+                if let Err(err) = globset::Glob::new(pattern) {
+                    return Err(invalid(
+                        "generated.include",
+                        format!("bad pattern {pattern:?}: {err}"),
+                    ));
+                }
+            }
+            if let Some(source) = &outputs.source {
+                let placeholders_ok = source
+                    .split('{')
+                    .skip(1)
+                    .all(|rest| rest.starts_with("dir}") || rest.starts_with("stem}"));
+                if source.trim().is_empty() || !placeholders_ok {
+                    return Err(invalid(
+                        "generated.source",
+                        format!(
+                            "{source:?} is not a source path (placeholders are {{dir}} and {{stem}})"
+                        ),
+                    ));
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,82 @@
+//! Public API surface report: every public/exported declaration of the
+//! workspace (or a focus path), grouped by module, with signatures.
+//!
+//! Library maintainers diff this between releases to catch accidental API
+//! growth. Everything comes from the stored `visibility` column — a symbol
+//! the extractor did not mark public is not part of the surface.
+
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+use julie_core::database::SymbolDatabase;
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ApiSurfaceReport {
+    /// Workspace-relative file or directory the report is scoped to.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub focus_path: Option<String>,
+    pub total_symbols: usize,
+    pub modules: Vec<ApiModule>,
+}
+
+/// One module (source file) and its public declarations, in source order.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ApiModule {
+    pub module: String,
+    pub language: String,
+    pub symbols: Vec<ApiSymbol>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ApiSymbol {
+    /// `Parent.name` for members, plain `name` otherwise.
+    pub name: String,
+    pub kind: String,
+    pub line: u32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub signature: Option<String>,
+}
+
+/// Build the API surface report. `focus_path` is a stored (workspace-relative)
+/// file or directory; `None` covers the whole workspace. Test files and
+/// fixture/example/doc directories are excluded.
+pub fn generate_api_surface(
+    db: &SymbolDatabase,
+    focus_path: Option<&str>,
+) -> Result<ApiSurfaceReport> {
+    let symbols = db.get_public_api_symbols(focus_path)?;
+    let total_symbols = symbols.len();
+
+    let mut modules: BTreeMap<String, ApiModule> = BTreeMap::new();
+    for symbol in symbols {
+        let module = modules
+            .entry(symbol.file_path.clone())
+            .or_insert_with(|| ApiModule {
+                module: symbol.file_path.clone(),
+                language: symbol.language.clone(),
+                symbols: Vec::new(),
+            });
+        let name = match &symbol.parent_name {
+            Some(parent) => format!("{parent}.{}", symbol.name),
+            None => symbol.name,
+        };
+        module.symbols.push(ApiSymbol {
+            name,
+            kind: symbol.kind,
+            line: symbol.start_line,
+            signature: symbol
+                .signature
+                .as_deref()
+                .and_then(|signature| signature.lines().next())
+                .map(|line| line.trim().to_string())
+                .filter(|line| !line.is_empty()),
+        });
+    }
+
+    Ok(ApiSurfaceReport {
+        focus_path: focus_path.map(str::to_string),
+        total_symbols,
+        modules: modules.into_values().collect(),
+    })
+}
@@ -0,0 +1,86 @@
+//! Build target map: which Make, CMake or Bazel targets compile each indexed
+//! file.
+//!
+//! Build target symbols are extracted by
+//! `julie_pipeline::indexing_core::build_targets`, each listing the
+//! workspace-relative files it compiles under its `build` metadata. This pass
+//! keeps the entries that name an indexed file and stores them in
+//! `build_target_files`, so navigation can show the targets that build a
+//! symbol's file. Sources that are generated at build time or live outside
+//! the workspace have no indexed file and drop out.
+
+use anyhow::Result;
+use std::collections::HashSet;
+use tracing::info;
+
+use julie_core::database::{BuildTargetFile, SymbolDatabase};
+
+/// `semantic_group` of build target symbols (see
+/// `julie_pipeline::indexing_core::build_targets::BUILD_TARGET_SEMANTIC_GROUP`).
+const BUILD_TARGET_SEMANTIC_GROUP: &str = "build_target";
+
+/// Metadata key holding a target's `{system, rule, label, sources, deps}`.
+const BUILD_METADATA_KEY: &str = "build";
+
+/// Summary stats from building the build target map.
+#[derive(Debug, Clone, Default)]
+pub struct BuildTargetStats {
+    /// Build target symbols read.
+    pub targets: usize,
+    /// `(file, target)` pairs stored.
+    pub files: usize,
+}
+
+/// Rebuild the build target map from every indexed build target symbol.
+///
+/// Runs alongside `compute_workspace_projects()` in the indexing pipeline.
+/// Replaces the whole `build_target_files` table, so it is safe to run
+/// repeatedly.
+pub fn compute_build_target_files(db: &SymbolDatabase) -> Result<BuildTargetStats> {
+    let targets = db.get_symbols_by_semantic_group(BUILD_TARGET_SEMANTIC_GROUP)?;
+    let mut stats = BuildTargetStats {
+        targets: targets.len(),
+        ..Default::default()
+    };
+    if targets.is_empty() {
+        db.replace_all_build_target_files(&[])?;
+        return Ok(stats);
+    }
+
+    let indexed: HashSet<String> = {
+        let mut stmt = db.conn.prepare("SELECT path FROM files")?;
+        let rows = stmt.query_map([], |row| row.get::<_, String>(0))?;
+        rows.collect::<rusqlite::Result<_>>()?
+    };
+
+    let mut rows = Vec::new();
+    for target in &targets {
+        let Some(info) = target
+            .metadata
+            .as_ref()
+            .and_then(|metadata| metadata.get(BUILD_METADATA_KEY))
+        else {
+            continue;
+        };
+        let label = info["label"].as_str().unwrap_or(&target.name);
+        let sources = info["sources"].as_array().into_iter().flatten();
+        for source in sources.filter_map(|source| source.as_str()) {
+            if !indexed.contains(source) {
+                continue;
+            }
+            rows.push(BuildTargetFile {
+                file_path: source.to_string(),
+                target_id: target.id.clone(),
+                label: label.to_string(),
+                build_file: target.file_path.clone(),
+            });
+        }
+    }
+    stats.files = rows.len();
+    db.replace_all_build_target_files(&rows)?;
+    info!(
+        "Build target map: {} files across {} targets",
+        stats.files, stats.targets
+    );
+    Ok(stats)
+}
@@ -0,0 +1,490 @@
+//! Cross-language counterpart linking: finds type-like symbols in different
+//! languages that model the same entity — a TypeScript `User` interface, a C#
+//! `UserDto` class, a SQL `users` table — and stores confidence-scored links
+//! in the `cross_language_links` table.
+//!
+//! Candidates are grouped by a canonical entity name (snake_case, common
+//! transport/persistence suffixes stripped, last word singularized). Within a
+//! group, each cross-language pair is scored on name evidence plus the overlap
+//! of their field/property/column names.
+//!
+//! Links between `.proto` definitions and their generated gRPC stubs come
+//! from [`grpc_links`](super::grpc_links), and links between OpenAPI
+//! operations and their handlers and clients from
+//! [`openapi_links`](super::openapi_links); both are stored alongside.
+//!
+//! [`match_counterparts`] answers the same question for one symbol on
+//! demand, for `fast_counterparts`: it scores shapes live below the storage
+//! threshold and reports the field diff of each candidate.
+
+use anyhow::Result;
+use std::collections::{BTreeMap, HashMap};
+use tracing::{debug, info};
+
+use julie_core::cross_language_intelligence::to_snake_case;
+use julie_core::database::{CrossLanguageLink, SymbolDatabase, WebEdge, WebEdgeKind};
+use julie_extractors::Symbol;
+
+/// Symbol kinds that can describe an entity shape. SQL tables are extracted
+/// as classes, so they are covered here too.
+const ENTITY_KINDS: &str = "'class', 'struct', 'interface', 'type', 'enum'";
+
+/// Member kinds whose names are compared for shape overlap.
+const MEMBER_KINDS: &str = "'field', 'property', 'enum_member'";
+
+/// Trailing name words that mark a transport or persistence variant of an
+/// entity rather than a different entity (`UserDto`, `user_record`).
+const ENTITY_SUFFIXES: &[&str] = &[
+    "dto",
+    "model",
+    "entity",
+    "record",
+    "row",
+    "schema",
+    "vm",
+    "viewmodel",
+];
+
+/// Confidence for identical snake_case names (`UserProfile` / `user_profile`).
+const EXACT_NAME_CONFIDENCE: f32 = 0.6;
+/// Confidence for names equal only after suffix stripping / singularizing.
+const CANONICAL_NAME_CONFIDENCE: f32 = 0.5;
+/// Weight of the field-overlap Jaccard index on top of the name evidence.
+const FIELD_OVERLAP_WEIGHT: f32 = 0.4;
+/// Penalty when both sides have members but share none of them.
+const DISJOINT_FIELDS_PENALTY: f32 = 0.2;
+
+/// Links below this confidence are not stored.
+pub const MIN_LINK_CONFIDENCE: f32 = 0.5;
+
+/// Default floor of [`match_counterparts`]. Below [`MIN_LINK_CONFIDENCE`] so a
+/// same-named shape whose fields drifted apart is still listed.
+pub const DEFAULT_MATCH_CONFIDENCE: f32 = 0.3;
+
+/// Groups larger than this are generic names (`Config`, `Options`) shared by
+/// unrelated types; linking every pair would be noise.
+const MAX_GROUP_SIZE: usize = 16;
+
+/// Summary stats from running cross-language linking.
+#[derive(Debug, Clone, Default)]
+pub struct CrossLanguageLinkStats {
+    pub candidates: usize,
+    /// Linked pairs (each stored in both directions).
+    pub pairs: usize,
+    /// Proto definition ↔ generated gRPC stub pairs, not counted in `pairs`.
+    pub grpc_pairs: usize,
+    /// OpenAPI operation ↔ handler/client pairs, not counted in `pairs`.
+    pub openapi_pairs: usize,
+}
+
+#[derive(Debug)]
+struct Candidate {
+    id: String,
+    name: String,
+    language: String,
+    /// Normalized member name → the name as this side spells it.
+    members: BTreeMap<String, String>,
+}
+
+/// A candidate counterpart of the symbol passed to [`match_counterparts`].
+#[derive(Debug, Clone)]
+pub struct CounterpartMatch {
+    pub symbol: Symbol,
+    /// Match confidence in `0.0..=1.0`.
+    pub confidence: f32,
+    pub reason: String,
+    /// Members of the queried symbol the candidate lacks.
+    pub missing_fields: Vec<String>,
+    /// Members of the candidate the queried symbol lacks.
+    pub extra_fields: Vec<String>,
+}
+
+/// Recompute all cross-language counterpart links.
+///
+/// Runs after `compute_test_linkage()` in the indexing pipeline. Replaces the
+/// whole `cross_language_links` table, so it is safe to run repeatedly.
+pub fn compute_cross_language_links(db: &SymbolDatabase) -> Result<CrossLanguageLinkStats> {
+    let candidates = load_candidates(db)?;
+
+    let mut groups: BTreeMap<String, Vec<&Candidate>> = BTreeMap::new();
+    for candidate in &candidates {
+        let key = canonical_entity_name(&candidate.name);
+        if !key.is_empty() {
+            groups.entry(key).or_default().push(candidate);
+        }
+    }
+
+    let mut links = Vec::new();
+    let mut stats = CrossLanguageLinkStats {
+        candidates: candidates.len(),
+        pairs: 0,
+        grpc_pairs: 0,
+        openapi_pairs: 0,
+    };
+    for (key, group) in &groups {
+        if group.len() > MAX_GROUP_SIZE {
+            debug!(
+                "Skipping ambiguous cross-language group '{}' ({} symbols)",
+                key,
+                group.len()
+            );
+            continue;
+        }
+        for (i, left) in group.iter().enumerate() {
+            for right in &group[i + 1..] {
+                if left.language == right.language {
+                    continue;
+                }
+                let Some((confidence, reason)) = score_pair(left, right) else {
+                    continue;
+                };
+                stats.pairs += 1;
+                links.push(CrossLanguageLink {
+                    from_symbol_id: left.id.clone(),
+                    to_symbol_id: right.id.clone(),
+                    confidence,
+                    reason: reason.clone(),
+                });
+                links.push(CrossLanguageLink {
+                    from_symbol_id: right.id.clone(),
+                    to_symbol_id: left.id.clone(),
+                    confidence,
+                    reason,
+                });
+            }
+        }
+    }
+
+    // Appended last so a gRPC or OpenAPI link replaces a name-only link for
+    // the same pair.
+    let (grpc_links, grpc_pairs) = super::grpc_links::compute_grpc_links(db)?;
+    stats.grpc_pairs = grpc_pairs;
+    links.extend(grpc_links);
+    let (openapi_links, openapi_pairs) = super::openapi_links::compute_openapi_links(db)?;
+    stats.openapi_pairs = openapi_pairs;
+    links.extend(openapi_links);
+
+    db.replace_all_cross_language_links(&links)?;
+    info!(
+        "Cross-language linking: {} candidates, {} linked pairs, {} gRPC stub pairs, {} OpenAPI pairs",
+        stats.candidates, stats.pairs, stats.grpc_pairs, stats.openapi_pairs
+    );
+    Ok(stats)
+}
+
+fn load_candidates(db: &SymbolDatabase) -> Result<Vec<Candidate>> {
+    let mut stmt = db.conn.prepare(&format!(
+        "SELECT id, name, language FROM symbols
+         WHERE kind IN ({ENTITY_KINDS})
+         ORDER BY file_path, start_line"
+    ))?;
+    let mut candidates: Vec<Candidate> = stmt
+        .query_map([], |row| {
+            Ok(Candidate {
+                id: row.get(0)?,
+                name: row.get(1)?,
+                language: row.get(2)?,
+                members: BTreeMap::new(),
+            })
+        })?
+        .collect::<rusqlite::Result<Vec<_>>>()?;
+
+    let stmt = db.conn.prepare(&format!(
+        "SELECT m.parent_id, m.name
+         FROM symbols m
+         JOIN symbols p ON p.id = m.parent_id
+         WHERE m.kind IN ({MEMBER_KINDS}) AND p.kind IN ({ENTITY_KINDS})"
+    ))?;
+    attach_members(stmt, [], &mut candidates)?;
+    Ok(candidates)
+}
+
+/// Fill in `candidates`' members from `stmt`, which selects
+/// `(parent_id, name)` rows.
+fn attach_members<P: rusqlite::Params>(
+    mut stmt: rusqlite::Statement<'_>,
+    params: P,
+    candidates: &mut [Candidate],
+) -> Result<()> {
+    let mut members: HashMap<String, BTreeMap<String, String>> = HashMap::new();
+    let rows = stmt.query_map(params, |row| {
+        Ok((row.get::<_, String>(0)?, row.get::<_, String>(1)?))
+    })?;
+    for row in rows {
+        let (parent_id, name) = row?;
+        let normalized = normalize_member_name(&name);
+        if !normalized.is_empty() {
+            members
+                .entry(parent_id)
+                .or_default()
+                .entry(normalized)
+                .or_insert(name);
+        }
+    }
+
+    for candidate in candidates {
+        if let Some(names) = members.remove(&candidate.id) {
+            candidate.members = names;
+        }
+    }
+    Ok(())
+}
+
+/// Score one cross-language pair, or `None` when it falls below
+/// [`MIN_LINK_CONFIDENCE`].
+fn score_pair(left: &Candidate, right: &Candidate) -> Option<(f32, String)> {
+    let (confidence, reason) = score_shape(left, right);
+    (confidence >= MIN_LINK_CONFIDENCE).then_some((confidence, reason))
+}
+
+/// Name evidence plus member overlap of a pair sharing a canonical name.
+fn score_shape(left: &Candidate, right: &Candidate) -> (f32, String) {
+    let exact = to_snake_case(&left.name) == to_snake_case(&right.name);
+    let (mut confidence, mut reason) = if exact {
+        (EXACT_NAME_CONFIDENCE, "same name".to_string())
+    } else {
+        (CANONICAL_NAME_CONFIDENCE, "normalized name".to_string())
+    };
+
+    if !left.members.is_empty() && !right.members.is_empty() {
+        let shared = left
+            .members
+            .keys()
+            .filter(|name| right.members.contains_key(*name))
+            .count();
+        let total = left.members.len() + right.members.len() - shared;
+        if shared == 0 {
+            confidence -= DISJOINT_FIELDS_PENALTY;
+        } else {
+            confidence += FIELD_OVERLAP_WEIGHT * shared as f32 / total as f32;
+        }
+        reason.push_str(&format!(", {shared}/{total} fields"));
+    }
+
+    (confidence.clamp(0.0, 1.0), reason)
+}
+
+/// Candidate counterparts of `symbol_id` in other languages with confidence
+/// of at least `min_confidence`, highest first.
+///
+/// Entity shapes sharing the symbol's canonical name are scored live, so
+/// pairs below [`MIN_LINK_CONFIDENCE`] are found too, and each carries the
+/// member names only one side has. Stored gRPC and OpenAPI links and HTTP
+/// edges between a client call and the route handler it reaches (endpoints
+/// with matching paths) are merged in; the best evidence per candidate wins.
+pub fn match_counterparts(
+    db: &SymbolDatabase,
+    symbol_id: &str,
+    min_confidence: f32,
+) -> Result<Vec<CounterpartMatch>> {
+    let ids = [symbol_id.to_string()];
+    let Some(symbol) = db.get_symbols_by_ids(&ids)?.into_iter().next() else {
+        return Ok(Vec::new());
+    };
+
+    let mut evidence: HashMap<String, Evidence> = HashMap::new();
+    for link in db.cross_language_links_for_symbols(&ids)? {
+        keep_best(
+            &mut evidence,
+            link.to_symbol_id,
+            link.confidence,
+            link.reason,
+        );
+    }
+    for edge in db.web_edges_from_symbols(&ids)? {
+        if let (WebEdgeKind::HttpCall, Some(to)) = (edge.kind, edge.to_symbol_id.clone()) {
+            let reason = format!("serves route {}", route_label(&edge));
+            keep_best(&mut evidence, to, edge.confidence, reason);
+        }
+    }
+    for edge in db.web_edges_to_symbols(&ids)? {
+        if edge.kind == WebEdgeKind::HttpCall {
+            let reason = format!("calls route {}", route_label(&edge));
+            keep_best(
+                &mut evidence,
+                edge.from_symbol_id.clone(),
+                edge.confidence,
+                reason,
+            );
+        }
+    }
+
+    let group = load_entity_group(db, &canonical_entity_name(&symbol.name))?;
+    if let Some(queried) = group.iter().find(|candidate| candidate.id == symbol.id) {
+        for candidate in &group {
+            if candidate.language == queried.language {
+                continue;
+            }
+            let (confidence, reason) = score_shape(queried, candidate);
+            let entry = keep_best(&mut evidence, candidate.id.clone(), confidence, reason);
+            if !queried.members.is_empty() && !candidate.members.is_empty() {
+                entry.2 = only_in(&queried.members, &candidate.members);
+                entry.3 = only_in(&candidate.members, &queried.members);
+            }
+        }
+    }
+
+    evidence.retain(|_, (confidence, ..)| *confidence >= min_confidence);
+    let candidate_ids: Vec<String> = evidence.keys().cloned().collect();
+    let mut matches: Vec<CounterpartMatch> = db
+        .get_symbols_by_ids(&candidate_ids)?
+        .into_iter()
+        .filter(|candidate| candidate.language != symbol.language)
+        .filter_map(|candidate| {
+            let (confidence, reason, missing_fields, extra_fields) =
+                evidence.remove(&candidate.id)?;
+            Some(CounterpartMatch {
+                symbol: candidate,
+                confidence,
+                reason,
+                missing_fields,
+                extra_fields,
+            })
+        })
+        .collect();
+    matches.sort_by(|a, b| {
+        b.confidence
+            .total_cmp(&a.confidence)
+            .then_with(|| a.symbol.file_path.cmp(&b.symbol.file_path))
+            .then_with(|| a.symbol.start_line.cmp(&b.symbol.start_line))
+    });
+    Ok(matches)
+}
+
+/// Confidence, reason, missing fields and extra fields of one candidate.
+type Evidence = (f32, String, Vec<String>, Vec<String>);
+
+/// Record `confidence` and `reason` for `id` unless it already has stronger
+/// evidence.
+fn keep_best(
+    evidence: &mut HashMap<String, Evidence>,
+    id: String,
+    confidence: f32,
+    reason: String,
+) -> &mut Evidence {
+    let entry = evidence
+        .entry(id)
+        .or_insert_with(|| (confidence, reason.clone(), Vec::new(), Vec::new()));
+    if confidence > entry.0 {
+        entry.0 = confidence;
+        entry.1 = reason;
+    }
+    entry
+}
+
+/// Entity symbols whose canonical name is `key`, with their members.
+fn load_entity_group(db: &SymbolDatabase, key: &str) -> Result<Vec<Candidate>> {
+    if key.is_empty() {
+        return Ok(Vec::new());
+    }
+    let mut stmt = db.conn.prepare(&format!(
+        "SELECT id, name, language FROM symbols
+         WHERE kind IN ({ENTITY_KINDS})
+         ORDER BY file_path, start_line"
+    ))?;
+    let mut group: Vec<Candidate> = stmt
+        .query_map([], |row| {
+            Ok(Candidate {
+                id: row.get(0)?,
+                name: row.get(1)?,
+                language: row.get(2)?,
+                members: BTreeMap::new(),
+            })
+        })?
+        .collect::<rusqlite::Result<Vec<_>>>()?;
+    group.retain(|candidate| canonical_entity_name(&candidate.name) == key);
+    if group.is_empty() {
+        return Ok(group);
+    }
+
+    let placeholders = (1..=group.len())
+        .map(|i| format!("?{i}"))
+        .collect::<Vec<_>>()
+        .join(",");
+    let ids: Vec<String> = group.iter().map(|candidate| candidate.id.clone()).collect();
+    let stmt = db.conn.prepare(&format!(
+        "SELECT parent_id, name FROM symbols
+         WHERE kind IN ({MEMBER_KINDS}) AND parent_id IN ({placeholders})
+         ORDER BY start_line"
+    ))?;
+    attach_members(stmt, rusqlite::params_from_iter(ids.iter()), &mut group)?;
+    Ok(group)
+}
+
+/// Names in `side` whose normalized form `other` lacks, as `side` spells them.
+fn only_in(side: &BTreeMap<String, String>, other: &BTreeMap<String, String>) -> Vec<String> {
+    side.iter()
+        .filter(|(normalized, _)| !other.contains_key(*normalized))
+        .map(|(_, name)| name.clone())
+        .collect()
+}
+
+fn route_label(edge: &WebEdge) -> String {
+    format!(
+        "{} {}",
+        edge.method.as_deref().unwrap_or("ANY"),
+        edge.path.as_deref().unwrap_or("?")
+    )
+}
+
+/// Canonical entity name used to group counterparts: `IUserDto`, `UserModel`,
+/// `user_records` and `users` all become `user`.
+pub fn canonical_entity_name(name: &str) -> String {
+    let snake = to_snake_case(name);
+    let mut words: Vec<String> = snake
+        .split('_')
+        .filter(|w| !w.is_empty())
+        .map(str::to_string)
+        .collect();
+
+    // C#/TS interface prefix: `IUser` → `i_user`.
+    if words.len() > 1 && words[0] == "i" && name.starts_with('I') {
+        words.remove(0);
+    }
+    // Singularize before and after suffix stripping so both `user_records`
+    // and `UsersDto` reduce to `user`.
+    singularize_last(&mut words);
+    while words.len() > 1
+        && words
+            .last()
+            .is_some_and(|word| ENTITY_SUFFIXES.contains(&word.as_str()))
+    {
+        words.pop();
+    }
+    singularize_last(&mut words);
+    words.join("_")
+}
+
+fn singularize_last(words: &mut [String]) {
+    if let Some(last) = words.last_mut() {
+        *last = singularize(last);
+    }
+}
+
+fn singularize(word: &str) -> String {
+    if let Some(stem) = word.strip_suffix("ies")
+        && !stem.is_empty()
+    {
+        return format!("{stem}y");
+    }
+    if word.ends_with("sses") || word.ends_with("xes") || word.ends_with("ches") {
+        return word[..word.len() - 2].to_string();
+    }
+    if word.len() > 3
+        && word.ends_with('s')
+        && !["ss", "us", "is"]
+            .iter()
+            .any(|ending| word.ends_with(ending))
+    {
+        return word[..word.len() - 1].to_string();
+    }
+    word.to_string()
+}
+
+/// `user_id`, `userId` and `UserId` compare equal.
+fn normalize_member_name(name: &str) -> String {
+    name.chars()
+        .filter(|c| c.is_alphanumeric())
+        .flat_map(char::to_lowercase)
+        .collect()
+}
@@ -0,0 +1,207 @@
+//! Module dependency matrix: cross-file relationships rolled up into
+//! directory-to-directory counts for the most connected modules, with
+//! upward dependencies flagged for layered architectures.
+//!
+//! A module is a file's directory truncated to `depth` components below the
+//! focus path (`src/tools/search/mod.rs` at depth 2 is `src/tools`). A
+//! dependency is upward when it runs against the layering: with explicit
+//! `layers` (highest first), a module depending on a module in a higher
+//! layer; without them, the weaker direction of two modules that depend on
+//! each other.
+
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+
+use julie_core::database::SymbolDatabase;
+
+pub const DEFAULT_DEPTH: usize = 2;
+pub const DEFAULT_TOP_MODULES: usize = 10;
+
+#[derive(Debug, Clone)]
+pub struct DependencyMatrixOptions {
+    /// Workspace-relative directory the report is scoped to.
+    pub focus_path: Option<String>,
+    /// Directory components (below the focus path) that name a module.
+    pub depth: usize,
+    /// Modules shown in the matrix.
+    pub top: usize,
+    /// Layer path prefixes, highest layer first (`src/handler`, `src/tools`,
+    /// `crates/julie-core`). Empty infers direction from mutual dependencies.
+    pub layers: Vec<String>,
+}
+
+impl Default for DependencyMatrixOptions {
+    fn default() -> Self {
+        Self {
+            focus_path: None,
+            depth: DEFAULT_DEPTH,
+            top: DEFAULT_TOP_MODULES,
+            layers: Vec::new(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct DependencyMatrixReport {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub focus_path: Option<String>,
+    pub depth: usize,
+    /// Modules with at least one cross-module dependency.
+    pub total_modules: usize,
+    /// Cross-module relationships across all modules.
+    pub total_dependencies: usize,
+    /// The most connected modules (dependencies in plus out), in layer order
+    /// when layers are given.
+    pub modules: Vec<String>,
+    /// `matrix[i][j]`: relationships from `modules[i]` to `modules[j]`.
+    pub matrix: Vec<Vec<usize>>,
+    /// Upward dependencies across all modules, largest first.
+    pub upward: Vec<UpwardDependency>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct UpwardDependency {
+    pub from: String,
+    pub to: String,
+    pub relationships: usize,
+    /// Why the dependency counts as upward.
+    pub reason: String,
+}
+
+/// Build the dependency matrix. Test files and fixture/example/doc
+/// directories are excluded.
+pub fn generate_dependency_matrix(
+    db: &SymbolDatabase,
+    options: &DependencyMatrixOptions,
+) -> Result<DependencyMatrixReport> {
+    let focus_path = options
+        .focus_path
+        .as_deref()
+        .map(|path| path.trim_end_matches('/'));
+    let depth = options.depth.max(1);
+
+    let mut edges: BTreeMap<(String, String), usize> = BTreeMap::new();
+    for dependency in db.get_file_dependencies(focus_path)? {
+        let from = module_of(&dependency.from_file, focus_path, depth);
+        let to = module_of(&dependency.to_file, focus_path, depth);
+        if from != to {
+            *edges.entry((from, to)).or_default() += dependency.relationships as usize;
+        }
+    }
+
+    let mut weights: HashMap<&str, usize> = HashMap::new();
+    for ((from, to), count) in &edges {
+        *weights.entry(from.as_str()).or_default() += count;
+        *weights.entry(to.as_str()).or_default() += count;
+    }
+    let total_modules = weights.len();
+    let total_dependencies = edges.values().sum();
+
+    let mut ranked: Vec<(&str, usize)> = weights.into_iter().collect();
+    ranked.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(b.0)));
+    ranked.truncate(options.top);
+    let mut modules: Vec<String> = ranked
+        .iter()
+        .map(|(module, _)| module.to_string())
+        .collect();
+    if !options.layers.is_empty() {
+        // Stable: modules outside every layer keep their rank order, last.
+        modules.sort_by_key(|module| layer_of(module, &options.layers).unwrap_or(usize::MAX));
+    }
+
+    let matrix = modules
+        .iter()
+        .map(|from| {
+            modules
+                .iter()
+                .map(|to| edges.get(&(from.clone(), to.clone())).copied().unwrap_or(0))
+                .collect()
+        })
+        .collect();
+
+    let mut upward: Vec<UpwardDependency> = edges
+        .iter()
+        .filter_map(|((from, to), &count)| {
+            let reason = if options.layers.is_empty() {
+                let reverse = edges.get(&(to.clone(), from.clone())).copied()?;
+                (count < reverse)
+                    .then(|| format!("{to} depends on {from} more ({reverse} relationships)"))?
+            } else {
+                let from_layer = layer_of(from, &options.layers)?;
+                let to_layer = layer_of(to, &options.layers)?;
+                (to_layer < from_layer).then(|| {
+                    format!(
+                        "layer {} ({}) depends on higher layer {} ({})",
+                        from_layer + 1,
+                        options.layers[from_layer],
+                        to_layer + 1,
+                        options.layers[to_layer]
+                    )
+                })?
+            };
+            Some(UpwardDependency {
+                from: from.clone(),
+                to: to.clone(),
+                relationships: count,
+                reason,
+            })
+        })
+        .collect();
+    upward.sort_by(|a, b| {
+        b.relationships
+            .cmp(&a.relationships)
+            .then_with(|| a.from.cmp(&b.from))
+            .then_with(|| a.to.cmp(&b.to))
+    });
+
+    Ok(DependencyMatrixReport {
+        focus_path: focus_path.map(str::to_string),
+        depth,
+        total_modules,
+        total_dependencies,
+        modules,
+        matrix,
+        upward,
+    })
+}
+
+/// The module a file belongs to: its directory, truncated to `depth`
+/// components below `focus_path`. Files directly in the focus directory (or
+/// the workspace root, shown as `.`) form their own module.
+pub fn module_of(file_path: &str, focus_path: Option<&str>, depth: usize) -> String {
+    let (prefix, relative) = match focus_path.and_then(|focus| {
+        file_path
+            .strip_prefix(focus)
+            .and_then(|rest| rest.strip_prefix('/'))
+            .map(|rest| (focus, rest))
+    }) {
+        Some((focus, rest)) => (Some(focus), rest),
+        None => (None, file_path),
+    };
+    let directories: Vec<&str> = relative.split('/').collect();
+    let directories = &directories[..directories.len() - 1];
+    let module = directories[..directories.len().min(depth)].join("/");
+    match (prefix, module.is_empty()) {
+        (Some(focus), true) => focus.to_string(),
+        (Some(focus), false) => format!("{focus}/{module}"),
+        (None, true) => ".".to_string(),
+        (None, false) => module,
+    }
+}
+
+/// Index of the most specific layer containing `module`, if any.
+fn layer_of(module: &str, layers: &[String]) -> Option<usize> {
+    layers
+        .iter()
+        .enumerate()
+        .filter(|(_, layer)| {
+            let layer = layer.trim_end_matches('/');
+            module == layer
+                || module
+                    .strip_prefix(layer)
+                    .is_some_and(|rest| rest.starts_with('/'))
+        })
+        .max_by_key(|(_, layer)| layer.len())
+        .map(|(index, _)| index)
+}
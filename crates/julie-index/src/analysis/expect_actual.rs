@@ -0,0 +1,107 @@
+//! Kotlin Multiplatform linking: connects each `actual` declaration in a
+//! platform source set to the `expect` declaration in common code it
+//! implements.
+//!
+//! Extraction (`julie_pipeline::indexing_core::kotlin_multiplatform`) tags
+//! both sides with their role and a shared `partial_group` key; this pass
+//! pairs them across files and stores an `Implements` relationship from every
+//! `actual` to its `expect`, so `fast_refs` on the `expect` lists the
+//! platform implementations and `deep_dive` on an `actual` shows what it
+//! implements.
+
+use anyhow::Result;
+use std::collections::BTreeMap;
+use tracing::info;
+
+use julie_core::database::SymbolDatabase;
+use julie_extractors::{Relationship, RelationshipKind};
+
+/// Id prefix of the relationships this pass owns. The whole set is replaced
+/// on every run.
+pub const EXPECT_ACTUAL_RELATIONSHIP_PREFIX: &str = "expect_actual";
+
+/// Summary stats from expect/actual linking.
+#[derive(Debug, Clone, Default)]
+pub struct ExpectActualStats {
+    /// `expect` declarations seen.
+    pub expects: usize,
+    /// `actual` declarations seen.
+    pub actuals: usize,
+    /// `actual` → `expect` relationships stored.
+    pub links: usize,
+}
+
+#[derive(Debug)]
+struct Declaration {
+    id: String,
+    file_path: String,
+    line: u32,
+    expect: bool,
+}
+
+/// Recompute every `actual` → `expect` relationship.
+pub fn compute_expect_actual_links(db: &SymbolDatabase) -> Result<ExpectActualStats> {
+    let mut stmt = db.conn.prepare(
+        "SELECT id, file_path, start_line,
+                json_extract(metadata, '$.kotlin_multiplatform'),
+                json_extract(metadata, '$.partial_group')
+         FROM symbols
+         WHERE language = 'kotlin'
+           AND json_extract(metadata, '$.kotlin_multiplatform') IN ('expect', 'actual')
+           AND json_extract(metadata, '$.partial_group') IS NOT NULL
+         ORDER BY file_path, start_line",
+    )?;
+    let mut groups: BTreeMap<String, Vec<Declaration>> = BTreeMap::new();
+    let rows = stmt.query_map([], |row| {
+        Ok((
+            Declaration {
+                id: row.get(0)?,
+                file_path: row.get(1)?,
+                line: row.get(2)?,
+                expect: row.get::<_, String>(3)? == "expect",
+            },
+            row.get::<_, String>(4)?,
+        ))
+    })?;
+    for row in rows {
+        let (declaration, group) = row?;
+        groups.entry(group).or_default().push(declaration);
+    }
+
+    let mut stats = ExpectActualStats::default();
+    let mut relationships = Vec::new();
+    for declarations in groups.values() {
+        let (expects, actuals): (Vec<&Declaration>, Vec<&Declaration>) =
+            declarations.iter().partition(|d| d.expect);
+        stats.expects += expects.len();
+        stats.actuals += actuals.len();
+        for actual in &actuals {
+            for expect in &expects {
+                relationships.push(Relationship {
+                    id: format!(
+                        "{EXPECT_ACTUAL_RELATIONSHIP_PREFIX}:{}:{}",
+                        actual.id, expect.id
+                    ),
+                    from_symbol_id: actual.id.clone(),
+                    to_symbol_id: expect.id.clone(),
+                    kind: RelationshipKind::Implements,
+                    file_path: actual.file_path.clone(),
+                    line_number: actual.line,
+                    confidence: 1.0,
+                    metadata: None,
+                });
+            }
+        }
+    }
+    drop(stmt);
+
+    stats.links =
+        db.replace_derived_relationships(EXPECT_ACTUAL_RELATIONSHIP_PREFIX, &relationships)?;
+    if stats.expects + stats.actuals > 0 {
+        info!(
+            "Kotlin expect/actual linking: {} links between {} expect and {} actual declarations",
+            stats.links, stats.expects, stats.actuals
+        );
+    }
+    Ok(stats)
+}
@@ -0,0 +1,608 @@
+//! External-library attribution for unresolved calls: when a call target is
+//! not defined in the workspace, the file's imports usually say where it
+//! comes from — `_.debounce(...)` after `import _ from 'lodash'`,
+//! `requests.get(...)` after `import requests`. This pass records that
+//! library for each such call identifier in `identifier_external_modules`.
+//!
+//! Imports are parsed from the import symbols' statements per language
+//! (JS/TS, Python, Rust, Go, Java/Kotlin, C# aliases). A call is attributed
+//! when its name, or the receiver its `code_context` shows it called on, is a
+//! name the import binds. Relative and workspace-local imports are skipped:
+//! a module whose path matches a workspace directory or file, or whose
+//! namespace the workspace declares, is not a library.
+
+use anyhow::Result;
+use std::collections::{HashMap, HashSet};
+use tracing::info;
+
+use julie_core::database::{IdentifierExternalModule, ImportStatement, SymbolDatabase};
+
+/// Summary stats from running external-module attribution.
+#[derive(Debug, Clone, Default)]
+pub struct ExternalModuleStats {
+    /// Import statements that resolved to an external library.
+    pub external_imports: usize,
+    /// Unresolved call identifiers considered.
+    pub unresolved_calls: usize,
+    /// Calls attributed to a library.
+    pub attributed_calls: usize,
+}
+
+/// One module brought in by an import statement.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ImportedModule {
+    /// Module path as written (`lodash/fp`, `requests.adapters`, `net/http`).
+    pub module: String,
+    /// Library the module belongs to (`lodash`, `requests`, `net/http`).
+    pub library: String,
+    /// Local names the import binds.
+    pub bindings: Vec<String>,
+    /// Relative or crate-local import (`./util`, `from . import x`, `crate::`).
+    pub relative: bool,
+}
+
+/// Recompute external-library attribution for every unresolved call.
+///
+/// Runs alongside `compute_cross_language_links()` in the indexing pipeline.
+/// Replaces the whole `identifier_external_modules` table, so it is safe to
+/// run repeatedly.
+pub fn compute_external_call_modules(db: &SymbolDatabase) -> Result<ExternalModuleStats> {
+    let workspace = WorkspaceModules::load(db)?;
+    let mut stats = ExternalModuleStats::default();
+
+    // file_path -> binding -> library
+    let mut bindings: HashMap<String, HashMap<String, String>> = HashMap::new();
+    for ImportStatement {
+        file_path,
+        language,
+        name,
+        statement,
+        ..
+    } in db.get_import_statements()?
+    {
+        for import in parse_imports(&language, &statement) {
+            if import.relative || workspace.contains(&language, &import.module) {
+                continue;
+            }
+            stats.external_imports += 1;
+            let file_bindings = bindings.entry(file_path.clone()).or_default();
+            for binding in &import.bindings {
+                file_bindings.insert(binding.clone(), import.library.clone());
+            }
+            // Extractors may name the symbol after the binding even when the
+            // statement parse found none (e.g. a truncated signature).
+            if import.bindings.is_empty() && name.chars().all(is_ident_char) {
+                file_bindings.insert(name.clone(), import.library.clone());
+            }
+        }
+    }
+
+    let mut attributed = Vec::new();
+    let mut stmt = db.conn.prepare(
+        "SELECT id, name, file_path, code_context
+         FROM identifiers
+         WHERE kind = 'call' AND target_symbol_id IS NULL",
+    )?;
+    let rows = stmt.query_map([], |row| {
+        Ok((
+            row.get::<_, String>(0)?,
+            row.get::<_, String>(1)?,
+            row.get::<_, String>(2)?,
+            row.get::<_, Option<String>>(3)?,
+        ))
+    })?;
+    for row in rows {
+        let (id, name, file_path, code_context) = row?;
+        stats.unresolved_calls += 1;
+        let Some(file_bindings) = bindings.get(&file_path) else {
+            continue;
+        };
+        if let Some(library) = attribute_call(&name, code_context.as_deref(), file_bindings) {
+            attributed.push(IdentifierExternalModule {
+                identifier_id: id,
+                module: library.to_string(),
+            });
+        }
+    }
+    stats.attributed_calls = attributed.len();
+
+    db.replace_all_identifier_external_modules(&attributed)?;
+    info!(
+        "External module attribution: {} external imports, {}/{} unresolved calls attributed",
+        stats.external_imports, stats.attributed_calls, stats.unresolved_calls
+    );
+    Ok(stats)
+}
+
+/// The library a call most likely comes from, given its file's bindings.
+///
+/// A qualified name is attributed by its qualifier (`_.debounce`,
+/// `Client::new`). A bare name is checked against how `code_context` shows
+/// it being called: directly (`get(` after `from requests import get`) or on
+/// a receiver (`requests.get(`, while `items.map(` is not lodash's `map`).
+pub fn attribute_call<'a>(
+    name: &str,
+    code_context: Option<&str>,
+    bindings: &'a HashMap<String, String>,
+) -> Option<&'a str> {
+    let root = qualifier_root(name);
+    if root != name {
+        return bindings.get(root).map(String::as_str);
+    }
+
+    let mut seen_call = false;
+    for receiver in code_context
+        .into_iter()
+        .flat_map(|context| call_receivers(context, name))
+    {
+        seen_call = true;
+        if let Some(library) = bindings.get(receiver.unwrap_or(name)) {
+            return Some(library);
+        }
+    }
+    // No usable context: trust the bare name.
+    if seen_call {
+        None
+    } else {
+        bindings.get(name).map(String::as_str)
+    }
+}
+
+/// First segment of a possibly qualified name: `_` for `_.map`, `Client`
+/// for `Client::new`, the name itself when unqualified.
+fn qualifier_root(name: &str) -> &str {
+    name.split(['.', ':']).next().unwrap_or(name)
+}
+
+/// How each whole-word occurrence of `name` in `context` is reached:
+/// `Some(root)` for a receiver chain (`np` in `np.linalg.norm`), `None` for
+/// a direct call.
+fn call_receivers<'a>(context: &'a str, name: &'a str) -> impl Iterator<Item = Option<&'a str>> {
+    context.match_indices(name).filter_map(move |(start, _)| {
+        let after = context[start + name.len()..].chars().next();
+        let before = &context[..start];
+        if after.is_some_and(is_ident_char) || before.chars().next_back().is_some_and(is_ident_char)
+        {
+            return None;
+        }
+        let chain_start = before
+            .char_indices()
+            .rev()
+            .find(|&(_, ch)| !(is_ident_char(ch) || ch == '.' || ch == ':'))
+            .map_or(0, |(index, ch)| index + ch.len_utf8());
+        let chain = &before[chain_start..];
+        if chain.ends_with('.') || chain.ends_with("::") {
+            // A leading-dot chain (`\n  .map(`) yields an empty root that
+            // matches no binding.
+            Some(Some(qualifier_root(chain)))
+        } else {
+            Some(None)
+        }
+    })
+}
+
+fn is_ident_char(ch: char) -> bool {
+    ch.is_alphanumeric() || ch == '_' || ch == '$'
+}
+
+/// Parse the modules an import statement brings in. Unknown languages and
+/// statements that do not parse yield nothing.
+pub fn parse_imports(language: &str, statement: &str) -> Vec<ImportedModule> {
+    let statement = statement.trim();
+    match language {
+        "javascript" | "typescript" | "tsx" | "jsx" | "vue" => {
+            parse_js_import(statement).into_iter().collect()
+        }
+        "python" => parse_python_import(statement),
+        "rust" => parse_rust_use(statement).into_iter().collect(),
+        "go" => parse_go_import(statement),
+        "java" | "kotlin" => parse_jvm_import(statement).into_iter().collect(),
+        "csharp" => parse_csharp_using(statement).into_iter().collect(),
+        _ => Vec::new(),
+    }
+}
+
+/// First string literal (single, double or back quotes) in `text`.
+fn first_string_literal(text: &str) -> Option<&str> {
+    let (start, quote) = text
+        .char_indices()
+        .find(|&(_, ch)| matches!(ch, '\'' | '"' | '`'))?;
+    let rest = &text[start + 1..];
+    rest.find(quote).map(|end| &rest[..end])
+}
+
+/// `import _ from 'lodash'`, `import { a as b } from "x"`,
+/// `import * as fs from 'fs'`, `const { get } = require('axios')`.
+fn parse_js_import(statement: &str) -> Option<ImportedModule> {
+    let (clause, module) = if let Some(index) = statement.find("require(") {
+        let module = first_string_literal(&statement[index..])?;
+        let clause = statement[..index]
+            .trim_end()
+            .trim_end_matches('=')
+            .trim_start_matches("export ");
+        let clause = ["const ", "let ", "var "]
+            .iter()
+            .find_map(|keyword| clause.trim_start().strip_prefix(keyword))
+            .unwrap_or("");
+        (clause, module)
+    } else {
+        let rest = statement
+            .strip_prefix("import")
+            .or_else(|| statement.strip_prefix("export"))?;
+        match rest.rfind(" from ") {
+            Some(index) => (&rest[..index], first_string_literal(&rest[index..])?),
+            // Side-effect import: binds nothing.
+            None => ("", first_string_literal(rest)?),
+        }
+    };
+
+    let mut bindings = Vec::new();
+    let clause = clause.trim();
+    let clause = clause.strip_prefix("type ").unwrap_or(clause);
+    let (outside, inside) = match (clause.find('{'), clause.rfind('}')) {
+        (Some(open), Some(close)) if open < close => (
+            format!("{} {}", &clause[..open], &clause[close + 1..]),
+            &clause[open + 1..close],
+        ),
+        _ => (clause.to_string(), ""),
+    };
+    for part in outside.split(',') {
+        let part = part.trim();
+        let binding = part
+            .strip_prefix('*')
+            .map(|rest| rest.trim().strip_prefix("as").unwrap_or(rest).trim())
+            .unwrap_or(part);
+        push_binding(&mut bindings, binding);
+    }
+    for part in inside.split(',') {
+        let part = part.trim();
+        let part = part.strip_prefix("type ").unwrap_or(part);
+        let binding = part
+            .rsplit_once(" as ")
+            .or_else(|| part.rsplit_once(':'))
+            .map_or(part, |(_, alias)| alias);
+        push_binding(&mut bindings, binding);
+    }
+
+    let relative = module.starts_with(['.', '/', '#', '~']) || module.starts_with("@/");
+    let library = if module.starts_with('@') {
+        module.splitn(3, '/').take(2).collect::<Vec<_>>().join("/")
+    } else {
+        module.split('/').next().unwrap_or(module).to_string()
+    };
+    Some(ImportedModule {
+        module: module.to_string(),
+        library,
+        bindings,
+        relative,
+    })
+}
+
+/// `import requests`, `import numpy as np, os`, `from a.b import c, d as e`.
+fn parse_python_import(statement: &str) -> Vec<ImportedModule> {
+    let python_module = |module: &str, bindings: Vec<String>| {
+        let module = module.trim();
+        ImportedModule {
+            module: module.to_string(),
+            library: module.split('.').next().unwrap_or(module).to_string(),
+            bindings,
+            relative: module.starts_with('.'),
+        }
+    };
+
+    if let Some(rest) = statement.strip_prefix("from ") {
+        let Some((module, names)) = rest.split_once(" import ") else {
+            return Vec::new();
+        };
+        let mut bindings = Vec::new();
+        for name in names
+            .trim_matches(|ch| matches!(ch, '(' | ')' | ' '))
+            .split(',')
+        {
+            let name = name.trim().trim_matches(|ch| matches!(ch, '(' | ')'));
+            let binding = name.rsplit_once(" as ").map_or(name, |(_, alias)| alias);
+            push_binding(&mut bindings, binding);
+        }
+        return vec![python_module(module, bindings)];
+    }
+
+    let Some(rest) = statement.strip_prefix("import ") else {
+        return Vec::new();
+    };
+    rest.split(',')
+        .filter_map(|part| {
+            let part = part.trim();
+            let (module, binding) = match part.rsplit_once(" as ") {
+                Some((module, alias)) => (module.trim(), alias.trim()),
+                // `import a.b` binds `a`.
+                None => (part, part.split('.').next().unwrap_or(part)),
+            };
+            if module.is_empty() {
+                return None;
+            }
+            let mut bindings = Vec::new();
+            push_binding(&mut bindings, binding);
+            Some(python_module(module, bindings))
+        })
+        .collect()
+}
+
+/// `use reqwest::Client;`, `use serde::{Deserialize, Serialize as Ser};`,
+/// `extern crate rand;`.
+fn parse_rust_use(statement: &str) -> Option<ImportedModule> {
+    let statement = statement.trim_end_matches(';').trim();
+    let statement = statement
+        .strip_prefix("pub")
+        .map(|rest| {
+            rest.trim_start()
+                .strip_prefix('(')
+                .and_then(|rest| rest.split_once(')'))
+                .map_or(rest, |(_, rest)| rest)
+        })
+        .unwrap_or(statement)
+        .trim_start();
+    let tree = if let Some(name) = statement.strip_prefix("extern crate ") {
+        name
+    } else {
+        statement.strip_prefix("use ")?
+    };
+    let tree = tree.trim().trim_start_matches("::");
+
+    let root = tree
+        .split(|ch: char| ch == ':' || ch == '{' || ch == ' ' || ch == ',')
+        .next()
+        .filter(|root| !root.is_empty())?;
+    let mut bindings = Vec::new();
+    collect_use_bindings(tree, "", &mut bindings);
+    Some(ImportedModule {
+        module: tree
+            .split("::{")
+            .next()
+            .unwrap_or(tree)
+            .split(" as ")
+            .next()
+            .unwrap_or(tree)
+            .to_string(),
+        library: root.to_string(),
+        bindings,
+        relative: matches!(root, "crate" | "self" | "super"),
+    })
+}
+
+/// Names bound by a `use` tree; `parent` is the last path segment before
+/// the enclosing braces (bound by a `self` leaf).
+fn collect_use_bindings(tree: &str, parent: &str, bindings: &mut Vec<String>) {
+    let tree = tree.trim();
+    if let Some(open) = tree.find('{') {
+        let path = tree[..open].trim_end_matches("::");
+        let last = path.rsplit("::").next().unwrap_or(path);
+        let close = tree.rfind('}').unwrap_or(tree.len());
+        for item in split_top_level(&tree[open + 1..close]) {
+            collect_use_bindings(item, last, bindings);
+        }
+        return;
+    }
+    if let Some((_, alias)) = tree.rsplit_once(" as ") {
+        push_binding(bindings, alias);
+        return;
+    }
+    match tree.rsplit("::").next().unwrap_or(tree) {
+        "self" => push_binding(bindings, parent),
+        "*" => {}
+        name => push_binding(bindings, name),
+    }
+}
+
+/// Split on commas that are not nested inside braces.
+fn split_top_level(text: &str) -> Vec<&str> {
+    let mut parts = Vec::new();
+    let mut depth = 0usize;
+    let mut start = 0;
+    for (index, ch) in text.char_indices() {
+        match ch {
+            '{' => depth += 1,
+            '}' => depth = depth.saturating_sub(1),
+            ',' if depth == 0 => {
+                parts.push(&text[start..index]);
+                start = index + 1;
+            }
+            _ => {}
+        }
+    }
+    parts.push(&text[start..]);
+    parts
+}
+
+/// `import "fmt"`, `import h "net/http"`, and grouped `import ( ... )`.
+fn parse_go_import(statement: &str) -> Vec<ImportedModule> {
+    let Some(rest) = statement.strip_prefix("import") else {
+        return Vec::new();
+    };
+    rest.trim()
+        .trim_start_matches('(')
+        .trim_end_matches(')')
+        .split(['\n', ';'])
+        .filter_map(|spec| {
+            let spec = spec.trim();
+            let path = first_string_literal(spec)?;
+            let alias = spec[..spec.find(['"', '`'])?].trim();
+            let mut segments = path.split('/');
+            let last = path.rsplit('/').next().unwrap_or(path);
+            // `gopkg.in/yaml.v3` binds `yaml`; `.../v2` binds the segment before.
+            let package = last.split_once(".v").map_or(last, |(name, _)| name);
+            let package = if is_go_major_version(package) {
+                path.rsplit('/').nth(1).unwrap_or(package)
+            } else {
+                package
+            };
+            let mut bindings = Vec::new();
+            match alias {
+                "" => push_binding(&mut bindings, package),
+                "_" | "." => {}
+                alias => push_binding(&mut bindings, alias),
+            }
+            // Hosted module paths: the repository is the library.
+            let library = if segments.next().is_some_and(|host| host.contains('.')) {
+                path.splitn(4, '/').take(3).collect::<Vec<_>>().join("/")
+            } else {
+                path.to_string()
+            };
+            Some(ImportedModule {
+                module: path.to_string(),
+                library,
+                bindings,
+                relative: path.starts_with('.'),
+            })
+        })
+        .collect()
+}
+
+fn is_go_major_version(segment: &str) -> bool {
+    segment
+        .strip_prefix('v')
+        .is_some_and(|digits| !digits.is_empty() && digits.chars().all(|ch| ch.is_ascii_digit()))
+}
+
+/// `import com.fasterxml.jackson.databind.ObjectMapper;`,
+/// `import static org.junit.Assert.assertEquals;`, Kotlin `import a.B as C`.
+fn parse_jvm_import(statement: &str) -> Option<ImportedModule> {
+    let rest = statement
+        .strip_prefix("import ")?
+        .trim_end_matches(';')
+        .trim();
+    let (rest, is_static) = match rest.strip_prefix("static ") {
+        Some(rest) => (rest.trim(), true),
+        None => (rest, false),
+    };
+    let (path, alias) = match rest.split_once(" as ") {
+        Some((path, alias)) => (path.trim(), Some(alias.trim())),
+        None => (rest, None),
+    };
+    let segments: Vec<&str> = path.split('.').collect();
+    let last = *segments.last()?;
+    let mut bindings = Vec::new();
+    if last != "*" {
+        push_binding(&mut bindings, alias.unwrap_or(last));
+    }
+    // Drop the imported member (static) and class names to get the package.
+    let package_len = segments
+        .iter()
+        .position(|segment| segment.starts_with(|ch: char| ch.is_ascii_uppercase()))
+        .unwrap_or(segments.len() - usize::from(last == "*" || is_static));
+    let package = segments[..package_len.max(1)].join(".");
+    Some(ImportedModule {
+        module: package.clone(),
+        library: package,
+        bindings,
+        relative: false,
+    })
+}
+
+/// `using Json = Newtonsoft.Json;` — only aliases bind a callable name;
+/// plain `using` directives import a namespace without one.
+fn parse_csharp_using(statement: &str) -> Option<ImportedModule> {
+    let rest = statement
+        .trim_start_matches("global ")
+        .strip_prefix("using ")?
+        .trim_end_matches(';');
+    let (alias, target) = rest.split_once('=')?;
+    let target = target.trim();
+    let mut bindings = Vec::new();
+    push_binding(&mut bindings, alias.trim());
+    Some(ImportedModule {
+        module: target.to_string(),
+        library: target.to_string(),
+        bindings,
+        relative: false,
+    })
+}
+
+fn push_binding(bindings: &mut Vec<String>, binding: &str) {
+    let binding = binding.trim();
+    if !binding.is_empty()
+        && binding.chars().all(is_ident_char)
+        && !bindings.iter().any(|existing| existing == binding)
+    {
+        bindings.push(binding.to_string());
+    }
+}
+
+/// What the workspace itself defines, to tell local modules from libraries.
+struct WorkspaceModules {
+    /// Every directory path suffix and extension-less file path suffix, with
+    /// `-` folded to `_` (`julie-core/src` -> `julie_core/src`).
+    paths: HashSet<String>,
+    /// Declared namespace / package / module names.
+    namespaces: HashSet<String>,
+}
+
+impl WorkspaceModules {
+    fn load(db: &SymbolDatabase) -> Result<Self> {
+        let mut paths = HashSet::new();
+        let mut stmt = db.conn.prepare("SELECT path FROM files")?;
+        let rows = stmt.query_map([], |row| row.get::<_, String>(0))?;
+        for row in rows {
+            let path = row?.replace('\\', "/").replace('-', "_");
+            let stem = path
+                .rsplit_once('.')
+                .filter(|(stem, _)| !stem.is_empty() && !stem.ends_with('/'))
+                .map_or(path.as_str(), |(stem, _)| stem);
+            let segments: Vec<&str> = stem.split('/').filter(|s| !s.is_empty()).collect();
+            for end in 1..=segments.len() {
+                for start in 0..end {
+                    paths.insert(segments[start..end].join("/"));
+                }
+            }
+        }
+
+        let mut stmt = db
+            .conn
+            .prepare("SELECT DISTINCT name FROM symbols WHERE kind IN ('namespace', 'module')")?;
+        let namespaces = stmt
+            .query_map([], |row| row.get::<_, String>(0))?
+            .collect::<rusqlite::Result<HashSet<_>>>()?;
+        Ok(Self { paths, namespaces })
+    }
+
+    /// Whether `module` (imported from a `language` file) is defined in the
+    /// workspace rather than by a library.
+    fn contains(&self, language: &str, module: &str) -> bool {
+        let separator = match language {
+            "python" | "java" | "kotlin" | "csharp" => ".",
+            "rust" => "::",
+            _ => "/",
+        };
+        let segments: Vec<&str> = module.split(separator).filter(|s| !s.is_empty()).collect();
+        if segments.is_empty() {
+            return false;
+        }
+
+        let path = |segments: &[&str]| segments.join("/").replace('-', "_");
+        // A bare single segment (`react`, `fmt`) is too easily matched by an
+        // unrelated directory; only multi-segment paths are compared whole.
+        if segments.len() >= 2 && self.paths.contains(&path(&segments)) {
+            return true;
+        }
+        // Python packages and Rust crates are identified by their root.
+        if matches!(language, "python" | "rust") && self.paths.contains(&path(&segments[..1])) {
+            return true;
+        }
+        // A module path ending in a workspace directory (Go module paths
+        // carry the repository prefix).
+        if segments.len() >= 2 && self.paths.contains(&path(&segments[segments.len() - 2..])) {
+            return true;
+        }
+
+        // Dotted languages: a namespace the workspace declares.
+        if separator != "." {
+            return false;
+        }
+        let dotted = segments.join(".");
+        self.namespaces.iter().any(|namespace| {
+            dotted == *namespace
+                || (namespace.contains('.')
+                    && (dotted.starts_with(&format!("{namespace}."))
+                        || namespace.starts_with(&format!("{dotted}."))))
+        })
+    }
+}
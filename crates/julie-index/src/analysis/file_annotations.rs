@@ -0,0 +1,161 @@
+//! Per-file symbol annotations for editor inlay hints and decorations.
+//!
+//! One call returns everything a thin editor extension needs to decorate a
+//! file: incoming reference counts, whether static test linkage found any
+//! tests, and deprecation markers. Everything is read from data the indexer
+//! and post-indexing analysis already stored — nothing is recomputed here.
+
+use anyhow::Result;
+use rusqlite::params;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+
+use crate::analysis::test_linkage::TestLinkageInfo;
+use julie_core::database::SymbolDatabase;
+use julie_extractors::{Symbol, SymbolKind};
+
+/// Annotation keys (normalized, lowercase) that mark a symbol deprecated:
+/// Java/Kotlin `@Deprecated`, Rust `#[deprecated]`, Python `@deprecated`,
+/// C# `[Obsolete]`.
+const DEPRECATION_ANNOTATION_KEYS: &[&str] = &["deprecated", "obsolete"];
+
+/// Doc-comment tag used by JSDoc, TSDoc, PHPDoc and Javadoc.
+const DEPRECATED_DOC_TAG: &str = "@deprecated";
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct FileAnnotationReport {
+    pub file_path: String,
+    pub symbols: Vec<SymbolAnnotation>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct SymbolAnnotation {
+    pub symbol_id: String,
+    pub name: String,
+    pub kind: String,
+    pub start_line: u32,
+    pub start_column: u32,
+    pub end_line: u32,
+    /// Incoming relationships from other symbols (self-edges excluded).
+    pub reference_count: usize,
+    /// `reference_count` split by relationship kind (`calls`, `uses`, ...).
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub references_by_kind: BTreeMap<String, usize>,
+    pub test_count: usize,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub best_test_tier: Option<String>,
+    /// `Some` when the symbol is deprecated; holds the marker's raw text or
+    /// doc-comment reason, or an empty string when neither is available.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub deprecated: Option<String>,
+}
+
+impl SymbolAnnotation {
+    pub fn has_tests(&self) -> bool {
+        self.test_count > 0
+    }
+}
+
+/// Build the annotation report for one stored (workspace-relative) file path.
+///
+/// Symbols come back in source order. Imports and exports are skipped: they
+/// are use sites, not declarations an editor would decorate. An unknown path
+/// yields an empty report rather than an error, so editors can call this for
+/// any open buffer.
+pub fn generate_file_annotations(
+    db: &SymbolDatabase,
+    file_path: &str,
+) -> Result<FileAnnotationReport> {
+    let symbols = db.get_symbols_for_file(file_path)?;
+    let mut references = incoming_reference_counts(db, file_path)?;
+
+    let symbols = symbols
+        .into_iter()
+        .filter(|symbol| !matches!(symbol.kind, SymbolKind::Import | SymbolKind::Export))
+        .map(|symbol| {
+            let references_by_kind = references.remove(&symbol.id).unwrap_or_default();
+            let linkage = test_linkage(&symbol);
+            SymbolAnnotation {
+                reference_count: references_by_kind.values().sum(),
+                references_by_kind,
+                test_count: linkage.as_ref().map_or(0, |info| info.test_count),
+                best_test_tier: linkage
+                    .filter(|info| info.test_count > 0)
+                    .map(|info| info.best_tier),
+                deprecated: deprecation(&symbol),
+                symbol_id: symbol.id,
+                name: symbol.name,
+                kind: symbol.kind.to_string(),
+                start_line: symbol.start_line,
+                start_column: symbol.start_column,
+                end_line: symbol.end_line,
+            }
+        })
+        .collect();
+
+    Ok(FileAnnotationReport {
+        file_path: file_path.to_string(),
+        symbols,
+    })
+}
+
+/// Incoming relationship counts for every symbol defined in `file_path`, in a
+/// single grouped query.
+fn incoming_reference_counts(
+    db: &SymbolDatabase,
+    file_path: &str,
+) -> Result<HashMap<String, BTreeMap<String, usize>>> {
+    let mut stmt = db.conn.prepare(
+        "SELECT r.to_symbol_id, r.kind, COUNT(*)
+         FROM relationships r
+         JOIN symbols s ON s.id = r.to_symbol_id
+         WHERE s.file_path = ?1 AND r.from_symbol_id != r.to_symbol_id
+         GROUP BY r.to_symbol_id, r.kind",
+    )?;
+    let rows = stmt.query_map(params![file_path], |row| {
+        Ok((
+            row.get::<_, String>(0)?,
+            row.get::<_, String>(1)?,
+            row.get::<_, i64>(2)?,
+        ))
+    })?;
+
+    let mut counts: HashMap<String, BTreeMap<String, usize>> = HashMap::new();
+    for row in rows {
+        let (symbol_id, kind, count) = row?;
+        counts
+            .entry(symbol_id)
+            .or_default()
+            .insert(kind, count.max(0) as usize);
+    }
+    Ok(counts)
+}
+
+fn test_linkage(symbol: &Symbol) -> Option<TestLinkageInfo> {
+    let metadata = symbol.metadata.as_ref()?;
+    let entry = metadata
+        .get("test_linkage")
+        .or_else(|| metadata.get("test_coverage"))?;
+    serde_json::from_value(entry.clone()).ok()
+}
+
+fn deprecation(symbol: &Symbol) -> Option<String> {
+    if let Some(marker) = symbol
+        .annotations
+        .iter()
+        .find(|marker| DEPRECATION_ANNOTATION_KEYS.contains(&marker.annotation_key.as_str()))
+    {
+        return Some(marker.raw_text.clone().unwrap_or_default());
+    }
+
+    let doc = symbol.doc_comment.as_deref()?;
+    let (_, rest) = doc.split_once(DEPRECATED_DOC_TAG)?;
+    let reason = rest
+        .lines()
+        .next()
+        .unwrap_or_default()
+        .trim()
+        .trim_end_matches("*/")
+        .trim();
+    Some(reason.to_string())
+}
@@ -0,0 +1,568 @@
+//! Framework detection: which application frameworks each project uses, and
+//! the symbols that play a framework role in them.
+//!
+//! A framework is detected for a directory from its manifest, or failing
+//! that from an import in one of its source files:
+//!
+//! - React / Angular: `react` / `@angular/core` in a `package.json`
+//!   dependency list, or imported from a JS/TS file.
+//! - Spring: `org.springframework` in `pom.xml` / `build.gradle(.kts)`, or
+//!   an `import org.springframework.` in Java/Kotlin.
+//! - Rails: `gem "rails"` in the `Gemfile`, or an `ActionController`
+//!   reference in Ruby.
+//! - ASP.NET: the `Microsoft.NET.Sdk.Web` SDK or a `Microsoft.AspNetCore`
+//!   package in a `.csproj`, or a `using Microsoft.AspNetCore.` in C#.
+//! - Flutter: `sdk: flutter` in `pubspec.yaml`, or a `package:flutter/`
+//!   import in Dart.
+//!
+//! Imports are credited to the importing file's project (from the project
+//! map, so this runs after `compute_workspace_projects()`). Symbols under a
+//! directory using a framework are then matched against its conventions:
+//! PascalCase components in JSX/TSX, Spring stereotype annotations, public
+//! methods of Rails and ASP.NET controllers, and so on. Results land in
+//! `project_frameworks` and `framework_symbols`.
+
+use anyhow::Result;
+use std::collections::{HashMap, HashSet};
+use tracing::info;
+
+use julie_core::database::{FrameworkSymbol, ProjectFramework, SymbolDatabase};
+use julie_extractors::{Symbol, SymbolKind, Visibility};
+
+use super::workspace_projects::{file_name, parent_dir, path_is_under};
+
+/// A framework Julie detects.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Framework {
+    /// Key stored in the database (`react`, `aspnet`, ...).
+    pub key: &'static str,
+    /// Display name.
+    pub name: &'static str,
+}
+
+/// A symbol role within a framework.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FrameworkRole {
+    /// Key stored in the database and accepted by filters (`spring_bean`).
+    pub key: &'static str,
+    /// Key of the framework the role belongs to.
+    pub framework: &'static str,
+    /// Display label (`Spring bean`).
+    pub label: &'static str,
+}
+
+pub const FRAMEWORKS: &[Framework] = &[
+    Framework {
+        key: "react",
+        name: "React",
+    },
+    Framework {
+        key: "angular",
+        name: "Angular",
+    },
+    Framework {
+        key: "spring",
+        name: "Spring",
+    },
+    Framework {
+        key: "rails",
+        name: "Rails",
+    },
+    Framework {
+        key: "aspnet",
+        name: "ASP.NET",
+    },
+    Framework {
+        key: "flutter",
+        name: "Flutter",
+    },
+];
+
+pub const FRAMEWORK_ROLES: &[FrameworkRole] = &[
+    FrameworkRole {
+        key: "react_component",
+        framework: "react",
+        label: "React component",
+    },
+    FrameworkRole {
+        key: "angular_component",
+        framework: "angular",
+        label: "Angular component",
+    },
+    FrameworkRole {
+        key: "angular_service",
+        framework: "angular",
+        label: "Angular service",
+    },
+    FrameworkRole {
+        key: "spring_bean",
+        framework: "spring",
+        label: "Spring bean",
+    },
+    FrameworkRole {
+        key: "spring_endpoint",
+        framework: "spring",
+        label: "Spring endpoint",
+    },
+    FrameworkRole {
+        key: "rails_controller_action",
+        framework: "rails",
+        label: "Rails controller action",
+    },
+    FrameworkRole {
+        key: "aspnet_controller_action",
+        framework: "aspnet",
+        label: "ASP.NET controller action",
+    },
+    FrameworkRole {
+        key: "flutter_widget",
+        framework: "flutter",
+        label: "Flutter widget",
+    },
+];
+
+/// The framework with this key.
+pub fn framework(key: &str) -> Option<&'static Framework> {
+    FRAMEWORKS.iter().find(|framework| framework.key == key)
+}
+
+/// The role with this key.
+pub fn framework_role(key: &str) -> Option<&'static FrameworkRole> {
+    FRAMEWORK_ROLES.iter().find(|role| role.key == key)
+}
+
+/// Summary stats from framework detection.
+#[derive(Debug, Clone, Default)]
+pub struct FrameworkStats {
+    /// `(directory, framework)` pairs detected.
+    pub frameworks: usize,
+    /// Symbols given a framework role.
+    pub symbols: usize,
+}
+
+/// Spring stereotypes that register the annotated class as a bean.
+const SPRING_BEAN_ANNOTATIONS: &[&str] = &[
+    "component",
+    "service",
+    "repository",
+    "controller",
+    "restcontroller",
+    "configuration",
+];
+
+/// Spring annotations that map a method to an HTTP route.
+const SPRING_ENDPOINT_ANNOTATIONS: &[&str] = &[
+    "requestmapping",
+    "getmapping",
+    "postmapping",
+    "putmapping",
+    "deletemapping",
+    "patchmapping",
+];
+
+/// Files whose contents are read per batch while scanning imports.
+const IMPORT_SCAN_BATCH: usize = 500;
+
+/// Detect every project's frameworks and label the framework symbols.
+///
+/// Runs after `compute_workspace_projects()` in the indexing pipeline.
+/// Replaces both framework tables, so it is safe to run repeatedly.
+pub fn compute_frameworks(db: &SymbolDatabase) -> Result<FrameworkStats> {
+    let mut files = Vec::new();
+    {
+        let mut stmt = db.conn.prepare("SELECT path FROM files")?;
+        let rows = stmt.query_map([], |row| row.get::<_, String>(0))?;
+        for row in rows {
+            files.push(row?);
+        }
+    }
+    // File -> (project, project root), and project root -> project.
+    let mut file_projects: HashMap<String, (String, String)> = HashMap::new();
+    let mut root_projects: HashMap<String, String> = HashMap::new();
+    {
+        let mut stmt = db
+            .conn
+            .prepare("SELECT file_path, project, manifest_path FROM project_files")?;
+        let rows = stmt.query_map([], |row| {
+            Ok((
+                row.get::<_, String>(0)?,
+                row.get::<_, String>(1)?,
+                row.get::<_, String>(2)?,
+            ))
+        })?;
+        for row in rows {
+            let (file, project, manifest) = row?;
+            let root = parent_dir(&manifest).to_string();
+            root_projects.insert(root.clone(), project.clone());
+            file_projects.insert(file, (project, root));
+        }
+    }
+
+    let mut detected = Detections::default();
+
+    // Manifests first: they are the stronger evidence.
+    let manifests: Vec<String> = files
+        .iter()
+        .filter(|path| is_framework_manifest(path))
+        .cloned()
+        .collect();
+    let contents = db.get_file_contents_by_paths(&manifests)?;
+    for manifest in &manifests {
+        let Some(Some(content)) = contents.get(manifest) else {
+            continue;
+        };
+        let root = parent_dir(manifest);
+        for (key, evidence) in detect_manifest_frameworks(manifest, content) {
+            detected.add(root, root_projects.get(root).cloned(), key, evidence);
+        }
+    }
+
+    // Then imports, for the frameworks a file's project doesn't have yet.
+    let sources: Vec<(&String, &str, Option<String>)> = files
+        .iter()
+        .filter_map(|path| {
+            let (root, project) = match file_projects.get(path) {
+                Some((project, root)) => (root.as_str(), Some(project.clone())),
+                None => ("", None),
+            };
+            import_candidates(path)
+                .iter()
+                .any(|key| !detected.covers(path, key))
+                .then_some((path, root, project))
+        })
+        .collect();
+    for batch in sources.chunks(IMPORT_SCAN_BATCH) {
+        let paths: Vec<String> = batch.iter().map(|(path, _, _)| (*path).clone()).collect();
+        let contents = db.get_file_contents_by_paths(&paths)?;
+        for (path, root, project) in batch {
+            let Some(Some(content)) = contents.get(*path) else {
+                continue;
+            };
+            for key in detect_import_frameworks(path, content) {
+                if !detected.covers(path, key) {
+                    detected.add(root, project.clone(), key, format!("import in {path}"));
+                }
+            }
+        }
+    }
+    let detected = detected.rows;
+
+    let mut stats = FrameworkStats {
+        frameworks: detected.len(),
+        ..Default::default()
+    };
+    if detected.is_empty() {
+        db.replace_all_frameworks(&[], &[])?;
+        return Ok(stats);
+    }
+
+    let symbols = db.get_all_symbols()?;
+    let by_id: HashMap<&str, &Symbol> = symbols.iter().map(|s| (s.id.as_str(), s)).collect();
+    let mut active_cache: HashMap<&str, Vec<&'static str>> = HashMap::new();
+    let mut rows = Vec::new();
+    for symbol in &symbols {
+        if import_candidates(&symbol.file_path).is_empty() {
+            continue;
+        }
+        let active = active_cache
+            .entry(symbol.file_path.as_str())
+            .or_insert_with(|| {
+                detected
+                    .iter()
+                    .filter(|d| path_is_under(&symbol.file_path, &d.root))
+                    .filter_map(|d| framework(&d.framework).map(|f| f.key))
+                    .collect()
+            });
+        if active.is_empty() {
+            continue;
+        }
+        let parent = symbol
+            .parent_id
+            .as_deref()
+            .and_then(|id| by_id.get(id).copied());
+        if let Some(role) = classify_framework_role(symbol, parent, active) {
+            rows.push(FrameworkSymbol {
+                symbol_id: symbol.id.clone(),
+                framework: role.framework.to_string(),
+                role: role.key.to_string(),
+            });
+        }
+    }
+    stats.symbols = rows.len();
+    db.replace_all_frameworks(&detected, &rows)?;
+    info!(
+        "Framework detection: {} project frameworks, {} framework symbols",
+        stats.frameworks, stats.symbols
+    );
+    Ok(stats)
+}
+
+// ---------------------------------------------------------------------------
+// Detection
+// ---------------------------------------------------------------------------
+
+/// Detected `(root, framework)` pairs; the first evidence for a pair wins.
+#[derive(Default)]
+struct Detections {
+    rows: Vec<ProjectFramework>,
+    seen: HashSet<(String, &'static str)>,
+}
+
+impl Detections {
+    /// Whether `path` already lies under a directory using `key`.
+    fn covers(&self, path: &str, key: &str) -> bool {
+        self.rows
+            .iter()
+            .any(|row| row.framework == key && path_is_under(path, &row.root))
+    }
+
+    fn add(&mut self, root: &str, project: Option<String>, key: &'static str, evidence: String) {
+        if self.seen.insert((root.to_string(), key)) {
+            self.rows.push(ProjectFramework {
+                root: root.to_string(),
+                project,
+                framework: key.to_string(),
+                evidence,
+            });
+        }
+    }
+}
+
+fn is_framework_manifest(path: &str) -> bool {
+    let lower = file_name(path).to_ascii_lowercase();
+    matches!(
+        lower.as_str(),
+        "package.json"
+            | "pom.xml"
+            | "build.gradle"
+            | "build.gradle.kts"
+            | "gemfile"
+            | "pubspec.yaml"
+    ) || lower.ends_with(".csproj")
+}
+
+/// Frameworks declared by the manifest at `path`, with the evidence for each.
+pub fn detect_manifest_frameworks(path: &str, content: &str) -> Vec<(&'static str, String)> {
+    let name = file_name(path);
+    let lower = name.to_ascii_lowercase();
+    let mut found = Vec::new();
+    match lower.as_str() {
+        "package.json" => {
+            let Ok(value) = serde_json::from_str::<serde_json::Value>(content) else {
+                return found;
+            };
+            for (key, package) in [("react", "react"), ("angular", "@angular/core")] {
+                let declared = ["dependencies", "devDependencies", "peerDependencies"]
+                    .iter()
+                    .any(|section| value[*section].get(package).is_some());
+                if declared {
+                    found.push((key, format!("{name} dependency {package}")));
+                }
+            }
+        }
+        "pom.xml" | "build.gradle" | "build.gradle.kts" => {
+            if content.contains("org.springframework") {
+                found.push(("spring", format!("{name} dependency org.springframework")));
+            }
+        }
+        "gemfile" => {
+            let declares_rails = content.lines().any(|line| {
+                let line = line.trim();
+                line.starts_with("gem \"rails\"") || line.starts_with("gem 'rails'")
+            });
+            if declares_rails {
+                found.push(("rails", format!("{name} gem rails")));
+            }
+        }
+        "pubspec.yaml" => {
+            if content.contains("sdk: flutter") {
+                found.push(("flutter", format!("{name} flutter sdk")));
+            }
+        }
+        _ if lower.ends_with(".csproj") => {
+            if content.contains("Microsoft.NET.Sdk.Web") {
+                found.push(("aspnet", format!("{name} Microsoft.NET.Sdk.Web")));
+            } else if content.contains("Include=\"Microsoft.AspNetCore") {
+                found.push(("aspnet", format!("{name} package Microsoft.AspNetCore")));
+            }
+        }
+        _ => {}
+    }
+    found
+}
+
+/// Frameworks an import in a file like `path` can reveal.
+fn import_candidates(path: &str) -> &'static [&'static str] {
+    match extension(path) {
+        "js" | "jsx" | "mjs" | "cjs" | "ts" | "tsx" => &["react", "angular"],
+        "java" | "kt" | "kts" => &["spring"],
+        "rb" => &["rails"],
+        "cs" => &["aspnet"],
+        "dart" => &["flutter"],
+        _ => &[],
+    }
+}
+
+/// Frameworks the source file at `path` imports.
+pub fn detect_import_frameworks(path: &str, content: &str) -> Vec<&'static str> {
+    let mut found = Vec::new();
+    for &key in import_candidates(path) {
+        let imported = content.lines().any(|line| {
+            let line = line.trim();
+            match key {
+                "react" => imports_js_module(line, "react"),
+                "angular" => imports_js_module(line, "@angular/core"),
+                "spring" => line.starts_with("import org.springframework."),
+                "rails" => {
+                    line.contains("ActionController::") || line.contains("Rails.application")
+                }
+                "aspnet" => {
+                    line.starts_with("using Microsoft.AspNetCore.")
+                        || line.starts_with("global using Microsoft.AspNetCore.")
+                }
+                "flutter" => {
+                    line.starts_with("import 'package:flutter/")
+                        || line.starts_with("import \"package:flutter/")
+                }
+                _ => false,
+            }
+        });
+        if imported {
+            found.push(key);
+        }
+    }
+    found
+}
+
+/// `import ... from 'module'`, `import 'module/sub'` or `require("module")`.
+fn imports_js_module(line: &str, module: &str) -> bool {
+    if !line.starts_with("import ") && !line.contains("require(") && !line.contains("} from ") {
+        return false;
+    }
+    ['\'', '"'].iter().any(|quote| {
+        line.contains(&format!("{quote}{module}{quote}"))
+            || line.contains(&format!("{quote}{module}/"))
+    })
+}
+
+// ---------------------------------------------------------------------------
+// Roles
+// ---------------------------------------------------------------------------
+
+/// The role `symbol` plays under the `active` frameworks of its directory.
+/// `parent` is the symbol's enclosing symbol, if any.
+pub fn classify_framework_role(
+    symbol: &Symbol,
+    parent: Option<&Symbol>,
+    active: &[&str],
+) -> Option<&'static FrameworkRole> {
+    let ext = extension(&symbol.file_path);
+    let role = match ext {
+        "js" | "jsx" | "mjs" | "cjs" | "ts" | "tsx" => {
+            if active.contains(&"angular") && symbol.kind == SymbolKind::Class {
+                if has_annotation(symbol, &["component"]) {
+                    return framework_role("angular_component");
+                }
+                if has_annotation(symbol, &["injectable"]) {
+                    return framework_role("angular_service");
+                }
+            }
+            (active.contains(&"react") && is_react_component(symbol, ext))
+                .then_some("react_component")
+        }
+        "java" | "kt" | "kts" if active.contains(&"spring") => match symbol.kind {
+            SymbolKind::Class if has_annotation(symbol, SPRING_BEAN_ANNOTATIONS) => {
+                Some("spring_bean")
+            }
+            SymbolKind::Method | SymbolKind::Function => {
+                if has_annotation(symbol, SPRING_ENDPOINT_ANNOTATIONS) {
+                    Some("spring_endpoint")
+                } else if has_annotation(symbol, &["bean"]) {
+                    Some("spring_bean")
+                } else {
+                    None
+                }
+            }
+            _ => None,
+        },
+        "rb" if active.contains(&"rails") => {
+            let in_controller = symbol.file_path.contains("app/controllers/")
+                && parent.is_some_and(is_controller_class);
+            (symbol.kind == SymbolKind::Method
+                && in_controller
+                && !matches!(
+                    symbol.visibility,
+                    Some(Visibility::Private | Visibility::Protected)
+                ))
+            .then_some("rails_controller_action")
+        }
+        "cs" if active.contains(&"aspnet") => {
+            let in_controller = parent.is_some_and(|parent| {
+                is_controller_class(parent)
+                    || has_annotation(parent, &["apicontroller", "controller"])
+            });
+            (symbol.kind == SymbolKind::Method
+                && in_controller
+                && matches!(symbol.visibility, Some(Visibility::Public))
+                && !has_annotation(symbol, &["nonaction"]))
+            .then_some("aspnet_controller_action")
+        }
+        "dart" if active.contains(&"flutter") => {
+            (symbol.kind == SymbolKind::Class && extends_widget(symbol)).then_some("flutter_widget")
+        }
+        _ => None,
+    };
+    role.and_then(framework_role)
+}
+
+/// A top-level PascalCase function, class or function-valued binding: in
+/// JSX/TSX files any of them, elsewhere only classes extending a React
+/// component base.
+fn is_react_component(symbol: &Symbol, ext: &str) -> bool {
+    if symbol.parent_id.is_some() || !is_pascal_case(&symbol.name) {
+        return false;
+    }
+    let signature = symbol.signature.as_deref().unwrap_or("");
+    let extends_component = signature.contains("Component") && signature.contains("extends");
+    match symbol.kind {
+        SymbolKind::Class => extends_component || matches!(ext, "jsx" | "tsx"),
+        SymbolKind::Function => matches!(ext, "jsx" | "tsx"),
+        SymbolKind::Variable | SymbolKind::Constant => {
+            matches!(ext, "jsx" | "tsx")
+                && ["=>", "function", "memo(", "forwardRef("]
+                    .iter()
+                    .any(|marker| signature.contains(marker))
+        }
+        _ => false,
+    }
+}
+
+fn is_controller_class(symbol: &Symbol) -> bool {
+    symbol.kind == SymbolKind::Class && symbol.name.ends_with("Controller")
+}
+
+/// `class X extends StatelessWidget` and other `...Widget` bases.
+fn extends_widget(symbol: &Symbol) -> bool {
+    let signature = symbol.signature.as_deref().unwrap_or("");
+    signature
+        .split_once("extends")
+        .and_then(|(_, base)| base.split_whitespace().next())
+        .is_some_and(|base| base.trim_end_matches('{').ends_with("Widget"))
+}
+
+/// Whether any annotation's key (last dotted segment) is one of `keys`.
+fn has_annotation(symbol: &Symbol, keys: &[&str]) -> bool {
+    symbol.annotations.iter().any(|marker| {
+        let key = marker.annotation_key.rsplit('.').next().unwrap_or("");
+        keys.contains(&key)
+    })
+}
+
+fn is_pascal_case(name: &str) -> bool {
+    name.starts_with(|c: char| c.is_ascii_uppercase()) && name.chars().any(|c| c.is_lowercase())
+}
+
+fn extension(path: &str) -> &str {
+    let name = file_name(path);
+    name.rsplit_once('.').map_or("", |(_, ext)| ext)
+}
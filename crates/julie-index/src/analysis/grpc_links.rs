@@ -0,0 +1,388 @@
+//! gRPC stub linking: connects `.proto` definitions (extracted by
+//! `julie_pipeline::indexing_core::protobuf`) to the client/server code
+//! generated from them in Go, Java, C# and TypeScript/JavaScript, so a call
+//! through `GreeterClient.SayHello` can be traced to `rpc SayHello` and on to
+//! every server implementing it.
+//!
+//! Matching follows the naming schemes of the standard generators:
+//!
+//! - services → stub containers (`GreeterClient`, `UnimplementedGreeterServer`,
+//!   `GreeterGrpc`, `GreeterImplBase`, `GreeterBase`, `IGreeterClient`, ...)
+//!   and Go's `NewGreeterClient` / `RegisterGreeterServer` functions;
+//! - rpcs → methods of a matched container with the same name, ignoring case
+//!   and an `Async` suffix (`SayHello`, `sayHello`, `SayHelloAsync`);
+//! - messages and enums → same-named types in generated files (Go flattens
+//!   nesting to `Outer_Inner`).
+//!
+//! Links are returned to [`compute_cross_language_links`](super::compute_cross_language_links),
+//! which stores them with the other counterpart links.
+
+use anyhow::Result;
+use std::collections::HashMap;
+
+use julie_core::database::{CrossLanguageLink, SymbolDatabase};
+
+/// Language id of `.proto` symbols.
+const PROTOBUF_LANGUAGE: &str = "protobuf";
+
+/// Languages with a gRPC code generator whose output we recognize.
+const STUB_LANGUAGES: &str = "'go', 'java', 'csharp', 'typescript', 'javascript'";
+
+/// Kinds a generated stub container or message type can have.
+const STUB_TYPE_KINDS: &str = "'class', 'struct', 'interface', 'type', 'enum'";
+
+/// File name endings of generator output (`protoc-gen-go`, grpc-java,
+/// Grpc.Tools, grpc-tools / protoc-gen-ts).
+const GENERATED_FILE_SUFFIXES: &[&str] = &[
+    ".pb.go",
+    "grpc.java",
+    "proto.java",
+    ".pb.cs",
+    "grpc.cs",
+    "_pb.ts",
+    "_pb.d.ts",
+    "_pb.js",
+];
+
+/// Confidence for a name match inside generator output.
+const GENERATED_CONFIDENCE: f32 = 0.95;
+/// Confidence for a stub-shaped name (`GreeterClient`) in hand-written code.
+const STUB_NAME_CONFIDENCE: f32 = 0.8;
+
+/// More matches than this for one definition means the name is too generic
+/// to link (e.g. a `Status` enum in every package).
+const MAX_MATCHES_PER_DEFINITION: usize = 8;
+
+#[derive(Debug)]
+struct ProtoSymbol {
+    id: String,
+    name: String,
+    kind: String,
+    parent_name: Option<String>,
+    parent_kind: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+struct StubSymbol {
+    id: String,
+    name: String,
+    kind: String,
+    parent_name: Option<String>,
+    generated: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum StubRole {
+    Client,
+    Server,
+    Service,
+}
+
+impl StubRole {
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Client => "client",
+            Self::Server => "server",
+            Self::Service => "service",
+        }
+    }
+}
+
+/// Compute the links between `.proto` definitions and generated stubs. Each
+/// linked pair is returned in both directions; the second value is the
+/// number of pairs.
+pub fn compute_grpc_links(db: &SymbolDatabase) -> Result<(Vec<CrossLanguageLink>, usize)> {
+    let protos = load_proto_symbols(db)?;
+    if protos.is_empty() {
+        return Ok((Vec::new(), 0));
+    }
+    let stubs = load_stub_types(db)?;
+    let mut by_name: HashMap<&str, Vec<&StubSymbol>> = HashMap::new();
+    for stub in &stubs {
+        by_name.entry(stub.name.as_str()).or_default().push(stub);
+    }
+
+    let mut links = LinkSet::default();
+
+    // Services first: rpcs are matched against the containers found here.
+    let mut containers: HashMap<&str, Vec<(StubSymbol, StubRole, f32)>> = HashMap::new();
+    for service in protos.iter().filter(|proto| proto.kind == "interface") {
+        let mut matched = Vec::new();
+        for (stub_name, role) in service_stub_names(&service.name) {
+            for stub in by_name.get(stub_name.as_str()).into_iter().flatten() {
+                // A bare `Greeter` is only a stub inside generator output.
+                if stub_name == service.name && !stub.generated {
+                    continue;
+                }
+                let confidence = if stub.generated {
+                    GENERATED_CONFIDENCE
+                } else {
+                    STUB_NAME_CONFIDENCE
+                };
+                matched.push(((*stub).clone(), role, confidence));
+            }
+        }
+        let matched = cap_matches(matched, |(stub, _, _)| stub.generated);
+        for (stub, role, confidence) in &matched {
+            links.add(
+                &service.id,
+                &stub.id,
+                *confidence,
+                format!("gRPC {} stub of service {}", role.as_str(), service.name),
+            );
+        }
+        containers
+            .entry(service.name.as_str())
+            .or_default()
+            .extend(matched);
+    }
+
+    for rpc in protos.iter().filter(|proto| proto.kind == "method") {
+        let Some(service) = rpc.parent_name.as_deref() else {
+            continue;
+        };
+        let mut matched = Vec::new();
+        for (container, role, confidence) in containers.get(service).into_iter().flatten() {
+            if container.kind == "function" {
+                continue;
+            }
+            for method in load_members(db, &container.id)? {
+                if rpc_method_matches(&rpc.name, &method.name) {
+                    matched.push((method, *role, *confidence));
+                }
+            }
+        }
+        for (method, role, confidence) in cap_matches(matched, |(method, _, _)| method.generated) {
+            links.add(
+                &rpc.id,
+                &method.id,
+                confidence,
+                format!(
+                    "gRPC {} method for rpc {service}.{}",
+                    role.as_str(),
+                    rpc.name
+                ),
+            );
+        }
+    }
+
+    for definition in protos
+        .iter()
+        .filter(|proto| proto.kind == "struct" || proto.kind == "enum")
+    {
+        let nested_parent = definition
+            .parent_name
+            .as_deref()
+            .filter(|_| definition.parent_kind.as_deref() == Some("struct"));
+        let mut matched: Vec<&StubSymbol> = Vec::new();
+        if let Some(parent) = nested_parent {
+            let flattened = format!("{parent}_{}", definition.name);
+            matched.extend(by_name.get(flattened.as_str()).into_iter().flatten());
+        }
+        matched.extend(
+            by_name
+                .get(definition.name.as_str())
+                .into_iter()
+                .flatten()
+                .filter(|stub| {
+                    nested_parent.is_none() || stub.parent_name.as_deref() == nested_parent
+                }),
+        );
+        let matched: Vec<&StubSymbol> = matched
+            .into_iter()
+            .filter(|stub| stub.generated && stub.kind != "function")
+            .collect();
+        let what = if definition.kind == "enum" {
+            "enum"
+        } else {
+            "message"
+        };
+        for stub in cap_matches(matched, |_| true) {
+            links.add(
+                &definition.id,
+                &stub.id,
+                GENERATED_CONFIDENCE,
+                format!("generated from proto {what} {}", definition.name),
+            );
+        }
+    }
+
+    let pairs = links.pairs;
+    Ok((links.links, pairs))
+}
+
+#[derive(Default)]
+struct LinkSet {
+    links: Vec<CrossLanguageLink>,
+    pairs: usize,
+}
+
+impl LinkSet {
+    fn add(&mut self, proto_id: &str, stub_id: &str, confidence: f32, reason: String) {
+        self.pairs += 1;
+        self.links.push(CrossLanguageLink {
+            from_symbol_id: proto_id.to_string(),
+            to_symbol_id: stub_id.to_string(),
+            confidence,
+            reason: reason.clone(),
+        });
+        self.links.push(CrossLanguageLink {
+            from_symbol_id: stub_id.to_string(),
+            to_symbol_id: proto_id.to_string(),
+            confidence,
+            reason,
+        });
+    }
+}
+
+/// Keep at most [`MAX_MATCHES_PER_DEFINITION`] matches, preferring those
+/// `preferred` accepts; drop everything when even those are too many.
+fn cap_matches<T>(matches: Vec<T>, preferred: impl Fn(&T) -> bool) -> Vec<T> {
+    if matches.len() <= MAX_MATCHES_PER_DEFINITION {
+        return matches;
+    }
+    let preferred: Vec<T> = matches.into_iter().filter(preferred).collect();
+    if preferred.len() <= MAX_MATCHES_PER_DEFINITION {
+        preferred
+    } else {
+        Vec::new()
+    }
+}
+
+/// Generated names for service `name`, with the side of the call they serve.
+fn service_stub_names(name: &str) -> Vec<(String, StubRole)> {
+    vec![
+        (format!("{name}Client"), StubRole::Client),
+        (format!("I{name}Client"), StubRole::Client),
+        (format!("New{name}Client"), StubRole::Client),
+        (format!("{name}Stub"), StubRole::Client),
+        (format!("{name}BlockingStub"), StubRole::Client),
+        (format!("{name}FutureStub"), StubRole::Client),
+        (format!("{name}Server"), StubRole::Server),
+        (format!("I{name}Server"), StubRole::Server),
+        (format!("Unimplemented{name}Server"), StubRole::Server),
+        (format!("Unsafe{name}Server"), StubRole::Server),
+        (format!("Register{name}Server"), StubRole::Server),
+        (format!("{name}ImplBase"), StubRole::Server),
+        (format!("{name}Base"), StubRole::Server),
+        (format!("{name}Grpc"), StubRole::Service),
+        (format!("{name}Service"), StubRole::Service),
+        (format!("{name}Definition"), StubRole::Service),
+        (name.to_string(), StubRole::Service),
+    ]
+}
+
+/// `SayHello` matches `SayHello`, `sayHello` and `SayHelloAsync`.
+fn rpc_method_matches(rpc: &str, method: &str) -> bool {
+    let method = method.strip_suffix("Async").unwrap_or(method);
+    rpc.eq_ignore_ascii_case(method)
+}
+
+/// How much generator output backs the indexed `.proto` services. Stub links
+/// need the generated files in the source tree, which a bare clone often
+/// lacks until code generation runs.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct GrpcStubCoverage {
+    pub proto_services: usize,
+    pub generated_stub_files: usize,
+}
+
+pub fn grpc_stub_coverage(db: &SymbolDatabase) -> Result<GrpcStubCoverage> {
+    let proto_services: i64 = db.conn.query_row(
+        "SELECT COUNT(*) FROM symbols WHERE language = ?1 AND kind = 'interface'",
+        [PROTOBUF_LANGUAGE],
+        |row| row.get(0),
+    )?;
+    let mut stmt = db.conn.prepare(&format!(
+        "SELECT path FROM files WHERE language IN ({STUB_LANGUAGES})"
+    ))?;
+    let mut generated_stub_files = 0;
+    for path in stmt.query_map([], |row| row.get::<_, String>(0))? {
+        if is_generated_grpc_file(&path?) {
+            generated_stub_files += 1;
+        }
+    }
+    Ok(GrpcStubCoverage {
+        proto_services: proto_services as usize,
+        generated_stub_files,
+    })
+}
+
+/// Whether `file_path` looks like protoc / gRPC generator output.
+pub fn is_generated_grpc_file(file_path: &str) -> bool {
+    let lower = file_path.to_ascii_lowercase();
+    GENERATED_FILE_SUFFIXES
+        .iter()
+        .any(|suffix| lower.ends_with(suffix))
+}
+
+fn load_proto_symbols(db: &SymbolDatabase) -> Result<Vec<ProtoSymbol>> {
+    let mut stmt = db.conn.prepare(
+        "SELECT s.id, s.name, s.kind, p.name, p.kind
+         FROM symbols s
+         LEFT JOIN symbols p ON p.id = s.parent_id
+         WHERE s.language = ?1 AND s.kind IN ('interface', 'method', 'struct', 'enum')
+         ORDER BY s.file_path, s.start_line",
+    )?;
+    let symbols = stmt
+        .query_map([PROTOBUF_LANGUAGE], |row| {
+            Ok(ProtoSymbol {
+                id: row.get(0)?,
+                name: row.get(1)?,
+                kind: row.get(2)?,
+                parent_name: row.get(3)?,
+                parent_kind: row.get(4)?,
+            })
+        })?
+        .collect::<rusqlite::Result<Vec<_>>>()?;
+    Ok(symbols)
+}
+
+/// Type-like symbols plus Go's `New*Client` / `Register*Server` functions.
+fn load_stub_types(db: &SymbolDatabase) -> Result<Vec<StubSymbol>> {
+    let mut stmt = db.conn.prepare(&format!(
+        "SELECT s.id, s.name, s.kind, s.file_path, p.name
+         FROM symbols s
+         LEFT JOIN symbols p ON p.id = s.parent_id
+         WHERE s.language IN ({STUB_LANGUAGES})
+           AND (s.kind IN ({STUB_TYPE_KINDS})
+                OR (s.kind = 'function' AND s.language = 'go'
+                    AND (s.name LIKE 'New%Client' OR s.name LIKE 'Register%Server')))
+         ORDER BY s.file_path, s.start_line"
+    ))?;
+    let stubs = stmt
+        .query_map([], |row| {
+            let file_path: String = row.get(3)?;
+            Ok(StubSymbol {
+                id: row.get(0)?,
+                name: row.get(1)?,
+                kind: row.get(2)?,
+                parent_name: row.get(4)?,
+                generated: is_generated_grpc_file(&file_path),
+            })
+        })?
+        .collect::<rusqlite::Result<Vec<_>>>()?;
+    Ok(stubs)
+}
+
+/// Methods declared inside the stub container `parent_id`.
+fn load_members(db: &SymbolDatabase, parent_id: &str) -> Result<Vec<StubSymbol>> {
+    let mut stmt = db.conn.prepare_cached(
+        "SELECT id, name, kind, file_path
+         FROM symbols
+         WHERE parent_id = ?1 AND kind IN ('method', 'function')",
+    )?;
+    let members = stmt
+        .query_map([parent_id], |row| {
+            let file_path: String = row.get(3)?;
+            Ok(StubSymbol {
+                id: row.get(0)?,
+                name: row.get(1)?,
+                kind: row.get(2)?,
+                parent_name: None,
+                generated: is_generated_grpc_file(&file_path),
+            })
+        })?
+        .collect::<rusqlite::Result<Vec<_>>>()?;
+    Ok(members)
+}
@@ -1,23 +1,71 @@
-//! Post-indexing analysis: test quality metrics and static test linkage.
+//! Post-indexing analysis: test quality metrics, static test linkage,
+//! cross-language counterpart links (including proto ↔ gRPC stub and
+//! OpenAPI operation ↔ handler/client links),
+//! external-library attribution of unresolved calls, module importers,
+//! build-time symbol aliases (macro and codegen renames), Kotlin Multiplatform
+//! `expect`/`actual` links, the Swift module map, the monorepo project
+//! map, the build target map, framework detection and the topic map.
 //!
 //! Runs after symbols are indexed and reference scores computed.
 //! These analyses enrich symbol metadata with derived quality signals
 //! that tools can surface to users.
 
+pub mod api_surface;
+pub mod build_targets;
 pub mod change_risk;
+pub mod cross_language_links;
+pub mod dependency_matrix;
 pub mod early_warnings;
+pub mod expect_actual;
+pub mod external_modules;
+pub mod file_annotations;
+pub mod frameworks;
+pub mod grpc_links;
 pub mod literals;
+pub mod module_imports;
+pub mod naming_drift;
+pub mod openapi_links;
+pub mod quality_gate;
+pub mod swift_modules;
+pub mod symbol_aliases;
 pub mod test_linkage;
 pub mod test_quality;
 pub mod test_roles;
+pub mod topics;
+pub mod workspace_projects;
 
+pub use api_surface::{ApiModule, ApiSurfaceReport, ApiSymbol, generate_api_surface};
+pub use build_targets::compute_build_target_files;
+pub use cross_language_links::compute_cross_language_links;
+pub use dependency_matrix::{
+    DependencyMatrixOptions, DependencyMatrixReport, UpwardDependency, generate_dependency_matrix,
+};
 pub use early_warnings::{
     AuthCoverageCandidate, EarlyWarningReport, EarlyWarningReportOptions, EntryPointLinkageGap,
     EntryPointSignal, HighCentralityLinkageGap, ReportSummary, ReviewMarkerSignal, SchedulerSignal,
     generate_early_warning_report,
 };
+pub use expect_actual::compute_expect_actual_links;
+pub use external_modules::compute_external_call_modules;
+pub use file_annotations::{FileAnnotationReport, SymbolAnnotation, generate_file_annotations};
+pub use frameworks::compute_frameworks;
+pub use module_imports::{ImportSite, ImportStyle, ImportTarget, find_importers};
+pub use naming_drift::{
+    DriftSymbol, NamingDriftCluster, NamingDriftOptions, NamingDriftReport, NamingVariant,
+    generate_naming_drift_report,
+};
+pub use quality_gate::{
+    QualityCheck, QualityCheckResult, QualityGateReport, QualitySnapshot, capture_quality_snapshot,
+    evaluate_quality_gate,
+};
+pub use swift_modules::compute_swift_modules;
+pub use symbol_aliases::compute_symbol_aliases;
 pub use test_linkage::compute_test_linkage;
 pub use test_quality::compute_test_quality_metrics;
 pub use test_roles::{
     TestRoleConfig, classify_symbols_by_role, classify_test_role, is_scorable_test, is_test_related,
 };
+pub use topics::{
+    Topic, TopicMapOptions, TopicMapReport, TopicSymbol, build_topic_map, generate_topic_map,
+};
+pub use workspace_projects::compute_workspace_projects;
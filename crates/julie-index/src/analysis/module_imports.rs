@@ -0,0 +1,381 @@
+//! Who imports a module: every import statement in the workspace matched
+//! against one source file, or against a library name.
+//!
+//! Statements are parsed with the same per-language parsers as external
+//! module attribution ([`parse_imports`]), then resolved the way each
+//! language finds modules:
+//!
+//! - JS/TS relative specifiers (`./util/format`) and Python relative imports
+//!   (`from ..core import db`) resolve against the importing file's
+//!   directory and must name the file exactly; an `index`, `__init__` or
+//!   `mod.rs` file is also imported through its directory.
+//! - Python absolute imports, Rust paths and JVM class imports match when
+//!   their path is a suffix of the file's path (`app.core.db` names
+//!   `src/app/core/db.py`). A Rust path outside `crate`/`self`/`super` must
+//!   name a crate directory the file lives under.
+//! - Go imports a package directory, so every file in it is imported; the
+//!   import path ends with the directory.
+//! - A JVM wildcard import (`import com.acme.*`) brings in every class of
+//!   the package, so it matches the files directly inside its directory.
+//!
+//! JS bare specifiers (`lodash`, `@acme/ui`) and C# `using` directives name
+//! packages and namespaces rather than files; they are found by library name.
+
+use std::collections::HashSet;
+
+use anyhow::Result;
+use serde::Serialize;
+
+use julie_core::database::SymbolDatabase;
+
+use crate::analysis::external_modules::{ImportedModule, parse_imports};
+
+/// Longest statement text kept per import site.
+const MAX_STATEMENT_CHARS: usize = 160;
+
+/// What to find importers of.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImportTarget<'a> {
+    /// A workspace-relative source file.
+    File(&'a str),
+    /// A library or module name as written in imports (`lodash`,
+    /// `requests`, `github.com/gin-gonic/gin`, `serde`).
+    Library(&'a str),
+}
+
+/// How an import brings names in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ImportStyle {
+    /// Plain names: `import { a } from`, `use a::B`, `import numpy`.
+    Named,
+    /// At least one name is renamed: `import numpy as np`, `{ a as b }`.
+    Aliased,
+    /// The module as one object: `import * as fs from 'fs'`.
+    Namespace,
+    /// Every public name: `from x import *`, `use a::*`, `export * from`.
+    Wildcard,
+    /// Nothing bound: `import './polyfills'`, Go `import _ "pkg"`.
+    SideEffect,
+}
+
+impl ImportStyle {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Named => "named",
+            Self::Aliased => "aliased",
+            Self::Namespace => "namespace",
+            Self::Wildcard => "wildcard",
+            Self::SideEffect => "side_effect",
+        }
+    }
+}
+
+/// One import statement that brings in the target.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ImportSite {
+    pub file_path: String,
+    pub language: String,
+    pub line: u32,
+    /// Module path as written (`./util/format`, `app.core.db`).
+    pub module: String,
+    /// Local names the import binds.
+    pub bindings: Vec<String>,
+    pub style: ImportStyle,
+    /// The statement on one line, shortened when long.
+    pub statement: String,
+}
+
+/// Every import of `target`, by file and line. A file importing the same
+/// module through several import symbols is listed once per statement.
+pub fn find_importers(db: &SymbolDatabase, target: ImportTarget<'_>) -> Result<Vec<ImportSite>> {
+    let mut sites = Vec::new();
+    let mut seen = HashSet::new();
+    for import in db.get_import_statements()? {
+        if target == ImportTarget::File(import.file_path.as_str()) {
+            continue;
+        }
+        for module in parse_imports(&import.language, &import.statement) {
+            let style = import_style(&import.language, &import.statement, &module);
+            let matched = match target {
+                ImportTarget::File(path) => imports_file(
+                    &import.file_path,
+                    &import.language,
+                    &import.statement,
+                    &module,
+                    style,
+                    path,
+                ),
+                ImportTarget::Library(name) => imports_library(&import.language, &module, name),
+            };
+            if !matched
+                || !seen.insert((
+                    import.file_path.clone(),
+                    import.start_line,
+                    module.module.clone(),
+                ))
+            {
+                continue;
+            }
+            sites.push(ImportSite {
+                file_path: import.file_path.clone(),
+                language: import.language.clone(),
+                line: import.start_line,
+                module: module.module,
+                bindings: module.bindings,
+                style,
+                statement: one_line(&import.statement),
+            });
+        }
+    }
+    Ok(sites)
+}
+
+/// Classify how `module` (parsed from `statement`) binds names.
+pub fn import_style(language: &str, statement: &str, module: &ImportedModule) -> ImportStyle {
+    if statement.contains("* as ") {
+        return ImportStyle::Namespace;
+    }
+    if module.bindings.is_empty() {
+        return if statement.contains('*') || statement.contains(". \"") {
+            ImportStyle::Wildcard
+        } else {
+            ImportStyle::SideEffect
+        };
+    }
+    let renamed = if language == "go" {
+        // `import h "net/http"` binds `h` instead of the package name
+        // (which may sit before a `/v2` or carry a `.v3` suffix).
+        module.bindings.iter().any(|binding| {
+            !module.module.split('/').any(|segment| {
+                segment == binding
+                    || segment
+                        .strip_prefix(binding.as_str())
+                        .is_some_and(|rest| rest.starts_with(".v"))
+            })
+        })
+    } else {
+        statement.contains(" as ")
+    };
+    if renamed {
+        ImportStyle::Aliased
+    } else {
+        ImportStyle::Named
+    }
+}
+
+/// Whether a non-relative import names library `name` or one of its modules.
+pub fn imports_library(language: &str, module: &ImportedModule, name: &str) -> bool {
+    let name = name.trim();
+    if module.relative || name.is_empty() {
+        return false;
+    }
+    let separator = match language {
+        "python" | "java" | "kotlin" | "csharp" => ".",
+        "rust" => "::",
+        _ => "/",
+    };
+    module.library == name
+        || module.module == name
+        || module
+            .module
+            .strip_prefix(name)
+            .is_some_and(|rest| rest.starts_with(separator))
+}
+
+/// Whether `module`, imported by `importer`, resolves to `target` (both
+/// workspace-relative paths).
+pub fn imports_file(
+    importer: &str,
+    language: &str,
+    statement: &str,
+    module: &ImportedModule,
+    style: ImportStyle,
+    target: &str,
+) -> bool {
+    let target = fold(target);
+    let stem = target
+        .rsplit_once('.')
+        .filter(|(stem, extension)| {
+            !stem.is_empty() && !stem.ends_with('/') && !extension.contains('/')
+        })
+        .map_or(target.as_str(), |(stem, _)| stem);
+    let file_stem = stem.rsplit('/').next().unwrap_or(stem);
+    let directory = parent(stem);
+    let through_directory = matches!(file_stem, "index" | "__init__" | "mod")
+        || language == "go"
+        || (style == ImportStyle::Wildcard && matches!(language, "java" | "kotlin"));
+    let mut keys = vec![stem];
+    if through_directory && !directory.is_empty() {
+        keys.push(directory);
+    }
+
+    match language {
+        "javascript" | "typescript" | "tsx" | "jsx" | "vue" => {
+            let specifier = module.module.as_str();
+            if let Some(aliased) = specifier.strip_prefix("@/") {
+                return keys
+                    .iter()
+                    .any(|key| path_ends_with(key, &fold(strip_script_extension(aliased))));
+            }
+            if !specifier.starts_with('.') {
+                return false;
+            }
+            let resolved = join(
+                parent(&fold(importer)),
+                &fold(strip_script_extension(specifier)),
+            );
+            keys.iter().any(|key| *key == resolved)
+        }
+        "python" => {
+            let dots = module.module.len() - module.module.trim_start_matches('.').len();
+            let path = fold(&module.module[dots..].replace('.', "/"));
+            let mut candidates = vec![path.clone()];
+            candidates.extend(
+                module
+                    .bindings
+                    .iter()
+                    .map(|binding| join(&path, binding).trim_start_matches('/').to_string()),
+            );
+            if dots == 0 {
+                return candidates.iter().any(|candidate| {
+                    !candidate.is_empty() && keys.iter().any(|key| path_ends_with(key, candidate))
+                });
+            }
+            let mut base = fold(importer);
+            for _ in 0..dots {
+                base = parent(&base).to_string();
+            }
+            candidates
+                .iter()
+                .any(|candidate| keys.contains(&join(&base, candidate).as_str()))
+        }
+        "rust" => {
+            let segments: Vec<&str> = module
+                .module
+                .split("::")
+                .map(str::trim)
+                .filter(|segment| !segment.is_empty() && *segment != "*")
+                .collect();
+            let Some((root, rest)) = segments.split_first() else {
+                return false;
+            };
+            let local = matches!(*root, "crate" | "self" | "super");
+            let rest: Vec<&str> = rest
+                .iter()
+                .copied()
+                .skip_while(|segment| matches!(*segment, "self" | "super"))
+                .collect();
+            if local {
+                if crate_root(&fold(importer)) != crate_root(&target) {
+                    return false;
+                }
+            } else if !target.split('/').any(|segment| segment == fold(root)) {
+                return false;
+            }
+            // A braced `use a::b::{..}` names the module itself; a leaf path
+            // names a module or an item of its parent.
+            let mut candidates = vec![fold(&rest.join("/"))];
+            if !statement.contains('{') {
+                candidates.push(fold(&rest[..rest.len().saturating_sub(1)].join("/")));
+            }
+            candidates.iter().any(|candidate| {
+                if candidate.is_empty() {
+                    matches!(file_stem, "lib" | "main")
+                } else {
+                    keys.iter().any(|key| path_ends_with(key, candidate))
+                }
+            })
+        }
+        "go" => {
+            let import_path = fold(&module.module);
+            keys.iter().skip(1).any(|key| {
+                import_path == *key || (key.contains('/') && path_ends_with(&import_path, key))
+            })
+        }
+        "java" | "kotlin" => {
+            let class_path = jvm_class_path(statement);
+            keys.iter().any(|key| {
+                (!class_path.is_empty() && path_ends_with(key, &class_path))
+                    || (style == ImportStyle::Wildcard
+                        && path_ends_with(key, &fold(&module.module.replace('.', "/"))))
+            })
+        }
+        _ => false,
+    }
+}
+
+/// `com/acme/Widget` for `import com.acme.Widget.Inner;` or
+/// `import static com.acme.Widget.build;`: the path up to the first type name.
+fn jvm_class_path(statement: &str) -> String {
+    let rest = statement
+        .trim()
+        .trim_start_matches("import ")
+        .trim_start_matches("static ")
+        .trim_end_matches(';');
+    let path = rest.split(" as ").next().unwrap_or(rest).trim();
+    let segments: Vec<&str> = path.split('.').collect();
+    match segments
+        .iter()
+        .position(|segment| segment.starts_with(|ch: char| ch.is_ascii_uppercase()))
+    {
+        Some(class) => segments[..=class].join("/"),
+        None => String::new(),
+    }
+}
+
+/// Path up to and including the first `src` directory, or `""`: where a
+/// Rust crate's `crate::` paths start.
+fn crate_root(path: &str) -> &str {
+    if path.starts_with("src/") {
+        return "src/";
+    }
+    path.find("/src/").map_or("", |index| &path[..index + 5])
+}
+
+/// Whether `path` is `suffix` or ends with `/suffix`.
+fn path_ends_with(path: &str, suffix: &str) -> bool {
+    path == suffix
+        || path
+            .strip_suffix(suffix)
+            .is_some_and(|head| head.ends_with('/'))
+}
+
+fn parent(path: &str) -> &str {
+    path.rsplit_once('/').map_or("", |(parent, _)| parent)
+}
+
+/// `base` joined with the relative `path`, resolving `.` and `..`.
+fn join(base: &str, path: &str) -> String {
+    let mut segments: Vec<&str> = base.split('/').filter(|s| !s.is_empty()).collect();
+    for segment in path.split('/') {
+        match segment {
+            "" | "." => {}
+            ".." => {
+                segments.pop();
+            }
+            segment => segments.push(segment),
+        }
+    }
+    segments.join("/")
+}
+
+/// Backslashes to slashes and `-` to `_`, as module names spell paths.
+fn fold(path: &str) -> String {
+    path.replace('\\', "/").replace('-', "_")
+}
+
+fn strip_script_extension(specifier: &str) -> &str {
+    [".js", ".jsx", ".mjs", ".cjs", ".ts", ".tsx", ".vue"]
+        .iter()
+        .find_map(|extension| specifier.strip_suffix(extension))
+        .unwrap_or(specifier)
+}
+
+fn one_line(statement: &str) -> String {
+    let line = statement.split_whitespace().collect::<Vec<_>>().join(" ");
+    match line.char_indices().nth(MAX_STATEMENT_CHARS) {
+        Some((cut, _)) => format!("{}…", &line[..cut]),
+        None => line,
+    }
+}
@@ -0,0 +1,182 @@
+//! Naming drift report: functions and methods that do the same thing but are
+//! named inconsistently (`getUser` / `fetchUser` / `retrieveUser`).
+//!
+//! Two signals are combined. Embedding similarity (see
+//! [`cluster_by_embedding`]) says "these symbols do the same thing"; name
+//! tokenization says "but their leading verbs differ while the rest of the name
+//! agrees". Only groups where both hold are reported, with the verb the group
+//! (and then the workspace) already prefers suggested as the standard.
+
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+
+use julie_core::cross_language_intelligence::to_snake_case;
+use julie_core::database::{NamingDriftCandidate, SymbolDatabase};
+
+use crate::search::clustering::{ClusterOptions, cluster_by_embedding};
+
+/// Options for [`generate_naming_drift_report`].
+#[derive(Debug, Clone, Default)]
+pub struct NamingDriftOptions {
+    /// Workspace-relative file or directory to scope the report to.
+    pub focus_path: Option<String>,
+    /// Embedding clustering parameters.
+    pub clustering: ClusterOptions,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct NamingDriftReport {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub focus_path: Option<String>,
+    /// Embedded functions/methods considered.
+    pub candidates: usize,
+    /// Drift groups, largest first.
+    pub clusters: Vec<NamingDriftCluster>,
+}
+
+/// Symbols that share a subject (`user`) and an embedding cluster but use
+/// different verbs for it.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct NamingDriftCluster {
+    /// Name words after the verb, snake_case (`user`, `order_total`).
+    pub subject: String,
+    pub suggested_verb: String,
+    /// Verbs in use, most frequent first.
+    pub variants: Vec<NamingVariant>,
+    pub symbols: Vec<DriftSymbol>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct NamingVariant {
+    pub verb: String,
+    pub count: usize,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct DriftSymbol {
+    pub name: String,
+    pub kind: String,
+    pub language: String,
+    pub file_path: String,
+    pub line: u32,
+}
+
+/// Build the naming drift report. Symbols without embeddings are not
+/// considered, so the report is empty until the embedding pipeline has run.
+pub fn generate_naming_drift_report(
+    db: &SymbolDatabase,
+    options: &NamingDriftOptions,
+) -> Result<NamingDriftReport> {
+    let candidates = db.get_naming_drift_candidates(options.focus_path.as_deref())?;
+
+    let mut verb_usage: HashMap<String, usize> = HashMap::new();
+    for candidate in &candidates {
+        if let Some((verb, _)) = split_verb_subject(&candidate.name) {
+            *verb_usage.entry(verb).or_default() += 1;
+        }
+    }
+
+    let by_id: HashMap<&str, &NamingDriftCandidate> = candidates
+        .iter()
+        .map(|candidate| (candidate.id.as_str(), candidate))
+        .collect();
+    let ids: Vec<String> = candidates
+        .iter()
+        .map(|candidate| candidate.id.clone())
+        .collect();
+
+    let mut clusters = Vec::new();
+    for cluster in cluster_by_embedding(db, &ids, options.clustering)? {
+        let mut by_subject: BTreeMap<String, Vec<(String, &NamingDriftCandidate)>> =
+            BTreeMap::new();
+        for id in &cluster {
+            let Some(&candidate) = by_id.get(id.as_str()) else {
+                continue;
+            };
+            if let Some((verb, subject)) = split_verb_subject(&candidate.name) {
+                by_subject
+                    .entry(subject)
+                    .or_default()
+                    .push((verb, candidate));
+            }
+        }
+        for (subject, members) in by_subject {
+            if let Some(drift) = drift_cluster(subject, &members, &verb_usage) {
+                clusters.push(drift);
+            }
+        }
+    }
+
+    clusters.sort_by(|a, b| {
+        b.symbols
+            .len()
+            .cmp(&a.symbols.len())
+            .then_with(|| a.subject.cmp(&b.subject))
+    });
+
+    Ok(NamingDriftReport {
+        focus_path: options.focus_path.clone(),
+        candidates: candidates.len(),
+        clusters,
+    })
+}
+
+/// A drift group for one subject, or `None` when every member already uses
+/// the same verb.
+fn drift_cluster(
+    subject: String,
+    members: &[(String, &NamingDriftCandidate)],
+    verb_usage: &HashMap<String, usize>,
+) -> Option<NamingDriftCluster> {
+    let mut counts: BTreeMap<&str, usize> = BTreeMap::new();
+    for (verb, _) in members {
+        *counts.entry(verb.as_str()).or_default() += 1;
+    }
+    if counts.len() < 2 {
+        return None;
+    }
+
+    let usage = |verb: &str| verb_usage.get(verb).copied().unwrap_or(0);
+    let mut variants: Vec<NamingVariant> = counts
+        .into_iter()
+        .map(|(verb, count)| NamingVariant {
+            verb: verb.to_string(),
+            count,
+        })
+        .collect();
+    // Most used in the group, then across the workspace, then alphabetical.
+    variants.sort_by(|a, b| {
+        b.count
+            .cmp(&a.count)
+            .then_with(|| usage(b.verb.as_str()).cmp(&usage(a.verb.as_str())))
+            .then_with(|| a.verb.cmp(&b.verb))
+    });
+
+    Some(NamingDriftCluster {
+        subject,
+        suggested_verb: variants[0].verb.clone(),
+        variants,
+        symbols: members
+            .iter()
+            .map(|(_, candidate)| DriftSymbol {
+                name: candidate.name.clone(),
+                kind: candidate.kind.clone(),
+                language: candidate.language.clone(),
+                file_path: candidate.file_path.clone(),
+                line: candidate.start_line,
+            })
+            .collect(),
+    })
+}
+
+/// Split a callable name into its leading verb and the remaining subject:
+/// `getUserById` → (`get`, `user_by_id`). `None` for single-word names, which
+/// have no subject to compare.
+pub fn split_verb_subject(name: &str) -> Option<(String, String)> {
+    let snake = to_snake_case(name);
+    let mut words = snake.split('_').filter(|word| !word.is_empty());
+    let verb = words.next()?.to_string();
+    let subject = words.collect::<Vec<_>>().join("_");
+    (!subject.is_empty()).then_some((verb, subject))
+}
@@ -0,0 +1,382 @@
+//! OpenAPI operation linking: connects the operations of an indexed spec
+//! (symbols produced by `julie_pipeline::indexing_core::openapi`) to the code
+//! on either side of the contract, so `fast_refs getPetById` leads from the
+//! spec to the handler serving it and the clients calling it.
+//!
+//! - route handlers → a route-handler structural fact whose
+//!   `normalized_route_template` matches the operation's path (with or
+//!   without the server base path) and whose verb matches or is absent;
+//! - generated servers and SDKs → functions and methods named after the
+//!   `operationId`, ignoring case, `_`/`-` and an `Async` suffix
+//!   (`getPetById`, `get_pet_by_id`, `GetPetByIdAsync`);
+//! - HTTP clients → `http.client_request.v1` facts whose literal target path
+//!   and verb match the operation.
+//!
+//! Schemas need no special handling: they are indexed as structs, so the
+//! entity matching in [`compute_cross_language_links`](super::compute_cross_language_links)
+//! already pairs them with same-named DTOs. Links are returned to that
+//! function, which stores them with the other counterpart links.
+
+use anyhow::Result;
+use std::collections::HashMap;
+
+use julie_core::database::{CrossLanguageLink, SymbolDatabase};
+
+/// `semantic_group` of OpenAPI symbols.
+const OPENAPI_SEMANTIC_GROUP: &str = "openapi";
+
+/// Languages a spec is written in; their symbols are never implementations.
+const SPEC_LANGUAGES: &str = "'yaml', 'json'";
+
+/// Pattern id of an HTTP client call fact.
+const HTTP_CLIENT_CALL_PATTERN_ID: &str = "http.client_request.v1";
+
+/// Confidence for a handler whose route and verb both match.
+const HANDLER_CONFIDENCE: f32 = 0.9;
+/// Confidence for a catch-all handler (no verb) whose route matches.
+const ANY_VERB_HANDLER_CONFIDENCE: f32 = 0.7;
+/// Confidence for a function named exactly like the `operationId`.
+const OPERATION_ID_CONFIDENCE: f32 = 0.85;
+/// Confidence for a function whose name matches after normalization.
+const NORMALIZED_OPERATION_ID_CONFIDENCE: f32 = 0.75;
+/// Confidence for a client call whose path and verb match.
+const CLIENT_CALL_CONFIDENCE: f32 = 0.8;
+
+/// More matches than this of one kind for an operation means the route or
+/// name is too generic to link (e.g. `list` in every module).
+const MAX_MATCHES_PER_OPERATION: usize = 8;
+
+#[derive(Debug)]
+struct Operation {
+    id: String,
+    name: String,
+    method: String,
+    path: String,
+    full_path: String,
+    operation_id: Option<String>,
+}
+
+#[derive(Debug)]
+struct RouteFact {
+    symbol_id: String,
+    verb: Option<String>,
+    path: String,
+}
+
+#[derive(Debug)]
+struct NamedSymbol {
+    id: String,
+    name: String,
+}
+
+/// Compute the links between OpenAPI operations and the code serving or
+/// calling them. Each linked pair is returned in both directions; the second
+/// value is the number of pairs.
+pub fn compute_openapi_links(db: &SymbolDatabase) -> Result<(Vec<CrossLanguageLink>, usize)> {
+    let operations = load_operations(db)?;
+    if operations.is_empty() {
+        return Ok((Vec::new(), 0));
+    }
+    let handlers = load_route_handlers(db)?;
+    let client_calls = load_client_calls(db)?;
+    let mut by_key: HashMap<String, Vec<NamedSymbol>> = HashMap::new();
+    for symbol in load_callables(db)? {
+        by_key
+            .entry(operation_key(&symbol.name))
+            .or_default()
+            .push(symbol);
+    }
+
+    let mut links: HashMap<(String, String), (f32, String)> = HashMap::new();
+    let mut add = |operation: &Operation, symbol_id: &str, confidence: f32, reason: String| {
+        let entry = links
+            .entry((operation.id.clone(), symbol_id.to_string()))
+            .or_insert((confidence, reason.clone()));
+        if confidence > entry.0 {
+            *entry = (confidence, reason);
+        }
+    };
+
+    for operation in &operations {
+        let label = operation_label(operation);
+
+        let mut best_score = 0;
+        let mut served_by: Vec<(&str, f32)> = Vec::new();
+        for handler in &handlers {
+            let confidence = match handler.verb.as_deref() {
+                Some(verb) if verb.eq_ignore_ascii_case(&operation.method) => HANDLER_CONFIDENCE,
+                Some(_) => continue,
+                None => ANY_VERB_HANDLER_CONFIDENCE,
+            };
+            let Some(score) = [&operation.path, &operation.full_path]
+                .into_iter()
+                .filter_map(|path| template_match_score(path, &handler.path))
+                .max()
+            else {
+                continue;
+            };
+            // Prefer the most literal route: `/pets/mine` over `/pets/{id}`.
+            if score > best_score {
+                best_score = score;
+                served_by.clear();
+            }
+            if score == best_score {
+                served_by.push((handler.symbol_id.as_str(), confidence));
+            }
+        }
+        for (symbol_id, confidence) in cap_matches(served_by) {
+            add(
+                operation,
+                symbol_id,
+                confidence,
+                format!("serves OpenAPI operation {label}"),
+            );
+        }
+
+        if let Some(operation_id) = operation.operation_id.as_deref() {
+            let named: Vec<(&str, f32)> = by_key
+                .get(&operation_key(operation_id))
+                .into_iter()
+                .flatten()
+                .map(|symbol| {
+                    let confidence = if symbol.name == operation_id {
+                        OPERATION_ID_CONFIDENCE
+                    } else {
+                        NORMALIZED_OPERATION_ID_CONFIDENCE
+                    };
+                    (symbol.id.as_str(), confidence)
+                })
+                .collect();
+            for (symbol_id, confidence) in cap_matches(named) {
+                add(
+                    operation,
+                    symbol_id,
+                    confidence,
+                    format!("named after OpenAPI operationId {operation_id}"),
+                );
+            }
+        }
+
+        let callers: Vec<(&str, f32)> = client_calls
+            .iter()
+            .filter(|call| {
+                call.verb
+                    .as_deref()
+                    .is_some_and(|verb| verb.eq_ignore_ascii_case(&operation.method))
+                    && [&operation.path, &operation.full_path]
+                        .into_iter()
+                        .any(|path| template_match_score(&call.path, path).is_some())
+            })
+            .map(|call| (call.symbol_id.as_str(), CLIENT_CALL_CONFIDENCE))
+            .collect();
+        for (symbol_id, confidence) in cap_matches(callers) {
+            add(
+                operation,
+                symbol_id,
+                confidence,
+                format!("calls OpenAPI operation {label}"),
+            );
+        }
+    }
+
+    let mut pairs: Vec<_> = links.into_iter().collect();
+    pairs.sort_by(|a, b| a.0.cmp(&b.0));
+    let count = pairs.len();
+    let mut result = Vec::with_capacity(count * 2);
+    for ((operation_id, symbol_id), (confidence, reason)) in pairs {
+        result.push(CrossLanguageLink {
+            from_symbol_id: operation_id.clone(),
+            to_symbol_id: symbol_id.clone(),
+            confidence,
+            reason: reason.clone(),
+        });
+        result.push(CrossLanguageLink {
+            from_symbol_id: symbol_id,
+            to_symbol_id: operation_id,
+            confidence,
+            reason,
+        });
+    }
+    Ok((result, count))
+}
+
+/// `getPetById (GET /pets/{petId})`, or just `GET /pets/{petId}` for an
+/// operation without an `operationId`.
+fn operation_label(operation: &Operation) -> String {
+    let route = format!("{} {}", operation.method, operation.path);
+    if operation.name == route {
+        route
+    } else {
+        format!("{} ({route})", operation.name)
+    }
+}
+
+/// Drop the matches (sorted by id for stable output) when there are more
+/// than [`MAX_MATCHES_PER_OPERATION`], keeping one link per symbol.
+fn cap_matches(mut matches: Vec<(&str, f32)>) -> Vec<(&str, f32)> {
+    matches.sort_by(|a, b| a.0.cmp(b.0).then(b.1.total_cmp(&a.1)));
+    matches.dedup_by(|a, b| a.0 == b.0);
+    if matches.len() > MAX_MATCHES_PER_OPERATION {
+        return Vec::new();
+    }
+    matches
+}
+
+/// `getPetById`, `get_pet_by_id`, `get-pet-by-id` and `GetPetByIdAsync` all
+/// become `getpetbyid`.
+pub fn operation_key(name: &str) -> String {
+    let key: String = name
+        .chars()
+        .filter(|c| *c != '_' && *c != '-')
+        .map(|c| c.to_ascii_lowercase())
+        .collect();
+    match key.strip_suffix("async") {
+        Some(stripped) if !stripped.is_empty() => stripped.to_string(),
+        _ => key,
+    }
+}
+
+/// Whether `path` (a literal request path or an OpenAPI route) fits route
+/// `template`, and if so how many segments matched literally. Parameters
+/// (`{id}`, `:id`, `<id>`) in the template match any segment; parameters in
+/// `path` only match template parameters. Query strings and the scheme and
+/// host of absolute URLs are ignored.
+pub fn template_match_score(path: &str, template: &str) -> Option<usize> {
+    let path = request_path(path);
+    let left: Vec<&str> = path.split('/').filter(|s| !s.is_empty()).collect();
+    let right: Vec<&str> = template.split('/').filter(|s| !s.is_empty()).collect();
+    if left.len() != right.len() {
+        return None;
+    }
+    let mut literal = 0;
+    for (segment, pattern) in left.iter().zip(&right) {
+        match (is_parameter(segment), is_parameter(pattern)) {
+            (_, true) => {}
+            (true, false) => return None,
+            (false, false) if segment == pattern => literal += 1,
+            (false, false) => return None,
+        }
+    }
+    Some(literal)
+}
+
+fn request_path(path: &str) -> &str {
+    let path = path.split(['?', '#']).next().unwrap_or("");
+    match path.split_once("://") {
+        Some((_, rest)) => rest.find('/').map_or("", |slash| &rest[slash..]),
+        None => path,
+    }
+}
+
+fn is_parameter(segment: &str) -> bool {
+    segment.starts_with(':')
+        || (segment.starts_with('{') && segment.ends_with('}'))
+        || (segment.starts_with('<') && segment.ends_with('>'))
+        || segment.starts_with("${")
+}
+
+fn load_operations(db: &SymbolDatabase) -> Result<Vec<Operation>> {
+    let mut stmt = db.conn.prepare(
+        "SELECT id, name,
+                json_extract(metadata, '$.openapi.method'),
+                json_extract(metadata, '$.openapi.path'),
+                json_extract(metadata, '$.openapi.full_path'),
+                json_extract(metadata, '$.openapi.operation_id')
+         FROM symbols
+         WHERE semantic_group = ?1
+           AND json_extract(metadata, '$.openapi.kind') = 'operation'
+         ORDER BY file_path, start_line",
+    )?;
+    let rows = stmt
+        .query_map([OPENAPI_SEMANTIC_GROUP], |row| {
+            Ok((
+                row.get::<_, String>(0)?,
+                row.get::<_, String>(1)?,
+                row.get::<_, Option<String>>(2)?,
+                row.get::<_, Option<String>>(3)?,
+                row.get::<_, Option<String>>(4)?,
+                row.get::<_, Option<String>>(5)?,
+            ))
+        })?
+        .collect::<rusqlite::Result<Vec<_>>>()?;
+    Ok(rows
+        .into_iter()
+        .filter_map(|(id, name, method, path, full_path, operation_id)| {
+            let (method, path) = (method?, path?);
+            Some(Operation {
+                id,
+                name,
+                method,
+                full_path: full_path.unwrap_or_else(|| path.clone()),
+                path,
+                operation_id,
+            })
+        })
+        .collect())
+}
+
+/// Route-handler facts attached to a symbol, from every framework that
+/// records a `normalized_route_template`.
+fn load_route_handlers(db: &SymbolDatabase) -> Result<Vec<RouteFact>> {
+    let mut stmt = db.conn.prepare(
+        "SELECT containing_symbol_id,
+                json_extract(metadata, '$.verb'),
+                json_extract(metadata, '$.normalized_route_template')
+         FROM structural_facts
+         WHERE containing_symbol_id IS NOT NULL
+           AND json_extract(metadata, '$.normalized_route_template') IS NOT NULL
+         ORDER BY file_path, start_line",
+    )?;
+    let handlers = stmt
+        .query_map([], |row| {
+            Ok(RouteFact {
+                symbol_id: row.get(0)?,
+                verb: row.get(1)?,
+                path: row.get(2)?,
+            })
+        })?
+        .collect::<rusqlite::Result<Vec<_>>>()?;
+    Ok(handlers)
+}
+
+fn load_client_calls(db: &SymbolDatabase) -> Result<Vec<RouteFact>> {
+    let mut stmt = db.conn.prepare(
+        "SELECT containing_symbol_id,
+                json_extract(metadata, '$.verb'),
+                json_extract(metadata, '$.target_path')
+         FROM structural_facts
+         WHERE pattern_id = ?1
+           AND containing_symbol_id IS NOT NULL
+           AND json_extract(metadata, '$.target_path') IS NOT NULL
+         ORDER BY file_path, start_line",
+    )?;
+    let calls = stmt
+        .query_map([HTTP_CLIENT_CALL_PATTERN_ID], |row| {
+            Ok(RouteFact {
+                symbol_id: row.get(0)?,
+                verb: row.get(1)?,
+                path: row.get(2)?,
+            })
+        })?
+        .collect::<rusqlite::Result<Vec<_>>>()?;
+    Ok(calls)
+}
+
+/// Functions and methods outside spec files.
+fn load_callables(db: &SymbolDatabase) -> Result<Vec<NamedSymbol>> {
+    let mut stmt = db.conn.prepare(&format!(
+        "SELECT id, name FROM symbols
+         WHERE kind IN ('function', 'method')
+           AND language NOT IN ({SPEC_LANGUAGES})
+         ORDER BY file_path, start_line"
+    ))?;
+    let symbols = stmt
+        .query_map([], |row| {
+            Ok(NamedSymbol {
+                id: row.get(0)?,
+                name: row.get(1)?,
+            })
+        })?
+        .collect::<rusqlite::Result<Vec<_>>>()?;
+    Ok(symbols)
+}
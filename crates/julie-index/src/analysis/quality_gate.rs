@@ -0,0 +1,189 @@
+//! CI quality gate: compare analysis results between a base and a head index
+//! and flag regressions.
+//!
+//! A [`QualitySnapshot`] captures what each check needs from one index. The
+//! caller indexes the base revision and the head revision separately, takes a
+//! snapshot of each, and [`evaluate_quality_gate`] decides pass/fail per check:
+//!
+//! - `dead_code`: new public functions/methods with zero references fail.
+//! - `api_surface`: removed public declarations fail (additions are listed).
+//! - `deprecated_usage`: more usages of deprecated symbols than the base fails.
+
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeSet;
+use std::fmt;
+use std::str::FromStr;
+
+use julie_core::database::SymbolDatabase;
+
+use crate::analysis::api_surface::generate_api_surface;
+
+/// One enforceable check.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum QualityCheck {
+    DeadCode,
+    ApiSurface,
+    DeprecatedUsage,
+}
+
+impl QualityCheck {
+    pub const ALL: [Self; 3] = [Self::DeadCode, Self::ApiSurface, Self::DeprecatedUsage];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::DeadCode => "dead_code",
+            Self::ApiSurface => "api_surface",
+            Self::DeprecatedUsage => "deprecated_usage",
+        }
+    }
+}
+
+impl fmt::Display for QualityCheck {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for QualityCheck {
+    type Err = String;
+
+    /// Accepts `dead-code` / `dead_code`, `api` / `api-surface`, and
+    /// `deprecated` / `deprecated-usage`.
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value.trim().to_ascii_lowercase().replace('-', "_").as_str() {
+            "dead_code" => Ok(Self::DeadCode),
+            "api" | "api_surface" => Ok(Self::ApiSurface),
+            "deprecated" | "deprecated_usage" => Ok(Self::DeprecatedUsage),
+            other => Err(format!(
+                "unknown check '{other}' (expected dead-code, api-surface or deprecated-usage)"
+            )),
+        }
+    }
+}
+
+/// What the quality gate needs from one index.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct QualitySnapshot {
+    /// Dead-code candidates as `file_path::name`.
+    pub dead_code: BTreeSet<String>,
+    /// Public declarations as `module::name (kind)`.
+    pub api_surface: BTreeSet<String>,
+    pub deprecated_usages: usize,
+}
+
+/// Read a snapshot from an index whose post-index analysis (reference scores)
+/// has run.
+pub fn capture_quality_snapshot(db: &SymbolDatabase) -> Result<QualitySnapshot> {
+    let dead_code_total = db.count_dead_code_candidates()?.max(0) as usize;
+    let dead_code = db
+        .get_dead_code_candidates(dead_code_total)?
+        .into_iter()
+        .map(|candidate| format!("{}::{}", candidate.file_path, candidate.name))
+        .collect();
+
+    let api_surface = generate_api_surface(db, None)?
+        .modules
+        .into_iter()
+        .flat_map(|module| {
+            let path = module.module;
+            module
+                .symbols
+                .into_iter()
+                .map(move |symbol| format!("{path}::{} ({})", symbol.name, symbol.kind))
+        })
+        .collect();
+
+    Ok(QualitySnapshot {
+        dead_code,
+        api_surface,
+        deprecated_usages: db.count_deprecated_usages()?.max(0) as usize,
+    })
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct QualityGateReport {
+    pub base_ref: String,
+    pub passed: bool,
+    pub checks: Vec<QualityCheckResult>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct QualityCheckResult {
+    pub check: QualityCheck,
+    pub passed: bool,
+    pub base: usize,
+    pub head: usize,
+    /// Entries present in head but not in base.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub added: Vec<String>,
+    /// Entries present in base but not in head.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub removed: Vec<String>,
+}
+
+/// Compare `base` and `head` for each of `checks` (all checks when empty).
+pub fn evaluate_quality_gate(
+    base_ref: &str,
+    base: &QualitySnapshot,
+    head: &QualitySnapshot,
+    checks: &[QualityCheck],
+) -> QualityGateReport {
+    let checks = if checks.is_empty() {
+        &QualityCheck::ALL[..]
+    } else {
+        checks
+    };
+
+    let mut seen = BTreeSet::new();
+    let results: Vec<QualityCheckResult> = checks
+        .iter()
+        .filter(|check| seen.insert(check.as_str()))
+        .map(|&check| match check {
+            QualityCheck::DeadCode => {
+                let (added, removed) = set_delta(&base.dead_code, &head.dead_code);
+                QualityCheckResult {
+                    check,
+                    passed: added.is_empty(),
+                    base: base.dead_code.len(),
+                    head: head.dead_code.len(),
+                    added,
+                    removed,
+                }
+            }
+            QualityCheck::ApiSurface => {
+                let (added, removed) = set_delta(&base.api_surface, &head.api_surface);
+                QualityCheckResult {
+                    check,
+                    passed: removed.is_empty(),
+                    base: base.api_surface.len(),
+                    head: head.api_surface.len(),
+                    added,
+                    removed,
+                }
+            }
+            QualityCheck::DeprecatedUsage => QualityCheckResult {
+                check,
+                passed: head.deprecated_usages <= base.deprecated_usages,
+                base: base.deprecated_usages,
+                head: head.deprecated_usages,
+                added: Vec::new(),
+                removed: Vec::new(),
+            },
+        })
+        .collect();
+
+    QualityGateReport {
+        base_ref: base_ref.to_string(),
+        passed: results.iter().all(|result| result.passed),
+        checks: results,
+    }
+}
+
+fn set_delta(base: &BTreeSet<String>, head: &BTreeSet<String>) -> (Vec<String>, Vec<String>) {
+    (
+        head.difference(base).cloned().collect(),
+        base.difference(head).cloned().collect(),
+    )
+}
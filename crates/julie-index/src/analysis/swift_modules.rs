@@ -0,0 +1,602 @@
+//! Swift module map: which SwiftPM or Xcode target compiles each Swift file.
+//!
+//! Swift sources never name their module, so the map comes from the build
+//! manifests the indexer already stores as file content:
+//!
+//! - `Package.swift`: `.target`, `.executableTarget`, `.testTarget` and
+//!   `.macro` declarations, with their `path:` or the SwiftPM default
+//!   (`Sources/<name>`, `Tests/<name>` for test targets).
+//! - `*.xcodeproj/project.pbxproj`: native targets, the files of their
+//!   Sources build phase (resolved through the group tree) and, for Xcode 16
+//!   projects, their file-system-synchronized folders. The module name is
+//!   `PRODUCT_MODULE_NAME` when set, else the target name made a C99
+//!   identifier the way Xcode does.
+//!
+//! An explicit Sources-phase membership wins; otherwise a file belongs to the
+//! target with the longest source directory containing it. The result lands
+//! in `swift_module_files`, where navigation resolves `Module.Symbol` and
+//! labels same-named definitions with their module.
+
+use anyhow::Result;
+use std::collections::HashMap;
+use tracing::info;
+
+use julie_core::database::{SwiftModuleFile, SymbolDatabase};
+
+/// Summary stats from building the Swift module map.
+#[derive(Debug, Clone, Default)]
+pub struct SwiftModuleStats {
+    /// `Package.swift` and `project.pbxproj` files parsed.
+    pub manifests: usize,
+    /// Targets declared across all manifests.
+    pub targets: usize,
+    /// Swift files assigned to a module.
+    pub files: usize,
+}
+
+/// A Swift target declared by one manifest.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SwiftTarget {
+    /// Module name as imported.
+    pub module: String,
+    /// Workspace-relative directories whose Swift files belong to the target.
+    pub source_dirs: Vec<String>,
+    /// Workspace-relative files listed explicitly (Xcode Sources phase).
+    pub source_files: Vec<String>,
+}
+
+/// SwiftPM target kinds that compile Swift sources into an importable module.
+const PACKAGE_TARGET_CALLS: &[&str] =
+    &[".target(", ".executableTarget(", ".testTarget(", ".macro("];
+
+/// Directories SwiftPM searches for a target without an explicit `path:`.
+const PACKAGE_SOURCE_ROOTS: &[&str] = &["Sources", "Source", "src", "srcs"];
+
+/// Rebuild the Swift module map from every indexed manifest.
+///
+/// Runs alongside `compute_symbol_aliases()` in the indexing pipeline.
+/// Replaces the whole `swift_module_files` table, so it is safe to run
+/// repeatedly.
+pub fn compute_swift_modules(db: &SymbolDatabase) -> Result<SwiftModuleStats> {
+    let mut swift_files = Vec::new();
+    let mut manifests = Vec::new();
+    {
+        let mut stmt = db.conn.prepare("SELECT path, language FROM files")?;
+        let rows = stmt.query_map([], |row| {
+            Ok((row.get::<_, String>(0)?, row.get::<_, String>(1)?))
+        })?;
+        for row in rows {
+            let (path, language) = row?;
+            if is_package_manifest(&path) || is_xcode_project(&path) {
+                manifests.push(path.clone());
+            }
+            if language == "swift" && !is_package_manifest(&path) {
+                swift_files.push(path);
+            }
+        }
+    }
+
+    let mut stats = SwiftModuleStats::default();
+    if manifests.is_empty() || swift_files.is_empty() {
+        db.replace_all_swift_module_files(&[])?;
+        return Ok(stats);
+    }
+    manifests.sort();
+    // SwiftPM manifests first, so they win directory ties with Xcode targets.
+    manifests.sort_by_key(|path| !is_package_manifest(path));
+
+    let contents = db.get_file_contents_by_paths(&manifests)?;
+    let mut targets: Vec<(String, SwiftTarget)> = Vec::new();
+    for manifest in &manifests {
+        let Some(Some(content)) = contents.get(manifest) else {
+            continue;
+        };
+        stats.manifests += 1;
+        let declared = if is_package_manifest(manifest) {
+            parse_package_manifest(manifest, content, &swift_files)
+        } else {
+            parse_xcode_project(manifest, content)
+        };
+        targets.extend(declared.into_iter().map(|t| (manifest.clone(), t)));
+    }
+    stats.targets = targets.len();
+
+    let rows = assign_swift_modules(&swift_files, &targets);
+    stats.files = rows.len();
+    db.replace_all_swift_module_files(&rows)?;
+    info!(
+        "Swift module map: {} files in {} targets from {} manifests",
+        stats.files, stats.targets, stats.manifests
+    );
+    Ok(stats)
+}
+
+/// Pick each Swift file's module: explicit Sources-phase membership first,
+/// then the longest containing source directory.
+pub fn assign_swift_modules(
+    swift_files: &[String],
+    targets: &[(String, SwiftTarget)],
+) -> Vec<SwiftModuleFile> {
+    let mut explicit: HashMap<&str, (&str, &str)> = HashMap::new();
+    for (manifest, target) in targets {
+        for file in &target.source_files {
+            explicit
+                .entry(file.as_str())
+                .or_insert((target.module.as_str(), manifest.as_str()));
+        }
+    }
+
+    let mut rows = Vec::new();
+    for file in swift_files {
+        let owner = explicit.get(file.as_str()).copied().or_else(|| {
+            let mut best: Option<(usize, (&str, &str))> = None;
+            for (manifest, target) in targets {
+                for dir in &target.source_dirs {
+                    if !path_is_under(file, dir) {
+                        continue;
+                    }
+                    if best.is_none_or(|(len, _)| dir.len() > len) {
+                        best = Some((dir.len(), (target.module.as_str(), manifest.as_str())));
+                    }
+                }
+            }
+            best.map(|(_, owner)| owner)
+        });
+        if let Some((module, manifest)) = owner {
+            rows.push(SwiftModuleFile {
+                file_path: file.clone(),
+                module: module.to_string(),
+                manifest_path: manifest.to_string(),
+            });
+        }
+    }
+    rows
+}
+
+fn is_package_manifest(path: &str) -> bool {
+    file_name(path) == "Package.swift"
+}
+
+fn is_xcode_project(path: &str) -> bool {
+    file_name(path) == "project.pbxproj" && parent_dir(path).ends_with(".xcodeproj")
+}
+
+fn file_name(path: &str) -> &str {
+    path.rsplit('/').next().unwrap_or(path)
+}
+
+fn parent_dir(path: &str) -> &str {
+    path.rsplit_once('/').map_or("", |(dir, _)| dir)
+}
+
+fn path_is_under(path: &str, dir: &str) -> bool {
+    dir.is_empty()
+        || path
+            .strip_prefix(dir)
+            .is_some_and(|rest| rest.starts_with('/'))
+}
+
+/// Join `relative` onto `base` and fold `.` / `..` segments.
+fn join_path(base: &str, relative: &str) -> String {
+    let mut segments: Vec<&str> = Vec::new();
+    for segment in base.split('/').chain(relative.split('/')) {
+        match segment {
+            "" | "." => {}
+            ".." => {
+                segments.pop();
+            }
+            segment => segments.push(segment),
+        }
+    }
+    segments.join("/")
+}
+
+// ---------------------------------------------------------------------------
+// Package.swift
+// ---------------------------------------------------------------------------
+
+/// Targets declared by a `Package.swift`. `swift_files` decides which default
+/// source root a target without `path:` uses.
+pub fn parse_package_manifest(
+    manifest_path: &str,
+    content: &str,
+    swift_files: &[String],
+) -> Vec<SwiftTarget> {
+    let package_dir = parent_dir(manifest_path);
+    let mut calls: Vec<(usize, &str)> = PACKAGE_TARGET_CALLS
+        .iter()
+        .flat_map(|call| content.match_indices(call))
+        .collect();
+    calls.sort_unstable();
+
+    let mut targets = Vec::new();
+    // End of the last declaration, so `.target(name:)` inside a target's
+    // `dependencies:` is not mistaken for a declaration of its own.
+    let mut consumed = 0;
+    for (start, call) in calls {
+        if start < consumed {
+            continue;
+        }
+        let args_start = start + call.len();
+        let Some(args) = balanced_arguments(&content[args_start..]) else {
+            continue;
+        };
+        consumed = args_start + args.len();
+        let arguments = split_top_level(args);
+        let Some(name) = labeled_string(&arguments, "name") else {
+            continue;
+        };
+        let dir = match labeled_string(&arguments, "path") {
+            Some(path) => join_path(package_dir, &path),
+            None if call == ".testTarget(" => join_path(package_dir, &format!("Tests/{name}")),
+            None => default_source_dir(package_dir, &name, swift_files),
+        };
+        targets.push(SwiftTarget {
+            module: c99_identifier(&name),
+            source_dirs: vec![dir],
+            source_files: Vec::new(),
+        });
+    }
+    targets
+}
+
+fn default_source_dir(package_dir: &str, name: &str, swift_files: &[String]) -> String {
+    PACKAGE_SOURCE_ROOTS
+        .iter()
+        .map(|root| join_path(package_dir, &format!("{root}/{name}")))
+        .find(|dir| swift_files.iter().any(|file| path_is_under(file, dir)))
+        .unwrap_or_else(|| join_path(package_dir, &format!("Sources/{name}")))
+}
+
+/// Text between an opening `(` (already consumed) and its matching `)`.
+fn balanced_arguments(rest: &str) -> Option<&str> {
+    let mut depth = 0usize;
+    let mut in_string = false;
+    let mut escaped = false;
+    for (index, c) in rest.char_indices() {
+        if in_string {
+            match c {
+                _ if escaped => escaped = false,
+                '\\' => escaped = true,
+                '"' => in_string = false,
+                _ => {}
+            }
+            continue;
+        }
+        match c {
+            '"' => in_string = true,
+            '(' | '[' | '{' => depth += 1,
+            ')' if depth == 0 => return Some(&rest[..index]),
+            ')' | ']' | '}' => depth = depth.saturating_sub(1),
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Split call arguments on top-level commas.
+fn split_top_level(args: &str) -> Vec<&str> {
+    let mut parts = Vec::new();
+    let mut depth = 0usize;
+    let mut in_string = false;
+    let mut escaped = false;
+    let mut start = 0;
+    for (index, c) in args.char_indices() {
+        if in_string {
+            match c {
+                _ if escaped => escaped = false,
+                '\\' => escaped = true,
+                '"' => in_string = false,
+                _ => {}
+            }
+            continue;
+        }
+        match c {
+            '"' => in_string = true,
+            '(' | '[' | '{' => depth += 1,
+            ')' | ']' | '}' => depth = depth.saturating_sub(1),
+            ',' if depth == 0 => {
+                parts.push(args[start..index].trim());
+                start = index + 1;
+            }
+            _ => {}
+        }
+    }
+    parts.push(args[start..].trim());
+    parts
+}
+
+/// The string literal passed for `label:` among top-level arguments.
+fn labeled_string(arguments: &[&str], label: &str) -> Option<String> {
+    arguments.iter().find_map(|argument| {
+        let value = argument
+            .strip_prefix(label)?
+            .trim_start()
+            .strip_prefix(':')?;
+        let literal = value.trim().strip_prefix('"')?;
+        let end = literal.find('"')?;
+        Some(literal[..end].to_string()).filter(|s| !s.is_empty())
+    })
+}
+
+// ---------------------------------------------------------------------------
+// project.pbxproj
+// ---------------------------------------------------------------------------
+
+/// A value in an old-style (NeXTSTEP) property list.
+#[derive(Debug, Clone, PartialEq)]
+enum Plist {
+    String(String),
+    Array(Vec<Plist>),
+    Dict(HashMap<String, Plist>),
+}
+
+impl Plist {
+    fn as_str(&self) -> Option<&str> {
+        match self {
+            Plist::String(s) => Some(s),
+            _ => None,
+        }
+    }
+
+    fn get(&self, key: &str) -> Option<&Plist> {
+        match self {
+            Plist::Dict(map) => map.get(key),
+            _ => None,
+        }
+    }
+
+    fn get_str(&self, key: &str) -> Option<&str> {
+        self.get(key).and_then(Plist::as_str)
+    }
+
+    fn get_strs(&self, key: &str) -> Vec<&str> {
+        match self.get(key) {
+            Some(Plist::Array(items)) => items.iter().filter_map(Plist::as_str).collect(),
+            _ => Vec::new(),
+        }
+    }
+}
+
+/// Targets declared by an Xcode `project.pbxproj`.
+pub fn parse_xcode_project(pbxproj_path: &str, content: &str) -> Vec<SwiftTarget> {
+    let Some(root) = PlistParser::new(content).parse() else {
+        return Vec::new();
+    };
+    let Some(Plist::Dict(objects)) = root.get("objects") else {
+        return Vec::new();
+    };
+    let Some(project) = root.get_str("rootObject").and_then(|id| objects.get(id)) else {
+        return Vec::new();
+    };
+
+    // `Foo.xcodeproj/project.pbxproj` -> the directory holding `Foo.xcodeproj`
+    let project_dir = join_path(
+        parent_dir(parent_dir(pbxproj_path)),
+        project.get_str("projectDirPath").unwrap_or_default(),
+    );
+    let mut paths: HashMap<&str, String> = HashMap::new();
+    if let Some(main_group) = project.get_str("mainGroup") {
+        resolve_group_paths(objects, main_group, &project_dir, &project_dir, &mut paths);
+    }
+
+    let mut targets = Vec::new();
+    for target_id in project.get_strs("targets") {
+        let Some(target) = objects.get(target_id) else {
+            continue;
+        };
+        if target.get_str("isa") != Some("PBXNativeTarget") {
+            continue;
+        }
+        let Some(name) = target.get_str("name") else {
+            continue;
+        };
+
+        let mut source_files = Vec::new();
+        for phase in target
+            .get_strs("buildPhases")
+            .into_iter()
+            .filter_map(|id| objects.get(id))
+            .filter(|phase| phase.get_str("isa") == Some("PBXSourcesBuildPhase"))
+        {
+            for build_file in phase.get_strs("files") {
+                let file_ref = objects
+                    .get(build_file)
+                    .and_then(|build_file| build_file.get_str("fileRef"));
+                if let Some(path) = file_ref.and_then(|id| paths.get(id)) {
+                    source_files.push(path.clone());
+                }
+            }
+        }
+        let source_dirs = target
+            .get_strs("fileSystemSynchronizedGroups")
+            .into_iter()
+            .filter_map(|id| paths.get(id).cloned())
+            .collect();
+
+        targets.push(SwiftTarget {
+            module: xcode_module_name(objects, target, name),
+            source_dirs,
+            source_files,
+        });
+    }
+    targets
+}
+
+/// Walk the group tree from `id`, recording the workspace-relative path of
+/// every group and file reference reachable from the main group.
+fn resolve_group_paths<'a>(
+    objects: &'a HashMap<String, Plist>,
+    id: &'a str,
+    parent_path: &str,
+    project_dir: &str,
+    paths: &mut HashMap<&'a str, String>,
+) {
+    let Some(object) = objects.get(id) else {
+        return;
+    };
+    if paths.contains_key(id) {
+        return;
+    }
+    let relative = object.get_str("path").unwrap_or_default();
+    let path = match object.get_str("sourceTree").unwrap_or("<group>") {
+        "<group>" => join_path(parent_path, relative),
+        "SOURCE_ROOT" => join_path(project_dir, relative),
+        // `<absolute>`, SDKROOT, BUILT_PRODUCTS_DIR: outside the workspace
+        _ => return,
+    };
+    paths.insert(id, path.clone());
+    for child in object.get_strs("children") {
+        resolve_group_paths(objects, child, &path, project_dir, paths);
+    }
+}
+
+/// `PRODUCT_MODULE_NAME` from the target's build settings when it is a
+/// literal, else the target name as a C99 identifier.
+fn xcode_module_name(objects: &HashMap<String, Plist>, target: &Plist, name: &str) -> String {
+    let configurations = target
+        .get_str("buildConfigurationList")
+        .and_then(|id| objects.get(id))
+        .map(|list| list.get_strs("buildConfigurations"))
+        .unwrap_or_default();
+    configurations
+        .into_iter()
+        .filter_map(|id| {
+            objects
+                .get(id)?
+                .get("buildSettings")?
+                .get_str("PRODUCT_MODULE_NAME")
+        })
+        .find(|module| !module.contains('$'))
+        .map(str::to_string)
+        .unwrap_or_else(|| c99_identifier(name))
+}
+
+/// Xcode's `c99extidentifier`: anything outside `[A-Za-z0-9_]` becomes `_`,
+/// and a leading digit gets a `_` prefix.
+fn c99_identifier(name: &str) -> String {
+    let mut identifier: String = name
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect();
+    if identifier.starts_with(|c: char| c.is_ascii_digit()) {
+        identifier.insert(0, '_');
+    }
+    identifier
+}
+
+/// Minimal old-style property list reader: dictionaries, arrays, quoted and
+/// bare strings, `/* */` and `//` comments. Enough for `project.pbxproj`.
+struct PlistParser<'a> {
+    input: &'a str,
+    pos: usize,
+}
+
+impl<'a> PlistParser<'a> {
+    fn new(input: &'a str) -> Self {
+        Self { input, pos: 0 }
+    }
+
+    fn parse(mut self) -> Option<Plist> {
+        self.value()
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.input[self.pos..].chars().next()
+    }
+
+    fn skip_trivia(&mut self) {
+        loop {
+            let rest = &self.input[self.pos..];
+            let trimmed = rest.trim_start();
+            self.pos += rest.len() - trimmed.len();
+            if trimmed.starts_with("/*") {
+                self.pos += trimmed.find("*/").map_or(trimmed.len(), |end| end + 2);
+            } else if trimmed.starts_with("//") {
+                self.pos += trimmed.find('\n').unwrap_or(trimmed.len());
+            } else {
+                return;
+            }
+        }
+    }
+
+    fn expect(&mut self, c: char) -> Option<()> {
+        self.skip_trivia();
+        (self.peek()? == c).then(|| self.pos += c.len_utf8())
+    }
+
+    fn value(&mut self) -> Option<Plist> {
+        self.skip_trivia();
+        match self.peek()? {
+            '{' => self.dict(),
+            '(' => self.array(),
+            _ => self.string().map(Plist::String),
+        }
+    }
+
+    fn dict(&mut self) -> Option<Plist> {
+        self.expect('{')?;
+        let mut map = HashMap::new();
+        loop {
+            self.skip_trivia();
+            if self.peek()? == '}' {
+                self.pos += 1;
+                return Some(Plist::Dict(map));
+            }
+            let key = self.string()?;
+            self.expect('=')?;
+            let value = self.value()?;
+            self.expect(';')?;
+            map.insert(key, value);
+        }
+    }
+
+    fn array(&mut self) -> Option<Plist> {
+        self.expect('(')?;
+        let mut items = Vec::new();
+        loop {
+            self.skip_trivia();
+            if self.peek()? == ')' {
+                self.pos += 1;
+                return Some(Plist::Array(items));
+            }
+            items.push(self.value()?);
+            self.skip_trivia();
+            if self.peek()? == ',' {
+                self.pos += 1;
+            }
+        }
+    }
+
+    fn string(&mut self) -> Option<String> {
+        self.skip_trivia();
+        let rest = &self.input[self.pos..];
+        if let Some(quoted) = rest.strip_prefix('"') {
+            let mut value = String::new();
+            let mut chars = quoted.char_indices();
+            while let Some((index, c)) = chars.next() {
+                match c {
+                    '"' => {
+                        self.pos += 1 + index + 1;
+                        return Some(value);
+                    }
+                    '\\' => match chars.next()?.1 {
+                        'n' => value.push('\n'),
+                        't' => value.push('\t'),
+                        other => value.push(other),
+                    },
+                    c => value.push(c),
+                }
+            }
+            return None;
+        }
+        let end = rest
+            .find(|c: char| !(c.is_alphanumeric() || "_$/:.-+".contains(c)))
+            .unwrap_or(rest.len());
+        if end == 0 {
+            return None;
+        }
+        self.pos += end;
+        Some(rest[..end].to_string())
+    }
+}
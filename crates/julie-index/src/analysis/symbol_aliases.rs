@@ -0,0 +1,241 @@
+//! Build-time rename inference: names a definition is published under by a
+//! macro or code generator, which never appear in its source.
+//!
+//! Each language registers an [`AliasHook`] that looks at a symbol's
+//! annotations (and its parent's, for class-level generators such as
+//! Lombok's `@Data`) and returns the generated names:
+//!
+//! - Rust: `#[napi]` exports `snake_case` functions as `camelCase`;
+//!   `js_name = ...` (napi, wasm_bindgen) and pyo3's `name = ...` rename
+//!   explicitly; `#[derive(Builder)]` adds `{Type}Builder`.
+//! - C#: CommunityToolkit's `[ObservableProperty]` turns `_userName` into
+//!   `UserName`, and `[RelayCommand]` turns `SaveAsync` into `SaveCommand`.
+//! - Java: Lombok `@Getter`/`@Setter`/`@Data`/`@Value` generate
+//!   `getX`/`isX`/`setX` accessors for fields.
+//!
+//! The aliases land in `symbol_aliases`, where `fast_refs` uses them to
+//! resolve a generated name to its source definition and to count call sites
+//! spelled the generated way as references.
+
+use anyhow::Result;
+use std::collections::{HashMap, HashSet};
+use tracing::info;
+
+use julie_core::cross_language_intelligence::{to_camel_case, to_pascal_case};
+use julie_core::database::{SymbolAlias, SymbolDatabase};
+use julie_extractors::{AnnotationMarker, Symbol, SymbolKind};
+
+/// Summary stats from running alias inference.
+#[derive(Debug, Clone, Default)]
+pub struct SymbolAliasStats {
+    /// Annotated symbols (and children of annotated symbols) examined.
+    pub candidates: usize,
+    /// Aliases recorded.
+    pub aliases: usize,
+}
+
+/// A generated name inferred for one symbol.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InferredAlias {
+    pub alias: String,
+    /// What produced the name (`napi`, `lombok getter`).
+    pub source: &'static str,
+}
+
+/// Per-language alias inference: a symbol and its parent, if loaded, to the
+/// names the build publishes the symbol under.
+pub type AliasHook = fn(&Symbol, Option<&Symbol>) -> Vec<InferredAlias>;
+
+/// The alias hook for `language`, if it has one.
+pub fn alias_hook(language: &str) -> Option<AliasHook> {
+    match language {
+        "rust" => Some(rust_aliases),
+        "csharp" => Some(csharp_aliases),
+        "java" => Some(java_aliases),
+        _ => None,
+    }
+}
+
+/// Recompute aliases for every annotated symbol and every child of one.
+///
+/// Runs alongside `compute_cross_language_links()` in the indexing pipeline.
+/// Replaces the whole `symbol_aliases` table, so it is safe to run
+/// repeatedly.
+pub fn compute_symbol_aliases(db: &SymbolDatabase) -> Result<SymbolAliasStats> {
+    let mut stmt = db.conn.prepare(
+        "SELECT id FROM symbols
+         WHERE id IN (SELECT symbol_id FROM symbol_annotations)
+            OR parent_id IN (SELECT symbol_id FROM symbol_annotations)",
+    )?;
+    let ids = stmt
+        .query_map([], |row| row.get::<_, String>(0))?
+        .collect::<rusqlite::Result<Vec<_>>>()?;
+    // Children are loaded because their parent is annotated, so every parent
+    // a hook needs is in this set.
+    let symbols = db.get_symbols_by_ids(&ids)?;
+    let by_id: HashMap<&str, &Symbol> = symbols.iter().map(|s| (s.id.as_str(), s)).collect();
+
+    let mut stats = SymbolAliasStats {
+        candidates: symbols.len(),
+        ..Default::default()
+    };
+    let mut seen = HashSet::new();
+    let mut aliases = Vec::new();
+    for symbol in &symbols {
+        let Some(hook) = alias_hook(&symbol.language) else {
+            continue;
+        };
+        let parent = symbol
+            .parent_id
+            .as_deref()
+            .and_then(|id| by_id.get(id).copied());
+        for inferred in hook(symbol, parent) {
+            if inferred.alias == symbol.name
+                || !seen.insert((inferred.alias.clone(), symbol.id.clone()))
+            {
+                continue;
+            }
+            aliases.push(SymbolAlias {
+                symbol_id: symbol.id.clone(),
+                alias: inferred.alias,
+                source: inferred.source.to_string(),
+            });
+        }
+    }
+    stats.aliases = aliases.len();
+
+    db.replace_all_symbol_aliases(&aliases)?;
+    info!(
+        "Symbol alias inference: {} aliases from {} candidate symbols",
+        stats.aliases, stats.candidates
+    );
+    Ok(stats)
+}
+
+fn rust_aliases(symbol: &Symbol, _parent: Option<&Symbol>) -> Vec<InferredAlias> {
+    let mut aliases = Vec::new();
+    for marker in &symbol.annotations {
+        let args = marker.raw_text.as_deref().unwrap_or_default();
+        if has_key(marker, "napi") {
+            match named_argument(args, "js_name") {
+                Some(js_name) => aliases.push(alias(js_name, "napi js_name")),
+                None if is_callable(symbol) => {
+                    aliases.push(alias(to_camel_case(&symbol.name), "napi"))
+                }
+                None => {}
+            }
+        } else if has_key(marker, "wasm_bindgen") {
+            if let Some(js_name) = named_argument(args, "js_name") {
+                aliases.push(alias(js_name, "wasm_bindgen js_name"));
+            }
+        } else if ["pyo3", "pyfunction", "pyclass"]
+            .iter()
+            .any(|key| has_key(marker, key))
+        {
+            if let Some(name) = named_argument(args, "name") {
+                aliases.push(alias(name, "pyo3 name"));
+            }
+        } else if has_key(marker, "builder") && marker.carrier.as_deref() == Some("derive") {
+            aliases.push(alias(format!("{}Builder", symbol.name), "derive(Builder)"));
+        }
+    }
+    aliases
+}
+
+fn csharp_aliases(symbol: &Symbol, _parent: Option<&Symbol>) -> Vec<InferredAlias> {
+    let mut aliases = Vec::new();
+    for marker in &symbol.annotations {
+        if has_key(marker, "observableproperty") && symbol.kind == SymbolKind::Field {
+            // The generator strips `_` / `m_` prefixes and upper-cases the rest.
+            let base = symbol
+                .name
+                .strip_prefix("m_")
+                .unwrap_or(symbol.name.trim_start_matches('_'));
+            aliases.push(alias(to_pascal_case(base), "[ObservableProperty]"));
+        } else if has_key(marker, "relaycommand") && is_callable(symbol) {
+            let base = symbol.name.strip_suffix("Async").unwrap_or(&symbol.name);
+            aliases.push(alias(format!("{base}Command"), "[RelayCommand]"));
+        }
+    }
+    aliases
+}
+
+fn java_aliases(symbol: &Symbol, parent: Option<&Symbol>) -> Vec<InferredAlias> {
+    if symbol.kind != SymbolKind::Field {
+        return Vec::new();
+    }
+    let own = |key: &str| symbol.annotations.iter().any(|m| has_key(m, key));
+    let class = |key: &str| parent.is_some_and(|p| p.annotations.iter().any(|m| has_key(m, key)));
+
+    let is_static = symbol
+        .signature
+        .as_deref()
+        .is_some_and(|signature| signature.split_whitespace().any(|word| word == "static"));
+    if is_static {
+        return Vec::new();
+    }
+    let is_final = symbol
+        .signature
+        .as_deref()
+        .is_some_and(|signature| signature.split_whitespace().any(|word| word == "final"));
+    let is_boolean = symbol.signature.as_deref().is_some_and(|signature| {
+        signature
+            .split(|c: char| !c.is_alphanumeric())
+            .any(|word| word == "boolean")
+    });
+
+    let property = to_pascal_case(&symbol.name);
+    let mut aliases = Vec::new();
+    if own("getter") || class("getter") || class("data") || class("value") {
+        let prefix = if is_boolean { "is" } else { "get" };
+        aliases.push(alias(format!("{prefix}{property}"), "lombok getter"));
+    }
+    if !is_final && (own("setter") || class("setter") || class("data")) {
+        aliases.push(alias(format!("set{property}"), "lombok setter"));
+    }
+    aliases
+}
+
+fn alias(name: impl Into<String>, source: &'static str) -> InferredAlias {
+    InferredAlias {
+        alias: name.into(),
+        source,
+    }
+}
+
+/// Whether the marker's key, ignoring any path (`lombok.Getter`,
+/// `napi_derive::napi`), is `key`.
+fn has_key(marker: &AnnotationMarker, key: &str) -> bool {
+    marker
+        .annotation_key
+        .rsplit(['.', ':'])
+        .next()
+        .is_some_and(|last| last.eq_ignore_ascii_case(key))
+}
+
+fn is_callable(symbol: &Symbol) -> bool {
+    matches!(symbol.kind, SymbolKind::Function | SymbolKind::Method)
+}
+
+/// Value of `key = value` in an attribute's argument text, with or without
+/// quotes: `js_name = "fooBar"`, `js_name = fooBar`.
+pub fn named_argument<'a>(args: &'a str, key: &str) -> Option<&'a str> {
+    args.match_indices(key).find_map(|(start, _)| {
+        let before = args[..start].chars().next_back();
+        if before.is_some_and(|c| c.is_alphanumeric() || c == '_') {
+            return None;
+        }
+        let rest = args[start + key.len()..].trim_start().strip_prefix('=')?;
+        let rest = rest.trim_start();
+        let value = match rest.strip_prefix('"') {
+            Some(quoted) => &quoted[..quoted.find('"')?],
+            None => {
+                let end = rest
+                    .find(|c: char| !(c.is_alphanumeric() || c == '_' || c == '$'))
+                    .unwrap_or(rest.len());
+                &rest[..end]
+            }
+        };
+        (!value.is_empty()).then_some(value)
+    })
+}
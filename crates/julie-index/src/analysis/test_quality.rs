@@ -621,20 +621,31 @@ pub fn compute_test_quality_metrics(
 
     // Query all test symbols
     let mut stmt = db.conn.prepare(
-        "SELECT id, code_context, metadata, language FROM symbols \
+        "SELECT id, code_context, context_hash, metadata, language FROM symbols \
          WHERE json_extract(metadata, '$.is_test') = 1",
     )?;
 
+    // Bodies are stored compressed; resolve them up front so the loop below
+    // sees plain text either way.
     let rows: Vec<(String, Option<String>, Option<String>, String)> = stmt
         .query_map([], |row| {
             Ok((
                 row.get::<_, String>(0)?,
                 row.get::<_, Option<String>>(1)?,
                 row.get::<_, Option<String>>(2)?,
-                row.get::<_, String>(3)?,
+                row.get::<_, Option<String>>(3)?,
+                row.get::<_, String>(4)?,
             ))
         })?
         .filter_map(|r| r.ok())
+        .map(|(id, code_context, context_hash, metadata, language)| {
+            let code_context = match (code_context, context_hash) {
+                (Some(inline), _) => Some(inline),
+                (None, Some(hash)) => db.get_code_context(&hash).ok().flatten(),
+                (None, None) => None,
+            };
+            (id, code_context, metadata, language)
+        })
         .collect();
 
     debug!(
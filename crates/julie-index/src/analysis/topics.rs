@@ -0,0 +1,480 @@
+//! Topic map: the workspace's declarations grouped into semantic topics
+//! (auth, billing, parsing, ...), for a newcomer asking what the codebase is
+//! about.
+//!
+//! Two signals are combined. The stored embeddings decide which symbols
+//! belong together: spherical k-means (cosine similarity, k-means++ seeding
+//! from a fixed seed, so the same index gives the same map). The symbols'
+//! identifiers name each group: the words of their names and files, ranked
+//! by TF-IDF across topics, so a topic is named by what sets it apart and
+//! not by words every topic shares.
+
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+use julie_core::database::{SymbolDatabase, TopicCandidate};
+
+use crate::search::tokenizer::{split_camel_case, split_snake_case};
+
+/// Fewest and most topics chosen when [`TopicMapOptions::topics`] is unset.
+const MIN_AUTO_TOPICS: usize = 2;
+const MAX_AUTO_TOPICS: usize = 16;
+/// k-means rounds before giving up on convergence.
+const MAX_ITERATIONS: usize = 30;
+/// Words naming a topic.
+const LABEL_TERMS: usize = 3;
+/// Directories listed per topic.
+const TOPIC_DIRECTORIES: usize = 3;
+/// Fixed k-means++ seed.
+const SEED: u64 = 0x9e37_79b9_7f4a_7c15;
+
+/// Words too common in code to tell topics apart.
+const GENERIC_TERMS: &[&str] = &[
+    "all", "and", "any", "base", "core", "data", "default", "for", "from", "get", "has", "impl",
+    "index", "info", "init", "into", "item", "lib", "main", "mod", "new", "none", "not", "set",
+    "src", "the", "this", "type", "util", "utils", "value", "with",
+];
+
+/// File stems that say nothing about their file; the directory name is used
+/// instead.
+const GENERIC_STEMS: &[&str] = &["mod", "index", "lib", "main", "__init__", "init", "types"];
+
+/// Options for [`generate_topic_map`].
+#[derive(Debug, Clone)]
+pub struct TopicMapOptions {
+    /// Workspace-relative file or directory to scope the map to.
+    pub focus_path: Option<String>,
+    /// Number of topics; `None` picks one from the number of symbols.
+    pub topics: Option<usize>,
+    /// Embedded symbols clustered; larger workspaces are sampled evenly
+    /// across files.
+    pub max_symbols: usize,
+    /// Groups smaller than this are not reported as topics.
+    pub min_topic_size: usize,
+    /// Symbols listed per topic, closest to its center first.
+    pub sample_symbols: usize,
+}
+
+impl Default for TopicMapOptions {
+    fn default() -> Self {
+        Self {
+            focus_path: None,
+            topics: None,
+            max_symbols: 5000,
+            min_topic_size: 3,
+            sample_symbols: 5,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct TopicMapReport {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub focus_path: Option<String>,
+    /// Embedded declarations considered.
+    pub candidates: usize,
+    /// Symbols clustered (`candidates`, or the sample of them).
+    pub clustered: usize,
+    /// Clustered symbols in groups below the minimum topic size.
+    pub unassigned: usize,
+    /// Topics, largest first.
+    pub topics: Vec<Topic>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Topic {
+    /// The top terms joined, e.g. `auth / token / session`.
+    pub label: String,
+    /// Terms by TF-IDF weight, best first.
+    pub terms: Vec<String>,
+    pub size: usize,
+    /// Directories holding most of the topic's symbols, with counts.
+    pub directories: Vec<(String, usize)>,
+    /// Symbols closest to the topic's center.
+    pub symbols: Vec<TopicSymbol>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct TopicSymbol {
+    pub id: String,
+    pub name: String,
+    pub kind: String,
+    pub file_path: String,
+    pub line: u32,
+}
+
+/// Build the topic map of the embedded declarations under
+/// `options.focus_path`. Empty until the embedding pipeline has run.
+pub fn generate_topic_map(
+    db: &SymbolDatabase,
+    options: &TopicMapOptions,
+) -> Result<TopicMapReport> {
+    let candidates = db.get_topic_candidates(options.focus_path.as_deref())?;
+    build_topic_map(db, candidates, options)
+}
+
+/// Build the topic map of `candidates` (see
+/// [`SymbolDatabase::get_topic_candidates`]), after the caller's own
+/// filtering.
+pub fn build_topic_map(
+    db: &SymbolDatabase,
+    candidates: Vec<TopicCandidate>,
+    options: &TopicMapOptions,
+) -> Result<TopicMapReport> {
+    let total = candidates.len();
+    let mut members = Vec::new();
+    let mut vectors = Vec::new();
+    for candidate in sample_evenly(candidates, options.max_symbols) {
+        let Some(vector) = db.get_embedding(&candidate.id)?.and_then(unit) else {
+            continue;
+        };
+        members.push(candidate);
+        vectors.push(vector);
+    }
+
+    let mut report = TopicMapReport {
+        focus_path: options.focus_path.clone(),
+        candidates: total,
+        clustered: members.len(),
+        unassigned: 0,
+        topics: Vec::new(),
+    };
+    let min_size = options.min_topic_size.max(1);
+    if members.len() < min_size {
+        report.unassigned = members.len();
+        return Ok(report);
+    }
+
+    let k = options
+        .topics
+        .unwrap_or_else(|| auto_topic_count(members.len()))
+        .clamp(1, members.len());
+    let (assignments, centroids) = cluster_vectors(&vectors, k);
+
+    let mut groups: Vec<Vec<usize>> = vec![Vec::new(); k];
+    for (index, &cluster) in assignments.iter().enumerate() {
+        groups[cluster].push(index);
+    }
+    let (groups, small): (Vec<_>, Vec<_>) = groups
+        .into_iter()
+        .enumerate()
+        .partition(|(_, group)| group.len() >= min_size);
+    report.unassigned = small.iter().map(|(_, group)| group.len()).sum();
+
+    let member_terms: Vec<HashSet<String>> = members
+        .iter()
+        .map(|member| {
+            identifier_terms(&member.name)
+                .into_iter()
+                .chain(file_terms(&member.file_path))
+                .collect()
+        })
+        .collect();
+    let topic_terms: Vec<Vec<&HashSet<String>>> = groups
+        .iter()
+        .map(|(_, group)| group.iter().map(|&index| &member_terms[index]).collect())
+        .collect();
+    let labels = rank_topic_terms(&topic_terms);
+
+    for ((cluster, group), terms) in groups.into_iter().zip(labels) {
+        let centroid = &centroids[cluster];
+        let mut closest = group.clone();
+        closest.sort_by(|&a, &b| {
+            dot(&vectors[b], centroid)
+                .total_cmp(&dot(&vectors[a], centroid))
+                .then(a.cmp(&b))
+        });
+        let symbols = closest
+            .into_iter()
+            .take(options.sample_symbols)
+            .map(|index| {
+                let member = &members[index];
+                TopicSymbol {
+                    id: member.id.clone(),
+                    name: member.name.clone(),
+                    kind: member.kind.clone(),
+                    file_path: member.file_path.clone(),
+                    line: member.start_line,
+                }
+            })
+            .collect();
+
+        let mut directories: BTreeMap<String, usize> = BTreeMap::new();
+        for &index in &group {
+            *directories
+                .entry(directory_of(&members[index].file_path))
+                .or_default() += 1;
+        }
+        let mut directories: Vec<(String, usize)> = directories.into_iter().collect();
+        directories.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+        directories.truncate(TOPIC_DIRECTORIES);
+
+        let label = if terms.is_empty() {
+            directories
+                .first()
+                .map_or_else(|| "misc".to_string(), |(dir, _)| dir.clone())
+        } else {
+            terms.join(" / ")
+        };
+        report.topics.push(Topic {
+            label,
+            terms,
+            size: group.len(),
+            directories,
+            symbols,
+        });
+    }
+    report
+        .topics
+        .sort_by(|a, b| b.size.cmp(&a.size).then_with(|| a.label.cmp(&b.label)));
+    Ok(report)
+}
+
+/// The square root of a quarter of the symbols (10 topics for 400), within
+/// the auto bounds.
+fn auto_topic_count(symbols: usize) -> usize {
+    ((symbols as f64 / 4.0).sqrt().round() as usize).clamp(MIN_AUTO_TOPICS, MAX_AUTO_TOPICS)
+}
+
+/// At most `max` items spread evenly over `items`, so every part of the
+/// workspace (which is in file order) stays represented.
+fn sample_evenly<T>(items: Vec<T>, max: usize) -> Vec<T> {
+    if items.len() <= max {
+        return items;
+    }
+    let stride = items.len() as f64 / max as f64;
+    let mut next = 0.0_f64;
+    items
+        .into_iter()
+        .enumerate()
+        .filter(|(index, _)| {
+            if (*index as f64) < next {
+                return false;
+            }
+            next += stride;
+            true
+        })
+        .map(|(_, item)| item)
+        .take(max)
+        .collect()
+}
+
+/// Spherical k-means over unit `vectors`: each vector's cluster, and the
+/// unit center of each of the `k` clusters. Deterministic for a given input.
+pub fn cluster_vectors(vectors: &[Vec<f32>], k: usize) -> (Vec<usize>, Vec<Vec<f32>>) {
+    if vectors.is_empty() || k == 0 {
+        return (Vec::new(), Vec::new());
+    }
+    let k = k.min(vectors.len());
+    let mut rng = XorShift(SEED);
+    let mut centroids = seed_centroids(vectors, k, &mut rng);
+    let mut assignments = vec![usize::MAX; vectors.len()];
+
+    for _ in 0..MAX_ITERATIONS {
+        let mut changed = false;
+        for (index, vector) in vectors.iter().enumerate() {
+            let best = nearest(vector, &centroids).0;
+            if assignments[index] != best {
+                assignments[index] = best;
+                changed = true;
+            }
+        }
+        if !changed {
+            break;
+        }
+
+        let dims = vectors[0].len();
+        let mut sums = vec![vec![0.0_f32; dims]; k];
+        let mut counts = vec![0_usize; k];
+        for (vector, &cluster) in vectors.iter().zip(&assignments) {
+            counts[cluster] += 1;
+            for (sum, value) in sums[cluster].iter_mut().zip(vector) {
+                *sum += value;
+            }
+        }
+        for cluster in 0..k {
+            centroids[cluster] = if counts[cluster] == 0 {
+                // An emptied cluster restarts at the worst-fitting vector.
+                let worst = (0..vectors.len())
+                    .min_by(|&a, &b| {
+                        dot(&vectors[a], &centroids[assignments[a]])
+                            .total_cmp(&dot(&vectors[b], &centroids[assignments[b]]))
+                    })
+                    .unwrap_or(0);
+                vectors[worst].clone()
+            } else {
+                unit(std::mem::take(&mut sums[cluster]))
+                    .unwrap_or_else(|| centroids[cluster].clone())
+            };
+        }
+    }
+    (assignments, centroids)
+}
+
+/// k-means++: each next center is drawn with probability proportional to its
+/// squared distance from the centers so far.
+fn seed_centroids(vectors: &[Vec<f32>], k: usize, rng: &mut XorShift) -> Vec<Vec<f32>> {
+    let mut centroids = vec![vectors[rng.below(vectors.len())].clone()];
+    while centroids.len() < k {
+        let weights: Vec<f64> = vectors
+            .iter()
+            .map(|vector| {
+                let distance = f64::from(1.0 - nearest(vector, &centroids).1).max(0.0);
+                distance * distance
+            })
+            .collect();
+        let total: f64 = weights.iter().sum();
+        let pick = if total <= f64::EPSILON {
+            // Every vector sits on a center already; any will do.
+            rng.below(vectors.len())
+        } else {
+            let mut target = rng.unit() * total;
+            weights
+                .iter()
+                .position(|&weight| {
+                    target -= weight;
+                    target <= 0.0
+                })
+                .unwrap_or(vectors.len() - 1)
+        };
+        centroids.push(vectors[pick].clone());
+    }
+    centroids
+}
+
+/// Index and cosine similarity of the closest centroid.
+fn nearest(vector: &[f32], centroids: &[Vec<f32>]) -> (usize, f32) {
+    centroids
+        .iter()
+        .enumerate()
+        .map(|(index, centroid)| (index, dot(vector, centroid)))
+        .fold((0, f32::NEG_INFINITY), |best, candidate| {
+            if candidate.1 > best.1 {
+                candidate
+            } else {
+                best
+            }
+        })
+}
+
+fn dot(a: &[f32], b: &[f32]) -> f32 {
+    a.iter().zip(b).map(|(x, y)| x * y).sum()
+}
+
+/// `vector` scaled to unit length, or `None` for a zero vector.
+fn unit(mut vector: Vec<f32>) -> Option<Vec<f32>> {
+    let norm = dot(&vector, &vector).sqrt();
+    if norm <= f32::EPSILON {
+        return None;
+    }
+    vector.iter_mut().for_each(|value| *value /= norm);
+    Some(vector)
+}
+
+/// Small deterministic generator for k-means++ seeding.
+struct XorShift(u64);
+
+impl XorShift {
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    /// Uniform in `[0, 1)`.
+    fn unit(&mut self) -> f64 {
+        (self.next() >> 11) as f64 / (1_u64 << 53) as f64
+    }
+
+    fn below(&mut self, bound: usize) -> usize {
+        (self.next() % bound as u64) as usize
+    }
+}
+
+/// Lowercase words of an identifier (`parseHTTPRequest`, `user_session`),
+/// without generic words, numbers and words under three letters.
+pub fn identifier_terms(identifier: &str) -> Vec<String> {
+    split_snake_case(identifier)
+        .into_iter()
+        .flat_map(|part| part.split(|ch: char| !ch.is_alphanumeric()))
+        .flat_map(split_camel_case)
+        .map(str::to_lowercase)
+        .filter(|word| {
+            word.chars().count() >= 3
+                && !word.chars().all(|ch| ch.is_ascii_digit())
+                && !GENERIC_TERMS.contains(&word.as_str())
+        })
+        .collect()
+}
+
+/// Words of the file's stem, or of its directory when the stem is generic
+/// (`auth/mod.rs` is about auth).
+fn file_terms(file_path: &str) -> Vec<String> {
+    let mut segments = file_path.rsplit('/');
+    let file = segments.next().unwrap_or(file_path);
+    let stem = file.split('.').next().unwrap_or(file);
+    let name = if GENERIC_STEMS.contains(&stem) {
+        segments.next().unwrap_or(stem)
+    } else {
+        stem
+    };
+    identifier_terms(name)
+}
+
+fn directory_of(file_path: &str) -> String {
+    file_path
+        .rsplit_once('/')
+        .map_or_else(|| ".".to_string(), |(dir, _)| dir.to_string())
+}
+
+/// The best [`LABEL_TERMS`] terms of each topic, by TF-IDF. A topic is a
+/// document of its members' term sets: term frequency is the share of
+/// members using the term, inverse document frequency discounts terms many
+/// topics use. A term has to be used by two members (one in a topic of two)
+/// to count, so one odd name does not name a topic.
+pub fn rank_topic_terms(topics: &[Vec<&HashSet<String>>]) -> Vec<Vec<String>> {
+    let counts: Vec<HashMap<&str, usize>> = topics
+        .iter()
+        .map(|members| {
+            let mut counts: HashMap<&str, usize> = HashMap::new();
+            for terms in members {
+                for term in terms.iter() {
+                    *counts.entry(term.as_str()).or_default() += 1;
+                }
+            }
+            counts
+        })
+        .collect();
+    let mut document_frequency: HashMap<&str, usize> = HashMap::new();
+    for topic in &counts {
+        for term in topic.keys() {
+            *document_frequency.entry(*term).or_default() += 1;
+        }
+    }
+
+    let topic_count = topics.len() as f64;
+    topics
+        .iter()
+        .zip(&counts)
+        .map(|(members, counts)| {
+            let min_count = members.len().min(2);
+            let mut scored: Vec<(f64, &str)> = counts
+                .iter()
+                .filter(|(_, count)| **count >= min_count)
+                .map(|(term, count)| {
+                    let tf = *count as f64 / members.len() as f64;
+                    let idf = (topic_count / document_frequency[term] as f64).ln() + 1.0;
+                    (tf * idf, *term)
+                })
+                .collect();
+            scored.sort_by(|a, b| b.0.total_cmp(&a.0).then_with(|| a.1.cmp(b.1)));
+            scored
+                .into_iter()
+                .take(LABEL_TERMS)
+                .map(|(_, term)| term.to_string())
+                .collect()
+        })
+        .collect()
+}
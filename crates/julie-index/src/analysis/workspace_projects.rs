@@ -0,0 +1,331 @@
+//! Monorepo project map: which package each indexed file belongs to.
+//!
+//! Two packages of a monorepo can both define `UserService`; the project
+//! tells them apart. Projects come from the manifests the indexer already
+//! stores as file content:
+//!
+//! - `Cargo.toml`: the `[package]` name. A virtual workspace root (no
+//!   `[package]`) declares nothing; its members have their own manifests.
+//! - `package.json`: the top-level `name`. npm/yarn/pnpm workspace members
+//!   each carry their own `package.json`.
+//! - `go.mod`: the `module` path.
+//! - `*.csproj` / `*.fsproj` / `*.vbproj`: `<AssemblyName>`, else the file
+//!   stem.
+//! - `*.sln`: every project entry, rooted at its project file, so solutions
+//!   whose project files are not indexed still map their directories.
+//!
+//! Git repositories nested in the workspace (submodules and plain clones,
+//! found on disk by indexing) are projects too, named after their directory
+//! and rooted there, so a vendored repository never counts as part of the
+//! package around it. A manifest in the repository's own root names the
+//! project instead.
+//!
+//! A project owns the directory of its manifest. Each file belongs to the
+//! project with the deepest directory containing it; when two manifests share
+//! a directory, the order above decides. The result lands in
+//! `project_files`, where tools label results with their project and filter
+//! on it.
+
+use anyhow::Result;
+use tracing::info;
+
+use julie_core::database::{ProjectFile, SymbolDatabase};
+
+/// Summary stats from building the project map.
+#[derive(Debug, Clone, Default)]
+pub struct ProjectStats {
+    /// Manifests parsed.
+    pub manifests: usize,
+    /// Projects declared across all manifests.
+    pub projects: usize,
+    /// Files assigned to a project.
+    pub files: usize,
+}
+
+/// A project declared by one manifest.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DeclaredProject {
+    /// Package name as declared.
+    pub name: String,
+    /// Workspace-relative directory the project owns (`""` for the root).
+    pub root: String,
+    /// Manifest that declares the project.
+    pub manifest_path: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum ManifestKind {
+    Cargo,
+    GoMod,
+    DotnetProject,
+    Npm,
+    Solution,
+}
+
+impl ManifestKind {
+    fn detect(path: &str) -> Option<Self> {
+        let name = file_name(path);
+        let lower = name.to_ascii_lowercase();
+        match lower.as_str() {
+            "cargo.toml" => Some(Self::Cargo),
+            "package.json" => Some(Self::Npm),
+            "go.mod" => Some(Self::GoMod),
+            _ if is_dotnet_project(&lower) => Some(Self::DotnetProject),
+            _ if lower.ends_with(".sln") => Some(Self::Solution),
+            _ => None,
+        }
+    }
+}
+
+/// Rebuild the project map from every indexed manifest.
+///
+/// Runs alongside `compute_swift_modules()` in the indexing pipeline.
+/// Replaces the whole `project_files` table, so it is safe to run repeatedly.
+pub fn compute_workspace_projects(db: &SymbolDatabase) -> Result<ProjectStats> {
+    let mut files = Vec::new();
+    let mut manifests = Vec::new();
+    {
+        let mut stmt = db.conn.prepare("SELECT path FROM files")?;
+        let rows = stmt.query_map([], |row| row.get::<_, String>(0))?;
+        for row in rows {
+            let path = row?;
+            if let Some(kind) = ManifestKind::detect(&path) {
+                manifests.push((kind, path.clone()));
+            }
+            files.push(path);
+        }
+    }
+
+    let repositories = db.nested_repositories()?;
+    let mut stats = ProjectStats::default();
+    if manifests.is_empty() && repositories.is_empty() {
+        db.replace_all_project_files(&[])?;
+        return Ok(stats);
+    }
+    manifests.sort();
+
+    let paths: Vec<String> = manifests.iter().map(|(_, path)| path.clone()).collect();
+    let contents = db.get_file_contents_by_paths(&paths)?;
+    let mut projects = Vec::new();
+    for (_, manifest) in &manifests {
+        let Some(Some(content)) = contents.get(manifest) else {
+            continue;
+        };
+        stats.manifests += 1;
+        projects.extend(parse_project_manifest(manifest, content));
+    }
+    // After the manifests, so a manifest in the same directory wins the tie.
+    projects.extend(
+        repositories
+            .iter()
+            .map(|repository| repository_project(&repository.root)),
+    );
+    stats.projects = projects.len();
+
+    let rows = assign_projects(&files, &projects);
+    stats.files = rows.len();
+    db.replace_all_project_files(&rows)?;
+    info!(
+        "Project map: {} files in {} projects from {} manifests",
+        stats.files, stats.projects, stats.manifests
+    );
+    Ok(stats)
+}
+
+/// Projects declared by the manifest at `path`. Unrecognized files and
+/// manifests without a name declare nothing.
+pub fn parse_project_manifest(path: &str, content: &str) -> Vec<DeclaredProject> {
+    let root = parent_dir(path);
+    let declared = |name: String| DeclaredProject {
+        name,
+        root: root.to_string(),
+        manifest_path: path.to_string(),
+    };
+    match ManifestKind::detect(path) {
+        Some(ManifestKind::Cargo) => cargo_package_name(content)
+            .map(declared)
+            .into_iter()
+            .collect(),
+        Some(ManifestKind::Npm) => npm_package_name(content)
+            .map(declared)
+            .into_iter()
+            .collect(),
+        Some(ManifestKind::GoMod) => go_module_path(content).map(declared).into_iter().collect(),
+        Some(ManifestKind::DotnetProject) => {
+            let name = xml_element_text(content, "AssemblyName")
+                .unwrap_or_else(|| file_stem(path).to_string());
+            vec![declared(name)]
+        }
+        Some(ManifestKind::Solution) => solution_projects(path, content),
+        None => Vec::new(),
+    }
+}
+
+/// The project of a nested git repository rooted at `root`.
+pub fn repository_project(root: &str) -> DeclaredProject {
+    DeclaredProject {
+        name: file_name(root).to_string(),
+        root: root.to_string(),
+        manifest_path: format!("{root}/.git"),
+    }
+}
+
+/// Pick each file's project: the deepest project directory containing it.
+/// `projects` earlier in the slice win ties on the same directory.
+pub fn assign_projects(files: &[String], projects: &[DeclaredProject]) -> Vec<ProjectFile> {
+    let mut rows = Vec::new();
+    for file in files {
+        let mut best: Option<&DeclaredProject> = None;
+        for project in projects {
+            if !path_is_under(file, &project.root) {
+                continue;
+            }
+            if best.is_none_or(|current| project.root.len() > current.root.len()) {
+                best = Some(project);
+            }
+        }
+        if let Some(project) = best {
+            rows.push(ProjectFile {
+                file_path: file.clone(),
+                project: project.name.clone(),
+                manifest_path: project.manifest_path.clone(),
+            });
+        }
+    }
+    rows
+}
+
+// ---------------------------------------------------------------------------
+// Manifest parsing
+// ---------------------------------------------------------------------------
+
+/// `name` of the `[package]` table.
+fn cargo_package_name(content: &str) -> Option<String> {
+    let mut in_package = false;
+    for line in content.lines() {
+        let line = line.split('#').next().unwrap_or("").trim();
+        if line.starts_with('[') {
+            in_package = line == "[package]";
+            continue;
+        }
+        if !in_package {
+            continue;
+        }
+        if let Some((key, value)) = line.split_once('=')
+            && key.trim() == "name"
+        {
+            return quoted(value.trim()).map(str::to_string);
+        }
+    }
+    None
+}
+
+fn npm_package_name(content: &str) -> Option<String> {
+    let value: serde_json::Value = serde_json::from_str(content).ok()?;
+    let name = value.get("name")?.as_str()?.trim();
+    (!name.is_empty()).then(|| name.to_string())
+}
+
+fn go_module_path(content: &str) -> Option<String> {
+    content.lines().find_map(|line| {
+        let rest = line.trim().strip_prefix("module")?;
+        if !rest.starts_with(char::is_whitespace) {
+            return None;
+        }
+        let module = rest.split("//").next().unwrap_or("").trim();
+        let module = quoted(module).unwrap_or(module);
+        (!module.is_empty()).then(|| module.to_string())
+    })
+}
+
+/// `Project("{type}") = "Name", "dir\Name.csproj", "{guid}"` entries.
+/// Solution folders, whose "path" is not a project file, are skipped.
+fn solution_projects(path: &str, content: &str) -> Vec<DeclaredProject> {
+    let solution_dir = parent_dir(path);
+    let mut projects = Vec::new();
+    for line in content.lines() {
+        let Some(rest) = line.trim().strip_prefix("Project(") else {
+            continue;
+        };
+        let Some((_, entry)) = rest.split_once('=') else {
+            continue;
+        };
+        let fields: Vec<&str> = entry
+            .split(',')
+            .filter_map(|field| quoted(field.trim()))
+            .collect();
+        let [name, project_path, ..] = fields.as_slice() else {
+            continue;
+        };
+        let project_path = project_path.replace('\\', "/");
+        if name.is_empty() || !is_dotnet_project(&project_path.to_ascii_lowercase()) {
+            continue;
+        }
+        let project_file = join_path(solution_dir, &project_path);
+        projects.push(DeclaredProject {
+            name: name.to_string(),
+            root: parent_dir(&project_file).to_string(),
+            manifest_path: path.to_string(),
+        });
+    }
+    projects
+}
+
+fn xml_element_text(content: &str, tag: &str) -> Option<String> {
+    let open = format!("<{tag}>");
+    let start = content.find(&open)? + open.len();
+    let end = content[start..].find(&format!("</{tag}>"))?;
+    let text = content[start..start + end].trim();
+    // `$(MSBuildProjectName)` and friends are left to the file-stem default.
+    (!text.is_empty() && !text.contains("$(")).then(|| text.to_string())
+}
+
+fn quoted(value: &str) -> Option<&str> {
+    let value = value.strip_prefix('"')?;
+    value.split_once('"').map(|(inner, _)| inner)
+}
+
+// ---------------------------------------------------------------------------
+// Paths
+// ---------------------------------------------------------------------------
+
+fn is_dotnet_project(lower_path: &str) -> bool {
+    [".csproj", ".fsproj", ".vbproj"]
+        .iter()
+        .any(|ext| lower_path.ends_with(ext))
+}
+
+pub(super) fn file_name(path: &str) -> &str {
+    path.rsplit('/').next().unwrap_or(path)
+}
+
+fn file_stem(path: &str) -> &str {
+    let name = file_name(path);
+    name.rsplit_once('.').map_or(name, |(stem, _)| stem)
+}
+
+pub(super) fn parent_dir(path: &str) -> &str {
+    path.rsplit_once('/').map_or("", |(dir, _)| dir)
+}
+
+pub(super) fn path_is_under(path: &str, dir: &str) -> bool {
+    dir.is_empty()
+        || path
+            .strip_prefix(dir)
+            .is_some_and(|rest| rest.starts_with('/'))
+}
+
+/// Join `relative` onto `base` and fold `.` / `..` segments.
+fn join_path(base: &str, relative: &str) -> String {
+    let mut segments: Vec<&str> = Vec::new();
+    for segment in base.split('/').chain(relative.split('/')) {
+        match segment {
+            "" | "." => {}
+            ".." => {
+                segments.pop();
+            }
+            segment => segments.push(segment),
+        }
+    }
+    segments.join("/")
+}
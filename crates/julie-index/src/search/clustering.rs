@@ -0,0 +1,117 @@
+//! Embedding-based clustering over the stored symbol vectors.
+//!
+//! Groups a set of symbols into clusters of semantically similar ones by
+//! running a KNN query per symbol and joining every pair whose similarity
+//! clears a threshold (single-linkage, via union-find). Used by the naming
+//! drift report; anything that needs "which of these symbols do the same
+//! thing" can reuse it.
+
+use anyhow::Result;
+use std::collections::HashMap;
+
+use julie_core::database::SymbolDatabase;
+
+/// Clustering parameters.
+#[derive(Debug, Clone, Copy)]
+pub struct ClusterOptions {
+    /// Minimum similarity (1.0 - cosine_distance) for two symbols to join.
+    pub min_score: f32,
+    /// Nearest neighbours examined per symbol.
+    pub neighbors: usize,
+    /// Clusters larger than this are dropped: single-linkage chains through
+    /// generic helpers, and a huge cluster is not a useful group.
+    pub max_cluster_size: usize,
+}
+
+impl Default for ClusterOptions {
+    fn default() -> Self {
+        Self {
+            min_score: 0.85,
+            neighbors: 8,
+            max_cluster_size: 24,
+        }
+    }
+}
+
+/// Cluster `symbol_ids` by embedding similarity.
+///
+/// Only the given symbols take part: neighbours outside the set are ignored,
+/// and symbols without a stored embedding are skipped. Returns clusters of two
+/// or more ids, each in input order, ordered by their first member.
+pub fn cluster_by_embedding(
+    db: &SymbolDatabase,
+    symbol_ids: &[String],
+    options: ClusterOptions,
+) -> Result<Vec<Vec<String>>> {
+    let index: HashMap<&str, usize> = symbol_ids
+        .iter()
+        .enumerate()
+        .map(|(i, id)| (id.as_str(), i))
+        .collect();
+    let mut sets = DisjointSets::new(symbol_ids.len());
+
+    for (i, id) in symbol_ids.iter().enumerate() {
+        let Some(embedding) = db.get_embedding(id)? else {
+            continue;
+        };
+        for (neighbor, distance) in db.knn_search(&embedding, options.neighbors + 1)? {
+            let score = (1.0 - distance) as f32;
+            if score < options.min_score {
+                continue;
+            }
+            if let Some(&j) = index.get(neighbor.as_str())
+                && j != i
+            {
+                sets.union(i, j);
+            }
+        }
+    }
+
+    let mut clusters: Vec<Vec<String>> = Vec::new();
+    let mut cluster_of_root: HashMap<usize, usize> = HashMap::new();
+    for (i, id) in symbol_ids.iter().enumerate() {
+        let root = sets.find(i);
+        let slot = *cluster_of_root.entry(root).or_insert_with(|| {
+            clusters.push(Vec::new());
+            clusters.len() - 1
+        });
+        clusters[slot].push(id.clone());
+    }
+    clusters.retain(|cluster| cluster.len() >= 2 && cluster.len() <= options.max_cluster_size);
+    Ok(clusters)
+}
+
+/// Union-find with path halving and union by size.
+struct DisjointSets {
+    parent: Vec<usize>,
+    size: Vec<usize>,
+}
+
+impl DisjointSets {
+    fn new(len: usize) -> Self {
+        Self {
+            parent: (0..len).collect(),
+            size: vec![1; len],
+        }
+    }
+
+    fn find(&mut self, mut i: usize) -> usize {
+        while self.parent[i] != i {
+            self.parent[i] = self.parent[self.parent[i]];
+            i = self.parent[i];
+        }
+        i
+    }
+
+    fn union(&mut self, a: usize, b: usize) {
+        let (mut a, mut b) = (self.find(a), self.find(b));
+        if a == b {
+            return;
+        }
+        if self.size[a] < self.size[b] {
+            std::mem::swap(&mut a, &mut b);
+        }
+        self.parent[b] = a;
+        self.size[a] += self.size[b];
+    }
+}
@@ -13,6 +13,8 @@
 //!   Reciprocal Rank Fusion. Formula: `RRF(d) = Σ 1/(k + rank)`.
 //! - `knn_to_search_results`: Converts sqlite-vec KNN output `(symbol_id, distance)`
 //!   into `SymbolSearchResult` objects by looking up symbol metadata from the database.
+//! - `scoped_knn_search`: KNN restricted to and/or boosting a `SemanticLanguageScope`,
+//!   with the language filter applied inside the vector query.
 
 use std::collections::HashMap;
 
@@ -36,6 +38,111 @@ use julie_core::embeddings_contract::EmbeddingProvider;
 /// reranker a comparable working set on both paths.
 const HYBRID_CANDIDATE_OVERFETCH_FACTOR: usize = 4;
 
+/// Distance multiplier for symbols in a boosted language. Scaling the KNN
+/// distance (rather than the fused score) keeps the boost inside the semantic
+/// ranking, so it behaves the same for semantic-only and hybrid search.
+const LANGUAGE_BOOST_DISTANCE_FACTOR: f64 = 0.8;
+
+/// Per-query language scope for the KNN side of semantic and hybrid search.
+///
+/// `languages` restricts candidates to those languages inside the vector
+/// query, so the top-k is filled from matching symbols only.
+/// `boost_languages` excludes nothing; it ranks symbols in those languages
+/// ahead of similarly close symbols in others.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SemanticLanguageScope {
+    pub languages: Vec<String>,
+    pub boost_languages: Vec<String>,
+}
+
+impl SemanticLanguageScope {
+    /// Build a scope from user input: names are trimmed, lowercased to match
+    /// stored languages, and deduplicated; blanks are dropped.
+    pub fn new(languages: &[String], boost_languages: &[String]) -> Self {
+        Self {
+            languages: normalize_languages(languages),
+            boost_languages: normalize_languages(boost_languages),
+        }
+    }
+
+    /// Fold a single-language filter into the scope so it is applied in the
+    /// vector query too. An explicit `languages` list takes precedence; the
+    /// filter still post-filters the results either way.
+    pub fn with_filter_language(mut self, language: Option<&str>) -> Self {
+        if self.languages.is_empty() {
+            self.languages = normalize_languages(language);
+        }
+        self
+    }
+
+    /// True when the scope neither filters nor boosts.
+    pub fn is_unscoped(&self) -> bool {
+        self.languages.is_empty() && self.boost_languages.is_empty()
+    }
+
+    /// Whether a result in `language` passes the `languages` filter.
+    pub fn allows(&self, language: &str) -> bool {
+        self.languages.is_empty() || self.languages.iter().any(|l| l == language)
+    }
+}
+
+fn normalize_languages<I>(languages: I) -> Vec<String>
+where
+    I: IntoIterator,
+    I::Item: AsRef<str>,
+{
+    let mut normalized: Vec<String> = Vec::new();
+    for language in languages {
+        let language = language.as_ref().trim().to_ascii_lowercase();
+        if !language.is_empty() && !normalized.contains(&language) {
+            normalized.push(language);
+        }
+    }
+    normalized
+}
+
+/// KNN over symbol and chunk vectors within a `SemanticLanguageScope`.
+///
+/// The `languages` filter runs inside the vector store query. Boosting runs a
+/// second KNN restricted to the boosted languages and scales those distances
+/// by `LANGUAGE_BOOST_DISTANCE_FACTOR` before merging, so a boosted symbol is
+/// still found when the unboosted top-k is crowded out by other languages.
+/// Returns `(symbol_id, distance)` pairs ordered by ascending distance.
+pub fn scoped_knn_search(
+    db: &SymbolDatabase,
+    query_vector: &[f32],
+    limit: usize,
+    scope: &SemanticLanguageScope,
+) -> Result<Vec<(String, f64)>> {
+    let hits = db.knn_search_with_chunks_in_languages(query_vector, limit, &scope.languages)?;
+
+    let boosted: Vec<String> = scope
+        .boost_languages
+        .iter()
+        .filter(|language| scope.allows(language))
+        .cloned()
+        .collect();
+    // Boosting every allowed language changes nothing.
+    if boosted.is_empty() || boosted.len() == scope.languages.len() {
+        return Ok(hits);
+    }
+
+    // Every boosted-language symbol in `hits` is also in this narrower top-k,
+    // so keeping the minimum applies the boost to it exactly once.
+    let mut best: HashMap<String, f64> = hits.into_iter().collect();
+    for (id, distance) in db.knn_search_with_chunks_in_languages(query_vector, limit, &boosted)? {
+        let distance = distance * LANGUAGE_BOOST_DISTANCE_FACTOR;
+        best.entry(id)
+            .and_modify(|current| *current = current.min(distance))
+            .or_insert(distance);
+    }
+
+    let mut merged: Vec<(String, f64)> = best.into_iter().collect();
+    merged.sort_by(|(a_id, a), (b_id, b)| a.total_cmp(b).then_with(|| a_id.cmp(b_id)));
+    merged.truncate(limit);
+    Ok(merged)
+}
+
 /// Merge two ranked lists of search results using Reciprocal Rank Fusion.
 ///
 /// # Arguments
@@ -314,6 +421,8 @@ fn matches_filter(result: &SymbolSearchResult, filter: &SearchFilter) -> bool {
 }
 
 /// Internal: run the semantic search pipeline (embed → KNN → convert).
+/// KNN covers body-chunk vectors, so long functions match on any part of
+/// their body.
 ///
 /// Separated from `hybrid_search` so the orchestrator can catch errors from
 /// any step in a single `match`.
@@ -324,7 +433,7 @@ fn run_semantic_search(
     provider: &dyn EmbeddingProvider,
 ) -> Result<Vec<SymbolSearchResult>> {
     let query_vector = provider.embed_query(query)?;
-    let knn_hits = db.knn_search(&query_vector, limit)?;
+    let knn_hits = db.knn_search_with_chunks(&query_vector, limit)?;
     knn_to_search_results(&knn_hits, db)
 }
 
@@ -380,6 +489,37 @@ pub fn hybrid_search_with_embedding(
     query_embedding: Option<Vec<f32>>,
     weight_profile: Option<SearchWeightProfile>,
 ) -> Result<SymbolSearchResults> {
+    hybrid_search_with_embedding_scoped(
+        query,
+        filter,
+        limit,
+        search_index,
+        db,
+        query_embedding,
+        weight_profile,
+        &SemanticLanguageScope::default(),
+    )
+}
+
+/// [`hybrid_search_with_embedding`] with a per-query language scope.
+///
+/// The KNN side runs through [`scoped_knn_search`]; keyword results outside
+/// `scope.languages` are dropped so both sides of the merge agree.
+#[allow(clippy::too_many_arguments)]
+pub fn hybrid_search_with_embedding_scoped(
+    query: &str,
+    filter: &SearchFilter,
+    limit: usize,
+    search_index: &SearchIndex,
+    db: &SymbolDatabase,
+    query_embedding: Option<Vec<f32>>,
+    weight_profile: Option<SearchWeightProfile>,
+    scope: &SemanticLanguageScope,
+) -> Result<SymbolSearchResults> {
+    let scope = scope
+        .clone()
+        .with_filter_language(filter.language.as_deref());
+
     // Step 1: Tantivy keyword search (always runs).
     // Over-fetch when we have a semantic candidate pool to merge with.
     let tantivy_limit = if query_embedding.is_some() {
@@ -387,7 +527,11 @@ pub fn hybrid_search_with_embedding(
     } else {
         limit
     };
-    let tantivy_results = search_index.search_symbols_via_unified(query, filter, tantivy_limit)?;
+    let mut tantivy_results =
+        search_index.search_symbols_via_unified(query, filter, tantivy_limit)?;
+    tantivy_results
+        .results
+        .retain(|result| scope.allows(&result.language));
 
     // Step 2: If no pre-computed embedding, return keyword results directly.
     let vec = match query_embedding {
@@ -397,7 +541,7 @@ pub fn hybrid_search_with_embedding(
 
     // Step 3: KNN search against SQLite — no sidecar I/O, just a vector scan.
     let knn_limit = limit.saturating_mul(HYBRID_CANDIDATE_OVERFETCH_FACTOR);
-    let semantic_results = match db.knn_search(&vec, knn_limit) {
+    let semantic_results = match scoped_knn_search(db, &vec, knn_limit, &scope) {
         Ok(hits) => match knn_to_search_results(&hits, db) {
             Ok(results) => results,
             Err(e) => {
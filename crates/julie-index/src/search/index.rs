@@ -7,13 +7,16 @@
 //! The `CodeTokenizer` handles CamelCase/snake_case splitting so that
 //! searching "user" finds both `getUserData` and `get_user_data`.
 
+mod backup;
 mod compatibility;
+pub(crate) mod in_memory;
 mod lifecycle;
 mod mutation;
 mod query;
 mod types;
 
 pub use compatibility::SEARCH_COMPAT_MARKER_FILE;
+pub use in_memory::IN_MEMORY_MARKER_FILE;
 pub use lifecycle::{SearchIndexOpenDisposition, SearchIndexOpenOutcome};
 #[cfg(any(test, feature = "test-support"))]
 #[allow(unused_imports)]
@@ -0,0 +1,97 @@
+//! Online snapshot of a live index.
+//!
+//! Committed Tantivy segment files are immutable, so a consistent copy is the
+//! files of the segments listed in one `meta.json` plus that `meta.json`.
+//! Commits and merges only add new segment files and later garbage-collect
+//! the unreferenced ones; if a listed file disappears mid-copy, the copy
+//! starts over against the newer meta.
+
+use std::path::Path;
+
+use tantivy::Directory;
+use tantivy::directory::error::OpenReadError;
+
+use super::SearchIndex;
+use super::compatibility::SEARCH_COMPAT_MARKER_FILE;
+use crate::search::error::{Result, SearchError};
+
+/// Restarts allowed when a merge garbage-collects a segment mid-copy.
+const SNAPSHOT_ATTEMPTS: usize = 3;
+
+impl SearchIndex {
+    /// Copy the last committed state of this index into `dest` without
+    /// pausing readers or writers. Uncommitted documents are not included.
+    ///
+    /// `dest` must not exist. Returns the number of bytes copied. Indexes
+    /// kept in memory because an index key is set are never copied out.
+    pub fn snapshot_to(&self, dest: &Path) -> Result<u64> {
+        if super::in_memory::enabled() {
+            return Err(SearchError::IndexError(
+                "the index is kept in memory while an index key is set".to_string(),
+            ));
+        }
+        if dest.exists() {
+            return Err(SearchError::IndexError(format!(
+                "snapshot target already exists: {}",
+                dest.display()
+            )));
+        }
+
+        for attempt in 1..=SNAPSHOT_ATTEMPTS {
+            std::fs::create_dir_all(dest)?;
+            match self.copy_committed_files(dest) {
+                Ok(bytes) => return Ok(bytes),
+                Err(SearchError::TantivyError(tantivy::TantivyError::OpenReadError(
+                    OpenReadError::FileDoesNotExist(path),
+                ))) if attempt < SNAPSHOT_ATTEMPTS => {
+                    tracing::debug!(
+                        "segment file {} was collected during snapshot; retrying",
+                        path.display()
+                    );
+                    std::fs::remove_dir_all(dest)?;
+                }
+                Err(err) => {
+                    let _ = std::fs::remove_dir_all(dest);
+                    return Err(err);
+                }
+            }
+        }
+        unreachable!("the last attempt always returns")
+    }
+
+    fn copy_committed_files(&self, dest: &Path) -> Result<u64> {
+        let directory = self.index.directory();
+        let metas = self.index.load_metas()?;
+        let mut bytes = 0u64;
+
+        for segment in &metas.segments {
+            for file in segment.list_files() {
+                let data = directory
+                    .open_read(&file)
+                    .map_err(tantivy::TantivyError::from)?
+                    .read_bytes()?;
+                std::fs::write(dest.join(&file), data.as_slice())?;
+                bytes += data.len() as u64;
+            }
+        }
+
+        match directory.atomic_read(Path::new(SEARCH_COMPAT_MARKER_FILE)) {
+            Ok(marker) => {
+                std::fs::write(dest.join(SEARCH_COMPAT_MARKER_FILE), &marker)?;
+                bytes += marker.len() as u64;
+            }
+            Err(OpenReadError::FileDoesNotExist(_)) => {}
+            Err(err) => return Err(tantivy::TantivyError::from(err).into()),
+        }
+
+        // Written last: the copy only becomes openable once every segment it
+        // names is in place.
+        let mut meta_json = serde_json::to_vec_pretty(&metas)
+            .map_err(|e| SearchError::IndexError(format!("failed to serialize meta.json: {e}")))?;
+        meta_json.push(b'\n');
+        std::fs::write(dest.join("meta.json"), &meta_json)?;
+        bytes += meta_json.len() as u64;
+
+        Ok(bytes)
+    }
+}
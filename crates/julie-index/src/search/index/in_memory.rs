@@ -0,0 +1,87 @@
+//! Search indexes that never touch the disk.
+//!
+//! Tantivy segments hold symbol names, signatures and file content in plain
+//! files. While an index key is configured
+//! ([`julie_core::database::encryption::index_key_configured`]) the index of
+//! a path is created in a RAM directory instead, shared by every open of that
+//! path in this process, and rebuilt from the encrypted database the first
+//! time it is opened.
+//!
+//! The index directory itself only holds [`IN_MEMORY_MARKER_FILE`], which
+//! carries no data: deleting the directory (workspace removal, a forced
+//! reindex) drops the marker, and the next open starts from an empty index
+//! again, as it would on disk.
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, OnceLock};
+
+use tantivy::Index;
+use tantivy::schema::Schema;
+
+use super::lifecycle::SearchIndexOpenDisposition;
+use crate::search::error::Result;
+
+/// Written in place of the segment files of an in-memory index.
+pub const IN_MEMORY_MARKER_FILE: &str = "in-memory";
+
+fn indexes() -> &'static Mutex<HashMap<PathBuf, Index>> {
+    static INDEXES: OnceLock<Mutex<HashMap<PathBuf, Index>>> = OnceLock::new();
+    INDEXES.get_or_init(Default::default)
+}
+
+/// Whether indexes are kept in memory in this process.
+pub(crate) fn enabled() -> bool {
+    julie_core::database::encryption::index_key_configured()
+}
+
+/// Whether an in-memory index is open for `path`.
+pub(crate) fn exists(path: &Path) -> bool {
+    path.join(IN_MEMORY_MARKER_FILE).exists()
+        && indexes()
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .contains_key(path)
+}
+
+/// The in-memory index of `path`, created empty when there is none yet.
+/// A new index reports [`SearchIndexOpenDisposition::CreatedInMemory`] so
+/// the caller rebuilds it from the database.
+pub(crate) fn open_or_create(
+    path: &Path,
+    schema: &Schema,
+) -> Result<(Index, SearchIndexOpenDisposition)> {
+    let mut indexes = indexes()
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    let marker = path.join(IN_MEMORY_MARKER_FILE);
+    if marker.exists()
+        && let Some(index) = indexes.get(path)
+    {
+        return Ok((index.clone(), SearchIndexOpenDisposition::Compatible));
+    }
+
+    // Segments written before the key was set are plaintext; drop them.
+    if path.join("meta.json").exists() {
+        tracing::warn!(
+            "Removing the unencrypted Tantivy index at {}; it is kept in memory while an index key is set",
+            path.display()
+        );
+        std::fs::remove_dir_all(path)?;
+    }
+    std::fs::create_dir_all(path)?;
+    std::fs::write(&marker, b"")?;
+
+    let index = Index::create_in_ram(schema.clone());
+    indexes.insert(path.to_path_buf(), index.clone());
+    Ok((index, SearchIndexOpenDisposition::CreatedInMemory))
+}
+
+/// A new, empty in-memory index for `path`, replacing any open one.
+pub(crate) fn create(path: &Path, schema: &Schema) -> Result<Index> {
+    indexes()
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .remove(path);
+    open_or_create(path, schema).map(|(index, _)| index)
+}
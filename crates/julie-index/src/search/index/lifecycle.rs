@@ -6,6 +6,7 @@ use tantivy::Index;
 use tantivy::tokenizer::TextAnalyzer;
 
 use super::SearchIndex;
+use super::in_memory;
 use crate::search::error::{Result, SearchError};
 use crate::search::language_config::LanguageConfigs;
 use crate::search::schema::{SchemaFields, create_schema};
@@ -16,6 +17,9 @@ pub enum SearchIndexOpenDisposition {
     Compatible,
     RecreatedIncompatible,
     RecreatedOpenFailure,
+    /// Created empty in memory because an index key is set (see
+    /// `in_memory`); rebuilt from the database like a recreated index.
+    CreatedInMemory,
 }
 
 impl SearchIndexOpenDisposition {
@@ -40,6 +44,15 @@ impl SearchIndexOpenOutcome {
 }
 
 impl SearchIndex {
+    /// Whether an index exists at `path` to be opened.
+    pub fn exists(path: &Path) -> bool {
+        if in_memory::enabled() {
+            in_memory::exists(path)
+        } else {
+            path.join("meta.json").exists()
+        }
+    }
+
     /// Create a new index at the given directory path using default patterns.
     pub fn create(path: &Path) -> Result<Self> {
         let tokenizer = CodeTokenizer::with_default_patterns();
@@ -54,7 +67,7 @@ impl SearchIndex {
 
     /// Open an existing index at the given directory path.
     pub fn open(path: &Path) -> Result<Self> {
-        if !path.join("meta.json").exists() {
+        if !Self::exists(path) {
             return Err(SearchError::IndexNotFound(path.display().to_string()));
         }
         let tokenizer = CodeTokenizer::with_default_patterns();
@@ -63,7 +76,7 @@ impl SearchIndex {
 
     /// Open an existing index with language-specific tokenizer patterns.
     pub fn open_with_language_configs(path: &Path, configs: &LanguageConfigs) -> Result<Self> {
-        if !path.join("meta.json").exists() {
+        if !Self::exists(path) {
             return Err(SearchError::IndexNotFound(path.display().to_string()));
         }
         let tokenizer = CodeTokenizer::from_language_configs(configs);
@@ -75,7 +88,7 @@ impl SearchIndex {
         path: &Path,
         configs: &LanguageConfigs,
     ) -> Result<SearchIndexOpenOutcome> {
-        if !path.join("meta.json").exists() {
+        if !Self::exists(path) {
             return Err(SearchError::IndexNotFound(path.display().to_string()));
         }
         let tokenizer = CodeTokenizer::from_language_configs(configs);
@@ -114,6 +127,12 @@ impl SearchIndex {
         language_configs: Option<LanguageConfigs>,
     ) -> Result<SearchIndexOpenOutcome> {
         let expected_schema = create_schema();
+        if in_memory::enabled() {
+            let (index, disposition) = in_memory::open_or_create(path, &expected_schema)?;
+            let index =
+                Self::build_search_index(index, &expected_schema, tokenizer, language_configs)?;
+            return Ok(SearchIndexOpenOutcome { index, disposition });
+        }
         let expected_marker = Self::expected_compat_marker(&expected_schema, &tokenizer);
 
         let (index, disposition) = if path.join("meta.json").exists() {
@@ -176,6 +195,10 @@ impl SearchIndex {
         language_configs: Option<LanguageConfigs>,
     ) -> Result<Self> {
         let schema = create_schema();
+        if in_memory::enabled() {
+            let index = in_memory::create(path, &schema)?;
+            return Self::build_search_index(index, &schema, tokenizer, language_configs);
+        }
         let expected_marker = Self::expected_compat_marker(&schema, &tokenizer);
         let index = Index::create_in_dir(path, schema.clone())?;
         Self::write_compat_marker(path, &expected_marker)?;
@@ -188,6 +211,12 @@ impl SearchIndex {
         language_configs: Option<LanguageConfigs>,
     ) -> Result<SearchIndexOpenOutcome> {
         let expected_schema = create_schema();
+        if in_memory::enabled() {
+            let (index, disposition) = in_memory::open_or_create(path, &expected_schema)?;
+            let index =
+                Self::build_search_index(index, &expected_schema, tokenizer, language_configs)?;
+            return Ok(SearchIndexOpenOutcome { index, disposition });
+        }
         let expected_marker = Self::expected_compat_marker(&expected_schema, &tokenizer);
 
         let (index, disposition) = match Index::open_in_dir(path) {
@@ -291,6 +291,13 @@ impl SearchIndex {
                     } else {
                         hit.role.clone()
                     };
+                    // `include_generated` ranks generated code like source
+                    // by skipping the reranker's generated-role demotion.
+                    let role = if filter.include_generated && role == "generated" {
+                        "source".to_string()
+                    } else {
+                        role
+                    };
                     let test_role = if hit.test_role.is_empty() {
                         test_subrole(&hit.file_path).to_string()
                     } else {
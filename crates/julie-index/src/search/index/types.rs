@@ -265,6 +265,8 @@ pub struct SearchFilter {
     pub kind: Option<String>,
     pub file_pattern: Option<String>,
     pub exclude_tests: bool,
+    /// Rank generated code like hand-written code instead of demoting it.
+    pub include_generated: bool,
 }
 
 impl SearchFilter {
@@ -299,7 +301,8 @@ pub(crate) fn is_test_symbol_result(file_path: &str, role: &str) -> bool {
     is_test_path(file_path) || role == "test"
 }
 
-/// Project search role fields from path classification plus extractor test metadata.
+/// Project search role fields from path classification plus extractor test
+/// and generated-code metadata.
 pub(crate) fn symbol_role_and_test_role(
     file_path: &str,
     language: &str,
@@ -319,14 +322,18 @@ pub(crate) fn symbol_role_and_test_role(
         .filter(|s| !s.is_empty())
         .map(str::to_string);
 
-    if metadata_is_test && path_role != "test" {
-        let test_role = metadata_test_role.unwrap_or_else(|| path_test_role.to_string());
+    let metadata_is_generated = metadata
+        .and_then(|m| m.get("is_generated"))
+        .and_then(|v| v.as_bool())
+        .unwrap_or(false);
+
+    let test_role = metadata_test_role.unwrap_or_else(|| path_test_role.to_string());
+    if metadata_is_generated && path_role != "vendor" {
+        ("generated".to_string(), test_role)
+    } else if metadata_is_test && path_role != "test" {
         ("test".to_string(), test_role)
     } else {
-        (
-            path_role.to_string(),
-            metadata_test_role.unwrap_or_else(|| path_test_role.to_string()),
-        )
+        (path_role.to_string(), test_role)
     }
 }
 
@@ -7,8 +7,8 @@ use super::facts_text::{
     truncate_to_whitespace_boundary,
 };
 use crate::search::SearchIndex;
-use crate::search::index::{SearchDocument, truncate_utf8_bytes};
-use crate::search::scoring::{classify_role, test_subrole};
+use crate::search::index::{SearchDocument, symbol_role_and_test_role, truncate_utf8_bytes};
+use crate::search::scoring::{classify_file_role, test_subrole};
 use crate::search::tokenizer::pretokenize_code;
 use julie_core::database::{FileInfo, SymbolDatabase};
 use julie_extractors::{AnnotationMarker, Symbol};
@@ -304,7 +304,7 @@ fn file_info_to_search_document(file_info: &FileInfo) -> SearchDocument {
     };
     let content = file_info.content.as_deref().unwrap_or("");
     let language = &file_info.language;
-    let role = classify_role(&normalized_path, language);
+    let role = classify_file_role(&normalized_path, language, content);
     let test_role_str = test_subrole(&normalized_path);
 
     // pretokenized_code: CamelCase/snake_case-split of the first ≤ 2000 bytes of content.
@@ -347,34 +347,14 @@ fn symbol_to_search_document(
         .next()
         .unwrap_or(&normalized_path)
         .to_string();
-    let path_role = classify_role(&normalized_path, &symbol.language);
-    let path_test_role_str = test_subrole(&normalized_path);
 
     // Inline test helpers live in non-test files (e.g. `#[cfg(test)]` blocks
-    // inside `src/lib.rs`).  Path heuristics can't detect them; check the
-    // extractor's metadata override so the role and test_role fields carry
-    // the correct classification for the unified reranker and the
-    // `exclude_tests` filter.
-    let meta = symbol.metadata.as_ref();
-    let metadata_is_test = meta
-        .and_then(|m| m.get("is_test"))
-        .and_then(|v| v.as_bool())
-        .unwrap_or(false);
-    let metadata_test_role = meta
-        .and_then(|m| m.get("test_role"))
-        .and_then(|v| v.as_str())
-        .filter(|s| !s.is_empty())
-        .map(str::to_string);
-
-    let (role, test_role) = if metadata_is_test && path_role != "test" {
-        let tr = metadata_test_role.unwrap_or_else(|| path_test_role_str.to_string());
-        ("test".to_string(), tr)
-    } else {
-        (
-            path_role.to_string(),
-            metadata_test_role.unwrap_or_else(|| path_test_role_str.to_string()),
-        )
-    };
+    // inside `src/lib.rs`), and generated code is often checked in next to
+    // hand-written code. Path heuristics can't detect either; the extractor's
+    // metadata overrides make the role and test_role fields carry the correct
+    // classification for the unified reranker and the `exclude_tests` filter.
+    let (role, test_role) =
+        symbol_role_and_test_role(&normalized_path, &symbol.language, symbol.metadata.as_ref());
 
     let raw_body = symbol.code_context.as_deref().unwrap_or("");
     let code_body = truncate_utf8_bytes(raw_body, 2000).to_string();
@@ -419,7 +399,7 @@ fn raw_file_to_search_document(file_path: &str, content: &str, language: &str) -
     } else {
         basename.clone()
     };
-    let role = classify_role(&normalized_path, language);
+    let role = classify_file_role(&normalized_path, language, content);
     let test_role_str = test_subrole(&normalized_path);
     let content_truncated = truncate_utf8_bytes(content, 2000);
     let pretokenized_code = pretokenize_code(content_truncated);
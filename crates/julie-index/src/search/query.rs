@@ -7,6 +7,7 @@
 //! - doc_comment: 2.0x
 //! - code_body: 1.0x
 
+use std::borrow::Cow;
 use std::collections::HashSet;
 
 use tantivy::Term;
@@ -32,6 +33,9 @@ const FILE_PATH_EXACT_BOOST: f32 = 40.0;
 const BASENAME_EXACT_BOOST: f32 = 25.0;
 const PATH_TEXT_TERM_BOOST: f32 = 3.0;
 
+/// Query prefix that filters on an annotation by name: `annotation:Deprecated`.
+const ANNOTATION_FILTER_PREFIX: &str = "annotation:";
+
 #[derive(Debug, Clone, PartialEq, Eq, Default)]
 pub(crate) struct ParsedAnnotationQuery {
     pub annotation_keys: Vec<String>,
@@ -52,7 +56,7 @@ pub(crate) fn parse_annotation_query(query: &str) -> ParsedAnnotationQuery {
 
     for token in query.split_whitespace() {
         if let Some((raw_annotation, language)) = annotation_token(token) {
-            let markers = normalize_annotations(&[raw_annotation], language);
+            let markers = normalize_annotations(&[raw_annotation.as_ref()], language);
             if markers.is_empty() {
                 remaining_terms.push(token.to_string());
             } else {
@@ -75,8 +79,25 @@ pub(crate) fn parse_annotation_query(query: &str) -> ParsedAnnotationQuery {
     }
 }
 
-fn annotation_token(token: &str) -> Option<(&str, &'static str)> {
+/// An annotation term and the language whose syntax it is written in. Besides
+/// pasted native syntax (`@Test`, `#[test]`, `[Authorize]`, `[[nodiscard]]`),
+/// `annotation:<name>` names an annotation in any language's syntax or bare,
+/// e.g. `annotation:Deprecated` or `annotation:pytest.fixture`.
+fn annotation_token(token: &str) -> Option<(Cow<'_, str>, &'static str)> {
     let token = token.trim_matches(|ch| matches!(ch, ',' | ';'));
+    if let Some(name) = token.strip_prefix(ANNOTATION_FILTER_PREFIX) {
+        if name.is_empty() {
+            return None;
+        }
+        return Some(match native_annotation_token(name) {
+            Some((name, language)) => (Cow::Borrowed(name), language),
+            None => (Cow::Owned(format!("@{name}")), "python"),
+        });
+    }
+    native_annotation_token(token).map(|(token, language)| (Cow::Borrowed(token), language))
+}
+
+fn native_annotation_token(token: &str) -> Option<(&str, &'static str)> {
     if token.starts_with("#[") && token.ends_with(']') {
         return Some((token, "rust"));
     }
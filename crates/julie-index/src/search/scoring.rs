@@ -9,6 +9,7 @@ use std::collections::HashMap;
 use crate::search::index::SymbolSearchResult;
 use crate::search::language_config::LanguageConfigs;
 use julie_core::database::SymbolDatabase;
+use julie_core::generated_files::{has_generated_header, is_generated_file_name};
 
 /// Score multiplier for results matching an important pattern.
 const IMPORTANT_PATTERN_BOOST: f32 = 1.5;
@@ -393,7 +394,9 @@ pub(crate) fn is_vendor_path(path: &str) -> bool {
 /// Detect whether a file path indicates generated / build-output code.
 ///
 /// Matches path segments: `target`, `build`, `dist`, `out`, `bin`, `obj`,
-/// `generated`, `__generated__`, `gen`.
+/// `generated`, `__generated__`, `gen`, and generator file names such as
+/// `*_pb2.py`, `*.g.dart` or ORM migrations (see
+/// [`julie_core::generated_files::is_generated_file_name`]).
 pub(crate) fn is_generated_path(path: &str) -> bool {
     for segment in path.split('/') {
         match segment {
@@ -402,7 +405,7 @@ pub(crate) fn is_generated_path(path: &str) -> bool {
             _ => {}
         }
     }
-    false
+    is_generated_file_name(path)
 }
 
 /// Classify a file path + language into a [`role`] string for the C.3
@@ -423,6 +426,16 @@ pub fn classify_role(path: &str, language: &str) -> &'static str {
     }
 }
 
+/// [`classify_role`] for a file row, which also reads the file's header: a
+/// "Code generated ... DO NOT EDIT" file is `generated` wherever it lives.
+pub fn classify_file_role(path: &str, language: &str, content: &str) -> &'static str {
+    match classify_role(path, language) {
+        role @ ("vendor" | "generated") => role,
+        _ if has_generated_header(content) => "generated",
+        role => role,
+    }
+}
+
 /// If `path` is a test path, return its sub-role (`unit | integration |
 /// smoke`) or empty string when no sub-role segment is present.
 pub fn test_subrole(path: &str) -> &'static str {
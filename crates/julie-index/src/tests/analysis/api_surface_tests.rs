@@ -0,0 +1,165 @@
+use crate::analysis::api_surface::generate_api_surface;
+use julie_core::database::{FileInfo, SymbolDatabase};
+use julie_extractors::{Symbol, SymbolKind, Visibility};
+use julie_test_support::{file_info_builder, symbol_builder};
+use tempfile::TempDir;
+
+fn file_info(path: &str) -> FileInfo {
+    file_info_builder(path)
+        .language("typescript")
+        .size(128)
+        .last_modified(1_700_000_000)
+        .last_indexed(0)
+        .line_count(40)
+        .content(format!("// {path}"))
+        .build()
+}
+
+fn symbol(
+    id: &str,
+    name: &str,
+    kind: SymbolKind,
+    file_path: &str,
+    line: u32,
+    visibility: Visibility,
+) -> Symbol {
+    symbol_builder(id, name, file_path)
+        .kind(kind)
+        .language("typescript")
+        .span(line, 0, line + 2, 1)
+        .signature(format!("{name}()\n  body"))
+        .visibility(visibility)
+        .build()
+}
+
+fn seeded_db() -> (TempDir, SymbolDatabase) {
+    let temp_dir = TempDir::new().unwrap();
+    let mut db = SymbolDatabase::new(temp_dir.path().join("api.db")).unwrap();
+    for path in [
+        "src/index.ts",
+        "src/client/http.ts",
+        "src/clientele.ts",
+        "src/tests/http.test.ts",
+    ] {
+        db.store_file_info(&file_info(path)).unwrap();
+    }
+
+    let client = symbol(
+        "client",
+        "HttpClient",
+        SymbolKind::Class,
+        "src/client/http.ts",
+        3,
+        Visibility::Public,
+    );
+    let internal = symbol(
+        "internal",
+        "RetryState",
+        SymbolKind::Class,
+        "src/client/http.ts",
+        30,
+        Visibility::Private,
+    );
+    let mut send = symbol(
+        "send",
+        "send",
+        SymbolKind::Method,
+        "src/client/http.ts",
+        5,
+        Visibility::Public,
+    );
+    send.parent_id = Some("client".to_string());
+    let mut bump = symbol(
+        "bump",
+        "bump",
+        SymbolKind::Method,
+        "src/client/http.ts",
+        32,
+        Visibility::Public,
+    );
+    bump.parent_id = Some("internal".to_string());
+
+    db.store_symbols(&[
+        symbol(
+            "create",
+            "createClient",
+            SymbolKind::Function,
+            "src/index.ts",
+            1,
+            Visibility::Public,
+        ),
+        symbol(
+            "helper",
+            "normalize",
+            SymbolKind::Function,
+            "src/index.ts",
+            10,
+            Visibility::Private,
+        ),
+        client,
+        internal,
+        send,
+        bump,
+        symbol(
+            "clientele",
+            "Clientele",
+            SymbolKind::Class,
+            "src/clientele.ts",
+            1,
+            Visibility::Public,
+        ),
+        symbol(
+            "fixture",
+            "makeServer",
+            SymbolKind::Function,
+            "src/tests/http.test.ts",
+            1,
+            Visibility::Public,
+        ),
+    ])
+    .unwrap();
+    (temp_dir, db)
+}
+
+#[test]
+fn api_surface_groups_public_declarations_by_module() {
+    let (_temp_dir, db) = seeded_db();
+    let report = generate_api_surface(&db, None).unwrap();
+
+    let modules: Vec<&str> = report.modules.iter().map(|m| m.module.as_str()).collect();
+    assert_eq!(
+        modules,
+        ["src/client/http.ts", "src/clientele.ts", "src/index.ts"],
+        "test files excluded, modules sorted"
+    );
+    assert_eq!(report.total_symbols, 4);
+
+    let http = &report.modules[0];
+    let names: Vec<&str> = http.symbols.iter().map(|s| s.name.as_str()).collect();
+    assert_eq!(
+        names,
+        ["HttpClient", "HttpClient.send"],
+        "private class and its members are not API"
+    );
+    assert_eq!(http.symbols[1].kind, "method");
+    assert_eq!(http.symbols[1].line, 5);
+    assert_eq!(
+        http.symbols[1].signature.as_deref(),
+        Some("send()"),
+        "first signature line only"
+    );
+}
+
+#[test]
+fn api_surface_focus_path_matches_whole_path_segments() {
+    let (_temp_dir, db) = seeded_db();
+
+    let report = generate_api_surface(&db, Some("src/client/")).unwrap();
+    assert_eq!(report.focus_path.as_deref(), Some("src/client/"));
+    let modules: Vec<&str> = report.modules.iter().map(|m| m.module.as_str()).collect();
+    assert_eq!(modules, ["src/client/http.ts"], "src/clientele.ts excluded");
+
+    let report = generate_api_surface(&db, Some("src/index.ts")).unwrap();
+    assert_eq!(report.total_symbols, 1);
+    assert_eq!(report.modules[0].symbols[0].name, "createClient");
+}
@@ -0,0 +1,80 @@
+use std::collections::HashMap;
+
+use super::assert_recompute_is_idempotent;
+use crate::analysis::build_targets::compute_build_target_files;
+use julie_core::database::{FileInfo, SymbolDatabase};
+use julie_extractors::{Symbol, SymbolKind};
+use julie_test_support::{file_info_builder, symbol_builder};
+use serde_json::json;
+use tempfile::TempDir;
+
+fn file_info(path: &str, language: &str) -> FileInfo {
+    file_info_builder(path)
+        .language(language)
+        .size(1)
+        .last_modified(1_700_000_000)
+        .last_indexed(0)
+        .line_count(1)
+        .build()
+}
+
+fn target(id: &str, name: &str, build_file: &str, label: &str, sources: &[&str]) -> Symbol {
+    let mut symbol = symbol_builder(id, name, build_file)
+        .kind(SymbolKind::Module)
+        .metadata(HashMap::from([(
+            "build".to_string(),
+            json!({ "system": "bazel", "rule": "cc_library", "label": label, "sources": sources }),
+        )]))
+        .build();
+    symbol.semantic_group = Some("build_target".to_string());
+    symbol
+}
+
+#[test]
+fn maps_indexed_sources_to_the_targets_that_compile_them() {
+    let temp_dir = TempDir::new().unwrap();
+    let mut db = SymbolDatabase::new(temp_dir.path().join("build.db")).unwrap();
+    for (path, language) in [
+        ("net/BUILD.bazel", "bazel"),
+        ("net/http.cc", "cpp"),
+        ("net/http.h", "cpp"),
+        ("net/socket.cc", "cpp"),
+    ] {
+        db.store_file_info(&file_info(path, language)).unwrap();
+    }
+    db.store_symbols(&[
+        target(
+            "http",
+            "http",
+            "net/BUILD.bazel",
+            "//net:http",
+            &["net/http.cc", "net/http.h", "net/generated.cc"],
+        ),
+        target(
+            "http_test",
+            "http_test",
+            "net/BUILD.bazel",
+            "//net:http_test",
+            &["net/http.h"],
+        ),
+    ])
+    .unwrap();
+
+    let stats = compute_build_target_files(&db).unwrap();
+    assert_eq!(stats.targets, 2);
+    assert_eq!(stats.files, 3, "generated sources have no indexed file");
+
+    let paths = ["net/http.h", "net/socket.cc"].map(str::to_string);
+    let targets = db.build_targets_for_files(&paths).unwrap();
+    let labels: Vec<&str> = targets["net/http.h"]
+        .iter()
+        .map(|target| target.label.as_str())
+        .collect();
+    assert_eq!(labels, ["//net:http", "//net:http_test"]);
+    assert_eq!(targets["net/http.h"][0].build_file, "net/BUILD.bazel");
+    assert!(!targets.contains_key("net/socket.cc"));
+
+    assert_recompute_is_idempotent(&db, compute_build_target_files, |db| {
+        db.build_targets_for_files(&paths).unwrap()
+    });
+}
@@ -0,0 +1,270 @@
+use super::assert_recompute_is_idempotent;
+use crate::analysis::cross_language_links::{
+    canonical_entity_name, compute_cross_language_links, match_counterparts,
+};
+use julie_core::database::{FileInfo, SymbolDatabase, WebEdge, WebEdgeKind};
+use julie_extractors::{Symbol, SymbolKind};
+use julie_test_support::{file_info_builder, symbol_builder};
+use tempfile::TempDir;
+
+fn file_info(path: &str, language: &str) -> FileInfo {
+    file_info_builder(path)
+        .language(language)
+        .size(128)
+        .last_modified(1_700_000_000)
+        .last_indexed(0)
+        .line_count(40)
+        .content(format!("// {path}"))
+        .build()
+}
+
+fn entity(id: &str, name: &str, kind: SymbolKind, language: &str, file_path: &str) -> Symbol {
+    symbol_builder(id, name, file_path)
+        .kind(kind)
+        .language(language)
+        .span(1, 0, 20, 1)
+        .build()
+}
+
+fn member(parent: &Symbol, name: &str, kind: SymbolKind, line: u32) -> Symbol {
+    symbol_builder(format!("{}_{name}", parent.id), name, &parent.file_path)
+        .kind(kind)
+        .language(&parent.language)
+        .span(line, 4, line, 20)
+        .parent_id(&parent.id)
+        .build()
+}
+
+fn seeded_db() -> (TempDir, SymbolDatabase) {
+    let temp_dir = TempDir::new().unwrap();
+    let mut db = SymbolDatabase::new(temp_dir.path().join("links.db")).unwrap();
+    for (path, language) in [
+        ("web/src/user.ts", "typescript"),
+        ("api/Dtos/UserDto.cs", "csharp"),
+        ("db/schema.sql", "sql"),
+        ("api/Models/Order.cs", "csharp"),
+        ("web/src/order.ts", "typescript"),
+        ("web/src/account.ts", "typescript"),
+    ] {
+        db.store_file_info(&file_info(path, language)).unwrap();
+    }
+
+    let ts_user = entity(
+        "ts_user",
+        "IUser",
+        SymbolKind::Interface,
+        "typescript",
+        "web/src/user.ts",
+    );
+    let cs_user = entity(
+        "cs_user",
+        "UserDto",
+        SymbolKind::Class,
+        "csharp",
+        "api/Dtos/UserDto.cs",
+    );
+    let sql_users = entity(
+        "sql_users",
+        "users",
+        SymbolKind::Class,
+        "sql",
+        "db/schema.sql",
+    );
+    // Same name across languages, but no shared fields.
+    let cs_order = entity(
+        "cs_order",
+        "Order",
+        SymbolKind::Class,
+        "csharp",
+        "api/Models/Order.cs",
+    );
+    let ts_order = entity(
+        "ts_order",
+        "Order",
+        SymbolKind::Interface,
+        "typescript",
+        "web/src/order.ts",
+    );
+    // Shares a name with nothing in another language.
+    let ts_account = entity(
+        "ts_account",
+        "Account",
+        SymbolKind::Interface,
+        "typescript",
+        "web/src/account.ts",
+    );
+
+    let members = vec![
+        member(&ts_user, "userId", SymbolKind::Property, 2),
+        member(&ts_user, "email", SymbolKind::Property, 3),
+        member(&ts_user, "displayName", SymbolKind::Property, 4),
+        member(&cs_user, "UserId", SymbolKind::Property, 2),
+        member(&cs_user, "Email", SymbolKind::Property, 3),
+        member(&cs_user, "DisplayName", SymbolKind::Property, 4),
+        member(&sql_users, "user_id", SymbolKind::Field, 2),
+        member(&sql_users, "email", SymbolKind::Field, 3),
+        member(&sql_users, "password_hash", SymbolKind::Field, 4),
+        member(&cs_order, "Total", SymbolKind::Property, 2),
+        member(&ts_order, "lineItems", SymbolKind::Property, 2),
+    ];
+
+    let mut symbols = vec![cs_user, ts_user, sql_users, cs_order, ts_order, ts_account];
+    symbols.extend(members);
+    db.store_symbols(&symbols).unwrap();
+    (temp_dir, db)
+}
+
+#[test]
+fn canonical_entity_name_strips_prefixes_suffixes_and_plurals() {
+    assert_eq!(canonical_entity_name("IUser"), "user");
+    assert_eq!(canonical_entity_name("UserDto"), "user");
+    assert_eq!(canonical_entity_name("users"), "user");
+    assert_eq!(canonical_entity_name("user_records"), "user");
+    assert_eq!(canonical_entity_name("UsersDto"), "user");
+    assert_eq!(canonical_entity_name("order_line_items"), "order_line_item");
+    assert_eq!(canonical_entity_name("Categories"), "category");
+    assert_eq!(canonical_entity_name("Addresses"), "address");
+    assert_eq!(canonical_entity_name("Status"), "status");
+    assert_eq!(canonical_entity_name("Analysis"), "analysis");
+    assert_eq!(
+        canonical_entity_name("Dto"),
+        "dto",
+        "a lone suffix is the name"
+    );
+}
+
+#[test]
+fn links_counterparts_across_languages_ranked_by_field_overlap() {
+    let (_temp_dir, db) = seeded_db();
+    let stats = compute_cross_language_links(&db).unwrap();
+
+    assert_eq!(stats.candidates, 6);
+    assert_eq!(stats.pairs, 3, "user ts↔cs, ts↔sql, cs↔sql");
+
+    let links = db
+        .cross_language_links_for_symbols(&["ts_user".to_string()])
+        .unwrap();
+    let targets: Vec<&str> = links.iter().map(|l| l.to_symbol_id.as_str()).collect();
+    assert_eq!(
+        targets,
+        ["cs_user", "sql_users"],
+        "highest confidence first"
+    );
+
+    let dto = &links[0];
+    assert!((dto.confidence - 0.9).abs() < 1e-6, "{dto:?}");
+    assert_eq!(dto.reason, "normalized name, 3/3 fields");
+
+    let table = &links[1];
+    assert!(table.confidence < dto.confidence);
+    assert_eq!(table.reason, "normalized name, 2/4 fields");
+
+    let reverse = db
+        .cross_language_links_for_symbols(&["sql_users".to_string()])
+        .unwrap();
+    assert_eq!(reverse.len(), 2, "links are stored in both directions");
+}
+
+#[test]
+fn disjoint_fields_drop_a_same_name_pair_below_threshold() {
+    let (_temp_dir, db) = seeded_db();
+    compute_cross_language_links(&db).unwrap();
+
+    let links = db
+        .cross_language_links_for_symbols(&["ts_order".to_string(), "ts_account".to_string()])
+        .unwrap();
+    assert!(links.is_empty(), "{links:?}");
+}
+
+#[test]
+fn recompute_replaces_previous_links() {
+    let (_temp_dir, db) = seeded_db();
+    compute_cross_language_links(&db).unwrap();
+    assert_eq!(db.cross_language_link_count().unwrap(), 6);
+
+    assert_recompute_is_idempotent(&db, compute_cross_language_links, |db| {
+        db.cross_language_link_count().unwrap()
+    });
+}
+
+#[test]
+fn match_counterparts_lists_drifted_shapes_with_their_field_diff() {
+    let (_temp_dir, db) = seeded_db();
+    compute_cross_language_links(&db).unwrap();
+
+    let matches = match_counterparts(&db, "ts_user", 0.3).unwrap();
+    let ranked: Vec<(&str, &str)> = matches
+        .iter()
+        .map(|m| (m.symbol.id.as_str(), m.reason.as_str()))
+        .collect();
+    assert_eq!(
+        ranked,
+        [
+            ("cs_user", "normalized name, 3/3 fields"),
+            ("sql_users", "normalized name, 2/4 fields"),
+        ]
+    );
+    assert!(matches[0].missing_fields.is_empty() && matches[0].extra_fields.is_empty());
+    assert_eq!(matches[1].missing_fields, ["displayName"]);
+    assert_eq!(matches[1].extra_fields, ["password_hash"]);
+
+    // Not stored (0.4 < 0.5), but a query still finds the same-named shape.
+    let order = match_counterparts(&db, "ts_order", 0.3).unwrap();
+    assert_eq!(order.len(), 1);
+    assert_eq!(order[0].symbol.id, "cs_order");
+    assert!((order[0].confidence - 0.4).abs() < 1e-6, "{:?}", order[0]);
+    assert_eq!(order[0].missing_fields, ["lineItems"]);
+    assert_eq!(order[0].extra_fields, ["Total"]);
+    assert!(
+        match_counterparts(&db, "ts_order", 0.5).unwrap().is_empty(),
+        "the floor applies"
+    );
+    assert!(
+        match_counterparts(&db, "ts_account", 0.0)
+            .unwrap()
+            .is_empty()
+    );
+    assert!(match_counterparts(&db, "missing", 0.0).unwrap().is_empty());
+}
+
+#[test]
+fn match_counterparts_pairs_client_calls_with_their_route_handlers() {
+    let (_temp_dir, mut db) = seeded_db();
+    db.store_file_info(&file_info("api/routes.py", "python"))
+        .unwrap();
+    let handler = symbol_builder("py_list_users", "list_users", "api/routes.py")
+        .kind(SymbolKind::Function)
+        .language("python")
+        .span(10, 0, 14, 1)
+        .build();
+    let client = symbol_builder("ts_fetch_users", "fetchUsers", "web/src/user.ts")
+        .kind(SymbolKind::Function)
+        .language("typescript")
+        .span(30, 0, 33, 1)
+        .build();
+    db.store_symbols(&[handler, client]).unwrap();
+    db.replace_all_web_edges(&[WebEdge {
+        from_symbol_id: "ts_fetch_users".to_string(),
+        to_symbol_id: Some("py_list_users".to_string()),
+        to_external: None,
+        kind: WebEdgeKind::HttpCall,
+        method: Some("GET".to_string()),
+        path: Some("/api/users".to_string()),
+        table: None,
+        file_path: "web/src/user.ts".to_string(),
+        line_number: 31,
+        confidence: 0.8,
+        metadata: None,
+    }])
+    .unwrap();
+
+    let served = match_counterparts(&db, "ts_fetch_users", 0.3).unwrap();
+    assert_eq!(served.len(), 1);
+    assert_eq!(served[0].symbol.id, "py_list_users");
+    assert_eq!(served[0].reason, "serves route GET /api/users");
+
+    let callers = match_counterparts(&db, "py_list_users", 0.3).unwrap();
+    assert_eq!(callers.len(), 1);
+    assert_eq!(callers[0].symbol.id, "ts_fetch_users");
+    assert_eq!(callers[0].reason, "calls route GET /api/users");
+}
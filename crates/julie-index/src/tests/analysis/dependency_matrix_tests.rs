@@ -0,0 +1,173 @@
+use crate::analysis::dependency_matrix::{
+    DependencyMatrixOptions, generate_dependency_matrix, module_of,
+};
+use julie_core::database::SymbolDatabase;
+use julie_extractors::{RelationshipKind, SymbolKind};
+use julie_test_support::{file_info_builder, relationship_builder, symbol_builder};
+use tempfile::TempDir;
+
+/// `src/handler` calls `src/tools` three times, `src/tools` calls
+/// `crates/core` twice and `crates/core` calls back once. Calls inside
+/// `src/handler` and from a test file are not cross-module dependencies.
+fn setup_db() -> (TempDir, SymbolDatabase) {
+    let temp_dir = TempDir::new().unwrap();
+    let mut db = SymbolDatabase::new(temp_dir.path().join("deps.db")).unwrap();
+
+    let files = [
+        ("handler", "src/handler/mod.rs"),
+        ("handler_util", "src/handler/util.rs"),
+        ("search", "src/tools/search.rs"),
+        ("core_db", "crates/core/src/db.rs"),
+        ("handler_test", "src/tests/handler_tests.rs"),
+    ];
+    for (_, path) in files {
+        db.store_file_info(
+            &file_info_builder(path)
+                .language("rust")
+                .size(128)
+                .last_modified(1_700_000_000)
+                .last_indexed(0)
+                .line_count(40)
+                .content(format!("// {path}"))
+                .build(),
+        )
+        .unwrap();
+    }
+    let symbols: Vec<_> = files
+        .iter()
+        .map(|(id, path)| {
+            symbol_builder(*id, *id, *path)
+                .kind(SymbolKind::Function)
+                .language("rust")
+                .build()
+        })
+        .collect();
+    db.store_symbols(&symbols).unwrap();
+
+    let calls = [
+        ("handler", "search", "src/handler/mod.rs"),
+        ("handler", "search", "src/handler/mod.rs"),
+        ("handler_util", "search", "src/handler/util.rs"),
+        ("search", "core_db", "src/tools/search.rs"),
+        ("search", "core_db", "src/tools/search.rs"),
+        ("core_db", "search", "crates/core/src/db.rs"),
+        ("handler", "handler_util", "src/handler/mod.rs"),
+        ("handler_test", "handler", "src/tests/handler_tests.rs"),
+    ];
+    let relationships: Vec<_> = calls
+        .iter()
+        .enumerate()
+        .map(|(line, (from, to, path))| {
+            relationship_builder(format!("rel_{line}"), *from, *to)
+                .kind(RelationshipKind::Calls)
+                .file_path(*path)
+                .line_number(line as u32 + 1)
+                .build()
+        })
+        .collect();
+    db.store_relationships(&relationships).unwrap();
+
+    (temp_dir, db)
+}
+
+#[test]
+fn module_of_truncates_directories_below_the_focus_path() {
+    assert_eq!(module_of("src/tools/search/mod.rs", None, 2), "src/tools");
+    assert_eq!(module_of("src/tools/search/mod.rs", None, 1), "src");
+    assert_eq!(module_of("src/main.rs", None, 2), "src");
+    assert_eq!(module_of("build.rs", None, 2), ".");
+    assert_eq!(
+        module_of("src/tools/search/mod.rs", Some("src"), 1),
+        "src/tools"
+    );
+    assert_eq!(module_of("src/lib.rs", Some("src"), 1), "src");
+    assert_eq!(
+        module_of("srcgen/out.rs", Some("src"), 1),
+        "srcgen",
+        "the focus is a directory, not a string prefix"
+    );
+}
+
+#[test]
+fn aggregates_cross_module_relationships_into_a_matrix() {
+    let (_temp, db) = setup_db();
+    let report = generate_dependency_matrix(&db, &DependencyMatrixOptions::default()).unwrap();
+
+    assert_eq!(report.total_modules, 3);
+    assert_eq!(
+        report.total_dependencies, 6,
+        "intra-module and test-file calls are not counted"
+    );
+    // Ranked by relationships in plus out; ties alphabetical.
+    assert_eq!(report.modules, ["src/tools", "crates/core", "src/handler"]);
+    assert_eq!(report.matrix, [[0, 2, 0], [1, 0, 0], [3, 0, 0]]);
+
+    assert_eq!(report.upward.len(), 1);
+    let upward = &report.upward[0];
+    assert_eq!(
+        (upward.from.as_str(), upward.to.as_str()),
+        ("crates/core", "src/tools")
+    );
+    assert_eq!(upward.relationships, 1);
+    assert_eq!(
+        upward.reason,
+        "src/tools depends on crates/core more (2 relationships)"
+    );
+}
+
+#[test]
+fn layers_order_the_matrix_and_define_upward_dependencies() {
+    let (_temp, db) = setup_db();
+    let options = DependencyMatrixOptions {
+        layers: vec![
+            "src/handler".to_string(),
+            "src/tools".to_string(),
+            "crates".to_string(),
+        ],
+        ..Default::default()
+    };
+    let report = generate_dependency_matrix(&db, &options).unwrap();
+
+    assert_eq!(report.modules, ["src/handler", "src/tools", "crates/core"]);
+    assert_eq!(report.matrix, [[0, 3, 0], [0, 0, 2], [0, 1, 0]]);
+    assert_eq!(report.upward.len(), 1);
+    assert_eq!(report.upward[0].from, "crates/core");
+    assert_eq!(
+        report.upward[0].reason,
+        "layer 3 (crates) depends on higher layer 2 (src/tools)"
+    );
+}
+
+#[test]
+fn focus_path_and_top_limit_the_matrix() {
+    let (_temp, db) = setup_db();
+    let focused = generate_dependency_matrix(
+        &db,
+        &DependencyMatrixOptions {
+            focus_path: Some("src/".to_string()),
+            depth: 1,
+            ..Default::default()
+        },
+    )
+    .unwrap();
+    assert_eq!(focused.focus_path.as_deref(), Some("src"));
+    assert_eq!(focused.modules, ["src/handler", "src/tools"]);
+    assert_eq!(focused.total_dependencies, 3);
+    assert!(focused.upward.is_empty());
+
+    let top = generate_dependency_matrix(
+        &db,
+        &DependencyMatrixOptions {
+            top: 2,
+            ..Default::default()
+        },
+    )
+    .unwrap();
+    assert_eq!(top.modules, ["src/tools", "crates/core"]);
+    assert_eq!(top.total_modules, 3);
+    assert_eq!(
+        top.upward.len(),
+        1,
+        "upward dependencies cover all modules, not just the top ones"
+    );
+}
@@ -0,0 +1,82 @@
+use std::collections::HashMap;
+
+use super::assert_recompute_is_idempotent;
+use crate::analysis::expect_actual::compute_expect_actual_links;
+use julie_core::database::{FileInfo, SymbolDatabase};
+use julie_extractors::{RelationshipKind, Symbol, SymbolKind};
+use julie_test_support::{file_info_builder, relationship_builder, symbol_builder};
+use tempfile::TempDir;
+
+fn file_info(path: &str) -> FileInfo {
+    file_info_builder(path)
+        .language("kotlin")
+        .size(128)
+        .last_modified(1_700_000_000)
+        .last_indexed(0)
+        .line_count(40)
+        .content(format!("// {path}"))
+        .build()
+}
+
+fn declaration(id: &str, file_path: &str, role: &str, group: &str) -> Symbol {
+    symbol_builder(id, "platformName", file_path)
+        .kind(SymbolKind::Function)
+        .language("kotlin")
+        .metadata(HashMap::from([
+            ("kotlin_multiplatform".to_string(), role.into()),
+            ("partial_group".to_string(), group.into()),
+        ]))
+        .build()
+}
+
+#[test]
+fn links_each_actual_to_its_expect() {
+    let temp_dir = TempDir::new().unwrap();
+    let mut db = SymbolDatabase::new(temp_dir.path().join("kmp.db")).unwrap();
+    let common = "src/commonMain/kotlin/Platform.kt";
+    let jvm = "src/jvmMain/kotlin/Platform.kt";
+    let ios = "src/iosMain/kotlin/Platform.kt";
+    for path in [common, jvm, ios] {
+        db.store_file_info(&file_info(path)).unwrap();
+    }
+    let group = "kotlin:app.platformName/0";
+    let unrelated = symbol_builder("caller", "greet", jvm)
+        .kind(SymbolKind::Function)
+        .language("kotlin")
+        .build();
+    db.store_symbols(&[
+        declaration("expect", common, "expect", group),
+        declaration("jvm", jvm, "actual", group),
+        declaration("ios", ios, "actual", group),
+        declaration("orphan", ios, "actual", "kotlin:app.other/0"),
+        unrelated,
+    ])
+    .unwrap();
+    db.store_relationships(&[relationship_builder("call", "caller", "jvm").build()])
+        .unwrap();
+
+    let stats = compute_expect_actual_links(&db).unwrap();
+    assert_eq!(stats.expects, 1);
+    assert_eq!(stats.actuals, 3);
+    assert_eq!(stats.links, 2, "an actual without an expect is not linked");
+
+    let incoming = db
+        .get_relationships_to_symbols(&["expect".to_string()])
+        .unwrap();
+    let mut implementers: Vec<&str> = incoming
+        .iter()
+        .filter(|rel| matches!(rel.kind, RelationshipKind::Implements))
+        .map(|rel| rel.from_symbol_id.as_str())
+        .collect();
+    implementers.sort();
+    assert_eq!(implementers, ["ios", "jvm"]);
+
+    // The extracted call into `jvm` survives the rerun alongside the links.
+    assert_recompute_is_idempotent(&db, compute_expect_actual_links, |db| {
+        ["expect", "jvm"].map(|id| {
+            db.get_relationships_to_symbols(&[id.to_string()])
+                .unwrap()
+                .len()
+        })
+    });
+}
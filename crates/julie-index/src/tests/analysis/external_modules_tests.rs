@@ -0,0 +1,327 @@
+use std::collections::HashMap;
+
+use super::assert_recompute_is_idempotent;
+use crate::analysis::external_modules::{
+    ImportedModule, attribute_call, compute_external_call_modules, parse_imports,
+};
+use julie_core::database::{ExternalModuleCallCount, FileInfo, SymbolDatabase};
+use julie_extractors::{IdentifierKind, SymbolKind};
+use julie_test_support::db::identifier_builder;
+use julie_test_support::{file_info_builder, symbol_builder};
+use tempfile::TempDir;
+
+fn file_info(path: &str, language: &str) -> FileInfo {
+    file_info_builder(path)
+        .language(language)
+        .size(128)
+        .last_modified(1_700_000_000)
+        .last_indexed(0)
+        .line_count(40)
+        .content(format!("// {path}"))
+        .build()
+}
+
+fn only(language: &str, statement: &str) -> ImportedModule {
+    let mut imports = parse_imports(language, statement);
+    assert_eq!(imports.len(), 1, "{statement}: {imports:?}");
+    imports.remove(0)
+}
+
+#[test]
+fn parses_javascript_imports_and_requires() {
+    let default = only("typescript", "import _ from 'lodash';");
+    assert_eq!(default.library, "lodash");
+    assert_eq!(default.bindings, ["_"]);
+    assert!(!default.relative);
+
+    let named = only(
+        "typescript",
+        "import React, { useState, useEffect as onEffect } from \"react\"",
+    );
+    assert_eq!(named.bindings, ["React", "useState", "onEffect"]);
+
+    let namespace = only("javascript", "import * as fp from 'lodash/fp'");
+    assert_eq!(namespace.module, "lodash/fp");
+    assert_eq!(namespace.library, "lodash");
+    assert_eq!(namespace.bindings, ["fp"]);
+
+    let scoped = only("tsx", "import { render } from '@testing-library/react'");
+    assert_eq!(scoped.library, "@testing-library/react");
+
+    let require = only("javascript", "const { get, post: send } = require('axios')");
+    assert_eq!(require.library, "axios");
+    assert_eq!(require.bindings, ["get", "send"]);
+
+    assert!(only("typescript", "import { x } from './util'").relative);
+    assert!(only("typescript", "import { x } from '@/components/x'").relative);
+}
+
+#[test]
+fn parses_python_imports() {
+    let plain = parse_imports("python", "import numpy as np, os.path");
+    assert_eq!(plain.len(), 2);
+    assert_eq!(plain[0].library, "numpy");
+    assert_eq!(plain[0].bindings, ["np"]);
+    assert_eq!(plain[1].module, "os.path");
+    assert_eq!(plain[1].bindings, ["os"], "`import a.b` binds `a`");
+
+    let from = only(
+        "python",
+        "from requests.adapters import (HTTPAdapter, Retry as R)",
+    );
+    assert_eq!(from.library, "requests");
+    assert_eq!(from.bindings, ["HTTPAdapter", "R"]);
+
+    assert!(only("python", "from . import views").relative);
+}
+
+#[test]
+fn parses_rust_go_jvm_and_csharp_imports() {
+    let rust = only(
+        "rust",
+        "pub use serde::{Deserialize, de::{self, Visitor as V}};",
+    );
+    assert_eq!(rust.library, "serde");
+    assert_eq!(rust.bindings, ["Deserialize", "de", "V"]);
+    assert!(only("rust", "use crate::database::SymbolDatabase;").relative);
+
+    let go = parse_imports(
+        "go",
+        "import (\n\t\"fmt\"\n\tyaml \"gopkg.in/yaml.v3\"\n\t\"github.com/gin-gonic/gin/binding\"\n\t\"github.com/go-chi/chi/v5\"\n)",
+    );
+    let summary: Vec<(&str, &[String])> = go
+        .iter()
+        .map(|import| (import.library.as_str(), import.bindings.as_slice()))
+        .collect();
+    assert_eq!(
+        summary,
+        [
+            ("fmt", &["fmt".to_string()][..]),
+            ("gopkg.in/yaml.v3", &["yaml".to_string()][..]),
+            ("github.com/gin-gonic/gin", &["binding".to_string()][..]),
+            ("github.com/go-chi/chi", &["chi".to_string()][..]),
+        ]
+    );
+
+    let java = only(
+        "java",
+        "import com.fasterxml.jackson.databind.ObjectMapper;",
+    );
+    assert_eq!(java.library, "com.fasterxml.jackson.databind");
+    assert_eq!(java.bindings, ["ObjectMapper"]);
+    let static_import = only("java", "import static org.junit.Assert.assertEquals;");
+    assert_eq!(static_import.library, "org.junit");
+    assert_eq!(static_import.bindings, ["assertEquals"]);
+
+    let alias = only("csharp", "using Json = Newtonsoft.Json;");
+    assert_eq!(alias.library, "Newtonsoft.Json");
+    assert_eq!(alias.bindings, ["Json"]);
+    assert!(
+        parse_imports("csharp", "using System.Linq;").is_empty(),
+        "namespace imports bind no callable name"
+    );
+}
+
+#[test]
+fn attributes_calls_by_qualifier_name_or_receiver() {
+    let bindings: HashMap<String, String> =
+        [("_", "lodash"), ("map", "lodash"), ("requests", "requests")]
+            .into_iter()
+            .map(|(binding, library)| (binding.to_string(), library.to_string()))
+            .collect();
+
+    assert_eq!(
+        attribute_call("_.debounce", None, &bindings),
+        Some("lodash")
+    );
+    assert_eq!(attribute_call("map", None, &bindings), Some("lodash"));
+    assert_eq!(
+        attribute_call("map", Some("const rows = map(items, toRow);"), &bindings),
+        Some("lodash")
+    );
+    assert_eq!(
+        attribute_call("map", Some("return items.map(toRow);"), &bindings),
+        None,
+        "a method call on another receiver is not the imported function"
+    );
+    assert_eq!(
+        attribute_call(
+            "get",
+            Some("resp = requests.get(url, timeout=5)"),
+            &bindings
+        ),
+        Some("requests")
+    );
+    assert_eq!(
+        attribute_call("get", Some("forget(cache)"), &bindings),
+        None
+    );
+    assert_eq!(attribute_call("self.get", None, &bindings), None);
+}
+
+fn seeded_db() -> (TempDir, SymbolDatabase) {
+    let temp_dir = TempDir::new().unwrap();
+    let mut db = SymbolDatabase::new(temp_dir.path().join("external.db")).unwrap();
+    for (path, language) in [
+        ("web/src/search.ts", "typescript"),
+        ("web/src/util/format.ts", "typescript"),
+        ("app/client.py", "python"),
+        ("app/helpers.py", "python"),
+    ] {
+        db.store_file_info(&file_info(path, language)).unwrap();
+    }
+
+    let import = |id: &str, name: &str, file: &str, language: &str, statement: &str| {
+        symbol_builder(id, name, file)
+            .kind(SymbolKind::Import)
+            .language(language)
+            .span(1, 0, 1, 40)
+            .signature(statement)
+            .build()
+    };
+    let local_get = symbol_builder("py_local_get", "get", "app/helpers.py")
+        .kind(SymbolKind::Function)
+        .language("python")
+        .span(3, 0, 8, 1)
+        .build();
+    db.store_symbols(&[
+        import(
+            "ts_lodash",
+            "_",
+            "web/src/search.ts",
+            "typescript",
+            "import _ from 'lodash'",
+        ),
+        import(
+            "ts_lodash_map",
+            "map",
+            "web/src/search.ts",
+            "typescript",
+            "import { map } from 'lodash'",
+        ),
+        import(
+            "ts_format",
+            "formatDate",
+            "web/src/search.ts",
+            "typescript",
+            "import { formatDate } from './util/format'",
+        ),
+        import(
+            "py_requests",
+            "requests",
+            "app/client.py",
+            "python",
+            "import requests",
+        ),
+        import(
+            "py_helpers",
+            "retry",
+            "app/client.py",
+            "python",
+            "from app.helpers import retry",
+        ),
+        local_get,
+    ])
+    .unwrap();
+
+    let call = |id: &str, name: &str, file: &str, line: u32, context: &str| {
+        identifier_builder(id, name, file)
+            .kind(IdentifierKind::Call)
+            .language(if file.ends_with(".py") {
+                "python"
+            } else {
+                "typescript"
+            })
+            .line(line)
+            .code_context(context)
+    };
+    db.bulk_store_identifiers(
+        &[
+            call(
+                "c1",
+                "_.debounce",
+                "web/src/search.ts",
+                5,
+                "const run = _.debounce(search, 200);",
+            )
+            .build(),
+            call(
+                "c2",
+                "map",
+                "web/src/search.ts",
+                8,
+                "return items.map(toRow);",
+            )
+            .build(),
+            call(
+                "c3",
+                "map",
+                "web/src/search.ts",
+                9,
+                "const rows = map(items, toRow);",
+            )
+            .build(),
+            call(
+                "c4",
+                "formatDate",
+                "web/src/search.ts",
+                10,
+                "formatDate(now)",
+            )
+            .build(),
+            call("c5", "get", "app/client.py", 4, "resp = requests.get(url)").build(),
+            call("c6", "retry", "app/client.py", 6, "retry(fetch)").build(),
+            call("c7", "get", "app/client.py", 9, "value = get(key)")
+                .target_symbol_id("py_local_get")
+                .build(),
+        ],
+        "",
+    )
+    .unwrap();
+    (temp_dir, db)
+}
+
+#[test]
+fn attributes_unresolved_calls_to_external_libraries() {
+    let (_temp_dir, db) = seeded_db();
+    let stats = compute_external_call_modules(&db).unwrap();
+    assert_eq!(stats.external_imports, 3, "lodash twice and requests");
+    assert_eq!(stats.unresolved_calls, 6, "the resolved call is skipped");
+    assert_eq!(stats.attributed_calls, 3);
+
+    assert_eq!(
+        db.external_module_call_counts().unwrap(),
+        [
+            ExternalModuleCallCount {
+                module: "lodash".to_string(),
+                calls: 2,
+                files: 1,
+            },
+            ExternalModuleCallCount {
+                module: "requests".to_string(),
+                calls: 1,
+                files: 1,
+            },
+        ]
+    );
+
+    let by_library = db
+        .external_module_usages_for_names(&["requests".to_string()])
+        .unwrap();
+    assert_eq!(by_library.len(), 1);
+    assert_eq!(
+        (by_library[0].name.as_str(), by_library[0].start_line),
+        ("get", 4)
+    );
+
+    let by_name = db
+        .external_module_usages_for_names(&["debounce".to_string()])
+        .unwrap();
+    assert_eq!(by_name.len(), 1);
+    assert_eq!(by_name[0].name, "_.debounce");
+    assert_eq!(by_name[0].module, "lodash");
+
+    assert_recompute_is_idempotent(&db, compute_external_call_modules, |db| {
+        db.external_module_call_counts().unwrap()
+    });
+}
@@ -0,0 +1,162 @@
+use crate::analysis::file_annotations::generate_file_annotations;
+use julie_core::database::{FileInfo, SymbolDatabase};
+use julie_extractors::{AnnotationMarker, Relationship, RelationshipKind, Symbol, SymbolKind};
+use julie_test_support::{file_info_builder, symbol_builder};
+use std::collections::HashMap;
+use tempfile::TempDir;
+
+fn file_info(path: &str) -> FileInfo {
+    file_info_builder(path)
+        .language("rust")
+        .size(128)
+        .last_modified(1_700_000_000)
+        .last_indexed(0)
+        .line_count(40)
+        .content(format!("// {path}"))
+        .build()
+}
+
+fn symbol(id: &str, name: &str, kind: SymbolKind, file_path: &str, line: u32) -> Symbol {
+    symbol_builder(id, name, file_path)
+        .kind(kind)
+        .language("rust")
+        .span(line, 4, line + 3, 1)
+        .bytes(20, 80)
+        .signature(format!("fn {name}()"))
+        .build()
+}
+
+fn relationship(from: &str, to: &str, kind: RelationshipKind, line: u32) -> Relationship {
+    Relationship {
+        id: format!("{from}_{to}_{kind:?}_{line}"),
+        from_symbol_id: from.to_string(),
+        to_symbol_id: to.to_string(),
+        kind,
+        file_path: "src/caller.rs".to_string(),
+        line_number: line,
+        confidence: 1.0,
+        metadata: None,
+    }
+}
+
+fn open_db() -> (TempDir, SymbolDatabase) {
+    let temp_dir = TempDir::new().unwrap();
+    let db = SymbolDatabase::new(temp_dir.path().join("annotations.db")).unwrap();
+    (temp_dir, db)
+}
+
+fn seeded_db() -> (TempDir, SymbolDatabase) {
+    let (temp_dir, mut db) = open_db();
+    db.store_file_info(&file_info("src/lib.rs")).unwrap();
+    db.store_file_info(&file_info("src/caller.rs")).unwrap();
+
+    let mut linkage = HashMap::new();
+    linkage.insert(
+        "test_linkage".to_string(),
+        serde_json::json!({
+            "test_count": 2,
+            "best_tier": "thorough",
+            "worst_tier": "thin",
+            "best_confidence": 0.9,
+            "linked_tests": ["test_parse", "test_parse_empty"],
+            "linked_test_paths": ["tests/parse.rs"],
+            "evidence_sources": ["relationship"],
+        }),
+    );
+    let parse = symbol_builder("parse", "parse", "src/lib.rs")
+        .kind(SymbolKind::Function)
+        .language("rust")
+        .span(3, 0, 10, 1)
+        .metadata(linkage)
+        .build();
+    let legacy = symbol_builder("legacy", "legacy_parse", "src/lib.rs")
+        .kind(SymbolKind::Function)
+        .language("rust")
+        .span(12, 0, 14, 1)
+        .annotations(vec![AnnotationMarker {
+            annotation: "deprecated".to_string(),
+            annotation_key: "deprecated".to_string(),
+            raw_text: Some("#[deprecated(note = \"use parse\")]".to_string()),
+            carrier: None,
+        }])
+        .build();
+    let documented = symbol_builder("old_api", "old_api", "src/lib.rs")
+        .kind(SymbolKind::Function)
+        .language("rust")
+        .span(20, 0, 22, 1)
+        .doc_comment("/** Old entry point.\n * @deprecated Use parse instead. */")
+        .build();
+    let import = symbol("import", "HashMap", SymbolKind::Import, "src/lib.rs", 1);
+    let caller = symbol("caller", "main", SymbolKind::Function, "src/caller.rs", 1);
+    let other = symbol("other", "helper", SymbolKind::Function, "src/caller.rs", 8);
+    db.store_symbols(&[parse, legacy, documented, import, caller, other])
+        .unwrap();
+
+    db.store_relationships(&[
+        relationship("caller", "parse", RelationshipKind::Calls, 2),
+        relationship("other", "parse", RelationshipKind::Calls, 9),
+        relationship("caller", "parse", RelationshipKind::Uses, 3),
+        relationship("parse", "parse", RelationshipKind::Calls, 5),
+        relationship("caller", "legacy", RelationshipKind::Calls, 4),
+    ])
+    .unwrap();
+    (temp_dir, db)
+}
+
+#[test]
+fn annotations_count_incoming_references_by_kind() {
+    let (_temp_dir, db) = seeded_db();
+    let report = generate_file_annotations(&db, "src/lib.rs").unwrap();
+
+    let names: Vec<&str> = report.symbols.iter().map(|s| s.name.as_str()).collect();
+    assert_eq!(
+        names,
+        ["parse", "legacy_parse", "old_api"],
+        "imports skipped"
+    );
+
+    let parse = &report.symbols[0];
+    assert_eq!(
+        parse.reference_count, 3,
+        "self-recursion is not a reference"
+    );
+    assert_eq!(parse.references_by_kind.get("calls"), Some(&2));
+    assert_eq!(parse.references_by_kind.get("uses"), Some(&1));
+    assert_eq!(report.symbols[1].reference_count, 1);
+    assert_eq!(report.symbols[2].reference_count, 0);
+}
+
+#[test]
+fn annotations_report_test_linkage_and_deprecation() {
+    let (_temp_dir, db) = seeded_db();
+    let report = generate_file_annotations(&db, "src/lib.rs").unwrap();
+
+    let parse = &report.symbols[0];
+    assert!(parse.has_tests());
+    assert_eq!(parse.test_count, 2);
+    assert_eq!(parse.best_test_tier.as_deref(), Some("thorough"));
+    assert!(parse.deprecated.is_none());
+
+    let legacy = &report.symbols[1];
+    assert!(!legacy.has_tests());
+    assert_eq!(
+        legacy.deprecated.as_deref(),
+        Some("#[deprecated(note = \"use parse\")]")
+    );
+    assert_eq!(
+        report.symbols[2].deprecated.as_deref(),
+        Some("Use parse instead.")
+    );
+
+    let json = serde_json::to_value(&report).unwrap();
+    assert!(json["symbols"][2].get("references_by_kind").is_none());
+    assert!(json["symbols"][0].get("deprecated").is_none());
+}
+
+#[test]
+fn annotations_for_unknown_file_are_empty() {
+    let (_temp_dir, db) = seeded_db();
+    let report = generate_file_annotations(&db, "src/missing.rs").unwrap();
+    assert_eq!(report.file_path, "src/missing.rs");
+    assert!(report.symbols.is_empty());
+}
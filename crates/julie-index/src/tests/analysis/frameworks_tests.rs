@@ -0,0 +1,303 @@
+use super::assert_recompute_is_idempotent;
+use crate::analysis::frameworks::{
+    classify_framework_role, compute_frameworks, detect_import_frameworks,
+    detect_manifest_frameworks,
+};
+use crate::analysis::workspace_projects::compute_workspace_projects;
+use julie_core::database::{FileInfo, SymbolDatabase};
+use julie_extractors::{AnnotationMarker, Symbol, SymbolKind, Visibility};
+use julie_test_support::{file_info_builder, symbol_builder};
+use tempfile::TempDir;
+
+fn file_info(path: &str, language: &str, content: &str) -> FileInfo {
+    file_info_builder(path)
+        .language(language)
+        .size(content.len() as i64)
+        .last_modified(1_700_000_000)
+        .last_indexed(0)
+        .line_count(content.lines().count() as i32)
+        .content(content)
+        .build()
+}
+
+fn marker(key: &str) -> AnnotationMarker {
+    AnnotationMarker {
+        annotation: key.to_string(),
+        annotation_key: key.to_ascii_lowercase(),
+        raw_text: None,
+        carrier: None,
+    }
+}
+
+fn role(symbol: &Symbol, parent: Option<&Symbol>, active: &[&str]) -> Option<&'static str> {
+    classify_framework_role(symbol, parent, active).map(|role| role.key)
+}
+
+#[test]
+fn detects_frameworks_from_manifests() {
+    let keys = |path: &str, content: &str| -> Vec<&'static str> {
+        detect_manifest_frameworks(path, content)
+            .into_iter()
+            .map(|(key, _)| key)
+            .collect()
+    };
+
+    let npm =
+        r#"{ "dependencies": { "react": "^18" }, "devDependencies": { "@angular/core": "17" } }"#;
+    assert_eq!(keys("web/package.json", npm), ["react", "angular"]);
+    assert!(keys("package.json", r#"{ "dependencies": { "preact": "10" } }"#).is_empty());
+
+    let pom = "<dependency><groupId>org.springframework.boot</groupId></dependency>";
+    assert_eq!(keys("api/pom.xml", pom), ["spring"]);
+    assert_eq!(
+        keys(
+            "api/build.gradle.kts",
+            "implementation(\"org.springframework.boot:spring-boot-starter-web\")"
+        ),
+        ["spring"]
+    );
+    assert_eq!(
+        keys(
+            "Gemfile",
+            "source \"https://rubygems.org\"\ngem \"rails\", \"~> 7.1\"\n"
+        ),
+        ["rails"]
+    );
+    assert!(keys("Gemfile", "gem \"rails-html-sanitizer\"\n").is_empty());
+    assert_eq!(
+        keys(
+            "src/Api/Api.csproj",
+            "<Project Sdk=\"Microsoft.NET.Sdk.Web\" />"
+        ),
+        ["aspnet"]
+    );
+    assert!(
+        keys(
+            "src/Lib/Lib.csproj",
+            "<Project Sdk=\"Microsoft.NET.Sdk\" />"
+        )
+        .is_empty()
+    );
+    assert_eq!(
+        keys(
+            "app/pubspec.yaml",
+            "dependencies:\n  flutter:\n    sdk: flutter\n"
+        ),
+        ["flutter"]
+    );
+}
+
+#[test]
+fn detects_frameworks_from_imports() {
+    assert_eq!(
+        detect_import_frameworks("src/App.tsx", "import React, { useState } from 'react';\n"),
+        ["react"]
+    );
+    assert_eq!(
+        detect_import_frameworks(
+            "src/index.js",
+            "const { render } = require(\"react-dom\");\nconst React = require(\"react\");\n"
+        ),
+        ["react"]
+    );
+    assert!(
+        detect_import_frameworks("src/config.ts", "export const renderer = 'react';\n").is_empty(),
+        "a string that is not an import is not evidence"
+    );
+    assert_eq!(
+        detect_import_frameworks(
+            "src/app.component.ts",
+            "import { Component } from '@angular/core';\n"
+        ),
+        ["angular"]
+    );
+    assert_eq!(
+        detect_import_frameworks(
+            "Api.java",
+            "import org.springframework.stereotype.Service;\n"
+        ),
+        ["spring"]
+    );
+    assert_eq!(
+        detect_import_frameworks("Startup.cs", "using Microsoft.AspNetCore.Mvc;\n"),
+        ["aspnet"]
+    );
+    assert_eq!(
+        detect_import_frameworks("lib/main.dart", "import 'package:flutter/material.dart';\n"),
+        ["flutter"]
+    );
+    assert!(detect_import_frameworks("lib/util.py", "import flask\n").is_empty());
+}
+
+#[test]
+fn classifies_symbols_by_framework_convention() {
+    let component = symbol_builder("c", "UserCard", "web/src/UserCard.tsx")
+        .kind(SymbolKind::Function)
+        .build();
+    assert_eq!(role(&component, None, &["react"]), Some("react_component"));
+    assert_eq!(role(&component, None, &["angular"]), None);
+    let hook = symbol_builder("h", "useUser", "web/src/useUser.tsx")
+        .kind(SymbolKind::Function)
+        .build();
+    assert_eq!(role(&hook, None, &["react"]), None);
+    let arrow = symbol_builder("a", "Avatar", "web/src/Avatar.jsx")
+        .kind(SymbolKind::Constant)
+        .signature("const Avatar = ({ url }) => <img src={url} />")
+        .build();
+    assert_eq!(role(&arrow, None, &["react"]), Some("react_component"));
+    let class_component = symbol_builder("k", "Legacy", "web/src/legacy.js")
+        .kind(SymbolKind::Class)
+        .signature("class Legacy extends React.Component")
+        .build();
+    assert_eq!(
+        role(&class_component, None, &["react"]),
+        Some("react_component")
+    );
+
+    let angular = symbol_builder("n", "UserService", "web/src/user.service.ts")
+        .kind(SymbolKind::Class)
+        .annotations(vec![marker("Injectable")])
+        .build();
+    assert_eq!(role(&angular, None, &["angular"]), Some("angular_service"));
+
+    let bean = symbol_builder("b", "UserService", "api/src/UserService.java")
+        .kind(SymbolKind::Class)
+        .annotations(vec![marker("Service")])
+        .build();
+    assert_eq!(role(&bean, None, &["spring"]), Some("spring_bean"));
+    assert_eq!(role(&bean, None, &["react"]), None);
+    let endpoint = symbol_builder("e", "listUsers", "api/src/UserController.java")
+        .kind(SymbolKind::Method)
+        .annotations(vec![marker("GetMapping")])
+        .build();
+    assert_eq!(role(&endpoint, None, &["spring"]), Some("spring_endpoint"));
+    let factory = symbol_builder("f", "clock", "api/src/AppConfig.java")
+        .kind(SymbolKind::Method)
+        .annotations(vec![marker("org.springframework.context.annotation.Bean")])
+        .build();
+    assert_eq!(role(&factory, None, &["spring"]), Some("spring_bean"));
+
+    let rails_controller = symbol_builder(
+        "rc",
+        "UsersController",
+        "app/controllers/users_controller.rb",
+    )
+    .kind(SymbolKind::Class)
+    .build();
+    let index = symbol_builder("ri", "index", "app/controllers/users_controller.rb")
+        .kind(SymbolKind::Method)
+        .parent_id("rc")
+        .build();
+    let finder = symbol_builder("rf", "find_user", "app/controllers/users_controller.rb")
+        .kind(SymbolKind::Method)
+        .parent_id("rc")
+        .visibility(Visibility::Private)
+        .build();
+    assert_eq!(
+        role(&index, Some(&rails_controller), &["rails"]),
+        Some("rails_controller_action")
+    );
+    assert_eq!(role(&finder, Some(&rails_controller), &["rails"]), None);
+
+    let api_controller = symbol_builder("ac", "Users", "src/Api/Users.cs")
+        .kind(SymbolKind::Class)
+        .annotations(vec![marker("ApiController")])
+        .build();
+    let get = symbol_builder("ag", "Get", "src/Api/Users.cs")
+        .kind(SymbolKind::Method)
+        .parent_id("ac")
+        .visibility(Visibility::Public)
+        .build();
+    assert_eq!(
+        role(&get, Some(&api_controller), &["aspnet"]),
+        Some("aspnet_controller_action")
+    );
+
+    let widget = symbol_builder("w", "UserTile", "app/lib/user_tile.dart")
+        .kind(SymbolKind::Class)
+        .signature("class UserTile extends StatelessWidget")
+        .build();
+    assert_eq!(role(&widget, None, &["flutter"]), Some("flutter_widget"));
+}
+
+#[test]
+fn computes_project_frameworks_and_symbol_roles() {
+    let temp_dir = TempDir::new().unwrap();
+    let mut db = SymbolDatabase::new(temp_dir.path().join("frameworks.db")).unwrap();
+    for (path, language, content) in [
+        (
+            "web/package.json",
+            "json",
+            r#"{ "name": "web", "dependencies": { "react": "^18" } }"#,
+        ),
+        (
+            "web/src/UserCard.tsx",
+            "tsx",
+            "export function UserCard() { return <div />; }",
+        ),
+        ("admin/package.json", "json", r#"{ "name": "admin" }"#),
+        (
+            "admin/src/Panel.tsx",
+            "tsx",
+            "import { useState } from \"react\";\nexport function Panel() { return null; }",
+        ),
+        ("tools/Report.tsx", "tsx", "export function Report() {}"),
+    ] {
+        db.store_file_info(&file_info(path, language, content))
+            .unwrap();
+    }
+    db.store_symbols(&[
+        symbol_builder("card", "UserCard", "web/src/UserCard.tsx")
+            .kind(SymbolKind::Function)
+            .build(),
+        symbol_builder("panel", "Panel", "admin/src/Panel.tsx")
+            .kind(SymbolKind::Function)
+            .build(),
+        symbol_builder("report", "Report", "tools/Report.tsx")
+            .kind(SymbolKind::Function)
+            .build(),
+    ])
+    .unwrap();
+    compute_workspace_projects(&db).unwrap();
+
+    let stats = compute_frameworks(&db).unwrap();
+    assert_eq!(stats.frameworks, 2);
+    assert_eq!(stats.symbols, 2, "tools/ uses no framework");
+
+    let frameworks: Vec<(String, Option<String>, String, String)> = db
+        .project_frameworks()
+        .unwrap()
+        .into_iter()
+        .map(|f| (f.root, f.project, f.framework, f.evidence))
+        .collect();
+    assert_eq!(
+        frameworks,
+        [
+            (
+                "admin".to_string(),
+                Some("admin".to_string()),
+                "react".to_string(),
+                "import in admin/src/Panel.tsx".to_string()
+            ),
+            (
+                "web".to_string(),
+                Some("web".to_string()),
+                "react".to_string(),
+                "package.json dependency react".to_string()
+            ),
+        ]
+    );
+
+    let ids = ["card", "panel", "report"].map(str::to_string);
+    let roles = db.framework_roles_for_symbols(&ids).unwrap();
+    assert_eq!(roles["card"].role, "react_component");
+    assert_eq!(roles["panel"].framework, "react");
+    assert!(!roles.contains_key("report"));
+
+    assert_recompute_is_idempotent(&db, compute_frameworks, |db| {
+        (
+            db.project_frameworks().unwrap(),
+            db.framework_roles_for_symbols(&ids).unwrap(),
+        )
+    });
+}
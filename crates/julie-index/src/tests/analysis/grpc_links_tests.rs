@@ -0,0 +1,255 @@
+use crate::analysis::cross_language_links::compute_cross_language_links;
+use crate::analysis::grpc_links::is_generated_grpc_file;
+use julie_core::database::{FileInfo, SymbolDatabase};
+use julie_extractors::{Symbol, SymbolKind};
+use julie_test_support::{file_info_builder, symbol_builder};
+use tempfile::TempDir;
+
+fn file_info(path: &str, language: &str) -> FileInfo {
+    file_info_builder(path)
+        .language(language)
+        .size(128)
+        .last_modified(1_700_000_000)
+        .last_indexed(0)
+        .line_count(40)
+        .content(format!("// {path}"))
+        .build()
+}
+
+fn symbol(
+    id: &str,
+    name: &str,
+    kind: SymbolKind,
+    file: (&str, &str),
+    parent: Option<&Symbol>,
+) -> Symbol {
+    let (file_path, language) = file;
+    let mut builder = symbol_builder(id, name, file_path)
+        .kind(kind)
+        .language(language)
+        .span(1, 0, 20, 1);
+    if let Some(parent) = parent {
+        builder = builder.parent_id(&parent.id);
+    }
+    builder.build()
+}
+
+const PROTO: (&str, &str) = ("proto/greeter.proto", "protobuf");
+const GO_GRPC: (&str, &str) = ("gen/greeter_grpc.pb.go", "go");
+const GO_PB: (&str, &str) = ("gen/greeter.pb.go", "go");
+const GO_APP: (&str, &str) = ("cmd/greeter.go", "go");
+const TS_CLIENT: (&str, &str) = ("web/src/greeterClient.ts", "typescript");
+
+fn seeded_db() -> (TempDir, SymbolDatabase) {
+    let temp_dir = TempDir::new().unwrap();
+    let mut db = SymbolDatabase::new(temp_dir.path().join("grpc.db")).unwrap();
+    for (path, language) in [PROTO, GO_GRPC, GO_PB, GO_APP, TS_CLIENT] {
+        db.store_file_info(&file_info(path, language)).unwrap();
+    }
+
+    let service = symbol(
+        "proto_greeter",
+        "Greeter",
+        SymbolKind::Interface,
+        PROTO,
+        None,
+    );
+    let rpc = symbol(
+        "proto_say_hello",
+        "SayHello",
+        SymbolKind::Method,
+        PROTO,
+        Some(&service),
+    );
+    let request = symbol(
+        "proto_request",
+        "HelloRequest",
+        SymbolKind::Struct,
+        PROTO,
+        None,
+    );
+
+    let go_client = symbol(
+        "go_client",
+        "GreeterClient",
+        SymbolKind::Interface,
+        GO_GRPC,
+        None,
+    );
+    let go_client_call = symbol(
+        "go_client_say_hello",
+        "SayHello",
+        SymbolKind::Method,
+        GO_GRPC,
+        Some(&go_client),
+    );
+    let go_server = symbol(
+        "go_server",
+        "UnimplementedGreeterServer",
+        SymbolKind::Struct,
+        GO_GRPC,
+        None,
+    );
+    let go_server_call = symbol(
+        "go_server_say_hello",
+        "SayHello",
+        SymbolKind::Method,
+        GO_GRPC,
+        Some(&go_server),
+    );
+    let go_new_client = symbol(
+        "go_new_client",
+        "NewGreeterClient",
+        SymbolKind::Function,
+        GO_GRPC,
+        None,
+    );
+    let go_request = symbol(
+        "go_request",
+        "HelloRequest",
+        SymbolKind::Struct,
+        GO_PB,
+        None,
+    );
+    // Hand-written type sharing the bare service name: not a stub.
+    let go_app = symbol(
+        "go_app_greeter",
+        "Greeter",
+        SymbolKind::Struct,
+        GO_APP,
+        None,
+    );
+    let ts_client = symbol(
+        "ts_client",
+        "GreeterClient",
+        SymbolKind::Class,
+        TS_CLIENT,
+        None,
+    );
+    let ts_client_call = symbol(
+        "ts_say_hello",
+        "sayHello",
+        SymbolKind::Method,
+        TS_CLIENT,
+        Some(&ts_client),
+    );
+    let ts_other = symbol(
+        "ts_close",
+        "close",
+        SymbolKind::Method,
+        TS_CLIENT,
+        Some(&ts_client),
+    );
+
+    db.store_symbols(&[
+        service,
+        rpc,
+        request,
+        go_client,
+        go_client_call,
+        go_server,
+        go_server_call,
+        go_new_client,
+        go_request,
+        go_app,
+        ts_client,
+        ts_client_call,
+        ts_other,
+    ])
+    .unwrap();
+    (temp_dir, db)
+}
+
+fn link_targets(db: &SymbolDatabase, id: &str) -> Vec<(String, f32, String)> {
+    db.cross_language_links_for_symbols(&[id.to_string()])
+        .unwrap()
+        .into_iter()
+        .map(|link| (link.to_symbol_id, link.confidence, link.reason))
+        .collect()
+}
+
+#[test]
+fn links_service_to_generated_and_hand_written_stubs() {
+    let (_temp_dir, db) = seeded_db();
+    let stats = compute_cross_language_links(&db).unwrap();
+    assert_eq!(
+        stats.grpc_pairs, 8,
+        "4 service stubs, 3 rpc methods, 1 message"
+    );
+
+    let links = link_targets(&db, "proto_greeter");
+    let ids: Vec<&str> = links.iter().map(|(id, _, _)| id.as_str()).collect();
+    for expected in ["go_client", "go_server", "go_new_client", "ts_client"] {
+        assert!(ids.contains(&expected), "missing {expected}: {links:?}");
+    }
+    let go_client = links.iter().find(|(id, _, _)| id == "go_client").unwrap();
+    assert!((go_client.1 - 0.95).abs() < 1e-6);
+    assert_eq!(go_client.2, "gRPC client stub of service Greeter");
+    let go_server = links.iter().find(|(id, _, _)| id == "go_server").unwrap();
+    assert_eq!(go_server.2, "gRPC server stub of service Greeter");
+    let ts_client = links.iter().find(|(id, _, _)| id == "ts_client").unwrap();
+    assert!(
+        ts_client.1 < go_client.1,
+        "hand-written stubs rank below generated ones"
+    );
+}
+
+#[test]
+fn links_rpc_to_stub_methods_in_both_directions() {
+    let (_temp_dir, db) = seeded_db();
+    compute_cross_language_links(&db).unwrap();
+
+    let links = link_targets(&db, "proto_say_hello");
+    let mut ids: Vec<&str> = links.iter().map(|(id, _, _)| id.as_str()).collect();
+    ids.sort();
+    assert_eq!(
+        ids,
+        ["go_client_say_hello", "go_server_say_hello", "ts_say_hello"]
+    );
+    let client = links
+        .iter()
+        .find(|(id, _, _)| id == "go_client_say_hello")
+        .unwrap();
+    assert_eq!(client.2, "gRPC client method for rpc Greeter.SayHello");
+
+    let reverse = link_targets(&db, "go_server_say_hello");
+    assert!(
+        reverse.iter().any(|(id, _, _)| id == "proto_say_hello"),
+        "{reverse:?}"
+    );
+}
+
+#[test]
+fn links_messages_only_to_generated_types() {
+    let (_temp_dir, db) = seeded_db();
+    compute_cross_language_links(&db).unwrap();
+
+    let links = link_targets(&db, "proto_request");
+    let generated = links
+        .iter()
+        .find(|(id, _, _)| id == "go_request")
+        .expect("generated message struct is linked");
+    assert_eq!(generated.2, "generated from proto message HelloRequest");
+    assert!((generated.1 - 0.95).abs() < 1e-6);
+
+    let service_links = link_targets(&db, "proto_greeter");
+    assert!(
+        service_links
+            .iter()
+            .all(|(id, _, reason)| id != "go_app_greeter" || !reason.starts_with("gRPC")),
+        "a hand-written type named like the service is not a stub: {service_links:?}"
+    );
+}
+
+#[test]
+fn recognizes_generated_grpc_files() {
+    assert!(is_generated_grpc_file("gen/greeter_grpc.pb.go"));
+    assert!(is_generated_grpc_file(
+        "src/main/java/io/x/GreeterGrpc.java"
+    ));
+    assert!(is_generated_grpc_file("Protos/GreeterGrpc.cs"));
+    assert!(is_generated_grpc_file("web/gen/greeter_grpc_pb.js"));
+    assert!(is_generated_grpc_file("web/gen/greeter_pb.d.ts"));
+    assert!(!is_generated_grpc_file("cmd/greeter.go"));
+    assert!(!is_generated_grpc_file("web/src/greeterClient.ts"));
+}
@@ -1,11 +1,50 @@
-//! Analysis layer tests — test-linkage, quality, change-risk, early-warning.
+//! Analysis layer tests — test-linkage, quality, change-risk, early-warning,
+//! file annotations, cross-language, gRPC and OpenAPI links, external-module
+//! attribution, module importers, build-time symbol aliases, Kotlin
+//! expect/actual links, Swift module map, monorepo project map, build target
+//! map, framework detection, API surface, naming drift, dependency matrix,
+//! quality gate, topic map.
 
+pub mod api_surface_tests;
+pub mod build_targets_tests;
 pub mod change_risk_tests;
+pub mod cross_language_links_tests;
+pub mod dependency_matrix_tests;
 pub mod early_warning_report_tests;
+pub mod expect_actual_tests;
+pub mod external_modules_tests;
+pub mod file_annotations_tests;
+pub mod frameworks_tests;
+pub mod grpc_links_tests;
 pub mod linkage_edge_cases_tests;
 pub mod literals_tests;
+pub mod module_imports_tests;
+pub mod naming_drift_tests;
+pub mod openapi_links_tests;
 pub mod quality_body_analysis_tests;
+pub mod quality_gate_tests;
 pub mod quality_pipeline_tests;
+pub mod swift_modules_tests;
+pub mod symbol_aliases_tests;
 pub mod test_linkage_tests;
 pub mod test_quality_tests;
 pub mod test_roles_tests;
+pub mod topics_tests;
+pub mod workspace_projects_tests;
+
+use julie_core::database::SymbolDatabase;
+
+/// Run the analysis pass `compute` again and assert `derived` reads the same
+/// rows as before: a pass replaces the table it derives instead of appending
+/// to it.
+pub(crate) fn assert_recompute_is_idempotent<S, T>(
+    db: &SymbolDatabase,
+    compute: impl Fn(&SymbolDatabase) -> anyhow::Result<S>,
+    derived: impl Fn(&SymbolDatabase) -> T,
+) where
+    T: PartialEq + std::fmt::Debug,
+{
+    let before = derived(db);
+    compute(db).expect("recomputing should succeed");
+    assert_eq!(derived(db), before, "recomputing changed the derived rows");
+}
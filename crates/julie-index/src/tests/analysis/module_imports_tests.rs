@@ -0,0 +1,322 @@
+use crate::analysis::external_modules::parse_imports;
+use crate::analysis::module_imports::{
+    ImportStyle, ImportTarget, find_importers, import_style, imports_file, imports_library,
+};
+use julie_core::database::SymbolDatabase;
+use julie_extractors::SymbolKind;
+use julie_test_support::{file_info_builder, symbol_builder};
+use tempfile::TempDir;
+
+/// Whether the single module parsed from `statement` in `importer` resolves
+/// to `target`.
+fn resolves(importer: &str, language: &str, statement: &str, target: &str) -> bool {
+    let modules = parse_imports(language, statement);
+    assert_eq!(modules.len(), 1, "{statement}: {modules:?}");
+    let style = import_style(language, statement, &modules[0]);
+    imports_file(importer, language, statement, &modules[0], style, target)
+}
+
+#[test]
+fn javascript_relative_imports_resolve_against_the_importer() {
+    let statement = "import { formatDate } from '../util/format'";
+    assert!(resolves(
+        "web/src/pages/home.tsx",
+        "typescript",
+        statement,
+        "web/src/util/format.ts"
+    ));
+    assert!(!resolves(
+        "web/src/pages/home.tsx",
+        "typescript",
+        statement,
+        "web/src/pages/util/format.ts"
+    ));
+    // Directory imports reach the index file; extensions in the specifier are
+    // dropped.
+    assert!(resolves(
+        "web/src/app.ts",
+        "typescript",
+        "import * as ui from './components'",
+        "web/src/components/index.ts"
+    ));
+    assert!(resolves(
+        "web/src/app.js",
+        "javascript",
+        "export * from './api.js'",
+        "web/src/api.ts"
+    ));
+    assert!(resolves(
+        "web/src/app.ts",
+        "typescript",
+        "import { Button } from '@/components/button'",
+        "web/src/components/button.tsx"
+    ));
+    // Bare specifiers name packages, not files.
+    assert!(!resolves(
+        "web/src/app.ts",
+        "typescript",
+        "import _ from 'lodash'",
+        "web/lodash.ts"
+    ));
+}
+
+#[test]
+fn python_imports_resolve_relative_and_absolute_modules() {
+    assert!(resolves(
+        "app/api/views.py",
+        "python",
+        "from .models import User",
+        "app/api/models.py"
+    ));
+    assert!(resolves(
+        "app/api/views.py",
+        "python",
+        "from ..core import db",
+        "app/core/db.py"
+    ));
+    assert!(resolves(
+        "app/api/views.py",
+        "python",
+        "from . import serializers",
+        "app/api/serializers.py"
+    ));
+    assert!(resolves(
+        "scripts/load.py",
+        "python",
+        "import app.core.db as database",
+        "src/app/core/db.py"
+    ));
+    assert!(resolves(
+        "scripts/load.py",
+        "python",
+        "from app.core import *",
+        "src/app/core/__init__.py"
+    ));
+    assert!(!resolves(
+        "scripts/load.py",
+        "python",
+        "from app.core import *",
+        "src/app/core/db.py"
+    ));
+}
+
+#[test]
+fn rust_use_paths_resolve_within_and_across_crates() {
+    let target = "crates/julie-index/src/search/query.rs";
+    assert!(resolves(
+        "crates/julie-index/src/search/index.rs",
+        "rust",
+        "use crate::search::query::{parse_query, QueryPlan};",
+        target
+    ));
+    assert!(resolves(
+        "crates/julie-index/src/lib.rs",
+        "rust",
+        "use crate::search::query::parse_query;",
+        target
+    ));
+    // `crate::` in another crate is that crate's module.
+    assert!(!resolves(
+        "crates/julie-tools/src/lib.rs",
+        "rust",
+        "use crate::search::query::parse_query;",
+        target
+    ));
+    assert!(resolves(
+        "crates/julie-tools/src/search/mod.rs",
+        "rust",
+        "use julie_index::search::query::parse_query as parse;",
+        target
+    ));
+    assert!(resolves(
+        "crates/julie-tools/src/lib.rs",
+        "rust",
+        "use julie_index::search::*;",
+        "crates/julie-index/src/search/mod.rs"
+    ));
+    // A braced import of `search::query` does not import `search` itself.
+    assert!(!resolves(
+        "crates/julie-index/src/lib.rs",
+        "rust",
+        "use crate::search::query::{parse_query};",
+        "crates/julie-index/src/search.rs"
+    ));
+}
+
+#[test]
+fn go_and_jvm_imports_resolve_packages_and_classes() {
+    let store = "internal/store/users.go";
+    assert!(resolves(
+        "cmd/api/main.go",
+        "go",
+        "import \"github.com/acme/app/internal/store\"",
+        store
+    ));
+    assert!(resolves(
+        "cmd/api/main.go",
+        "go",
+        "import db \"github.com/acme/app/internal/store\"",
+        "internal/store/orders.go"
+    ));
+    assert!(!resolves(
+        "cmd/api/main.go",
+        "go",
+        "import \"github.com/other/lib/store\"",
+        store
+    ));
+
+    let widget = "src/main/java/com/acme/ui/Widget.java";
+    assert!(resolves(
+        "src/main/java/com/acme/App.java",
+        "java",
+        "import com.acme.ui.Widget;",
+        widget
+    ));
+    assert!(resolves(
+        "src/main/java/com/acme/App.java",
+        "java",
+        "import static com.acme.ui.Widget.build;",
+        widget
+    ));
+    assert!(resolves(
+        "src/main/java/com/acme/App.java",
+        "java",
+        "import com.acme.ui.*;",
+        widget
+    ));
+    assert!(!resolves(
+        "src/main/java/com/acme/App.java",
+        "java",
+        "import com.acme.ui.Button;",
+        widget
+    ));
+}
+
+#[test]
+fn classifies_import_styles() {
+    let style = |language: &str, statement: &str| {
+        let module = parse_imports(language, statement).remove(0);
+        import_style(language, statement, &module)
+    };
+    assert_eq!(
+        style("typescript", "import { a } from './a'"),
+        ImportStyle::Named
+    );
+    assert_eq!(
+        style("typescript", "import { a as b } from './a'"),
+        ImportStyle::Aliased
+    );
+    assert_eq!(
+        style("typescript", "import * as a from './a'"),
+        ImportStyle::Namespace
+    );
+    assert_eq!(
+        style("typescript", "export * from './a'"),
+        ImportStyle::Wildcard
+    );
+    assert_eq!(
+        style("typescript", "import './polyfills'"),
+        ImportStyle::SideEffect
+    );
+    assert_eq!(
+        style("python", "from app.core import *"),
+        ImportStyle::Wildcard
+    );
+    assert_eq!(style("python", "import numpy as np"), ImportStyle::Aliased);
+    assert_eq!(style("rust", "use a::b::*;"), ImportStyle::Wildcard);
+    assert_eq!(
+        style("go", "import \"gopkg.in/yaml.v3\""),
+        ImportStyle::Named
+    );
+    assert_eq!(style("go", "import h \"net/http\""), ImportStyle::Aliased);
+    assert_eq!(
+        style("go", "import _ \"github.com/lib/pq\""),
+        ImportStyle::SideEffect
+    );
+}
+
+#[test]
+fn matches_libraries_by_name_or_module_prefix() {
+    let module = |language: &str, statement: &str| parse_imports(language, statement).remove(0);
+    assert!(imports_library(
+        "typescript",
+        &module("typescript", "import fp from 'lodash/fp'"),
+        "lodash"
+    ));
+    assert!(imports_library(
+        "python",
+        &module("python", "from requests.adapters import HTTPAdapter"),
+        "requests.adapters"
+    ));
+    assert!(!imports_library(
+        "python",
+        &module("python", "from requests_oauth import Session"),
+        "requests"
+    ));
+    assert!(!imports_library(
+        "typescript",
+        &module("typescript", "import { x } from './lodash'"),
+        "./lodash"
+    ));
+}
+
+#[test]
+fn find_importers_lists_each_statement_once() {
+    let temp_dir = TempDir::new().unwrap();
+    let mut db = SymbolDatabase::new(temp_dir.path().join("imports.db")).unwrap();
+    for (path, language) in [
+        ("web/src/util/format.ts", "typescript"),
+        ("web/src/pages/home.tsx", "typescript"),
+        ("web/src/pages/about.tsx", "typescript"),
+    ] {
+        db.store_file_info(&file_info_builder(path).language(language).build())
+            .unwrap();
+    }
+    let import = |id: &str, name: &str, file: &str, line: u32, statement: &str| {
+        symbol_builder(id, name, file)
+            .kind(SymbolKind::Import)
+            .language("typescript")
+            .span(line, 0, line, 60)
+            .signature(statement)
+            .build()
+    };
+    let both = "import { formatDate, formatTime as time } from '../util/format'";
+    db.store_symbols(&[
+        // One symbol per imported name, same statement.
+        import("home_date", "formatDate", "web/src/pages/home.tsx", 1, both),
+        import("home_time", "time", "web/src/pages/home.tsx", 1, both),
+        import(
+            "about_all",
+            "format",
+            "web/src/pages/about.tsx",
+            2,
+            "import * as format from '../util/format'",
+        ),
+        import(
+            "about_lodash",
+            "_",
+            "web/src/pages/about.tsx",
+            3,
+            "import _ from 'lodash'",
+        ),
+    ])
+    .unwrap();
+
+    let sites = find_importers(&db, ImportTarget::File("web/src/util/format.ts")).unwrap();
+    let summary: Vec<(&str, u32, ImportStyle)> = sites
+        .iter()
+        .map(|site| (site.file_path.as_str(), site.line, site.style))
+        .collect();
+    assert_eq!(
+        summary,
+        [
+            ("web/src/pages/about.tsx", 2, ImportStyle::Namespace),
+            ("web/src/pages/home.tsx", 1, ImportStyle::Aliased),
+        ]
+    );
+    assert_eq!(sites[1].bindings, ["formatDate", "time"]);
+
+    let lodash = find_importers(&db, ImportTarget::Library("lodash")).unwrap();
+    assert_eq!(lodash.len(), 1);
+    assert_eq!(lodash[0].statement, "import _ from 'lodash'");
+}
@@ -0,0 +1,154 @@
+use crate::analysis::naming_drift::{
+    NamingDriftOptions, generate_naming_drift_report, split_verb_subject,
+};
+use crate::search::clustering::ClusterOptions;
+use julie_core::database::{FileInfo, SymbolDatabase};
+use julie_extractors::{Symbol, SymbolKind};
+use julie_test_support::{file_info_builder, symbol_builder};
+use tempfile::TempDir;
+
+const DIMS: usize = 384;
+
+fn file_info(path: &str) -> FileInfo {
+    file_info_builder(path)
+        .language("typescript")
+        .size(128)
+        .last_modified(1_700_000_000)
+        .last_indexed(0)
+        .line_count(40)
+        .content(format!("// {path}"))
+        .build()
+}
+
+fn function(id: &str, name: &str, file_path: &str, line: u32) -> Symbol {
+    symbol_builder(id, name, file_path)
+        .kind(SymbolKind::Function)
+        .language("typescript")
+        .span(line, 0, line + 3, 1)
+        .build()
+}
+
+/// Unit vector along `axis`, nudged towards `jitter` so members of one group
+/// are near-identical but not equal.
+fn embedding(axis: usize, jitter: usize) -> Vec<f32> {
+    let mut vector = vec![0.0_f32; DIMS];
+    vector[axis] = 1.0;
+    vector[200 + jitter] = 0.05;
+    vector
+}
+
+fn seeded_db() -> (TempDir, SymbolDatabase) {
+    let temp_dir = TempDir::new().unwrap();
+    let mut db = SymbolDatabase::new(temp_dir.path().join("drift.db")).unwrap();
+    db.recreate_vectors_table(DIMS).unwrap();
+    for path in [
+        "src/users.ts",
+        "src/admin.ts",
+        "src/api.ts",
+        "src/legacy.ts",
+        "src/orders.ts",
+        "src/config.ts",
+        "src/tests/users.test.ts",
+    ] {
+        db.store_file_info(&file_info(path)).unwrap();
+    }
+
+    db.store_symbols(&[
+        function("get_user", "getUser", "src/users.ts", 1),
+        function("admin_get_user", "getUser", "src/admin.ts", 1),
+        function("fetch_user", "fetchUser", "src/api.ts", 1),
+        function("retrieve_user", "retrieveUser", "src/legacy.ts", 1),
+        // Not embedded: never considered.
+        function("load_user", "loadUser", "src/legacy.ts", 10),
+        function("save_order", "saveOrder", "src/orders.ts", 1),
+        function("store_order", "storeOrder", "src/orders.ts", 10),
+        // Unrelated behaviour, but makes `save` the workspace's preferred verb.
+        function("save_invoice", "saveInvoice", "src/orders.ts", 20),
+        // Similar behaviour, different subjects: not drift.
+        function("load_config", "loadConfig", "src/config.ts", 1),
+        function("read_settings", "readSettings", "src/config.ts", 10),
+        function("mock_user", "fetchUser", "src/tests/users.test.ts", 1),
+    ])
+    .unwrap();
+
+    db.store_embeddings(&[
+        ("get_user".to_string(), embedding(0, 0)),
+        ("admin_get_user".to_string(), embedding(0, 1)),
+        ("fetch_user".to_string(), embedding(0, 2)),
+        ("retrieve_user".to_string(), embedding(0, 3)),
+        ("save_order".to_string(), embedding(1, 0)),
+        ("store_order".to_string(), embedding(1, 1)),
+        ("save_invoice".to_string(), embedding(2, 0)),
+        ("load_config".to_string(), embedding(3, 0)),
+        ("read_settings".to_string(), embedding(3, 1)),
+        ("mock_user".to_string(), embedding(0, 4)),
+    ])
+    .unwrap();
+    (temp_dir, db)
+}
+
+#[test]
+fn split_verb_subject_tokenizes_camel_and_snake_case() {
+    assert_eq!(
+        split_verb_subject("getUserById"),
+        Some(("get".to_string(), "user_by_id".to_string()))
+    );
+    assert_eq!(
+        split_verb_subject("fetch_user"),
+        Some(("fetch".to_string(), "user".to_string()))
+    );
+    assert_eq!(split_verb_subject("render"), None, "no subject to compare");
+}
+
+#[test]
+fn naming_drift_groups_similar_symbols_with_different_verbs() {
+    let (_temp_dir, db) = seeded_db();
+    let report = generate_naming_drift_report(&db, &NamingDriftOptions::default()).unwrap();
+
+    assert_eq!(
+        report.candidates, 9,
+        "test file and unembedded symbol excluded"
+    );
+    let subjects: Vec<&str> = report.clusters.iter().map(|c| c.subject.as_str()).collect();
+    assert_eq!(subjects, ["user", "order"], "largest group first");
+
+    let users = &report.clusters[0];
+    let variants: Vec<(&str, usize)> = users
+        .variants
+        .iter()
+        .map(|v| (v.verb.as_str(), v.count))
+        .collect();
+    assert_eq!(variants, [("get", 2), ("fetch", 1), ("retrieve", 1)]);
+    assert_eq!(users.suggested_verb, "get");
+    assert_eq!(users.symbols.len(), 4);
+
+    let orders = &report.clusters[1];
+    assert_eq!(
+        orders.suggested_verb, "save",
+        "tie in the group is broken by workspace-wide usage"
+    );
+}
+
+#[test]
+fn naming_drift_focus_path_and_threshold_narrow_the_report() {
+    let (_temp_dir, db) = seeded_db();
+
+    let options = NamingDriftOptions {
+        focus_path: Some("src/orders.ts".to_string()),
+        ..Default::default()
+    };
+    let report = generate_naming_drift_report(&db, &options).unwrap();
+    assert_eq!(report.candidates, 3);
+    let subjects: Vec<&str> = report.clusters.iter().map(|c| c.subject.as_str()).collect();
+    assert_eq!(subjects, ["order"]);
+
+    let strict = NamingDriftOptions {
+        clustering: ClusterOptions {
+            min_score: 0.999,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    let report = generate_naming_drift_report(&db, &strict).unwrap();
+    assert!(report.clusters.is_empty(), "{:?}", report.clusters);
+}
@@ -0,0 +1,227 @@
+use std::collections::HashMap;
+
+use crate::analysis::cross_language_links::compute_cross_language_links;
+use crate::analysis::openapi_links::{operation_key, template_match_score};
+use julie_core::database::SymbolDatabase;
+use julie_core::database::bulk::atomic::{AtomicPersistenceMetadata, CanonicalWriteSet};
+use julie_extractors::base::StructuralFact;
+use julie_extractors::{Symbol, SymbolKind};
+use julie_test_support::{file_info_builder, symbol_builder};
+use serde_json::json;
+use tempfile::TempDir;
+
+const SPEC: (&str, &str) = ("api/petstore.yaml", "yaml");
+const SERVER: (&str, &str) = ("server/routes/pets.ts", "typescript");
+const SDK: (&str, &str) = ("sdk/pets_api.py", "python");
+const WEB: (&str, &str) = ("web/src/petPage.ts", "typescript");
+
+fn symbol(id: &str, name: &str, kind: SymbolKind, file: (&str, &str)) -> Symbol {
+    let (file_path, language) = file;
+    symbol_builder(id, name, file_path)
+        .kind(kind)
+        .language(language)
+        .span(1, 0, 20, 1)
+        .build()
+}
+
+fn operation(id: &str, name: &str, method: &str, path: &str, operation_id: Option<&str>) -> Symbol {
+    let mut symbol = symbol_builder(id, name, SPEC.0)
+        .kind(SymbolKind::Method)
+        .language(SPEC.1)
+        .span(1, 0, 20, 1)
+        .signature(format!("{method} {path}"))
+        .metadata(HashMap::from([(
+            "openapi".to_string(),
+            json!({
+                "kind": "operation",
+                "method": method,
+                "path": path,
+                "full_path": format!("/v1{path}"),
+                "operation_id": operation_id,
+            }),
+        )]))
+        .build();
+    symbol.semantic_group = Some("openapi".to_string());
+    symbol
+}
+
+fn fact(
+    id: &str,
+    file: (&str, &str),
+    pattern_id: &str,
+    symbol_id: &str,
+    metadata: serde_json::Value,
+) -> StructuralFact {
+    let (file_path, language) = file;
+    StructuralFact {
+        id: id.to_string(),
+        file_path: file_path.to_string(),
+        language: language.to_string(),
+        pattern_id: pattern_id.to_string(),
+        capture_name: "route".to_string(),
+        node_kind: "call_expression".to_string(),
+        containing_symbol_id: Some(symbol_id.to_string()),
+        start_line: 5,
+        start_column: 0,
+        end_line: 5,
+        end_column: 20,
+        start_byte: 50,
+        end_byte: 70,
+        confidence: 0.9,
+        metadata: Some(serde_json::from_value(metadata).unwrap()),
+    }
+}
+
+fn seeded_db() -> (TempDir, SymbolDatabase) {
+    let temp_dir = TempDir::new().unwrap();
+    let mut db = SymbolDatabase::new(temp_dir.path().join("openapi.db")).unwrap();
+    let files: Vec<_> = [SPEC, SERVER, SDK, WEB]
+        .into_iter()
+        .map(|(path, language)| file_info_builder(path).language(language).build())
+        .collect();
+    let symbols = vec![
+        operation(
+            "op_get_pet",
+            "getPetById",
+            "GET",
+            "/pets/{petId}",
+            Some("getPetById"),
+        ),
+        operation(
+            "op_delete_pet",
+            "DELETE /pets/{petId}",
+            "DELETE",
+            "/pets/{petId}",
+            None,
+        ),
+        operation(
+            "op_list_mine",
+            "listMyPets",
+            "GET",
+            "/pets/mine",
+            Some("listMyPets"),
+        ),
+        symbol("ts_show_pet", "showPet", SymbolKind::Function, SERVER),
+        symbol("ts_delete_pet", "removePet", SymbolKind::Function, SERVER),
+        symbol("ts_list_mine", "listMine", SymbolKind::Function, SERVER),
+        symbol("py_get_pet", "get_pet_by_id", SymbolKind::Method, SDK),
+        symbol("ts_load_pet", "loadPet", SymbolKind::Function, WEB),
+    ];
+    let facts = vec![
+        fact(
+            "fact_show",
+            SERVER,
+            "express.route.v1",
+            "ts_show_pet",
+            json!({ "verb": "GET", "normalized_route_template": "/pets/:id" }),
+        ),
+        fact(
+            "fact_delete",
+            SERVER,
+            "express.route.v1",
+            "ts_delete_pet",
+            json!({ "verb": "DELETE", "normalized_route_template": "/v1/pets/:id" }),
+        ),
+        fact(
+            "fact_mine",
+            SERVER,
+            "express.route.v1",
+            "ts_list_mine",
+            json!({ "verb": "GET", "normalized_route_template": "/pets/mine" }),
+        ),
+        fact(
+            "fact_load",
+            WEB,
+            "http.client_request.v1",
+            "ts_load_pet",
+            json!({ "verb": "get", "target_path": "https://api.example.com/v1/pets/42?expand=1" }),
+        ),
+    ];
+    let write_set = CanonicalWriteSet {
+        files: &files,
+        symbols: &symbols,
+        structural_facts: &facts,
+        ..Default::default()
+    };
+    let paths: Vec<String> = files.iter().map(|file| file.path.clone()).collect();
+    db.incremental_update_atomic_with_metadata(
+        &paths,
+        &write_set,
+        "openapi-test",
+        AtomicPersistenceMetadata::default(),
+    )
+    .unwrap();
+    (temp_dir, db)
+}
+
+fn link_targets(db: &SymbolDatabase, id: &str) -> Vec<(String, f32, String)> {
+    let mut links: Vec<_> = db
+        .cross_language_links_for_symbols(&[id.to_string()])
+        .unwrap()
+        .into_iter()
+        .map(|link| (link.to_symbol_id, link.confidence, link.reason))
+        .collect();
+    links.sort_by(|a, b| a.0.cmp(&b.0));
+    links
+}
+
+#[test]
+fn links_operations_to_handlers_sdk_methods_and_clients() {
+    let (_temp_dir, db) = seeded_db();
+    let stats = compute_cross_language_links(&db).unwrap();
+    assert_eq!(stats.openapi_pairs, 5);
+
+    let links = link_targets(&db, "op_get_pet");
+    let ids: Vec<&str> = links.iter().map(|(id, _, _)| id.as_str()).collect();
+    assert_eq!(ids, ["py_get_pet", "ts_load_pet", "ts_show_pet"]);
+    assert_eq!(
+        links[2].2,
+        "serves OpenAPI operation getPetById (GET /pets/{petId})"
+    );
+    assert!((links[2].1 - 0.9).abs() < 1e-6);
+    assert_eq!(links[0].2, "named after OpenAPI operationId getPetById");
+    assert!((links[0].1 - 0.75).abs() < 1e-6);
+    assert_eq!(
+        links[1].2,
+        "calls OpenAPI operation getPetById (GET /pets/{petId})"
+    );
+
+    let reverse = link_targets(&db, "ts_load_pet");
+    assert_eq!(reverse.len(), 1);
+    assert_eq!(reverse[0].0, "op_get_pet");
+}
+
+#[test]
+fn matches_base_path_and_prefers_literal_routes() {
+    let (_temp_dir, db) = seeded_db();
+    compute_cross_language_links(&db).unwrap();
+
+    // Mounted under the server base path `/v1`.
+    let delete = link_targets(&db, "op_delete_pet");
+    assert_eq!(delete.len(), 1);
+    assert_eq!(delete[0].0, "ts_delete_pet");
+    assert_eq!(delete[0].2, "serves OpenAPI operation DELETE /pets/{petId}");
+
+    // `/pets/mine` is served by the literal route, not `/pets/:id`.
+    let mine = link_targets(&db, "op_list_mine");
+    let ids: Vec<&str> = mine.iter().map(|(id, _, _)| id.as_str()).collect();
+    assert_eq!(ids, ["ts_list_mine"]);
+}
+
+#[test]
+fn normalizes_operation_ids_and_route_templates() {
+    assert_eq!(operation_key("getPetById"), "getpetbyid");
+    assert_eq!(operation_key("get_pet_by_id"), "getpetbyid");
+    assert_eq!(operation_key("GetPetByIdAsync"), "getpetbyid");
+    assert_eq!(operation_key("get-pet-by-id"), "getpetbyid");
+
+    assert_eq!(template_match_score("/pets/{petId}", "/pets/:id"), Some(1));
+    assert_eq!(template_match_score("/pets/mine", "/pets/<id>"), Some(1));
+    assert_eq!(template_match_score("/pets/mine", "/pets/mine"), Some(2));
+    assert_eq!(template_match_score("/pets/{petId}", "/pets/mine"), None);
+    assert_eq!(template_match_score("/pets", "/pets/{id}"), None);
+    assert_eq!(
+        template_match_score("https://api.example.com/v1/pets/7?x=1", "/v1/pets/{id}"),
+        Some(2)
+    );
+}
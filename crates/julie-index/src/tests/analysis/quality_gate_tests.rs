@@ -0,0 +1,139 @@
+use crate::analysis::quality_gate::{
+    QualityCheck, QualitySnapshot, capture_quality_snapshot, evaluate_quality_gate,
+};
+use julie_core::database::SymbolDatabase;
+use julie_extractors::{Symbol, SymbolKind, Visibility};
+use julie_test_support::{file_info_builder, relationship_builder, symbol_builder};
+use tempfile::TempDir;
+
+fn function(id: &str, name: &str, line: u32) -> Symbol {
+    symbol_builder(id, name, "src/lib.ts")
+        .kind(SymbolKind::Function)
+        .language("typescript")
+        .span(line, 0, line + 3, 1)
+        .visibility(Visibility::Public)
+        .build()
+}
+
+fn snapshot(dead_code: &[&str], api_surface: &[&str], deprecated_usages: usize) -> QualitySnapshot {
+    QualitySnapshot {
+        dead_code: dead_code.iter().map(|s| s.to_string()).collect(),
+        api_surface: api_surface.iter().map(|s| s.to_string()).collect(),
+        deprecated_usages,
+    }
+}
+
+#[test]
+fn quality_check_parses_cli_spellings() {
+    assert_eq!("dead-code".parse(), Ok(QualityCheck::DeadCode));
+    assert_eq!("api".parse(), Ok(QualityCheck::ApiSurface));
+    assert_eq!("API_SURFACE".parse(), Ok(QualityCheck::ApiSurface));
+    assert_eq!("deprecated".parse(), Ok(QualityCheck::DeprecatedUsage));
+    assert!("complexity".parse::<QualityCheck>().is_err());
+}
+
+#[test]
+fn capture_reads_dead_code_api_surface_and_deprecated_usages() {
+    let temp_dir = TempDir::new().unwrap();
+    let mut db = SymbolDatabase::new(temp_dir.path().join("gate.db")).unwrap();
+    db.store_file_info(
+        &file_info_builder("src/lib.ts")
+            .language("typescript")
+            .size(128)
+            .last_modified(1_700_000_000)
+            .last_indexed(0)
+            .line_count(40)
+            .build(),
+    )
+    .unwrap();
+
+    let mut old_api = function("old_api", "oldApi", 1);
+    old_api.doc_comment = Some("/** @deprecated use newApi */".to_string());
+    db.store_symbols(&[
+        old_api,
+        function("caller", "caller", 10),
+        function("unused", "unused", 20),
+    ])
+    .unwrap();
+    db.store_relationships(&[
+        relationship_builder("r1", "caller", "old_api")
+            .file_path("src/lib.ts")
+            .line_number(11)
+            .build(),
+        relationship_builder("r2", "caller", "old_api")
+            .file_path("src/lib.ts")
+            .line_number(12)
+            .build(),
+    ])
+    .unwrap();
+    db.compute_reference_scores().unwrap();
+
+    let snapshot = capture_quality_snapshot(&db).unwrap();
+    assert_eq!(
+        snapshot.dead_code.iter().collect::<Vec<_>>(),
+        ["src/lib.ts::caller", "src/lib.ts::unused"]
+    );
+    assert!(
+        snapshot
+            .api_surface
+            .contains("src/lib.ts::oldApi (function)"),
+        "{:?}",
+        snapshot.api_surface
+    );
+    assert_eq!(snapshot.api_surface.len(), 3);
+    assert_eq!(snapshot.deprecated_usages, 2);
+}
+
+#[test]
+fn gate_fails_on_new_dead_code_removed_api_and_more_deprecated_usages() {
+    let base = snapshot(
+        &["a.ts::old"],
+        &["a.ts::run (function)", "a.ts::stop (function)"],
+        3,
+    );
+    let head = snapshot(
+        &["a.ts::old", "b.ts::orphan"],
+        &["a.ts::run (function)", "b.ts::start (function)"],
+        4,
+    );
+
+    let report = evaluate_quality_gate("origin/main", &base, &head, &[]);
+    assert!(!report.passed);
+    assert_eq!(report.base_ref, "origin/main");
+
+    let checks: Vec<(QualityCheck, bool)> =
+        report.checks.iter().map(|c| (c.check, c.passed)).collect();
+    assert_eq!(
+        checks,
+        [
+            (QualityCheck::DeadCode, false),
+            (QualityCheck::ApiSurface, false),
+            (QualityCheck::DeprecatedUsage, false),
+        ]
+    );
+    assert_eq!(report.checks[0].added, ["b.ts::orphan"]);
+    assert_eq!(report.checks[1].removed, ["a.ts::stop (function)"]);
+    assert_eq!(report.checks[1].added, ["b.ts::start (function)"]);
+    assert_eq!((report.checks[2].base, report.checks[2].head), (3, 4));
+}
+
+#[test]
+fn gate_passes_on_improvements_and_runs_only_selected_checks() {
+    let base = snapshot(&["a.ts::old"], &["a.ts::run (function)"], 3);
+    let head = snapshot(&[], &["a.ts::run (function)", "a.ts::walk (function)"], 1);
+
+    let report = evaluate_quality_gate("v1.2.0", &base, &head, &[]);
+    assert!(report.passed, "{report:?}");
+    assert_eq!(report.checks[0].removed, ["a.ts::old"]);
+
+    let regressed = snapshot(&["a.ts::old", "a.ts::new"], &[], 9);
+    let report = evaluate_quality_gate(
+        "v1.2.0",
+        &base,
+        &regressed,
+        &[QualityCheck::DeprecatedUsage, QualityCheck::DeprecatedUsage],
+    );
+    assert_eq!(report.checks.len(), 1, "duplicates collapse");
+    assert_eq!(report.checks[0].check, QualityCheck::DeprecatedUsage);
+    assert!(!report.passed);
+}
@@ -0,0 +1,224 @@
+use super::assert_recompute_is_idempotent;
+use crate::analysis::swift_modules::{
+    SwiftTarget, assign_swift_modules, compute_swift_modules, parse_package_manifest,
+    parse_xcode_project,
+};
+use julie_core::database::{FileInfo, SymbolDatabase};
+use julie_test_support::file_info_builder;
+use tempfile::TempDir;
+
+const PACKAGE_SWIFT: &str = r#"// swift-tools-version:5.9
+import PackageDescription
+
+let package = Package(
+    name: "Kit",
+    products: [
+        .library(name: "Networking", targets: ["Networking"]),
+    ],
+    targets: [
+        .target(
+            name: "Networking",
+            dependencies: [.target(name: "Core"), .product(name: "Logging", package: "swift-log")]
+        ),
+        .target(name: "Core", path: "Shared/Core"),
+        .executableTarget(name: "kit-cli"),
+        .testTarget(name: "NetworkingTests", dependencies: ["Networking"]),
+    ]
+)
+"#;
+
+const PBXPROJ: &str = r#"// !$*UTF8*$!
+{
+	archiveVersion = 1;
+	objectVersion = 56;
+	objects = {
+
+/* Begin PBXBuildFile section */
+		B1 /* AppDelegate.swift in Sources */ = {isa = PBXBuildFile; fileRef = F1 /* AppDelegate.swift */; };
+		B2 /* Client.swift in Sources */ = {isa = PBXBuildFile; fileRef = F2 /* Client.swift */; };
+/* End PBXBuildFile section */
+
+		F1 /* AppDelegate.swift */ = {isa = PBXFileReference; lastKnownFileType = sourcecode.swift; path = AppDelegate.swift; sourceTree = "<group>"; };
+		F2 /* Client.swift */ = {isa = PBXFileReference; lastKnownFileType = sourcecode.swift; name = Client.swift; path = Networking/Client.swift; sourceTree = SOURCE_ROOT; };
+		F3 /* UIKit.framework */ = {isa = PBXFileReference; name = UIKit.framework; path = System/Library/Frameworks/UIKit.framework; sourceTree = SDKROOT; };
+		G0 = {isa = PBXGroup; children = (G1, F2, S1, F3); sourceTree = "<group>"; };
+		G1 /* My App */ = {isa = PBXGroup; children = (F1); path = "My App"; sourceTree = "<group>"; };
+		S1 /* Widgets */ = {isa = PBXFileSystemSynchronizedRootGroup; path = Widgets; sourceTree = "<group>"; };
+		P1 /* Sources */ = {isa = PBXSourcesBuildPhase; files = (B1, B2, ); };
+		P2 /* Sources */ = {isa = PBXSourcesBuildPhase; files = ( ); };
+		T1 /* My App */ = {isa = PBXNativeTarget; buildConfigurationList = L1; buildPhases = (P1); name = "My App"; };
+		T2 /* Widgets */ = {isa = PBXNativeTarget; buildConfigurationList = L2; buildPhases = (P2); fileSystemSynchronizedGroups = (S1); name = WidgetsExtension; };
+		L1 = {isa = XCConfigurationList; buildConfigurations = (C1); };
+		L2 = {isa = XCConfigurationList; buildConfigurations = (C2); };
+		C1 /* Debug */ = {isa = XCBuildConfiguration; buildSettings = { PRODUCT_MODULE_NAME = "$(PRODUCT_NAME:c99extidentifier)"; }; name = Debug; };
+		C2 /* Debug */ = {isa = XCBuildConfiguration; buildSettings = { PRODUCT_MODULE_NAME = Widgets; SWIFT_VERSION = 5.0; }; name = Debug; };
+		R1 /* Project object */ = {isa = PBXProject; mainGroup = G0; projectDirPath = ""; targets = (T1, T2); };
+	};
+	rootObject = R1 /* Project object */;
+}
+"#;
+
+fn file_info(path: &str, language: &str, content: &str) -> FileInfo {
+    file_info_builder(path)
+        .language(language)
+        .size(content.len() as i64)
+        .last_modified(1_700_000_000)
+        .last_indexed(0)
+        .line_count(content.lines().count() as i32)
+        .content(content)
+        .build()
+}
+
+fn strings(paths: &[&str]) -> Vec<String> {
+    paths.iter().map(|path| path.to_string()).collect()
+}
+
+#[test]
+fn parses_package_targets_with_default_and_explicit_paths() {
+    let swift_files = strings(&["pkg/Sources/Networking/Client.swift"]);
+    let targets = parse_package_manifest("pkg/Package.swift", PACKAGE_SWIFT, &swift_files);
+
+    let summary: Vec<(&str, &str)> = targets
+        .iter()
+        .map(|t| (t.module.as_str(), t.source_dirs[0].as_str()))
+        .collect();
+    assert_eq!(
+        summary,
+        [
+            ("Networking", "pkg/Sources/Networking"),
+            ("Core", "pkg/Shared/Core"),
+            ("kit_cli", "pkg/Sources/kit-cli"),
+            ("NetworkingTests", "pkg/Tests/NetworkingTests"),
+        ],
+        "`.target(name:)` inside dependencies is not a declaration"
+    );
+}
+
+#[test]
+fn parses_xcode_sources_phase_and_synchronized_folders() {
+    let targets = parse_xcode_project("ios/App.xcodeproj/project.pbxproj", PBXPROJ);
+
+    assert_eq!(
+        targets,
+        [
+            SwiftTarget {
+                module: "My_App".to_string(),
+                source_dirs: vec![],
+                source_files: strings(&[
+                    "ios/My App/AppDelegate.swift",
+                    "ios/Networking/Client.swift"
+                ]),
+            },
+            SwiftTarget {
+                module: "Widgets".to_string(),
+                source_dirs: strings(&["ios/Widgets"]),
+                source_files: vec![],
+            },
+        ]
+    );
+    assert!(parse_xcode_project("App.xcodeproj/project.pbxproj", "not a plist").is_empty());
+}
+
+#[test]
+fn explicit_membership_beats_the_longest_containing_directory() {
+    let targets = vec![
+        (
+            "Package.swift".to_string(),
+            SwiftTarget {
+                module: "App".to_string(),
+                source_dirs: strings(&["Sources"]),
+                source_files: vec![],
+            },
+        ),
+        (
+            "Package.swift".to_string(),
+            SwiftTarget {
+                module: "Feature".to_string(),
+                source_dirs: strings(&["Sources/Feature"]),
+                source_files: vec![],
+            },
+        ),
+        (
+            "App.xcodeproj/project.pbxproj".to_string(),
+            SwiftTarget {
+                module: "Shared".to_string(),
+                source_dirs: vec![],
+                source_files: strings(&["Sources/Feature/Shared.swift"]),
+            },
+        ),
+    ];
+    let files = strings(&[
+        "Sources/main.swift",
+        "Sources/Feature/View.swift",
+        "Sources/Feature/Shared.swift",
+        "Scripts/tool.swift",
+    ]);
+
+    let modules: Vec<(String, String)> = assign_swift_modules(&files, &targets)
+        .into_iter()
+        .map(|row| (row.file_path, row.module))
+        .collect();
+    assert_eq!(
+        modules,
+        [
+            ("Sources/main.swift".to_string(), "App".to_string()),
+            (
+                "Sources/Feature/View.swift".to_string(),
+                "Feature".to_string()
+            ),
+            (
+                "Sources/Feature/Shared.swift".to_string(),
+                "Shared".to_string()
+            ),
+        ],
+        "files outside every target get no module"
+    );
+}
+
+#[test]
+fn computes_and_stores_the_module_map_from_indexed_manifests() {
+    let temp_dir = TempDir::new().unwrap();
+    let db = SymbolDatabase::new(temp_dir.path().join("swift.db")).unwrap();
+    db.store_file_info(&file_info("pkg/Package.swift", "swift", PACKAGE_SWIFT))
+        .unwrap();
+    db.store_file_info(&file_info(
+        "ios/App.xcodeproj/project.pbxproj",
+        "text",
+        PBXPROJ,
+    ))
+    .unwrap();
+    for path in [
+        "pkg/Sources/Networking/Client.swift",
+        "pkg/Shared/Core/Store.swift",
+        "ios/My App/AppDelegate.swift",
+        "ios/Networking/Client.swift",
+        "ios/Widgets/Clock.swift",
+    ] {
+        db.store_file_info(&file_info(path, "swift", "struct S {}"))
+            .unwrap();
+    }
+
+    let stats = compute_swift_modules(&db).unwrap();
+    assert_eq!(stats.manifests, 2);
+    assert_eq!(stats.targets, 6);
+    assert_eq!(stats.files, 5, "Package.swift itself has no module");
+
+    let modules = db
+        .swift_modules_for_files(&strings(&[
+            "pkg/Sources/Networking/Client.swift",
+            "pkg/Shared/Core/Store.swift",
+            "ios/Networking/Client.swift",
+            "ios/Widgets/Clock.swift",
+            "pkg/Package.swift",
+        ]))
+        .unwrap();
+    assert_eq!(modules["pkg/Sources/Networking/Client.swift"], "Networking");
+    assert_eq!(modules["pkg/Shared/Core/Store.swift"], "Core");
+    assert_eq!(modules["ios/Networking/Client.swift"], "My_App");
+    assert_eq!(modules["ios/Widgets/Clock.swift"], "Widgets");
+    assert!(!modules.contains_key("pkg/Package.swift"));
+
+    assert_recompute_is_idempotent(&db, compute_swift_modules, |db| {
+        db.swift_module_file_count().unwrap()
+    });
+}
@@ -0,0 +1,248 @@
+use super::assert_recompute_is_idempotent;
+use crate::analysis::symbol_aliases::{
+    InferredAlias, alias_hook, compute_symbol_aliases, named_argument,
+};
+use julie_core::database::{FileInfo, SymbolAlias, SymbolDatabase};
+use julie_extractors::{AnnotationMarker, Symbol, SymbolKind};
+use julie_test_support::{file_info_builder, symbol_builder};
+use tempfile::TempDir;
+
+fn file_info(path: &str, language: &str) -> FileInfo {
+    file_info_builder(path)
+        .language(language)
+        .size(128)
+        .last_modified(1_700_000_000)
+        .last_indexed(0)
+        .line_count(40)
+        .content(format!("// {path}"))
+        .build()
+}
+
+fn marker(annotation: &str, raw_text: &str, carrier: Option<&str>) -> AnnotationMarker {
+    AnnotationMarker {
+        annotation: annotation.to_string(),
+        annotation_key: annotation.to_ascii_lowercase(),
+        raw_text: Some(raw_text.to_string()),
+        carrier: carrier.map(str::to_string),
+    }
+}
+
+fn annotated(
+    id: &str,
+    name: &str,
+    kind: SymbolKind,
+    language: &str,
+    annotations: Vec<AnnotationMarker>,
+) -> Symbol {
+    symbol_builder(id, name, "src/lib")
+        .kind(kind)
+        .language(language)
+        .annotations(annotations)
+        .build()
+}
+
+fn infer(symbol: &Symbol, parent: Option<&Symbol>) -> Vec<String> {
+    let hook = alias_hook(&symbol.language).expect("language has an alias hook");
+    hook(symbol, parent)
+        .into_iter()
+        .map(|InferredAlias { alias, .. }| alias)
+        .collect()
+}
+
+#[test]
+fn parses_named_attribute_arguments() {
+    assert_eq!(
+        named_argument(r#"napi(js_name = "fetchUser")"#, "js_name"),
+        Some("fetchUser")
+    );
+    assert_eq!(
+        named_argument("wasm_bindgen(js_name = renderChart, skip)", "js_name"),
+        Some("renderChart")
+    );
+    assert_eq!(
+        named_argument(r#"pyclass(module = "app", name = "Point")"#, "name"),
+        Some("Point")
+    );
+    assert_eq!(
+        named_argument(r#"napi(js_name = "fetchUser")"#, "name"),
+        None,
+        "`name` inside `js_name` is not the `name` argument"
+    );
+    assert_eq!(named_argument("napi(constructor)", "js_name"), None);
+}
+
+#[test]
+fn rust_hook_covers_napi_wasm_bindgen_pyo3_and_derive_builder() {
+    let napi = annotated(
+        "r1",
+        "get_user_name",
+        SymbolKind::Function,
+        "rust",
+        vec![marker("napi", "napi", None)],
+    );
+    assert_eq!(infer(&napi, None), ["getUserName"]);
+
+    let renamed = annotated(
+        "r2",
+        "load",
+        SymbolKind::Function,
+        "rust",
+        vec![marker("napi", r#"napi(js_name = "loadConfig")"#, None)],
+    );
+    assert_eq!(infer(&renamed, None), ["loadConfig"]);
+
+    let wasm = annotated(
+        "r3",
+        "render",
+        SymbolKind::Function,
+        "rust",
+        vec![marker(
+            "wasm_bindgen",
+            "wasm_bindgen(js_name = renderChart)",
+            None,
+        )],
+    );
+    assert_eq!(infer(&wasm, None), ["renderChart"]);
+
+    let pyo3 = annotated(
+        "r4",
+        "RustPoint",
+        SymbolKind::Struct,
+        "rust",
+        vec![marker("pyclass", r#"pyclass(name = "Point")"#, None)],
+    );
+    assert_eq!(infer(&pyo3, None), ["Point"]);
+
+    let builder = annotated(
+        "r5",
+        "Config",
+        SymbolKind::Struct,
+        "rust",
+        vec![
+            marker("Debug", "Debug", Some("derive")),
+            marker("Builder", "Builder", Some("derive")),
+        ],
+    );
+    assert_eq!(infer(&builder, None), ["ConfigBuilder"]);
+}
+
+#[test]
+fn csharp_and_java_hooks_cover_source_generators_and_lombok() {
+    let field = annotated(
+        "c1",
+        "_userName",
+        SymbolKind::Field,
+        "csharp",
+        vec![marker("ObservableProperty", "[ObservableProperty]", None)],
+    );
+    assert_eq!(infer(&field, None), ["UserName"]);
+
+    let command = annotated(
+        "c2",
+        "SaveAsync",
+        SymbolKind::Method,
+        "csharp",
+        vec![marker("RelayCommand", "[RelayCommand]", None)],
+    );
+    assert_eq!(infer(&command, None), ["SaveCommand"]);
+
+    let data_class = annotated(
+        "j1",
+        "Account",
+        SymbolKind::Class,
+        "java",
+        vec![marker("Data", "@Data", None)],
+    );
+    let field = |id: &str, name: &str, signature: &str| {
+        symbol_builder(id, name, "src/Account.java")
+            .kind(SymbolKind::Field)
+            .language("java")
+            .parent_id("j1")
+            .signature(signature)
+            .build()
+    };
+    assert_eq!(
+        infer(
+            &field("j2", "ownerName", "private String ownerName"),
+            Some(&data_class)
+        ),
+        ["getOwnerName", "setOwnerName"]
+    );
+    assert_eq!(
+        infer(
+            &field("j3", "active", "private boolean active"),
+            Some(&data_class)
+        ),
+        ["isActive", "setActive"]
+    );
+    assert_eq!(
+        infer(
+            &field("j4", "id", "private final long id"),
+            Some(&data_class)
+        ),
+        ["getId"],
+        "final fields get no setter"
+    );
+    assert!(
+        infer(&field("j5", "ownerName", "private String ownerName"), None).is_empty(),
+        "no Lombok annotation, no accessors"
+    );
+}
+
+#[test]
+fn computes_and_stores_aliases_for_annotated_symbols_and_their_children() {
+    let temp_dir = TempDir::new().unwrap();
+    let mut db = SymbolDatabase::new(temp_dir.path().join("aliases.db")).unwrap();
+    db.store_file_info(&file_info("src/lib.rs", "rust"))
+        .unwrap();
+    db.store_file_info(&file_info("src/Account.java", "java"))
+        .unwrap();
+
+    let napi = symbol_builder("napi_fn", "get_user_name", "src/lib.rs")
+        .kind(SymbolKind::Function)
+        .language("rust")
+        .annotations(vec![marker("napi", "napi", None)])
+        .build();
+    let plain = symbol_builder("plain_fn", "helper", "src/lib.rs")
+        .kind(SymbolKind::Function)
+        .language("rust")
+        .span(20, 0, 22, 1)
+        .build();
+    let class = symbol_builder("account", "Account", "src/Account.java")
+        .kind(SymbolKind::Class)
+        .language("java")
+        .annotations(vec![marker("Getter", "@Getter", None)])
+        .build();
+    let owner = symbol_builder("owner", "owner", "src/Account.java")
+        .kind(SymbolKind::Field)
+        .language("java")
+        .parent_id("account")
+        .signature("private String owner")
+        .span(4, 4, 4, 25)
+        .build();
+    db.store_symbols(&[napi, plain, class, owner]).unwrap();
+
+    let stats = compute_symbol_aliases(&db).unwrap();
+    assert_eq!(stats.candidates, 3, "the unannotated helper is skipped");
+    assert_eq!(stats.aliases, 2);
+
+    assert_eq!(
+        db.symbol_aliases_by_names(&["getUserName".to_string()])
+            .unwrap(),
+        [SymbolAlias {
+            symbol_id: "napi_fn".to_string(),
+            alias: "getUserName".to_string(),
+            source: "napi".to_string(),
+        }]
+    );
+    let owner_aliases = db
+        .symbol_aliases_for_symbols(&["owner".to_string()])
+        .unwrap();
+    assert_eq!(owner_aliases.len(), 1);
+    assert_eq!(owner_aliases[0].alias, "getOwner");
+    assert_eq!(owner_aliases[0].source, "lombok getter");
+
+    assert_recompute_is_idempotent(&db, compute_symbol_aliases, |db| {
+        db.symbol_alias_count().unwrap()
+    });
+}
@@ -0,0 +1,209 @@
+use std::collections::HashSet;
+
+use crate::analysis::topics::{
+    TopicMapOptions, cluster_vectors, generate_topic_map, identifier_terms, rank_topic_terms,
+};
+use julie_core::database::{FileInfo, SymbolDatabase};
+use julie_extractors::{Symbol, SymbolKind};
+use julie_test_support::{file_info_builder, symbol_builder};
+use tempfile::TempDir;
+
+const DIMS: usize = 384;
+
+fn file_info(path: &str) -> FileInfo {
+    file_info_builder(path)
+        .language("typescript")
+        .size(128)
+        .last_modified(1_700_000_000)
+        .last_indexed(0)
+        .line_count(40)
+        .content(format!("// {path}"))
+        .build()
+}
+
+fn function(id: &str, name: &str, file_path: &str, line: u32) -> Symbol {
+    symbol_builder(id, name, file_path)
+        .kind(SymbolKind::Function)
+        .language("typescript")
+        .span(line, 0, line + 3, 1)
+        .build()
+}
+
+/// Unit vector along `axis`, nudged towards `jitter` so members of one group
+/// are near-identical but not equal.
+fn embedding(axis: usize, jitter: usize) -> Vec<f32> {
+    let mut vector = vec![0.0_f32; DIMS];
+    vector[axis] = 1.0;
+    vector[200 + jitter] = 0.05;
+    vector
+}
+
+fn seeded_db() -> (TempDir, SymbolDatabase) {
+    let temp_dir = TempDir::new().unwrap();
+    let mut db = SymbolDatabase::new(temp_dir.path().join("topics.db")).unwrap();
+    db.recreate_vectors_table(DIMS).unwrap();
+    for path in [
+        "src/auth/session.ts",
+        "src/auth/token.ts",
+        "src/billing/invoice.ts",
+        "src/billing/payment.ts",
+        "src/config.ts",
+        "src/tests/auth.test.ts",
+    ] {
+        db.store_file_info(&file_info(path)).unwrap();
+    }
+
+    db.store_symbols(&[
+        function(
+            "authenticate",
+            "authenticateSession",
+            "src/auth/session.ts",
+            1,
+        ),
+        function("validate", "validateSession", "src/auth/session.ts", 10),
+        function("refresh", "refreshSessionToken", "src/auth/token.ts", 1),
+        function("revoke", "revokeSessionToken", "src/auth/token.ts", 10),
+        function(
+            "create_invoice",
+            "createInvoice",
+            "src/billing/invoice.ts",
+            1,
+        ),
+        function("send_invoice", "sendInvoice", "src/billing/invoice.ts", 10),
+        function("charge", "chargePayment", "src/billing/payment.ts", 1),
+        function("refund", "refundPayment", "src/billing/payment.ts", 10),
+        // Not embedded: never considered.
+        function("load_config", "loadConfig", "src/config.ts", 1),
+        function("mock_session", "mockSession", "src/tests/auth.test.ts", 1),
+    ])
+    .unwrap();
+
+    db.store_embeddings(&[
+        ("authenticate".to_string(), embedding(0, 0)),
+        ("validate".to_string(), embedding(0, 1)),
+        ("refresh".to_string(), embedding(0, 2)),
+        ("revoke".to_string(), embedding(0, 3)),
+        ("create_invoice".to_string(), embedding(1, 0)),
+        ("send_invoice".to_string(), embedding(1, 1)),
+        ("charge".to_string(), embedding(1, 2)),
+        ("refund".to_string(), embedding(1, 3)),
+        ("mock_session".to_string(), embedding(0, 4)),
+    ])
+    .unwrap();
+    (temp_dir, db)
+}
+
+fn term_set(terms: &[&str]) -> HashSet<String> {
+    terms.iter().map(|term| term.to_string()).collect()
+}
+
+#[test]
+fn identifier_terms_split_words_and_drop_generic_ones() {
+    assert_eq!(
+        identifier_terms("parseHTTPRequest"),
+        ["parse", "http", "request"]
+    );
+    assert_eq!(identifier_terms("get_user_session"), ["user", "session"]);
+    assert_eq!(
+        identifier_terms("v2_to_id"),
+        Vec::<String>::new(),
+        "short words and numbers say nothing"
+    );
+}
+
+#[test]
+fn cluster_vectors_separates_distinct_directions() {
+    let vectors = vec![
+        embedding(0, 0),
+        embedding(1, 0),
+        embedding(0, 1),
+        embedding(1, 1),
+        embedding(0, 2),
+    ];
+    let (assignments, centroids) = cluster_vectors(&vectors, 2);
+    assert_eq!(centroids.len(), 2);
+    assert_eq!(assignments[0], assignments[2]);
+    assert_eq!(assignments[0], assignments[4]);
+    assert_eq!(assignments[1], assignments[3]);
+    assert_ne!(assignments[0], assignments[1]);
+    assert_eq!(
+        cluster_vectors(&vectors, 2).0,
+        assignments,
+        "same input, same clusters"
+    );
+}
+
+#[test]
+fn topic_terms_prefer_words_that_set_a_topic_apart() {
+    let parse_a = term_set(&["parse", "token", "request"]);
+    let parse_b = term_set(&["parse", "token", "lexer"]);
+    let auth_a = term_set(&["login", "token", "request"]);
+    let auth_b = term_set(&["login", "token"]);
+    let labels = rank_topic_terms(&[vec![&parse_a, &parse_b], vec![&auth_a, &auth_b]]);
+
+    assert_eq!(
+        labels[0],
+        ["parse", "token"],
+        "shared words rank below distinctive ones"
+    );
+    assert_eq!(labels[1], ["login", "token"]);
+    assert!(
+        !labels[0].contains(&"lexer".to_string()),
+        "a word one member uses does not name a topic"
+    );
+}
+
+#[test]
+fn topic_map_groups_embedded_symbols_and_names_them() {
+    let (_temp_dir, db) = seeded_db();
+    let report = generate_topic_map(&db, &TopicMapOptions::default()).unwrap();
+
+    assert_eq!(
+        report.candidates, 8,
+        "test file and unembedded symbol excluded"
+    );
+    assert_eq!(report.clustered, 8);
+    assert_eq!(report.unassigned, 0);
+
+    let labels: Vec<&str> = report.topics.iter().map(|t| t.label.as_str()).collect();
+    assert_eq!(labels, ["invoice / payment", "session / token"]);
+
+    let auth = &report.topics[1];
+    assert_eq!(auth.size, 4);
+    assert_eq!(auth.directories, [("src/auth".to_string(), 4)]);
+    let names: HashSet<&str> = auth.symbols.iter().map(|s| s.name.as_str()).collect();
+    assert_eq!(
+        names,
+        HashSet::from([
+            "authenticateSession",
+            "validateSession",
+            "refreshSessionToken",
+            "revokeSessionToken",
+        ])
+    );
+}
+
+#[test]
+fn topic_map_leaves_small_groups_unassigned() {
+    let (_temp_dir, db) = seeded_db();
+    let options = TopicMapOptions {
+        min_topic_size: 5,
+        ..TopicMapOptions::default()
+    };
+    let report = generate_topic_map(&db, &options).unwrap();
+    assert!(report.topics.is_empty());
+    assert_eq!(report.unassigned, 8);
+
+    let options = TopicMapOptions {
+        focus_path: Some("src/billing/".to_string()),
+        topics: Some(1),
+        ..TopicMapOptions::default()
+    };
+    let report = generate_topic_map(&db, &options).unwrap();
+    assert_eq!(report.candidates, 4);
+    assert_eq!(report.topics.len(), 1);
+    assert_eq!(
+        report.topics[0].label, "invoice / payment",
+        "a single topic still names itself from its words"
+    );
+}
@@ -0,0 +1,254 @@
+use super::assert_recompute_is_idempotent;
+use crate::analysis::workspace_projects::{
+    DeclaredProject, assign_projects, compute_workspace_projects, parse_project_manifest,
+};
+use julie_core::database::{FileInfo, NestedRepository, SymbolDatabase};
+use julie_test_support::file_info_builder;
+use tempfile::TempDir;
+
+const SOLUTION: &str = r#"
+Microsoft Visual Studio Solution File, Format Version 12.00
+Project("{9A19103F-16F7-4668-BE54-9A1E7A4F7556}") = "Billing.Api", "src\Billing.Api\Billing.Api.csproj", "{11111111-1111-1111-1111-111111111111}"
+EndProject
+Project("{2150E333-8FDC-42A3-9474-1A3956D46DE8}") = "Solution Items", "Solution Items", "{22222222-2222-2222-2222-222222222222}"
+EndProject
+Project("{F2A71F9B-5D33-465A-A702-920D77279786}") = "Billing.Core", "src\Billing.Core\Billing.Core.fsproj", "{33333333-3333-3333-3333-333333333333}"
+EndProject
+"#;
+
+fn file_info(path: &str, language: &str, content: &str) -> FileInfo {
+    file_info_builder(path)
+        .language(language)
+        .size(content.len() as i64)
+        .last_modified(1_700_000_000)
+        .last_indexed(0)
+        .line_count(content.lines().count() as i32)
+        .content(content)
+        .build()
+}
+
+fn names(projects: &[DeclaredProject]) -> Vec<(&str, &str)> {
+    projects
+        .iter()
+        .map(|p| (p.name.as_str(), p.root.as_str()))
+        .collect()
+}
+
+#[test]
+fn parses_names_from_each_manifest_kind() {
+    let cargo = "[workspace]\nmembers = [\"crates/*\"]\n\n[package]\nname = \"billing\" # crate\nversion = \"0.1.0\"\n";
+    assert_eq!(
+        names(&parse_project_manifest("Cargo.toml", cargo)),
+        [("billing", "")]
+    );
+    let virtual_root = "[workspace]\nmembers = [\"crates/*\"]\n";
+    assert!(parse_project_manifest("Cargo.toml", virtual_root).is_empty());
+
+    let npm = r#"{ "name": "@acme/web", "workspaces": ["packages/*"] }"#;
+    assert_eq!(
+        names(&parse_project_manifest("apps/web/package.json", npm)),
+        [("@acme/web", "apps/web")]
+    );
+    assert!(parse_project_manifest("package.json", r#"{ "private": true }"#).is_empty());
+
+    let go_mod = "// service\nmodule github.com/acme/users // v2 soon\n\ngo 1.22\n";
+    assert_eq!(
+        names(&parse_project_manifest("services/users/go.mod", go_mod)),
+        [("github.com/acme/users", "services/users")]
+    );
+
+    let csproj = "<Project Sdk=\"Microsoft.NET.Sdk\">\n  <PropertyGroup>\n    <AssemblyName>Acme.Users</AssemblyName>\n  </PropertyGroup>\n</Project>\n";
+    assert_eq!(
+        names(&parse_project_manifest("src/Users/Users.csproj", csproj)),
+        [("Acme.Users", "src/Users")]
+    );
+    assert_eq!(
+        names(&parse_project_manifest(
+            "src/Users/Users.csproj",
+            "<Project Sdk=\"Microsoft.NET.Sdk\" />"
+        )),
+        [("Users", "src/Users")]
+    );
+
+    assert_eq!(
+        names(&parse_project_manifest("billing/Billing.sln", SOLUTION)),
+        [
+            ("Billing.Api", "billing/src/Billing.Api"),
+            ("Billing.Core", "billing/src/Billing.Core"),
+        ],
+        "solution folders are not projects"
+    );
+}
+
+#[test]
+fn files_belong_to_the_deepest_project() {
+    let project = |name: &str, root: &str| DeclaredProject {
+        name: name.to_string(),
+        root: root.to_string(),
+        manifest_path: format!("{root}/manifest"),
+    };
+    let projects = [
+        project("root", ""),
+        project("users", "services/users"),
+        project("users-js", "services/users"),
+        project("users-admin", "services/users/admin"),
+    ];
+    let files = [
+        "README.md",
+        "services/users/service.go",
+        "services/users/admin/page.go",
+        "services/users-legacy/old.go",
+    ]
+    .map(str::to_string);
+
+    let assigned: Vec<(String, String)> = assign_projects(&files, &projects)
+        .into_iter()
+        .map(|row| (row.file_path, row.project))
+        .collect();
+    assert_eq!(
+        assigned,
+        [
+            ("README.md".to_string(), "root".to_string()),
+            ("services/users/service.go".to_string(), "users".to_string()),
+            (
+                "services/users/admin/page.go".to_string(),
+                "users-admin".to_string()
+            ),
+            (
+                "services/users-legacy/old.go".to_string(),
+                "root".to_string()
+            ),
+        ]
+    );
+}
+
+#[test]
+fn computes_and_stores_the_project_map_from_indexed_manifests() {
+    let temp_dir = TempDir::new().unwrap();
+    let db = SymbolDatabase::new(temp_dir.path().join("projects.db")).unwrap();
+    for (path, language, content) in [
+        (
+            "Cargo.toml",
+            "toml",
+            "[workspace]\nmembers = [\"crates/*\"]\n",
+        ),
+        (
+            "crates/auth/Cargo.toml",
+            "toml",
+            "[package]\nname = \"auth\"\n",
+        ),
+        ("crates/auth/src/lib.rs", "rust", "pub struct UserService;"),
+        (
+            "crates/billing/Cargo.toml",
+            "toml",
+            "[package]\nname = \"billing\"\n",
+        ),
+        (
+            "crates/billing/src/lib.rs",
+            "rust",
+            "pub struct UserService;",
+        ),
+        ("web/package.json", "json", r#"{"name": "web"}"#),
+        (
+            "web/src/user.ts",
+            "typescript",
+            "export class UserService {}",
+        ),
+        ("scripts/tool.py", "python", "pass"),
+    ] {
+        db.store_file_info(&file_info(path, language, content))
+            .unwrap();
+    }
+
+    let stats = compute_workspace_projects(&db).unwrap();
+    assert_eq!(stats.manifests, 4);
+    assert_eq!(
+        stats.projects, 3,
+        "the virtual workspace root declares nothing"
+    );
+    assert_eq!(stats.files, 6);
+
+    let paths = [
+        "crates/auth/src/lib.rs",
+        "crates/billing/src/lib.rs",
+        "web/src/user.ts",
+        "scripts/tool.py",
+    ]
+    .map(str::to_string);
+    let projects = db.projects_for_files(&paths).unwrap();
+    assert_eq!(projects["crates/auth/src/lib.rs"], "auth");
+    assert_eq!(projects["crates/billing/src/lib.rs"], "billing");
+    assert_eq!(projects["web/src/user.ts"], "web");
+    assert!(!projects.contains_key("scripts/tool.py"));
+
+    assert_eq!(
+        db.project_file_counts().unwrap(),
+        [
+            ("auth".to_string(), 2),
+            ("billing".to_string(), 2),
+            ("web".to_string(), 2),
+        ]
+    );
+    assert_recompute_is_idempotent(&db, compute_workspace_projects, |db| {
+        db.project_file_counts().unwrap()
+    });
+}
+
+#[test]
+fn nested_repositories_are_projects_of_their_own() {
+    let temp_dir = TempDir::new().unwrap();
+    let db = SymbolDatabase::new(temp_dir.path().join("projects.db")).unwrap();
+    for (path, language, content) in [
+        ("package.json", "json", r#"{"name": "app"}"#),
+        (
+            "src/main.ts",
+            "typescript",
+            "import { parse } from './parse';",
+        ),
+        (
+            "vendor/yaml/src/parse.c",
+            "c",
+            "int parse(void) { return 0; }",
+        ),
+        (
+            "vendor/toml/package.json",
+            "json",
+            r#"{"name": "@iarna/toml"}"#,
+        ),
+        ("vendor/toml/lib.js", "javascript", "module.exports = {};"),
+    ] {
+        db.store_file_info(&file_info(path, language, content))
+            .unwrap();
+    }
+    db.replace_nested_repositories(&[
+        NestedRepository {
+            root: "vendor/toml".to_string(),
+            submodule: true,
+        },
+        NestedRepository {
+            root: "vendor/yaml".to_string(),
+            submodule: false,
+        },
+    ])
+    .unwrap();
+
+    let stats = compute_workspace_projects(&db).unwrap();
+    assert_eq!(stats.projects, 4);
+
+    let paths = [
+        "src/main.ts",
+        "vendor/yaml/src/parse.c",
+        "vendor/toml/lib.js",
+    ]
+    .map(str::to_string);
+    let projects = db.projects_for_files(&paths).unwrap();
+    assert_eq!(projects["src/main.ts"], "app");
+    assert_eq!(
+        projects["vendor/yaml/src/parse.c"], "yaml",
+        "a nested repository is not part of the package around it"
+    );
+    assert_eq!(
+        projects["vendor/toml/lib.js"], "@iarna/toml",
+        "a manifest in the repository root names the project"
+    );
+}
@@ -6,7 +6,8 @@
 //! - `test_subrole` for unit / integration / smoke.
 //! - Schema contains the three new field names.
 
-use crate::search::scoring::{classify_role, test_subrole};
+use crate::search::index::symbol_role_and_test_role;
+use crate::search::scoring::{classify_file_role, classify_role, test_subrole};
 
 // ----- classify_role -----
 
@@ -109,6 +110,43 @@ fn test_classify_role_generated_takes_priority_over_test() {
     );
 }
 
+#[test]
+fn test_classify_role_generator_file_names() {
+    assert_eq!(classify_role("api/user_pb2.py", "python"), "generated");
+    assert_eq!(classify_role("lib/models/user.g.dart", "dart"), "generated");
+    assert_eq!(
+        classify_role("app/migrations/0003_add_email.py", "python"),
+        "generated"
+    );
+    assert_eq!(classify_role("src/migrations.rs", "rust"), "source");
+}
+
+#[test]
+fn test_classify_file_role_reads_do_not_edit_header() {
+    let header = "// Code generated by sqlc. DO NOT EDIT.\npackage db\n";
+    assert_eq!(classify_file_role("db/query.go", "go", header), "generated");
+    assert_eq!(
+        classify_file_role("db/query.go", "go", "package db\n"),
+        "source"
+    );
+    assert_eq!(
+        classify_file_role("vendor/db/query.go", "go", header),
+        "vendor"
+    );
+}
+
+#[test]
+fn test_symbol_role_honors_is_generated_metadata() {
+    let metadata = std::collections::HashMap::from([(
+        "is_generated".to_string(),
+        serde_json::Value::Bool(true),
+    )]);
+    let (role, _) = symbol_role_and_test_role("src/api/client.ts", "typescript", Some(&metadata));
+    assert_eq!(role, "generated");
+    let (role, _) = symbol_role_and_test_role("src/api/client.ts", "typescript", None);
+    assert_eq!(role, "source");
+}
+
 // ----- test_subrole -----
 
 #[test]
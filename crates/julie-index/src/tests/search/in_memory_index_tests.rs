@@ -0,0 +1,48 @@
+//! In-memory indexes used while an index key is set.
+
+use tempfile::TempDir;
+
+use crate::search::index::in_memory;
+use crate::search::index::{IN_MEMORY_MARKER_FILE, SearchIndex, SearchIndexOpenDisposition};
+use crate::search::schema::create_schema;
+
+#[test]
+fn in_memory_indexes_are_shared_per_path_and_never_write_segments() {
+    let temp_dir = TempDir::new().unwrap();
+    let path = temp_dir.path().join("tantivy");
+    let schema = create_schema();
+
+    let (_, disposition) = in_memory::open_or_create(&path, &schema).unwrap();
+    assert_eq!(disposition, SearchIndexOpenDisposition::CreatedInMemory);
+    assert!(disposition.repair_required());
+    assert!(in_memory::exists(&path));
+
+    let (_, disposition) = in_memory::open_or_create(&path, &schema).unwrap();
+    assert_eq!(disposition, SearchIndexOpenDisposition::Compatible);
+
+    let files: Vec<_> = std::fs::read_dir(&path)
+        .unwrap()
+        .map(|entry| entry.unwrap().file_name().into_string().unwrap())
+        .collect();
+    assert_eq!(files, [IN_MEMORY_MARKER_FILE]);
+
+    // Deleting the index directory starts the next open from scratch.
+    std::fs::remove_dir_all(&path).unwrap();
+    assert!(!in_memory::exists(&path));
+    let (_, disposition) = in_memory::open_or_create(&path, &schema).unwrap();
+    assert_eq!(disposition, SearchIndexOpenDisposition::CreatedInMemory);
+}
+
+#[test]
+fn unencrypted_segments_are_removed_when_switching_to_memory() {
+    let temp_dir = TempDir::new().unwrap();
+    let path = temp_dir.path().join("tantivy");
+    std::fs::create_dir_all(&path).unwrap();
+    drop(SearchIndex::create(&path).unwrap());
+    assert!(path.join("meta.json").exists());
+
+    let (_, disposition) = in_memory::open_or_create(&path, &create_schema()).unwrap();
+    assert_eq!(disposition, SearchIndexOpenDisposition::CreatedInMemory);
+    assert!(!path.join("meta.json").exists());
+    assert!(path.join(IN_MEMORY_MARKER_FILE).exists());
+}
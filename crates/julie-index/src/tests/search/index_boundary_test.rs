@@ -5,6 +5,7 @@ fn search_index_implementation_files_stay_within_limit() {
     for relative_path in [
         "src/search/index.rs",
         "src/search/index/types.rs",
+        "src/search/index/backup.rs",
         "src/search/index/compatibility.rs",
         "src/search/index/lifecycle.rs",
         "src/search/index/mutation.rs",
@@ -3,6 +3,7 @@
 pub mod c3_enriched_schema_tests;
 pub mod compat_marker_v4_test;
 pub mod file_mode_index_tests;
+mod in_memory_index_tests;
 mod index_boundary_test;
 pub mod language_config_embedded_tests;
 pub mod projection_search_doc_test;
@@ -147,3 +147,45 @@ fn test_delete_by_file_path() {
     index.commit().unwrap();
     assert_eq!(index.num_docs(), 1);
 }
+
+#[test]
+fn test_snapshot_to_copies_committed_state_only() {
+    let temp_dir = TempDir::new().unwrap();
+    let live_dir = temp_dir.path().join("live");
+    std::fs::create_dir(&live_dir).unwrap();
+    let index = SearchIndex::create(&live_dir).unwrap();
+    index
+        .add_search_doc(&SearchDocument::file_from_parts(
+            "src/main.rs",
+            "fn main() { println!(\"hello world\"); }",
+            "rust",
+        ))
+        .unwrap();
+    index.commit().unwrap();
+
+    // Pending writes are not part of the snapshot.
+    index
+        .add_search_doc(&SearchDocument::file_from_parts(
+            "src/lib.rs",
+            "pub fn println_helper() {}",
+            "rust",
+        ))
+        .unwrap();
+
+    let snapshot_dir = temp_dir.path().join("snapshot");
+    let bytes = index.snapshot_to(&snapshot_dir).unwrap();
+    assert!(bytes > 0);
+    index.commit().unwrap();
+    assert_eq!(index.num_docs(), 2);
+
+    let snapshot = SearchIndex::open(&snapshot_dir).unwrap();
+    assert_eq!(snapshot.num_docs(), 1);
+    let results = snapshot
+        .search_content("println", &SearchFilter::default(), 10)
+        .unwrap()
+        .results;
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].file_path, "src/main.rs");
+
+    assert!(index.snapshot_to(&snapshot_dir).is_err());
+}
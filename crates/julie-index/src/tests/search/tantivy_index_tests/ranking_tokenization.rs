@@ -268,6 +268,7 @@ fn test_compound_token_finds_exact_identifier() {
         kind: None,
         file_pattern: None,
         exclude_tests: false,
+        include_generated: false,
     };
 
     let results = index
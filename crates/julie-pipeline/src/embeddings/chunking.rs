@@ -0,0 +1,169 @@
+//! Snippet-level chunking for long symbol bodies.
+//!
+//! The metadata text embedded for every symbol (see `metadata`) is capped at
+//! `MAX_METADATA_CHARS`, so a long function is represented only by its
+//! signature, doc comment and callee names. Functions and methods whose source
+//! exceeds one chunk additionally get their body split into overlapping,
+//! line-aligned chunks. Each chunk is embedded on its own and stored in
+//! `symbol_chunk_vectors`; query-time search aggregates chunk hits back onto
+//! the symbol (`SymbolDatabase::knn_search_with_chunks`).
+
+use std::collections::HashMap;
+
+use julie_extractors::{Symbol, SymbolKind};
+
+use super::metadata::kind_to_str;
+
+/// Byte budget per chunk body — the same budget as metadata text, which is
+/// safe for every supported model.
+pub const CHUNK_MAX_CHARS: usize = 1200;
+
+/// Lines repeated at the start of the next chunk, so a statement that
+/// straddles a boundary is whole in at least one chunk.
+pub const CHUNK_OVERLAP_LINES: usize = 3;
+
+/// Upper bound on chunks per symbol. Generated lookup tables and giant match
+/// statements would otherwise flood the vector table.
+pub const MAX_CHUNKS_PER_SYMBOL: usize = 16;
+
+/// Symbol kinds whose bodies are chunked. Containers are already summarized
+/// by their members' names, and their members are chunked individually.
+const CHUNKABLE_KINDS: &[SymbolKind] = &[SymbolKind::Function, SymbolKind::Method];
+
+/// Returns true if long bodies of this kind get chunk vectors.
+pub fn is_chunkable_kind(kind: &SymbolKind) -> bool {
+    CHUNKABLE_KINDS.contains(kind)
+}
+
+/// One line-aligned slice of a body.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BodyChunk {
+    pub start_line: u32,
+    pub end_line: u32,
+    pub text: String,
+}
+
+/// A chunk ready for `embed_batch`, with the ids needed to store it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PreparedChunk {
+    pub chunk_id: String,
+    pub symbol_id: String,
+    pub start_line: u32,
+    pub end_line: u32,
+    pub text: String,
+}
+
+/// Stable id of the `index`-th chunk of a symbol.
+pub fn chunk_id(symbol_id: &str, index: usize) -> String {
+    format!("{symbol_id}#chunk{index}")
+}
+
+/// Split `body`, whose first line is `first_line`, into overlapping
+/// line-aligned chunks of at most `max_chars` bytes each.
+///
+/// Returns nothing when the body already fits in one chunk: the metadata
+/// vector covers it. A single line longer than `max_chars` is cut on a char
+/// boundary. At most `MAX_CHUNKS_PER_SYMBOL` chunks are produced.
+pub fn split_body(
+    body: &str,
+    first_line: u32,
+    max_chars: usize,
+    overlap_lines: usize,
+) -> Vec<BodyChunk> {
+    if body.len() <= max_chars {
+        return Vec::new();
+    }
+
+    let lines: Vec<&str> = body
+        .lines()
+        .map(|line| truncate_to_char_boundary(line.trim_end(), max_chars))
+        .collect();
+    let mut chunks = Vec::new();
+    let mut start = 0;
+
+    while start < lines.len() && chunks.len() < MAX_CHUNKS_PER_SYMBOL {
+        let mut end = start;
+        let mut size = 0;
+        while end < lines.len() && (end == start || size + lines[end].len() + 1 <= max_chars) {
+            size += lines[end].len() + 1;
+            end += 1;
+        }
+
+        let text = lines[start..end].join("\n");
+        if !text.trim().is_empty() {
+            chunks.push(BodyChunk {
+                start_line: first_line + start as u32,
+                end_line: first_line + (end - 1) as u32,
+                text,
+            });
+        }
+
+        if end >= lines.len() {
+            break;
+        }
+        start = end.saturating_sub(overlap_lines).max(start + 1);
+    }
+
+    chunks
+}
+
+/// Slice each symbol's body out of its file content and split it.
+///
+/// Symbols are expected to be pre-filtered (kind, language, test code); those
+/// whose file content is unavailable or whose byte span does not fall on char
+/// boundaries are skipped. Returns one group per chunked symbol, so callers can
+/// keep a symbol's chunks together in one batch.
+pub fn prepare_chunks_for_embedding(
+    symbols: &[&Symbol],
+    file_contents: &HashMap<String, Option<String>>,
+) -> Vec<Vec<PreparedChunk>> {
+    symbols
+        .iter()
+        .filter_map(|symbol| {
+            let content = file_contents.get(&symbol.file_path)?.as_deref()?;
+            let body = content.get(symbol.start_byte as usize..symbol.end_byte as usize)?;
+            let chunks = split_body(
+                body,
+                symbol.start_line,
+                CHUNK_MAX_CHARS,
+                CHUNK_OVERLAP_LINES,
+            );
+            if chunks.is_empty() {
+                return None;
+            }
+
+            let total = chunks.len();
+            let kind = kind_to_str(&symbol.kind);
+            Some(
+                chunks
+                    .into_iter()
+                    .enumerate()
+                    .map(|(index, chunk)| PreparedChunk {
+                        chunk_id: chunk_id(&symbol.id, index),
+                        symbol_id: symbol.id.clone(),
+                        start_line: chunk.start_line,
+                        end_line: chunk.end_line,
+                        // Name the owner so every chunk keeps the symbol's identity.
+                        text: format!(
+                            "{kind} {} (part {}/{total})\n{}",
+                            symbol.name,
+                            index + 1,
+                            chunk.text
+                        ),
+                    })
+                    .collect(),
+            )
+        })
+        .collect()
+}
+
+fn truncate_to_char_boundary(text: &str, max_bytes: usize) -> &str {
+    if text.len() <= max_bytes {
+        return text;
+    }
+    let mut end = max_bytes;
+    while end > 0 && !text.is_char_boundary(end) {
+        end -= 1;
+    }
+    &text[..end]
+}
@@ -36,6 +36,8 @@ impl BackendResolverCapabilities {
 pub struct EmbeddingConfig {
     pub provider: String,
     pub cache_dir: Option<PathBuf>,
+    /// Sidecar model id; `None` keeps the sidecar's own default.
+    pub model_id: Option<String>,
 }
 
 impl Default for EmbeddingConfig {
@@ -43,6 +45,7 @@ impl Default for EmbeddingConfig {
         Self {
             provider: "auto".to_string(),
             cache_dir: None,
+            model_id: None,
         }
     }
 }
@@ -128,7 +131,9 @@ impl EmbeddingProviderFactory {
             EmbeddingBackend::Sidecar => {
                 #[cfg(feature = "embeddings-sidecar")]
                 {
-                    return Ok(Arc::new(SidecarEmbeddingProvider::try_new()?));
+                    return Ok(Arc::new(SidecarEmbeddingProvider::try_new_with_model(
+                        config.model_id.as_deref(),
+                    )?));
                 }
 
                 #[cfg(not(feature = "embeddings-sidecar"))]
@@ -5,6 +5,7 @@
 
 use std::sync::Arc;
 
+use julie_core::workspace_settings::EmbeddingSettings;
 use tracing::{info, warn};
 
 use crate::embeddings::log_fields::build_embedding_runtime_log_fields;
@@ -24,6 +25,18 @@ use crate::embeddings::{
 pub fn create_embedding_provider() -> (
     Option<Arc<dyn EmbeddingProvider>>,
     Option<EmbeddingRuntimeStatus>,
+) {
+    create_embedding_provider_with_settings(&EmbeddingSettings::default())
+}
+
+/// Like [`create_embedding_provider`], with a workspace's `[embeddings]`
+/// settings filling in whatever the environment leaves unset. Environment
+/// variables win so that CI and tests can always disable or pin the backend.
+pub fn create_embedding_provider_with_settings(
+    settings: &EmbeddingSettings,
+) -> (
+    Option<Arc<dyn EmbeddingProvider>>,
+    Option<EmbeddingRuntimeStatus>,
 ) {
     // Test-only knob: simulate a slow `create_embedding_provider` by sleeping
     // for the specified milliseconds at the very start. Used by the daemon
@@ -49,6 +62,11 @@ pub fn create_embedding_provider() -> (
     let mut config = EmbeddingConfig::default();
     if let Ok(provider) = std::env::var("JULIE_EMBEDDING_PROVIDER") {
         config.provider = provider;
+    } else if let Some(provider) = &settings.provider {
+        config.provider = provider.clone();
+    }
+    if std::env::var_os("JULIE_EMBEDDING_SIDECAR_MODEL_ID").is_none() {
+        config.model_id = settings.model.clone();
     }
     config.cache_dir = std::env::var("JULIE_EMBEDDING_CACHE_DIR")
         .ok()
@@ -59,10 +77,7 @@ pub fn create_embedding_provider() -> (
         config.provider.trim().to_ascii_lowercase().as_str(),
         "none" | "disabled" | "off"
     ) {
-        info!(
-            "Embedding disabled via JULIE_EMBEDDING_PROVIDER={}",
-            config.provider
-        );
+        info!("Embedding disabled via provider={}", config.provider);
         return (None, None);
     }
 
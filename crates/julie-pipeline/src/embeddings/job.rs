@@ -0,0 +1,188 @@
+//! One run of the embedding pipeline as a resumable job.
+//!
+//! The job owns what the pipeline checks between batches: the cancel flag,
+//! the [`BackgroundTask`] that reports progress and carries pause requests,
+//! and the batch checkpoints. Every [`CHECKPOINT_EVERY_BATCHES`] batches it
+//! publishes the staged vectors and records the symbols they cover (see
+//! `SymbolDatabase::checkpoint_embedding_generation`), so cancelling, a failed
+//! batch or a killed process costs at most that many batches: the next run
+//! skips what the interrupted job recorded, including symbols it would
+//! otherwise re-embed for fresh enrichment.
+
+use std::collections::HashSet;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+
+use anyhow::{Context, Result};
+use tracing::info;
+
+use julie_core::background_tasks::BackgroundTask;
+use julie_core::database::{EmbeddingGeneration, EmbeddingJobStatus, SymbolDatabase};
+
+/// Batches between two checkpoints. With 250 symbols per batch, an
+/// interruption loses at most 2000 symbols of work.
+pub const CHECKPOINT_EVERY_BATCHES: usize = 8;
+
+/// Cancellation, pause, progress and checkpoint state of one pipeline run.
+pub struct EmbeddingJob<'a> {
+    cancel: Option<&'a AtomicBool>,
+    task: Option<&'a BackgroundTask>,
+    checkpoint_every: usize,
+    batches_since_checkpoint: usize,
+    done_since_checkpoint: Vec<String>,
+    completed: u64,
+    failed: bool,
+}
+
+impl<'a> EmbeddingJob<'a> {
+    pub fn new(cancel: Option<&'a AtomicBool>, task: Option<&'a BackgroundTask>) -> Self {
+        Self {
+            cancel,
+            task,
+            checkpoint_every: CHECKPOINT_EVERY_BATCHES,
+            batches_since_checkpoint: 0,
+            done_since_checkpoint: Vec::new(),
+            completed: 0,
+            failed: false,
+        }
+    }
+
+    /// Checkpoint every `batches` batches instead of the default.
+    pub fn with_checkpoint_every(mut self, batches: usize) -> Self {
+        self.checkpoint_every = batches.max(1);
+        self
+    }
+
+    pub fn cancel_flag(&self) -> Option<&'a AtomicBool> {
+        self.cancel
+    }
+
+    pub fn task(&self) -> Option<&'a BackgroundTask> {
+        self.task
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.cancel.is_some_and(|flag| flag.load(Ordering::Acquire))
+    }
+
+    /// Block while the job's task is paused; returns early on cancel.
+    pub fn wait_while_paused(&self) {
+        if let Some(task) = self.task {
+            task.wait_while_paused(self.cancel);
+        }
+    }
+
+    /// Stop the run after the current batch; the job ends as failed.
+    pub fn mark_failed(&mut self) {
+        self.failed = true;
+    }
+
+    /// Symbols an interrupted job finished that still have a vector. A symbol
+    /// whose vector was dropped since (its file changed, the model changed)
+    /// is embedded again.
+    pub fn resumable_ids(
+        &self,
+        db: &Arc<Mutex<SymbolDatabase>>,
+        already_embedded: &HashSet<String>,
+    ) -> Result<HashSet<String>> {
+        let done = lock(db)?
+            .unfinished_embedding_job_ids()
+            .context("Failed to load interrupted embedding job progress")?;
+        Ok(done
+            .into_iter()
+            .filter(|id| already_embedded.contains(id))
+            .collect())
+    }
+
+    /// Record the job as running with `total` symbols, `resumed` of which an
+    /// interrupted job already finished.
+    pub fn start(
+        &mut self,
+        db: &Arc<Mutex<SymbolDatabase>>,
+        total: u64,
+        resumed: u64,
+    ) -> Result<()> {
+        self.completed = resumed;
+        lock(db)?
+            .start_embedding_job(total, resumed)
+            .context("Failed to record embedding job start")?;
+        if resumed > 0 {
+            info!(
+                "Embedding pipeline: resuming interrupted job ({resumed}/{total} symbols already embedded)"
+            );
+        }
+        if let Some(task) = self.task {
+            task.set_total(total);
+            task.advance(resumed);
+            task.set_detail(if resumed > 0 {
+                format!("embedding symbols (resumed at {resumed})")
+            } else {
+                "embedding symbols".to_string()
+            });
+        }
+        Ok(())
+    }
+
+    /// Count a staged batch of `items` work items, `symbol_ids` of which are
+    /// symbol vectors, and checkpoint when one is due.
+    pub fn batch_done(
+        &mut self,
+        db: &Arc<Mutex<SymbolDatabase>>,
+        generation: &EmbeddingGeneration,
+        symbol_ids: impl IntoIterator<Item = String>,
+        items: u64,
+    ) -> Result<()> {
+        let before = self.done_since_checkpoint.len();
+        self.done_since_checkpoint.extend(symbol_ids);
+        self.completed += (self.done_since_checkpoint.len() - before) as u64;
+        self.batches_since_checkpoint += 1;
+        if let Some(task) = self.task {
+            task.advance(items);
+        }
+        if self.batches_since_checkpoint >= self.checkpoint_every {
+            self.checkpoint(db, generation)?;
+        }
+        Ok(())
+    }
+
+    /// Publish everything staged since the last checkpoint.
+    pub fn checkpoint(
+        &mut self,
+        db: &Arc<Mutex<SymbolDatabase>>,
+        generation: &EmbeddingGeneration,
+    ) -> Result<usize> {
+        if self.batches_since_checkpoint == 0 {
+            return Ok(0);
+        }
+        let commit = lock(db)?
+            .checkpoint_embedding_generation(
+                generation,
+                &self.done_since_checkpoint,
+                self.completed,
+            )
+            .context("Failed to checkpoint embedding generation")?;
+        self.batches_since_checkpoint = 0;
+        self.done_since_checkpoint.clear();
+        info!(
+            "Embedding checkpoint: published {} vectors and {} chunks ({} symbols done)",
+            commit.vectors_committed, commit.chunks_committed, self.completed
+        );
+        Ok(commit.stale_discarded)
+    }
+
+    /// How the run ends, once its batches are done or abandoned.
+    pub fn outcome(&self) -> EmbeddingJobStatus {
+        if self.is_cancelled() {
+            EmbeddingJobStatus::Cancelled
+        } else if self.failed {
+            EmbeddingJobStatus::Failed
+        } else {
+            EmbeddingJobStatus::Complete
+        }
+    }
+}
+
+fn lock(db: &Arc<Mutex<SymbolDatabase>>) -> Result<std::sync::MutexGuard<'_, SymbolDatabase>> {
+    db.lock()
+        .map_err(|e| anyhow::anyhow!("DB mutex poisoned: {e}"))
+}
@@ -387,7 +387,7 @@ pub fn has_simple_default_literal(signature: &str) -> bool {
 }
 
 /// Convert SymbolKind to a lowercase embedding-friendly string.
-fn kind_to_str(kind: &SymbolKind) -> &'static str {
+pub(crate) fn kind_to_str(kind: &SymbolKind) -> &'static str {
     match kind {
         SymbolKind::Function => "function",
         SymbolKind::Method => "method",
@@ -10,10 +10,12 @@
 //! - [`SidecarEmbeddingProvider`] — production implementation using a managed Python sidecar
 //! - Vector storage lives in `database::vectors` (sqlite-vec)
 
+pub mod chunking;
 pub mod factory;
 pub mod host_server;
 pub mod host_transport;
 pub mod init;
+pub mod job;
 pub mod log_fields;
 pub mod metadata;
 pub mod pipeline;
@@ -46,7 +48,8 @@ pub use factory::{
     strict_acceleration_enabled_from_env_value,
 };
 pub use host_transport::{HostAddress, HostClientConn, HostListener, HostServerConn};
-pub use init::create_embedding_provider;
+pub use init::{create_embedding_provider, create_embedding_provider_with_settings};
+pub use job::{CHECKPOINT_EVERY_BATCHES, EmbeddingJob};
 pub use sidecar_protocol::{
     DeviceBackendCapabilities, DeviceBackendCapability, DeviceLoadPolicy, EmbedBatchRequest,
     EmbedBatchResult, EmbedQueryRequest, EmbedQueryResult, HealthResult, ProtocolError,
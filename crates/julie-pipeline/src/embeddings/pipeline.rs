@@ -2,7 +2,9 @@
 //!
 //! After Tantivy indexing completes, this pipeline runs in the background to
 //! generate vector embeddings for all embeddable symbols. It processes symbols
-//! in batches to avoid holding the database lock for too long.
+//! in batches to avoid holding the database lock for too long, and publishes
+//! them at checkpoints so an interrupted run can be resumed (see
+//! [`crate::embeddings::job`]).
 
 use std::collections::{HashMap, HashSet};
 use std::sync::{Arc, Mutex};
@@ -11,11 +13,17 @@ use anyhow::{Context, Result};
 use tracing::{info, warn};
 
 use crate::embeddings::EmbeddingProvider;
+use crate::embeddings::chunking::{PreparedChunk, is_chunkable_kind, prepare_chunks_for_embedding};
+use crate::embeddings::job::EmbeddingJob;
 use crate::embeddings::metadata::{
     GLOBAL_VARIABLE_EMBEDDING_CAP, NON_EMBEDDABLE_LANGUAGES, VariableEmbeddingPolicy,
     prepare_batch_for_embedding, select_budgeted_variables,
 };
-use julie_core::database::SymbolDatabase;
+use julie_core::background_tasks::BackgroundTask;
+use julie_core::database::{
+    ChunkEmbedding, EmbeddingGeneration, EmbeddingJobStatus, SymbolDatabase,
+};
+use julie_core::memory_budget::{MemoryBudget, MemoryComponent};
 use julie_extractors::{RelationshipKind, Symbol, SymbolKind};
 use julie_index::search::language_config::LanguageConfigs;
 
@@ -26,6 +34,8 @@ use julie_index::search::language_config::LanguageConfigs;
 /// Smaller values here mean shorter per-request time and less risk of hitting the
 /// response timeout — especially for larger models like CodeRankEmbed (768d).
 const EMBEDDING_BATCH_SIZE: usize = 250;
+/// Smallest batch the memory budget can shrink an embedding batch to.
+const MIN_EMBEDDING_BATCH_SIZE: usize = 16;
 const VARIABLE_EMBEDDING_POLICY: VariableEmbeddingPolicy = VariableEmbeddingPolicy {
     enabled: true,
     max_ratio: 0.20,
@@ -50,6 +60,11 @@ pub struct EmbeddingStats {
     pub symbols_embedded: usize,
     pub symbols_skipped: usize,
     pub batches_processed: usize,
+    /// Body-chunk vectors stored for long functions/methods.
+    pub chunks_embedded: usize,
+    /// Vectors dropped at commit because their symbol changed while the run
+    /// was embedding it.
+    pub stale_discarded: usize,
 }
 
 /// Build a map of symbol_id -> callee names from the relationship graph.
@@ -164,6 +179,11 @@ fn build_implementor_map(db: &SymbolDatabase, symbols: &[Symbol]) -> HashMap<Str
 
 /// Run the full embedding pipeline: load symbols → filter → embed → store.
 ///
+/// Vectors are staged under an embedding generation begun when the symbols
+/// are loaded and published together at the end (see
+/// `SymbolDatabase::commit_embedding_generation`), so an index write or a
+/// crash mid-run never leaves vectors that disagree with the symbols.
+///
 /// This is designed to run in a `spawn_blocking` context since both the
 /// embedding provider and database use synchronous Mutex access.
 ///
@@ -184,13 +204,32 @@ pub fn run_embedding_pipeline_cancellable(
     provider: &dyn EmbeddingProvider,
     lang_configs: Option<&LanguageConfigs>,
     cancel: Option<&std::sync::atomic::AtomicBool>,
+) -> Result<EmbeddingStats> {
+    run_embedding_pipeline_tracked(db, provider, lang_configs, cancel, None)
+}
+
+/// Cancellable variant that reports progress on `task` and honors its pause
+/// flag between batches. Pausing only delays the next batch; a cancel still
+/// stops a paused run.
+///
+/// The run is an [`EmbeddingJob`]: it checkpoints every few batches, and a
+/// run that was cancelled, failed or killed is resumed by the next one.
+pub fn run_embedding_pipeline_tracked(
+    db: &Arc<Mutex<SymbolDatabase>>,
+    provider: &dyn EmbeddingProvider,
+    lang_configs: Option<&LanguageConfigs>,
+    cancel: Option<&std::sync::atomic::AtomicBool>,
+    task: Option<&BackgroundTask>,
 ) -> Result<EmbeddingStats> {
     let mut stats = EmbeddingStats {
         symbols_scanned: 0,
         symbols_embedded: 0,
         symbols_skipped: 0,
         batches_processed: 0,
+        chunks_embedded: 0,
+        stale_discarded: 0,
     };
+    let mut job = EmbeddingJob::new(cancel, task);
 
     // Detect model/dimension/format changes and recreate the vector table if needed.
     // Any change wipes all vectors and re-embeds from scratch, because vectors from
@@ -242,17 +281,25 @@ pub fn run_embedding_pipeline_cancellable(
         }
     }
 
-    // Load all symbols, existing embedding IDs, and variable reference scores.
-    let (symbols, already_embedded, variable_reference_scores) = {
-        let db_guard = db
+    // Load all symbols, existing embedding/chunk IDs, and variable reference
+    // scores, and begin the generation under the same lock so its source
+    // revision is the one these symbols were read at.
+    let (generation, symbols, already_embedded, already_chunked, variable_reference_scores) = {
+        let mut db_guard = db
             .lock()
             .map_err(|e| anyhow::anyhow!("DB mutex poisoned: {e}"))?;
+        let generation = db_guard
+            .begin_embedding_generation()
+            .context("Failed to begin embedding generation")?;
         let syms = db_guard
             .get_all_symbols()
             .context("Failed to load symbols for embedding")?;
         let embedded = db_guard
             .get_embedded_symbol_ids()
             .context("Failed to load existing embedding IDs")?;
+        let chunked = db_guard
+            .get_chunked_symbol_ids()
+            .context("Failed to load existing chunk IDs")?;
 
         let variable_ids: Vec<&str> = syms
             .iter()
@@ -269,7 +316,7 @@ pub fn run_embedding_pipeline_cancellable(
             }
         };
 
-        (syms, embedded, reference_scores)
+        (generation, syms, embedded, chunked, reference_scores)
     };
 
     stats.symbols_scanned = symbols.len();
@@ -326,6 +373,7 @@ pub fn run_embedding_pipeline_cancellable(
     all_prepared.extend(selected_variables);
     if all_prepared.is_empty() {
         info!("Embedding pipeline: no embeddable symbols found, skipping");
+        finish_job(db, &generation, &mut stats, &mut job)?;
         return Ok(stats);
     }
 
@@ -334,10 +382,21 @@ pub fn run_embedding_pipeline_cancellable(
     // dropped below the budget cutoff, deleted symbols).
     let eligible_ids: HashSet<&str> = all_prepared.iter().map(|(id, _)| id.as_str()).collect();
     let stale_ids: Vec<String> = already_embedded
-        .iter()
+        .union(&already_chunked)
         .filter(|id| !eligible_ids.contains(id.as_str()))
         .cloned()
         .collect();
+    // Long functions/methods without chunk vectors yet. A symbol's body only
+    // changes with its file, and file changes drop the file's chunks, so
+    // "not chunked yet" is the whole incremental rule.
+    let chunk_candidates: Vec<&Symbol> = symbols
+        .iter()
+        .filter(|s| {
+            is_chunkable_kind(&s.kind)
+                && eligible_ids.contains(s.id.as_str())
+                && !already_chunked.contains(&s.id)
+        })
+        .collect();
     let stale_deleted = if stale_ids.is_empty() {
         0
     } else {
@@ -386,10 +445,15 @@ pub fn run_embedding_pipeline_cancellable(
         .collect();
 
     // Skip symbols that already have embeddings (incremental),
-    // EXCEPT enriched symbols which always get re-embedded.
+    // EXCEPT enriched symbols which always get re-embedded, unless an
+    // interrupted job already re-embedded them.
+    let resumed_ids = job.resumable_ids(db, &already_embedded)?;
     let prepared: Vec<_> = all_prepared
         .into_iter()
-        .filter(|(id, _)| !already_embedded.contains(id) || enriched_ids.contains(id.as_str()))
+        .filter(|(id, _)| {
+            !resumed_ids.contains(id)
+                && (!already_embedded.contains(id) || enriched_ids.contains(id.as_str()))
+        })
         .collect();
 
     // Count skipped as symbols that were already embedded AND not being re-embedded
@@ -398,12 +462,22 @@ pub fn run_embedding_pipeline_cancellable(
         .filter(|(id, _)| already_embedded.contains(id))
         .count();
     stats.symbols_skipped = already_embedded.len() - re_embedded_count;
+    let resumed = resumed_ids.len() as u64;
+    job.start(db, prepared.len() as u64 + resumed, resumed)?;
 
     if prepared.is_empty() {
         info!(
-            "Embedding pipeline: all {} embeddable symbols already embedded, nothing to do",
+            "Embedding pipeline: all {} embeddable symbols already embedded",
             stats.symbols_skipped
         );
+        stats.chunks_embedded = embed_symbol_chunks(
+            db,
+            provider,
+            &chunk_candidates,
+            Some(&generation),
+            Some(&mut job),
+        )?;
+        finish_job(db, &generation, &mut stats, &mut job)?;
         return Ok(stats);
     }
 
@@ -416,11 +490,19 @@ pub fn run_embedding_pipeline_cancellable(
         symbols.len()
     );
 
-    // Process in batches
-    let total_batches = (prepared.len() + EMBEDDING_BATCH_SIZE - 1) / EMBEDDING_BATCH_SIZE;
-    for chunk in prepared.chunks(EMBEDDING_BATCH_SIZE) {
+    // Process in batches, smaller ones when memory is short
+    let budget = MemoryBudget::global();
+    let mut next = 0;
+    while next < prepared.len() {
+        let batch_size = budget.batch_size(EMBEDDING_BATCH_SIZE, MIN_EMBEDDING_BATCH_SIZE);
+        let chunk = &prepared[next..(next + batch_size).min(prepared.len())];
+        next += chunk.len();
+        let total_batches =
+            stats.batches_processed + 1 + (prepared.len() - next).div_ceil(batch_size);
+        job.wait_while_paused();
+
         // Check cancellation between batches (e.g., force reindex aborts old pipeline)
-        if cancel.map_or(false, |c| c.load(std::sync::atomic::Ordering::Acquire)) {
+        if job.is_cancelled() {
             info!(
                 "Embedding pipeline cancelled after {} batches ({} embeddings stored)",
                 stats.batches_processed, stats.symbols_embedded
@@ -429,6 +511,7 @@ pub fn run_embedding_pipeline_cancellable(
         }
 
         let texts: Vec<String> = chunk.iter().map(|(_, text)| text.clone()).collect();
+        let _texts_charge = budget.charge(MemoryComponent::EmbeddingBatches, text_bytes(&texts));
 
         // Generate embeddings -- if a batch fails (e.g., DirectML RuntimeError),
         // log the error and stop. Successful batches are already persisted, and
@@ -443,6 +526,7 @@ pub fn run_embedding_pipeline_cancellable(
                     texts.len(),
                     stats.symbols_embedded,
                 );
+                job.mark_failed();
                 break;
             }
         };
@@ -468,23 +552,29 @@ pub fn run_embedding_pipeline_cancellable(
             .map(|((id, _), vec)| (id.clone(), vec))
             .collect();
 
-        // Store in database
+        // Stage in database; published when the generation commits
         let stored = {
             let mut db_guard = db
                 .lock()
                 .map_err(|e| anyhow::anyhow!("DB mutex poisoned: {e}"))?;
             db_guard
-                .store_embeddings(&pairs)
-                .context("Failed to store embeddings")?
+                .stage_embeddings(&generation, &pairs)
+                .context("Failed to stage embeddings")?
         };
 
         stats.symbols_embedded += stored;
         stats.batches_processed += 1;
+        job.batch_done(
+            db,
+            &generation,
+            pairs.into_iter().map(|(id, _)| id),
+            chunk.len() as u64,
+        )?;
 
         // Fix B part 1: post-batch cancel check. The pre-batch check catches cancellations
         // set before the batch started; this check catches cancellations set DURING embed_batch
         // (e.g., a concurrent force-reindex), stopping before the next batch begins.
-        if cancel.map_or(false, |c| c.load(std::sync::atomic::Ordering::Acquire)) {
+        if job.is_cancelled() {
             info!(
                 "Embedding pipeline cancelled after batch write ({} embeddings stored)",
                 stats.symbols_embedded
@@ -495,19 +585,223 @@ pub fn run_embedding_pipeline_cancellable(
         info!(
             "Embedding batch {}/{}: stored {stored} embeddings ({} total so far)",
             stats.batches_processed,
-            (prepared.len() + EMBEDDING_BATCH_SIZE - 1) / EMBEDDING_BATCH_SIZE,
+            total_batches,
             stats.symbols_embedded,
         );
     }
 
+    stats.chunks_embedded = embed_symbol_chunks(
+        db,
+        provider,
+        &chunk_candidates,
+        Some(&generation),
+        Some(&mut job),
+    )?;
+    finish_job(db, &generation, &mut stats, &mut job)?;
+
     info!(
-        "Embedding pipeline complete: {}/{} symbols embedded in {} batches",
-        stats.symbols_embedded, stats.symbols_scanned, stats.batches_processed
+        "Embedding pipeline complete: {}/{} symbols embedded in {} batches, {} body chunks",
+        stats.symbols_embedded,
+        stats.symbols_scanned,
+        stats.batches_processed,
+        stats.chunks_embedded
     );
 
     Ok(stats)
 }
 
+/// Bytes of `texts` charged to the memory budget while a batch is in flight.
+fn text_bytes(texts: &[String]) -> u64 {
+    texts.iter().map(String::len).sum::<usize>() as u64
+}
+
+/// End a run's job: checkpoint the stretch since the last checkpoint when
+/// the run did not complete (so the next run resumes after it), commit the
+/// generation and record how the job ended.
+fn finish_job(
+    db: &Arc<Mutex<SymbolDatabase>>,
+    generation: &EmbeddingGeneration,
+    stats: &mut EmbeddingStats,
+    job: &mut EmbeddingJob<'_>,
+) -> Result<()> {
+    let outcome = job.outcome();
+    if outcome != EmbeddingJobStatus::Complete {
+        match job.checkpoint(db, generation) {
+            Ok(stale) => stats.stale_discarded += stale,
+            Err(err) if job.is_cancelled() => {
+                info!("Embedding pipeline cancelled; final checkpoint skipped: {err:#}");
+            }
+            Err(err) => return Err(err),
+        }
+    }
+    commit_generation(db, generation, stats, job.cancel_flag())?;
+    let db = db
+        .lock()
+        .map_err(|e| anyhow::anyhow!("DB mutex poisoned: {e}"))?;
+    // A run superseded by a newer one must not overwrite that run's job state.
+    let superseded = db
+        .pending_embedding_generation()?
+        .is_some_and(|pending| pending.generation > generation.generation);
+    if !superseded {
+        db.finish_embedding_job(outcome)
+            .context("Failed to record embedding job outcome")?;
+    }
+    Ok(())
+}
+
+/// Publish a run's staged vectors: phase two of its embedding generation.
+///
+/// A cancelled run still commits what it staged. If the generation was
+/// superseded meanwhile (a forced reindex cleared the vectors, or a newer run
+/// began), the cancelled run's vectors are simply dropped.
+fn commit_generation(
+    db: &Arc<Mutex<SymbolDatabase>>,
+    generation: &EmbeddingGeneration,
+    stats: &mut EmbeddingStats,
+    cancel: Option<&std::sync::atomic::AtomicBool>,
+) -> Result<()> {
+    let committed = db
+        .lock()
+        .map_err(|e| anyhow::anyhow!("DB mutex poisoned: {e}"))?
+        .commit_embedding_generation(generation);
+    let commit = match committed {
+        Ok(commit) => commit,
+        Err(err) if cancel.map_or(false, |c| c.load(std::sync::atomic::Ordering::Acquire)) => {
+            info!("Embedding pipeline cancelled; staged vectors not committed: {err:#}");
+            return Ok(());
+        }
+        Err(err) => return Err(err.context("Failed to commit embedding generation")),
+    };
+    stats.stale_discarded += commit.stale_discarded;
+    if commit.stale_discarded > 0 || commit.orphans_collected > 0 {
+        info!(
+            "Embedding generation {}: discarded {} vectors of symbols changed mid-run, \
+             collected {} orphaned vectors",
+            commit.generation, commit.stale_discarded, commit.orphans_collected
+        );
+    }
+    Ok(())
+}
+
+/// Embed body chunks for the long functions/methods among `candidates`.
+/// Returns the number of chunk vectors stored, or staged when `generation`
+/// is set.
+///
+/// A symbol's chunks always travel in the same batch, so a cancelled or failed
+/// run never leaves a symbol half-chunked (it would not be retried).
+fn embed_symbol_chunks(
+    db: &Arc<Mutex<SymbolDatabase>>,
+    provider: &dyn EmbeddingProvider,
+    candidates: &[&Symbol],
+    generation: Option<&EmbeddingGeneration>,
+    mut job: Option<&mut EmbeddingJob<'_>>,
+) -> Result<usize> {
+    if candidates.is_empty() {
+        return Ok(0);
+    }
+
+    let paths: Vec<String> = candidates
+        .iter()
+        .map(|s| s.file_path.clone())
+        .collect::<HashSet<_>>()
+        .into_iter()
+        .collect();
+    let file_contents = db
+        .lock()
+        .map_err(|e| anyhow::anyhow!("DB mutex poisoned: {e}"))?
+        .get_file_contents_by_paths(&paths)
+        .context("Failed to load file contents for chunking")?;
+
+    let groups = prepare_chunks_for_embedding(candidates, &file_contents);
+    if groups.is_empty() {
+        return Ok(0);
+    }
+    let chunk_count = groups.iter().map(Vec::len).sum::<usize>();
+    info!(
+        "Embedding pipeline: {chunk_count} body chunks for {} long symbols",
+        groups.len()
+    );
+    if let Some(task) = job.as_deref().and_then(EmbeddingJob::task) {
+        task.extend_total(chunk_count as u64);
+        task.set_detail("embedding body chunks");
+    }
+
+    let budget = MemoryBudget::global();
+    let batch_size = budget.batch_size(EMBEDDING_BATCH_SIZE, MIN_EMBEDDING_BATCH_SIZE);
+    let mut batches: Vec<Vec<PreparedChunk>> = vec![Vec::new()];
+    for group in groups {
+        let current = batches.last_mut().expect("batches is never empty");
+        if !current.is_empty() && current.len() + group.len() > batch_size {
+            batches.push(group);
+        } else {
+            current.extend(group);
+        }
+    }
+
+    let mut stored_total = 0;
+    for batch in batches {
+        if let Some(job) = job.as_deref() {
+            job.wait_while_paused();
+            if job.is_cancelled() {
+                info!("Chunk embedding cancelled ({stored_total} chunk vectors stored)");
+                break;
+            }
+        }
+
+        let texts: Vec<String> = batch.iter().map(|chunk| chunk.text.clone()).collect();
+        let _texts_charge = budget.charge(MemoryComponent::EmbeddingBatches, text_bytes(&texts));
+        let vectors = match provider.embed_batch(&texts) {
+            Ok(v) => v,
+            Err(err) => {
+                warn!(
+                    "Chunk embedding batch failed ({} texts), stopping ({stored_total} stored so far): {err:#}",
+                    texts.len()
+                );
+                break;
+            }
+        };
+        if vectors.len() != batch.len() {
+            warn!(
+                "Chunk embedding count mismatch: expected {}, got {}; skipping batch (retried next run)",
+                batch.len(),
+                vectors.len()
+            );
+            continue;
+        }
+
+        let batch_len = batch.len() as u64;
+        let chunks: Vec<ChunkEmbedding> = batch
+            .into_iter()
+            .zip(vectors)
+            .map(|(chunk, vector)| ChunkEmbedding {
+                chunk_id: chunk.chunk_id,
+                symbol_id: chunk.symbol_id,
+                start_line: chunk.start_line,
+                end_line: chunk.end_line,
+                vector,
+            })
+            .collect();
+        stored_total += {
+            let mut db_guard = db
+                .lock()
+                .map_err(|e| anyhow::anyhow!("DB mutex poisoned: {e}"))?;
+            match generation {
+                Some(generation) => db_guard
+                    .stage_chunk_embeddings(generation, &chunks)
+                    .context("Failed to stage chunk embeddings")?,
+                None => db_guard
+                    .store_chunk_embeddings(&chunks)
+                    .context("Failed to store chunk embeddings")?,
+            }
+        };
+        if let (Some(job), Some(generation)) = (job.as_deref_mut(), generation) {
+            job.batch_done(db, generation, std::iter::empty(), batch_len)?;
+        }
+    }
+
+    Ok(stored_total)
+}
+
 /// Embed symbols for a single file (used by incremental indexer after file changes).
 ///
 /// This is synchronous and fast (<200ms for typical files with 5-50 embeddable symbols).
@@ -581,12 +875,30 @@ pub fn embed_symbols_for_file(
         .map(|((id, _), vec)| (id.clone(), vec))
         .collect();
 
-    let mut db_guard = db
-        .lock()
-        .map_err(|e| anyhow::anyhow!("DB mutex poisoned: {e}"))?;
-    db_guard
-        .store_embeddings(&pairs)
-        .context("Failed to store file embeddings")
+    let stored = {
+        let mut db_guard = db
+            .lock()
+            .map_err(|e| anyhow::anyhow!("DB mutex poisoned: {e}"))?;
+        let stored = db_guard
+            .store_embeddings(&pairs)
+            .context("Failed to store file embeddings")?;
+
+        // Clear old chunks first: an edited body may now split into fewer.
+        let stored_ids: Vec<String> = pairs.iter().map(|(id, _)| id.clone()).collect();
+        db_guard
+            .delete_chunk_embeddings_for_symbol_ids(&stored_ids)
+            .context("Failed to delete stale chunk embeddings")?;
+        stored
+    };
+
+    let stored_ids: HashSet<&str> = pairs.iter().map(|(id, _)| id.as_str()).collect();
+    let chunk_candidates: Vec<&Symbol> = symbols
+        .iter()
+        .filter(|s| is_chunkable_kind(&s.kind) && stored_ids.contains(s.id.as_str()))
+        .collect();
+    embed_symbol_chunks(db, provider, &chunk_candidates, None, None)?;
+
+    Ok(stored)
 }
 
 /// Re-embed all symbols for a file, replacing any stale vectors for that file.
@@ -17,7 +17,9 @@ use super::sidecar_protocol::{
     validate_batch_response, validate_health_response, validate_query_response,
     validate_response_envelope,
 };
-use super::sidecar_supervisor::{SidecarLaunchConfig, build_sidecar_launch_config};
+use super::sidecar_supervisor::{
+    SIDECAR_MODEL_ID_ENV, SidecarLaunchConfig, build_sidecar_launch_config,
+};
 use super::{DeviceInfo, EmbeddingProvider};
 
 pub struct SidecarEmbeddingProvider {
@@ -61,7 +63,17 @@ const SHUTDOWN_TIMEOUT_MS: u64 = 500;
 
 impl SidecarEmbeddingProvider {
     pub fn try_new() -> Result<Self> {
-        let launch = build_sidecar_launch_config()?;
+        Self::try_new_with_model(None)
+    }
+
+    /// Like [`Self::try_new`], but asks the sidecar to load `model_id`.
+    pub fn try_new_with_model(model_id: Option<&str>) -> Result<Self> {
+        let mut launch = build_sidecar_launch_config()?;
+        if let Some(model_id) = model_id {
+            launch
+                .env
+                .push((SIDECAR_MODEL_ID_ENV.into(), model_id.into()));
+        }
         Self::spawn_from_launch_config(launch, read_response_timeout())
     }
 
@@ -12,6 +12,8 @@ const SIDECAR_PROGRAM_ENV: &str = "JULIE_EMBEDDING_SIDECAR_PROGRAM";
 const SIDECAR_RAW_PROGRAM_ENV: &str = "JULIE_EMBEDDING_SIDECAR_RAW_PROGRAM";
 const SIDECAR_SCRIPT_ENV: &str = "JULIE_EMBEDDING_SIDECAR_SCRIPT";
 const SIDECAR_MODULE_ENV: &str = "JULIE_EMBEDDING_SIDECAR_MODULE";
+/// Read by the sidecar itself (`sidecar/main.py`) to pick its model.
+pub const SIDECAR_MODEL_ID_ENV: &str = "JULIE_EMBEDDING_SIDECAR_MODEL_ID";
 const EMBEDDING_CACHE_DIR_ENV: &str = "JULIE_EMBEDDING_CACHE_DIR";
 pub const INSTALL_MARKER_VERSION: &str = "v11-gpu-torch";
 /// PyTorch publishes wheels for these minor versions (3.10 through 3.13).
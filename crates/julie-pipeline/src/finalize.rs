@@ -1,7 +1,11 @@
+use std::borrow::Cow;
 use std::collections::HashSet;
 
 use tracing::{info, warn};
 
+use crate::indexing_core::{
+    build_targets, config_keys, godot_scenes, markdown_links, razor_components, terraform,
+};
 use crate::resolver;
 use julie_extractors::PendingRelationship;
 use julie_extractors::base::StructuredPendingRelationship;
@@ -15,7 +19,6 @@ pub fn resolve_pending_relationships(
         return;
     }
 
-    let resolution_start = std::time::Instant::now();
     let mut db_lock = match db.lock() {
         Ok(guard) => guard,
         Err(poisoned) => {
@@ -23,12 +26,60 @@ pub fn resolve_pending_relationships(
             poisoned.into_inner()
         }
     };
+    resolve_pending_relationships_in_db(
+        &mut db_lock,
+        pending_relationships,
+        structured_pending_relationships,
+    );
+}
 
-    let (resolved_relationships, stats) = if structured_pending_relationships.is_empty() {
-        resolver::resolve_batch(pending_relationships, &db_lock)
+/// [`resolve_pending_relationships`] for callers that own the database
+/// directly (e.g. the external-extract merge coordinator).
+pub fn resolve_pending_relationships_in_db(
+    db: &mut julie_core::database::SymbolDatabase,
+    pending_relationships: &[PendingRelationship],
+    structured_pending_relationships: &[StructuredPendingRelationship],
+) {
+    if pending_relationships.is_empty() && structured_pending_relationships.is_empty() {
+        return;
+    }
+
+    let resolution_start = std::time::Instant::now();
+    // Config reads, Razor component tags, build target dependencies, Godot
+    // scene links, Terraform references and documentation links resolve
+    // project-wide against their own targets, not by language-aware name
+    // lookup; keep them away from the general resolver.
+    let mut project_resolved = config_keys::resolve_config_usages(pending_relationships, db);
+    project_resolved.extend(razor_components::resolve_component_usages(
+        pending_relationships,
+        db,
+    ));
+    project_resolved.extend(build_targets::resolve_build_dependencies(
+        pending_relationships,
+        db,
+    ));
+    project_resolved.extend(godot_scenes::resolve_scene_links(pending_relationships, db));
+    project_resolved.extend(terraform::resolve_terraform_references(
+        pending_relationships,
+        db,
+    ));
+    project_resolved.extend(markdown_links::resolve_doc_links(pending_relationships, db));
+    let pending_relationships: Cow<'_, [PendingRelationship]> =
+        if pending_relationships.iter().any(has_own_resolution) {
+            pending_relationships
+                .iter()
+                .filter(|pending| !has_own_resolution(pending))
+                .cloned()
+                .collect()
+        } else {
+            Cow::Borrowed(pending_relationships)
+        };
+    let pending_relationships = pending_relationships.as_ref();
+    let (mut resolved_relationships, stats) = if structured_pending_relationships.is_empty() {
+        resolver::resolve_batch(pending_relationships, db)
     } else {
         let (mut resolved, mut stats) =
-            resolver::resolve_structured_batch(structured_pending_relationships, &db_lock);
+            resolver::resolve_structured_batch(structured_pending_relationships, db);
         let structured_keys: HashSet<_> = structured_pending_relationships
             .iter()
             .map(|structured| pending_key(&structured.pending))
@@ -39,7 +90,7 @@ pub fn resolve_pending_relationships(
             .cloned()
             .collect();
         if !legacy_only.is_empty() {
-            let (legacy_resolved, legacy_stats) = resolver::resolve_batch(&legacy_only, &db_lock);
+            let (legacy_resolved, legacy_stats) = resolver::resolve_batch(&legacy_only, db);
             resolved.extend(legacy_resolved);
             stats.total += legacy_stats.total;
             stats.resolved += legacy_stats.resolved;
@@ -49,8 +100,9 @@ pub fn resolve_pending_relationships(
         }
         (resolved, stats)
     };
+    resolved_relationships.extend(project_resolved);
     if !resolved_relationships.is_empty()
-        && let Err(e) = db_lock.bulk_store_relationships(&resolved_relationships)
+        && let Err(e) = db.bulk_store_relationships(&resolved_relationships)
     {
         warn!("Failed to store resolved relationships: {}", e);
     }
@@ -62,6 +114,16 @@ pub fn resolve_pending_relationships(
     );
 }
 
+/// Pending relationships recorded by a pass that resolves them itself.
+fn has_own_resolution(pending: &PendingRelationship) -> bool {
+    config_keys::is_config_usage(pending)
+        || razor_components::is_component_usage(pending)
+        || build_targets::is_build_dependency(pending)
+        || godot_scenes::is_scene_link(pending)
+        || terraform::is_terraform_reference(pending)
+        || markdown_links::is_doc_link(pending)
+}
+
 fn pending_key(
     pending: &PendingRelationship,
 ) -> (
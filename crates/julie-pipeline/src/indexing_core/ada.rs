@@ -0,0 +1,348 @@
+//! Symbols for Ada and SPARK (`.ads`, `.adb`, `.ada`).
+//!
+//! This pass tokenizes the source with the shared [`lexer`](super::lexer)
+//! and walks its declarations: packages and package bodies, generic and
+//! instantiated units, procedures, functions and operators with their
+//! parameters, records and their components, enumerations, tagged and derived
+//! types, interfaces, task and protected units with their entries, and
+//! package-level objects and exceptions. `with` clauses become imports. Every
+//! symbol carries `{kind, part, ...}` under [`ADA_METADATA_KEY`]: subprograms
+//! add their parameters and result type, and any unit with aspects records
+//! them there, so SPARK contracts (`Pre`, `Post`, `Global`, `Depends`) and
+//! `SPARK_Mode` are kept with the declaration they constrain.
+//!
+//! A unit is usually declared in a `.ads` spec and completed in a `.adb`
+//! body. Both declarations carry the same [`PARTIAL_GROUP_METADATA_KEY`]
+//! key: the lowercased expanded name, plus the parameter and result type
+//! profile for subprograms so overloads stay apart. `deep_dive` uses the key
+//! to present the spec and the body as one symbol and to list both sites, so
+//! navigation from either one reaches the other. The key is derived from the
+//! declaring file alone, so it stays stable when only one of the two files is
+//! re-indexed; `separate (Parent)` bodies take the parent's name as prefix.
+//!
+//! Procedure call statements, and references `Name (...)` in statements whose
+//! name is neither a declared object nor a type, record a `call` identifier
+//! and a `Calls` relationship from the enclosing body. `with` links the
+//! library unit to each package it names (`Imports`), `is new` links an
+//! instance to its generic (`Instantiates`), derived types link to their
+//! parent (`Extends`) and progenitor interfaces (`Implements`), and
+//! components and objects to the type they are declared with (`Uses`).
+//! Targets defined in the same file are resolved here, matching names
+//! case-insensitively as Ada does; the rest are left pending for the
+//! cross-file resolver. A unit missing its `end` ends at end of file.
+
+mod resolution;
+mod statements;
+mod subprograms;
+mod symbols;
+mod tokenize;
+mod tokens;
+mod types;
+mod units;
+
+use tokenize::{END_CONSTRUCTS, is_reserved, is_standard_type, tokenize};
+
+use std::collections::{HashMap, HashSet};
+
+use anyhow::Result;
+use julie_extractors::base::Visibility;
+use julie_extractors::{
+    ExtractionResults, Identifier, IdentifierKind, PendingRelationship, Relationship,
+    RelationshipKind, Symbol, SymbolKind,
+};
+use serde_json::{Map, Value, json};
+
+use crate::indexing_core::csharp_partials::PARTIAL_GROUP_METADATA_KEY;
+use crate::indexing_core::lexer::{
+    Comment, Lexer, Token, TokenKind, doubled_quote_end, join_comments, line_end, punct_len,
+    word_end,
+};
+pub use julie_core::file_policy::{ADA_LANGUAGE, is_ada_language};
+
+/// Metadata key holding the Ada-specific facts of a symbol.
+pub const ADA_METADATA_KEY: &str = "ada";
+
+/// Extract an Ada or SPARK source file.
+pub fn extract_ada(file_path: &str, content: &str) -> Result<ExtractionResults> {
+    let (tokens, comments) = tokenize(content);
+    let mut parser = Parser {
+        file_path,
+        content,
+        tokens: &tokens,
+        comments: &comments,
+        pos: 0,
+        symbols: Vec::new(),
+        identifiers: Vec::new(),
+        references: Vec::new(),
+        scopes: Vec::new(),
+        ids: HashSet::new(),
+        context: Vec::new(),
+        prefix: Prefix::default(),
+        types: HashSet::new(),
+        objects: HashSet::new(),
+    };
+    while parser.pos < tokens.len() {
+        parser.step();
+    }
+    parser.close_remaining();
+    Ok(parser.into_results())
+}
+
+// ---------------------------------------------------------------------------
+// Parser
+// ---------------------------------------------------------------------------
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum ScopeKind {
+    Package,
+    PackageBody,
+    Subprogram,
+    Task,
+    TaskBody,
+    Protected,
+    ProtectedBody,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Part {
+    /// The visible part of a spec.
+    Visible,
+    /// After `private` in a spec.
+    Private,
+    /// The declarative part of a body.
+    Declarations,
+    /// After `begin`.
+    Statements,
+}
+
+struct Scope {
+    kind: ScopeKind,
+    /// Index into `symbols` of the unit this scope's `end` closes.
+    symbol: usize,
+    part: Part,
+    /// Lowercased expanded name of the unit.
+    qualified: String,
+}
+
+/// Words that qualify the next declaration: `generic`, `overriding`, a
+/// `separate (Parent)` clause.
+#[derive(Default)]
+struct Prefix {
+    /// Token where the declaration starts, for its doc comment.
+    start: Option<usize>,
+    generic: bool,
+    separate: Option<String>,
+    /// A `pragma SPARK_Mode` configuration pragma before a library unit.
+    spark_mode: Option<String>,
+}
+
+/// A name used by a symbol, resolved once every definition is known.
+struct Reference {
+    /// Index into `symbols` of the referring symbol.
+    from: usize,
+    name: String,
+    kind: RelationshipKind,
+    line: u32,
+    /// Index into `identifiers` of the usage, if one was recorded.
+    identifier: Option<usize>,
+    /// Confidence when left to the cross-file resolver.
+    pending_confidence: f32,
+}
+
+/// One `A, B : in out T := Default` parameter specification.
+struct Parameter {
+    names: Vec<String>,
+    mode: String,
+    type_text: String,
+    /// Type tokens with package prefixes dropped, for the profile key.
+    type_key: String,
+}
+
+/// What follows a subprogram or entry header.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Completion {
+    Declaration,
+    Body,
+    Stub,
+    Abstract,
+    Null,
+    Expression,
+    Renaming,
+    Instantiation,
+}
+
+impl Completion {
+    fn part(self) -> &'static str {
+        match self {
+            Completion::Declaration => "spec",
+            Completion::Body => "body",
+            Completion::Stub => "stub",
+            Completion::Abstract => "abstract",
+            Completion::Null => "null",
+            Completion::Expression => "expression",
+            Completion::Renaming => "renaming",
+            Completion::Instantiation => "instantiation",
+        }
+    }
+}
+
+struct Parser<'a, 't> {
+    file_path: &'a str,
+    content: &'a str,
+    tokens: &'t [Token<'a>],
+    comments: &'t [Comment<'a>],
+    pos: usize,
+    symbols: Vec<Symbol>,
+    identifiers: Vec<Identifier>,
+    references: Vec<Reference>,
+    scopes: Vec<Scope>,
+    ids: HashSet<String>,
+    /// Last name tokens of `with` clauses waiting for their library unit.
+    context: Vec<usize>,
+    prefix: Prefix,
+    /// Lowercased names of the types declared in this file.
+    types: HashSet<String>,
+    /// Lowercased names of objects, parameters, components and enumeration
+    /// literals declared in this file: `Name (...)` on one is not a call.
+    objects: HashSet<String>,
+}
+
+impl<'a> Parser<'a, '_> {
+    fn step(&mut self) {
+        if self
+            .scopes
+            .last()
+            .is_some_and(|scope| scope.part == Part::Statements)
+        {
+            self.parse_statements();
+        } else {
+            self.parse_declaration();
+        }
+    }
+
+    fn parse_declaration(&mut self) {
+        let from = self.pos;
+        let Some(word) = self.word_at(from).map(str::to_ascii_lowercase) else {
+            self.pos += 1;
+            return;
+        };
+        let next = self.word_at(from + 1).map(str::to_ascii_lowercase);
+        match word.as_str() {
+            "with" => self.parse_with(from + 1, false),
+            "limited" | "private" if next.as_deref() == Some("with") => {
+                self.parse_with(from + 2, word == "limited")
+            }
+            "private" => {
+                // A private part, or a private child unit.
+                if let Some(scope) = self.scopes.last_mut()
+                    && scope.part == Part::Visible
+                {
+                    scope.part = Part::Private;
+                }
+                self.pos = from + 1;
+            }
+            "use" => self.pos = self.skip_statement(from),
+            "pragma" => self.parse_pragma(from),
+            "separate" if self.punct_at(from + 1, "(") => {
+                let close = self.matching_close(from + 1).unwrap_or(from + 1);
+                self.prefix.separate = Some(self.text(from + 2, close).to_ascii_lowercase());
+                self.prefix.start.get_or_insert(from);
+                self.pos = close + 1;
+            }
+            "generic" => self.parse_generic_formals(from),
+            "overriding" => {
+                self.prefix.start.get_or_insert(from);
+                self.pos = from + 1;
+            }
+            "not" if next.as_deref() == Some("overriding") => {
+                self.prefix.start.get_or_insert(from);
+                self.pos = from + 2;
+            }
+            "package" => self.parse_package(from),
+            "procedure" | "function" => self.parse_subprogram(from),
+            "entry" => self.parse_entry(from),
+            "task" | "protected" => self.parse_concurrent_unit(from),
+            "type" => self.parse_type(from),
+            "subtype" => self.parse_subtype(from),
+            "for" => self.parse_representation_clause(from),
+            "begin" => {
+                if let Some(scope) = self.scopes.last_mut() {
+                    scope.part = Part::Statements;
+                }
+                self.pos = from + 1;
+            }
+            "end" => {
+                let last = self.statement_end(from);
+                self.close_scope(last);
+                self.pos = last + 1;
+            }
+            _ if self.punct_at(from + 1, ":") || self.punct_at(from + 1, ",") => {
+                self.parse_object_declaration(from)
+            }
+            _ => self.pos = self.skip_statement(from),
+        }
+    }
+}
+
+struct SymbolSpec {
+    name: String,
+    kind: SymbolKind,
+    ada_kind: &'static str,
+    signature: String,
+    parent: Option<usize>,
+    doc: Option<String>,
+    visibility: Option<Visibility>,
+    extra: Value,
+}
+
+struct UnitSpec<'n> {
+    /// As written: `Flight.Control`, `Compute`, `"+"`.
+    name: &'n str,
+    kind: SymbolKind,
+    ada_kind: &'static str,
+    /// `spec`, `body`, `stub`, ...; `None` for instances and renamings.
+    completion: Option<&'static str>,
+    /// Parameter and result profile for the spec/body group; `None` when the
+    /// unit has no completion to pair with.
+    profile: Option<String>,
+    signature: String,
+    extra: Value,
+}
+
+/// Profile part of a subprogram's spec/body group: each parameter's type
+/// once per name, then the result type.
+fn profile_key(parameters: &[Parameter], returns: Option<&str>) -> String {
+    let mut key = parameters
+        .iter()
+        .flat_map(|parameter| {
+            std::iter::repeat_n(parameter.type_key.as_str(), parameter.names.len())
+        })
+        .collect::<Vec<_>>()
+        .join(";");
+    if let Some(returns) = returns {
+        key.push_str(" return ");
+        key.push_str(returns);
+    }
+    key
+}
+
+fn parameters_json(parameters: &[Parameter]) -> Value {
+    let list: Vec<Value> = parameters
+        .iter()
+        .flat_map(|parameter| {
+            parameter.names.iter().map(
+                |name| json!({ "name": name, "mode": parameter.mode, "type": parameter.type_text }),
+            )
+        })
+        .collect();
+    if list.is_empty() {
+        Value::Null
+    } else {
+        Value::Array(list)
+    }
+}
+
+/// The facts recorded under [`ADA_METADATA_KEY`].
+fn symbol_info(symbol: &Symbol) -> Option<&Value> {
+    symbol.metadata.as_ref()?.get(ADA_METADATA_KEY)
+}
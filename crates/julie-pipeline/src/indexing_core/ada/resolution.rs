@@ -0,0 +1,151 @@
+//! Resolve references to same-file definitions or leave them pending.
+
+use super::*;
+
+impl<'a> Parser<'a, '_> {
+    pub(super) fn into_results(mut self) -> ExtractionResults {
+        let mut subprograms: HashMap<String, usize> = HashMap::new();
+        let mut packages: HashMap<String, usize> = HashMap::new();
+        let mut types: HashMap<String, usize> = HashMap::new();
+        for (index, symbol) in self.symbols.iter().enumerate() {
+            let Some(info) = symbol_info(symbol) else {
+                continue;
+            };
+            let definitions = match info["kind"].as_str() {
+                Some("procedure" | "function" | "entry") => &mut subprograms,
+                Some("package" | "package_instantiation" | "package_renaming") => &mut packages,
+                Some("type" | "subtype" | "task_type" | "protected_type") => &mut types,
+                _ => continue,
+            };
+            definitions
+                .entry(symbol.name.to_ascii_lowercase())
+                .or_insert(index);
+        }
+
+        let mut relationships = Vec::new();
+        let mut relationship_ids = HashSet::new();
+        let mut pending_relationships = Vec::new();
+        for reference in &self.references {
+            let name = reference.name.to_ascii_lowercase();
+            let target = match reference.kind {
+                RelationshipKind::Calls => subprograms.get(&name),
+                RelationshipKind::Imports => packages.get(&name),
+                RelationshipKind::Instantiates => {
+                    packages.get(&name).or_else(|| subprograms.get(&name))
+                }
+                _ => types.get(&name),
+            };
+            let from_symbol_id = self.symbols[reference.from].id.clone();
+            let Some(&target) = target else {
+                pending_relationships.push(PendingRelationship {
+                    from_symbol_id,
+                    callee_name: reference.name.clone(),
+                    kind: reference.kind.clone(),
+                    file_path: self.file_path.to_string(),
+                    line_number: reference.line,
+                    confidence: reference.pending_confidence,
+                });
+                continue;
+            };
+            let to_symbol_id = self.symbols[target].id.clone();
+            if let Some(identifier) = reference.identifier {
+                self.identifiers[identifier].target_symbol_id = Some(to_symbol_id.clone());
+            }
+            let kind = reference.kind.clone();
+            let id = format!(
+                "{}_{}_{:?}_{}_{}",
+                from_symbol_id, to_symbol_id, kind, self.file_path, reference.line
+            );
+            if relationship_ids.insert(id.clone()) {
+                relationships.push(Relationship {
+                    id,
+                    from_symbol_id,
+                    to_symbol_id,
+                    kind,
+                    file_path: self.file_path.to_string(),
+                    line_number: reference.line,
+                    confidence: 1.0,
+                    metadata: None,
+                });
+            }
+        }
+
+        let mut results = ExtractionResults::empty();
+        results.symbols = self.symbols;
+        results.identifiers = self.identifiers;
+        results.relationships = relationships;
+        results.pending_relationships = pending_relationships;
+        results
+    }
+
+    pub(super) fn push_reference(
+        &mut self,
+        from: usize,
+        token: usize,
+        kind: RelationshipKind,
+        identifier_kind: Option<IdentifierKind>,
+        pending_confidence: f32,
+    ) {
+        let token = &self.tokens[token];
+        let identifier = identifier_kind.map(|identifier_kind| {
+            let line_start = self.content[..token.start]
+                .rfind('\n')
+                .map_or(0, |newline| newline + 1);
+            let id_source = format!("{}:ada:usage:{}", self.file_path, token.start);
+            self.identifiers.push(Identifier {
+                id: blake3::hash(id_source.as_bytes()).to_hex()[..32].to_string(),
+                name: token.text.to_string(),
+                kind: identifier_kind,
+                language: ADA_LANGUAGE.to_string(),
+                file_path: self.file_path.to_string(),
+                start_line: token.line,
+                start_column: token.column,
+                end_line: token.line,
+                end_column: token.column + (token.end - token.start) as u32,
+                start_byte: token.start as u32,
+                end_byte: token.end as u32,
+                containing_symbol_id: Some(self.symbols[from].id.clone()),
+                target_symbol_id: None,
+                confidence: 1.0,
+                code_context: self.content[line_start..]
+                    .lines()
+                    .next()
+                    .map(|line| line.trim().to_string()),
+            });
+            self.identifiers.len() - 1
+        });
+        self.references.push(Reference {
+            from,
+            name: token.text.to_string(),
+            kind,
+            line: token.line,
+            identifier,
+            pending_confidence,
+        });
+    }
+
+    /// A `Uses` edge to the subtype mark in `tokens[from..to]`, unless it is
+    /// predefined or not a plain (possibly dotted) name.
+    pub(super) fn type_reference(&mut self, from_symbol: usize, from: usize, to: usize) {
+        let mut at = from;
+        while self.word_is(at, "not") || self.word_is(at, "null") || self.word_is(at, "access") {
+            at += 1;
+        }
+        if self.word_is(at, "constant") || self.word_is(at, "all") {
+            at += 1;
+        }
+        let Some((_, name_end)) = self.dotted_name(at) else {
+            return;
+        };
+        if name_end > to || is_standard_type(self.tokens[name_end - 1].text) {
+            return;
+        }
+        self.push_reference(
+            from_symbol,
+            name_end - 1,
+            RelationshipKind::Uses,
+            Some(IdentifierKind::TypeUsage),
+            0.8,
+        );
+    }
+}
@@ -0,0 +1,188 @@
+//! Calls in statement parts, and scope bookkeeping.
+
+use super::*;
+
+impl<'a> Parser<'a, '_> {
+    /// Scan the statements of the innermost body for calls until the `end`
+    /// that closes it.
+    pub(super) fn parse_statements(&mut self) {
+        let Some(scope) = self.scopes.last() else {
+            return;
+        };
+        let from_symbol = scope.symbol;
+        let mut index = self.pos;
+        // Open `begin` blocks, `declare` blocks and `do` parts.
+        let mut blocks = 0usize;
+        // `declare` blocks whose `begin` is still ahead.
+        let mut declares = 0usize;
+        let mut depth = 0usize;
+        let mut statement_start = true;
+        while index < self.tokens.len() {
+            let token = &self.tokens[index];
+            match token.kind {
+                TokenKind::Punct => {
+                    match token.text {
+                        "(" => depth += 1,
+                        ")" => depth = depth.saturating_sub(1),
+                        _ => {}
+                    }
+                    statement_start = depth == 0 && matches!(token.text, ";" | "=>");
+                    index += 1;
+                    continue;
+                }
+                TokenKind::Word => {}
+                _ => {
+                    statement_start = false;
+                    index += 1;
+                    continue;
+                }
+            }
+            let word = token.text.to_ascii_lowercase();
+            match word.as_str() {
+                "end" => {
+                    let closes_block = !self.word_at(index + 1).is_some_and(|next| {
+                        END_CONSTRUCTS.contains(&next.to_ascii_lowercase().as_str())
+                    });
+                    let end = self.statement_end(index);
+                    if closes_block {
+                        if blocks == 0 {
+                            self.close_scope(end);
+                            self.pos = end + 1;
+                            return;
+                        }
+                        blocks -= 1;
+                    }
+                    index = end + 1;
+                    statement_start = true;
+                    continue;
+                }
+                "declare" => {
+                    blocks += 1;
+                    declares += 1;
+                }
+                "begin" => {
+                    if declares > 0 {
+                        declares -= 1;
+                    } else {
+                        blocks += 1;
+                    }
+                }
+                "do" => blocks += 1,
+                "pragma" => {
+                    index = self.statement_end(index) + 1;
+                    statement_start = true;
+                    continue;
+                }
+                _ if is_reserved(&word) => {}
+                _ => {
+                    let (callee, next) = self
+                        .dotted_name(index)
+                        .map_or((index, index + 1), |(_, end)| (end - 1, end));
+                    if self.punct_at(next, ":") && statement_start {
+                        // Statement or block name `Outer : loop`.
+                        index = next + 1;
+                        continue;
+                    }
+                    if self.is_call(callee, next, statement_start) {
+                        self.push_reference(
+                            from_symbol,
+                            callee,
+                            RelationshipKind::Calls,
+                            Some(IdentifierKind::Call),
+                            if statement_start { 0.9 } else { 0.7 },
+                        );
+                    }
+                    statement_start = false;
+                    index = next;
+                    continue;
+                }
+            }
+            statement_start = matches!(
+                word.as_str(),
+                "begin" | "then" | "else" | "loop" | "do" | "declare" | "select" | "exception"
+            );
+            index += 1;
+        }
+        self.pos = index;
+    }
+
+    /// Scan `tokens[from..to]` (an expression function) for calls.
+    pub(super) fn scan_calls(&mut self, from_symbol: usize, from: usize, to: usize) {
+        let mut index = from;
+        while index < to {
+            if self.tokens[index].kind != TokenKind::Word || is_reserved(self.tokens[index].text) {
+                index += 1;
+                continue;
+            }
+            let (callee, next) = self
+                .dotted_name(index)
+                .map_or((index, index + 1), |(_, end)| (end - 1, end));
+            if self.is_call(callee, next, false) {
+                self.push_reference(
+                    from_symbol,
+                    callee,
+                    RelationshipKind::Calls,
+                    Some(IdentifierKind::Call),
+                    0.7,
+                );
+            }
+            index = next;
+        }
+    }
+
+    /// Whether the name at `callee`, followed by `tokens[next]`, calls a
+    /// subprogram rather than naming an object, a type or an attribute.
+    fn is_call(&self, callee: usize, next: usize, statement_start: bool) -> bool {
+        let name = self.tokens[callee].text.to_ascii_lowercase();
+        if callee > 0 && self.punct_at(callee - 1, "'") {
+            return false;
+        }
+        if self.objects.contains(&name) || self.types.contains(&name) || is_standard_type(&name) {
+            return false;
+        }
+        if self.punct_at(next, "(") {
+            if !statement_start {
+                return true;
+            }
+            // `Table (I) := X;` assigns; `Log (X);` calls.
+            return self
+                .matching_close(next)
+                .is_none_or(|close| !self.punct_at(close + 1, ":="));
+        }
+        statement_start && self.punct_at(next, ";")
+    }
+
+    // -- scopes -----------------------------------------------------------------
+
+    pub(super) fn close_scope(&mut self, last: usize) {
+        if let Some(scope) = self.scopes.pop() {
+            self.set_end(scope.symbol, last);
+        }
+    }
+
+    /// Units still open at end of file end at its last token.
+    pub(super) fn close_remaining(&mut self) {
+        let last = self.tokens.len().saturating_sub(1);
+        while let Some(scope) = self.scopes.pop() {
+            self.set_end(scope.symbol, last);
+        }
+    }
+
+    /// Lowercased expanded name of a symbol declared in the current scope.
+    pub(super) fn group_name(&self, index: usize) -> String {
+        let symbol = &self.symbols[index];
+        let info = symbol_info(symbol);
+        let own = info
+            .and_then(|info| info["unit"].as_str())
+            .unwrap_or(&symbol.name)
+            .to_ascii_lowercase();
+        if let Some(parent) = info.and_then(|info| info["separate"].as_str()) {
+            // A subunit nests under its parent's expanded name.
+            return format!("{parent}.{own}");
+        }
+        match self.scopes.last() {
+            Some(scope) if scope.symbol != index => format!("{}.{own}", scope.qualified),
+            _ => own,
+        }
+    }
+}
@@ -0,0 +1,232 @@
+//! Subprograms and entries: headers, parameters and completions.
+
+use super::*;
+
+impl<'a> Parser<'a, '_> {
+    pub(super) fn parse_subprogram(&mut self, from: usize) {
+        let is_function = self.word_is(from, "function");
+        let (full_name, mut next, operator) = match self.tokens.get(from + 1) {
+            Some(token) if token.kind == TokenKind::Str => (token.text.to_string(), from + 2, true),
+            _ => match self.dotted_name(from + 1) {
+                Some((name, end)) => (name, end, false),
+                None => {
+                    self.pos = self.skip_statement(from);
+                    return;
+                }
+            },
+        };
+
+        let mut parameters = Vec::new();
+        if self.punct_at(next, "(") {
+            let close = self.matching_close(next).unwrap_or(next);
+            parameters = self.parse_parameters(next + 1, close);
+            next = close + 1;
+        }
+        let mut returns = None;
+        if self.word_is(next, "return") {
+            let stop = self.header_stop(next + 1);
+            returns = Some((self.text(next + 1, stop), self.type_key(next + 1, stop)));
+            next = stop;
+        }
+        let signature = self.text(from, next);
+        let (completion, end) = self.parse_completion(&mut next);
+        let aspects = self.take_aspects_before(from, end);
+
+        let in_protected = self.scopes.last().is_some_and(|scope| {
+            matches!(scope.kind, ScopeKind::Protected | ScopeKind::ProtectedBody)
+        });
+        let kind = if operator {
+            SymbolKind::Operator
+        } else if in_protected {
+            SymbolKind::Method
+        } else {
+            SymbolKind::Function
+        };
+        let profile = (completion != Completion::Instantiation)
+            .then(|| profile_key(&parameters, returns.as_ref().map(|(_, key)| key.as_str())));
+        let generic = (completion == Completion::Instantiation)
+            .then(|| self.dotted_name(next + 2).map(|(name, _)| name))
+            .flatten();
+        let extra = json!({
+            "parameters": parameters_json(&parameters),
+            "returns": returns.as_ref().map(|(text, _)| text),
+            "aspects": aspects,
+            "generic": generic,
+        });
+        let index = self.push_unit(
+            UnitSpec {
+                name: &full_name,
+                kind,
+                ada_kind: if is_function { "function" } else { "procedure" },
+                completion: Some(completion.part()),
+                profile,
+                signature,
+                extra,
+            },
+            from,
+            end,
+        );
+        self.after_header(index, completion, next, end, &parameters);
+    }
+
+    /// `entry Get (Item : out Element)` in a task or protected spec, or an
+    /// entry body `entry Get (...) when Count > 0 is ... end Get;`.
+    pub(super) fn parse_entry(&mut self, from: usize) {
+        let Some(name) = self.word_at(from + 1).map(str::to_string) else {
+            self.pos = self.skip_statement(from);
+            return;
+        };
+        let mut next = from + 2;
+        let mut parameters = Vec::new();
+        let mut family = None;
+        while self.punct_at(next, "(") {
+            let close = self.matching_close(next).unwrap_or(next);
+            let has_colon = (next + 1..close).any(|index| self.punct_at(index, ":"));
+            if has_colon && !self.word_is(next + 1, "for") {
+                parameters = self.parse_parameters(next + 1, close);
+            } else {
+                family = Some(self.text(next + 1, close));
+            }
+            next = close + 1;
+        }
+        let signature = self.text(from, next);
+        if self.word_is(next, "when") {
+            // Entry barrier; the body follows its `is`.
+            while next < self.tokens.len() && !self.word_is(next, "is") && !self.punct_at(next, ";")
+            {
+                next += 1;
+            }
+        }
+        let (completion, end) = self.parse_completion(&mut next);
+        let aspects = self.take_aspects_before(from, end);
+        let index = self.push_unit(
+            UnitSpec {
+                name: &name,
+                kind: SymbolKind::Method,
+                ada_kind: "entry",
+                completion: Some(completion.part()),
+                profile: Some(profile_key(&parameters, None)),
+                signature,
+                extra: json!({
+                    "parameters": parameters_json(&parameters),
+                    "family": family,
+                    "aspects": aspects,
+                }),
+            },
+            from,
+            end,
+        );
+        self.after_header(index, completion, next, end, &parameters);
+    }
+
+    /// Classify what follows a subprogram header at `*next`: returns the
+    /// completion and the index of the token ending the declaration (its `;`,
+    /// or the `is` opening a body). Aspects before the completion are
+    /// stepped over; [`Self::take_aspects_before`] reads them back.
+    fn parse_completion(&mut self, next: &mut usize) -> (Completion, usize) {
+        let mut at = *next;
+        if self.word_is(at, "with") {
+            at = self.aspects_end(at + 1);
+        }
+        *next = at;
+        if self.word_is(at, "renames") {
+            return (Completion::Renaming, self.statement_end(at));
+        }
+        if !self.word_is(at, "is") {
+            return (Completion::Declaration, self.statement_end(at));
+        }
+        let after = self.word_at(at + 1).map(str::to_ascii_lowercase);
+        match after.as_deref() {
+            Some("separate") => (Completion::Stub, self.statement_end(at)),
+            Some("abstract") => (Completion::Abstract, self.statement_end(at)),
+            Some("null") => (Completion::Null, self.statement_end(at)),
+            Some("new") => (Completion::Instantiation, self.statement_end(at)),
+            _ if self.punct_at(at + 1, "(") => (Completion::Expression, self.statement_end(at)),
+            _ => (Completion::Body, at),
+        }
+    }
+
+    /// Open the body of a subprogram or entry, or scan an expression
+    /// function for calls.
+    fn after_header(
+        &mut self,
+        index: usize,
+        completion: Completion,
+        next: usize,
+        end: usize,
+        parameters: &[Parameter],
+    ) {
+        for parameter in parameters {
+            self.objects
+                .extend(parameter.names.iter().map(|name| name.to_ascii_lowercase()));
+        }
+        match completion {
+            Completion::Body => {
+                let qualified = self.group_name(index);
+                self.scopes.push(Scope {
+                    kind: ScopeKind::Subprogram,
+                    symbol: index,
+                    part: Part::Declarations,
+                    qualified,
+                });
+                self.pos = end + 1;
+            }
+            Completion::Expression => {
+                let close = self.matching_close(next + 1).unwrap_or(end);
+                self.scan_calls(index, next + 2, close);
+                self.pos = end + 1;
+            }
+            Completion::Instantiation => {
+                if let Some((_, generic_end)) = self.dotted_name(next + 2) {
+                    self.push_reference(
+                        index,
+                        generic_end - 1,
+                        RelationshipKind::Instantiates,
+                        Some(IdentifierKind::TypeUsage),
+                        0.9,
+                    );
+                }
+                self.pos = end + 1;
+            }
+            _ => self.pos = end + 1,
+        }
+    }
+
+    /// Parameter specifications between `(` and `)`, separated by `;`.
+    pub(super) fn parse_parameters(&self, from: usize, to: usize) -> Vec<Parameter> {
+        let mut parameters = Vec::new();
+        for (start, stop) in self.split_top_level(from, to, ";") {
+            let Some(colon) = (start..stop).find(|&index| self.punct_at(index, ":")) else {
+                continue;
+            };
+            let names = self
+                .split_top_level(start, colon, ",")
+                .into_iter()
+                .map(|(s, e)| self.text(s, e))
+                .collect();
+            let mut type_start = colon + 1;
+            let mut mode = Vec::new();
+            while let Some(word) = self.word_at(type_start).map(str::to_ascii_lowercase) {
+                if !matches!(word.as_str(), "in" | "out" | "aliased") {
+                    break;
+                }
+                mode.push(word);
+                type_start += 1;
+            }
+            let type_end = (type_start..stop)
+                .find(|&index| self.punct_at(index, ":="))
+                .unwrap_or(stop);
+            parameters.push(Parameter {
+                names,
+                mode: if mode.is_empty() {
+                    "in".to_string()
+                } else {
+                    mode.join(" ")
+                },
+                type_text: self.text(type_start, type_end),
+                type_key: self.type_key(type_start, type_end),
+            });
+        }
+        parameters
+    }
+}
@@ -0,0 +1,201 @@
+//! Emit symbols and their Ada metadata.
+
+use super::*;
+
+impl<'a> Parser<'a, '_> {
+    /// Emit a library unit, subprogram, entry, package, task or protected
+    /// unit declared at `tokens[from..=last]`, with its spec/body group.
+    pub(super) fn push_unit(&mut self, unit: UnitSpec<'_>, from: usize, last: usize) -> usize {
+        let library_unit = self.scopes.is_empty();
+        let (name, unit_name) = match unit.name.rsplit_once('.') {
+            Some((_, last)) if !unit.name.starts_with('"') => {
+                (last.to_string(), Some(unit.name.to_string()))
+            }
+            _ => (unit.name.to_string(), None),
+        };
+        let separate = self.prefix.separate.take();
+        let mut extra = json!({
+            "part": unit.completion,
+            "unit": unit_name,
+            "separate": separate,
+            "generic": self.prefix.generic.then_some(true),
+        });
+        if let (Value::Object(info), Value::Object(more)) = (&mut extra, unit.extra) {
+            info.extend(more);
+        }
+        if library_unit && let Some(mode) = self.prefix.spark_mode.take() {
+            extra["spark_mode"] = Value::String(mode);
+        }
+        let doc_start = self.prefix.start.take().unwrap_or(from);
+        self.prefix.generic = false;
+
+        let visibility = match self.scopes.last().map(|scope| scope.part) {
+            None | Some(Part::Visible) => Some(Visibility::Public),
+            Some(Part::Private) => Some(Visibility::Private),
+            Some(_) if matches!(unit.completion, Some("body" | "expression" | "null")) => None,
+            Some(_) => Some(Visibility::Private),
+        };
+        let doc = self.doc_before(doc_start).or_else(|| {
+            // Declarations ending in `;` may be documented below.
+            self.punct_at(last, ";")
+                .then(|| self.doc_after(last))
+                .flatten()
+        });
+        let index = self.push_symbol(
+            SymbolSpec {
+                name,
+                kind: unit.kind,
+                ada_kind: unit.ada_kind,
+                signature: unit.signature,
+                parent: self.scopes.last().map(|scope| scope.symbol),
+                doc,
+                visibility,
+                extra,
+            },
+            from,
+            last,
+        );
+
+        if let Some(profile) = unit.profile {
+            let qualified = self.group_name(index);
+            let group = if matches!(unit.ada_kind, "procedure" | "function" | "entry") {
+                format!("ada:{qualified}({profile})")
+            } else {
+                format!("ada:{qualified}")
+            };
+            self.set_metadata(index, PARTIAL_GROUP_METADATA_KEY, Value::String(group));
+        }
+        if library_unit {
+            for token in std::mem::take(&mut self.context) {
+                self.push_reference(index, token, RelationshipKind::Imports, None, 0.9);
+            }
+        }
+        index
+    }
+
+    /// Emit a type, subtype or object declared at `tokens[from..=last]` in
+    /// the current scope.
+    pub(super) fn push_declaration(
+        &mut self,
+        mut spec: SymbolSpec,
+        from: usize,
+        last: usize,
+    ) -> usize {
+        let doc_start = self.prefix.start.take().unwrap_or(from);
+        self.prefix.generic = false;
+        spec.visibility = match self.scopes.last().map(|scope| scope.part) {
+            None | Some(Part::Visible) => Some(Visibility::Public),
+            _ => Some(Visibility::Private),
+        };
+        spec.parent = self.scopes.last().map(|scope| scope.symbol);
+        if spec.signature.is_empty() {
+            spec.signature = self.text(from, last);
+        }
+        spec.doc = self.doc_before(doc_start);
+        self.push_symbol(spec, from, last)
+    }
+
+    /// Extend a declaration through its `;` and pick up a doc comment below
+    /// it when there was none above.
+    pub(super) fn finish_declaration(&mut self, index: usize, end: usize) {
+        self.set_end(index, end);
+        if self.symbols[index].doc_comment.is_none() && self.punct_at(end, ";") {
+            self.symbols[index].doc_comment = self.doc_after(end);
+        }
+    }
+
+    /// Extend a symbol's span and code context through `tokens[last]`.
+    pub(super) fn set_end(&mut self, index: usize, last: usize) {
+        let Some(last) = self.tokens.get(last) else {
+            return;
+        };
+        let symbol = &mut self.symbols[index];
+        if (last.end as u32) < symbol.end_byte {
+            return;
+        }
+        symbol.end_line = last.line;
+        symbol.end_column = last.column + (last.end - last.start) as u32;
+        symbol.end_byte = last.end as u32;
+        symbol.code_context = Some(self.content[symbol.start_byte as usize..last.end].to_string());
+    }
+
+    pub(super) fn set_info(&mut self, index: usize, key: &str, value: Value) {
+        if let Some(Value::Object(info)) = self.symbols[index]
+            .metadata
+            .as_mut()
+            .and_then(|metadata| metadata.get_mut(ADA_METADATA_KEY))
+        {
+            info.entry(key.to_string()).or_insert(value);
+        }
+    }
+
+    pub(super) fn set_metadata(&mut self, index: usize, key: &str, value: Value) {
+        self.symbols[index]
+            .metadata
+            .get_or_insert_with(HashMap::new)
+            .insert(key.to_string(), value);
+    }
+
+    pub(super) fn push_symbol(&mut self, spec: SymbolSpec, first: usize, last: usize) -> usize {
+        let first = &self.tokens[first];
+        let last = &self.tokens[last.min(self.tokens.len() - 1)];
+        let parent = spec.parent.map(|index| &self.symbols[index]);
+        let parent_id = parent.map(|parent| parent.id.clone());
+        let full_name = match parent {
+            Some(parent) => format!("{}.{}", parent.name, spec.name),
+            None => spec.name.clone(),
+        };
+
+        let mut info = json!({ "kind": spec.ada_kind });
+        if let (Value::Object(info), Value::Object(extra)) = (&mut info, spec.extra) {
+            info.extend(extra);
+        }
+        if let Value::Object(info) = &mut info {
+            info.retain(|_, value| !value.is_null());
+        }
+
+        // A spec may declare overloads, and a body repeats its spec's
+        // forward declaration; keep ids unique.
+        let base = format!(
+            "{}:ada:{}:{}",
+            self.file_path,
+            spec.ada_kind,
+            full_name.to_ascii_lowercase()
+        );
+        let mut id_source = base.clone();
+        let mut duplicate = 1;
+        while !self.ids.insert(id_source.clone()) {
+            duplicate += 1;
+            id_source = format!("{base}#{duplicate}");
+        }
+        let id = blake3::hash(id_source.as_bytes()).to_hex()[..32].to_string();
+
+        let metadata = HashMap::from([(ADA_METADATA_KEY.to_string(), info)]);
+        self.symbols.push(Symbol {
+            id,
+            name: spec.name,
+            kind: spec.kind,
+            language: ADA_LANGUAGE.to_string(),
+            file_path: self.file_path.to_string(),
+            start_line: first.line,
+            start_column: first.column,
+            end_line: last.line,
+            end_column: last.column + (last.end - last.start) as u32,
+            start_byte: first.start as u32,
+            end_byte: last.end as u32,
+            signature: Some(spec.signature),
+            doc_comment: spec.doc,
+            visibility: spec.visibility,
+            parent_id,
+            metadata: Some(metadata),
+            semantic_group: None,
+            confidence: None,
+            code_context: Some(self.content[first.start..last.end].to_string()),
+            content_type: None,
+            body_span: None,
+            body_hash: None,
+            annotations: Vec::new(),
+        });
+        self.symbols.len() - 1
+    }
+}
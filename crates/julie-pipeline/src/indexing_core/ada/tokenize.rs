@@ -0,0 +1,198 @@
+//! Tokenizer and reserved vocabulary.
+
+use super::*;
+
+/// Punctuation read as a single token.
+const COMPOUND_PUNCT: &[&str] =
+    &["=>", ":=", "..", "**", "/=", ">=", "<=", "<>", "<<", ">>"];
+
+fn is_word_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_'
+}
+
+pub(super) fn tokenize(content: &str) -> (Vec<Token<'_>>, Vec<Comment<'_>>) {
+    let bytes = content.as_bytes();
+    let mut lexer = Lexer::new(content);
+    let mut i = 0;
+
+    while i < bytes.len() {
+        let c = bytes[i];
+        if c.is_ascii_whitespace() {
+            i += 1;
+            continue;
+        }
+
+        let start = i;
+        let rest = &content[i..];
+        let line_end = line_end(content, i);
+        if rest.starts_with("--") {
+            lexer.push_comment(start, start + content[start..line_end].trim_end().len());
+            i = line_end;
+            continue;
+        }
+
+        let kind = if c == b'"' {
+            i = doubled_quote_end(bytes, i + 1, line_end, b'"');
+            TokenKind::Str
+        } else if c == b'\'' && !follows_name(lexer.last()) && is_char_literal(rest) {
+            i += 1 + rest[1..].chars().next().map_or(1, char::len_utf8) + 1;
+            TokenKind::Char
+        } else if c.is_ascii_digit() {
+            // `16#FF#`, `1_000`, `3.14`, `1.0E-6`; `1..10` is a range.
+            i += 1;
+            while i < bytes.len() {
+                let b = bytes[i];
+                let continues = b.is_ascii_alphanumeric()
+                    || b == b'_'
+                    || b == b'#'
+                    || (b == b'.' && bytes.get(i + 1).is_some_and(u8::is_ascii_digit))
+                    || (matches!(b, b'+' | b'-') && matches!(bytes[i - 1], b'e' | b'E'));
+                if !continues {
+                    break;
+                }
+                i += 1;
+            }
+            TokenKind::Number
+        } else if rest.chars().next().is_some_and(is_word_char) {
+            i = word_end(content, i, is_word_char);
+            TokenKind::Word
+        } else {
+            i += punct_len(rest, COMPOUND_PUNCT);
+            TokenKind::Punct
+        };
+        lexer.push(kind, start, i);
+    }
+    lexer.finish()
+}
+
+/// A tick after a name or `)` starts an attribute (`Buffer'Length`,
+/// `T'Class`); anywhere else it may open a character literal.
+fn follows_name(previous: Option<&Token<'_>>) -> bool {
+    previous.is_some_and(|token| match token.kind {
+        TokenKind::Word => {
+            let word = token.text.to_ascii_lowercase();
+            word == "all" || !RESERVED_WORDS.contains(&word.as_str())
+        }
+        TokenKind::Punct => token.text == ")",
+        TokenKind::Str => true,
+        _ => false,
+    })
+}
+
+fn is_char_literal(rest: &str) -> bool {
+    let mut chars = rest.chars();
+    chars.next();
+    chars.next().is_some_and(|c| c != '\n') && chars.next() == Some('\'')
+}
+
+const RESERVED_WORDS: &[&str] = &[
+    "abort",
+    "abs",
+    "abstract",
+    "accept",
+    "access",
+    "aliased",
+    "all",
+    "and",
+    "array",
+    "at",
+    "begin",
+    "body",
+    "case",
+    "constant",
+    "declare",
+    "delay",
+    "delta",
+    "digits",
+    "do",
+    "else",
+    "elsif",
+    "end",
+    "entry",
+    "exception",
+    "exit",
+    "for",
+    "function",
+    "generic",
+    "goto",
+    "if",
+    "in",
+    "interface",
+    "is",
+    "limited",
+    "loop",
+    "mod",
+    "new",
+    "not",
+    "null",
+    "of",
+    "or",
+    "others",
+    "out",
+    "overriding",
+    "package",
+    "parallel",
+    "pragma",
+    "private",
+    "procedure",
+    "protected",
+    "raise",
+    "range",
+    "record",
+    "rem",
+    "renames",
+    "requeue",
+    "return",
+    "reverse",
+    "select",
+    "separate",
+    "some",
+    "subtype",
+    "synchronized",
+    "tagged",
+    "task",
+    "terminate",
+    "then",
+    "type",
+    "until",
+    "use",
+    "when",
+    "while",
+    "with",
+    "xor",
+];
+
+/// Predefined types: conversions to them are not calls, and declaring an
+/// object of one is not worth a `Uses` edge.
+const STANDARD_TYPES: &[&str] = &[
+    "boolean",
+    "character",
+    "duration",
+    "float",
+    "integer",
+    "long_float",
+    "long_integer",
+    "long_long_float",
+    "long_long_integer",
+    "natural",
+    "positive",
+    "short_float",
+    "short_integer",
+    "string",
+    "unbounded_string",
+    "wide_character",
+    "wide_string",
+    "wide_wide_character",
+    "wide_wide_string",
+];
+
+/// `end` followed by one of these closes a construct that has no `begin`.
+pub(super) const END_CONSTRUCTS: &[&str] = &["if", "loop", "case", "select", "record"];
+
+pub(super) fn is_reserved(word: &str) -> bool {
+    RESERVED_WORDS.contains(&word.to_ascii_lowercase().as_str())
+}
+
+pub(super) fn is_standard_type(name: &str) -> bool {
+    STANDARD_TYPES.contains(&name.to_ascii_lowercase().as_str())
+}
@@ -0,0 +1,311 @@
+//! Token helpers, aspects and doc comments.
+
+use super::*;
+
+impl<'a> Parser<'a, '_> {
+    pub(super) fn word_at(&self, index: usize) -> Option<&'a str> {
+        self.tokens
+            .get(index)
+            .filter(|token| token.kind == TokenKind::Word)
+            .map(|token| token.text)
+    }
+
+    pub(super) fn word_is(&self, index: usize, word: &str) -> bool {
+        self.word_at(index)
+            .is_some_and(|text| text.eq_ignore_ascii_case(word))
+    }
+
+    pub(super) fn punct_at(&self, index: usize, punct: &str) -> bool {
+        self.tokens
+            .get(index)
+            .is_some_and(|token| token.kind == TokenKind::Punct && token.text == punct)
+    }
+
+    /// A name `A.B.C` starting at `index`: its text and the index just past
+    /// it. Reserved words are not names.
+    pub(super) fn dotted_name(&self, index: usize) -> Option<(String, usize)> {
+        let first = self.word_at(index)?;
+        if is_reserved(first) {
+            return None;
+        }
+        let mut end = index + 1;
+        while self.punct_at(end, ".")
+            && self.word_at(end + 1).is_some_and(|word| !is_reserved(word))
+        {
+            end += 2;
+        }
+        Some((self.text(index, end), end))
+    }
+
+    /// Index of the bracket closing the `(` at `open`.
+    pub(super) fn matching_close(&self, open: usize) -> Option<usize> {
+        let mut depth = 0usize;
+        for (index, token) in self.tokens.iter().enumerate().skip(open) {
+            if token.kind != TokenKind::Punct {
+                continue;
+            }
+            match token.text {
+                "(" => depth += 1,
+                ")" => {
+                    depth = depth.saturating_sub(1);
+                    if depth == 0 {
+                        return Some(index);
+                    }
+                }
+                _ => {}
+            }
+        }
+        None
+    }
+
+    /// Index of the `;` ending the declaration or statement at `from`,
+    /// outside parentheses; the last token when there is none.
+    pub(super) fn statement_end(&self, from: usize) -> usize {
+        let mut depth = 0usize;
+        for (index, token) in self.tokens.iter().enumerate().skip(from) {
+            if token.kind != TokenKind::Punct {
+                continue;
+            }
+            match token.text {
+                "(" => depth += 1,
+                ")" => depth = depth.saturating_sub(1),
+                ";" if depth == 0 => return index,
+                _ => {}
+            }
+        }
+        self.tokens.len().saturating_sub(1)
+    }
+
+    pub(super) fn skip_statement(&self, from: usize) -> usize {
+        self.statement_end(from) + 1
+    }
+
+    /// First token at or after `from` that ends a declaration header: `;`,
+    /// `is`, `with`, `renames` or `:=` outside parentheses.
+    pub(super) fn header_stop(&self, from: usize) -> usize {
+        let mut depth = 0usize;
+        for (index, token) in self.tokens.iter().enumerate().skip(from) {
+            match (token.kind, token.text) {
+                (TokenKind::Punct, "(") => depth += 1,
+                (TokenKind::Punct, ")") => depth = depth.saturating_sub(1),
+                (TokenKind::Punct, ";" | ":=") if depth == 0 => return index,
+                (TokenKind::Word, word)
+                    if depth == 0
+                        && ["is", "with", "renames", "do"]
+                            .iter()
+                            .any(|stop| word.eq_ignore_ascii_case(stop)) =>
+                {
+                    return index;
+                }
+                _ => {}
+            }
+        }
+        self.tokens.len()
+    }
+
+    /// Split `tokens[from..to]` at `separator` outside parentheses.
+    pub(super) fn split_top_level(
+        &self,
+        from: usize,
+        to: usize,
+        separator: &str,
+    ) -> Vec<(usize, usize)> {
+        let to = to.min(self.tokens.len());
+        let mut segments = Vec::new();
+        let mut depth = 0usize;
+        let mut segment_start = from;
+        for index in from..to {
+            let token = &self.tokens[index];
+            if token.kind != TokenKind::Punct {
+                continue;
+            }
+            match token.text {
+                "(" => depth += 1,
+                ")" => depth = depth.saturating_sub(1),
+                text if text == separator && depth == 0 => {
+                    if index > segment_start {
+                        segments.push((segment_start, index));
+                    }
+                    segment_start = index + 1;
+                }
+                _ => {}
+            }
+        }
+        if to > segment_start {
+            segments.push((segment_start, to));
+        }
+        segments
+    }
+
+    /// Source text of `tokens[from..to]` with comments dropped and
+    /// whitespace collapsed to single spaces.
+    pub(super) fn text(&self, from: usize, to: usize) -> String {
+        let mut text = String::new();
+        let mut previous_end = None;
+        for token in self
+            .tokens
+            .get(from..to.min(self.tokens.len()))
+            .unwrap_or(&[])
+        {
+            if previous_end.is_some_and(|end| end < token.start) {
+                text.push(' ');
+            }
+            text.push_str(token.text);
+            previous_end = Some(token.end);
+        }
+        text
+    }
+
+    /// Lowercased type tokens with package prefixes dropped, so `Types.Knots`
+    /// in a spec and `Knots` under a `use` clause in the body compare equal.
+    pub(super) fn type_key(&self, from: usize, to: usize) -> String {
+        let mut words = Vec::new();
+        for index in from..to.min(self.tokens.len()) {
+            if self.punct_at(index, ".") || self.punct_at(index + 1, ".") {
+                continue;
+            }
+            words.push(self.tokens[index].text.to_ascii_lowercase());
+        }
+        words.join(" ")
+    }
+
+    // -- aspects ------------------------------------------------------------------
+
+    /// Index just past the aspect specification starting at `from` (after
+    /// its `with`): the `is`, `;` or `renames` that follows it.
+    pub(super) fn aspects_end(&self, from: usize) -> usize {
+        let mut depth = 0usize;
+        for (index, token) in self.tokens.iter().enumerate().skip(from) {
+            match (token.kind, token.text) {
+                (TokenKind::Punct, "(") => depth += 1,
+                (TokenKind::Punct, ")") => depth = depth.saturating_sub(1),
+                (TokenKind::Punct, ";") if depth == 0 => return index,
+                (TokenKind::Word, word)
+                    if depth == 0
+                        && (word.eq_ignore_ascii_case("is")
+                            || word.eq_ignore_ascii_case("renames")) =>
+                {
+                    return index;
+                }
+                _ => {}
+            }
+        }
+        self.tokens.len()
+    }
+
+    /// Parse `with Aspect [=> Value], ...` at `*next`, advancing past it.
+    pub(super) fn parse_aspects(&self, next: &mut usize) -> Value {
+        if !self.word_is(*next, "with") {
+            return Value::Null;
+        }
+        let end = self.aspects_end(*next + 1);
+        let aspects = self.aspect_map(*next + 1, end);
+        *next = end;
+        aspects
+    }
+
+    /// The aspect specification of the declaration `tokens[from..=end]`: the
+    /// last top-level `with` whose items read as aspects.
+    pub(super) fn take_aspects_before(&self, from: usize, end: usize) -> Value {
+        let mut depth = 0usize;
+        let mut found = None;
+        for index in from..end.min(self.tokens.len()) {
+            let token = &self.tokens[index];
+            match (token.kind, token.text) {
+                (TokenKind::Punct, "(") => depth += 1,
+                (TokenKind::Punct, ")") => depth = depth.saturating_sub(1),
+                (TokenKind::Word, _)
+                    if depth == 0
+                        && self.word_is(index, "with")
+                        && !self.word_is(index + 1, "record")
+                        && !self.word_is(index + 1, "private")
+                        && !self.word_is(index + 1, "null") =>
+                {
+                    found = Some(index);
+                }
+                _ => {}
+            }
+        }
+        match found {
+            Some(with) => self.aspect_map(with + 1, self.aspects_end(with + 1).min(end + 1)),
+            None => Value::Null,
+        }
+    }
+
+    fn aspect_map(&self, from: usize, to: usize) -> Value {
+        let mut aspects = Map::new();
+        for (start, stop) in self.split_top_level(from, to, ",") {
+            let arrow = (start..stop).find(|&index| self.punct_at(index, "=>"));
+            let (name, value) = match arrow {
+                Some(arrow) => (self.text(start, arrow), self.text(arrow + 1, stop)),
+                None => (self.text(start, stop), "True".to_string()),
+            };
+            if !name.is_empty() {
+                aspects.insert(name, Value::String(value));
+            }
+        }
+        if aspects.is_empty() {
+            Value::Null
+        } else {
+            Value::Object(aspects)
+        }
+    }
+
+    // -- comments -------------------------------------------------------------
+
+    /// The block of comments directly above `tokens[index]`, joined.
+    /// Comments trailing code, or separated from the token by a blank line,
+    /// are not documentation.
+    pub(super) fn doc_before(&self, index: usize) -> Option<String> {
+        let token = &self.tokens[index];
+        let previous_end = index
+            .checked_sub(1)
+            .map_or(0, |previous| self.tokens[previous].end);
+        let before = self
+            .comments
+            .partition_point(|comment| comment.start < token.start);
+        let mut block = Vec::new();
+        let mut next_line = token.line;
+        for comment in self.comments[..before].iter().rev() {
+            if comment.start < previous_end || comment.trailing || comment.line + 1 < next_line {
+                break;
+            }
+            block.push(comment);
+            next_line = comment.line;
+        }
+        join_comments(block.into_iter().rev())
+    }
+
+    /// GNAT style documents a declaration after it: on the same line, or in
+    /// comment lines right below it that a blank line separates from the next
+    /// declaration (otherwise they belong to that one).
+    pub(super) fn doc_after(&self, last: usize) -> Option<String> {
+        let token = &self.tokens[last];
+        let next = self.tokens.get(last + 1);
+        let after = self
+            .comments
+            .partition_point(|comment| comment.start < token.end);
+        let mut block = Vec::new();
+        let mut expected_line = token.line;
+        for comment in &self.comments[after..] {
+            if next.is_some_and(|next| comment.start > next.start)
+                || (comment.line != expected_line && comment.line != expected_line + 1)
+                || (comment.trailing && comment.line != token.line)
+            {
+                break;
+            }
+            block.push(comment);
+            expected_line = comment.line;
+        }
+        let below = block.iter().any(|comment| comment.line != token.line);
+        if below && next.is_some_and(|next| next.line <= expected_line + 1) {
+            block.retain(|comment| comment.line == token.line);
+        }
+        join_comments(block.into_iter())
+    }
+
+    pub(super) fn item_doc(&self, first: usize, last: usize) -> Option<String> {
+        self.doc_before(first)
+            .or_else(|| self.doc_after(last.min(self.tokens.len() - 1)))
+    }
+}
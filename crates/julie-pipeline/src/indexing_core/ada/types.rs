@@ -0,0 +1,425 @@
+//! Type, subtype, record and object declarations.
+
+use super::*;
+
+impl<'a> Parser<'a, '_> {
+    pub(super) fn parse_type(&mut self, from: usize) {
+        let Some(name) = self.word_at(from + 1).map(str::to_string) else {
+            self.pos = self.skip_statement(from);
+            return;
+        };
+        self.types.insert(name.to_ascii_lowercase());
+        let mut next = from + 2;
+        let mut discriminants = Vec::new();
+        if self.punct_at(next, "(") {
+            let close = self.matching_close(next).unwrap_or(next);
+            discriminants = self.parse_parameters(next + 1, close);
+            next = close + 1;
+        }
+        if !self.word_is(next, "is") {
+            // Incomplete declaration `type Node;`: the full one follows.
+            self.pos = self.statement_end(from) + 1;
+            return;
+        }
+        next += 1;
+
+        let mut modifiers = Vec::new();
+        while let Some(word) = self.word_at(next).map(str::to_ascii_lowercase) {
+            let is_modifier = matches!(
+                word.as_str(),
+                "abstract" | "tagged" | "limited" | "synchronized"
+            ) || (matches!(word.as_str(), "task" | "protected")
+                && self.word_is(next + 1, "interface"));
+            if !is_modifier {
+                break;
+            }
+            modifiers.push(word);
+            next += 1;
+        }
+        let tagged = modifiers.iter().any(|modifier| modifier == "tagged");
+        let definition = self.word_at(next).map(str::to_ascii_lowercase);
+        let (kind, ada_kind) = match definition.as_deref() {
+            _ if self.punct_at(next, "(") => (SymbolKind::Enum, "enumeration"),
+            Some("record") => (
+                if tagged {
+                    SymbolKind::Class
+                } else {
+                    SymbolKind::Struct
+                },
+                "record",
+            ),
+            Some("null") if self.word_is(next + 1, "record") => (
+                if tagged {
+                    SymbolKind::Class
+                } else {
+                    SymbolKind::Struct
+                },
+                "record",
+            ),
+            Some("new") if self.is_type_extension(next) => (SymbolKind::Class, "derived"),
+            Some("new") => (SymbolKind::Type, "derived"),
+            Some("interface") => (SymbolKind::Interface, "interface"),
+            Some("private") => (
+                if tagged {
+                    SymbolKind::Class
+                } else {
+                    SymbolKind::Type
+                },
+                "private",
+            ),
+            Some("array") => (SymbolKind::Type, "array"),
+            Some("access") => (SymbolKind::Type, "access"),
+            _ => (SymbolKind::Type, "scalar"),
+        };
+
+        // The signature stops before components, literals and aspects.
+        let signature_end = match definition.as_deref() {
+            _ if ada_kind == "enumeration" => self
+                .matching_close(next)
+                .map_or(next + 1, |close| close + 1),
+            Some("record") => next + 1,
+            Some("null") if ada_kind == "record" => next + 2,
+            _ => self.header_stop(next),
+        };
+        let index = self.push_declaration(
+            SymbolSpec {
+                name: name.clone(),
+                kind,
+                ada_kind: "type",
+                signature: self.text(from, signature_end),
+                parent: None,
+                doc: None,
+                visibility: None,
+                extra: json!({
+                    "definition": ada_kind,
+                    "modifiers": (!modifiers.is_empty()).then_some(&modifiers),
+                    "discriminants": (!discriminants.is_empty()).then(|| parameters_json(&discriminants)),
+                }),
+            },
+            from,
+            signature_end.saturating_sub(1),
+        );
+        // Partial and full views of a private type share a group.
+        let group = format!("ada:{}", self.group_name(index));
+        self.set_metadata(index, PARTIAL_GROUP_METADATA_KEY, Value::String(group));
+        for discriminant in &discriminants {
+            self.objects.extend(
+                discriminant
+                    .names
+                    .iter()
+                    .map(|name| name.to_ascii_lowercase()),
+            );
+        }
+
+        let end = match definition.as_deref() {
+            _ if ada_kind == "enumeration" => self.parse_enumeration(index, next),
+            Some("record") => self.parse_record(index, next + 1),
+            Some("new") => self.parse_derivation(index, next + 1),
+            Some("interface") => {
+                let mut at = next + 1;
+                while self.word_is(at, "and") {
+                    match self.dotted_name(at + 1) {
+                        Some((_, name_end)) => {
+                            self.push_reference(
+                                index,
+                                name_end - 1,
+                                RelationshipKind::Extends,
+                                Some(IdentifierKind::TypeUsage),
+                                0.9,
+                            );
+                            at = name_end;
+                        }
+                        None => break,
+                    }
+                }
+                self.statement_end(at)
+            }
+            _ => self.statement_end(next),
+        };
+        let aspects = self.take_aspects_before(from, end);
+        if !aspects.is_null() {
+            self.set_info(index, "aspects", aspects);
+        }
+        self.finish_declaration(index, end);
+        self.pos = end + 1;
+    }
+
+    /// `new Parent ... with record`, `with null record` or `with private`.
+    fn is_type_extension(&self, new_at: usize) -> bool {
+        let end = self.statement_end(new_at);
+        (new_at..end).any(|index| {
+            self.word_is(index, "with")
+                && (self.word_is(index + 1, "record")
+                    || self.word_is(index + 1, "private")
+                    || (self.word_is(index + 1, "null") && self.word_is(index + 2, "record")))
+        })
+    }
+
+    fn parse_enumeration(&mut self, index: usize, open: usize) -> usize {
+        let close = self.matching_close(open).unwrap_or(open);
+        for (start, stop) in self.split_top_level(open + 1, close, ",") {
+            let literal = self.tokens[start].text.to_string();
+            self.objects.insert(literal.to_ascii_lowercase());
+            self.push_symbol(
+                SymbolSpec {
+                    name: literal.clone(),
+                    kind: SymbolKind::EnumMember,
+                    ada_kind: "enumeration_literal",
+                    signature: literal,
+                    parent: Some(index),
+                    doc: self.item_doc(start, stop - 1),
+                    visibility: self.symbols[index].visibility.clone(),
+                    extra: Value::Null,
+                },
+                start,
+                stop - 1,
+            );
+        }
+        self.statement_end(close)
+    }
+
+    /// `new Parent [and Interface ...] [with record ... end record]`.
+    fn parse_derivation(&mut self, index: usize, from: usize) -> usize {
+        let mut at = from;
+        if let Some((_, name_end)) = self.dotted_name(at) {
+            if !is_standard_type(self.tokens[name_end - 1].text) {
+                self.push_reference(
+                    index,
+                    name_end - 1,
+                    RelationshipKind::Extends,
+                    Some(IdentifierKind::TypeUsage),
+                    0.9,
+                );
+            }
+            at = name_end;
+        }
+        while self.word_is(at, "and") {
+            let Some((_, name_end)) = self.dotted_name(at + 1) else {
+                break;
+            };
+            self.push_reference(
+                index,
+                name_end - 1,
+                RelationshipKind::Implements,
+                Some(IdentifierKind::TypeUsage),
+                0.9,
+            );
+            at = name_end;
+        }
+        let end = self.statement_end(at);
+        if let Some(record) =
+            (at..end).find(|&i| self.word_is(i, "with") && self.word_is(i + 1, "record"))
+        {
+            return self.parse_record(index, record + 2);
+        }
+        end
+    }
+
+    /// Components from after `record` through `end record;`, including
+    /// those in variant parts. Returns the index of the closing `;`.
+    fn parse_record(&mut self, index: usize, from: usize) -> usize {
+        let mut at = from;
+        while at < self.tokens.len() {
+            if self.word_is(at, "end") && self.word_is(at + 1, "record") {
+                return self.statement_end(at);
+            }
+            let word = self.word_at(at).map(str::to_ascii_lowercase);
+            match word.as_deref() {
+                Some("case") => {
+                    // Variant part: `case Kind is when A => ...`.
+                    at = (at..self.tokens.len())
+                        .find(|&i| self.word_is(i, "is"))
+                        .map_or(at + 1, |is| is + 1);
+                }
+                Some("when") => {
+                    at = (at..self.tokens.len())
+                        .find(|&i| self.punct_at(i, "=>"))
+                        .map_or(at + 1, |arrow| arrow + 1);
+                }
+                Some("end") if self.word_is(at + 1, "case") => at = self.statement_end(at) + 1,
+                Some("null") => at = self.statement_end(at) + 1,
+                Some(_) if self.punct_at(at + 1, ":") || self.punct_at(at + 1, ",") => {
+                    at = self.parse_component(index, at) + 1;
+                }
+                _ => at = self.statement_end(at) + 1,
+            }
+        }
+        self.tokens.len().saturating_sub(1)
+    }
+
+    fn parse_component(&mut self, record: usize, from: usize) -> usize {
+        let end = self.statement_end(from);
+        let Some(colon) = (from..end).find(|&index| self.punct_at(index, ":")) else {
+            return end;
+        };
+        let mut type_start = colon + 1;
+        if self.word_is(type_start, "aliased") {
+            type_start += 1;
+        }
+        let type_end = (type_start..end)
+            .find(|&index| self.punct_at(index, ":=") || self.word_is(index, "with"))
+            .unwrap_or(end);
+        let type_text = self.text(type_start, type_end);
+        let visibility = self.symbols[record].visibility.clone();
+        for (start, _) in self.split_top_level(from, colon, ",") {
+            let name = self.tokens[start].text.to_string();
+            self.objects.insert(name.to_ascii_lowercase());
+            let component = self.push_symbol(
+                SymbolSpec {
+                    name: name.clone(),
+                    kind: SymbolKind::Field,
+                    ada_kind: "component",
+                    signature: format!("{name} : {type_text}"),
+                    parent: Some(record),
+                    doc: self.item_doc(start, end),
+                    visibility: visibility.clone(),
+                    extra: json!({ "type": type_text }),
+                },
+                start,
+                end,
+            );
+            self.type_reference(component, type_start, type_end);
+        }
+        end
+    }
+
+    pub(super) fn parse_subtype(&mut self, from: usize) {
+        let end = self.statement_end(from);
+        let Some(name) = self.word_at(from + 1).map(str::to_string) else {
+            self.pos = end + 1;
+            return;
+        };
+        self.types.insert(name.to_ascii_lowercase());
+        let base = self
+            .word_is(from + 2, "is")
+            .then(|| self.header_stop(from + 3));
+        let index = self.push_declaration(
+            SymbolSpec {
+                name,
+                kind: SymbolKind::Type,
+                ada_kind: "subtype",
+                signature: String::new(),
+                parent: None,
+                doc: None,
+                visibility: None,
+                extra: Value::Null,
+            },
+            from,
+            base.unwrap_or(end),
+        );
+        if base.is_some() {
+            let stop = (from + 3..end)
+                .find(|&i| {
+                    self.word_is(i, "range") || self.punct_at(i, "(") || self.word_is(i, "with")
+                })
+                .unwrap_or(end);
+            self.type_reference(index, from + 3, stop);
+        }
+        let aspects = self.take_aspects_before(from, end);
+        if !aspects.is_null() {
+            self.set_info(index, "aspects", aspects);
+        }
+        self.finish_declaration(index, end);
+        self.pos = end + 1;
+    }
+
+    /// `for T use ...;` and `for T'Size use 32;` carry no symbols; a record
+    /// representation clause contains `;` of its own.
+    pub(super) fn parse_representation_clause(&mut self, from: usize) {
+        let end = self.statement_end(from);
+        let record = (from..end).find(|&i| self.word_is(i, "use") && self.word_is(i + 1, "record"));
+        self.pos = match record {
+            Some(at) => {
+                let close = (at..self.tokens.len())
+                    .find(|&i| self.word_is(i, "end") && self.word_is(i + 1, "record"))
+                    .map_or(end, |close| self.statement_end(close));
+                close + 1
+            }
+            None => end + 1,
+        };
+    }
+
+    // -- objects ----------------------------------------------------------------
+
+    /// `A, B : [aliased] [constant] T [:= Init];`, `E : exception;` and
+    /// object renamings. Only package-level and protected objects become
+    /// symbols; locals of a body are recorded as names only.
+    pub(super) fn parse_object_declaration(&mut self, from: usize) {
+        let end = self.statement_end(from);
+        let Some(colon) = (from..end).find(|&index| self.punct_at(index, ":")) else {
+            self.pos = end + 1;
+            return;
+        };
+        let names: Vec<(usize, String)> = self
+            .split_top_level(from, colon, ",")
+            .into_iter()
+            .filter(|&(start, _)| self.tokens[start].kind == TokenKind::Word)
+            .map(|(start, _)| (start, self.tokens[start].text.to_string()))
+            .collect();
+        self.objects
+            .extend(names.iter().map(|(_, name)| name.to_ascii_lowercase()));
+
+        let emits = self.scopes.last().is_some_and(|scope| {
+            matches!(
+                scope.kind,
+                ScopeKind::Package | ScopeKind::PackageBody | ScopeKind::Protected
+            )
+        });
+        if !emits {
+            self.pos = end + 1;
+            return;
+        }
+
+        let mut type_start = colon + 1;
+        let mut constant = false;
+        while let Some(word) = self.word_at(type_start).map(str::to_ascii_lowercase) {
+            match word.as_str() {
+                "aliased" => {}
+                "constant" => constant = true,
+                _ => break,
+            }
+            type_start += 1;
+        }
+        let exception = self.word_is(type_start, "exception");
+        let type_end = (type_start..end)
+            .find(|&index| {
+                self.punct_at(index, ":=")
+                    || self.word_is(index, "renames")
+                    || self.word_is(index, "with")
+            })
+            .unwrap_or(end);
+        let type_text = self.text(type_start, type_end);
+        let (kind, ada_kind) = if exception {
+            (SymbolKind::Constant, "exception")
+        } else if constant {
+            (SymbolKind::Constant, "constant")
+        } else {
+            (SymbolKind::Variable, "object")
+        };
+        let aspects = self.take_aspects_before(from, end);
+        for (start, name) in names {
+            let index = self.push_declaration(
+                SymbolSpec {
+                    name: name.clone(),
+                    kind: kind.clone(),
+                    ada_kind,
+                    signature: self.text(from, end),
+                    parent: None,
+                    doc: None,
+                    visibility: None,
+                    extra: json!({
+                        "type": (!exception && !type_text.is_empty()).then_some(&type_text),
+                        "aspects": aspects,
+                    }),
+                },
+                start,
+                end,
+            );
+            self.finish_declaration(index, end);
+            if !exception {
+                self.type_reference(index, type_start, type_end);
+            }
+        }
+        self.pos = end + 1;
+    }
+}
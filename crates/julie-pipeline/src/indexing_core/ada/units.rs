@@ -0,0 +1,292 @@
+//! Context clauses, pragmas, generic formals, packages, tasks and protected
+//! units.
+
+use super::*;
+
+impl<'a> Parser<'a, '_> {
+    /// `with A.B, C;` — one import per unit named.
+    pub(super) fn parse_with(&mut self, from: usize, limited: bool) {
+        let end = self.statement_end(from);
+        if !self.scopes.is_empty() {
+            self.pos = end + 1;
+            return;
+        }
+        for (start, stop) in self.split_top_level(from, end, ",") {
+            let unit = self.text(start, stop);
+            if unit.is_empty() {
+                continue;
+            }
+            let name = self.tokens[stop - 1].text.to_string();
+            self.push_symbol(
+                SymbolSpec {
+                    name: unit.clone(),
+                    kind: SymbolKind::Import,
+                    ada_kind: "with",
+                    signature: format!("with {unit}"),
+                    parent: None,
+                    doc: None,
+                    visibility: None,
+                    extra: json!({ "unit": unit, "referenced": name, "limited": limited.then_some(true) }),
+                },
+                start,
+                stop - 1,
+            );
+            self.context.push(stop - 1);
+        }
+        self.pos = end + 1;
+    }
+
+    pub(super) fn parse_pragma(&mut self, from: usize) {
+        let end = self.statement_end(from);
+        if self
+            .word_at(from + 1)
+            .is_some_and(|name| name.eq_ignore_ascii_case("spark_mode"))
+        {
+            let mode = if self.punct_at(from + 2, "(") {
+                self.text(from + 3, end.saturating_sub(1).max(from + 3))
+            } else {
+                "On".to_string()
+            };
+            match self.scopes.last() {
+                Some(scope) => {
+                    let key = if scope.part == Part::Private {
+                        "private_spark_mode"
+                    } else {
+                        "spark_mode"
+                    };
+                    let symbol = scope.symbol;
+                    self.set_info(symbol, key, Value::String(mode));
+                }
+                None => self.prefix.spark_mode = Some(mode),
+            }
+        }
+        self.pos = end + 1;
+    }
+
+    /// Step over a generic formal part up to the unit it parameterizes.
+    pub(super) fn parse_generic_formals(&mut self, from: usize) {
+        self.prefix.start.get_or_insert(from);
+        self.prefix.generic = true;
+        let mut index = from + 1;
+        while index < self.tokens.len() {
+            let word = self.word_at(index).map(str::to_ascii_lowercase);
+            if matches!(word.as_deref(), Some("package" | "procedure" | "function")) {
+                break;
+            }
+            index = self.skip_statement(index);
+        }
+        self.pos = index;
+    }
+
+    // -- packages -----------------------------------------------------------
+
+    pub(super) fn parse_package(&mut self, from: usize) {
+        let body = self.word_is(from + 1, "body");
+        let name_at = if body { from + 2 } else { from + 1 };
+        let Some((full_name, name_end)) = self.dotted_name(name_at) else {
+            self.pos = self.skip_statement(from);
+            return;
+        };
+        let mut next = name_end;
+        let header = self.text(from, name_end);
+
+        if !body && self.word_is(next, "is") && self.word_is(next + 1, "new") {
+            let generic_at = next + 2;
+            let generic = self.dotted_name(generic_at);
+            let end = self.statement_end(from);
+            let index = self.push_unit(
+                UnitSpec {
+                    name: &full_name,
+                    kind: SymbolKind::Module,
+                    ada_kind: "package_instantiation",
+                    completion: None,
+                    profile: None,
+                    signature: self.text(from, generic.as_ref().map_or(end, |(_, e)| *e)),
+                    extra: json!({ "generic": generic.as_ref().map(|(name, _)| name) }),
+                },
+                from,
+                end,
+            );
+            if let Some((_, generic_end)) = generic {
+                self.push_reference(
+                    index,
+                    generic_end - 1,
+                    RelationshipKind::Instantiates,
+                    Some(IdentifierKind::TypeUsage),
+                    0.9,
+                );
+            }
+            self.pos = end + 1;
+            return;
+        }
+        if !body && self.word_is(next, "renames") {
+            let end = self.statement_end(from);
+            let renamed = self.text(next + 1, end);
+            self.push_unit(
+                UnitSpec {
+                    name: &full_name,
+                    kind: SymbolKind::Module,
+                    ada_kind: "package_renaming",
+                    completion: None,
+                    profile: None,
+                    signature: self.text(from, end),
+                    extra: json!({ "renames": renamed }),
+                },
+                from,
+                end,
+            );
+            self.pos = end + 1;
+            return;
+        }
+        if body && self.word_is(next, "is") && self.word_is(next + 1, "separate") {
+            let end = self.statement_end(from);
+            self.push_unit(
+                UnitSpec {
+                    name: &full_name,
+                    kind: SymbolKind::Module,
+                    ada_kind: "package_body",
+                    completion: Some("stub"),
+                    profile: Some(String::new()),
+                    signature: header,
+                    extra: Value::Null,
+                },
+                from,
+                end,
+            );
+            self.pos = end + 1;
+            return;
+        }
+
+        let aspects = self.parse_aspects(&mut next);
+        if !self.word_is(next, "is") {
+            self.pos = self.skip_statement(from);
+            return;
+        }
+        let index = self.push_unit(
+            UnitSpec {
+                name: &full_name,
+                kind: SymbolKind::Module,
+                ada_kind: if body { "package_body" } else { "package" },
+                completion: Some(if body { "body" } else { "spec" }),
+                profile: Some(String::new()),
+                signature: header,
+                extra: json!({ "aspects": aspects }),
+            },
+            from,
+            next,
+        );
+        let qualified = self.group_name(index);
+        self.scopes.push(Scope {
+            kind: if body {
+                ScopeKind::PackageBody
+            } else {
+                ScopeKind::Package
+            },
+            symbol: index,
+            part: if body {
+                Part::Declarations
+            } else {
+                Part::Visible
+            },
+            qualified,
+        });
+        self.pos = next + 1;
+    }
+
+    pub(super) fn parse_concurrent_unit(&mut self, from: usize) {
+        let protected = self.word_is(from, "protected");
+        let body = self.word_is(from + 1, "body");
+        let is_type = self.word_is(from + 1, "type");
+        let name_at = if body || is_type { from + 2 } else { from + 1 };
+        let Some(name) = self.word_at(name_at).map(str::to_string) else {
+            self.pos = self.skip_statement(from);
+            return;
+        };
+        let mut next = name_at + 1;
+        if self.punct_at(next, "(") {
+            // Discriminants.
+            next = self
+                .matching_close(next)
+                .map_or(next + 1, |close| close + 1);
+        }
+        let signature = self.text(from, next);
+        let aspects = self.parse_aspects(&mut next);
+        let ada_kind = match (protected, body, is_type) {
+            (false, true, _) => "task_body",
+            (false, false, true) => "task_type",
+            (false, false, false) => "task",
+            (true, true, _) => "protected_body",
+            (true, false, true) => "protected_type",
+            (true, false, false) => "protected",
+        };
+        let opens = self.word_is(next, "is") && !self.word_is(next + 1, "separate");
+        let end = if opens {
+            next
+        } else {
+            self.statement_end(next)
+        };
+        let index = self.push_unit(
+            UnitSpec {
+                name: &name,
+                kind: SymbolKind::Class,
+                ada_kind,
+                completion: Some(match (body, opens) {
+                    (true, true) => "body",
+                    (true, false) => "stub",
+                    _ => "spec",
+                }),
+                profile: Some(String::new()),
+                signature,
+                extra: json!({ "aspects": aspects }),
+            },
+            from,
+            end,
+        );
+        if is_type {
+            self.types.insert(name.to_ascii_lowercase());
+        }
+        if !opens {
+            self.pos = end + 1;
+            return;
+        }
+        next += 1;
+        if !body && self.word_is(next, "new") {
+            // `task type Worker is new Runnable with ...`
+            next += 1;
+            while let Some((_, name_end)) = self.dotted_name(next) {
+                self.push_reference(
+                    index,
+                    name_end - 1,
+                    RelationshipKind::Implements,
+                    Some(IdentifierKind::TypeUsage),
+                    0.9,
+                );
+                next = name_end;
+                if !self.word_is(next, "and") {
+                    break;
+                }
+                next += 1;
+            }
+            if self.word_is(next, "with") {
+                next += 1;
+            }
+        }
+        let qualified = self.group_name(index);
+        self.scopes.push(Scope {
+            kind: match (protected, body) {
+                (false, false) => ScopeKind::Task,
+                (false, true) => ScopeKind::TaskBody,
+                (true, false) => ScopeKind::Protected,
+                (true, true) => ScopeKind::ProtectedBody,
+            },
+            symbol: index,
+            part: if body {
+                Part::Declarations
+            } else {
+                Part::Visible
+            },
+            qualified,
+        });
+        self.pos = next;
+    }
+}
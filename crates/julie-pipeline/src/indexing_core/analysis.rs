@@ -7,5 +7,13 @@ pub fn run_sqlite_analysis(db: &SymbolDatabase) -> Result<()> {
     let language_configs = julie_index::search::LanguageConfigs::load_embedded();
     julie_index::analysis::compute_test_quality_metrics(db, &language_configs)?;
     julie_index::analysis::compute_test_linkage(db)?;
+    julie_index::analysis::compute_cross_language_links(db)?;
+    julie_index::analysis::compute_external_call_modules(db)?;
+    julie_index::analysis::compute_symbol_aliases(db)?;
+    julie_index::analysis::compute_expect_actual_links(db)?;
+    julie_index::analysis::compute_swift_modules(db)?;
+    julie_index::analysis::compute_workspace_projects(db)?;
+    julie_index::analysis::compute_build_target_files(db)?;
+    julie_index::analysis::compute_frameworks(db)?;
     Ok(())
 }
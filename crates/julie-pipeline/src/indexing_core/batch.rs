@@ -2,8 +2,13 @@ use julie_extractors::base::{
     ComplexityMetric, ParseDiagnostic, SourceRegion, StructuralFact, StructuredPendingRelationship,
 };
 use julie_extractors::{Identifier, Literal, PendingRelationship, Relationship, Symbol};
+use serde::{Deserialize, Serialize};
 
-#[derive(Debug)]
+/// Extraction output for a set of files, ready for persistence.
+///
+/// Serializable so sharded workers can stream batches to a coordinator
+/// (see [`shards`](crate::indexing_core::shards)).
+#[derive(Debug, Serialize, Deserialize)]
 pub struct ExtractedBatch {
     pub all_symbols: Vec<Symbol>,
     pub all_relationships: Vec<Relationship>,
@@ -28,6 +33,8 @@ pub struct ExtractedBatch {
     pub parse_diagnostics_by_file: Vec<(String, Vec<ParseDiagnostic>)>,
     pub files_to_clean: Vec<String>,
     pub repair_entries: Vec<(String, String)>,
+    /// `(path, reason)` for parser-backed files indexed text-only instead.
+    pub parse_skips: Vec<(String, String)>,
     pub files_processed: usize,
 }
 
@@ -73,11 +80,91 @@ impl ExtractedBatch {
             parse_diagnostics_by_file: Vec::new(),
             files_to_clean: Vec::new(),
             repair_entries: Vec::new(),
+            parse_skips: Vec::new(),
             files_processed: 0,
         }
     }
 }
 
+impl ExtractedBatch {
+    /// Move every row of `other` into this batch, leaving `other` empty.
+    pub fn absorb(&mut self, other: &mut ExtractedBatch) {
+        self.all_symbols.append(&mut other.all_symbols);
+        self.all_relationships.append(&mut other.all_relationships);
+        self.all_pending_relationships
+            .append(&mut other.all_pending_relationships);
+        self.all_structured_pending_relationships
+            .append(&mut other.all_structured_pending_relationships);
+        self.all_identifiers.append(&mut other.all_identifiers);
+        self.all_types.append(&mut other.all_types);
+        self.all_type_argument_rows
+            .append(&mut other.all_type_argument_rows);
+        self.all_literals.append(&mut other.all_literals);
+        self.all_source_regions
+            .append(&mut other.all_source_regions);
+        self.all_structural_facts
+            .append(&mut other.all_structural_facts);
+        self.all_complexity_metrics
+            .append(&mut other.all_complexity_metrics);
+        self.all_file_infos.append(&mut other.all_file_infos);
+        self.parse_diagnostics_by_file
+            .append(&mut other.parse_diagnostics_by_file);
+        self.files_to_clean.append(&mut other.files_to_clean);
+        self.repair_entries.append(&mut other.repair_entries);
+        self.parse_skips.append(&mut other.parse_skips);
+        self.files_processed += std::mem::take(&mut other.files_processed);
+    }
+}
+
+impl ExtractedBatch {
+    /// Rough heap size of the batch for the memory budget: the inline size
+    /// of every row, plus the text of file contents, symbols and
+    /// identifiers, which dominate it.
+    pub fn approximate_bytes(&self) -> u64 {
+        fn text(value: &Option<String>) -> usize {
+            value.as_ref().map_or(0, String::len)
+        }
+
+        let rows = std::mem::size_of_val(self.all_symbols.as_slice())
+            + std::mem::size_of_val(self.all_relationships.as_slice())
+            + std::mem::size_of_val(self.all_pending_relationships.as_slice())
+            + std::mem::size_of_val(self.all_structured_pending_relationships.as_slice())
+            + std::mem::size_of_val(self.all_identifiers.as_slice())
+            + std::mem::size_of_val(self.all_types.as_slice())
+            + std::mem::size_of_val(self.all_type_argument_rows.as_slice())
+            + std::mem::size_of_val(self.all_literals.as_slice())
+            + std::mem::size_of_val(self.all_source_regions.as_slice())
+            + std::mem::size_of_val(self.all_structural_facts.as_slice())
+            + std::mem::size_of_val(self.all_complexity_metrics.as_slice())
+            + std::mem::size_of_val(self.all_file_infos.as_slice());
+        let file_text: usize = self
+            .all_file_infos
+            .iter()
+            .map(|file| file.path.len() + file.hash.len() + text(&file.content))
+            .sum();
+        let symbol_text: usize = self
+            .all_symbols
+            .iter()
+            .map(|symbol| {
+                symbol.id.len()
+                    + symbol.name.len()
+                    + symbol.file_path.len()
+                    + text(&symbol.signature)
+                    + text(&symbol.doc_comment)
+                    + text(&symbol.code_context)
+            })
+            .sum();
+        let identifier_text: usize = self
+            .all_identifiers
+            .iter()
+            .map(|identifier| {
+                identifier.name.len() + identifier.file_path.len() + text(&identifier.code_context)
+            })
+            .sum();
+        (rows + file_text + symbol_text + identifier_text) as u64
+    }
+}
+
 impl Default for ExtractedBatch {
     fn default() -> Self {
         Self::new()
@@ -0,0 +1,373 @@
+//! Build targets from Makefiles, CMake scripts and Bazel packages.
+//!
+//! Build files define the real module graph, but julie-extractors has no
+//! grammar for them, so they would otherwise be indexed as plain text. This
+//! pass reads Make rules (`Makefile`, `GNUmakefile`, `*.mk`), CMake
+//! `add_executable` / `add_library` / `add_custom_target` (plus
+//! `target_sources`, `target_link_libraries` and `add_dependencies` on those
+//! targets) and Bazel rule calls in `BUILD` / `BUILD.bazel`, and emits one
+//! module symbol per target. Every target carries semantic group
+//! [`BUILD_TARGET_SEMANTIC_GROUP`] and `{system, rule, label, sources, deps}`
+//! under [`BUILD_METADATA_KEY`]: `label` is how other targets name it
+//! (`//src/net:http` for Bazel, the target name for Make and CMake) and
+//! `sources` the workspace-relative files it compiles, which
+//! `julie_index::analysis::build_targets` maps back to their targets after
+//! indexing.
+//!
+//! Each dependency becomes a `Uses` relationship from the target to the
+//! target it depends on. It is resolved here when both live in the same file
+//! and left pending otherwise, with a callee carrying
+//! [`BUILD_DEPENDENCY_CALLEE_PREFIX`]; finalize hands those to
+//! [`resolve_build_dependencies`], which matches labels within one build
+//! system. Dependencies on system libraries and external repositories
+//! (`pthread`, `@abseil-cpp//absl/strings`) have no target and stay unlinked.
+//!
+//! Parsing is tolerant: simple variables are expanded, while anything that
+//! needs real evaluation (functions, globs, `select`, generator expressions)
+//! is skipped, never an error, because a half-edited build file must still
+//! index.
+
+mod bazel;
+mod cmake;
+mod make;
+
+use bazel::parse_bazel;
+use cmake::parse_cmake;
+use make::parse_makefile;
+
+use std::collections::{HashMap, HashSet};
+use std::path::Path;
+
+use anyhow::Result;
+use julie_core::database::SymbolDatabase;
+use julie_core::file_policy::detect_language_for_indexing;
+use julie_extractors::{
+    ExtractionResults, PendingRelationship, Relationship, RelationshipKind, Symbol, SymbolKind,
+};
+use serde_json::json;
+use tracing::{debug, warn};
+
+use crate::indexing_core::text_lines::TextLines;
+
+pub use julie_core::file_policy::{
+    BAZEL_LANGUAGE, CMAKE_LANGUAGE, MAKEFILE_LANGUAGE, is_build_file_language,
+};
+
+/// Metadata key holding `{system, rule, label, sources, deps}`.
+pub const BUILD_METADATA_KEY: &str = "build";
+
+/// `semantic_group` shared by every build target symbol.
+pub const BUILD_TARGET_SEMANTIC_GROUP: &str = "build_target";
+
+/// Prefix on the `callee_name` of a pending dependency between targets. The
+/// rest of the name is `<system>:<label>`.
+pub const BUILD_DEPENDENCY_CALLEE_PREFIX: &str = "build:";
+
+/// Nesting limit for Make variable expansion; deeper references are left
+/// unexpanded (and their words skipped).
+const MAX_EXPANSION_DEPTH: usize = 8;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BuildSystem {
+    Make,
+    CMake,
+    Bazel,
+}
+
+impl BuildSystem {
+    /// The build system of a file indexed as `language`.
+    pub fn for_language(language: &str) -> Option<Self> {
+        match language {
+            MAKEFILE_LANGUAGE => Some(Self::Make),
+            CMAKE_LANGUAGE => Some(Self::CMake),
+            BAZEL_LANGUAGE => Some(Self::Bazel),
+            _ => None,
+        }
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Make => "make",
+            Self::CMake => "cmake",
+            Self::Bazel => "bazel",
+        }
+    }
+}
+
+/// One target declared in a build file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BuildTarget {
+    /// Name as declared (`http`, `libcore`, `main.o`).
+    pub name: String,
+    /// How other targets refer to it: `//pkg:name` for Bazel, the name
+    /// otherwise.
+    pub label: String,
+    /// Declaring rule: `cc_library`, `add_executable`, `rule` for Make.
+    pub rule: String,
+    /// Workspace-relative files the target compiles, in declaration order.
+    pub sources: Vec<String>,
+    /// Targets it depends on, in declaration order.
+    pub deps: Vec<BuildDependency>,
+    /// Byte span of the declaration (a Make rule includes its recipe).
+    pub start_byte: usize,
+    pub end_byte: usize,
+}
+
+/// A dependency of a [`BuildTarget`] on another target.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BuildDependency {
+    /// Label of the target depended on, in the [`BuildTarget::label`] form.
+    pub label: String,
+    /// Byte offset of the statement declaring the dependency.
+    pub offset: usize,
+}
+
+/// Extract a Makefile, CMake script or Bazel package.
+pub fn extract_build_targets(file_path: &str, content: &str) -> Result<ExtractionResults> {
+    let language = detect_language_for_indexing(Path::new(file_path));
+    let Some(system) = BuildSystem::for_language(&language) else {
+        return Ok(ExtractionResults::empty());
+    };
+    let targets = parse_build_targets(system, file_path, content);
+    let lines = TextLines::new(content);
+
+    let mut ids = HashSet::new();
+    let symbols: Vec<Symbol> = targets
+        .iter()
+        .map(|target| {
+            target_symbol(
+                target, system, file_path, &language, content, &lines, &mut ids,
+            )
+        })
+        .collect();
+
+    let mut by_label: HashMap<&str, &str> = HashMap::new();
+    for (target, symbol) in targets.iter().zip(&symbols) {
+        by_label
+            .entry(target.label.as_str())
+            .or_insert(symbol.id.as_str());
+    }
+
+    let mut relationships = Vec::new();
+    let mut pending_relationships = Vec::new();
+    for (target, symbol) in targets.iter().zip(&symbols) {
+        for dep in &target.deps {
+            let line_number = lines.line_of(dep.offset);
+            match by_label.get(dep.label.as_str()) {
+                Some(&to) if to == symbol.id => {}
+                Some(&to) => relationships.push(Relationship {
+                    id: format!(
+                        "{}_{}_{:?}_{}_{}",
+                        symbol.id,
+                        to,
+                        RelationshipKind::Uses,
+                        file_path,
+                        line_number
+                    ),
+                    from_symbol_id: symbol.id.clone(),
+                    to_symbol_id: to.to_string(),
+                    kind: RelationshipKind::Uses,
+                    file_path: file_path.to_string(),
+                    line_number,
+                    confidence: 1.0,
+                    metadata: None,
+                }),
+                None => pending_relationships.push(PendingRelationship {
+                    from_symbol_id: symbol.id.clone(),
+                    callee_name: format!(
+                        "{BUILD_DEPENDENCY_CALLEE_PREFIX}{}:{}",
+                        system.name(),
+                        dep.label
+                    ),
+                    kind: RelationshipKind::Uses,
+                    file_path: file_path.to_string(),
+                    line_number,
+                    confidence: 0.9,
+                }),
+            }
+        }
+    }
+
+    let mut results = ExtractionResults::empty();
+    results.symbols = symbols;
+    results.relationships = relationships;
+    results.pending_relationships = pending_relationships;
+    Ok(results)
+}
+
+/// Parse every target declared in a build file, in file order.
+pub fn parse_build_targets(
+    system: BuildSystem,
+    file_path: &str,
+    content: &str,
+) -> Vec<BuildTarget> {
+    let dir = file_path.rsplit_once('/').map_or("", |(dir, _)| dir);
+    match system {
+        BuildSystem::Make => parse_makefile(dir, content),
+        BuildSystem::CMake => parse_cmake(dir, content),
+        BuildSystem::Bazel => parse_bazel(dir, content),
+    }
+}
+
+fn target_symbol(
+    target: &BuildTarget,
+    system: BuildSystem,
+    file_path: &str,
+    language: &str,
+    content: &str,
+    lines: &TextLines,
+    ids: &mut HashSet<String>,
+) -> Symbol {
+    // A Make target can be declared by several rules; keep ids unique.
+    let base = format!("{}:build:{}", file_path, target.label);
+    let mut id_source = base.clone();
+    let mut duplicate = 1;
+    while !ids.insert(id_source.clone()) {
+        duplicate += 1;
+        id_source = format!("{base}#{duplicate}");
+    }
+    let id = blake3::hash(id_source.as_bytes()).to_hex()[..32].to_string();
+
+    let start_line = lines.line_of(target.start_byte);
+    let end_line = lines.line_of(target.end_byte.saturating_sub(1).max(target.start_byte));
+    let start_column = target.start_byte - lines.line_start(start_line);
+    let end_column = target.end_byte - lines.line_start(end_line);
+    let declaration = content[target.start_byte..target.end_byte].trim_end();
+    let signature = declaration.lines().next().unwrap_or("").trim().to_string();
+    let deps: Vec<&str> = target.deps.iter().map(|dep| dep.label.as_str()).collect();
+    let info = json!({
+        "system": system.name(),
+        "rule": target.rule,
+        "label": target.label,
+        "sources": target.sources,
+        "deps": deps,
+    });
+
+    Symbol {
+        id,
+        name: target.name.clone(),
+        kind: SymbolKind::Module,
+        language: language.to_string(),
+        file_path: file_path.to_string(),
+        start_line,
+        start_column: start_column as u32,
+        end_line,
+        end_column: end_column as u32,
+        start_byte: target.start_byte as u32,
+        end_byte: target.end_byte as u32,
+        signature: Some(signature),
+        doc_comment: None,
+        visibility: None,
+        parent_id: None,
+        metadata: Some(HashMap::from([(BUILD_METADATA_KEY.to_string(), info)])),
+        semantic_group: Some(BUILD_TARGET_SEMANTIC_GROUP.to_string()),
+        confidence: None,
+        code_context: Some(declaration.to_string()),
+        content_type: None,
+        body_span: None,
+        body_hash: None,
+        annotations: Vec::new(),
+    }
+}
+
+/// Join `relative` onto `base` and fold `.` / `..` segments. Absolute paths
+/// point outside the workspace and yield `None`.
+fn join_path(base: &str, relative: &str) -> Option<String> {
+    if relative.starts_with('/') || relative.get(1..2) == Some(":") {
+        return None;
+    }
+    let mut segments: Vec<&str> = Vec::new();
+    for segment in base.split('/').chain(relative.split('/')) {
+        match segment {
+            "" | "." => {}
+            ".." => {
+                segments.pop()?;
+            }
+            segment => segments.push(segment),
+        }
+    }
+    Some(segments.join("/"))
+}
+
+/// Push `item` unless it is already present.
+fn push_unique<T: PartialEq>(items: &mut Vec<T>, item: T) {
+    if !items.contains(&item) {
+        items.push(item);
+    }
+}
+
+fn push_dependency(target: &mut BuildTarget, label: String, offset: usize) {
+    if target.deps.iter().all(|dep| dep.label != label) {
+        target.deps.push(BuildDependency { label, offset });
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Cross-file dependencies
+// ---------------------------------------------------------------------------
+
+/// Is this pending relationship a dependency between build targets recorded
+/// by this pass?
+pub fn is_build_dependency(pending: &PendingRelationship) -> bool {
+    pending.kind == RelationshipKind::Uses
+        && pending
+            .callee_name
+            .starts_with(BUILD_DEPENDENCY_CALLEE_PREFIX)
+}
+
+/// Resolve dependencies between targets declared in different build files
+/// against the build target symbols in `db`, matching the label within the
+/// same build system. A label declared more than once (two CMake projects
+/// with a `common` library) links to each declaration.
+pub fn resolve_build_dependencies(
+    pendings: &[PendingRelationship],
+    db: &SymbolDatabase,
+) -> Vec<Relationship> {
+    let dependencies: Vec<&PendingRelationship> =
+        pendings.iter().filter(|p| is_build_dependency(p)).collect();
+    if dependencies.is_empty() {
+        return Vec::new();
+    }
+    let targets = match db.get_symbols_by_semantic_group(BUILD_TARGET_SEMANTIC_GROUP) {
+        Ok(symbols) => symbols,
+        Err(e) => {
+            warn!("Build target lookup failed: {}", e);
+            return Vec::new();
+        }
+    };
+
+    let mut by_label: HashMap<(&str, &str), Vec<&Symbol>> = HashMap::new();
+    for symbol in &targets {
+        let Some(info) = symbol
+            .metadata
+            .as_ref()
+            .and_then(|metadata| metadata.get(BUILD_METADATA_KEY))
+        else {
+            continue;
+        };
+        if let (Some(system), Some(label)) = (info["system"].as_str(), info["label"].as_str()) {
+            by_label.entry((system, label)).or_default().push(symbol);
+        }
+    }
+
+    let mut resolved = Vec::new();
+    for pending in &dependencies {
+        let reference = &pending.callee_name[BUILD_DEPENDENCY_CALLEE_PREFIX.len()..];
+        let Some(key) = reference.split_once(':') else {
+            continue;
+        };
+        for target in by_label.get(&key).into_iter().flatten() {
+            if target.id != pending.from_symbol_id {
+                resolved.push(crate::resolver::build_resolved_relationship(
+                    pending, target,
+                ));
+            }
+        }
+    }
+    debug!(
+        "Linked {} build dependencies to {} target declarations",
+        dependencies.len(),
+        resolved.len()
+    );
+    resolved
+}
@@ -0,0 +1,313 @@
+//! Bazel rule calls, read with a small Starlark tokenizer.
+
+use super::*;
+
+/// Bazel calls that never declare a target.
+const BAZEL_NON_TARGET_CALLS: &[&str] = &[
+    "exports_files",
+    "licenses",
+    "load",
+    "package",
+    "package_group",
+    "workspace",
+];
+
+/// Bazel attributes listing files the target compiles.
+const BAZEL_SOURCE_ATTRS: &[&str] = &["srcs", "hdrs", "textual_hdrs"];
+
+/// Bazel attributes listing targets the target depends on.
+const BAZEL_DEPENDENCY_ATTRS: &[&str] = &["deps", "runtime_deps", "implementation_deps", "exports"];
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum StarlarkToken {
+    Ident(String),
+    Str(String),
+    Punct(char),
+    Other,
+}
+
+struct Token {
+    kind: StarlarkToken,
+    start: usize,
+    end: usize,
+}
+
+fn starlark_tokens(content: &str) -> Vec<Token> {
+    let bytes = content.as_bytes();
+    let mut tokens = Vec::new();
+    let mut i = 0;
+    while i < bytes.len() {
+        let start = i;
+        let c = bytes[i];
+        let kind = if c.is_ascii_whitespace() {
+            i += 1;
+            continue;
+        } else if c == b'#' {
+            i = content[i..].find('\n').map_or(bytes.len(), |end| i + end);
+            continue;
+        } else if c == b'"' || c == b'\'' {
+            let (text, end) = starlark_string(content, i);
+            i = end;
+            StarlarkToken::Str(text)
+        } else if c.is_ascii_alphabetic() || c == b'_' {
+            while i < bytes.len()
+                && (bytes[i].is_ascii_alphanumeric() || bytes[i] == b'_' || bytes[i] == b'.')
+            {
+                i += 1;
+            }
+            let word = &content[start..i];
+            // String prefixes: r"...", b'...'.
+            if matches!(word, "r" | "b" | "rb" | "br")
+                && matches!(bytes.get(i), Some(b'"') | Some(b'\''))
+            {
+                let (text, end) = starlark_string(content, i);
+                i = end;
+                StarlarkToken::Str(text)
+            } else {
+                StarlarkToken::Ident(word.to_string())
+            }
+        } else if c.is_ascii_digit() {
+            while i < bytes.len() && bytes[i].is_ascii_alphanumeric() {
+                i += 1;
+            }
+            StarlarkToken::Other
+        } else {
+            let ch = content[i..].chars().next().unwrap_or(' ');
+            i += ch.len_utf8();
+            StarlarkToken::Punct(ch)
+        };
+        tokens.push(Token {
+            kind,
+            start,
+            end: i,
+        });
+    }
+    tokens
+}
+
+/// String literal starting at the quote at `start`; returns its contents and
+/// the offset just past the closing quote.
+fn starlark_string(content: &str, start: usize) -> (String, usize) {
+    let quote = &content[start..start + 1];
+    let triple = quote.repeat(3);
+    let (delimiter, body_start) = if content[start..].starts_with(&triple) {
+        (triple.as_str(), start + 3)
+    } else {
+        (quote, start + 1)
+    };
+    let mut text = String::new();
+    let mut i = body_start;
+    while i < content.len() {
+        let rest = &content[i..];
+        if rest.starts_with(delimiter) {
+            return (text, i + delimiter.len());
+        }
+        let mut chars = rest.chars();
+        let ch = chars.next().unwrap_or(' ');
+        if ch == '\\' {
+            if let Some(escaped) = chars.next() {
+                text.push(escaped);
+                i += 1 + escaped.len_utf8();
+                continue;
+            }
+        } else if ch == '\n' && delimiter.len() == 1 {
+            // Unterminated single-line string.
+            return (text, i);
+        }
+        text.push(ch);
+        i += ch.len_utf8();
+    }
+    (text, content.len())
+}
+
+/// Index of the bracket closing the one at `open`.
+fn matching_close(tokens: &[Token], open: usize) -> Option<usize> {
+    let mut depth = 0usize;
+    for (index, token) in tokens.iter().enumerate().skip(open) {
+        match token.kind {
+            StarlarkToken::Punct('(' | '[' | '{') => depth += 1,
+            StarlarkToken::Punct(')' | ']' | '}') => {
+                depth = depth.saturating_sub(1);
+                if depth == 0 {
+                    return Some(index);
+                }
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+pub(super) fn parse_bazel(package: &str, content: &str) -> Vec<BuildTarget> {
+    let tokens = starlark_tokens(content);
+    let mut targets = Vec::new();
+    let mut i = 0;
+    while i < tokens.len() {
+        let opens = matches!(tokens[i].kind, StarlarkToken::Punct('(' | '[' | '{'));
+        let call = match (&tokens[i].kind, tokens.get(i + 1).map(|token| &token.kind)) {
+            (StarlarkToken::Ident(rule), Some(StarlarkToken::Punct('('))) => Some(rule),
+            _ => None,
+        };
+        let Some(rule) = call else {
+            // Skip top-level literals and comprehensions whole.
+            i = if opens {
+                matching_close(&tokens, i).map_or(tokens.len(), |close| close + 1)
+            } else {
+                i + 1
+            };
+            continue;
+        };
+        let close = matching_close(&tokens, i + 1).unwrap_or(tokens.len() - 1);
+        if !BAZEL_NON_TARGET_CALLS.contains(&rule.as_str())
+            && let Some(target) = bazel_target(package, rule, &tokens[i..=close])
+        {
+            targets.push(target);
+        }
+        i = close + 1;
+    }
+    targets
+}
+
+/// The target declared by one rule call (`tokens` runs from the rule name to
+/// the closing parenthesis), if it has a literal `name`.
+fn bazel_target(package: &str, rule: &str, tokens: &[Token]) -> Option<BuildTarget> {
+    let mut args: Vec<&[Token]> = Vec::new();
+    let mut depth = 0usize;
+    let mut arg_start = 2;
+    for (index, token) in tokens.iter().enumerate().skip(2) {
+        let end_of_arg = match token.kind {
+            StarlarkToken::Punct('(' | '[' | '{') => {
+                depth += 1;
+                false
+            }
+            StarlarkToken::Punct(')' | ']' | '}') if depth == 0 => true,
+            StarlarkToken::Punct(')' | ']' | '}') => {
+                depth -= 1;
+                false
+            }
+            StarlarkToken::Punct(',') => depth == 0,
+            _ => false,
+        };
+        if end_of_arg {
+            args.push(&tokens[arg_start..index]);
+            arg_start = index + 1;
+        }
+    }
+    // An unclosed call runs to end of file.
+    if arg_start < tokens.len() {
+        args.push(&tokens[arg_start..]);
+    }
+
+    let mut attrs: HashMap<&str, &[Token]> = HashMap::new();
+    for arg in args {
+        if let [
+            Token {
+                kind: StarlarkToken::Ident(attr),
+                ..
+            },
+            Token {
+                kind: StarlarkToken::Punct('='),
+                ..
+            },
+            value @ ..,
+        ] = arg
+        {
+            attrs.insert(attr.as_str(), value);
+        }
+    }
+
+    let name = match attrs.get("name") {
+        Some(
+            [
+                Token {
+                    kind: StarlarkToken::Str(name),
+                    ..
+                },
+            ],
+        ) if !name.is_empty() => name.clone(),
+        _ => return None,
+    };
+    let mut target = BuildTarget {
+        label: format!("//{package}:{name}"),
+        name,
+        rule: rule.to_string(),
+        sources: Vec::new(),
+        deps: Vec::new(),
+        start_byte: tokens[0].start,
+        end_byte: tokens[tokens.len() - 1].end,
+    };
+    for attr in BAZEL_SOURCE_ATTRS {
+        for src in attrs
+            .get(attr)
+            .map_or(Vec::new(), |value| list_strings(value))
+        {
+            if let Some(source) = bazel_source_path(package, &src) {
+                push_unique(&mut target.sources, source);
+            }
+        }
+    }
+    for attr in BAZEL_DEPENDENCY_ATTRS {
+        let Some(value) = attrs.get(attr) else {
+            continue;
+        };
+        let offset = value.first().map_or(target.start_byte, |token| token.start);
+        for dep in list_strings(value) {
+            if let Some(label) = bazel_label(package, &dep) {
+                push_dependency(&mut target, label, offset);
+            }
+        }
+    }
+    Some(target)
+}
+
+/// Strings directly inside the list literals of an attribute value. Strings
+/// under `glob(...)`, `select({...})` or other calls need evaluation and are
+/// left out.
+fn list_strings(value: &[Token]) -> Vec<String> {
+    let mut strings = Vec::new();
+    let mut stack: Vec<char> = Vec::new();
+    for token in value {
+        match &token.kind {
+            StarlarkToken::Punct(open @ ('(' | '[' | '{')) => stack.push(*open),
+            StarlarkToken::Punct(')' | ']' | '}') => {
+                stack.pop();
+            }
+            StarlarkToken::Str(text) if stack == ['['] => strings.push(text.clone()),
+            _ => {}
+        }
+    }
+    strings
+}
+
+/// Canonical `//pkg:name` form of a label written in `package`. Labels in
+/// other repositories have no target in this workspace and yield `None`.
+fn bazel_label(package: &str, label: &str) -> Option<String> {
+    let label = label.trim();
+    let label = match label.strip_prefix('@') {
+        // `@//pkg:name` is the main repository.
+        Some(rest) if rest.starts_with("//") => rest,
+        Some(_) => return None,
+        None => label,
+    };
+    let (target_package, name) = if let Some(rest) = label.strip_prefix("//") {
+        match rest.split_once(':') {
+            Some((target_package, name)) => (target_package, name),
+            // `//pkg/sub` is short for `//pkg/sub:sub`.
+            None => (rest, rest.rsplit('/').next().unwrap_or(rest)),
+        }
+    } else {
+        (package, label.strip_prefix(':').unwrap_or(label))
+    };
+    (!name.is_empty()).then(|| format!("//{target_package}:{name}"))
+}
+
+/// Workspace-relative path of a source written in `package`: a relative
+/// path or a file label (`:gen.cc`, `//pkg:file.cc`).
+fn bazel_source_path(package: &str, source: &str) -> Option<String> {
+    if source.starts_with(['/', ':', '@']) {
+        let label = bazel_label(package, source)?;
+        let (target_package, name) = label[2..].split_once(':')?;
+        return join_path(target_package, name);
+    }
+    join_path(package, source)
+}
@@ -0,0 +1,289 @@
+//! CMake target commands.
+
+use super::*;
+
+/// Options of `add_executable` / `add_library` that are not sources.
+const CMAKE_TARGET_OPTIONS: &[&str] = &[
+    "EXCLUDE_FROM_ALL",
+    "INTERFACE",
+    "MACOSX_BUNDLE",
+    "MODULE",
+    "OBJECT",
+    "SHARED",
+    "STATIC",
+    "UNKNOWN",
+    "WIN32",
+];
+
+/// Keywords of `target_sources`.
+const CMAKE_SOURCE_KEYWORDS: &[&str] = &[
+    "BASE_DIRS",
+    "CXX_MODULES",
+    "FILES",
+    "FILE_SET",
+    "HEADERS",
+    "INTERFACE",
+    "PRIVATE",
+    "PUBLIC",
+    "TYPE",
+];
+
+/// Keywords of `target_link_libraries`.
+const CMAKE_LINK_KEYWORDS: &[&str] = &[
+    "INTERFACE",
+    "LINK_INTERFACE_LIBRARIES",
+    "LINK_PRIVATE",
+    "LINK_PUBLIC",
+    "PRIVATE",
+    "PUBLIC",
+    "debug",
+    "general",
+    "optimized",
+];
+
+/// File endings of prebuilt libraries named directly in a link line.
+const LIBRARY_FILE_SUFFIXES: &[&str] = &[".a", ".dylib", ".framework", ".lib", ".so"];
+
+/// One command invocation: `name(args...)`.
+struct CMakeCommand {
+    /// Command name, lowercased (CMake commands are case-insensitive).
+    name: String,
+    args: Vec<CMakeArg>,
+    start: usize,
+    end: usize,
+}
+
+struct CMakeArg {
+    text: String,
+    quoted: bool,
+}
+
+fn cmake_commands(content: &str) -> Vec<CMakeCommand> {
+    let bytes = content.as_bytes();
+    let mut commands = Vec::new();
+    let mut i = 0;
+    while i < bytes.len() {
+        let c = bytes[i];
+        if c == b'#' {
+            i = skip_cmake_comment(content, i);
+        } else if c.is_ascii_alphabetic() || c == b'_' {
+            let start = i;
+            while i < bytes.len() && (bytes[i].is_ascii_alphanumeric() || bytes[i] == b'_') {
+                i += 1;
+            }
+            let name = content[start..i].to_ascii_lowercase();
+            let mut j = i;
+            while j < bytes.len() && (bytes[j] == b' ' || bytes[j] == b'\t') {
+                j += 1;
+            }
+            if bytes.get(j) == Some(&b'(') {
+                let (args, end) = cmake_args(content, j + 1);
+                commands.push(CMakeCommand {
+                    name,
+                    args,
+                    start,
+                    end,
+                });
+                i = end;
+            }
+        } else {
+            i += 1;
+        }
+    }
+    commands
+}
+
+/// Skip a `#` line comment or a `#[[ ... ]]` bracket comment.
+fn skip_cmake_comment(content: &str, start: usize) -> usize {
+    if content[start..].starts_with("#[[") {
+        return content[start..]
+            .find("]]")
+            .map_or(content.len(), |end| start + end + 2);
+    }
+    content[start..]
+        .find('\n')
+        .map_or(content.len(), |end| start + end)
+}
+
+/// Arguments from just after `(` to the matching `)`; returns them with the
+/// offset just past the `)` (end of input for an unclosed call).
+fn cmake_args(content: &str, start: usize) -> (Vec<CMakeArg>, usize) {
+    let bytes = content.as_bytes();
+    let mut args = Vec::new();
+    let mut depth = 1;
+    let mut i = start;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'#' => i = skip_cmake_comment(content, i),
+            b'(' => {
+                depth += 1;
+                i += 1;
+            }
+            b')' => {
+                depth -= 1;
+                i += 1;
+                if depth == 0 {
+                    return (args, i);
+                }
+            }
+            b'"' => {
+                let mut text = String::new();
+                i += 1;
+                while i < bytes.len() && bytes[i] != b'"' {
+                    if bytes[i] == b'\\' && i + 1 < bytes.len() {
+                        i += 1;
+                    }
+                    let ch = content[i..].chars().next().unwrap_or(' ');
+                    text.push(ch);
+                    i += ch.len_utf8();
+                }
+                i += 1;
+                args.push(CMakeArg { text, quoted: true });
+            }
+            c if c.is_ascii_whitespace() => i += 1,
+            _ => {
+                let arg_start = i;
+                while i < bytes.len()
+                    && !bytes[i].is_ascii_whitespace()
+                    && !matches!(bytes[i], b'(' | b')' | b'"')
+                {
+                    i += 1;
+                }
+                args.push(CMakeArg {
+                    text: content[arg_start..i].to_string(),
+                    quoted: false,
+                });
+            }
+        }
+    }
+    (args, content.len())
+}
+
+pub(super) fn parse_cmake(dir: &str, content: &str) -> Vec<BuildTarget> {
+    // Paths built from the current directory resolve against this file's.
+    let mut variables: HashMap<String, Vec<String>> =
+        ["CMAKE_CURRENT_SOURCE_DIR", "CMAKE_CURRENT_LIST_DIR"]
+            .into_iter()
+            .map(|name| (name.to_string(), vec![".".to_string()]))
+            .collect();
+    let mut targets: Vec<BuildTarget> = Vec::new();
+    let mut by_name: HashMap<String, usize> = HashMap::new();
+
+    for command in cmake_commands(content) {
+        let args = expand_cmake_args(&command.args, &variables);
+        let Some(first) = args.first() else {
+            continue;
+        };
+        match command.name.as_str() {
+            "set" => {
+                let values = args[1..]
+                    .iter()
+                    .take_while(|arg| *arg != "CACHE" && *arg != "PARENT_SCOPE")
+                    .cloned()
+                    .collect();
+                variables.insert(first.clone(), values);
+            }
+            "list" if first == "APPEND" && args.len() > 1 => {
+                variables
+                    .entry(args[1].clone())
+                    .or_default()
+                    .extend(args[2..].iter().cloned());
+            }
+            "add_executable" | "add_library" | "add_custom_target" => {
+                let imported_or_alias = args
+                    .get(1)
+                    .is_some_and(|arg| arg == "IMPORTED" || arg == "ALIAS");
+                if imported_or_alias || first.contains('$') || by_name.contains_key(first) {
+                    continue;
+                }
+                let mut target = BuildTarget {
+                    name: first.clone(),
+                    label: first.clone(),
+                    rule: command.name.clone(),
+                    sources: Vec::new(),
+                    deps: Vec::new(),
+                    start_byte: command.start,
+                    end_byte: command.end,
+                };
+                // A custom target's arguments are commands, not sources.
+                if command.name != "add_custom_target" {
+                    add_cmake_sources(&mut target, dir, &args[1..], CMAKE_TARGET_OPTIONS);
+                }
+                by_name.insert(first.clone(), targets.len());
+                targets.push(target);
+            }
+            "target_sources" => {
+                if let Some(&index) = by_name.get(first) {
+                    add_cmake_sources(&mut targets[index], dir, &args[1..], CMAKE_SOURCE_KEYWORDS);
+                }
+            }
+            "target_link_libraries" | "add_dependencies" => {
+                let Some(&index) = by_name.get(first) else {
+                    continue;
+                };
+                for arg in &args[1..] {
+                    if CMAKE_LINK_KEYWORDS.contains(&arg.as_str()) || !is_cmake_target_name(arg) {
+                        continue;
+                    }
+                    push_dependency(&mut targets[index], arg.clone(), command.start);
+                }
+            }
+            _ => {}
+        }
+    }
+    targets
+}
+
+/// Expand `${NAME}` references. An unquoted argument that is exactly one
+/// list reference, or that contains `;`, splits into list items.
+fn expand_cmake_args(args: &[CMakeArg], variables: &HashMap<String, Vec<String>>) -> Vec<String> {
+    let mut expanded = Vec::new();
+    for arg in args {
+        let mut text = arg.text.clone();
+        for _ in 0..MAX_EXPANSION_DEPTH {
+            let Some(start) = text.find("${") else {
+                break;
+            };
+            let Some(len) = text[start..].find('}') else {
+                break;
+            };
+            let name = &text[start + 2..start + len];
+            let Some(value) = variables.get(name) else {
+                break;
+            };
+            text.replace_range(start..=start + len, &value.join(";"));
+        }
+        if arg.quoted {
+            expanded.push(text);
+        } else {
+            expanded.extend(
+                text.split(';')
+                    .filter(|item| !item.is_empty())
+                    .map(str::to_string),
+            );
+        }
+    }
+    expanded
+}
+
+fn add_cmake_sources(target: &mut BuildTarget, dir: &str, args: &[String], keywords: &[&str]) {
+    for arg in args {
+        if keywords.contains(&arg.as_str()) || arg.contains('$') {
+            continue;
+        }
+        if let Some(source) = join_path(dir, arg) {
+            push_unique(&mut target.sources, source);
+        }
+    }
+}
+
+/// A link item that can name a target: not a flag, path, library file or
+/// unevaluated expression.
+fn is_cmake_target_name(item: &str) -> bool {
+    !item.is_empty()
+        && !item.starts_with('-')
+        && !item.contains(['/', '\\', '$'])
+        && !LIBRARY_FILE_SUFFIXES
+            .iter()
+            .any(|suffix| item.ends_with(suffix))
+}
@@ -0,0 +1,224 @@
+//! Make rules and variables.
+
+use super::*;
+
+/// A line with its backslash continuations joined.
+struct LogicalLine {
+    text: String,
+    start: usize,
+    end: usize,
+}
+
+fn logical_lines(content: &str) -> Vec<LogicalLine> {
+    let mut lines = Vec::new();
+    let mut pending: Option<LogicalLine> = None;
+    let mut offset = 0;
+    for raw in content.split_inclusive('\n') {
+        let start = offset;
+        offset += raw.len();
+        let text = raw.trim_end_matches(['\n', '\r']);
+        let end = start + text.len();
+        let (text, continued) = match text.strip_suffix('\\') {
+            Some(text) => (text, true),
+            None => (text, false),
+        };
+        let line = match pending.take() {
+            Some(mut line) => {
+                line.text.push(' ');
+                line.text.push_str(text.trim_start());
+                line.end = end;
+                line
+            }
+            None => LogicalLine {
+                text: text.to_string(),
+                start,
+                end,
+            },
+        };
+        if continued {
+            pending = Some(line);
+        } else {
+            lines.push(line);
+        }
+    }
+    lines.extend(pending);
+    lines
+}
+
+pub(super) fn parse_makefile(dir: &str, content: &str) -> Vec<BuildTarget> {
+    let mut variables: HashMap<String, String> = HashMap::new();
+    let mut targets: Vec<BuildTarget> = Vec::new();
+    let mut by_name: HashMap<String, usize> = HashMap::new();
+    // Prerequisites per target, classified once every rule has been read.
+    let mut prerequisites: Vec<Vec<(String, usize)>> = Vec::new();
+    // Targets of the rule whose recipe is being read.
+    let mut current: Vec<usize> = Vec::new();
+    let mut in_define = false;
+
+    for line in logical_lines(content) {
+        if in_define {
+            in_define = line.text.trim() != "endef";
+            continue;
+        }
+        if line.text.starts_with('\t') {
+            for &index in &current {
+                targets[index].end_byte = targets[index].end_byte.max(line.end);
+            }
+            continue;
+        }
+        let code = line.text.split('#').next().unwrap_or("").trim();
+        if code.is_empty() {
+            continue;
+        }
+        current.clear();
+
+        let directive = code
+            .strip_prefix("override ")
+            .or_else(|| code.strip_prefix("export "))
+            .unwrap_or(code)
+            .trim_start();
+        if directive == "define" || directive.starts_with("define ") {
+            in_define = true;
+            continue;
+        }
+        if let Some((name, op, value)) = make_assignment(directive) {
+            let value = expand_make(value, &variables, 0);
+            match op {
+                "?=" => {
+                    variables.entry(name.to_string()).or_insert(value);
+                }
+                "+=" => {
+                    let entry = variables.entry(name.to_string()).or_default();
+                    if !entry.is_empty() {
+                        entry.push(' ');
+                    }
+                    entry.push_str(&value);
+                }
+                // `!=` runs a shell command.
+                "!=" => {}
+                _ => {
+                    variables.insert(name.to_string(), value);
+                }
+            }
+            continue;
+        }
+        let Some((head, rest)) = split_make_rule(code) else {
+            continue;
+        };
+
+        let head = expand_make(head, &variables, 0);
+        let rest = expand_make(rest, &variables, 0);
+        let prereqs: Vec<String> = rest
+            .split_whitespace()
+            .filter(|word| is_plain_make_word(word))
+            .map(str::to_string)
+            .collect();
+        for name in head.split_whitespace() {
+            // `.PHONY`, `.SUFFIXES` and old-style suffix rules like `.c.o`.
+            if name.starts_with('.') || !is_plain_make_word(name) {
+                continue;
+            }
+            let index = *by_name.entry(name.to_string()).or_insert_with(|| {
+                targets.push(BuildTarget {
+                    name: name.to_string(),
+                    label: name.to_string(),
+                    rule: "rule".to_string(),
+                    sources: Vec::new(),
+                    deps: Vec::new(),
+                    start_byte: line.start,
+                    end_byte: line.end,
+                });
+                prerequisites.push(Vec::new());
+                targets.len() - 1
+            });
+            prerequisites[index].extend(prereqs.iter().map(|prereq| (prereq.clone(), line.start)));
+            push_unique(&mut current, index);
+        }
+    }
+
+    for (index, prereqs) in prerequisites.into_iter().enumerate() {
+        for (prereq, offset) in prereqs {
+            if by_name.contains_key(&prereq) {
+                if prereq != targets[index].name {
+                    push_dependency(&mut targets[index], prereq, offset);
+                }
+            } else if let Some(source) = join_path(dir, &prereq) {
+                push_unique(&mut targets[index].sources, source);
+            }
+        }
+    }
+    targets
+}
+
+/// `NAME op value` for a variable assignment line. A `:` before the operator
+/// makes it a rule (target-specific variable), not an assignment.
+fn make_assignment(line: &str) -> Option<(&str, &str, &str)> {
+    let eq = line.find('=')?;
+    let op_start = ["::=", ":=", "?=", "+=", "!="]
+        .iter()
+        .find(|op| line[..=eq].ends_with(*op))
+        .map_or(eq, |op| eq + 1 - op.len());
+    let op = &line[op_start..=eq];
+    let name = line[..op_start].trim();
+    if name.is_empty() || name.contains(char::is_whitespace) || name.contains(':') {
+        return None;
+    }
+    Some((name, op, line[eq + 1..].trim()))
+}
+
+/// Split a rule line into its targets and normal prerequisites. Inline
+/// recipes (`; cmd`) and order-only prerequisites (`| dir`) are dropped;
+/// static pattern rules and target-specific variables are not rules here.
+fn split_make_rule(line: &str) -> Option<(&str, &str)> {
+    let (head, rest) = line.split_once(':')?;
+    let rest = rest.strip_prefix(':').unwrap_or(rest);
+    let rest = rest.split(';').next().unwrap_or("");
+    if rest.contains(['=', ':']) {
+        return None;
+    }
+    let rest = rest.split('|').next().unwrap_or("");
+    Some((head, rest))
+}
+
+/// A word that names a file or target once variables are expanded: no
+/// remaining references, patterns or wildcards.
+fn is_plain_make_word(word: &str) -> bool {
+    !word.contains(['$', '%', '*', '?'])
+}
+
+/// Expand `$(NAME)` and `${NAME}` references to known variables. Function
+/// calls, substitution references and unknown variables stay as written.
+fn expand_make(text: &str, variables: &HashMap<String, String>, depth: usize) -> String {
+    if depth > MAX_EXPANSION_DEPTH || !text.contains('$') {
+        return text.to_string();
+    }
+    let mut out = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(dollar) = rest.find('$') {
+        out.push_str(&rest[..dollar]);
+        let after = &rest[dollar + 1..];
+        let close = match after.chars().next() {
+            Some('(') => Some(')'),
+            Some('{') => Some('}'),
+            _ => None,
+        };
+        let reference = close.and_then(|close| {
+            let end = after.find(close)?;
+            let name = &after[1..end];
+            let value = variables.get(name)?;
+            Some((expand_make(value, variables, depth + 1), end + 1))
+        });
+        match reference {
+            Some((value, consumed)) => {
+                out.push_str(&value);
+                rest = &after[consumed..];
+            }
+            None => {
+                out.push('$');
+                rest = after;
+            }
+        }
+    }
+    out.push_str(rest);
+    out
+}
@@ -0,0 +1,367 @@
+//! C/C++ macro expansion provenance.
+//!
+//! Macro-heavy C code declares symbols through invocations such as
+//! `DEFINE_HANDLER(Foo)`. Tree-sitter parses the invocation, not the expansion,
+//! so the generated `Foo_handler` never reaches the index and navigation
+//! dead-ends at the macro name.
+//!
+//! This pass reads the file's own function-like `#define`s and expands the
+//! declaration-producing parts of each top-level invocation (token pasting and
+//! parameters in declarator position). For every generated name it either
+//! tags the symbol the extractor already produced, or synthesizes one at the
+//! invocation site. Symbols the extractor emitted *for* the invocation itself
+//! (typically misparsed as a function named after the macro) are tagged too,
+//! listing what the invocation generates.
+//!
+//! Only macros defined in the same file are expanded. Invocations of macros
+//! from headers still get provenance on the symbols found at the invocation
+//! site, just without a list of generated names.
+//!
+//! Calls hide behind macros the same way: `CHECK(x)` with
+//! `#define CHECK(x) validate(x)` is a call to `CHECK` as far as the parser
+//! knows. [`link_macro_calls`] adds the calls the expansion makes.
+
+mod expand;
+mod scan;
+
+use expand::{Token, expand_tokens, generated_names};
+use scan::{identifier_len, macro_definitions, macro_invocations};
+
+use std::collections::{HashMap, HashSet};
+
+use julie_extractors::base::{Symbol, SymbolKind};
+use julie_extractors::{
+    ExtractionResults, IdentifierKind, PendingRelationship, Relationship, RelationshipKind,
+};
+use serde_json::json;
+
+/// Metadata key holding `{macro, invocation, line}` (plus `generated` on
+/// invocation-site symbols).
+pub const MACRO_EXPANSION_METADATA_KEY: &str = "macro_expansion";
+
+/// Relationship metadata key naming the macro a linked call went through.
+pub const VIA_MACRO_METADATA_KEY: &str = "via_macro";
+
+/// How many macros deep `link_macro_calls` follows `A(x)` → `B(x)` → `f(x)`.
+const MAX_MACRO_CALL_DEPTH: usize = 4;
+
+/// Longest invocation (in logical lines) scanned for a closing parenthesis.
+const MAX_INVOCATION_LINES: usize = 20;
+
+#[derive(Debug)]
+struct MacroDefinition {
+    params: Vec<String>,
+    body: String,
+}
+
+#[derive(Debug)]
+struct MacroInvocation {
+    name: String,
+    args: Vec<String>,
+    text: String,
+    line: u32,
+    column: u32,
+    start_byte: usize,
+    end_byte: usize,
+    end_line: u32,
+}
+
+#[derive(Debug, PartialEq)]
+struct GeneratedName {
+    name: String,
+    kind: SymbolKind,
+}
+
+/// Record macro provenance for one C/C++ file's symbols. Other languages are
+/// left untouched.
+pub fn annotate_macro_expansions(symbols: &mut Vec<Symbol>, content: &str) {
+    let Some(template) = symbols
+        .iter()
+        .find(|s| matches!(s.language.as_str(), "c" | "cpp"))
+    else {
+        return;
+    };
+    let (file_path, language) = (template.file_path.clone(), template.language.clone());
+
+    let lines = logical_lines(content);
+    let definitions = macro_definitions(&lines);
+    let invocations = macro_invocations(&lines, content, &definitions);
+    if invocations.is_empty() {
+        return;
+    }
+
+    let mut synthesized = Vec::new();
+    let mut known_names: HashSet<String> = symbols.iter().map(|s| s.name.clone()).collect();
+    for invocation in &invocations {
+        let generated = definitions
+            .get(&invocation.name)
+            .map(|definition| generated_names(definition, &invocation.args))
+            .unwrap_or_default();
+        let provenance = json!({
+            "macro": invocation.name,
+            "invocation": invocation.text,
+            "line": invocation.line,
+        });
+
+        for symbol in symbols.iter_mut() {
+            let at_site =
+                symbol.start_line >= invocation.line && symbol.start_line <= invocation.end_line;
+            if at_site && symbol.name == invocation.name {
+                let mut site = provenance.clone();
+                site["generated"] = json!(generated.iter().map(|g| &g.name).collect::<Vec<_>>());
+                set_provenance(symbol, site);
+            } else if at_site && generated.iter().any(|g| g.name == symbol.name) {
+                set_provenance(symbol, provenance.clone());
+            }
+        }
+
+        for generated_name in generated {
+            if !known_names.insert(generated_name.name.clone()) {
+                continue;
+            }
+            synthesized.push(synthesize_symbol(
+                generated_name,
+                invocation,
+                &file_path,
+                &language,
+                provenance.clone(),
+            ));
+        }
+    }
+
+    symbols.extend(synthesized);
+}
+
+/// Add `Calls` edges for the functions that same-file function-like macros
+/// call when a symbol invokes them. Callees defined in the file resolve
+/// directly; the rest are left pending for the cross-file resolver. Returns
+/// how many edges were added.
+pub fn link_macro_calls(results: &mut ExtractionResults, content: &str) -> usize {
+    if !results
+        .symbols
+        .iter()
+        .any(|s| matches!(s.language.as_str(), "c" | "cpp"))
+    {
+        return 0;
+    }
+    let definitions = macro_definitions(&logical_lines(content));
+    if definitions.is_empty() {
+        return 0;
+    }
+
+    let functions: HashMap<&str, &str> = results
+        .symbols
+        .iter()
+        .filter(|s| matches!(s.kind, SymbolKind::Function | SymbolKind::Method))
+        .map(|s| (s.name.as_str(), s.id.as_str()))
+        .collect();
+    let mut relationship_ids: HashSet<String> =
+        results.relationships.iter().map(|r| r.id.clone()).collect();
+    let mut relationships = Vec::new();
+    let mut pending = Vec::new();
+    for identifier in &results.identifiers {
+        if !matches!(identifier.kind, IdentifierKind::Call)
+            || !definitions.contains_key(&identifier.name)
+        {
+            continue;
+        }
+        let Some(from_symbol_id) = identifier.containing_symbol_id.as_deref() else {
+            continue;
+        };
+        let metadata =
+            HashMap::from([(VIA_MACRO_METADATA_KEY.to_string(), json!(identifier.name))]);
+        for callee in macro_callees(&identifier.name, &definitions) {
+            match functions.get(callee.as_str()) {
+                Some(&to_symbol_id) => {
+                    let id = format!(
+                        "{}_{}_{:?}_{}_{}",
+                        from_symbol_id,
+                        to_symbol_id,
+                        RelationshipKind::Calls,
+                        identifier.file_path,
+                        identifier.start_line
+                    );
+                    if relationship_ids.insert(id.clone()) {
+                        relationships.push(Relationship {
+                            id,
+                            from_symbol_id: from_symbol_id.to_string(),
+                            to_symbol_id: to_symbol_id.to_string(),
+                            kind: RelationshipKind::Calls,
+                            file_path: identifier.file_path.clone(),
+                            line_number: identifier.start_line,
+                            confidence: 0.9,
+                            metadata: Some(metadata.clone()),
+                        });
+                    }
+                }
+                None => pending.push(PendingRelationship {
+                    from_symbol_id: from_symbol_id.to_string(),
+                    callee_name: callee,
+                    kind: RelationshipKind::Calls,
+                    file_path: identifier.file_path.clone(),
+                    line_number: identifier.start_line,
+                    confidence: 0.8,
+                }),
+            }
+        }
+    }
+
+    let added = relationships.len() + pending.len();
+    results.relationships.extend(relationships);
+    results.pending_relationships.extend(pending);
+    added
+}
+
+/// Functions called by the body of macro `name`, following calls to other
+/// same-file macros.
+fn macro_callees(name: &str, definitions: &HashMap<String, MacroDefinition>) -> Vec<String> {
+    let mut callees = Vec::new();
+    let mut visited = HashSet::from([name.to_string()]);
+    let mut frontier = vec![name.to_string()];
+    for _ in 0..MAX_MACRO_CALL_DEPTH {
+        let mut next = Vec::new();
+        for macro_name in &frontier {
+            let Some(definition) = definitions.get(macro_name) else {
+                continue;
+            };
+            for callee in body_calls(definition) {
+                if definitions.contains_key(&callee) {
+                    if visited.insert(callee.clone()) {
+                        next.push(callee);
+                    }
+                } else if !callees.contains(&callee) {
+                    callees.push(callee);
+                }
+            }
+        }
+        if next.is_empty() {
+            break;
+        }
+        frontier = next;
+    }
+    callees
+}
+
+/// `name(` calls in a macro body. Declarations (`void name(`), member calls
+/// and calls through a parameter are skipped.
+fn body_calls(definition: &MacroDefinition) -> Vec<String> {
+    let tokens = expand_tokens(&definition.body, &HashMap::new());
+    let mut calls = Vec::new();
+    for (i, token) in tokens.iter().enumerate() {
+        let Token::Ident { text, .. } = token else {
+            continue;
+        };
+        if tokens.get(i + 1) != Some(&Token::Punct('('))
+            || definition.params.contains(text)
+            || matches!(
+                text.as_str(),
+                "if" | "while" | "for" | "switch" | "return" | "sizeof" | "do" | "defined"
+            )
+        {
+            continue;
+        }
+        let declared_or_member = match i.checked_sub(1).map(|p| &tokens[p]) {
+            Some(Token::Ident { text, .. }) => text != "return",
+            Some(Token::Punct(c)) => matches!(c, '.' | '>' | '*' | '&' | '#'),
+            None => false,
+        };
+        if !declared_or_member && !calls.contains(text) {
+            calls.push(text.clone());
+        }
+    }
+    calls
+}
+
+fn set_provenance(symbol: &mut Symbol, provenance: serde_json::Value) {
+    symbol
+        .metadata
+        .get_or_insert_with(HashMap::new)
+        .insert(MACRO_EXPANSION_METADATA_KEY.to_string(), provenance);
+}
+
+fn synthesize_symbol(
+    generated: GeneratedName,
+    invocation: &MacroInvocation,
+    file_path: &str,
+    language: &str,
+    provenance: serde_json::Value,
+) -> Symbol {
+    let id_source = format!(
+        "{}:{}:{}:{}",
+        file_path, invocation.line, invocation.column, generated.name
+    );
+    let id = blake3::hash(id_source.as_bytes()).to_hex()[..32].to_string();
+    Symbol {
+        id,
+        name: generated.name,
+        kind: generated.kind,
+        language: language.to_string(),
+        file_path: file_path.to_string(),
+        start_line: invocation.line,
+        start_column: invocation.column,
+        end_line: invocation.end_line,
+        end_column: invocation.column + invocation.text.len() as u32,
+        start_byte: invocation.start_byte as u32,
+        end_byte: invocation.end_byte as u32,
+        signature: Some(invocation.text.clone()),
+        doc_comment: None,
+        visibility: None,
+        parent_id: None,
+        metadata: Some(HashMap::from([(
+            MACRO_EXPANSION_METADATA_KEY.to_string(),
+            provenance,
+        )])),
+        semantic_group: None,
+        confidence: None,
+        code_context: Some(invocation.text.clone()),
+        content_type: None,
+        body_span: None,
+        body_hash: None,
+        annotations: Vec::new(),
+    }
+}
+
+/// A physical-line run joined across `\` continuations.
+pub(super) struct LogicalLine<'a> {
+    pub(super) text: String,
+    first: &'a str,
+    pub(super) line: u32,
+    pub(super) last_line: u32,
+    start_byte: usize,
+}
+
+pub(super) fn logical_lines(content: &str) -> Vec<LogicalLine<'_>> {
+    let mut lines = Vec::new();
+    let mut current: Option<LogicalLine<'_>> = None;
+    let mut offset = 0usize;
+    for (idx, raw) in content.split_inclusive('\n').enumerate() {
+        let line_no = idx as u32 + 1;
+        let text = raw.trim_end_matches(['\n', '\r']);
+        let (body, continues) = match text.strip_suffix('\\') {
+            Some(body) => (body, true),
+            None => (text, false),
+        };
+        match current.as_mut() {
+            Some(logical) => {
+                logical.text.push(' ');
+                logical.text.push_str(body);
+                logical.last_line = line_no;
+            }
+            None => {
+                current = Some(LogicalLine {
+                    text: body.to_string(),
+                    first: text,
+                    line: line_no,
+                    last_line: line_no,
+                    start_byte: offset,
+                })
+            }
+        }
+        if !continues {
+            lines.extend(current.take());
+        }
+        offset += raw.len();
+    }
+    lines.extend(current);
+    lines
+}
@@ -0,0 +1,141 @@
+//! Expanding an invocation into the names it declares.
+
+use super::*;
+
+#[derive(Debug, Clone, PartialEq)]
+pub(super) enum Token {
+    Ident { text: String, from_param: bool },
+    Punct(char),
+}
+
+/// Expand the declaration-producing names of one invocation.
+pub(super) fn generated_names(definition: &MacroDefinition, args: &[String]) -> Vec<GeneratedName> {
+    let substitutions: HashMap<&str, &str> = definition
+        .params
+        .iter()
+        .map(String::as_str)
+        .zip(args.iter().map(String::as_str))
+        .collect();
+    let tokens = expand_tokens(&definition.body, &substitutions);
+
+    let mut names = Vec::new();
+    let (mut brace_depth, mut paren_depth) = (0i32, 0i32);
+    for (i, token) in tokens.iter().enumerate() {
+        let ident = match token {
+            Token::Punct('{') => {
+                brace_depth += 1;
+                continue;
+            }
+            Token::Punct('}') => {
+                brace_depth -= 1;
+                continue;
+            }
+            Token::Punct('(') => {
+                paren_depth += 1;
+                continue;
+            }
+            Token::Punct(')') => {
+                paren_depth -= 1;
+                continue;
+            }
+            Token::Punct(_) => continue,
+            Token::Ident { text, from_param } => {
+                if !from_param || brace_depth != 0 || paren_depth != 0 {
+                    continue;
+                }
+                text
+            }
+        };
+
+        let prev = i.checked_sub(1).and_then(|p| tokens.get(p));
+        let next = tokens.get(i + 1);
+        let kind = match (prev, next) {
+            (Some(Token::Ident { text, .. }), _) if text == "enum" => SymbolKind::Enum,
+            (Some(Token::Ident { text, .. }), _) if text == "class" => SymbolKind::Class,
+            (Some(Token::Ident { text, .. }), _) if text == "struct" || text == "union" => {
+                SymbolKind::Struct
+            }
+            (Some(Token::Ident { text, .. }), _) if text == "return" || text == "sizeof" => {
+                continue;
+            }
+            (Some(Token::Ident { .. } | Token::Punct('*' | '&')), Some(Token::Punct('('))) => {
+                SymbolKind::Function
+            }
+            (
+                Some(Token::Ident { .. } | Token::Punct('*' | '&')),
+                Some(Token::Punct(';' | '=' | '[' | ',' | '{')) | None,
+            ) => SymbolKind::Variable,
+            _ => continue,
+        };
+        if identifier_len(ident) != ident.len()
+            || names.iter().any(|g: &GeneratedName| g.name == *ident)
+        {
+            continue;
+        }
+        names.push(GeneratedName {
+            name: ident.clone(),
+            kind,
+        });
+    }
+    names
+}
+
+/// Tokenize a macro body, substituting parameters and applying `##` pastes.
+/// Identifiers touched by a parameter are flagged `from_param`: only those
+/// can name something the invocation generates.
+pub(super) fn expand_tokens(body: &str, substitutions: &HashMap<&str, &str>) -> Vec<Token> {
+    let mut tokens: Vec<Token> = Vec::new();
+    let mut paste_next = false;
+    let mut rest = body;
+    while let Some(c) = rest.chars().next() {
+        if c.is_whitespace() {
+            rest = &rest[c.len_utf8()..];
+            continue;
+        }
+        if rest.starts_with("##") {
+            paste_next = true;
+            rest = &rest[2..];
+            continue;
+        }
+        let len = identifier_len(rest).max(
+            // pp-numbers paste too (`name##2`)
+            rest.chars()
+                .take_while(|c| c.is_ascii_alphanumeric() || *c == '_')
+                .count(),
+        );
+        let token = if len > 0 {
+            let word = &rest[..len];
+            rest = &rest[len..];
+            match substitutions.get(word) {
+                Some(arg) => Token::Ident {
+                    text: arg.to_string(),
+                    from_param: true,
+                },
+                None => Token::Ident {
+                    text: word.to_string(),
+                    from_param: false,
+                },
+            }
+        } else {
+            rest = &rest[c.len_utf8()..];
+            Token::Punct(c)
+        };
+
+        if std::mem::take(&mut paste_next) {
+            if let (
+                Some(Token::Ident { text, from_param }),
+                Token::Ident {
+                    text: right,
+                    from_param: right_from_param,
+                },
+            ) = (tokens.last_mut(), &token)
+            {
+                text.push_str(right);
+                *from_param |= *right_from_param;
+                continue;
+            }
+        }
+        tokens.push(token);
+    }
+    tokens
+}
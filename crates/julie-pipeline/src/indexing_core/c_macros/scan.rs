@@ -0,0 +1,256 @@
+//! Finding the `#define`s and top-level invocations of a file.
+
+use super::*;
+
+pub(super) fn macro_definitions(lines: &[LogicalLine<'_>]) -> HashMap<String, MacroDefinition> {
+    let mut definitions = HashMap::new();
+    for line in lines {
+        let Some(rest) = line.text.trim_start().strip_prefix('#') else {
+            continue;
+        };
+        let Some(rest) = rest.trim_start().strip_prefix("define") else {
+            continue;
+        };
+        let rest = rest.trim_start();
+        let name_len = identifier_len(rest);
+        if name_len == 0 {
+            continue;
+        }
+        let (name, after) = rest.split_at(name_len);
+        // Function-like macros have no space between the name and `(`.
+        let Some(after) = after.strip_prefix('(') else {
+            continue;
+        };
+        let Some(close) = after.find(')') else {
+            continue;
+        };
+        let params = after[..close]
+            .split(',')
+            .map(|p| p.trim().to_string())
+            .filter(|p| !p.is_empty())
+            .collect();
+        definitions.insert(
+            name.to_string(),
+            MacroDefinition {
+                params,
+                body: after[close + 1..].trim().to_string(),
+            },
+        );
+    }
+    definitions
+}
+
+/// Statements of the form `NAME(args)` at file, namespace, or type scope,
+/// where `NAME` is a macro defined in this file or follows the SCREAMING_CASE
+/// macro convention. Invocations inside function bodies declare nothing
+/// visible to navigation and are skipped.
+pub(super) fn macro_invocations(
+    lines: &[LogicalLine<'_>],
+    content: &str,
+    definitions: &HashMap<String, MacroDefinition>,
+) -> Vec<MacroInvocation> {
+    let mut invocations = Vec::new();
+    let mut scopes = ScopeTracker::default();
+    let mut idx = 0;
+    while idx < lines.len() {
+        let line = &lines[idx];
+        let first = idx;
+        idx += 1;
+        let trimmed = line.text.trim_start();
+        if trimmed.starts_with('#') {
+            continue;
+        }
+        if let Some(invocation) = scopes
+            .at_declaration_scope()
+            .then(|| parse_invocation(lines, first, content, definitions))
+            .flatten()
+        {
+            let consumed = invocation.1;
+            invocations.push(invocation.0);
+            for consumed_line in &lines[first..=consumed] {
+                scopes.track(&consumed_line.text);
+            }
+            idx = consumed + 1;
+            continue;
+        }
+        scopes.track(&line.text);
+    }
+    invocations
+}
+
+/// Brace-scope stack: `true` for namespace/type/`extern` blocks, `false` for
+/// function bodies and initializers.
+#[derive(Default)]
+struct ScopeTracker {
+    stack: Vec<bool>,
+    previous_line: String,
+}
+
+impl ScopeTracker {
+    fn at_declaration_scope(&self) -> bool {
+        self.stack.iter().all(|container| *container)
+    }
+
+    fn track(&mut self, line: &str) {
+        // The block header is the text before `{`, or the previous line for
+        // Allman-style braces. Parentheses mean a function or initializer.
+        let before_brace = line.split('{').next().unwrap_or("");
+        let header = if before_brace.trim().is_empty() {
+            self.previous_line.as_str()
+        } else {
+            before_brace
+        };
+        let container = !header.contains('(')
+            && header
+                .split(|c: char| !c.is_ascii_alphanumeric() && c != '_')
+                .any(|word| {
+                    matches!(
+                        word,
+                        "namespace" | "class" | "struct" | "union" | "enum" | "extern"
+                    )
+                });
+        for c in line.chars() {
+            match c {
+                '{' => self.stack.push(container),
+                '}' => {
+                    self.stack.pop();
+                }
+                _ => {}
+            }
+        }
+        if !line.trim().is_empty() {
+            self.previous_line = line.to_string();
+        }
+    }
+}
+
+/// Parse an invocation starting at `lines[first]`, following its argument
+/// list onto later lines if needed. Returns the invocation and the index of
+/// the last logical line it spans.
+fn parse_invocation(
+    lines: &[LogicalLine<'_>],
+    first: usize,
+    content: &str,
+    definitions: &HashMap<String, MacroDefinition>,
+) -> Option<(MacroInvocation, usize)> {
+    let line = &lines[first];
+    let trimmed = line.text.trim_start();
+    let name_len = identifier_len(trimmed);
+    if name_len == 0 {
+        return None;
+    }
+    let name = &trimmed[..name_len];
+    if !definitions.contains_key(name) && !is_screaming_case(name) {
+        return None;
+    }
+    if !trimmed[name_len..].trim_start().starts_with('(') {
+        return None;
+    }
+
+    let mut text = trimmed.to_string();
+    let mut last = first;
+    while paren_balance(&text) > 0 && last + 1 < lines.len() && last - first < MAX_INVOCATION_LINES
+    {
+        last += 1;
+        text.push(' ');
+        text.push_str(lines[last].text.trim());
+    }
+    let (args, call_len) = split_invocation_args(&text[name_len..])?;
+
+    let column = (line.first.len() - line.first.trim_start().len()) as u32;
+    let start_byte = line.start_byte + column as usize;
+    let call_text = text[..name_len + call_len].to_string();
+    let end_byte = closing_paren_end(content, start_byte).unwrap_or(content.len());
+    Some((
+        MacroInvocation {
+            name: name.to_string(),
+            args,
+            text: call_text,
+            line: line.line,
+            column,
+            start_byte,
+            end_byte,
+            end_line: lines[last].last_line,
+        },
+        last,
+    ))
+}
+
+/// Byte offset just past the parenthesis closing the first `(` at or after
+/// `start`.
+fn closing_paren_end(content: &str, start: usize) -> Option<usize> {
+    let mut depth = 0;
+    for (i, c) in content.get(start..)?.char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' if depth == 1 => return Some(start + i + 1),
+            ')' => depth -= 1,
+            _ => {}
+        }
+    }
+    None
+}
+
+fn is_screaming_case(name: &str) -> bool {
+    name.len() > 2
+        && name.contains('_')
+        && name
+            .chars()
+            .all(|c| c.is_ascii_uppercase() || c.is_ascii_digit() || c == '_')
+}
+
+pub(super) fn identifier_len(text: &str) -> usize {
+    let mut chars = text.char_indices();
+    match chars.next() {
+        Some((_, c)) if c == '_' || c.is_ascii_alphabetic() => {}
+        _ => return 0,
+    }
+    chars
+        .find(|(_, c)| !(*c == '_' || c.is_ascii_alphanumeric()))
+        .map(|(i, _)| i)
+        .unwrap_or(text.len())
+}
+
+fn paren_balance(text: &str) -> i32 {
+    text.chars().fold(0, |depth, c| match c {
+        '(' => depth + 1,
+        ')' => depth - 1,
+        _ => depth,
+    })
+}
+
+/// Split `  (a, f(b, c))...` into its top-level arguments. Returns the
+/// arguments and the byte length up to and including the closing paren.
+fn split_invocation_args(text: &str) -> Option<(Vec<String>, usize)> {
+    let open = text.find('(')?;
+    let mut depth = 0;
+    let mut args = Vec::new();
+    let mut current = String::new();
+    for (i, c) in text[open..].char_indices() {
+        match c {
+            '(' => {
+                depth += 1;
+                if depth == 1 {
+                    continue;
+                }
+            }
+            ')' => {
+                depth -= 1;
+                if depth == 0 {
+                    if !current.trim().is_empty() || !args.is_empty() {
+                        args.push(current.trim().to_string());
+                    }
+                    return Some((args, open + i + 1));
+                }
+            }
+            ',' if depth == 1 => {
+                args.push(current.trim().to_string());
+                current.clear();
+                continue;
+            }
+            _ => {}
+        }
+        current.push(c);
+    }
+    None
+}
@@ -0,0 +1,302 @@
+//! Resumable extraction for large indexing runs.
+//!
+//! Extraction of a whole workspace happens in memory before one atomic
+//! persist, so a server killed halfway through the first index of a huge
+//! repository used to start over from zero. Large runs are instead extracted
+//! in fixed-size chunks, and each finished chunk is written to the workspace's
+//! checkpoint directory together with a completion marker for every file it
+//! covers: the file's workspace-relative path and content hash. On the next
+//! launch a chunk whose files all still hash the same (under the same engine
+//! version) is loaded instead of re-extracted; any edited file invalidates
+//! only its own chunk.
+//!
+//! Checkpoints hold extraction output only. Persistence, relationship
+//! resolution and analysis still run over the full merged batch, and the
+//! directory is cleared once that batch is persisted.
+
+use std::collections::HashMap;
+use std::fs;
+use std::io::{BufReader, BufWriter, Write};
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use julie_core::memory_budget::{MemoryBudget, MemoryComponent};
+use serde::{Deserialize, Serialize};
+use tracing::{debug, info, warn};
+
+use crate::indexing_core::batch::ExtractedBatch;
+use crate::indexing_core::extraction::{
+    ExtractedFileRecord, extract_files_for_indexing_with_records,
+};
+use crate::indexing_core::paths::relative_path_for_storage;
+
+/// On-disk format version. Bump on any incompatible change to a chunk file.
+pub const CHECKPOINT_VERSION: u32 = 1;
+
+/// Files per checkpointed chunk. Runs no larger than one chunk are not
+/// checkpointed at all.
+pub const DEFAULT_CHECKPOINT_CHUNK_FILES: usize = 512;
+
+/// Marker written in place of a content hash for files that cannot be read.
+/// Such files never match on resume, so their chunk is always re-extracted.
+const UNREADABLE_FILE_HASH: &str = "unreadable";
+
+/// Per-file completion marker: a file is done when its path and content hash
+/// appear in a stored chunk.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FileCompletionMarker {
+    pub relative_path: String,
+    pub content_hash: String,
+}
+
+#[derive(Serialize)]
+struct StoredChunkRef<'a> {
+    version: u32,
+    engine_version: &'a str,
+    files: &'a [FileCompletionMarker],
+    batch: &'a ExtractedBatch,
+    records: &'a [ExtractedFileRecord],
+}
+
+#[derive(Deserialize)]
+struct StoredChunk {
+    version: u32,
+    engine_version: String,
+    files: Vec<FileCompletionMarker>,
+    batch: ExtractedBatch,
+    records: Vec<ExtractedFileRecord>,
+}
+
+/// Checkpoint directory of one workspace index.
+#[derive(Debug, Clone)]
+pub struct ExtractionCheckpoints {
+    dir: PathBuf,
+    engine_version: String,
+}
+
+impl ExtractionCheckpoints {
+    pub fn new(dir: impl Into<PathBuf>, engine_version: &str) -> Self {
+        Self {
+            dir: dir.into(),
+            engine_version: engine_version.to_string(),
+        }
+    }
+
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    fn chunk_path(&self, files: &[FileCompletionMarker]) -> PathBuf {
+        let mut hasher = blake3::Hasher::new();
+        hasher.update(self.engine_version.as_bytes());
+        for marker in files {
+            hasher.update(b"\0");
+            hasher.update(marker.relative_path.as_bytes());
+            hasher.update(b"\0");
+            hasher.update(marker.content_hash.as_bytes());
+        }
+        let key = hasher.finalize().to_hex();
+        self.dir.join(format!("chunk-{}.json", &key[..32]))
+    }
+
+    /// The stored extraction for exactly these files, if one exists and is
+    /// usable. Corrupt or mismatched chunk files are ignored (and re-extracted).
+    pub fn load(
+        &self,
+        files: &[FileCompletionMarker],
+    ) -> Option<(ExtractedBatch, Vec<ExtractedFileRecord>)> {
+        if files
+            .iter()
+            .any(|marker| marker.content_hash == UNREADABLE_FILE_HASH)
+        {
+            return None;
+        }
+        let path = self.chunk_path(files);
+        let file = fs::File::open(&path).ok()?;
+        let stored: StoredChunk = match serde_json::from_reader(BufReader::new(file)) {
+            Ok(stored) => stored,
+            Err(error) => {
+                warn!(
+                    "Ignoring unreadable extraction checkpoint {}: {}",
+                    path.display(),
+                    error
+                );
+                return None;
+            }
+        };
+        if stored.version != CHECKPOINT_VERSION
+            || stored.engine_version != self.engine_version
+            || stored.files != files
+        {
+            return None;
+        }
+        Some((stored.batch, stored.records))
+    }
+
+    /// Record a finished chunk. Written to a temporary file and renamed, so a
+    /// kill mid-write leaves no partial chunk behind.
+    pub fn store(
+        &self,
+        files: &[FileCompletionMarker],
+        batch: &ExtractedBatch,
+        records: &[ExtractedFileRecord],
+    ) -> Result<()> {
+        fs::create_dir_all(&self.dir).with_context(|| {
+            format!(
+                "failed to create checkpoint directory {}",
+                self.dir.display()
+            )
+        })?;
+        let path = self.chunk_path(files);
+        let tmp = path.with_extension("json.tmp");
+        {
+            let mut out = BufWriter::new(
+                fs::File::create(&tmp)
+                    .with_context(|| format!("failed to create {}", tmp.display()))?,
+            );
+            serde_json::to_writer(
+                &mut out,
+                &StoredChunkRef {
+                    version: CHECKPOINT_VERSION,
+                    engine_version: &self.engine_version,
+                    files,
+                    batch,
+                    records,
+                },
+            )
+            .context("failed to serialize extraction checkpoint")?;
+            out.flush()?;
+        }
+        fs::rename(&tmp, &path)
+            .with_context(|| format!("failed to move checkpoint into {}", path.display()))?;
+        Ok(())
+    }
+
+    /// Drop every stored chunk. Called once the extracted batch is persisted.
+    pub fn clear(&self) -> Result<()> {
+        match fs::remove_dir_all(&self.dir) {
+            Ok(()) => Ok(()),
+            Err(error) if error.kind() == std::io::ErrorKind::NotFound => Ok(()),
+            Err(error) => Err(error).with_context(|| {
+                format!(
+                    "failed to clear checkpoint directory {}",
+                    self.dir.display()
+                )
+            }),
+        }
+    }
+}
+
+/// Result of a checkpointed extraction run.
+pub struct CheckpointedExtraction {
+    pub batch: ExtractedBatch,
+    pub records: Vec<ExtractedFileRecord>,
+    /// Files whose extraction was loaded from a checkpoint instead of redone.
+    pub files_resumed: usize,
+}
+
+/// Extract `files_by_language` in deterministic chunks of `chunk_files`,
+/// reusing stored chunks and storing newly extracted ones. Produces the same
+/// batch as [`extract_files_for_indexing_with_records`], in chunk order.
+pub async fn extract_files_with_checkpoints(
+    files_by_language: HashMap<String, Vec<PathBuf>>,
+    workspace_root: &Path,
+    checkpoints: &ExtractionCheckpoints,
+    chunk_files: usize,
+) -> Result<CheckpointedExtraction> {
+    let mut work: Vec<(String, String, PathBuf)> = files_by_language
+        .into_iter()
+        .flat_map(|(language, paths)| {
+            paths.into_iter().map(move |path| {
+                (
+                    relative_path_for_storage(&path, workspace_root),
+                    language.clone(),
+                    path,
+                )
+            })
+        })
+        .collect();
+    // Chunk boundaries must not depend on walk order, or no chunk would ever
+    // be found again after a restart.
+    work.sort_by(|a, b| a.0.cmp(&b.0));
+
+    let mut batch = ExtractedBatch::new();
+    let mut records = Vec::new();
+    let mut files_resumed = 0;
+    // The merged batch grows chunk by chunk; later chunks extract with less
+    // parallelism as it fills the memory budget.
+    let mut batch_bytes = 0;
+    let mut batch_charge = MemoryBudget::global().charge(MemoryComponent::BatchBuffers, 0);
+    let chunk_count = work.len().div_ceil(chunk_files.max(1));
+    for (index, chunk) in work.chunks(chunk_files.max(1)).enumerate() {
+        let markers = completion_markers(chunk).await?;
+
+        let (mut chunk_batch, mut chunk_records) = match checkpoints.load(&markers) {
+            Some(stored) => {
+                debug!(
+                    "Resumed extraction chunk {}/{} ({} files) from checkpoint",
+                    index + 1,
+                    chunk_count,
+                    chunk.len()
+                );
+                files_resumed += chunk.len();
+                stored
+            }
+            None => {
+                let mut chunk_by_language: HashMap<String, Vec<PathBuf>> = HashMap::new();
+                for (_, language, path) in chunk {
+                    chunk_by_language
+                        .entry(language.clone())
+                        .or_default()
+                        .push(path.clone());
+                }
+                let (chunk_batch, chunk_records) =
+                    extract_files_for_indexing_with_records(chunk_by_language, workspace_root)
+                        .await?;
+                if let Err(error) = checkpoints.store(&markers, &chunk_batch, &chunk_records) {
+                    warn!("Failed to store extraction checkpoint: {:#}", error);
+                }
+                (chunk_batch, chunk_records)
+            }
+        };
+        batch_bytes += chunk_batch.approximate_bytes();
+        batch_charge.resize(batch_bytes);
+        batch.absorb(&mut chunk_batch);
+        records.append(&mut chunk_records);
+    }
+
+    if files_resumed > 0 {
+        info!(
+            "♻️  Resumed {} of {} files from extraction checkpoints",
+            files_resumed,
+            work.len()
+        );
+    }
+
+    Ok(CheckpointedExtraction {
+        batch,
+        records,
+        files_resumed,
+    })
+}
+
+async fn completion_markers(
+    chunk: &[(String, String, PathBuf)],
+) -> Result<Vec<FileCompletionMarker>> {
+    let files: Vec<(String, PathBuf)> = chunk
+        .iter()
+        .map(|(relative_path, _, path)| (relative_path.clone(), path.clone()))
+        .collect();
+    tokio::task::spawn_blocking(move || {
+        files
+            .into_iter()
+            .map(|(relative_path, path)| FileCompletionMarker {
+                content_hash: julie_core::database::calculate_file_hash(&path)
+                    .unwrap_or_else(|_| UNREADABLE_FILE_HASH.to_string()),
+                relative_path,
+            })
+            .collect()
+    })
+    .await
+    .context("checkpoint hashing task panicked")
+}
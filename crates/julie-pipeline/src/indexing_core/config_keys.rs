@@ -0,0 +1,726 @@
+//! Configuration keys and the code that reads them.
+//!
+//! `.env` files are indexed as plain text, and the JSON/YAML extractors see
+//! `"ConnectionStrings": { "Default": ... }` as anonymous nesting, so "where is
+//! `DATABASE_URL` set and who reads it" has nothing to land on. This pass
+//! reads `.env` files, `appsettings*.json` and YAML config files
+//! (`application.yml`, `config/*.yaml`, ...) and gives every key a config
+//! symbol: semantic group [`CONFIG_SEMANTIC_GROUP`] and `{format, key}` under
+//! [`CONFIG_METADATA_KEY`], where `key` is the full path the application uses
+//! (`ConnectionStrings:Default` for .NET, `spring.datasource.url` for YAML). A
+//! key symbol the extractor already produced on the declaration line is
+//! tagged in place; otherwise one is synthesized, named by its full path.
+//! `.env` symbols never copy the value, which is often a secret.
+//!
+//! On the reading side, `os.environ["X"]`, `process.env.X`,
+//! `Configuration["X"]`, `std::env::var("X")` and their relatives become
+//! pending relationships whose callee carries [`CONFIG_USAGE_CALLEE_PREFIX`].
+//! Finalize hands those to [`resolve_config_usages`] instead of the general
+//! resolver, which would happily bind `DATABASE_URL` to a same-named constant
+//! in the caller's own language. Key matching ignores ASCII case and treats
+//! `__` as `:`, as .NET configuration does.
+//!
+//! Parsing is line-oriented and tolerant: anything unrecognized is skipped,
+//! never an error, because a half-edited config file must still index.
+
+use std::collections::HashMap;
+
+use julie_core::database::SymbolDatabase;
+use julie_extractors::base::{Symbol, SymbolKind};
+use julie_extractors::{ExtractionResults, PendingRelationship, Relationship, RelationshipKind};
+use serde_json::json;
+use tracing::{debug, warn};
+
+use crate::indexing_core::text_lines::TextLines;
+
+/// Metadata key holding `{format, key}`.
+pub const CONFIG_METADATA_KEY: &str = "config";
+
+/// `semantic_group` shared by every config key symbol.
+pub const CONFIG_SEMANTIC_GROUP: &str = "config";
+
+/// Prefix on the `callee_name` of a pending relationship recorded for a
+/// config read. The rest of the name is the key as written in code.
+pub const CONFIG_USAGE_CALLEE_PREFIX: &str = "config:";
+
+/// Languages scanned for config reads. Data and markup formats are left out:
+/// a README that mentions `process.env.PORT` is not a reader.
+const CONFIG_READER_LANGUAGES: &[&str] = &[
+    "c",
+    "cpp",
+    "csharp",
+    "dart",
+    "elixir",
+    "go",
+    "groovy",
+    "java",
+    "javascript",
+    "jsx",
+    "kotlin",
+    "php",
+    "python",
+    "razor",
+    "ruby",
+    "rust",
+    "scala",
+    "swift",
+    "tsx",
+    "typescript",
+    "vue",
+];
+
+/// YAML file stems (first segment) that hold application configuration.
+const YAML_CONFIG_STEMS: &[&str] = &[
+    "application",
+    "appsettings",
+    "bootstrap",
+    "config",
+    "settings",
+];
+
+/// Directories whose YAML files are configuration (`config/database.yml`).
+const YAML_CONFIG_DIRS: &[&str] = &["config", "configs", "conf"];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConfigFormat {
+    Dotenv,
+    AppSettings,
+    Yaml,
+}
+
+impl ConfigFormat {
+    /// Recognize a config file by its path.
+    pub fn detect(file_path: &str) -> Option<Self> {
+        let (dir, file_name) = file_path.rsplit_once('/').unwrap_or(("", file_path));
+        let file_name = file_name.to_ascii_lowercase();
+        if file_name == ".env" || file_name.starts_with(".env.") || file_name.ends_with(".env") {
+            return Some(Self::Dotenv);
+        }
+        if file_name.starts_with("appsettings") && file_name.ends_with(".json") {
+            return Some(Self::AppSettings);
+        }
+        let stem = file_name
+            .strip_suffix(".yml")
+            .or_else(|| file_name.strip_suffix(".yaml"))?;
+        let first = stem.split(['-', '.', '_']).next().unwrap_or(stem);
+        let in_config_dir = dir
+            .rsplit('/')
+            .next()
+            .is_some_and(|parent| YAML_CONFIG_DIRS.contains(&parent.to_ascii_lowercase().as_str()));
+        (YAML_CONFIG_STEMS.contains(&first) || in_config_dir).then_some(Self::Yaml)
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Dotenv => "dotenv",
+            Self::AppSettings => "appsettings",
+            Self::Yaml => "yaml",
+        }
+    }
+}
+
+/// One key declared in a config file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConfigKey {
+    /// Full key path: `DATABASE_URL`, `ConnectionStrings:Default`,
+    /// `spring.datasource.url`.
+    pub key: String,
+    /// Last path segment, as written in the file.
+    pub leaf: String,
+    /// 1-based line of the key.
+    pub line: u32,
+    /// Byte offset of the key's first character.
+    pub offset: usize,
+}
+
+/// One config read found in source code.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConfigUsage {
+    /// The key as written in code (`ConnectionStrings:Default` for
+    /// `GetConnectionString("Default")`).
+    pub key: String,
+    /// 1-based line of the key.
+    pub line: u32,
+    /// Byte offset of the key's first character.
+    pub offset: usize,
+}
+
+/// Tag or synthesize config key symbols for a config file. Other files are
+/// left untouched.
+pub fn annotate_config_keys(
+    symbols: &mut Vec<Symbol>,
+    file_path: &str,
+    language: &str,
+    content: &str,
+) {
+    let Some(format) = ConfigFormat::detect(file_path) else {
+        return;
+    };
+    let keys = parse_config_keys(format, content);
+    if keys.is_empty() {
+        return;
+    }
+
+    let lines = TextLines::new(content);
+    let mut synthesized = Vec::new();
+    for key in &keys {
+        let info = json!({ "format": format.name(), "key": key.key });
+        match symbols
+            .iter_mut()
+            .find(|symbol| symbol.start_line == key.line && symbol.name == key.leaf)
+        {
+            Some(symbol) => {
+                symbol.semantic_group = Some(CONFIG_SEMANTIC_GROUP.to_string());
+                symbol
+                    .metadata
+                    .get_or_insert_with(HashMap::new)
+                    .insert(CONFIG_METADATA_KEY.to_string(), info);
+            }
+            None => synthesized.push(synthesize_symbol(
+                key, format, file_path, language, content, &lines, info,
+            )),
+        }
+    }
+    symbols.extend(synthesized);
+}
+
+/// Parse every key in a config file of the given format, in file order.
+pub fn parse_config_keys(format: ConfigFormat, content: &str) -> Vec<ConfigKey> {
+    match format {
+        ConfigFormat::Dotenv => parse_dotenv(content),
+        ConfigFormat::AppSettings => parse_appsettings(content),
+        ConfigFormat::Yaml => parse_yaml(content),
+    }
+}
+
+fn synthesize_symbol(
+    key: &ConfigKey,
+    format: ConfigFormat,
+    file_path: &str,
+    language: &str,
+    content: &str,
+    lines: &TextLines,
+    info: serde_json::Value,
+) -> Symbol {
+    let id_source = format!("{}:{}:{}", file_path, key.line, key.key);
+    let id = blake3::hash(id_source.as_bytes()).to_hex()[..32].to_string();
+    let line_start = lines.line_start(key.line);
+    let line_end = content[line_start..]
+        .find('\n')
+        .map_or(content.len(), |offset| line_start + offset);
+    let line_text = content[line_start..line_end].trim_end_matches('\r');
+    // The value of an env file entry is frequently a credential.
+    let declaration = match format {
+        ConfigFormat::Dotenv => key.key.clone(),
+        _ => line_text.trim().to_string(),
+    };
+    Symbol {
+        id,
+        name: key.key.clone(),
+        kind: SymbolKind::Property,
+        language: language.to_string(),
+        file_path: file_path.to_string(),
+        start_line: key.line,
+        start_column: (key.offset - line_start) as u32,
+        end_line: key.line,
+        end_column: line_text.len() as u32,
+        start_byte: key.offset as u32,
+        end_byte: (line_start + line_text.len()) as u32,
+        signature: Some(declaration.clone()),
+        doc_comment: None,
+        visibility: None,
+        parent_id: None,
+        metadata: Some(HashMap::from([(CONFIG_METADATA_KEY.to_string(), info)])),
+        semantic_group: Some(CONFIG_SEMANTIC_GROUP.to_string()),
+        confidence: None,
+        code_context: (format != ConfigFormat::Dotenv).then_some(declaration),
+        content_type: None,
+        body_span: None,
+        body_hash: None,
+        annotations: Vec::new(),
+    }
+}
+
+fn is_key_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || matches!(c, '_' | '.' | '-')
+}
+
+// ---------------------------------------------------------------------------
+// .env
+// ---------------------------------------------------------------------------
+
+fn parse_dotenv(content: &str) -> Vec<ConfigKey> {
+    let mut keys = Vec::new();
+    let mut line_start = 0;
+    for (index, line) in content.split_inclusive('\n').enumerate() {
+        let start = line_start;
+        line_start += line.len();
+        let trimmed = line.trim_start();
+        let mut offset = start + (line.len() - trimmed.len());
+        let body = match trimmed.strip_prefix("export ") {
+            Some(rest) => {
+                let rest_trimmed = rest.trim_start();
+                offset += trimmed.len() - rest_trimmed.len();
+                rest_trimmed
+            }
+            None => trimmed,
+        };
+        if body.starts_with('#') {
+            continue;
+        }
+        let Some((key, _value)) = body.split_once('=') else {
+            continue;
+        };
+        let key = key.trim_end();
+        if key.is_empty() || !key.chars().all(is_key_char) {
+            continue;
+        }
+        keys.push(ConfigKey {
+            key: key.to_string(),
+            leaf: key.to_string(),
+            line: index as u32 + 1,
+            offset,
+        });
+    }
+    keys
+}
+
+// ---------------------------------------------------------------------------
+// appsettings.json
+// ---------------------------------------------------------------------------
+
+/// Open container while scanning JSON, with the key path it was assigned to.
+enum JsonFrame {
+    Object { path: String },
+    Array { path: String, index: usize },
+}
+
+/// Flatten an appsettings file the way .NET configuration does: nested keys
+/// join with `:` and array elements use their index (`Servers:0:Host`).
+/// Comments are allowed, as the .NET JSON provider allows them.
+fn parse_appsettings(content: &str) -> Vec<ConfigKey> {
+    let bytes = content.as_bytes();
+    let lines = TextLines::new(content);
+    let mut keys = Vec::new();
+    let mut stack: Vec<JsonFrame> = Vec::new();
+    // Path for the value that follows the last `"key":`.
+    let mut value_path: Option<String> = None;
+    let mut i = 0;
+
+    while i < bytes.len() {
+        match bytes[i] {
+            b'/' if bytes.get(i + 1) == Some(&b'/') => {
+                i = content[i..].find('\n').map_or(bytes.len(), |end| i + end);
+            }
+            b'/' if bytes.get(i + 1) == Some(&b'*') => {
+                i = content[i + 2..]
+                    .find("*/")
+                    .map_or(bytes.len(), |end| i + 2 + end + 2);
+            }
+            b'"' => {
+                let Some(end) = string_end(bytes, i + 1) else {
+                    break;
+                };
+                let text = &content[i + 1..end];
+                let after = skip_json_space(bytes, end + 1);
+                let is_key = bytes.get(after) == Some(&b':')
+                    && matches!(stack.last(), Some(JsonFrame::Object { .. }));
+                if is_key {
+                    let Some(JsonFrame::Object { path }) = stack.last() else {
+                        unreachable!("is_key checked the frame");
+                    };
+                    let key = if path.is_empty() {
+                        text.to_string()
+                    } else {
+                        format!("{path}:{text}")
+                    };
+                    keys.push(ConfigKey {
+                        key: key.clone(),
+                        leaf: text.to_string(),
+                        line: lines.line_of(i + 1),
+                        offset: i + 1,
+                    });
+                    value_path = Some(key);
+                    i = after + 1;
+                } else {
+                    value_path = None;
+                    i = end + 1;
+                }
+            }
+            open @ (b'{' | b'[') => {
+                let path = match stack.last() {
+                    Some(JsonFrame::Array { path, index }) if path.is_empty() => index.to_string(),
+                    Some(JsonFrame::Array { path, index }) => format!("{path}:{index}"),
+                    Some(JsonFrame::Object { .. }) => value_path.take().unwrap_or_default(),
+                    None => String::new(),
+                };
+                stack.push(if open == b'{' {
+                    JsonFrame::Object { path }
+                } else {
+                    JsonFrame::Array { path, index: 0 }
+                });
+                i += 1;
+            }
+            b'}' | b']' => {
+                stack.pop();
+                value_path = None;
+                i += 1;
+            }
+            b',' => {
+                if let Some(JsonFrame::Array { index, .. }) = stack.last_mut() {
+                    *index += 1;
+                }
+                value_path = None;
+                i += 1;
+            }
+            _ => i += 1,
+        }
+    }
+    keys
+}
+
+/// Index of the closing quote of a JSON string whose body starts at `start`.
+fn string_end(bytes: &[u8], start: usize) -> Option<usize> {
+    let mut i = start;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'\\' => i += 2,
+            b'"' => return Some(i),
+            b'\n' => return None,
+            _ => i += 1,
+        }
+    }
+    None
+}
+
+fn skip_json_space(bytes: &[u8], mut i: usize) -> usize {
+    while bytes.get(i).is_some_and(u8::is_ascii_whitespace) {
+        i += 1;
+    }
+    i
+}
+
+// ---------------------------------------------------------------------------
+// YAML
+// ---------------------------------------------------------------------------
+
+/// Flatten mapping keys into dotted paths (`spring.datasource.url`). Keys
+/// inside sequences and block scalars are skipped: they have no stable path.
+fn parse_yaml(content: &str) -> Vec<ConfigKey> {
+    let mut keys = Vec::new();
+    // Open mappings as (indent, key); `None` marks a sequence item.
+    let mut stack: Vec<(usize, Option<String>)> = Vec::new();
+    // Indent of a key whose value is a `|` / `>` block scalar.
+    let mut block_indent: Option<usize> = None;
+    let mut line_start = 0;
+
+    for (index, raw) in content.split_inclusive('\n').enumerate() {
+        let start = line_start;
+        line_start += raw.len();
+        let line = strip_yaml_comment(raw.trim_end_matches(['\n', '\r']));
+        let text = line.trim_start();
+        if text.is_empty() {
+            continue;
+        }
+        let indent = line.len() - text.len();
+        if let Some(block) = block_indent {
+            if indent > block {
+                continue;
+            }
+            block_indent = None;
+        }
+        if indent == 0 && (text.starts_with("---") || text.starts_with("...")) {
+            stack.clear();
+            continue;
+        }
+
+        while stack.last().is_some_and(|(open, _)| *open >= indent) {
+            stack.pop();
+        }
+        if text == "-" || text.starts_with("- ") {
+            stack.push((indent, None));
+            continue;
+        }
+        if stack.iter().any(|(_, key)| key.is_none()) {
+            continue;
+        }
+
+        let Some((key, value)) = split_yaml_key(text) else {
+            continue;
+        };
+        if key.is_empty() || key.starts_with(['<', '?', '&', '*', '{', '[']) {
+            continue;
+        }
+        let key_offset = start + indent + usize::from(text.starts_with(['"', '\'']));
+        let path = stack
+            .iter()
+            .filter_map(|(_, key)| key.as_deref())
+            .chain(std::iter::once(key))
+            .collect::<Vec<_>>()
+            .join(".");
+        keys.push(ConfigKey {
+            key: path,
+            leaf: key.to_string(),
+            line: index as u32 + 1,
+            offset: key_offset,
+        });
+        if value.starts_with(['|', '>']) {
+            block_indent = Some(indent);
+        }
+        stack.push((indent, Some(key.to_string())));
+    }
+    keys
+}
+
+/// `key: value` → (unquoted key, trimmed value). `None` for lines that are
+/// not a mapping entry.
+fn split_yaml_key(text: &str) -> Option<(&str, &str)> {
+    if let Some(quote) = text.chars().next().filter(|c| *c == '"' || *c == '\'') {
+        let close = text[1..].find(quote)? + 1;
+        let rest = text[close + 1..].trim_start().strip_prefix(':')?;
+        if !(rest.is_empty() || rest.starts_with(' ')) {
+            return None;
+        }
+        return Some((&text[1..close], rest.trim()));
+    }
+    let colon = text
+        .match_indices(':')
+        .map(|(at, _)| at)
+        .find(|at| matches!(text.as_bytes().get(at + 1), None | Some(b' ')))?;
+    Some((text[..colon].trim_end(), text[colon + 1..].trim()))
+}
+
+/// Drop a trailing `# comment` that is not inside quotes.
+fn strip_yaml_comment(line: &str) -> &str {
+    let mut quote = None;
+    let mut previous = ' ';
+    for (at, c) in line.char_indices() {
+        match quote {
+            Some(open) if c == open => quote = None,
+            Some(_) => {}
+            None if c == '"' || c == '\'' => quote = Some(c),
+            None if c == '#' && previous.is_whitespace() => return &line[..at],
+            None => {}
+        }
+        previous = c;
+    }
+    line
+}
+
+// ---------------------------------------------------------------------------
+// Reads in source code
+// ---------------------------------------------------------------------------
+
+/// What follows an accessor.
+#[derive(Clone, Copy)]
+enum Access {
+    /// A string literal: `os.environ["X"]`, `getenv("X")`.
+    Quoted,
+    /// A bare identifier: `process.env.X`.
+    Member,
+    /// A Spring placeholder: `@Value("${x.y:default}")`.
+    Placeholder,
+}
+
+/// Accessors and the key prefix they imply. Matching requires a
+/// non-alphanumeric character before the accessor, so `getenv(` also covers
+/// `os.getenv(` and `Configuration[` also covers `builder.Configuration[`.
+const ACCESSORS: &[(&str, Access, &str)] = &[
+    ("os.environ[", Access::Quoted, ""),
+    ("os.environ.get(", Access::Quoted, ""),
+    ("getenv(", Access::Quoted, ""),
+    ("process.env[", Access::Quoted, ""),
+    ("process.env.", Access::Member, ""),
+    ("import.meta.env.", Access::Member, ""),
+    ("Configuration[", Access::Quoted, ""),
+    ("configuration[", Access::Quoted, ""),
+    ("GetSection(", Access::Quoted, ""),
+    ("GetConnectionString(", Access::Quoted, "ConnectionStrings:"),
+    ("Environment.GetEnvironmentVariable(", Access::Quoted, ""),
+    ("env::var(", Access::Quoted, ""),
+    ("env::var_os(", Access::Quoted, ""),
+    ("env!(", Access::Quoted, ""),
+    ("option_env!(", Access::Quoted, ""),
+    ("os.Getenv(", Access::Quoted, ""),
+    ("os.LookupEnv(", Access::Quoted, ""),
+    ("System.getenv(", Access::Quoted, ""),
+    ("System.get_env(", Access::Quoted, ""),
+    ("System.fetch_env(", Access::Quoted, ""),
+    ("System.fetch_env!(", Access::Quoted, ""),
+    ("ENV[", Access::Quoted, ""),
+    ("ENV.fetch(", Access::Quoted, ""),
+    ("$_ENV[", Access::Quoted, ""),
+    ("Platform.environment[", Access::Quoted, ""),
+    ("processInfo.environment[", Access::Quoted, ""),
+    ("@Value(\"${", Access::Placeholder, ""),
+];
+
+/// Find every config read in `content`, in source order.
+pub fn find_config_usages(content: &str) -> Vec<ConfigUsage> {
+    let lines = TextLines::new(content);
+    let mut usages: Vec<ConfigUsage> = Vec::new();
+    for (accessor, access, prefix) in ACCESSORS {
+        for (at, _) in content.match_indices(accessor) {
+            let boundary = content[..at]
+                .chars()
+                .next_back()
+                .is_none_or(|c| !c.is_ascii_alphanumeric());
+            if !boundary {
+                continue;
+            }
+            let Some((key, offset)) = read_key(content, at + accessor.len(), *access) else {
+                continue;
+            };
+            usages.push(ConfigUsage {
+                key: format!("{prefix}{key}"),
+                line: lines.line_of(offset),
+                offset,
+            });
+        }
+    }
+    // `getenv(` also fires inside `os.getenv(`; keep one read per literal.
+    usages.sort_by_key(|usage| usage.offset);
+    usages.dedup_by_key(|usage| usage.offset);
+    usages
+}
+
+/// The key after an accessor ending at `start`, with its byte offset.
+fn read_key(content: &str, start: usize, access: Access) -> Option<(&str, usize)> {
+    match access {
+        Access::Member => {
+            let len = content[start..]
+                .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))
+                .unwrap_or(content.len() - start);
+            let key = &content[start..start + len];
+            key.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_')
+                .then_some((key, start))
+        }
+        Access::Quoted => {
+            let rest = &content[start..];
+            let literal = start + (rest.len() - rest.trim_start().len());
+            let quote = content[literal..]
+                .chars()
+                .next()
+                .filter(|c| matches!(c, '"' | '\'' | '`'))?;
+            let body = literal + 1;
+            let len = content[body..].find([quote, '\\', '\n', '$'])?;
+            let key = &content[body..body + len];
+            (!key.is_empty() && content[body + len..].starts_with(quote)).then_some((key, body))
+        }
+        Access::Placeholder => {
+            let len = content[start..].find(['}', ':', '"', '\n'])?;
+            let key = &content[start..start + len];
+            (!key.is_empty()).then_some((key, start))
+        }
+    }
+}
+
+/// Record the config reads in a source file as pending relationships from
+/// the innermost symbol around each read. Reads outside any symbol, and
+/// files in languages that don't read configuration, are skipped.
+pub fn link_config_usages(
+    results: &mut ExtractionResults,
+    file_path: &str,
+    language: &str,
+    content: &str,
+) {
+    if !CONFIG_READER_LANGUAGES.contains(&language) || results.symbols.is_empty() {
+        return;
+    }
+    for usage in find_config_usages(content) {
+        let Some(from) = innermost_symbol(&results.symbols, usage.line, usage.offset) else {
+            continue;
+        };
+        results.pending_relationships.push(PendingRelationship {
+            from_symbol_id: from.id.clone(),
+            callee_name: format!("{CONFIG_USAGE_CALLEE_PREFIX}{}", usage.key),
+            kind: RelationshipKind::References,
+            file_path: file_path.to_string(),
+            line_number: usage.line,
+            confidence: 0.9,
+        });
+    }
+}
+
+/// Smallest symbol whose span covers byte `offset` on `line`, by bytes when
+/// the extractor recorded them and by lines otherwise. Imports never enclose.
+pub(crate) fn innermost_symbol(symbols: &[Symbol], line: u32, offset: usize) -> Option<&Symbol> {
+    let offset = offset as u32;
+    symbols
+        .iter()
+        .filter(|symbol| symbol.kind != SymbolKind::Import)
+        .filter(|symbol| {
+            if symbol.end_byte > symbol.start_byte {
+                symbol.start_byte <= offset && offset < symbol.end_byte
+            } else {
+                symbol.start_line <= line && line <= symbol.end_line
+            }
+        })
+        .min_by_key(|symbol| {
+            (
+                symbol.end_line - symbol.start_line,
+                symbol.end_byte.saturating_sub(symbol.start_byte),
+            )
+        })
+}
+
+/// Is this pending relationship a config read recorded by this pass?
+pub fn is_config_usage(pending: &PendingRelationship) -> bool {
+    pending.kind == RelationshipKind::References
+        && pending.callee_name.starts_with(CONFIG_USAGE_CALLEE_PREFIX)
+}
+
+/// Case-insensitive, with .NET's `__` environment separator folded to `:`.
+fn normalize_key(key: &str) -> String {
+    key.replace("__", ":").to_ascii_lowercase()
+}
+
+/// Resolve config reads against the config key symbols in `db`. A key
+/// defined in several files (`appsettings.json` and
+/// `appsettings.Production.json`) links to each definition. Reads of keys no
+/// config file declares stay unlinked.
+pub fn resolve_config_usages(
+    pendings: &[PendingRelationship],
+    db: &SymbolDatabase,
+) -> Vec<Relationship> {
+    let usages: Vec<&PendingRelationship> =
+        pendings.iter().filter(|p| is_config_usage(p)).collect();
+    if usages.is_empty() {
+        return Vec::new();
+    }
+    let config_symbols = match db.get_symbols_by_semantic_group(CONFIG_SEMANTIC_GROUP) {
+        Ok(symbols) => symbols,
+        Err(e) => {
+            warn!("Config key lookup failed: {}", e);
+            return Vec::new();
+        }
+    };
+
+    let mut by_key: HashMap<String, Vec<&Symbol>> = HashMap::new();
+    for symbol in &config_symbols {
+        let key = symbol
+            .metadata
+            .as_ref()
+            .and_then(|metadata| metadata.get(CONFIG_METADATA_KEY))
+            .and_then(|info| info.get("key"))
+            .and_then(|key| key.as_str())
+            .unwrap_or(&symbol.name);
+        by_key.entry(normalize_key(key)).or_default().push(symbol);
+    }
+
+    let mut resolved = Vec::new();
+    for pending in &usages {
+        let key = &pending.callee_name[CONFIG_USAGE_CALLEE_PREFIX.len()..];
+        for target in by_key.get(&normalize_key(key)).into_iter().flatten() {
+            resolved.push(crate::resolver::build_resolved_relationship(
+                pending, target,
+            ));
+        }
+    }
+    debug!(
+        "Linked {} config reads to {} config key definitions",
+        usages.len(),
+        resolved.len()
+    );
+    resolved
+}
@@ -0,0 +1,95 @@
+//! C# partial type awareness.
+//!
+//! A `partial class` can be split across any number of files — a hand-written
+//! half, a source-generator half, a `.Designer.cs` file. The extractor emits one
+//! symbol per declaration, so members hang off different parents and navigation
+//! shows several unrelated classes with the same name.
+//!
+//! This pass tags every partial type declaration with a `partial_group` key: the
+//! namespace-qualified type name. Query-time tools (`deep_dive`) use the key to
+//! unify all declaration sites into one logical symbol. The key is derived from
+//! the declaring file alone, so it stays stable when only one half of a type is
+//! re-indexed.
+
+use std::collections::HashMap;
+
+use julie_extractors::base::{Symbol, SymbolKind};
+
+/// Metadata key holding the namespace-qualified name shared by every
+/// declaration of one partial type.
+pub const PARTIAL_GROUP_METADATA_KEY: &str = "partial_group";
+
+/// Tag C# partial type declarations in one file's symbols with their
+/// [`PARTIAL_GROUP_METADATA_KEY`]. Non-C# symbols are left untouched.
+pub fn annotate_partial_declarations(symbols: &mut [Symbol]) {
+    if !symbols.iter().any(is_csharp_partial_type) {
+        return;
+    }
+
+    let by_id: HashMap<&str, &Symbol> = symbols.iter().map(|s| (s.id.as_str(), s)).collect();
+    let groups: Vec<(usize, String)> = symbols
+        .iter()
+        .enumerate()
+        .filter(|(_, symbol)| is_csharp_partial_type(symbol))
+        .map(|(idx, symbol)| (idx, qualified_type_name(symbol, &by_id)))
+        .collect();
+
+    for (idx, group) in groups {
+        symbols[idx]
+            .metadata
+            .get_or_insert_with(HashMap::new)
+            .insert(
+                PARTIAL_GROUP_METADATA_KEY.to_string(),
+                serde_json::Value::String(group),
+            );
+    }
+}
+
+fn is_csharp_partial_type(symbol: &Symbol) -> bool {
+    symbol.language == "csharp"
+        && matches!(
+            symbol.kind,
+            SymbolKind::Class | SymbolKind::Struct | SymbolKind::Interface
+        )
+        && symbol
+            .signature
+            .as_deref()
+            .is_some_and(has_partial_modifier)
+}
+
+/// `partial` must appear as a modifier before the type keyword, so a class
+/// merely *named* `PartialResult` or a `partial` method inside it do not count.
+fn has_partial_modifier(signature: &str) -> bool {
+    let header = signature.split(['{', ':', '(', '<']).next().unwrap_or("");
+    let mut saw_partial = false;
+    for token in header.split_whitespace() {
+        match token {
+            "partial" => saw_partial = true,
+            "class" | "struct" | "interface" | "record" => return saw_partial,
+            _ => {}
+        }
+    }
+    false
+}
+
+/// Build `Namespace.Outer.Name` by walking the in-file parent chain through
+/// namespaces and enclosing types.
+fn qualified_type_name(symbol: &Symbol, by_id: &HashMap<&str, &Symbol>) -> String {
+    let mut segments = vec![symbol.name.as_str()];
+    let mut parent_id = symbol.parent_id.as_deref();
+    while let Some(parent) = parent_id.and_then(|id| by_id.get(id)) {
+        if matches!(
+            parent.kind,
+            SymbolKind::Namespace
+                | SymbolKind::Module
+                | SymbolKind::Class
+                | SymbolKind::Struct
+                | SymbolKind::Interface
+        ) {
+            segments.push(parent.name.as_str());
+        }
+        parent_id = parent.parent_id.as_deref();
+    }
+    segments.reverse();
+    segments.join(".")
+}
@@ -0,0 +1,308 @@
+//! Column headers of delimited data files (`.csv`, `.tsv`).
+//!
+//! In data engineering repositories the header row of a CSV is the schema:
+//! the column `customer_id` is what the loader, the SQL and the notebook all
+//! name. Data files are indexed as text, so a search for a column found the
+//! code that reads it but never the file that defines it. This pass reads the
+//! header row and emits a module symbol for the file (named after its stem)
+//! with one field symbol per column. Both carry `{format, ...}` under
+//! [`DELIMITED_DATA_METADATA_KEY`]: the file adds its delimiter and column
+//! count, each column its 0-based position.
+//!
+//! Only the first [`HEADER_SCAN_BYTES`] of the file are scanned, however
+//! large it is. The delimiter of a `.csv` is sniffed from the header row
+//! (`,`, `;`, `|` or tab), and a first row that looks like data (any numeric
+//! cell) is not taken for a header.
+
+use std::collections::HashMap;
+
+use julie_extractors::base::{Symbol, SymbolKind};
+use serde_json::{Value, json};
+
+pub use julie_core::file_policy::{CSV_LANGUAGE, TSV_LANGUAGE, is_delimited_data_language};
+
+/// Metadata key holding the data-file facts of a symbol.
+pub const DELIMITED_DATA_METADATA_KEY: &str = "delimited_data";
+
+/// `semantic_group` shared by data file and column symbols.
+pub const DELIMITED_DATA_SEMANTIC_GROUP: &str = "data";
+
+/// Bytes scanned for the header row.
+pub const HEADER_SCAN_BYTES: usize = 64 * 1024;
+
+/// Columns kept per file; wider headers are cut off.
+const MAX_COLUMNS: usize = 1_000;
+
+/// Delimiters a `.csv` header is sniffed for, most common first so ties go to
+/// the comma.
+const CSV_DELIMITERS: &[char] = &[',', ';', '\t', '|'];
+
+/// One column of a header row.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HeaderColumn {
+    /// Column name, unquoted and trimmed.
+    pub name: String,
+    /// 0-based position in the row, counting unnamed columns.
+    pub index: usize,
+    /// Byte offset where the cell starts, quotes included.
+    pub start: usize,
+    /// Byte offset just past the cell.
+    pub end: usize,
+}
+
+/// The header row of a data file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DataHeader {
+    pub delimiter: char,
+    pub columns: Vec<HeaderColumn>,
+    /// 1-based line the header row starts on.
+    pub line: u32,
+}
+
+/// Add column symbols for a `.csv` or `.tsv` file. Other files, and files an
+/// extractor already produced symbols for, are left untouched.
+pub fn annotate_delimited_data_headers(
+    symbols: &mut Vec<Symbol>,
+    file_path: &str,
+    language: &str,
+    content: &str,
+) {
+    if !is_delimited_data_language(language) || !symbols.is_empty() {
+        return;
+    }
+    symbols.extend(parse_delimited_data_symbols(file_path, language, content));
+}
+
+/// The file symbol followed by its column symbols, or nothing when the file
+/// has no recognizable header row.
+pub fn parse_delimited_data_symbols(file_path: &str, language: &str, content: &str) -> Vec<Symbol> {
+    let Some(header) = parse_header(language, content) else {
+        return Vec::new();
+    };
+
+    let file_name = file_path.rsplit('/').next().unwrap_or(file_path);
+    let stem = file_name
+        .rsplit_once('.')
+        .map_or(file_name, |(stem, _)| stem);
+    let first = header.columns.first().map_or(0, |column| column.start);
+    let last = header.columns.last().map_or(0, |column| column.end);
+    let column_names: Vec<&str> = header
+        .columns
+        .iter()
+        .map(|column| column.name.as_str())
+        .collect();
+    let file_symbol = data_symbol(
+        file_path,
+        language,
+        content,
+        stem,
+        SymbolKind::Module,
+        (first, last),
+        None,
+        column_names.join(", "),
+        json!({
+            "format": language,
+            "delimiter": header.delimiter.to_string(),
+            "columns": header.columns.len(),
+        }),
+    );
+
+    let mut symbols = Vec::with_capacity(header.columns.len() + 1);
+    for column in &header.columns {
+        symbols.push(data_symbol(
+            file_path,
+            language,
+            content,
+            &column.name,
+            SymbolKind::Field,
+            (column.start, column.end),
+            Some(&file_symbol.id),
+            format!("{stem}.{}", column.name),
+            json!({ "format": language, "column": column.index }),
+        ));
+    }
+    symbols.insert(0, file_symbol);
+    symbols
+}
+
+/// Parse the header row: the first non-blank record in the scanned prefix.
+pub fn parse_header(language: &str, content: &str) -> Option<DataHeader> {
+    let mut scan_end = content.len().min(HEADER_SCAN_BYTES);
+    while !content.is_char_boundary(scan_end) {
+        scan_end -= 1;
+    }
+    let scanned = &content[..scan_end];
+    let bom = if scanned.starts_with('\u{feff}') {
+        '\u{feff}'.len_utf8()
+    } else {
+        0
+    };
+    // Leading blank lines are skipped; the header starts on the first line
+    // with text.
+    let first_text = bom + scanned[bom..].find(|ch: char| !ch.is_whitespace())?;
+    let start = scanned[..first_text]
+        .rfind('\n')
+        .map_or(bom, |newline| newline + 1);
+
+    let delimiter = if language == TSV_LANGUAGE {
+        '\t'
+    } else {
+        sniff_delimiter(&scanned[start..])
+    };
+    let (mut cells, ended) = split_record(scanned, start, delimiter);
+    // A record cut off by the scan limit keeps the cells read so far, minus
+    // the one in progress.
+    if !ended && scan_end < content.len() {
+        cells.pop();
+    }
+
+    let columns: Vec<HeaderColumn> = cells
+        .into_iter()
+        .filter(|column| !column.name.is_empty())
+        .take(MAX_COLUMNS)
+        .collect();
+    if columns.is_empty() || columns.iter().any(|column| looks_numeric(&column.name)) {
+        return None;
+    }
+    Some(DataHeader {
+        delimiter,
+        columns,
+        line: scanned[..start].matches('\n').count() as u32 + 1,
+    })
+}
+
+/// The candidate delimiter seen most often outside quotes on the first line.
+fn sniff_delimiter(text: &str) -> char {
+    let mut counts = [0usize; CSV_DELIMITERS.len()];
+    let mut quoted = false;
+    for ch in text.chars() {
+        match ch {
+            '"' => quoted = !quoted,
+            '\n' if !quoted => break,
+            _ if !quoted => {
+                if let Some(index) = CSV_DELIMITERS.iter().position(|d| *d == ch) {
+                    counts[index] += 1;
+                }
+            }
+            _ => {}
+        }
+    }
+    let mut best = 0;
+    for (index, count) in counts.iter().enumerate() {
+        if *count > counts[best] {
+            best = index;
+        }
+    }
+    CSV_DELIMITERS[best]
+}
+
+/// Split the record starting at `start` into cells. Quoted cells may hold
+/// delimiters, doubled quotes and newlines. The flag is false when the text
+/// runs out before a line break ends the record.
+fn split_record(text: &str, start: usize, delimiter: char) -> (Vec<HeaderColumn>, bool) {
+    let mut cells = Vec::new();
+    let mut cell_start = start;
+    let mut value = String::new();
+    let mut quoted = false;
+    let mut chars = text[start..].char_indices().peekable();
+    while let Some((offset, ch)) = chars.next() {
+        let at = start + offset;
+        match ch {
+            '"' if quoted => {
+                if chars.peek().is_some_and(|(_, next)| *next == '"') {
+                    chars.next();
+                    value.push('"');
+                } else {
+                    quoted = false;
+                }
+            }
+            '"' if value.trim().is_empty() => {
+                value.clear();
+                quoted = true;
+            }
+            _ if quoted => value.push(ch),
+            '\n' | '\r' => {
+                cells.push(cell(&value, cells.len(), cell_start, at));
+                return (cells, true);
+            }
+            _ if ch == delimiter => {
+                cells.push(cell(&value, cells.len(), cell_start, at));
+                value.clear();
+                cell_start = at + ch.len_utf8();
+            }
+            _ => value.push(ch),
+        }
+    }
+    cells.push(cell(&value, cells.len(), cell_start, text.len()));
+    (cells, false)
+}
+
+fn cell(value: &str, index: usize, start: usize, end: usize) -> HeaderColumn {
+    HeaderColumn {
+        name: value.trim().to_string(),
+        index,
+        start,
+        end,
+    }
+}
+
+/// `42`, `-1.5`, `3e8`: a data value rather than a column name. `nan` and
+/// `inf` parse as numbers too but are fine column names.
+fn looks_numeric(text: &str) -> bool {
+    text.bytes().any(|byte| byte.is_ascii_digit()) && text.parse::<f64>().is_ok()
+}
+
+#[allow(clippy::too_many_arguments)]
+fn data_symbol(
+    file_path: &str,
+    language: &str,
+    content: &str,
+    name: &str,
+    kind: SymbolKind,
+    (start, end): (usize, usize),
+    parent_id: Option<&str>,
+    signature: String,
+    info: Value,
+) -> Symbol {
+    let (start_line, start_column) = position(content, start);
+    let (end_line, end_column) = position(content, end);
+    let id_source = format!("{file_path}:{start}:{kind:?}:{name}");
+    Symbol {
+        id: blake3::hash(id_source.as_bytes()).to_hex()[..32].to_string(),
+        name: name.to_string(),
+        kind,
+        language: language.to_string(),
+        file_path: file_path.to_string(),
+        start_line,
+        start_column,
+        end_line,
+        end_column,
+        start_byte: start as u32,
+        end_byte: end as u32,
+        signature: Some(signature),
+        doc_comment: None,
+        visibility: None,
+        parent_id: parent_id.map(str::to_string),
+        metadata: Some(HashMap::from([(
+            DELIMITED_DATA_METADATA_KEY.to_string(),
+            info,
+        )])),
+        semantic_group: Some(DELIMITED_DATA_SEMANTIC_GROUP.to_string()),
+        confidence: None,
+        code_context: None,
+        content_type: None,
+        body_span: None,
+        body_hash: None,
+        annotations: Vec::new(),
+    }
+}
+
+/// 1-based line and 0-based column of byte `offset`.
+fn position(content: &str, offset: usize) -> (u32, u32) {
+    let before = &content[..offset];
+    let line_start = before.rfind('\n').map_or(0, |nl| nl + 1);
+    (
+        before.matches('\n').count() as u32 + 1,
+        (offset - line_start) as u32,
+    )
+}
@@ -0,0 +1,78 @@
+//! Choosing the extractor for a file.
+//!
+//! Most languages go through the canonical tree-sitter pipeline from
+//! `julie_extractors`, followed by the in-tree passes that fill in what it
+//! leaves out: identifiers for the extractors that report none, embedded
+//! languages, and SQL routine annotations. Languages without a grammar there
+//! (notebooks, Verilog, Groovy, Fortran, Ada, build files, Godot scenes,
+//! Terraform and the SQL dialect sources) have their own extractors here.
+//!
+//! The full indexer and the file watcher both extract through
+//! [`dispatch_extractor`], so a file indexes the same way on either path.
+
+use std::path::Path;
+
+use anyhow::Result;
+use julie_core::file_policy::{
+    is_ada_language, is_build_file_language, is_fortran_language, is_godot_scene_language,
+    is_groovy_language, is_notebook_language, is_terraform_language, is_verilog_language,
+};
+use julie_extractors::ExtractionResults;
+
+use crate::indexing_core::ada::extract_ada;
+use crate::indexing_core::build_targets::extract_build_targets;
+use crate::indexing_core::fortran::extract_fortran;
+use crate::indexing_core::godot_scenes::extract_godot_scene;
+use crate::indexing_core::groovy::extract_groovy;
+use crate::indexing_core::identifiers::fill_missing_identifiers;
+use crate::indexing_core::injection::inject_embedded_languages;
+use crate::indexing_core::notebook::extract_notebook;
+use crate::indexing_core::partial_parse::extract_tolerating_syntax_errors;
+use crate::indexing_core::sql_dialects::{
+    annotate_sql_routines, extract_sql_dialect_source, is_sql_dialect_source, sql_dialect_for,
+};
+use crate::indexing_core::terraform::extract_terraform;
+use crate::indexing_core::verilog::extract_verilog;
+
+/// Extract `content`, stored at the workspace-relative `file_path`, with the
+/// extractor for `language`.
+pub fn dispatch_extractor(
+    language: &str,
+    file_path: &str,
+    content: &str,
+    workspace_root: &Path,
+) -> Result<ExtractionResults> {
+    if is_notebook_language(language) {
+        extract_notebook(file_path, content, workspace_root)
+    } else if is_verilog_language(language) {
+        extract_verilog(file_path, content)
+    } else if is_groovy_language(language) {
+        extract_groovy(file_path, content)
+    } else if is_fortran_language(language) {
+        extract_fortran(file_path, content)
+    } else if is_ada_language(language) {
+        extract_ada(file_path, content)
+    } else if is_build_file_language(language) {
+        extract_build_targets(file_path, content)
+    } else if is_godot_scene_language(language) {
+        extract_godot_scene(file_path, content)
+    } else if is_terraform_language(language) {
+        extract_terraform(file_path, content)
+    } else if is_sql_dialect_source(file_path) {
+        extract_sql_dialect_source(file_path, content)
+    } else {
+        let mut results = extract_tolerating_syntax_errors(language, content, |content| {
+            julie_extractors::extract_canonical(file_path, content, workspace_root)
+        })?;
+        fill_missing_identifiers(&mut results, language, file_path, content);
+        inject_embedded_languages(&mut results, language, file_path, content, workspace_root);
+        annotate_sql_routines(
+            &mut results,
+            file_path,
+            language,
+            content,
+            sql_dialect_for(workspace_root),
+        );
+        Ok(results)
+    }
+}
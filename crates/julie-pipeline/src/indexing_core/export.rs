@@ -0,0 +1,155 @@
+//! JSONL bulk export of extraction output for external analytics pipelines.
+//!
+//! Unlike a [shard stream](crate::indexing_core::shards), which carries whole
+//! batches between julie processes, an export stream is flat: one record per
+//! line, each tagged with its `type`, so `jq`, DuckDB or a data-lake loader can
+//! ingest it without knowing julie's batch layout:
+//!
+//! ```text
+//! {"type":"symbol","id":"...","name":"parse","kind":"function",...}
+//! {"type":"identifier","id":"...","name":"tokenize","kind":"call",...}
+//! {"type":"relationship","from_symbol_id":"...","to_symbol_id":"...",...}
+//! {"type":"pending_relationship","from_symbol_id":"...","callee_name":"...",...}
+//! ```
+//!
+//! Relationships are resolved within one extraction chunk; references that
+//! cross chunks are exported as `pending_relationship` records.
+
+use std::collections::HashSet;
+use std::io::Write;
+
+use anyhow::{Context, Result};
+use julie_extractors::{Identifier, PendingRelationship, Relationship, Symbol};
+use serde::Serialize;
+
+use crate::indexing_core::batch::ExtractedBatch;
+
+/// One line of an export stream.
+#[derive(Debug, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ExportRecord<'a> {
+    Symbol(&'a Symbol),
+    Identifier(&'a Identifier),
+    Relationship(&'a Relationship),
+    PendingRelationship(&'a PendingRelationship),
+}
+
+/// Symbol kind filter for an export. Language filtering happens before
+/// extraction (files of other languages are never parsed), so only kinds are
+/// checked here.
+#[derive(Debug, Clone, Default)]
+pub struct ExportFilter {
+    /// Lowercase symbol kinds to keep (`function`, `class`, ...). Empty keeps
+    /// every kind.
+    kinds: HashSet<String>,
+}
+
+impl ExportFilter {
+    pub fn new<I, S>(kinds: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        Self {
+            kinds: kinds
+                .into_iter()
+                .map(|kind| kind.as_ref().trim().to_ascii_lowercase())
+                .filter(|kind| !kind.is_empty())
+                .collect(),
+        }
+    }
+
+    pub fn is_unfiltered(&self) -> bool {
+        self.kinds.is_empty()
+    }
+
+    pub fn keeps_symbol(&self, symbol: &Symbol) -> bool {
+        self.is_unfiltered() || self.kinds.contains(&symbol.kind.to_string())
+    }
+}
+
+/// Per-type record counts written by an [`ExportStreamWriter`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ExportCounts {
+    pub files_processed: usize,
+    pub symbols: usize,
+    pub identifiers: usize,
+    pub relationships: usize,
+}
+
+/// Writes extracted batches as export records, flushing after each batch so a
+/// consumer reading over a pipe sees progress as it happens.
+pub struct ExportStreamWriter<W: Write> {
+    out: W,
+    filter: ExportFilter,
+    counts: ExportCounts,
+}
+
+impl<W: Write> ExportStreamWriter<W> {
+    pub fn new(out: W, filter: ExportFilter) -> Self {
+        Self {
+            out,
+            filter,
+            counts: ExportCounts::default(),
+        }
+    }
+
+    /// Write every record of `batch` that passes the filter. With a kind
+    /// filter, identifiers and relationships are kept only when they belong to
+    /// an exported symbol, so the stream stays self-consistent.
+    pub fn write_batch(&mut self, batch: &ExtractedBatch) -> Result<()> {
+        let exported: HashSet<&str> = batch
+            .all_symbols
+            .iter()
+            .filter(|symbol| self.filter.keeps_symbol(symbol))
+            .map(|symbol| symbol.id.as_str())
+            .collect();
+        let unfiltered = self.filter.is_unfiltered();
+        let owned = |id: &str| unfiltered || exported.contains(id);
+
+        for symbol in &batch.all_symbols {
+            if exported.contains(symbol.id.as_str()) {
+                write_record(&mut self.out, &ExportRecord::Symbol(symbol))?;
+                self.counts.symbols += 1;
+            }
+        }
+        for identifier in &batch.all_identifiers {
+            if unfiltered
+                || identifier
+                    .containing_symbol_id
+                    .as_deref()
+                    .is_some_and(owned)
+            {
+                write_record(&mut self.out, &ExportRecord::Identifier(identifier))?;
+                self.counts.identifiers += 1;
+            }
+        }
+        for relationship in &batch.all_relationships {
+            if owned(&relationship.from_symbol_id) {
+                write_record(&mut self.out, &ExportRecord::Relationship(relationship))?;
+                self.counts.relationships += 1;
+            }
+        }
+        for pending in &batch.all_pending_relationships {
+            if owned(&pending.from_symbol_id) {
+                write_record(&mut self.out, &ExportRecord::PendingRelationship(pending))?;
+                self.counts.relationships += 1;
+            }
+        }
+
+        self.counts.files_processed += batch.files_processed;
+        self.out.flush()?;
+        Ok(())
+    }
+
+    pub fn finish(mut self) -> Result<ExportCounts> {
+        self.out.flush()?;
+        Ok(self.counts)
+    }
+}
+
+fn write_record<W: Write>(out: &mut W, record: &ExportRecord<'_>) -> Result<()> {
+    serde_json::to_writer(&mut *out, record).context("failed to serialize export record")?;
+    out.write_all(b"\n")?;
+    Ok(())
+}
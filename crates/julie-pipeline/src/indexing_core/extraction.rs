@@ -1,25 +1,54 @@
 use std::collections::HashMap;
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
+use std::time::Duration;
 
 use anyhow::Result;
 use futures::stream::{self, StreamExt};
+use serde::{Deserialize, Serialize};
 use tracing::{debug, info, trace, warn};
 
 use crate::indexing_core::batch::ExtractedBatch;
+use crate::indexing_core::config_keys::annotate_config_keys;
+use crate::indexing_core::delimited_data::annotate_delimited_data_headers;
+use crate::indexing_core::dispatch::dispatch_extractor;
+use crate::indexing_core::generated_outputs::annotate_generated_symbols;
+use crate::indexing_core::manifest_dependencies::annotate_manifest_dependencies;
+use crate::indexing_core::markdown_links::link_markdown_references;
 use crate::indexing_core::normalized::{NormalizedExtractionData, normalize_extraction_results};
 use crate::indexing_core::paths::relative_path_for_storage;
+use crate::indexing_core::preprocessor::{
+    annotate_preprocessor_conditions, preprocessor_defines_for,
+};
+use crate::indexing_core::protobuf::annotate_protobuf_definitions;
+use crate::indexing_core::symbol_options::{apply_symbol_options, symbol_options_for};
 use julie_core::file_policy::{
     ExtractionMode, detect_language_for_indexing_with_content, determine_extraction_mode,
+    is_ada_language, is_build_file_language, is_fortran_language, is_godot_scene_language,
+    is_groovy_language, is_notebook_language, is_terraform_language, is_verilog_language,
+    parse_timeout, parser_skip_reason,
 };
+use julie_core::memory_budget::MemoryBudget;
+use julie_core::parser_pool::ParserPool;
+use julie_core::supervisor::{Subsystem, Supervisor, panic_message};
+use julie_core::telemetry::Telemetry;
 use julie_extractors::{ExtractionResults, Relationship, Symbol};
 
+#[derive(Debug, Serialize, Deserialize)]
 pub enum ExtractedFileDisposition {
     Parsed,
     TextOnly,
-    RepairNeeded { detail: String },
+    /// Parser-backed language indexed text-only: too large, minified, parse
+    /// timed out, or the extractor crashed.
+    Skipped {
+        reason: String,
+    },
+    RepairNeeded {
+        detail: String,
+    },
 }
 
+#[derive(Debug, Serialize, Deserialize)]
 pub struct ExtractedFileRecord {
     pub relative_path: String,
     pub language: String,
@@ -30,6 +59,8 @@ pub struct ExtractedFileRecord {
 pub struct ParserFileProcessResult {
     pub normalized: NormalizedExtractionData,
     pub file_info: julie_core::database::FileInfo,
+    /// Why the parser was skipped and the file indexed text-only, if it was.
+    pub skip_reason: Option<String>,
 }
 
 type TextFileProcessResult = (
@@ -56,17 +87,29 @@ pub async fn extract_files_for_indexing_with_records(
     files_by_language: HashMap<String, Vec<PathBuf>>,
     workspace_root: &Path,
 ) -> Result<(ExtractedBatch, Vec<ExtractedFileRecord>)> {
-    let concurrency = std::thread::available_parallelism()
-        .map(|n| n.get())
-        .unwrap_or(8);
+    // Fewer files in flight when memory is short (see `memory_budget`).
+    let concurrency = MemoryBudget::global().batch_size(
+        std::thread::available_parallelism()
+            .map(|n| n.get())
+            .unwrap_or(8),
+        1,
+    );
 
     let mut per_language_counts: HashMap<String, (usize, bool)> = HashMap::new();
     let work: Vec<(String, PathBuf, bool)> = files_by_language
         .into_iter()
         .filter(|(_, paths)| !paths.is_empty())
         .flat_map(|(language, file_paths)| {
-            let has_parser =
-                julie_extractors::language::get_tree_sitter_language(&language).is_ok();
+            let has_parser = julie_extractors::language::get_tree_sitter_language(&language)
+                .is_ok()
+                || is_notebook_language(&language)
+                || is_verilog_language(&language)
+                || is_groovy_language(&language)
+                || is_fortran_language(&language)
+                || is_ada_language(&language)
+                || is_build_file_language(&language)
+                || is_godot_scene_language(&language)
+                || is_terraform_language(&language);
             per_language_counts
                 .entry(language.clone())
                 .or_insert((file_paths.len(), has_parser));
@@ -97,6 +140,12 @@ pub async fn extract_files_for_indexing_with_records(
                 "text-only"
             }
         );
+        // One warm parser per grammar up front; the pool grows on demand.
+        // Languages extracted without a grammar (notebooks, Verilog, ...)
+        // have nothing to warm.
+        if *has_parser {
+            let _ = ParserPool::global().warm(language, 1);
+        }
     }
 
     let extract_start = std::time::Instant::now();
@@ -143,11 +192,21 @@ pub async fn extract_files_for_indexing_with_records(
                 let ParserFileProcessResult {
                     normalized,
                     file_info,
+                    skip_reason,
                 } = *result;
+                let disposition = match skip_reason {
+                    Some(reason) => {
+                        batch
+                            .parse_skips
+                            .push((relative_path.clone(), reason.clone()));
+                        ExtractedFileDisposition::Skipped { reason }
+                    }
+                    None => ExtractedFileDisposition::Parsed,
+                };
                 records.push(ExtractedFileRecord {
                     relative_path: relative_path.clone(),
                     language: file_info.language.clone(),
-                    disposition: ExtractedFileDisposition::Parsed,
+                    disposition,
                 });
                 batch.files_processed += 1;
                 trace!(
@@ -302,16 +361,26 @@ async fn process_file_with_parser_using_configs(
     workspace_root: &Path,
     configs: Arc<julie_index::search::LanguageConfigs>,
 ) -> Result<ParserFileProcessResult> {
-    process_file_with_parser_using(
+    let host_language = language.to_string();
+    let started = std::time::Instant::now();
+    let result = process_file_with_parser_using(
         file_path,
         language,
         workspace_root,
-        |relative_path, content, workspace_root_path| {
-            julie_extractors::extract_canonical(&relative_path, &content, &workspace_root_path)
+        move |relative_path, content, workspace_root_path| {
+            dispatch_extractor(
+                &host_language,
+                &relative_path,
+                &content,
+                &workspace_root_path,
+            )
         },
         configs,
+        parse_timeout(),
     )
-    .await
+    .await;
+    Telemetry::global().record_extraction(language, started.elapsed());
+    result
 }
 
 pub async fn process_file_with_parser_for_test<F>(
@@ -320,6 +389,26 @@ pub async fn process_file_with_parser_for_test<F>(
     workspace_root: &Path,
     extract: F,
 ) -> Result<ParserFileProcessResult>
+where
+    F: FnOnce(String, String, PathBuf) -> Result<ExtractionResults> + Send + 'static,
+{
+    process_file_with_parser_with_timeout_for_test(
+        file_path,
+        language,
+        workspace_root,
+        parse_timeout(),
+        extract,
+    )
+    .await
+}
+
+pub async fn process_file_with_parser_with_timeout_for_test<F>(
+    file_path: &Path,
+    language: &str,
+    workspace_root: &Path,
+    parse_timeout: Duration,
+    extract: F,
+) -> Result<ParserFileProcessResult>
 where
     F: FnOnce(String, String, PathBuf) -> Result<ExtractionResults> + Send + 'static,
 {
@@ -329,6 +418,7 @@ where
         workspace_root,
         extract,
         Arc::new(julie_index::search::LanguageConfigs::load_embedded()),
+        parse_timeout,
     )
     .await
 }
@@ -339,6 +429,7 @@ async fn process_file_with_parser_using<F>(
     workspace_root: &Path,
     extract: F,
     configs: Arc<julie_index::search::LanguageConfigs>,
+    parse_timeout: Duration,
 ) -> Result<ParserFileProcessResult>
 where
     F: FnOnce(String, String, PathBuf) -> Result<ExtractionResults> + Send + 'static,
@@ -366,20 +457,33 @@ where
 
     tracing::trace!("✅ spawn_blocking completed for: {:?}", file_path);
 
-    let language = file_info.language.as_str();
-    if determine_extraction_mode(language, &content) == ExtractionMode::TextOnly {
+    let language = file_info.language.clone();
+    let relative_path = relative_path_for_storage(file_path, workspace_root);
+    let text_only = |file_info: julie_core::database::FileInfo, skip_reason: Option<String>| {
+        ParserFileProcessResult {
+            normalized: normalize_extraction_results(
+                ExtractionResults::empty(),
+                &relative_path,
+                &language,
+                &content,
+                &configs,
+            ),
+            file_info,
+            skip_reason,
+        }
+    };
+    if determine_extraction_mode(&language, &content) == ExtractionMode::TextOnly {
         debug!(
             "⏭️  Switching to text-only indexing for {} ({})",
             file_path.display(),
             language
         );
-        return Ok(ParserFileProcessResult {
-            normalized: normalize_extraction_results(ExtractionResults::empty(), &configs),
+        return Ok(text_only(
             file_info,
-        });
+            parser_skip_reason(&language, &content),
+        ));
     }
 
-    let relative_path = relative_path_for_storage(file_path, workspace_root);
     let relative_path_clone = relative_path.clone();
     let content_clone = content.clone();
     let workspace_root_clone2 = workspace_root.to_path_buf();
@@ -389,9 +493,39 @@ where
         extract(relative_path_clone, content_clone, workspace_root_clone2)
     });
 
-    let results = match task.await {
-        Ok(result) => result?,
-        Err(e) => return Err(anyhow::anyhow!("Spawn blocking task panicked: {}", e)),
+    // A pathological file must not stall the batch. On timeout the blocking
+    // thread can't be cancelled and runs to completion in the background, but
+    // the file is indexed text-only and the pipeline moves on. A crashing
+    // extractor degrades the same way: retrying the same content would crash
+    // again.
+    let mut results = match tokio::time::timeout(parse_timeout, task).await {
+        Ok(Ok(result)) => result?,
+        Ok(Err(e)) => {
+            let reason = format!("extractor crashed: {e}");
+            if e.is_panic() {
+                // The file degrades to text-only and the next one gets a fresh
+                // blocking thread, so the parser recovers.
+                Supervisor::global().record_panic(
+                    Subsystem::Parser,
+                    &relative_path,
+                    &panic_message(e.into_panic()),
+                    true,
+                );
+            } else {
+                warn!("Extractor crashed on {}: {}", relative_path, e);
+            }
+            return Ok(text_only(file_info, Some(reason)));
+        }
+        Err(_) => {
+            warn!(
+                "Parsing {} exceeded {:?}; indexing it text-only",
+                relative_path, parse_timeout
+            );
+            return Ok(text_only(
+                file_info,
+                Some(format!("parse timed out after {parse_timeout:?}")),
+            ));
+        }
     };
 
     let extract_elapsed = extract_start.elapsed();
@@ -402,7 +536,21 @@ where
         );
     }
 
-    let normalized = normalize_extraction_results(results, &configs);
+    apply_symbol_options(&mut results, &symbol_options_for(workspace_root, &language));
+    annotate_preprocessor_conditions(
+        &mut results.symbols,
+        &content,
+        preprocessor_defines_for(workspace_root).as_ref(),
+    );
+    annotate_generated_symbols(
+        &mut results.symbols,
+        workspace_root,
+        &relative_path,
+        &content,
+    );
+    link_markdown_references(&mut results, &relative_path, &language, &content);
+    let normalized =
+        normalize_extraction_results(results, &relative_path, &language, &content, &configs);
     file_info.symbol_count = normalized.symbols.len() as i32;
 
     if normalized.symbols.len() > 10 {
@@ -424,6 +572,7 @@ where
     Ok(ParserFileProcessResult {
         normalized,
         file_info,
+        skip_reason: None,
     })
 }
 
@@ -441,7 +590,7 @@ pub async fn process_file_without_parser(
     let file_path_clone = file_path.to_path_buf();
     let workspace_root_clone = workspace_root.to_path_buf();
 
-    let (_canonical_file_path, content, file_info) = tokio::task::spawn_blocking(move || {
+    let (_canonical_file_path, content, mut file_info) = tokio::task::spawn_blocking(move || {
         tracing::trace!(
             "🔄 Inside spawn_blocking (no parser) for: {:?}",
             file_path_clone
@@ -464,5 +613,13 @@ pub async fn process_file_without_parser(
     .map_err(|e| anyhow::anyhow!("Failed to spawn blocking file I/O task: {}", e))??;
 
     trace!("Read {} bytes from file without parser", content.len());
-    Ok((Vec::new(), Vec::new(), file_info))
+    let mut symbols = Vec::new();
+    let relative_path = relative_path_for_storage(file_path, workspace_root);
+    annotate_manifest_dependencies(&mut symbols, &relative_path, &file_info.language, &content);
+    annotate_protobuf_definitions(&mut symbols, &relative_path, &file_info.language, &content);
+    annotate_config_keys(&mut symbols, &relative_path, &file_info.language, &content);
+    annotate_delimited_data_headers(&mut symbols, &relative_path, &file_info.language, &content);
+    julie_core::symbol_ids::assign_stable_symbol_ids(&mut symbols);
+    file_info.symbol_count = symbols.len() as i32;
+    Ok((symbols, Vec::new(), file_info))
 }
@@ -0,0 +1,411 @@
+//! Symbols for free-form Fortran 90/95/2003/2008 (`.f90`, `.f95`, `.f03`,
+//! `.f08`).
+//!
+//! This pass tokenizes the source with the shared [`lexer`](super::lexer),
+//! splits it into statements (joining `&` continuations and splitting on
+//! `;`) and emits modules, submodules and programs, subroutines and
+//! functions, derived types with their components and type-bound procedures,
+//! interfaces, module variables and parameters, and `use` statements as
+//! imports. Every symbol
+//! carries `{kind, ...}` under [`FORTRAN_METADATA_KEY`]: procedures add their
+//! arguments and result variable, components and variables their type and
+//! attributes, bindings the procedure they bind to.
+//!
+//! `call` statements, and references `name(...)` in executable statements
+//! whose name is neither a declared variable nor an intrinsic, record a
+//! `call` identifier and a `Calls` relationship from the enclosing procedure.
+//! `use` links the enclosing unit to the module (`Imports`), `extends(...)` a
+//! derived type to its parent (`Extends`), and `type(...)` declarations to
+//! the type they name (`Uses`). Targets defined in the same file are resolved
+//! here, matching names case-insensitively as Fortran does; the rest are left
+//! pending for the cross-file resolver.
+//!
+//! Preprocessor lines are skipped and a unit missing its `end` ends at end of
+//! file. Fixed-form sources (`.f`, `.for`) are not handled.
+
+mod declarations;
+mod references;
+mod tokenize;
+mod tokens;
+mod types;
+mod units;
+mod vocabulary;
+
+use tokenize::{split_statements, tokenize};
+use vocabulary::{
+    DECLARING_STATEMENTS, INTRINSIC_MODULES, INTRINSIC_PROCEDURES, NON_EXECUTABLE_STATEMENTS,
+    PROCEDURE_PREFIXES, STATEMENT_KEYWORDS, TYPE_KEYWORDS,
+};
+
+use std::collections::{HashMap, HashSet};
+
+use anyhow::Result;
+use julie_extractors::base::Visibility;
+use julie_extractors::{
+    ExtractionResults, Identifier, IdentifierKind, PendingRelationship, Relationship,
+    RelationshipKind, Symbol, SymbolKind,
+};
+use serde_json::{Value, json};
+
+use crate::indexing_core::lexer::{
+    Comment, Lexer, Token, TokenKind, doubled_quote_end, line_end, punct_len, word_end,
+};
+
+pub use julie_core::file_policy::{FORTRAN_LANGUAGE, is_fortran_language};
+
+/// Metadata key holding the Fortran-specific facts of a symbol.
+pub const FORTRAN_METADATA_KEY: &str = "fortran";
+
+/// Extract a free-form Fortran file.
+pub fn extract_fortran(file_path: &str, content: &str) -> Result<ExtractionResults> {
+    let (tokens, comments) = tokenize(content);
+    let mut parser = Parser {
+        file_path,
+        content,
+        tokens: &tokens,
+        comments: &comments,
+        symbols: Vec::new(),
+        identifiers: Vec::new(),
+        references: Vec::new(),
+        scopes: Vec::new(),
+        ids: HashSet::new(),
+    };
+    for (from, to) in split_statements(&tokens) {
+        parser.parse_statement(from, to);
+    }
+    parser.close_remaining();
+    Ok(parser.into_results())
+}
+
+// ---------------------------------------------------------------------------
+// Parser
+// ---------------------------------------------------------------------------
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum ScopeKind {
+    Module,
+    Submodule,
+    Program,
+    Procedure,
+    Type,
+    Interface,
+}
+
+struct Scope {
+    kind: ScopeKind,
+    /// Index into `symbols` of the symbol this scope's `end` closes, if any.
+    symbol: Option<usize>,
+    /// Past `contains`: only procedures (or type-bound bindings) follow.
+    contains: bool,
+    /// A procedure declared in an interface block: no executable part.
+    interface_body: bool,
+    /// Lowercased names declared as variables here, including dummy
+    /// arguments and the function result.
+    variables: HashSet<String>,
+    /// `private` without a list: the default of a module's entities, or of a
+    /// type's components or bindings.
+    default_private: bool,
+    /// `public :: a` / `private :: b` in a module, by lowercased name.
+    access: HashMap<String, Visibility>,
+    /// Specific procedures of a generic interface.
+    specifics: Vec<String>,
+}
+
+impl Scope {
+    fn new(kind: ScopeKind, symbol: Option<usize>) -> Self {
+        Self {
+            kind,
+            symbol,
+            contains: false,
+            interface_body: false,
+            variables: HashSet::new(),
+            default_private: false,
+            access: HashMap::new(),
+            specifics: Vec::new(),
+        }
+    }
+}
+
+/// A name used by a symbol, resolved once every definition is known.
+struct Reference {
+    /// Index into `symbols` of the referring symbol.
+    from: usize,
+    name: String,
+    kind: RelationshipKind,
+    line: u32,
+    /// Index into `identifiers` of the usage, if one was recorded.
+    identifier: Option<usize>,
+    /// Confidence when left to the cross-file resolver.
+    pending_confidence: f32,
+}
+
+/// A type declaration statement, as token ranges.
+struct Declaration {
+    /// `real(dp)`, `type(particle)`, `character(len=*)`.
+    type_spec: (usize, usize),
+    /// The name token inside `type(...)` / `class(...)`.
+    derived: Option<usize>,
+    attributes: Vec<(usize, usize)>,
+    entities: Vec<(usize, usize)>,
+}
+
+struct Parser<'a, 't> {
+    file_path: &'a str,
+    content: &'a str,
+    tokens: &'t [Token<'a>],
+    comments: &'t [Comment<'a>],
+    symbols: Vec<Symbol>,
+    identifiers: Vec<Identifier>,
+    references: Vec<Reference>,
+    scopes: Vec<Scope>,
+    ids: HashSet<String>,
+}
+
+impl<'a> Parser<'a, '_> {
+    fn parse_statement(&mut self, mut from: usize, to: usize) {
+        // Statement label `100 continue`, construct name `outer: do`.
+        if self
+            .word_at(from)
+            .is_some_and(|word| word.bytes().all(|byte| byte.is_ascii_digit()))
+        {
+            from += 1;
+        }
+        if self.word_at(from).is_some() && self.punct_at(from + 1, ":") {
+            from += 2;
+        }
+        if from >= to {
+            return;
+        }
+        if let Some(kind) = self.end_statement(from, to) {
+            self.close(kind, to - 1);
+            return;
+        }
+        match self.scopes.last().map(|scope| scope.kind) {
+            Some(ScopeKind::Type) => self.parse_type_item(from, to),
+            Some(ScopeKind::Interface) => self.parse_interface_item(from, to),
+            _ => self.parse_unit_item(from, to),
+        }
+    }
+
+    /// A statement in a program unit or procedure.
+    fn parse_unit_item(&mut self, from: usize, to: usize) {
+        let Some(word) = self.word_at(from).map(str::to_ascii_lowercase) else {
+            self.scan_references(from, to);
+            return;
+        };
+        let next = self.word_at(from + 1).map(str::to_ascii_lowercase);
+        match word.as_str() {
+            "module" if next.as_deref() == Some("procedure") => {
+                self.parse_separate_procedure(from, to)
+            }
+            "module" if next.is_some() && from + 2 == to => {
+                self.parse_program_unit(from, to, ScopeKind::Module)
+            }
+            "program" if next.is_some() && from + 2 == to => {
+                self.parse_program_unit(from, to, ScopeKind::Program)
+            }
+            "submodule" if self.punct_at(from + 1, "(") => {
+                self.parse_program_unit(from, to, ScopeKind::Submodule)
+            }
+            "use" => self.parse_use(from, to),
+            "contains" => {
+                if let Some(scope) = self.scopes.last_mut() {
+                    scope.contains = true;
+                }
+            }
+            "interface" => self.parse_interface_block(from, from + 1, to),
+            "abstract" if next.as_deref() == Some("interface") => {
+                self.parse_interface_block(from, from + 2, to)
+            }
+            "type" if self.is_type_definition(from) => self.parse_type_definition(from, to),
+            "public" | "private"
+                if matches!(
+                    self.scopes.last().map(|scope| scope.kind),
+                    Some(ScopeKind::Module | ScopeKind::Submodule)
+                ) =>
+            {
+                self.parse_access(from, to, word == "private")
+            }
+            "enumerator" => self.parse_enumerators(from, to),
+            _ => {
+                if let Some(keyword) = self.procedure_keyword(from, to) {
+                    self.parse_procedure(from, to, keyword);
+                } else if let Some(declaration) = self.declaration(from, to) {
+                    self.record_declaration(from, to, &declaration);
+                } else if DECLARING_STATEMENTS.contains(&word.as_str()) {
+                    self.declare_listed(from, to);
+                } else if !NON_EXECUTABLE_STATEMENTS.contains(&word.as_str()) {
+                    self.scan_references(from, to);
+                }
+            }
+        }
+    }
+
+    /// For `end`, `end <unit> [name]` and `end<unit> [name]`: the kind of
+    /// scope it closes, `Some(None)` for a bare `end`. Ends of block
+    /// constructs (`end do`, `end if`, ...) close no scope.
+    fn end_statement(&self, from: usize, to: usize) -> Option<Option<ScopeKind>> {
+        let word = self.word_at(from)?.to_ascii_lowercase();
+        let rest = word.strip_prefix("end")?;
+        // A variable named `end...`.
+        if ["=", "(", "%"]
+            .iter()
+            .any(|punct| self.punct_at(from + 1, punct))
+        {
+            return None;
+        }
+        let keyword = if !rest.is_empty() {
+            rest.to_string()
+        } else if from + 1 < to {
+            self.word_at(from + 1)?.to_ascii_lowercase()
+        } else {
+            return Some(None);
+        };
+        match keyword.as_str() {
+            "module" => Some(Some(ScopeKind::Module)),
+            "submodule" => Some(Some(ScopeKind::Submodule)),
+            "program" => Some(Some(ScopeKind::Program)),
+            "function" | "subroutine" | "procedure" => Some(Some(ScopeKind::Procedure)),
+            "type" => Some(Some(ScopeKind::Type)),
+            "interface" => Some(Some(ScopeKind::Interface)),
+            _ => None,
+        }
+    }
+
+    /// Close the innermost scope of `kind` (of any kind for a bare `end`)
+    /// and any still open inside it, ending them at token `last`.
+    fn close(&mut self, kind: Option<ScopeKind>, last: usize) {
+        let Some(position) = self
+            .scopes
+            .iter()
+            .rposition(|scope| kind.is_none_or(|kind| scope.kind == kind))
+        else {
+            return;
+        };
+        while self.scopes.len() > position {
+            if let Some(scope) = self.scopes.pop() {
+                self.finish_scope(scope, last);
+            }
+        }
+    }
+
+    /// Units still open at end of file end at its last token.
+    fn close_remaining(&mut self) {
+        let Some(last) = self
+            .tokens
+            .iter()
+            .rposition(|token| token.kind != TokenKind::End)
+        else {
+            return;
+        };
+        while let Some(scope) = self.scopes.pop() {
+            self.finish_scope(scope, last);
+        }
+    }
+
+    fn finish_scope(&mut self, scope: Scope, last: usize) {
+        let Some(index) = scope.symbol else {
+            return;
+        };
+        self.set_end(index, last);
+        match scope.kind {
+            ScopeKind::Module | ScopeKind::Submodule => self.apply_access(index, &scope),
+            ScopeKind::Interface if !scope.specifics.is_empty() => {
+                if let Some(Value::Object(info)) = self.symbols[index]
+                    .metadata
+                    .as_mut()
+                    .and_then(|metadata| metadata.get_mut(FORTRAN_METADATA_KEY))
+                {
+                    info.insert("procedures".to_string(), json!(scope.specifics));
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Module entities are public unless listed `private`, declared with
+    /// the `private` attribute, or the module defaults to private.
+    fn apply_access(&mut self, module: usize, scope: &Scope) {
+        let module_id = self.symbols[module].id.clone();
+        let default = if scope.default_private {
+            Visibility::Private
+        } else {
+            Visibility::Public
+        };
+        for symbol in &mut self.symbols {
+            if symbol.parent_id.as_deref() != Some(module_id.as_str())
+                || symbol.kind == SymbolKind::Import
+            {
+                continue;
+            }
+            match scope.access.get(&symbol.name.to_ascii_lowercase()) {
+                Some(access) => symbol.visibility = Some(access.clone()),
+                None if symbol.visibility.is_none() => symbol.visibility = Some(default.clone()),
+                None => {}
+            }
+        }
+    }
+
+    /// The innermost enclosing symbol: the parent of a new definition.
+    fn enclosing_symbol(&self) -> Option<usize> {
+        self.scopes.iter().rev().find_map(|scope| scope.symbol)
+    }
+
+    /// The procedure or main program whose executable part we are in.
+    fn executable_scope(&self) -> Option<usize> {
+        let scope = self.scopes.last()?;
+        let executable = match scope.kind {
+            ScopeKind::Procedure => !scope.interface_body && !scope.contains,
+            ScopeKind::Program => !scope.contains,
+            _ => false,
+        };
+        if executable { scope.symbol } else { None }
+    }
+
+    fn is_variable(&self, lowercase_name: &str) -> bool {
+        self.scopes
+            .iter()
+            .any(|scope| scope.variables.contains(lowercase_name))
+    }
+
+    fn declare_variable(&mut self, name: &str) {
+        if let Some(scope) = self.scopes.last_mut() {
+            scope.variables.insert(name.to_ascii_lowercase());
+        }
+    }
+}
+
+struct SymbolSpec {
+    name: String,
+    kind: SymbolKind,
+    fortran_kind: &'static str,
+    signature: String,
+    parent: Option<usize>,
+    doc: Option<String>,
+    visibility: Option<Visibility>,
+    extra: Value,
+}
+
+/// The facts recorded under [`FORTRAN_METADATA_KEY`].
+fn symbol_info(symbol: &Symbol) -> Option<&Value> {
+    symbol.metadata.as_ref()?.get(FORTRAN_METADATA_KEY)
+}
+
+/// A comment's text without `!` and the doc markers `!>`, `!<`, `!!`.
+/// Compiler directives (`!$omp`, `!dir$`, `!gcc$`) and rulers (`!-----`)
+/// are not documentation.
+fn comment_text(comment: &str) -> Option<String> {
+    let body = comment.strip_prefix('!')?;
+    let lowercase = body.to_ascii_lowercase();
+    if body.starts_with('$')
+        || ["dir$", "dec$", "gcc$"]
+            .iter()
+            .any(|d| lowercase.starts_with(d))
+    {
+        return None;
+    }
+    let body = body.trim_start_matches(['>', '<', '!']).trim();
+    body.chars()
+        .any(char::is_alphanumeric)
+        .then(|| body.to_string())
+}
@@ -0,0 +1,221 @@
+//! Type declaration statements and the entities they declare.
+
+use super::*;
+
+impl<'a> Parser<'a, '_> {
+    /// Parse a type declaration statement: `type-spec [, attrs ::] entities`.
+    pub(super) fn declaration(&self, from: usize, to: usize) -> Option<Declaration> {
+        let word = self.word_at(from)?.to_ascii_lowercase();
+        let (type_end, derived) = match word.as_str() {
+            "integer" | "real" | "complex" | "logical" | "character" => {
+                (self.skip_kind_selector(from + 1), None)
+            }
+            "double" => {
+                let next = self.word_at(from + 1)?.to_ascii_lowercase();
+                if next != "precision" && next != "complex" {
+                    return None;
+                }
+                (from + 2, None)
+            }
+            "type" | "class" | "procedure" if self.punct_at(from + 1, "(") => {
+                let close = self.matching_close(from + 1)?;
+                let derived =
+                    (word != "procedure" && close == from + 3 && self.word_at(from + 2).is_some())
+                        .then_some(from + 2);
+                (close + 1, derived)
+            }
+            _ => return None,
+        };
+
+        let mut cursor = type_end;
+        let mut attributes = Vec::new();
+        if self.punct_at(cursor, ",") {
+            let colons = (cursor..to).find(|&index| self.punct_at(index, "::"))?;
+            attributes = self.split_top_level(cursor + 1, colons);
+            cursor = colons + 1;
+        } else if self.punct_at(cursor, "::") {
+            cursor += 1;
+        } else if self.word_at(cursor).is_none() {
+            return None;
+        }
+        let entities: Vec<(usize, usize)> = self
+            .split_top_level(cursor, to)
+            .into_iter()
+            .filter(|&(start, _)| self.word_at(start).is_some())
+            .collect();
+        (!entities.is_empty()).then_some(Declaration {
+            type_spec: (from, type_end),
+            derived,
+            attributes,
+            entities,
+        })
+    }
+
+    /// Past a kind or length selector after a type keyword: `(kind=8)`,
+    /// `*8`, `*(*)`.
+    pub(super) fn skip_kind_selector(&self, mut cursor: usize) -> usize {
+        if self.punct_at(cursor, "*") {
+            cursor += 1;
+            if !self.punct_at(cursor, "(") {
+                return cursor + 1;
+            }
+        }
+        if self.punct_at(cursor, "(") {
+            return self
+                .matching_close(cursor)
+                .map_or(cursor + 1, |close| close + 1);
+        }
+        cursor
+    }
+
+    /// Variables and parameters declared in a module's specification part
+    /// become symbols; elsewhere the declared names are only remembered, so
+    /// array references are not mistaken for calls.
+    pub(super) fn record_declaration(&mut self, from: usize, to: usize, declaration: &Declaration) {
+        let names: Vec<&str> = declaration
+            .entities
+            .iter()
+            .filter_map(|&(start, _)| self.word_at(start))
+            .collect();
+        for name in &names {
+            self.declare_variable(name);
+        }
+        let in_module_specification = self.scopes.last().is_some_and(|scope| {
+            matches!(scope.kind, ScopeKind::Module | ScopeKind::Submodule) && !scope.contains
+        });
+        let owner = if in_module_specification {
+            let parameter = self.has_attribute(declaration, "parameter");
+            let (kind, fortran_kind) = if parameter {
+                (SymbolKind::Constant, "parameter")
+            } else {
+                (SymbolKind::Variable, "variable")
+            };
+            self.push_entities(from, to, declaration, kind, fortran_kind, None)
+        } else {
+            Vec::new()
+        };
+        self.record_type_usage(declaration, &owner);
+    }
+
+    /// One symbol per declared entity, children of the enclosing symbol.
+    pub(super) fn push_entities(
+        &mut self,
+        from: usize,
+        to: usize,
+        declaration: &Declaration,
+        kind: SymbolKind,
+        fortran_kind: &'static str,
+        default_visibility: Option<Visibility>,
+    ) -> Vec<usize> {
+        let type_text = self.text(declaration.type_spec.0, declaration.type_spec.1);
+        let attributes: Vec<String> = declaration
+            .attributes
+            .iter()
+            .map(|&(start, end)| self.text(start, end))
+            .collect();
+        let visibility = if self.has_attribute(declaration, "private") {
+            Some(Visibility::Private)
+        } else if self.has_attribute(declaration, "public") {
+            Some(Visibility::Public)
+        } else {
+            default_visibility
+        };
+        let mut head = type_text.clone();
+        for attribute in &attributes {
+            head.push_str(", ");
+            head.push_str(attribute);
+        }
+        let doc = self.item_doc(from, to - 1);
+        let parent = self.enclosing_symbol();
+
+        let mut indices = Vec::new();
+        for (position, &(start, end)) in declaration.entities.iter().enumerate() {
+            let name = self.tokens[start].text;
+            let value = self.initializer(start, end);
+            let index = self.push_symbol(
+                SymbolSpec {
+                    name: name.to_string(),
+                    kind: kind.clone(),
+                    fortran_kind,
+                    signature: format!("{head} :: {}", self.text(start, end)),
+                    parent,
+                    doc: doc.clone(),
+                    visibility: visibility.clone(),
+                    extra: json!({
+                        "type": type_text,
+                        "attributes": (!attributes.is_empty()).then_some(&attributes),
+                        "value": value,
+                    }),
+                },
+                if position == 0 { from } else { start },
+                end - 1,
+            );
+            indices.push(index);
+        }
+        indices
+    }
+
+    /// `type(name)` / `class(name)` in a declaration: a usage of the derived
+    /// type by the declared entities, or by the enclosing symbol.
+    pub(super) fn record_type_usage(&mut self, declaration: &Declaration, owners: &[usize]) {
+        let Some(token) = declaration.derived else {
+            return;
+        };
+        let Some(owner) = owners.first().copied().or_else(|| self.enclosing_symbol()) else {
+            return;
+        };
+        self.push_reference(
+            owner,
+            token,
+            RelationshipKind::Uses,
+            Some(IdentifierKind::TypeUsage),
+            0.8,
+        );
+    }
+
+    fn has_attribute(&self, declaration: &Declaration, attribute: &str) -> bool {
+        declaration.attributes.iter().any(|&(start, _)| {
+            self.word_at(start)
+                .is_some_and(|word| word.eq_ignore_ascii_case(attribute))
+        })
+    }
+
+    /// `dimension a(10), b(n)`, `parameter (pi = 3.14)` and the like: the
+    /// names they declare are variables.
+    pub(super) fn declare_listed(&mut self, from: usize, to: usize) {
+        let mut depth = 0usize;
+        let mut names = Vec::new();
+        for index in from + 1..to {
+            let token = &self.tokens[index];
+            match (token.kind, token.text) {
+                (TokenKind::Punct, "(") => depth += 1,
+                (TokenKind::Punct, ")") => depth = depth.saturating_sub(1),
+                (TokenKind::Word, name) if depth == 0 || self.punct_at(index + 1, "=") => {
+                    names.push(name)
+                }
+                _ => {}
+            }
+        }
+        for name in names {
+            self.declare_variable(name);
+        }
+    }
+
+    /// The value after `=` or `=>` in an entity: `pi = 3.14`, `p => null()`.
+    pub(super) fn initializer(&self, start: usize, end: usize) -> Option<String> {
+        let mut depth = 0usize;
+        for index in start..end {
+            let token = &self.tokens[index];
+            if token.kind != TokenKind::Punct {
+                continue;
+            }
+            match token.text {
+                "(" | "[" => depth += 1,
+                ")" | "]" => depth = depth.saturating_sub(1),
+                "=" | "=>" if depth == 0 => return Some(self.text(index + 1, end)),
+                _ => {}
+            }
+        }
+        None
+    }
+}
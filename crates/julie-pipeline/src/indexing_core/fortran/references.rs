@@ -0,0 +1,222 @@
+//! Procedure references and their resolution.
+
+use super::*;
+
+impl<'a> Parser<'a, '_> {
+    /// Procedure references in an executable statement: `call name(...)`,
+    /// `call obj%binding(...)` and `name(...)` where `name` is not a
+    /// variable, an intrinsic or a keyword.
+    pub(super) fn scan_references(&mut self, from: usize, to: usize) {
+        let Some(caller) = self.executable_scope() else {
+            return;
+        };
+        let tokens = self.tokens;
+        for index in from..to {
+            let token = &tokens[index];
+            if token.kind != TokenKind::Word {
+                continue;
+            }
+            let previous = (index > from).then(|| &tokens[index - 1]);
+            let word = token.text.to_ascii_lowercase();
+            // `call` starts the statement or follows a logical `if (...)`.
+            if word == "call" && previous.is_none_or(|previous| previous.text == ")") {
+                if let Some(target) = self.call_target(index + 1, to) {
+                    self.push_reference(
+                        caller,
+                        target,
+                        RelationshipKind::Calls,
+                        Some(IdentifierKind::Call),
+                        0.9,
+                    );
+                }
+                continue;
+            }
+            if !self.punct_at(index + 1, "(")
+                || previous.is_some_and(|previous| {
+                    matches!(previous.text, "%" | ".") || previous.text.eq_ignore_ascii_case("call")
+                })
+                || STATEMENT_KEYWORDS.contains(&word.as_str())
+                || TYPE_KEYWORDS.contains(&word.as_str())
+                || INTRINSIC_PROCEDURES.contains(&word.as_str())
+                || self.is_variable(&word)
+            {
+                continue;
+            }
+            // An array element being assigned: `a(i) = ...`, `a(i)%x = ...`.
+            let assigned = self.matching_close(index + 1).is_some_and(|close| {
+                ["=", "=>", "%"]
+                    .iter()
+                    .any(|punct| self.punct_at(close + 1, punct))
+            });
+            if !assigned {
+                self.push_reference(
+                    caller,
+                    index,
+                    RelationshipKind::Calls,
+                    Some(IdentifierKind::Call),
+                    0.7,
+                );
+            }
+        }
+    }
+
+    /// The procedure named after `call`: the last component of
+    /// `obj%part(i)%binding`.
+    fn call_target(&self, from: usize, to: usize) -> Option<usize> {
+        let mut cursor = from;
+        let mut target = None;
+        while self.word_at(cursor).is_some() {
+            target = Some(cursor);
+            let mut next = cursor + 1;
+            if self.punct_at(next, "(") {
+                next = self.matching_close(next)? + 1;
+            }
+            if next < to && self.punct_at(next, "%") {
+                cursor = next + 1;
+            } else {
+                break;
+            }
+        }
+        target
+    }
+
+    pub(super) fn push_reference(
+        &mut self,
+        from: usize,
+        token: usize,
+        kind: RelationshipKind,
+        identifier_kind: Option<IdentifierKind>,
+        pending_confidence: f32,
+    ) {
+        let token = &self.tokens[token];
+        let identifier = identifier_kind.map(|identifier_kind| {
+            let line_start = self.content[..token.start]
+                .rfind('\n')
+                .map_or(0, |newline| newline + 1);
+            let id_source = format!("{}:fortran:usage:{}", self.file_path, token.start);
+            self.identifiers.push(Identifier {
+                id: blake3::hash(id_source.as_bytes()).to_hex()[..32].to_string(),
+                name: token.text.to_string(),
+                kind: identifier_kind,
+                language: FORTRAN_LANGUAGE.to_string(),
+                file_path: self.file_path.to_string(),
+                start_line: token.line,
+                start_column: token.column,
+                end_line: token.line,
+                end_column: token.column + (token.end - token.start) as u32,
+                start_byte: token.start as u32,
+                end_byte: token.end as u32,
+                containing_symbol_id: Some(self.symbols[from].id.clone()),
+                target_symbol_id: None,
+                confidence: 1.0,
+                code_context: self.content[line_start..]
+                    .lines()
+                    .next()
+                    .map(|line| line.trim().to_string()),
+            });
+            self.identifiers.len() - 1
+        });
+        self.references.push(Reference {
+            from,
+            name: token.text.to_string(),
+            kind,
+            line: token.line,
+            identifier,
+            pending_confidence,
+        });
+    }
+
+    /// Resolve references to procedures, modules and types defined in this
+    /// file, case-insensitively; the rest are left to the cross-file
+    /// resolver.
+    pub(super) fn into_results(mut self) -> ExtractionResults {
+        let mut procedures: HashMap<String, usize> = HashMap::new();
+        let mut bindings: HashMap<String, usize> = HashMap::new();
+        let mut modules: HashMap<String, usize> = HashMap::new();
+        let mut types: HashMap<String, usize> = HashMap::new();
+        for (index, symbol) in self.symbols.iter().enumerate() {
+            let Some(info) = symbol_info(symbol) else {
+                continue;
+            };
+            let definitions = match info["kind"].as_str() {
+                Some("function" | "subroutine" | "module_procedure")
+                    if info["interface_body"] != true =>
+                {
+                    &mut procedures
+                }
+                Some("interface") => &mut procedures,
+                Some("type_bound_procedure" | "generic_binding") => &mut bindings,
+                Some("module") => &mut modules,
+                Some("type") => &mut types,
+                _ => continue,
+            };
+            definitions
+                .entry(symbol.name.to_ascii_lowercase())
+                .or_insert(index);
+        }
+
+        let mut relationships = Vec::new();
+        let mut relationship_ids = HashSet::new();
+        let mut pending_relationships = Vec::new();
+        for reference in &self.references {
+            let name = reference.name.to_ascii_lowercase();
+            let target = match reference.kind {
+                RelationshipKind::Calls => procedures
+                    .get(&name)
+                    .or_else(|| bindings.get(&name))
+                    .map(|&target| (target, RelationshipKind::Calls))
+                    // A structure constructor: `point(1.0, 2.0)`.
+                    .or_else(|| {
+                        types
+                            .get(&name)
+                            .map(|&target| (target, RelationshipKind::Instantiates))
+                    }),
+                RelationshipKind::Imports => modules
+                    .get(&name)
+                    .map(|&target| (target, RelationshipKind::Imports)),
+                _ => types
+                    .get(&name)
+                    .map(|&target| (target, reference.kind.clone())),
+            };
+            let from_symbol_id = self.symbols[reference.from].id.clone();
+            let Some((target, kind)) = target else {
+                pending_relationships.push(PendingRelationship {
+                    from_symbol_id,
+                    callee_name: reference.name.clone(),
+                    kind: reference.kind.clone(),
+                    file_path: self.file_path.to_string(),
+                    line_number: reference.line,
+                    confidence: reference.pending_confidence,
+                });
+                continue;
+            };
+            let to_symbol_id = self.symbols[target].id.clone();
+            if let Some(identifier) = reference.identifier {
+                self.identifiers[identifier].target_symbol_id = Some(to_symbol_id.clone());
+            }
+            let id = format!(
+                "{}_{}_{:?}_{}_{}",
+                from_symbol_id, to_symbol_id, kind, self.file_path, reference.line
+            );
+            if relationship_ids.insert(id.clone()) {
+                relationships.push(Relationship {
+                    id,
+                    from_symbol_id,
+                    to_symbol_id,
+                    kind,
+                    file_path: self.file_path.to_string(),
+                    line_number: reference.line,
+                    confidence: 1.0,
+                    metadata: None,
+                });
+            }
+        }
+
+        let mut results = ExtractionResults::empty();
+        results.symbols = self.symbols;
+        results.identifiers = self.identifiers;
+        results.relationships = relationships;
+        results.pending_relationships = pending_relationships;
+        results
+    }
+}
@@ -0,0 +1,105 @@
+//! Tokenizer and statement splitting.
+
+use super::*;
+
+/// Punctuation read as a single token.
+const COMPOUND_PUNCT: &[&str] = &["::", "=>", "==", "/=", "<=", ">="];
+
+fn is_word_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || c == '_'
+}
+
+pub(super) fn tokenize(content: &str) -> (Vec<Token<'_>>, Vec<Comment<'_>>) {
+    let bytes = content.as_bytes();
+    let mut lexer = Lexer::new(content);
+    let mut i = 0;
+    // A trailing `&` continues the statement on the next code line; comment
+    // and blank lines in between do not end it.
+    let mut continued = false;
+
+    while i < bytes.len() {
+        let c = bytes[i];
+        if c == b'\n' {
+            if !continued {
+                push_end(&mut lexer, i);
+            }
+            i += 1;
+            continue;
+        }
+        if c.is_ascii_whitespace() {
+            i += 1;
+            continue;
+        }
+
+        let start = i;
+        let rest = &content[i..];
+        let line_end = line_end(content, i);
+        if c == b'#' && content[lexer.line_start_of(i)..i].trim().is_empty() {
+            // Preprocessor directive in `.F90` sources.
+            i = line_end;
+            continue;
+        }
+        if c == b'!' {
+            lexer.push_comment(start, start + content[start..line_end].trim_end().len());
+            i = line_end;
+            continue;
+        }
+        if c == b'&' {
+            // Only a trailing `&` continues; a leading one on the continuation
+            // line is just stepped over.
+            let after = content[i + 1..line_end].trim_start();
+            if after.is_empty() || after.starts_with('!') {
+                continued = true;
+            }
+            i += 1;
+            continue;
+        }
+        if c == b';' {
+            push_end(&mut lexer, i);
+            i += 1;
+            continue;
+        }
+        continued = false;
+
+        let kind = if c == b'\'' || c == b'"' {
+            i = doubled_quote_end(bytes, i + 1, line_end, c);
+            TokenKind::Str
+        } else if is_word_char(c as char) {
+            i = word_end(content, i, is_word_char);
+            TokenKind::Word
+        } else {
+            i += punct_len(rest, COMPOUND_PUNCT);
+            TokenKind::Punct
+        };
+        lexer.push(kind, start, i);
+    }
+    lexer.finish()
+}
+
+/// End the current statement at `content[at]`, unless it is already ended.
+fn push_end(lexer: &mut Lexer<'_>, at: usize) {
+    if lexer
+        .last()
+        .is_some_and(|token| token.kind != TokenKind::End)
+    {
+        lexer.push(TokenKind::End, at, at + 1);
+    }
+}
+
+/// Token ranges of the statements, without their `End` tokens.
+pub(super) fn split_statements(tokens: &[Token<'_>]) -> Vec<(usize, usize)> {
+    let mut statements = Vec::new();
+    let mut start = 0;
+    for (index, token) in tokens.iter().enumerate() {
+        if token.kind == TokenKind::End {
+            if index > start {
+                statements.push((start, index));
+            }
+            start = index + 1;
+        }
+    }
+    if tokens.len() > start {
+        statements.push((start, tokens.len()));
+    }
+    statements
+}
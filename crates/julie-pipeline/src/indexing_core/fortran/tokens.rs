@@ -0,0 +1,212 @@
+//! Token helpers, doc comments and symbol emission.
+
+use super::*;
+
+impl<'a> Parser<'a, '_> {
+    pub(super) fn word_at(&self, index: usize) -> Option<&'a str> {
+        self.tokens
+            .get(index)
+            .filter(|token| token.kind == TokenKind::Word)
+            .map(|token| token.text)
+    }
+
+    pub(super) fn punct_at(&self, index: usize, punct: &str) -> bool {
+        self.tokens
+            .get(index)
+            .is_some_and(|token| token.kind == TokenKind::Punct && token.text == punct)
+    }
+
+    /// Index of the bracket closing the one at `open`, counting `()` and
+    /// `[]` together, within the statement.
+    pub(super) fn matching_close(&self, open: usize) -> Option<usize> {
+        let mut depth = 0usize;
+        for (index, token) in self.tokens.iter().enumerate().skip(open) {
+            match (token.kind, token.text) {
+                (TokenKind::End, _) => return None,
+                (TokenKind::Punct, "(" | "[") => depth += 1,
+                (TokenKind::Punct, ")" | "]") => {
+                    depth = depth.saturating_sub(1);
+                    if depth == 0 {
+                        return Some(index);
+                    }
+                }
+                _ => {}
+            }
+        }
+        None
+    }
+
+    /// Split `tokens[from..to]` at commas outside brackets.
+    pub(super) fn split_top_level(&self, from: usize, to: usize) -> Vec<(usize, usize)> {
+        let to = to.min(self.tokens.len());
+        let mut segments = Vec::new();
+        let mut depth = 0usize;
+        let mut segment_start = from;
+        for index in from..to {
+            let token = &self.tokens[index];
+            if token.kind != TokenKind::Punct {
+                continue;
+            }
+            match token.text {
+                "(" | "[" => depth += 1,
+                ")" | "]" => depth = depth.saturating_sub(1),
+                "," if depth == 0 => {
+                    if index > segment_start {
+                        segments.push((segment_start, index));
+                    }
+                    segment_start = index + 1;
+                }
+                _ => {}
+            }
+        }
+        if to > segment_start {
+            segments.push((segment_start, to));
+        }
+        segments
+    }
+
+    /// The comma-separated items of `tokens[from..to]` as text.
+    pub(super) fn list_texts(&self, from: usize, to: usize) -> Vec<String> {
+        self.split_top_level(from, to)
+            .into_iter()
+            .map(|(start, end)| self.text(start, end))
+            .collect()
+    }
+
+    /// Source text of `tokens[from..to]`, with comments and continuation
+    /// markers dropped and whitespace collapsed to single spaces.
+    pub(super) fn text(&self, from: usize, to: usize) -> String {
+        let mut text = String::new();
+        let mut previous_end = None;
+        for token in self
+            .tokens
+            .get(from..to.min(self.tokens.len()))
+            .unwrap_or(&[])
+        {
+            if previous_end.is_some_and(|end| end < token.start) {
+                text.push(' ');
+            }
+            text.push_str(token.text);
+            previous_end = Some(token.end);
+        }
+        text
+    }
+
+    // -- comments -----------------------------------------------------------
+
+    /// The block of comments directly above `tokens[index]`, joined. Comments
+    /// trailing code, or separated from the token by a blank line, are not
+    /// documentation.
+    pub(super) fn doc_before(&self, index: usize) -> Option<String> {
+        let token = &self.tokens[index];
+        let previous_end = self.tokens[..index]
+            .iter()
+            .rev()
+            .find(|token| token.kind != TokenKind::End)
+            .map_or(0, |token| token.end);
+        let before = self
+            .comments
+            .partition_point(|comment| comment.start < token.start);
+        let mut block = Vec::new();
+        let mut next_line = token.line;
+        for comment in self.comments[..before].iter().rev() {
+            if comment.start < previous_end || comment.trailing || comment.line + 1 < next_line {
+                break;
+            }
+            block.push(comment);
+            next_line = comment.line;
+        }
+        let lines: Vec<String> = block
+            .iter()
+            .rev()
+            .filter_map(|comment| comment_text(comment.text))
+            .collect();
+        (!lines.is_empty()).then(|| lines.join("\n"))
+    }
+
+    /// Documentation for a component or variable: the comments above it, or
+    /// a comment trailing its statement (`real :: x  !< x coordinate`).
+    pub(super) fn item_doc(&self, first: usize, last: usize) -> Option<String> {
+        self.doc_before(first).or_else(|| {
+            let token = &self.tokens[last.min(self.tokens.len() - 1)];
+            self.comments
+                .iter()
+                .find(|comment| {
+                    comment.trailing && comment.line == token.line && comment.start > token.end
+                })
+                .and_then(|comment| comment_text(comment.text))
+        })
+    }
+
+    // -- symbols ------------------------------------------------------------
+
+    /// Extend a unit's span and code context through its `end` statement.
+    pub(super) fn set_end(&mut self, index: usize, last: usize) {
+        let Some(last) = self.tokens.get(last) else {
+            return;
+        };
+        let symbol = &mut self.symbols[index];
+        symbol.end_line = last.line;
+        symbol.end_column = last.column + (last.end - last.start) as u32;
+        symbol.end_byte = last.end as u32;
+        symbol.code_context = Some(self.content[symbol.start_byte as usize..last.end].to_string());
+    }
+
+    pub(super) fn push_symbol(&mut self, spec: SymbolSpec, first: usize, last: usize) -> usize {
+        let first = &self.tokens[first];
+        let last = &self.tokens[last.min(self.tokens.len() - 1)];
+        let parent = spec.parent.map(|index| &self.symbols[index]);
+        let parent_id = parent.map(|parent| parent.id.clone());
+        let full_name = match parent {
+            Some(parent) => format!("{}.{}", parent.name, spec.name),
+            None => spec.name.clone(),
+        };
+
+        let mut info = json!({ "kind": spec.fortran_kind });
+        if let (Value::Object(info), Value::Object(extra)) = (&mut info, spec.extra) {
+            info.extend(extra.into_iter().filter(|(_, value)| !value.is_null()));
+        }
+
+        // A module may `use` the same module twice; keep ids unique.
+        let base = format!(
+            "{}:fortran:{}:{}",
+            self.file_path,
+            spec.fortran_kind,
+            full_name.to_ascii_lowercase()
+        );
+        let mut id_source = base.clone();
+        let mut duplicate = 1;
+        while !self.ids.insert(id_source.clone()) {
+            duplicate += 1;
+            id_source = format!("{base}#{duplicate}");
+        }
+        let id = blake3::hash(id_source.as_bytes()).to_hex()[..32].to_string();
+
+        self.symbols.push(Symbol {
+            id,
+            name: spec.name,
+            kind: spec.kind,
+            language: FORTRAN_LANGUAGE.to_string(),
+            file_path: self.file_path.to_string(),
+            start_line: first.line,
+            start_column: first.column,
+            end_line: last.line,
+            end_column: last.column + (last.end - last.start) as u32,
+            start_byte: first.start as u32,
+            end_byte: last.end as u32,
+            signature: Some(spec.signature),
+            doc_comment: spec.doc,
+            visibility: spec.visibility,
+            parent_id,
+            metadata: Some(HashMap::from([(FORTRAN_METADATA_KEY.to_string(), info)])),
+            semantic_group: None,
+            confidence: None,
+            code_context: Some(self.content[first.start..last.end].to_string()),
+            content_type: None,
+            body_span: None,
+            body_hash: None,
+            annotations: Vec::new(),
+        });
+        self.symbols.len() - 1
+    }
+}
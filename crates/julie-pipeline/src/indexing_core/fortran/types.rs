@@ -0,0 +1,341 @@
+//! Derived types, their bindings, and interface blocks.
+
+use super::*;
+
+impl<'a> Parser<'a, '_> {
+    /// `type :: name`, `type, attrs :: name` or `type name`; not a
+    /// `type(spec)` declaration or a `type is (...)` guard.
+    pub(super) fn is_type_definition(&self, from: usize) -> bool {
+        self.punct_at(from + 1, "::")
+            || self.punct_at(from + 1, ",")
+            || self
+                .word_at(from + 1)
+                .is_some_and(|word| !word.eq_ignore_ascii_case("is"))
+    }
+
+    /// `type [, extends(parent), abstract, ... ::] name[(params)]`.
+    pub(super) fn parse_type_definition(&mut self, from: usize, to: usize) {
+        let mut cursor = from + 1;
+        let mut attributes = Vec::new();
+        if self.punct_at(cursor, ",") {
+            let Some(colons) = (cursor..to).find(|&index| self.punct_at(index, "::")) else {
+                return;
+            };
+            attributes = self.split_top_level(cursor + 1, colons);
+            cursor = colons + 1;
+        } else if self.punct_at(cursor, "::") {
+            cursor += 1;
+        }
+        let Some(name) = self.word_at(cursor) else {
+            return;
+        };
+        let parameters: Vec<&str> = self
+            .punct_at(cursor + 1, "(")
+            .then(|| self.matching_close(cursor + 1))
+            .flatten()
+            .map(|close| {
+                self.split_top_level(cursor + 2, close)
+                    .into_iter()
+                    .filter_map(|(start, _)| self.word_at(start))
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        let mut extends = None;
+        let mut is_abstract = false;
+        let mut visibility = None;
+        for &(start, _) in &attributes {
+            let Some(attribute) = self.word_at(start).map(str::to_ascii_lowercase) else {
+                continue;
+            };
+            match attribute.as_str() {
+                "extends" if self.punct_at(start + 1, "(") && self.word_at(start + 2).is_some() => {
+                    extends = Some(start + 2)
+                }
+                "abstract" => is_abstract = true,
+                "public" => visibility = Some(Visibility::Public),
+                "private" => visibility = Some(Visibility::Private),
+                _ => {}
+            }
+        }
+
+        let parent = self.enclosing_symbol();
+        let index = self.push_symbol(
+            SymbolSpec {
+                name: name.to_string(),
+                kind: SymbolKind::Struct,
+                fortran_kind: "type",
+                signature: self.text(from, to),
+                parent,
+                doc: self.doc_before(from),
+                visibility,
+                extra: json!({
+                    "extends": extends.map(|token| self.tokens[token].text),
+                    "abstract": is_abstract.then_some(true),
+                    "parameters": (!parameters.is_empty()).then_some(parameters),
+                }),
+            },
+            from,
+            to - 1,
+        );
+        if let Some(token) = extends {
+            self.push_reference(
+                index,
+                token,
+                RelationshipKind::Extends,
+                Some(IdentifierKind::TypeUsage),
+                0.9,
+            );
+        }
+        self.scopes.push(Scope::new(ScopeKind::Type, Some(index)));
+    }
+
+    /// Components before `contains`, type-bound procedures after it.
+    pub(super) fn parse_type_item(&mut self, from: usize, to: usize) {
+        let Some(word) = self.word_at(from).map(str::to_ascii_lowercase) else {
+            return;
+        };
+        let Some(scope) = self.scopes.last_mut() else {
+            return;
+        };
+        let contains = scope.contains;
+        let default_visibility = Some(if scope.default_private {
+            Visibility::Private
+        } else {
+            Visibility::Public
+        });
+        match word.as_str() {
+            "contains" => {
+                scope.contains = true;
+                scope.default_private = false;
+            }
+            "private" | "public" if from + 1 == to => scope.default_private = word == "private",
+            "procedure" if contains => self.parse_bindings(from, to, default_visibility),
+            "generic" if contains => self.parse_generic_binding(from, to, default_visibility),
+            "final" if contains => self.parse_final_bindings(from, to),
+            _ if !contains => {
+                if let Some(declaration) = self.declaration(from, to) {
+                    let fields = self.push_entities(
+                        from,
+                        to,
+                        &declaration,
+                        SymbolKind::Field,
+                        "component",
+                        default_visibility,
+                    );
+                    self.record_type_usage(&declaration, &fields);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// `procedure[(interface)] [, attrs] [::] binding [=> procedure], ...`.
+    fn parse_bindings(
+        &mut self,
+        from: usize,
+        to: usize,
+        default_visibility: Option<Visibility>,
+    ) {
+        let mut cursor = from + 1;
+        let mut interface = None;
+        if self.punct_at(cursor, "(") {
+            let Some(close) = self.matching_close(cursor) else {
+                return;
+            };
+            interface = self.word_at(cursor + 1);
+            cursor = close + 1;
+        }
+        let head_end = cursor;
+        let mut attributes = Vec::new();
+        if self.punct_at(cursor, ",") {
+            let Some(colons) = (cursor..to).find(|&index| self.punct_at(index, "::")) else {
+                return;
+            };
+            attributes = self.split_top_level(cursor + 1, colons);
+            cursor = colons + 1;
+        } else if self.punct_at(cursor, "::") {
+            cursor += 1;
+        }
+        let attribute_texts: Vec<String> = attributes
+            .iter()
+            .map(|&(start, end)| self.text(start, end))
+            .collect();
+        let lowercase_attributes: Vec<String> = attribute_texts
+            .iter()
+            .map(|attribute| attribute.to_ascii_lowercase())
+            .collect();
+        let visibility = if lowercase_attributes.iter().any(|a| a == "private") {
+            Some(Visibility::Private)
+        } else if lowercase_attributes.iter().any(|a| a == "public") {
+            Some(Visibility::Public)
+        } else {
+            default_visibility
+        };
+        let mut head = self.text(from, head_end);
+        for attribute in &attribute_texts {
+            head.push_str(", ");
+            head.push_str(attribute);
+        }
+        let doc = self.item_doc(from, to - 1);
+        let parent = self.enclosing_symbol();
+
+        for (position, (start, end)) in self.split_top_level(cursor, to).into_iter().enumerate() {
+            let Some(binding) = self.word_at(start) else {
+                continue;
+            };
+            let procedure = if self.punct_at(start + 1, "=>") {
+                self.word_at(start + 2)
+            } else {
+                None
+            };
+            self.push_symbol(
+                SymbolSpec {
+                    name: binding.to_string(),
+                    kind: SymbolKind::Method,
+                    fortran_kind: "type_bound_procedure",
+                    signature: format!("{head} :: {}", self.text(start, end)),
+                    parent,
+                    doc: doc.clone(),
+                    visibility: visibility.clone(),
+                    extra: json!({
+                        "binding": procedure.unwrap_or(binding),
+                        "interface": interface,
+                        "attributes": (!attribute_texts.is_empty()).then_some(&attribute_texts),
+                    }),
+                },
+                if position == 0 { from } else { start },
+                end - 1,
+            );
+        }
+    }
+
+    /// `generic [, access] :: spec => specific, ...`.
+    fn parse_generic_binding(
+        &mut self,
+        from: usize,
+        to: usize,
+        default_visibility: Option<Visibility>,
+    ) {
+        let Some(colons) = (from..to).find(|&index| self.punct_at(index, "::")) else {
+            return;
+        };
+        let Some(arrow) = (colons..to).find(|&index| self.punct_at(index, "=>")) else {
+            return;
+        };
+        let name = self.text(colons + 1, arrow).replace(' ', "");
+        if name.is_empty() {
+            return;
+        }
+        let visibility = self
+            .split_top_level(from + 2, colons)
+            .into_iter()
+            .find_map(
+                |(start, _)| match self.word_at(start)?.to_ascii_lowercase().as_str() {
+                    "private" => Some(Visibility::Private),
+                    "public" => Some(Visibility::Public),
+                    _ => None,
+                },
+            )
+            .or(default_visibility);
+        let specifics = self.list_texts(arrow + 1, to);
+        let parent = self.enclosing_symbol();
+        self.push_symbol(
+            SymbolSpec {
+                name,
+                kind: SymbolKind::Method,
+                fortran_kind: "generic_binding",
+                signature: self.text(from, to),
+                parent,
+                doc: self.item_doc(from, to - 1),
+                visibility,
+                extra: json!({ "procedures": specifics }),
+            },
+            from,
+            to - 1,
+        );
+    }
+
+    /// `final [::] cleanup, ...`.
+    fn parse_final_bindings(&mut self, from: usize, to: usize) {
+        let mut cursor = from + 1;
+        if self.punct_at(cursor, "::") {
+            cursor += 1;
+        }
+        let parent = self.enclosing_symbol();
+        for (start, end) in self.split_top_level(cursor, to) {
+            let Some(name) = self.word_at(start) else {
+                continue;
+            };
+            self.push_symbol(
+                SymbolSpec {
+                    name: name.to_string(),
+                    kind: SymbolKind::Destructor,
+                    fortran_kind: "final",
+                    signature: format!("final :: {name}"),
+                    parent,
+                    doc: None,
+                    visibility: None,
+                    extra: Value::Null,
+                },
+                start,
+                end - 1,
+            );
+        }
+    }
+
+    // -- interfaces ---------------------------------------------------------
+
+    /// `[abstract] interface [generic-spec]`: a named (generic) interface is
+    /// a symbol; an unnamed one only groups the interface bodies inside it.
+    /// `name_from` is the token after `interface`.
+    pub(super) fn parse_interface_block(&mut self, from: usize, name_from: usize, to: usize) {
+        let symbol = (name_from < to).then(|| {
+            let parent = self.enclosing_symbol();
+            self.push_symbol(
+                SymbolSpec {
+                    name: self.text(name_from, to).replace(' ', ""),
+                    kind: SymbolKind::Interface,
+                    fortran_kind: "interface",
+                    signature: self.text(from, to),
+                    parent,
+                    doc: self.doc_before(from),
+                    visibility: None,
+                    extra: Value::Null,
+                },
+                from,
+                to - 1,
+            )
+        });
+        self.scopes.push(Scope::new(ScopeKind::Interface, symbol));
+    }
+
+    /// Interface bodies and `[module] procedure [::] a, b` specifics.
+    pub(super) fn parse_interface_item(&mut self, from: usize, to: usize) {
+        if let Some(keyword) = self.procedure_keyword(from, to) {
+            self.parse_procedure(from, to, keyword);
+            return;
+        }
+        let mut cursor = from;
+        if self
+            .word_at(cursor)
+            .is_some_and(|word| word.eq_ignore_ascii_case("module"))
+        {
+            cursor += 1;
+        }
+        if !self
+            .word_at(cursor)
+            .is_some_and(|word| word.eq_ignore_ascii_case("procedure"))
+        {
+            return;
+        }
+        cursor += 1;
+        if self.punct_at(cursor, "::") {
+            cursor += 1;
+        }
+        let specifics = self.list_texts(cursor, to);
+        if let Some(scope) = self.scopes.last_mut() {
+            scope.specifics.extend(specifics);
+        }
+    }
+}
@@ -0,0 +1,298 @@
+//! Program units, procedures, `use` and access statements.
+
+use super::*;
+
+impl<'a> Parser<'a, '_> {
+    /// `module name`, `program name`, `submodule (ancestor[:parent]) name`.
+    pub(super) fn parse_program_unit(&mut self, from: usize, to: usize, kind: ScopeKind) {
+        let (name, fortran_kind, extra) = match kind {
+            ScopeKind::Submodule => {
+                let Some(close) = self.matching_close(from + 1) else {
+                    return;
+                };
+                let Some(name) = self.word_at(close + 1) else {
+                    return;
+                };
+                let ancestor = self.word_at(from + 2);
+                let parent = self.text(from + 2, close);
+                (
+                    name,
+                    "submodule",
+                    json!({ "ancestor": ancestor, "parent": parent }),
+                )
+            }
+            ScopeKind::Program => (self.tokens[from + 1].text, "program", Value::Null),
+            _ => (self.tokens[from + 1].text, "module", Value::Null),
+        };
+        let parent = self.enclosing_symbol();
+        let index = self.push_symbol(
+            SymbolSpec {
+                name: name.to_string(),
+                kind: SymbolKind::Module,
+                fortran_kind,
+                signature: self.text(from, to),
+                parent,
+                doc: self.doc_before(from),
+                visibility: None,
+                extra,
+            },
+            from,
+            to - 1,
+        );
+        self.scopes.push(Scope::new(kind, Some(index)));
+    }
+
+    /// The index of `function` or `subroutine` if the statement is a
+    /// procedure header: `[prefixes] [type] function name(...) [result(r)]`.
+    pub(super) fn procedure_keyword(&self, from: usize, to: usize) -> Option<usize> {
+        let mut cursor = from;
+        while cursor < to {
+            let word = self.word_at(cursor)?.to_ascii_lowercase();
+            match word.as_str() {
+                "function" | "subroutine" => {
+                    return self.word_at(cursor + 1).is_some().then_some(cursor);
+                }
+                word if PROCEDURE_PREFIXES.contains(&word) => cursor += 1,
+                word if TYPE_KEYWORDS.contains(&word) => {
+                    cursor = self.skip_kind_selector(cursor + 1)
+                }
+                _ => return None,
+            }
+        }
+        None
+    }
+
+    pub(super) fn parse_procedure(&mut self, from: usize, to: usize, keyword: usize) {
+        let fortran_kind = if self.tokens[keyword].text.eq_ignore_ascii_case("function") {
+            "function"
+        } else {
+            "subroutine"
+        };
+        let name = self.tokens[keyword + 1].text;
+        let mut cursor = keyword + 2;
+        let mut arguments = Vec::new();
+        if self.punct_at(cursor, "(") {
+            let close = self.matching_close(cursor).unwrap_or(to);
+            arguments = self
+                .split_top_level(cursor + 1, close)
+                .into_iter()
+                .filter_map(|(start, _)| self.word_at(start))
+                .collect();
+            cursor = close + 1;
+        }
+        let mut result = None;
+        let mut bind = None;
+        while cursor < to {
+            let suffix = self.word_at(cursor).map(str::to_ascii_lowercase);
+            let close = self
+                .punct_at(cursor + 1, "(")
+                .then(|| self.matching_close(cursor + 1))
+                .flatten();
+            match (suffix.as_deref(), close) {
+                (Some("result"), Some(close)) => {
+                    result = self.word_at(cursor + 2);
+                    cursor = close + 1;
+                }
+                (Some("bind"), Some(close)) => {
+                    bind = Some(self.text(cursor, close + 1));
+                    cursor = close + 1;
+                }
+                _ => cursor += 1,
+            }
+        }
+        let prefixes: Vec<String> = (from..keyword)
+            .filter_map(|index| self.word_at(index))
+            .map(str::to_ascii_lowercase)
+            .filter(|word| PROCEDURE_PREFIXES.contains(&word.as_str()))
+            .collect();
+        let interface_body =
+            self.scopes.last().map(|scope| scope.kind) == Some(ScopeKind::Interface);
+
+        let parent = self.enclosing_symbol();
+        let index = self.push_symbol(
+            SymbolSpec {
+                name: name.to_string(),
+                kind: SymbolKind::Function,
+                fortran_kind,
+                signature: self.text(from, to),
+                parent,
+                doc: self.doc_before(from),
+                visibility: None,
+                extra: json!({
+                    "arguments": arguments,
+                    "result": result,
+                    "bind": bind,
+                    "prefixes": (!prefixes.is_empty()).then_some(prefixes),
+                    "interface_body": interface_body.then_some(true),
+                }),
+            },
+            from,
+            to - 1,
+        );
+
+        let mut scope = Scope::new(ScopeKind::Procedure, Some(index));
+        scope.interface_body = interface_body;
+        scope.variables.extend(
+            arguments
+                .iter()
+                .map(|argument| argument.to_ascii_lowercase()),
+        );
+        if fortran_kind == "function" {
+            scope
+                .variables
+                .insert(result.unwrap_or(name).to_ascii_lowercase());
+        }
+        self.scopes.push(scope);
+    }
+
+    /// `module procedure name` in a submodule: the body of a separate module
+    /// procedure whose interface its ancestor module declares.
+    pub(super) fn parse_separate_procedure(&mut self, from: usize, to: usize) {
+        let Some(name) = self.word_at(from + 2) else {
+            return;
+        };
+        let parent = self.enclosing_symbol();
+        let index = self.push_symbol(
+            SymbolSpec {
+                name: name.to_string(),
+                kind: SymbolKind::Function,
+                fortran_kind: "module_procedure",
+                signature: self.text(from, to),
+                parent,
+                doc: self.doc_before(from),
+                visibility: None,
+                extra: Value::Null,
+            },
+            from,
+            to - 1,
+        );
+        let mut scope = Scope::new(ScopeKind::Procedure, Some(index));
+        // A function's result variable is its own name unless the interface
+        // says otherwise.
+        scope.variables.insert(name.to_ascii_lowercase());
+        self.scopes.push(scope);
+    }
+
+    /// `use [, intrinsic | non_intrinsic ::] name [, only: list | renames]`.
+    pub(super) fn parse_use(&mut self, from: usize, to: usize) {
+        let mut cursor = from + 1;
+        let mut nature = None;
+        if self.punct_at(cursor, ",") {
+            nature = self.word_at(cursor + 1).map(str::to_ascii_lowercase);
+            cursor += 2;
+        }
+        if self.punct_at(cursor, "::") {
+            cursor += 1;
+        }
+        let Some(module) = self.word_at(cursor) else {
+            return;
+        };
+        let module_token = cursor;
+        let mut only = None;
+        let mut renames = Vec::new();
+        if self.punct_at(cursor + 1, ",") {
+            let list_start = cursor + 2;
+            if self
+                .word_at(list_start)
+                .is_some_and(|word| word.eq_ignore_ascii_case("only"))
+                && self.punct_at(list_start + 1, ":")
+            {
+                only = Some(self.list_texts(list_start + 2, to));
+            } else {
+                renames = self.list_texts(list_start, to);
+            }
+        }
+        let intrinsic = match nature.as_deref() {
+            Some("intrinsic") => true,
+            Some(_) => false,
+            None => INTRINSIC_MODULES.contains(&module.to_ascii_lowercase().as_str()),
+        };
+
+        let parent = self.enclosing_symbol();
+        let index = self.push_symbol(
+            SymbolSpec {
+                name: module.to_string(),
+                kind: SymbolKind::Import,
+                fortran_kind: "use",
+                signature: self.text(from, to),
+                parent,
+                doc: None,
+                visibility: None,
+                extra: json!({
+                    "only": only,
+                    "renames": (!renames.is_empty()).then_some(renames),
+                    "intrinsic": intrinsic.then_some(true),
+                }),
+            },
+            from,
+            to - 1,
+        );
+        if !intrinsic {
+            self.push_reference(
+                parent.unwrap_or(index),
+                module_token,
+                RelationshipKind::Imports,
+                None,
+                0.9,
+            );
+        }
+    }
+
+    /// `public` / `private` in a module: the default without a list, or the
+    /// accessibility of the listed entities.
+    pub(super) fn parse_access(&mut self, from: usize, to: usize, private: bool) {
+        let mut cursor = from + 1;
+        if self.punct_at(cursor, "::") {
+            cursor += 1;
+        }
+        let names: Vec<String> = self
+            .list_texts(cursor, to)
+            .into_iter()
+            .map(|name| name.replace(' ', "").to_ascii_lowercase())
+            .collect();
+        let Some(scope) = self.scopes.last_mut() else {
+            return;
+        };
+        if names.is_empty() {
+            scope.default_private = private;
+            return;
+        }
+        let access = if private {
+            Visibility::Private
+        } else {
+            Visibility::Public
+        };
+        for name in names {
+            scope.access.insert(name, access.clone());
+        }
+    }
+
+    /// `enumerator [::] red = 1, green` inside `enum, bind(c)`.
+    pub(super) fn parse_enumerators(&mut self, from: usize, to: usize) {
+        let mut cursor = from + 1;
+        if self.punct_at(cursor, "::") {
+            cursor += 1;
+        }
+        let parent = self.enclosing_symbol();
+        for (start, end) in self.split_top_level(cursor, to) {
+            let Some(name) = self.word_at(start) else {
+                continue;
+            };
+            let value = self.initializer(start, end);
+            self.push_symbol(
+                SymbolSpec {
+                    name: name.to_string(),
+                    kind: SymbolKind::Constant,
+                    fortran_kind: "enumerator",
+                    signature: format!("enumerator :: {}", self.text(start, end)),
+                    parent,
+                    doc: self.item_doc(start, end - 1),
+                    visibility: None,
+                    extra: json!({ "value": value }),
+                },
+                start,
+                end - 1,
+            );
+        }
+    }
+}
@@ -0,0 +1,319 @@
+//! Keywords, intrinsic modules and intrinsic procedures.
+
+use super::*;
+
+/// Words that may precede `function` or `subroutine` in a procedure header,
+/// besides a result type.
+pub(super) const PROCEDURE_PREFIXES: &[&str] = &[
+    "pure",
+    "impure",
+    "elemental",
+    "recursive",
+    "non_recursive",
+    "module",
+];
+
+/// Keywords that start a type specifier.
+pub(super) const TYPE_KEYWORDS: &[&str] = &[
+    "integer",
+    "real",
+    "complex",
+    "logical",
+    "character",
+    "double",
+    "precision",
+    "type",
+    "class",
+];
+
+/// Intrinsic modules: `use` of one of these links to nothing.
+pub(super) const INTRINSIC_MODULES: &[&str] = &[
+    "iso_c_binding",
+    "iso_fortran_env",
+    "ieee_arithmetic",
+    "ieee_exceptions",
+    "ieee_features",
+    "omp_lib",
+    "omp_lib_kinds",
+];
+
+/// Specification statements: never executable, and the names they list are
+/// variables.
+pub(super) const DECLARING_STATEMENTS: &[&str] = &[
+    "allocatable",
+    "asynchronous",
+    "common",
+    "contiguous",
+    "data",
+    "dimension",
+    "intent",
+    "namelist",
+    "optional",
+    "parameter",
+    "pointer",
+    "protected",
+    "save",
+    "target",
+    "value",
+    "volatile",
+];
+
+/// Other statements without calls in them.
+pub(super) const NON_EXECUTABLE_STATEMENTS: &[&str] = &[
+    "implicit",
+    "import",
+    "external",
+    "intrinsic",
+    "format",
+    "include",
+    "equivalence",
+    "entry",
+    "enum",
+    "bind",
+];
+
+/// Keywords that can be followed by `(` in an executable statement.
+pub(super) const STATEMENT_KEYWORDS: &[&str] = &[
+    "if",
+    "then",
+    "else",
+    "elseif",
+    "while",
+    "do",
+    "concurrent",
+    "select",
+    "case",
+    "is",
+    "rank",
+    "default",
+    "where",
+    "elsewhere",
+    "forall",
+    "allocate",
+    "deallocate",
+    "nullify",
+    "associate",
+    "block",
+    "critical",
+    "sync",
+    "all",
+    "images",
+    "memory",
+    "team",
+    "change",
+    "form",
+    "event",
+    "post",
+    "wait",
+    "lock",
+    "unlock",
+    "fail",
+    "image",
+    "read",
+    "write",
+    "print",
+    "open",
+    "close",
+    "inquire",
+    "rewind",
+    "backspace",
+    "endfile",
+    "flush",
+    "format",
+    "return",
+    "stop",
+    "error",
+    "exit",
+    "cycle",
+    "go",
+    "goto",
+    "to",
+    "continue",
+    "call",
+    "result",
+    "procedure",
+];
+
+/// Intrinsic procedures, which are never defined in user code.
+pub(super) const INTRINSIC_PROCEDURES: &[&str] = &[
+    "abs",
+    "achar",
+    "acos",
+    "acosh",
+    "adjustl",
+    "adjustr",
+    "aimag",
+    "aint",
+    "all",
+    "allocated",
+    "anint",
+    "any",
+    "asin",
+    "asinh",
+    "associated",
+    "atan",
+    "atan2",
+    "atanh",
+    "bessel_j0",
+    "bessel_j1",
+    "bessel_jn",
+    "bessel_y0",
+    "bessel_y1",
+    "bessel_yn",
+    "bge",
+    "bgt",
+    "bit_size",
+    "ble",
+    "blt",
+    "btest",
+    "c_associated",
+    "c_f_pointer",
+    "c_f_procpointer",
+    "c_funloc",
+    "c_loc",
+    "c_sizeof",
+    "ceiling",
+    "char",
+    "cmplx",
+    "command_argument_count",
+    "conjg",
+    "cos",
+    "cosh",
+    "count",
+    "cpu_time",
+    "cshift",
+    "date_and_time",
+    "dble",
+    "digits",
+    "dim",
+    "dot_product",
+    "dprod",
+    "dshiftl",
+    "dshiftr",
+    "eoshift",
+    "epsilon",
+    "erf",
+    "erfc",
+    "erfc_scaled",
+    "execute_command_line",
+    "exp",
+    "exponent",
+    "extends_type_of",
+    "findloc",
+    "float",
+    "floor",
+    "fraction",
+    "gamma",
+    "get_command",
+    "get_command_argument",
+    "get_environment_variable",
+    "huge",
+    "hypot",
+    "iachar",
+    "iall",
+    "iand",
+    "iany",
+    "ibclr",
+    "ibits",
+    "ibset",
+    "ichar",
+    "ieor",
+    "image_index",
+    "index",
+    "int",
+    "ior",
+    "iparity",
+    "is_contiguous",
+    "is_iostat_end",
+    "is_iostat_eor",
+    "ishft",
+    "ishftc",
+    "kind",
+    "lbound",
+    "lcobound",
+    "leadz",
+    "len",
+    "len_trim",
+    "lge",
+    "lgt",
+    "lle",
+    "llt",
+    "log",
+    "log10",
+    "log_gamma",
+    "logical",
+    "maskl",
+    "maskr",
+    "matmul",
+    "max",
+    "maxexponent",
+    "maxloc",
+    "maxval",
+    "merge",
+    "merge_bits",
+    "min",
+    "minexponent",
+    "minloc",
+    "minval",
+    "mod",
+    "modulo",
+    "move_alloc",
+    "mvbits",
+    "nearest",
+    "new_line",
+    "nint",
+    "norm2",
+    "not",
+    "null",
+    "num_images",
+    "pack",
+    "parity",
+    "popcnt",
+    "poppar",
+    "precision",
+    "present",
+    "product",
+    "radix",
+    "random_number",
+    "random_seed",
+    "range",
+    "rank",
+    "real",
+    "repeat",
+    "reshape",
+    "rrspacing",
+    "same_type_as",
+    "scale",
+    "scan",
+    "selected_char_kind",
+    "selected_int_kind",
+    "selected_real_kind",
+    "set_exponent",
+    "shape",
+    "shifta",
+    "shiftl",
+    "shiftr",
+    "sign",
+    "sin",
+    "sinh",
+    "size",
+    "sngl",
+    "spacing",
+    "spread",
+    "sqrt",
+    "storage_size",
+    "sum",
+    "system_clock",
+    "tan",
+    "tanh",
+    "this_image",
+    "tiny",
+    "trailz",
+    "transfer",
+    "transpose",
+    "trim",
+    "ubound",
+    "ucobound",
+    "unpack",
+    "verify",
+];
@@ -0,0 +1,54 @@
+//! Generated-code tags.
+//!
+//! Every symbol extracted from generated code is flagged with
+//! [`IS_GENERATED_METADATA_KEY`], so search ranks it below hand-written code
+//! (see `julie_core::generated_files::detect_generated_code` for what counts).
+//!
+//! Symbols from a file under a configured `[[generated]]` output directory
+//! also record that directory and, when the table maps one, the source (IDL)
+//! file the output comes from. Navigation tools show the source next to the
+//! generated definition so changes go to the IDL, not to the output.
+
+use std::path::Path;
+
+use julie_core::generated_files::{detect_generated_code, generated_file};
+use julie_extractors::base::Symbol;
+use serde_json::json;
+
+/// Metadata key holding `{root, source}`; `source` is absent when the
+/// `[[generated]]` table does not set one.
+pub const GENERATED_METADATA_KEY: &str = "generated";
+
+/// Metadata flag set to `true` on every symbol from generated code.
+pub const IS_GENERATED_METADATA_KEY: &str = "is_generated";
+
+/// Tag the symbols of `relative_path` when it is generated code. Returns how
+/// many symbols were tagged.
+pub fn annotate_generated_symbols(
+    symbols: &mut [Symbol],
+    workspace_root: &Path,
+    relative_path: &str,
+    content: &str,
+) -> usize {
+    if symbols.is_empty() {
+        return 0;
+    }
+    if detect_generated_code(workspace_root, relative_path, content).is_none() {
+        return 0;
+    }
+    let provenance = generated_file(workspace_root, relative_path).map(|generated| {
+        let mut info = json!({ "root": generated.root });
+        if let Some(source) = generated.source {
+            info["source"] = json!(source);
+        }
+        info
+    });
+    for symbol in symbols.iter_mut() {
+        let metadata = symbol.metadata.get_or_insert_with(Default::default);
+        metadata.insert(IS_GENERATED_METADATA_KEY.to_string(), json!(true));
+        if let Some(info) = &provenance {
+            metadata.insert(GENERATED_METADATA_KEY.to_string(), info.clone());
+        }
+    }
+    symbols.len()
+}
@@ -0,0 +1,617 @@
+//! Godot scenes (`.tscn`): nodes, attached scripts and signal connections.
+//!
+//! A Godot game wires its code up in scene files. A node's `script` property
+//! attaches a script to it, and each `[connection]` section connects a signal
+//! of one node to a method of the script on another. julie-extractors has no
+//! grammar for the text scene format, so a script function called only
+//! through a signal had no references at all. This pass reads the sections of
+//! a scene and emits a module symbol for the scene (named after its stem) and
+//! one variable symbol per node, nested as in the scene tree. Every symbol
+//! carries semantic group [`GODOT_SCENE_SEMANTIC_GROUP`] and its facts under
+//! [`GODOT_METADATA_KEY`]: the scene its node and connection counts, each
+//! node its path, type, script and instanced scene.
+//!
+//! Links point into other files, so they are left pending with a callee
+//! carrying one of the prefixes below, and finalize hands them to
+//! [`resolve_scene_links`]. A node `Uses` its script (the script's top-level
+//! class, when it declares one) and the scene it instances. A connection
+//! `Calls` the handler method in the script of the receiving node, from the
+//! node that emits the signal. Resolution goes by file path, not language, so
+//! C# scripts and Lua scripts (through the Lua PluginScript) link like
+//! GDScript does.
+//!
+//! `res://` paths are relative to the Godot project, the directory holding
+//! `project.godot`, which need not be the workspace root. Each directory
+//! above the scene is tried, nearest first, and the first file with indexed
+//! symbols wins.
+//!
+//! Both the Godot 3 (`id=1`, `ExtResource( 1 )`) and the Godot 4
+//! (`id="1_abc"`, `ExtResource("1_abc")`) formats are read. Built-in scripts
+//! (`SubResource`) and receivers inside instanced scenes have no file to link
+//! and are skipped.
+
+use std::collections::{HashMap, HashSet};
+
+use anyhow::Result;
+use julie_core::database::SymbolDatabase;
+use julie_extractors::{
+    ExtractionResults, PendingRelationship, Relationship, RelationshipKind, Symbol, SymbolKind,
+};
+use serde_json::{Value, json};
+use tracing::{debug, warn};
+
+use crate::indexing_core::text_lines::TextLines;
+
+pub use julie_core::file_policy::{GODOT_SCENE_LANGUAGE, is_godot_scene_language};
+
+/// Metadata key holding the scene facts of a symbol.
+pub const GODOT_METADATA_KEY: &str = "godot";
+
+/// `semantic_group` shared by scene and node symbols.
+pub const GODOT_SCENE_SEMANTIC_GROUP: &str = "godot_scene";
+
+/// Prefix on the `callee_name` of a pending link from a node to its script.
+/// The rest of the name is the script's `res://` path.
+pub const SCRIPT_CALLEE_PREFIX: &str = "godot-script:";
+
+/// Prefix on the `callee_name` of a pending link from a node to the scene it
+/// instances. The rest of the name is the scene's `res://` path.
+pub const SCENE_CALLEE_PREFIX: &str = "godot-scene:";
+
+/// Prefix on the `callee_name` of a pending link from a signal connection to
+/// its handler. The rest of the name is `<script res:// path>#<method>`.
+pub const METHOD_CALLEE_PREFIX: &str = "godot-method:";
+
+const RES_SCHEME: &str = "res://";
+
+/// One `[section]` of a scene file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SceneSection {
+    /// `gd_scene`, `ext_resource`, `node`, `connection`, ...
+    pub tag: String,
+    /// Header attributes in order, string values unquoted.
+    pub attributes: Vec<(String, String)>,
+    /// `key = value` lines below the header.
+    pub properties: Vec<SceneProperty>,
+    /// Byte span from the header to the last line of the section.
+    pub start_byte: usize,
+    pub end_byte: usize,
+}
+
+/// A property of a [`SceneSection`]. Only the first line of a value that
+/// spans several is kept.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SceneProperty {
+    pub key: String,
+    pub value: String,
+    /// Byte offset of the key.
+    pub offset: usize,
+}
+
+impl SceneSection {
+    pub fn attribute(&self, key: &str) -> Option<&str> {
+        self.attributes
+            .iter()
+            .find(|(name, _)| name == key)
+            .map(|(_, value)| value.as_str())
+    }
+
+    pub fn property(&self, key: &str) -> Option<&SceneProperty> {
+        self.properties.iter().find(|property| property.key == key)
+    }
+}
+
+/// A node of the scene tree.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SceneNode {
+    pub name: String,
+    /// Path connections name the node by: `.` for the root, `UI/Button`
+    /// below it.
+    pub path: String,
+    /// Path of the parent node; `None` for the root.
+    pub parent: Option<String>,
+    pub node_type: Option<String>,
+    /// `res://` path of the attached script and the offset of its property.
+    pub script: Option<(String, usize)>,
+    /// `res://` path of the scene this node instances.
+    pub instance: Option<String>,
+    pub start_byte: usize,
+    pub end_byte: usize,
+}
+
+/// A `[connection]` of a signal to a handler method.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SceneConnection {
+    pub signal: String,
+    /// Path of the emitting node.
+    pub from: String,
+    /// Path of the receiving node.
+    pub to: String,
+    pub method: String,
+    pub start_byte: usize,
+    pub end_byte: usize,
+}
+
+/// The nodes and connections of a scene, in file order.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct GodotScene {
+    pub nodes: Vec<SceneNode>,
+    pub connections: Vec<SceneConnection>,
+}
+
+impl GodotScene {
+    pub fn node(&self, path: &str) -> Option<&SceneNode> {
+        self.nodes.iter().find(|node| node.path == path)
+    }
+}
+
+/// Extract a Godot scene file.
+pub fn extract_godot_scene(file_path: &str, content: &str) -> Result<ExtractionResults> {
+    let sections = parse_sections(content);
+    let Some(header) = sections.first() else {
+        return Ok(ExtractionResults::empty());
+    };
+    let scene = scene_from_sections(&sections);
+    let lines = TextLines::new(content);
+
+    let file_name = file_path.rsplit('/').next().unwrap_or(file_path);
+    let stem = file_name
+        .rsplit_once('.')
+        .map_or(file_name, |(stem, _)| stem);
+    let scene_symbol = scene_symbol(
+        file_path,
+        content,
+        &lines,
+        stem,
+        SymbolKind::Module,
+        (header.start_byte, header.end_byte),
+        None,
+        format!("{file_path}:godot"),
+        json!({
+            "nodes": scene.nodes.len(),
+            "connections": scene.connections.len(),
+        }),
+    );
+
+    let mut ids: HashMap<&str, String> = HashMap::new();
+    let mut symbols = vec![scene_symbol];
+    let mut pending_relationships = Vec::new();
+    let pending = |from: &str, callee_name: String, kind, offset| PendingRelationship {
+        from_symbol_id: from.to_string(),
+        callee_name,
+        kind,
+        file_path: file_path.to_string(),
+        line_number: lines.line_of(offset),
+        confidence: 0.9,
+    };
+    for node in &scene.nodes {
+        let parent_id = match &node.parent {
+            Some(parent) => ids.get(parent.as_str()).unwrap_or(&symbols[0].id),
+            None => &symbols[0].id,
+        }
+        .clone();
+        let symbol = scene_symbol(
+            file_path,
+            content,
+            &lines,
+            &node.name,
+            SymbolKind::Variable,
+            (node.start_byte, node.end_byte),
+            Some(parent_id),
+            format!("{file_path}:godot:{}", node.path),
+            json!({
+                "path": node.path,
+                "type": node.node_type,
+                "script": node.script.as_ref().map(|(script, _)| script),
+                "instance": node.instance,
+            }),
+        );
+        if let Some((script, offset)) = &node.script {
+            pending_relationships.push(pending(
+                &symbol.id,
+                format!("{SCRIPT_CALLEE_PREFIX}{script}"),
+                RelationshipKind::Uses,
+                *offset,
+            ));
+        }
+        if let Some(instance) = &node.instance {
+            pending_relationships.push(pending(
+                &symbol.id,
+                format!("{SCENE_CALLEE_PREFIX}{instance}"),
+                RelationshipKind::Uses,
+                node.start_byte,
+            ));
+        }
+        ids.insert(node.path.as_str(), symbol.id.clone());
+        symbols.push(symbol);
+    }
+
+    for connection in &scene.connections {
+        let Some((script, _)) = scene
+            .node(&connection.to)
+            .and_then(|node| node.script.as_ref())
+        else {
+            continue;
+        };
+        // An emitter inside an instanced scene has no node symbol here; the
+        // scene itself wires the handler up.
+        let from = ids.get(connection.from.as_str()).unwrap_or(&symbols[0].id);
+        pending_relationships.push(pending(
+            from,
+            format!("{METHOD_CALLEE_PREFIX}{script}#{}", connection.method),
+            RelationshipKind::Calls,
+            connection.start_byte,
+        ));
+    }
+
+    let mut results = ExtractionResults::empty();
+    results.symbols = symbols;
+    results.pending_relationships = pending_relationships;
+    Ok(results)
+}
+
+/// Parse the nodes and connections of a scene file.
+pub fn parse_scene(content: &str) -> GodotScene {
+    scene_from_sections(&parse_sections(content))
+}
+
+fn scene_from_sections(sections: &[SceneSection]) -> GodotScene {
+    let resources: HashMap<&str, &str> = sections
+        .iter()
+        .filter(|section| section.tag == "ext_resource")
+        .filter_map(|section| Some((section.attribute("id")?, section.attribute("path")?)))
+        .collect();
+    let resource = |value: &str| {
+        resources
+            .get(ext_resource_id(value)?)
+            .map(|path| path.to_string())
+    };
+
+    let mut scene = GodotScene::default();
+    let mut paths = HashSet::new();
+    for section in sections {
+        match section.tag.as_str() {
+            "node" => {
+                let Some(name) = section.attribute("name") else {
+                    continue;
+                };
+                let parent = section.attribute("parent").map(str::to_string);
+                let path = match parent.as_deref() {
+                    None => ".".to_string(),
+                    Some(".") => name.to_string(),
+                    Some(parent) => format!("{parent}/{name}"),
+                };
+                if !paths.insert(path.clone()) {
+                    continue;
+                }
+                scene.nodes.push(SceneNode {
+                    name: name.to_string(),
+                    path,
+                    parent,
+                    node_type: section.attribute("type").map(str::to_string),
+                    script: section
+                        .property("script")
+                        .and_then(|property| Some((resource(&property.value)?, property.offset))),
+                    instance: section.attribute("instance").and_then(resource),
+                    start_byte: section.start_byte,
+                    end_byte: section.end_byte,
+                });
+            }
+            "connection" => {
+                let (Some(signal), Some(from), Some(to), Some(method)) = (
+                    section.attribute("signal"),
+                    section.attribute("from"),
+                    section.attribute("to"),
+                    section.attribute("method"),
+                ) else {
+                    continue;
+                };
+                scene.connections.push(SceneConnection {
+                    signal: signal.to_string(),
+                    from: from.to_string(),
+                    to: to.to_string(),
+                    method: method.to_string(),
+                    start_byte: section.start_byte,
+                    end_byte: section.end_byte,
+                });
+            }
+            _ => {}
+        }
+    }
+    scene
+}
+
+/// Split a scene file into its sections. Lines inside a multi-line string
+/// value never start a section or a property.
+pub fn parse_sections(content: &str) -> Vec<SceneSection> {
+    let mut sections: Vec<SceneSection> = Vec::new();
+    let mut in_string = false;
+    let mut line_start = 0;
+    for line in content.split_inclusive('\n') {
+        let start = line_start;
+        line_start += line.len();
+        let continues_string = in_string;
+        in_string = ends_in_string(line, in_string);
+
+        let text = line.trim_end();
+        let trimmed = text.trim_start();
+        let indent = start + (text.len() - trimmed.len());
+        if trimmed.is_empty() || (!continues_string && trimmed.starts_with(';')) {
+            continue;
+        }
+        if !continues_string && let Some(header) = trimmed.strip_prefix('[') {
+            let header = header.strip_suffix(']').unwrap_or(header);
+            let (tag, attributes) = header
+                .split_once(char::is_whitespace)
+                .unwrap_or((header, ""));
+            sections.push(SceneSection {
+                tag: tag.to_string(),
+                attributes: parse_attributes(attributes),
+                properties: Vec::new(),
+                start_byte: indent,
+                end_byte: start + text.len(),
+            });
+            continue;
+        }
+        let Some(section) = sections.last_mut() else {
+            continue;
+        };
+        section.end_byte = start + text.len();
+        if continues_string {
+            continue;
+        }
+        if let Some((key, value)) = trimmed.split_once('=') {
+            let key = key.trim();
+            if is_property_key(key) {
+                section.properties.push(SceneProperty {
+                    key: key.to_string(),
+                    value: value.trim().to_string(),
+                    offset: indent,
+                });
+            }
+        }
+    }
+    sections
+}
+
+/// `script`, `theme_override_colors/font_color`, `metadata/_edit_lock_`.
+fn is_property_key(key: &str) -> bool {
+    !key.is_empty()
+        && key
+            .chars()
+            .all(|ch| ch.is_alphanumeric() || matches!(ch, '_' | '/' | ':' | '.'))
+}
+
+/// Whether a string is still open at the end of `line`.
+fn ends_in_string(line: &str, mut in_string: bool) -> bool {
+    let mut chars = line.chars();
+    while let Some(ch) = chars.next() {
+        match ch {
+            '\\' if in_string => {
+                chars.next();
+            }
+            '"' => in_string = !in_string,
+            _ => {}
+        }
+    }
+    in_string
+}
+
+/// `key=value` pairs of a section header. Values are quoted strings, bare
+/// words, or calls such as `ExtResource( 1 )` whose parentheses may hold
+/// spaces.
+fn parse_attributes(text: &str) -> Vec<(String, String)> {
+    let mut attributes = Vec::new();
+    let mut rest = text.trim_start();
+    while let Some(equals) = rest.find('=') {
+        let key = rest[..equals].trim();
+        let value_text = rest[equals + 1..].trim_start();
+        let (value, consumed) = attribute_value(value_text);
+        if !key.is_empty() {
+            attributes.push((key.to_string(), value));
+        }
+        rest = value_text[consumed..].trim_start();
+    }
+    attributes
+}
+
+/// The value at the start of `text` and how many bytes it spans.
+fn attribute_value(text: &str) -> (String, usize) {
+    let mut chars = text.char_indices();
+    if text.starts_with('"') {
+        chars.next();
+        let mut value = String::new();
+        while let Some((offset, ch)) = chars.next() {
+            match ch {
+                '\\' => {
+                    if let Some((_, escaped)) = chars.next() {
+                        value.push(escaped);
+                    }
+                }
+                '"' => return (value, offset + 1),
+                _ => value.push(ch),
+            }
+        }
+        return (value, text.len());
+    }
+
+    let mut depth = 0usize;
+    let mut in_string = false;
+    while let Some((offset, ch)) = chars.next() {
+        match ch {
+            '\\' if in_string => {
+                chars.next();
+            }
+            '"' => in_string = !in_string,
+            _ if in_string => {}
+            '(' | '[' | '{' => depth += 1,
+            ')' | ']' | '}' => depth = depth.saturating_sub(1),
+            _ if depth == 0 && ch.is_whitespace() => {
+                return (text[..offset].to_string(), offset);
+            }
+            _ => {}
+        }
+    }
+    (text.to_string(), text.len())
+}
+
+/// The id in `ExtResource("1_abc")` or `ExtResource( 1 )`.
+fn ext_resource_id(value: &str) -> Option<&str> {
+    let id = value
+        .trim()
+        .strip_prefix("ExtResource")?
+        .trim_start()
+        .strip_prefix('(')?
+        .trim_end()
+        .strip_suffix(')')?
+        .trim()
+        .trim_matches('"');
+    (!id.is_empty()).then_some(id)
+}
+
+#[allow(clippy::too_many_arguments)]
+fn scene_symbol(
+    file_path: &str,
+    content: &str,
+    lines: &TextLines,
+    name: &str,
+    kind: SymbolKind,
+    (start, end): (usize, usize),
+    parent_id: Option<String>,
+    id_source: String,
+    info: Value,
+) -> Symbol {
+    let start_line = lines.line_of(start);
+    let end_line = lines.line_of(end.saturating_sub(1).max(start));
+    Symbol {
+        id: blake3::hash(id_source.as_bytes()).to_hex()[..32].to_string(),
+        name: name.to_string(),
+        kind,
+        language: GODOT_SCENE_LANGUAGE.to_string(),
+        file_path: file_path.to_string(),
+        start_line,
+        start_column: (start - lines.line_start(start_line)) as u32,
+        end_line,
+        end_column: (end - lines.line_start(end_line)) as u32,
+        start_byte: start as u32,
+        end_byte: end as u32,
+        // The section header.
+        signature: content[start..end]
+            .lines()
+            .next()
+            .map(|header| header.trim().to_string()),
+        doc_comment: None,
+        visibility: None,
+        parent_id,
+        metadata: Some(HashMap::from([(GODOT_METADATA_KEY.to_string(), info)])),
+        semantic_group: Some(GODOT_SCENE_SEMANTIC_GROUP.to_string()),
+        confidence: None,
+        code_context: None,
+        content_type: None,
+        body_span: None,
+        body_hash: None,
+        annotations: Vec::new(),
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Cross-file links
+// ---------------------------------------------------------------------------
+
+/// Is this pending relationship a script, instance or signal link recorded by
+/// this pass?
+pub fn is_scene_link(pending: &PendingRelationship) -> bool {
+    [
+        SCRIPT_CALLEE_PREFIX,
+        SCENE_CALLEE_PREFIX,
+        METHOD_CALLEE_PREFIX,
+    ]
+    .iter()
+    .any(|prefix| pending.callee_name.starts_with(prefix))
+}
+
+/// Resolve scene links against the symbols of the files their `res://`
+/// paths name: a script's top-level classes, an instanced scene's scene
+/// symbol, and the functions and methods a connection names.
+pub fn resolve_scene_links(
+    pendings: &[PendingRelationship],
+    db: &SymbolDatabase,
+) -> Vec<Relationship> {
+    let links: Vec<&PendingRelationship> = pendings.iter().filter(|p| is_scene_link(p)).collect();
+    if links.is_empty() {
+        return Vec::new();
+    }
+
+    let mut files: HashMap<String, Vec<Symbol>> = HashMap::new();
+    let mut resolved = Vec::new();
+    for pending in &links {
+        let callee = pending.callee_name.as_str();
+        let (resource, target): (&str, fn(&Symbol, &str) -> bool) =
+            if let Some(script) = callee.strip_prefix(SCRIPT_CALLEE_PREFIX) {
+                (script, |symbol, _| {
+                    symbol.parent_id.is_none()
+                        && matches!(symbol.kind, SymbolKind::Class | SymbolKind::Module)
+                })
+            } else if let Some(scene) = callee.strip_prefix(SCENE_CALLEE_PREFIX) {
+                (scene, |symbol, _| {
+                    symbol.parent_id.is_none()
+                        && symbol.semantic_group.as_deref() == Some(GODOT_SCENE_SEMANTIC_GROUP)
+                })
+            } else if let Some(handler) = callee.strip_prefix(METHOD_CALLEE_PREFIX) {
+                let Some((script, _)) = handler.rsplit_once('#') else {
+                    continue;
+                };
+                (script, |symbol, method| {
+                    symbol.name == method
+                        && matches!(symbol.kind, SymbolKind::Function | SymbolKind::Method)
+                })
+            } else {
+                continue;
+            };
+        let method = callee.rsplit_once('#').map_or("", |(_, method)| method);
+        let Some(file) = resource_file(db, &mut files, &pending.file_path, resource) else {
+            continue;
+        };
+        resolved.extend(
+            files[&file]
+                .iter()
+                .filter(|symbol| target(symbol, method) && symbol.id != pending.from_symbol_id)
+                .map(|symbol| crate::resolver::build_resolved_relationship(pending, symbol)),
+        );
+    }
+    debug!(
+        "Linked {} Godot scene references to {} script and scene symbols",
+        links.len(),
+        resolved.len()
+    );
+    resolved
+}
+
+/// The indexed file a `res://` path in `scene_path` names, tried against
+/// each directory above the scene, nearest first. Symbols of every file
+/// tried are cached in `files`.
+fn resource_file(
+    db: &SymbolDatabase,
+    files: &mut HashMap<String, Vec<Symbol>>,
+    scene_path: &str,
+    resource: &str,
+) -> Option<String> {
+    let relative = resource.strip_prefix(RES_SCHEME)?.trim_start_matches('/');
+    let mut dir = scene_path.rsplit_once('/').map(|(dir, _)| dir);
+    loop {
+        let candidate = match dir {
+            Some(dir) => format!("{dir}/{relative}"),
+            None => relative.to_string(),
+        };
+        let symbols = files.entry(candidate.clone()).or_insert_with(|| {
+            db.get_symbols_for_file(&candidate).unwrap_or_else(|e| {
+                warn!("Failed to load symbols for {}: {}", candidate, e);
+                Vec::new()
+            })
+        });
+        if !symbols.is_empty() {
+            return Some(candidate);
+        }
+        dir = dir?.rsplit_once('/').map(|(dir, _)| dir);
+    }
+}
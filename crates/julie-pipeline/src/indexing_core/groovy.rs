@@ -0,0 +1,285 @@
+//! Symbols for Groovy sources, Gradle build scripts and Jenkinsfiles
+//! (`.groovy`, `.gvy`, `.gy`, `.gsh`, `.gradle`, `Jenkinsfile`).
+//!
+//! This pass tokenizes the source with the shared [`lexer`](super::lexer)
+//! and emits packages and imports, classes (and interfaces, traits, enums,
+//! records and annotation types) with their methods, constructors, fields and
+//! properties, script functions and variables, and closures assigned to
+//! properties. Build DSL blocks become symbols too: Gradle task declarations, plugin
+//! applications and Jenkins pipeline stages. Every symbol carries
+//! `{kind, ...}` under [`GROOVY_METADATA_KEY`].
+//!
+//! Calls inside methods, closures, tasks and stages record a `call`
+//! identifier and a `Calls` relationship, and `dependsOn`/`finalizedBy`
+//! record one from the task to each task it names, so `fast_refs` and call
+//! paths reach into build code. Supertypes become `Extends`/`Implements`
+//! links. Targets defined in the same file are resolved here; the rest are
+//! left pending for the cross-file resolver. An unclosed block runs to end
+//! of file.
+
+mod build;
+mod calls;
+mod declarations;
+mod tokenize;
+mod tokens;
+
+use tokenize::{
+    continues_line, continues_previous_line, is_keyword, is_modifier, is_primitive, string_value,
+    tokenize,
+};
+
+use std::collections::{HashMap, HashSet};
+
+use anyhow::Result;
+use julie_extractors::base::Visibility;
+use julie_extractors::{
+    ExtractionResults, Identifier, IdentifierKind, PendingRelationship, Relationship,
+    RelationshipKind, Symbol, SymbolKind,
+};
+use serde_json::{Value, json};
+
+use crate::indexing_core::lexer::{
+    Comment, Lexer, Token, TokenKind, block_end, comment_lines, line_end, punct_len, word_end,
+};
+
+pub use julie_core::file_policy::{GROOVY_LANGUAGE, is_groovy_language};
+
+/// Metadata key holding the Groovy-specific facts of a symbol.
+pub const GROOVY_METADATA_KEY: &str = "groovy";
+
+/// Gradle task methods whose arguments name other tasks.
+const TASK_DEPENDENCY_METHODS: &[&str] = &["dependsOn", "finalizedBy"];
+
+/// Extract a Groovy source, Gradle script or Jenkinsfile.
+pub fn extract_groovy(file_path: &str, content: &str) -> Result<ExtractionResults> {
+    let (tokens, comments) = tokenize(content);
+    let mut parser = Parser {
+        file_path,
+        content,
+        tokens: &tokens,
+        comments: &comments,
+        symbols: Vec::new(),
+        links: Vec::new(),
+        ids: HashSet::new(),
+    };
+    parser.parse_range(0, tokens.len(), Scope::Script, true);
+    Ok(parser.into_results())
+}
+
+// ---------------------------------------------------------------------------
+// Parser
+// ---------------------------------------------------------------------------
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Scope {
+    /// Script statements and build DSL closures.
+    Script,
+    /// The members of the type at this index.
+    Class(usize),
+    /// The body of the method, closure, task or stage at this index.
+    Body(usize),
+}
+
+impl Scope {
+    fn owner(self) -> Option<usize> {
+        match self {
+            Scope::Script => None,
+            Scope::Class(index) | Scope::Body(index) => Some(index),
+        }
+    }
+
+    /// Scope of a `{ ... }` block opened inside this one.
+    fn nested(self) -> Self {
+        match self {
+            Scope::Class(index) => Scope::Body(index),
+            other => other,
+        }
+    }
+}
+
+/// What a [`Link`] resolves to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum Target {
+    Callable,
+    Task,
+    Type,
+}
+
+/// A reference seen while parsing, resolved once every symbol is known.
+struct Link {
+    /// Index into `symbols` of the referencing symbol.
+    from: usize,
+    name: String,
+    /// Index into `tokens` of the usage.
+    token: usize,
+    kind: RelationshipKind,
+    usage: IdentifierKind,
+    target: Target,
+    /// Method that produced a task dependency (`dependsOn`).
+    via: Option<&'static str>,
+    /// Command-style calls (`deploy 'prod'`) look like any DSL statement;
+    /// they are kept only when they resolve in this file.
+    local_only: bool,
+}
+
+/// A call argument: `name: value` or a positional value.
+struct Argument<'a> {
+    name: Option<&'a str>,
+    from: usize,
+    to: usize,
+}
+
+struct Parser<'a, 't> {
+    file_path: &'a str,
+    content: &'a str,
+    tokens: &'t [Token<'a>],
+    comments: &'t [Comment<'a>],
+    symbols: Vec<Symbol>,
+    links: Vec<Link>,
+    ids: HashSet<String>,
+}
+
+impl<'a> Parser<'a, '_> {
+    /// Parse `tokens[from..to]`. `statements` is false inside `(...)` and
+    /// `[...]`, where only calls and nested blocks matter.
+    fn parse_range(&mut self, from: usize, to: usize, scope: Scope, statements: bool) {
+        let mut index = from;
+        while index < to {
+            if statements
+                && self.starts_statement(index, from)
+                && let Some(next) = self.parse_statement(index, to, scope)
+            {
+                index = next.max(index + 1);
+                continue;
+            }
+            let token = &self.tokens[index];
+            match (token.kind, token.text) {
+                (TokenKind::Punct, open @ ("{" | "(" | "[")) => {
+                    let close = self.matching_close(index).unwrap_or(to).min(to);
+                    if open == "{" {
+                        self.parse_range(index + 1, close, scope.nested(), true);
+                    } else {
+                        self.parse_range(index + 1, close, scope, false);
+                    }
+                    index = close + 1;
+                    continue;
+                }
+                (TokenKind::Word, word) => {
+                    if let Some(owner) = scope.owner()
+                        && self.is_call(index)
+                    {
+                        self.link(owner, word, index, Target::Callable, false);
+                    }
+                }
+                _ => {}
+            }
+            index += 1;
+        }
+    }
+
+    fn starts_statement(&self, index: usize, from: usize) -> bool {
+        if index == from {
+            return true;
+        }
+        let previous = &self.tokens[index - 1];
+        let token = &self.tokens[index];
+        if previous.kind == TokenKind::Punct && matches!(previous.text, ";" | "{" | "}") {
+            return true;
+        }
+        previous.end_line < token.line
+            && !continues_line(previous)
+            && !continues_previous_line(token)
+    }
+
+    /// Parse the statement at `start` when it declares something. Returns the
+    /// index after what was consumed, or `None` to scan it as plain tokens.
+    fn parse_statement(&mut self, start: usize, to: usize, scope: Scope) -> Option<usize> {
+        let word = self.word_at(start);
+        if scope == Scope::Script {
+            match word {
+                Some("package") => return Some(self.parse_package(start, to)),
+                Some("import") => return Some(self.parse_import(start, to)),
+                _ => {}
+            }
+        }
+        if !matches!(scope, Scope::Class(_)) {
+            let parsed = match word {
+                Some("task") => self.parse_task_keyword(start, to, scope),
+                Some("tasks" | "project") => self
+                    .parse_task_registration(start, to, scope)
+                    .or_else(|| self.parse_dependency_call(start, to)),
+                Some("plugins") if self.punct_at(start + 1, "{") => {
+                    Some(self.parse_plugins(start, to, scope))
+                }
+                Some("apply") => self.parse_apply(start, to, scope),
+                Some("stage") => self.parse_stage(start, to, scope),
+                Some(_) => self
+                    .parse_task_setting(start, to, scope)
+                    .or_else(|| self.parse_dependency_call(start, to)),
+                None => None,
+            };
+            if parsed.is_some() {
+                return parsed;
+            }
+            if let Scope::Body(owner) = scope {
+                self.command_call(start, owner);
+                return None;
+            }
+        }
+        self.parse_declaration(start, to, scope)
+    }
+}
+
+/// Annotations and modifiers in front of a declaration.
+struct Header<'a> {
+    /// Index of the first token, annotations included.
+    first: usize,
+    /// Index of the first modifier or, without modifiers, the declaration.
+    signature_start: usize,
+    modifiers: Vec<&'a str>,
+    annotations: Vec<String>,
+}
+
+impl Header<'_> {
+    /// The access modifier, or `default` when there is none.
+    fn visibility(&self, default: Option<Visibility>) -> Option<Visibility> {
+        self.modifiers
+            .iter()
+            .find_map(|modifier| match *modifier {
+                "public" => Some(Visibility::Public),
+                "protected" => Some(Visibility::Protected),
+                "private" => Some(Visibility::Private),
+                _ => None,
+            })
+            .or(default)
+    }
+
+    fn extra(&self) -> Value {
+        if self.annotations.is_empty() {
+            Value::Null
+        } else {
+            json!({ "annotations": self.annotations })
+        }
+    }
+}
+
+struct SymbolSpec {
+    name: String,
+    kind: SymbolKind,
+    groovy_kind: &'static str,
+    signature: String,
+    parent: Option<usize>,
+    doc: Option<String>,
+    visibility: Option<Visibility>,
+    extra: Value,
+}
+
+/// The `kind` recorded under [`GROOVY_METADATA_KEY`].
+fn symbol_kind(symbol: &Symbol) -> Option<&str> {
+    symbol
+        .metadata
+        .as_ref()?
+        .get(GROOVY_METADATA_KEY)?
+        .get("kind")?
+        .as_str()
+}
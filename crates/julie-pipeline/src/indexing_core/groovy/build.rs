@@ -0,0 +1,463 @@
+//! Package and import statements, and the Gradle build DSL: tasks, their
+//! dependencies, plugins and Jenkins stages.
+
+use super::*;
+
+impl<'a> Parser<'a, '_> {
+    // -- script structure ---------------------------------------------------
+
+    /// `package a.b.c`
+    pub(super) fn parse_package(&mut self, start: usize, to: usize) -> usize {
+        let last = self.statement_last(start, to);
+        let name: String = self.text(start + 1, last + 1).split_whitespace().collect();
+        if !name.is_empty() {
+            let doc = self.doc_before(start);
+            self.push_symbol(
+                SymbolSpec {
+                    name,
+                    kind: SymbolKind::Namespace,
+                    groovy_kind: "package",
+                    signature: self.text(start, last + 1),
+                    parent: None,
+                    doc,
+                    visibility: None,
+                    extra: Value::Null,
+                },
+                start,
+                last,
+            );
+        }
+        last + 1
+    }
+
+    /// `import [static] a.b.C [as D]` and `import a.b.*`
+    pub(super) fn parse_import(&mut self, start: usize, to: usize) -> usize {
+        let last = self.statement_last(start, to);
+        let mut path_start = start + 1;
+        if self.word_at(path_start) == Some("static") {
+            path_start += 1;
+        }
+        let alias_index = (path_start..=last).find(|&index| self.word_at(index) == Some("as"));
+        let path: String = self
+            .text(path_start, alias_index.unwrap_or(last + 1))
+            .split_whitespace()
+            .collect();
+        let name = match alias_index.and_then(|index| self.word_at(index + 1)) {
+            Some(alias) => alias.to_string(),
+            None => match path.strip_suffix(".*") {
+                Some(package) => package.to_string(),
+                None => path.rsplit('.').next().unwrap_or_default().to_string(),
+            },
+        };
+        if !name.is_empty() {
+            self.push_symbol(
+                SymbolSpec {
+                    name,
+                    kind: SymbolKind::Import,
+                    groovy_kind: "import",
+                    signature: self.text(start, last + 1),
+                    parent: None,
+                    doc: None,
+                    visibility: None,
+                    extra: json!({ "path": path }),
+                },
+                start,
+                last,
+            );
+        }
+        last + 1
+    }
+
+    // -- build DSL ----------------------------------------------------------
+
+    /// `task name`, `task name(type: Copy) { ... }`, `task('name')`.
+    pub(super) fn parse_task_keyword(
+        &mut self,
+        start: usize,
+        to: usize,
+        scope: Scope,
+    ) -> Option<usize> {
+        let mut cursor = start + 1;
+        let mut name = None;
+        if let Some(word) = self.word_at(cursor).filter(|word| !is_keyword(word)) {
+            name = Some(word.to_string());
+            cursor += 1;
+        } else if let Some(value) = self.string_at(cursor) {
+            name = Some(value.to_string());
+            cursor += 1;
+        }
+        let mut args = None;
+        if self.punct_at(cursor, "(") {
+            let close = self.matching_close(cursor)?;
+            args = Some((cursor + 1, close));
+            cursor = close + 1;
+        }
+        if name.is_none() && args.is_none() {
+            return None;
+        }
+        // Legacy `task name << { ... }`.
+        if self.punct_at(cursor, "<") && self.punct_at(cursor + 1, "<") {
+            cursor += 2;
+        }
+        self.finish_task(start, name, args, cursor, to, scope)
+    }
+
+    /// `tasks.register('name', Type) { ... }` and `tasks.create(...)`,
+    /// optionally through `project.`.
+    pub(super) fn parse_task_registration(
+        &mut self,
+        start: usize,
+        to: usize,
+        scope: Scope,
+    ) -> Option<usize> {
+        let mut cursor = start;
+        if self.word_at(cursor) == Some("project") && self.punct_at(cursor + 1, ".") {
+            cursor += 2;
+        }
+        if self.word_at(cursor) != Some("tasks")
+            || !self.punct_at(cursor + 1, ".")
+            || !matches!(
+                self.word_at(cursor + 2),
+                Some("register" | "create" | "maybeCreate")
+            )
+            || !self.punct_at(cursor + 3, "(")
+        {
+            return None;
+        }
+        let close = self.matching_close(cursor + 3)?;
+        self.finish_task(start, None, Some((cursor + 4, close)), close + 1, to, scope)
+    }
+
+    /// Record the task whose header ends before `cursor`; `args` are the
+    /// call arguments (`type:`, `dependsOn:`, a name, a configure closure).
+    fn finish_task(
+        &mut self,
+        start: usize,
+        mut name: Option<String>,
+        args: Option<(usize, usize)>,
+        cursor: usize,
+        to: usize,
+        scope: Scope,
+    ) -> Option<usize> {
+        let mut task_type = None;
+        let mut description = None;
+        let mut group = None;
+        let mut dependencies = Vec::new();
+        let mut closure = None;
+        if let Some((from, close)) = args {
+            for argument in self.arguments(from, close) {
+                match argument.name {
+                    Some("type") => task_type = Some(self.type_text(argument.from, argument.to)),
+                    Some("dependsOn") => dependencies.push((argument.from, argument.to)),
+                    Some("description") => {
+                        description = self.first_string(argument.from, argument.to)
+                    }
+                    Some("group") => group = self.first_string(argument.from, argument.to),
+                    Some("name") if name.is_none() => {
+                        name = self.first_string(argument.from, argument.to);
+                    }
+                    Some(_) => {}
+                    None if self.punct_at(argument.from, "{") => closure = Some(argument.from),
+                    None if argument.to == argument.from + 1
+                        && name.is_none()
+                        && self.string_at(argument.from).is_some() =>
+                    {
+                        name = self.string_at(argument.from).map(str::to_string);
+                    }
+                    None if task_type.is_none() && self.word_at(argument.from).is_some() => {
+                        task_type = Some(self.type_text(argument.from, argument.to));
+                    }
+                    None => {}
+                }
+            }
+        }
+        let name = name.filter(|name| !name.is_empty())?;
+
+        let body = if self.punct_at(cursor, "{") {
+            Some(cursor)
+        } else {
+            closure
+        };
+        let body_close = body.map(|open| self.matching_close(open).unwrap_or(to).min(to));
+        let last = body_close.map_or(cursor - 1, |close| close.max(cursor - 1));
+        let signature = match (closure, args) {
+            (Some(open), Some(_)) => {
+                format!("{})", self.text(start, open).trim_end_matches([',', ' ']))
+            }
+            _ => self.text(start, cursor),
+        };
+        let doc = self.doc_before(start).or_else(|| description.clone());
+        let index = self.push_symbol(
+            SymbolSpec {
+                name,
+                kind: SymbolKind::Function,
+                groovy_kind: "task",
+                signature,
+                parent: scope.owner(),
+                doc,
+                visibility: None,
+                extra: json!({ "type": task_type, "description": description, "group": group }),
+            },
+            start,
+            last,
+        );
+        for (from, to) in dependencies {
+            self.task_dependencies(index, from, to, "dependsOn");
+        }
+        if let (Some(open), Some(close)) = (body, body_close) {
+            self.parse_range(open + 1, close, Scope::Body(index), true);
+        }
+        Some(last + 1)
+    }
+
+    /// `description = '...'`, `group '...'` and `dependsOn ...` inside a
+    /// task's configure closure.
+    pub(super) fn parse_task_setting(
+        &mut self,
+        start: usize,
+        to: usize,
+        scope: Scope,
+    ) -> Option<usize> {
+        let Scope::Body(owner) = scope else {
+            return None;
+        };
+        if symbol_kind(&self.symbols[owner]) != Some("task") {
+            return None;
+        }
+        let word = self.word_at(start)?;
+        let last = self.statement_last(start, to);
+        if let Some(via) = TASK_DEPENDENCY_METHODS
+            .iter()
+            .copied()
+            .find(|method| *method == word)
+        {
+            self.task_dependencies(owner, start + 1, last + 1, via);
+            return Some(last + 1);
+        }
+        if !matches!(word, "description" | "group") {
+            return None;
+        }
+        let value = self.first_string(start + 1, last + 1)?;
+        let symbol = &mut self.symbols[owner];
+        if word == "description" && symbol.doc_comment.is_none() {
+            symbol.doc_comment = Some(value.clone());
+        }
+        if let Some(Value::Object(info)) = symbol
+            .metadata
+            .as_mut()
+            .and_then(|metadata| metadata.get_mut(GROOVY_METADATA_KEY))
+        {
+            info.insert(word.to_string(), Value::String(value));
+        }
+        Some(last + 1)
+    }
+
+    /// `jar.dependsOn test` and `tasks.jar.finalizedBy sign` for a task
+    /// declared earlier in this file.
+    pub(super) fn parse_dependency_call(&mut self, start: usize, to: usize) -> Option<usize> {
+        let mut task_index = start;
+        if self.word_at(start) == Some("tasks") && self.punct_at(start + 1, ".") {
+            task_index = start + 2;
+        }
+        let task = self.word_at(task_index)?;
+        let method = self.word_at(task_index + 2)?;
+        let via = TASK_DEPENDENCY_METHODS
+            .iter()
+            .copied()
+            .find(|candidate| *candidate == method)?;
+        if !self.punct_at(task_index + 1, ".") {
+            return None;
+        }
+        let owner = self
+            .symbols
+            .iter()
+            .position(|symbol| symbol.name == task && symbol_kind(symbol) == Some("task"))?;
+        let last = self.statement_last(start, to);
+        self.task_dependencies(owner, task_index + 3, last + 1, via);
+        Some(last + 1)
+    }
+
+    /// Link `task` to each task named in `tokens[from..to]`: string literals
+    /// (`'jar'`, `':lib:jar'`) and references (`jar`, `tasks.jar`,
+    /// `tasks.named('jar')`).
+    fn task_dependencies(
+        &mut self,
+        task: usize,
+        from: usize,
+        to: usize,
+        via: &'static str,
+    ) {
+        for index in from..to.min(self.tokens.len()) {
+            let token = &self.tokens[index];
+            let name = match token.kind {
+                TokenKind::Str => string_value(token.text)
+                    .and_then(|value| value.rsplit(':').next())
+                    .filter(|name| !name.is_empty() && !name.contains(char::is_whitespace)),
+                TokenKind::Word
+                    if !is_keyword(token.text)
+                        && !matches!(token.text, "tasks" | "project" | "rootProject")
+                        && !self.punct_at(index + 1, ".")
+                        && !self.punct_at(index + 1, "(") =>
+                {
+                    Some(token.text)
+                }
+                _ => None,
+            };
+            if let Some(name) = name {
+                self.links.push(Link {
+                    from: task,
+                    name: name.to_string(),
+                    token: index,
+                    kind: RelationshipKind::Calls,
+                    usage: IdentifierKind::Call,
+                    target: Target::Task,
+                    via: Some(via),
+                    local_only: false,
+                });
+            }
+        }
+    }
+
+    /// `plugins { id 'java'; id 'org.x' version '1.0' apply false; groovy }`
+    pub(super) fn parse_plugins(&mut self, start: usize, to: usize, scope: Scope) -> usize {
+        let open = start + 1;
+        let close = self.matching_close(open).unwrap_or(to).min(to);
+        let mut cursor = open + 1;
+        while cursor < close {
+            let last = self.statement_last(cursor, close);
+            self.plugin_statement(cursor, last, scope);
+            cursor = last + 1;
+            while self.punct_at(cursor, ";") {
+                cursor += 1;
+            }
+        }
+        close + 1
+    }
+
+    fn plugin_statement(&mut self, start: usize, last: usize, scope: Scope) {
+        let (name, extra) = match self.word_at(start) {
+            Some("id") => {
+                let Some(id) = self.first_string(start + 1, last + 1) else {
+                    return;
+                };
+                let version = (start..last)
+                    .find(|&index| self.word_at(index) == Some("version"))
+                    .and_then(|index| self.first_string(index + 1, last + 1));
+                let applied = !(start..last).any(|index| {
+                    self.word_at(index) == Some("apply") && self.word_at(index + 1) == Some("false")
+                });
+                (id, json!({ "version": version, "apply": applied }))
+            }
+            // Version catalog entry: `alias(libs.plugins.spotless)`.
+            Some("alias") if self.punct_at(start + 1, "(") => {
+                let Some(close) = self.matching_close(start + 1) else {
+                    return;
+                };
+                let alias: String = self.text(start + 2, close).split_whitespace().collect();
+                (alias, json!({ "alias": true }))
+            }
+            // Core plugin: `java`, `groovy`, `application`.
+            Some(word) if start == last && !is_keyword(word) => (word.to_string(), Value::Null),
+            _ => return,
+        };
+        if name.is_empty() {
+            return;
+        }
+        let doc = self.doc_before(start);
+        self.push_symbol(
+            SymbolSpec {
+                name,
+                kind: SymbolKind::Import,
+                groovy_kind: "plugin",
+                signature: self.text(start, last + 1),
+                parent: scope.owner(),
+                doc,
+                visibility: None,
+                extra,
+            },
+            start,
+            last,
+        );
+    }
+
+    /// `apply plugin: 'java'`, `apply(plugin: JavaPlugin)` and
+    /// `apply from: 'gradle/publishing.gradle'`.
+    pub(super) fn parse_apply(&mut self, start: usize, to: usize, scope: Scope) -> Option<usize> {
+        let last = self.statement_last(start, to);
+        let (from, until) = if self.punct_at(start + 1, "(") {
+            (start + 2, self.matching_close(start + 1)?)
+        } else {
+            (start + 1, last + 1)
+        };
+        let mut applied = None;
+        for argument in self.arguments(from, until) {
+            let groovy_kind = match argument.name {
+                Some("plugin") => "plugin",
+                Some("from") => "script_plugin",
+                _ => continue,
+            };
+            let name = self
+                .first_string(argument.from, argument.to)
+                .unwrap_or_else(|| self.type_text(argument.from, argument.to));
+            applied = Some((name, groovy_kind));
+        }
+        let (name, groovy_kind) = applied.filter(|(name, _)| !name.is_empty())?;
+        let doc = self.doc_before(start);
+        self.push_symbol(
+            SymbolSpec {
+                name,
+                kind: SymbolKind::Import,
+                groovy_kind,
+                signature: self.text(start, last + 1),
+                parent: scope.owner(),
+                doc,
+                visibility: None,
+                extra: Value::Null,
+            },
+            start,
+            last,
+        );
+        Some(last + 1)
+    }
+
+    /// Jenkins `stage('Build') { ... }` (and legacy `stage 'Build'`).
+    pub(super) fn parse_stage(&mut self, start: usize, to: usize, scope: Scope) -> Option<usize> {
+        let mut cursor = start + 1;
+        let name_index = if self.punct_at(cursor, "(") {
+            let close = self.matching_close(cursor)?;
+            let name_index =
+                (cursor + 1..close).find(|&index| self.tokens[index].kind == TokenKind::Str)?;
+            cursor = close + 1;
+            name_index
+        } else if self.string_at(cursor).is_some() {
+            cursor += 1;
+            start + 1
+        } else {
+            return None;
+        };
+        let name = string_value(self.tokens[name_index].text)?.to_string();
+        let body = self.punct_at(cursor, "{").then_some(cursor);
+        let last = body.map_or(cursor - 1, |open| {
+            self.matching_close(open).unwrap_or(to).min(to)
+        });
+        let doc = self.doc_before(start);
+        let index = self.push_symbol(
+            SymbolSpec {
+                name,
+                kind: SymbolKind::Function,
+                groovy_kind: "stage",
+                signature: self.text(start, cursor),
+                parent: scope.owner(),
+                doc,
+                visibility: None,
+                extra: Value::Null,
+            },
+            start,
+            last,
+        );
+        if let Some(open) = body {
+            self.parse_range(open + 1, last, Scope::Body(index), true);
+        }
+        Some(last + 1)
+    }
+}
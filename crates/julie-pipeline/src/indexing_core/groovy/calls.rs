@@ -0,0 +1,155 @@
+//! Calls and their resolution.
+
+use super::*;
+
+impl<'a> Parser<'a, '_> {
+    /// `name(...)` or `receiver.name(...)`, not a declaration or `new T(...)`.
+    pub(super) fn is_call(&self, index: usize) -> bool {
+        let Some(word) = self.word_at(index) else {
+            return false;
+        };
+        if is_keyword(word) || !self.punct_at(index + 1, "(") {
+            return false;
+        }
+        match index.checked_sub(1).map(|previous| &self.tokens[previous]) {
+            Some(previous) if previous.kind == TokenKind::Word => {
+                !matches!(previous.text, "new" | "def") && !is_modifier(previous.text)
+            }
+            Some(previous) => !matches!(previous.text, ".&" | "::" | "@"),
+            None => true,
+        }
+    }
+
+    /// A parenthesis-free call at the start of a statement: `deploy 'prod'`.
+    pub(super) fn command_call(&mut self, start: usize, owner: usize) {
+        let Some(word) = self.word_at(start).filter(|word| !is_keyword(word)) else {
+            return;
+        };
+        let Some(next) = self.tokens.get(start + 1) else {
+            return;
+        };
+        let argument = next.line == self.tokens[start].line
+            && match next.kind {
+                TokenKind::Str => true,
+                TokenKind::Word => !is_keyword(next.text) && !self.punct_at(start + 2, "="),
+                _ => false,
+            };
+        if argument {
+            self.link(owner, word, start, Target::Callable, true);
+        }
+    }
+
+    pub(super) fn link(
+        &mut self,
+        from: usize,
+        name: &str,
+        token: usize,
+        target: Target,
+        local_only: bool,
+    ) {
+        self.links.push(Link {
+            from,
+            name: name.to_string(),
+            token,
+            kind: RelationshipKind::Calls,
+            usage: IdentifierKind::Call,
+            target,
+            via: None,
+            local_only,
+        });
+    }
+
+    /// Resolve links to symbols defined in this file; the rest are left to
+    /// the cross-file resolver.
+    pub(super) fn into_results(self) -> ExtractionResults {
+        let mut targets: HashMap<(Target, &str), &str> = HashMap::new();
+        for symbol in &self.symbols {
+            let target = match symbol_kind(symbol) {
+                Some("method" | "function" | "closure") => Target::Callable,
+                Some("task") => Target::Task,
+                Some("class" | "interface" | "trait" | "enum" | "record" | "annotation") => {
+                    Target::Type
+                }
+                _ => continue,
+            };
+            targets
+                .entry((target, symbol.name.as_str()))
+                .or_insert(symbol.id.as_str());
+        }
+
+        let mut identifiers = Vec::new();
+        let mut relationships = Vec::new();
+        let mut pending_relationships = Vec::new();
+        let mut relationship_ids = HashSet::new();
+        for link in &self.links {
+            let target = targets.get(&(link.target, link.name.as_str())).copied();
+            if target.is_none() && link.local_only {
+                continue;
+            }
+            let from_symbol_id = self.symbols[link.from].id.clone();
+            let token = &self.tokens[link.token];
+            let line_start = self.content[..token.start]
+                .rfind('\n')
+                .map_or(0, |newline| newline + 1);
+            let id_source = format!("{}:groovy:usage:{}", self.file_path, token.start);
+            identifiers.push(Identifier {
+                id: blake3::hash(id_source.as_bytes()).to_hex()[..32].to_string(),
+                name: link.name.clone(),
+                kind: link.usage.clone(),
+                language: GROOVY_LANGUAGE.to_string(),
+                file_path: self.file_path.to_string(),
+                start_line: token.line,
+                start_column: token.column,
+                end_line: token.end_line,
+                end_column: token.column + (token.end - token.start) as u32,
+                start_byte: token.start as u32,
+                end_byte: token.end as u32,
+                containing_symbol_id: Some(from_symbol_id.clone()),
+                target_symbol_id: target.map(str::to_string),
+                confidence: 1.0,
+                code_context: self.content[line_start..]
+                    .lines()
+                    .next()
+                    .map(|line| line.trim().to_string()),
+            });
+            let metadata = link
+                .via
+                .map(|via| HashMap::from([("via".to_string(), json!(via))]));
+            match target {
+                Some(target) => {
+                    let id = format!(
+                        "{}_{}_{:?}_{}_{}",
+                        from_symbol_id, target, link.kind, self.file_path, token.line
+                    );
+                    if relationship_ids.insert(id.clone()) {
+                        relationships.push(Relationship {
+                            id,
+                            from_symbol_id,
+                            to_symbol_id: target.to_string(),
+                            kind: link.kind.clone(),
+                            file_path: self.file_path.to_string(),
+                            line_number: token.line,
+                            confidence: 1.0,
+                            metadata,
+                        });
+                    }
+                }
+                None => pending_relationships.push(PendingRelationship {
+                    from_symbol_id,
+                    callee_name: link.name.clone(),
+                    kind: link.kind.clone(),
+                    file_path: self.file_path.to_string(),
+                    line_number: token.line,
+                    confidence: 0.9,
+                }),
+            }
+        }
+
+        let mut results = ExtractionResults::empty();
+        results.symbols = self.symbols;
+        results.identifiers = identifiers;
+        results.relationships = relationships;
+        results.pending_relationships = pending_relationships;
+        results
+    }
+}
@@ -0,0 +1,437 @@
+//! Classes, interfaces, traits, enums and their members.
+
+use super::*;
+
+impl<'a> Parser<'a, '_> {
+    /// Types, methods, fields and script functions and variables, after any
+    /// annotations and modifiers.
+    pub(super) fn parse_declaration(
+        &mut self,
+        start: usize,
+        to: usize,
+        scope: Scope,
+    ) -> Option<usize> {
+        let mut cursor = start;
+        let mut annotations = Vec::new();
+        let mut modifiers = Vec::new();
+        let mut signature_start = None;
+        loop {
+            if self.punct_at(cursor, "@")
+                && self
+                    .word_at(cursor + 1)
+                    .is_some_and(|word| word != "interface")
+            {
+                let mut next = cursor + 2;
+                while self.punct_at(next, ".") && self.word_at(next + 1).is_some() {
+                    next += 2;
+                }
+                annotations.push(self.text(cursor + 1, next));
+                if self.punct_at(next, "(") {
+                    next = self.matching_close(next)? + 1;
+                }
+                cursor = next;
+            } else if let Some(word) = self.word_at(cursor).filter(|word| is_modifier(word)) {
+                signature_start.get_or_insert(cursor);
+                modifiers.push(word);
+                cursor += 1;
+            } else {
+                break;
+            }
+        }
+        if cursor >= to {
+            return None;
+        }
+        let header = Header {
+            first: start,
+            signature_start: signature_start.unwrap_or(cursor),
+            modifiers,
+            annotations,
+        };
+
+        let type_keyword =
+            if self.punct_at(cursor, "@") && self.word_at(cursor + 1) == Some("interface") {
+                Some(("annotation", cursor + 2))
+            } else {
+                match self.word_at(cursor) {
+                    Some(keyword @ ("class" | "interface" | "trait" | "enum" | "record")) => {
+                        let keyword: &'static str = match keyword {
+                            "class" => "class",
+                            "interface" => "interface",
+                            "trait" => "trait",
+                            "enum" => "enum",
+                            _ => "record",
+                        };
+                        Some((keyword, cursor + 1))
+                    }
+                    _ => None,
+                }
+            };
+        match type_keyword {
+            Some((keyword, name_index)) => self.parse_type(&header, keyword, name_index, to, scope),
+            None => self.parse_member(&header, cursor, to, scope),
+        }
+    }
+
+    /// `class Name<T> extends Base implements A, B { ... }` and the other
+    /// type forms.
+    fn parse_type(
+        &mut self,
+        header: &Header<'a>,
+        keyword: &'static str,
+        name_index: usize,
+        to: usize,
+        scope: Scope,
+    ) -> Option<usize> {
+        let name = self.word_at(name_index).filter(|name| !is_keyword(name))?;
+        let open = (name_index + 1..to)
+            .find(|&index| {
+                self.tokens[index].kind == TokenKind::Punct
+                    && matches!(self.tokens[index].text, "{" | ";" | "}")
+            })
+            .filter(|&index| self.punct_at(index, "{"))?;
+        let close = self.matching_close(open).unwrap_or(to).min(to);
+        let kind = match keyword {
+            "interface" | "annotation" => SymbolKind::Interface,
+            "trait" => SymbolKind::Trait,
+            "enum" => SymbolKind::Enum,
+            _ => SymbolKind::Class,
+        };
+
+        // Supertypes: the last segment of each (possibly qualified or
+        // generic) name after `extends`/`implements`, outside `<...>`.
+        let mut supertypes = Vec::new();
+        let mut relation = None;
+        let mut depth = 0usize;
+        for index in name_index + 1..open {
+            let token = &self.tokens[index];
+            match (token.kind, token.text) {
+                (TokenKind::Punct, "<" | "(") => depth += 1,
+                (TokenKind::Punct, ">" | ")") => depth = depth.saturating_sub(1),
+                (TokenKind::Word, "extends") if depth == 0 => {
+                    relation = Some(RelationshipKind::Extends);
+                }
+                (TokenKind::Word, "implements") if depth == 0 => {
+                    relation = Some(RelationshipKind::Implements);
+                }
+                (TokenKind::Word, word)
+                    if depth == 0 && !is_keyword(word) && !self.punct_at(index + 1, ".") =>
+                {
+                    if let Some(relation) = &relation {
+                        supertypes.push((word, index, relation.clone()));
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        let doc = self.doc_before(header.first);
+        let index = self.push_symbol(
+            SymbolSpec {
+                name: name.to_string(),
+                kind,
+                groovy_kind: keyword,
+                signature: self.text(header.signature_start, open),
+                parent: scope.owner(),
+                doc,
+                visibility: header.visibility(Some(Visibility::Public)),
+                extra: header.extra(),
+            },
+            header.first,
+            close,
+        );
+        for (supertype, token, relation) in supertypes {
+            self.links.push(Link {
+                from: index,
+                name: supertype.to_string(),
+                token,
+                kind: relation,
+                usage: IdentifierKind::TypeUsage,
+                target: Target::Type,
+                via: None,
+                local_only: false,
+            });
+        }
+
+        let mut body = open + 1;
+        if keyword == "enum" {
+            body = self.parse_enum_constants(body, close, index);
+        }
+        self.parse_range(body, close, Scope::Class(index), true);
+        Some(close + 1)
+    }
+
+    /// `RED, GREEN('g') { ... }, BLUE;` at the top of an enum body. Returns
+    /// the index after the constants.
+    fn parse_enum_constants(&mut self, from: usize, to: usize, parent: usize) -> usize {
+        let mut cursor = from;
+        while let Some(name) = self
+            .word_at(cursor)
+            .filter(|word| !is_keyword(word) && !is_modifier(word))
+        {
+            let mut next = cursor + 1;
+            for open in ["(", "{"] {
+                if self.punct_at(next, open) {
+                    next = self.matching_close(next).map_or(to, |close| close + 1);
+                }
+            }
+            if next < to && !self.punct_at(next, ",") && !self.punct_at(next, ";") {
+                break;
+            }
+            let doc = self.doc_before(cursor);
+            self.push_symbol(
+                SymbolSpec {
+                    name: name.to_string(),
+                    kind: SymbolKind::EnumMember,
+                    groovy_kind: "enum_constant",
+                    signature: self.text(cursor, next.min(to)),
+                    parent: Some(parent),
+                    doc,
+                    visibility: Some(Visibility::Public),
+                    extra: Value::Null,
+                },
+                cursor,
+                next.min(to) - 1,
+            );
+            cursor = next;
+            if !self.punct_at(cursor, ",") {
+                break;
+            }
+            cursor += 1;
+        }
+        if self.punct_at(cursor, ";") {
+            cursor += 1;
+        }
+        cursor
+    }
+
+    /// Methods, constructors, fields, properties and closures assigned to
+    /// them; in scripts, functions and variables.
+    fn parse_member(
+        &mut self,
+        header: &Header<'a>,
+        cursor: usize,
+        to: usize,
+        scope: Scope,
+    ) -> Option<usize> {
+        let class = match scope {
+            Scope::Class(index) => Some(index),
+            _ => None,
+        };
+        if let Some(class) = class
+            && self.word_at(cursor) == Some(self.symbols[class].name.as_str())
+            && self.punct_at(cursor + 1, "(")
+        {
+            return self.parse_method(header, cursor, to, scope, true);
+        }
+
+        let name_index = match self.word_at(cursor)? {
+            "def" | "var" => cursor + 1,
+            // `static main(args)`, `private count = 0`: modifiers stand in
+            // for the type.
+            _ if !header.modifiers.is_empty()
+                && (self.punct_at(cursor + 1, "(") || self.punct_at(cursor + 1, "=")) =>
+            {
+                cursor
+            }
+            word => {
+                let type_end = self.type_end(cursor)?;
+                let looks_like_type = is_primitive(word)
+                    || self.tokens[type_end - 1]
+                        .text
+                        .starts_with(char::is_uppercase)
+                    || self.punct_at(type_end - 1, ">")
+                    || self.punct_at(type_end - 1, "]");
+                if !looks_like_type && header.modifiers.is_empty() {
+                    return None;
+                }
+                type_end
+            }
+        };
+
+        let name_token = self.tokens.get(name_index)?;
+        if self.punct_at(name_index + 1, "(") {
+            // Spock feature methods are named by a string: `def "adds"()`.
+            let named = match name_token.kind {
+                TokenKind::Word => !is_keyword(name_token.text),
+                TokenKind::Str => true,
+                _ => false,
+            };
+            if !named {
+                return None;
+            }
+            return self.parse_method(header, name_index, to, scope, false);
+        }
+        let name = self.word_at(name_index).filter(|name| !is_keyword(name))?;
+        let last = self.statement_last(name_index, to);
+        let ends_declaration = name_index == last
+            || self.punct_at(name_index + 1, "=")
+            || self.punct_at(name_index + 1, ",");
+        if !ends_declaration {
+            return None;
+        }
+
+        let doc = self.doc_before(header.first);
+        if self.punct_at(name_index + 1, "=") && self.punct_at(name_index + 2, "{") {
+            let open = name_index + 2;
+            let close = self.matching_close(open).unwrap_or(to).min(to);
+            let arrow = (open + 1..close).find(|&index| self.punct_at(index, "->"));
+            let prefix = self.text(header.signature_start, name_index + 1);
+            let signature = match arrow {
+                Some(arrow) => format!("{prefix} = {{ {} -> }}", self.text(open + 1, arrow)),
+                None => format!("{prefix} = {{ }}"),
+            };
+            let index = self.push_symbol(
+                SymbolSpec {
+                    name: name.to_string(),
+                    kind: if class.is_some() {
+                        SymbolKind::Method
+                    } else {
+                        SymbolKind::Function
+                    },
+                    groovy_kind: "closure",
+                    signature,
+                    parent: scope.owner(),
+                    doc,
+                    visibility: class.and_then(|_| header.visibility(Some(Visibility::Public))),
+                    extra: header.extra(),
+                },
+                header.first,
+                close,
+            );
+            let body_start = arrow.map_or(open + 1, |arrow| arrow + 1);
+            self.parse_range(body_start, close, Scope::Body(index), true);
+            return Some(close + 1);
+        }
+
+        let constant = header.modifiers.contains(&"static") && header.modifiers.contains(&"final");
+        let (kind, groovy_kind, visibility) = match class {
+            Some(_) if constant => (
+                SymbolKind::Constant,
+                "constant",
+                header.visibility(Some(Visibility::Public)),
+            ),
+            // Without an access modifier a class member is a property.
+            Some(_) if header.visibility(None).is_none() => {
+                (SymbolKind::Property, "property", Some(Visibility::Public))
+            }
+            Some(_) => (SymbolKind::Field, "field", header.visibility(None)),
+            None => (SymbolKind::Variable, "variable", None),
+        };
+        let index = self.push_symbol(
+            SymbolSpec {
+                name: name.to_string(),
+                kind,
+                groovy_kind,
+                signature: self.text(header.signature_start, last + 1),
+                parent: scope.owner(),
+                doc,
+                visibility,
+                extra: header.extra(),
+            },
+            header.first,
+            last,
+        );
+        if self.punct_at(name_index + 1, "=") {
+            self.parse_range(name_index + 2, last + 1, Scope::Body(index), false);
+        }
+        Some(last + 1)
+    }
+
+    /// `[type] name(params) [throws X] { ... }` with `tokens[name_index]` the
+    /// name. Abstract and interface methods have no body.
+    fn parse_method(
+        &mut self,
+        header: &Header<'a>,
+        name_index: usize,
+        to: usize,
+        scope: Scope,
+        constructor: bool,
+    ) -> Option<usize> {
+        let class = match scope {
+            Scope::Class(index) => Some(index),
+            _ => None,
+        };
+        let params_close = self.matching_close(name_index + 1)?;
+        let mut cursor = params_close + 1;
+        if self.word_at(cursor) == Some("throws") {
+            cursor += 1;
+            while self.word_at(cursor).is_some()
+                || self.punct_at(cursor, ",")
+                || self.punct_at(cursor, ".")
+            {
+                cursor += 1;
+            }
+        }
+        let body = self.punct_at(cursor, "{").then_some(cursor);
+        if body.is_none() && class.is_none() {
+            return None;
+        }
+        let last = match body {
+            Some(open) => self.matching_close(open).unwrap_or(to).min(to),
+            None => self.statement_last(params_close, to),
+        };
+
+        let token = &self.tokens[name_index];
+        let name = match token.kind {
+            TokenKind::Str => string_value(token.text).unwrap_or(token.text),
+            _ => token.text,
+        };
+        let (kind, groovy_kind) = match class {
+            Some(_) if constructor => (SymbolKind::Constructor, "constructor"),
+            Some(_) => (SymbolKind::Method, "method"),
+            None => (SymbolKind::Function, "function"),
+        };
+        let doc = self.doc_before(header.first);
+        let index = self.push_symbol(
+            SymbolSpec {
+                name: name.to_string(),
+                kind,
+                groovy_kind,
+                signature: self.text(header.signature_start, cursor),
+                parent: scope.owner(),
+                doc,
+                visibility: class.and_then(|_| header.visibility(Some(Visibility::Public))),
+                extra: header.extra(),
+            },
+            header.first,
+            last,
+        );
+        if let Some(open) = body {
+            self.parse_range(open + 1, last, Scope::Body(index), true);
+        }
+        Some(last + 1)
+    }
+
+    /// Index after the type at `start`: a qualified name with optional
+    /// `<...>` arguments and `[]` suffixes.
+    fn type_end(&self, start: usize) -> Option<usize> {
+        self.word_at(start)?;
+        let mut cursor = start + 1;
+        while self.punct_at(cursor, ".") && self.word_at(cursor + 1).is_some() {
+            cursor += 2;
+        }
+        if self.punct_at(cursor, "<") {
+            let mut depth = 0usize;
+            loop {
+                let token = self.tokens.get(cursor)?;
+                match (token.kind, token.text) {
+                    (TokenKind::Punct, "<") => depth += 1,
+                    (TokenKind::Punct, ">") => {
+                        depth -= 1;
+                        if depth == 0 {
+                            break;
+                        }
+                    }
+                    (TokenKind::Word, _) | (TokenKind::Punct, "," | "." | "?" | "[" | "]") => {}
+                    _ => return None,
+                }
+                cursor += 1;
+            }
+            cursor += 1;
+        }
+        while self.punct_at(cursor, "[") && self.punct_at(cursor + 1, "]") {
+            cursor += 2;
+        }
+        Some(cursor)
+    }
+}
@@ -0,0 +1,294 @@
+//! Tokenizer, string forms and the keyword vocabulary.
+
+use super::*;
+
+/// Operators kept as one token, longest first. `<<` and `>>` stay split so
+/// generic type arguments still balance.
+const OPERATORS: &[&str] = &[
+    "...", "->", "?.", "*.", ".&", "?:", "::", "==", "!=", "<=", ">=", "&&", "||", "..", "++",
+    "--", "+=", "-=", "*=", "/=",
+];
+
+fn is_word_start(byte: u8) -> bool {
+    byte.is_ascii_alphabetic() || byte == b'_' || byte == b'$' || byte >= 0x80
+}
+
+fn is_word_byte(byte: u8) -> bool {
+    is_word_start(byte) || byte.is_ascii_digit()
+}
+
+fn is_word_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || c == '_' || c == '$' || !c.is_ascii()
+}
+
+pub(super) fn tokenize(content: &str) -> (Vec<Token<'_>>, Vec<Comment<'_>>) {
+    let bytes = content.as_bytes();
+    let mut lexer = Lexer::new(content);
+    let mut i = 0;
+
+    // A `#!` line is only a shebang at the very start.
+    if content.starts_with("#!") {
+        i = line_end(content, 0);
+    }
+
+    while i < bytes.len() {
+        let c = bytes[i];
+        if c.is_ascii_whitespace() {
+            i += 1;
+            continue;
+        }
+
+        let rest = &content[i..];
+        if rest.starts_with("//") || rest.starts_with("/*") {
+            let end = if rest.starts_with("//") {
+                line_end(content, i)
+            } else {
+                block_end(content, i + 2, "*/")
+            };
+            lexer.push_comment(i, end);
+            i = end;
+            continue;
+        }
+
+        let (kind, end) = if c == b'"' || c == b'\'' {
+            (TokenKind::Str, string_end(content, i))
+        } else if rest.starts_with("$/") {
+            (TokenKind::Str, block_end(content, i + 2, "/$"))
+        } else if let Some(end) = (c == b'/' && slashy_allowed(lexer.last()))
+            .then(|| slashy_end(bytes, i))
+            .flatten()
+        {
+            (TokenKind::Str, end)
+        } else if c.is_ascii_digit() {
+            let mut end = i + 1;
+            while end < bytes.len()
+                && (is_word_byte(bytes[end])
+                    || (bytes[end] == b'.' && bytes.get(end + 1).is_some_and(u8::is_ascii_digit)))
+            {
+                end += 1;
+            }
+            (TokenKind::Word, end)
+        } else if is_word_start(c) {
+            (TokenKind::Word, word_end(content, i, is_word_char))
+        } else {
+            (TokenKind::Punct, i + punct_len(rest, OPERATORS))
+        };
+        lexer.push(kind, i, end);
+        i = end;
+    }
+    lexer.finish()
+}
+
+/// End (exclusive) of the quoted string starting at `start`: `'...'`,
+/// `"..."` with `${...}` interpolation, or a triple-quoted block.
+fn string_end(content: &str, start: usize) -> usize {
+    let bytes = content.as_bytes();
+    let quote = bytes[start];
+    let triple = bytes.get(start..start + 3) == Some(&[quote, quote, quote][..]);
+    let mut i = start + if triple { 3 } else { 1 };
+    let mut interpolation = 0usize;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'\\' => {
+                i += 2;
+                continue;
+            }
+            b'$' if quote == b'"' && bytes.get(i + 1) == Some(&b'{') => {
+                interpolation += 1;
+                i += 2;
+                continue;
+            }
+            b'{' if interpolation > 0 => interpolation += 1,
+            b'}' if interpolation > 0 => interpolation -= 1,
+            b'\n' if !triple && interpolation == 0 => return i,
+            byte if byte == quote && interpolation == 0 => {
+                if !triple {
+                    return i + 1;
+                }
+                if bytes.get(i..i + 3) == Some(&[quote, quote, quote][..]) {
+                    return i + 3;
+                }
+            }
+            _ => {}
+        }
+        i += 1;
+    }
+    bytes.len()
+}
+
+/// A `/` starts a slashy string (`/\d+/`) where an operand is expected.
+fn slashy_allowed(previous: Option<&Token<'_>>) -> bool {
+    match previous {
+        None => true,
+        Some(token) if token.kind == TokenKind::Punct => matches!(
+            token.text,
+            "=" | "("
+                | ","
+                | "["
+                | ":"
+                | "~"
+                | "!"
+                | "{"
+                | ";"
+                | "=="
+                | "!="
+                | "&&"
+                | "||"
+                | "?"
+                | "->"
+        ),
+        Some(token) => matches!(token.text, "return" | "in" | "case" | "assert"),
+    }
+}
+
+/// End of the slashy string at `start`, or `None` when it does not close on
+/// the same line (then the `/` is division after all).
+fn slashy_end(bytes: &[u8], start: usize) -> Option<usize> {
+    if bytes
+        .get(start + 1)
+        .is_none_or(|next| matches!(next, b'/' | b'*' | b'=') || next.is_ascii_whitespace())
+    {
+        return None;
+    }
+    let mut i = start + 1;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'\\' => i += 1,
+            b'\n' => return None,
+            b'/' => return Some(i + 1),
+            _ => {}
+        }
+        i += 1;
+    }
+    None
+}
+
+/// The contents of a quoted string token, without its quotes.
+pub(super) fn string_value(text: &str) -> Option<&str> {
+    ["'''", "\"\"\"", "'", "\""].iter().find_map(|quote| {
+        text.strip_prefix(quote)
+            .and_then(|rest| rest.strip_suffix(quote))
+    })
+}
+
+pub(super) fn is_keyword(word: &str) -> bool {
+    matches!(
+        word,
+        "as" | "assert"
+            | "break"
+            | "case"
+            | "catch"
+            | "class"
+            | "continue"
+            | "def"
+            | "default"
+            | "do"
+            | "else"
+            | "enum"
+            | "extends"
+            | "false"
+            | "finally"
+            | "for"
+            | "if"
+            | "implements"
+            | "import"
+            | "in"
+            | "instanceof"
+            | "interface"
+            | "new"
+            | "null"
+            | "package"
+            | "return"
+            | "super"
+            | "switch"
+            | "this"
+            | "throw"
+            | "throws"
+            | "trait"
+            | "true"
+            | "try"
+            | "var"
+            | "while"
+    )
+}
+
+pub(super) fn is_modifier(word: &str) -> bool {
+    matches!(
+        word,
+        "public"
+            | "protected"
+            | "private"
+            | "static"
+            | "final"
+            | "abstract"
+            | "synchronized"
+            | "transient"
+            | "volatile"
+            | "native"
+            | "strictfp"
+            | "sealed"
+    )
+}
+
+pub(super) fn is_primitive(word: &str) -> bool {
+    matches!(
+        word,
+        "void" | "boolean" | "byte" | "char" | "short" | "int" | "long" | "float" | "double"
+    )
+}
+
+/// The last token of a line that continues the statement on the next line.
+pub(super) fn continues_line(token: &Token<'_>) -> bool {
+    match token.kind {
+        TokenKind::Punct => matches!(
+            token.text,
+            "=" | "+"
+                | "-"
+                | "*"
+                | "/"
+                | "%"
+                | ","
+                | "."
+                | "?."
+                | "*."
+                | "("
+                | "["
+                | "{"
+                | "&&"
+                | "||"
+                | "?"
+                | ":"
+                | "?:"
+                | "->"
+                | "=="
+                | "!="
+                | "<"
+                | "<="
+                | ">="
+                | "+="
+                | "-="
+                | "*="
+                | "/="
+                | ".."
+                | "!"
+                | "&"
+                | "|"
+                | "^"
+        ),
+        TokenKind::Word => matches!(
+            token.text,
+            "extends" | "implements" | "throws" | "new" | "in" | "instanceof" | "as"
+        ),
+        _ => false,
+    }
+}
+
+/// The first token of a line that continues the previous line's statement
+/// (a method chain or a wrapped condition).
+pub(super) fn continues_previous_line(token: &Token<'_>) -> bool {
+    token.kind == TokenKind::Punct
+        && matches!(
+            token.text,
+            "." | "?." | "*." | ".&" | "?:" | "&&" | "||" | "?" | ":"
+        )
+}
@@ -0,0 +1,246 @@
+//! Token helpers, doc comments and symbol emission.
+
+use super::*;
+
+impl<'a> Parser<'a, '_> {
+    pub(super) fn word_at(&self, index: usize) -> Option<&'a str> {
+        self.tokens
+            .get(index)
+            .filter(|token| token.kind == TokenKind::Word)
+            .map(|token| token.text)
+    }
+
+    pub(super) fn punct_at(&self, index: usize, punct: &str) -> bool {
+        self.tokens
+            .get(index)
+            .is_some_and(|token| token.kind == TokenKind::Punct && token.text == punct)
+    }
+
+    pub(super) fn string_at(&self, index: usize) -> Option<&'a str> {
+        self.tokens
+            .get(index)
+            .filter(|token| token.kind == TokenKind::Str)
+            .and_then(|token| string_value(token.text))
+    }
+
+    /// The value of the first quoted string in `tokens[from..to]`.
+    pub(super) fn first_string(&self, from: usize, to: usize) -> Option<String> {
+        (from..to.min(self.tokens.len()))
+            .find_map(|index| self.string_at(index))
+            .map(str::to_string)
+    }
+
+    /// A class reference without its `.class` suffix: `Copy`, `org.x.Zip`.
+    pub(super) fn type_text(&self, from: usize, to: usize) -> String {
+        let text: String = self.text(from, to).split_whitespace().collect();
+        text.strip_suffix(".class").unwrap_or(&text).to_string()
+    }
+
+    /// Index of the bracket closing the one at `open`, counting `()`, `[]`
+    /// and `{}` together.
+    pub(super) fn matching_close(&self, open: usize) -> Option<usize> {
+        let mut depth = 0usize;
+        for (index, token) in self.tokens.iter().enumerate().skip(open) {
+            if token.kind != TokenKind::Punct {
+                continue;
+            }
+            match token.text {
+                "(" | "[" | "{" => depth += 1,
+                ")" | "]" | "}" => {
+                    depth = depth.saturating_sub(1);
+                    if depth == 0 {
+                        return Some(index);
+                    }
+                }
+                _ => {}
+            }
+        }
+        None
+    }
+
+    /// Index of the last token of the statement starting at `from`: the one
+    /// before a `;`, a line break outside brackets, or the enclosing block's
+    /// closing bracket.
+    pub(super) fn statement_last(&self, from: usize, to: usize) -> usize {
+        let to = to.min(self.tokens.len());
+        let mut depth = 0usize;
+        for index in from..to {
+            let token = &self.tokens[index];
+            if token.kind == TokenKind::Punct {
+                match token.text {
+                    "(" | "[" | "{" => depth += 1,
+                    ")" | "]" | "}" if depth == 0 => return index.saturating_sub(1).max(from),
+                    ")" | "]" | "}" => depth -= 1,
+                    ";" if depth == 0 => return index.saturating_sub(1).max(from),
+                    _ => {}
+                }
+            }
+            if depth == 0
+                && let Some(next) = self.tokens.get(index + 1).filter(|_| index + 1 < to)
+                && next.line > token.end_line
+                && !continues_line(token)
+                && !continues_previous_line(next)
+            {
+                return index;
+            }
+        }
+        to.saturating_sub(1).max(from)
+    }
+
+    /// Split `tokens[from..to]` at commas outside brackets into named
+    /// (`type: Copy`) and positional arguments.
+    pub(super) fn arguments(&self, from: usize, to: usize) -> Vec<Argument<'a>> {
+        let mut segments = Vec::new();
+        let mut depth = 0usize;
+        let mut segment_start = from;
+        let to = to.min(self.tokens.len());
+        for index in from..to {
+            let token = &self.tokens[index];
+            if token.kind != TokenKind::Punct {
+                continue;
+            }
+            match token.text {
+                "(" | "[" | "{" => depth += 1,
+                ")" | "]" | "}" => depth = depth.saturating_sub(1),
+                "," if depth == 0 => {
+                    segments.push((segment_start, index));
+                    segment_start = index + 1;
+                }
+                _ => {}
+            }
+        }
+        segments.push((segment_start, to));
+        segments
+            .into_iter()
+            .filter(|(from, to)| from < to)
+            .map(|(from, to)| {
+                let name = self
+                    .tokens
+                    .get(from)
+                    .filter(|token| token.kind != TokenKind::Punct)
+                    .filter(|_| self.punct_at(from + 1, ":") && from + 2 < to)
+                    .map(|token| string_value(token.text).unwrap_or(token.text));
+                match name {
+                    Some(name) => Argument {
+                        name: Some(name),
+                        from: from + 2,
+                        to,
+                    },
+                    None => Argument {
+                        name: None,
+                        from,
+                        to,
+                    },
+                }
+            })
+            .collect()
+    }
+
+    /// Source text of `tokens[from..to]`, with comments dropped and
+    /// whitespace collapsed to single spaces.
+    pub(super) fn text(&self, from: usize, to: usize) -> String {
+        let mut text = String::new();
+        let mut previous_end = None;
+        for token in self
+            .tokens
+            .get(from..to.min(self.tokens.len()))
+            .unwrap_or(&[])
+        {
+            if previous_end.is_some_and(|end| end < token.start) {
+                text.push(' ');
+            }
+            text.push_str(&self.content[token.start..token.end]);
+            previous_end = Some(token.end);
+        }
+        text
+    }
+
+    // -- comments -----------------------------------------------------------
+
+    /// The block of comments directly above `tokens[index]`, joined. Comments
+    /// trailing code, or separated from the token by a blank line, are not
+    /// documentation.
+    pub(super) fn doc_before(&self, index: usize) -> Option<String> {
+        let token = &self.tokens[index];
+        let previous_end = index
+            .checked_sub(1)
+            .map_or(0, |previous| self.tokens[previous].end);
+        let before = self
+            .comments
+            .partition_point(|comment| comment.start < token.start);
+        let mut block = Vec::new();
+        let mut next_line = token.line;
+        for comment in self.comments[..before].iter().rev() {
+            if comment.start < previous_end || comment.trailing || comment.end_line + 1 < next_line
+            {
+                break;
+            }
+            block.push(comment);
+            next_line = comment.line;
+        }
+        let lines: Vec<String> = block
+            .iter()
+            .rev()
+            .flat_map(|comment| comment_lines(comment.text))
+            .collect();
+        (!lines.is_empty()).then(|| lines.join("\n"))
+    }
+
+    // -- symbols ------------------------------------------------------------
+
+    pub(super) fn push_symbol(&mut self, spec: SymbolSpec, first: usize, last: usize) -> usize {
+        let first = &self.tokens[first];
+        let last = &self.tokens[last.min(self.tokens.len() - 1)];
+        let parent = spec.parent.map(|index| &self.symbols[index]);
+        let parent_id = parent.map(|parent| parent.id.clone());
+        let full_name = match parent {
+            Some(parent) => format!("{}.{}", parent.name, spec.name),
+            None => spec.name.clone(),
+        };
+
+        let mut info = json!({ "kind": spec.groovy_kind });
+        if let (Value::Object(info), Value::Object(extra)) = (&mut info, spec.extra) {
+            info.extend(extra.into_iter().filter(|(_, value)| !value.is_null()));
+        }
+
+        // Overloads and repeated DSL blocks share a name; keep ids unique.
+        let base = format!(
+            "{}:groovy:{}:{}",
+            self.file_path, spec.groovy_kind, full_name
+        );
+        let mut id_source = base.clone();
+        let mut duplicate = 1;
+        while !self.ids.insert(id_source.clone()) {
+            duplicate += 1;
+            id_source = format!("{base}#{duplicate}");
+        }
+        let id = blake3::hash(id_source.as_bytes()).to_hex()[..32].to_string();
+
+        self.symbols.push(Symbol {
+            id,
+            name: spec.name,
+            kind: spec.kind,
+            language: GROOVY_LANGUAGE.to_string(),
+            file_path: self.file_path.to_string(),
+            start_line: first.line,
+            start_column: first.column,
+            end_line: last.end_line,
+            end_column: last.column + (last.end - last.start) as u32,
+            start_byte: first.start as u32,
+            end_byte: last.end as u32,
+            signature: Some(spec.signature),
+            doc_comment: spec.doc,
+            visibility: spec.visibility,
+            parent_id,
+            metadata: Some(HashMap::from([(GROOVY_METADATA_KEY.to_string(), info)])),
+            semantic_group: None,
+            confidence: None,
+            code_context: Some(self.content[first.start..last.end].to_string()),
+            content_type: None,
+            body_span: None,
+            body_hash: None,
+            annotations: Vec::new(),
+        });
+        self.symbols.len() - 1
+    }
+}
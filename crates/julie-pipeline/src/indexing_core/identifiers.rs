@@ -0,0 +1,238 @@
+//! Identifier usages for languages whose extractor reports none.
+//!
+//! `fast_refs` and the reference graph read usages from the identifier table,
+//! and a few extractors (QML, GDScript, R, PowerShell, Lua, Bash) return
+//! symbols but no identifiers. For those files this walks the grammar's tree
+//! and records calls, member accesses and type usages. Each usage is contained
+//! by the innermost symbol of the same file that spans it, as the Rust, C# and
+//! Dart extractors do, and is left unresolved for the resolver to link.
+//!
+//! Nothing is added when the extractor already reported identifiers in the
+//! file's language, so an extractor that gains support takes over.
+
+use std::collections::HashSet;
+
+use julie_core::parser_pool::ParserPool;
+use julie_extractors::{ExtractionResults, Identifier, IdentifierKind, Symbol};
+use tree_sitter::Node;
+
+/// A node kind that marks a usage, and where its name is: a field name or the
+/// kind of a child (the last child of that kind). The name is the last leaf
+/// of that child, so `pkg::fn`, `obj.method` and `Foo.Bar` name `fn`,
+/// `method` and `Bar`. An empty `name` means the first named child, or the node itself when it
+/// is a leaf.
+struct UsageNode {
+    kind: &'static str,
+    name: &'static str,
+    usage: IdentifierKind,
+}
+
+const fn call(kind: &'static str, name: &'static str) -> UsageNode {
+    UsageNode {
+        kind,
+        name,
+        usage: IdentifierKind::Call,
+    }
+}
+
+const fn member(kind: &'static str, name: &'static str) -> UsageNode {
+    UsageNode {
+        kind,
+        name,
+        usage: IdentifierKind::MemberAccess,
+    }
+}
+
+const fn type_usage(kind: &'static str, name: &'static str) -> UsageNode {
+    UsageNode {
+        kind,
+        name,
+        usage: IdentifierKind::TypeUsage,
+    }
+}
+
+const QML: &[UsageNode] = &[
+    call("call_expression", "function"),
+    member("member_expression", "property"),
+    type_usage("ui_object_definition", "type_name"),
+    type_usage("ui_property", "type"),
+];
+
+const GDSCRIPT: &[UsageNode] = &[
+    call("call", ""),
+    call("attribute_call", ""),
+    member("attribute", "identifier"),
+    type_usage("type", ""),
+    type_usage("extends_statement", ""),
+];
+
+const R: &[UsageNode] = &[call("call", "function"), member("extract_operator", "rhs")];
+
+const POWERSHELL: &[UsageNode] = &[
+    call("command", "command_name"),
+    call("invokation_expression", "member_name"),
+    member("member_access", "member_name"),
+    type_usage("type_literal", ""),
+];
+
+const LUA: &[UsageNode] = &[
+    call("function_call", "name"),
+    member("dot_index_expression", "field"),
+    member("method_index_expression", "method"),
+];
+
+const BASH: &[UsageNode] = &[call("command", "name")];
+
+fn usage_nodes(language: &str) -> Option<&'static [UsageNode]> {
+    match language {
+        "qml" => Some(QML),
+        "gdscript" => Some(GDSCRIPT),
+        "r" => Some(R),
+        "powershell" => Some(POWERSHELL),
+        "lua" => Some(LUA),
+        "bash" => Some(BASH),
+        _ => None,
+    }
+}
+
+/// Add the usages of `content` to `results` when its extractor reported no
+/// identifiers in `language`.
+pub fn fill_missing_identifiers(
+    results: &mut ExtractionResults,
+    language: &str,
+    file_path: &str,
+    content: &str,
+) {
+    if results
+        .identifiers
+        .iter()
+        .any(|identifier| identifier.language == language)
+    {
+        return;
+    }
+    let Some(usage_nodes) = usage_nodes(language) else {
+        return;
+    };
+    let Ok(mut parser) = ParserPool::global().checkout(language) else {
+        return;
+    };
+    let Some(tree) = parser.parse(content, None) else {
+        return;
+    };
+
+    let mut collector = UsageCollector {
+        usage_nodes,
+        language,
+        file_path,
+        content,
+        symbols: &results.symbols,
+        seen: HashSet::new(),
+        identifiers: Vec::new(),
+    };
+    collector.visit(tree.root_node());
+    let identifiers = collector.identifiers;
+    results.identifiers.extend(identifiers);
+}
+
+struct UsageCollector<'a> {
+    usage_nodes: &'static [UsageNode],
+    language: &'a str,
+    file_path: &'a str,
+    content: &'a str,
+    symbols: &'a [Symbol],
+    /// Start bytes already recorded: `a.b()` is both a call and a member
+    /// access of `b`, and the outer node wins.
+    seen: HashSet<usize>,
+    identifiers: Vec<Identifier>,
+}
+
+impl UsageCollector<'_> {
+    fn visit(&mut self, node: Node<'_>) {
+        if let Some(usage_node) = self.usage_nodes.iter().find(|n| n.kind == node.kind()) {
+            if let Some(name) = name_node(node, usage_node.name).map(last_leaf) {
+                self.record(name, usage_node.usage.clone());
+            }
+            // Type names hold nothing but their parts.
+            if matches!(usage_node.usage, IdentifierKind::TypeUsage) {
+                return;
+            }
+        }
+        let mut cursor = node.walk();
+        for child in node.named_children(&mut cursor) {
+            self.visit(child);
+        }
+    }
+
+    fn record(&mut self, node: Node<'_>, kind: IdentifierKind) {
+        let range = node.byte_range();
+        let name = self.content[range.clone()].trim();
+        if name.is_empty() || !self.seen.insert(range.start) {
+            return;
+        }
+        let start = node.start_position();
+        let end = node.end_position();
+        let id_source = format!("{}:{}:usage:{}", self.file_path, self.language, range.start);
+        let line_start = self.content[..range.start]
+            .rfind('\n')
+            .map_or(0, |newline| newline + 1);
+        self.identifiers.push(Identifier {
+            id: blake3::hash(id_source.as_bytes()).to_hex()[..32].to_string(),
+            name: name.to_string(),
+            kind,
+            language: self.language.to_string(),
+            file_path: self.file_path.to_string(),
+            start_line: start.row as u32 + 1,
+            start_column: start.column as u32,
+            end_line: end.row as u32 + 1,
+            end_column: end.column as u32,
+            start_byte: range.start as u32,
+            end_byte: range.end as u32,
+            containing_symbol_id: containing_symbol(self.symbols, self.file_path, range.start)
+                .map(|symbol| symbol.id.clone()),
+            target_symbol_id: None,
+            confidence: 1.0,
+            code_context: self.content[line_start..]
+                .lines()
+                .next()
+                .map(|line| line.trim().to_string()),
+        });
+    }
+}
+
+fn name_node<'tree>(node: Node<'tree>, name: &str) -> Option<Node<'tree>> {
+    if name.is_empty() {
+        return node.named_child(0).or(Some(node));
+    }
+    node.child_by_field_name(name).or_else(|| {
+        let mut cursor = node.walk();
+        node.named_children(&mut cursor)
+            .filter(|child| child.kind() == name)
+            .last()
+    })
+}
+
+fn last_leaf(mut node: Node<'_>) -> Node<'_> {
+    while let Some(child) = node
+        .named_child_count()
+        .checked_sub(1)
+        .and_then(|last| node.named_child(last as u32))
+    {
+        node = child;
+    }
+    node
+}
+
+/// The innermost symbol of `file_path` whose span covers `byte`.
+fn containing_symbol<'a>(
+    symbols: &'a [Symbol],
+    file_path: &str,
+    byte: usize,
+) -> Option<&'a Symbol> {
+    let byte = byte as u32;
+    symbols
+        .iter()
+        .filter(|symbol| {
+            symbol.file_path == file_path && symbol.start_byte <= byte && byte < symbol.end_byte
+        })
+        .min_by_key(|symbol| symbol.end_byte - symbol.start_byte)
+}
@@ -0,0 +1,171 @@
+//! Language injection: code embedded in another language's file.
+//!
+//! A host extractor only sees its own grammar, so the JavaScript in an HTML
+//! `<script>` block, the CSS in a Vue `<style>` block or the SQL in a Python
+//! string would otherwise be opaque text. This pass finds those embedded
+//! ranges and hands each one to the embedded language's extractor:
+//!
+//! - markup hosts (HTML, Vue, Razor): `<script>` blocks as JavaScript or
+//!   TypeScript (`lang="ts"`), HTML templates (`type="text/x-template"`)
+//!   as HTML, and `<style>` blocks as CSS;
+//! - code hosts: string literals that read as SQL, heredocs labelled `SQL`,
+//!   `HTML` or `CSS` (Ruby, PHP), and JavaScript templates tagged `html`,
+//!   `css` or `sql` (or marked with a `/* html */` comment).
+//!
+//! Each embedded language is extracted from a virtual copy of the host file
+//! in which everything outside its ranges is blanked to spaces. Lines,
+//! columns and byte offsets are therefore the host file's own and need no
+//! remapping. Injected symbols keep their language, are nested under the
+//! host symbol enclosing them (the function holding the query, the element
+//! holding the script), and carry `{host_language}` under
+//! [`INJECTION_METADATA_KEY`]. A symbol the host extractor already reported
+//! at the same name and line is not duplicated.
+
+mod code;
+mod extract;
+mod markup;
+
+pub use code::looks_like_sql;
+pub use extract::{extract_injections, virtual_source};
+
+use code::find_code_injections;
+use markup::find_markup_injections;
+
+use std::collections::{HashMap, HashSet};
+use std::path::Path;
+
+use julie_extractors::ExtractionResults;
+use serde_json::json;
+use tracing::warn;
+
+/// Metadata key marking a symbol extracted from embedded code.
+pub const INJECTION_METADATA_KEY: &str = "injection";
+
+/// A range of a host file written in another language.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Injection {
+    /// Language of the embedded code (`javascript`, `css`, `sql`, ...).
+    pub language: &'static str,
+    /// Byte range of the embedded code in the host file.
+    pub start_byte: usize,
+    pub end_byte: usize,
+    /// Interpolations (`${...}`) inside the range, blanked before parsing.
+    pub holes: Vec<(usize, usize)>,
+}
+
+/// How string literals and comments are written in a code host.
+#[derive(Debug, Clone, Copy, Default)]
+struct CodeSyntax {
+    slash_comments: bool,
+    hash_comments: bool,
+    triple_quotes: bool,
+    /// `"` and `'` strings may span lines.
+    multiline_quotes: bool,
+    /// `'x'` is a character literal and a lone `'` a lifetime or label.
+    char_literals: bool,
+    /// Backtick strings: JavaScript templates, Go raw strings.
+    backticks: bool,
+    template_interpolation: bool,
+    rust_raw_strings: bool,
+    /// C# `@"..."`: no backslash escapes, `""` is a quote.
+    verbatim_strings: bool,
+    ruby_heredocs: bool,
+    php_heredocs: bool,
+}
+
+enum HostSyntax {
+    Markup,
+    Code(CodeSyntax),
+}
+
+fn host_syntax(host_language: &str) -> Option<HostSyntax> {
+    let c_like = CodeSyntax {
+        slash_comments: true,
+        char_literals: true,
+        ..Default::default()
+    };
+    let syntax = match host_language {
+        "html" | "vue" | "razor" => return Some(HostSyntax::Markup),
+        "python" => CodeSyntax {
+            hash_comments: true,
+            triple_quotes: true,
+            ..Default::default()
+        },
+        "javascript" | "typescript" | "jsx" | "tsx" => CodeSyntax {
+            slash_comments: true,
+            backticks: true,
+            template_interpolation: true,
+            ..Default::default()
+        },
+        "java" | "kotlin" | "scala" | "swift" => CodeSyntax {
+            triple_quotes: true,
+            ..c_like
+        },
+        "csharp" => CodeSyntax {
+            triple_quotes: true,
+            verbatim_strings: true,
+            ..c_like
+        },
+        "go" => CodeSyntax {
+            backticks: true,
+            ..c_like
+        },
+        "rust" => CodeSyntax {
+            rust_raw_strings: true,
+            multiline_quotes: true,
+            ..c_like
+        },
+        "c" | "cpp" => c_like,
+        "dart" => CodeSyntax {
+            slash_comments: true,
+            triple_quotes: true,
+            ..Default::default()
+        },
+        "php" => CodeSyntax {
+            slash_comments: true,
+            hash_comments: true,
+            multiline_quotes: true,
+            php_heredocs: true,
+            ..Default::default()
+        },
+        "ruby" => CodeSyntax {
+            hash_comments: true,
+            multiline_quotes: true,
+            ruby_heredocs: true,
+            ..Default::default()
+        },
+        _ => return None,
+    };
+    Some(HostSyntax::Code(syntax))
+}
+
+/// Embedded code in a host file, in file order.
+pub fn find_injections(host_language: &str, content: &str) -> Vec<Injection> {
+    match host_syntax(host_language) {
+        Some(HostSyntax::Markup) => find_markup_injections(content),
+        Some(HostSyntax::Code(syntax)) => find_code_injections(content, syntax),
+        None => Vec::new(),
+    }
+}
+
+/// Extract the embedded code of a host file and merge it into the host's
+/// results. A no-op for languages that host nothing.
+pub fn inject_embedded_languages(
+    results: &mut ExtractionResults,
+    host_language: &str,
+    file_path: &str,
+    content: &str,
+    workspace_root: &Path,
+) {
+    let injections = find_injections(host_language, content);
+    if !injections.is_empty() {
+        extract_injections(
+            results,
+            &injections,
+            host_language,
+            file_path,
+            content,
+            workspace_root,
+        );
+    }
+}
@@ -0,0 +1,358 @@
+//! Finding embedded languages in the string literals of code hosts.
+
+use super::*;
+
+/// A string literal of a code host: its body and what marked its language.
+struct StringLiteral {
+    start: usize,
+    end: usize,
+    holes: Vec<(usize, usize)>,
+    /// Heredoc label or template tag (`SQL`, `html`, ...).
+    label: Option<String>,
+}
+
+/// String literals of a code host that hold another language.
+pub(super) fn find_code_injections(content: &str, syntax: CodeSyntax) -> Vec<Injection> {
+    string_literals(content, syntax)
+        .into_iter()
+        .filter_map(|literal| {
+            let text = &content[literal.start..literal.end];
+            let language = match literal.label.as_deref() {
+                Some(label) => {
+                    label_language(label).or_else(|| looks_like_sql(text).then_some("sql"))?
+                }
+                None if looks_like_sql(text) => "sql",
+                None => return None,
+            };
+            Some(Injection {
+                language,
+                start_byte: literal.start,
+                end_byte: literal.end,
+                holes: literal.holes,
+            })
+        })
+        .collect()
+}
+
+fn label_language(label: &str) -> Option<&'static str> {
+    match label.to_ascii_lowercase().as_str() {
+        "sql" | "psql" | "mysql" | "sqlite" => Some("sql"),
+        "html" | "svg" | "xhtml" => Some("html"),
+        "css" => Some("css"),
+        "js" | "javascript" => Some("javascript"),
+        _ => None,
+    }
+}
+
+const SQL_OBJECTS: &[&str] = &[
+    "TABLE",
+    "VIEW",
+    "INDEX",
+    "UNIQUE",
+    "TRIGGER",
+    "PROCEDURE",
+    "FUNCTION",
+    "SCHEMA",
+    "TEMP",
+    "TEMPORARY",
+    "OR",
+    "MATERIALIZED",
+    "VIRTUAL",
+];
+
+/// Whether a string reads as a SQL statement rather than prose. A statement
+/// needs two keywords in the right places (`SELECT ... FROM`, `INSERT INTO`,
+/// `UPDATE t SET`, ...), and one spelled in lowercase also needs SQL
+/// punctuation, so "select a file from disk" stays prose.
+pub fn looks_like_sql(text: &str) -> bool {
+    let text = text.trim_start();
+    if text.len() < 10 {
+        return false;
+    }
+    let words: Vec<&str> = text
+        .split(|c: char| c.is_whitespace() || c == '(' || c == ',')
+        .filter(|word| !word.is_empty())
+        .take(64)
+        .collect();
+    let Some(first) = words.first() else {
+        return false;
+    };
+    let word = |index: usize| {
+        words
+            .get(index)
+            .map(|word| word.to_ascii_uppercase())
+            .unwrap_or_default()
+    };
+    let statement = match word(0).as_str() {
+        "SELECT" => words
+            .iter()
+            .skip(2)
+            .any(|word| word.eq_ignore_ascii_case("FROM")),
+        "INSERT" | "REPLACE" => word(1) == "INTO",
+        "UPDATE" => word(2) == "SET",
+        "DELETE" => word(1) == "FROM",
+        "CREATE" | "DROP" => SQL_OBJECTS.contains(&word(1).as_str()),
+        "ALTER" => word(1) == "TABLE",
+        "WITH" => word(2) == "AS" || word(1) == "RECURSIVE",
+        _ => false,
+    };
+    statement
+        && (first.chars().all(|c| c.is_ascii_uppercase())
+            || text.contains(['*', '=', '(', ',', '?', '$', ';']))
+}
+
+fn is_word_byte(byte: u8) -> bool {
+    byte.is_ascii_alphanumeric() || byte == b'_'
+}
+
+/// Every string literal of a code host, skipping comments.
+fn string_literals(content: &str, syntax: CodeSyntax) -> Vec<StringLiteral> {
+    let bytes = content.as_bytes();
+    let mut literals = Vec::new();
+    let mut pos = 0;
+    while pos < bytes.len() {
+        let rest = &bytes[pos..];
+        let previous = pos.checked_sub(1).map(|index| bytes[index]);
+        match bytes[pos] {
+            b'/' if syntax.slash_comments && rest.starts_with(b"//") => {
+                pos = line_end(bytes, pos);
+            }
+            b'/' if syntax.slash_comments && rest.starts_with(b"/*") => {
+                pos = content[pos + 2..]
+                    .find("*/")
+                    .map_or(bytes.len(), |end| pos + 2 + end + 2);
+            }
+            b'#' if syntax.hash_comments && !(syntax.php_heredocs && rest.starts_with(b"#[")) => {
+                pos = line_end(bytes, pos);
+            }
+            b'<' if syntax.php_heredocs && rest.starts_with(b"<<<") => {
+                pos = heredoc(content, pos + 3, &mut literals).unwrap_or(pos + 3);
+            }
+            b'<' if syntax.ruby_heredocs && rest.starts_with(b"<<") => {
+                let mut label_start = pos + 2;
+                if matches!(bytes.get(label_start), Some(b'~' | b'-')) {
+                    label_start += 1;
+                }
+                let uppercase = bytes
+                    .get(label_start)
+                    .is_some_and(|byte| byte.is_ascii_uppercase() || matches!(byte, b'\'' | b'"'));
+                pos = uppercase
+                    .then(|| heredoc(content, label_start, &mut literals))
+                    .flatten()
+                    .unwrap_or(pos + 2);
+            }
+            b'r' if syntax.rust_raw_strings
+                && !previous.is_some_and(is_word_byte)
+                && matches!(rest.get(1), Some(b'"' | b'#')) =>
+            {
+                let hashes = rest[1..].iter().take_while(|&&byte| byte == b'#').count();
+                if rest.get(1 + hashes) != Some(&b'"') {
+                    pos += 1;
+                    continue;
+                }
+                let start = pos + hashes + 2;
+                let closing = format!("\"{}", "#".repeat(hashes));
+                let end = content[start..]
+                    .find(&closing)
+                    .map_or(bytes.len(), |end| start + end);
+                literals.push(StringLiteral {
+                    start,
+                    end,
+                    holes: Vec::new(),
+                    label: None,
+                });
+                pos = end + closing.len();
+            }
+            quote @ (b'"' | b'\'')
+                if syntax.triple_quotes && rest.starts_with(&[quote, quote, quote]) =>
+            {
+                let start = pos + 3;
+                let closing = [quote, quote, quote];
+                let mut end = start;
+                while end < bytes.len() && !bytes[end..].starts_with(&closing) {
+                    end += if bytes[end] == b'\\' { 2 } else { 1 };
+                }
+                let end = end.min(bytes.len());
+                literals.push(StringLiteral {
+                    start,
+                    end,
+                    holes: Vec::new(),
+                    label: None,
+                });
+                pos = end + 3;
+            }
+            b'\'' if syntax.char_literals => {
+                pos = char_literal_end(bytes, pos).unwrap_or(pos + 1);
+            }
+            b'"' if syntax.verbatim_strings && previous == Some(b'@') => {
+                let start = pos + 1;
+                let mut end = start;
+                while end < bytes.len() {
+                    if bytes[end] == b'"' {
+                        if bytes.get(end + 1) == Some(&b'"') {
+                            end += 2;
+                            continue;
+                        }
+                        break;
+                    }
+                    end += 1;
+                }
+                literals.push(StringLiteral {
+                    start,
+                    end,
+                    holes: Vec::new(),
+                    label: None,
+                });
+                pos = end + 1;
+            }
+            quote @ (b'"' | b'\'') => {
+                let start = pos + 1;
+                let mut end = start;
+                while end < bytes.len() && bytes[end] != quote {
+                    if bytes[end] == b'\n' && !syntax.multiline_quotes {
+                        break;
+                    }
+                    end += if bytes[end] == b'\\' { 2 } else { 1 };
+                }
+                let end = end.min(bytes.len());
+                literals.push(StringLiteral {
+                    start,
+                    end,
+                    holes: Vec::new(),
+                    label: None,
+                });
+                pos = end + 1;
+            }
+            b'`' if syntax.backticks => {
+                let start = pos + 1;
+                let mut end = start;
+                let mut holes = Vec::new();
+                while end < bytes.len() && bytes[end] != b'`' {
+                    if syntax.template_interpolation && bytes[end..].starts_with(b"${") {
+                        let hole_end = interpolation_end(bytes, end + 2);
+                        holes.push((end, hole_end));
+                        end = hole_end;
+                    } else if syntax.template_interpolation && bytes[end] == b'\\' {
+                        end += 2;
+                    } else {
+                        end += 1;
+                    }
+                }
+                let end = end.min(bytes.len());
+                literals.push(StringLiteral {
+                    start,
+                    end,
+                    holes,
+                    label: syntax
+                        .template_interpolation
+                        .then(|| template_tag(content, pos))
+                        .flatten(),
+                });
+                pos = end + 1;
+            }
+            _ => pos += 1,
+        }
+    }
+    literals
+}
+
+fn line_end(bytes: &[u8], from: usize) -> usize {
+    bytes[from..]
+        .iter()
+        .position(|&byte| byte == b'\n')
+        .map_or(bytes.len(), |offset| from + offset)
+}
+
+/// End of a character literal starting at `start`, or `None` for a lone
+/// quote (a Rust lifetime or loop label).
+fn char_literal_end(bytes: &[u8], start: usize) -> Option<usize> {
+    let mut pos = start + 1;
+    if bytes.get(pos) == Some(&b'\\') {
+        pos += 2;
+        while pos < bytes.len() && pos - start <= 12 && !matches!(bytes[pos], b'\'' | b'\n') {
+            pos += 1;
+        }
+    } else {
+        // One character, however many bytes.
+        pos += 1;
+        while bytes.get(pos).is_some_and(|byte| byte & 0xC0 == 0x80) {
+            pos += 1;
+        }
+    }
+    (bytes.get(pos) == Some(&b'\'')).then_some(pos + 1)
+}
+
+/// End (exclusive, past the `}`) of a `${...}` interpolation whose body
+/// starts at `from`.
+fn interpolation_end(bytes: &[u8], from: usize) -> usize {
+    let mut depth = 1;
+    let mut pos = from;
+    while pos < bytes.len() {
+        match bytes[pos] {
+            b'{' => depth += 1,
+            b'}' => {
+                depth -= 1;
+                if depth == 0 {
+                    return pos + 1;
+                }
+            }
+            _ => {}
+        }
+        pos += 1;
+    }
+    bytes.len()
+}
+
+/// The tag of a template literal opening at `backtick`: an identifier right
+/// before it (`html`...``), or a `/* html */` comment.
+fn template_tag(content: &str, backtick: usize) -> Option<String> {
+    let before = content[..backtick].trim_end();
+    if let Some(comment) = before.strip_suffix("*/") {
+        let open = comment.rfind("/*")?;
+        return Some(comment[open + 2..].trim().to_string());
+    }
+    let tag_start = before
+        .bytes()
+        .rposition(|byte| !is_word_byte(byte))
+        .map_or(0, |index| index + 1);
+    // Only a tag written directly against the backtick.
+    (before.len() == backtick && tag_start < before.len()).then(|| before[tag_start..].to_string())
+}
+
+/// Read a heredoc whose label starts at `label_start` (after `<<<`, `<<~`,
+/// ...), record its body and return where scanning resumes.
+fn heredoc(content: &str, label_start: usize, literals: &mut Vec<StringLiteral>) -> Option<usize> {
+    let bytes = content.as_bytes();
+    let quoted = matches!(bytes.get(label_start), Some(b'\'' | b'"'));
+    let name_start = label_start + usize::from(quoted);
+    let name_end = name_start
+        + bytes[name_start..]
+            .iter()
+            .take_while(|&&byte| is_word_byte(byte))
+            .count();
+    if name_end == name_start {
+        return None;
+    }
+    let label = &content[name_start..name_end];
+    let body_start = (line_end(bytes, name_end) + 1).min(bytes.len());
+
+    let mut line_start = body_start;
+    while line_start < bytes.len() {
+        let end = line_end(bytes, line_start);
+        let line = content[line_start..end].trim_start();
+        let terminates = line
+            .strip_prefix(label)
+            .is_some_and(|rest| !rest.bytes().next().is_some_and(is_word_byte));
+        if terminates {
+            literals.push(StringLiteral {
+                start: body_start,
+                end: line_start.saturating_sub(1).max(body_start),
+                holes: Vec::new(),
+                label: Some(label.to_string()),
+            });
+            return Some(end);
+        }
+        line_start = end + 1;
+    }
+    None
+}
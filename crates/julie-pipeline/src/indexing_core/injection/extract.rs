@@ -0,0 +1,171 @@
+//! Re-extracting embedded ranges and merging the results into the host's.
+
+use super::*;
+
+/// Run each embedded language's extractor over its ranges of `content` and
+/// merge the results into the host's.
+pub fn extract_injections(
+    results: &mut ExtractionResults,
+    injections: &[Injection],
+    host_language: &str,
+    file_path: &str,
+    content: &str,
+    workspace_root: &Path,
+) {
+    let mut by_language: Vec<(&str, Vec<&Injection>)> = Vec::new();
+    for injection in injections {
+        match by_language
+            .iter_mut()
+            .find(|(language, _)| *language == injection.language)
+        {
+            Some((_, group)) => group.push(injection),
+            None => by_language.push((injection.language, vec![injection])),
+        }
+    }
+
+    for (language, group) in by_language {
+        let Some(extension) = injection_extension(language) else {
+            continue;
+        };
+        let source = virtual_source(content, language, &group);
+        let virtual_path = format!("{file_path}.{extension}");
+        match julie_extractors::extract_canonical(&virtual_path, &source, workspace_root) {
+            Ok(extracted) => merge_injected_results(results, extracted, host_language, file_path),
+            Err(e) => warn!("Failed to extract embedded {language} in {file_path}: {e}"),
+        }
+    }
+}
+
+fn injection_extension(language: &str) -> Option<&'static str> {
+    Some(match language {
+        "javascript" => "js",
+        "typescript" => "ts",
+        "css" => "css",
+        "html" => "html",
+        "sql" => "sql",
+        _ => return None,
+    })
+}
+
+/// `content` with everything outside `injections` blanked, newlines kept, so
+/// every byte stays where it is. Statement languages get a `;` after each
+/// range so neighbouring ranges don't run together.
+pub fn virtual_source(content: &str, language: &str, injections: &[&Injection]) -> String {
+    let bytes = content.as_bytes();
+    let mut source: Vec<u8> = bytes
+        .iter()
+        .map(|&byte| if byte == b'\n' { b'\n' } else { b' ' })
+        .collect();
+    let separator = matches!(language, "sql" | "javascript" | "typescript");
+    for injection in injections {
+        let (start, end) = (injection.start_byte, injection.end_byte.min(bytes.len()));
+        source[start..end].copy_from_slice(&bytes[start..end]);
+        for &(hole_start, hole_end) in &injection.holes {
+            for (offset, byte) in source[hole_start..hole_end].iter_mut().enumerate() {
+                if *byte != b'\n' {
+                    // A placeholder keeps `id = ${id}` a complete expression.
+                    *byte = if offset == 0 { b'?' } else { b' ' };
+                }
+            }
+        }
+        if separator && end < source.len() && source[end] == b' ' {
+            source[end] = b';';
+        }
+    }
+    // Only ASCII was written over whole characters, and ranges start and
+    // end at ASCII delimiters.
+    String::from_utf8(source).unwrap_or_default()
+}
+
+/// Map an embedded language's results onto the host file.
+fn merge_injected_results(
+    results: &mut ExtractionResults,
+    extracted: ExtractionResults,
+    host_language: &str,
+    file_path: &str,
+) {
+    let host_by_position: HashMap<(String, u32), String> = results
+        .symbols
+        .iter()
+        .map(|symbol| ((symbol.name.clone(), symbol.start_line), symbol.id.clone()))
+        .collect();
+    // Host symbols already covering an injected one replace it.
+    let mut replaced: HashMap<String, String> = HashMap::new();
+    let mut symbols = Vec::new();
+    for symbol in extracted.symbols {
+        match host_by_position.get(&(symbol.name.clone(), symbol.start_line)) {
+            Some(host_id) => {
+                replaced.insert(symbol.id, host_id.clone());
+            }
+            None => symbols.push(symbol),
+        }
+    }
+    let injected_ids: HashSet<String> = symbols.iter().map(|symbol| symbol.id.clone()).collect();
+    let remap = |id: &mut String| {
+        if let Some(host_id) = replaced.get(id.as_str()) {
+            *id = host_id.clone();
+        }
+    };
+    let remap_optional = |id: &mut Option<String>| {
+        if let Some(id) = id.as_mut() {
+            remap(id);
+        }
+    };
+
+    for mut symbol in symbols {
+        remap_optional(&mut symbol.parent_id);
+        let nested = symbol.parent_id.as_ref().is_some_and(|parent| {
+            injected_ids.contains(parent) || replaced.values().any(|host_id| host_id == parent)
+        });
+        if !nested {
+            symbol.parent_id = enclosing_host_symbol(results, symbol.start_byte, symbol.end_byte);
+        }
+        symbol.file_path = file_path.to_string();
+        symbol.metadata.get_or_insert_with(HashMap::new).insert(
+            INJECTION_METADATA_KEY.to_string(),
+            json!({ "host_language": host_language }),
+        );
+        results.symbols.push(symbol);
+    }
+    for mut relationship in extracted.relationships {
+        remap(&mut relationship.from_symbol_id);
+        remap(&mut relationship.to_symbol_id);
+        relationship.file_path = file_path.to_string();
+        results.relationships.push(relationship);
+    }
+    for mut pending in extracted.pending_relationships {
+        remap(&mut pending.from_symbol_id);
+        pending.file_path = file_path.to_string();
+        results.pending_relationships.push(pending);
+    }
+    for mut structured in extracted.structured_pending_relationships {
+        remap(&mut structured.pending.from_symbol_id);
+        remap_optional(&mut structured.caller_scope_symbol_id);
+        structured.pending.file_path = file_path.to_string();
+        results.structured_pending_relationships.push(structured);
+    }
+    for mut identifier in extracted.identifiers {
+        remap_optional(&mut identifier.containing_symbol_id);
+        remap_optional(&mut identifier.target_symbol_id);
+        identifier.file_path = file_path.to_string();
+        results.identifiers.push(identifier);
+    }
+    results.types.extend(extracted.types);
+}
+
+/// The innermost host symbol spanning `start..end`.
+fn enclosing_host_symbol(results: &ExtractionResults, start: u32, end: u32) -> Option<String> {
+    results
+        .symbols
+        .iter()
+        .filter(|symbol| {
+            symbol.start_byte <= start
+                && end <= symbol.end_byte
+                && symbol
+                    .metadata
+                    .as_ref()
+                    .is_none_or(|metadata| !metadata.contains_key(INJECTION_METADATA_KEY))
+        })
+        .min_by_key(|symbol| symbol.end_byte.saturating_sub(symbol.start_byte))
+        .map(|symbol| symbol.id.clone())
+}
@@ -0,0 +1,155 @@
+//! Finding `<script>` and `<style>` blocks in markup hosts.
+
+use super::*;
+
+/// `<script>` and `<style>` blocks of an HTML-like file.
+pub(super) fn find_markup_injections(content: &str) -> Vec<Injection> {
+    let bytes = content.as_bytes();
+    let mut injections = Vec::new();
+    let mut pos = 0;
+    while let Some(offset) = content[pos..].find('<') {
+        let tag_start = pos + offset;
+        if content[tag_start..].starts_with("<!--") {
+            pos = content[tag_start..]
+                .find("-->")
+                .map_or(bytes.len(), |end| tag_start + end + 3);
+            continue;
+        }
+        let Some((tag, name_end)) = ["script", "style"].into_iter().find_map(|tag| {
+            let name_end = tag_start + 1 + tag.len();
+            let boundary = bytes.get(name_end).copied();
+            (bytes.len() >= name_end
+                && bytes[tag_start + 1..name_end].eq_ignore_ascii_case(tag.as_bytes())
+                && matches!(
+                    boundary,
+                    Some(b'>' | b'/') | Some(b' ' | b'\t' | b'\r' | b'\n')
+                ))
+            .then_some((tag, name_end))
+        }) else {
+            pos = tag_start + 1;
+            continue;
+        };
+        let Some(open_end) = tag_end(bytes, name_end) else {
+            break;
+        };
+        let attributes = &content[name_end..open_end];
+        let body_start = open_end + 1;
+        if attributes.trim_end().ends_with('/') {
+            pos = body_start;
+            continue;
+        }
+        let body_end =
+            find_ignore_case(content, body_start, &format!("</{tag}")).unwrap_or(bytes.len());
+        let language = if tag == "script" {
+            script_language(attributes)
+        } else {
+            style_language(attributes)
+        };
+        if let Some(language) = language
+            && !content[body_start..body_end].trim().is_empty()
+        {
+            injections.push(Injection {
+                language,
+                start_byte: body_start,
+                end_byte: body_end,
+                holes: Vec::new(),
+            });
+        }
+        pos = body_end;
+    }
+    injections
+}
+
+/// The `>` closing a start tag, skipping quoted attribute values.
+fn tag_end(bytes: &[u8], from: usize) -> Option<usize> {
+    let mut quote = None;
+    for (index, &byte) in bytes.iter().enumerate().skip(from) {
+        match (quote, byte) {
+            (Some(open), _) if byte == open => quote = None,
+            (Some(_), _) => {}
+            (None, b'"' | b'\'') => quote = Some(byte),
+            (None, b'>') => return Some(index),
+            _ => {}
+        }
+    }
+    None
+}
+
+fn find_ignore_case(content: &str, from: usize, needle: &str) -> Option<usize> {
+    let needle = needle.as_bytes();
+    content.as_bytes()[from..]
+        .windows(needle.len())
+        .position(|window| window.eq_ignore_ascii_case(needle))
+        .map(|offset| from + offset)
+}
+
+/// Value of attribute `name` in a start tag's attribute text, lowercased.
+fn attribute(attributes: &str, name: &str) -> Option<String> {
+    let bytes = attributes.as_bytes();
+    let mut pos = 0;
+    while pos < bytes.len() {
+        while pos < bytes.len() && (bytes[pos].is_ascii_whitespace() || bytes[pos] == b'/') {
+            pos += 1;
+        }
+        let name_start = pos;
+        while pos < bytes.len() && !bytes[pos].is_ascii_whitespace() && bytes[pos] != b'=' {
+            pos += 1;
+        }
+        let attribute_name = &attributes[name_start..pos];
+        if bytes.get(pos) != Some(&b'=') {
+            if attribute_name.eq_ignore_ascii_case(name) {
+                return Some(String::new());
+            }
+            continue;
+        }
+        pos += 1;
+        let value = match bytes.get(pos) {
+            Some(&quote @ (b'"' | b'\'')) => {
+                let end = attributes[pos + 1..]
+                    .find(quote as char)
+                    .map_or(bytes.len(), |end| pos + 1 + end);
+                let value = &attributes[pos + 1..end];
+                pos = end + 1;
+                value
+            }
+            _ => {
+                let start = pos;
+                while pos < bytes.len() && !bytes[pos].is_ascii_whitespace() {
+                    pos += 1;
+                }
+                &attributes[start..pos]
+            }
+        };
+        if attribute_name.eq_ignore_ascii_case(name) {
+            return Some(value.trim().to_ascii_lowercase());
+        }
+    }
+    None
+}
+
+fn script_language(attributes: &str) -> Option<&'static str> {
+    if let Some(lang) = attribute(attributes, "lang") {
+        return match lang.as_str() {
+            "ts" | "typescript" | "tsx" => Some("typescript"),
+            "js" | "javascript" | "jsx" => Some("javascript"),
+            _ => None,
+        };
+    }
+    match attribute(attributes, "type").as_deref() {
+        None
+        | Some("" | "module" | "text/javascript" | "application/javascript" | "text/babel") => {
+            Some("javascript")
+        }
+        Some("text/typescript" | "application/typescript") => Some("typescript"),
+        Some(kind) if kind.contains("template") || kind == "text/html" => Some("html"),
+        Some(_) => None,
+    }
+}
+
+fn style_language(attributes: &str) -> Option<&'static str> {
+    let lang = attribute(attributes, "lang");
+    let kind = attribute(attributes, "type");
+    (matches!(lang.as_deref(), None | Some("css"))
+        && matches!(kind.as_deref(), None | Some("" | "text/css")))
+    .then_some("css")
+}
@@ -0,0 +1,278 @@
+//! Kotlin Multiplatform `expect`/`actual` declarations.
+//!
+//! Common code declares an API with `expect` and each platform source set
+//! (`jvmMain`, `iosMain`, `jsMain`, ...) supplies the `actual` implementation
+//! in a file of its own. The extractor sees unrelated same-named symbols, so
+//! navigation lands on the bodiless `expect` stub.
+//!
+//! This pass marks each such declaration with its role under
+//! [`KOTLIN_MULTIPLATFORM_METADATA_KEY`] and gives every declaration of one
+//! API the same [`PARTIAL_GROUP_METADATA_KEY`] key: the package-qualified
+//! name, plus the parameter count for functions so overloads stay apart.
+//! Members of an `expect class` are expected implicitly and are tagged too.
+//! `deep_dive` uses the key to present the declarations as one symbol and to
+//! list every site, and `julie_index::analysis::expect_actual` links each
+//! `actual` to its `expect` with an `Implements` relationship. The key is
+//! derived from the declaring file alone, so it stays stable when only one
+//! source set is re-indexed.
+
+use std::collections::HashMap;
+
+use julie_extractors::base::{Symbol, SymbolKind};
+use serde_json::Value;
+
+use crate::indexing_core::csharp_partials::PARTIAL_GROUP_METADATA_KEY;
+
+/// Metadata key holding `"expect"` or `"actual"`.
+pub const KOTLIN_MULTIPLATFORM_METADATA_KEY: &str = "kotlin_multiplatform";
+
+const KOTLIN_LANGUAGE: &str = "kotlin";
+
+/// Keywords that end a declaration's modifier list.
+const DECLARATION_KEYWORDS: &[&str] = &[
+    "fun",
+    "class",
+    "interface",
+    "object",
+    "val",
+    "var",
+    "typealias",
+    "constructor",
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Role {
+    Expect,
+    Actual,
+}
+
+impl Role {
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Expect => "expect",
+            Self::Actual => "actual",
+        }
+    }
+}
+
+/// Tag the `expect` and `actual` declarations in one Kotlin file's symbols.
+pub fn annotate_expect_actual_declarations(symbols: &mut [Symbol], language: &str, content: &str) {
+    if language != KOTLIN_LANGUAGE || !(content.contains("expect") || content.contains("actual")) {
+        return;
+    }
+
+    let own_roles: HashMap<&str, Role> = symbols
+        .iter()
+        .filter(|symbol| is_declaration_kind(&symbol.kind))
+        .filter_map(|symbol| {
+            declared_role(declaration_text(symbol, content)).map(|role| (symbol.id.as_str(), role))
+        })
+        .collect();
+    if own_roles.is_empty() {
+        return;
+    }
+
+    let by_id: HashMap<&str, &Symbol> = symbols.iter().map(|s| (s.id.as_str(), s)).collect();
+    let package = package_name(content);
+    let tagged: Vec<(usize, Role, String)> = symbols
+        .iter()
+        .enumerate()
+        .filter(|(_, symbol)| is_declaration_kind(&symbol.kind))
+        .filter_map(|(idx, symbol)| {
+            let role = own_roles
+                .get(symbol.id.as_str())
+                .copied()
+                .or_else(|| inherited_expect(symbol, &by_id, &own_roles))?;
+            Some((idx, role, group_key(symbol, &by_id, package, content)))
+        })
+        .collect();
+
+    for (idx, role, group) in tagged {
+        let metadata = symbols[idx].metadata.get_or_insert_with(HashMap::new);
+        metadata.insert(
+            KOTLIN_MULTIPLATFORM_METADATA_KEY.to_string(),
+            Value::String(role.as_str().to_string()),
+        );
+        metadata.insert(PARTIAL_GROUP_METADATA_KEY.to_string(), Value::String(group));
+    }
+}
+
+fn is_declaration_kind(kind: &SymbolKind) -> bool {
+    matches!(
+        kind,
+        SymbolKind::Class
+            | SymbolKind::Interface
+            | SymbolKind::Struct
+            | SymbolKind::Enum
+            | SymbolKind::Module
+            | SymbolKind::Type
+            | SymbolKind::Function
+            | SymbolKind::Method
+            | SymbolKind::Constructor
+            | SymbolKind::Property
+            | SymbolKind::Field
+            | SymbolKind::Constant
+            | SymbolKind::Variable
+    )
+}
+
+/// Source of the declaration, starting at its modifiers. Falls back to the
+/// signature when the byte span does not fit the content.
+fn declaration_text<'a>(symbol: &'a Symbol, content: &'a str) -> &'a str {
+    content
+        .get(symbol.start_byte as usize..symbol.end_byte as usize)
+        .filter(|text| !text.trim().is_empty())
+        .or(symbol.signature.as_deref())
+        .unwrap_or_default()
+}
+
+/// `expect` or `actual` among the modifiers before the declaration keyword.
+/// Annotations (`@Suppress("x")`) in the modifier list are stepped over.
+fn declared_role(text: &str) -> Option<Role> {
+    let mut rest = text.trim_start();
+    loop {
+        if let Some(annotation) = rest.strip_prefix('@') {
+            rest = skip_annotation(annotation).trim_start();
+            continue;
+        }
+        let end = rest
+            .find(|ch: char| !(ch.is_alphanumeric() || ch == '_'))
+            .unwrap_or(rest.len());
+        let word = &rest[..end];
+        match word {
+            "" => return None,
+            "expect" => return Some(Role::Expect),
+            "actual" => return Some(Role::Actual),
+            _ if DECLARATION_KEYWORDS.contains(&word) => return None,
+            _ => rest = rest[end..].trim_start(),
+        }
+    }
+}
+
+/// The text after an annotation: its (possibly qualified) name and an
+/// optional balanced argument list.
+fn skip_annotation(text: &str) -> &str {
+    let name_end = text
+        .find(|ch: char| !(ch.is_alphanumeric() || ch == '_' || ch == '.' || ch == ':'))
+        .unwrap_or(text.len());
+    let rest = &text[name_end..];
+    if !rest.starts_with('(') {
+        return rest;
+    }
+    let mut depth = 0usize;
+    for (idx, ch) in rest.char_indices() {
+        match ch {
+            '(' => depth += 1,
+            ')' => {
+                depth -= 1;
+                if depth == 0 {
+                    return &rest[idx + 1..];
+                }
+            }
+            _ => {}
+        }
+    }
+    ""
+}
+
+/// Members of an `expect class` (or `expect interface`, `expect object`)
+/// carry no modifier of their own but are expected all the same. Only type
+/// parents pass the role on, so a function's parameters and locals do not.
+fn inherited_expect(
+    symbol: &Symbol,
+    by_id: &HashMap<&str, &Symbol>,
+    own_roles: &HashMap<&str, Role>,
+) -> Option<Role> {
+    let mut parent_id = symbol.parent_id.as_deref();
+    while let Some(parent) = parent_id.and_then(|id| by_id.get(id)) {
+        if !matches!(
+            parent.kind,
+            SymbolKind::Class
+                | SymbolKind::Interface
+                | SymbolKind::Struct
+                | SymbolKind::Enum
+                | SymbolKind::Module
+        ) {
+            return None;
+        }
+        if own_roles.get(parent.id.as_str()) == Some(&Role::Expect) {
+            return Some(Role::Expect);
+        }
+        parent_id = parent.parent_id.as_deref();
+    }
+    None
+}
+
+fn package_name(content: &str) -> Option<&str> {
+    content.lines().find_map(|line| {
+        let name = line.trim().strip_prefix("package ")?.trim();
+        Some(name.trim_end_matches(';').trim())
+    })
+}
+
+/// `kotlin:com.example.Platform.name/1`: package, enclosing declarations,
+/// name, and for callables the parameter count.
+fn group_key(
+    symbol: &Symbol,
+    by_id: &HashMap<&str, &Symbol>,
+    package: Option<&str>,
+    content: &str,
+) -> String {
+    let mut segments = vec![symbol.name.as_str()];
+    let mut parent_id = symbol.parent_id.as_deref();
+    while let Some(parent) = parent_id.and_then(|id| by_id.get(id)) {
+        segments.push(parent.name.as_str());
+        parent_id = parent.parent_id.as_deref();
+    }
+    segments.extend(package);
+    segments.reverse();
+    let mut key = format!("{KOTLIN_LANGUAGE}:{}", segments.join("."));
+    if matches!(
+        symbol.kind,
+        SymbolKind::Function | SymbolKind::Method | SymbolKind::Constructor
+    ) {
+        let text = symbol
+            .signature
+            .as_deref()
+            .unwrap_or_else(|| declaration_text(symbol, content));
+        key.push_str(&format!("/{}", parameter_count(text, &symbol.name)));
+    }
+    key
+}
+
+/// Non-empty entries of the parameter list after `name`. An `expect`
+/// declaration may give defaults its `actual` cannot repeat, so the count
+/// (not the text) identifies an overload.
+fn parameter_count(text: &str, name: &str) -> usize {
+    let after_name = text.find(name).map_or(0, |idx| idx + name.len());
+    let Some(open) = text[after_name..].find('(').map(|idx| after_name + idx) else {
+        return 0;
+    };
+    let mut depth = 0usize;
+    let mut count = 0;
+    let mut entry_has_text = false;
+    let mut previous = '(';
+    for ch in text[open..].chars() {
+        match ch {
+            '(' | '<' | '[' | '{' => depth += 1,
+            // `->` in a function type is not a closing bracket.
+            '>' if previous == '-' => {}
+            ')' | '>' | ']' | '}' => {
+                depth = depth.saturating_sub(1);
+                if depth == 0 {
+                    break;
+                }
+            }
+            ',' if depth == 1 => {
+                count += usize::from(entry_has_text);
+                entry_has_text = false;
+            }
+            _ => {}
+        }
+        if depth >= 1 && !matches!(ch, '(' | ',') && !ch.is_whitespace() {
+            entry_has_text = true;
+        }
+        previous = ch;
+    }
+    count + usize::from(entry_has_text)
+}
@@ -0,0 +1,230 @@
+//! Tokens for the extractors that read source text directly because
+//! julie-extractors has no grammar for the language: Ada, Fortran, Groovy,
+//! HCL, protobuf, Verilog and the procedural SQL dialects.
+//!
+//! Each of them keeps a small `tokenize` loop for its own lexical rules and
+//! hands the spans it finds to a [`Lexer`], which records them with their
+//! line and column. The scanning helpers here cover what the languages have
+//! in common. None of them fails: an unterminated string or comment runs to
+//! the end of its line or of the file, and the parsers step over statements
+//! they do not recognize, so a half-edited file still indexes.
+
+use crate::indexing_core::text_lines::TextLines;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum TokenKind {
+    Word,
+    Number,
+    Punct,
+    /// A quoted string or heredoc, quotes and markers included.
+    Str,
+    /// A quoted identifier: SQL `"Name"` or `[Name]`.
+    Quoted,
+    /// An Ada character literal (`'x'`).
+    Char,
+    /// A comment kept in the token stream, for parsers that read doc
+    /// comments inline (protobuf).
+    Comment,
+    /// End of a Fortran statement: a newline not continued with `&`, or `;`.
+    End,
+    /// A line holding only `/` (SQL*Plus) or `GO` (T-SQL batches).
+    Separator,
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct Token<'a> {
+    pub(crate) kind: TokenKind,
+    pub(crate) text: &'a str,
+    pub(crate) start: usize,
+    pub(crate) end: usize,
+    /// 1-based lines of `start` and of the last byte, and 0-based column of
+    /// `start`.
+    pub(crate) line: u32,
+    pub(crate) end_line: u32,
+    pub(crate) column: u32,
+    /// Lexed from a `${...}` or `%{...}` inside an HCL string.
+    pub(crate) interpolated: bool,
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct Comment<'a> {
+    /// Markers included.
+    pub(crate) text: &'a str,
+    pub(crate) start: usize,
+    pub(crate) line: u32,
+    pub(crate) end_line: u32,
+    /// Follows code on its first line (`int port // http`).
+    pub(crate) trailing: bool,
+}
+
+/// Collects the tokens and comments of one file.
+pub(crate) struct Lexer<'a> {
+    content: &'a str,
+    lines: TextLines,
+    tokens: Vec<Token<'a>>,
+    comments: Vec<Comment<'a>>,
+}
+
+impl<'a> Lexer<'a> {
+    pub(crate) fn new(content: &'a str) -> Self {
+        Self {
+            content,
+            lines: TextLines::new(content),
+            tokens: Vec::new(),
+            comments: Vec::new(),
+        }
+    }
+
+    pub(crate) fn last(&self) -> Option<&Token<'a>> {
+        self.tokens.last()
+    }
+
+    /// Byte offset of the start of the line holding `offset`.
+    pub(crate) fn line_start_of(&self, offset: usize) -> usize {
+        self.lines.line_start(self.lines.line_of(offset))
+    }
+
+    /// Record `content[start..end]` as a token.
+    pub(crate) fn push(&mut self, kind: TokenKind, start: usize, end: usize) -> &mut Token<'a> {
+        let line = self.lines.line_of(start);
+        self.tokens.push(Token {
+            kind,
+            text: &self.content[start..end],
+            start,
+            end,
+            line,
+            end_line: self.lines.line_of(end.saturating_sub(1).max(start)),
+            column: (start - self.lines.line_start(line)) as u32,
+            interpolated: false,
+        });
+        self.tokens.last_mut().expect("just pushed")
+    }
+
+    /// Record `content[start..end]` as a comment.
+    pub(crate) fn push_comment(&mut self, start: usize, end: usize) {
+        let line = self.lines.line_of(start);
+        self.comments.push(Comment {
+            text: &self.content[start..end],
+            start,
+            line,
+            end_line: self.lines.line_of(end.saturating_sub(1).max(start)),
+            trailing: self
+                .tokens
+                .last()
+                .is_some_and(|token| token.kind != TokenKind::End && token.end_line == line),
+        });
+    }
+
+    pub(crate) fn finish(self) -> (Vec<Token<'a>>, Vec<Comment<'a>>) {
+        (self.tokens, self.comments)
+    }
+}
+
+/// Index of the `\n` ending the line that holds `from`, or the end of
+/// `content`.
+pub(crate) fn line_end(content: &str, from: usize) -> usize {
+    content[from..]
+        .find('\n')
+        .map_or(content.len(), |end| from + end)
+}
+
+/// Index just past the first `close` at or after `from`, or the end of
+/// `content` when there is none.
+pub(crate) fn block_end(content: &str, from: usize, close: &str) -> usize {
+    content[from..]
+        .find(close)
+        .map_or(content.len(), |end| from + end + close.len())
+}
+
+/// Index just past the `quote` closing a quoted run whose body starts at
+/// `from`, where a doubled quote is an escaped one (`'it''s'`). A run still
+/// open at `to` ends there.
+pub(crate) fn doubled_quote_end(bytes: &[u8], from: usize, to: usize, quote: u8) -> usize {
+    let mut i = from;
+    while i < to {
+        if bytes[i] == quote {
+            if bytes.get(i + 1) == Some(&quote) {
+                i += 2;
+                continue;
+            }
+            return i + 1;
+        }
+        i += 1;
+    }
+    to
+}
+
+/// Index just past the `quote` closing a backslash-escaped string whose
+/// body starts at `from`. An unterminated string takes the rest of its line.
+pub(crate) fn escaped_quote_end(bytes: &[u8], from: usize, quote: u8) -> usize {
+    let mut i = from;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'\\' if bytes.get(i + 1).is_some_and(|next| *next != b'\n') => i += 2,
+            b'\n' => return i,
+            byte if byte == quote => return i + 1,
+            _ => i += 1,
+        }
+    }
+    bytes.len()
+}
+
+/// Index just past the run of `is_word_char` characters starting at `from`.
+pub(crate) fn word_end(content: &str, from: usize, is_word_char: impl Fn(char) -> bool) -> usize {
+    content[from..]
+        .char_indices()
+        .find(|(_, c)| !is_word_char(*c))
+        .map_or(content.len(), |(end, _)| from + end)
+}
+
+/// Length of the punctuation token `rest` starts with: the first of
+/// `compound` it starts with, or else one character.
+pub(crate) fn punct_len(rest: &str, compound: &[&str]) -> usize {
+    compound
+        .iter()
+        .find(|punct| rest.starts_with(**punct))
+        .map_or_else(
+            || rest.chars().next().map_or(1, char::len_utf8),
+            |punct| punct.len(),
+        )
+}
+
+/// Comment body lines without `//`, `#`, `/*`, `*/` and leading `*`.
+pub(crate) fn comment_lines(comment: &str) -> Vec<String> {
+    let body = comment
+        .strip_prefix('#')
+        .or_else(|| comment.strip_prefix("//"))
+        .or_else(|| {
+            comment
+                .strip_prefix("/*")
+                .map(|rest| rest.strip_suffix("*/").unwrap_or(rest))
+        })
+        .unwrap_or(comment);
+    body.lines()
+        .map(|line| {
+            let line = line.trim();
+            line.strip_prefix('*').unwrap_or(line).trim().to_string()
+        })
+        .filter(|line| !line.is_empty())
+        .collect()
+}
+
+/// Join `--` and `/* */` comments without their markers and leading `*`.
+/// Rulers (`-------`) and empty lines are not documentation.
+pub(crate) fn join_comments<'c, 'a: 'c>(
+    comments: impl Iterator<Item = &'c Comment<'a>>,
+) -> Option<String> {
+    let lines: Vec<String> = comments
+        .flat_map(|comment| {
+            let body = match comment.text.strip_prefix("/*") {
+                Some(block) => block.strip_suffix("*/").unwrap_or(block),
+                None => comment.text.trim_start_matches('-'),
+            };
+            body.lines()
+                .map(|line| line.trim().trim_start_matches('*').trim().to_string())
+                .collect::<Vec<_>>()
+        })
+        .filter(|line| line.chars().any(char::is_alphanumeric))
+        .collect();
+    (!lines.is_empty()).then(|| lines.join("\n"))
+}
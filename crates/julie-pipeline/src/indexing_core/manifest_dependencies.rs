@@ -0,0 +1,716 @@
+//! Dependency declarations in package manifests.
+//!
+//! The TOML/JSON extractors see `serde = "1.0"` or `"lodash": "^4.17.21"` as
+//! ordinary keys, and `go.mod` / `pom.xml` are indexed as plain text, so
+//! "where do we depend on lodash" has no symbol to land on. This pass reads
+//! the dependency sections of `Cargo.toml`, `package.json`, `pyproject.toml`,
+//! `go.mod` and `pom.xml` and gives every declaration a dependency symbol:
+//! an `Import` with semantic group [`DEPENDENCY_SEMANTIC_GROUP`] and
+//! `{ecosystem, package, section, version}` under
+//! [`DEPENDENCY_METADATA_KEY`]. A key symbol the extractor already produced
+//! on the declaration line is retagged in place; otherwise one is
+//! synthesized.
+//!
+//! Parsing is line-oriented and tolerant: anything unrecognized is skipped,
+//! never an error, because a half-edited manifest must still index.
+
+use std::collections::HashMap;
+
+use julie_extractors::base::{Symbol, SymbolKind};
+use serde_json::json;
+
+use crate::indexing_core::text_lines::TextLines;
+
+/// Metadata key holding `{ecosystem, package, section, version}`.
+pub const DEPENDENCY_METADATA_KEY: &str = "dependency";
+
+/// `semantic_group` shared by every dependency symbol, so searches and
+/// overviews can select them without matching on file names.
+pub const DEPENDENCY_SEMANTIC_GROUP: &str = "dependency";
+
+/// Dependency table names in `Cargo.toml` and Poetry's `pyproject.toml`.
+const TOML_DEPENDENCY_TABLES: &[&str] = &["dependencies", "dev-dependencies", "build-dependencies"];
+
+/// Top-level `package.json` objects that map package names to versions.
+const NPM_DEPENDENCY_OBJECTS: &[&str] = &[
+    "dependencies",
+    "devDependencies",
+    "peerDependencies",
+    "optionalDependencies",
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ManifestKind {
+    Cargo,
+    Npm,
+    Pyproject,
+    GoMod,
+    Maven,
+}
+
+impl ManifestKind {
+    /// Recognize a manifest by file name.
+    pub fn detect(file_path: &str) -> Option<Self> {
+        let file_name = file_path.rsplit('/').next().unwrap_or(file_path);
+        match file_name.to_ascii_lowercase().as_str() {
+            "cargo.toml" => Some(Self::Cargo),
+            "package.json" => Some(Self::Npm),
+            "pyproject.toml" => Some(Self::Pyproject),
+            "go.mod" => Some(Self::GoMod),
+            "pom.xml" => Some(Self::Maven),
+            _ => None,
+        }
+    }
+
+    pub fn ecosystem(self) -> &'static str {
+        match self {
+            Self::Cargo => "cargo",
+            Self::Npm => "npm",
+            Self::Pyproject => "pypi",
+            Self::GoMod => "go",
+            Self::Maven => "maven",
+        }
+    }
+}
+
+/// One dependency declaration. `name` is what users search for (crate,
+/// package, module path, or Maven artifactId); `package` is the full
+/// coordinate when it differs (a renamed crate, `groupId:artifactId`).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ManifestDependency {
+    pub name: String,
+    pub package: String,
+    pub version: Option<String>,
+    pub section: String,
+    /// 1-based line of the declaration.
+    pub line: u32,
+    pub column: u32,
+    /// The declaration's source line, trimmed.
+    pub declaration: String,
+}
+
+/// Tag or synthesize dependency symbols for a manifest. Other files are left
+/// untouched.
+pub fn annotate_manifest_dependencies(
+    symbols: &mut Vec<Symbol>,
+    file_path: &str,
+    language: &str,
+    content: &str,
+) {
+    let Some(kind) = ManifestKind::detect(file_path) else {
+        return;
+    };
+    let dependencies = parse_manifest_dependencies(kind, content);
+    if dependencies.is_empty() {
+        return;
+    }
+
+    let lines = TextLines::new(content);
+    let mut synthesized = Vec::new();
+    for dependency in &dependencies {
+        let info = json!({
+            "ecosystem": kind.ecosystem(),
+            "package": dependency.package,
+            "section": dependency.section,
+            "version": dependency.version,
+        });
+        match symbols
+            .iter_mut()
+            .find(|symbol| symbol.start_line == dependency.line && symbol.name == dependency.name)
+        {
+            Some(symbol) => tag_dependency(symbol, dependency, info),
+            None => synthesized.push(synthesize_symbol(
+                dependency, file_path, language, content, &lines, info,
+            )),
+        }
+    }
+    symbols.extend(synthesized);
+}
+
+/// Parse every dependency declaration in a manifest of the given kind.
+pub fn parse_manifest_dependencies(kind: ManifestKind, content: &str) -> Vec<ManifestDependency> {
+    match kind {
+        ManifestKind::Cargo => parse_toml_manifest(content, false),
+        ManifestKind::Pyproject => parse_toml_manifest(content, true),
+        ManifestKind::Npm => parse_package_json(content),
+        ManifestKind::GoMod => parse_go_mod(content),
+        ManifestKind::Maven => parse_pom(content),
+    }
+}
+
+fn tag_dependency(symbol: &mut Symbol, dependency: &ManifestDependency, info: serde_json::Value) {
+    symbol.kind = SymbolKind::Import;
+    symbol.signature = Some(dependency.declaration.clone());
+    symbol.semantic_group = Some(DEPENDENCY_SEMANTIC_GROUP.to_string());
+    symbol
+        .metadata
+        .get_or_insert_with(HashMap::new)
+        .insert(DEPENDENCY_METADATA_KEY.to_string(), info);
+}
+
+fn synthesize_symbol(
+    dependency: &ManifestDependency,
+    file_path: &str,
+    language: &str,
+    content: &str,
+    lines: &TextLines,
+    info: serde_json::Value,
+) -> Symbol {
+    let id_source = format!(
+        "{}:{}:{}:{}",
+        file_path, dependency.line, dependency.section, dependency.name
+    );
+    let id = blake3::hash(id_source.as_bytes()).to_hex()[..32].to_string();
+    let line_start = lines.line_start(dependency.line);
+    let line_end = content[line_start..]
+        .find('\n')
+        .map_or(content.len(), |offset| line_start + offset);
+    let line_text = content[line_start..line_end].trim_end_matches('\r');
+    Symbol {
+        id,
+        name: dependency.name.clone(),
+        kind: SymbolKind::Import,
+        language: language.to_string(),
+        file_path: file_path.to_string(),
+        start_line: dependency.line,
+        start_column: dependency.column,
+        end_line: dependency.line,
+        end_column: line_text.len() as u32,
+        start_byte: (line_start + dependency.column as usize) as u32,
+        end_byte: (line_start + line_text.len()) as u32,
+        signature: Some(dependency.declaration.clone()),
+        doc_comment: None,
+        visibility: None,
+        parent_id: None,
+        metadata: Some(HashMap::from([(DEPENDENCY_METADATA_KEY.to_string(), info)])),
+        semantic_group: Some(DEPENDENCY_SEMANTIC_GROUP.to_string()),
+        confidence: None,
+        code_context: Some(dependency.declaration.clone()),
+        content_type: None,
+        body_span: None,
+        body_hash: None,
+        annotations: Vec::new(),
+    }
+}
+
+/// Physical lines with their 1-based number and indentation width.
+fn numbered_lines(content: &str) -> impl Iterator<Item = (u32, u32, &str)> {
+    content.lines().enumerate().map(|(idx, line)| {
+        let indent = (line.len() - line.trim_start().len()) as u32;
+        (idx as u32 + 1, indent, line)
+    })
+}
+
+// ---------------------------------------------------------------------------
+// Cargo.toml / pyproject.toml
+// ---------------------------------------------------------------------------
+
+/// Where the TOML scanner is: a `name = version` table, a `[deps.NAME]`
+/// table whose `version` key belongs to one pending declaration, or a table
+/// whose arrays hold PEP 508 requirement strings.
+enum TomlSection {
+    Other,
+    KeyTable(String),
+    DependencyTable(usize),
+    RequirementArrays(String),
+}
+
+fn parse_toml_manifest(content: &str, pyproject: bool) -> Vec<ManifestDependency> {
+    let mut dependencies: Vec<ManifestDependency> = Vec::new();
+    let mut section = TomlSection::Other;
+    // Requirement array being read across lines, and its section label.
+    let mut open_array: Option<String> = None;
+
+    for (line_no, indent, line) in numbered_lines(content) {
+        let text = strip_toml_comment(line).trim();
+        if text.is_empty() {
+            continue;
+        }
+
+        if let Some(label) = open_array.clone() {
+            dependencies.extend(requirement_strings(text, &label, line_no, indent, line));
+            if closes_array(text) {
+                open_array = None;
+            }
+            continue;
+        }
+
+        if let Some(header) = text
+            .strip_prefix('[')
+            .and_then(|rest| rest.strip_suffix(']'))
+            .filter(|header| !header.starts_with('['))
+        {
+            let header = header.trim();
+            section = toml_section(header, pyproject);
+            if let TomlSection::DependencyTable(_) = section {
+                let (table, name) = header.rsplit_once('.').unwrap_or(("", header));
+                dependencies.push(ManifestDependency {
+                    name: unquote(name).to_string(),
+                    package: unquote(name).to_string(),
+                    version: None,
+                    section: table.to_string(),
+                    line: line_no,
+                    column: indent,
+                    declaration: text.to_string(),
+                });
+                section = TomlSection::DependencyTable(dependencies.len() - 1);
+            }
+            continue;
+        }
+
+        let Some((key, value)) = text.split_once('=') else {
+            continue;
+        };
+        let key = unquote(key.trim());
+        let value = value.trim();
+        match &section {
+            TomlSection::KeyTable(label) => {
+                if pyproject && key == "python" {
+                    continue;
+                }
+                // `serde = "1"`, `serde = { version = "1" }`, `serde.workspace = true`
+                let (key, subkey) = match key.split_once('.') {
+                    Some((name, subkey)) => (unquote(name), Some(unquote(subkey))),
+                    None => (key, None),
+                };
+                let (version, package) = match subkey {
+                    Some("version") => (quoted(value).map(str::to_string), None),
+                    Some(_) => (None, None),
+                    None if value.starts_with('{') => (
+                        inline_string(value, "version"),
+                        inline_string(value, "package"),
+                    ),
+                    None => (quoted(value).map(str::to_string), None),
+                };
+                dependencies.push(ManifestDependency {
+                    name: key.to_string(),
+                    package: package.unwrap_or_else(|| key.to_string()),
+                    version,
+                    section: label.clone(),
+                    line: line_no,
+                    column: indent,
+                    declaration: text.to_string(),
+                });
+            }
+            TomlSection::DependencyTable(index) => match key {
+                "version" => dependencies[*index].version = quoted(value).map(str::to_string),
+                "package" => {
+                    if let Some(package) = quoted(value) {
+                        dependencies[*index].package = package.to_string();
+                    }
+                }
+                _ => {}
+            },
+            TomlSection::RequirementArrays(table) if value.starts_with('[') => {
+                let label = if table == "project" {
+                    "project.dependencies".to_string()
+                } else {
+                    format!("{table}.{key}")
+                };
+                let accepts = match table.as_str() {
+                    "project" => key == "dependencies",
+                    "build-system" => key == "requires",
+                    _ => true,
+                };
+                if !accepts {
+                    continue;
+                }
+                dependencies.extend(requirement_strings(value, &label, line_no, indent, line));
+                if !closes_array(value) {
+                    open_array = Some(label);
+                }
+            }
+            _ => {}
+        }
+    }
+    dependencies
+}
+
+fn toml_section(header: &str, pyproject: bool) -> TomlSection {
+    let is_dependency_table = |name: &str| TOML_DEPENDENCY_TABLES.contains(&name);
+    let last = header.rsplit('.').next().unwrap_or(header);
+
+    if pyproject {
+        return match header {
+            "project" | "project.optional-dependencies" | "build-system" => {
+                TomlSection::RequirementArrays(header.to_string())
+            }
+            _ if header.starts_with("tool.poetry") && is_dependency_table(last) => {
+                TomlSection::KeyTable(header.to_string())
+            }
+            _ => TomlSection::Other,
+        };
+    }
+
+    if is_dependency_table(last) {
+        return TomlSection::KeyTable(header.to_string());
+    }
+    // `[dependencies.serde]`, `[target.'cfg(unix)'.dev-dependencies.libc]`
+    match header.rsplit_once('.') {
+        Some((table, _)) if is_dependency_table(table.rsplit('.').next().unwrap_or(table)) => {
+            TomlSection::DependencyTable(0)
+        }
+        _ => TomlSection::Other,
+    }
+}
+
+/// Every quoted PEP 508 requirement on one line of a TOML array.
+fn requirement_strings(
+    text: &str,
+    label: &str,
+    line_no: u32,
+    indent: u32,
+    line: &str,
+) -> Vec<ManifestDependency> {
+    quoted_strings(text)
+        .into_iter()
+        .filter_map(|requirement| {
+            let (name, version) = parse_requirement(requirement)?;
+            Some(ManifestDependency {
+                package: name.clone(),
+                name,
+                version,
+                section: label.to_string(),
+                line: line_no,
+                column: indent,
+                declaration: line.trim().trim_end_matches(',').to_string(),
+            })
+        })
+        .collect()
+}
+
+/// `requests[socks]>=2.31; python_version >= "3.8"` -> (`requests`, `>=2.31`).
+fn parse_requirement(requirement: &str) -> Option<(String, Option<String>)> {
+    let requirement = requirement.trim();
+    let name_len = requirement
+        .find(|c: char| !(c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.')))
+        .unwrap_or(requirement.len());
+    if name_len == 0 {
+        return None;
+    }
+    let mut rest = requirement[name_len..].trim_start();
+    if rest.starts_with('[') {
+        rest = rest.find(']').map_or("", |close| &rest[close + 1..]);
+    }
+    let spec = rest.split(';').next().unwrap_or("").trim();
+    let version = (!spec.is_empty() && !spec.starts_with('@')).then(|| spec.to_string());
+    Some((requirement[..name_len].to_string(), version))
+}
+
+fn strip_toml_comment(line: &str) -> &str {
+    let mut in_string: Option<char> = None;
+    for (i, c) in line.char_indices() {
+        match (c, in_string) {
+            ('"' | '\'', None) => in_string = Some(c),
+            (c, Some(open)) if c == open => in_string = None,
+            ('#', None) => return &line[..i],
+            _ => {}
+        }
+    }
+    line
+}
+
+/// The string value of `key` inside an inline table such as
+/// `{ version = "1.0", features = ["derive"] }`.
+fn inline_string(table: &str, key: &str) -> Option<String> {
+    let mut search = table;
+    while let Some(pos) = search.find(key) {
+        let before = search[..pos].chars().next_back();
+        let after = search[pos + key.len()..].trim_start();
+        if before.is_none_or(|c| !(c.is_ascii_alphanumeric() || c == '_' || c == '-'))
+            && let Some(value) = after.strip_prefix('=')
+        {
+            return quoted(value.trim_start()).map(str::to_string);
+        }
+        search = &search[pos + key.len()..];
+    }
+    None
+}
+
+/// The contents of a leading `"..."` or `'...'` literal.
+fn quoted(value: &str) -> Option<&str> {
+    let quote = value.chars().next().filter(|c| matches!(c, '"' | '\''))?;
+    let rest = &value[1..];
+    rest.find(quote).map(|end| &rest[..end])
+}
+
+fn quoted_strings(text: &str) -> Vec<&str> {
+    let mut strings = Vec::new();
+    let mut rest = text;
+    while let Some(start) = rest.find(['"', '\'']) {
+        let Some(value) = quoted(&rest[start..]) else {
+            break;
+        };
+        strings.push(value);
+        rest = &rest[start + value.len() + 2..];
+    }
+    strings
+}
+
+/// Whether a `]` outside string literals ends the array on this line
+/// (`"requests[socks]"` does not).
+fn closes_array(text: &str) -> bool {
+    let mut rest = text;
+    while let Some(pos) = rest.find(['"', '\'', ']']) {
+        if rest[pos..].starts_with(']') {
+            return true;
+        }
+        match quoted(&rest[pos..]) {
+            Some(value) => rest = &rest[pos + value.len() + 2..],
+            None => return false,
+        }
+    }
+    false
+}
+
+fn unquote(key: &str) -> &str {
+    quoted(key).unwrap_or(key)
+}
+
+// ---------------------------------------------------------------------------
+// package.json
+// ---------------------------------------------------------------------------
+
+#[derive(Debug, PartialEq)]
+enum JsonToken<'a> {
+    String(&'a str),
+    Open,
+    Close,
+    Colon,
+    Other,
+}
+
+/// JSON tokens with their 1-based line and column. Only strings and
+/// structure matter; scalars collapse to `Other`.
+fn json_tokens(content: &str) -> Vec<(JsonToken<'_>, u32, u32)> {
+    let mut tokens = Vec::new();
+    let (mut line, mut line_start) = (1u32, 0usize);
+    let mut chars = content.char_indices().peekable();
+    while let Some((i, c)) = chars.next() {
+        let column = (i - line_start) as u32;
+        match c {
+            '\n' => {
+                line += 1;
+                line_start = i + 1;
+            }
+            '"' => {
+                let start = i + 1;
+                let mut end = content.len();
+                let mut escaped = false;
+                for (j, c) in chars.by_ref() {
+                    match c {
+                        '\\' if !escaped => escaped = true,
+                        '"' if !escaped => {
+                            end = j;
+                            break;
+                        }
+                        _ => escaped = false,
+                    }
+                }
+                tokens.push((JsonToken::String(&content[start..end]), line, column));
+            }
+            '{' | '[' => tokens.push((JsonToken::Open, line, column)),
+            '}' | ']' => tokens.push((JsonToken::Close, line, column)),
+            ':' => tokens.push((JsonToken::Colon, line, column)),
+            ',' => {}
+            c if c.is_whitespace() => {}
+            _ => {
+                while chars
+                    .peek()
+                    .is_some_and(|(_, c)| !matches!(c, ',' | '}' | ']' | '\n'))
+                {
+                    chars.next();
+                }
+                tokens.push((JsonToken::Other, line, column));
+            }
+        }
+    }
+    tokens
+}
+
+fn parse_package_json(content: &str) -> Vec<ManifestDependency> {
+    let lines: Vec<&str> = content.lines().collect();
+    let tokens = json_tokens(content);
+    let mut dependencies = Vec::new();
+    let mut depth = 0usize;
+    let mut section: Option<&str> = None;
+
+    for (i, (token, line, column)) in tokens.iter().enumerate() {
+        let next = tokens.get(i + 1).map(|(token, ..)| token);
+        let value = tokens.get(i + 2).map(|(token, ..)| token);
+        match token {
+            JsonToken::Open => depth += 1,
+            JsonToken::Close => {
+                depth = depth.saturating_sub(1);
+                if depth < 2 {
+                    section = None;
+                }
+            }
+            JsonToken::String(key) if next == Some(&JsonToken::Colon) => {
+                if depth == 1 && value == Some(&JsonToken::Open) {
+                    section = NPM_DEPENDENCY_OBJECTS
+                        .iter()
+                        .find(|object| **object == *key)
+                        .copied();
+                } else if let (2, Some(label), Some(JsonToken::String(version))) =
+                    (depth, section, value)
+                {
+                    dependencies.push(ManifestDependency {
+                        name: key.to_string(),
+                        package: key.to_string(),
+                        version: Some(version.to_string()),
+                        section: label.to_string(),
+                        line: *line,
+                        column: *column,
+                        declaration: lines
+                            .get(*line as usize - 1)
+                            .map_or("", |line| line.trim().trim_end_matches(','))
+                            .to_string(),
+                    });
+                }
+            }
+            _ => {}
+        }
+    }
+    dependencies
+}
+
+// ---------------------------------------------------------------------------
+// go.mod
+// ---------------------------------------------------------------------------
+
+fn parse_go_mod(content: &str) -> Vec<ManifestDependency> {
+    let mut dependencies = Vec::new();
+    let mut in_block = false;
+    for (line_no, indent, line) in numbered_lines(content) {
+        let (code, comment) = line.split_once("//").unwrap_or((line, ""));
+        let code = code.trim();
+        let spec = if in_block {
+            if code == ")" {
+                in_block = false;
+                continue;
+            }
+            code
+        } else if let Some(rest) = code.strip_prefix("require") {
+            let rest = rest.trim();
+            if rest == "(" {
+                in_block = true;
+                continue;
+            }
+            rest
+        } else {
+            continue;
+        };
+
+        let mut parts = spec.split_whitespace();
+        let (Some(module), version) = (parts.next(), parts.next()) else {
+            continue;
+        };
+        let section = if comment.trim() == "indirect" {
+            "indirect"
+        } else {
+            "require"
+        };
+        dependencies.push(ManifestDependency {
+            name: module.to_string(),
+            package: module.to_string(),
+            version: version.map(str::to_string),
+            section: section.to_string(),
+            line: line_no,
+            column: indent,
+            declaration: line.trim().to_string(),
+        });
+    }
+    dependencies
+}
+
+// ---------------------------------------------------------------------------
+// pom.xml
+// ---------------------------------------------------------------------------
+
+fn parse_pom(content: &str) -> Vec<ManifestDependency> {
+    #[derive(Default)]
+    struct Pending {
+        group: Option<String>,
+        artifact: Option<(String, u32, u32, String)>,
+        version: Option<String>,
+        scope: Option<String>,
+    }
+
+    let mut dependencies = Vec::new();
+    let mut managed = false;
+    let mut in_exclusions = false;
+    let mut current: Option<Pending> = None;
+    for (line_no, indent, line) in numbered_lines(content) {
+        let text = line.trim();
+        if text.starts_with("<dependencyManagement") {
+            managed = true;
+        } else if text.starts_with("</dependencyManagement") {
+            managed = false;
+        }
+        if text.starts_with("<dependency>") {
+            current = Some(Pending::default());
+        }
+        let Some(pending) = current.as_mut() else {
+            continue;
+        };
+        // Excluded transitive artifacts reuse groupId/artifactId.
+        if text.starts_with("<exclusions") {
+            in_exclusions = !text.contains("</exclusions>");
+            continue;
+        }
+        if in_exclusions {
+            in_exclusions = !text.contains("</exclusions>");
+            continue;
+        }
+        if let Some(group) = xml_element(text, "groupId") {
+            pending.group = Some(group.to_string());
+        }
+        if let Some(artifact) = xml_element(text, "artifactId") {
+            pending.artifact = Some((artifact.to_string(), line_no, indent, text.to_string()));
+        }
+        if let Some(version) = xml_element(text, "version") {
+            pending.version = Some(version.to_string());
+        }
+        if let Some(scope) = xml_element(text, "scope") {
+            pending.scope = Some(scope.to_string());
+        }
+        if text.contains("</dependency>") {
+            let Pending {
+                group,
+                artifact,
+                version,
+                scope,
+            } = current.take().unwrap_or_default();
+            let Some((artifact, line, column, declaration)) = artifact else {
+                continue;
+            };
+            let section = if managed {
+                "dependencyManagement".to_string()
+            } else {
+                scope.unwrap_or_else(|| "compile".to_string())
+            };
+            dependencies.push(ManifestDependency {
+                package: match group {
+                    Some(group) => format!("{group}:{artifact}"),
+                    None => artifact.clone(),
+                },
+                name: artifact,
+                version,
+                section,
+                line,
+                column,
+                declaration,
+            });
+        }
+    }
+    dependencies
+}
+
+/// Text of `<tag>text</tag>` when it appears on the given line.
+fn xml_element<'a>(text: &'a str, tag: &str) -> Option<&'a str> {
+    let open = format!("<{tag}>");
+    let start = text.find(&open)? + open.len();
+    let end = text[start..].find("</")? + start;
+    Some(text[start..end].trim())
+}
@@ -0,0 +1,568 @@
+//! Markdown cross-links: the code a document mentions, as relationships.
+//!
+//! julie-extractors turns each heading of a Markdown file into a section
+//! symbol, but what the prose points at was lost, so a design doc walking
+//! through `authenticate()` never showed up among that function's
+//! references. This pass gives every section its GitHub-style heading anchor
+//! under [`MARKDOWN_METADATA_KEY`] and records one pending `References` link
+//! from the innermost section around each mention:
+//!
+//! - a relative link, `[flow](../src/auth.rs#L40)` or `[setup](guide.md#install)`;
+//! - an inline code span naming a file (`src/auth.rs:40`) or a symbol
+//!   (`authenticate`, `Session::refresh()`);
+//! - a fenced code block, through a path in its info string
+//!   (`rust title="src/auth.rs"` or `rust:src/auth.rs`) and the functions
+//!   its code calls.
+//!
+//! Links point into other files, so they are left pending with a callee
+//! carrying [`FILE_CALLEE_PREFIX`] or [`SYMBOL_CALLEE_PREFIX`], and finalize
+//! hands them to [`resolve_doc_links`]. A file link targets the symbol
+//! around an `#L<line>` fragment, the section an `#anchor` fragment names,
+//! or else the file's top-level symbols. A symbol mention targets the code
+//! definitions of that name when there are at most [`MAX_SYMBOL_MATCHES`],
+//! so a name as common as `new` links nothing. Every resolved link carries
+//! the anchor of the section it came from under [`DOC_ANCHOR_METADATA_KEY`],
+//! which is how fast_refs reports "documented in docs/architecture.md#auth-flow".
+
+use std::collections::{HashMap, HashSet};
+
+use julie_core::database::SymbolDatabase;
+use julie_core::file_policy::supported_extensions_for_indexing;
+use julie_extractors::{
+    ExtractionResults, PendingRelationship, Relationship, RelationshipKind, Symbol, SymbolKind,
+};
+use serde_json::json;
+use tracing::{debug, warn};
+
+/// Language of the files this pass reads.
+pub const MARKDOWN_LANGUAGE: &str = "markdown";
+
+/// Metadata key holding the heading facts of a section symbol, so far its
+/// `anchor`.
+pub const MARKDOWN_METADATA_KEY: &str = "markdown";
+
+/// Relationship metadata key holding the anchor of the section a resolved
+/// link came from.
+pub const DOC_ANCHOR_METADATA_KEY: &str = "doc_anchor";
+
+/// Prefix on the `callee_name` of a pending link to a file. The rest of the
+/// name is the workspace-relative path, with an optional `#fragment`.
+pub const FILE_CALLEE_PREFIX: &str = "doc-file:";
+
+/// Prefix on the `callee_name` of a pending mention of a symbol by name.
+pub const SYMBOL_CALLEE_PREFIX: &str = "doc-symbol:";
+
+/// A symbol mention links only when at most this many definitions share
+/// the name.
+pub const MAX_SYMBOL_MATCHES: usize = 3;
+
+/// Top-level symbols a link to a whole file fans out to.
+const MAX_FILE_TARGETS: usize = 20;
+
+/// Shorter names (`id`, `io`) are too ambiguous to link from prose.
+const MIN_SYMBOL_NAME_LEN: usize = 3;
+
+/// Give each section of a Markdown file its heading anchor and record a
+/// pending link for every file and symbol its sections mention. Returns how
+/// many links were recorded.
+pub fn link_markdown_references(
+    results: &mut ExtractionResults,
+    relative_path: &str,
+    language: &str,
+    content: &str,
+) -> usize {
+    if language != MARKDOWN_LANGUAGE || results.symbols.is_empty() {
+        return 0;
+    }
+
+    // Code in fenced blocks may have been extracted in its own language;
+    // only the headings are sections.
+    let mut sections: Vec<usize> = (0..results.symbols.len())
+        .filter(|&index| results.symbols[index].language == MARKDOWN_LANGUAGE)
+        .collect();
+    sections.sort_by_key(|&index| results.symbols[index].start_line);
+    let mut seen_anchors: HashMap<String, usize> = HashMap::new();
+    for &index in &sections {
+        let symbol = &mut results.symbols[index];
+        let base = heading_anchor(&symbol.name);
+        let count = seen_anchors.entry(base.clone()).or_insert(0);
+        let anchor = match *count {
+            0 => base,
+            n => format!("{base}-{n}"),
+        };
+        *count += 1;
+        symbol.metadata.get_or_insert_with(Default::default).insert(
+            MARKDOWN_METADATA_KEY.to_string(),
+            json!({ "anchor": anchor }),
+        );
+    }
+
+    let mut linked = HashSet::new();
+    let mut count = 0;
+    for (line_number, callee_name) in scan_mentions(relative_path, content) {
+        // Innermost section around the line: the latest to start that has
+        // not ended yet.
+        let Some(section) = sections
+            .iter()
+            .rev()
+            .map(|&i| &results.symbols[i])
+            .find(|s| s.start_line <= line_number && line_number <= s.end_line.max(s.start_line))
+        else {
+            continue;
+        };
+        if !linked.insert((section.id.clone(), callee_name.clone())) {
+            continue;
+        }
+        let pending = PendingRelationship {
+            from_symbol_id: section.id.clone(),
+            callee_name,
+            kind: RelationshipKind::References,
+            file_path: relative_path.to_string(),
+            line_number,
+            confidence: 0.8,
+        };
+        results.pending_relationships.push(pending);
+        count += 1;
+    }
+    count
+}
+
+/// The GitHub anchor of a heading: lowercased, punctuation dropped, spaces
+/// turned into hyphens. Repeats get `-1`, `-2`, ... from the caller.
+pub fn heading_anchor(heading: &str) -> String {
+    heading
+        .trim()
+        .trim_start_matches('#')
+        .trim()
+        .chars()
+        .filter_map(|ch| match ch {
+            ' ' => Some('-'),
+            '-' | '_' => Some(ch),
+            _ if ch.is_alphanumeric() => Some(ch),
+            _ => None,
+        })
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+/// The anchor this pass stored on a section symbol.
+pub fn section_anchor(symbol: &Symbol) -> Option<&str> {
+    symbol
+        .metadata
+        .as_ref()?
+        .get(MARKDOWN_METADATA_KEY)?
+        .get("anchor")?
+        .as_str()
+}
+
+// ---------------------------------------------------------------------------
+// Mentions
+// ---------------------------------------------------------------------------
+
+/// Every mention in `content`, as (1-based line, pending callee name).
+pub fn scan_mentions(relative_path: &str, content: &str) -> Vec<(u32, String)> {
+    let doc_dir = relative_path.rsplit_once('/').map_or("", |(dir, _)| dir);
+    let mut mentions = Vec::new();
+    let mut fence: Option<(char, usize)> = None;
+    for (index, line) in content.lines().enumerate() {
+        let line_number = index as u32 + 1;
+        let trimmed = line.trim_start();
+
+        if let Some((marker, length)) = fence {
+            if closes_fence(trimmed, marker, length) {
+                fence = None;
+            } else {
+                mentions.extend(
+                    calls_in_code(line)
+                        .map(|name| (line_number, format!("{SYMBOL_CALLEE_PREFIX}{name}"))),
+                );
+            }
+            continue;
+        }
+        if let Some((marker, length, info)) = opens_fence(trimmed) {
+            fence = Some((marker, length));
+            if let Some(path) = fence_info_path(info) {
+                mentions.push((line_number, format!("{FILE_CALLEE_PREFIX}{path}")));
+            }
+            continue;
+        }
+
+        for target in link_targets(line) {
+            if let Some(target) = resolve_link_target(doc_dir, relative_path, target) {
+                mentions.push((line_number, format!("{FILE_CALLEE_PREFIX}{target}")));
+            }
+        }
+        for span in code_spans(line) {
+            if let Some(path) = code_span_path(span) {
+                mentions.push((line_number, format!("{FILE_CALLEE_PREFIX}{path}")));
+            } else if let Some(name) = code_span_symbol(span) {
+                mentions.push((line_number, format!("{SYMBOL_CALLEE_PREFIX}{name}")));
+            }
+        }
+    }
+    mentions
+}
+
+/// A fence opener: at least three backticks or tildes, then the info
+/// string.
+fn opens_fence(line: &str) -> Option<(char, usize, &str)> {
+    let marker = line.chars().next().filter(|ch| matches!(ch, '`' | '~'))?;
+    let length = line.chars().take_while(|ch| *ch == marker).count();
+    if length < 3 {
+        return None;
+    }
+    let info = line[length..].trim();
+    // A backtick fence's info string cannot hold backticks; that is a code
+    // span.
+    (marker == '~' || !info.contains('`')).then_some((marker, length, info))
+}
+
+fn closes_fence(line: &str, marker: char, length: usize) -> bool {
+    let run = line.chars().take_while(|ch| *ch == marker).count();
+    run >= length && line[run..].trim().is_empty()
+}
+
+/// A path named by a fence info string: `rust:src/auth.rs`,
+/// `rust title="src/auth.rs"`, `file=src/auth.rs`.
+fn fence_info_path(info: &str) -> Option<String> {
+    info.split_whitespace().find_map(|token| {
+        let value = ["title=", "file=", "filename="]
+            .iter()
+            .find_map(|key| token.strip_prefix(key))
+            .or_else(|| token.split_once(':').map(|(_, path)| path))
+            .unwrap_or(token)
+            .trim_matches(|ch| ch == '"' || ch == '\'');
+        code_span_path(value)
+    })
+}
+
+/// Names called in a line of code: identifiers right before `(`.
+fn calls_in_code(line: &str) -> impl Iterator<Item = &str> {
+    line.match_indices('(').filter_map(|(offset, _)| {
+        let before = line[..offset].trim_end();
+        let start = before
+            .rfind(|ch: char| !is_identifier_char(ch))
+            .map_or(0, |i| i + 1);
+        let name = &before[start..];
+        is_linkable_name(name).then_some(name)
+    })
+}
+
+/// Targets of the inline links (`[text](target "title")`) and reference
+/// definitions (`[id]: target`) in a line.
+fn link_targets(line: &str) -> Vec<&str> {
+    let trimmed = line.trim_start();
+    if trimmed.starts_with('[')
+        && let Some((label, rest)) = trimmed[1..].split_once("]:")
+        && !label.is_empty()
+    {
+        return rest.split_whitespace().next().into_iter().collect();
+    }
+
+    let mut targets = Vec::new();
+    let mut rest = line;
+    while let Some(start) = rest.find("](") {
+        let after = &rest[start + 2..];
+        let end = after.find(')').unwrap_or(after.len());
+        if let Some(target) = after[..end].split_whitespace().next() {
+            targets.push(target);
+        }
+        rest = &after[end..];
+    }
+    targets
+}
+
+/// A link target as a workspace-relative `path#fragment`, or `None` for
+/// URLs and paths that leave the workspace.
+fn resolve_link_target(doc_dir: &str, doc_path: &str, target: &str) -> Option<String> {
+    let target = target.trim_start_matches('<').trim_end_matches('>');
+    if target.contains("://") || has_scheme(target) {
+        return None;
+    }
+    let (path, fragment) = target.split_once('#').unwrap_or((target, ""));
+    let path = path.replace("%20", " ");
+    let path = if path.is_empty() {
+        // `#anchor` points into the document itself.
+        if fragment.is_empty() {
+            return None;
+        }
+        doc_path.to_string()
+    } else if let Some(rooted) = path.strip_prefix('/') {
+        normalize_path("", rooted)?
+    } else {
+        normalize_path(doc_dir, &path)?
+    };
+    if path.is_empty() || path.ends_with('/') {
+        return None;
+    }
+    Some(match fragment {
+        "" => path,
+        fragment => format!("{path}#{fragment}"),
+    })
+}
+
+/// `mailto:`, `tel:` and other schemes.
+fn has_scheme(target: &str) -> bool {
+    target.split_once(':').is_some_and(|(scheme, _)| {
+        !scheme.is_empty() && scheme.chars().all(|ch| ch.is_ascii_alphabetic())
+    })
+}
+
+/// `path` joined onto `dir` with `.` and `..` segments folded, or `None`
+/// when it climbs out of the workspace.
+fn normalize_path(dir: &str, path: &str) -> Option<String> {
+    let mut segments: Vec<&str> = dir.split('/').filter(|s| !s.is_empty()).collect();
+    for segment in path.split('/') {
+        match segment {
+            "" | "." => {}
+            ".." => {
+                segments.pop()?;
+            }
+            _ => segments.push(segment),
+        }
+    }
+    Some(segments.join("/"))
+}
+
+/// The contents of the inline code spans in a line.
+fn code_spans(line: &str) -> Vec<&str> {
+    let mut spans = Vec::new();
+    let mut rest = line;
+    while let Some(start) = rest.find('`') {
+        let ticks = rest[start..].chars().take_while(|ch| *ch == '`').count();
+        let delimiter = &rest[start..start + ticks];
+        let after = &rest[start + ticks..];
+        let Some(end) = after.find(delimiter) else {
+            break;
+        };
+        spans.push(after[..end].trim());
+        rest = &after[end + ticks..];
+    }
+    spans
+}
+
+/// A code span naming a file: `src/auth.rs`, `./build.rs`, `src/auth.rs:40`.
+/// A bare file name counts only with an extension julie indexes, so that
+/// `self.session` is not read as a file.
+fn code_span_path(span: &str) -> Option<String> {
+    if span.is_empty()
+        || span.contains(char::is_whitespace)
+        || span.contains("://")
+        || span.contains(['(', ')', '<', '>', '*', '{', '}'])
+        || span.contains("::")
+    {
+        return None;
+    }
+    let (path, fragment) = match span.rsplit_once(':') {
+        Some((path, line)) if !line.is_empty() && line.chars().all(|ch| ch.is_ascii_digit()) => {
+            (path, Some(line))
+        }
+        _ => (span, None),
+    };
+    let path = normalize_path("", path.trim_start_matches("./").trim_start_matches('/'))?;
+    let file_name = path.rsplit('/').next()?;
+    let (stem, extension) = file_name.rsplit_once('.')?;
+    let extension = extension.to_lowercase();
+    if extension.is_empty()
+        || !extension.chars().all(|ch| ch.is_ascii_alphanumeric())
+        || (stem.is_empty() && !path.contains('/'))
+        || (!path.contains('/') && !supported_extensions_for_indexing().contains(&extension))
+    {
+        return None;
+    }
+    Some(match fragment {
+        Some(line) => format!("{path}#L{line}"),
+        None => path,
+    })
+}
+
+/// A code span naming a symbol: `authenticate`, `authenticate()`,
+/// `Session::refresh`, `session.refresh(token)`. The last segment is the
+/// name linked.
+fn code_span_symbol(span: &str) -> Option<&str> {
+    let span = match span.find('(') {
+        Some(open) if span.ends_with(')') => &span[..open],
+        Some(_) => return None,
+        None => span,
+    };
+    let segments: Vec<&str> = span.split("::").flat_map(|part| part.split('.')).collect();
+    if segments
+        .iter()
+        .any(|segment| segment.is_empty() || !segment.chars().all(is_identifier_char))
+    {
+        return None;
+    }
+    let name = *segments.last()?;
+    is_linkable_name(name).then_some(name)
+}
+
+fn is_identifier_char(ch: char) -> bool {
+    ch.is_alphanumeric() || ch == '_'
+}
+
+fn is_linkable_name(name: &str) -> bool {
+    name.chars().count() >= MIN_SYMBOL_NAME_LEN
+        && name.chars().all(is_identifier_char)
+        && !name.starts_with(|ch: char| ch.is_ascii_digit())
+}
+
+// ---------------------------------------------------------------------------
+// Cross-file links
+// ---------------------------------------------------------------------------
+
+/// Is this pending relationship a documentation link recorded by this pass?
+pub fn is_doc_link(pending: &PendingRelationship) -> bool {
+    pending.callee_name.starts_with(FILE_CALLEE_PREFIX)
+        || pending.callee_name.starts_with(SYMBOL_CALLEE_PREFIX)
+}
+
+/// Resolve documentation links against the symbols of the files and names
+/// they mention, tagging each with the anchor of the section it came from.
+pub fn resolve_doc_links(
+    pendings: &[PendingRelationship],
+    db: &SymbolDatabase,
+) -> Vec<Relationship> {
+    let links: Vec<&PendingRelationship> = pendings.iter().filter(|p| is_doc_link(p)).collect();
+    if links.is_empty() {
+        return Vec::new();
+    }
+
+    let from_ids: Vec<String> = links
+        .iter()
+        .map(|link| link.from_symbol_id.clone())
+        .collect::<HashSet<_>>()
+        .into_iter()
+        .collect();
+    let anchors: HashMap<String, String> = match db.get_symbols_by_ids(&from_ids) {
+        Ok(sections) => sections
+            .iter()
+            .filter_map(|section| Some((section.id.clone(), section_anchor(section)?.to_string())))
+            .collect(),
+        Err(e) => {
+            warn!("Failed to load documentation sections: {}", e);
+            HashMap::new()
+        }
+    };
+    let names: Vec<String> = links
+        .iter()
+        .filter_map(|link| link.callee_name.strip_prefix(SYMBOL_CALLEE_PREFIX))
+        .map(str::to_string)
+        .collect();
+    let by_name = db.find_symbols_by_names_batch(&names).unwrap_or_else(|e| {
+        warn!("Failed to look up documented symbols: {}", e);
+        HashMap::new()
+    });
+
+    let mut files: HashMap<String, Vec<Symbol>> = HashMap::new();
+    let mut resolved = Vec::new();
+    for pending in &links {
+        let callee = pending.callee_name.as_str();
+        let targets: Vec<&Symbol> = if let Some(target) = callee.strip_prefix(FILE_CALLEE_PREFIX) {
+            let (path, fragment) = target.split_once('#').unwrap_or((target, ""));
+            let symbols = files.entry(path.to_string()).or_insert_with(|| {
+                db.get_symbols_for_file(path).unwrap_or_else(|e| {
+                    warn!("Failed to load symbols for {}: {}", path, e);
+                    Vec::new()
+                })
+            });
+            file_targets(symbols, fragment)
+        } else if let Some(name) = callee.strip_prefix(SYMBOL_CALLEE_PREFIX) {
+            let definitions: Vec<&Symbol> = by_name
+                .get(name)
+                .into_iter()
+                .flatten()
+                .filter(|symbol| is_definition(symbol) && !is_markdown_path(&symbol.file_path))
+                .collect();
+            if definitions.len() > MAX_SYMBOL_MATCHES {
+                continue;
+            }
+            definitions
+        } else {
+            continue;
+        };
+
+        for target in targets {
+            if target.id == pending.from_symbol_id {
+                continue;
+            }
+            let mut relationship = crate::resolver::build_resolved_relationship(pending, target);
+            if let Some(anchor) = anchors.get(&pending.from_symbol_id) {
+                relationship
+                    .metadata
+                    .get_or_insert_with(Default::default)
+                    .insert(DOC_ANCHOR_METADATA_KEY.to_string(), json!(anchor));
+            }
+            resolved.push(relationship);
+        }
+    }
+    debug!(
+        "Linked {} documentation mentions to {} symbols",
+        links.len(),
+        resolved.len()
+    );
+    resolved
+}
+
+/// The symbols of a file a link with `fragment` points at: the innermost
+/// symbol around an `L<line>` fragment, the section an anchor fragment
+/// names, or the file's top-level symbols.
+fn file_targets<'a>(symbols: &'a [Symbol], fragment: &str) -> Vec<&'a Symbol> {
+    let line = fragment
+        .strip_prefix('L')
+        .and_then(|lines| lines.split('-').next())
+        .and_then(|line| line.parse::<u32>().ok());
+    if let Some(line) = line {
+        return symbols
+            .iter()
+            .filter(|symbol| {
+                symbol.kind != SymbolKind::Import
+                    && symbol.start_line <= line
+                    && line <= symbol.end_line
+            })
+            .min_by_key(|symbol| symbol.end_line.saturating_sub(symbol.start_line))
+            .into_iter()
+            .collect();
+    }
+    if !fragment.is_empty() {
+        return symbols
+            .iter()
+            .filter(|symbol| section_anchor(symbol) == Some(fragment))
+            .collect();
+    }
+    symbols
+        .iter()
+        .filter(|symbol| symbol.parent_id.is_none() && symbol.kind != SymbolKind::Import)
+        .take(MAX_FILE_TARGETS)
+        .collect()
+}
+
+/// Code examples embedded in documentation are not what a mention means.
+fn is_markdown_path(path: &str) -> bool {
+    path.rsplit_once('.').is_some_and(|(_, extension)| {
+        matches!(
+            extension.to_ascii_lowercase().as_str(),
+            "md" | "markdown" | "mdx"
+        )
+    })
+}
+
+/// Kinds prose documents by name; variables, fields and imports are too
+/// easily confused with ordinary words.
+fn is_definition(symbol: &Symbol) -> bool {
+    matches!(
+        symbol.kind,
+        SymbolKind::Class
+            | SymbolKind::Struct
+            | SymbolKind::Interface
+            | SymbolKind::Trait
+            | SymbolKind::Enum
+            | SymbolKind::Union
+            | SymbolKind::Type
+            | SymbolKind::Function
+            | SymbolKind::Method
+            | SymbolKind::Constructor
+            | SymbolKind::Constant
+            | SymbolKind::Module
+            | SymbolKind::Namespace
+    )
+}
@@ -1,8 +1,42 @@
+pub mod ada;
 pub mod analysis;
 pub mod batch;
+pub mod build_targets;
+pub mod c_macros;
+pub mod checkpoint;
+pub mod config_keys;
+pub mod csharp_partials;
+pub mod delimited_data;
 pub mod discovery;
+pub mod dispatch;
+pub mod export;
 pub mod extraction;
+pub mod fortran;
+pub mod generated_outputs;
+pub mod godot_scenes;
+pub mod groovy;
+pub mod identifiers;
+pub mod injection;
+pub mod kotlin_multiplatform;
+pub(crate) mod lexer;
+pub mod manifest_dependencies;
+pub mod markdown_links;
 pub mod normalized;
+pub mod notebook;
+pub mod openapi;
+pub mod partial_parse;
 pub(crate) mod paths;
 pub mod persistence;
+pub mod preprocessor;
+pub mod protobuf;
+pub mod razor_components;
+pub mod shards;
+pub mod signatures;
+pub mod sql_dialects;
+pub mod stable_ids;
+pub mod symbol_options;
+pub mod task_comments;
+pub mod terraform;
+pub(crate) mod text_lines;
+pub mod verilog;
 pub mod web_edges;
@@ -24,6 +24,9 @@ pub struct NormalizedExtractionData {
 
 pub fn normalize_extraction_results(
     mut results: ExtractionResults,
+    file_path: &str,
+    language: &str,
+    content: &str,
     configs: &julie_index::search::LanguageConfigs,
 ) -> NormalizedExtractionData {
     if !results.literals.is_empty() {
@@ -36,7 +39,41 @@ pub fn normalize_extraction_results(
     if !results.symbols.is_empty() {
         let roles = configs.build_test_role_configs();
         julie_index::analysis::test_roles::classify_symbols_by_role(&mut results.symbols, &roles);
+        super::csharp_partials::annotate_partial_declarations(&mut results.symbols);
+        super::c_macros::annotate_macro_expansions(&mut results.symbols, content);
     }
+    super::c_macros::link_macro_calls(&mut results, content);
+    super::manifest_dependencies::annotate_manifest_dependencies(
+        &mut results.symbols,
+        file_path,
+        language,
+        content,
+    );
+    super::protobuf::annotate_protobuf_definitions(
+        &mut results.symbols,
+        file_path,
+        language,
+        content,
+    );
+    super::openapi::annotate_openapi_spec(&mut results, file_path, language, content);
+    super::kotlin_multiplatform::annotate_expect_actual_declarations(
+        &mut results.symbols,
+        language,
+        content,
+    );
+    super::config_keys::annotate_config_keys(&mut results.symbols, file_path, language, content);
+    super::delimited_data::annotate_delimited_data_headers(
+        &mut results.symbols,
+        file_path,
+        language,
+        content,
+    );
+    super::config_keys::link_config_usages(&mut results, file_path, language, content);
+    super::razor_components::link_component_usages(&mut results, file_path, language, content);
+    super::task_comments::annotate_task_comments(&mut results, file_path, language, content);
+    // Last, so the passes above see each declaration in full.
+    super::signatures::normalize_signatures(&mut results.symbols);
+    super::stable_ids::assign_stable_ids(&mut results);
 
     NormalizedExtractionData {
         symbols: results.symbols,
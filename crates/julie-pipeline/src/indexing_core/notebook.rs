@@ -0,0 +1,445 @@
+//! Symbols for notebooks: Jupyter (`.ipynb`) and R Markdown (`.Rmd`).
+//!
+//! A notebook has no grammar of its own, so it would otherwise be indexed as
+//! plain text (or, for `.ipynb`, as a JSON blob). This pass splits it into
+//! cells, concatenates the code cells of each cell language into one virtual
+//! source file, and runs that language's extractor over it, so definitions in
+//! one cell resolve calls from another. Markdown cells are extracted the same
+//! way with the markdown extractor, which makes their headings and prose
+//! searchable as documentation.
+//!
+//! Every result is mapped back to the notebook: file paths become the
+//! notebook's, lines become lines of the notebook file, and each symbol
+//! carries `{cell, cell_line}` under [`NOTEBOOK_METADATA_KEY`] (both
+//! 1-based). For `.Rmd` files the mapping is exact. For `.ipynb` files a line
+//! maps to the line of the JSON string holding it, and byte offsets point at
+//! that string, so they are approximate when the source contains escapes.
+//!
+//! IPython magics and shell escapes (`%time`, `!pip install`) are blanked
+//! before parsing; a cell magic naming another language (`%%bash`, `%%sql`)
+//! moves the cell to that language.
+
+use std::collections::HashMap;
+use std::path::Path;
+
+use anyhow::Result;
+use julie_extractors::ExtractionResults;
+use serde_json::{Value, json};
+use tracing::warn;
+
+pub use julie_core::file_policy::{JUPYTER_LANGUAGE, RMARKDOWN_LANGUAGE, is_notebook_language};
+
+/// Metadata key holding a symbol's cell coordinates.
+pub const NOTEBOOK_METADATA_KEY: &str = "notebook";
+
+/// Cell language used for markdown cells.
+const MARKDOWN: &str = "markdown";
+
+/// One cell of a notebook, with where each of its lines sits in the file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NotebookCell {
+    /// 1-based position among the notebook's cells.
+    pub number: usize,
+    /// Cell language (`python`, `r`, `sql`), or `markdown`.
+    pub language: String,
+    pub lines: Vec<String>,
+    /// `(line, byte offset)` in the notebook file where each line starts.
+    pub positions: Vec<(u32, usize)>,
+}
+
+/// Where a line of a virtual source came from.
+#[derive(Debug, Clone, Copy)]
+struct LineOrigin {
+    cell: usize,
+    cell_line: usize,
+    file_line: u32,
+    file_byte: usize,
+}
+
+/// Extract a notebook: parse its cells, run each cell language's extractor
+/// and map every result back onto the notebook file.
+pub fn extract_notebook(
+    file_path: &str,
+    content: &str,
+    workspace_root: &Path,
+) -> Result<ExtractionResults> {
+    let cells = match Path::new(file_path)
+        .extension()
+        .and_then(|ext| ext.to_str())
+    {
+        Some(ext) if ext.eq_ignore_ascii_case("ipynb") => parse_jupyter_cells(content)?,
+        _ => parse_rmarkdown_cells(content),
+    };
+
+    let mut by_language: Vec<(&str, Vec<&NotebookCell>)> = Vec::new();
+    for cell in &cells {
+        match by_language
+            .iter_mut()
+            .find(|(language, _)| *language == cell.language)
+        {
+            Some((_, group)) => group.push(cell),
+            None => by_language.push((cell.language.as_str(), vec![cell])),
+        }
+    }
+
+    let mut results = ExtractionResults::empty();
+    for (language, group) in by_language {
+        let Some(extension) = cell_extension(language).filter(|ext| {
+            julie_extractors::language::detect_language_from_extension(ext).is_some()
+        }) else {
+            continue;
+        };
+
+        let mut source = String::new();
+        let mut origins = Vec::new();
+        for cell in group {
+            for (index, line) in cell.lines.iter().enumerate() {
+                let (file_line, file_byte) = cell.positions[index];
+                source.push_str(line);
+                source.push('\n');
+                origins.push(LineOrigin {
+                    cell: cell.number,
+                    cell_line: index + 1,
+                    file_line,
+                    file_byte,
+                });
+            }
+            // Blank separator so the next cell starts on a fresh statement;
+            // it belongs to the cell above.
+            if let Some(last) = origins.last().copied() {
+                source.push('\n');
+                origins.push(last);
+            }
+        }
+        if origins.is_empty() {
+            continue;
+        }
+
+        let virtual_path = format!("{file_path}.{extension}");
+        match julie_extractors::extract_canonical(&virtual_path, &source, workspace_root) {
+            Ok(extracted) => merge_cell_results(&mut results, extracted, file_path, &origins),
+            Err(e) => warn!("Failed to extract {language} cells of {file_path}: {e}"),
+        }
+    }
+    Ok(results)
+}
+
+/// Map one language's virtual-source results onto the notebook file.
+fn merge_cell_results(
+    results: &mut ExtractionResults,
+    extracted: ExtractionResults,
+    file_path: &str,
+    origins: &[LineOrigin],
+) {
+    let origin = |line: u32| origins[(line.max(1) as usize - 1).min(origins.len() - 1)];
+
+    for mut symbol in extracted.symbols {
+        let start = origin(symbol.start_line);
+        let end = origin(symbol.end_line);
+        symbol.file_path = file_path.to_string();
+        symbol.start_line = start.file_line;
+        symbol.end_line = end.file_line;
+        symbol.start_byte = (start.file_byte + symbol.start_column as usize) as u32;
+        symbol.end_byte = (end.file_byte + symbol.end_column as usize) as u32;
+        // Body spans are offsets into the virtual source.
+        symbol.body_span = None;
+        symbol.metadata.get_or_insert_with(HashMap::new).insert(
+            NOTEBOOK_METADATA_KEY.to_string(),
+            json!({ "cell": start.cell, "cell_line": start.cell_line }),
+        );
+        results.symbols.push(symbol);
+    }
+    for mut relationship in extracted.relationships {
+        relationship.file_path = file_path.to_string();
+        relationship.line_number = origin(relationship.line_number).file_line;
+        results.relationships.push(relationship);
+    }
+    for mut pending in extracted.pending_relationships {
+        pending.file_path = file_path.to_string();
+        pending.line_number = origin(pending.line_number).file_line;
+        results.pending_relationships.push(pending);
+    }
+    for mut identifier in extracted.identifiers {
+        let start = origin(identifier.start_line);
+        let end = origin(identifier.end_line);
+        identifier.file_path = file_path.to_string();
+        identifier.start_line = start.file_line;
+        identifier.end_line = end.file_line;
+        identifier.start_byte = (start.file_byte + identifier.start_column as usize) as u32;
+        identifier.end_byte = (end.file_byte + identifier.end_column as usize) as u32;
+        results.identifiers.push(identifier);
+    }
+    results.types.extend(extracted.types);
+}
+
+/// Code and markdown cells of a Jupyter notebook (nbformat 4). Raw cells are
+/// skipped; code cells use the kernel language unless a cell magic says
+/// otherwise.
+pub fn parse_jupyter_cells(content: &str) -> Result<Vec<NotebookCell>> {
+    let notebook: Value = serde_json::from_str(content)?;
+    let kernel_language = notebook
+        .pointer("/metadata/kernelspec/language")
+        .or_else(|| notebook.pointer("/metadata/language_info/name"))
+        .and_then(Value::as_str)
+        .map(str::to_ascii_lowercase)
+        .unwrap_or_else(|| "python".to_string());
+    let Some(raw_cells) = notebook.get("cells").and_then(Value::as_array) else {
+        return Ok(Vec::new());
+    };
+    let source_positions = jupyter_source_positions(content);
+
+    let mut cells = Vec::new();
+    for (index, raw_cell) in raw_cells.iter().enumerate() {
+        let language = match raw_cell.get("cell_type").and_then(Value::as_str) {
+            Some("code") => kernel_language.as_str(),
+            Some("markdown") => MARKDOWN,
+            _ => continue,
+        };
+        let fragments: Vec<&str> = match raw_cell.get("source") {
+            Some(Value::String(text)) => vec![text.as_str()],
+            Some(Value::Array(items)) => items.iter().filter_map(Value::as_str).collect(),
+            _ => continue,
+        };
+        let fragment_positions = source_positions.get(index).cloned().unwrap_or_default();
+
+        // A fragment normally holds one line; map every line to the fragment
+        // it starts in.
+        let mut lines = Vec::new();
+        let mut positions = Vec::new();
+        let mut at_line_start = true;
+        for (fragment_index, fragment) in fragments.iter().enumerate() {
+            let position = fragment_positions
+                .get(fragment_index)
+                .or(fragment_positions.last())
+                .copied()
+                .unwrap_or((1, 0));
+            for segment in fragment.split_inclusive('\n') {
+                let text = segment.trim_end_matches(['\n', '\r']);
+                if at_line_start {
+                    lines.push(text.to_string());
+                    positions.push(position);
+                } else if let Some(line) = lines.last_mut() {
+                    line.push_str(text);
+                }
+                at_line_start = segment.ends_with('\n');
+            }
+        }
+
+        let mut cell = NotebookCell {
+            number: index + 1,
+            language: language.to_string(),
+            lines,
+            positions,
+        };
+        if language != MARKDOWN {
+            apply_magics(&mut cell);
+        }
+        cells.push(cell);
+    }
+    Ok(cells)
+}
+
+/// Blank IPython magics and shell escapes; a leading cell magic naming a
+/// language switches the cell to it.
+fn apply_magics(cell: &mut NotebookCell) {
+    if let Some(magic) = cell
+        .lines
+        .first()
+        .and_then(|line| line.trim_start().strip_prefix("%%"))
+    {
+        let name = magic.split_whitespace().next().unwrap_or_default();
+        cell.language = match name {
+            "bash" | "sh" | "script" => "bash".to_string(),
+            "javascript" | "js" => "javascript".to_string(),
+            "sql" => "sql".to_string(),
+            "R" => "r".to_string(),
+            _ => cell.language.clone(),
+        };
+        cell.lines[0].clear();
+    }
+    if cell.language == "python" {
+        for line in &mut cell.lines {
+            let trimmed = line.trim_start();
+            if trimmed.starts_with('%') || trimmed.starts_with('!') {
+                line.clear();
+            }
+        }
+    }
+}
+
+/// For every cell, the `(line, byte offset)` of each string in its `source`
+/// (a single string or an array of strings). A small JSON scanner, because
+/// serde_json does not report positions.
+fn jupyter_source_positions(content: &str) -> Vec<Vec<(u32, usize)>> {
+    let bytes = content.as_bytes();
+    let mut stack: Vec<JsonFrame> = Vec::new();
+    let mut cells: Vec<Vec<(u32, usize)>> = Vec::new();
+    let mut line = 1u32;
+    let mut pos = 0;
+    while pos < bytes.len() {
+        match bytes[pos] {
+            b'\n' => line += 1,
+            open @ (b'{' | b'[') => {
+                // A cell object opens directly inside the top-level `cells`.
+                if open == b'{' && in_cells_array(&stack) {
+                    cells.push(Vec::new());
+                }
+                stack.push(JsonFrame {
+                    is_array: open == b'[',
+                    key: None,
+                    expect_key: open == b'{',
+                });
+            }
+            b'}' | b']' => {
+                stack.pop();
+            }
+            b',' => {
+                if let Some(frame) = stack.last_mut().filter(|frame| !frame.is_array) {
+                    frame.expect_key = true;
+                }
+            }
+            b':' => {
+                if let Some(frame) = stack.last_mut() {
+                    frame.expect_key = false;
+                }
+            }
+            b'"' => {
+                let start = pos + 1;
+                pos = start;
+                while pos < bytes.len() && bytes[pos] != b'"' {
+                    if bytes[pos] == b'\\' {
+                        pos += 1;
+                    } else if bytes[pos] == b'\n' {
+                        line += 1;
+                    }
+                    pos += 1;
+                }
+                let text = &content[start..pos.min(bytes.len())];
+                match stack.last_mut() {
+                    Some(frame) if !frame.is_array && frame.expect_key => {
+                        frame.key = Some(text.to_string());
+                    }
+                    _ if is_cell_source(&stack) => {
+                        if let Some(cell) = cells.last_mut() {
+                            cell.push((line, start));
+                        }
+                    }
+                    _ => {}
+                }
+            }
+            _ => {}
+        }
+        pos += 1;
+    }
+    cells
+}
+
+/// An open JSON object or array while scanning a notebook.
+struct JsonFrame {
+    is_array: bool,
+    /// Last key read, for objects.
+    key: Option<String>,
+    expect_key: bool,
+}
+
+/// The innermost open container is the top-level `cells` array.
+fn in_cells_array(stack: &[JsonFrame]) -> bool {
+    stack.len() == 2 && stack[0].key.as_deref() == Some("cells") && stack[1].is_array
+}
+
+/// A string read now is (part of) a cell's `source`.
+fn is_cell_source(stack: &[JsonFrame]) -> bool {
+    let source_value = |cell: &JsonFrame| cell.key.as_deref() == Some("source") && !cell.expect_key;
+    match stack {
+        [_, _, cell] => in_cells_array(&stack[..2]) && source_value(cell),
+        [_, _, cell, array] => in_cells_array(&stack[..2]) && source_value(cell) && array.is_array,
+        _ => false,
+    }
+}
+
+/// Chunks and prose of an R Markdown document. Chunks open with
+/// ```` ```{engine ...} ```` and close with ```` ``` ````; the text between
+/// chunks forms markdown cells, except a leading YAML front matter block.
+pub fn parse_rmarkdown_cells(content: &str) -> Vec<NotebookCell> {
+    let mut cells: Vec<NotebookCell> = Vec::new();
+    let mut current: Option<NotebookCell> = None;
+    let mut in_chunk = false;
+    let mut in_front_matter = content.starts_with("---");
+    let mut byte = 0;
+
+    for (index, raw_line) in content.split_inclusive('\n').enumerate() {
+        let line_number = index as u32 + 1;
+        let line = raw_line.trim_end_matches(['\n', '\r']);
+        let trimmed = line.trim();
+        let line_start = byte;
+        byte += raw_line.len();
+
+        if in_front_matter {
+            if index > 0 && (trimmed == "---" || trimmed == "...") {
+                in_front_matter = false;
+            }
+            continue;
+        }
+        if in_chunk {
+            if trimmed.starts_with("```") && trimmed.trim_start_matches('`').is_empty() {
+                cells.extend(current.take());
+                in_chunk = false;
+            } else if let Some(cell) = current.as_mut() {
+                cell.lines.push(line.to_string());
+                cell.positions.push((line_number, line_start));
+            }
+            continue;
+        }
+        if let Some(header) = trimmed.strip_prefix("```{") {
+            let engine = header
+                .split(|c: char| c == ' ' || c == ',' || c == '}')
+                .next()
+                .unwrap_or_default()
+                .to_ascii_lowercase();
+            cells.extend(current.take());
+            current = Some(NotebookCell {
+                number: cells.len() + 1,
+                language: engine,
+                lines: Vec::new(),
+                positions: Vec::new(),
+            });
+            in_chunk = true;
+            continue;
+        }
+
+        let cell = current.get_or_insert_with(|| NotebookCell {
+            number: cells.len() + 1,
+            language: MARKDOWN.to_string(),
+            lines: Vec::new(),
+            positions: Vec::new(),
+        });
+        cell.lines.push(line.to_string());
+        cell.positions.push((line_number, line_start));
+    }
+    cells.extend(current);
+    cells
+}
+
+/// File extension whose extractor parses cells of `language` (a Jupyter
+/// kernel language or knitr engine name).
+fn cell_extension(language: &str) -> Option<&'static str> {
+    Some(match language {
+        "python" | "python3" | "ipython" => "py",
+        "r" => "r",
+        "julia" => "jl",
+        "sql" => "sql",
+        "bash" | "sh" | "shell" => "sh",
+        "javascript" | "js" | "node" => "js",
+        "typescript" | "ts" => "ts",
+        "scala" => "scala",
+        "rust" => "rs",
+        "c++" | "cpp" => "cpp",
+        "c#" | "csharp" => "cs",
+        "java" => "java",
+        "kotlin" => "kt",
+        "go" => "go",
+        "ruby" => "rb",
+        "markdown" => "md",
+        _ => return None,
+    })
+}
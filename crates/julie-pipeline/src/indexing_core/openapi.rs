@@ -0,0 +1,473 @@
+//! Symbols for OpenAPI / Swagger specs.
+//!
+//! The YAML and JSON extractors see an API spec as anonymous nesting: `get:`
+//! under `/users/{id}:` is just another key. This pass recognizes a spec (a
+//! root `openapi:` or `swagger:` key) and gives it shape:
+//!
+//! - every route under `paths` becomes a namespace named by the route;
+//! - every operation under a route becomes a method named by its
+//!   `operationId` (`GET /users/{id}` when there is none), documented by its
+//!   `summary` or `description`;
+//! - every schema under `components.schemas` (OpenAPI 3) or `definitions`
+//!   (Swagger 2) becomes a struct, or an enum when it only lists values, with
+//!   a field per property.
+//!
+//! Symbols share the semantic group [`OPENAPI_SEMANTIC_GROUP`] and carry
+//! `{kind, ...}` under [`OPENAPI_METADATA_KEY`]; operations record `method`,
+//! `path`, `full_path` (behind the server base path) and `operation_id`. A
+//! key symbol the extractor already produced on the same line is converted in
+//! place; otherwise one is synthesized. Local `$ref`s become `Uses`
+//! relationships to the referenced schema.
+//!
+//! The post-index pass in `julie_index::analysis::openapi_links` connects
+//! operations to the route handlers serving them, to the functions and SDK
+//! methods named after their `operationId`, and to client code calling them.
+//!
+//! Parsing covers JSON and the block and flow YAML specs are written in.
+//! Anything unrecognized is skipped, never an error, because a half-edited
+//! spec must still index.
+
+mod document;
+mod yaml;
+
+use document::{Entry, Node, parse_json};
+use yaml::parse_yaml;
+
+use std::collections::{HashMap, HashSet};
+
+use julie_extractors::base::{Symbol, SymbolKind};
+use julie_extractors::{ExtractionResults, Relationship, RelationshipKind};
+use serde_json::{Value, json};
+
+use crate::indexing_core::text_lines::TextLines;
+
+/// Metadata key holding the OpenAPI facts of a symbol.
+pub const OPENAPI_METADATA_KEY: &str = "openapi";
+
+/// `semantic_group` shared by every OpenAPI symbol.
+pub const OPENAPI_SEMANTIC_GROUP: &str = "openapi";
+
+/// Deepest `{`/`[` nesting parsed; deeper documents are not specs.
+const MAX_NESTING: usize = 128;
+
+/// Operation keys of a path item.
+const HTTP_METHODS: &[&str] = &[
+    "get", "put", "post", "delete", "options", "head", "patch", "trace",
+];
+
+/// Convert or synthesize the symbols of an OpenAPI spec and link its `$ref`s.
+/// Files that are not specs are left untouched.
+pub fn annotate_openapi_spec(
+    results: &mut ExtractionResults,
+    file_path: &str,
+    language: &str,
+    content: &str,
+) {
+    if !matches!(language, "yaml" | "json") {
+        return;
+    }
+    let definitions = parse_openapi_spec(language, content);
+    if definitions.is_empty() {
+        return;
+    }
+
+    let lines = TextLines::new(content);
+    let mut ids: Vec<String> = Vec::with_capacity(definitions.len());
+    let mut converted = HashSet::new();
+    let mut synthesized = Vec::new();
+    for definition in &definitions {
+        let line = lines.line_of(definition.start);
+        let parent_id = definition.parent.map(|parent| ids[parent].clone());
+        let existing = results.symbols.iter().position(|symbol| {
+            symbol.start_line == line
+                && symbol.name == definition.key
+                && !converted.contains(&symbol.id)
+        });
+        match existing {
+            Some(index) => {
+                let symbol = &mut results.symbols[index];
+                symbol.name = definition.name.clone();
+                symbol.kind = definition.kind.clone();
+                symbol.signature = Some(definition.signature.clone());
+                if definition.doc.is_some() {
+                    symbol.doc_comment = definition.doc.clone();
+                }
+                symbol.parent_id = parent_id;
+                symbol.semantic_group = Some(OPENAPI_SEMANTIC_GROUP.to_string());
+                symbol
+                    .metadata
+                    .get_or_insert_with(HashMap::new)
+                    .insert(OPENAPI_METADATA_KEY.to_string(), definition.info.clone());
+                converted.insert(symbol.id.clone());
+                ids.push(symbol.id.clone());
+            }
+            None => {
+                let symbol =
+                    synthesize_symbol(definition, parent_id, file_path, language, content, &lines);
+                ids.push(symbol.id.clone());
+                synthesized.push(symbol);
+            }
+        }
+    }
+    results.symbols.extend(synthesized);
+
+    let schema_ids: HashMap<&str, &str> = definitions
+        .iter()
+        .zip(&ids)
+        .filter(|(definition, _)| definition.info["kind"] == "schema")
+        .map(|(definition, id)| (definition.name.as_str(), id.as_str()))
+        .collect();
+    for (definition, from) in definitions.iter().zip(&ids) {
+        let line_number = lines.line_of(definition.start);
+        let mut seen = HashSet::new();
+        for schema in &definition.refs {
+            let Some(&to) = schema_ids.get(schema.as_str()) else {
+                continue;
+            };
+            if to == from || !seen.insert(to) {
+                continue;
+            }
+            results.relationships.push(Relationship {
+                id: format!(
+                    "{}_{}_{:?}_{}_{}",
+                    from,
+                    to,
+                    RelationshipKind::Uses,
+                    file_path,
+                    line_number
+                ),
+                from_symbol_id: from.clone(),
+                to_symbol_id: to.to_string(),
+                kind: RelationshipKind::Uses,
+                file_path: file_path.to_string(),
+                line_number,
+                confidence: 1.0,
+                metadata: None,
+            });
+        }
+    }
+}
+
+/// One path, operation, schema or property of a spec.
+#[derive(Debug, Clone, PartialEq)]
+pub struct OpenApiDefinition {
+    /// Symbol name: the route, the `operationId`, the schema or property.
+    pub name: String,
+    /// The key as written, used to find the extractor's symbol for it.
+    pub key: String,
+    pub kind: SymbolKind,
+    pub signature: String,
+    pub doc: Option<String>,
+    /// Index of the enclosing definition in the parsed list.
+    pub parent: Option<usize>,
+    /// `{kind, ...}` stored under [`OPENAPI_METADATA_KEY`].
+    pub info: Value,
+    /// Schemas referenced through local `$ref`s.
+    pub refs: Vec<String>,
+    /// Byte range of the key and its value.
+    pub start: usize,
+    pub end: usize,
+}
+
+/// Parse an OpenAPI 3 or Swagger 2 document; parents come before children.
+/// Returns nothing for documents that are not specs.
+pub fn parse_openapi_spec(language: &str, content: &str) -> Vec<OpenApiDefinition> {
+    if !content.contains("openapi") && !content.contains("swagger") {
+        return Vec::new();
+    }
+    let root = match language {
+        "json" => parse_json(content),
+        _ => Some(parse_yaml(content)),
+    };
+    let Some(root) = root else {
+        return Vec::new();
+    };
+    let Some(version) = ["openapi", "swagger"]
+        .iter()
+        .find_map(|key| root.get(key).and_then(|entry| entry.value.as_str()))
+    else {
+        return Vec::new();
+    };
+
+    let mut definitions = Vec::new();
+    let base_path = if version.starts_with('2') {
+        root.get("basePath")
+            .and_then(|entry| entry.value.as_str())
+            .map(str::to_string)
+    } else {
+        root.get("servers")
+            .and_then(|entry| entry.value.items().first())
+            .and_then(|server| server.get("url"))
+            .and_then(|entry| entry.value.as_str())
+            .map(url_path)
+    }
+    .map(|path| path.trim_end_matches('/').to_string())
+    .unwrap_or_default();
+
+    if let Some(paths) = root.get("paths") {
+        for route in paths.value.entries() {
+            if !route.key.starts_with('/') {
+                continue;
+            }
+            let route_index = definitions.len();
+            definitions.push(OpenApiDefinition {
+                name: route.key.clone(),
+                key: route.key.clone(),
+                kind: SymbolKind::Namespace,
+                signature: route.key.clone(),
+                doc: route
+                    .value
+                    .get("summary")
+                    .and_then(|entry| entry.value.as_str())
+                    .map(str::to_string),
+                parent: None,
+                info: json!({ "kind": "path", "path": route.key }),
+                refs: Vec::new(),
+                start: route.start,
+                end: route.end,
+            });
+            for operation in route.value.entries() {
+                let method = operation.key.to_ascii_lowercase();
+                if !HTTP_METHODS.contains(&method.as_str()) {
+                    continue;
+                }
+                definitions.push(operation_definition(
+                    operation,
+                    &method.to_ascii_uppercase(),
+                    &route.key,
+                    &base_path,
+                    route_index,
+                ));
+            }
+        }
+    }
+
+    let schemas = root
+        .get("components")
+        .and_then(|components| components.value.get("schemas"))
+        .or_else(|| root.get("definitions"));
+    for schema in schemas.map(|entry| entry.value.entries()).unwrap_or(&[]) {
+        let schema_index = definitions.len();
+        let properties = schema.value.get("properties");
+        let is_enum = schema.value.get("enum").is_some() && properties.is_none();
+        let schema_type = schema
+            .value
+            .get("type")
+            .and_then(|entry| entry.value.as_str())
+            .unwrap_or("object");
+        let mut refs = Vec::new();
+        for entry in schema.value.entries() {
+            if entry.key != "properties" {
+                collect_refs(&entry.value, &mut refs);
+            }
+        }
+        definitions.push(OpenApiDefinition {
+            name: schema.key.clone(),
+            key: schema.key.clone(),
+            kind: if is_enum {
+                SymbolKind::Enum
+            } else {
+                SymbolKind::Struct
+            },
+            signature: format!("{}: {schema_type}", schema.key),
+            doc: description(&schema.value),
+            parent: None,
+            info: json!({ "kind": "schema", "name": schema.key }),
+            refs,
+            start: schema.start,
+            end: schema.end,
+        });
+
+        let required = string_list(&schema.value, "required");
+        for property in properties.map(|entry| entry.value.entries()).unwrap_or(&[]) {
+            let mut refs = Vec::new();
+            collect_refs(&property.value, &mut refs);
+            let is_required = required.contains(&property.key.as_str());
+            definitions.push(OpenApiDefinition {
+                name: property.key.clone(),
+                key: property.key.clone(),
+                kind: SymbolKind::Field,
+                signature: format!("{}: {}", property.key, property_type(&property.value)),
+                doc: description(&property.value),
+                parent: Some(schema_index),
+                info: json!({ "kind": "property", "required": is_required }),
+                refs,
+                start: property.start,
+                end: property.end,
+            });
+        }
+    }
+    definitions
+}
+
+fn operation_definition(
+    operation: &Entry,
+    method: &str,
+    route: &str,
+    base_path: &str,
+    parent: usize,
+) -> OpenApiDefinition {
+    let operation_id = operation
+        .value
+        .get("operationId")
+        .and_then(|entry| entry.value.as_str())
+        .filter(|id| !id.is_empty());
+    let tags = string_list(&operation.value, "tags");
+    let doc = operation
+        .value
+        .get("summary")
+        .and_then(|entry| entry.value.as_str())
+        .map(str::to_string)
+        .or_else(|| description(&operation.value));
+    let mut refs = Vec::new();
+    collect_refs(&operation.value, &mut refs);
+    let signature = format!("{method} {route}");
+    OpenApiDefinition {
+        name: operation_id.map_or_else(|| signature.clone(), str::to_string),
+        key: operation.key.clone(),
+        kind: SymbolKind::Method,
+        signature,
+        doc,
+        parent: Some(parent),
+        info: json!({
+            "kind": "operation",
+            "method": method,
+            "path": route,
+            "full_path": format!("{base_path}{route}"),
+            "operation_id": operation_id,
+            "tags": tags,
+        }),
+        refs,
+        start: operation.start,
+        end: operation.end,
+    }
+}
+
+/// The strings listed under `key`, e.g. `tags` or `required`.
+fn string_list<'a>(node: &'a Node, key: &str) -> Vec<&'a str> {
+    node.get(key)
+        .map(|entry| {
+            entry
+                .value
+                .items()
+                .iter()
+                .filter_map(Node::as_str)
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+fn description(node: &Node) -> Option<String> {
+    node.get("description")
+        .or_else(|| node.get("title"))
+        .and_then(|entry| entry.value.as_str())
+        .map(|text| text.trim().to_string())
+        .filter(|text| !text.is_empty())
+}
+
+/// `string`, `int64`, `Address`, `Address[]`.
+fn property_type(node: &Node) -> String {
+    if let Some(schema) = node
+        .get("$ref")
+        .and_then(|entry| entry.value.as_str())
+        .and_then(ref_schema)
+    {
+        return schema.to_string();
+    }
+    let schema_type = node.get("type").and_then(|entry| entry.value.as_str());
+    if schema_type == Some("array")
+        && let Some(items) = node.get("items")
+    {
+        return format!("{}[]", property_type(&items.value));
+    }
+    node.get("format")
+        .and_then(|entry| entry.value.as_str())
+        .or(schema_type)
+        .unwrap_or("any")
+        .to_string()
+}
+
+fn collect_refs(node: &Node, refs: &mut Vec<String>) {
+    match node {
+        Node::Map(entries) => {
+            for entry in entries {
+                if entry.key == "$ref"
+                    && let Some(schema) = entry.value.as_str().and_then(ref_schema)
+                {
+                    refs.push(schema.to_string());
+                } else {
+                    collect_refs(&entry.value, refs);
+                }
+            }
+        }
+        Node::Seq(items) => items.iter().for_each(|item| collect_refs(item, refs)),
+        Node::Scalar(_) => {}
+    }
+}
+
+/// Schema named by a local `$ref`. References into other files are not
+/// followed.
+fn ref_schema(reference: &str) -> Option<&str> {
+    reference
+        .strip_prefix("#/components/schemas/")
+        .or_else(|| reference.strip_prefix("#/definitions/"))
+        .filter(|name| !name.is_empty() && !name.contains('/'))
+}
+
+/// Path part of a server URL: `https://api.example.com/v1` → `/v1`.
+fn url_path(url: &str) -> String {
+    let rest = url.split_once("://").map_or(url, |(_, rest)| rest);
+    let path = if url.starts_with('/') {
+        url
+    } else {
+        rest.find('/').map_or("", |slash| &rest[slash..])
+    };
+    path.split(['?', '#']).next().unwrap_or("").to_string()
+}
+
+fn synthesize_symbol(
+    definition: &OpenApiDefinition,
+    parent_id: Option<String>,
+    file_path: &str,
+    language: &str,
+    content: &str,
+    lines: &TextLines,
+) -> Symbol {
+    let id_source = format!(
+        "{}:openapi:{}:{}",
+        file_path, definition.start, definition.name
+    );
+    let id = blake3::hash(id_source.as_bytes()).to_hex()[..32].to_string();
+    let start_line = lines.line_of(definition.start);
+    let end = definition.end.max(definition.start);
+    let end_line = lines.line_of(end.saturating_sub(1).max(definition.start));
+    let declaration = content[definition.start..end].trim_end();
+    Symbol {
+        id,
+        name: definition.name.clone(),
+        kind: definition.kind.clone(),
+        language: language.to_string(),
+        file_path: file_path.to_string(),
+        start_line,
+        start_column: (definition.start - lines.line_start(start_line)) as u32,
+        end_line,
+        end_column: (end - lines.line_start(end_line)) as u32,
+        start_byte: definition.start as u32,
+        end_byte: end as u32,
+        signature: Some(definition.signature.clone()),
+        doc_comment: definition.doc.clone(),
+        visibility: None,
+        parent_id,
+        metadata: Some(HashMap::from([(
+            OPENAPI_METADATA_KEY.to_string(),
+            definition.info.clone(),
+        )])),
+        semantic_group: Some(OPENAPI_SEMANTIC_GROUP.to_string()),
+        confidence: None,
+        code_context: Some(declaration.to_string()),
+        content_type: None,
+        body_span: None,
+        body_hash: None,
+        annotations: Vec::new(),
+    }
+}
@@ -0,0 +1,178 @@
+//! The document tree both formats parse into, and the JSON parser.
+
+use super::*;
+
+#[derive(Debug, Clone)]
+pub(super) enum Node {
+    Map(Vec<Entry>),
+    Seq(Vec<Node>),
+    Scalar(String),
+}
+
+#[derive(Debug, Clone)]
+pub(super) struct Entry {
+    pub(super) key: String,
+    /// Byte offset of the key.
+    pub(super) start: usize,
+    /// Byte offset just past the value.
+    pub(super) end: usize,
+    pub(super) value: Node,
+}
+
+impl Node {
+    pub(super) fn get(&self, key: &str) -> Option<&Entry> {
+        self.entries().iter().find(|entry| entry.key == key)
+    }
+
+    pub(super) fn entries(&self) -> &[Entry] {
+        match self {
+            Node::Map(entries) => entries,
+            _ => &[],
+        }
+    }
+
+    pub(super) fn items(&self) -> &[Node] {
+        match self {
+            Node::Seq(items) => items,
+            _ => &[],
+        }
+    }
+
+    pub(super) fn as_str(&self) -> Option<&str> {
+        match self {
+            Node::Scalar(text) => Some(text),
+            _ => None,
+        }
+    }
+}
+
+pub(super) fn parse_json(content: &str) -> Option<Node> {
+    let mut parser = JsonParser {
+        bytes: content.as_bytes(),
+        content,
+        pos: 0,
+        depth: 0,
+    };
+    parser.value()
+}
+
+struct JsonParser<'a> {
+    bytes: &'a [u8],
+    content: &'a str,
+    pos: usize,
+    depth: usize,
+}
+
+impl JsonParser<'_> {
+    fn skip_space(&mut self) {
+        while self.pos < self.bytes.len() && self.bytes[self.pos].is_ascii_whitespace() {
+            self.pos += 1;
+        }
+    }
+
+    fn value(&mut self) -> Option<Node> {
+        self.skip_space();
+        match *self.bytes.get(self.pos)? {
+            b'{' | b'[' if self.depth >= MAX_NESTING => None,
+            b'{' => {
+                self.pos += 1;
+                self.depth += 1;
+                let mut entries = Vec::new();
+                loop {
+                    self.skip_space();
+                    match *self.bytes.get(self.pos)? {
+                        b'}' => {
+                            self.pos += 1;
+                            self.depth -= 1;
+                            return Some(Node::Map(entries));
+                        }
+                        b',' => self.pos += 1,
+                        b'"' => {
+                            let start = self.pos;
+                            let key = self.string()?;
+                            self.skip_space();
+                            if self.bytes.get(self.pos) != Some(&b':') {
+                                return None;
+                            }
+                            self.pos += 1;
+                            let value = self.value()?;
+                            entries.push(Entry {
+                                key,
+                                start,
+                                end: self.pos,
+                                value,
+                            });
+                        }
+                        _ => return None,
+                    }
+                }
+            }
+            b'[' => {
+                self.pos += 1;
+                self.depth += 1;
+                let mut items = Vec::new();
+                loop {
+                    self.skip_space();
+                    match *self.bytes.get(self.pos)? {
+                        b']' => {
+                            self.pos += 1;
+                            self.depth -= 1;
+                            return Some(Node::Seq(items));
+                        }
+                        b',' => self.pos += 1,
+                        _ => items.push(self.value()?),
+                    }
+                }
+            }
+            b'"' => self.string().map(Node::Scalar),
+            _ => {
+                let start = self.pos;
+                while self.pos < self.bytes.len()
+                    && !matches!(self.bytes[self.pos], b',' | b'}' | b']')
+                    && !self.bytes[self.pos].is_ascii_whitespace()
+                {
+                    self.pos += 1;
+                }
+                (self.pos > start).then(|| Node::Scalar(self.content[start..self.pos].to_string()))
+            }
+        }
+    }
+
+    fn string(&mut self) -> Option<String> {
+        self.pos += 1;
+        let mut text = String::new();
+        let mut run = self.pos;
+        while let Some(&byte) = self.bytes.get(self.pos) {
+            match byte {
+                b'"' => {
+                    text.push_str(&self.content[run..self.pos]);
+                    self.pos += 1;
+                    return Some(text);
+                }
+                b'\\' => {
+                    text.push_str(&self.content[run..self.pos]);
+                    let escaped = *self.bytes.get(self.pos + 1)?;
+                    match escaped {
+                        b'n' => text.push('\n'),
+                        b't' => text.push('\t'),
+                        b'u' => {
+                            let hex = self.content.get(self.pos + 2..self.pos + 6)?;
+                            text.push(
+                                u32::from_str_radix(hex, 16)
+                                    .ok()
+                                    .and_then(char::from_u32)
+                                    .unwrap_or('\u{fffd}'),
+                            );
+                            self.pos += 4;
+                        }
+                        other => text.push(other as char),
+                    }
+                    self.pos += 2;
+                    run = self.pos;
+                }
+                _ => self.pos += 1,
+            }
+        }
+        None
+    }
+}
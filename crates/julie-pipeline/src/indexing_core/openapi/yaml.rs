@@ -0,0 +1,420 @@
+//! A YAML parser for the block and flow styles specs are written in.
+
+use super::*;
+
+/// A non-blank line of the first YAML document, comments removed.
+#[derive(Debug, Clone, Copy)]
+struct YamlLine<'a> {
+    indent: usize,
+    /// Byte offset of `text`.
+    start: usize,
+    text: &'a str,
+}
+
+impl YamlLine<'_> {
+    fn end(&self) -> usize {
+        self.start + self.text.len()
+    }
+}
+
+pub(super) fn parse_yaml(content: &str) -> Node {
+    let mut lines = Vec::new();
+    let mut offset = 0;
+    for raw in content.split_inclusive('\n') {
+        let line_start = offset;
+        offset += raw.len();
+        let line = raw.trim_end_matches(['\n', '\r']);
+        let text = line.trim_start_matches(' ');
+        let indent = line.len() - text.len();
+        if indent == 0 && (text.starts_with("---") || text.starts_with("...")) {
+            if lines.is_empty() {
+                continue;
+            }
+            break;
+        }
+        let text = strip_yaml_comment(text).trim_end();
+        if text.is_empty() || (indent == 0 && text.starts_with('%')) {
+            continue;
+        }
+        lines.push(YamlLine {
+            indent,
+            start: line_start + indent,
+            text,
+        });
+    }
+    let mut parser = YamlParser { lines, pos: 0 };
+    if parser.lines.is_empty() {
+        return Node::Map(Vec::new());
+    }
+    let indent = parser.lines[0].indent;
+    parser.block(indent).0
+}
+
+struct YamlParser<'a> {
+    lines: Vec<YamlLine<'a>>,
+    pos: usize,
+}
+
+impl<'a> YamlParser<'a> {
+    /// The node starting at the current line, at `indent`, and its end.
+    fn block(&mut self, indent: usize) -> (Node, usize) {
+        let line = self.lines[self.pos];
+        if is_seq_item(line.text) {
+            self.seq(indent)
+        } else if split_yaml_key(line.text).is_some() {
+            self.map(indent)
+        } else {
+            self.pos += 1;
+            self.scalar(indent, line.text, line.end())
+        }
+    }
+
+    fn map(&mut self, indent: usize) -> (Node, usize) {
+        let mut entries = Vec::new();
+        let mut end = self.lines[self.pos].end();
+        while let Some(&line) = self.lines.get(self.pos) {
+            if line.indent < indent {
+                break;
+            }
+            if line.indent > indent {
+                // Stray deeper line (e.g. under a malformed scalar).
+                self.pos += 1;
+                continue;
+            }
+            let Some((key, rest)) = split_yaml_key(line.text) else {
+                break;
+            };
+            self.pos += 1;
+            let rest_start = line.end() - rest.len();
+            let (value, value_end) = self.value(indent, rest, rest_start, line.end(), true);
+            end = value_end;
+            entries.push(Entry {
+                key,
+                start: line.start,
+                end: value_end,
+                value,
+            });
+        }
+        (Node::Map(entries), end)
+    }
+
+    fn seq(&mut self, indent: usize) -> (Node, usize) {
+        let mut items = Vec::new();
+        let mut end = self.lines[self.pos].end();
+        while let Some(&line) = self.lines.get(self.pos) {
+            if line.indent < indent {
+                break;
+            }
+            if line.indent > indent {
+                self.pos += 1;
+                continue;
+            }
+            if !is_seq_item(line.text) {
+                break;
+            }
+            let rest = line.text[1..].trim_start();
+            let offset = line.text.len() - rest.len();
+            let (item, item_end) = if rest.is_empty() {
+                self.pos += 1;
+                self.value(indent, "", line.end(), line.end(), false)
+            } else if is_seq_item(rest)
+                || (split_yaml_key(rest).is_some() && !rest.starts_with(['{', '[']))
+            {
+                // `- key: value` opens a map (or `- - x` a sequence) whose
+                // first line is the rest of this one.
+                self.lines[self.pos] = YamlLine {
+                    indent: indent + offset,
+                    start: line.start + offset,
+                    text: rest,
+                };
+                self.block(indent + offset)
+            } else {
+                self.pos += 1;
+                self.value(indent, rest, line.end() - rest.len(), line.end(), false)
+            };
+            items.push(item);
+            end = item_end;
+        }
+        (Node::Seq(items), end)
+    }
+
+    /// The value after `key:` (or `- `) on a line at `indent`.
+    fn value(
+        &mut self,
+        indent: usize,
+        rest: &'a str,
+        rest_start: usize,
+        line_end: usize,
+        in_map: bool,
+    ) -> (Node, usize) {
+        let rest = strip_node_properties(rest);
+        if rest.is_empty() {
+            if let Some(&next) = self.lines.get(self.pos) {
+                if next.indent > indent {
+                    return self.block(next.indent);
+                }
+                // Block sequences may sit at their key's indentation.
+                if in_map && next.indent == indent && is_seq_item(next.text) {
+                    return self.seq(indent);
+                }
+            }
+            return (Node::Scalar(String::new()), line_end);
+        }
+        if rest.starts_with(['|', '>']) {
+            let mut text = Vec::new();
+            let mut end = line_end;
+            while let Some(&next) = self.lines.get(self.pos) {
+                if next.indent <= indent {
+                    break;
+                }
+                text.push(next.text);
+                end = next.end();
+                self.pos += 1;
+            }
+            let separator = if rest.starts_with('|') { "\n" } else { " " };
+            return (Node::Scalar(text.join(separator)), end);
+        }
+        if rest.starts_with(['{', '[']) {
+            let mut text = rest.to_string();
+            let mut depth = bracket_depth(rest);
+            let mut end = line_end;
+            while depth > 0 {
+                let Some(&next) = self.lines.get(self.pos) else {
+                    break;
+                };
+                if next.indent <= indent {
+                    break;
+                }
+                text.push(' ');
+                text.push_str(next.text);
+                depth += bracket_depth(next.text);
+                end = next.end();
+                self.pos += 1;
+            }
+            let mut flow = FlowParser {
+                text: &text,
+                pos: 0,
+                depth: 0,
+                start: rest_start,
+                end,
+            };
+            return (flow.value(), end);
+        }
+        self.scalar(indent, rest, line_end)
+    }
+
+    /// A plain or quoted scalar, folding continuation lines.
+    fn scalar(&mut self, indent: usize, first: &str, line_end: usize) -> (Node, usize) {
+        let mut text = unquote(first).to_string();
+        let mut end = line_end;
+        while let Some(&next) = self.lines.get(self.pos) {
+            if next.indent <= indent
+                || is_seq_item(next.text)
+                || split_yaml_key(next.text).is_some()
+            {
+                break;
+            }
+            text.push(' ');
+            text.push_str(next.text);
+            end = next.end();
+            self.pos += 1;
+        }
+        (Node::Scalar(text), end)
+    }
+}
+
+/// `{a: 1, b: [x, y]}` and `[a, b]`. Entries take the range of the whole
+/// flow collection.
+struct FlowParser<'a> {
+    text: &'a str,
+    pos: usize,
+    depth: usize,
+    start: usize,
+    end: usize,
+}
+
+impl FlowParser<'_> {
+    fn skip_space(&mut self) {
+        let rest = &self.text[self.pos..];
+        self.pos += rest.len() - rest.trim_start().len();
+    }
+
+    fn value(&mut self) -> Node {
+        self.skip_space();
+        let Some(first) = self.text[self.pos..].chars().next() else {
+            return Node::Scalar(String::new());
+        };
+        match first {
+            '{' | '[' if self.depth >= MAX_NESTING => {
+                self.pos = self.text.len();
+                Node::Scalar(String::new())
+            }
+            '{' | '[' => {
+                let close = if first == '{' { '}' } else { ']' };
+                self.pos += 1;
+                self.depth += 1;
+                let mut entries = Vec::new();
+                let mut items = Vec::new();
+                loop {
+                    self.skip_space();
+                    let Some(next) = self.text[self.pos..].chars().next() else {
+                        break;
+                    };
+                    if next == close {
+                        self.pos += 1;
+                        break;
+                    }
+                    if next == ',' {
+                        self.pos += 1;
+                        continue;
+                    }
+                    let before = self.pos;
+                    if first == '{' {
+                        let key = self.scalar_text();
+                        self.skip_space();
+                        let value = if self.text[self.pos..].starts_with(':') {
+                            self.pos += 1;
+                            self.value()
+                        } else {
+                            Node::Scalar(String::new())
+                        };
+                        entries.push(Entry {
+                            key,
+                            start: self.start,
+                            end: self.end,
+                            value,
+                        });
+                    } else {
+                        items.push(self.value());
+                    }
+                    if self.pos == before {
+                        // Unexpected character: step over it.
+                        self.pos += self.text[self.pos..]
+                            .chars()
+                            .next()
+                            .map_or(1, char::len_utf8);
+                    }
+                }
+                self.depth -= 1;
+                if first == '{' {
+                    Node::Map(entries)
+                } else {
+                    Node::Seq(items)
+                }
+            }
+            _ => Node::Scalar(self.scalar_text()),
+        }
+    }
+
+    fn scalar_text(&mut self) -> String {
+        let rest = &self.text[self.pos..];
+        if let Some(quote) = rest.chars().next().filter(|c| matches!(c, '"' | '\'')) {
+            let inner = &rest[1..];
+            let len = inner.find(quote).unwrap_or(inner.len());
+            self.pos += (1 + len + 1).min(rest.len());
+            return inner[..len].to_string();
+        }
+        let len = rest
+            .char_indices()
+            .find(|(i, c)| {
+                matches!(c, ',' | '}' | ']')
+                    || (*c == ':' && rest[i + 1..].starts_with([' ', ',', '}', ']']))
+                    || (*c == ':' && i + 1 == rest.len())
+            })
+            .map_or(rest.len(), |(i, _)| i);
+        self.pos += len;
+        rest[..len].trim().to_string()
+    }
+}
+
+fn is_seq_item(text: &str) -> bool {
+    text == "-" || text.starts_with("- ")
+}
+
+/// `key: rest` → `(key, rest)`, unquoting the key.
+fn split_yaml_key(text: &str) -> Option<(String, &str)> {
+    if is_seq_item(text) || text.starts_with(['{', '[', '?', '|', '>', '#']) {
+        return None;
+    }
+    let (key, after) = if let Some(quote) = text.chars().next().filter(|c| matches!(c, '"' | '\''))
+    {
+        let inner = &text[1..];
+        let close = inner.find(quote)?;
+        (inner[..close].to_string(), inner[close + 1..].trim_start())
+    } else {
+        let colon = text.char_indices().find_map(|(i, c)| {
+            (c == ':' && (i + 1 == text.len() || text[i + 1..].starts_with([' ', '\t'])))
+                .then_some(i)
+        })?;
+        (text[..colon].trim_end().to_string(), &text[colon..])
+    };
+    let rest = after.strip_prefix(':')?;
+    if !rest.is_empty() && !rest.starts_with([' ', '\t']) {
+        return None;
+    }
+    (!key.is_empty()).then(|| (key, rest.trim()))
+}
+
+/// Drop a trailing `# comment`; `#` inside quotes or words is kept.
+fn strip_yaml_comment(text: &str) -> &str {
+    if text.starts_with('#') {
+        return "";
+    }
+    let mut quote = None;
+    let mut previous = ' ';
+    for (i, c) in text.char_indices() {
+        match quote {
+            Some(q) if c == q => quote = None,
+            Some(_) => {}
+            None if matches!(c, '"' | '\'') && matches!(previous, ' ' | ':' | '[' | '{' | ',') => {
+                quote = Some(c)
+            }
+            None if c == '#' && matches!(previous, ' ' | '\t') => return &text[..i],
+            None => {}
+        }
+        previous = c;
+    }
+    text
+}
+
+/// Skip leading `&anchor` and `!tag` properties.
+fn strip_node_properties(mut rest: &str) -> &str {
+    while rest.starts_with(['&', '!']) {
+        rest = rest
+            .split_once(' ')
+            .map_or("", |(_, after)| after)
+            .trim_start();
+    }
+    rest
+}
+
+fn unquote(text: &str) -> &str {
+    for quote in ['"', '\''] {
+        if let Some(inner) = text
+            .strip_prefix(quote)
+            .and_then(|inner| inner.strip_suffix(quote))
+        {
+            return inner;
+        }
+    }
+    text
+}
+
+/// Net `{`/`[` nesting opened by `text`, ignoring quoted brackets.
+fn bracket_depth(text: &str) -> i32 {
+    let mut depth = 0;
+    let mut quote = None;
+    for c in text.chars() {
+        match quote {
+            Some(q) if c == q => quote = None,
+            Some(_) => {}
+            None => match c {
+                '"' | '\'' => quote = Some(c),
+                '{' | '[' => depth += 1,
+                '}' | ']' => depth -= 1,
+                _ => {}
+            },
+        }
+    }
+    depth
+}
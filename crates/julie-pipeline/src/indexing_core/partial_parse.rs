@@ -0,0 +1,136 @@
+//! Error-tolerant extraction for files with syntax errors.
+//!
+//! A file that is mid-edit often fails to parse, and tree-sitter's recovery
+//! can fold the code after the error into one `ERROR` node, so the extractor
+//! finds few symbols or none. When extraction reports parse diagnostics, the
+//! stray tokens inside each `ERROR` node are blanked out, keeping the
+//! well-formed subtrees the node still holds. Newlines survive the masking,
+//! so every byte offset, line and column stays where it was. The masked copy
+//! is extracted again, and whichever pass recovered more symbols is kept.
+//!
+//! Either way the results keep the original diagnostics, which are stored with
+//! the file; a file with stored diagnostics is partially indexed until it
+//! parses cleanly again.
+
+use std::ops::Range;
+
+use anyhow::Result;
+use julie_core::parser_pool::ParserPool;
+use julie_extractors::ExtractionResults;
+use julie_extractors::base::ParseDiagnostic;
+use tree_sitter::Node;
+
+/// Masking rounds: blanking one error region can expose the next.
+const MAX_RECOVERY_PASSES: usize = 3;
+
+/// Run `extract` on `content`, and again on a copy with its syntax errors
+/// masked when the first pass reports parse diagnostics.
+pub fn extract_tolerating_syntax_errors<F>(
+    language: &str,
+    content: &str,
+    extract: F,
+) -> Result<ExtractionResults>
+where
+    F: Fn(&str) -> Result<ExtractionResults>,
+{
+    let results = extract(content)?;
+    if results.parse_diagnostics.is_empty() {
+        return Ok(results);
+    }
+    let Some(masked) = mask_syntax_errors(language, content) else {
+        return Ok(results);
+    };
+    match extract(&masked) {
+        Ok(mut recovered) if recovered.symbols.len() > results.symbols.len() => {
+            recovered.parse_diagnostics = results.parse_diagnostics;
+            Ok(recovered)
+        }
+        _ => Ok(results),
+    }
+}
+
+/// `content` with the stray tokens of every `ERROR` node replaced by spaces,
+/// or `None` when the grammar is unknown or there is nothing to mask.
+pub fn mask_syntax_errors(language: &str, content: &str) -> Option<String> {
+    let mut parser = ParserPool::global().checkout(language).ok()?;
+
+    let mut masked = content.to_string();
+    for _ in 0..MAX_RECOVERY_PASSES {
+        let tree = parser.parse(&masked, None)?;
+        let root = tree.root_node();
+        if !root.has_error() {
+            break;
+        }
+        let mut ranges = Vec::new();
+        collect_error_tokens(root, &mut ranges);
+        let next = blank_ranges(&masked, &ranges);
+        if next == masked {
+            break;
+        }
+        masked = next;
+    }
+    (masked != content).then_some(masked)
+}
+
+/// Byte ranges of the tokens under `node` that belong to no well-formed
+/// subtree: leaves of `ERROR` nodes, and the nodes tree-sitter skipped.
+fn collect_error_tokens(node: Node<'_>, ranges: &mut Vec<Range<usize>>) {
+    if !node.has_error() {
+        return;
+    }
+    if !node.is_error() {
+        let mut cursor = node.walk();
+        for child in node.children(&mut cursor) {
+            collect_error_tokens(child, ranges);
+        }
+        return;
+    }
+    if node.child_count() == 0 {
+        ranges.push(node.byte_range());
+        return;
+    }
+    let mut cursor = node.walk();
+    for child in node.children(&mut cursor) {
+        if child.has_error() {
+            collect_error_tokens(child, ranges);
+        } else if !child.is_named() || child.child_count() == 0 {
+            ranges.push(child.byte_range());
+        }
+    }
+}
+
+/// Replace every byte in `ranges` except line breaks with a space, widening
+/// each range to whole characters so the result stays valid UTF-8.
+fn blank_ranges(content: &str, ranges: &[Range<usize>]) -> String {
+    let mut bytes = content.as_bytes().to_vec();
+    for range in ranges {
+        let mut start = range.start.min(content.len());
+        let mut end = range.end.min(content.len());
+        while !content.is_char_boundary(start) {
+            start -= 1;
+        }
+        while !content.is_char_boundary(end) {
+            end += 1;
+        }
+        for byte in &mut bytes[start..end] {
+            if *byte != b'\n' && *byte != b'\r' {
+                *byte = b' ';
+            }
+        }
+    }
+    String::from_utf8(bytes).expect("masking keeps whole characters")
+}
+
+/// One-line summary of a partially indexed file's diagnostics, such as
+/// `2 syntax errors, first at line 14`.
+pub fn describe_parse_diagnostics(diagnostics: &[ParseDiagnostic]) -> Option<String> {
+    let first = diagnostics
+        .iter()
+        .min_by_key(|diagnostic| diagnostic.start_byte)?;
+    Some(format!(
+        "{} syntax error{}, first at line {}",
+        diagnostics.len(),
+        if diagnostics.len() == 1 { "" } else { "s" },
+        first.start_line
+    ))
+}
@@ -1,10 +1,20 @@
 use std::path::Path;
 
+use julie_core::paths::{encode_path_lossless, normalize_stored_path_within};
+
 pub(crate) fn relative_path_for_storage(file_path: &Path, workspace_root: &Path) -> String {
     if file_path.is_absolute() {
         julie_core::paths::to_relative_unix_style(file_path, workspace_root)
-            .unwrap_or_else(|_| file_path.to_string_lossy().replace('\\', "/"))
+            .unwrap_or_else(|_| storage_fallback(file_path, workspace_root))
     } else {
-        file_path.to_string_lossy().replace('\\', "/")
+        storage_fallback(file_path, workspace_root)
     }
 }
+
+/// Files outside the workspace root keep their full path, minus any `\\?\`
+/// prefix a canonicalized Windows path carries. A path the root comparison
+/// missed only because of its spelling is still stored relative.
+fn storage_fallback(file_path: &Path, workspace_root: &Path) -> String {
+    let encoded = encode_path_lossless(file_path.as_os_str()).replace('\\', "/");
+    normalize_stored_path_within(&encoded, workspace_root).into_owned()
+}
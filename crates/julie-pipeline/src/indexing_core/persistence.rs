@@ -22,6 +22,32 @@ pub fn persist_force_rebuild(
     Ok(revision)
 }
 
+/// [`persist_force_rebuild`] for a batch too large to hold at once, such as a
+/// distributed merge: `next_part` yields the batch one part at a time, each
+/// of whole files, and returns `None` after the last. Every part is written
+/// in one transaction, so an error from `next_part` keeps the previous index.
+pub fn persist_force_rebuild_streamed<F>(
+    db: &mut SymbolDatabase,
+    workspace_id: &str,
+    mut next_part: F,
+) -> Result<Option<i64>>
+where
+    F: FnMut() -> Result<Option<ExtractedBatch>>,
+{
+    require_workspace_id(workspace_id)?;
+    let revision = db.replace_workspace_data_streamed(workspace_id, |writer| {
+        while let Some(batch) = next_part()? {
+            writer.write(
+                &batch.canonical_write_set(),
+                external_mutation_metadata(&batch),
+            )?;
+        }
+        Ok(())
+    })?;
+    rebuild_web_edges_for_workspace(db, workspace_id)?;
+    Ok(revision)
+}
+
 pub fn persist_incremental_scan(
     db: &mut SymbolDatabase,
     workspace_id: &str,
@@ -99,6 +125,7 @@ fn external_mutation_metadata(batch: &ExtractedBatch) -> AtomicPersistenceMetada
     AtomicPersistenceMetadata {
         parse_diagnostics_by_file: &batch.parse_diagnostics_by_file,
         repair_entries: &batch.repair_entries,
+        parse_skips: &batch.parse_skips,
         mark_external_analysis_stale: true,
     }
 }
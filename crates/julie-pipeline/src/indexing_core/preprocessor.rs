@@ -0,0 +1,312 @@
+//! C/C++ conditional-compilation tags.
+//!
+//! Tree-sitter parses every branch of an `#ifdef`, so a file that defines
+//! `open_file` once for Windows and once for POSIX yields both symbols with
+//! nothing telling them apart. This pass walks the file's `#if`/`#ifdef`/
+//! `#elif`/`#else`/`#endif` directives and records on each symbol the
+//! condition its branch is compiled under, as a single `#if` expression
+//! (`defined(_WIN32) && !(LEVEL > 2)`).
+//!
+//! With `[preprocessor] defines` configured, the condition is also evaluated
+//! against that set plus the file's own `#define`/`#undef`s, and the symbol is
+//! marked `active` or not. Symbols are never dropped: the inactive branch is
+//! still navigable, and search can re-evaluate the stored condition for
+//! another configuration. Include guards are recognised and left out of the
+//! condition.
+
+use std::path::Path;
+
+use julie_core::preprocessor::{DefineSet, evaluate_condition};
+use julie_core::workspace_settings::WorkspaceSettingsRegistry;
+use julie_extractors::base::Symbol;
+use serde_json::json;
+
+use super::c_macros::{LogicalLine, logical_lines};
+
+/// Metadata key holding `{condition, active}`; `active` is present only when
+/// the workspace configures a define set and the condition is decidable.
+pub const PREPROCESSOR_METADATA_KEY: &str = "preprocessor";
+
+/// The define set configured for the workspace, if any.
+pub fn preprocessor_defines_for(workspace_root: &Path) -> Option<DefineSet> {
+    WorkspaceSettingsRegistry::global()
+        .get(workspace_root)
+        .preprocessor_defines()
+}
+
+/// Tag one C/C++ file's symbols with the conditions guarding them. Other
+/// languages are left untouched. Returns how many symbols were tagged.
+pub fn annotate_preprocessor_conditions(
+    symbols: &mut [Symbol],
+    content: &str,
+    defines: Option<&DefineSet>,
+) -> usize {
+    if !symbols
+        .iter()
+        .any(|s| matches!(s.language.as_str(), "c" | "cpp"))
+    {
+        return 0;
+    }
+    let regions = conditional_regions(&logical_lines(content), defines);
+    if regions.is_empty() {
+        return 0;
+    }
+
+    let mut tagged = 0;
+    for symbol in symbols.iter_mut() {
+        let index = regions.partition_point(|region| region.line <= symbol.start_line);
+        let Some(region) = index.checked_sub(1).map(|i| &regions[i]) else {
+            continue;
+        };
+        if region.condition.is_empty() {
+            continue;
+        }
+        let mut info = json!({ "condition": region.condition });
+        if let Some(active) = region.active {
+            info["active"] = json!(active);
+        }
+        symbol
+            .metadata
+            .get_or_insert_with(Default::default)
+            .insert(PREPROCESSOR_METADATA_KEY.to_string(), info);
+        tagged += 1;
+    }
+    tagged
+}
+
+/// From `line` on (until the next region), code is compiled under
+/// `condition`; empty means unconditionally.
+#[derive(Debug)]
+struct Region {
+    line: u32,
+    condition: String,
+    active: Option<bool>,
+}
+
+/// One open `#if` group.
+struct Frame {
+    /// Conditions of the group's earlier branches, all false in this one.
+    earlier: Vec<String>,
+    /// This branch's own condition; `None` for `#else`.
+    current: Option<String>,
+    /// An include guard, left out of conditions.
+    guard: bool,
+    parent_active: Option<bool>,
+    /// Whether an earlier branch was taken.
+    taken: Option<bool>,
+    active: Option<bool>,
+}
+
+struct Directive<'a> {
+    keyword: &'a str,
+    argument: String,
+    last_line: u32,
+}
+
+fn directives<'a>(lines: &'a [LogicalLine<'_>]) -> Vec<Directive<'a>> {
+    lines
+        .iter()
+        .filter_map(|line| {
+            let rest = line.text.trim_start().strip_prefix('#')?.trim_start();
+            let keyword_len = rest
+                .find(|c: char| !c.is_ascii_alphabetic())
+                .unwrap_or(rest.len());
+            Some(Directive {
+                keyword: &rest[..keyword_len],
+                argument: normalize_expression(&rest[keyword_len..]),
+                last_line: line.last_line,
+            })
+        })
+        .collect()
+}
+
+fn conditional_regions(lines: &[LogicalLine<'_>], defines: Option<&DefineSet>) -> Vec<Region> {
+    let directives = directives(lines);
+    let mut local = defines.cloned();
+    let mut frames: Vec<Frame> = Vec::new();
+    let mut regions = Vec::new();
+
+    for (index, directive) in directives.iter().enumerate() {
+        let argument = directive.argument.as_str();
+        let condition = match directive.keyword {
+            "if" | "elif" => Some(argument.to_string()),
+            "ifdef" | "elifdef" => Some(format!("defined({})", first_word(argument))),
+            "ifndef" | "elifndef" => Some(format!("!defined({})", first_word(argument))),
+            _ => None,
+        };
+        let enclosing_active = frames.last().map_or(Some(true), |frame| frame.active);
+
+        match (directive.keyword, condition) {
+            ("if" | "ifdef" | "ifndef", Some(condition)) => {
+                let value = evaluate(&condition, local.as_ref());
+                let guard = index == 0 && is_include_guard(&condition, directives.get(1));
+                frames.push(Frame {
+                    earlier: Vec::new(),
+                    current: Some(condition),
+                    guard,
+                    parent_active: enclosing_active,
+                    taken: value,
+                    active: and(enclosing_active, value),
+                });
+            }
+            ("elif" | "elifdef" | "elifndef", Some(condition)) => {
+                let value = evaluate(&condition, local.as_ref());
+                let Some(frame) = frames.last_mut() else {
+                    continue;
+                };
+                frame.earlier.extend(frame.current.take());
+                frame.active = and(frame.parent_active, and(not(frame.taken), value));
+                frame.taken = or(frame.taken, value);
+                frame.current = Some(condition);
+            }
+            ("else", _) => {
+                let Some(frame) = frames.last_mut() else {
+                    continue;
+                };
+                frame.earlier.extend(frame.current.take());
+                frame.active = and(frame.parent_active, not(frame.taken));
+                frame.taken = Some(true);
+            }
+            ("endif", _) => {
+                frames.pop();
+            }
+            ("define" | "undef", _) => {
+                // Only definitions the compiler would actually see count.
+                if let (Some(local), Some(true)) = (local.as_mut(), enclosing_active) {
+                    let (name, value) = split_definition(argument);
+                    if directive.keyword == "define" {
+                        local.define(name, value);
+                    } else {
+                        local.undefine(name);
+                    }
+                }
+                continue;
+            }
+            _ => continue,
+        }
+
+        regions.push(Region {
+            line: directive.last_line + 1,
+            condition: combined_condition(&frames),
+            active: defines.and(frames.last().map_or(Some(true), |frame| frame.active)),
+        });
+    }
+    regions
+}
+
+fn evaluate(condition: &str, defines: Option<&DefineSet>) -> Option<bool> {
+    defines.and_then(|defines| evaluate_condition(condition, defines))
+}
+
+/// `#ifndef X` / `#define X` as the first two directives of the file.
+fn is_include_guard(condition: &str, next: Option<&Directive<'_>>) -> bool {
+    let Some(name) = condition
+        .strip_prefix("!defined(")
+        .and_then(|rest| rest.strip_suffix(')'))
+    else {
+        return false;
+    };
+    next.is_some_and(|next| next.keyword == "define" && first_word(&next.argument) == name)
+}
+
+/// The conjunction of every open branch's condition.
+fn combined_condition(frames: &[Frame]) -> String {
+    // Negations come back from `negate` already safe to join; a branch's own
+    // condition is bracketed unless it stands alone.
+    let parts: Vec<(String, bool)> = frames
+        .iter()
+        .filter(|frame| !frame.guard)
+        .flat_map(|frame| {
+            frame
+                .earlier
+                .iter()
+                .map(|earlier| (negate(earlier), true))
+                .chain(frame.current.iter().map(|current| {
+                    let safe = is_atomic(current.strip_prefix('!').unwrap_or(current));
+                    (current.clone(), safe)
+                }))
+        })
+        .collect();
+    if let [(only, _)] = parts.as_slice() {
+        return only.clone();
+    }
+    parts
+        .into_iter()
+        .map(|(part, safe)| if safe { part } else { format!("({part})") })
+        .collect::<Vec<_>>()
+        .join(" && ")
+}
+
+fn negate(condition: &str) -> String {
+    match condition.strip_prefix('!') {
+        Some(inner) if is_atomic(inner) => inner.to_string(),
+        _ if is_atomic(condition) => format!("!{condition}"),
+        _ => format!("!({condition})"),
+    }
+}
+
+/// A name, a number or `defined(NAME)`: safe to negate or join unbracketed.
+fn is_atomic(condition: &str) -> bool {
+    let word = condition
+        .strip_prefix("defined(")
+        .and_then(|rest| rest.strip_suffix(')'))
+        .unwrap_or(condition);
+    !word.is_empty() && word.chars().all(|c| c == '_' || c.is_ascii_alphanumeric())
+}
+
+/// Strip comments and collapse whitespace so stored conditions are stable.
+fn normalize_expression(text: &str) -> String {
+    let mut stripped = String::with_capacity(text.len());
+    let mut rest = text;
+    while !rest.is_empty() {
+        if rest.starts_with("//") {
+            break;
+        }
+        if let Some(comment) = rest.strip_prefix("/*") {
+            rest = comment.find("*/").map_or("", |end| &comment[end + 2..]);
+            stripped.push(' ');
+            continue;
+        }
+        let c = rest.chars().next().unwrap_or_default();
+        stripped.push(c);
+        rest = &rest[c.len_utf8()..];
+    }
+    stripped.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+fn first_word(argument: &str) -> &str {
+    argument.split_whitespace().next().unwrap_or_default()
+}
+
+/// `NAME value...` of an object-like `#define`, value `""` when absent.
+/// Function-like macros get no value: they are never numeric in `#if`.
+fn split_definition(argument: &str) -> (&str, &str) {
+    let name_len = argument
+        .find(|c: char| !(c == '_' || c.is_ascii_alphanumeric()))
+        .unwrap_or(argument.len());
+    let (name, rest) = argument.split_at(name_len);
+    if rest.starts_with('(') {
+        return (name, "");
+    }
+    (name, rest.trim())
+}
+
+fn and(left: Option<bool>, right: Option<bool>) -> Option<bool> {
+    match (left, right) {
+        (Some(false), _) | (_, Some(false)) => Some(false),
+        (Some(true), Some(true)) => Some(true),
+        _ => None,
+    }
+}
+
+fn or(left: Option<bool>, right: Option<bool>) -> Option<bool> {
+    match (left, right) {
+        (Some(true), _) | (_, Some(true)) => Some(true),
+        (Some(false), Some(false)) => Some(false),
+        _ => None,
+    }
+}
+
+fn not(value: Option<bool>) -> Option<bool> {
+    value.map(|value| !value)
+}
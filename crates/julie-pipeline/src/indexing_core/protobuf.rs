@@ -0,0 +1,621 @@
+//! Symbols for Protocol Buffers / gRPC definitions (`.proto` files).
+//!
+//! This pass tokenizes `.proto` files with the shared [`lexer`](super::lexer)
+//! and emits a namespace for the `package`, structs for messages (with their
+//! fields), enums (with their values), interfaces for services and methods
+//! for RPCs. Every symbol carries `{kind, full_name, ...}` under
+//! [`PROTOBUF_METADATA_KEY`]; RPCs add their request/response types and
+//! streaming flags.
+//!
+//! The post-index pass in `julie_index::analysis::grpc_links` connects these
+//! symbols to the generated Go/Java/C#/TypeScript stubs.
+//!
+//! Unknown statements are skipped up to the next `;` or balanced block.
+
+use std::collections::HashMap;
+
+use julie_extractors::base::{Symbol, SymbolKind};
+use serde_json::{Value, json};
+
+use crate::indexing_core::lexer::{
+    Lexer, Token, TokenKind, block_end, comment_lines, escaped_quote_end, line_end, punct_len,
+    word_end,
+};
+
+/// Language id assigned to `.proto` files (see `file_policy`).
+pub const PROTOBUF_LANGUAGE: &str = "protobuf";
+
+/// Metadata key holding the protobuf-specific facts of a symbol.
+pub const PROTOBUF_METADATA_KEY: &str = "protobuf";
+
+/// Add protobuf symbols for a `.proto` file. Other files, and files an
+/// extractor already produced symbols for, are left untouched.
+pub fn annotate_protobuf_definitions(
+    symbols: &mut Vec<Symbol>,
+    file_path: &str,
+    language: &str,
+    content: &str,
+) {
+    if language != PROTOBUF_LANGUAGE || !symbols.is_empty() {
+        return;
+    }
+    symbols.extend(parse_protobuf_symbols(file_path, content));
+}
+
+/// Parse every definition in a `.proto` file, parents before children.
+pub fn parse_protobuf_symbols(file_path: &str, content: &str) -> Vec<Symbol> {
+    let tokens = tokenize(content);
+    let mut parser = Parser {
+        file_path,
+        content,
+        tokens: &tokens,
+        pos: 0,
+        package: None,
+        symbols: Vec::new(),
+        scopes: Vec::new(),
+    };
+    parser.run();
+    parser.symbols
+}
+
+// ---------------------------------------------------------------------------
+// Tokenizer
+// ---------------------------------------------------------------------------
+
+/// Package and type names are read as one word (`google.protobuf.Empty`).
+fn is_word_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || c == '_' || c == '.'
+}
+
+fn tokenize(content: &str) -> Vec<Token<'_>> {
+    let bytes = content.as_bytes();
+    let mut lexer = Lexer::new(content);
+    let mut i = 0;
+
+    while i < bytes.len() {
+        let c = bytes[i];
+        if c.is_ascii_whitespace() {
+            i += 1;
+            continue;
+        }
+
+        let start = i;
+        let kind = if content[i..].starts_with("//") {
+            i = line_end(content, i);
+            TokenKind::Comment
+        } else if content[i..].starts_with("/*") {
+            i = block_end(content, i + 2, "*/");
+            TokenKind::Comment
+        } else if c == b'"' || c == b'\'' {
+            i = escaped_quote_end(bytes, i + 1, c);
+            TokenKind::Str
+        } else if is_word_char(c as char) {
+            i = word_end(content, i, is_word_char);
+            TokenKind::Word
+        } else {
+            i += punct_len(&content[i..], &[]);
+            TokenKind::Punct
+        };
+        lexer.push(kind, start, i);
+    }
+    lexer.finish().0
+}
+
+// ---------------------------------------------------------------------------
+// Parser
+// ---------------------------------------------------------------------------
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum ScopeKind {
+    Message,
+    Enum,
+    Service,
+    /// `oneof`: its fields belong to the enclosing message.
+    Oneof,
+}
+
+struct Scope {
+    kind: ScopeKind,
+    /// Index into `symbols` of the symbol this block closes, if any.
+    symbol: Option<usize>,
+    /// Dotted name of the enclosing definition, for `full_name`.
+    full_name: Option<String>,
+}
+
+struct Parser<'a, 't> {
+    file_path: &'a str,
+    content: &'a str,
+    tokens: &'t [Token<'a>],
+    pos: usize,
+    package: Option<String>,
+    symbols: Vec<Symbol>,
+    scopes: Vec<Scope>,
+}
+
+impl<'a> Parser<'a, '_> {
+    fn run(&mut self) {
+        while self.pos < self.tokens.len() {
+            let doc = self.take_doc_comment();
+            let tokens = self.tokens;
+            let Some(token) = tokens.get(self.pos) else {
+                break;
+            };
+            let statement_start = self.pos;
+            match (token.kind, token.text) {
+                (TokenKind::Punct, "}") => {
+                    self.close_scope(token.end, token.line, token.column + 1);
+                    self.pos += 1;
+                }
+                (TokenKind::Punct, ";") => self.pos += 1,
+                (TokenKind::Word, "package") if self.scopes.is_empty() => self.parse_package(doc),
+                (TokenKind::Word, "message") => self.parse_block_definition(
+                    "message",
+                    ScopeKind::Message,
+                    SymbolKind::Struct,
+                    doc,
+                ),
+                (TokenKind::Word, "enum") => {
+                    self.parse_block_definition("enum", ScopeKind::Enum, SymbolKind::Enum, doc)
+                }
+                (TokenKind::Word, "service") if self.scopes.is_empty() => self
+                    .parse_block_definition(
+                        "service",
+                        ScopeKind::Service,
+                        SymbolKind::Interface,
+                        doc,
+                    ),
+                (TokenKind::Word, "rpc") if self.current_scope() == Some(ScopeKind::Service) => {
+                    self.parse_rpc(doc)
+                }
+                (TokenKind::Word, "oneof") if self.in_message() => {
+                    self.skip_to_block_open(ScopeKind::Oneof)
+                }
+                (
+                    TokenKind::Word,
+                    "option" | "reserved" | "extensions" | "import" | "syntax" | "edition",
+                ) => self.skip_statement(),
+                (TokenKind::Word, _) if self.in_message() => self.parse_field(doc),
+                (TokenKind::Word, _) if self.current_scope() == Some(ScopeKind::Enum) => {
+                    self.parse_enum_value(doc)
+                }
+                _ => self.skip_statement(),
+            }
+            // Guarantee progress on malformed input.
+            if self.pos == statement_start {
+                self.pos += 1;
+            }
+        }
+    }
+
+    fn current_scope(&self) -> Option<ScopeKind> {
+        self.scopes.last().map(|scope| scope.kind)
+    }
+
+    /// Fields are allowed directly in a message or in one of its `oneof`s.
+    fn in_message(&self) -> bool {
+        matches!(
+            self.current_scope(),
+            Some(ScopeKind::Message | ScopeKind::Oneof)
+        )
+    }
+
+    /// The nearest enclosing definition: its symbol index and dotted name.
+    fn enclosing(&self) -> (Option<usize>, Option<String>) {
+        self.scopes
+            .iter()
+            .rev()
+            .find(|scope| scope.symbol.is_some())
+            .map_or((None, self.package.clone()), |scope| {
+                (scope.symbol, scope.full_name.clone())
+            })
+    }
+
+    /// Consecutive `//` or `/* */` comments before the next token, joined.
+    /// Comments separated from the next token by a blank line, and trailing
+    /// comments on the previous statement's line, are dropped.
+    fn take_doc_comment(&mut self) -> Option<String> {
+        let mut lines: Vec<String> = Vec::new();
+        let mut last_line = 0;
+        while let Some(token) = self.tokens.get(self.pos) {
+            if token.kind != TokenKind::Comment {
+                break;
+            }
+            let trailing = self.pos > 0
+                && self.tokens[self.pos - 1].kind != TokenKind::Comment
+                && self.tokens[self.pos - 1].line == token.line;
+            if trailing {
+                self.pos += 1;
+                continue;
+            }
+            if !lines.is_empty() && token.line > last_line + 1 {
+                lines.clear();
+            }
+            lines.extend(comment_lines(token.text));
+            last_line = token.line + token.text.matches('\n').count() as u32;
+            self.pos += 1;
+        }
+        let next_line = self
+            .tokens
+            .get(self.pos)
+            .map_or(u32::MAX, |token| token.line);
+        (!lines.is_empty() && next_line <= last_line + 1).then(|| lines.join("\n"))
+    }
+
+    fn word_at(&self, index: usize) -> Option<&'a str> {
+        self.tokens
+            .get(index)
+            .filter(|token| token.kind == TokenKind::Word)
+            .map(|token| token.text)
+    }
+
+    fn punct_at(&self, index: usize, punct: &str) -> bool {
+        self.tokens
+            .get(index)
+            .is_some_and(|token| token.kind == TokenKind::Punct && token.text == punct)
+    }
+
+    /// Skip to just past the next `;` at this nesting level, or past a
+    /// balanced `{ ... }` block if one comes first.
+    fn skip_statement(&mut self) {
+        let mut depth = 0usize;
+        while let Some(token) = self.tokens.get(self.pos) {
+            if token.kind == TokenKind::Punct {
+                match token.text {
+                    ";" if depth == 0 => {
+                        self.pos += 1;
+                        return;
+                    }
+                    "{" => depth += 1,
+                    "}" if depth == 0 => return,
+                    "}" => {
+                        depth -= 1;
+                        if depth == 0 {
+                            self.pos += 1;
+                            return;
+                        }
+                    }
+                    _ => {}
+                }
+            }
+            self.pos += 1;
+        }
+    }
+
+    /// Advance past the next `{` and open a scope without a symbol.
+    fn skip_to_block_open(&mut self, kind: ScopeKind) {
+        while let Some(token) = self.tokens.get(self.pos) {
+            self.pos += 1;
+            if token.kind == TokenKind::Punct && token.text == "{" {
+                self.scopes.push(Scope {
+                    kind,
+                    symbol: None,
+                    full_name: None,
+                });
+                return;
+            }
+            if token.kind == TokenKind::Punct && token.text == ";" {
+                return;
+            }
+        }
+    }
+
+    fn close_scope(&mut self, end_byte: usize, end_line: u32, end_column: u32) {
+        if let Some(index) = self.scopes.pop().and_then(|scope| scope.symbol) {
+            self.set_end(index, end_byte, end_line, end_column);
+        }
+    }
+
+    fn set_end(&mut self, index: usize, end_byte: usize, end_line: u32, end_column: u32) {
+        let symbol = &mut self.symbols[index];
+        symbol.end_byte = end_byte as u32;
+        symbol.end_line = end_line;
+        symbol.end_column = end_column;
+        symbol.code_context = Some(self.content[symbol.start_byte as usize..end_byte].to_string());
+    }
+
+    fn parse_package(&mut self, doc: Option<String>) {
+        let start = self.pos;
+        let Some(name) = self.word_at(start + 1) else {
+            self.skip_statement();
+            return;
+        };
+        self.pos += 2;
+        let end = self.end_of_statement();
+        self.package = Some(name.to_string());
+        self.push_symbol(
+            SymbolSpec {
+                name: name.to_string(),
+                kind: SymbolKind::Namespace,
+                proto_kind: "package",
+                full_name: name.to_string(),
+                signature: format!("package {name};"),
+                parent: None,
+                doc,
+                extra: Value::Null,
+            },
+            start,
+            end,
+        );
+    }
+
+    /// `message Name {`, `enum Name {`, `service Name {`.
+    fn parse_block_definition(
+        &mut self,
+        keyword: &'static str,
+        scope: ScopeKind,
+        kind: SymbolKind,
+        doc: Option<String>,
+    ) {
+        let start = self.pos;
+        let Some(name) = self.word_at(start + 1) else {
+            self.skip_statement();
+            return;
+        };
+        if !self.punct_at(start + 2, "{") {
+            self.skip_statement();
+            return;
+        }
+        self.pos = start + 3;
+
+        let (parent, outer) = self.enclosing();
+        let full_name = qualify(outer.as_deref(), name);
+        let index = self.push_symbol(
+            SymbolSpec {
+                name: name.to_string(),
+                kind,
+                proto_kind: keyword,
+                full_name: full_name.clone(),
+                signature: format!("{keyword} {name}"),
+                parent,
+                doc,
+                extra: Value::Null,
+            },
+            start,
+            start + 2,
+        );
+        self.scopes.push(Scope {
+            kind: scope,
+            symbol: Some(index),
+            full_name: Some(full_name),
+        });
+    }
+
+    /// `rpc Name (stream Req) returns (stream Resp);` or `... { options }`.
+    fn parse_rpc(&mut self, doc: Option<String>) {
+        let start = self.pos;
+        let Some(name) = self.word_at(start + 1) else {
+            self.skip_statement();
+            return;
+        };
+        let mut cursor = start + 2;
+        let Some((request, client_streaming, next)) = self.rpc_message_type(cursor) else {
+            self.skip_statement();
+            return;
+        };
+        cursor = next;
+        if self.word_at(cursor) != Some("returns") {
+            self.skip_statement();
+            return;
+        }
+        let Some((response, server_streaming, next)) = self.rpc_message_type(cursor + 1) else {
+            self.skip_statement();
+            return;
+        };
+        self.pos = next;
+        let end = if self.punct_at(self.pos, "{") {
+            // Option block: `{ option (google.api.http) = { ... }; }`.
+            self.skip_statement();
+            self.pos.saturating_sub(1)
+        } else {
+            self.end_of_statement()
+        };
+
+        let (parent, outer) = self.enclosing();
+        let stream = |streaming: bool| if streaming { "stream " } else { "" };
+        self.push_symbol(
+            SymbolSpec {
+                name: name.to_string(),
+                kind: SymbolKind::Method,
+                proto_kind: "rpc",
+                full_name: qualify(outer.as_deref(), name),
+                signature: format!(
+                    "rpc {name}({}{request}) returns ({}{response})",
+                    stream(client_streaming),
+                    stream(server_streaming)
+                ),
+                parent,
+                doc,
+                extra: json!({
+                    "request": request,
+                    "response": response,
+                    "client_streaming": client_streaming,
+                    "server_streaming": server_streaming,
+                }),
+            },
+            start,
+            end,
+        );
+    }
+
+    /// `( [stream] Type )` starting at `index`: the type, whether it streams,
+    /// and the index after `)`.
+    fn rpc_message_type(&self, index: usize) -> Option<(&'a str, bool, usize)> {
+        if !self.punct_at(index, "(") {
+            return None;
+        }
+        let (streaming, type_index) = match self.word_at(index + 1) {
+            Some("stream") if self.word_at(index + 2).is_some() => (true, index + 2),
+            Some(_) => (false, index + 1),
+            None => return None,
+        };
+        let message_type = self.word_at(type_index)?;
+        self.punct_at(type_index + 1, ")")
+            .then_some((message_type, streaming, type_index + 2))
+    }
+
+    /// `[repeated|optional|required] Type name = N [opts];` or
+    /// `map<K, V> name = N;`. Nested `message`/`enum` are handled by `run`.
+    fn parse_field(&mut self, doc: Option<String>) {
+        let start = self.pos;
+        let mut cursor = start;
+        let label = match self.word_at(cursor) {
+            Some(label @ ("repeated" | "optional" | "required")) => {
+                cursor += 1;
+                Some(label)
+            }
+            _ => None,
+        };
+
+        let field_type = if self.word_at(cursor) == Some("map") && self.punct_at(cursor + 1, "<") {
+            let mut close = cursor + 2;
+            while close < self.tokens.len() && !self.punct_at(close, ">") {
+                close += 1;
+            }
+            let text = &self.content[self.tokens[cursor].start
+                ..self.tokens.get(close).map_or(self.content.len(), |t| t.end)];
+            cursor = close + 1;
+            text.split_whitespace().collect::<String>()
+        } else {
+            match self.word_at(cursor) {
+                Some(field_type) => {
+                    cursor += 1;
+                    field_type.to_string()
+                }
+                None => {
+                    self.skip_statement();
+                    return;
+                }
+            }
+        };
+
+        let (Some(name), true) = (self.word_at(cursor), self.punct_at(cursor + 1, "=")) else {
+            self.skip_statement();
+            return;
+        };
+        let number = self.word_at(cursor + 2).unwrap_or("?");
+        self.pos = cursor + 2;
+        let end = self.end_of_statement();
+
+        let (parent, outer) = self.enclosing();
+        let label_prefix = label.map(|label| format!("{label} ")).unwrap_or_default();
+        self.push_symbol(
+            SymbolSpec {
+                name: name.to_string(),
+                kind: SymbolKind::Field,
+                proto_kind: "field",
+                full_name: qualify(outer.as_deref(), name),
+                signature: format!("{label_prefix}{field_type} {name} = {number}"),
+                parent,
+                doc,
+                extra: json!({ "type": field_type, "number": number }),
+            },
+            start,
+            end,
+        );
+    }
+
+    /// `NAME = N [opts];`
+    fn parse_enum_value(&mut self, doc: Option<String>) {
+        let start = self.pos;
+        let (Some(name), true) = (self.word_at(start), self.punct_at(start + 1, "=")) else {
+            self.skip_statement();
+            return;
+        };
+        let number = self.tokens.get(start + 2).map_or("?", |token| token.text);
+        let number = if number == "-" {
+            self.word_at(start + 3)
+                .map_or("?".to_string(), |n| format!("-{n}"))
+        } else {
+            number.to_string()
+        };
+        self.pos = start + 2;
+        let end = self.end_of_statement();
+
+        let (parent, outer) = self.enclosing();
+        self.push_symbol(
+            SymbolSpec {
+                name: name.to_string(),
+                kind: SymbolKind::EnumMember,
+                proto_kind: "enum_value",
+                full_name: qualify(outer.as_deref(), name),
+                signature: format!("{name} = {number}"),
+                parent,
+                doc,
+                extra: json!({ "number": number }),
+            },
+            start,
+            end,
+        );
+    }
+
+    /// Consume through the statement's `;` and return the index of the last
+    /// token it spans.
+    fn end_of_statement(&mut self) -> usize {
+        self.skip_statement();
+        self.pos.saturating_sub(1)
+    }
+
+    fn push_symbol(&mut self, spec: SymbolSpec, first: usize, last: usize) -> usize {
+        let first = &self.tokens[first];
+        let last = &self.tokens[last.min(self.tokens.len() - 1)];
+        let parent_id = spec.parent.map(|index| self.symbols[index].id.clone());
+
+        let mut info = json!({
+            "kind": spec.proto_kind,
+            "full_name": spec.full_name,
+        });
+        if let (Value::Object(info), Value::Object(extra)) = (&mut info, spec.extra) {
+            info.extend(extra);
+        }
+
+        let id_source = format!(
+            "{}:proto:{}:{}",
+            self.file_path, spec.proto_kind, spec.full_name
+        );
+        let id = blake3::hash(id_source.as_bytes()).to_hex()[..32].to_string();
+        self.symbols.push(Symbol {
+            id,
+            name: spec.name,
+            kind: spec.kind,
+            language: PROTOBUF_LANGUAGE.to_string(),
+            file_path: self.file_path.to_string(),
+            start_line: first.line,
+            start_column: first.column,
+            end_line: last.line,
+            end_column: last.column + (last.end - last.start) as u32,
+            start_byte: first.start as u32,
+            end_byte: last.end as u32,
+            signature: Some(spec.signature),
+            doc_comment: spec.doc,
+            visibility: None,
+            parent_id,
+            metadata: Some(HashMap::from([(PROTOBUF_METADATA_KEY.to_string(), info)])),
+            semantic_group: None,
+            confidence: None,
+            code_context: Some(self.content[first.start..last.end].to_string()),
+            content_type: None,
+            body_span: None,
+            body_hash: None,
+            annotations: Vec::new(),
+        });
+        self.symbols.len() - 1
+    }
+}
+
+struct SymbolSpec {
+    name: String,
+    kind: SymbolKind,
+    proto_kind: &'static str,
+    full_name: String,
+    signature: String,
+    parent: Option<usize>,
+    doc: Option<String>,
+    extra: Value,
+}
+
+fn qualify(outer: Option<&str>, name: &str) -> String {
+    match outer {
+        Some(outer) => format!("{outer}.{name}"),
+        None => name.to_string(),
+    }
+}
@@ -0,0 +1,499 @@
+//! Razor/Blazor component tags.
+//!
+//! A Blazor component is a `.razor` file; the extractor gives it a class
+//! symbol named after the file. Markup uses it as a tag — `<AppHeader
+//! Title="Home" />` — but nothing connects the tag to `AppHeader.razor`, so
+//! references to a component stop at the file that defines it.
+//!
+//! This pass scans Razor markup for component tags (PascalCase element names)
+//! and records, from the innermost symbol around each tag:
+//! - a `Uses` pending relationship to the component
+//!   (callee `razor-component:AppHeader`);
+//! - a `References` pending relationship per attribute to the component
+//!   parameter it sets (callee `razor-parameter:AppHeader.Title`).
+//!   `@bind-Value` sets `Value`, and a child tag such as `<ChildContent>`
+//!   inside `<ErrorBoundary>` sets the parent's render-fragment parameter.
+//!
+//! Finalize hands these to [`resolve_component_usages`], which looks the
+//! component up across the whole project: a `.razor` file of that name first
+//! (the one sharing the longest directory prefix with the using file when
+//! several exist), a C# class otherwise. Parameters are the properties of that
+//! component's `@code` block, its `.razor.cs` code-behind and its class.
+//! Tags that match nothing — HTML-cased framework components, typos — are
+//! dropped.
+
+use std::collections::{HashMap, HashSet};
+
+use julie_core::database::SymbolDatabase;
+use julie_extractors::base::{Symbol, SymbolKind};
+use julie_extractors::{ExtractionResults, PendingRelationship, Relationship, RelationshipKind};
+use tracing::{debug, warn};
+
+use super::config_keys::innermost_symbol;
+use super::text_lines::TextLines;
+
+/// Callee prefix of a pending component usage; the rest is the component name.
+pub const COMPONENT_CALLEE_PREFIX: &str = "razor-component:";
+
+/// Callee prefix of a pending parameter usage; the rest is
+/// `Component.Parameter`.
+pub const PARAMETER_CALLEE_PREFIX: &str = "razor-parameter:";
+
+/// One component tag in Razor markup.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ComponentTag {
+    /// Component name; a namespaced tag (`<Shared.AppHeader>`) keeps only the
+    /// last segment.
+    pub name: String,
+    /// 1-based line of the tag name.
+    pub line: u32,
+    /// Byte offset of the tag name.
+    pub offset: usize,
+    /// Parameters set by attributes, in source order.
+    pub attributes: Vec<TagAttribute>,
+    /// Innermost open component tag around this one.
+    pub parent: Option<String>,
+}
+
+/// A component parameter set in markup.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TagAttribute {
+    /// Parameter name (`Value` for `@bind-Value`).
+    pub name: String,
+    /// 1-based line of the attribute.
+    pub line: u32,
+    /// Byte offset of the attribute.
+    pub offset: usize,
+}
+
+/// Find every component tag in a Razor file, skipping comments, `@code` /
+/// `@functions` blocks and `<script>` / `<style>` bodies, where `<Name`
+/// is C# generics or script rather than markup.
+pub fn find_component_tags(content: &str) -> Vec<ComponentTag> {
+    let bytes = content.as_bytes();
+    let lines = TextLines::new(content);
+
+    let mut tags = Vec::new();
+    let mut open: Vec<String> = Vec::new();
+    let mut i = 0;
+    while i < bytes.len() {
+        if !content.is_char_boundary(i) {
+            i += 1;
+            continue;
+        }
+        let rest = &content[i..];
+        if rest.starts_with("@*") {
+            i = rest.find("*@").map_or(bytes.len(), |end| i + end + 2);
+        } else if rest.starts_with("<!--") {
+            i = rest.find("-->").map_or(bytes.len(), |end| i + end + 3);
+        } else if let Some(body) = code_block_start(rest) {
+            i = skip_braces(bytes, i + body);
+        } else if let Some(close) = raw_text_close(rest) {
+            i = find_ignore_case(content, i + 1, close)
+                .map_or(bytes.len(), |end| end + close.len());
+        } else if rest.starts_with("</") {
+            let name = tag_name(&rest[2..]);
+            let component = last_segment(name);
+            if let Some(at) = open.iter().rposition(|tag| tag == component) {
+                open.truncate(at);
+            }
+            i += 2 + name.len().max(1);
+        } else if bytes[i] == b'<' && starts_component(content, i) {
+            let name_start = i + 1;
+            let name = tag_name(&content[name_start..]);
+            let (attributes, end, self_closing) = read_attributes(content, name_start + name.len());
+            let name = last_segment(name).to_string();
+            tags.push(ComponentTag {
+                name: name.clone(),
+                line: lines.line_of(name_start),
+                offset: name_start,
+                attributes: attributes
+                    .into_iter()
+                    .map(|(name, offset)| TagAttribute {
+                        name,
+                        line: lines.line_of(offset),
+                        offset,
+                    })
+                    .collect(),
+                parent: open.last().cloned(),
+            });
+            if !self_closing {
+                open.push(name);
+            }
+            i = end;
+        } else {
+            i += 1;
+        }
+    }
+    tags
+}
+
+/// `<` followed by an uppercase letter, in markup position: after a non-word
+/// character, so `List<User>` and `Func<Task>` in inline C# are not tags.
+fn starts_component(content: &str, at: usize) -> bool {
+    let after = content.as_bytes().get(at + 1).copied();
+    let before = content[..at].chars().next_back();
+    after.is_some_and(|c| c.is_ascii_uppercase())
+        && !before.is_some_and(|c| c.is_alphanumeric() || c == '_' || c == '.')
+}
+
+fn tag_name(text: &str) -> &str {
+    let len = text
+        .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_' || c == '.'))
+        .unwrap_or(text.len());
+    &text[..len]
+}
+
+fn last_segment(name: &str) -> &str {
+    name.rsplit('.').next().unwrap_or(name)
+}
+
+/// Offset of the `{` opening an `@code` / `@functions` block.
+fn code_block_start(rest: &str) -> Option<usize> {
+    let after = rest
+        .strip_prefix("@code")
+        .or_else(|| rest.strip_prefix("@functions"))?;
+    let trimmed = after.trim_start();
+    trimmed.starts_with('{').then(|| rest.len() - trimmed.len())
+}
+
+/// Closing tag to skip to when `rest` opens a `<script>` or `<style>` body.
+fn raw_text_close(rest: &str) -> Option<&'static str> {
+    let (open, close) = [("<script", "</script>"), ("<style", "</style>")]
+        .into_iter()
+        .find(|(open, _)| rest.starts_with(open))?;
+    let next = rest.as_bytes().get(open.len());
+    (!next.is_some_and(u8::is_ascii_alphanumeric)).then_some(close)
+}
+
+fn find_ignore_case(content: &str, from: usize, needle: &str) -> Option<usize> {
+    content.as_bytes()[from..]
+        .windows(needle.len())
+        .position(|window| window.eq_ignore_ascii_case(needle.as_bytes()))
+        .map(|at| from + at)
+}
+
+/// Index just past the `}` matching the `{` at `open`, skipping C# string and
+/// char literals.
+fn skip_braces(bytes: &[u8], open: usize) -> usize {
+    let mut depth = 0usize;
+    let mut i = open;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'{' => depth += 1,
+            b'}' => {
+                depth -= 1;
+                if depth == 0 {
+                    return i + 1;
+                }
+            }
+            quote @ (b'"' | b'\'') => {
+                i += 1;
+                while i < bytes.len() && bytes[i] != quote && bytes[i] != b'\n' {
+                    i += if bytes[i] == b'\\' { 2 } else { 1 };
+                }
+            }
+            b'/' if bytes.get(i + 1) == Some(&b'/') => {
+                while i < bytes.len() && bytes[i] != b'\n' {
+                    i += 1;
+                }
+            }
+            _ => {}
+        }
+        i += 1;
+    }
+    bytes.len()
+}
+
+/// Read a tag's attributes starting after its name. Returns the parameter
+/// names with their offsets, the index past the tag, and whether it is
+/// self-closing. Directive attributes (`@ref`, `@onclick`, `@rendermode`) set
+/// no parameter and are skipped; `@bind-X` sets `X`.
+fn read_attributes(content: &str, start: usize) -> (Vec<(String, usize)>, usize, bool) {
+    let bytes = content.as_bytes();
+    let mut attributes = Vec::new();
+    let mut i = start;
+    loop {
+        while bytes.get(i).is_some_and(u8::is_ascii_whitespace) {
+            i += 1;
+        }
+        match bytes.get(i) {
+            None => return (attributes, i, false),
+            Some(b'>') => return (attributes, i + 1, false),
+            Some(b'/') if bytes.get(i + 1) == Some(&b'>') => return (attributes, i + 2, true),
+            _ => {}
+        }
+
+        let name_start = i;
+        while bytes.get(i).is_some_and(|c| {
+            !c.is_ascii_whitespace()
+                && !matches!(c, b'=' | b'>')
+                && !(*c == b'/' && bytes.get(i + 1) == Some(&b'>'))
+        }) {
+            i += 1;
+        }
+        if i == name_start {
+            i += 1;
+            continue;
+        }
+        let name = &content[name_start..i];
+        let parameter = match name.strip_prefix("@bind-") {
+            Some(bound) => Some((bound.split(':').next().unwrap_or(bound), name_start + 6)),
+            None if name.starts_with('@') => None,
+            None => Some((name, name_start)),
+        };
+        if let Some((parameter, offset)) = parameter
+            && parameter.starts_with(|c: char| c.is_ascii_alphabetic())
+            && parameter
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '_')
+        {
+            attributes.push((parameter.to_string(), offset));
+        }
+
+        while bytes.get(i).is_some_and(u8::is_ascii_whitespace) {
+            i += 1;
+        }
+        if bytes.get(i) != Some(&b'=') {
+            continue;
+        }
+        i += 1;
+        while bytes.get(i).is_some_and(u8::is_ascii_whitespace) {
+            i += 1;
+        }
+        i = skip_attribute_value(bytes, i);
+    }
+}
+
+fn skip_attribute_value(bytes: &[u8], mut i: usize) -> usize {
+    match bytes.get(i) {
+        Some(&quote @ (b'"' | b'\'')) => {
+            i += 1;
+            while i < bytes.len() && bytes[i] != quote {
+                i += 1;
+            }
+            i + 1
+        }
+        Some(b'@') if bytes.get(i + 1) == Some(&b'(') => {
+            let mut depth = 0usize;
+            i += 1;
+            while i < bytes.len() {
+                match bytes[i] {
+                    b'(' => depth += 1,
+                    b')' => {
+                        depth -= 1;
+                        if depth == 0 {
+                            return i + 1;
+                        }
+                    }
+                    _ => {}
+                }
+                i += 1;
+            }
+            i
+        }
+        _ => {
+            while i < bytes.len()
+                && !bytes[i].is_ascii_whitespace()
+                && bytes[i] != b'>'
+                && !(bytes[i] == b'/' && bytes.get(i + 1) == Some(&b'>'))
+            {
+                i += 1;
+            }
+            i
+        }
+    }
+}
+
+/// Record the component tags in a Razor file as pending relationships from
+/// the innermost symbol around each tag. Other languages are left untouched.
+pub fn link_component_usages(
+    results: &mut ExtractionResults,
+    file_path: &str,
+    language: &str,
+    content: &str,
+) {
+    if language != "razor" || results.symbols.is_empty() {
+        return;
+    }
+    for tag in find_component_tags(content) {
+        let Some(from) = innermost_symbol(&results.symbols, tag.line, tag.offset) else {
+            continue;
+        };
+        let from = from.id.clone();
+        let pending =
+            |callee_name: String, kind: RelationshipKind, line_number: u32| PendingRelationship {
+                from_symbol_id: from.clone(),
+                callee_name,
+                kind,
+                file_path: file_path.to_string(),
+                line_number,
+                confidence: 0.9,
+            };
+        let mut found = vec![pending(
+            format!("{COMPONENT_CALLEE_PREFIX}{}", tag.name),
+            RelationshipKind::Uses,
+            tag.line,
+        )];
+        if let Some(parent) = &tag.parent {
+            found.push(pending(
+                format!("{PARAMETER_CALLEE_PREFIX}{parent}.{}", tag.name),
+                RelationshipKind::References,
+                tag.line,
+            ));
+        }
+        for attribute in &tag.attributes {
+            found.push(pending(
+                format!("{PARAMETER_CALLEE_PREFIX}{}.{}", tag.name, attribute.name),
+                RelationshipKind::References,
+                attribute.line,
+            ));
+        }
+        results.pending_relationships.extend(found);
+    }
+}
+
+/// Is this pending relationship a component or parameter usage recorded by
+/// this pass?
+pub fn is_component_usage(pending: &PendingRelationship) -> bool {
+    pending.callee_name.starts_with(COMPONENT_CALLEE_PREFIX)
+        || pending.callee_name.starts_with(PARAMETER_CALLEE_PREFIX)
+}
+
+/// Resolve component and parameter usages against the project's components.
+pub fn resolve_component_usages(
+    pendings: &[PendingRelationship],
+    db: &SymbolDatabase,
+) -> Vec<Relationship> {
+    // (pending, component, parameter)
+    let usages: Vec<(&PendingRelationship, &str, Option<&str>)> = pendings
+        .iter()
+        .filter_map(|pending| {
+            if let Some(component) = pending.callee_name.strip_prefix(COMPONENT_CALLEE_PREFIX) {
+                Some((pending, component, None))
+            } else {
+                let target = pending.callee_name.strip_prefix(PARAMETER_CALLEE_PREFIX)?;
+                let (component, parameter) = target.split_once('.')?;
+                Some((pending, component, Some(parameter)))
+            }
+        })
+        .collect();
+    if usages.is_empty() {
+        return Vec::new();
+    }
+
+    let names: Vec<String> = usages
+        .iter()
+        .map(|(_, component, _)| component.to_string())
+        .collect::<HashSet<_>>()
+        .into_iter()
+        .collect();
+    let candidates = match db.find_symbols_by_names_batch(&names) {
+        Ok(candidates) => candidates,
+        Err(e) => {
+            warn!("Razor component lookup failed: {}", e);
+            return Vec::new();
+        }
+    };
+    let definitions: HashMap<&str, Vec<&Symbol>> = candidates
+        .iter()
+        .map(|(name, symbols)| (name.as_str(), component_definitions(name, symbols)))
+        .collect();
+
+    let mut parameters: HashMap<String, Vec<Symbol>> = HashMap::new();
+    let mut resolved = Vec::new();
+    for (pending, component, parameter) in usages {
+        let Some(definitions) = definitions.get(component) else {
+            continue;
+        };
+        for definition in nearest(definitions, &pending.file_path) {
+            match parameter {
+                None => resolved.push(crate::resolver::build_resolved_relationship(
+                    pending, definition,
+                )),
+                Some(parameter) => {
+                    let properties = parameters
+                        .entry(definition.id.clone())
+                        .or_insert_with(|| component_parameters(db, definition));
+                    resolved.extend(
+                        properties
+                            .iter()
+                            .filter(|property| property.name.eq_ignore_ascii_case(parameter))
+                            .map(|property| {
+                                crate::resolver::build_resolved_relationship(pending, property)
+                            }),
+                    );
+                }
+            }
+        }
+    }
+    debug!(
+        "Linked {} Razor component and parameter usages",
+        resolved.len()
+    );
+    resolved
+}
+
+/// Components named `name`: the class of a `NAME.razor` file, or failing
+/// that, C# classes of that name.
+fn component_definitions<'a>(name: &str, symbols: &'a [Symbol]) -> Vec<&'a Symbol> {
+    let file_name = format!("{name}.razor");
+    let razor: Vec<&Symbol> = symbols
+        .iter()
+        .filter(|symbol| symbol.kind == SymbolKind::Class && symbol.language == "razor")
+        .filter(|symbol| symbol.file_path.rsplit('/').next() == Some(file_name.as_str()))
+        .collect();
+    if !razor.is_empty() {
+        return razor;
+    }
+    symbols
+        .iter()
+        .filter(|symbol| symbol.kind == SymbolKind::Class && symbol.language == "csharp")
+        .collect()
+}
+
+/// The definitions sharing the most leading directories with `file_path`.
+fn nearest<'a>(definitions: &[&'a Symbol], file_path: &str) -> Vec<&'a Symbol> {
+    let shared = |symbol: &Symbol| {
+        symbol
+            .file_path
+            .split('/')
+            .zip(file_path.split('/'))
+            .take_while(|(a, b)| a == b)
+            .count()
+    };
+    let best = definitions.iter().map(|symbol| shared(symbol)).max();
+    definitions
+        .iter()
+        .copied()
+        .filter(|symbol| Some(shared(symbol)) == best)
+        .collect()
+}
+
+/// Properties and fields a component can take as parameters: the members of
+/// its class and, for a `.razor` component, everything in its `@code` block
+/// and `.razor.cs` code-behind.
+fn component_parameters(db: &SymbolDatabase, component: &Symbol) -> Vec<Symbol> {
+    let mut members = match db.get_children_by_parent_id(&component.id) {
+        Ok(children) => children,
+        Err(e) => {
+            warn!("Failed to load members of {}: {}", component.name, e);
+            Vec::new()
+        }
+    };
+    if component.language == "razor" {
+        let code_behind = format!("{}.cs", component.file_path);
+        for file in [component.file_path.as_str(), code_behind.as_str()] {
+            match db.get_symbols_for_file(file) {
+                Ok(symbols) => members.extend(symbols),
+                Err(e) => warn!("Failed to load symbols for {}: {}", file, e),
+            }
+        }
+    }
+    let mut seen = HashSet::new();
+    members.retain(|symbol| {
+        matches!(symbol.kind, SymbolKind::Property | SymbolKind::Field)
+            && seen.insert(symbol.id.clone())
+    });
+    members
+}
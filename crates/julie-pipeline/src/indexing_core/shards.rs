@@ -0,0 +1,335 @@
+//! Sharded extraction for distributed indexing.
+//!
+//! A monorepo too large for one process is split into `N` disjoint shards by a
+//! stable hash of each file's workspace-relative path, so every worker — on
+//! any machine, with any directory-walk order — agrees on which files it owns.
+//! Workers extract their shard and stream the results as JSON lines:
+//!
+//! ```text
+//! {"type":"header","version":1,"shard":{"index":2,"count":8},...}
+//! {"type":"chunk","batch":{...},"records":[...]}     (repeated)
+//! {"type":"done","files_processed":1234,"symbols_extracted":56789}
+//! ```
+//!
+//! The coordinator reads each stream chunk by chunk with a
+//! [`ShardStreamReader`] (which refuses truncated streams, version or engine
+//! mismatches, and files outside the shard) and persists every chunk as it
+//! arrives. A [`ShardMerger`] keeps only the bookkeeping: it refuses
+//! mismatched shard counts, duplicate shards, files claimed twice, and missing
+//! shards.
+
+use std::collections::HashSet;
+use std::fmt;
+use std::io::{BufRead, Lines, Write};
+use std::iter::Enumerate;
+use std::str::FromStr;
+
+use anyhow::{Context, Result, anyhow, bail};
+use serde::{Deserialize, Serialize};
+
+use crate::indexing_core::batch::ExtractedBatch;
+use crate::indexing_core::extraction::ExtractedFileRecord;
+
+/// Wire format version. Bump on any incompatible change to [`ShardStreamRecord`].
+pub const SHARD_STREAM_VERSION: u32 = 1;
+
+/// Files per streamed chunk. Bounds worker memory and keeps lines reasonable.
+pub const DEFAULT_SHARD_CHUNK_FILES: usize = 512;
+
+/// One shard of `count`, zero-based. Parsed from and displayed as `index/count`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct ShardSpec {
+    pub index: u32,
+    pub count: u32,
+}
+
+impl ShardSpec {
+    pub fn new(index: u32, count: u32) -> Result<Self> {
+        if count == 0 {
+            bail!("shard count must be at least 1");
+        }
+        if index >= count {
+            bail!("shard index {index} is out of range for {count} shards (expected 0..{count})");
+        }
+        Ok(Self { index, count })
+    }
+
+    /// Every shard of a `count`-way split, in index order.
+    pub fn all(count: u32) -> Result<Vec<Self>> {
+        (0..count.max(1))
+            .map(|index| Self::new(index, count))
+            .collect()
+    }
+
+    /// Whether this shard owns `relative_path` (a stored, workspace-relative
+    /// Unix-style path). FNV-1a keeps the assignment identical across
+    /// platforms and Rust versions, unlike `DefaultHasher`.
+    pub fn contains(&self, relative_path: &str) -> bool {
+        let mut hash: u64 = 0xcbf29ce484222325;
+        for byte in relative_path.as_bytes() {
+            hash ^= u64::from(*byte);
+            hash = hash.wrapping_mul(0x100000001b3);
+        }
+        hash % u64::from(self.count) == u64::from(self.index)
+    }
+}
+
+impl fmt::Display for ShardSpec {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}/{}", self.index, self.count)
+    }
+}
+
+impl FromStr for ShardSpec {
+    type Err = anyhow::Error;
+
+    fn from_str(value: &str) -> Result<Self> {
+        let (index, count) = value
+            .split_once('/')
+            .with_context(|| format!("invalid shard '{value}': expected INDEX/COUNT, e.g. 0/4"))?;
+        let index = index
+            .trim()
+            .parse()
+            .with_context(|| format!("invalid shard index in '{value}'"))?;
+        let count = count
+            .trim()
+            .parse()
+            .with_context(|| format!("invalid shard count in '{value}'"))?;
+        Self::new(index, count)
+    }
+}
+
+/// One line of a shard stream.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ShardStreamRecord {
+    Header {
+        version: u32,
+        shard: ShardSpec,
+        /// Extractor contract of the worker binary. Mixing contracts would
+        /// merge incompatible extraction shapes into one index.
+        engine_version: String,
+        files_total: usize,
+    },
+    Chunk {
+        batch: ExtractedBatch,
+        records: Vec<ExtractedFileRecord>,
+    },
+    Done {
+        files_processed: usize,
+        symbols_extracted: usize,
+    },
+}
+
+/// Worker-side writer: one JSON document per line, flushed per record so a
+/// coordinator reading over a pipe sees progress as it happens.
+pub struct ShardStreamWriter<W: Write> {
+    out: W,
+    shard: ShardSpec,
+    files_processed: usize,
+    symbols_extracted: usize,
+}
+
+impl<W: Write> ShardStreamWriter<W> {
+    pub fn start(
+        mut out: W,
+        shard: ShardSpec,
+        engine_version: &str,
+        files_total: usize,
+    ) -> Result<Self> {
+        write_record(
+            &mut out,
+            &ShardStreamRecord::Header {
+                version: SHARD_STREAM_VERSION,
+                shard,
+                engine_version: engine_version.to_string(),
+                files_total,
+            },
+        )?;
+        Ok(Self {
+            out,
+            shard,
+            files_processed: 0,
+            symbols_extracted: 0,
+        })
+    }
+
+    pub fn shard(&self) -> ShardSpec {
+        self.shard
+    }
+
+    pub fn write_chunk(
+        &mut self,
+        batch: ExtractedBatch,
+        records: Vec<ExtractedFileRecord>,
+    ) -> Result<()> {
+        self.files_processed += batch.files_processed;
+        self.symbols_extracted += batch.all_symbols.len();
+        write_record(&mut self.out, &ShardStreamRecord::Chunk { batch, records })
+    }
+
+    /// Write the trailer. A stream without it is treated as truncated.
+    pub fn finish(mut self) -> Result<(usize, usize)> {
+        write_record(
+            &mut self.out,
+            &ShardStreamRecord::Done {
+                files_processed: self.files_processed,
+                symbols_extracted: self.symbols_extracted,
+            },
+        )?;
+        Ok((self.files_processed, self.symbols_extracted))
+    }
+}
+
+fn write_record<W: Write>(out: &mut W, record: &ShardStreamRecord) -> Result<()> {
+    serde_json::to_writer(&mut *out, record).context("failed to serialize shard record")?;
+    out.write_all(b"\n")?;
+    out.flush()?;
+    Ok(())
+}
+
+/// One shard stream, read a chunk at a time so the coordinator never holds a
+/// whole shard.
+pub struct ShardStreamReader<R: BufRead> {
+    lines: Enumerate<Lines<R>>,
+    shard: ShardSpec,
+    source: String,
+}
+
+impl<R: BufRead> ShardStreamReader<R> {
+    /// Read and check the stream header. `engine_version` is the reader's own
+    /// extractor contract; the worker must match it. `source` names the
+    /// stream in error messages.
+    pub fn open(reader: R, engine_version: &str, source: &str) -> Result<Self> {
+        let mut lines = reader.lines().enumerate();
+        let shard = match next_record(&mut lines, source)? {
+            Some(ShardStreamRecord::Header {
+                version,
+                shard,
+                engine_version: worker_engine,
+                ..
+            }) => {
+                if version != SHARD_STREAM_VERSION {
+                    bail!(
+                        "{source}: shard stream version {version} is not supported (expected {SHARD_STREAM_VERSION})"
+                    );
+                }
+                if worker_engine != engine_version {
+                    bail!(
+                        "{source}: worker engine '{worker_engine}' does not match coordinator engine '{engine_version}'"
+                    );
+                }
+                shard
+            }
+            Some(_) => bail!("{source}: shard stream must start with a header"),
+            None => bail!("{source}: shard stream is empty"),
+        };
+        Ok(Self {
+            lines,
+            shard,
+            source: source.to_string(),
+        })
+    }
+
+    pub fn shard(&self) -> ShardSpec {
+        self.shard
+    }
+
+    /// The next chunk, or `None` once the done record is read. A stream that
+    /// ends without one is truncated and fails.
+    pub fn next_chunk(&mut self) -> Result<Option<(ExtractedBatch, Vec<ExtractedFileRecord>)>> {
+        let (shard, source) = (self.shard, self.source.as_str());
+        match next_record(&mut self.lines, source)? {
+            Some(ShardStreamRecord::Chunk { batch, records }) => {
+                if let Some(path) = batch.files_to_clean.iter().find(|p| !shard.contains(p)) {
+                    bail!(
+                        "{source}: shard {shard} returned {path}, which belongs to another shard"
+                    );
+                }
+                Ok(Some((batch, records)))
+            }
+            Some(ShardStreamRecord::Done { .. }) => Ok(None),
+            Some(ShardStreamRecord::Header { .. }) => {
+                bail!("{source}: unexpected second header in shard stream")
+            }
+            None => bail!("{source}: shard {shard} stream ended without a done record"),
+        }
+    }
+}
+
+/// Coordinator-side bookkeeping for every shard of one distributed run. The
+/// chunks themselves are persisted as they arrive; the merger only checks
+/// that together they cover the split exactly once.
+#[derive(Default)]
+pub struct ShardMerger {
+    count: Option<u32>,
+    seen_shards: HashSet<u32>,
+    seen_files: HashSet<String>,
+}
+
+impl ShardMerger {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Start merging one shard. Rejects shards from a different split and
+    /// shards merged twice.
+    pub fn add_shard(&mut self, shard: ShardSpec, source: &str) -> Result<()> {
+        match self.count {
+            Some(count) if count != shard.count => bail!(
+                "{source}: shard {shard} disagrees with the {count}-way split of earlier shards"
+            ),
+            _ => self.count = Some(shard.count),
+        }
+        if !self.seen_shards.insert(shard.index) {
+            bail!("{source}: shard {shard} was already merged");
+        }
+        Ok(())
+    }
+
+    /// Claim the files of one chunk. Rejects files already claimed by another
+    /// shard.
+    pub fn claim_files(&mut self, batch: &ExtractedBatch, source: &str) -> Result<()> {
+        for path in &batch.files_to_clean {
+            if self.seen_files.contains(path) {
+                bail!("{source}: {path} was extracted by more than one shard");
+            }
+        }
+        self.seen_files.extend(batch.files_to_clean.iter().cloned());
+        Ok(())
+    }
+
+    /// Finish the merge. Fails unless every shard of the split was added.
+    pub fn finish(&self) -> Result<()> {
+        let count = self
+            .count
+            .ok_or_else(|| anyhow!("no shard streams were merged"))?;
+        let missing: Vec<String> = (0..count)
+            .filter(|index| !self.seen_shards.contains(index))
+            .map(|index| index.to_string())
+            .collect();
+        if !missing.is_empty() {
+            bail!(
+                "incomplete distributed extraction: missing shard(s) {} of {count}",
+                missing.join(", ")
+            );
+        }
+        Ok(())
+    }
+}
+
+fn next_record<I>(lines: &mut I, source: &str) -> Result<Option<ShardStreamRecord>>
+where
+    I: Iterator<Item = (usize, std::io::Result<String>)>,
+{
+    for (line_no, line) in lines.by_ref() {
+        let line = line.with_context(|| format!("{source}: failed to read shard stream"))?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        let record = serde_json::from_str(&line)
+            .with_context(|| format!("{source}:{}: malformed shard record", line_no + 1))?;
+        return Ok(Some(record));
+    }
+    Ok(None)
+}
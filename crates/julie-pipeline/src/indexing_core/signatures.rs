@@ -0,0 +1,121 @@
+//! Signature normalization and length capping.
+//!
+//! Extractors take a symbol's signature from its declaration text, which for
+//! minified bundles and generated code can be a single line of several
+//! kilobytes: an object literal, a default argument holding a whole table, a
+//! parameter list of hundreds of fields. Stored as-is it bloats the symbols
+//! table and the search index, and drowns every result that shows it.
+//!
+//! Every stored signature is therefore normalized (trailing whitespace and
+//! blank lines dropped) and capped at [`MAX_SIGNATURE_CHARS`]. A capped
+//! signature ends in [`TRUNCATION_MARKER`] and its symbol records the full
+//! length under [`SIGNATURE_METADATA_KEY`]; the full text is still in the
+//! file, and [`recover_signature`] reads it back from the symbol's span
+//! (`fast_signature` does so).
+
+use julie_extractors::base::Symbol;
+use serde_json::json;
+
+/// Longest stored signature, in characters.
+pub const MAX_SIGNATURE_CHARS: usize = 400;
+
+/// How far back from the cap a cut looks for a token boundary, so a
+/// signature is not cut mid-identifier.
+const BOUNDARY_LOOKBACK_CHARS: usize = 80;
+
+/// Appended to a capped signature.
+pub const TRUNCATION_MARKER: &str = " …";
+
+/// Metadata key holding `{truncated: true, chars}` on a symbol whose
+/// signature was capped; `chars` is the length of the normalized original.
+pub const SIGNATURE_METADATA_KEY: &str = "signature";
+
+/// Normalize the signature of every symbol and cap the long ones. Returns
+/// how many were capped.
+pub fn normalize_signatures(symbols: &mut [Symbol]) -> usize {
+    let mut capped = 0;
+    for symbol in symbols.iter_mut() {
+        let Some(signature) = symbol.signature.take() else {
+            continue;
+        };
+        let normalized = normalize_signature(&signature);
+        if normalized.is_empty() {
+            symbol.signature = Some(signature);
+            continue;
+        }
+        let chars = normalized.chars().count();
+        if chars <= MAX_SIGNATURE_CHARS {
+            symbol.signature = Some(normalized);
+            continue;
+        }
+        symbol.signature = Some(cap_signature(&normalized));
+        symbol.metadata.get_or_insert_with(Default::default).insert(
+            SIGNATURE_METADATA_KEY.to_string(),
+            json!({ "truncated": true, "chars": chars }),
+        );
+        capped += 1;
+    }
+    capped
+}
+
+/// Trim the signature, drop trailing whitespace on each line and blank
+/// lines between them. Indentation inside the signature is kept.
+pub fn normalize_signature(signature: &str) -> String {
+    signature
+        .trim()
+        .lines()
+        .map(str::trim_end)
+        .filter(|line| !line.is_empty())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// The first [`MAX_SIGNATURE_CHARS`] characters of `signature`, cut back to
+/// the last whitespace or separator when one is close, plus the marker.
+fn cap_signature(signature: &str) -> String {
+    let cut = signature
+        .char_indices()
+        .nth(MAX_SIGNATURE_CHARS)
+        .map_or(signature.len(), |(index, _)| index);
+    let head = &signature[..cut];
+    let floor = head
+        .char_indices()
+        .rev()
+        .nth(BOUNDARY_LOOKBACK_CHARS)
+        .map_or(0, |(index, _)| index);
+    let head = head[floor..]
+        .rfind(|ch: char| ch.is_whitespace() || matches!(ch, ',' | ';' | '(' | '{' | '['))
+        .map_or(head, |offset| {
+            let boundary = floor + offset;
+            // Keep an opening bracket or separator, drop whitespace.
+            let end = boundary + head[boundary..].chars().next().map_or(0, char::len_utf8);
+            &head[..end]
+        });
+    format!("{}{TRUNCATION_MARKER}", head.trim_end())
+}
+
+/// Whether the signature of `symbol` was capped at indexing time.
+pub fn is_signature_truncated(symbol: &Symbol) -> bool {
+    symbol
+        .metadata
+        .as_ref()
+        .and_then(|metadata| metadata.get(SIGNATURE_METADATA_KEY))
+        .and_then(|info| info.get("truncated"))
+        .and_then(|truncated| truncated.as_bool())
+        .unwrap_or(false)
+}
+
+/// The full declaration of a symbol whose signature was capped, read back
+/// from `content` (its file): the text from the symbol's start to its body,
+/// or the whole symbol when it has no body. `None` when the span does not
+/// fit the content, e.g. after the file changed.
+pub fn recover_signature(symbol: &Symbol, content: &str) -> Option<String> {
+    let start = symbol.start_byte as usize;
+    let end = symbol
+        .body_span
+        .map(|span| span.start_byte as usize)
+        .filter(|&body| body > start)
+        .unwrap_or(symbol.end_byte as usize);
+    let declaration = normalize_signature(content.get(start..end)?);
+    (!declaration.is_empty()).then_some(declaration)
+}
@@ -0,0 +1,400 @@
+//! Routines of PL/SQL and T-SQL sources.
+//!
+//! The SQL grammar covers portable DDL and DML, not the procedural dialects:
+//! an Oracle package body, or a T-SQL procedure with `@` parameters, `GO`
+//! batches and `BEGIN TRY` blocks, parses into error nodes and its routines
+//! are lost or cut short. This pass tokenizes the file with the shared
+//! [`lexer`](super::lexer) and extracts packages (spec and body), procedures,
+//! functions and triggers, each with `{dialect, kind, ...}` under
+//! [`SQL_METADATA_KEY`]: routines add their parameters and result type,
+//! triggers their timing, events and table.
+//!
+//! The dialect comes from the file extension (`.pks`, `.pkb`, `.prc`, ...
+//! for PL/SQL, `.tsql` for T-SQL), then from `[sql] dialect` in
+//! `.julie/config.toml`, then from the content: `CREATE PACKAGE`, `IS`
+//! headers and `/` terminator lines mean PL/SQL; `GO` batch separators,
+//! `CREATE OR ALTER` and `@` parameters mean T-SQL. A `.sql` file showing
+//! neither (ANSI SQL, PostgreSQL, MySQL) is left to the grammar alone.
+//!
+//! The routines replace whatever the grammar extracted inside their span.
+//! Rows the grammar attached to a replaced symbol are moved to the routine
+//! containing it, so the tables, columns and facts it found keep an owner;
+//! statements outside routines are kept as the grammar extracted them.
+//!
+//! A package spec and its body, and a packaged routine's declaration and
+//! definition, share a [`PARTIAL_GROUP_METADATA_KEY`] key, the lowercased
+//! package and routine name plus the parameter types, so `deep_dive` shows
+//! them as one symbol whether they sit in one file or in a `.pks`/`.pkb`
+//! pair. T-SQL `EXEC` and PL/SQL procedure call statements become `Calls`
+//! relationships, left pending for the cross-file resolver when the target
+//! is not in the file; function calls are linked only to routines of the
+//! same file. A trigger `Uses` the table it is defined on.
+
+mod calls;
+mod dialect;
+mod merge;
+mod plsql;
+mod symbols;
+mod tokenize;
+mod tokens;
+mod tsql;
+
+use dialect::sniff_dialect;
+use merge::merge_routines;
+use tokenize::tokenize;
+
+use std::collections::{HashMap, HashSet};
+use std::path::Path;
+
+use anyhow::{Result, anyhow};
+use julie_core::workspace_settings::WorkspaceSettingsRegistry;
+use julie_extractors::base::Visibility;
+use julie_extractors::{
+    ExtractionResults, Identifier, IdentifierKind, PendingRelationship, Relationship,
+    RelationshipKind, Symbol, SymbolKind,
+};
+use serde_json::{Map, Value, json};
+
+use crate::indexing_core::csharp_partials::PARTIAL_GROUP_METADATA_KEY;
+use crate::indexing_core::lexer::{
+    Comment, Lexer, Token, TokenKind, block_end, doubled_quote_end, join_comments, line_end,
+    punct_len, word_end,
+};
+pub use julie_core::file_policy::{SQL_LANGUAGE, SqlDialect};
+
+/// Metadata key holding the dialect-specific facts of a routine.
+pub const SQL_METADATA_KEY: &str = "sql";
+
+/// The `.sql` dialect configured for the workspace, if any.
+pub fn sql_dialect_for(workspace_root: &Path) -> Option<SqlDialect> {
+    WorkspaceSettingsRegistry::global()
+        .get(workspace_root)
+        .sql_dialect()
+}
+
+/// Whether `file_path` names its dialect by extension. The SQL grammar only
+/// reads `.sql`, so these files are extracted by this pass alone.
+pub fn is_sql_dialect_source(file_path: &str) -> bool {
+    SqlDialect::from_extension(Path::new(file_path)).is_some()
+}
+
+/// Extract a PL/SQL or T-SQL file named by its extension (`.pkb`, `.tsql`).
+pub fn extract_sql_dialect_source(file_path: &str, content: &str) -> Result<ExtractionResults> {
+    let dialect = SqlDialect::from_extension(Path::new(file_path))
+        .ok_or_else(|| anyhow!("{file_path} does not name a SQL dialect"))?;
+    Ok(extract_sql_routines(file_path, content, dialect))
+}
+
+/// The dialect of a SQL file: its extension, else `configured`, else what
+/// its content shows. `None` for SQL without procedural dialect code.
+pub fn detect_sql_dialect(
+    file_path: &str,
+    content: &str,
+    configured: Option<SqlDialect>,
+) -> Option<SqlDialect> {
+    SqlDialect::from_extension(Path::new(file_path))
+        .or(configured)
+        .or_else(|| sniff_dialect(content))
+}
+
+/// Replace the grammar's view of a PL/SQL or T-SQL file's routines with this
+/// pass's. Other languages, and SQL in neither dialect, are left untouched.
+/// Returns how many symbols were added.
+pub fn annotate_sql_routines(
+    results: &mut ExtractionResults,
+    file_path: &str,
+    language: &str,
+    content: &str,
+    configured: Option<SqlDialect>,
+) -> usize {
+    if language != SQL_LANGUAGE {
+        return 0;
+    }
+    let Some(dialect) = detect_sql_dialect(file_path, content, configured) else {
+        return 0;
+    };
+    let routines = extract_sql_routines(file_path, content, dialect);
+    if routines.symbols.is_empty() {
+        return 0;
+    }
+    let added = routines.symbols.len();
+    merge_routines(results, routines);
+    added
+}
+
+/// Packages, procedures, functions and triggers of a file in `dialect`.
+pub fn extract_sql_routines(
+    file_path: &str,
+    content: &str,
+    dialect: SqlDialect,
+) -> ExtractionResults {
+    let (tokens, comments) = tokenize(content);
+    let mut parser = Parser {
+        file_path,
+        content,
+        dialect,
+        tokens: &tokens,
+        comments: &comments,
+        symbols: Vec::new(),
+        ids: HashSet::new(),
+        bodies: Vec::new(),
+        tables: Vec::new(),
+    };
+    let mut pos = 0;
+    while pos < tokens.len() {
+        pos = match parser.parse_create(pos) {
+            Some(last) => last + 1,
+            None => pos + 1,
+        };
+    }
+    parser.into_results()
+}
+
+// ---------------------------------------------------------------------------
+// Vocabulary
+// ---------------------------------------------------------------------------
+
+/// PL/SQL statement keywords, never procedure calls.
+const PLSQL_STATEMENT_WORDS: &[&str] = &[
+    "begin",
+    "case",
+    "close",
+    "commit",
+    "continue",
+    "cursor",
+    "declare",
+    "delete",
+    "else",
+    "elsif",
+    "end",
+    "exception",
+    "execute",
+    "exit",
+    "fetch",
+    "for",
+    "forall",
+    "function",
+    "goto",
+    "if",
+    "insert",
+    "lock",
+    "loop",
+    "merge",
+    "null",
+    "open",
+    "pipe",
+    "pragma",
+    "procedure",
+    "raise",
+    "return",
+    "rollback",
+    "savepoint",
+    "select",
+    "set",
+    "subtype",
+    "type",
+    "update",
+    "when",
+    "while",
+    "with",
+];
+
+/// Oracle-supplied packages; calls into them are not left pending.
+const SUPPLIED_PACKAGE_PREFIXES: &[&str] = &["dbms_", "utl_", "apex_", "owa_", "htp", "htf"];
+
+/// DML and DDL events a trigger can fire on.
+const TRIGGER_EVENTS: &[&str] = &[
+    "insert",
+    "update",
+    "delete",
+    "truncate",
+    "create",
+    "alter",
+    "drop",
+    "rename",
+    "ddl",
+    "grant",
+    "revoke",
+    "logon",
+    "logoff",
+    "startup",
+    "shutdown",
+    "servererror",
+];
+
+/// `BEGIN` words in T-SQL that open no block.
+const NON_BLOCK_BEGINS: &[&str] = &[
+    "tran",
+    "transaction",
+    "distributed",
+    "dialog",
+    "conversation",
+];
+
+/// Clauses that may follow a PL/SQL result type.
+const RESULT_CLAUSES: &[&str] = &[
+    "is",
+    "as",
+    "authid",
+    "deterministic",
+    "pipelined",
+    "parallel_enable",
+    "result_cache",
+    "accessible",
+    "aggregate",
+    "sql_macro",
+    "default",
+];
+
+// ---------------------------------------------------------------------------
+// Parser
+// ---------------------------------------------------------------------------
+
+struct Parameter {
+    name: String,
+    mode: &'static str,
+    type_text: String,
+    default: Option<String>,
+}
+
+struct Header {
+    /// Index of the routine keyword (`PROCEDURE`, `FUNCTION`, `TRIGGER`).
+    keyword: usize,
+    /// Name parts, unquoted: `["hr", "emp_pkg"]`.
+    name: Vec<String>,
+    /// Index just past the header text used as the signature.
+    signature_end: usize,
+    parameters: Vec<Parameter>,
+    returns: Option<String>,
+    extra: Value,
+}
+
+struct Parser<'a, 't> {
+    file_path: &'a str,
+    content: &'a str,
+    dialect: SqlDialect,
+    tokens: &'t [Token<'a>],
+    comments: &'t [Comment<'a>],
+    symbols: Vec<Symbol>,
+    ids: HashSet<String>,
+    /// Token ranges of routine bodies and the symbol owning them, scanned
+    /// for calls once every routine in the file is known.
+    bodies: Vec<(usize, usize, usize)>,
+    /// Triggers and the token naming their table.
+    tables: Vec<(usize, usize)>,
+}
+
+impl<'a, 't> Parser<'a, 't> {
+    /// Parse the `CREATE` statement at `from` if it creates a routine or a
+    /// package; returns the index of its last token.
+    fn parse_create(&mut self, from: usize) -> Option<usize> {
+        let tsql = self.dialect == SqlDialect::TSql;
+        if !(self.word_is(from, "create") || (tsql && self.word_is(from, "alter"))) {
+            return None;
+        }
+        let mut at = from + 1;
+        if self.word_is(at, "or")
+            && (self.word_is(at + 1, "replace") || self.word_is(at + 1, "alter"))
+        {
+            at += 2;
+        }
+        while self.word_is(at, "editionable") || self.word_is(at, "noneditionable") {
+            at += 1;
+        }
+        let keyword = self.word_at(at)?.to_ascii_lowercase();
+        match (self.dialect, keyword.as_str()) {
+            (SqlDialect::PlSql, "package") => self.parse_package(from, at),
+            (SqlDialect::PlSql, "procedure" | "function") => {
+                self.parse_plsql_routine(from, at, None, None)
+            }
+            (SqlDialect::PlSql, "trigger") => self.parse_plsql_trigger(from, at),
+            (SqlDialect::TSql, "proc" | "procedure" | "function" | "trigger") => {
+                self.parse_tsql_routine(from, at)
+            }
+            _ => None,
+        }
+    }
+}
+
+struct SymbolSpec {
+    name: String,
+    kind: SymbolKind,
+    sql_kind: &'static str,
+    signature: String,
+    parent: Option<usize>,
+    visibility: Option<Visibility>,
+    /// Spec/body group shared with the other declaration of the same unit.
+    group: Option<String>,
+    extra: Value,
+}
+
+/// Links the calls in routine bodies to their targets.
+struct Resolver<'p, 'a, 't> {
+    parser: &'p Parser<'a, 't>,
+    routines: &'p HashMap<String, Vec<usize>>,
+    identifiers: Vec<Identifier>,
+    relationships: Vec<Relationship>,
+    relationship_ids: HashSet<String>,
+    pending_relationships: Vec<PendingRelationship>,
+}
+
+// ---------------------------------------------------------------------------
+// Helpers
+// ---------------------------------------------------------------------------
+
+/// `"Name"` and `[Name]` without their quotes.
+fn unquote(text: &str) -> String {
+    let quoted = (text.starts_with('"') && text.ends_with('"'))
+        || (text.starts_with('[') && text.ends_with(']'));
+    if quoted && text.len() >= 2 {
+        text[1..text.len() - 1].to_string()
+    } else {
+        text.to_string()
+    }
+}
+
+/// The schema of a qualified name `schema.name`.
+fn schema_of(name: &[String]) -> Option<&str> {
+    (name.len() > 1).then(|| name[name.len() - 2].as_str())
+}
+
+/// Profile part of a packaged routine's spec/body group: its parameter types
+/// in order, then the result type.
+fn profile_key(parameters: &[Parameter], returns: Option<&str>) -> String {
+    let mut key = parameters
+        .iter()
+        .map(|parameter| parameter.type_text.to_ascii_lowercase())
+        .collect::<Vec<_>>()
+        .join(";");
+    if let Some(returns) = returns {
+        key.push_str(" return ");
+        key.push_str(&returns.to_ascii_lowercase());
+    }
+    key
+}
+
+fn parameters_json(parameters: &[Parameter]) -> Value {
+    let list: Vec<Value> = parameters
+        .iter()
+        .map(|parameter| {
+            let mut entry = Map::new();
+            entry.insert("name".to_string(), json!(parameter.name));
+            entry.insert("mode".to_string(), json!(parameter.mode));
+            entry.insert("type".to_string(), json!(parameter.type_text));
+            if let Some(default) = &parameter.default {
+                entry.insert("default".to_string(), json!(default));
+            }
+            Value::Object(entry)
+        })
+        .collect();
+    if list.is_empty() {
+        Value::Null
+    } else {
+        Value::Array(list)
+    }
+}
+
+/// The facts recorded under [`SQL_METADATA_KEY`].
+fn symbol_info(symbol: &Symbol) -> Option<&Value> {
+    symbol.metadata.as_ref()?.get(SQL_METADATA_KEY)
+}
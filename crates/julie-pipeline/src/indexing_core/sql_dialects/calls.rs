@@ -0,0 +1,209 @@
+//! Calls from routine bodies, and the relationships they make.
+
+use super::*;
+
+impl Resolver<'_, '_, '_> {
+    /// Look for a call at `tokens[index]` inside `owner`'s body; returns the
+    /// index to continue from.
+    pub(super) fn scan(&mut self, owner: usize, index: usize) -> usize {
+        let parser = self.parser;
+        if parser.dialect == SqlDialect::TSql
+            && (parser.word_is(index, "exec") || parser.word_is(index, "execute"))
+        {
+            let mut at = index + 1;
+            if parser.word_at(at).is_some_and(|word| word.starts_with('@'))
+                && parser.punct_at(at + 1, "=")
+            {
+                // `EXEC @status = proc`
+                at += 2;
+            }
+            let dynamic = parser.word_at(at).is_some_and(|word| word.starts_with('@'));
+            if let Some((name, end)) = parser.qualified_name(at).filter(|_| !dynamic) {
+                self.push_call(owner, &name, end - 1, true);
+                return end;
+            }
+            return index + 1;
+        }
+
+        let Some((name, end)) = parser.qualified_name(index) else {
+            return index + 1;
+        };
+        if name[0].starts_with('@') {
+            // A variable.
+            return end;
+        }
+        let previous = index.checked_sub(1);
+        let declares = previous.is_some_and(|previous| {
+            ["procedure", "function", "proc", "trigger", "end"]
+                .iter()
+                .any(|word| parser.word_is(previous, word))
+        });
+        if declares {
+            return end;
+        }
+        let statement = parser.dialect == SqlDialect::PlSql
+            && previous.is_some_and(|previous| {
+                parser.punct_at(previous, ";")
+                    || parser.punct_at(previous, ">>")
+                    || ["begin", "then", "else", "loop"]
+                        .iter()
+                        .any(|word| parser.word_is(previous, word))
+            })
+            && !PLSQL_STATEMENT_WORDS.contains(&name[0].to_ascii_lowercase().as_str());
+        if parser.punct_at(end, "(") {
+            // `items(i) := x;` assigns to a collection element.
+            let assigns = statement
+                && parser
+                    .matching_close(end)
+                    .is_some_and(|close| parser.punct_at(close + 1, ":="));
+            if !assigns {
+                self.push_call(owner, &name, end - 1, statement);
+            }
+        } else if statement && parser.punct_at(end, ";") {
+            self.push_call(owner, &name, end - 1, true);
+        }
+        end
+    }
+
+    /// Record a call of `name` (the token at `token` is its last part).
+    /// Calls that name no routine of this file are kept pending only when
+    /// `explicit`: a procedure call statement or `EXEC`.
+    fn push_call(&mut self, from: usize, name: &[String], token: usize, explicit: bool) {
+        let callee = name
+            .last()
+            .map_or(String::new(), |name| name.to_ascii_lowercase());
+        let qualifier = (name.len() > 1).then(|| name[name.len() - 2].to_ascii_lowercase());
+        // `pkg.proc` must name the package declaring the routine; a schema
+        // prefix matches any standalone routine.
+        let target = self.routines.get(&callee).and_then(|candidates| {
+            candidates.iter().copied().find(|&candidate| {
+                let package =
+                    self.parser.symbols[candidate]
+                        .parent_id
+                        .as_ref()
+                        .and_then(|parent| {
+                            self.parser
+                                .symbols
+                                .iter()
+                                .find(|symbol| &symbol.id == parent)
+                        });
+                match (&qualifier, package) {
+                    (Some(qualifier), Some(package)) => {
+                        package.name.eq_ignore_ascii_case(qualifier)
+                    }
+                    _ => true,
+                }
+            })
+        });
+        if target.is_none() && (!explicit || is_supplied(qualifier.as_deref(), &callee)) {
+            return;
+        }
+        let identifier = self.push_identifier(from, token, IdentifierKind::Call);
+        let line = self.parser.tokens[token].line;
+        let from_symbol_id = self.parser.symbols[from].id.clone();
+        match target {
+            Some(target) => {
+                let to_symbol_id = self.parser.symbols[target].id.clone();
+                self.identifiers[identifier].target_symbol_id = Some(to_symbol_id.clone());
+                self.push_relationship(from_symbol_id, to_symbol_id, RelationshipKind::Calls, line);
+            }
+            None => self.pending_relationships.push(PendingRelationship {
+                from_symbol_id,
+                callee_name: name.last().cloned().unwrap_or_default(),
+                kind: RelationshipKind::Calls,
+                file_path: self.parser.file_path.to_string(),
+                line_number: line,
+                confidence: 0.8,
+            }),
+        }
+    }
+
+    /// A trigger `Uses` its table (named at `token`), left pending for the
+    /// resolver since the table is usually created in another file.
+    pub(super) fn link_trigger_table(&mut self, trigger: usize, token: usize) {
+        let parser = self.parser;
+        let Some(table) = parser.name_at(token) else {
+            return;
+        };
+        self.push_identifier(trigger, token, IdentifierKind::TypeUsage);
+        self.pending_relationships.push(PendingRelationship {
+            from_symbol_id: parser.symbols[trigger].id.clone(),
+            callee_name: table,
+            kind: RelationshipKind::Uses,
+            file_path: parser.file_path.to_string(),
+            line_number: parser.tokens[token].line,
+            confidence: 0.8,
+        });
+    }
+
+    fn push_relationship(
+        &mut self,
+        from_symbol_id: String,
+        to_symbol_id: String,
+        kind: RelationshipKind,
+        line: u32,
+    ) {
+        let id = format!(
+            "{}_{}_{:?}_{}_{}",
+            from_symbol_id, to_symbol_id, kind, self.parser.file_path, line
+        );
+        if self.relationship_ids.insert(id.clone()) {
+            self.relationships.push(Relationship {
+                id,
+                from_symbol_id,
+                to_symbol_id,
+                kind,
+                file_path: self.parser.file_path.to_string(),
+                line_number: line,
+                confidence: 1.0,
+                metadata: None,
+            });
+        }
+    }
+
+    fn push_identifier(
+        &mut self,
+        from: usize,
+        token: usize,
+        kind: IdentifierKind,
+    ) -> usize {
+        let parser = self.parser;
+        let token = &parser.tokens[token];
+        let line_start = parser.content[..token.start]
+            .rfind('\n')
+            .map_or(0, |newline| newline + 1);
+        let id_source = format!("{}:sql:usage:{}", parser.file_path, token.start);
+        self.identifiers.push(Identifier {
+            id: blake3::hash(id_source.as_bytes()).to_hex()[..32].to_string(),
+            name: unquote(token.text),
+            kind,
+            language: SQL_LANGUAGE.to_string(),
+            file_path: parser.file_path.to_string(),
+            start_line: token.line,
+            start_column: token.column,
+            end_line: token.line,
+            end_column: token.column + (token.end - token.start) as u32,
+            start_byte: token.start as u32,
+            end_byte: token.end as u32,
+            containing_symbol_id: Some(parser.symbols[from].id.clone()),
+            target_symbol_id: None,
+            confidence: 1.0,
+            code_context: parser.content[line_start..]
+                .lines()
+                .next()
+                .map(|line| line.trim().to_string()),
+        });
+        self.identifiers.len() - 1
+    }
+}
+
+/// Built-in routines that no file in the workspace defines.
+fn is_supplied(qualifier: Option<&str>, callee: &str) -> bool {
+    qualifier.is_some_and(|qualifier| {
+        qualifier == "sys"
+            || SUPPLIED_PACKAGE_PREFIXES
+                .iter()
+                .any(|prefix| qualifier.starts_with(prefix))
+    }) || callee == "sp_executesql"
+        || callee.starts_with("xp_")
+}
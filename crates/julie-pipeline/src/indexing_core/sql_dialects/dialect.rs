@@ -0,0 +1,86 @@
+//! Guess the dialect of a `.sql` file from its content.
+
+use super::*;
+
+/// Vote on the dialect from batch separators and routine headers. PostgreSQL
+/// dollar quoting and MySQL `DELIMITER` lines rule both dialects out.
+pub(super) fn sniff_dialect(content: &str) -> Option<SqlDialect> {
+    let (tokens, _) = tokenize(content);
+    let word_is = |index: usize, word: &str| {
+        tokens.get(index).is_some_and(|token| {
+            token.kind == TokenKind::Word && token.text.eq_ignore_ascii_case(word)
+        })
+    };
+    let (mut plsql, mut tsql) = (0usize, 0usize);
+    for (index, token) in tokens.iter().enumerate() {
+        match token.kind {
+            TokenKind::Separator if token.text == "/" => plsql += 1,
+            TokenKind::Separator => tsql += 1,
+            // `$$` and `$tag$` open PostgreSQL dollar-quoted bodies.
+            TokenKind::Punct
+                if token.text == "$"
+                    && (content[token.end..].starts_with('$')
+                        || tokens.get(index + 1).is_some_and(|next| {
+                            next.start == token.end && next.text.ends_with('$')
+                        })) =>
+            {
+                return None;
+            }
+            TokenKind::Word if token.column == 0 && word_is(index, "delimiter") => return None,
+            TokenKind::Word if word_is(index, "create") || word_is(index, "alter") => {
+                let mut at = index + 1;
+                if word_is(at, "or") && word_is(at + 1, "alter") {
+                    tsql += 1;
+                    continue;
+                }
+                if word_is(at, "or") && word_is(at + 1, "replace") {
+                    at += 2;
+                }
+                if word_is(at, "editionable") || word_is(at, "noneditionable") {
+                    plsql += 1;
+                    at += 1;
+                }
+                if word_is(at, "package") {
+                    plsql += 1;
+                } else if word_is(at, "proc") {
+                    tsql += 1;
+                } else if ["procedure", "function", "trigger"]
+                    .iter()
+                    .any(|keyword| word_is(at, keyword))
+                {
+                    match header_vote(&tokens, at + 1) {
+                        Some(SqlDialect::PlSql) => plsql += 1,
+                        Some(SqlDialect::TSql) => tsql += 1,
+                        None => {}
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+    match plsql.cmp(&tsql) {
+        std::cmp::Ordering::Greater => Some(SqlDialect::PlSql),
+        std::cmp::Ordering::Less => Some(SqlDialect::TSql),
+        std::cmp::Ordering::Equal => None,
+    }
+}
+
+/// What a routine header starting at `from` says about its dialect: `@`
+/// parameters are T-SQL; an `IS` header or a `RETURN` result clause (where
+/// T-SQL and PostgreSQL write `RETURNS`) is PL/SQL.
+fn header_vote(tokens: &[Token<'_>], from: usize) -> Option<SqlDialect> {
+    for token in tokens.iter().skip(from).take(200) {
+        match token.kind {
+            TokenKind::Word if token.text.starts_with('@') => return Some(SqlDialect::TSql),
+            TokenKind::Word => match token.text.to_ascii_lowercase().as_str() {
+                "is" | "return" => return Some(SqlDialect::PlSql),
+                "as" | "begin" | "returns" | "language" => return None,
+                _ => {}
+            },
+            TokenKind::Separator => return None,
+            TokenKind::Punct if token.text == ";" => return None,
+            _ => {}
+        }
+    }
+    None
+}
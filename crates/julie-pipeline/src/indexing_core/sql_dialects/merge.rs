@@ -0,0 +1,85 @@
+//! Merge the routines with the grammar's results.
+
+use super::*;
+
+/// Add `routines` to `results`, dropping the grammar's symbols that start
+/// inside a routine and moving every row pointing at one to the innermost
+/// routine containing it.
+pub(super) fn merge_routines(results: &mut ExtractionResults, routines: ExtractionResults) {
+    let covering = |start: u32| {
+        routines
+            .symbols
+            .iter()
+            .filter(|routine| routine.start_byte <= start && start < routine.end_byte)
+            .min_by_key(|routine| routine.end_byte - routine.start_byte)
+            .map(|routine| routine.id.clone())
+    };
+    let mut replaced: HashMap<String, String> = HashMap::new();
+    results
+        .symbols
+        .retain(|symbol| match covering(symbol.start_byte) {
+            Some(routine) => {
+                replaced.insert(symbol.id.clone(), routine);
+                false
+            }
+            None => true,
+        });
+
+    if !replaced.is_empty() {
+        let remap = |id: &mut String| {
+            if let Some(routine) = replaced.get(id.as_str()) {
+                *id = routine.clone();
+            }
+        };
+        let remap_optional = |id: &mut Option<String>| {
+            if let Some(id) = id.as_mut() {
+                remap(id);
+            }
+        };
+        for symbol in &mut results.symbols {
+            remap_optional(&mut symbol.parent_id);
+        }
+        for relationship in &mut results.relationships {
+            remap(&mut relationship.from_symbol_id);
+            remap(&mut relationship.to_symbol_id);
+        }
+        results
+            .relationships
+            .retain(|relationship| relationship.from_symbol_id != relationship.to_symbol_id);
+        for pending in &mut results.pending_relationships {
+            remap(&mut pending.from_symbol_id);
+        }
+        for pending in &mut results.structured_pending_relationships {
+            remap(&mut pending.pending.from_symbol_id);
+            remap_optional(&mut pending.caller_scope_symbol_id);
+        }
+        for identifier in &mut results.identifiers {
+            remap_optional(&mut identifier.containing_symbol_id);
+            remap_optional(&mut identifier.target_symbol_id);
+        }
+        for literal in &mut results.literals {
+            remap_optional(&mut literal.containing_symbol_id);
+        }
+        for region in &mut results.source_regions {
+            remap_optional(&mut region.containing_symbol_id);
+        }
+        for fact in &mut results.structural_facts {
+            remap_optional(&mut fact.containing_symbol_id);
+        }
+        // Metrics of a misparsed fragment say nothing about the routine.
+        results.complexity_metrics.retain(|metric| {
+            metric
+                .symbol_id
+                .as_ref()
+                .is_none_or(|id| !replaced.contains_key(id))
+        });
+        results.types.retain(|id, _| !replaced.contains_key(id));
+    }
+
+    results.symbols.extend(routines.symbols);
+    results.relationships.extend(routines.relationships);
+    results
+        .pending_relationships
+        .extend(routines.pending_relationships);
+    results.identifiers.extend(routines.identifiers);
+}
@@ -0,0 +1,317 @@
+//! PL/SQL packages, routines and triggers.
+
+use super::*;
+
+impl<'a, 't> Parser<'a, 't> {
+    /// `PACKAGE [BODY] name ... IS declarations [BEGIN init] END [name];`
+    pub(super) fn parse_package(&mut self, from: usize, keyword: usize) -> Option<usize> {
+        let body = self.word_is(keyword + 1, "body");
+        let name_at = keyword + 1 + usize::from(body);
+        let (name, next) = self.qualified_name(name_at)?;
+        let is = self.find_top_level(next, |parser, index| {
+            parser.word_is(index, "is") || parser.word_is(index, "as")
+        })?;
+        let part = if body { "body" } else { "spec" };
+        let package = name.last()?.to_ascii_lowercase();
+        let index = self.push_symbol(
+            SymbolSpec {
+                name: name.last()?.clone(),
+                kind: SymbolKind::Module,
+                sql_kind: "package",
+                signature: self.text(keyword, next),
+                parent: None,
+                visibility: Some(Visibility::Public),
+                group: Some(format!("plsql:{package}")),
+                extra: json!({ "part": part, "schema": schema_of(&name) }),
+            },
+            from,
+            is,
+        );
+
+        let mut at = self.parse_declarations(is + 1, index, Some(body), false);
+        if self.word_is(at, "begin") {
+            // Package initialization; its `END` closes the package.
+            let begin = at;
+            at = self.skip_block(begin);
+            self.bodies.push((begin, at, index));
+        }
+        let last = self.finish_end(at);
+        self.set_end(index, last);
+        Some(last)
+    }
+
+    /// Declarations up to the `BEGIN` or `END` closing them: packaged and
+    /// local subprograms become symbols, everything else is stepped over.
+    /// `package_body` tells a package's members apart from local ones.
+    /// Compound triggers list timing-point sections here too. Returns the index of the closing
+    /// keyword (or of the last token when the unit is cut short).
+    fn parse_declarations(
+        &mut self,
+        from: usize,
+        parent: usize,
+        package_body: Option<bool>,
+        compound: bool,
+    ) -> usize {
+        let mut at = from;
+        while at < self.tokens.len() {
+            if self.word_is(at, "begin") || self.word_is(at, "end") || self.is_separator(at) {
+                return at;
+            }
+            if self.word_is(at, "create") && at > from && self.punct_at(at - 1, ";") {
+                // The next unit of a file without `/` terminators.
+                return at - 1;
+            }
+            if self.word_is(at, "procedure") || self.word_is(at, "function") {
+                let last = self.parse_plsql_routine(at, at, Some(parent), package_body);
+                at = last.unwrap_or_else(|| self.statement_end(at)) + 1;
+                continue;
+            }
+            let timing = self.word_is(at, "before")
+                || self.word_is(at, "after")
+                || self.word_is(at, "instead");
+            if compound && timing {
+                at = self.skip_timing_point(at, parent) + 1;
+                continue;
+            }
+            at = self.statement_end(at) + 1;
+        }
+        self.tokens.len().saturating_sub(1)
+    }
+
+    /// `BEFORE EACH ROW IS [declarations] BEGIN ... END BEFORE EACH ROW;`
+    fn skip_timing_point(&mut self, from: usize, trigger: usize) -> usize {
+        let Some(is) = self.find_top_level(from, |parser, index| parser.word_is(index, "is"))
+        else {
+            return self.statement_end(from);
+        };
+        let begin = self.parse_declarations(is + 1, trigger, None, false);
+        if !self.word_is(begin, "begin") {
+            return self.statement_end(begin);
+        }
+        let end = self.skip_block(begin);
+        self.bodies.push((begin, end, trigger));
+        self.finish_end(end)
+    }
+
+    /// `PROCEDURE name [(params)] [RETURN type] [clauses] {; | IS ... END;}`
+    pub(super) fn parse_plsql_routine(
+        &mut self,
+        from: usize,
+        keyword: usize,
+        parent: Option<usize>,
+        package_body: Option<bool>,
+    ) -> Option<usize> {
+        let is_function = self.word_is(keyword, "function");
+        let (name, mut next) = self.qualified_name(keyword + 1)?;
+        let mut parameters = Vec::new();
+        if self.punct_at(next, "(") {
+            let close = self.matching_close(next)?;
+            parameters = self.parse_plsql_parameters(next + 1, close);
+            next = close + 1;
+        }
+        let mut returns = None;
+        if is_function && self.word_is(next, "return") {
+            let stop = self.find_top_level(next + 1, |parser, index| {
+                parser.punct_at(index, ";")
+                    || RESULT_CLAUSES
+                        .iter()
+                        .any(|clause| parser.word_is(index, clause))
+            });
+            let stop = stop.unwrap_or(self.tokens.len());
+            returns = Some(self.text(next + 1, stop));
+            next = stop;
+        }
+        let signature_end = next;
+        let stop = self.find_top_level(next, |parser, index| {
+            parser.punct_at(index, ";")
+                || parser.word_is(index, "is")
+                || parser.word_is(index, "as")
+        });
+        let definition = stop.is_some_and(|stop| !self.punct_at(stop, ";"))
+            && !stop.is_some_and(|stop| {
+                self.word_is(stop + 1, "language") || self.word_is(stop + 1, "external")
+            });
+        let header_last = stop.unwrap_or(self.tokens.len().saturating_sub(1));
+
+        let part = match package_body {
+            Some(true) if definition => Some("body"),
+            Some(_) => Some("spec"),
+            None => None,
+        };
+        let group = package_body.is_some().then(|| {
+            let package = parent.map_or(String::new(), |parent| {
+                self.symbols[parent].name.to_ascii_lowercase()
+            });
+            format!(
+                "plsql:{package}.{}({})",
+                name.last()
+                    .map_or(String::new(), |name| name.to_ascii_lowercase()),
+                profile_key(&parameters, returns.as_deref())
+            )
+        });
+        // Body-only package members are private unless the spec (possibly
+        // in another file) declares them; local subprograms always are.
+        let visibility = match (package_body, parent) {
+            (Some(false), _) | (None, None) => Some(Visibility::Public),
+            (Some(true), _) => None,
+            (None, Some(_)) => Some(Visibility::Private),
+        };
+        let header = Header {
+            keyword,
+            name,
+            signature_end,
+            parameters,
+            returns,
+            extra: json!({ "part": part }),
+        };
+        let index = self.push_routine(header, from, header_last, parent, group, visibility)?;
+        if !definition {
+            let last = if self.punct_at(header_last, ";") {
+                header_last
+            } else {
+                self.statement_end(header_last)
+            };
+            self.set_end(index, last);
+            return Some(last);
+        }
+
+        let begin = self.parse_declarations(header_last + 1, index, None, false);
+        let end = if self.word_is(begin, "begin") {
+            self.skip_block(begin)
+        } else {
+            begin
+        };
+        self.bodies.push((header_last + 1, end, index));
+        let last = self.finish_end(end);
+        self.set_end(index, last);
+        Some(last)
+    }
+
+    /// `TRIGGER name {BEFORE|AFTER|INSTEAD OF|FOR} events ON table ...`
+    /// followed by a block, a compound trigger body or a `CALL`.
+    pub(super) fn parse_plsql_trigger(&mut self, from: usize, keyword: usize) -> Option<usize> {
+        let (name, next) = self.qualified_name(keyword + 1)?;
+        let on = self.find_top_level(next, |parser, index| parser.word_is(index, "on"))?;
+        let timing = self.text(next, next + 1 + usize::from(self.word_is(next, "instead")));
+        let events = self.trigger_events(next, on);
+        let (table, target_end) = self.trigger_target(on + 1);
+        let body = self.find_top_level(target_end, |parser, index| {
+            ["declare", "begin", "compound", "call"]
+                .iter()
+                .any(|word| parser.word_is(index, word))
+        })?;
+        let compound = self.word_is(body, "compound");
+        let for_each_row = (target_end..body)
+            .any(|index| self.word_is(index, "each") && self.word_is(index + 1, "row"));
+        let header = Header {
+            keyword,
+            name,
+            signature_end: target_end,
+            parameters: Vec::new(),
+            returns: None,
+            extra: json!({
+                "timing": timing.to_ascii_lowercase(),
+                "events": events,
+                "table": table.as_ref().map(|(name, _)| name),
+                "for_each_row": for_each_row.then_some(true),
+                "compound": compound.then_some(true),
+            }),
+        };
+        let index = self.push_routine(header, from, body, None, None, Some(Visibility::Public))?;
+        if let Some((_, token)) = table {
+            self.tables.push((index, token));
+        }
+
+        let last = if compound {
+            let end = self.parse_declarations(body + 2, index, None, true);
+            self.finish_end(end)
+        } else if self.word_is(body, "call") {
+            let last = self.statement_end(body);
+            self.bodies.push((body, last, index));
+            last
+        } else {
+            let begin = if self.word_is(body, "declare") {
+                self.parse_declarations(body + 1, index, None, false)
+            } else {
+                body
+            };
+            let end = if self.word_is(begin, "begin") {
+                self.skip_block(begin)
+            } else {
+                begin
+            };
+            self.bodies.push((body, end, index));
+            self.finish_end(end)
+        };
+        self.set_end(index, last);
+        Some(last)
+    }
+
+    /// `name [IN] [OUT] [NOCOPY] type [{DEFAULT | :=} value], ...`
+    fn parse_plsql_parameters(&self, from: usize, to: usize) -> Vec<Parameter> {
+        let mut parameters = Vec::new();
+        for (start, stop) in self.split_top_level(from, to, ",") {
+            let Some(name) = self.name_at(start) else {
+                continue;
+            };
+            let mut at = start + 1;
+            let (mut input, mut output) = (false, false);
+            loop {
+                if self.word_is(at, "in") {
+                    input = true;
+                } else if self.word_is(at, "out") {
+                    output = true;
+                } else if !self.word_is(at, "nocopy") {
+                    break;
+                }
+                at += 1;
+            }
+            let default_at = (at..stop)
+                .find(|&index| self.punct_at(index, ":=") || self.word_is(index, "default"));
+            parameters.push(Parameter {
+                name,
+                mode: match (input, output) {
+                    (true, true) => "in out",
+                    (false, true) => "out",
+                    _ => "in",
+                },
+                type_text: self.text(at, default_at.unwrap_or(stop)),
+                default: default_at.map(|default| self.text(default + 1, stop)),
+            });
+        }
+        parameters
+    }
+
+    /// Lowercased events between the timing keyword at `from` and `ON`.
+    pub(super) fn trigger_events(&self, from: usize, on: usize) -> Value {
+        let events: Vec<Value> = (from..on)
+            .filter_map(|index| self.word_at(index))
+            .map(str::to_ascii_lowercase)
+            .filter(|word| TRIGGER_EVENTS.contains(&word.as_str()))
+            .map(Value::String)
+            .collect();
+        Value::Array(events)
+    }
+
+    /// The table a trigger is defined on (and its name token), and the index
+    /// just past the target. `DATABASE`, `SCHEMA` and `ALL SERVER` have no
+    /// table.
+    pub(super) fn trigger_target(&self, from: usize) -> (Option<(String, usize)>, usize) {
+        if self.word_is(from, "database") || self.word_is(from, "schema") {
+            return (None, from + 1);
+        }
+        if self.word_is(from, "all") && self.word_is(from + 1, "server") {
+            return (None, from + 2);
+        }
+        let name_at = if self.word_is(from, "nested") && self.word_is(from + 1, "table") {
+            // `NESTED TABLE column OF view`
+            from + 4
+        } else {
+            from
+        };
+        match self.qualified_name(name_at) {
+            Some((name, end)) => (Some((name.join("."), end - 1)), end),
+            None => (None, from),
+        }
+    }
+}
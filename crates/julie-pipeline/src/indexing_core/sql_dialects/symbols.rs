@@ -0,0 +1,188 @@
+//! Emit routines and collect the results.
+
+use super::*;
+
+impl<'a, 't> Parser<'a, 't> {
+    pub(super) fn into_results(self) -> ExtractionResults {
+        let mut routines: HashMap<String, Vec<usize>> = HashMap::new();
+        for (index, symbol) in self.symbols.iter().enumerate() {
+            let kind = symbol_info(symbol).and_then(|info| info["kind"].as_str());
+            if matches!(kind, Some("procedure" | "function")) {
+                routines
+                    .entry(symbol.name.to_ascii_lowercase())
+                    .or_default()
+                    .push(index);
+            }
+        }
+        // Definitions before forward declarations.
+        for candidates in routines.values_mut() {
+            candidates.sort_by_key(|&index| {
+                symbol_info(&self.symbols[index]).is_some_and(|info| info["part"] == "spec")
+            });
+        }
+
+        // Innermost body owning each token.
+        let mut owners: Vec<Option<usize>> = vec![None; self.tokens.len()];
+        let mut bodies = self.bodies.clone();
+        bodies.sort_by_key(|(first, last, _)| std::cmp::Reverse(last.saturating_sub(*first)));
+        for (first, last, symbol) in bodies {
+            for owner in owners.iter_mut().take(last.saturating_add(1)).skip(first) {
+                *owner = Some(symbol);
+            }
+        }
+
+        let mut resolver = Resolver {
+            parser: &self,
+            routines: &routines,
+            identifiers: Vec::new(),
+            relationships: Vec::new(),
+            relationship_ids: HashSet::new(),
+            pending_relationships: Vec::new(),
+        };
+        let mut index = 0;
+        while index < self.tokens.len() {
+            let Some(owner) = owners[index] else {
+                index += 1;
+                continue;
+            };
+            index = resolver.scan(owner, index);
+        }
+        for &(trigger, token) in &self.tables {
+            resolver.link_trigger_table(trigger, token);
+        }
+
+        let Resolver {
+            identifiers,
+            relationships,
+            pending_relationships,
+            ..
+        } = resolver;
+        let mut results = ExtractionResults::empty();
+        results.symbols = self.symbols;
+        results.identifiers = identifiers;
+        results.relationships = relationships;
+        results.pending_relationships = pending_relationships;
+        results
+    }
+
+    /// Emit the routine declared by `header` at `tokens[from..=last]`.
+    pub(super) fn push_routine(
+        &mut self,
+        header: Header,
+        from: usize,
+        last: usize,
+        parent: Option<usize>,
+        group: Option<String>,
+        visibility: Option<Visibility>,
+    ) -> Option<usize> {
+        let sql_kind = match self.word_at(header.keyword)?.to_ascii_lowercase().as_str() {
+            "function" => "function",
+            "trigger" => "trigger",
+            _ => "procedure",
+        };
+        let mut extra = json!({
+            "schema": schema_of(&header.name),
+            "parameters": parameters_json(&header.parameters),
+            "returns": header.returns,
+        });
+        if let (Value::Object(info), Value::Object(more)) = (&mut extra, header.extra) {
+            info.extend(more);
+        }
+        Some(self.push_symbol(
+            SymbolSpec {
+                name: header.name.last()?.clone(),
+                kind: SymbolKind::Function,
+                sql_kind,
+                signature: self.text(header.keyword, header.signature_end),
+                parent,
+                visibility,
+                group,
+                extra,
+            },
+            from,
+            last,
+        ))
+    }
+
+    /// Extend a symbol's span and code context through `tokens[last]`.
+    pub(super) fn set_end(&mut self, index: usize, last: usize) {
+        let Some(last) = self.tokens.get(last) else {
+            return;
+        };
+        let symbol = &mut self.symbols[index];
+        if (last.end as u32) < symbol.end_byte {
+            return;
+        }
+        symbol.end_line = last.line;
+        symbol.end_column = last.column + (last.end - last.start) as u32;
+        symbol.end_byte = last.end as u32;
+        symbol.code_context = Some(self.content[symbol.start_byte as usize..last.end].to_string());
+    }
+
+    pub(super) fn push_symbol(&mut self, spec: SymbolSpec, first: usize, last: usize) -> usize {
+        let doc = self.doc_before(first);
+        let first = &self.tokens[first];
+        let last = &self.tokens[last.min(self.tokens.len() - 1)];
+        let parent = spec.parent.map(|index| &self.symbols[index]);
+        let parent_id = parent.map(|parent| parent.id.clone());
+        let full_name = match parent {
+            Some(parent) => format!("{}.{}", parent.name, spec.name),
+            None => spec.name.clone(),
+        };
+
+        let mut info = json!({ "dialect": self.dialect.as_str(), "kind": spec.sql_kind });
+        if let (Value::Object(info), Value::Object(extra)) = (&mut info, spec.extra) {
+            info.extend(extra);
+        }
+        if let Value::Object(info) = &mut info {
+            info.retain(|_, value| !value.is_null());
+        }
+
+        // Package specs repeat in their bodies, and overloads share a name;
+        // keep ids unique.
+        let base = format!(
+            "{}:sql:{}:{}",
+            self.file_path,
+            spec.sql_kind,
+            full_name.to_ascii_lowercase()
+        );
+        let mut id_source = base.clone();
+        let mut duplicate = 1;
+        while !self.ids.insert(id_source.clone()) {
+            duplicate += 1;
+            id_source = format!("{base}#{duplicate}");
+        }
+        let id = blake3::hash(id_source.as_bytes()).to_hex()[..32].to_string();
+
+        let mut metadata = HashMap::from([(SQL_METADATA_KEY.to_string(), info)]);
+        if let Some(group) = spec.group {
+            metadata.insert(PARTIAL_GROUP_METADATA_KEY.to_string(), Value::String(group));
+        }
+        self.symbols.push(Symbol {
+            id,
+            name: spec.name,
+            kind: spec.kind,
+            language: SQL_LANGUAGE.to_string(),
+            file_path: self.file_path.to_string(),
+            start_line: first.line,
+            start_column: first.column,
+            end_line: last.line,
+            end_column: last.column + (last.end - last.start) as u32,
+            start_byte: first.start as u32,
+            end_byte: last.end as u32,
+            signature: Some(spec.signature),
+            doc_comment: doc,
+            visibility: spec.visibility,
+            parent_id,
+            metadata: Some(metadata),
+            semantic_group: None,
+            confidence: None,
+            code_context: Some(self.content[first.start..last.end].to_string()),
+            content_type: None,
+            body_span: None,
+            body_hash: None,
+            annotations: Vec::new(),
+        });
+        self.symbols.len() - 1
+    }
+}
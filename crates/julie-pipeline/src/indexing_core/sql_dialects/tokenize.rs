@@ -0,0 +1,122 @@
+//! Tokenizer for both dialects.
+
+use super::*;
+
+/// Punctuation read as a single token.
+const COMPOUND_PUNCT: &[&str] =
+    &["=>", ":=", "||", "<>", "!=", ">=", "<=", "..", "<<", ">>"];
+
+fn is_word_start(c: char) -> bool {
+    c.is_alphabetic() || matches!(c, '_' | '@' | '#')
+}
+
+fn is_word_char(c: char) -> bool {
+    c.is_alphanumeric() || matches!(c, '_' | '@' | '#' | '$')
+}
+
+pub(super) fn tokenize(content: &str) -> (Vec<Token<'_>>, Vec<Comment<'_>>) {
+    let bytes = content.as_bytes();
+    let mut lexer = Lexer::new(content);
+    let mut i = 0;
+
+    while i < bytes.len() {
+        let c = bytes[i];
+        if c.is_ascii_whitespace() {
+            i += 1;
+            continue;
+        }
+
+        let start = i;
+        let rest = &content[i..];
+        let line_end = line_end(content, i);
+        if rest.starts_with("--") || rest.starts_with("/*") {
+            i = if rest.starts_with("--") {
+                line_end
+            } else {
+                block_end(content, i + 2, "*/")
+            };
+            lexer.push_comment(start, start + content[start..i].trim_end().len());
+            continue;
+        }
+
+        let starts_line = content[lexer.line_start_of(i)..i].trim().is_empty();
+        let kind = if starts_line && is_separator_line(content[start..line_end].trim_end()) {
+            i = start + content[start..line_end].trim_end().len();
+            TokenKind::Separator
+        } else if c == b'\'' {
+            i = doubled_quote_end(bytes, i + 1, bytes.len(), b'\'');
+            TokenKind::Str
+        } else if matches!(c, b'n' | b'N' | b'q' | b'Q') && rest[1..].starts_with('\'') {
+            // `N'...'` national strings and Oracle `q'[...]'` quoting.
+            i = if matches!(c, b'q' | b'Q') {
+                q_quote_end(content, i + 2)
+            } else {
+                doubled_quote_end(bytes, i + 2, bytes.len(), b'\'')
+            };
+            TokenKind::Str
+        } else if matches!(c, b'n' | b'N')
+            && matches!(bytes.get(i + 1), Some(b'q' | b'Q'))
+            && bytes.get(i + 2) == Some(&b'\'')
+        {
+            i = q_quote_end(content, i + 3);
+            TokenKind::Str
+        } else if c == b'"' {
+            i = doubled_quote_end(bytes, i + 1, bytes.len(), b'"');
+            TokenKind::Quoted
+        } else if c == b'[' {
+            i = doubled_quote_end(bytes, i + 1, bytes.len(), b']');
+            TokenKind::Quoted
+        } else if c.is_ascii_digit() {
+            i += 1;
+            while i < bytes.len()
+                && (bytes[i].is_ascii_alphanumeric()
+                    || (bytes[i] == b'.' && bytes.get(i + 1).is_some_and(u8::is_ascii_digit)))
+            {
+                i += 1;
+            }
+            TokenKind::Number
+        } else if rest.chars().next().is_some_and(is_word_start) {
+            i = word_end(content, i, is_word_char);
+            TokenKind::Word
+        } else {
+            i += punct_len(rest, COMPOUND_PUNCT);
+            TokenKind::Punct
+        };
+        lexer.push(kind, start, i);
+    }
+    lexer.finish()
+}
+
+/// `/` ends a PL/SQL unit in SQL*Plus scripts; `GO [count]` ends a T-SQL
+/// batch.
+fn is_separator_line(line: &str) -> bool {
+    if line == "/" {
+        return true;
+    }
+    let mut words = line.split_whitespace();
+    words.next().is_some_and(|go| go.eq_ignore_ascii_case("go"))
+        && words
+            .next()
+            .is_none_or(|count| count.bytes().all(|b| b.is_ascii_digit()))
+        && words.next().is_none()
+}
+
+/// Index just past an Oracle `q'<delimiter>...<closing delimiter>'` string
+/// whose delimiter is at `from`.
+fn q_quote_end(content: &str, from: usize) -> usize {
+    let Some(open) = content[from..].chars().next() else {
+        return content.len();
+    };
+    let close = match open {
+        '[' => ']',
+        '{' => '}',
+        '(' => ')',
+        '<' => '>',
+        other => other,
+    };
+    let body = from + open.len_utf8();
+    let terminator = format!("{close}'");
+    content[body..]
+        .find(&terminator)
+        .map_or(content.len(), |end| body + end + terminator.len())
+}
@@ -0,0 +1,241 @@
+//! Blocks and token helpers.
+
+use super::*;
+
+impl<'a, 't> Parser<'a, 't> {
+    // -- blocks -------------------------------------------------------------------
+
+    /// Index of the `END` closing the `BEGIN` at `begin`. `CASE` opens a
+    /// level closed by `END` or `END CASE`; `END IF` and `END LOOP` close
+    /// nothing counted here. A unit cut short ends before the next
+    /// separator or at end of file.
+    pub(super) fn skip_block(&self, begin: usize) -> usize {
+        let mut depth = 0usize;
+        let mut index = begin;
+        while index < self.tokens.len() {
+            if self.is_separator(index) {
+                return index.saturating_sub(1);
+            }
+            let word = self.word_at(index).map(str::to_ascii_lowercase);
+            match word.as_deref() {
+                Some("begin")
+                    if !NON_BLOCK_BEGINS
+                        .iter()
+                        .any(|word| self.word_is(index + 1, word)) =>
+                {
+                    depth += 1;
+                }
+                Some("case") if !(index > 0 && self.word_is(index - 1, "end")) => depth += 1,
+                Some("end") => {
+                    if ["if", "loop", "while", "repeat"]
+                        .iter()
+                        .any(|word| self.word_is(index + 1, word))
+                    {
+                        index += 2;
+                        continue;
+                    }
+                    depth = depth.saturating_sub(1);
+                    if depth == 0 {
+                        return index;
+                    }
+                }
+                _ => {}
+            }
+            index += 1;
+        }
+        self.tokens.len().saturating_sub(1)
+    }
+
+    /// The `;` after `END [name]` at `end`, or `end` itself when the unit
+    /// stops at a separator or end of file first.
+    pub(super) fn finish_end(&self, end: usize) -> usize {
+        if !self.word_is(end, "end") {
+            return end.min(self.tokens.len().saturating_sub(1));
+        }
+        self.statement_end(end)
+    }
+
+    // -- token helpers ----------------------------------------------------------
+
+    pub(super) fn token_kind(&self, index: usize) -> Option<TokenKind> {
+        self.tokens.get(index).map(|token| token.kind)
+    }
+
+    pub(super) fn word_at(&self, index: usize) -> Option<&'a str> {
+        self.tokens
+            .get(index)
+            .filter(|token| token.kind == TokenKind::Word)
+            .map(|token| token.text)
+    }
+
+    pub(super) fn word_is(&self, index: usize, word: &str) -> bool {
+        self.word_at(index)
+            .is_some_and(|text| text.eq_ignore_ascii_case(word))
+    }
+
+    pub(super) fn punct_at(&self, index: usize, punct: &str) -> bool {
+        self.tokens
+            .get(index)
+            .is_some_and(|token| token.kind == TokenKind::Punct && token.text == punct)
+    }
+
+    pub(super) fn is_separator(&self, index: usize) -> bool {
+        self.token_kind(index) == Some(TokenKind::Separator)
+    }
+
+    /// A word or quoted identifier at `index`, unquoted.
+    pub(super) fn name_at(&self, index: usize) -> Option<String> {
+        let token = self.tokens.get(index)?;
+        matches!(token.kind, TokenKind::Word | TokenKind::Quoted).then(|| unquote(token.text))
+    }
+
+    /// A name `a.b.c` starting at `index`: its unquoted parts and the index
+    /// just past it.
+    pub(super) fn qualified_name(&self, index: usize) -> Option<(Vec<String>, usize)> {
+        let mut parts = vec![self.name_at(index)?];
+        let mut end = index + 1;
+        while self.punct_at(end, ".") {
+            let Some(part) = self.name_at(end + 1) else {
+                break;
+            };
+            parts.push(part);
+            end += 2;
+        }
+        Some((parts, end))
+    }
+
+    /// Index of the bracket closing the `(` at `open`.
+    pub(super) fn matching_close(&self, open: usize) -> Option<usize> {
+        let mut depth = 0usize;
+        for (index, token) in self.tokens.iter().enumerate().skip(open) {
+            if token.kind != TokenKind::Punct {
+                continue;
+            }
+            match token.text {
+                "(" => depth += 1,
+                ")" => {
+                    depth = depth.saturating_sub(1);
+                    if depth == 0 {
+                        return Some(index);
+                    }
+                }
+                _ => {}
+            }
+        }
+        None
+    }
+
+    /// First index at or after `from`, outside parentheses, where `stop`
+    /// holds. `None` past a separator or at end of file.
+    pub(super) fn find_top_level(
+        &self,
+        from: usize,
+        stop: impl Fn(&Self, usize) -> bool,
+    ) -> Option<usize> {
+        let mut depth = 0usize;
+        for index in from..self.tokens.len() {
+            let token = &self.tokens[index];
+            match (token.kind, token.text) {
+                (TokenKind::Separator, _) => return None,
+                (TokenKind::Punct, "(") => depth += 1,
+                (TokenKind::Punct, ")") => depth = depth.saturating_sub(1),
+                _ if depth == 0 && stop(self, index) => return Some(index),
+                _ => {}
+            }
+        }
+        None
+    }
+
+    /// Index of the `;` ending the statement at `from`, outside parentheses;
+    /// the token before a separator, or the last token, when there is none.
+    pub(super) fn statement_end(&self, from: usize) -> usize {
+        let mut depth = 0usize;
+        for (index, token) in self.tokens.iter().enumerate().skip(from) {
+            match (token.kind, token.text) {
+                (TokenKind::Separator, _) => return index.saturating_sub(1).max(from),
+                (TokenKind::Punct, "(") => depth += 1,
+                (TokenKind::Punct, ")") => depth = depth.saturating_sub(1),
+                (TokenKind::Punct, ";") if depth == 0 => return index,
+                _ => {}
+            }
+        }
+        self.tokens.len().saturating_sub(1)
+    }
+
+    /// Split `tokens[from..to]` at `separator` outside parentheses.
+    pub(super) fn split_top_level(
+        &self,
+        from: usize,
+        to: usize,
+        separator: &str,
+    ) -> Vec<(usize, usize)> {
+        let to = to.min(self.tokens.len());
+        let mut segments = Vec::new();
+        let mut depth = 0usize;
+        let mut segment_start = from;
+        for index in from..to {
+            let token = &self.tokens[index];
+            if token.kind != TokenKind::Punct {
+                continue;
+            }
+            match token.text {
+                "(" => depth += 1,
+                ")" => depth = depth.saturating_sub(1),
+                text if text == separator && depth == 0 => {
+                    if index > segment_start {
+                        segments.push((segment_start, index));
+                    }
+                    segment_start = index + 1;
+                }
+                _ => {}
+            }
+        }
+        if to > segment_start {
+            segments.push((segment_start, to));
+        }
+        segments
+    }
+
+    /// Source text of `tokens[from..to]` with comments dropped and
+    /// whitespace collapsed to single spaces.
+    pub(super) fn text(&self, from: usize, to: usize) -> String {
+        let mut text = String::new();
+        let mut previous_end = None;
+        for token in self
+            .tokens
+            .get(from..to.min(self.tokens.len()))
+            .unwrap_or(&[])
+        {
+            if previous_end.is_some_and(|end| end < token.start) {
+                text.push(' ');
+            }
+            text.push_str(token.text);
+            previous_end = Some(token.end);
+        }
+        text
+    }
+
+    /// The block of comments directly above `tokens[index]`, joined.
+    /// Comments trailing code, or separated from the token by a blank line,
+    /// are not documentation.
+    pub(super) fn doc_before(&self, index: usize) -> Option<String> {
+        let token = &self.tokens[index];
+        let previous_end = index
+            .checked_sub(1)
+            .map_or(0, |previous| self.tokens[previous].end);
+        let before = self
+            .comments
+            .partition_point(|comment| comment.start < token.start);
+        let mut block = Vec::new();
+        let mut next_line = token.line;
+        for comment in self.comments[..before].iter().rev() {
+            if comment.start < previous_end || comment.trailing || comment.end_line + 1 < next_line
+            {
+                break;
+            }
+            block.push(comment);
+            next_line = comment.line;
+        }
+        join_comments(block.into_iter().rev())
+    }
+}
@@ -0,0 +1,170 @@
+//! T-SQL routines, their parameters and `GO` batches.
+
+use super::*;
+
+impl<'a, 't> Parser<'a, 't> {
+    /// `{PROC | FUNCTION | TRIGGER} name ...  AS body`. The body runs to the
+    /// end of the batch: the next `GO`, the next routine or end of file.
+    pub(super) fn parse_tsql_routine(&mut self, from: usize, keyword: usize) -> Option<usize> {
+        let (name, mut next) = self.qualified_name(keyword + 1)?;
+        if self.punct_at(next, ";") && self.token_kind(next + 1) == Some(TokenKind::Number) {
+            // Numbered procedure group `proc;2`.
+            next += 2;
+        }
+        let unit_end = self.batch_end(keyword);
+        let as_at = self
+            .find_tsql_as(next, unit_end)
+            .unwrap_or(unit_end.min(self.tokens.len().saturating_sub(1)));
+
+        let mut parameters = Vec::new();
+        let mut returns = None;
+        let mut extra = json!({});
+        let mut table_token = None;
+        if self.word_is(keyword, "trigger") {
+            if self.word_is(next, "on") {
+                let (table, target_end) = self.trigger_target(next + 1);
+                let timing_at = (target_end..as_at).find(|&index| {
+                    ["for", "after", "instead"]
+                        .iter()
+                        .any(|word| self.word_is(index, word))
+                });
+                let timing = timing_at.map(|at| {
+                    let words = 1 + usize::from(self.word_is(at, "instead"));
+                    self.text(at, at + words).to_ascii_lowercase()
+                });
+                extra = json!({
+                    "timing": timing,
+                    "events": self.trigger_events(timing_at.unwrap_or(as_at), as_at),
+                    "table": table.as_ref().map(|(name, _)| name),
+                });
+                table_token = table.map(|(_, token)| token);
+            }
+        } else {
+            let params_end = if self.punct_at(next, "(") {
+                let close = self.matching_close(next).unwrap_or(as_at);
+                parameters = self.parse_tsql_parameters(next + 1, close);
+                close + 1
+            } else if self.word_at(next).is_some_and(|word| word.starts_with('@')) {
+                let end = self
+                    .find_top_level(next, |parser, index| {
+                        index >= as_at
+                            || parser.word_is(index, "with")
+                            || parser.word_is(index, "for")
+                            || parser.word_is(index, "returns")
+                    })
+                    .unwrap_or(as_at);
+                parameters = self.parse_tsql_parameters(next, end);
+                end
+            } else {
+                next
+            };
+            next = params_end;
+            if self.word_is(next, "returns") {
+                let stop = self
+                    .find_top_level(next + 1, |parser, index| {
+                        index >= as_at || parser.word_is(index, "with")
+                    })
+                    .unwrap_or(as_at);
+                returns = Some(self.text(next + 1, stop));
+                next = stop;
+            }
+        }
+
+        let header = Header {
+            keyword,
+            name,
+            signature_end: if self.word_is(keyword, "trigger") {
+                as_at
+            } else {
+                next
+            },
+            parameters,
+            returns,
+            extra,
+        };
+        let index =
+            self.push_routine(header, from, unit_end, None, None, Some(Visibility::Public))?;
+        if let Some(token) = table_token {
+            self.tables.push((index, token));
+        }
+        if as_at < unit_end {
+            self.bodies.push((as_at + 1, unit_end, index));
+        }
+        Some(unit_end)
+    }
+
+    /// `@name [AS] type [= default] [OUT | OUTPUT] [READONLY], ...`
+    fn parse_tsql_parameters(&self, from: usize, to: usize) -> Vec<Parameter> {
+        let mut parameters = Vec::new();
+        for (start, stop) in self.split_top_level(from, to, ",") {
+            let Some(name) = self.word_at(start).filter(|name| name.starts_with('@')) else {
+                continue;
+            };
+            let type_start = start + 1 + usize::from(self.word_is(start + 1, "as"));
+            let type_end = (type_start..stop)
+                .find(|&index| {
+                    self.punct_at(index, "=")
+                        || ["out", "output", "readonly"]
+                            .iter()
+                            .any(|word| self.word_is(index, word))
+                })
+                .unwrap_or(stop);
+            let default_end = (type_end..stop)
+                .find(|&index| {
+                    ["out", "output", "readonly"]
+                        .iter()
+                        .any(|word| self.word_is(index, word))
+                })
+                .unwrap_or(stop);
+            let output = (type_end..stop)
+                .any(|index| self.word_is(index, "out") || self.word_is(index, "output"));
+            parameters.push(Parameter {
+                name: name.to_string(),
+                mode: if output { "out" } else { "in" },
+                type_text: self.text(type_start, type_end),
+                default: self
+                    .punct_at(type_end, "=")
+                    .then(|| self.text(type_end + 1, default_end)),
+            });
+        }
+        parameters
+    }
+
+    /// The `AS` ending a T-SQL routine header: the first one outside
+    /// parentheses that does not follow a parameter name (`@id AS int`).
+    fn find_tsql_as(&self, from: usize, to: usize) -> Option<usize> {
+        self.find_top_level(from, |parser, index| {
+            index > to
+                || (parser.word_is(index, "as")
+                    && !parser
+                        .word_at(index.wrapping_sub(1))
+                        .is_some_and(|word| word.starts_with('@')))
+        })
+        .filter(|&index| index <= to)
+    }
+
+    /// Index of the last token of the batch containing `from`, a routine
+    /// keyword (so `CREATE OR ALTER` is not read as a second routine).
+    fn batch_end(&self, from: usize) -> usize {
+        for index in from + 1..self.tokens.len() {
+            if self.is_separator(index) || self.starts_tsql_routine(index) {
+                return index - 1;
+            }
+        }
+        self.tokens.len().saturating_sub(1)
+    }
+
+    fn starts_tsql_routine(&self, index: usize) -> bool {
+        if !(self.word_is(index, "create") || self.word_is(index, "alter")) {
+            return false;
+        }
+        let at = if self.word_is(index + 1, "or") {
+            index + 3
+        } else {
+            index + 1
+        };
+        ["proc", "procedure", "function", "trigger"]
+            .iter()
+            .any(|keyword| self.word_is(at, keyword))
+    }
+}
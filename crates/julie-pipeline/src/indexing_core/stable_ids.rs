@@ -0,0 +1,62 @@
+//! Re-key extraction results onto stable symbol IDs.
+//!
+//! See [`julie_core::symbol_ids`] for how the IDs are derived. Every row
+//! that points at a symbol is rewritten with it, so the results stay
+//! self-consistent.
+
+use julie_core::symbol_ids::assign_stable_symbol_ids;
+use julie_extractors::ExtractionResults;
+
+/// Replace extractor-assigned symbol IDs in `results` with stable ones.
+pub fn assign_stable_ids(results: &mut ExtractionResults) {
+    if results.symbols.is_empty() {
+        return;
+    }
+    let ids = assign_stable_symbol_ids(&mut results.symbols);
+    let remap = |id: &mut String| {
+        if let Some(stable) = ids.get(id.as_str()) {
+            *id = stable.clone();
+        }
+    };
+    let remap_optional = |id: &mut Option<String>| {
+        if let Some(id) = id.as_mut() {
+            remap(id);
+        }
+    };
+
+    for relationship in &mut results.relationships {
+        remap(&mut relationship.from_symbol_id);
+        remap(&mut relationship.to_symbol_id);
+    }
+    for pending in &mut results.pending_relationships {
+        remap(&mut pending.from_symbol_id);
+    }
+    for pending in &mut results.structured_pending_relationships {
+        remap(&mut pending.pending.from_symbol_id);
+        remap_optional(&mut pending.caller_scope_symbol_id);
+    }
+    for identifier in &mut results.identifiers {
+        remap_optional(&mut identifier.containing_symbol_id);
+        remap_optional(&mut identifier.target_symbol_id);
+    }
+    for literal in &mut results.literals {
+        remap_optional(&mut literal.containing_symbol_id);
+    }
+    for region in &mut results.source_regions {
+        remap_optional(&mut region.containing_symbol_id);
+    }
+    for fact in &mut results.structural_facts {
+        remap_optional(&mut fact.containing_symbol_id);
+    }
+    for metric in &mut results.complexity_metrics {
+        remap_optional(&mut metric.symbol_id);
+    }
+    results.types = std::mem::take(&mut results.types)
+        .into_iter()
+        .map(|(key, mut info)| {
+            remap(&mut info.symbol_id);
+            let key = ids.get(&key).cloned().unwrap_or(key);
+            (key, info)
+        })
+        .collect();
+}
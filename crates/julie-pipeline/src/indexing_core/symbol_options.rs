@@ -0,0 +1,144 @@
+//! Per-language symbol filtering from `[symbols.<language>]` in
+//! `.julie/config.toml`.
+//!
+//! Extractors always emit their full symbol set; the options trim it before
+//! anything is stored, so the bulk pipeline and the watcher index the same
+//! symbols. Dropping a symbol drops its descendants and every row that needs
+//! it to exist (relationships, types, metrics). Rows that merely sit inside it
+//! (identifiers, literals, regions, facts) move to the nearest kept ancestor.
+
+use std::collections::{HashMap, HashSet};
+use std::path::Path;
+
+use julie_core::workspace_settings::{SymbolOptions, WorkspaceSettingsRegistry};
+use julie_extractors::base::Visibility;
+use julie_extractors::{ExtractionResults, Symbol, SymbolKind};
+
+/// Options configured for `language` in the workspace settings.
+pub fn symbol_options_for(workspace_root: &Path, language: &str) -> SymbolOptions {
+    WorkspaceSettingsRegistry::global()
+        .get(workspace_root)
+        .symbol_options(language)
+}
+
+/// Remove the symbols `options` exclude from `results`. Returns how many
+/// symbols were removed.
+pub fn apply_symbol_options(results: &mut ExtractionResults, options: &SymbolOptions) -> usize {
+    if options.keeps_everything() || results.symbols.is_empty() {
+        return 0;
+    }
+
+    let by_id: HashMap<&str, &Symbol> = results
+        .symbols
+        .iter()
+        .map(|symbol| (symbol.id.as_str(), symbol))
+        .collect();
+    let dropped: HashSet<String> = results
+        .symbols
+        .iter()
+        .filter(|symbol| {
+            ancestry(symbol, &by_id).any(|ancestor| is_excluded(ancestor, &by_id, options))
+        })
+        .map(|symbol| symbol.id.clone())
+        .collect();
+    if dropped.is_empty() {
+        return 0;
+    }
+
+    // Nearest kept ancestor of each dropped symbol, for rows nested in it.
+    let replacement: HashMap<String, Option<String>> = dropped
+        .iter()
+        .map(|id| {
+            let kept = ancestry(by_id[id.as_str()], &by_id)
+                .find(|ancestor| !dropped.contains(&ancestor.id))
+                .map(|ancestor| ancestor.id.clone());
+            (id.clone(), kept)
+        })
+        .collect();
+    let reattach = |slot: &mut Option<String>| {
+        if let Some(kept) = slot.as_deref().and_then(|id| replacement.get(id)) {
+            *slot = kept.clone();
+        }
+    };
+
+    results
+        .symbols
+        .retain(|symbol| !dropped.contains(&symbol.id));
+    results.relationships.retain(|relationship| {
+        !dropped.contains(&relationship.from_symbol_id)
+            && !dropped.contains(&relationship.to_symbol_id)
+    });
+    results
+        .pending_relationships
+        .retain(|pending| !dropped.contains(&pending.from_symbol_id));
+    results
+        .structured_pending_relationships
+        .retain(|pending| !dropped.contains(&pending.pending.from_symbol_id));
+    for pending in &mut results.structured_pending_relationships {
+        reattach(&mut pending.caller_scope_symbol_id);
+    }
+    results
+        .types
+        .retain(|_, info| !dropped.contains(&info.symbol_id));
+    results.complexity_metrics.retain(|metric| {
+        metric
+            .symbol_id
+            .as_ref()
+            .is_none_or(|id| !dropped.contains(id))
+    });
+    for identifier in &mut results.identifiers {
+        reattach(&mut identifier.containing_symbol_id);
+        if identifier
+            .target_symbol_id
+            .as_ref()
+            .is_some_and(|id| dropped.contains(id))
+        {
+            identifier.target_symbol_id = None;
+        }
+    }
+    for literal in &mut results.literals {
+        reattach(&mut literal.containing_symbol_id);
+    }
+    for region in &mut results.source_regions {
+        reattach(&mut region.containing_symbol_id);
+    }
+    for fact in &mut results.structural_facts {
+        reattach(&mut fact.containing_symbol_id);
+    }
+
+    dropped.len()
+}
+
+/// `symbol` followed by its parents, innermost first.
+fn ancestry<'a>(
+    symbol: &'a Symbol,
+    by_id: &'a HashMap<&'a str, &'a Symbol>,
+) -> impl Iterator<Item = &'a Symbol> {
+    let mut seen = HashSet::new();
+    std::iter::successors(Some(symbol), move |current| {
+        let parent = by_id.get(current.parent_id.as_deref()?)?;
+        // Guard against malformed parent cycles.
+        seen.insert(current.id.as_str()).then_some(*parent)
+    })
+}
+
+fn is_excluded(symbol: &Symbol, by_id: &HashMap<&str, &Symbol>, options: &SymbolOptions) -> bool {
+    (!options.includes_imports() && symbol.kind == SymbolKind::Import)
+        || (!options.includes_private() && matches!(symbol.visibility, Some(Visibility::Private)))
+        || (!options.includes_locals() && is_local(symbol, by_id))
+}
+
+/// A variable or constant whose parent is a function-like symbol.
+fn is_local(symbol: &Symbol, by_id: &HashMap<&str, &Symbol>) -> bool {
+    matches!(symbol.kind, SymbolKind::Variable | SymbolKind::Constant)
+        && symbol
+            .parent_id
+            .as_deref()
+            .and_then(|parent_id| by_id.get(parent_id))
+            .is_some_and(|parent| {
+                matches!(
+                    parent.kind,
+                    SymbolKind::Function | SymbolKind::Method | SymbolKind::Constructor
+                )
+            })
+}
@@ -0,0 +1,227 @@
+//! TODO / FIXME / HACK comments as task symbols.
+//!
+//! Extractors already hand back every comment as a source region, but a
+//! comment is not a symbol, so "what is left to do in the billing module" had
+//! nothing to query. This pass reads the comment regions of a file and turns
+//! each comment line that opens with one of [`TASK_MARKERS`] into a symbol:
+//! semantic group [`TASK_SEMANTIC_GROUP`] and `{marker, text, assignee}`
+//! under [`TASK_METADATA_KEY`], parented to the symbol the comment sits in.
+//! `SymbolKind` has no task kind, so the symbols are variables named by their
+//! marker; the semantic group is what identifies them.
+//!
+//! Only a marker at the start of a comment line counts (`// TODO: ...`,
+//! `# FIXME(ana) ...`, ` * HACK - ...`). A marker mentioned mid-sentence
+//! ("this is not a TODO") is prose, not a task.
+
+use std::collections::{HashMap, HashSet};
+
+use julie_extractors::ExtractionResults;
+use julie_extractors::base::{SourceRegionKind, Symbol, SymbolKind};
+use serde_json::json;
+
+use super::config_keys::innermost_symbol;
+
+/// Metadata key holding `{marker, text, assignee}`.
+pub const TASK_METADATA_KEY: &str = "task";
+
+/// `semantic_group` shared by every task symbol.
+pub const TASK_SEMANTIC_GROUP: &str = "task";
+
+/// Comment markers that open a task, in the case they must be written in.
+pub const TASK_MARKERS: &[&str] = &["TODO", "FIXME", "HACK"];
+
+/// Longest `TODO(assignee)` name accepted; longer parentheses are text.
+const MAX_ASSIGNEE_LEN: usize = 64;
+
+/// One task comment line.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TaskComment {
+    pub marker: &'static str,
+    /// `ana` in `TODO(ana): ...`.
+    pub assignee: Option<String>,
+    /// The comment after the marker, without separators or closing tokens.
+    pub text: String,
+    /// Byte offset of the marker within the comment line.
+    pub column: usize,
+}
+
+/// Add a task symbol for every task comment line in `results`' comment
+/// regions.
+pub fn annotate_task_comments(
+    results: &mut ExtractionResults,
+    file_path: &str,
+    language: &str,
+    content: &str,
+) {
+    let mut seen_lines = HashSet::new();
+    let mut tasks = Vec::new();
+    for region in &results.source_regions {
+        if !matches!(
+            region.kind,
+            SourceRegionKind::Comment | SourceRegionKind::DocComment
+        ) {
+            continue;
+        }
+        let start = region.start_byte as usize;
+        let Some(text) = content.get(start..region.end_byte as usize) else {
+            continue;
+        };
+        let mut line_offset = 0;
+        for (index, line) in text.split('\n').enumerate() {
+            let line_number = region.start_line + index as u32;
+            let offset = start + line_offset;
+            line_offset += line.len() + 1;
+            // A comment can open mid-line, after code.
+            let line_column = if index == 0 {
+                region.start_column as usize
+            } else {
+                0
+            };
+            if !seen_lines.insert(line_number) {
+                continue;
+            }
+            if let Some(task) = parse_task_comment(line) {
+                tasks.push((
+                    task,
+                    TaskLocation {
+                        line_number,
+                        line_column,
+                        offset,
+                    },
+                    line,
+                    region.containing_symbol_id.clone(),
+                ));
+            }
+        }
+    }
+
+    let symbols: Vec<Symbol> = tasks
+        .into_iter()
+        .map(|(task, location, line, containing)| {
+            let parent_id = containing.or_else(|| {
+                innermost_symbol(
+                    &results.symbols,
+                    location.line_number,
+                    location.offset + task.column,
+                )
+                .map(|symbol| symbol.id.clone())
+            });
+            task_symbol(&task, file_path, language, &location, line, parent_id)
+        })
+        .collect();
+    results.symbols.extend(symbols);
+}
+
+/// The task on one comment line, if the line opens with a task marker after
+/// its comment leader.
+pub fn parse_task_comment(line: &str) -> Option<TaskComment> {
+    let body = line
+        .trim_start()
+        .trim_start_matches(['/', '*', '#', '-', ';', '!', '<', '%', '"', '\''])
+        .trim_start();
+    let column = line.len() - body.len();
+    let marker = TASK_MARKERS
+        .iter()
+        .copied()
+        .find(|marker| body.starts_with(marker))?;
+    let mut rest = &body[marker.len()..];
+    if rest
+        .chars()
+        .next()
+        .is_some_and(|c| c.is_alphanumeric() || c == '_')
+    {
+        return None;
+    }
+
+    let mut assignee = None;
+    if let Some(after_paren) = rest.strip_prefix('(')
+        && let Some(close) = after_paren.find(')')
+        && close <= MAX_ASSIGNEE_LEN
+    {
+        let name = after_paren[..close].trim();
+        assignee = (!name.is_empty()).then(|| name.to_string());
+        rest = &after_paren[close + 1..];
+    }
+
+    let text = rest
+        .trim_start_matches([':', '-', '!', ' ', '\t'])
+        .trim_end();
+    let text = text
+        .strip_suffix("*/")
+        .or_else(|| text.strip_suffix("-->"))
+        .or_else(|| text.strip_suffix("\"\"\""))
+        .unwrap_or(text)
+        .trim_end();
+    Some(TaskComment {
+        marker,
+        assignee,
+        text: text.to_string(),
+        column,
+    })
+}
+
+/// Where a comment line starts: its 1-based line, the column and byte offset
+/// of its first character within the file.
+struct TaskLocation {
+    line_number: u32,
+    line_column: usize,
+    offset: usize,
+}
+
+fn task_symbol(
+    task: &TaskComment,
+    file_path: &str,
+    language: &str,
+    location: &TaskLocation,
+    line: &str,
+    parent_id: Option<String>,
+) -> Symbol {
+    let id_source = format!("{file_path}:{}:{}", location.line_number, task.marker);
+    let id = blake3::hash(id_source.as_bytes()).to_hex()[..32].to_string();
+    let line = line.trim_end_matches('\r');
+    let declaration = line[task.column..].trim_end().to_string();
+    let info = json!({
+        "marker": task.marker,
+        "text": task.text,
+        "assignee": task.assignee,
+    });
+    Symbol {
+        id,
+        name: task.marker.to_string(),
+        kind: SymbolKind::Variable,
+        language: language.to_string(),
+        file_path: file_path.to_string(),
+        start_line: location.line_number,
+        start_column: (location.line_column + task.column) as u32,
+        end_line: location.line_number,
+        end_column: (location.line_column + line.len()) as u32,
+        start_byte: (location.offset + task.column) as u32,
+        end_byte: (location.offset + line.len()) as u32,
+        signature: Some(declaration),
+        doc_comment: None,
+        visibility: None,
+        parent_id,
+        metadata: Some(HashMap::from([(TASK_METADATA_KEY.to_string(), info)])),
+        semantic_group: Some(TASK_SEMANTIC_GROUP.to_string()),
+        confidence: None,
+        code_context: Some(line.trim().to_string()),
+        content_type: None,
+        body_span: None,
+        body_hash: None,
+        annotations: Vec::new(),
+    }
+}
+
+/// `{marker, text, assignee}` of a task symbol, or `None` for any other
+/// symbol.
+pub fn task_info(symbol: &Symbol) -> Option<(&str, &str, Option<&str>)> {
+    if symbol.semantic_group.as_deref() != Some(TASK_SEMANTIC_GROUP) {
+        return None;
+    }
+    let info = symbol.metadata.as_ref()?.get(TASK_METADATA_KEY)?;
+    Some((
+        info.get("marker")?.as_str()?,
+        info.get("text")?.as_str().unwrap_or_default(),
+        info.get("assignee").and_then(|assignee| assignee.as_str()),
+    ))
+}
@@ -0,0 +1,222 @@
+//! Terraform and OpenTofu configuration (`.tf`, `.tofu`).
+//!
+//! Infrastructure lives next to the application code that talks to it. This
+//! pass tokenizes `.tf` files with the shared [`lexer`](super::lexer) and
+//! emits a symbol per `resource`, `data` source, `module` call, `variable`,
+//! `output` and local value. Every symbol carries semantic group
+//! [`TERRAFORM_SEMANTIC_GROUP`] and `{block, address, ...}` under
+//! [`TERRAFORM_METADATA_KEY`]. `address` is how expressions name the symbol (`aws_s3_bucket.logs`,
+//! `data.aws_ami.ubuntu`, `module.vpc`, `var.region`, `local.tags`), and
+//! `output.<name>` for outputs, which expressions only reach through their
+//! module. Resources and data sources are named by that address, the others
+//! by their label. Each symbol's code context is its whole block, so a search
+//! for a bucket or queue name finds the resource that creates it.
+//!
+//! References in expressions (`var.region`, `"${local.prefix}-logs"`,
+//! `aws_iam_role.app.arn`, `module.vpc.vpc_id`) become `Uses` relationships
+//! from the enclosing block and identifiers on the referenced name. A module
+//! call also uses, for each argument it sets, the variable of that name in
+//! the module's source directory. A Terraform module is a directory, so a
+//! reference is resolved here only when its target is declared in the same
+//! file. The rest are left pending with a callee carrying
+//! [`TERRAFORM_CALLEE_PREFIX`], and finalize hands them to
+//! [`resolve_terraform_references`], which matches addresses within the
+//! referencing file's directory and follows local module sources
+//! (`./modules/vpc`) for module outputs and arguments. Registry and git
+//! module sources have no files to link to.
+//!
+//! Blocks it does not model (`provider`, `terraform`, `moved`, ...) are
+//! stepped over, and an unclosed block ends at end of file.
+
+mod blocks;
+mod cross_file;
+mod references;
+mod tokenize;
+mod tokens;
+
+pub use cross_file::{is_terraform_reference, resolve_terraform_references};
+use tokenize::{bracket_depths, lex};
+
+use std::collections::{HashMap, HashSet};
+
+use anyhow::Result;
+use julie_core::database::SymbolDatabase;
+use julie_extractors::{
+    ExtractionResults, Identifier, IdentifierKind, PendingRelationship, Relationship,
+    RelationshipKind, Symbol, SymbolKind,
+};
+use serde_json::{Value, json};
+use tracing::{debug, warn};
+
+use crate::indexing_core::lexer::{
+    Comment, Lexer, Token, TokenKind, block_end, comment_lines, line_end, punct_len, word_end,
+};
+use crate::indexing_core::text_lines::TextLines;
+
+pub use julie_core::file_policy::{TERRAFORM_LANGUAGE, is_terraform_language};
+
+/// Metadata key holding `{block, address, ...}`.
+pub const TERRAFORM_METADATA_KEY: &str = "terraform";
+
+/// `semantic_group` shared by every Terraform symbol.
+pub const TERRAFORM_SEMANTIC_GROUP: &str = "terraform";
+
+/// Prefix on the `callee_name` of a pending reference. The rest of the name
+/// is the referenced address, or `module.<name>.output.<output>` and
+/// `module.<name>.var.<variable>` for what a module call reaches inside its
+/// source.
+pub const TERRAFORM_CALLEE_PREFIX: &str = "terraform:";
+
+/// Module block arguments Terraform itself interprets; every other argument
+/// sets a variable of the module.
+const MODULE_META_ARGUMENTS: &[&str] = &[
+    "source",
+    "version",
+    "count",
+    "for_each",
+    "providers",
+    "depends_on",
+];
+
+/// Extract a Terraform file.
+pub fn extract_terraform(file_path: &str, content: &str) -> Result<ExtractionResults> {
+    let mut lexer = Lexer::new(content);
+    lex(&mut lexer, content, 0, content.len(), false);
+    let (tokens, mut comments) = lexer.finish();
+    // Comments inside interpolations are lexed after the string holding them.
+    comments.sort_by_key(|comment| comment.start);
+
+    let lines = TextLines::new(content);
+    let mut parser = Parser {
+        file_path,
+        content,
+        lines: &lines,
+        depths: bracket_depths(&tokens),
+        tokens: &tokens,
+        comments: &comments,
+        symbols: Vec::new(),
+        identifiers: Vec::new(),
+        references: Vec::new(),
+        ids: HashSet::new(),
+    };
+    parser.run();
+    Ok(parser.into_results())
+}
+
+// ---------------------------------------------------------------------------
+// Parser
+// ---------------------------------------------------------------------------
+
+/// A reference seen while parsing, resolved once every block is known.
+struct Reference {
+    /// Index into `symbols` of the referencing symbol.
+    from: usize,
+    address: String,
+    line: u32,
+    /// Index into `identifiers` of the usage.
+    identifier: usize,
+}
+
+/// A traversal such as `aws_iam_role.app.arn`, read as the address it
+/// refers to.
+struct Traversal<'a> {
+    address: String,
+    /// Name of the referenced symbol.
+    name: String,
+    /// Tokens the identifier spans.
+    first: usize,
+    last: usize,
+    /// `vpc_id` and its token in `module.vpc.vpc_id`.
+    output: Option<(&'a str, usize)>,
+}
+
+struct Parser<'a, 't> {
+    file_path: &'a str,
+    content: &'a str,
+    lines: &'t TextLines,
+    tokens: &'t [Token<'a>],
+    depths: Vec<usize>,
+    comments: &'t [Comment<'a>],
+    symbols: Vec<Symbol>,
+    identifiers: Vec<Identifier>,
+    references: Vec<Reference>,
+    ids: HashSet<String>,
+}
+
+impl<'a> Parser<'a, '_> {
+    fn run(&mut self) {
+        let tokens = self.tokens;
+        let mut index = 0;
+        while index < tokens.len() {
+            if self.depths[index] == 0 && self.starts_line(index) && self.word_at(index).is_some() {
+                let mut open = index + 1;
+                while tokens
+                    .get(open)
+                    .is_some_and(|token| matches!(token.kind, TokenKind::Word | TokenKind::Str))
+                {
+                    open += 1;
+                }
+                if self.punct_at(open, "{") {
+                    let close = self.matching_close(open).unwrap_or(tokens.len() - 1);
+                    self.parse_block(index, open, close);
+                    index = close + 1;
+                    continue;
+                }
+            }
+            index += 1;
+        }
+    }
+}
+
+struct SymbolSpec {
+    name: String,
+    kind: SymbolKind,
+    block: &'static str,
+    address: String,
+    signature: String,
+    doc: Option<String>,
+    extra: Value,
+}
+
+/// `aws_s3_bucket`, `google_compute_instance`: a provider prefix, an
+/// underscore, then the type.
+fn is_resource_type(word: &str) -> bool {
+    word.split_once('_').is_some_and(|(provider, rest)| {
+        !provider.is_empty()
+            && !rest.is_empty()
+            && word
+                .bytes()
+                .all(|byte| byte.is_ascii_lowercase() || byte.is_ascii_digit() || byte == b'_')
+    })
+}
+
+/// `./modules/vpc` and `../shared`; registry and git sources are remote.
+fn is_local_source(source: &str) -> bool {
+    source.starts_with("./") || source.starts_with("../")
+}
+
+/// The facts recorded under [`TERRAFORM_METADATA_KEY`].
+fn symbol_info(symbol: &Symbol) -> Option<&Value> {
+    symbol.metadata.as_ref()?.get(TERRAFORM_METADATA_KEY)
+}
+
+/// Join `relative` onto `base` and fold `.` / `..` segments. `None` when it
+/// climbs above the workspace root.
+fn join_path(base: &str, relative: &str) -> Option<String> {
+    let mut segments: Vec<&str> = Vec::new();
+    for segment in base.split('/').chain(relative.split('/')) {
+        match segment {
+            "" | "." => {}
+            ".." => {
+                segments.pop()?;
+            }
+            segment => segments.push(segment),
+        }
+    }
+    Some(segments.join("/"))
+}
+
+/// Directory of a workspace-relative file; empty at the root.
+fn parent_dir(file_path: &str) -> &str {
+    file_path.rsplit_once('/').map_or("", |(dir, _)| dir)
+}
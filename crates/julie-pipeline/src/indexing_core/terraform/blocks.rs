@@ -0,0 +1,173 @@
+//! Blocks, attributes and locals.
+
+use super::*;
+
+impl<'a> Parser<'a, '_> {
+    /// A top-level block: the keyword at `keyword`, its labels, and the body
+    /// between the braces at `open` and `close`.
+    pub(super) fn parse_block(&mut self, keyword: usize, open: usize, close: usize) {
+        let labels: Vec<&str> = (keyword + 1..open).map(|index| self.label(index)).collect();
+        let attributes = self.attributes(open, close);
+        let string = |name: &str| {
+            attributes
+                .iter()
+                .find(|(key, _, _)| self.tokens[*key].text == name)
+                .and_then(|(_, from, to)| self.string_value(*from, *to))
+        };
+        let expression = |name: &str| {
+            attributes
+                .iter()
+                .find(|(key, _, _)| self.tokens[*key].text == name)
+                .map(|(_, from, to)| self.value_text(*from, *to))
+        };
+
+        let (block, name, kind, address, extra) =
+            match (self.tokens[keyword].text, labels.as_slice()) {
+                ("resource", [resource_type, name]) => {
+                    let address = format!("{resource_type}.{name}");
+                    let provider = expression("provider").unwrap_or_else(|| {
+                        resource_type
+                            .split_once('_')
+                            .map_or(*resource_type, |(provider, _)| provider)
+                            .to_string()
+                    });
+                    (
+                        "resource",
+                        address.clone(),
+                        SymbolKind::Class,
+                        address,
+                        json!({ "type": resource_type, "provider": provider }),
+                    )
+                }
+                ("data", [data_type, name]) => {
+                    let address = format!("data.{data_type}.{name}");
+                    (
+                        "data",
+                        address.clone(),
+                        SymbolKind::Class,
+                        address,
+                        json!({ "type": data_type }),
+                    )
+                }
+                ("module", [name]) => {
+                    let inputs: Vec<&str> = attributes
+                        .iter()
+                        .map(|(key, _, _)| self.tokens[*key].text)
+                        .filter(|key| !MODULE_META_ARGUMENTS.contains(key))
+                        .collect();
+                    (
+                        "module",
+                        name.to_string(),
+                        SymbolKind::Module,
+                        format!("module.{name}"),
+                        json!({
+                            "source": string("source"),
+                            "version": string("version"),
+                            "inputs": inputs,
+                        }),
+                    )
+                }
+                ("variable", [name]) => (
+                    "variable",
+                    name.to_string(),
+                    SymbolKind::Variable,
+                    format!("var.{name}"),
+                    json!({ "type": expression("type"), "default": expression("default") }),
+                ),
+                ("output", [name]) => (
+                    "output",
+                    name.to_string(),
+                    SymbolKind::Property,
+                    format!("output.{name}"),
+                    json!({ "value": expression("value") }),
+                ),
+                ("locals", []) => {
+                    self.parse_locals(&attributes);
+                    return;
+                }
+                _ => return,
+            };
+
+        let signature = self.value_text(keyword, open);
+        let doc = self.doc_before(keyword).or_else(|| string("description"));
+        let module_source = extra["source"].as_str().map(str::to_string);
+        let symbol = self.push_symbol(
+            SymbolSpec {
+                name,
+                kind,
+                block,
+                address,
+                signature,
+                doc,
+                extra,
+            },
+            keyword,
+            self.tokens[close].end,
+        );
+        self.collect_references(symbol, open + 1, close);
+
+        // Arguments of a local module call set the module's variables.
+        if block == "module" && module_source.as_deref().is_some_and(is_local_source) {
+            let module = labels[0];
+            for (key, _, _) in &attributes {
+                let argument = self.tokens[*key].text;
+                if !MODULE_META_ARGUMENTS.contains(&argument) {
+                    self.push_reference(
+                        symbol,
+                        format!("module.{module}.var.{argument}"),
+                        argument,
+                        *key,
+                        *key,
+                    );
+                }
+            }
+        }
+    }
+
+    /// One symbol per attribute of a `locals` block.
+    fn parse_locals(&mut self, attributes: &[(usize, usize, usize)]) {
+        for &(key, from, to) in attributes {
+            let name = self.tokens[key].text;
+            let end = self.span_end(key, to);
+            let signature = self.content[self.tokens[key].start..end]
+                .lines()
+                .next()
+                .unwrap_or_default()
+                .trim()
+                .to_string();
+            let doc = self.doc_before(key);
+            let symbol = self.push_symbol(
+                SymbolSpec {
+                    name: name.to_string(),
+                    kind: SymbolKind::Variable,
+                    block: "locals",
+                    address: format!("local.{name}"),
+                    signature,
+                    doc,
+                    extra: json!({ "value": self.value_text(from, to) }),
+                },
+                key,
+                end,
+            );
+            self.collect_references(symbol, from, to);
+        }
+    }
+
+    /// `key = value` attributes directly in the body between `open` and
+    /// `close`: the key and the token range of its value.
+    fn attributes(&self, open: usize, close: usize) -> Vec<(usize, usize, usize)> {
+        let depth = self.depths[open] + 1;
+        let starts_item = |index: usize| self.depths[index] == depth && self.starts_line(index);
+        (open + 1..close)
+            .filter(|&key| {
+                starts_item(key) && self.word_at(key).is_some() && self.punct_at(key + 1, "=")
+            })
+            .map(|key| {
+                let end = (key + 2..close)
+                    .find(|&index| starts_item(index))
+                    .unwrap_or(close);
+                (key, key + 2, end)
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,86 @@
+//! Resolve the references left pending across files of one module.
+
+use super::*;
+
+/// Is this pending relationship a Terraform reference recorded by this pass?
+pub fn is_terraform_reference(pending: &PendingRelationship) -> bool {
+    pending.callee_name.starts_with(TERRAFORM_CALLEE_PREFIX)
+}
+
+/// Resolve Terraform references declared in other files against the
+/// Terraform symbols in `db`: an address links to the block declaring it in
+/// the referencing file's directory, and a module output or argument to the
+/// output or variable in the directory of each local module call of that
+/// name.
+pub fn resolve_terraform_references(
+    pendings: &[PendingRelationship],
+    db: &SymbolDatabase,
+) -> Vec<Relationship> {
+    let references: Vec<&PendingRelationship> = pendings
+        .iter()
+        .filter(|p| is_terraform_reference(p))
+        .collect();
+    if references.is_empty() {
+        return Vec::new();
+    }
+    let symbols = match db.get_symbols_by_semantic_group(TERRAFORM_SEMANTIC_GROUP) {
+        Ok(symbols) => symbols,
+        Err(e) => {
+            warn!("Terraform symbol lookup failed: {}", e);
+            return Vec::new();
+        }
+    };
+
+    let mut by_address: HashMap<(&str, &str), Vec<&Symbol>> = HashMap::new();
+    for symbol in &symbols {
+        if let Some(address) = symbol_info(symbol).and_then(|info| info["address"].as_str()) {
+            by_address
+                .entry((parent_dir(&symbol.file_path), address))
+                .or_default()
+                .push(symbol);
+        }
+    }
+
+    let mut resolved = Vec::new();
+    for pending in &references {
+        let address = &pending.callee_name[TERRAFORM_CALLEE_PREFIX.len()..];
+        let dir = parent_dir(&pending.file_path);
+        let inside_module = address.strip_prefix("module.").and_then(|rest| {
+            let (module, member) = rest.split_once('.')?;
+            (member.starts_with("output.") || member.starts_with("var."))
+                .then_some((module, member))
+        });
+        let targets = match inside_module {
+            Some((module, member)) => declared(&by_address, dir, &format!("module.{module}"))
+                .into_iter()
+                .filter_map(|call| symbol_info(call)?["source"].as_str())
+                .filter(|source| is_local_source(source))
+                .filter_map(|source| join_path(dir, source))
+                .flat_map(|module_dir| declared(&by_address, &module_dir, member))
+                .collect(),
+            None => declared(&by_address, dir, address),
+        };
+        for target in targets {
+            if target.id != pending.from_symbol_id {
+                resolved.push(crate::resolver::build_resolved_relationship(
+                    pending, target,
+                ));
+            }
+        }
+    }
+    debug!(
+        "Linked {} Terraform references to {} declarations",
+        references.len(),
+        resolved.len()
+    );
+    resolved
+}
+
+/// The symbols declaring `address` in `dir`.
+fn declared<'s>(
+    by_address: &HashMap<(&str, &str), Vec<&'s Symbol>>,
+    dir: &str,
+    address: &str,
+) -> Vec<&'s Symbol> {
+    by_address.get(&(dir, address)).cloned().unwrap_or_default()
+}
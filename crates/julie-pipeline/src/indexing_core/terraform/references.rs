@@ -0,0 +1,198 @@
+//! References in expressions, and resolving them within the file.
+
+use super::*;
+
+impl<'a> Parser<'a, '_> {
+    /// Record every reference in `tokens[from..to]` as used by `symbol`.
+    pub(super) fn collect_references(&mut self, symbol: usize, from: usize, to: usize) {
+        for index in from..to {
+            if index > 0 && self.punct_at(index - 1, ".") {
+                continue;
+            }
+            let Some(traversal) = self.traversal(index) else {
+                continue;
+            };
+            if let Some((output, token)) = traversal.output {
+                let module = traversal.address.trim_start_matches("module.");
+                self.push_reference(
+                    symbol,
+                    format!("module.{module}.output.{output}"),
+                    output,
+                    token,
+                    token,
+                );
+            }
+            self.push_reference(
+                symbol,
+                traversal.address,
+                &traversal.name,
+                traversal.first,
+                traversal.last,
+            );
+        }
+    }
+
+    /// The address a traversal starting at `tokens[index]` refers to. Roots
+    /// Terraform defines itself (`count`, `each`, `path`, `self`, ...) and
+    /// `for` expression variables refer to nothing indexed.
+    fn traversal(&self, index: usize) -> Option<Traversal<'a>> {
+        let root = self.word_at(index)?;
+        let mut segments = vec![(root, index)];
+        let mut cursor = index + 1;
+        while segments.len() < 4 {
+            if self.punct_at(cursor, "[") {
+                cursor = self.matching_close(cursor)? + 1;
+            } else if self.punct_at(cursor, ".")
+                && let Some(word) = self.word_at(cursor + 1)
+            {
+                segments.push((word, cursor + 1));
+                cursor += 2;
+            } else if self.punct_at(cursor, ".")
+                && self
+                    .tokens
+                    .get(cursor + 1)
+                    .is_some_and(|token| token.kind == TokenKind::Number)
+            {
+                // Legacy index syntax: `aws_instance.web.0.id`.
+                cursor += 2;
+            } else {
+                break;
+            }
+        }
+
+        let traversal = |address: String, name: &str, first, last| Traversal {
+            address,
+            name: name.to_string(),
+            first,
+            last,
+            output: None,
+        };
+        match segments.as_slice() {
+            [("var", _), (name, token), ..] => {
+                Some(traversal(format!("var.{name}"), name, *token, *token))
+            }
+            [("local", _), (name, token), ..] => {
+                Some(traversal(format!("local.{name}"), name, *token, *token))
+            }
+            [("module", _), (name, token), rest @ ..] => Some(Traversal {
+                output: rest.first().copied(),
+                ..traversal(format!("module.{name}"), name, *token, *token)
+            }),
+            [("data", _), (data_type, _), (name, token), ..] => {
+                let address = format!("data.{data_type}.{name}");
+                Some(traversal(address.clone(), &address, index, *token))
+            }
+            [(resource_type, _), (name, token), ..] if is_resource_type(resource_type) => {
+                let address = format!("{resource_type}.{name}");
+                Some(traversal(address.clone(), &address, index, *token))
+            }
+            _ => None,
+        }
+    }
+
+    pub(super) fn push_reference(
+        &mut self,
+        symbol: usize,
+        address: String,
+        name: &str,
+        first: usize,
+        last: usize,
+    ) {
+        let first = &self.tokens[first];
+        let last = &self.tokens[last];
+        let line_start = self.lines.line_start(first.line);
+        let id_source = format!(
+            "{}:terraform:usage:{}:{}",
+            self.file_path, first.start, name
+        );
+        self.identifiers.push(Identifier {
+            id: blake3::hash(id_source.as_bytes()).to_hex()[..32].to_string(),
+            name: name.to_string(),
+            kind: IdentifierKind::VariableRef,
+            language: TERRAFORM_LANGUAGE.to_string(),
+            file_path: self.file_path.to_string(),
+            start_line: first.line,
+            start_column: first.column,
+            end_line: last.line,
+            end_column: last.column + (last.end - last.start) as u32,
+            start_byte: first.start as u32,
+            end_byte: last.end as u32,
+            containing_symbol_id: Some(self.symbols[symbol].id.clone()),
+            target_symbol_id: None,
+            confidence: 1.0,
+            code_context: self.content[line_start..]
+                .lines()
+                .next()
+                .map(|line| line.trim().to_string()),
+        });
+        self.references.push(Reference {
+            from: symbol,
+            address,
+            line: first.line,
+            identifier: self.identifiers.len() - 1,
+        });
+    }
+
+    /// Resolve references to blocks declared in this file; the rest are left
+    /// to [`resolve_terraform_references`]. Repeated references from one
+    /// symbol to the same address make one relationship.
+    pub(super) fn into_results(mut self) -> ExtractionResults {
+        let addresses: HashMap<String, String> = self
+            .symbols
+            .iter()
+            .filter_map(|symbol| {
+                let address = symbol_info(symbol)?["address"].as_str()?;
+                Some((address.to_string(), symbol.id.clone()))
+            })
+            .collect();
+
+        let mut linked = HashSet::new();
+        let mut relationships = Vec::new();
+        let mut pending_relationships = Vec::new();
+        for reference in &self.references {
+            let from_symbol_id = self.symbols[reference.from].id.clone();
+            let target = addresses.get(&reference.address);
+            if let Some(target) = target {
+                self.identifiers[reference.identifier].target_symbol_id = Some(target.clone());
+            }
+            if !linked.insert((reference.from, reference.address.as_str())) {
+                continue;
+            }
+            match target {
+                Some(target) if *target != from_symbol_id => relationships.push(Relationship {
+                    id: format!(
+                        "{}_{}_{:?}_{}_{}",
+                        from_symbol_id,
+                        target,
+                        RelationshipKind::Uses,
+                        self.file_path,
+                        reference.line
+                    ),
+                    from_symbol_id,
+                    to_symbol_id: target.clone(),
+                    kind: RelationshipKind::Uses,
+                    file_path: self.file_path.to_string(),
+                    line_number: reference.line,
+                    confidence: 1.0,
+                    metadata: None,
+                }),
+                Some(_) => {}
+                None => pending_relationships.push(PendingRelationship {
+                    from_symbol_id,
+                    callee_name: format!("{TERRAFORM_CALLEE_PREFIX}{}", reference.address),
+                    kind: RelationshipKind::Uses,
+                    file_path: self.file_path.to_string(),
+                    line_number: reference.line,
+                    confidence: 0.9,
+                }),
+            }
+        }
+
+        let mut results = ExtractionResults::empty();
+        results.symbols = self.symbols;
+        results.identifiers = self.identifiers;
+        results.relationships = relationships;
+        results.pending_relationships = pending_relationships;
+        results
+    }
+}
@@ -0,0 +1,204 @@
+//! HCL tokenizer: strings, heredocs and template interpolations.
+
+use super::*;
+
+/// HCL identifiers may contain dashes.
+fn is_word_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || c == '_' || c == '-'
+}
+
+/// Punctuation read as a single token.
+const COMPOUND_PUNCT: &[&str] = &["...", "==", "!=", "<=", ">=", "=>", "&&", "||", "::"];
+
+/// Lex `content[from..to]`; `interpolated` marks an interpolation body.
+pub(super) fn lex<'a>(
+    lexer: &mut Lexer<'a>,
+    content: &'a str,
+    from: usize,
+    to: usize,
+    interpolated: bool,
+) {
+    let bytes = content.as_bytes();
+    let mut i = from;
+    while i < to {
+        let c = bytes[i];
+        if c.is_ascii_whitespace() {
+            i += 1;
+            continue;
+        }
+        if c == b'#' || (c == b'/' && bytes.get(i + 1) == Some(&b'/')) {
+            let end = line_end(content, i).min(to);
+            lexer.push_comment(i, end);
+            i = end;
+            continue;
+        }
+        if c == b'/' && bytes.get(i + 1) == Some(&b'*') {
+            let end = block_end(content, i + 2, "*/").min(to);
+            lexer.push_comment(i, end);
+            i = end;
+            continue;
+        }
+        if c == b'"' {
+            let (end, interpolations) = scan_string(bytes, i, to);
+            lexer.push(TokenKind::Str, i, end).interpolated = interpolated;
+            for (start, close) in interpolations {
+                lex(lexer, content, start, close, true);
+            }
+            i = end;
+            continue;
+        }
+        if c == b'<'
+            && bytes.get(i + 1) == Some(&b'<')
+            && let Some(end) = heredoc(lexer, content, i, to, interpolated)
+        {
+            i = end;
+            continue;
+        }
+        let (kind, end) = if c.is_ascii_alphabetic() || c == b'_' {
+            (TokenKind::Word, word_end(content, i, is_word_char).min(to))
+        } else if c.is_ascii_digit() {
+            let mut end = i + 1;
+            while end < to
+                && (bytes[end].is_ascii_alphanumeric()
+                    || (bytes[end] == b'.' && bytes.get(end + 1).is_some_and(u8::is_ascii_digit)))
+            {
+                end += 1;
+            }
+            (TokenKind::Number, end)
+        } else {
+            (
+                TokenKind::Punct,
+                i + punct_len(&content[i..to], COMPOUND_PUNCT),
+            )
+        };
+        lexer.push(kind, i, end).interpolated = interpolated;
+        i = end;
+    }
+}
+
+/// `<<EOT` or `<<-EOT` up to its closing marker line. `None` when `<<` at
+/// `start` does not open a heredoc.
+fn heredoc<'a>(
+    lexer: &mut Lexer<'a>,
+    content: &'a str,
+    start: usize,
+    to: usize,
+    interpolated: bool,
+) -> Option<usize> {
+    let bytes = content.as_bytes();
+    let mut marker_start = start + 2;
+    if bytes.get(marker_start) == Some(&b'-') {
+        marker_start += 1;
+    }
+    let marker_end = word_end(content, marker_start, is_word_char).min(to);
+    if marker_end == marker_start {
+        return None;
+    }
+    let marker = &content[marker_start..marker_end];
+    let marker_line_end = line_end(content, marker_end).min(to);
+    if !content[marker_end..marker_line_end].trim().is_empty() {
+        return None;
+    }
+
+    let body_start = (marker_line_end + 1).min(to);
+    let mut body_end = to;
+    let mut end = to;
+    let mut line_start = body_start;
+    while line_start < to {
+        let next = line_end(content, line_start).min(to);
+        if content[line_start..next].trim() == marker {
+            body_end = line_start;
+            end = next;
+            break;
+        }
+        line_start = next + 1;
+    }
+
+    lexer.push(TokenKind::Str, start, end).interpolated = interpolated;
+    for (open, close) in template_interpolations(bytes, body_start, body_end) {
+        lex(lexer, content, open, close, true);
+    }
+    Some(end)
+}
+
+/// End of the quoted string opening at `start`, and the spans of the
+/// expressions interpolated into it. An unterminated string ends at the end
+/// of its line.
+fn scan_string(bytes: &[u8], start: usize, to: usize) -> (usize, Vec<(usize, usize)>) {
+    let mut interpolations = Vec::new();
+    let mut i = start + 1;
+    while i < to {
+        match bytes[i] {
+            b'\\' => i += 2,
+            b'"' => return (i + 1, interpolations),
+            b'\n' => return (i, interpolations),
+            // `$${` and `%%{` are escaped literals.
+            b'$' | b'%' if bytes.get(i + 1) == Some(&bytes[i]) => i += 2,
+            b'$' | b'%' if bytes.get(i + 1) == Some(&b'{') => {
+                let close = interpolation_end(bytes, i + 2, to);
+                interpolations.push((i + 2, close));
+                i = close + 1;
+            }
+            _ => i += 1,
+        }
+    }
+    (to, interpolations)
+}
+
+/// Spans of the expressions interpolated into the heredoc body
+/// `bytes[from..to]`.
+fn template_interpolations(bytes: &[u8], from: usize, to: usize) -> Vec<(usize, usize)> {
+    let mut interpolations = Vec::new();
+    let mut i = from;
+    while i < to {
+        match bytes[i] {
+            b'$' | b'%' if bytes.get(i + 1) == Some(&bytes[i]) => i += 2,
+            b'$' | b'%' if bytes.get(i + 1) == Some(&b'{') => {
+                let close = interpolation_end(bytes, i + 2, to);
+                interpolations.push((i + 2, close));
+                i = close + 1;
+            }
+            _ => i += 1,
+        }
+    }
+    interpolations
+}
+
+/// Index of the `}` closing an interpolation whose body starts at `from`.
+fn interpolation_end(bytes: &[u8], from: usize, to: usize) -> usize {
+    let mut depth = 0usize;
+    let mut i = from;
+    while i < to {
+        match bytes[i] {
+            b'"' => {
+                i = scan_string(bytes, i, to).0;
+                continue;
+            }
+            b'{' => depth += 1,
+            b'}' if depth == 0 => return i,
+            b'}' => depth -= 1,
+            _ => {}
+        }
+        i += 1;
+    }
+    to
+}
+
+/// Bracket depth each token sits at; a closing bracket counts as inside.
+pub(super) fn bracket_depths(tokens: &[Token<'_>]) -> Vec<usize> {
+    let mut depth = 0usize;
+    tokens
+        .iter()
+        .map(|token| {
+            let at = depth;
+            if token.kind == TokenKind::Punct {
+                match token.text {
+                    "(" | "[" | "{" => depth += 1,
+                    ")" | "]" | "}" => depth = depth.saturating_sub(1),
+                    _ => {}
+                }
+            }
+            at
+        })
+        .collect()
+}
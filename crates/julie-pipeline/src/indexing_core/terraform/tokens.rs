@@ -0,0 +1,156 @@
+//! Token helpers, doc comments and symbol emission.
+
+use super::*;
+
+impl<'a> Parser<'a, '_> {
+    pub(super) fn word_at(&self, index: usize) -> Option<&'a str> {
+        self.tokens
+            .get(index)
+            .filter(|token| token.kind == TokenKind::Word)
+            .map(|token| token.text)
+    }
+
+    pub(super) fn punct_at(&self, index: usize, punct: &str) -> bool {
+        self.tokens
+            .get(index)
+            .is_some_and(|token| token.kind == TokenKind::Punct && token.text == punct)
+    }
+
+    /// Whether `tokens[index]` is the first token on its line, outside any
+    /// string.
+    pub(super) fn starts_line(&self, index: usize) -> bool {
+        let token = &self.tokens[index];
+        !token.interpolated
+            && index
+                .checked_sub(1)
+                .is_none_or(|previous| self.tokens[previous].end_line < token.line)
+    }
+
+    /// Index of the bracket closing the one at `open`, counting `()`, `[]`
+    /// and `{}` together.
+    pub(super) fn matching_close(&self, open: usize) -> Option<usize> {
+        let depth = self.depths[open];
+        (open + 1..self.tokens.len()).find(|&index| {
+            self.depths[index] == depth + 1
+                && self.tokens[index].kind == TokenKind::Punct
+                && matches!(self.tokens[index].text, ")" | "]" | "}")
+        })
+    }
+
+    /// A block label: a string without its quotes, or a bare word.
+    pub(super) fn label(&self, index: usize) -> &'a str {
+        let text = self.tokens[index].text;
+        text.strip_prefix('"')
+            .map(|text| text.strip_suffix('"').unwrap_or(text))
+            .unwrap_or(text)
+    }
+
+    /// The value of `tokens[from..to]` when it is one plain string.
+    pub(super) fn string_value(&self, from: usize, to: usize) -> Option<String> {
+        (to == from + 1 && self.tokens[from].kind == TokenKind::Str)
+            .then(|| self.label(from))
+            .filter(|text| !text.starts_with("<<"))
+            .map(str::to_string)
+    }
+
+    /// End byte of `tokens[from..to]`; interpolated tokens end inside the
+    /// string before them.
+    pub(super) fn span_end(&self, from: usize, to: usize) -> usize {
+        self.tokens[from..to.max(from + 1).min(self.tokens.len())]
+            .iter()
+            .map(|token| token.end)
+            .max()
+            .unwrap_or(self.tokens[from].end)
+    }
+
+    /// Source text of `tokens[from..to]` with whitespace collapsed to single
+    /// spaces and comments kept.
+    pub(super) fn value_text(&self, from: usize, to: usize) -> String {
+        if from >= to {
+            return String::new();
+        }
+        self.content[self.tokens[from].start..self.span_end(from, to)]
+            .split_whitespace()
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
+    // -- comments -----------------------------------------------------------
+
+    /// The block of comments directly above `tokens[index]`, joined. Comments
+    /// trailing code, or separated from the token by a blank line, are not
+    /// documentation.
+    pub(super) fn doc_before(&self, index: usize) -> Option<String> {
+        let token = &self.tokens[index];
+        let previous_end = index
+            .checked_sub(1)
+            .map_or(0, |previous| self.tokens[previous].end);
+        let before = self
+            .comments
+            .partition_point(|comment| comment.start < token.start);
+        let mut block = Vec::new();
+        let mut next_line = token.line;
+        for comment in self.comments[..before].iter().rev() {
+            if comment.start < previous_end || comment.trailing || comment.end_line + 1 < next_line
+            {
+                break;
+            }
+            block.push(comment);
+            next_line = comment.line;
+        }
+        let lines: Vec<String> = block
+            .iter()
+            .rev()
+            .flat_map(|comment| comment_lines(comment.text))
+            .collect();
+        (!lines.is_empty()).then(|| lines.join("\n"))
+    }
+
+    // -- symbols ------------------------------------------------------------
+
+    pub(super) fn push_symbol(&mut self, spec: SymbolSpec, first: usize, end: usize) -> usize {
+        let first = &self.tokens[first];
+        let end_line = self.lines.line_of(end.saturating_sub(1).max(first.start));
+
+        let mut info = json!({ "block": spec.block, "address": spec.address });
+        if let (Value::Object(info), Value::Object(extra)) = (&mut info, spec.extra) {
+            info.extend(extra.into_iter().filter(|(_, value)| !value.is_null()));
+        }
+
+        // A file can repeat a block by mistake; keep ids unique.
+        let base = format!("{}:terraform:{}", self.file_path, spec.address);
+        let mut id_source = base.clone();
+        let mut duplicate = 1;
+        while !self.ids.insert(id_source.clone()) {
+            duplicate += 1;
+            id_source = format!("{base}#{duplicate}");
+        }
+
+        self.symbols.push(Symbol {
+            id: blake3::hash(id_source.as_bytes()).to_hex()[..32].to_string(),
+            name: spec.name,
+            kind: spec.kind,
+            language: TERRAFORM_LANGUAGE.to_string(),
+            file_path: self.file_path.to_string(),
+            start_line: first.line,
+            start_column: first.column,
+            end_line,
+            end_column: (end - self.lines.line_start(end_line)) as u32,
+            start_byte: first.start as u32,
+            end_byte: end as u32,
+            signature: Some(spec.signature),
+            doc_comment: spec.doc,
+            visibility: None,
+            parent_id: None,
+            metadata: Some(HashMap::from([(TERRAFORM_METADATA_KEY.to_string(), info)])),
+            semantic_group: Some(TERRAFORM_SEMANTIC_GROUP.to_string()),
+            confidence: None,
+            code_context: Some(self.content[first.start..end].to_string()),
+            content_type: None,
+            body_span: None,
+            body_hash: None,
+            annotations: Vec::new(),
+        });
+        self.symbols.len() - 1
+    }
+}
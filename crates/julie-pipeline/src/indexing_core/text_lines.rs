@@ -0,0 +1,32 @@
+//! Byte offset to line and column lookups for extractors that scan source
+//! text directly instead of walking a syntax tree.
+
+/// Start offsets of every line of a text.
+pub(crate) struct TextLines {
+    starts: Vec<usize>,
+}
+
+impl TextLines {
+    pub(crate) fn new(content: &str) -> Self {
+        Self {
+            starts: std::iter::once(0)
+                .chain(content.match_indices('\n').map(|(i, _)| i + 1))
+                .collect(),
+        }
+    }
+
+    /// 1-based line holding byte `offset`.
+    pub(crate) fn line_of(&self, offset: usize) -> u32 {
+        self.starts.partition_point(|start| *start <= offset) as u32
+    }
+
+    /// Byte offset of the start of 1-based `line`; 0 for a line that does
+    /// not exist.
+    pub(crate) fn line_start(&self, line: u32) -> usize {
+        (line as usize)
+            .checked_sub(1)
+            .and_then(|index| self.starts.get(index))
+            .copied()
+            .unwrap_or(0)
+    }
+}
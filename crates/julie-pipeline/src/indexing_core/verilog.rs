@@ -0,0 +1,136 @@
+//! Symbols for Verilog and SystemVerilog (`.v`, `.vh`, `.sv`, `.svh`).
+//!
+//! This pass tokenizes the source with the shared [`lexer`](super::lexer)
+//! and emits modules (and `interface`, `program` and `package` units), their
+//! ports and parameters, functions and tasks, and module instances. Every symbol
+//! carries `{kind, ...}` under [`VERILOG_METADATA_KEY`]: ports add their
+//! direction and type, parameters their type and default, instances the
+//! module they instantiate and its parameter overrides.
+//!
+//! Each instantiation also records a `type_usage` identifier on the module
+//! name and an `Instantiates` relationship from the enclosing module. The
+//! relationship is resolved here when the instantiated module is defined in
+//! the same file and left pending for the cross-file resolver otherwise, so
+//! `fast_refs` on a module lists where it is instantiated.
+//!
+//! Preprocessor directives and attributes are skipped, and a unit missing its
+//! `endmodule` ends at end of file.
+
+mod instances;
+mod tokenize;
+mod tokens;
+mod units;
+
+use tokenize::{is_keyword, tokenize};
+
+use std::collections::{HashMap, HashSet};
+use std::path::Path;
+
+use anyhow::Result;
+use julie_core::file_policy::detect_language_for_indexing;
+use julie_extractors::{
+    ExtractionResults, Identifier, IdentifierKind, PendingRelationship, Relationship,
+    RelationshipKind, Symbol, SymbolKind,
+};
+use serde_json::{Value, json};
+
+use crate::indexing_core::lexer::{
+    Comment, Lexer, Token, TokenKind, block_end, comment_lines, escaped_quote_end, line_end,
+    punct_len, word_end,
+};
+
+pub use julie_core::file_policy::{SYSTEMVERILOG_LANGUAGE, VERILOG_LANGUAGE, is_verilog_language};
+
+/// Metadata key holding the HDL-specific facts of a symbol.
+pub const VERILOG_METADATA_KEY: &str = "verilog";
+
+/// Extract a Verilog or SystemVerilog file.
+pub fn extract_verilog(file_path: &str, content: &str) -> Result<ExtractionResults> {
+    let language = detect_language_for_indexing(Path::new(file_path));
+    let (tokens, comments) = tokenize(content);
+    let mut parser = Parser {
+        file_path,
+        language: &language,
+        content,
+        tokens: &tokens,
+        comments: &comments,
+        pos: 0,
+        symbols: Vec::new(),
+        identifiers: Vec::new(),
+        instantiations: Vec::new(),
+        ids: HashSet::new(),
+    };
+    parser.run();
+    Ok(parser.into_results())
+}
+
+// ---------------------------------------------------------------------------
+// Parser
+// ---------------------------------------------------------------------------
+
+/// An instantiation seen while parsing, resolved once every unit is known.
+struct Instantiation {
+    /// Index into `symbols` of the enclosing unit.
+    from: usize,
+    module: String,
+    line: u32,
+    /// Index into `identifiers` of the module-name usage.
+    identifier: usize,
+}
+
+struct Parser<'a, 't> {
+    file_path: &'a str,
+    language: &'a str,
+    content: &'a str,
+    tokens: &'t [Token<'a>],
+    comments: &'t [Comment<'a>],
+    pos: usize,
+    symbols: Vec<Symbol>,
+    identifiers: Vec<Identifier>,
+    instantiations: Vec<Instantiation>,
+    ids: HashSet<String>,
+}
+
+impl<'a> Parser<'a, '_> {
+    fn run(&mut self) {
+        let tokens = self.tokens;
+        let mut statement_start = true;
+        while let Some(token) = tokens.get(self.pos) {
+            if statement_start && token.kind == TokenKind::Word {
+                let before = self.pos;
+                match token.text {
+                    keyword @ ("module" | "macromodule" | "program" | "package") => {
+                        self.parse_unit(keyword)
+                    }
+                    "class" => self.skip_past("endclass"),
+                    "virtual" | "interface" if self.word_at(self.pos + 1) == Some("class") => {
+                        self.skip_past("endclass")
+                    }
+                    "interface" => self.parse_unit("interface"),
+                    "function" | "task" => self.parse_subroutine(None),
+                    _ => {}
+                }
+                if self.pos != before {
+                    continue;
+                }
+            }
+            statement_start = token.kind == TokenKind::Punct && token.text == ";";
+            self.pos += 1;
+        }
+    }
+}
+
+struct SymbolSpec {
+    name: String,
+    kind: SymbolKind,
+    hdl_kind: &'static str,
+    signature: String,
+    parent: Option<usize>,
+    doc: Option<String>,
+    extra: Value,
+}
+
+/// The facts recorded under [`VERILOG_METADATA_KEY`].
+fn symbol_info(symbol: &Symbol) -> Option<&Value> {
+    symbol.metadata.as_ref()?.get(VERILOG_METADATA_KEY)
+}
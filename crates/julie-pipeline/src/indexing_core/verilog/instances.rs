@@ -0,0 +1,166 @@
+//! Module instantiations, and resolving them to same-file definitions.
+
+use super::*;
+
+impl<'a> Parser<'a, '_> {
+    /// `fifo #(.DEPTH(16)) u_fifo (.clk(clk), ...), u_fifo2 (...);`. Leaves
+    /// `pos` unchanged when the statement is not an instantiation.
+    pub(super) fn parse_instantiation(&mut self, unit: usize) {
+        let module_index = self.pos;
+        let module = self.tokens[module_index].text;
+        let mut cursor = module_index + 1;
+        let mut parameters = None;
+        if self.punct_at(cursor, "#") {
+            if self.punct_at(cursor + 1, "(") {
+                let Some(close) = self.matching_close(cursor + 1) else {
+                    return;
+                };
+                parameters = Some(self.text(cursor + 2, close));
+                cursor = close + 1;
+            } else {
+                // Gate delay: `#5`.
+                cursor += 2;
+            }
+        }
+
+        let mut instances = Vec::new();
+        while let Some(name) = self.word_at(cursor).filter(|word| !is_keyword(word)) {
+            let mut next = cursor + 1;
+            while self.punct_at(next, "[") {
+                let Some(close) = self.matching_close(next) else {
+                    return;
+                };
+                next = close + 1;
+            }
+            if !self.punct_at(next, "(") {
+                return;
+            }
+            let Some(close) = self.matching_close(next) else {
+                return;
+            };
+            instances.push((name, cursor, close));
+            cursor = close + 1;
+            if !self.punct_at(cursor, ",") {
+                break;
+            }
+            cursor += 1;
+        }
+        if instances.is_empty() || !self.punct_at(cursor, ";") {
+            return;
+        }
+
+        let doc = self.doc_before(module_index);
+        for (name, _, close) in instances {
+            let mut signature = module.to_string();
+            if let Some(parameters) = &parameters {
+                signature.push_str(&format!(" #({parameters})"));
+            }
+            signature.push(' ');
+            signature.push_str(name);
+            self.push_symbol(
+                SymbolSpec {
+                    name: name.to_string(),
+                    kind: SymbolKind::Variable,
+                    hdl_kind: "instance",
+                    signature,
+                    parent: Some(unit),
+                    doc: doc.clone(),
+                    extra: json!({ "module": module, "parameters": parameters }),
+                },
+                module_index,
+                close,
+            );
+        }
+
+        let token = &self.tokens[module_index];
+        let line_start = self.content[..token.start]
+            .rfind('\n')
+            .map_or(0, |newline| newline + 1);
+        let id_source = format!("{}:verilog:usage:{}", self.file_path, token.start);
+        self.identifiers.push(Identifier {
+            id: blake3::hash(id_source.as_bytes()).to_hex()[..32].to_string(),
+            name: module.to_string(),
+            kind: IdentifierKind::TypeUsage,
+            language: self.language.to_string(),
+            file_path: self.file_path.to_string(),
+            start_line: token.line,
+            start_column: token.column,
+            end_line: token.line,
+            end_column: token.column + (token.end - token.start) as u32,
+            start_byte: token.start as u32,
+            end_byte: token.end as u32,
+            containing_symbol_id: Some(self.symbols[unit].id.clone()),
+            target_symbol_id: None,
+            confidence: 1.0,
+            code_context: self.content[line_start..]
+                .lines()
+                .next()
+                .map(|line| line.trim().to_string()),
+        });
+        self.instantiations.push(Instantiation {
+            from: unit,
+            module: module.to_string(),
+            line: token.line,
+            identifier: self.identifiers.len() - 1,
+        });
+        self.pos = cursor + 1;
+    }
+
+    /// Resolve instantiations of modules and interfaces defined in this file;
+    /// the rest are left to the cross-file resolver.
+    pub(super) fn into_results(mut self) -> ExtractionResults {
+        let units: HashMap<&str, &str> = self
+            .symbols
+            .iter()
+            .filter(|symbol| {
+                symbol_info(symbol)
+                    .is_some_and(|info| info["kind"] == "module" || info["kind"] == "interface")
+            })
+            .map(|symbol| (symbol.name.as_str(), symbol.id.as_str()))
+            .collect();
+
+        let mut relationships = Vec::new();
+        let mut pending_relationships = Vec::new();
+        for instantiation in &self.instantiations {
+            let from_symbol_id = self.symbols[instantiation.from].id.clone();
+            match units.get(instantiation.module.as_str()) {
+                Some(&target) => {
+                    self.identifiers[instantiation.identifier].target_symbol_id =
+                        Some(target.to_string());
+                    relationships.push(Relationship {
+                        id: format!(
+                            "{}_{}_{:?}_{}_{}",
+                            from_symbol_id,
+                            target,
+                            RelationshipKind::Instantiates,
+                            self.file_path,
+                            instantiation.line
+                        ),
+                        from_symbol_id,
+                        to_symbol_id: target.to_string(),
+                        kind: RelationshipKind::Instantiates,
+                        file_path: self.file_path.to_string(),
+                        line_number: instantiation.line,
+                        confidence: 1.0,
+                        metadata: None,
+                    });
+                }
+                None => pending_relationships.push(PendingRelationship {
+                    from_symbol_id,
+                    callee_name: instantiation.module.clone(),
+                    kind: RelationshipKind::Instantiates,
+                    file_path: self.file_path.to_string(),
+                    line_number: instantiation.line,
+                    confidence: 0.9,
+                }),
+            }
+        }
+
+        let mut results = ExtractionResults::empty();
+        results.symbols = self.symbols;
+        results.identifiers = self.identifiers;
+        results.relationships = relationships;
+        results.pending_relationships = pending_relationships;
+        results
+    }
+}
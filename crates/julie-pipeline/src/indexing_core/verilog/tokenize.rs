@@ -0,0 +1,243 @@
+//! Tokenizer and keyword vocabulary.
+
+use super::*;
+
+/// Directives that are skipped to end of line. Any other backtick word is a
+/// macro use and stays in the token stream as a word.
+const SKIPPED_DIRECTIVES: &[&str] = &[
+    "define",
+    "undef",
+    "undefineall",
+    "include",
+    "ifdef",
+    "ifndef",
+    "elsif",
+    "else",
+    "endif",
+    "timescale",
+    "default_nettype",
+    "resetall",
+    "celldefine",
+    "endcelldefine",
+    "pragma",
+    "line",
+    "begin_keywords",
+    "end_keywords",
+    "unconnected_drive",
+    "nounconnected_drive",
+];
+
+fn is_word_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || c == '_' || c == '$'
+}
+
+pub(super) fn tokenize(content: &str) -> (Vec<Token<'_>>, Vec<Comment<'_>>) {
+    let bytes = content.as_bytes();
+    let mut lexer = Lexer::new(content);
+    let mut i = 0;
+
+    while i < bytes.len() {
+        let c = bytes[i];
+        if c.is_ascii_whitespace() {
+            i += 1;
+            continue;
+        }
+
+        let start = i;
+        let rest = &content[i..];
+        if rest.starts_with("//") || rest.starts_with("/*") {
+            let end = if rest.starts_with("//") {
+                line_end(content, i)
+            } else {
+                block_end(content, i + 2, "*/")
+            };
+            lexer.push_comment(start, end);
+            i = end;
+            continue;
+        }
+        // Attribute instances `(* full_case *)`; `@(*)` is a sensitivity list.
+        if rest.starts_with("(*") && !rest.starts_with("(*)") {
+            i = block_end(content, i + 2, "*)");
+            continue;
+        }
+        if c == b'`' {
+            let end = word_end(content, i + 1, is_word_char);
+            if SKIPPED_DIRECTIVES.contains(&&content[i + 1..end]) {
+                // Through end of line, following `\` continuations.
+                let mut directive_end = line_end(content, end);
+                while directive_end < bytes.len()
+                    && content[..directive_end]
+                        .trim_end_matches('\r')
+                        .ends_with('\\')
+                {
+                    directive_end = line_end(content, directive_end + 1);
+                }
+                i = directive_end;
+                continue;
+            }
+            i = end;
+            lexer.push(TokenKind::Word, start, i);
+            continue;
+        }
+
+        if c == b'"' {
+            i = escaped_quote_end(bytes, i + 1, b'"');
+            lexer.push(TokenKind::Str, start, i);
+        } else if c == b'\\' {
+            // Escaped identifier: `\bus[0] ` runs to the next whitespace.
+            i = word_end(content, i + 1, |c| !c.is_ascii_whitespace());
+            lexer.push(TokenKind::Word, start, i).text = &content[start + 1..i];
+        } else if is_word_char(c as char) {
+            i = word_end(content, i, is_word_char);
+            lexer.push(TokenKind::Word, start, i);
+        } else {
+            i += punct_len(rest, &["::"]);
+            lexer.push(TokenKind::Punct, start, i);
+        }
+    }
+    lexer.finish()
+}
+
+/// Reserved words that can start a statement inside a module. A statement
+/// starting with any other word followed by an instance name is an
+/// instantiation.
+pub(super) fn is_keyword(word: &str) -> bool {
+    matches!(
+        word,
+        "alias"
+            | "always"
+            | "always_comb"
+            | "always_ff"
+            | "always_latch"
+            | "and"
+            | "assert"
+            | "assign"
+            | "assume"
+            | "automatic"
+            | "begin"
+            | "bind"
+            | "bit"
+            | "buf"
+            | "bufif0"
+            | "bufif1"
+            | "byte"
+            | "case"
+            | "casex"
+            | "casez"
+            | "chandle"
+            | "checker"
+            | "class"
+            | "clocking"
+            | "cmos"
+            | "const"
+            | "constraint"
+            | "cover"
+            | "covergroup"
+            | "default"
+            | "defparam"
+            | "disable"
+            | "do"
+            | "else"
+            | "end"
+            | "endcase"
+            | "endgenerate"
+            | "enum"
+            | "event"
+            | "export"
+            | "extern"
+            | "final"
+            | "for"
+            | "force"
+            | "foreach"
+            | "forever"
+            | "fork"
+            | "function"
+            | "generate"
+            | "genvar"
+            | "if"
+            | "import"
+            | "initial"
+            | "inout"
+            | "input"
+            | "int"
+            | "integer"
+            | "interconnect"
+            | "interface"
+            | "join"
+            | "let"
+            | "localparam"
+            | "logic"
+            | "longint"
+            | "macromodule"
+            | "modport"
+            | "module"
+            | "nand"
+            | "nmos"
+            | "nor"
+            | "not"
+            | "notif0"
+            | "notif1"
+            | "or"
+            | "output"
+            | "package"
+            | "parameter"
+            | "pmos"
+            | "primitive"
+            | "program"
+            | "property"
+            | "pulldown"
+            | "pullup"
+            | "rcmos"
+            | "real"
+            | "realtime"
+            | "ref"
+            | "reg"
+            | "release"
+            | "repeat"
+            | "return"
+            | "rnmos"
+            | "rpmos"
+            | "rtran"
+            | "rtranif0"
+            | "rtranif1"
+            | "sequence"
+            | "shortint"
+            | "shortreal"
+            | "signed"
+            | "specify"
+            | "specparam"
+            | "static"
+            | "string"
+            | "struct"
+            | "supply0"
+            | "supply1"
+            | "task"
+            | "time"
+            | "tran"
+            | "tranif0"
+            | "tranif1"
+            | "tri"
+            | "tri0"
+            | "tri1"
+            | "triand"
+            | "trior"
+            | "trireg"
+            | "type"
+            | "typedef"
+            | "union"
+            | "unique"
+            | "unique0"
+            | "unsigned"
+            | "uwire"
+            | "var"
+            | "virtual"
+            | "void"
+            | "wait"
+            | "wand"
+            | "while"
+            | "wire"
+            | "wor"
+            | "xnor"
+            | "xor"
+    )
+}
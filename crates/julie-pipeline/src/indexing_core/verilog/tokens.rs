@@ -0,0 +1,259 @@
+//! Token helpers, doc comments and symbol emission.
+
+use super::*;
+
+impl<'a> Parser<'a, '_> {
+    pub(super) fn word_at(&self, index: usize) -> Option<&'a str> {
+        self.tokens
+            .get(index)
+            .filter(|token| token.kind == TokenKind::Word)
+            .map(|token| token.text)
+    }
+
+    pub(super) fn punct_at(&self, index: usize, punct: &str) -> bool {
+        self.tokens
+            .get(index)
+            .is_some_and(|token| token.kind == TokenKind::Punct && token.text == punct)
+    }
+
+    /// Index of the bracket closing the one at `open`, counting `()`, `[]`
+    /// and `{}` together.
+    pub(super) fn matching_close(&self, open: usize) -> Option<usize> {
+        let mut depth = 0usize;
+        for (index, token) in self.tokens.iter().enumerate().skip(open) {
+            if token.kind != TokenKind::Punct {
+                continue;
+            }
+            match token.text {
+                "(" | "[" | "{" => depth += 1,
+                ")" | "]" | "}" => {
+                    depth = depth.saturating_sub(1);
+                    if depth == 0 {
+                        return Some(index);
+                    }
+                }
+                _ => {}
+            }
+        }
+        None
+    }
+
+    /// Index of the `;` ending the statement at `from` (outside brackets), or
+    /// the last token.
+    pub(super) fn statement_end(&self, from: usize) -> usize {
+        let mut depth = 0usize;
+        for (index, token) in self.tokens.iter().enumerate().skip(from) {
+            if token.kind != TokenKind::Punct {
+                continue;
+            }
+            match token.text {
+                "(" | "[" | "{" => depth += 1,
+                ")" | "]" | "}" => depth = depth.saturating_sub(1),
+                ";" if depth == 0 => return index,
+                _ => {}
+            }
+        }
+        self.tokens.len().saturating_sub(1)
+    }
+
+    /// Split `tokens[from..to]` at commas outside brackets.
+    pub(super) fn split_top_level(&self, from: usize, to: usize) -> Vec<(usize, usize)> {
+        let mut segments = Vec::new();
+        let mut depth = 0usize;
+        let mut segment_start = from;
+        for index in from..to.min(self.tokens.len()) {
+            let token = &self.tokens[index];
+            if token.kind != TokenKind::Punct {
+                continue;
+            }
+            match token.text {
+                "(" | "[" | "{" => depth += 1,
+                ")" | "]" | "}" => depth = depth.saturating_sub(1),
+                "," if depth == 0 => {
+                    if index > segment_start {
+                        segments.push((segment_start, index));
+                    }
+                    segment_start = index + 1;
+                }
+                _ => {}
+            }
+        }
+        if to > segment_start {
+            segments.push((segment_start, to.min(self.tokens.len())));
+        }
+        segments
+    }
+
+    /// The last word in `tokens[from..to]` outside brackets: the declared
+    /// name in `logic [7:0] data [4]`.
+    pub(super) fn last_top_level_word(&self, from: usize, to: usize) -> Option<usize> {
+        let mut depth = 0usize;
+        let mut last = None;
+        for index in from..to.min(self.tokens.len()) {
+            let token = &self.tokens[index];
+            match (token.kind, token.text) {
+                (TokenKind::Punct, "(" | "[" | "{") => depth += 1,
+                (TokenKind::Punct, ")" | "]" | "}") => depth = depth.saturating_sub(1),
+                (TokenKind::Word, _) if depth == 0 => last = Some(index),
+                _ => {}
+            }
+        }
+        last
+    }
+
+    /// Source text of `tokens[from..to]`, with comments dropped and
+    /// whitespace collapsed to single spaces.
+    pub(super) fn text(&self, from: usize, to: usize) -> String {
+        let mut text = String::new();
+        let mut previous_end = None;
+        for token in self
+            .tokens
+            .get(from..to.min(self.tokens.len()))
+            .unwrap_or(&[])
+        {
+            if previous_end.is_some_and(|end| end < token.start) {
+                text.push(' ');
+            }
+            text.push_str(&self.content[token.start..token.end]);
+            previous_end = Some(token.end);
+        }
+        text
+    }
+
+    /// Advance past the next `keyword` and its `: label`.
+    pub(super) fn skip_past(&mut self, keyword: &str) {
+        let tokens = self.tokens;
+        while let Some(token) = tokens.get(self.pos) {
+            self.pos += 1;
+            if token.kind == TokenKind::Word && token.text == keyword {
+                self.skip_label();
+                return;
+            }
+        }
+    }
+
+    /// Skip a `: label` after `begin`, `end` or an end keyword.
+    pub(super) fn skip_label(&mut self) {
+        if self.punct_at(self.pos, ":") && self.word_at(self.pos + 1).is_some() {
+            self.pos += 2;
+        }
+    }
+
+    // -- comments -----------------------------------------------------------
+
+    /// The block of comments directly above `tokens[index]`, joined. Comments
+    /// trailing code, or separated from the token by a blank line, are not
+    /// documentation.
+    pub(super) fn doc_before(&self, index: usize) -> Option<String> {
+        let token = &self.tokens[index];
+        let previous_end = index
+            .checked_sub(1)
+            .map_or(0, |previous| self.tokens[previous].end);
+        let before = self
+            .comments
+            .partition_point(|comment| comment.start < token.start);
+        let mut block = Vec::new();
+        let mut next_line = token.line;
+        for comment in self.comments[..before].iter().rev() {
+            if comment.start < previous_end || comment.trailing || comment.end_line + 1 < next_line
+            {
+                break;
+            }
+            block.push(comment);
+            next_line = comment.line;
+        }
+        let lines: Vec<String> = block
+            .iter()
+            .rev()
+            .flat_map(|comment| comment_lines(comment.text))
+            .collect();
+        (!lines.is_empty()).then(|| lines.join("\n"))
+    }
+
+    /// Documentation for a port or parameter: the comments above it, or a
+    /// comment trailing it on its last line (`input clk, // clock`).
+    pub(super) fn item_doc(&self, first: usize, last: usize) -> Option<String> {
+        self.doc_before(first).or_else(|| {
+            let token = &self.tokens[last.min(self.tokens.len() - 1)];
+            let next_word = self.tokens[last + 1..]
+                .iter()
+                .find(|token| token.kind == TokenKind::Word)
+                .map_or(usize::MAX, |token| token.start);
+            self.comments
+                .iter()
+                .find(|comment| {
+                    comment.trailing
+                        && comment.line == token.line
+                        && comment.start > token.end
+                        && comment.start < next_word
+                })
+                .map(|comment| comment_lines(comment.text).join("\n"))
+                .filter(|doc| !doc.is_empty())
+        })
+    }
+
+    // -- symbols ------------------------------------------------------------
+
+    pub(super) fn set_end(&mut self, index: usize, last: usize) {
+        let Some(last) = self.tokens.get(last) else {
+            return;
+        };
+        let symbol = &mut self.symbols[index];
+        symbol.end_line = last.line;
+        symbol.end_column = last.column + (last.end - last.start) as u32;
+        symbol.end_byte = last.end as u32;
+    }
+
+    pub(super) fn push_symbol(&mut self, spec: SymbolSpec, first: usize, last: usize) -> usize {
+        let first = &self.tokens[first];
+        let last = &self.tokens[last.min(self.tokens.len() - 1)];
+        let parent = spec.parent.map(|index| &self.symbols[index]);
+        let parent_id = parent.map(|parent| parent.id.clone());
+        let full_name = match parent {
+            Some(parent) => format!("{}.{}", parent.name, spec.name),
+            None => spec.name.clone(),
+        };
+
+        let mut info = json!({ "kind": spec.hdl_kind });
+        if let (Value::Object(info), Value::Object(extra)) = (&mut info, spec.extra) {
+            info.extend(extra.into_iter().filter(|(_, value)| !value.is_null()));
+        }
+
+        // Generate blocks can repeat an instance name; keep ids unique.
+        let base = format!("{}:verilog:{}:{}", self.file_path, spec.hdl_kind, full_name);
+        let mut id_source = base.clone();
+        let mut duplicate = 1;
+        while !self.ids.insert(id_source.clone()) {
+            duplicate += 1;
+            id_source = format!("{base}#{duplicate}");
+        }
+        let id = blake3::hash(id_source.as_bytes()).to_hex()[..32].to_string();
+
+        self.symbols.push(Symbol {
+            id,
+            name: spec.name,
+            kind: spec.kind,
+            language: self.language.to_string(),
+            file_path: self.file_path.to_string(),
+            start_line: first.line,
+            start_column: first.column,
+            end_line: last.line,
+            end_column: last.column + (last.end - last.start) as u32,
+            start_byte: first.start as u32,
+            end_byte: last.end as u32,
+            signature: Some(spec.signature),
+            doc_comment: spec.doc,
+            visibility: None,
+            parent_id,
+            metadata: Some(HashMap::from([(VERILOG_METADATA_KEY.to_string(), info)])),
+            semantic_group: None,
+            confidence: None,
+            code_context: Some(self.content[first.start..last.end].to_string()),
+            content_type: None,
+            body_span: None,
+            body_hash: None,
+            annotations: Vec::new(),
+        });
+        self.symbols.len() - 1
+    }
+}
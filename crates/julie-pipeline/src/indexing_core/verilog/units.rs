@@ -0,0 +1,306 @@
+//! Modules, interfaces, packages and programs with their parameters and
+//! ports, and the tasks and functions inside them.
+
+use super::*;
+
+impl<'a> Parser<'a, '_> {
+    /// `module name [import ...;] [#(params)] [(ports)]; ... endmodule`, and
+    /// likewise for interfaces, programs and packages.
+    pub(super) fn parse_unit(&mut self, keyword: &str) {
+        let start = self.pos;
+        let (unit_kind, end_keyword, kind) = match keyword {
+            "interface" => ("interface", "endinterface", SymbolKind::Interface),
+            "program" => ("program", "endprogram", SymbolKind::Module),
+            "package" => ("package", "endpackage", SymbolKind::Namespace),
+            _ => ("module", "endmodule", SymbolKind::Module),
+        };
+        let mut cursor = start + 1;
+        while matches!(self.word_at(cursor), Some("static" | "automatic")) {
+            cursor += 1;
+        }
+        let Some(name) = self.word_at(cursor) else {
+            self.pos += 1;
+            return;
+        };
+        let doc = self.doc_before(start);
+        let unit = self.push_symbol(
+            SymbolSpec {
+                name: name.to_string(),
+                kind,
+                hdl_kind: unit_kind,
+                signature: format!("{unit_kind} {name}"),
+                parent: None,
+                doc,
+                extra: Value::Null,
+            },
+            start,
+            cursor,
+        );
+
+        // Header.
+        self.pos = cursor + 1;
+        loop {
+            if self.word_at(self.pos) == Some("import") {
+                self.pos = self.statement_end(self.pos) + 1;
+            } else if self.punct_at(self.pos, "#") && self.punct_at(self.pos + 1, "(") {
+                let Some(close) = self.matching_close(self.pos + 1) else {
+                    break;
+                };
+                let segments = self.split_top_level(self.pos + 2, close);
+                self.parse_parameters(&segments, unit);
+                self.pos = close + 1;
+            } else if self.punct_at(self.pos, "(") {
+                let Some(close) = self.matching_close(self.pos) else {
+                    break;
+                };
+                let segments = self.split_top_level(self.pos + 1, close);
+                self.parse_ports(&segments, unit);
+                self.pos = close + 1;
+            } else {
+                if self.punct_at(self.pos, ";") {
+                    self.pos += 1;
+                }
+                break;
+            }
+        }
+        // Body. A unit missing its end keyword runs to end of file.
+        let tokens = self.tokens;
+        let mut statement_start = true;
+        let mut last = tokens.len() - 1;
+        while let Some(token) = tokens.get(self.pos) {
+            if token.kind == TokenKind::Word && token.text == end_keyword {
+                last = self.pos;
+                self.pos += 1;
+                self.skip_label();
+                break;
+            }
+            if statement_start && token.kind == TokenKind::Word {
+                let before = self.pos;
+                match token.text {
+                    "parameter" | "localparam" => {
+                        let end = self.statement_end(self.pos);
+                        let segments = self.split_top_level(self.pos, end);
+                        self.parse_parameters(&segments, unit);
+                        self.pos = end + 1;
+                    }
+                    "input" | "output" | "inout" | "ref" => {
+                        let end = self.statement_end(self.pos);
+                        let segments = self.split_top_level(self.pos, end);
+                        self.parse_ports(&segments, unit);
+                        self.pos = end + 1;
+                    }
+                    "function" | "task" => self.parse_subroutine(Some(unit)),
+                    "class" => self.skip_past("endclass"),
+                    "virtual" | "interface" if self.word_at(self.pos + 1) == Some("class") => {
+                        self.skip_past("endclass")
+                    }
+                    word if !is_keyword(word) && !word.starts_with('`') => {
+                        self.parse_instantiation(unit)
+                    }
+                    _ => {}
+                }
+                if self.pos != before {
+                    continue;
+                }
+            }
+            statement_start = match token.kind {
+                TokenKind::Punct => token.text == ";",
+                TokenKind::Word => matches!(
+                    token.text,
+                    "begin" | "end" | "generate" | "endgenerate" | "else" | "endcase"
+                ),
+                _ => false,
+            };
+            self.pos += 1;
+            if matches!(token.text, "begin" | "end") {
+                self.skip_label();
+            }
+        }
+        self.set_end(unit, last);
+        self.symbols[unit].signature = Some(self.unit_signature(unit, unit_kind, name));
+    }
+
+    /// `module fifo #(WIDTH, DEPTH) (clk, din, dout)`: parameter and port
+    /// names only, so the signature stays one line. Localparams are left out.
+    fn unit_signature(&self, unit: usize, unit_kind: &str, name: &str) -> String {
+        let unit_id = &self.symbols[unit].id;
+        let names = |hdl_kind: &str| -> Vec<&str> {
+            self.symbols
+                .iter()
+                .filter(|symbol| {
+                    symbol.parent_id.as_ref() == Some(unit_id)
+                        && symbol_info(symbol)
+                            .is_some_and(|info| info["kind"] == hdl_kind && info["local"] != true)
+                })
+                .map(|symbol| symbol.name.as_str())
+                .collect()
+        };
+        let mut signature = format!("{unit_kind} {name}");
+        let parameters = names("parameter");
+        if !parameters.is_empty() {
+            signature.push_str(&format!(" #({})", parameters.join(", ")));
+        }
+        let ports = names("port");
+        if !ports.is_empty() {
+            signature.push_str(&format!(" ({})", ports.join(", ")));
+        }
+        signature
+    }
+
+    /// Parameter declarations, one per segment: `parameter int WIDTH = 8`,
+    /// then `DEPTH = 16` inheriting the keyword and type of the one before.
+    fn parse_parameters(&mut self, segments: &[(usize, usize)], unit: usize) {
+        let mut keyword = "parameter";
+        let mut param_type = String::new();
+        for &(first, end) in segments {
+            let mut cursor = first;
+            if let Some(word @ ("parameter" | "localparam")) = self.word_at(cursor) {
+                keyword = word;
+                cursor += 1;
+                param_type.clear();
+            }
+            let type_parameter = self.word_at(cursor) == Some("type");
+            let equals = (cursor..end).find(|&index| self.punct_at(index, "="));
+            let Some(name_index) = self.last_top_level_word(cursor, equals.unwrap_or(end)) else {
+                continue;
+            };
+            if name_index > cursor {
+                param_type = self.text(cursor, name_index);
+            }
+            let name = self.tokens[name_index].text;
+            let default = equals.map(|equals| self.text(equals + 1, end));
+
+            let mut signature = keyword.to_string();
+            if !param_type.is_empty() {
+                signature.push(' ');
+                signature.push_str(&param_type);
+            }
+            signature.push(' ');
+            signature.push_str(name);
+            if let Some(default) = &default {
+                signature.push_str(" = ");
+                signature.push_str(default);
+            }
+            let doc = self.item_doc(first, end.saturating_sub(1));
+            self.push_symbol(
+                SymbolSpec {
+                    name: name.to_string(),
+                    kind: if type_parameter {
+                        SymbolKind::Type
+                    } else {
+                        SymbolKind::Constant
+                    },
+                    hdl_kind: "parameter",
+                    signature,
+                    parent: Some(unit),
+                    doc,
+                    extra: json!({
+                        "local": keyword == "localparam",
+                        "type": (!param_type.is_empty()).then(|| param_type.clone()),
+                        "default": default,
+                    }),
+                },
+                first,
+                end.saturating_sub(1),
+            );
+        }
+    }
+
+    /// Port declarations, one per segment: `input logic [7:0] data` (ANSI
+    /// header or body declaration), `b` continuing the previous direction,
+    /// or `axi_if.master bus` for an interface port. Bare names in a
+    /// non-ANSI header (`module m(a, b);`) are skipped; their body
+    /// declarations define them.
+    fn parse_ports(&mut self, segments: &[(usize, usize)], unit: usize) {
+        let mut direction: Option<&str> = None;
+        let mut port_type = String::new();
+        for &(first, end) in segments {
+            if self.punct_at(first, ".") {
+                continue;
+            }
+            let mut cursor = first;
+            let declares = match self.word_at(cursor) {
+                Some(word @ ("input" | "output" | "inout" | "ref")) => {
+                    direction = Some(word);
+                    cursor += 1;
+                    true
+                }
+                _ => false,
+            };
+            let equals = (cursor..end).find(|&index| self.punct_at(index, "="));
+            let Some(name_index) = self.last_top_level_word(cursor, equals.unwrap_or(end)) else {
+                continue;
+            };
+            if declares || name_index > cursor {
+                port_type = self.text(cursor, name_index);
+                if !declares {
+                    // A type without a direction: an interface port.
+                    direction = None;
+                }
+            } else if direction.is_none() {
+                continue;
+            }
+            let name = self.tokens[name_index].text;
+
+            let signature = [direction.unwrap_or(""), port_type.as_str(), name]
+                .into_iter()
+                .filter(|part| !part.is_empty())
+                .collect::<Vec<_>>()
+                .join(" ");
+            let doc = self.item_doc(first, end.saturating_sub(1));
+            self.push_symbol(
+                SymbolSpec {
+                    name: name.to_string(),
+                    kind: SymbolKind::Field,
+                    hdl_kind: "port",
+                    signature,
+                    parent: Some(unit),
+                    doc,
+                    extra: json!({
+                        "direction": direction.unwrap_or("interface"),
+                        "type": (!port_type.is_empty()).then(|| port_type.clone()),
+                    }),
+                },
+                first,
+                end.saturating_sub(1),
+            );
+        }
+    }
+
+    /// `function [automatic] [type] name(args); ... endfunction`, likewise
+    /// for tasks.
+    pub(super) fn parse_subroutine(&mut self, parent: Option<usize>) {
+        let start = self.pos;
+        let (keyword, end_keyword) = if self.tokens[start].text == "task" {
+            ("task", "endtask")
+        } else {
+            ("function", "endfunction")
+        };
+        let header_end = self.statement_end(start);
+        let name_end = (start + 1..header_end)
+            .find(|&index| self.punct_at(index, "("))
+            .unwrap_or(header_end);
+        let Some(name_index) = self.last_top_level_word(start + 1, name_end) else {
+            self.pos += 1;
+            return;
+        };
+        let name = self.tokens[name_index].text;
+        let doc = self.doc_before(start);
+        let index = self.push_symbol(
+            SymbolSpec {
+                name: name.to_string(),
+                kind: SymbolKind::Function,
+                hdl_kind: keyword,
+                signature: self.text(start, header_end),
+                parent,
+                doc,
+                extra: Value::Null,
+            },
+            start,
+            header_end.min(self.tokens.len() - 1),
+        );
+        self.pos = header_end;
+        self.skip_past(end_keyword);
+        self.set_end(index, self.pos.saturating_sub(1));
+    }
+}
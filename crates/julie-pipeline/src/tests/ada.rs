@@ -0,0 +1,423 @@
+// Tests for Ada and SPARK extraction (indexing_core::ada)
+
+use crate::indexing_core::ada::{ADA_METADATA_KEY, extract_ada};
+use crate::indexing_core::csharp_partials::PARTIAL_GROUP_METADATA_KEY;
+use julie_extractors::base::Visibility;
+use julie_extractors::{ExtractionResults, IdentifierKind, RelationshipKind, Symbol, SymbolKind};
+
+const AUTOPILOT_SPEC: &str = r#"with Ada.Real_Time;
+with Flight.Sensors;
+
+--  Holds the commanded attitude and altitude.
+package Flight.Autopilot
+  with SPARK_Mode => On
+is
+   type Mode is (Off, Hold_Altitude, Hold_Heading);
+
+   type Knots is new Float range 0.0 .. 600.0;
+
+   --  Target values for the control laws.
+   type Targets is record
+      Altitude : Float := 0.0;  -- feet above mean sea level
+      Speed    : Knots;
+   end record;
+
+   Max_Bank : constant Float := 30.0;
+   Engage_Error : exception;
+
+   procedure Engage (M : Mode; T : in out Targets)
+     with Pre  => M /= Off,
+          Post => Current_Mode = M;
+
+   function Current_Mode return Mode;
+   --  Mode last engaged.
+
+   function "<" (L, R : Targets) return Boolean;
+
+private
+   Active : Mode := Off;
+end Flight.Autopilot;
+"#;
+
+const AUTOPILOT_BODY: &str = r#"with Flight.Sensors.Filters;
+
+package body Flight.Autopilot
+  with SPARK_Mode => On
+is
+   function Current_Mode return Mode is (Active);
+
+   function "<" (L, R : Targets) return Boolean is
+   begin
+      return L.Altitude < R.Altitude;
+   end "<";
+
+   procedure Trim (Bank : Float) is separate;
+
+   procedure Engage (M : Mode; T : in out Targets) is
+      Reading : Float := Sensors.Altitude;
+      Samples : array (1 .. 4) of Float;
+   begin
+      Samples (1) := Reading;
+      if Reading < 0.0 then
+         raise Engage_Error;
+      end if;
+      Active := M;
+      T.Speed := Float (Filters.Smooth (Samples));
+      Trim (Bank => Max_Bank);
+      Log;
+      declare
+         Level : Float := Reading;
+      begin
+         Sensors.Reset (Level);
+      end;
+   end Engage;
+end Flight.Autopilot;
+"#;
+
+const TRIM_SUBUNIT: &str = r#"separate (Flight.Autopilot)
+procedure Trim (Bank : Float) is
+begin
+   Rudder.Apply (Bank);
+end Trim;
+"#;
+
+fn find<'a>(symbols: &'a [Symbol], name: &str) -> &'a Symbol {
+    symbols
+        .iter()
+        .find(|symbol| symbol.name == name)
+        .unwrap_or_else(|| panic!("missing symbol {name}"))
+}
+
+fn info(symbol: &Symbol, key: &str) -> serde_json::Value {
+    symbol.metadata.as_ref().unwrap()[ADA_METADATA_KEY][key].clone()
+}
+
+fn group(symbol: &Symbol) -> Option<&str> {
+    symbol.metadata.as_ref().unwrap()[PARTIAL_GROUP_METADATA_KEY].as_str()
+}
+
+/// `(from, to, kind)` names of each resolved relationship.
+fn edges(results: &ExtractionResults) -> Vec<(&str, &str, RelationshipKind)> {
+    let name = |id: &str| {
+        results
+            .symbols
+            .iter()
+            .find(|symbol| symbol.id == id)
+            .map_or("?", |symbol| symbol.name.as_str())
+    };
+    results
+        .relationships
+        .iter()
+        .map(|rel| {
+            (
+                name(&rel.from_symbol_id),
+                name(&rel.to_symbol_id),
+                rel.kind.clone(),
+            )
+        })
+        .collect()
+}
+
+#[test]
+fn extracts_package_specs_with_types_subprograms_and_contracts() {
+    let results = extract_ada("src/flight-autopilot.ads", AUTOPILOT_SPEC).unwrap();
+    let package = find(&results.symbols, "Autopilot");
+    assert_eq!(package.kind, SymbolKind::Module);
+    assert_eq!(package.language, "ada");
+    assert_eq!((package.start_line, package.end_line), (5, 32));
+    assert_eq!(
+        package.doc_comment.as_deref(),
+        Some("Holds the commanded attitude and altitude.")
+    );
+    assert_eq!(info(package, "unit"), "Flight.Autopilot");
+    assert_eq!(info(package, "part"), "spec");
+    assert_eq!(
+        info(package, "aspects"),
+        serde_json::json!({ "SPARK_Mode": "On" })
+    );
+
+    let sensors = find(&results.symbols, "Flight.Sensors");
+    assert_eq!(sensors.kind, SymbolKind::Import);
+    assert_eq!(info(sensors, "kind"), "with");
+
+    let mode = find(&results.symbols, "Mode");
+    assert_eq!(mode.kind, SymbolKind::Enum);
+    assert_eq!(
+        mode.signature.as_deref(),
+        Some("type Mode is (Off, Hold_Altitude, Hold_Heading)")
+    );
+    let hold = find(&results.symbols, "Hold_Altitude");
+    assert_eq!(hold.kind, SymbolKind::EnumMember);
+    assert_eq!(hold.parent_id.as_ref(), Some(&mode.id));
+
+    let targets = find(&results.symbols, "Targets");
+    assert_eq!(targets.kind, SymbolKind::Struct);
+    assert_eq!((targets.start_line, targets.end_line), (13, 16));
+    assert_eq!(
+        targets.doc_comment.as_deref(),
+        Some("Target values for the control laws.")
+    );
+    let altitude = find(&results.symbols, "Altitude");
+    assert_eq!(altitude.kind, SymbolKind::Field);
+    assert_eq!(altitude.parent_id.as_ref(), Some(&targets.id));
+    assert_eq!(
+        altitude.doc_comment.as_deref(),
+        Some("feet above mean sea level")
+    );
+    assert_eq!(info(find(&results.symbols, "Speed"), "type"), "Knots");
+
+    assert_eq!(
+        find(&results.symbols, "Max_Bank").kind,
+        SymbolKind::Constant
+    );
+    assert_eq!(
+        info(find(&results.symbols, "Engage_Error"), "kind"),
+        "exception"
+    );
+
+    let engage = find(&results.symbols, "Engage");
+    assert_eq!(engage.kind, SymbolKind::Function);
+    assert_eq!(engage.parent_id.as_ref(), Some(&package.id));
+    assert_eq!(engage.visibility, Some(Visibility::Public));
+    assert_eq!(
+        engage.signature.as_deref(),
+        Some("procedure Engage (M : Mode; T : in out Targets)")
+    );
+    assert_eq!(
+        info(engage, "parameters"),
+        serde_json::json!([
+            { "name": "M", "mode": "in", "type": "Mode" },
+            { "name": "T", "mode": "in out", "type": "Targets" },
+        ])
+    );
+    assert_eq!(
+        info(engage, "aspects"),
+        serde_json::json!({ "Pre": "M /= Off", "Post": "Current_Mode = M" })
+    );
+
+    let current = find(&results.symbols, "Current_Mode");
+    assert_eq!(info(current, "returns"), "Mode");
+    assert_eq!(current.doc_comment.as_deref(), Some("Mode last engaged."));
+    assert_eq!(find(&results.symbols, "\"<\"").kind, SymbolKind::Operator);
+
+    let active = find(&results.symbols, "Active");
+    assert_eq!(active.kind, SymbolKind::Variable);
+    assert_eq!(active.visibility, Some(Visibility::Private));
+
+    let edges = edges(&results);
+    assert!(edges.contains(&("Speed", "Knots", RelationshipKind::Uses)));
+    assert!(edges.contains(&("Active", "Mode", RelationshipKind::Uses)));
+}
+
+#[test]
+fn spec_and_body_declarations_share_a_partial_group() {
+    let spec = extract_ada("src/flight-autopilot.ads", AUTOPILOT_SPEC).unwrap();
+    let body = extract_ada("src/flight-autopilot.adb", AUTOPILOT_BODY).unwrap();
+    let subunit = extract_ada("src/flight-autopilot-trim.adb", TRIM_SUBUNIT).unwrap();
+
+    for (name, completion) in [
+        ("Autopilot", "body"),
+        ("Engage", "body"),
+        ("Current_Mode", "expression"),
+        ("\"<\"", "body"),
+    ] {
+        let declared = find(&spec.symbols, name);
+        let completed = find(&body.symbols, name);
+        assert!(group(declared).is_some(), "{name}");
+        assert_eq!(group(declared), group(completed), "{name}");
+        assert_ne!(declared.id, completed.id, "{name}");
+        assert_eq!(info(declared, "part"), "spec", "{name}");
+        assert_eq!(info(completed, "part"), completion, "{name}");
+    }
+    assert_eq!(
+        group(find(&spec.symbols, "Engage")),
+        Some("ada:flight.autopilot.engage(mode;targets)")
+    );
+    assert_eq!(
+        group(find(&spec.symbols, "Current_Mode")),
+        Some("ada:flight.autopilot.current_mode( return mode)")
+    );
+
+    // The stub in the body and the subunit in its own file are one procedure.
+    let stub = find(&body.symbols, "Trim");
+    let trim = find(&subunit.symbols, "Trim");
+    assert_eq!(info(stub, "part"), "stub");
+    assert_eq!(info(trim, "separate"), "flight.autopilot");
+    assert_eq!(group(stub), Some("ada:flight.autopilot.trim(float)"));
+    assert_eq!(group(stub), group(trim));
+}
+
+#[test]
+fn calls_and_with_clauses_link_bodies_to_their_targets() {
+    let results = extract_ada("src/flight-autopilot.adb", AUTOPILOT_BODY).unwrap();
+    let edges = edges(&results);
+    assert!(edges.contains(&("Engage", "Trim", RelationshipKind::Calls)));
+
+    // Indexing an object, converting to a type and raising an exception are
+    // not calls.
+    let engage = find(&results.symbols, "Engage");
+    let calls: Vec<&str> = results
+        .identifiers
+        .iter()
+        .filter(|identifier| {
+            identifier.kind == IdentifierKind::Call
+                && identifier.containing_symbol_id.as_ref() == Some(&engage.id)
+        })
+        .map(|identifier| identifier.name.as_str())
+        .collect();
+    assert_eq!(calls, vec!["Smooth", "Trim", "Log", "Reset"]);
+
+    let pending: Vec<_> = results
+        .pending_relationships
+        .iter()
+        .map(|pending| (pending.callee_name.as_str(), pending.kind.clone()))
+        .collect();
+    assert_eq!(
+        pending,
+        vec![
+            ("Filters", RelationshipKind::Imports),
+            ("Smooth", RelationshipKind::Calls),
+            ("Log", RelationshipKind::Calls),
+            ("Reset", RelationshipKind::Calls),
+        ]
+    );
+
+    let subunit = extract_ada("src/flight-autopilot-trim.adb", TRIM_SUBUNIT).unwrap();
+    assert_eq!(subunit.pending_relationships[0].callee_name, "Apply");
+}
+
+#[test]
+fn extracts_tagged_types_protected_units_and_instances() {
+    let source = r#"generic
+   type Element is private;
+package Buffers is
+   procedure Put (Item : Element);
+end Buffers;
+
+package Devices is
+   type Device is limited interface;
+   procedure Reset (D : in out Device) is abstract;
+
+   type Sensor is abstract tagged record
+      Id : Natural;
+   end record;
+
+   type Gyro is new Sensor and Device with record
+      Rate : Float;
+   end record;
+
+   overriding procedure Reset (G : in out Gyro);
+
+   package Float_Buffers is new Buffers (Float);
+
+   protected type Mailbox is
+      entry Get (V : out Float);
+      procedure Put (V : Float);
+   private
+      Value : Float;
+      Full  : Boolean := False;
+   end Mailbox;
+end Devices;
+
+package body Devices is
+   protected body Mailbox is
+      entry Get (V : out Float) when Full is
+      begin
+         V := Value;
+         Full := False;
+      end Get;
+
+      procedure Put (V : Float) is
+      begin
+         Value := V;
+         Full := True;
+      end Put;
+   end Mailbox;
+
+   procedure Reset (G : in out Gyro) is
+   begin
+      G.Rate := 0.0;
+      Float_Buffers.Put (G.Rate);
+   end Reset;
+end Devices;
+"#;
+    let results = extract_ada("src/devices.ada", source).unwrap();
+    let buffers = find(&results.symbols, "Buffers");
+    assert_eq!(info(buffers, "generic"), true);
+
+    let device = find(&results.symbols, "Device");
+    assert_eq!(device.kind, SymbolKind::Interface);
+    assert_eq!(find(&results.symbols, "Sensor").kind, SymbolKind::Class);
+    let gyro = find(&results.symbols, "Gyro");
+    assert_eq!(gyro.kind, SymbolKind::Class);
+    assert_eq!(gyro.end_line, 17);
+    let rate = find(&results.symbols, "Rate");
+    assert_eq!(rate.parent_id.as_ref(), Some(&gyro.id));
+
+    let instance = find(&results.symbols, "Float_Buffers");
+    assert_eq!(info(instance, "kind"), "package_instantiation");
+    assert_eq!(info(instance, "generic"), "Buffers");
+
+    let mailboxes: Vec<&Symbol> = results
+        .symbols
+        .iter()
+        .filter(|symbol| symbol.name == "Mailbox")
+        .collect();
+    assert_eq!(mailboxes.len(), 2);
+    assert!(mailboxes.iter().all(|m| m.kind == SymbolKind::Class));
+    assert_eq!(group(mailboxes[0]), group(mailboxes[1]));
+    let entries: Vec<&Symbol> = results
+        .symbols
+        .iter()
+        .filter(|symbol| symbol.name == "Get")
+        .collect();
+    assert_eq!(entries.len(), 2);
+    assert!(entries.iter().all(|e| e.kind == SymbolKind::Method));
+    assert_eq!(entries[0].parent_id.as_ref(), Some(&mailboxes[0].id));
+    assert_eq!(entries[1].parent_id.as_ref(), Some(&mailboxes[1].id));
+    assert_eq!(group(entries[0]), Some("ada:devices.mailbox.get(float)"));
+    assert_eq!(group(entries[0]), group(entries[1]));
+    assert_eq!(
+        find(&results.symbols, "Value").visibility,
+        Some(Visibility::Private)
+    );
+
+    let resets: Vec<&Symbol> = results
+        .symbols
+        .iter()
+        .filter(|symbol| symbol.name == "Reset")
+        .collect();
+    assert_eq!(resets.len(), 3);
+    assert_eq!(info(resets[0], "part"), "abstract");
+    assert_ne!(group(resets[0]), group(resets[1]));
+    assert_eq!(group(resets[1]), group(resets[2]));
+
+    let edges = edges(&results);
+    assert!(edges.contains(&("Gyro", "Sensor", RelationshipKind::Extends)));
+    assert!(edges.contains(&("Gyro", "Device", RelationshipKind::Implements)));
+    assert!(edges.contains(&("Float_Buffers", "Buffers", RelationshipKind::Instantiates)));
+    assert!(edges.contains(&("Reset", "Put", RelationshipKind::Calls)));
+    assert!(results.pending_relationships.is_empty());
+}
+
+#[test]
+fn tolerates_attributes_character_literals_and_unterminated_units() {
+    let source = "PACKAGE BODY Parser IS\n   FUNCTION Is_Quote (C : Character) RETURN Boolean IS (C = '''');\n   PROCEDURE Scan (S : String) IS\n      N : Natural := S'Length;\n   BEGIN\n      IF Is_Quote (S (S'First)) THEN\n         Emit (Character'Val (N));\n      END IF;\n   END Scan;\n   PROCEDURE Half (X : Integer) IS\n   BEGIN\n      Scan (\"a\"\"b\";\n";
+    let results = extract_ada("src/parser.adb", source).unwrap();
+    assert_eq!(find(&results.symbols, "Parser").end_line, 12);
+    assert_eq!(find(&results.symbols, "Scan").end_line, 9);
+    assert_eq!(find(&results.symbols, "Half").end_line, 12);
+    assert_eq!(
+        edges(&results),
+        vec![
+            ("Scan", "Is_Quote", RelationshipKind::Calls),
+            ("Half", "Scan", RelationshipKind::Calls),
+        ]
+    );
+    let pending: Vec<&str> = results
+        .pending_relationships
+        .iter()
+        .map(|pending| pending.callee_name.as_str())
+        .collect();
+    assert_eq!(pending, vec!["Emit"]);
+}
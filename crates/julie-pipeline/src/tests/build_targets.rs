@@ -0,0 +1,275 @@
+// Tests for Make, CMake and Bazel build targets (indexing_core::build_targets)
+
+use crate::indexing_core::build_targets::{
+    BUILD_DEPENDENCY_CALLEE_PREFIX, BUILD_METADATA_KEY, BUILD_TARGET_SEMANTIC_GROUP, BuildSystem,
+    BuildTarget, extract_build_targets, is_build_dependency, parse_build_targets,
+    resolve_build_dependencies,
+};
+use julie_core::database::{FileInfo, SymbolDatabase};
+use julie_extractors::{RelationshipKind, Symbol, SymbolKind};
+use tempfile::TempDir;
+
+const MAKEFILE: &str = "\
+CC := gcc
+OBJS = main.o \\
+       util.o
+BIN ?= app
+
+.PHONY: all clean
+all: $(BIN)
+
+$(BIN): $(OBJS) | build
+\t$(CC) -o $@ $^
+
+main.o: main.c util.h
+\t$(CC) -c main.c
+util.o: util.c util.h ; $(CC) -c util.c
+%.o: %.c
+\t$(CC) -c $<
+clean:
+\trm -f $(OBJS) $(BIN)
+debug: CFLAGS += -g
+";
+
+const CMAKE: &str = r#"
+cmake_minimum_required(VERSION 3.20)
+project(demo C)
+set(CORE_SOURCES src/core.c src/io.c)
+add_library(core STATIC ${CORE_SOURCES})
+target_sources(core PRIVATE "${CMAKE_CURRENT_SOURCE_DIR}/src/extra.c")
+# add_library(ignored a.c)
+add_executable(demo WIN32
+  src/main.c
+)
+target_link_libraries(demo PRIVATE core m -lpthread ${LIBS} /usr/lib/libz.so Threads::Threads)
+add_library(demo::core ALIAS core)
+add_dependencies(demo generated)
+"#;
+
+const BUILD: &str = r#"
+load("@rules_cc//cc:defs.bzl", "cc_library", "cc_binary")
+
+package(default_visibility = ["//visibility:public"])
+
+# The HTTP client.
+cc_library(
+    name = "http",
+    srcs = ["http.cc", ":gen.cc"] + glob(["internal/*.cc"]),
+    hdrs = ["http.h"],
+    deps = [
+        ":socket",
+        "//base",
+        "//third_party/json:parser",
+        "@abseil-cpp//absl/strings",
+    ] + select({"//conditions:default": ["//never:seen"]}),
+)
+
+cc_library(name = "socket", srcs = ['socket.cc'])
+
+cc_binary(
+    name = "client",
+    srcs = ["main.cc"],
+    deps = [":http"],
+)
+"#;
+
+fn names(targets: &[BuildTarget]) -> Vec<&str> {
+    targets.iter().map(|target| target.name.as_str()).collect()
+}
+
+fn deps(target: &BuildTarget) -> Vec<&str> {
+    target.deps.iter().map(|dep| dep.label.as_str()).collect()
+}
+
+fn find<'a>(symbols: &'a [Symbol], name: &str) -> &'a Symbol {
+    symbols
+        .iter()
+        .find(|symbol| symbol.name == name)
+        .unwrap_or_else(|| panic!("missing symbol {name}"))
+}
+
+fn info(symbol: &Symbol) -> &serde_json::Value {
+    &symbol.metadata.as_ref().unwrap()[BUILD_METADATA_KEY]
+}
+
+#[test]
+fn make_rules_expand_variables_and_split_targets_from_sources() {
+    let targets = parse_build_targets(BuildSystem::Make, "native/Makefile", MAKEFILE);
+
+    // `.PHONY`, the pattern rule and the target-specific variable are not targets.
+    assert_eq!(names(&targets), ["all", "app", "main.o", "util.o", "clean"]);
+    assert_eq!(deps(&targets[0]), ["app"]);
+    assert_eq!(deps(&targets[1]), ["main.o", "util.o"]);
+    assert!(
+        targets[1].sources.is_empty(),
+        "order-only prerequisites are dropped"
+    );
+    assert_eq!(targets[2].sources, ["native/main.c", "native/util.h"]);
+    assert_eq!(targets[3].sources, ["native/util.c", "native/util.h"]);
+    assert_eq!(
+        &MAKEFILE[targets[1].start_byte..targets[1].end_byte],
+        "$(BIN): $(OBJS) | build\n\t$(CC) -o $@ $^",
+        "a rule spans its recipe"
+    );
+}
+
+#[test]
+fn cmake_targets_collect_sources_and_link_dependencies() {
+    let targets = parse_build_targets(BuildSystem::CMake, "lib/CMakeLists.txt", CMAKE);
+
+    assert_eq!(names(&targets), ["core", "demo"], "aliases are not targets");
+    assert_eq!(targets[0].rule, "add_library");
+    assert_eq!(
+        targets[0].sources,
+        ["lib/src/core.c", "lib/src/io.c", "lib/src/extra.c"]
+    );
+    assert_eq!(targets[1].sources, ["lib/src/main.c"]);
+    assert_eq!(
+        deps(&targets[1]),
+        ["core", "m", "Threads::Threads", "generated"],
+        "flags, paths and unevaluated variables are not dependencies"
+    );
+}
+
+#[test]
+fn bazel_rules_use_canonical_labels_and_skip_unevaluated_values() {
+    let targets = parse_build_targets(BuildSystem::Bazel, "net/BUILD.bazel", BUILD);
+
+    assert_eq!(names(&targets), ["http", "socket", "client"]);
+    let http = &targets[0];
+    assert_eq!(http.label, "//net:http");
+    assert_eq!(http.sources, ["net/http.cc", "net/gen.cc", "net/http.h"]);
+    assert_eq!(
+        deps(http),
+        ["//net:socket", "//base:base", "//third_party/json:parser"],
+        "external repositories and select() branches are skipped"
+    );
+    assert_eq!(targets[1].sources, ["net/socket.cc"]);
+
+    let root = parse_build_targets(
+        BuildSystem::Bazel,
+        "BUILD",
+        "py_binary(name = 'tool', srcs = ['tool.py'], deps = [':lib'])",
+    );
+    assert_eq!(root[0].label, "//:tool");
+    assert_eq!(root[0].sources, ["tool.py"]);
+    assert_eq!(deps(&root[0]), ["//:lib"]);
+}
+
+#[test]
+fn half_edited_build_files_still_parse() {
+    let bazel = parse_build_targets(BuildSystem::Bazel, "BUILD", "cc_library(name = \"x\"");
+    assert_eq!(names(&bazel), ["x"]);
+    let cmake = parse_build_targets(BuildSystem::CMake, "CMakeLists.txt", "add_library(x a.c");
+    assert_eq!(cmake[0].sources, ["a.c"]);
+    for system in [BuildSystem::Make, BuildSystem::CMake, BuildSystem::Bazel] {
+        for content in ["", "(", "a:\\", "\"open", "x = [", "#[[ open", "é(ü"] {
+            parse_build_targets(system, "pkg/BUILD", content);
+        }
+    }
+}
+
+#[test]
+fn targets_become_module_symbols_linked_to_their_dependencies() {
+    let results = extract_build_targets("net/BUILD.bazel", BUILD).unwrap();
+
+    let http = find(&results.symbols, "http");
+    assert_eq!(http.kind, SymbolKind::Module);
+    assert_eq!(http.language, "bazel");
+    assert_eq!(
+        http.semantic_group.as_deref(),
+        Some(BUILD_TARGET_SEMANTIC_GROUP)
+    );
+    assert_eq!(http.start_line, 7);
+    assert_eq!(http.signature.as_deref(), Some("cc_library("));
+    assert_eq!(info(http)["system"], "bazel");
+    assert_eq!(info(http)["rule"], "cc_library");
+    assert_eq!(info(http)["label"], "//net:http");
+
+    // Same-file dependencies resolve here.
+    let socket = find(&results.symbols, "socket");
+    let client = find(&results.symbols, "client");
+    let local: Vec<(&str, &str)> = results
+        .relationships
+        .iter()
+        .map(|rel| {
+            assert_eq!(rel.kind, RelationshipKind::Uses);
+            (rel.from_symbol_id.as_str(), rel.to_symbol_id.as_str())
+        })
+        .collect();
+    assert_eq!(
+        local,
+        [
+            (http.id.as_str(), socket.id.as_str()),
+            (client.id.as_str(), http.id.as_str())
+        ]
+    );
+
+    // Other packages are left for finalize.
+    let pending: Vec<&str> = results
+        .pending_relationships
+        .iter()
+        .map(|pending| {
+            assert!(is_build_dependency(pending));
+            pending.callee_name.as_str()
+        })
+        .collect();
+    assert_eq!(
+        pending,
+        [
+            format!("{BUILD_DEPENDENCY_CALLEE_PREFIX}bazel://base:base"),
+            format!("{BUILD_DEPENDENCY_CALLEE_PREFIX}bazel://third_party/json:parser"),
+        ]
+    );
+}
+
+#[test]
+fn cross_file_dependencies_resolve_within_the_same_build_system() {
+    let temp_dir = TempDir::new().unwrap();
+    let mut db = SymbolDatabase::new(temp_dir.path().join("test.db")).unwrap();
+
+    let files = [
+        (
+            "app/CMakeLists.txt",
+            "add_executable(app main.c)\ntarget_link_libraries(app core)\n",
+        ),
+        ("core/CMakeLists.txt", "add_library(core core.c)\n"),
+        ("legacy/Makefile", "core: core.c\n\tcc -c core.c\n"),
+    ];
+    let mut symbols = Vec::new();
+    let mut pendings = Vec::new();
+    for (path, content) in files {
+        let results = extract_build_targets(path, content).unwrap();
+        db.store_file_info(&FileInfo {
+            path: path.to_string(),
+            language: results.symbols[0].language.clone(),
+            hash: "h".to_string(),
+            size: content.len() as i64,
+            last_modified: 1000,
+            last_indexed: 0,
+            symbol_count: results.symbols.len() as i32,
+            line_count: 0,
+            content: None,
+        })
+        .unwrap();
+        symbols.extend(results.symbols);
+        pendings.extend(results.pending_relationships);
+    }
+    db.store_symbols_transactional(&symbols).unwrap();
+    assert_eq!(pendings.len(), 1);
+
+    let resolved = resolve_build_dependencies(&pendings, &db);
+    assert_eq!(
+        resolved.len(),
+        1,
+        "the Make target named core is not a CMake target"
+    );
+    let app = symbols.iter().find(|s| s.name == "app").unwrap();
+    let core = symbols
+        .iter()
+        .find(|s| s.name == "core" && s.language == "cmake")
+        .unwrap();
+    assert_eq!(resolved[0].from_symbol_id, app.id);
+    assert_eq!(resolved[0].to_symbol_id, core.id);
+    assert_eq!(resolved[0].line_number, 2);
+}
@@ -0,0 +1,220 @@
+// Tests for C/C++ macro expansion provenance (indexing_core::c_macros)
+
+use crate::indexing_core::c_macros::{
+    MACRO_EXPANSION_METADATA_KEY, VIA_MACRO_METADATA_KEY, annotate_macro_expansions,
+    link_macro_calls,
+};
+use julie_extractors::base::{Symbol, SymbolKind};
+use julie_extractors::{ExtractionResults, Identifier, IdentifierKind};
+
+const HANDLERS_C: &str = "\
+#include \"handlers.h\"
+
+#define DEFINE_HANDLER(name) \\
+    void name##_handler(struct request *req) { log_##name(req); } \\
+    static int name##_calls = 0;
+
+DEFINE_HANDLER(Foo)
+DEFINE_HANDLER(Bar)
+
+int dispatch(struct request *req) {
+    DEFINE_HANDLER(Local);
+    return Foo_handler(req);
+}
+
+REGISTER_PLUGIN(foo_plugin, 3);
+";
+
+fn sym(name: &str, kind: SymbolKind, line: u32, language: &str) -> Symbol {
+    Symbol {
+        id: format!("{name}_{line}"),
+        name: name.to_string(),
+        kind,
+        language: language.to_string(),
+        file_path: "src/handlers.c".to_string(),
+        start_line: line,
+        start_column: 0,
+        end_line: line,
+        end_column: 1,
+        start_byte: 0,
+        end_byte: 1,
+        signature: None,
+        doc_comment: None,
+        visibility: None,
+        parent_id: None,
+        metadata: None,
+        semantic_group: None,
+        confidence: None,
+        code_context: None,
+        content_type: None,
+        body_span: None,
+        body_hash: None,
+        annotations: Vec::new(),
+    }
+}
+
+fn expansion(symbol: &Symbol) -> Option<&serde_json::Value> {
+    symbol
+        .metadata
+        .as_ref()
+        .and_then(|metadata| metadata.get(MACRO_EXPANSION_METADATA_KEY))
+}
+
+fn find<'a>(symbols: &'a [Symbol], name: &str) -> &'a Symbol {
+    symbols
+        .iter()
+        .find(|s| s.name == name)
+        .unwrap_or_else(|| panic!("missing symbol {name}"))
+}
+
+fn c_symbols() -> Vec<Symbol> {
+    vec![
+        sym("DEFINE_HANDLER", SymbolKind::Constant, 3, "c"),
+        sym("DEFINE_HANDLER", SymbolKind::Function, 7, "c"),
+        sym("dispatch", SymbolKind::Function, 10, "c"),
+        sym("REGISTER_PLUGIN", SymbolKind::Function, 15, "c"),
+    ]
+}
+
+#[test]
+fn invocation_synthesizes_generated_symbols_at_call_site() {
+    let mut symbols = c_symbols();
+    annotate_macro_expansions(&mut symbols, HANDLERS_C);
+
+    let handler = find(&symbols, "Foo_handler");
+    assert_eq!(handler.kind, SymbolKind::Function);
+    assert_eq!(handler.start_line, 7);
+    assert_eq!(handler.signature.as_deref(), Some("DEFINE_HANDLER(Foo)"));
+    let provenance = expansion(handler).expect("generated symbol carries provenance");
+    assert_eq!(provenance["macro"], "DEFINE_HANDLER");
+    assert_eq!(provenance["invocation"], "DEFINE_HANDLER(Foo)");
+    assert_eq!(provenance["line"], 7);
+
+    assert_eq!(find(&symbols, "Foo_calls").kind, SymbolKind::Variable);
+    assert_eq!(find(&symbols, "Bar_handler").start_line, 8);
+    assert!(
+        symbols.iter().all(|s| s.name != "log_Foo"),
+        "calls inside the expanded body are not declarations"
+    );
+}
+
+#[test]
+fn invocation_site_symbol_lists_generated_names() {
+    let mut symbols = c_symbols();
+    annotate_macro_expansions(&mut symbols, HANDLERS_C);
+
+    let site = symbols
+        .iter()
+        .find(|s| s.name == "DEFINE_HANDLER" && s.start_line == 7)
+        .unwrap();
+    let provenance = expansion(site).unwrap();
+    assert_eq!(
+        provenance["generated"],
+        serde_json::json!(["Foo_handler", "Foo_calls"])
+    );
+
+    let definition = symbols
+        .iter()
+        .find(|s| s.name == "DEFINE_HANDLER" && s.start_line == 3)
+        .unwrap();
+    assert!(expansion(definition).is_none());
+}
+
+#[test]
+fn invocations_inside_function_bodies_are_ignored() {
+    let mut symbols = c_symbols();
+    annotate_macro_expansions(&mut symbols, HANDLERS_C);
+    assert!(symbols.iter().all(|s| s.name != "Local_handler"));
+}
+
+#[test]
+fn header_macro_invocation_is_tagged_without_expansion() {
+    let mut symbols = c_symbols();
+    annotate_macro_expansions(&mut symbols, HANDLERS_C);
+
+    let site = find(&symbols, "REGISTER_PLUGIN");
+    let provenance = expansion(site).unwrap();
+    assert_eq!(provenance["invocation"], "REGISTER_PLUGIN(foo_plugin, 3)");
+    assert_eq!(provenance["generated"], serde_json::json!([]));
+}
+
+#[test]
+fn synthesized_ids_are_stable_across_runs() {
+    let mut first = c_symbols();
+    let mut second = c_symbols();
+    annotate_macro_expansions(&mut first, HANDLERS_C);
+    annotate_macro_expansions(&mut second, HANDLERS_C);
+    assert_eq!(
+        find(&first, "Foo_handler").id,
+        find(&second, "Foo_handler").id
+    );
+}
+
+#[test]
+fn non_c_languages_are_untouched() {
+    let mut symbols = vec![sym("DEFINE_HANDLER", SymbolKind::Function, 7, "rust")];
+    annotate_macro_expansions(&mut symbols, HANDLERS_C);
+    assert_eq!(symbols.len(), 1);
+    assert!(expansion(&symbols[0]).is_none());
+}
+
+const CHECKED_C: &str = "\
+#define LOG(msg) write_log(__FILE__, msg)
+#define CHECK(x) do { if (!validate(x)) LOG(#x); } while (0)
+
+int validate(int x) { return x > 0; }
+
+void run(int a) {
+    CHECK(a);
+}
+";
+
+fn call(name: &str, containing: &str, line: u32) -> Identifier {
+    Identifier {
+        id: format!("{name}_{line}"),
+        name: name.to_string(),
+        kind: IdentifierKind::Call,
+        language: "c".to_string(),
+        file_path: "src/handlers.c".to_string(),
+        start_line: line,
+        start_column: 4,
+        end_line: line,
+        end_column: 9,
+        start_byte: 0,
+        end_byte: 5,
+        containing_symbol_id: Some(containing.to_string()),
+        target_symbol_id: None,
+        confidence: 1.0,
+        code_context: None,
+    }
+}
+
+#[test]
+fn calls_through_same_file_macros_become_edges() {
+    let mut results = ExtractionResults::empty();
+    results.symbols = vec![
+        sym("validate", SymbolKind::Function, 4, "c"),
+        sym("run", SymbolKind::Function, 6, "c"),
+    ];
+    results.identifiers = vec![call("CHECK", "run_6", 7), call("printf", "run_6", 7)];
+
+    assert_eq!(link_macro_calls(&mut results, CHECKED_C), 2);
+
+    // `validate` is local; `write_log` comes through `LOG` nested in `CHECK`.
+    assert_eq!(results.relationships.len(), 1);
+    let edge = &results.relationships[0];
+    assert_eq!(
+        (edge.from_symbol_id.as_str(), edge.to_symbol_id.as_str()),
+        ("run_6", "validate_4")
+    );
+    assert_eq!(
+        edge.metadata.as_ref().unwrap()[VIA_MACRO_METADATA_KEY],
+        "CHECK"
+    );
+    let pending: Vec<&str> = results
+        .pending_relationships
+        .iter()
+        .map(|pending| pending.callee_name.as_str())
+        .collect();
+    assert_eq!(pending, vec!["write_log"]);
+}
@@ -0,0 +1,107 @@
+// Tests for resumable, checkpointed extraction (indexing_core::checkpoint)
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use crate::indexing_core::checkpoint::{
+    ExtractionCheckpoints, FileCompletionMarker, extract_files_with_checkpoints,
+};
+use crate::indexing_core::extraction::extract_files_for_indexing_with_records;
+
+fn write_workspace(root: &Path, count: usize) -> HashMap<String, Vec<PathBuf>> {
+    let paths = (0..count)
+        .map(|i| {
+            let path = root.join(format!("src/file_{i}.rs"));
+            std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+            std::fs::write(&path, format!("pub fn function_{i}() {{}}\n")).unwrap();
+            path
+        })
+        .collect();
+    HashMap::from([("rust".to_string(), paths)])
+}
+
+fn chunk_files(dir: &Path) -> usize {
+    std::fs::read_dir(dir)
+        .map(|entries| entries.count())
+        .unwrap_or(0)
+}
+
+#[tokio::test]
+async fn interrupted_extraction_resumes_from_stored_chunks() {
+    let workspace = tempfile::tempdir().unwrap();
+    let index = tempfile::tempdir().unwrap();
+    let files = write_workspace(workspace.path(), 5);
+    let checkpoints = ExtractionCheckpoints::new(index.path().join("checkpoints"), "engine-v1");
+
+    let first = extract_files_with_checkpoints(files.clone(), workspace.path(), &checkpoints, 2)
+        .await
+        .unwrap();
+    assert_eq!(first.files_resumed, 0);
+    assert_eq!(first.batch.files_processed, 5);
+    assert_eq!(chunk_files(checkpoints.dir()), 3, "5 files in chunks of 2");
+
+    let (unchunked, _) = extract_files_for_indexing_with_records(files.clone(), workspace.path())
+        .await
+        .unwrap();
+    assert_eq!(first.batch.all_symbols.len(), unchunked.all_symbols.len());
+
+    // A restart sees the same files and loads every chunk.
+    let resumed = extract_files_with_checkpoints(files.clone(), workspace.path(), &checkpoints, 2)
+        .await
+        .unwrap();
+    assert_eq!(resumed.files_resumed, 5);
+    assert_eq!(resumed.batch.files_processed, 5);
+    assert_eq!(resumed.records.len(), 5);
+    assert_eq!(
+        resumed.batch.all_symbols.len(),
+        first.batch.all_symbols.len()
+    );
+
+    // Editing a file invalidates only the chunk that holds it.
+    std::fs::write(
+        workspace.path().join("src/file_4.rs"),
+        "pub fn renamed() {}\n",
+    )
+    .unwrap();
+    let edited = extract_files_with_checkpoints(files, workspace.path(), &checkpoints, 2)
+        .await
+        .unwrap();
+    assert_eq!(edited.files_resumed, 4);
+    assert!(
+        edited
+            .batch
+            .all_symbols
+            .iter()
+            .any(|symbol| symbol.name == "renamed")
+    );
+
+    checkpoints.clear().unwrap();
+    assert!(!checkpoints.dir().exists());
+    checkpoints.clear().unwrap();
+}
+
+#[test]
+fn chunks_from_another_engine_version_are_not_reused() {
+    let index = tempfile::tempdir().unwrap();
+    let dir = index.path().join("checkpoints");
+    let markers = vec![FileCompletionMarker {
+        relative_path: "src/lib.rs".to_string(),
+        content_hash: "abc".to_string(),
+    }];
+    let batch = crate::indexing_core::batch::ExtractedBatch::new();
+
+    let old = ExtractionCheckpoints::new(&dir, "engine-v1");
+    old.store(&markers, &batch, &[]).unwrap();
+    assert!(old.load(&markers).is_some());
+    assert!(
+        ExtractionCheckpoints::new(&dir, "engine-v2")
+            .load(&markers)
+            .is_none()
+    );
+
+    let changed = vec![FileCompletionMarker {
+        content_hash: "def".to_string(),
+        ..markers[0].clone()
+    }];
+    assert!(old.load(&changed).is_none());
+}
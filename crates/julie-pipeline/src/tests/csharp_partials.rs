@@ -0,0 +1,126 @@
+// Tests for C# partial type tagging (indexing_core::csharp_partials)
+
+use crate::indexing_core::csharp_partials::{
+    PARTIAL_GROUP_METADATA_KEY, annotate_partial_declarations,
+};
+use julie_extractors::base::{Symbol, SymbolKind, Visibility};
+
+fn sym(id: &str, name: &str, kind: SymbolKind, signature: &str, parent: Option<&str>) -> Symbol {
+    Symbol {
+        id: id.to_string(),
+        name: name.to_string(),
+        kind,
+        language: "csharp".to_string(),
+        file_path: "Services/OrderService.cs".to_string(),
+        start_line: 1,
+        start_column: 0,
+        end_line: 10,
+        end_column: 1,
+        start_byte: 0,
+        end_byte: 100,
+        signature: Some(signature.to_string()),
+        doc_comment: None,
+        visibility: Some(Visibility::Public),
+        parent_id: parent.map(str::to_string),
+        metadata: None,
+        semantic_group: None,
+        confidence: None,
+        code_context: None,
+        content_type: None,
+        body_span: None,
+        body_hash: None,
+        annotations: Vec::new(),
+    }
+}
+
+fn partial_group(symbol: &Symbol) -> Option<&str> {
+    symbol
+        .metadata
+        .as_ref()
+        .and_then(|metadata| metadata.get(PARTIAL_GROUP_METADATA_KEY))
+        .and_then(|value| value.as_str())
+}
+
+#[test]
+fn partial_class_gets_namespace_qualified_group() {
+    let mut symbols = vec![
+        sym(
+            "ns",
+            "Shop.Orders",
+            SymbolKind::Namespace,
+            "namespace Shop.Orders",
+            None,
+        ),
+        sym(
+            "outer",
+            "Outer",
+            SymbolKind::Class,
+            "public static partial class Outer",
+            Some("ns"),
+        ),
+        sym(
+            "inner",
+            "OrderService",
+            SymbolKind::Class,
+            "internal sealed partial class OrderService : IOrderService",
+            Some("outer"),
+        ),
+    ];
+
+    annotate_partial_declarations(&mut symbols);
+
+    assert_eq!(partial_group(&symbols[0]), None);
+    assert_eq!(partial_group(&symbols[1]), Some("Shop.Orders.Outer"));
+    assert_eq!(
+        partial_group(&symbols[2]),
+        Some("Shop.Orders.Outer.OrderService")
+    );
+}
+
+#[test]
+fn non_partial_and_partial_named_types_are_ignored() {
+    let mut symbols = vec![
+        sym(
+            "a",
+            "OrderService",
+            SymbolKind::Class,
+            "public class OrderService",
+            None,
+        ),
+        sym(
+            "b",
+            "PartialResult",
+            SymbolKind::Class,
+            "public class PartialResult",
+            None,
+        ),
+        sym(
+            "c",
+            "OnCreated",
+            SymbolKind::Method,
+            "partial void OnCreated()",
+            Some("a"),
+        ),
+    ];
+
+    annotate_partial_declarations(&mut symbols);
+
+    assert!(symbols.iter().all(|symbol| symbol.metadata.is_none()));
+}
+
+#[test]
+fn other_languages_are_ignored() {
+    let mut symbol = sym(
+        "a",
+        "Widget",
+        SymbolKind::Class,
+        "partial class Widget",
+        None,
+    );
+    symbol.language = "vbnet".to_string();
+    let mut symbols = vec![symbol];
+
+    annotate_partial_declarations(&mut symbols);
+
+    assert!(symbols[0].metadata.is_none());
+}
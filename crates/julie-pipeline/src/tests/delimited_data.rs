@@ -0,0 +1,97 @@
+// Tests for data file header columns (indexing_core::delimited_data)
+
+use crate::indexing_core::delimited_data::{
+    DELIMITED_DATA_METADATA_KEY, HEADER_SCAN_BYTES, annotate_delimited_data_headers,
+    parse_delimited_data_symbols, parse_header,
+};
+use julie_extractors::base::{Symbol, SymbolKind};
+
+fn names(symbols: &[Symbol]) -> Vec<&str> {
+    symbols.iter().map(|symbol| symbol.name.as_str()).collect()
+}
+
+#[test]
+fn csv_header_becomes_file_symbol_with_column_fields() {
+    let content = "order_id,customer_id,\"total, gross\"\n1,42,9.99\n";
+    let symbols = parse_delimited_data_symbols("data/orders.csv", "csv", content);
+
+    assert_eq!(
+        names(&symbols),
+        vec!["orders", "order_id", "customer_id", "total, gross"]
+    );
+    let file = &symbols[0];
+    assert_eq!(file.kind, SymbolKind::Module);
+    assert_eq!(
+        file.signature.as_deref(),
+        Some("order_id, customer_id, total, gross")
+    );
+    assert_eq!(
+        file.metadata.as_ref().unwrap()[DELIMITED_DATA_METADATA_KEY]["columns"],
+        3
+    );
+
+    let customer = &symbols[2];
+    assert_eq!(customer.kind, SymbolKind::Field);
+    assert_eq!(customer.parent_id.as_deref(), Some(file.id.as_str()));
+    assert_eq!(customer.signature.as_deref(), Some("orders.customer_id"));
+    assert_eq!(
+        (customer.start_line, customer.start_column),
+        (1, "order_id,".len() as u32)
+    );
+    assert_eq!(
+        customer.metadata.as_ref().unwrap()[DELIMITED_DATA_METADATA_KEY]["column"],
+        1
+    );
+}
+
+#[test]
+fn delimiter_is_sniffed_and_tsv_splits_on_tabs() {
+    let semicolons = parse_header("csv", "id;name;city\n1;a;b\n").unwrap();
+    assert_eq!(semicolons.delimiter, ';');
+    assert_eq!(semicolons.columns.len(), 3);
+
+    let tabs = parse_header("tsv", "gene id\tsample, a\n").unwrap();
+    assert_eq!(tabs.delimiter, '\t');
+    let names: Vec<_> = tabs.columns.iter().map(|c| c.name.as_str()).collect();
+    assert_eq!(names, vec!["gene id", "sample, a"]);
+}
+
+#[test]
+fn header_skips_bom_and_blank_lines_and_keeps_column_positions() {
+    let header = parse_header("csv", "\u{feff}\n\nid,,\"say \"\"hi\"\"\"\r\n").unwrap();
+
+    assert_eq!(header.line, 3);
+    let columns: Vec<_> = header
+        .columns
+        .iter()
+        .map(|c| (c.name.as_str(), c.index))
+        .collect();
+    assert_eq!(columns, vec![("id", 0), ("say \"hi\"", 2)]);
+}
+
+#[test]
+fn numeric_first_row_is_not_a_header() {
+    assert!(parse_header("csv", "1,2.5,-3\n4,5,6\n").is_none());
+    assert!(parse_header("csv", "\n  \n").is_none());
+    assert!(parse_header("csv", "nan,inf\n").is_some());
+}
+
+#[test]
+fn header_scan_stops_at_the_byte_limit() {
+    let content = format!("a,b,{}\n", "c".repeat(HEADER_SCAN_BYTES));
+
+    let header = parse_header("csv", &content).unwrap();
+
+    let names: Vec<_> = header.columns.iter().map(|c| c.name.as_str()).collect();
+    assert_eq!(names, vec!["a", "b"]);
+}
+
+#[test]
+fn only_data_files_without_symbols_are_annotated() {
+    let mut symbols = Vec::new();
+    annotate_delimited_data_headers(&mut symbols, "notes.txt", "text", "id,name\n");
+    assert!(symbols.is_empty());
+
+    annotate_delimited_data_headers(&mut symbols, "people.tsv", "tsv", "id\tname\n");
+    assert_eq!(names(&symbols), vec!["people", "id", "name"]);
+}
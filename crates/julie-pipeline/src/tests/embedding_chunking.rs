@@ -0,0 +1,123 @@
+//! Tests for body chunking (embeddings::chunking).
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use crate::embeddings::chunking::{
+        MAX_CHUNKS_PER_SYMBOL, is_chunkable_kind, prepare_chunks_for_embedding, split_body,
+    };
+    use julie_extractors::{Symbol, SymbolKind};
+
+    fn make_function(id: &str, name: &str, start_line: u32, body: &str) -> Symbol {
+        Symbol {
+            id: id.to_string(),
+            name: name.to_string(),
+            kind: SymbolKind::Function,
+            language: "rust".to_string(),
+            file_path: "src/lib.rs".to_string(),
+            start_line,
+            start_column: 0,
+            end_line: start_line + body.lines().count() as u32 - 1,
+            end_column: 0,
+            start_byte: 0,
+            end_byte: body.len() as u32,
+            signature: None,
+            doc_comment: None,
+            visibility: None,
+            parent_id: None,
+            metadata: None,
+            semantic_group: None,
+            confidence: None,
+            code_context: None,
+            content_type: None,
+            body_span: None,
+            body_hash: None,
+            annotations: Vec::new(),
+        }
+    }
+
+    fn numbered_lines(count: usize) -> String {
+        (0..count)
+            .map(|i| format!("    let value_{i} = compute({i});"))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    #[test]
+    fn test_short_body_is_not_chunked() {
+        assert!(split_body("fn f() {}\n", 1, 100, 1).is_empty());
+    }
+
+    #[test]
+    fn test_split_body_respects_budget_and_overlaps() {
+        let body = numbered_lines(20);
+        let chunks = split_body(&body, 10, 200, 2);
+
+        assert!(chunks.len() > 1);
+        assert_eq!(chunks[0].start_line, 10);
+        assert_eq!(
+            chunks.last().unwrap().end_line,
+            29,
+            "last body line is covered"
+        );
+        for chunk in &chunks {
+            assert!(
+                chunk.text.len() <= 200,
+                "chunk exceeds budget: {}",
+                chunk.text.len()
+            );
+        }
+        for pair in chunks.windows(2) {
+            assert_eq!(
+                pair[1].start_line,
+                pair[0].end_line - 1,
+                "consecutive chunks share two lines"
+            );
+        }
+    }
+
+    #[test]
+    fn test_split_body_caps_chunks_and_cuts_long_lines() {
+        let body = numbered_lines(2000);
+        assert_eq!(split_body(&body, 1, 200, 0).len(), MAX_CHUNKS_PER_SYMBOL);
+
+        let long_line = "é".repeat(300);
+        let chunks = split_body(&long_line, 1, 101, 0);
+        assert_eq!(chunks.len(), 1);
+        assert_eq!(chunks[0].text.len(), 100, "cut back to a char boundary");
+    }
+
+    #[test]
+    fn test_prepare_chunks_names_the_owner() {
+        let body = format!("fn big() {{\n{}\n}}", numbered_lines(80));
+        let symbol = make_function("sym_big", "big", 5, &body);
+        let contents = HashMap::from([("src/lib.rs".to_string(), Some(body.clone()))]);
+
+        let groups = prepare_chunks_for_embedding(&[&symbol], &contents);
+        assert_eq!(groups.len(), 1);
+        let group = &groups[0];
+        assert!(group.len() > 1);
+        assert_eq!(group[0].chunk_id, "sym_big#chunk0");
+        assert!(group.iter().all(|c| c.symbol_id == "sym_big"));
+        assert!(
+            group[0]
+                .text
+                .starts_with(&format!("function big (part 1/{})\n", group.len())),
+            "{}",
+            group[0].text
+        );
+
+        // Missing content skips the symbol rather than failing.
+        let missing = HashMap::from([("src/lib.rs".to_string(), None)]);
+        assert!(prepare_chunks_for_embedding(&[&symbol], &missing).is_empty());
+    }
+
+    #[test]
+    fn test_only_functions_and_methods_are_chunkable() {
+        assert!(is_chunkable_kind(&SymbolKind::Function));
+        assert!(is_chunkable_kind(&SymbolKind::Method));
+        assert!(!is_chunkable_kind(&SymbolKind::Class));
+        assert!(!is_chunkable_kind(&SymbolKind::Variable));
+    }
+}
@@ -0,0 +1,282 @@
+// Tests for free-form Fortran extraction (indexing_core::fortran)
+
+use crate::indexing_core::fortran::{FORTRAN_METADATA_KEY, extract_fortran};
+use julie_extractors::base::Visibility;
+use julie_extractors::{ExtractionResults, IdentifierKind, RelationshipKind, Symbol, SymbolKind};
+
+const GEOMETRY: &str = r#"!> Planar shapes and their areas.
+module geometry
+  use, intrinsic :: iso_fortran_env, only: dp => real64
+  use linalg
+  implicit none
+  private
+  public :: shape, circle, area_of, describe
+
+  !> Circle constant.
+  real(dp), parameter :: pi = 3.14159265358979_dp
+  integer :: shape_count = 0  !< Shapes created so far.
+
+  !> Any shape with an area.
+  type, abstract :: shape
+    character(len=32) :: label
+  contains
+    procedure(area_fn), deferred :: area
+  end type shape
+
+  type, extends(shape) :: circle
+    real(dp) :: radius  !< Radius in metres.
+  contains
+    procedure :: area => circle_area
+    final :: destroy_circle
+  end type circle
+
+  abstract interface
+    function area_fn(self) result(a)
+      import :: shape, dp
+      class(shape), intent(in) :: self
+      real(dp) :: a
+    end function area_fn
+  end interface
+
+  interface area_of
+    module procedure circle_area
+  end interface area_of
+
+contains
+
+  !> Area of a circle.
+  pure function circle_area(self) result(a)
+    class(circle), intent(in) :: self
+    real(dp) :: a
+    a = pi * self%radius**2
+  end function circle_area
+
+  subroutine destroy_circle(self)
+    type(circle), intent(inout) :: self
+  end subroutine
+
+  subroutine describe(s)
+    class(shape), intent(in) :: s
+    real(dp) :: a, values(3)
+    a = s%area()
+    values(1) = a
+    call log_area(a); call report(a)
+    a = norm(values) + sqrt(a)
+    if (a > 0) call report(sqrt(a))
+  end subroutine describe
+
+  subroutine report(a)
+    real(dp), intent(in) :: a
+    print *, 'area', a
+  end subroutine report
+end module geometry
+
+program main
+  use geometry
+  type(circle) :: c
+  c = circle(label='c', radius=2.0)
+  print *, area_of(c)
+  call describe(c)
+end program main
+"#;
+
+fn find<'a>(symbols: &'a [Symbol], name: &str) -> &'a Symbol {
+    symbols
+        .iter()
+        .find(|symbol| symbol.name == name)
+        .unwrap_or_else(|| panic!("missing symbol {name}"))
+}
+
+fn info(symbol: &Symbol, key: &str) -> serde_json::Value {
+    symbol.metadata.as_ref().unwrap()[FORTRAN_METADATA_KEY][key].clone()
+}
+
+/// `(from, to, kind)` names of each resolved relationship.
+fn edges(results: &ExtractionResults) -> Vec<(&str, &str, RelationshipKind)> {
+    let name = |id: &str| {
+        results
+            .symbols
+            .iter()
+            .find(|symbol| symbol.id == id)
+            .map_or("?", |symbol| symbol.name.as_str())
+    };
+    results
+        .relationships
+        .iter()
+        .map(|rel| {
+            (
+                name(&rel.from_symbol_id),
+                name(&rel.to_symbol_id),
+                rel.kind.clone(),
+            )
+        })
+        .collect()
+}
+
+#[test]
+fn extracts_modules_with_uses_declarations_and_access() {
+    let results = extract_fortran("src/geometry.f90", GEOMETRY).unwrap();
+    let module = find(&results.symbols, "geometry");
+    assert_eq!(module.kind, SymbolKind::Module);
+    assert_eq!(module.language, "fortran");
+    assert_eq!((module.start_line, module.end_line), (2, 66));
+    assert_eq!(
+        module.doc_comment.as_deref(),
+        Some("Planar shapes and their areas.")
+    );
+    assert_eq!(info(find(&results.symbols, "main"), "kind"), "program");
+
+    let env = find(&results.symbols, "iso_fortran_env");
+    assert_eq!(env.kind, SymbolKind::Import);
+    assert_eq!(info(env, "intrinsic"), true);
+    assert_eq!(info(env, "only"), serde_json::json!(["dp => real64"]));
+
+    let pi = find(&results.symbols, "pi");
+    assert_eq!(pi.kind, SymbolKind::Constant);
+    assert_eq!(pi.doc_comment.as_deref(), Some("Circle constant."));
+    assert_eq!(info(pi, "value"), "3.14159265358979_dp");
+    let count = find(&results.symbols, "shape_count");
+    assert_eq!(count.kind, SymbolKind::Variable);
+    assert_eq!(count.doc_comment.as_deref(), Some("Shapes created so far."));
+
+    // `private` makes the module default private; the `public` list opts
+    // entities back in.
+    for (name, visibility) in [
+        ("pi", Visibility::Private),
+        ("shape_count", Visibility::Private),
+        ("shape", Visibility::Public),
+        ("area_of", Visibility::Public),
+        ("describe", Visibility::Public),
+        ("report", Visibility::Private),
+    ] {
+        let symbol = find(&results.symbols, name);
+        assert_eq!(symbol.parent_id.as_ref(), Some(&module.id), "{name}");
+        assert_eq!(symbol.visibility, Some(visibility), "{name}");
+    }
+
+    let circle_area = find(&results.symbols, "circle_area");
+    assert_eq!(circle_area.kind, SymbolKind::Function);
+    assert_eq!((circle_area.start_line, circle_area.end_line), (42, 46));
+    assert_eq!(
+        circle_area.doc_comment.as_deref(),
+        Some("Area of a circle.")
+    );
+    assert_eq!(
+        circle_area.signature.as_deref(),
+        Some("pure function circle_area(self) result(a)")
+    );
+    assert_eq!(info(circle_area, "result"), "a");
+    assert_eq!(info(find(&results.symbols, "report"), "kind"), "subroutine");
+    assert_eq!(
+        info(find(&results.symbols, "area_of"), "procedures"),
+        serde_json::json!(["circle_area"])
+    );
+    assert_eq!(
+        info(find(&results.symbols, "area_fn"), "interface_body"),
+        true
+    );
+}
+
+#[test]
+fn extracts_derived_types_with_components_and_bindings() {
+    let results = extract_fortran("src/geometry.f90", GEOMETRY).unwrap();
+    let shape = find(&results.symbols, "shape");
+    assert_eq!(shape.kind, SymbolKind::Struct);
+    assert_eq!(info(shape, "abstract"), true);
+    assert_eq!(
+        shape.doc_comment.as_deref(),
+        Some("Any shape with an area.")
+    );
+
+    let circle = find(&results.symbols, "circle");
+    assert_eq!((circle.start_line, circle.end_line), (20, 25));
+    assert_eq!(info(circle, "extends"), "shape");
+
+    let member = |name: &str, kind: SymbolKind, parent: &Symbol| {
+        let symbol = results
+            .symbols
+            .iter()
+            .find(|symbol| symbol.name == name && symbol.parent_id.as_ref() == Some(&parent.id))
+            .unwrap_or_else(|| panic!("missing member {name}"));
+        assert_eq!(symbol.kind, kind, "{name}");
+        symbol
+    };
+    let label = member("label", SymbolKind::Field, shape);
+    assert_eq!(info(label, "type"), "character(len=32)");
+    let deferred = member("area", SymbolKind::Method, shape);
+    assert_eq!(info(deferred, "interface"), "area_fn");
+    assert_eq!(
+        info(deferred, "attributes"),
+        serde_json::json!(["deferred"])
+    );
+    let radius = member("radius", SymbolKind::Field, circle);
+    assert_eq!(radius.doc_comment.as_deref(), Some("Radius in metres."));
+    let area = member("area", SymbolKind::Method, circle);
+    assert_eq!(info(area, "binding"), "circle_area");
+    member("destroy_circle", SymbolKind::Destructor, circle);
+
+    let edges = edges(&results);
+    assert!(edges.contains(&("circle", "shape", RelationshipKind::Extends)));
+    assert!(edges.contains(&("circle_area", "circle", RelationshipKind::Uses)));
+}
+
+#[test]
+fn calls_and_uses_link_to_procedures_and_modules() {
+    let results = extract_fortran("src/geometry.f90", GEOMETRY).unwrap();
+    let edges = edges(&results);
+    assert!(edges.contains(&("describe", "report", RelationshipKind::Calls)));
+    assert!(edges.contains(&("main", "geometry", RelationshipKind::Imports)));
+    assert!(edges.contains(&("main", "describe", RelationshipKind::Calls)));
+    assert!(edges.contains(&("main", "area_of", RelationshipKind::Calls)));
+    // A structure constructor instantiates the type.
+    assert!(edges.contains(&("main", "circle", RelationshipKind::Instantiates)));
+
+    // Arrays, intrinsics and type-bound calls through `%` are not calls.
+    let describe = find(&results.symbols, "describe");
+    let calls: Vec<&str> = results
+        .identifiers
+        .iter()
+        .filter(|identifier| {
+            identifier.kind == IdentifierKind::Call
+                && identifier.containing_symbol_id.as_ref() == Some(&describe.id)
+        })
+        .map(|identifier| identifier.name.as_str())
+        .collect();
+    assert_eq!(calls, vec!["log_area", "report", "norm", "report"]);
+
+    // Procedures and modules defined elsewhere wait for the cross-file
+    // resolver; intrinsic modules link to nothing.
+    let pending: Vec<_> = results
+        .pending_relationships
+        .iter()
+        .map(|pending| (pending.callee_name.as_str(), pending.kind.clone()))
+        .collect();
+    assert_eq!(
+        pending,
+        vec![
+            ("linalg", RelationshipKind::Imports),
+            ("log_area", RelationshipKind::Calls),
+            ("norm", RelationshipKind::Calls),
+        ]
+    );
+}
+
+#[test]
+fn tolerates_continuations_case_and_unterminated_units() {
+    let source = "#ifdef USE_MPI\n#include \"mpif.h\"\n#endif\nMODULE Solver\n  REAL :: grid(10, &\n               10)\nCONTAINS\n  SUBROUTINE Helper(x, &\n       & y)\n    REAL, INTENT(IN) :: x, y\n  END SUBROUTINE\n  SUBROUTINE Run()\n    total = 0.0; grid(1, 1) = total\n    CALL helper(1.0, &\n                2.0)\n  END SUBROUTINE Run\n  SUBROUTINE Half(\n";
+    let results = extract_fortran("src/solver.F90", source).unwrap();
+    assert_eq!(find(&results.symbols, "Solver").end_line, 17);
+    assert_eq!(
+        find(&results.symbols, "grid").signature.as_deref(),
+        Some("REAL :: grid(10, 10)")
+    );
+    let helper = find(&results.symbols, "Helper");
+    assert_eq!(info(helper, "arguments"), serde_json::json!(["x", "y"]));
+    assert_eq!(helper.end_line, 11);
+
+    assert_eq!(
+        edges(&results),
+        vec![("Run", "Helper", RelationshipKind::Calls)]
+    );
+    assert!(results.pending_relationships.is_empty());
+}
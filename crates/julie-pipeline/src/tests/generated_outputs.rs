@@ -0,0 +1,136 @@
+// Tests for generated-code tags (indexing_core::generated_outputs)
+
+use std::fs;
+
+use crate::indexing_core::generated_outputs::{
+    GENERATED_METADATA_KEY, IS_GENERATED_METADATA_KEY, annotate_generated_symbols,
+};
+use julie_extractors::base::{Symbol, SymbolKind};
+use serde_json::json;
+use tempfile::TempDir;
+
+fn sym(name: &str, file_path: &str) -> Symbol {
+    Symbol {
+        id: name.to_string(),
+        name: name.to_string(),
+        kind: SymbolKind::Struct,
+        language: "go".to_string(),
+        file_path: file_path.to_string(),
+        start_line: 1,
+        start_column: 0,
+        end_line: 5,
+        end_column: 1,
+        start_byte: 0,
+        end_byte: 50,
+        signature: None,
+        doc_comment: None,
+        visibility: None,
+        parent_id: None,
+        metadata: None,
+        semantic_group: None,
+        confidence: None,
+        code_context: None,
+        content_type: None,
+        body_span: None,
+        body_hash: None,
+        annotations: Vec::new(),
+    }
+}
+
+fn workspace(config: &str) -> TempDir {
+    let dir = TempDir::new().unwrap();
+    fs::create_dir_all(dir.path().join(".julie")).unwrap();
+    fs::write(dir.path().join(".julie/config.toml"), config).unwrap();
+    dir
+}
+
+#[test]
+fn test_generated_symbols_point_at_their_source() {
+    let dir =
+        workspace("[[generated]]\nroot = \"gen/go\"\nsource = \"proto/{dir}/{stem}.proto\"\n");
+    let path = "gen/go/acme/user/user_grpc.pb.go";
+    let mut symbols = vec![
+        sym("UserServiceClient", path),
+        sym("NewUserServiceClient", path),
+    ];
+
+    assert_eq!(
+        annotate_generated_symbols(&mut symbols, dir.path(), path, ""),
+        2
+    );
+    for symbol in &symbols {
+        assert_eq!(
+            symbol.metadata.as_ref().unwrap()[GENERATED_METADATA_KEY],
+            json!({ "root": "gen/go", "source": "proto/acme/user/user.proto" })
+        );
+        assert_eq!(
+            symbol.metadata.as_ref().unwrap()[IS_GENERATED_METADATA_KEY],
+            json!(true)
+        );
+    }
+}
+
+#[test]
+fn test_files_outside_generated_roots_are_untouched() {
+    let dir = workspace("[[generated]]\nroot = \"gen/go\"\ninclude = [\"*.pb.go\"]\n");
+    let mut symbols = vec![sym("helper", "gen/go/acme/helper.go")];
+    assert_eq!(
+        annotate_generated_symbols(&mut symbols, dir.path(), "gen/go/acme/helper.go", ""),
+        0
+    );
+    assert!(symbols[0].metadata.is_none());
+
+    let mut symbols = vec![sym("User", "gen/go/user.pb.go")];
+    annotate_generated_symbols(&mut symbols, dir.path(), "gen/go/user.pb.go", "");
+    assert_eq!(
+        symbols[0].metadata.as_ref().unwrap()[GENERATED_METADATA_KEY],
+        json!({ "root": "gen/go" })
+    );
+}
+
+#[test]
+fn test_checked_in_generated_code_is_flagged_without_config() {
+    let dir = workspace("");
+    let header = "// Code generated by sqlc. DO NOT EDIT.\npackage db\n";
+    let mut symbols = vec![sym("Queries", "internal/db/queries.go")];
+    assert_eq!(
+        annotate_generated_symbols(&mut symbols, dir.path(), "internal/db/queries.go", header),
+        1
+    );
+    let metadata = symbols[0].metadata.as_ref().unwrap();
+    assert_eq!(metadata[IS_GENERATED_METADATA_KEY], json!(true));
+    // Only `[[generated]]` outputs carry provenance.
+    assert!(!metadata.contains_key(GENERATED_METADATA_KEY));
+
+    let mut symbols = vec![sym("User", "api/user_pb2.py")];
+    assert_eq!(
+        annotate_generated_symbols(&mut symbols, dir.path(), "api/user_pb2.py", ""),
+        1
+    );
+
+    let mut symbols = vec![sym("Queries", "internal/db/store.go")];
+    assert_eq!(
+        annotate_generated_symbols(
+            &mut symbols,
+            dir.path(),
+            "internal/db/store.go",
+            "package db\n"
+        ),
+        0
+    );
+    assert!(symbols[0].metadata.is_none());
+}
+
+#[test]
+fn test_index_generated_globs_flag_files_the_heuristics_miss() {
+    let dir = workspace("[index]\ngenerated = [\"web/src/api/**\"]\n");
+    let mut symbols = vec![sym("fetchUser", "web/src/api/users.ts")];
+    assert_eq!(
+        annotate_generated_symbols(&mut symbols, dir.path(), "web/src/api/users.ts", ""),
+        1
+    );
+    assert_eq!(
+        symbols[0].metadata.as_ref().unwrap()[IS_GENERATED_METADATA_KEY],
+        json!(true)
+    );
+}
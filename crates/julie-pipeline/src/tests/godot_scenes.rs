@@ -0,0 +1,316 @@
+// Tests for Godot scene linking (indexing_core::godot_scenes)
+
+use crate::indexing_core::godot_scenes::{
+    GODOT_METADATA_KEY, METHOD_CALLEE_PREFIX, SCENE_CALLEE_PREFIX, SCRIPT_CALLEE_PREFIX,
+    extract_godot_scene, is_scene_link, parse_scene, resolve_scene_links,
+};
+use julie_core::database::{FileInfo, SymbolDatabase};
+use julie_extractors::{RelationshipKind, Symbol, SymbolKind};
+use tempfile::TempDir;
+
+const MAIN_TSCN: &str = r#"[gd_scene load_steps=4 format=3 uid="uid://c8x"]
+
+[ext_resource type="Script" path="res://scripts/main.gd" id="1_main"]
+[ext_resource type="Script" uid="uid://b2" path="res://scripts/hud.gd" id="2_hud"]
+[ext_resource type="PackedScene" path="res://enemy.tscn" id="3_enemy"]
+
+[node name="Main" type="Node2D"]
+script = ExtResource("1_main")
+
+[node name="HUD" type="CanvasLayer" parent="."]
+script = ExtResource("2_hud")
+
+[node name="Label" type="Label" parent="HUD"]
+text = "Score:
+[node name=\"NotANode\"]
+script = ExtResource(\"2_hud\")"
+
+[node name="StartButton" type="Button" parent="HUD"]
+text = "Start"
+
+[node name="Enemy" parent="." instance=ExtResource("3_enemy")]
+
+[connection signal="pressed" from="HUD/StartButton" to="." method="_on_start_pressed"]
+[connection signal="died" from="Enemy" to="HUD" method="on_enemy_died"]
+[connection signal="hit" from="Enemy/Hitbox" to="." method="_on_hit"]
+[connection signal="pressed" from="HUD/StartButton" to="Enemy" method="stop"]
+"#;
+
+// Godot 3 scene format: numeric ids and spaced `ExtResource( 1 )`.
+const PLAYER_TSCN: &str = "[gd_scene load_steps=2 format=2]
+
+[ext_resource path=\"res://Player.gd\" type=\"Script\" id=1]
+
+[node name=\"Player\" type=\"KinematicBody2D\"]
+script = ExtResource( 1 )
+
+[node name=\"Area\" type=\"Area2D\" parent=\".\"]
+
+[connection signal=\"body_entered\" from=\"Area\" to=\".\" method=\"_on_Area_body_entered\"]
+";
+
+fn sym(id: &str, name: &str, kind: SymbolKind, language: &str, file_path: &str) -> Symbol {
+    Symbol {
+        id: id.to_string(),
+        name: name.to_string(),
+        kind,
+        language: language.to_string(),
+        file_path: file_path.to_string(),
+        start_line: 1,
+        start_column: 0,
+        end_line: 10,
+        end_column: 1,
+        start_byte: 0,
+        end_byte: 0,
+        signature: None,
+        doc_comment: None,
+        visibility: None,
+        parent_id: None,
+        metadata: None,
+        semantic_group: None,
+        confidence: None,
+        code_context: None,
+        content_type: None,
+        body_span: None,
+        body_hash: None,
+        annotations: Vec::new(),
+    }
+}
+
+fn find<'a>(symbols: &'a [Symbol], name: &str) -> &'a Symbol {
+    symbols
+        .iter()
+        .find(|symbol| symbol.name == name)
+        .unwrap_or_else(|| panic!("no symbol named {name}"))
+}
+
+#[test]
+fn scene_nodes_get_tree_paths_scripts_and_instances() {
+    let scene = parse_scene(MAIN_TSCN);
+
+    let nodes: Vec<(&str, Option<&str>)> = scene
+        .nodes
+        .iter()
+        .map(|node| {
+            (
+                node.path.as_str(),
+                node.script.as_ref().map(|(script, _)| script.as_str()),
+            )
+        })
+        .collect();
+    assert_eq!(
+        nodes,
+        [
+            (".", Some("res://scripts/main.gd")),
+            ("HUD", Some("res://scripts/hud.gd")),
+            ("HUD/Label", None),
+            ("HUD/StartButton", None),
+            ("Enemy", None),
+        ],
+        "a multi-line string is not a section"
+    );
+    assert_eq!(scene.nodes[4].instance.as_deref(), Some("res://enemy.tscn"));
+    assert_eq!(scene.nodes[1].node_type.as_deref(), Some("CanvasLayer"));
+    assert_eq!(scene.connections.len(), 4);
+    assert_eq!(scene.connections[0].signal, "pressed");
+    assert_eq!(scene.connections[0].from, "HUD/StartButton");
+}
+
+#[test]
+fn godot3_scenes_resolve_numeric_resource_ids() {
+    let scene = parse_scene(PLAYER_TSCN);
+
+    assert_eq!(
+        scene.nodes[0]
+            .script
+            .as_ref()
+            .map(|(script, _)| script.as_str()),
+        Some("res://Player.gd")
+    );
+    assert_eq!(scene.nodes[1].path, "Area");
+    assert_eq!(scene.connections[0].method, "_on_Area_body_entered");
+}
+
+#[test]
+fn scene_symbols_nest_nodes_and_record_pending_links() {
+    let results = extract_godot_scene("game/scenes/main.tscn", MAIN_TSCN).unwrap();
+    let symbols = &results.symbols;
+
+    let scene = &symbols[0];
+    assert_eq!(scene.name, "main");
+    assert_eq!(scene.kind, SymbolKind::Module);
+    assert_eq!(scene.language, "godot_scene");
+    assert_eq!(
+        scene.metadata.as_ref().unwrap()[GODOT_METADATA_KEY]["nodes"],
+        5
+    );
+    let root = find(symbols, "Main");
+    let hud = find(symbols, "HUD");
+    let button = find(symbols, "StartButton");
+    assert_eq!(root.parent_id.as_deref(), Some(scene.id.as_str()));
+    assert_eq!(hud.parent_id.as_deref(), Some(scene.id.as_str()));
+    assert_eq!(button.parent_id.as_deref(), Some(hud.id.as_str()));
+    assert_eq!(button.kind, SymbolKind::Variable);
+    assert_eq!(
+        button.signature.as_deref(),
+        Some(r#"[node name="StartButton" type="Button" parent="HUD"]"#)
+    );
+    assert_eq!(
+        button.metadata.as_ref().unwrap()[GODOT_METADATA_KEY]["path"],
+        "HUD/StartButton"
+    );
+
+    let links: Vec<(&str, &str, u32)> = results
+        .pending_relationships
+        .iter()
+        .map(|p| {
+            let from = &symbols
+                .iter()
+                .find(|s| s.id == p.from_symbol_id)
+                .unwrap()
+                .name;
+            (from.as_str(), p.callee_name.as_str(), p.line_number)
+        })
+        .collect();
+    assert_eq!(
+        links,
+        [
+            ("Main", "godot-script:res://scripts/main.gd", 8),
+            ("HUD", "godot-script:res://scripts/hud.gd", 11),
+            ("Enemy", "godot-scene:res://enemy.tscn", 21),
+            (
+                "StartButton",
+                "godot-method:res://scripts/main.gd#_on_start_pressed",
+                23
+            ),
+            (
+                "Enemy",
+                "godot-method:res://scripts/hud.gd#on_enemy_died",
+                24
+            ),
+            ("main", "godot-method:res://scripts/main.gd#_on_hit", 25),
+        ],
+        "a receiver without a script of its own is skipped"
+    );
+    assert!(results.pending_relationships.iter().all(is_scene_link));
+    assert_eq!(
+        results.pending_relationships[3].kind,
+        RelationshipKind::Calls
+    );
+}
+
+#[test]
+fn scene_links_resolve_res_paths_from_the_godot_project_root() {
+    let temp_dir = TempDir::new().unwrap();
+    let mut db = SymbolDatabase::new(temp_dir.path().join("test.db")).unwrap();
+
+    let main = extract_godot_scene("game/scenes/main.tscn", MAIN_TSCN).unwrap();
+    let enemy = extract_godot_scene("game/enemy.tscn", "[gd_scene format=3]\n").unwrap();
+    let hud_class = sym(
+        "hud",
+        "HUD",
+        SymbolKind::Class,
+        "gdscript",
+        "game/scripts/hud.gd",
+    );
+    let mut on_enemy_died = sym(
+        "on_enemy_died",
+        "on_enemy_died",
+        SymbolKind::Method,
+        "gdscript",
+        "game/scripts/hud.gd",
+    );
+    on_enemy_died.parent_id = Some("hud".to_string());
+    let mut symbols = vec![
+        // A script of the same name in another Godot project's root.
+        sym(
+            "other",
+            "_on_start_pressed",
+            SymbolKind::Function,
+            "gdscript",
+            "scripts/main.gd",
+        ),
+        sym(
+            "on_start",
+            "_on_start_pressed",
+            SymbolKind::Function,
+            "gdscript",
+            "game/scripts/main.gd",
+        ),
+        sym(
+            "speed",
+            "speed",
+            SymbolKind::Variable,
+            "gdscript",
+            "game/scripts/main.gd",
+        ),
+        hud_class,
+        on_enemy_died,
+    ];
+    symbols.extend(main.symbols.iter().cloned());
+    symbols.extend(enemy.symbols.iter().cloned());
+    let mut files: Vec<(String, &str)> = symbols
+        .iter()
+        .map(|symbol| (symbol.file_path.clone(), symbol.language.as_str()))
+        .collect();
+    files.sort();
+    files.dedup();
+    for (path, language) in files {
+        db.store_file_info(&FileInfo {
+            path,
+            language: language.to_string(),
+            hash: "h".to_string(),
+            size: 100,
+            last_modified: 1000,
+            last_indexed: 0,
+            symbol_count: 0,
+            line_count: 0,
+            content: None,
+        })
+        .unwrap();
+    }
+    db.store_symbols_transactional(&symbols).unwrap();
+
+    let resolved = resolve_scene_links(&main.pending_relationships, &db);
+    let name_of = |id: &str| {
+        symbols
+            .iter()
+            .find(|symbol| symbol.id == id)
+            .map(|symbol| symbol.name.as_str())
+            .unwrap()
+    };
+    let mut edges: Vec<(&str, &str, RelationshipKind)> = resolved
+        .iter()
+        .map(|r| {
+            (
+                name_of(&r.from_symbol_id),
+                name_of(&r.to_symbol_id),
+                r.kind.clone(),
+            )
+        })
+        .collect();
+    edges.sort_by_key(|(from, to, _)| (*from, *to));
+    assert_eq!(
+        edges,
+        [
+            ("Enemy", "enemy", RelationshipKind::Uses),
+            ("Enemy", "on_enemy_died", RelationshipKind::Calls),
+            ("HUD", "HUD", RelationshipKind::Uses),
+            ("StartButton", "_on_start_pressed", RelationshipKind::Calls),
+        ],
+        "main.gd declares no class, and the _on_hit handler does not exist"
+    );
+    let on_start = resolved
+        .iter()
+        .find(|r| name_of(&r.to_symbol_id) == "_on_start_pressed")
+        .unwrap();
+    assert_eq!(on_start.to_symbol_id, "on_start");
+    assert_eq!(on_start.file_path, "game/scenes/main.tscn");
+    assert_eq!(on_start.line_number, 23);
+
+    let mut unmatched = main.pending_relationships[..3].to_vec();
+    unmatched[0].callee_name = format!("{SCRIPT_CALLEE_PREFIX}res://missing.gd");
+    unmatched[1].callee_name = format!("{SCENE_CALLEE_PREFIX}scenes/enemy.tscn");
+    unmatched[2].callee_name = format!("{METHOD_CALLEE_PREFIX}res://scripts/main.gd");
+    assert!(resolve_scene_links(&unmatched, &db).is_empty());
+}
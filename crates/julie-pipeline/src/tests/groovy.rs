@@ -0,0 +1,293 @@
+// Tests for Groovy/Gradle/Jenkinsfile extraction (indexing_core::groovy)
+
+use crate::indexing_core::groovy::{GROOVY_METADATA_KEY, extract_groovy};
+use julie_extractors::base::Visibility;
+use julie_extractors::{ExtractionResults, RelationshipKind, Symbol, SymbolKind};
+
+const RELEASE_NOTES: &str = r#"package com.acme.build
+
+import groovy.transform.CompileStatic
+import static java.util.Collections.emptyList as none
+
+/** Renders release notes. */
+@CompileStatic
+class ReleaseNotes extends Base implements Renderer, Serializable {
+    static final String HEADER = '# Notes'
+    String title
+    private int count = 0
+
+    ReleaseNotes(String title) {
+        this.title = title
+    }
+
+    def formatter = { String line -> line.trim() }
+
+    String render(List<String> lines) {
+        lines.collect { formatter(it) }.join('\n') + footer()
+    }
+
+    private String footer() { "-- ${title}" }
+}
+"#;
+
+const BUILD_GRADLE: &str = r#"plugins {
+    id 'java'
+    id 'org.springframework.boot' version '3.2.0' apply false
+    groovy
+}
+
+apply plugin: 'maven-publish'
+apply from: 'gradle/publishing.gradle'
+
+def buildInfo = { String version -> "build-${version}" }
+
+// Bundles the docs.
+task docsZip(type: Zip, dependsOn: 'javadoc') {
+    description = 'Zips the generated docs'
+    from javadoc.destinationDir
+}
+
+tasks.register('integrationTest', Test) {
+    group = 'verification'
+    dependsOn docsZip, ':lib:assemble'
+    println buildInfo('1.0')
+}
+
+docsZip.finalizedBy 'integrationTest'
+"#;
+
+const JENKINSFILE: &str = r#"#!groovy
+
+def deploy(String env) {
+    sh "./deploy.sh ${env}"
+}
+
+pipeline {
+    agent any
+    stages {
+        stage('Build') {
+            steps {
+                sh 'make'
+            }
+        }
+        stage('Deploy') {
+            steps {
+                deploy 'prod'
+            }
+        }
+    }
+}
+"#;
+
+fn find<'a>(symbols: &'a [Symbol], name: &str) -> &'a Symbol {
+    symbols
+        .iter()
+        .find(|symbol| symbol.name == name)
+        .unwrap_or_else(|| panic!("missing symbol {name}"))
+}
+
+fn info(symbol: &Symbol, key: &str) -> serde_json::Value {
+    symbol.metadata.as_ref().unwrap()[GROOVY_METADATA_KEY][key].clone()
+}
+
+/// `(from, to, kind)` names of each resolved relationship.
+fn edges(results: &ExtractionResults) -> Vec<(&str, &str, RelationshipKind)> {
+    let name = |id: &str| {
+        results
+            .symbols
+            .iter()
+            .find(|symbol| symbol.id == id)
+            .map_or("?", |symbol| symbol.name.as_str())
+    };
+    results
+        .relationships
+        .iter()
+        .map(|rel| {
+            (
+                name(&rel.from_symbol_id),
+                name(&rel.to_symbol_id),
+                rel.kind.clone(),
+            )
+        })
+        .collect()
+}
+
+#[test]
+fn extracts_classes_with_members_and_closure_properties() {
+    let results = extract_groovy("src/main/groovy/ReleaseNotes.groovy", RELEASE_NOTES).unwrap();
+    assert_eq!(
+        find(&results.symbols, "com.acme.build").kind,
+        SymbolKind::Namespace
+    );
+    assert_eq!(
+        find(&results.symbols, "CompileStatic").kind,
+        SymbolKind::Import
+    );
+    assert_eq!(
+        info(find(&results.symbols, "none"), "path"),
+        "java.util.Collections.emptyList"
+    );
+
+    let class = find(&results.symbols, "ReleaseNotes");
+    assert_eq!(class.kind, SymbolKind::Class);
+    assert_eq!(class.language, "groovy");
+    assert_eq!((class.start_line, class.end_line), (7, 24));
+    assert_eq!(class.doc_comment.as_deref(), Some("Renders release notes."));
+    assert_eq!(
+        class.signature.as_deref(),
+        Some("class ReleaseNotes extends Base implements Renderer, Serializable")
+    );
+    assert_eq!(
+        info(class, "annotations"),
+        serde_json::json!(["CompileStatic"])
+    );
+
+    let member = |name: &str, kind: SymbolKind, visibility: Visibility| {
+        let symbol = results
+            .symbols
+            .iter()
+            .find(|symbol| symbol.name == name && symbol.kind == kind)
+            .unwrap_or_else(|| panic!("missing {kind:?} {name}"));
+        assert_eq!(symbol.parent_id.as_ref(), Some(&class.id), "{name}");
+        assert_eq!(symbol.visibility, Some(visibility), "{name}");
+        symbol
+    };
+    member("HEADER", SymbolKind::Constant, Visibility::Public);
+    member("title", SymbolKind::Property, Visibility::Public);
+    member("count", SymbolKind::Field, Visibility::Private);
+    member("ReleaseNotes", SymbolKind::Constructor, Visibility::Public);
+    member("footer", SymbolKind::Method, Visibility::Private);
+    let render = member("render", SymbolKind::Method, Visibility::Public);
+    assert_eq!(
+        render.signature.as_deref(),
+        Some("String render(List<String> lines)")
+    );
+    let formatter = member("formatter", SymbolKind::Method, Visibility::Public);
+    assert_eq!(info(formatter, "kind"), "closure");
+    assert_eq!(
+        formatter.signature.as_deref(),
+        Some("def formatter = { String line -> }")
+    );
+
+    let edges = edges(&results);
+    assert!(edges.contains(&("render", "formatter", RelationshipKind::Calls)));
+    assert!(edges.contains(&("render", "footer", RelationshipKind::Calls)));
+    // Supertypes live elsewhere and wait for the cross-file resolver.
+    let pending: Vec<_> = results
+        .pending_relationships
+        .iter()
+        .filter(|pending| pending.from_symbol_id == class.id)
+        .map(|pending| (pending.callee_name.as_str(), pending.kind.clone()))
+        .collect();
+    assert_eq!(
+        pending,
+        vec![
+            ("Base", RelationshipKind::Extends),
+            ("Renderer", RelationshipKind::Implements),
+            ("Serializable", RelationshipKind::Implements),
+        ]
+    );
+}
+
+#[test]
+fn extracts_gradle_plugins_and_tasks_with_dependencies() {
+    let results = extract_groovy("build.gradle", BUILD_GRADLE).unwrap();
+    let plugins: Vec<(&str, String)> = results
+        .symbols
+        .iter()
+        .filter(|symbol| symbol.kind == SymbolKind::Import)
+        .map(|symbol| {
+            let kind = info(symbol, "kind");
+            (symbol.name.as_str(), kind.as_str().unwrap().to_string())
+        })
+        .collect();
+    assert_eq!(
+        plugins,
+        [
+            ("java", "plugin"),
+            ("org.springframework.boot", "plugin"),
+            ("groovy", "plugin"),
+            ("maven-publish", "plugin"),
+            ("gradle/publishing.gradle", "script_plugin"),
+        ]
+        .map(|(name, kind)| (name, kind.to_string()))
+    );
+    let boot = find(&results.symbols, "org.springframework.boot");
+    assert_eq!(info(boot, "version"), "3.2.0");
+    assert_eq!(info(boot, "apply"), false);
+
+    let docs_zip = find(&results.symbols, "docsZip");
+    assert_eq!(docs_zip.kind, SymbolKind::Function);
+    assert_eq!(info(docs_zip, "kind"), "task");
+    assert_eq!(info(docs_zip, "type"), "Zip");
+    assert_eq!(info(docs_zip, "description"), "Zips the generated docs");
+    assert_eq!(docs_zip.doc_comment.as_deref(), Some("Bundles the docs."));
+    assert_eq!(
+        docs_zip.signature.as_deref(),
+        Some("task docsZip(type: Zip, dependsOn: 'javadoc')")
+    );
+    assert_eq!((docs_zip.start_line, docs_zip.end_line), (13, 16));
+
+    let integration = find(&results.symbols, "integrationTest");
+    assert_eq!(info(integration, "type"), "Test");
+    assert_eq!(info(integration, "group"), "verification");
+    assert_eq!(info(find(&results.symbols, "buildInfo"), "kind"), "closure");
+
+    let edges = edges(&results);
+    assert!(edges.contains(&("integrationTest", "docsZip", RelationshipKind::Calls)));
+    assert!(edges.contains(&("docsZip", "integrationTest", RelationshipKind::Calls)));
+    assert!(edges.contains(&("integrationTest", "buildInfo", RelationshipKind::Calls)));
+    let finalized = results
+        .relationships
+        .iter()
+        .find(|rel| rel.from_symbol_id == docs_zip.id)
+        .unwrap();
+    assert_eq!(
+        finalized.metadata.as_ref().unwrap()["via"],
+        serde_json::json!("finalizedBy")
+    );
+
+    // Tasks from other projects or plugins are left for the resolver, and
+    // DSL calls like `from` and `println` are not recorded at all.
+    let mut pending: Vec<&str> = results
+        .pending_relationships
+        .iter()
+        .map(|pending| pending.callee_name.as_str())
+        .collect();
+    pending.sort_unstable();
+    assert_eq!(pending, vec!["assemble", "javadoc"]);
+}
+
+#[test]
+fn extracts_jenkins_stages_and_local_steps() {
+    let results = extract_groovy("Jenkinsfile", JENKINSFILE).unwrap();
+    let deploy = find(&results.symbols, "deploy");
+    assert_eq!(deploy.kind, SymbolKind::Function);
+    assert_eq!(deploy.signature.as_deref(), Some("def deploy(String env)"));
+
+    for (name, line) in [("Build", 10), ("Deploy", 15)] {
+        let stage = find(&results.symbols, name);
+        assert_eq!(info(stage, "kind"), "stage");
+        assert_eq!(stage.start_line, line);
+        assert_eq!(stage.parent_id, None);
+    }
+    assert_eq!(
+        edges(&results),
+        vec![("Deploy", "deploy", RelationshipKind::Calls)]
+    );
+    // `sh` and friends are pipeline steps, not calls worth resolving.
+    assert!(results.pending_relationships.is_empty());
+}
+
+#[test]
+fn tolerates_unclosed_blocks_and_slashy_strings() {
+    let source =
+        "def check(v) {\n    v ==~ /\\d+\\/x/ && valid(v)\n}\n\ntask broken {\n    doLast {\n";
+    let results = extract_groovy("scripts/check.gradle", source).unwrap();
+    assert_eq!(find(&results.symbols, "check").end_line, 3);
+    assert_eq!(find(&results.symbols, "broken").end_line, 6);
+
+    let ids: std::collections::HashSet<_> =
+        results.symbols.iter().map(|symbol| &symbol.id).collect();
+    assert_eq!(ids.len(), results.symbols.len());
+}
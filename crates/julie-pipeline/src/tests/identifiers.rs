@@ -0,0 +1,218 @@
+// Tests for grammar-walked identifiers of extractors that report none
+// (indexing_core::identifiers)
+
+use crate::indexing_core::identifiers::fill_missing_identifiers;
+use julie_extractors::ExtractionResults;
+use julie_extractors::base::{Identifier, IdentifierKind, Symbol, SymbolKind};
+
+fn symbol(id: &str, language: &str, file_path: &str, content: &str, body: &str) -> Symbol {
+    let start = content.find(body).expect("symbol body in fixture");
+    let end = start + body.len();
+    let line = |byte: usize| content[..byte].matches('\n').count() as u32 + 1;
+    Symbol {
+        id: id.to_string(),
+        name: id.to_string(),
+        kind: SymbolKind::Function,
+        language: language.to_string(),
+        file_path: file_path.to_string(),
+        start_line: line(start),
+        start_column: 0,
+        end_line: line(end),
+        end_column: 0,
+        start_byte: start as u32,
+        end_byte: end as u32,
+        signature: None,
+        doc_comment: None,
+        visibility: None,
+        parent_id: None,
+        metadata: None,
+        semantic_group: None,
+        confidence: None,
+        code_context: None,
+        content_type: None,
+        body_span: None,
+        body_hash: None,
+        annotations: Vec::new(),
+    }
+}
+
+fn fill(language: &str, file_path: &str, content: &str, symbols: Vec<Symbol>) -> ExtractionResults {
+    let mut results = ExtractionResults::empty();
+    results.symbols = symbols;
+    fill_missing_identifiers(&mut results, language, file_path, content);
+    results
+}
+
+fn find<'a>(results: &'a ExtractionResults, name: &str) -> &'a Identifier {
+    results
+        .identifiers
+        .iter()
+        .find(|identifier| identifier.name == name)
+        .unwrap_or_else(|| panic!("no identifier {name} in {:?}", results.identifiers))
+}
+
+fn kind_of(results: &ExtractionResults, name: &str) -> IdentifierKind {
+    find(results, name).kind.clone()
+}
+
+fn container_of<'a>(results: &'a ExtractionResults, name: &str) -> Option<&'a str> {
+    find(results, name).containing_symbol_id.as_deref()
+}
+
+#[test]
+fn test_r_calls_and_members_are_contained_by_their_function() {
+    let content = "\
+summarise <- function(df) {
+  out <- dplyr::summarize(df)
+  print(out$total)
+}
+
+summarise(data)
+";
+    let body = &content[content.find("function").unwrap()..content.find("\n}").unwrap() + 2];
+    let results = fill(
+        "r",
+        "R/summary.R",
+        content,
+        vec![symbol("summarise", "r", "R/summary.R", content, body)],
+    );
+
+    assert!(matches!(
+        kind_of(&results, "summarize"),
+        IdentifierKind::Call
+    ));
+    assert!(matches!(kind_of(&results, "print"), IdentifierKind::Call));
+    assert!(matches!(
+        kind_of(&results, "total"),
+        IdentifierKind::MemberAccess
+    ));
+    assert_eq!(container_of(&results, "print"), Some("summarise"));
+
+    let top_level = results
+        .identifiers
+        .iter()
+        .find(|identifier| identifier.name == "summarise")
+        .expect("top-level call");
+    assert_eq!(top_level.containing_symbol_id, None);
+    assert_eq!(top_level.start_line, 6);
+    assert_eq!(top_level.start_column, 0);
+    assert_eq!(top_level.code_context.as_deref(), Some("summarise(data)"));
+    assert!(results.identifiers.iter().all(|i| i.language == "r"));
+}
+
+#[test]
+fn test_powershell_commands_and_methods_are_calls() {
+    let content = "\
+function Sync-Items {
+    $items = Get-ChildItem -Path $root
+    $items.Count
+    [System.IO.Path]::Combine($root, 'x')
+}
+";
+    let results = fill(
+        "powershell",
+        "scripts/sync.ps1",
+        content,
+        vec![symbol(
+            "Sync-Items",
+            "powershell",
+            "scripts/sync.ps1",
+            content,
+            content.trim_end(),
+        )],
+    );
+
+    assert!(matches!(
+        kind_of(&results, "Get-ChildItem"),
+        IdentifierKind::Call
+    ));
+    assert_eq!(container_of(&results, "Get-ChildItem"), Some("Sync-Items"));
+    assert!(
+        results
+            .identifiers
+            .iter()
+            .any(|identifier| matches!(identifier.kind, IdentifierKind::TypeUsage)),
+        "{:?}",
+        results.identifiers
+    );
+}
+
+#[test]
+fn test_qml_object_types_and_handler_calls() {
+    let content = "\
+import QtQuick
+
+Rectangle {
+    MouseArea {
+        onClicked: controller.select(index)
+    }
+}
+";
+    let results = fill("qml", "ui/Main.qml", content, Vec::new());
+
+    assert!(matches!(
+        kind_of(&results, "Rectangle"),
+        IdentifierKind::TypeUsage
+    ));
+    assert!(matches!(
+        kind_of(&results, "MouseArea"),
+        IdentifierKind::TypeUsage
+    ));
+    assert!(matches!(kind_of(&results, "select"), IdentifierKind::Call));
+    assert_eq!(
+        results
+            .identifiers
+            .iter()
+            .filter(|identifier| identifier.name == "select")
+            .count(),
+        1,
+        "a method call is not also a member access"
+    );
+}
+
+#[test]
+fn test_gdscript_lua_and_bash_calls() {
+    let gdscript = "\
+extends Node2D
+
+func _ready():
+    print(\"ready\")
+    $Timer.start()
+";
+    let results = fill("gdscript", "scenes/main.gd", gdscript, Vec::new());
+    assert!(matches!(kind_of(&results, "print"), IdentifierKind::Call));
+    assert!(matches!(
+        kind_of(&results, "Node2D"),
+        IdentifierKind::TypeUsage
+    ));
+
+    let lua = "local M = {}\nfunction M.run()\n  util.log(M.name)\n  self:flush()\nend\n";
+    let results = fill("lua", "lua/m.lua", lua, Vec::new());
+    assert!(matches!(kind_of(&results, "log"), IdentifierKind::Call));
+    assert!(matches!(kind_of(&results, "flush"), IdentifierKind::Call));
+    assert!(matches!(
+        kind_of(&results, "name"),
+        IdentifierKind::MemberAccess
+    ));
+
+    let bash = "deploy() {\n  rsync -a dist/ \"$HOST\"\n}\ndeploy\n";
+    let results = fill("bash", "deploy.sh", bash, Vec::new());
+    assert!(matches!(kind_of(&results, "rsync"), IdentifierKind::Call));
+    assert!(matches!(kind_of(&results, "deploy"), IdentifierKind::Call));
+}
+
+#[test]
+fn test_extractor_identifiers_and_other_languages_are_left_alone() {
+    let content = "print(x)\n";
+    let mut results = fill("r", "a.R", content, Vec::new());
+    let reported = results.identifiers.len();
+    assert!(reported > 0);
+    fill_missing_identifiers(&mut results, "r", "a.R", content);
+    assert_eq!(results.identifiers.len(), reported, "not filled twice");
+
+    assert!(
+        fill("rust", "src/lib.rs", "fn a() { b() }\n", Vec::new())
+            .identifiers
+            .is_empty()
+    );
+}
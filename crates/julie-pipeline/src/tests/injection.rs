@@ -0,0 +1,113 @@
+// Tests for embedded-language extraction (indexing_core::injection)
+
+use crate::indexing_core::injection::{
+    INJECTION_METADATA_KEY, find_injections, inject_embedded_languages, looks_like_sql,
+};
+use julie_extractors::Symbol;
+use std::path::Path;
+
+fn embedded(host_language: &str, content: &str) -> Vec<(&'static str, String)> {
+    find_injections(host_language, content)
+        .into_iter()
+        .map(|injection| {
+            (
+                injection.language,
+                content[injection.start_byte..injection.end_byte].to_string(),
+            )
+        })
+        .collect()
+}
+
+fn extract(file_path: &str, host_language: &str, content: &str) -> Vec<Symbol> {
+    let root = Path::new("/workspace");
+    let mut results = julie_extractors::extract_canonical(file_path, content, root).unwrap();
+    inject_embedded_languages(&mut results, host_language, file_path, content, root);
+    results.symbols
+}
+
+fn find<'a>(symbols: &'a [Symbol], name: &str) -> &'a Symbol {
+    symbols
+        .iter()
+        .find(|symbol| symbol.name == name)
+        .unwrap_or_else(|| panic!("missing symbol {name}"))
+}
+
+#[test]
+fn markup_hosts_hand_script_and_style_blocks_to_their_languages() {
+    let page = r#"<!-- <script>commented()</script> -->
+<script src="vendor.js"></script>
+<script type="module">
+function init() {}
+</script>
+<script lang="ts">let count: number = 0</script>
+<script type="application/ld+json">{"@type": "Thing"}</script>
+<script type="text/x-template" id="row"><tr>{{ name }}</tr></script>
+<style scoped>.card { color: red }</style>
+<style lang="scss">.card { .title { color: blue } }</style>
+"#;
+    assert_eq!(
+        embedded("html", page),
+        vec![
+            ("javascript", "\nfunction init() {}\n".to_string()),
+            ("typescript", "let count: number = 0".to_string()),
+            ("html", "<tr>{{ name }}</tr>".to_string()),
+            ("css", ".card { color: red }".to_string()),
+        ]
+    );
+}
+
+#[test]
+fn code_hosts_hand_sql_strings_heredocs_and_tagged_templates() {
+    let python = "# SELECT * FROM comments is not a string\ndef load(db):\n    db.execute(\"SELECT id, name FROM users WHERE id = ?\")\n    print('select a file from disk')\n";
+    assert_eq!(
+        embedded("python", python),
+        vec![("sql", "SELECT id, name FROM users WHERE id = ?".to_string())]
+    );
+
+    let ruby =
+        "def recent\n  <<~SQL.squish\n    SELECT * FROM posts\n  SQL\nend\nclass << self\nend\n";
+    assert_eq!(
+        embedded("ruby", ruby),
+        vec![("sql", "    SELECT * FROM posts".to_string())]
+    );
+
+    let script = "const row = html`<td class=\"${cls}\">${name}</td>`;\nconst q = sql`UPDATE users SET name = ${name}`;\nconst theme = /* css */ `.a { color: red }`;\nconst msg = `hello ${name}`;";
+    let injections = find_injections("typescript", script);
+    let languages: Vec<&str> = injections
+        .iter()
+        .map(|injection| injection.language)
+        .collect();
+    assert_eq!(languages, ["html", "sql", "css"]);
+    assert_eq!(injections[0].holes.len(), 2);
+
+    assert!(looks_like_sql("INSERT INTO audit (event) VALUES ($1)"));
+    assert!(looks_like_sql("create table users (id integer)"));
+    assert!(!looks_like_sql("Update the user set before saving"));
+    assert!(!looks_like_sql("select an option from the list"));
+}
+
+#[test]
+fn injected_symbols_keep_host_positions_and_nest_under_host_symbols() {
+    let page = "<html>\n<body>\n<script>\nfunction init() {\n  return 1;\n}\n</script>\n</body>\n</html>\n";
+    let symbols = extract("web/index.html", "html", page);
+    let init = find(&symbols, "init");
+    assert_eq!(init.language, "javascript");
+    assert_eq!(init.file_path, "web/index.html");
+    assert_eq!((init.start_line, init.end_line), (4, 6));
+    assert_eq!(
+        &page[init.start_byte as usize..init.end_byte as usize],
+        "function init() {\n  return 1;\n}"
+    );
+    assert_eq!(
+        init.metadata.as_ref().unwrap()[INJECTION_METADATA_KEY]["host_language"],
+        "html"
+    );
+
+    let module = "def migrate(db):\n    db.execute(\"\"\"\n        CREATE TABLE accounts (id INTEGER PRIMARY KEY)\n    \"\"\")\n";
+    let symbols = extract("app/migrate.py", "python", module);
+    let migrate = find(&symbols, "migrate");
+    let table = find(&symbols, "accounts");
+    assert_eq!(table.language, "sql");
+    assert_eq!(table.start_line, 3);
+    assert_eq!(table.parent_id.as_ref(), Some(&migrate.id));
+}
@@ -0,0 +1,268 @@
+// Tests for Kotlin Multiplatform expect/actual tagging
+// (indexing_core::kotlin_multiplatform)
+
+use crate::indexing_core::csharp_partials::PARTIAL_GROUP_METADATA_KEY;
+use crate::indexing_core::kotlin_multiplatform::{
+    KOTLIN_MULTIPLATFORM_METADATA_KEY, annotate_expect_actual_declarations,
+};
+use julie_extractors::base::{Symbol, SymbolKind};
+
+/// A symbol spanning the first occurrence of `declaration` in `content`.
+fn sym(
+    id: &str,
+    name: &str,
+    kind: SymbolKind,
+    content: &str,
+    declaration: &str,
+    parent: Option<&str>,
+) -> Symbol {
+    let start = content.find(declaration).expect("declaration in content");
+    Symbol {
+        id: id.to_string(),
+        name: name.to_string(),
+        kind,
+        language: "kotlin".to_string(),
+        file_path: "src/commonMain/kotlin/Platform.kt".to_string(),
+        start_line: 1,
+        start_column: 0,
+        end_line: 1,
+        end_column: 1,
+        start_byte: start as u32,
+        end_byte: (start + declaration.len()) as u32,
+        signature: None,
+        doc_comment: None,
+        visibility: None,
+        parent_id: parent.map(str::to_string),
+        metadata: None,
+        semantic_group: None,
+        confidence: None,
+        code_context: None,
+        content_type: None,
+        body_span: None,
+        body_hash: None,
+        annotations: Vec::new(),
+    }
+}
+
+fn tag<'a>(symbol: &'a Symbol, key: &str) -> Option<&'a str> {
+    symbol
+        .metadata
+        .as_ref()
+        .and_then(|metadata| metadata.get(key))
+        .and_then(|value| value.as_str())
+}
+
+#[test]
+fn expect_declarations_and_their_members_share_groups_with_actuals() {
+    let common = "package app.platform\n\n\
+        expect class Platform() {\n    val name: String\n    fun greet(who: String, times: Int = 1): String\n}\n\n\
+        expect fun platformName(): String\n\n\
+        fun shared(x: Int) = x\n";
+    let mut common_symbols = vec![
+        sym(
+            "p",
+            "Platform",
+            SymbolKind::Class,
+            common,
+            "expect class Platform()",
+            None,
+        ),
+        sym(
+            "name",
+            "name",
+            SymbolKind::Property,
+            common,
+            "val name: String",
+            Some("p"),
+        ),
+        sym(
+            "greet",
+            "greet",
+            SymbolKind::Method,
+            common,
+            "fun greet(who: String, times: Int = 1): String",
+            Some("p"),
+        ),
+        sym(
+            "who",
+            "who",
+            SymbolKind::Variable,
+            common,
+            "who: String",
+            Some("greet"),
+        ),
+        sym(
+            "fn",
+            "platformName",
+            SymbolKind::Function,
+            common,
+            "expect fun platformName(): String",
+            None,
+        ),
+        sym(
+            "shared",
+            "shared",
+            SymbolKind::Function,
+            common,
+            "fun shared(x: Int) = x",
+            None,
+        ),
+    ];
+    annotate_expect_actual_declarations(&mut common_symbols, "kotlin", common);
+
+    let jvm = "package app.platform\n\n\
+        actual class Platform actual constructor() {\n    actual val name: String = \"JVM\"\n    \
+        actual fun greet(who: String, times: Int): String = who.repeat(times)\n}\n\n\
+        @Suppress(\"NOTHING_TO_INLINE\") actual inline fun platformName(): String = \"jvm\"\n";
+    let mut jvm_symbols = vec![
+        sym(
+            "jp",
+            "Platform",
+            SymbolKind::Class,
+            jvm,
+            "actual class Platform",
+            None,
+        ),
+        sym(
+            "jname",
+            "name",
+            SymbolKind::Property,
+            jvm,
+            "actual val name",
+            Some("jp"),
+        ),
+        sym(
+            "jgreet",
+            "greet",
+            SymbolKind::Method,
+            jvm,
+            "actual fun greet(who: String, times: Int): String",
+            Some("jp"),
+        ),
+        sym(
+            "jfn",
+            "platformName",
+            SymbolKind::Function,
+            jvm,
+            "@Suppress(\"NOTHING_TO_INLINE\") actual inline fun platformName(): String",
+            None,
+        ),
+    ];
+    annotate_expect_actual_declarations(&mut jvm_symbols, "kotlin", jvm);
+
+    let roles: Vec<Option<&str>> = common_symbols
+        .iter()
+        .map(|s| tag(s, KOTLIN_MULTIPLATFORM_METADATA_KEY))
+        .collect();
+    assert_eq!(
+        roles,
+        [
+            Some("expect"),
+            Some("expect"),
+            Some("expect"),
+            None,
+            Some("expect"),
+            None
+        ],
+        "members inherit `expect`; parameters and plain functions do not"
+    );
+    assert!(
+        jvm_symbols
+            .iter()
+            .all(|s| tag(s, KOTLIN_MULTIPLATFORM_METADATA_KEY) == Some("actual"))
+    );
+
+    let groups = |symbols: &[Symbol]| -> Vec<Option<String>> {
+        symbols
+            .iter()
+            .filter(|s| tag(s, KOTLIN_MULTIPLATFORM_METADATA_KEY).is_some())
+            .map(|s| tag(s, PARTIAL_GROUP_METADATA_KEY).map(str::to_string))
+            .collect()
+    };
+    assert_eq!(
+        groups(&common_symbols),
+        [
+            Some("kotlin:app.platform.Platform".to_string()),
+            Some("kotlin:app.platform.Platform.name".to_string()),
+            Some("kotlin:app.platform.Platform.greet/2".to_string()),
+            Some("kotlin:app.platform.platformName/0".to_string()),
+        ]
+    );
+    assert_eq!(
+        groups(&jvm_symbols),
+        groups(&common_symbols),
+        "defaults on the expect side do not split the group"
+    );
+}
+
+#[test]
+fn overloads_and_function_types_are_counted_by_parameter() {
+    let content = "package p\n\
+        expect fun run(block: (Int) -> Unit)\n\
+        expect fun run(name: String, block: () -> Unit,)\n";
+    let mut symbols = vec![
+        sym(
+            "a",
+            "run",
+            SymbolKind::Function,
+            content,
+            "expect fun run(block: (Int) -> Unit)",
+            None,
+        ),
+        sym(
+            "b",
+            "run",
+            SymbolKind::Function,
+            content,
+            "expect fun run(name: String, block: () -> Unit,)",
+            None,
+        ),
+    ];
+    annotate_expect_actual_declarations(&mut symbols, "kotlin", content);
+
+    assert_eq!(
+        tag(&symbols[0], PARTIAL_GROUP_METADATA_KEY),
+        Some("kotlin:p.run/1")
+    );
+    assert_eq!(
+        tag(&symbols[1], PARTIAL_GROUP_METADATA_KEY),
+        Some("kotlin:p.run/2")
+    );
+}
+
+#[test]
+fn names_and_other_languages_are_not_modifiers() {
+    let content = "class ExpectedValue { val actual = 1 }\n";
+    let mut symbols = vec![
+        sym(
+            "c",
+            "ExpectedValue",
+            SymbolKind::Class,
+            content,
+            "class ExpectedValue { val actual = 1 }",
+            None,
+        ),
+        sym(
+            "v",
+            "actual",
+            SymbolKind::Property,
+            content,
+            "val actual = 1",
+            Some("c"),
+        ),
+    ];
+    annotate_expect_actual_declarations(&mut symbols, "kotlin", content);
+    assert!(symbols.iter().all(|s| s.metadata.is_none()));
+
+    let swift = "expect fun f()\n";
+    let mut symbols = vec![sym(
+        "f",
+        "f",
+        SymbolKind::Function,
+        swift,
+        "expect fun f()",
+        None,
+    )];
+    annotate_expect_actual_declarations(&mut symbols, "swift", swift);
+    assert!(symbols[0].metadata.is_none());
+}
@@ -0,0 +1,85 @@
+// Tests for the shared tokens of the text-scanning extractors (indexing_core::lexer)
+
+use crate::indexing_core::lexer::{
+    Lexer, TokenKind, block_end, comment_lines, doubled_quote_end, escaped_quote_end,
+    join_comments, line_end, punct_len, word_end,
+};
+
+#[test]
+fn tokens_and_comments_carry_their_lines() {
+    let content = "port a; // clock\n\"multi\nline\" /* after */\n// above\nb";
+    let mut lexer = Lexer::new(content);
+    lexer.push(TokenKind::Word, 0, 4);
+    lexer.push(TokenKind::Word, 5, 6);
+    lexer.push_comment(8, 16);
+    lexer.push(TokenKind::Str, 17, 29);
+    lexer.push_comment(30, 41);
+    lexer.push_comment(42, 50);
+    let column = lexer.push(TokenKind::Word, 51, 52).column;
+    assert_eq!(column, 0);
+    assert_eq!(lexer.line_start_of(36), 24);
+
+    let (tokens, comments) = lexer.finish();
+    assert_eq!(tokens[1].text, "a");
+    assert_eq!((tokens[1].line, tokens[1].column), (1, 5));
+    assert_eq!((tokens[2].line, tokens[2].end_line), (2, 3));
+    assert_eq!(tokens[3].line, 5);
+
+    let summary: Vec<_> = comments
+        .iter()
+        .map(|comment| (comment.text, comment.line, comment.trailing))
+        .collect();
+    assert_eq!(
+        summary,
+        [
+            ("// clock", 1, true),
+            ("/* after */", 3, true),
+            ("// above", 4, false),
+        ]
+    );
+}
+
+#[test]
+fn unterminated_strings_and_comments_run_out_instead_of_failing() {
+    let content = "x = \"open\ny = 'it''s' /* never closed";
+    let bytes = content.as_bytes();
+    assert_eq!(escaped_quote_end(bytes, 5, b'"'), 9, "stops at the newline");
+    assert_eq!(escaped_quote_end(br#""a\"b" c"#, 1, b'"'), 6);
+    assert_eq!(doubled_quote_end(bytes, 15, content.len(), b'\''), 21);
+    assert_eq!(doubled_quote_end(b"'abc", 1, 3, b'\''), 3);
+    assert_eq!(block_end(content, 24, "*/"), content.len());
+    assert_eq!(block_end("/* a */ b", 2, "*/"), 7);
+    assert_eq!(line_end(content, 0), 9);
+    assert_eq!(line_end(content, 10), content.len());
+}
+
+#[test]
+fn words_and_punctuation_end_where_their_rules_say() {
+    let is_word_char = |c: char| c.is_alphanumeric() || c == '_';
+    assert_eq!(word_end("Flight_1.Mode", 0, is_word_char), 8);
+    assert_eq!(word_end("héllo world", 0, is_word_char), 6);
+    assert_eq!(punct_len(":= 1", &["=>", ":="]), 2);
+    assert_eq!(punct_len(":: x", &["=>", ":="]), 1);
+    assert_eq!(punct_len("é", &[]), 2);
+}
+
+#[test]
+fn comment_markers_are_stripped_from_doc_lines() {
+    assert_eq!(comment_lines("# region name"), ["region name"]);
+    assert_eq!(
+        comment_lines("/**\n * Clock input.\n *\n * Active high.\n */"),
+        ["Clock input.", "Active high."]
+    );
+
+    let content = "-- Adds two numbers.\n-- -----------\n/* Returns\n * the sum. */";
+    let mut lexer = Lexer::new(content);
+    lexer.push_comment(0, 20);
+    lexer.push_comment(21, 35);
+    lexer.push_comment(36, content.len());
+    let (_, comments) = lexer.finish();
+    assert_eq!(
+        join_comments(comments.iter()).as_deref(),
+        Some("Adds two numbers.\nReturns\nthe sum.")
+    );
+    assert_eq!(join_comments(comments[1..2].iter()), None);
+}
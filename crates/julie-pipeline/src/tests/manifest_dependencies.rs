@@ -0,0 +1,285 @@
+// Tests for package-manifest dependency symbols (indexing_core::manifest_dependencies)
+
+use crate::indexing_core::manifest_dependencies::{
+    DEPENDENCY_METADATA_KEY, DEPENDENCY_SEMANTIC_GROUP, ManifestDependency, ManifestKind,
+    annotate_manifest_dependencies, parse_manifest_dependencies,
+};
+use julie_extractors::base::{Symbol, SymbolKind};
+
+const CARGO_TOML: &str = r#"[package]
+name = "demo"
+version = "0.1.0"
+
+[dependencies]
+serde = { version = "1.0", features = ["derive"] } # serialization
+anyhow = "1.0"
+local = { path = "../local" }
+tokio.workspace = true
+renamed = { package = "real-name", version = "0.3" }
+
+[dev-dependencies]
+tempfile = "3"
+
+[target.'cfg(unix)'.dependencies.libc]
+version = "0.2"
+"#;
+
+const PACKAGE_JSON: &str = r#"{
+  "name": "web",
+  "version": "1.0.0",
+  "scripts": { "test": "jest" },
+  "dependencies": {
+    "lodash": "^4.17.21",
+    "@scope/pkg": "2.0.0"
+  },
+  "devDependencies": {
+    "jest": "^29.0.0"
+  }
+}
+"#;
+
+const PYPROJECT_TOML: &str = r#"[build-system]
+requires = ["setuptools>=61"]
+
+[project]
+name = "svc"
+dependencies = [
+    "requests[socks]>=2.31; python_version >= '3.8'",
+    "click",
+]
+
+[project.optional-dependencies]
+dev = ["pytest>=7"]
+
+[tool.poetry.dependencies]
+python = "^3.11"
+httpx = "^0.27"
+"#;
+
+const GO_MOD: &str = "module example.com/svc
+
+go 1.22
+
+require github.com/spf13/cobra v1.8.0
+
+require (
+\tgolang.org/x/sync v0.7.0
+\tgithub.com/pkg/errors v0.9.1 // indirect
+)
+";
+
+const POM_XML: &str = r#"<project>
+  <groupId>com.example</groupId>
+  <artifactId>app</artifactId>
+  <dependencyManagement>
+    <dependencies>
+      <dependency>
+        <groupId>org.springframework</groupId>
+        <artifactId>spring-bom</artifactId>
+        <version>6.1.0</version>
+      </dependency>
+    </dependencies>
+  </dependencyManagement>
+  <dependencies>
+    <dependency>
+      <groupId>com.google.guava</groupId>
+      <artifactId>guava</artifactId>
+      <version>33.0.0-jre</version>
+      <exclusions>
+        <exclusion>
+          <groupId>com.google.code.findbugs</groupId>
+          <artifactId>jsr305</artifactId>
+        </exclusion>
+      </exclusions>
+    </dependency>
+    <dependency>
+      <groupId>junit</groupId>
+      <artifactId>junit</artifactId>
+      <version>4.13.2</version>
+      <scope>test</scope>
+    </dependency>
+  </dependencies>
+</project>
+"#;
+
+fn find<'a>(dependencies: &'a [ManifestDependency], name: &str) -> &'a ManifestDependency {
+    dependencies
+        .iter()
+        .find(|d| d.name == name)
+        .unwrap_or_else(|| panic!("missing dependency {name}"))
+}
+
+fn names(dependencies: &[ManifestDependency]) -> Vec<&str> {
+    dependencies.iter().map(|d| d.name.as_str()).collect()
+}
+
+#[test]
+fn detects_manifests_by_file_name() {
+    assert_eq!(
+        ManifestKind::detect("crates/core/Cargo.toml"),
+        Some(ManifestKind::Cargo)
+    );
+    assert_eq!(
+        ManifestKind::detect("package.json"),
+        Some(ManifestKind::Npm)
+    );
+    assert_eq!(
+        ManifestKind::detect("svc/go.mod"),
+        Some(ManifestKind::GoMod)
+    );
+    assert_eq!(ManifestKind::detect("pom.xml"), Some(ManifestKind::Maven));
+    assert_eq!(ManifestKind::detect("config/settings.toml"), None);
+}
+
+#[test]
+fn cargo_dependencies_cover_tables_and_inline_forms() {
+    let deps = parse_manifest_dependencies(ManifestKind::Cargo, CARGO_TOML);
+    assert_eq!(
+        names(&deps),
+        [
+            "serde", "anyhow", "local", "tokio", "renamed", "tempfile", "libc"
+        ]
+    );
+
+    let serde = find(&deps, "serde");
+    assert_eq!(serde.version.as_deref(), Some("1.0"));
+    assert_eq!(serde.section, "dependencies");
+    assert_eq!(serde.line, 6);
+    assert_eq!(find(&deps, "local").version, None);
+    assert_eq!(find(&deps, "renamed").package, "real-name");
+    assert_eq!(find(&deps, "tempfile").section, "dev-dependencies");
+
+    let libc = find(&deps, "libc");
+    assert_eq!(libc.version.as_deref(), Some("0.2"));
+    assert_eq!(libc.section, "target.'cfg(unix)'.dependencies");
+    assert_eq!(libc.line, 15);
+}
+
+#[test]
+fn package_json_dependencies_by_section() {
+    let deps = parse_manifest_dependencies(ManifestKind::Npm, PACKAGE_JSON);
+    assert_eq!(names(&deps), ["lodash", "@scope/pkg", "jest"]);
+
+    let lodash = find(&deps, "lodash");
+    assert_eq!(lodash.version.as_deref(), Some("^4.17.21"));
+    assert_eq!(lodash.section, "dependencies");
+    assert_eq!((lodash.line, lodash.column), (6, 4));
+    assert_eq!(lodash.declaration, "\"lodash\": \"^4.17.21\"");
+    assert_eq!(find(&deps, "jest").section, "devDependencies");
+}
+
+#[test]
+fn pyproject_dependencies_cover_pep508_and_poetry() {
+    let deps = parse_manifest_dependencies(ManifestKind::Pyproject, PYPROJECT_TOML);
+    assert_eq!(
+        names(&deps),
+        ["setuptools", "requests", "click", "pytest", "httpx"]
+    );
+
+    let requests = find(&deps, "requests");
+    assert_eq!(requests.version.as_deref(), Some(">=2.31"));
+    assert_eq!(requests.section, "project.dependencies");
+    assert_eq!(requests.line, 7);
+    assert_eq!(find(&deps, "click").version, None);
+    assert_eq!(
+        find(&deps, "pytest").section,
+        "project.optional-dependencies.dev"
+    );
+    assert_eq!(find(&deps, "httpx").section, "tool.poetry.dependencies");
+}
+
+#[test]
+fn go_mod_requires_single_and_block() {
+    let deps = parse_manifest_dependencies(ManifestKind::GoMod, GO_MOD);
+    assert_eq!(
+        names(&deps),
+        [
+            "github.com/spf13/cobra",
+            "golang.org/x/sync",
+            "github.com/pkg/errors"
+        ]
+    );
+    assert_eq!(
+        find(&deps, "github.com/spf13/cobra").version.as_deref(),
+        Some("v1.8.0")
+    );
+    assert_eq!(find(&deps, "golang.org/x/sync").section, "require");
+    assert_eq!(find(&deps, "github.com/pkg/errors").section, "indirect");
+}
+
+#[test]
+fn pom_dependencies_skip_exclusions() {
+    let deps = parse_manifest_dependencies(ManifestKind::Maven, POM_XML);
+    assert_eq!(names(&deps), ["spring-bom", "guava", "junit"]);
+
+    let guava = find(&deps, "guava");
+    assert_eq!(guava.package, "com.google.guava:guava");
+    assert_eq!(guava.version.as_deref(), Some("33.0.0-jre"));
+    assert_eq!(guava.section, "compile");
+    assert_eq!(guava.line, 16);
+    assert_eq!(find(&deps, "junit").section, "test");
+    assert_eq!(find(&deps, "spring-bom").section, "dependencyManagement");
+}
+
+#[test]
+fn extractor_key_symbols_are_retagged_and_missing_ones_synthesized() {
+    let existing = Symbol {
+        id: "lodash_key".to_string(),
+        name: "lodash".to_string(),
+        kind: SymbolKind::Property,
+        language: "json".to_string(),
+        file_path: "web/package.json".to_string(),
+        start_line: 6,
+        start_column: 4,
+        end_line: 6,
+        end_column: 24,
+        start_byte: 0,
+        end_byte: 1,
+        signature: None,
+        doc_comment: None,
+        visibility: None,
+        parent_id: None,
+        metadata: None,
+        semantic_group: None,
+        confidence: None,
+        code_context: None,
+        content_type: None,
+        body_span: None,
+        body_hash: None,
+        annotations: Vec::new(),
+    };
+    let mut symbols = vec![existing];
+    annotate_manifest_dependencies(&mut symbols, "web/package.json", "json", PACKAGE_JSON);
+
+    assert_eq!(symbols.len(), 3);
+    let lodash = &symbols[0];
+    assert_eq!(lodash.id, "lodash_key");
+    assert_eq!(lodash.kind, SymbolKind::Import);
+    assert_eq!(
+        lodash.semantic_group.as_deref(),
+        Some(DEPENDENCY_SEMANTIC_GROUP)
+    );
+    let info = &lodash.metadata.as_ref().unwrap()[DEPENDENCY_METADATA_KEY];
+    assert_eq!(info["ecosystem"], "npm");
+    assert_eq!(info["version"], "^4.17.21");
+
+    let jest = symbols.iter().find(|s| s.name == "jest").unwrap();
+    assert_eq!(jest.kind, SymbolKind::Import);
+    assert_eq!(jest.file_path, "web/package.json");
+    assert_eq!(jest.start_line, 10);
+    assert_eq!(jest.signature.as_deref(), Some("\"jest\": \"^29.0.0\""));
+    let start = jest.start_byte as usize;
+    assert!(PACKAGE_JSON[start..].starts_with("\"jest\""));
+}
+
+#[test]
+fn text_only_manifests_get_symbols_and_others_are_untouched() {
+    let mut symbols = Vec::new();
+    annotate_manifest_dependencies(&mut symbols, "go.mod", "text", GO_MOD);
+    assert_eq!(symbols.len(), 3);
+    assert!(symbols.iter().all(|s| s.language == "text"));
+
+    let mut symbols = Vec::new();
+    annotate_manifest_dependencies(&mut symbols, "config.toml", "toml", CARGO_TOML);
+    assert!(symbols.is_empty());
+}
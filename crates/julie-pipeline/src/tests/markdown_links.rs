@@ -0,0 +1,333 @@
+// Tests for Markdown documentation links (indexing_core::markdown_links)
+
+use crate::indexing_core::markdown_links::{
+    DOC_ANCHOR_METADATA_KEY, heading_anchor, is_doc_link, link_markdown_references,
+    resolve_doc_links, section_anchor,
+};
+use julie_core::database::{FileInfo, SymbolDatabase};
+use julie_extractors::{ExtractionResults, RelationshipKind, Symbol, SymbolKind};
+use tempfile::TempDir;
+
+const ARCHITECTURE_MD: &str = r#"# Architecture
+
+Start at [the server](../src/server.rs) and `src/auth.rs:3`.
+
+## Auth Flow
+
+`authenticate()` checks a `Session::refresh` token; see [setup](#setup) and [RFC](https://example.com/rfc).
+
+```rust title="src/auth.rs"
+let user = authenticate(token)?;
+log::info!("signed in");
+```
+
+## Setup
+
+Run `cargo test` and `id`, then [edit](../config.toml).
+
+## Auth Flow
+[back](#auth-flow)
+"#;
+
+fn sym(
+    id: &str,
+    name: &str,
+    kind: SymbolKind,
+    language: &str,
+    file_path: &str,
+    (start_line, end_line): (u32, u32),
+) -> Symbol {
+    Symbol {
+        id: id.to_string(),
+        name: name.to_string(),
+        kind,
+        language: language.to_string(),
+        file_path: file_path.to_string(),
+        start_line,
+        start_column: 0,
+        end_line,
+        end_column: 1,
+        start_byte: 0,
+        end_byte: 0,
+        signature: None,
+        doc_comment: None,
+        visibility: None,
+        parent_id: None,
+        metadata: None,
+        semantic_group: None,
+        confidence: None,
+        code_context: None,
+        content_type: None,
+        body_span: None,
+        body_hash: None,
+        annotations: Vec::new(),
+    }
+}
+
+/// The sections julie-extractors reports for `ARCHITECTURE_MD`, plus the
+/// code of its fenced block extracted as Rust.
+fn architecture_results() -> ExtractionResults {
+    let doc = "docs/architecture.md";
+    let mut results = ExtractionResults::empty();
+    results.symbols = vec![
+        sym(
+            "s_setup",
+            "Setup",
+            SymbolKind::Module,
+            "markdown",
+            doc,
+            (14, 17),
+        ),
+        sym(
+            "s_arch",
+            "Architecture",
+            SymbolKind::Module,
+            "markdown",
+            doc,
+            (1, 19),
+        ),
+        sym(
+            "s_auth",
+            "Auth Flow",
+            SymbolKind::Module,
+            "markdown",
+            doc,
+            (5, 13),
+        ),
+        sym(
+            "s_auth2",
+            "Auth Flow",
+            SymbolKind::Module,
+            "markdown",
+            doc,
+            (18, 19),
+        ),
+        sym("user", "user", SymbolKind::Variable, "rust", doc, (10, 10)),
+    ];
+    results
+}
+
+#[test]
+fn heading_anchors_follow_github() {
+    assert_eq!(heading_anchor("Auth Flow"), "auth-flow");
+    assert_eq!(
+        heading_anchor("## What's `new` in v2.0?"),
+        "whats-new-in-v20"
+    );
+    assert_eq!(
+        heading_anchor("snake_case & kebab-case"),
+        "snake_case--kebab-case"
+    );
+}
+
+#[test]
+fn sections_get_anchors_and_record_their_mentions() {
+    let mut results = architecture_results();
+    let linked = link_markdown_references(
+        &mut results,
+        "docs/architecture.md",
+        "markdown",
+        ARCHITECTURE_MD,
+    );
+
+    let anchors: Vec<(&str, Option<&str>)> = results
+        .symbols
+        .iter()
+        .map(|symbol| (symbol.id.as_str(), section_anchor(symbol)))
+        .collect();
+    assert_eq!(
+        anchors,
+        [
+            ("s_setup", Some("setup")),
+            ("s_arch", Some("architecture")),
+            ("s_auth", Some("auth-flow")),
+            ("s_auth2", Some("auth-flow-1")),
+            ("user", None),
+        ],
+        "a repeated heading gets a numbered anchor, and code is not a section"
+    );
+
+    let links: Vec<(&str, &str, u32)> = results
+        .pending_relationships
+        .iter()
+        .map(|p| {
+            (
+                p.from_symbol_id.as_str(),
+                p.callee_name.as_str(),
+                p.line_number,
+            )
+        })
+        .collect();
+    assert_eq!(
+        links,
+        [
+            ("s_arch", "doc-file:src/server.rs", 3),
+            ("s_arch", "doc-file:src/auth.rs#L3", 3),
+            ("s_auth", "doc-file:docs/architecture.md#setup", 7),
+            ("s_auth", "doc-symbol:authenticate", 7),
+            ("s_auth", "doc-symbol:refresh", 7),
+            ("s_auth", "doc-file:src/auth.rs", 9),
+            ("s_setup", "doc-file:config.toml", 16),
+            ("s_auth2", "doc-file:docs/architecture.md#auth-flow", 19),
+        ],
+        "URLs, commands, short names, macros and repeat mentions are skipped"
+    );
+    assert_eq!(linked, links.len());
+    assert!(results.pending_relationships.iter().all(is_doc_link));
+    assert!(
+        results
+            .pending_relationships
+            .iter()
+            .all(|p| p.kind == RelationshipKind::References)
+    );
+}
+
+#[test]
+fn other_languages_are_left_alone() {
+    let mut results = architecture_results();
+    assert_eq!(
+        link_markdown_references(
+            &mut results,
+            "docs/architecture.md",
+            "rust",
+            ARCHITECTURE_MD
+        ),
+        0
+    );
+    assert!(results.pending_relationships.is_empty());
+    assert!(
+        results
+            .symbols
+            .iter()
+            .all(|symbol| symbol.metadata.is_none())
+    );
+}
+
+#[test]
+fn doc_links_resolve_to_lines_sections_files_and_names() {
+    let temp_dir = TempDir::new().unwrap();
+    let mut db = SymbolDatabase::new(temp_dir.path().join("test.db")).unwrap();
+
+    let mut doc = architecture_results();
+    link_markdown_references(
+        &mut doc,
+        "docs/architecture.md",
+        "markdown",
+        ARCHITECTURE_MD,
+    );
+    let mut login = sym(
+        "login",
+        "login",
+        SymbolKind::Function,
+        "rust",
+        "src/auth.rs",
+        (2, 4),
+    );
+    login.parent_id = Some("authenticate".to_string());
+    let mut start = sym(
+        "start",
+        "start",
+        SymbolKind::Method,
+        "rust",
+        "src/server.rs",
+        (3, 5),
+    );
+    start.parent_id = Some("server".to_string());
+    let mut symbols = vec![
+        sym(
+            "authenticate",
+            "authenticate",
+            SymbolKind::Function,
+            "rust",
+            "src/auth.rs",
+            (1, 10),
+        ),
+        login,
+        sym(
+            "server",
+            "Server",
+            SymbolKind::Struct,
+            "rust",
+            "src/server.rs",
+            (1, 8),
+        ),
+        start,
+        // An example in another document is not the function it shows.
+        sym(
+            "example",
+            "authenticate",
+            SymbolKind::Function,
+            "rust",
+            "docs/example.md",
+            (3, 5),
+        ),
+    ];
+    // Four methods share the name `refresh`, too many to tell which one the
+    // doc means.
+    for file in ["a", "b", "c", "d"] {
+        symbols.push(sym(
+            &format!("refresh_{file}"),
+            "refresh",
+            SymbolKind::Method,
+            "rust",
+            &format!("src/{file}.rs"),
+            (1, 2),
+        ));
+    }
+    symbols.extend(doc.symbols.iter().cloned());
+    let mut files: Vec<(String, &str)> = symbols
+        .iter()
+        .map(|symbol| (symbol.file_path.clone(), symbol.language.as_str()))
+        .collect();
+    files.sort();
+    files.dedup_by(|a, b| a.0 == b.0);
+    for (path, language) in files {
+        db.store_file_info(&FileInfo {
+            path,
+            language: language.to_string(),
+            hash: "h".to_string(),
+            size: 100,
+            last_modified: 1000,
+            last_indexed: 0,
+            symbol_count: 0,
+            line_count: 0,
+            content: None,
+        })
+        .unwrap();
+    }
+    db.store_symbols_transactional(&symbols).unwrap();
+
+    let resolved = resolve_doc_links(&doc.pending_relationships, &db);
+    let mut edges: Vec<(&str, &str, u32, Option<&str>)> = resolved
+        .iter()
+        .map(|r| {
+            (
+                r.from_symbol_id.as_str(),
+                r.to_symbol_id.as_str(),
+                r.line_number,
+                r.metadata
+                    .as_ref()
+                    .and_then(|metadata| metadata.get(DOC_ANCHOR_METADATA_KEY))
+                    .and_then(|anchor| anchor.as_str()),
+            )
+        })
+        .collect();
+    edges.sort();
+    assert_eq!(
+        edges,
+        [
+            ("s_arch", "login", 3, Some("architecture")),
+            ("s_arch", "server", 3, Some("architecture")),
+            ("s_auth", "authenticate", 7, Some("auth-flow")),
+            ("s_auth", "authenticate", 9, Some("auth-flow")),
+            ("s_auth", "s_setup", 7, Some("auth-flow")),
+            ("s_auth2", "s_auth", 19, Some("auth-flow-1")),
+        ],
+        "config.toml has no symbols and `refresh` is ambiguous"
+    );
+    assert!(
+        resolved.iter().all(
+            |r| r.kind == RelationshipKind::References && r.file_path == "docs/architecture.md"
+        )
+    );
+}
@@ -1,11 +1,43 @@
+pub mod ada;
 pub mod batch_resolver;
+pub mod build_targets;
+pub mod c_macros;
+pub mod checkpoint;
+pub mod config_keys;
+pub mod csharp_partials;
+pub mod delimited_data;
+pub mod embedding_chunking;
 pub mod embedding_deps;
 pub mod embedding_metadata;
 pub mod embedding_metadata_enrichment;
 pub mod embedding_sidecar_protocol;
+pub mod fortran;
+pub mod generated_outputs;
+pub mod godot_scenes;
+pub mod groovy;
 pub mod host_server_test;
 pub mod host_transport_test;
+pub mod identifiers;
+pub mod injection;
+pub mod kotlin_multiplatform;
+pub mod lexer;
+pub mod manifest_dependencies;
+pub mod markdown_links;
+pub mod notebook;
+pub mod openapi;
+pub mod partial_parse;
+pub mod preprocessor;
+pub mod protobuf;
+pub mod razor_components;
 pub mod rpc_client_test;
+pub mod shards;
 pub mod sidecar_embedding_tests;
 pub mod sidecar_supervisor_tests;
+pub mod signatures;
+pub mod sql_dialects;
+pub mod symbol_options;
+pub mod task_comments;
+pub mod terraform;
+pub mod text_lines;
+pub mod verilog;
 pub mod web_edges;
@@ -0,0 +1,188 @@
+// Tests for notebook cell extraction (indexing_core::notebook)
+
+use crate::indexing_core::notebook::{
+    NOTEBOOK_METADATA_KEY, extract_notebook, parse_jupyter_cells, parse_rmarkdown_cells,
+};
+use std::path::Path;
+
+// Pretty-printed the way Jupyter saves notebooks: one source line per JSON line.
+const ANALYSIS_IPYNB: &str = r##"{
+ "cells": [
+  {
+   "cell_type": "markdown",
+   "metadata": {},
+   "source": [
+    "# Sales analysis\n",
+    "Loads the quarterly export."
+   ]
+  },
+  {
+   "cell_type": "code",
+   "execution_count": 1,
+   "metadata": {"tags": ["setup"]},
+   "outputs": [],
+   "source": [
+    "%matplotlib inline\n",
+    "def load_data(path):\n",
+    "    return open(path).read()"
+   ]
+  },
+  {
+   "cell_type": "raw",
+   "metadata": {},
+   "source": "not code"
+  },
+  {
+   "cell_type": "code",
+   "execution_count": 2,
+   "metadata": {},
+   "outputs": [{"name": "stdout", "output_type": "stream", "text": ["\"source\": [\n"]}],
+   "source": [
+    "rows = load_data(\"sales.csv\")"
+   ]
+  },
+  {
+   "cell_type": "code",
+   "execution_count": 3,
+   "metadata": {},
+   "outputs": [],
+   "source": "%%bash\nls data"
+  }
+ ],
+ "metadata": {
+  "kernelspec": {"display_name": "Python 3", "language": "python", "name": "python3"}
+ },
+ "nbformat": 4,
+ "nbformat_minor": 5
+}
+"##;
+
+const REPORT_RMD: &str = r#"---
+title: "Report"
+output: html_document
+---
+
+## Summary
+
+```{r setup, include=FALSE}
+summarise_sales <- function(df) {
+  aggregate(amount ~ region, df, sum)
+}
+```
+
+Totals by region:
+
+```{python}
+def total(values):
+    return sum(values)
+```
+"#;
+
+#[test]
+fn parses_jupyter_cells_with_file_positions() {
+    let cells = parse_jupyter_cells(ANALYSIS_IPYNB).unwrap();
+    let summary: Vec<_> = cells
+        .iter()
+        .map(|cell| (cell.number, cell.language.as_str(), cell.lines.len()))
+        .collect();
+    assert_eq!(
+        summary,
+        [
+            (1, "markdown", 2),
+            (2, "python", 3),
+            (4, "python", 1),
+            (5, "bash", 2)
+        ],
+        "raw cells are skipped but keep their cell number"
+    );
+
+    let setup = &cells[1];
+    assert_eq!(setup.lines[0], "", "line magics are blanked");
+    assert_eq!(setup.lines[1], "def load_data(path):");
+    // File lines of the three source strings.
+    let lines: Vec<u32> = setup.positions.iter().map(|(line, _)| *line).collect();
+    assert_eq!(lines, [17, 18, 19]);
+    let (_, byte) = setup.positions[1];
+    assert!(ANALYSIS_IPYNB[byte..].starts_with("def load_data"));
+
+    assert_eq!(
+        cells[2].positions[0].0, 33,
+        "a quoted `\"source\"` inside an output is not mistaken for the cell source"
+    );
+    assert_eq!(cells[3].lines, ["", "ls data"]);
+    assert_eq!(cells[3].positions, [cells[3].positions[0]; 2]);
+}
+
+#[test]
+fn parses_rmarkdown_chunks_and_prose() {
+    let cells = parse_rmarkdown_cells(REPORT_RMD);
+    let summary: Vec<_> = cells
+        .iter()
+        .map(|cell| (cell.number, cell.language.as_str(), cell.lines.len()))
+        .collect();
+    assert_eq!(
+        summary,
+        [
+            (1, "markdown", 3),
+            (2, "r", 3),
+            (3, "markdown", 3),
+            (4, "python", 2)
+        ],
+        "front matter is dropped; fences delimit chunks"
+    );
+    assert_eq!(cells[1].lines[0], "summarise_sales <- function(df) {");
+    assert_eq!(cells[1].positions[0].0, 9);
+    let (_, byte) = cells[3].positions[0];
+    assert!(REPORT_RMD[byte..].starts_with("def total(values):"));
+}
+
+#[test]
+fn extracted_symbols_map_back_to_notebook_cells() {
+    let results =
+        extract_notebook("notebooks/analysis.ipynb", ANALYSIS_IPYNB, Path::new(".")).unwrap();
+
+    let load_data = results
+        .symbols
+        .iter()
+        .find(|symbol| symbol.name == "load_data")
+        .expect("function defined in a code cell");
+    assert_eq!(load_data.file_path, "notebooks/analysis.ipynb");
+    assert_eq!(load_data.language, "python");
+    assert_eq!(load_data.start_line, 18);
+    assert_eq!(load_data.end_line, 19);
+    let cell = &load_data.metadata.as_ref().unwrap()[NOTEBOOK_METADATA_KEY];
+    assert_eq!(cell["cell"], 2);
+    assert_eq!(cell["cell_line"], 2);
+
+    let call = results
+        .identifiers
+        .iter()
+        .find(|identifier| identifier.name == "load_data" && identifier.start_line != 18)
+        .expect("call in a later cell");
+    assert_eq!(call.file_path, "notebooks/analysis.ipynb");
+    assert_eq!(call.start_line, 33);
+
+    assert!(
+        results
+            .symbols
+            .iter()
+            .any(|symbol| symbol.language == "markdown" && symbol.name.contains("Sales analysis")),
+        "markdown cells are indexed as documentation"
+    );
+}
+
+#[test]
+fn extracted_rmarkdown_lines_are_exact() {
+    let results = extract_notebook("reports/report.Rmd", REPORT_RMD, Path::new(".")).unwrap();
+    let total = results
+        .symbols
+        .iter()
+        .find(|symbol| symbol.name == "total")
+        .expect("python chunk function");
+    assert_eq!(total.start_line, 17);
+    assert!(REPORT_RMD[total.start_byte as usize..].starts_with("def total"));
+    assert_eq!(
+        total.metadata.as_ref().unwrap()[NOTEBOOK_METADATA_KEY]["cell"],
+        4
+    );
+}
@@ -0,0 +1,291 @@
+// Tests for OpenAPI / Swagger spec symbols (indexing_core::openapi)
+
+use crate::indexing_core::openapi::{
+    OPENAPI_METADATA_KEY, OPENAPI_SEMANTIC_GROUP, annotate_openapi_spec, parse_openapi_spec,
+};
+use julie_extractors::ExtractionResults;
+use julie_extractors::base::{RelationshipKind, Symbol, SymbolKind};
+
+const PETSTORE_YAML: &str = r##"openapi: 3.0.3
+info:
+  title: Petstore   # trailing comment
+  description: |
+    Multi-line
+    description
+servers:
+  - url: https://api.example.com/v1
+paths:
+  /pets/{petId}:
+    summary: A single pet
+    get:
+      operationId: getPetById
+      summary: Fetch a pet
+      tags: [pets, "read"]
+      parameters:
+      - name: petId
+        in: path
+        required: true
+        schema: {type: string}
+      responses:
+        '200':
+          content:
+            application/json:
+              schema:
+                $ref: '#/components/schemas/Pet'
+    delete:
+      responses: {"204": {description: gone}}
+components:
+  schemas:
+    Pet:
+      type: object
+      description: A pet in the store
+      required:
+        - id
+      properties:
+        id: {type: integer, format: int64}
+        tags:
+          type: array
+          items:
+            $ref: "#/components/schemas/Tag"
+        status:
+          $ref: '#/components/schemas/Status'
+    Tag:
+      type: object
+      properties:
+        name:
+          type: string
+    Status:
+      type: string
+      enum: [available, sold]
+"##;
+
+const SWAGGER_JSON: &str = r##"{
+  "swagger": "2.0",
+  "basePath": "/api/",
+  "paths": {
+    "/users/{id}": {
+      "parameters": [],
+      "get": {
+        "operationId": "get_user",
+        "description": "Get a \"user\"",
+        "responses": { "200": { "schema": { "$ref": "#/definitions/User" } } }
+      }
+    }
+  },
+  "definitions": {
+    "User": {
+      "type": "object",
+      "properties": {
+        "name": { "type": "string" },
+        "friends": { "type": "array", "items": { "$ref": "#/definitions/User" } }
+      }
+    }
+  }
+}"##;
+
+fn find<'a>(symbols: &'a [Symbol], name: &str) -> &'a Symbol {
+    symbols
+        .iter()
+        .find(|symbol| symbol.name == name)
+        .unwrap_or_else(|| panic!("missing symbol {name}"))
+}
+
+fn info(symbol: &Symbol) -> &serde_json::Value {
+    &symbol.metadata.as_ref().unwrap()[OPENAPI_METADATA_KEY]
+}
+
+fn annotate(file_path: &str, language: &str, content: &str) -> ExtractionResults {
+    let mut results = ExtractionResults::empty();
+    annotate_openapi_spec(&mut results, file_path, language, content);
+    results
+}
+
+#[test]
+fn test_yaml_paths_become_namespaces_with_operations() {
+    let results = annotate("api/petstore.yaml", "yaml", PETSTORE_YAML);
+    let symbols = &results.symbols;
+
+    let route = find(symbols, "/pets/{petId}");
+    assert_eq!(route.kind, SymbolKind::Namespace);
+    assert_eq!(route.doc_comment.as_deref(), Some("A single pet"));
+    assert_eq!(route.start_line, 10);
+
+    let get = find(symbols, "getPetById");
+    assert_eq!(get.kind, SymbolKind::Method);
+    assert_eq!(get.signature.as_deref(), Some("GET /pets/{petId}"));
+    assert_eq!(get.doc_comment.as_deref(), Some("Fetch a pet"));
+    assert_eq!(get.parent_id.as_deref(), Some(route.id.as_str()));
+    assert_eq!(get.semantic_group.as_deref(), Some(OPENAPI_SEMANTIC_GROUP));
+    assert_eq!((get.start_line, get.end_line), (12, 26));
+    let operation = info(get);
+    assert_eq!(operation["kind"], "operation");
+    assert_eq!(operation["method"], "GET");
+    assert_eq!(operation["path"], "/pets/{petId}");
+    assert_eq!(operation["full_path"], "/v1/pets/{petId}");
+    assert_eq!(operation["operation_id"], "getPetById");
+    assert_eq!(operation["tags"], serde_json::json!(["pets", "read"]));
+
+    // Without an operationId the operation is named by method and route.
+    let delete = find(symbols, "DELETE /pets/{petId}");
+    assert!(info(delete)["operation_id"].is_null());
+}
+
+#[test]
+fn test_schemas_become_structs_fields_and_enums() {
+    let results = annotate("api/petstore.yaml", "yaml", PETSTORE_YAML);
+    let symbols = &results.symbols;
+
+    let pet = find(symbols, "Pet");
+    assert_eq!(pet.kind, SymbolKind::Struct);
+    assert_eq!(pet.signature.as_deref(), Some("Pet: object"));
+    assert_eq!(pet.doc_comment.as_deref(), Some("A pet in the store"));
+
+    let id = find(symbols, "id");
+    assert_eq!(id.kind, SymbolKind::Field);
+    assert_eq!(id.parent_id.as_deref(), Some(pet.id.as_str()));
+    assert_eq!(id.signature.as_deref(), Some("id: int64"));
+    assert_eq!(info(id)["required"], true);
+    assert_eq!(
+        find(symbols, "tags").signature.as_deref(),
+        Some("tags: Tag[]")
+    );
+    assert_eq!(
+        find(symbols, "status").signature.as_deref(),
+        Some("status: Status")
+    );
+    assert_eq!(info(find(symbols, "status"))["required"], false);
+
+    assert_eq!(find(symbols, "Status").kind, SymbolKind::Enum);
+}
+
+#[test]
+fn test_refs_become_uses_relationships() {
+    let results = annotate("api/petstore.yaml", "yaml", PETSTORE_YAML);
+    let id_of = |name: &str| find(&results.symbols, name).id.clone();
+    let mut edges: Vec<(String, String)> = results
+        .relationships
+        .iter()
+        .inspect(|relationship| assert_eq!(relationship.kind, RelationshipKind::Uses))
+        .map(|relationship| {
+            (
+                relationship.from_symbol_id.clone(),
+                relationship.to_symbol_id.clone(),
+            )
+        })
+        .collect();
+    edges.sort();
+    let mut expected = vec![
+        (id_of("getPetById"), id_of("Pet")),
+        (id_of("tags"), id_of("Tag")),
+        (id_of("status"), id_of("Status")),
+    ];
+    expected.sort();
+    assert_eq!(edges, expected);
+}
+
+#[test]
+fn test_swagger_json_uses_definitions_and_base_path() {
+    let definitions = parse_openapi_spec("json", SWAGGER_JSON);
+    let names: Vec<&str> = definitions
+        .iter()
+        .map(|definition| definition.name.as_str())
+        .collect();
+    assert_eq!(
+        names,
+        ["/users/{id}", "get_user", "User", "name", "friends"]
+    );
+
+    let get = &definitions[1];
+    assert_eq!(get.doc.as_deref(), Some("Get a \"user\""));
+    assert_eq!(get.info["full_path"], "/api/users/{id}");
+    assert_eq!(get.refs, ["User"]);
+    assert_eq!(definitions[4].signature, "friends: User[]");
+    assert!(SWAGGER_JSON[get.start..].starts_with("\"get\""));
+}
+
+#[test]
+fn test_extractor_key_symbols_are_converted_in_place() {
+    let mut results = ExtractionResults::empty();
+    results.symbols.push(Symbol {
+        id: "get_key".to_string(),
+        name: "get".to_string(),
+        kind: SymbolKind::Property,
+        language: "yaml".to_string(),
+        file_path: "api/petstore.yaml".to_string(),
+        start_line: 12,
+        start_column: 4,
+        end_line: 26,
+        end_column: 1,
+        start_byte: 0,
+        end_byte: 1,
+        signature: None,
+        doc_comment: None,
+        visibility: None,
+        parent_id: None,
+        metadata: None,
+        semantic_group: None,
+        confidence: None,
+        code_context: None,
+        content_type: None,
+        body_span: None,
+        body_hash: None,
+        annotations: Vec::new(),
+    });
+    annotate_openapi_spec(&mut results, "api/petstore.yaml", "yaml", PETSTORE_YAML);
+
+    let converted = &results.symbols[0];
+    assert_eq!(converted.id, "get_key");
+    assert_eq!(converted.name, "getPetById");
+    assert_eq!(converted.kind, SymbolKind::Method);
+    assert_eq!(info(converted)["method"], "GET");
+    assert_eq!(
+        results
+            .symbols
+            .iter()
+            .filter(|symbol| symbol.name == "getPetById")
+            .count(),
+        1
+    );
+}
+
+#[test]
+fn test_non_specs_are_left_alone() {
+    assert!(parse_openapi_spec("yaml", "name: swagger-ui\nversion: 1\n").is_empty());
+    assert!(parse_openapi_spec("json", r#"{"name": "openapi-generator"}"#).is_empty());
+    // A truncated spec still yields what parses.
+    let truncated = parse_openapi_spec("yaml", "openapi: 3.0.0\npaths:\n  /x:\n    get: [");
+    assert_eq!(truncated.len(), 2);
+    let nested = format!(r#"{{"openapi": "3.0.0", "x": {}}}"#, "[".repeat(100_000));
+    assert!(parse_openapi_spec("json", &nested).is_empty());
+    assert!(
+        annotate("README.md", "markdown", PETSTORE_YAML)
+            .symbols
+            .is_empty()
+    );
+    assert!(
+        annotate("broken.json", "json", "{\"openapi\": ")
+            .symbols
+            .is_empty()
+    );
+}
+
+#[test]
+fn test_symbol_ids_are_stable_and_unique() {
+    let first = annotate("api/petstore.yaml", "yaml", PETSTORE_YAML);
+    let second = annotate("api/petstore.yaml", "yaml", PETSTORE_YAML);
+    let ids: Vec<&str> = first
+        .symbols
+        .iter()
+        .map(|symbol| symbol.id.as_str())
+        .collect();
+    assert_eq!(
+        ids,
+        second
+            .symbols
+            .iter()
+            .map(|symbol| symbol.id.as_str())
+            .collect::<Vec<_>>()
+    );
+    let unique: std::collections::HashSet<&str> = ids.iter().copied().collect();
+    assert_eq!(unique.len(), ids.len());
+}
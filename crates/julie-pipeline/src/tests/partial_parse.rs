@@ -0,0 +1,127 @@
+// Tests for error-tolerant extraction of files with syntax errors
+// (indexing_core::partial_parse)
+
+use crate::indexing_core::partial_parse::{
+    describe_parse_diagnostics, extract_tolerating_syntax_errors, mask_syntax_errors,
+};
+use julie_extractors::ExtractionResults;
+use julie_extractors::base::{ParseDiagnostic, ParseDiagnosticKind, Symbol, SymbolKind};
+
+const BROKEN_IMPL: &str = "impl Foo {\n    fn a( }\n\nfn b() {}\n\nstruct C;\n";
+
+fn diagnostic(start_line: u32, start_byte: u32) -> ParseDiagnostic {
+    ParseDiagnostic {
+        kind: ParseDiagnosticKind::Error,
+        start_line,
+        start_column: 0,
+        end_line: start_line,
+        end_column: 1,
+        start_byte,
+        end_byte: start_byte + 1,
+    }
+}
+
+fn symbol(name: &str) -> Symbol {
+    Symbol {
+        id: name.to_string(),
+        name: name.to_string(),
+        kind: SymbolKind::Function,
+        language: "rust".to_string(),
+        file_path: "src/lib.rs".to_string(),
+        start_line: 1,
+        start_column: 0,
+        end_line: 1,
+        end_column: 1,
+        start_byte: 0,
+        end_byte: 1,
+        signature: None,
+        doc_comment: None,
+        visibility: None,
+        parent_id: None,
+        metadata: None,
+        semantic_group: None,
+        confidence: None,
+        code_context: None,
+        content_type: None,
+        body_span: None,
+        body_hash: None,
+        annotations: Vec::new(),
+    }
+}
+
+/// Stand-in extractor: one symbol per `fn ` still in the text, and a
+/// diagnostic while the stray `a(` is there.
+fn fake_extract(content: &str) -> anyhow::Result<ExtractionResults> {
+    let mut results = ExtractionResults::empty();
+    if content.contains("a(") {
+        results.parse_diagnostics.push(diagnostic(2, 15));
+    } else {
+        results.symbols = content.matches("fn ").map(symbol).collect();
+    }
+    Ok(results)
+}
+
+#[test]
+fn test_masking_blanks_stray_tokens_and_keeps_positions() {
+    let masked = mask_syntax_errors("rust", BROKEN_IMPL).expect("error to mask");
+    assert_eq!(
+        masked,
+        "impl Foo {\n          }\n\nfn b() {}\n\nstruct C;\n"
+    );
+    assert_eq!(masked.len(), BROKEN_IMPL.len());
+
+    let extra = mask_syntax_errors("rust", "fn one() {}\n}}}\nfn two() {}\n").unwrap();
+    assert_eq!(extra, "fn one() {}\n   \nfn two() {}\n");
+    let unicode = mask_syntax_errors("rust", "fn ok() { let s = \"héllo\"; ) }\n").unwrap();
+    assert_eq!(unicode, "fn ok() { let s = \"héllo\";   }\n");
+}
+
+#[test]
+fn test_clean_files_and_unknown_languages_are_not_masked() {
+    assert_eq!(mask_syntax_errors("rust", "fn clean() {}\n"), None);
+    assert_eq!(mask_syntax_errors("not-a-language", BROKEN_IMPL), None);
+}
+
+#[test]
+fn test_recovered_pass_wins_and_keeps_the_diagnostics() {
+    let results = extract_tolerating_syntax_errors("rust", BROKEN_IMPL, fake_extract).unwrap();
+    assert_eq!(results.symbols.len(), 1, "`fn b` around the error");
+    assert_eq!(results.parse_diagnostics, vec![diagnostic(2, 15)]);
+}
+
+#[test]
+fn test_original_pass_is_kept_unless_masking_recovers_more() {
+    let calls = std::cell::Cell::new(0);
+    let results = extract_tolerating_syntax_errors("rust", BROKEN_IMPL, |_| {
+        calls.set(calls.get() + 1);
+        let mut results = ExtractionResults::empty();
+        results.symbols.push(symbol("a"));
+        results.parse_diagnostics.push(diagnostic(2, 15));
+        Ok(results)
+    })
+    .unwrap();
+    assert_eq!(calls.get(), 2);
+    assert_eq!(results.symbols[0].name, "a");
+
+    // Clean extractions run once.
+    let calls = std::cell::Cell::new(0);
+    extract_tolerating_syntax_errors("rust", "fn clean() {}\n", |content| {
+        calls.set(calls.get() + 1);
+        fake_extract(content)
+    })
+    .unwrap();
+    assert_eq!(calls.get(), 1);
+}
+
+#[test]
+fn test_diagnostics_summary_names_the_first_error() {
+    assert_eq!(describe_parse_diagnostics(&[]), None);
+    assert_eq!(
+        describe_parse_diagnostics(&[diagnostic(2, 15)]).as_deref(),
+        Some("1 syntax error, first at line 2")
+    );
+    assert_eq!(
+        describe_parse_diagnostics(&[diagnostic(9, 120), diagnostic(4, 40)]).as_deref(),
+        Some("2 syntax errors, first at line 4")
+    );
+}
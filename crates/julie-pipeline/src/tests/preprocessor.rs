@@ -0,0 +1,169 @@
+// Tests for C/C++ conditional-compilation tags (indexing_core::preprocessor)
+
+use crate::indexing_core::preprocessor::{
+    PREPROCESSOR_METADATA_KEY, annotate_preprocessor_conditions,
+};
+use julie_core::preprocessor::DefineSet;
+use julie_extractors::base::{Symbol, SymbolKind};
+
+const PLATFORM_H: &str = "\
+#ifndef PLATFORM_H
+#define PLATFORM_H
+
+int shared(void);
+
+#ifdef _WIN32
+int open_file(const wchar_t *path);
+#elif LEVEL > 2 /* verbose builds */
+int open_file(const char *path, int flags);
+#else
+int open_file(const char *path);
+#  if defined(TRACE) && \\
+      !defined(NDEBUG)
+void trace(void);
+#  endif
+#endif
+
+#define HAVE_POOL 1
+#if HAVE_POOL
+void pool_init(void);
+#endif
+
+#endif
+";
+
+fn sym(name: &str, line: u32) -> Symbol {
+    Symbol {
+        id: format!("{name}_{line}"),
+        name: name.to_string(),
+        kind: SymbolKind::Function,
+        language: "c".to_string(),
+        file_path: "include/platform.h".to_string(),
+        start_line: line,
+        start_column: 0,
+        end_line: line,
+        end_column: 1,
+        start_byte: 0,
+        end_byte: 1,
+        signature: None,
+        doc_comment: None,
+        visibility: None,
+        parent_id: None,
+        metadata: None,
+        semantic_group: None,
+        confidence: None,
+        code_context: None,
+        content_type: None,
+        body_span: None,
+        body_hash: None,
+        annotations: Vec::new(),
+    }
+}
+
+fn platform_symbols() -> Vec<Symbol> {
+    vec![
+        sym("shared", 4),
+        sym("open_file", 7),
+        sym("open_file", 9),
+        sym("open_file", 11),
+        sym("trace", 14),
+        sym("pool_init", 20),
+    ]
+}
+
+fn tag(symbol: &Symbol) -> Option<&serde_json::Value> {
+    symbol
+        .metadata
+        .as_ref()
+        .and_then(|metadata| metadata.get(PREPROCESSOR_METADATA_KEY))
+}
+
+fn condition(symbol: &Symbol) -> Option<&str> {
+    tag(symbol).and_then(|tag| tag["condition"].as_str())
+}
+
+#[test]
+fn symbols_record_their_branch_condition_without_the_include_guard() {
+    let mut symbols = platform_symbols();
+    assert_eq!(
+        annotate_preprocessor_conditions(&mut symbols, PLATFORM_H, None),
+        5
+    );
+
+    assert_eq!(tag(&symbols[0]), None);
+    assert_eq!(condition(&symbols[1]), Some("defined(_WIN32)"));
+    assert_eq!(
+        condition(&symbols[2]),
+        Some("!defined(_WIN32) && (LEVEL > 2)")
+    );
+    assert_eq!(
+        condition(&symbols[3]),
+        Some("!defined(_WIN32) && !(LEVEL > 2)")
+    );
+    assert_eq!(
+        condition(&symbols[4]),
+        Some("!defined(_WIN32) && !(LEVEL > 2) && (defined(TRACE) && !defined(NDEBUG))")
+    );
+    assert_eq!(condition(&symbols[5]), Some("HAVE_POOL"));
+    // Without a configured define set nothing is evaluated.
+    assert!(
+        symbols
+            .iter()
+            .filter_map(tag)
+            .all(|tag| tag.get("active").is_none())
+    );
+}
+
+#[test]
+fn configured_defines_mark_the_active_branch() {
+    let mut symbols = platform_symbols();
+    let defines = DefineSet::parse(["LEVEL=3", "TRACE"]).unwrap();
+    annotate_preprocessor_conditions(&mut symbols, PLATFORM_H, Some(&defines));
+
+    let active: Vec<Option<bool>> = symbols
+        .iter()
+        .map(|symbol| tag(symbol).and_then(|tag| tag["active"].as_bool()))
+        .collect();
+    // `HAVE_POOL` comes from the file's own `#define`.
+    assert_eq!(
+        active,
+        vec![
+            None,
+            Some(false),
+            Some(true),
+            Some(false),
+            Some(false),
+            Some(true)
+        ]
+    );
+}
+
+#[test]
+fn unknown_conditions_stay_undecided() {
+    let source =
+        "#if __has_include(<threads.h>)\nvoid spawn(void);\n#else\nvoid spawn(void);\n#endif\n";
+    let mut symbols = vec![sym("spawn", 2), sym("spawn", 4)];
+    let defines = DefineSet::default();
+    annotate_preprocessor_conditions(&mut symbols, source, Some(&defines));
+
+    assert_eq!(
+        condition(&symbols[1]),
+        Some("!(__has_include(<threads.h>))")
+    );
+    assert!(
+        symbols
+            .iter()
+            .all(|symbol| tag(symbol).unwrap().get("active").is_none())
+    );
+}
+
+#[test]
+fn non_c_languages_are_untouched() {
+    let mut symbols = vec![sym("open_file", 7)];
+    symbols[0].language = "rust".to_string();
+    assert_eq!(
+        annotate_preprocessor_conditions(&mut symbols, PLATFORM_H, None),
+        0
+    );
+    assert_eq!(tag(&symbols[0]), None);
+}
@@ -0,0 +1,202 @@
+// Tests for Protocol Buffers definition symbols (indexing_core::protobuf)
+
+use crate::indexing_core::protobuf::{
+    PROTOBUF_LANGUAGE, PROTOBUF_METADATA_KEY, annotate_protobuf_definitions, parse_protobuf_symbols,
+};
+use julie_extractors::base::{Symbol, SymbolKind};
+
+const GREETER_PROTO: &str = r#"syntax = "proto3";
+
+package helloworld.v1;
+
+import "google/api/annotations.proto";
+
+option go_package = "example.com/helloworld/v1;helloworld";
+
+// The greeting service definition.
+service Greeter {
+  // Sends a greeting.
+  rpc SayHello (HelloRequest) returns (HelloReply) {
+    option (google.api.http) = { get: "/v1/hello/{name}" };
+  }
+  rpc StreamHellos(stream HelloRequest) returns (stream HelloReply);
+}
+
+/* The request message
+ * containing the user's name. */
+message HelloRequest {
+  string name = 1; // trailing comment, not a doc
+  repeated string tags = 2;
+  map<string, int32> counts = 3;
+  oneof target {
+    string email = 4;
+    int64 user_id = 5;
+  }
+  reserved 6, 7;
+
+  message Locale {
+    string code = 1;
+  }
+  Locale locale = 8;
+}
+
+message HelloReply {
+  string message = 1;
+  Status status = 2;
+}
+
+enum Status {
+  STATUS_UNSPECIFIED = 0;
+  STATUS_OK = 1 [deprecated = true];
+  STATUS_NEGATIVE = -1;
+}
+"#;
+
+fn find<'a>(symbols: &'a [Symbol], name: &str) -> &'a Symbol {
+    symbols
+        .iter()
+        .find(|symbol| symbol.name == name)
+        .unwrap_or_else(|| panic!("missing symbol {name}"))
+}
+
+fn proto_info<'a>(symbol: &'a Symbol, key: &str) -> &'a serde_json::Value {
+    &symbol.metadata.as_ref().unwrap()[PROTOBUF_METADATA_KEY][key]
+}
+
+#[test]
+fn test_parses_package_service_and_rpcs() {
+    let symbols = parse_protobuf_symbols("proto/greeter.proto", GREETER_PROTO);
+
+    let package = find(&symbols, "helloworld.v1");
+    assert_eq!(package.kind, SymbolKind::Namespace);
+
+    let service = find(&symbols, "Greeter");
+    assert_eq!(service.kind, SymbolKind::Interface);
+    assert_eq!(service.language, PROTOBUF_LANGUAGE);
+    assert_eq!(
+        service.doc_comment.as_deref(),
+        Some("The greeting service definition.")
+    );
+    assert_eq!(proto_info(service, "full_name"), "helloworld.v1.Greeter");
+    assert_eq!((service.start_line, service.end_line), (10, 16));
+
+    let say_hello = find(&symbols, "SayHello");
+    assert_eq!(say_hello.kind, SymbolKind::Method);
+    assert_eq!(say_hello.parent_id.as_ref(), Some(&service.id));
+    assert_eq!(say_hello.doc_comment.as_deref(), Some("Sends a greeting."));
+    assert_eq!(
+        say_hello.signature.as_deref(),
+        Some("rpc SayHello(HelloRequest) returns (HelloReply)")
+    );
+    assert_eq!(proto_info(say_hello, "request"), "HelloRequest");
+    assert_eq!(proto_info(say_hello, "client_streaming"), false);
+    assert_eq!(
+        (say_hello.start_line, say_hello.end_line),
+        (12, 14),
+        "the option block belongs to the rpc"
+    );
+
+    let stream = find(&symbols, "StreamHellos");
+    assert_eq!(stream.parent_id.as_ref(), Some(&service.id));
+    assert_eq!(
+        stream.signature.as_deref(),
+        Some("rpc StreamHellos(stream HelloRequest) returns (stream HelloReply)")
+    );
+    assert_eq!(proto_info(stream, "server_streaming"), true);
+    assert_eq!(
+        proto_info(stream, "full_name"),
+        "helloworld.v1.Greeter.StreamHellos"
+    );
+}
+
+#[test]
+fn test_parses_messages_fields_and_enums() {
+    let symbols = parse_protobuf_symbols("proto/greeter.proto", GREETER_PROTO);
+
+    let request = find(&symbols, "HelloRequest");
+    assert_eq!(request.kind, SymbolKind::Struct);
+    assert_eq!(
+        request.doc_comment.as_deref(),
+        Some("The request message\ncontaining the user's name.")
+    );
+
+    let fields: Vec<&Symbol> = symbols
+        .iter()
+        .filter(|symbol| symbol.parent_id.as_ref() == Some(&request.id))
+        .collect();
+    let names: Vec<&str> = fields.iter().map(|symbol| symbol.name.as_str()).collect();
+    assert_eq!(
+        names,
+        vec![
+            "name", "tags", "counts", "email", "user_id", "Locale", "locale"
+        ],
+        "oneof members belong to the message; reserved is skipped"
+    );
+    assert!(find(&symbols, "name").doc_comment.is_none());
+    assert_eq!(
+        find(&symbols, "tags").signature.as_deref(),
+        Some("repeated string tags = 2")
+    );
+    assert_eq!(
+        find(&symbols, "counts").signature.as_deref(),
+        Some("map<string,int32> counts = 3")
+    );
+
+    let locale = find(&symbols, "Locale");
+    assert_eq!(locale.kind, SymbolKind::Struct);
+    assert_eq!(
+        proto_info(locale, "full_name"),
+        "helloworld.v1.HelloRequest.Locale"
+    );
+    let code = find(&symbols, "code");
+    assert_eq!(code.parent_id.as_ref(), Some(&locale.id));
+
+    let status = find(&symbols, "Status");
+    assert_eq!(status.kind, SymbolKind::Enum);
+    let ok = find(&symbols, "STATUS_OK");
+    assert_eq!(ok.kind, SymbolKind::EnumMember);
+    assert_eq!(ok.parent_id.as_ref(), Some(&status.id));
+    assert_eq!(ok.signature.as_deref(), Some("STATUS_OK = 1"));
+    assert_eq!(
+        find(&symbols, "STATUS_NEGATIVE").signature.as_deref(),
+        Some("STATUS_NEGATIVE = -1")
+    );
+}
+
+#[test]
+fn test_tolerates_truncated_and_malformed_input() {
+    let content = "message Broken {\n  string = ;\n  int32 ok = 2;\n  rpc Nope(\nservice S {\n  rpc Call(Req) returns (Resp);\n";
+    let symbols = parse_protobuf_symbols("broken.proto", content);
+    assert!(symbols.iter().any(|symbol| symbol.name == "Broken"));
+    assert!(symbols.iter().any(|symbol| symbol.name == "ok"));
+}
+
+#[test]
+fn test_annotate_only_applies_to_protobuf_files_without_symbols() {
+    let mut symbols = Vec::new();
+    annotate_protobuf_definitions(&mut symbols, "notes.txt", "text", GREETER_PROTO);
+    assert!(symbols.is_empty());
+
+    annotate_protobuf_definitions(&mut symbols, "greeter.proto", "protobuf", GREETER_PROTO);
+    assert!(symbols.iter().any(|symbol| symbol.name == "Greeter"));
+    let count = symbols.len();
+
+    annotate_protobuf_definitions(&mut symbols, "greeter.proto", "protobuf", GREETER_PROTO);
+    assert_eq!(symbols.len(), count, "existing symbols are left alone");
+}
+
+#[test]
+fn test_symbol_ids_are_stable_and_unique() {
+    let first = parse_protobuf_symbols("proto/greeter.proto", GREETER_PROTO);
+    let second = parse_protobuf_symbols("proto/greeter.proto", GREETER_PROTO);
+    let ids: Vec<&str> = first.iter().map(|symbol| symbol.id.as_str()).collect();
+    assert_eq!(
+        ids,
+        second
+            .iter()
+            .map(|symbol| symbol.id.as_str())
+            .collect::<Vec<_>>()
+    );
+    let unique: std::collections::HashSet<&str> = ids.iter().copied().collect();
+    assert_eq!(unique.len(), ids.len());
+}
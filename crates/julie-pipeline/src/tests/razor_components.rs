@@ -0,0 +1,281 @@
+// Tests for Razor/Blazor component tag linking (indexing_core::razor_components)
+
+use crate::indexing_core::razor_components::{
+    COMPONENT_CALLEE_PREFIX, PARAMETER_CALLEE_PREFIX, find_component_tags, is_component_usage,
+    link_component_usages, resolve_component_usages,
+};
+use julie_core::database::{FileInfo, SymbolDatabase};
+use julie_extractors::ExtractionResults;
+use julie_extractors::base::{RelationshipKind, Symbol, SymbolKind};
+use tempfile::TempDir;
+
+const INDEX_RAZOR: &str = "\u{feff}@page \"/\"
+@* <Commented /> *@
+<!-- <AlsoCommented /> -->
+<div class=\"page\">
+    <Shared.AppHeader Title=\"Home\" @ref=\"header\" ShowMenu />
+    <ErrorBoundary>
+        <ChildContent>
+            <Counter @bind-Value=\"count\" Step=@(step * 2) class=\"wide\" />
+        </ChildContent>
+    </ErrorBoundary>
+    @{ var items = new List<Item>(); }
+</div>
+<script>const el = document.querySelector('<Fake>');</script>
+
+@code {
+    private Dictionary<string, Widget> widgets = new();
+    private string label = \"<NotATag />\";
+}
+";
+
+fn sym(id: &str, name: &str, kind: SymbolKind, language: &str, file_path: &str) -> Symbol {
+    Symbol {
+        id: id.to_string(),
+        name: name.to_string(),
+        kind,
+        language: language.to_string(),
+        file_path: file_path.to_string(),
+        start_line: 1,
+        start_column: 0,
+        end_line: 30,
+        end_column: 1,
+        start_byte: 0,
+        end_byte: 0,
+        signature: None,
+        doc_comment: None,
+        visibility: None,
+        parent_id: None,
+        metadata: None,
+        semantic_group: None,
+        confidence: None,
+        code_context: None,
+        content_type: None,
+        body_span: None,
+        body_hash: None,
+        annotations: Vec::new(),
+    }
+}
+
+fn callees(results: &ExtractionResults) -> Vec<(&str, u32)> {
+    results
+        .pending_relationships
+        .iter()
+        .map(|p| (p.callee_name.as_str(), p.line_number))
+        .collect()
+}
+
+#[test]
+fn component_tags_skip_comments_code_and_generics() {
+    let tags = find_component_tags(INDEX_RAZOR);
+    let names: Vec<&str> = tags.iter().map(|tag| tag.name.as_str()).collect();
+    assert_eq!(
+        names,
+        ["AppHeader", "ErrorBoundary", "ChildContent", "Counter"]
+    );
+
+    let header = &tags[0];
+    assert_eq!(header.line, 5);
+    assert!(INDEX_RAZOR[header.offset..].starts_with("Shared.AppHeader"));
+    let attributes: Vec<&str> = header.attributes.iter().map(|a| a.name.as_str()).collect();
+    assert_eq!(attributes, ["Title", "ShowMenu"]);
+    assert_eq!(header.parent, None);
+
+    assert_eq!(tags[2].parent.as_deref(), Some("ErrorBoundary"));
+    let counter = &tags[3];
+    assert_eq!(counter.parent.as_deref(), Some("ChildContent"));
+    let attributes: Vec<&str> = counter.attributes.iter().map(|a| a.name.as_str()).collect();
+    assert_eq!(attributes, ["Value", "Step", "class"]);
+    assert!(INDEX_RAZOR[counter.attributes[0].offset..].starts_with("Value="));
+}
+
+#[test]
+fn tags_become_pending_usages_from_the_page() {
+    let mut results = ExtractionResults::empty();
+    results.symbols = vec![sym(
+        "index",
+        "Index",
+        SymbolKind::Class,
+        "razor",
+        "Pages/Index.razor",
+    )];
+    link_component_usages(&mut results, "Pages/Index.razor", "razor", INDEX_RAZOR);
+
+    assert_eq!(
+        callees(&results),
+        [
+            ("razor-component:AppHeader", 5),
+            ("razor-parameter:AppHeader.Title", 5),
+            ("razor-parameter:AppHeader.ShowMenu", 5),
+            ("razor-component:ErrorBoundary", 6),
+            ("razor-component:ChildContent", 7),
+            ("razor-parameter:ErrorBoundary.ChildContent", 7),
+            ("razor-component:Counter", 8),
+            ("razor-parameter:ChildContent.Counter", 8),
+            ("razor-parameter:Counter.Value", 8),
+            ("razor-parameter:Counter.Step", 8),
+            ("razor-parameter:Counter.class", 8),
+        ]
+    );
+    assert!(results.pending_relationships.iter().all(is_component_usage));
+    assert!(
+        results
+            .pending_relationships
+            .iter()
+            .all(|p| p.from_symbol_id == "index")
+    );
+    assert_eq!(
+        results.pending_relationships[0].kind,
+        RelationshipKind::Uses
+    );
+    assert_eq!(
+        results.pending_relationships[1].kind,
+        RelationshipKind::References
+    );
+
+    let mut cshtml_as_csharp = ExtractionResults::empty();
+    cshtml_as_csharp.symbols = results.symbols.clone();
+    link_component_usages(
+        &mut cshtml_as_csharp,
+        "Pages/Index.cs",
+        "csharp",
+        INDEX_RAZOR,
+    );
+    assert!(cshtml_as_csharp.pending_relationships.is_empty());
+}
+
+#[test]
+fn usages_resolve_to_the_nearest_component_and_its_parameters() {
+    let temp_dir = TempDir::new().unwrap();
+    let mut db = SymbolDatabase::new(temp_dir.path().join("test.db")).unwrap();
+
+    let mut title = sym(
+        "header_title",
+        "Title",
+        SymbolKind::Property,
+        "razor",
+        "Client/Shared/AppHeader.razor",
+    );
+    title.parent_id = Some("header".to_string());
+    let mut symbols = vec![
+        sym(
+            "index",
+            "Index",
+            SymbolKind::Class,
+            "razor",
+            "Client/Pages/Index.razor",
+        ),
+        sym(
+            "header",
+            "AppHeader",
+            SymbolKind::Class,
+            "razor",
+            "Client/Shared/AppHeader.razor",
+        ),
+        title,
+        sym(
+            "header_menu",
+            "ShowMenu",
+            SymbolKind::Property,
+            "csharp",
+            "Client/Shared/AppHeader.razor.cs",
+        ),
+        sym(
+            "admin_header",
+            "AppHeader",
+            SymbolKind::Class,
+            "razor",
+            "Admin/Shared/AppHeader.razor",
+        ),
+        sym(
+            "header_model",
+            "AppHeader",
+            SymbolKind::Class,
+            "csharp",
+            "Models/AppHeader.cs",
+        ),
+        sym(
+            "counter",
+            "Counter",
+            SymbolKind::Class,
+            "csharp",
+            "Components/Counter.cs",
+        ),
+        sym(
+            "other_value",
+            "Value",
+            SymbolKind::Property,
+            "csharp",
+            "Components/Counter.cs",
+        ),
+    ];
+    let mut counter_value = sym(
+        "counter_value",
+        "Value",
+        SymbolKind::Property,
+        "csharp",
+        "Components/Counter.cs",
+    );
+    counter_value.parent_id = Some("counter".to_string());
+    symbols.push(counter_value);
+
+    let mut files: Vec<&str> = symbols.iter().map(|s| s.file_path.as_str()).collect();
+    files.sort();
+    files.dedup();
+    for path in files {
+        db.store_file_info(&FileInfo {
+            path: path.to_string(),
+            language: if path.ends_with(".cs") {
+                "csharp"
+            } else {
+                "razor"
+            }
+            .to_string(),
+            hash: "h".to_string(),
+            size: 100,
+            last_modified: 1000,
+            last_indexed: 0,
+            symbol_count: 0,
+            line_count: 0,
+            content: None,
+        })
+        .unwrap();
+    }
+    db.store_symbols_transactional(&symbols).unwrap();
+
+    let mut results = ExtractionResults::empty();
+    results.symbols = vec![symbols[0].clone()];
+    link_component_usages(
+        &mut results,
+        "Client/Pages/Index.razor",
+        "razor",
+        INDEX_RAZOR,
+    );
+
+    let resolved = resolve_component_usages(&results.pending_relationships, &db);
+    let mut edges: Vec<(&str, &str)> = resolved
+        .iter()
+        .map(|r| (r.to_symbol_id.as_str(), r.from_symbol_id.as_str()))
+        .collect();
+    edges.sort();
+    assert_eq!(
+        edges,
+        [
+            ("counter", "index"),
+            ("counter_value", "index"),
+            ("header", "index"),
+            ("header_menu", "index"),
+            ("header_title", "index"),
+        ]
+    );
+    let header_edge = resolved
+        .iter()
+        .find(|r| r.to_symbol_id == "header")
+        .unwrap();
+    assert_eq!(header_edge.kind, RelationshipKind::Uses);
+
+    let mut unmatched = results.pending_relationships[..2].to_vec();
+    unmatched[0].callee_name = format!("{COMPONENT_CALLEE_PREFIX}Missing");
+    unmatched[1].callee_name = format!("{PARAMETER_CALLEE_PREFIX}AppHeader.Nope");
+    assert!(resolve_component_usages(&unmatched, &db).is_empty());
+}
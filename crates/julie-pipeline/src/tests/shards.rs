@@ -0,0 +1,184 @@
+// Tests for sharded extraction streams (indexing_core::shards)
+
+use std::io::Cursor;
+
+use crate::indexing_core::batch::ExtractedBatch;
+use crate::indexing_core::extraction::{ExtractedFileDisposition, ExtractedFileRecord};
+use crate::indexing_core::shards::{ShardMerger, ShardSpec, ShardStreamReader, ShardStreamWriter};
+
+const ENGINE: &str = "test-engine-v1";
+
+fn paths() -> Vec<String> {
+    (0..200)
+        .map(|i| format!("src/module_{i}/file_{i}.rs"))
+        .collect()
+}
+
+fn shard_paths(shard: ShardSpec) -> Vec<String> {
+    paths().into_iter().filter(|p| shard.contains(p)).collect()
+}
+
+fn chunk_for(paths: &[String]) -> (ExtractedBatch, Vec<ExtractedFileRecord>) {
+    let mut batch = ExtractedBatch::new();
+    batch.files_to_clean = paths.to_vec();
+    batch.files_processed = paths.len();
+    let records = paths
+        .iter()
+        .map(|path| ExtractedFileRecord {
+            relative_path: path.clone(),
+            language: "rust".to_string(),
+            disposition: ExtractedFileDisposition::Parsed,
+        })
+        .collect();
+    (batch, records)
+}
+
+fn stream_for(shard: ShardSpec, engine: &str, paths: &[String], finish: bool) -> Vec<u8> {
+    let mut out = Vec::new();
+    let mut writer = ShardStreamWriter::start(&mut out, shard, engine, paths.len()).unwrap();
+    for chunk in paths.chunks(7) {
+        let (batch, records) = chunk_for(chunk);
+        writer.write_chunk(batch, records).unwrap();
+    }
+    if finish {
+        writer.finish().unwrap();
+    }
+    out
+}
+
+/// Read a whole stream, returning its shard and every chunk.
+fn read_stream(
+    stream: Vec<u8>,
+    engine: &str,
+) -> anyhow::Result<(ShardSpec, Vec<(ExtractedBatch, Vec<ExtractedFileRecord>)>)> {
+    let mut reader = ShardStreamReader::open(Cursor::new(stream), engine, "test")?;
+    let mut chunks = Vec::new();
+    while let Some(chunk) = reader.next_chunk()? {
+        chunks.push(chunk);
+    }
+    Ok((reader.shard(), chunks))
+}
+
+fn read(shard: ShardSpec) -> Vec<(ExtractedBatch, Vec<ExtractedFileRecord>)> {
+    let stream = stream_for(shard, ENGINE, &shard_paths(shard), true);
+    let (streamed_shard, chunks) = read_stream(stream, ENGINE).unwrap();
+    assert_eq!(streamed_shard, shard);
+    chunks
+}
+
+/// Feed one shard's chunks to `merger`, as the coordinator does.
+fn merge(merger: &mut ShardMerger, shard: ShardSpec, source: &str) -> anyhow::Result<()> {
+    merger.add_shard(shard, source)?;
+    for (batch, _) in read(shard) {
+        merger.claim_files(&batch, source)?;
+    }
+    Ok(())
+}
+
+#[test]
+fn shard_spec_parses_and_displays() {
+    let shard: ShardSpec = "2/8".parse().unwrap();
+    assert_eq!(shard, ShardSpec { index: 2, count: 8 });
+    assert_eq!(shard.to_string(), "2/8");
+
+    assert!("8/8".parse::<ShardSpec>().is_err());
+    assert!("0/0".parse::<ShardSpec>().is_err());
+    assert!("3".parse::<ShardSpec>().is_err());
+    assert!("a/4".parse::<ShardSpec>().is_err());
+}
+
+#[test]
+fn shards_partition_every_path_exactly_once() {
+    let shards = ShardSpec::all(5).unwrap();
+    for path in paths() {
+        let owners = shards.iter().filter(|s| s.contains(&path)).count();
+        assert_eq!(owners, 1, "{path} owned by {owners} shards");
+    }
+    assert!(
+        shards.iter().all(|s| !shard_paths(*s).is_empty()),
+        "200 paths should land in every one of 5 shards"
+    );
+}
+
+#[test]
+fn merged_streams_cover_the_whole_workspace() {
+    let mut merger = ShardMerger::new();
+    let mut merged = Vec::new();
+    let mut records_read = 0;
+    for shard in ShardSpec::all(3).unwrap() {
+        merger.add_shard(shard, &shard.to_string()).unwrap();
+        for (batch, records) in read(shard) {
+            merger.claim_files(&batch, &shard.to_string()).unwrap();
+            merged.extend(batch.files_to_clean);
+            records_read += records.len();
+        }
+    }
+    merger.finish().unwrap();
+
+    merged.sort();
+    let mut expected = paths();
+    expected.sort();
+    assert_eq!(merged, expected);
+    assert_eq!(records_read, 200);
+}
+
+#[test]
+fn files_claimed_by_two_shards_are_rejected() {
+    let shard = ShardSpec::new(0, 2).unwrap();
+    let mut merger = ShardMerger::new();
+    let (batch, _) = chunk_for(&shard_paths(shard));
+    merger.claim_files(&batch, "a").unwrap();
+    assert!(merger.claim_files(&batch, "b").is_err());
+}
+
+#[test]
+fn truncated_stream_is_rejected() {
+    let shard = ShardSpec::new(0, 2).unwrap();
+    let stream = stream_for(shard, ENGINE, &shard_paths(shard), false);
+    let error = read_stream(stream, ENGINE)
+        .err()
+        .expect("stream without done record must fail");
+    assert!(
+        error.to_string().contains("without a done record"),
+        "{error}"
+    );
+}
+
+#[test]
+fn engine_mismatch_is_rejected() {
+    let shard = ShardSpec::new(0, 1).unwrap();
+    let stream = stream_for(shard, "other-engine", &shard_paths(shard), true);
+    let error = read_stream(stream, ENGINE)
+        .err()
+        .expect("engine mismatch must fail");
+    assert!(error.to_string().contains("does not match"), "{error}");
+}
+
+#[test]
+fn file_outside_shard_is_rejected() {
+    let shard = ShardSpec::new(0, 2).unwrap();
+    let foreign = shard_paths(ShardSpec::new(1, 2).unwrap());
+    let stream = stream_for(shard, ENGINE, &foreign, true);
+    assert!(read_stream(stream, ENGINE).is_err());
+}
+
+#[test]
+fn duplicate_and_missing_shards_are_rejected() {
+    let first = ShardSpec::new(0, 3).unwrap();
+    let mut merger = ShardMerger::new();
+    merge(&mut merger, first, "a").unwrap();
+    assert!(merge(&mut merger, first, "b").is_err());
+
+    let error = merger.finish().err().expect("missing shards must fail");
+    assert!(
+        error.to_string().contains("missing shard(s) 1, 2"),
+        "{error}"
+    );
+}
+
+#[test]
+fn mismatched_split_is_rejected() {
+    let mut merger = ShardMerger::new();
+    merge(&mut merger, ShardSpec::new(0, 2).unwrap(), "a").unwrap();
+    assert!(merge(&mut merger, ShardSpec::new(1, 3).unwrap(), "b").is_err());
+}
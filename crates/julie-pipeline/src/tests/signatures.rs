@@ -0,0 +1,138 @@
+// Tests for signature normalization and capping (indexing_core::signatures)
+
+use crate::indexing_core::signatures::{
+    MAX_SIGNATURE_CHARS, SIGNATURE_METADATA_KEY, TRUNCATION_MARKER, is_signature_truncated,
+    normalize_signature, normalize_signatures, recover_signature,
+};
+use julie_extractors::base::NormalizedSpan;
+use julie_extractors::{Symbol, SymbolKind};
+
+fn sym(id: &str, signature: Option<&str>) -> Symbol {
+    Symbol {
+        id: id.to_string(),
+        name: id.to_string(),
+        kind: SymbolKind::Function,
+        language: "javascript".to_string(),
+        file_path: "dist/app.min.js".to_string(),
+        start_line: 1,
+        start_column: 0,
+        end_line: 1,
+        end_column: 1,
+        start_byte: 0,
+        end_byte: 0,
+        signature: signature.map(str::to_string),
+        doc_comment: None,
+        visibility: None,
+        parent_id: None,
+        metadata: None,
+        semantic_group: None,
+        confidence: None,
+        code_context: None,
+        content_type: None,
+        body_span: None,
+        body_hash: None,
+        annotations: Vec::new(),
+    }
+}
+
+/// A minified function header with `params` parameters on one line.
+fn minified_header(params: usize) -> String {
+    let params: Vec<String> = (0..params).map(|i| format!("param{i}")).collect();
+    format!("function a({})", params.join(","))
+}
+
+#[test]
+fn signatures_are_trimmed_without_losing_their_layout() {
+    assert_eq!(
+        normalize_signature("  fn run(\n    a: u32,   \n\n    b: u32,\n) -> u32  \n"),
+        "fn run(\n    a: u32,\n    b: u32,\n) -> u32"
+    );
+
+    let mut symbols = vec![
+        sym("run", Some("pub fn run()   ")),
+        sym("field", None),
+        sym("blank", Some("  ")),
+    ];
+    assert_eq!(normalize_signatures(&mut symbols), 0);
+    assert_eq!(symbols[0].signature.as_deref(), Some("pub fn run()"));
+    assert_eq!(symbols[1].signature, None);
+    assert_eq!(symbols[2].signature.as_deref(), Some("  "));
+    assert!(symbols.iter().all(|symbol| symbol.metadata.is_none()));
+}
+
+#[test]
+fn long_signatures_are_capped_at_a_token_boundary() {
+    let header = minified_header(200);
+    let mut symbols = vec![sym("a", Some(&header))];
+    assert_eq!(normalize_signatures(&mut symbols), 1);
+
+    let capped = symbols[0].signature.as_deref().unwrap();
+    let kept = capped.strip_suffix(TRUNCATION_MARKER).unwrap();
+    assert!(kept.chars().count() <= MAX_SIGNATURE_CHARS);
+    assert!(header.starts_with(kept));
+    assert!(
+        kept.ends_with(','),
+        "cut after a separator, not inside a parameter name: {kept}"
+    );
+    assert!(is_signature_truncated(&symbols[0]));
+    assert_eq!(
+        symbols[0].metadata.as_ref().unwrap()[SIGNATURE_METADATA_KEY]["chars"],
+        header.chars().count()
+    );
+}
+
+#[test]
+fn capping_counts_characters_not_bytes() {
+    // No separator anywhere: the cut lands exactly on the cap.
+    let header = "é".repeat(MAX_SIGNATURE_CHARS + 1);
+    let mut symbols = vec![sym("accents", Some(&header))];
+    normalize_signatures(&mut symbols);
+    let capped = symbols[0].signature.as_deref().unwrap();
+    assert_eq!(
+        capped,
+        format!("{}{TRUNCATION_MARKER}", "é".repeat(MAX_SIGNATURE_CHARS))
+    );
+
+    let exact = "é".repeat(MAX_SIGNATURE_CHARS);
+    let mut symbols = vec![sym("exact", Some(&exact))];
+    assert_eq!(normalize_signatures(&mut symbols), 0);
+    assert_eq!(symbols[0].signature.as_deref(), Some(exact.as_str()));
+}
+
+#[test]
+fn capped_signatures_are_recovered_from_the_file() {
+    let header = minified_header(200);
+    let body = "{return param0}";
+    let content = format!("var x=1;{header}{body};");
+    let start = "var x=1;".len() as u32;
+    let body_start = start + header.len() as u32;
+    let end = body_start + body.len() as u32;
+
+    let mut symbol = sym("a", Some(&header));
+    symbol.start_byte = start;
+    symbol.end_byte = end;
+    assert_eq!(
+        recover_signature(&symbol, &content).as_deref(),
+        Some(format!("{header}{body}").as_str()),
+        "without a body span the whole symbol is the declaration"
+    );
+
+    symbol.body_span = Some(NormalizedSpan {
+        start_line: 1,
+        start_column: body_start,
+        end_line: 1,
+        end_column: end,
+        start_byte: body_start,
+        end_byte: end,
+    });
+    assert_eq!(
+        recover_signature(&symbol, &content).as_deref(),
+        Some(header.as_str())
+    );
+
+    assert_eq!(
+        recover_signature(&symbol, "var x=1;"),
+        None,
+        "a span past the end of a changed file recovers nothing"
+    );
+}
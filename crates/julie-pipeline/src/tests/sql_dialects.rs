@@ -0,0 +1,572 @@
+// Tests for PL/SQL and T-SQL routine extraction (indexing_core::sql_dialects)
+
+use crate::indexing_core::csharp_partials::PARTIAL_GROUP_METADATA_KEY;
+use crate::indexing_core::sql_dialects::{
+    SQL_METADATA_KEY, SqlDialect, annotate_sql_routines, detect_sql_dialect,
+    extract_sql_dialect_source, extract_sql_routines,
+};
+use julie_extractors::base::Visibility;
+use julie_extractors::{
+    ExtractionResults, Identifier, IdentifierKind, Relationship, RelationshipKind, Symbol,
+    SymbolKind,
+};
+
+const EMP_PKG: &str = r#"-- Employee operations.
+CREATE OR REPLACE PACKAGE hr.emp_pkg AUTHID DEFINER AS
+  /* Raise an employee's salary. */
+  PROCEDURE raise_salary(p_id IN employees.employee_id%TYPE, p_pct IN NUMBER DEFAULT 10);
+  FUNCTION get_salary(p_id IN NUMBER) RETURN NUMBER;
+  g_count NUMBER := 0;
+END emp_pkg;
+/
+
+CREATE OR REPLACE PACKAGE BODY hr.emp_pkg AS
+  PROCEDURE log_change(p_msg VARCHAR2) IS
+  BEGIN
+    INSERT INTO audit_log(msg) VALUES (p_msg);
+  END log_change;
+
+  PROCEDURE raise_salary(p_id IN employees.employee_id%type, p_pct IN NUMBER DEFAULT 10) IS
+    l_old NUMBER;
+  BEGIN
+    l_old := get_salary(p_id);
+    IF l_old > 0 THEN
+      UPDATE employees SET salary = salary * (1 + p_pct / 100) WHERE employee_id = p_id;
+    END IF;
+    CASE WHEN l_old > 100 THEN NULL; ELSE NULL; END CASE;
+    log_change('raised ' || p_id);
+    audit_pkg.record_event(p_id);
+    DBMS_OUTPUT.PUT_LINE('done');
+  END raise_salary;
+
+  FUNCTION get_salary(p_id IN NUMBER) RETURN NUMBER IS
+    l_sal NUMBER;
+  BEGIN
+    SELECT salary INTO l_sal FROM employees WHERE employee_id = p_id;
+    RETURN CASE WHEN l_sal IS NULL THEN 0 ELSE l_sal END;
+  END;
+BEGIN
+  g_count := 1;
+END emp_pkg;
+/
+
+CREATE OR REPLACE TRIGGER trg_emp_audit
+  BEFORE INSERT OR UPDATE OF salary ON hr.employees
+  FOR EACH ROW
+  WHEN (new.salary > 0)
+DECLARE
+  l_note VARCHAR2(100) := q'[it's END;]';
+BEGIN
+  emp_pkg.raise_salary(:new.employee_id);
+END;
+/
+
+CREATE OR REPLACE TRIGGER trg_compound
+FOR UPDATE ON employees
+COMPOUND TRIGGER
+  g_rows NUMBER;
+  BEFORE STATEMENT IS
+  BEGIN
+    g_rows := 0;
+  END BEFORE STATEMENT;
+  AFTER EACH ROW IS
+  BEGIN
+    cleanup;
+  END AFTER EACH ROW;
+END trg_compound;
+/
+
+CREATE PROCEDURE cleanup AS
+BEGIN
+  DELETE FROM tmp;
+  COMMIT;
+END;
+/
+"#;
+
+const ORDERS_TSQL: &str = r#"SET ANSI_NULLS ON
+GO
+/*
+ * Orders placed by a customer since a date.
+ */
+CREATE OR ALTER PROCEDURE [dbo].[usp_GetOrders]
+    @CustomerId INT,
+    @Since AS DATE = NULL,
+    @Total MONEY OUTPUT
+WITH RECOMPILE
+AS
+BEGIN
+    SET NOCOUNT ON;
+    BEGIN TRY
+        BEGIN TRAN;
+        IF @Since IS NULL
+            SET @Since = DATEADD(day, -30, GETDATE());
+        SELECT @Total = dbo.fn_OrderTotal(@CustomerId);
+        EXEC dbo.usp_LogAccess @CustomerId;
+        EXEC @rc = audit.usp_Record 'orders';
+        EXEC sp_executesql N'SELECT 1';
+        EXEC (@sql);
+        COMMIT;
+    END TRY
+    BEGIN CATCH
+        ROLLBACK;
+    END CATCH
+END
+GO
+
+CREATE FUNCTION dbo.fn_OrderTotal (@CustomerId INT)
+RETURNS DECIMAL(10, 2)
+WITH SCHEMABINDING
+AS
+BEGIN
+    RETURN (SELECT SUM(Amount) FROM dbo.Orders WHERE CustomerId = @CustomerId);
+END
+GO
+
+CREATE PROC usp_LogAccess @CustomerId int AS INSERT INTO AccessLog VALUES (@CustomerId)
+GO
+
+CREATE TRIGGER trg_Orders_Audit ON dbo.Orders
+AFTER INSERT, UPDATE
+AS
+BEGIN
+    EXEC usp_LogAccess 1;
+END
+GO
+"#;
+
+fn find<'a>(symbols: &'a [Symbol], name: &str) -> &'a Symbol {
+    symbols
+        .iter()
+        .find(|symbol| symbol.name == name)
+        .unwrap_or_else(|| panic!("missing symbol {name}"))
+}
+
+fn find_all<'a>(symbols: &'a [Symbol], name: &str) -> Vec<&'a Symbol> {
+    symbols
+        .iter()
+        .filter(|symbol| symbol.name == name)
+        .collect()
+}
+
+fn info(symbol: &Symbol, key: &str) -> serde_json::Value {
+    symbol.metadata.as_ref().unwrap()[SQL_METADATA_KEY][key].clone()
+}
+
+fn group(symbol: &Symbol) -> Option<&str> {
+    symbol
+        .metadata
+        .as_ref()?
+        .get(PARTIAL_GROUP_METADATA_KEY)?
+        .as_str()
+}
+
+/// `(from, to, kind)` names of each resolved relationship.
+fn edges(results: &ExtractionResults) -> Vec<(&str, &str, RelationshipKind)> {
+    let name = |id: &str| {
+        results
+            .symbols
+            .iter()
+            .find(|symbol| symbol.id == id)
+            .map_or("?", |symbol| symbol.name.as_str())
+    };
+    results
+        .relationships
+        .iter()
+        .map(|rel| {
+            (
+                name(&rel.from_symbol_id),
+                name(&rel.to_symbol_id),
+                rel.kind.clone(),
+            )
+        })
+        .collect()
+}
+
+fn pending(results: &ExtractionResults) -> Vec<(&str, RelationshipKind)> {
+    results
+        .pending_relationships
+        .iter()
+        .map(|pending| (pending.callee_name.as_str(), pending.kind.clone()))
+        .collect()
+}
+
+#[test]
+fn test_plsql_package_spec_and_body_share_partial_groups() {
+    let results = extract_sql_routines("db/emp_pkg.sql", EMP_PKG, SqlDialect::PlSql);
+
+    let packages = find_all(&results.symbols, "emp_pkg");
+    assert_eq!(packages.len(), 2);
+    let (spec, body) = (packages[0], packages[1]);
+    assert_eq!(spec.kind, SymbolKind::Module);
+    assert_eq!(spec.signature.as_deref(), Some("PACKAGE hr.emp_pkg"));
+    assert_eq!(spec.doc_comment.as_deref(), Some("Employee operations."));
+    assert_eq!((spec.start_line, spec.end_line), (2, 7));
+    assert_eq!(info(spec, "part"), "spec");
+    assert_eq!(info(spec, "schema"), "hr");
+    assert_eq!(info(body, "part"), "body");
+    assert_eq!((body.start_line, body.end_line), (10, 37));
+    assert_eq!(group(spec), Some("plsql:emp_pkg"));
+    assert_eq!(group(body), group(spec));
+
+    let raises = find_all(&results.symbols, "raise_salary");
+    assert_eq!(raises.len(), 2);
+    let (declared, defined) = (raises[0], raises[1]);
+    assert_eq!(declared.kind, SymbolKind::Function);
+    assert_eq!(declared.parent_id.as_deref(), Some(spec.id.as_str()));
+    assert_eq!(defined.parent_id.as_deref(), Some(body.id.as_str()));
+    assert_eq!(declared.visibility, Some(Visibility::Public));
+    assert_eq!(
+        declared.doc_comment.as_deref(),
+        Some("Raise an employee's salary.")
+    );
+    assert_eq!(info(declared, "kind"), "procedure");
+    assert_eq!(info(declared, "part"), "spec");
+    assert_eq!(info(defined, "part"), "body");
+    assert_eq!((defined.start_line, defined.end_line), (16, 27));
+    assert_eq!(
+        info(declared, "parameters"),
+        serde_json::json!([
+            {"name": "p_id", "mode": "in", "type": "employees.employee_id%TYPE"},
+            {"name": "p_pct", "mode": "in", "type": "NUMBER", "default": "10"},
+        ])
+    );
+    // Type case differs between the two; the group still matches.
+    assert_eq!(
+        group(declared),
+        Some("plsql:emp_pkg.raise_salary(employees.employee_id%type;number)")
+    );
+    assert_eq!(group(defined), group(declared));
+
+    let get_salary = find(&results.symbols, "get_salary");
+    assert_eq!(info(get_salary, "returns"), "NUMBER");
+    assert_eq!(
+        get_salary.signature.as_deref(),
+        Some("FUNCTION get_salary(p_id IN NUMBER) RETURN NUMBER")
+    );
+    assert_eq!(
+        group(get_salary),
+        Some("plsql:emp_pkg.get_salary(number return number)")
+    );
+
+    // Only declared in the body: not visible outside the package.
+    let log_change = find(&results.symbols, "log_change");
+    assert_eq!(log_change.visibility, None);
+    assert_eq!(info(log_change, "part"), "body");
+
+    let cleanup = find(&results.symbols, "cleanup");
+    assert!(cleanup.parent_id.is_none());
+    assert_eq!(group(cleanup), None);
+    assert_eq!((cleanup.start_line, cleanup.end_line), (66, 70));
+}
+
+#[test]
+fn test_plsql_triggers_and_calls_link_to_their_targets() {
+    let results = extract_sql_routines("db/emp_pkg.sql", EMP_PKG, SqlDialect::PlSql);
+
+    let trigger = find(&results.symbols, "trg_emp_audit");
+    assert_eq!(trigger.kind, SymbolKind::Function);
+    assert_eq!(info(trigger, "kind"), "trigger");
+    assert_eq!(info(trigger, "timing"), "before");
+    assert_eq!(
+        info(trigger, "events"),
+        serde_json::json!(["insert", "update"])
+    );
+    assert_eq!(info(trigger, "table"), "hr.employees");
+    assert_eq!(info(trigger, "for_each_row"), true);
+    // The quoted `END;` does not end the trigger.
+    assert_eq!((trigger.start_line, trigger.end_line), (40, 48));
+
+    let compound = find(&results.symbols, "trg_compound");
+    assert_eq!(info(compound, "compound"), true);
+    assert_eq!((compound.start_line, compound.end_line), (51, 63));
+
+    let calls = edges(&results);
+    for expected in [
+        ("raise_salary", "get_salary", RelationshipKind::Calls),
+        ("raise_salary", "log_change", RelationshipKind::Calls),
+        ("trg_emp_audit", "raise_salary", RelationshipKind::Calls),
+        ("trg_compound", "cleanup", RelationshipKind::Calls),
+    ] {
+        assert!(calls.contains(&expected), "{expected:?} in {calls:?}");
+    }
+    // Calls resolve to the definition, not the spec declaration.
+    let defined = find_all(&results.symbols, "get_salary")[1];
+    assert!(
+        results
+            .relationships
+            .iter()
+            .any(|rel| rel.to_symbol_id == defined.id)
+    );
+
+    // A procedure call into another package is left for the resolver;
+    // Oracle-supplied packages and function calls to unknown names are not.
+    let unresolved = pending(&results);
+    assert!(unresolved.contains(&("record_event", RelationshipKind::Calls)));
+    assert!(unresolved.contains(&("employees", RelationshipKind::Uses)));
+    assert!(
+        !unresolved
+            .iter()
+            .any(|(name, _)| name.eq_ignore_ascii_case("put_line"))
+    );
+    assert!(
+        results
+            .identifiers
+            .iter()
+            .any(|identifier| identifier.name == "employees"
+                && identifier.kind == IdentifierKind::TypeUsage)
+    );
+}
+
+#[test]
+fn test_tsql_routines_run_to_the_end_of_their_batch() {
+    let results = extract_sql_routines("db/orders.sql", ORDERS_TSQL, SqlDialect::TSql);
+    let names: Vec<&str> = results
+        .symbols
+        .iter()
+        .map(|symbol| symbol.name.as_str())
+        .collect();
+    assert_eq!(
+        names,
+        [
+            "usp_GetOrders",
+            "fn_OrderTotal",
+            "usp_LogAccess",
+            "trg_Orders_Audit"
+        ]
+    );
+
+    let get_orders = find(&results.symbols, "usp_GetOrders");
+    assert_eq!((get_orders.start_line, get_orders.end_line), (6, 28));
+    assert_eq!(
+        get_orders.doc_comment.as_deref(),
+        Some("Orders placed by a customer since a date.")
+    );
+    assert_eq!(info(get_orders, "schema"), "dbo");
+    assert_eq!(
+        info(get_orders, "parameters"),
+        serde_json::json!([
+            {"name": "@CustomerId", "mode": "in", "type": "INT"},
+            {"name": "@Since", "mode": "in", "type": "DATE", "default": "NULL"},
+            {"name": "@Total", "mode": "out", "type": "MONEY"},
+        ])
+    );
+
+    let total = find(&results.symbols, "fn_OrderTotal");
+    assert_eq!(info(total, "returns"), "DECIMAL(10, 2)");
+    assert_eq!(
+        total.signature.as_deref(),
+        Some("FUNCTION dbo.fn_OrderTotal (@CustomerId INT) RETURNS DECIMAL(10, 2)")
+    );
+
+    let log_access = find(&results.symbols, "usp_LogAccess");
+    assert_eq!(log_access.start_line, log_access.end_line);
+
+    let trigger = find(&results.symbols, "trg_Orders_Audit");
+    assert_eq!(info(trigger, "timing"), "after");
+    assert_eq!(
+        info(trigger, "events"),
+        serde_json::json!(["insert", "update"])
+    );
+    assert_eq!(info(trigger, "table"), "dbo.Orders");
+
+    let calls = edges(&results);
+    for expected in [
+        ("usp_GetOrders", "fn_OrderTotal", RelationshipKind::Calls),
+        ("usp_GetOrders", "usp_LogAccess", RelationshipKind::Calls),
+        ("trg_Orders_Audit", "usp_LogAccess", RelationshipKind::Calls),
+    ] {
+        assert!(calls.contains(&expected), "{expected:?} in {calls:?}");
+    }
+    // `EXEC @rc = proc` is a call; dynamic SQL and system procedures are not.
+    assert_eq!(
+        pending(&results),
+        [
+            ("usp_Record", RelationshipKind::Calls),
+            ("Orders", RelationshipKind::Uses)
+        ]
+    );
+}
+
+#[test]
+fn test_dialect_comes_from_extension_then_config_then_content() {
+    let neutral = "CREATE PROCEDURE p AS BEGIN NULL; END;";
+    assert_eq!(
+        detect_sql_dialect("db/emp.pkb", neutral, Some(SqlDialect::TSql)),
+        Some(SqlDialect::PlSql)
+    );
+    assert_eq!(
+        detect_sql_dialect("db/p.tsql", neutral, None),
+        Some(SqlDialect::TSql)
+    );
+    assert_eq!(
+        detect_sql_dialect("db/p.sql", neutral, Some(SqlDialect::PlSql)),
+        Some(SqlDialect::PlSql)
+    );
+    assert_eq!(detect_sql_dialect("db/p.sql", neutral, None), None);
+
+    assert_eq!(
+        detect_sql_dialect("db/emp.sql", EMP_PKG, None),
+        Some(SqlDialect::PlSql)
+    );
+    assert_eq!(
+        detect_sql_dialect("db/orders.sql", ORDERS_TSQL, None),
+        Some(SqlDialect::TSql)
+    );
+    let postgres = "CREATE OR REPLACE FUNCTION f(a int) RETURNS int AS $$\nBEGIN RETURN a; END;\n$$ LANGUAGE plpgsql;";
+    assert_eq!(detect_sql_dialect("db/f.sql", postgres, None), None);
+    let mysql = "DELIMITER //\nCREATE PROCEDURE p(IN x INT) BEGIN SELECT x; END //\nDELIMITER ;";
+    assert_eq!(detect_sql_dialect("db/p.sql", mysql, None), None);
+    let ansi = "CREATE TABLE t (id INT);\nSELECT * FROM t;";
+    assert_eq!(detect_sql_dialect("db/t.sql", ansi, None), None);
+
+    // Dialect extensions skip the grammar entirely.
+    let results = extract_sql_dialect_source("db/emp_pkg.pks", EMP_PKG).unwrap();
+    assert_eq!(find_all(&results.symbols, "emp_pkg").len(), 2);
+    assert!(extract_sql_dialect_source("db/emp_pkg.sql", EMP_PKG).is_err());
+}
+
+fn grammar_symbol(id: &str, name: &str, content: &str, needle: &str) -> Symbol {
+    let start = content.find(needle).unwrap();
+    let line = content[..start].matches('\n').count() as u32 + 1;
+    Symbol {
+        id: id.to_string(),
+        name: name.to_string(),
+        kind: SymbolKind::Function,
+        language: "sql".to_string(),
+        file_path: "db/orders.sql".to_string(),
+        start_line: line,
+        start_column: 0,
+        end_line: line,
+        end_column: 1,
+        start_byte: start as u32,
+        end_byte: (start + needle.len()) as u32,
+        signature: None,
+        doc_comment: None,
+        visibility: None,
+        parent_id: None,
+        metadata: None,
+        semantic_group: None,
+        confidence: None,
+        code_context: None,
+        content_type: None,
+        body_span: None,
+        body_hash: None,
+        annotations: Vec::new(),
+    }
+}
+
+#[test]
+fn test_routines_replace_grammar_symbols_inside_them() {
+    let content = format!("{ORDERS_TSQL}CREATE TABLE dbo.Orders (Id int);\n");
+    let mut results = ExtractionResults::empty();
+    results.symbols = vec![
+        grammar_symbol("g_proc", "usp_GetOrders", &content, "CREATE OR ALTER"),
+        grammar_symbol("g_select", "fn_OrderTotal", &content, "SELECT @Total"),
+        grammar_symbol("g_table", "Orders", &content, "CREATE TABLE dbo.Orders"),
+    ];
+    results.relationships.push(Relationship {
+        id: "g_rel".to_string(),
+        from_symbol_id: "g_select".to_string(),
+        to_symbol_id: "g_table".to_string(),
+        kind: RelationshipKind::Uses,
+        file_path: "db/orders.sql".to_string(),
+        line_number: 18,
+        confidence: 1.0,
+        metadata: None,
+    });
+    results.identifiers.push(Identifier {
+        id: "g_ident".to_string(),
+        name: "Orders".to_string(),
+        kind: IdentifierKind::TypeUsage,
+        language: "sql".to_string(),
+        file_path: "db/orders.sql".to_string(),
+        start_line: 36,
+        start_column: 0,
+        end_line: 36,
+        end_column: 6,
+        start_byte: 0,
+        end_byte: 6,
+        containing_symbol_id: Some("g_select".to_string()),
+        target_symbol_id: Some("g_table".to_string()),
+        confidence: 1.0,
+        code_context: None,
+    });
+
+    let added = annotate_sql_routines(&mut results, "db/orders.sql", "sql", &content, None);
+    assert_eq!(added, 4);
+    let get_orders = find(&results.symbols, "usp_GetOrders");
+    assert_eq!(
+        find_all(&results.symbols, "usp_GetOrders").len(),
+        1,
+        "the grammar's copy is replaced"
+    );
+    assert!(results.symbols.iter().all(|symbol| symbol.id != "g_select"));
+    assert_eq!(find(&results.symbols, "Orders").id, "g_table");
+
+    let moved = results
+        .relationships
+        .iter()
+        .find(|rel| rel.id == "g_rel")
+        .unwrap();
+    assert_eq!(moved.from_symbol_id, get_orders.id);
+    assert_eq!(moved.to_symbol_id, "g_table");
+    let identifier = results
+        .identifiers
+        .iter()
+        .find(|identifier| identifier.id == "g_ident")
+        .unwrap();
+    assert_eq!(
+        identifier.containing_symbol_id.as_deref(),
+        Some(get_orders.id.as_str())
+    );
+
+    // Other languages and dialect-free SQL are left alone.
+    let mut untouched = ExtractionResults::empty();
+    assert_eq!(
+        annotate_sql_routines(&mut untouched, "db/orders.ts", "typescript", &content, None),
+        0
+    );
+    assert_eq!(
+        annotate_sql_routines(
+            &mut untouched,
+            "db/t.sql",
+            "sql",
+            "CREATE TABLE t (id INT);",
+            None
+        ),
+        0
+    );
+    assert!(untouched.symbols.is_empty());
+}
+
+#[test]
+fn test_tolerates_unterminated_units_and_quoted_names() {
+    let source = "CREATE OR REPLACE PACKAGE BODY \"Billing\" IS\n  PROCEDURE run IS\n  BEGIN\n    IF x THEN\n      LOOP NULL; END LOOP;\n";
+    let results = extract_sql_routines("db/billing.pkb", source, SqlDialect::PlSql);
+    let package = find(&results.symbols, "Billing");
+    let run = find(&results.symbols, "run");
+    assert_eq!(run.parent_id.as_deref(), Some(package.id.as_str()));
+    assert_eq!(package.end_line, 5);
+    assert_eq!(run.end_line, 5);
+
+    let tsql = "CREATE PROCEDURE [sales].[Daily Report] AS\nSELECT 1\nCREATE PROCEDURE next_proc AS SELECT 2";
+    let results = extract_sql_routines("db/report.sql", tsql, SqlDialect::TSql);
+    let report = find(&results.symbols, "Daily Report");
+    assert_eq!(info(report, "schema"), "sales");
+    assert_eq!(report.end_line, 2, "a batch also ends at the next routine");
+    find(&results.symbols, "next_proc");
+
+    assert!(
+        extract_sql_routines("db/empty.sql", "", SqlDialect::PlSql)
+            .symbols
+            .is_empty()
+    );
+    let ids: std::collections::HashSet<String> =
+        extract_sql_routines("db/emp_pkg.sql", EMP_PKG, SqlDialect::PlSql)
+            .symbols
+            .into_iter()
+            .map(|symbol| symbol.id)
+            .collect();
+    assert_eq!(ids.len(), 10);
+}
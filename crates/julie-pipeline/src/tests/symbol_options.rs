@@ -0,0 +1,172 @@
+// Tests for per-language symbol filtering (indexing_core::symbol_options)
+
+use crate::indexing_core::symbol_options::apply_symbol_options;
+use julie_core::workspace_settings::SymbolOptions;
+use julie_extractors::base::Visibility;
+use julie_extractors::{
+    ExtractionResults, Identifier, IdentifierKind, Relationship, RelationshipKind, Symbol,
+    SymbolKind,
+};
+
+fn sym(id: &str, kind: SymbolKind, visibility: Visibility, parent: Option<&str>) -> Symbol {
+    Symbol {
+        id: id.to_string(),
+        name: id.to_string(),
+        kind,
+        language: "rust".to_string(),
+        file_path: "src/lib.rs".to_string(),
+        start_line: 1,
+        start_column: 0,
+        end_line: 10,
+        end_column: 1,
+        start_byte: 0,
+        end_byte: 100,
+        signature: None,
+        doc_comment: None,
+        visibility: Some(visibility),
+        parent_id: parent.map(str::to_string),
+        metadata: None,
+        semantic_group: None,
+        confidence: None,
+        code_context: None,
+        content_type: None,
+        body_span: None,
+        body_hash: None,
+        annotations: Vec::new(),
+    }
+}
+
+fn identifier(id: &str, containing: &str, target: &str) -> Identifier {
+    Identifier {
+        id: id.to_string(),
+        name: id.to_string(),
+        kind: IdentifierKind::Call,
+        language: "rust".to_string(),
+        file_path: "src/lib.rs".to_string(),
+        start_line: 5,
+        start_column: 4,
+        end_line: 5,
+        end_column: 10,
+        start_byte: 50,
+        end_byte: 56,
+        containing_symbol_id: Some(containing.to_string()),
+        target_symbol_id: Some(target.to_string()),
+        confidence: 1.0,
+        code_context: None,
+    }
+}
+
+fn calls(from: &str, to: &str) -> Relationship {
+    Relationship {
+        id: format!("{from}_{to}"),
+        from_symbol_id: from.to_string(),
+        to_symbol_id: to.to_string(),
+        kind: RelationshipKind::Calls,
+        file_path: "src/lib.rs".to_string(),
+        line_number: 5,
+        confidence: 1.0,
+        metadata: None,
+    }
+}
+
+/// `use std::fmt;`, a public struct with a private method holding a local,
+/// and a public function holding a local.
+fn sample() -> ExtractionResults {
+    let mut results = ExtractionResults::empty();
+    results.symbols = vec![
+        sym("import", SymbolKind::Import, Visibility::Private, None),
+        sym("Widget", SymbolKind::Struct, Visibility::Public, None),
+        sym(
+            "helper",
+            SymbolKind::Method,
+            Visibility::Private,
+            Some("Widget"),
+        ),
+        sym(
+            "tmp",
+            SymbolKind::Variable,
+            Visibility::Private,
+            Some("helper"),
+        ),
+        sym("run", SymbolKind::Function, Visibility::Public, None),
+        sym(
+            "count",
+            SymbolKind::Variable,
+            Visibility::Private,
+            Some("run"),
+        ),
+        sym("LIMIT", SymbolKind::Constant, Visibility::Public, None),
+    ];
+    results.relationships = vec![calls("run", "helper"), calls("run", "LIMIT")];
+    results.identifiers = vec![
+        identifier("in_helper", "helper", "LIMIT"),
+        identifier("in_run", "run", "helper"),
+    ];
+    results
+}
+
+fn ids(results: &ExtractionResults) -> Vec<&str> {
+    results.symbols.iter().map(|s| s.id.as_str()).collect()
+}
+
+#[test]
+fn default_options_keep_everything() {
+    let mut results = sample();
+    assert_eq!(
+        apply_symbol_options(&mut results, &SymbolOptions::default()),
+        0
+    );
+    assert_eq!(results.symbols.len(), 7);
+    assert_eq!(results.relationships.len(), 2);
+}
+
+#[test]
+fn excluding_locals_drops_function_scoped_variables_only() {
+    let mut results = sample();
+    let options = SymbolOptions {
+        include_locals: Some(false),
+        ..SymbolOptions::default()
+    };
+
+    assert_eq!(apply_symbol_options(&mut results, &options), 2);
+    assert_eq!(
+        ids(&results),
+        vec!["import", "Widget", "helper", "run", "LIMIT"]
+    );
+}
+
+#[test]
+fn excluding_imports_drops_import_symbols() {
+    let mut results = sample();
+    let options = SymbolOptions {
+        include_imports: Some(false),
+        ..SymbolOptions::default()
+    };
+
+    assert_eq!(apply_symbol_options(&mut results, &options), 1);
+    assert!(!ids(&results).contains(&"import"));
+}
+
+#[test]
+fn excluding_private_drops_descendants_and_dependent_rows() {
+    let mut results = sample();
+    let options = SymbolOptions {
+        include_private: Some(false),
+        ..SymbolOptions::default()
+    };
+
+    apply_symbol_options(&mut results, &options);
+
+    assert_eq!(ids(&results), vec!["Widget", "run", "LIMIT"]);
+    // The edge into the dropped method goes; the other stays.
+    assert_eq!(results.relationships.len(), 1);
+    assert_eq!(results.relationships[0].to_symbol_id, "LIMIT");
+    // Identifiers inside the dropped method move to the kept struct, and
+    // references to it lose their target.
+    let in_helper = &results.identifiers[0];
+    assert_eq!(in_helper.containing_symbol_id.as_deref(), Some("Widget"));
+    assert_eq!(in_helper.target_symbol_id.as_deref(), Some("LIMIT"));
+    let in_run = &results.identifiers[1];
+    assert_eq!(in_run.containing_symbol_id.as_deref(), Some("run"));
+    assert_eq!(in_run.target_symbol_id, None);
+}
@@ -0,0 +1,151 @@
+// Tests for TODO/FIXME/HACK task symbols (indexing_core::task_comments)
+
+use crate::indexing_core::task_comments::{
+    TASK_SEMANTIC_GROUP, TaskComment, annotate_task_comments, parse_task_comment, task_info,
+};
+use julie_extractors::ExtractionResults;
+use julie_extractors::base::{SourceRegion, SourceRegionKind, Symbol, SymbolKind};
+
+const SOURCE: &str = "\
+// TODO: split this module
+fn load() {
+    let x = 1; // FIXME(ana): overflow on 32-bit
+    /* not a TODO here
+     * HACK - skip the cache */
+}
+";
+
+fn region(id: &str, kind: SourceRegionKind, start: &str, end: &str) -> SourceRegion {
+    let start_byte = SOURCE.find(start).unwrap();
+    let end_byte = SOURCE.find(end).unwrap() + end.len();
+    let line_of = |byte: usize| SOURCE[..byte].matches('\n').count() as u32 + 1;
+    let line_start = SOURCE[..start_byte].rfind('\n').map_or(0, |i| i + 1);
+    SourceRegion {
+        id: id.into(),
+        file_path: "src/lib.rs".into(),
+        language: "rust".into(),
+        kind,
+        containing_symbol_id: None,
+        start_line: line_of(start_byte),
+        start_column: (start_byte - line_start) as u32,
+        end_line: line_of(end_byte),
+        end_column: 0,
+        start_byte: start_byte as u32,
+        end_byte: end_byte as u32,
+        metadata: None,
+    }
+}
+
+fn function(id: &str, name: &str, start_line: u32, end_line: u32) -> Symbol {
+    let start_byte = SOURCE.find(&format!("fn {name}")).unwrap() as u32;
+    Symbol {
+        id: id.to_string(),
+        name: name.to_string(),
+        kind: SymbolKind::Function,
+        language: "rust".to_string(),
+        file_path: "src/lib.rs".to_string(),
+        start_line,
+        start_column: 0,
+        end_line,
+        end_column: 1,
+        start_byte,
+        end_byte: SOURCE.len() as u32 - 1,
+        signature: None,
+        doc_comment: None,
+        visibility: None,
+        parent_id: None,
+        metadata: None,
+        semantic_group: None,
+        confidence: None,
+        code_context: None,
+        content_type: None,
+        body_span: None,
+        body_hash: None,
+        annotations: Vec::new(),
+    }
+}
+
+#[test]
+fn markers_count_only_at_the_start_of_a_comment_line() {
+    assert_eq!(
+        parse_task_comment("  // TODO(ana): retry on 503 */"),
+        Some(TaskComment {
+            marker: "TODO",
+            assignee: Some("ana".to_string()),
+            text: "retry on 503".to_string(),
+            column: 5,
+        })
+    );
+    assert_eq!(
+        parse_task_comment("# FIXME").map(|task| (task.marker, task.text)),
+        Some(("FIXME", String::new()))
+    );
+    assert_eq!(
+        parse_task_comment("<!-- HACK: inline styles -->").map(|task| task.text),
+        Some("inline styles".to_string())
+    );
+    for prose in [
+        "// this is not a TODO",
+        "// TODOS are tracked elsewhere",
+        "// todo: lower case is prose",
+        "// TODO_LIST constant",
+    ] {
+        assert_eq!(parse_task_comment(prose), None, "{prose}");
+    }
+}
+
+#[test]
+fn comment_regions_become_task_symbols_in_their_enclosing_symbol() {
+    let mut results = ExtractionResults::empty();
+    results.symbols.push(function("load", "load", 2, 6));
+    results.source_regions = vec![
+        region("r1", SourceRegionKind::Comment, "// TODO", "module"),
+        region("r2", SourceRegionKind::Comment, "// FIXME", "32-bit"),
+        region("r3", SourceRegionKind::Comment, "/* not", "cache */"),
+        region("r4", SourceRegionKind::StringLiteral, "TODO", "module"),
+    ];
+
+    annotate_task_comments(&mut results, "src/lib.rs", "rust", SOURCE);
+
+    let tasks: Vec<&Symbol> = results
+        .symbols
+        .iter()
+        .filter(|symbol| symbol.semantic_group.as_deref() == Some(TASK_SEMANTIC_GROUP))
+        .collect();
+    let summary: Vec<(u32, u32, Option<&str>, (&str, &str, Option<&str>))> = tasks
+        .iter()
+        .map(|symbol| {
+            (
+                symbol.start_line,
+                symbol.start_column,
+                symbol.parent_id.as_deref(),
+                task_info(symbol).unwrap(),
+            )
+        })
+        .collect();
+    assert_eq!(
+        summary,
+        [
+            (1, 3, None, ("TODO", "split this module", None)),
+            (
+                3,
+                18,
+                Some("load"),
+                ("FIXME", "overflow on 32-bit", Some("ana"))
+            ),
+            (5, 7, Some("load"), ("HACK", "skip the cache", None)),
+        ],
+        "string literals and mid-sentence markers are skipped"
+    );
+    let fixme = tasks[1];
+    assert_eq!(fixme.name, "FIXME");
+    assert_eq!(
+        fixme.signature.as_deref(),
+        Some("FIXME(ana): overflow on 32-bit")
+    );
+    assert_eq!(
+        &SOURCE[fixme.start_byte as usize..fixme.end_byte as usize],
+        "FIXME(ana): overflow on 32-bit"
+    );
+    assert_eq!(task_info(&results.symbols[0]), None);
+}
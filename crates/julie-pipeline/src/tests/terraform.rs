@@ -0,0 +1,351 @@
+// Tests for Terraform extraction (indexing_core::terraform)
+
+use crate::indexing_core::terraform::{
+    TERRAFORM_METADATA_KEY, extract_terraform, is_terraform_reference, resolve_terraform_references,
+};
+use julie_core::database::{FileInfo, SymbolDatabase};
+use julie_extractors::{ExtractionResults, RelationshipKind, Symbol, SymbolKind};
+use tempfile::TempDir;
+
+const MAIN_TF: &str = r#"# Bucket for access logs.
+resource "aws_s3_bucket" "logs" {
+  bucket = "${local.name_prefix}-access-logs"
+  tags   = local.tags
+}
+
+resource "aws_s3_bucket_policy" "logs" {
+  bucket = aws_s3_bucket.logs.id
+  policy = data.aws_iam_policy_document.logs.json
+}
+
+data "aws_iam_policy_document" "logs" {
+  statement {
+    actions   = ["s3:PutObject"]
+    resources = ["${aws_s3_bucket.logs.arn}/*"]
+  }
+}
+
+module "vpc" {
+  source = "./modules/vpc"
+  cidr   = var.vpc_cidr
+  azs    = ["eu-west-1a"]
+}
+
+module "dns" {
+  source  = "terraform-aws-modules/route53/aws"
+  version = "~> 2.0"
+  zone    = var.domain
+}
+
+locals {
+  name_prefix = "acme-${var.environment}"
+  # Tags on every resource.
+  tags = {
+    Environment = var.environment
+    Subnets     = join(",", module.vpc.private_subnets)
+  }
+  policy = <<-EOT
+    {"Resource": "${aws_s3_bucket.logs.arn}"}
+  EOT
+}
+
+output "log_bucket" {
+  value = aws_s3_bucket.logs.bucket
+}
+
+provider "aws" {
+  region = var.region
+}
+"#;
+
+const VARIABLES_TF: &str = r#"variable "environment" {
+  type        = string
+  description = "Deployment stage."
+}
+
+variable "vpc_cidr" {
+  type    = string
+  default = "10.0.0.0/16"
+}
+"#;
+
+fn find<'a>(symbols: &'a [Symbol], name: &str) -> &'a Symbol {
+    symbols
+        .iter()
+        .find(|symbol| symbol.name == name)
+        .unwrap_or_else(|| panic!("no symbol named {name}"))
+}
+
+fn name_of<'a>(symbols: &'a [Symbol], id: &str) -> &'a str {
+    symbols
+        .iter()
+        .find(|symbol| symbol.id == id)
+        .map(|symbol| symbol.name.as_str())
+        .unwrap()
+}
+
+fn seeded_db(temp_dir: &TempDir, files: &[(&str, &ExtractionResults)]) -> SymbolDatabase {
+    let mut db = SymbolDatabase::new(temp_dir.path().join("test.db")).unwrap();
+    for (path, _) in files {
+        db.store_file_info(&FileInfo {
+            path: path.to_string(),
+            language: "terraform".to_string(),
+            hash: "h".to_string(),
+            size: 100,
+            last_modified: 1000,
+            last_indexed: 0,
+            symbol_count: 0,
+            line_count: 0,
+            content: None,
+        })
+        .unwrap();
+    }
+    let symbols: Vec<Symbol> = files
+        .iter()
+        .flat_map(|(_, results)| results.symbols.iter().cloned())
+        .collect();
+    db.store_symbols_transactional(&symbols).unwrap();
+    db
+}
+
+#[test]
+fn blocks_become_symbols_named_by_address_or_label() {
+    let results = extract_terraform("infra/main.tf", MAIN_TF).unwrap();
+    let symbols = &results.symbols;
+
+    let kinds: Vec<(&str, SymbolKind)> = symbols
+        .iter()
+        .map(|symbol| (symbol.name.as_str(), symbol.kind.clone()))
+        .collect();
+    assert_eq!(
+        kinds,
+        [
+            ("aws_s3_bucket.logs", SymbolKind::Class),
+            ("aws_s3_bucket_policy.logs", SymbolKind::Class),
+            ("data.aws_iam_policy_document.logs", SymbolKind::Class),
+            ("vpc", SymbolKind::Module),
+            ("dns", SymbolKind::Module),
+            ("name_prefix", SymbolKind::Variable),
+            ("tags", SymbolKind::Variable),
+            ("policy", SymbolKind::Variable),
+            ("log_bucket", SymbolKind::Property),
+        ],
+        "provider blocks are not symbols"
+    );
+
+    let bucket = &symbols[0];
+    assert_eq!(bucket.language, "terraform");
+    assert_eq!(
+        bucket.signature.as_deref(),
+        Some(r#"resource "aws_s3_bucket" "logs""#)
+    );
+    assert_eq!(
+        bucket.doc_comment.as_deref(),
+        Some("Bucket for access logs.")
+    );
+    assert_eq!((bucket.start_line, bucket.end_line), (2, 5));
+    assert!(
+        bucket
+            .code_context
+            .as_deref()
+            .unwrap()
+            .contains("-access-logs"),
+        "the block body is searchable"
+    );
+    let info = &bucket.metadata.as_ref().unwrap()[TERRAFORM_METADATA_KEY];
+    assert_eq!(info["block"], "resource");
+    assert_eq!(info["address"], "aws_s3_bucket.logs");
+    assert_eq!(info["type"], "aws_s3_bucket");
+    assert_eq!(info["provider"], "aws");
+
+    let vpc = &find(symbols, "vpc").metadata.as_ref().unwrap()[TERRAFORM_METADATA_KEY];
+    assert_eq!(vpc["address"], "module.vpc");
+    assert_eq!(vpc["source"], "./modules/vpc");
+    assert_eq!(vpc["inputs"], serde_json::json!(["cidr", "azs"]));
+    let dns = &find(symbols, "dns").metadata.as_ref().unwrap()[TERRAFORM_METADATA_KEY];
+    assert_eq!(dns["version"], "~> 2.0");
+
+    let tags = find(symbols, "tags");
+    assert_eq!(tags.signature.as_deref(), Some("tags = {"));
+    assert_eq!(tags.doc_comment.as_deref(), Some("Tags on every resource."));
+    assert_eq!((tags.start_line, tags.end_line), (34, 37));
+    assert_eq!(
+        tags.metadata.as_ref().unwrap()[TERRAFORM_METADATA_KEY]["address"],
+        "local.tags"
+    );
+    assert_eq!(
+        find(symbols, "policy").end_line,
+        40,
+        "heredocs end at their marker"
+    );
+
+    let variables = extract_terraform("infra/variables.tf", VARIABLES_TF).unwrap();
+    let environment = &variables.symbols[0];
+    assert_eq!(environment.name, "environment");
+    assert_eq!(
+        environment.doc_comment.as_deref(),
+        Some("Deployment stage."),
+        "description documents a variable without a comment"
+    );
+    let info = &environment.metadata.as_ref().unwrap()[TERRAFORM_METADATA_KEY];
+    assert_eq!(info["address"], "var.environment");
+    assert_eq!(info["type"], "string");
+    assert_eq!(
+        variables.symbols[1].metadata.as_ref().unwrap()[TERRAFORM_METADATA_KEY]["default"],
+        "\"10.0.0.0/16\""
+    );
+}
+
+#[test]
+fn references_link_in_file_and_stay_pending_across_files() {
+    let results = extract_terraform("infra/main.tf", MAIN_TF).unwrap();
+    let symbols = &results.symbols;
+
+    let links: Vec<(&str, &str, u32)> = results
+        .relationships
+        .iter()
+        .map(|r| {
+            assert_eq!(r.kind, RelationshipKind::Uses);
+            (
+                name_of(symbols, &r.from_symbol_id),
+                name_of(symbols, &r.to_symbol_id),
+                r.line_number,
+            )
+        })
+        .collect();
+    assert_eq!(
+        links,
+        [
+            ("aws_s3_bucket.logs", "name_prefix", 3),
+            ("aws_s3_bucket.logs", "tags", 4),
+            ("aws_s3_bucket_policy.logs", "aws_s3_bucket.logs", 8),
+            (
+                "aws_s3_bucket_policy.logs",
+                "data.aws_iam_policy_document.logs",
+                9
+            ),
+            (
+                "data.aws_iam_policy_document.logs",
+                "aws_s3_bucket.logs",
+                15
+            ),
+            ("tags", "vpc", 36),
+            ("policy", "aws_s3_bucket.logs", 39),
+            ("log_bucket", "aws_s3_bucket.logs", 44),
+        ],
+        "interpolations and heredocs are expressions too"
+    );
+
+    let pending: Vec<(&str, &str, u32)> = results
+        .pending_relationships
+        .iter()
+        .map(|p| {
+            (
+                name_of(symbols, &p.from_symbol_id),
+                p.callee_name.as_str(),
+                p.line_number,
+            )
+        })
+        .collect();
+    assert_eq!(
+        pending,
+        [
+            ("vpc", "terraform:var.vpc_cidr", 21),
+            ("vpc", "terraform:module.vpc.var.cidr", 21),
+            ("vpc", "terraform:module.vpc.var.azs", 22),
+            ("dns", "terraform:var.domain", 28),
+            ("name_prefix", "terraform:var.environment", 32),
+            ("tags", "terraform:var.environment", 35),
+            ("tags", "terraform:module.vpc.output.private_subnets", 36),
+        ],
+        "a registry module's arguments have no variables to link to"
+    );
+    assert!(
+        results
+            .pending_relationships
+            .iter()
+            .all(is_terraform_reference)
+    );
+
+    let usage = results
+        .identifiers
+        .iter()
+        .find(|identifier| identifier.start_line == 8)
+        .unwrap();
+    assert_eq!(usage.name, "aws_s3_bucket.logs");
+    assert_eq!((usage.start_column, usage.end_column), (11, 29));
+    assert_eq!(
+        usage.target_symbol_id.as_deref(),
+        Some(symbols[0].id.as_str())
+    );
+    let in_string = results
+        .identifiers
+        .iter()
+        .find(|identifier| identifier.start_line == 3)
+        .unwrap();
+    assert_eq!(in_string.name, "name_prefix");
+    assert_eq!(in_string.start_column, 20);
+}
+
+#[test]
+fn pending_references_resolve_within_the_directory_and_into_local_modules() {
+    let temp_dir = TempDir::new().unwrap();
+    let main = extract_terraform("infra/main.tf", MAIN_TF).unwrap();
+    let variables = extract_terraform("infra/variables.tf", VARIABLES_TF).unwrap();
+    let module_variables = extract_terraform(
+        "infra/modules/vpc/variables.tf",
+        "variable \"cidr\" {}\nvariable \"azs\" {}\n",
+    )
+    .unwrap();
+    let module_outputs = extract_terraform(
+        "infra/modules/vpc/outputs.tf",
+        "output \"private_subnets\" {\n  value = aws_subnet.private[*].id\n}\n",
+    )
+    .unwrap();
+    // Another root module with a variable of the same name.
+    let other = extract_terraform(
+        "staging/variables.tf",
+        "variable \"environment\" {}\nvariable \"domain\" {}\n",
+    )
+    .unwrap();
+    let files = [
+        ("infra/main.tf", &main),
+        ("infra/variables.tf", &variables),
+        ("infra/modules/vpc/variables.tf", &module_variables),
+        ("infra/modules/vpc/outputs.tf", &module_outputs),
+        ("staging/variables.tf", &other),
+    ];
+    let db = seeded_db(&temp_dir, &files);
+    let symbols: Vec<Symbol> = files
+        .iter()
+        .flat_map(|(_, results)| results.symbols.iter().cloned())
+        .collect();
+
+    let resolved = resolve_terraform_references(&main.pending_relationships, &db);
+    let mut edges: Vec<(&str, &str, &str)> = resolved
+        .iter()
+        .map(|r| {
+            let target = symbols.iter().find(|s| s.id == r.to_symbol_id).unwrap();
+            (
+                name_of(&symbols, &r.from_symbol_id),
+                target.name.as_str(),
+                target.file_path.as_str(),
+            )
+        })
+        .collect();
+    edges.sort();
+    assert_eq!(
+        edges,
+        [
+            ("name_prefix", "environment", "infra/variables.tf"),
+            ("tags", "environment", "infra/variables.tf"),
+            ("tags", "private_subnets", "infra/modules/vpc/outputs.tf"),
+            ("vpc", "azs", "infra/modules/vpc/variables.tf"),
+            ("vpc", "cidr", "infra/modules/vpc/variables.tf"),
+            ("vpc", "vpc_cidr", "infra/variables.tf"),
+        ],
+        "var.domain is only declared in another directory"
+    );
+    assert!(resolved.iter().all(|r| r.kind == RelationshipKind::Uses));
+    assert!(resolved.iter().all(|r| r.file_path == "infra/main.tf"));
+}
@@ -0,0 +1,21 @@
+// Tests for byte offset to line lookups (indexing_core::text_lines)
+
+use crate::indexing_core::text_lines::TextLines;
+
+#[test]
+fn offsets_map_to_one_based_lines_and_back() {
+    let content = "first\nsecond\r\n\nlast";
+    let lines = TextLines::new(content);
+
+    assert_eq!(lines.line_of(0), 1);
+    assert_eq!(lines.line_of(5), 1, "the newline belongs to its line");
+    assert_eq!(lines.line_of(6), 2);
+    assert_eq!(lines.line_of(14), 3);
+    assert_eq!(lines.line_of(content.len()), 4);
+
+    assert_eq!(lines.line_start(2), 6);
+    assert_eq!(lines.line_start(4), 15);
+    assert_eq!(&content[lines.line_start(4)..], "last");
+    assert_eq!(lines.line_start(0), 0);
+    assert_eq!(lines.line_start(9), 0);
+}
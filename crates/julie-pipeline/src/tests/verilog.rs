@@ -0,0 +1,173 @@
+// Tests for Verilog/SystemVerilog extraction (indexing_core::verilog)
+
+use crate::indexing_core::verilog::{VERILOG_METADATA_KEY, extract_verilog};
+use julie_extractors::{IdentifierKind, RelationshipKind, Symbol, SymbolKind};
+
+const FIFO_SV: &str = r#"`timescale 1ns/1ps
+`include "defs.svh"
+
+// Synchronous FIFO.
+module fifo #(
+  parameter int WIDTH = 8, // data width
+  parameter DEPTH = 16
+) (
+  input  logic             clk,
+  input  logic             rst_n,
+  input  logic [WIDTH-1:0] din,
+  output logic [WIDTH-1:0] dout
+);
+  localparam AW = $clog2(DEPTH);
+
+  function automatic logic [AW-1:0] next_ptr(input logic [AW-1:0] ptr);
+    return ptr + 1;
+  endfunction
+endmodule : fifo
+
+module top (clk, data);
+  input clk;
+  input [7:0] data;
+
+  (* keep *) fifo #(.WIDTH(8), .DEPTH(32)) u_fifo (
+    .clk(clk), .rst_n(1'b1), .din(data), .dout()
+  );
+
+  generate
+    for (genvar i = 0; i < 2; i++) begin : g_lane
+      lane_ctrl u_lane (.clk(clk));
+    end
+  endgenerate
+
+  always @(*) begin
+    if (data) $display("x");
+  end
+endmodule
+"#;
+
+fn find<'a>(symbols: &'a [Symbol], name: &str) -> &'a Symbol {
+    symbols
+        .iter()
+        .find(|symbol| symbol.name == name)
+        .unwrap_or_else(|| panic!("missing symbol {name}"))
+}
+
+fn info(symbol: &Symbol, key: &str) -> serde_json::Value {
+    symbol.metadata.as_ref().unwrap()[VERILOG_METADATA_KEY][key].clone()
+}
+
+#[test]
+fn extracts_modules_with_ansi_ports_and_parameters() {
+    let results = extract_verilog("rtl/fifo.sv", FIFO_SV).unwrap();
+    let fifo = find(&results.symbols, "fifo");
+    assert_eq!(fifo.kind, SymbolKind::Module);
+    assert_eq!(fifo.language, "systemverilog");
+    assert_eq!((fifo.start_line, fifo.end_line), (5, 19));
+    assert_eq!(fifo.doc_comment.as_deref(), Some("Synchronous FIFO."));
+    assert_eq!(
+        fifo.signature.as_deref(),
+        Some("module fifo #(WIDTH, DEPTH) (clk, rst_n, din, dout)")
+    );
+
+    let width = find(&results.symbols, "WIDTH");
+    assert_eq!(width.kind, SymbolKind::Constant);
+    assert_eq!(width.parent_id.as_ref(), Some(&fifo.id));
+    assert_eq!(width.signature.as_deref(), Some("parameter int WIDTH = 8"));
+    assert_eq!(width.doc_comment.as_deref(), Some("data width"));
+    assert_eq!(info(find(&results.symbols, "AW"), "local"), true);
+
+    let dout = find(&results.symbols, "dout");
+    assert_eq!(dout.kind, SymbolKind::Field);
+    assert_eq!(dout.start_line, 12);
+    assert_eq!(info(dout, "direction"), "output");
+    assert_eq!(info(dout, "type"), "logic [WIDTH-1:0]");
+
+    let next_ptr = find(&results.symbols, "next_ptr");
+    assert_eq!(next_ptr.kind, SymbolKind::Function);
+    assert_eq!(next_ptr.parent_id.as_ref(), Some(&fifo.id));
+    assert_eq!((next_ptr.start_line, next_ptr.end_line), (16, 18));
+}
+
+#[test]
+fn non_ansi_ports_come_from_body_declarations() {
+    let results = extract_verilog("rtl/fifo.sv", FIFO_SV).unwrap();
+    let top = find(&results.symbols, "top");
+    let ports: Vec<_> = results
+        .symbols
+        .iter()
+        .filter(|symbol| symbol.parent_id.as_ref() == Some(&top.id))
+        .filter(|symbol| symbol.kind == SymbolKind::Field)
+        .map(|symbol| (symbol.name.as_str(), symbol.signature.as_deref().unwrap()))
+        .collect();
+    assert_eq!(ports, [("clk", "input clk"), ("data", "input [7:0] data")]);
+    assert_eq!(top.signature.as_deref(), Some("module top (clk, data)"));
+}
+
+#[test]
+fn instantiations_link_to_the_instantiated_module() {
+    let results = extract_verilog("rtl/fifo.sv", FIFO_SV).unwrap();
+    let fifo = find(&results.symbols, "fifo");
+    let top = find(&results.symbols, "top");
+
+    let u_fifo = find(&results.symbols, "u_fifo");
+    assert_eq!(u_fifo.kind, SymbolKind::Variable);
+    assert_eq!(u_fifo.parent_id.as_ref(), Some(&top.id));
+    assert_eq!(u_fifo.start_line, 25);
+    assert_eq!(info(u_fifo, "module"), "fifo");
+    assert_eq!(info(u_fifo, "parameters"), ".WIDTH(8), .DEPTH(32)");
+
+    // Defined in this file: resolved here.
+    assert_eq!(results.relationships.len(), 1);
+    let instantiates = &results.relationships[0];
+    assert_eq!(instantiates.kind, RelationshipKind::Instantiates);
+    assert_eq!(instantiates.from_symbol_id, top.id);
+    assert_eq!(instantiates.to_symbol_id, fifo.id);
+    assert_eq!(instantiates.line_number, 25);
+
+    // Defined elsewhere: left for the cross-file resolver.
+    assert_eq!(results.pending_relationships.len(), 1);
+    let pending = &results.pending_relationships[0];
+    assert_eq!(pending.callee_name, "lane_ctrl");
+    assert_eq!(pending.kind, RelationshipKind::Instantiates);
+    assert_eq!(pending.line_number, 31);
+    assert_eq!(
+        find(&results.symbols, "u_lane").parent_id.as_ref(),
+        Some(&top.id)
+    );
+
+    let usages: Vec<_> = results
+        .identifiers
+        .iter()
+        .map(|identifier| {
+            (
+                identifier.name.as_str(),
+                identifier.kind,
+                identifier.start_line,
+            )
+        })
+        .collect();
+    assert_eq!(
+        usages,
+        [
+            ("fifo", IdentifierKind::TypeUsage, 25),
+            ("lane_ctrl", IdentifierKind::TypeUsage, 31)
+        ]
+    );
+    assert_eq!(
+        results.identifiers[0].target_symbol_id.as_ref(),
+        Some(&fifo.id)
+    );
+}
+
+#[test]
+fn tolerates_unterminated_modules_and_plain_verilog() {
+    let source = "module half(input a);\n  and g1 (y, a, b);\n  sub s1 (a);\n";
+    let results = extract_verilog("rtl/half.v", source).unwrap();
+    let half = find(&results.symbols, "half");
+    assert_eq!(half.language, "verilog");
+    assert_eq!(half.end_line, 3, "runs to end of file");
+    assert!(
+        results.symbols.iter().all(|symbol| symbol.name != "g1"),
+        "gate primitives are not module instances"
+    );
+    assert_eq!(results.pending_relationships.len(), 1);
+    assert_eq!(results.pending_relationships[0].callee_name, "sub");
+}
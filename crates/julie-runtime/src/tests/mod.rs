@@ -3,6 +3,7 @@
 
 // ── Watcher tests ──────────────────────────────────────────────────────────
 pub mod watcher; // Core watcher tests (IncrementalIndexer, real-time indexing)
+pub mod watcher_backend; // Backend selection, polling fallback
 pub mod watcher_filtering; // Gitignore/julieignore filtering, blacklist, extension policy
 pub mod watcher_handlers; // Incremental-indexing handler tests (create/modify/delete/rename)
 pub mod watcher_mutation_gate; // Per-workspace mutation gate concurrency contract
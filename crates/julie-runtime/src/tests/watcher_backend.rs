@@ -0,0 +1,174 @@
+//! Tests for watcher backend selection and the polling fallback (`watcher::backend`).
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use julie_core::workspace_settings::WatcherSettings;
+use notify::EventKind;
+use tokio::sync::mpsc;
+
+use crate::watcher::backend::{
+    PollSnapshot, PollingWatcher, WatcherBackend, WatcherConfig, mount_filesystem_type,
+};
+use crate::watcher::filtering::build_supported_extensions;
+
+const MOUNTS: &str = "\
+/dev/sdb / ext4 rw,relatime 0 0
+C:\\134 /mnt/c 9p rw,noatime,aname=drvfs 0 0
+server:/export /home/dev/shared\\040repos nfs4 rw,vers=4.2 0 0
+tmpfs /home/dev/shared\\040repos/cache tmpfs rw 0 0
+";
+
+fn summarize(events: &[notify::Event], root: &Path) -> Vec<(&'static str, PathBuf)> {
+    events
+        .iter()
+        .map(|event| {
+            let kind = match event.kind {
+                EventKind::Create(_) => "create",
+                EventKind::Modify(_) => "modify",
+                EventKind::Remove(_) => "remove",
+                _ => "other",
+            };
+            (
+                kind,
+                event.paths[0].strip_prefix(root).unwrap().to_path_buf(),
+            )
+        })
+        .collect()
+}
+
+#[test]
+fn test_mount_filesystem_type_uses_longest_mount_point() {
+    assert_eq!(
+        mount_filesystem_type(MOUNTS, Path::new("/mnt/c/Users/dev/repo")),
+        Some("9p")
+    );
+    assert_eq!(
+        mount_filesystem_type(MOUNTS, Path::new("/home/dev/shared repos/julie")),
+        Some("nfs4"),
+        "octal-escaped spaces in mount points are decoded"
+    );
+    assert_eq!(
+        mount_filesystem_type(MOUNTS, Path::new("/home/dev/shared repos/cache/x")),
+        Some("tmpfs")
+    );
+    assert_eq!(
+        mount_filesystem_type(MOUNTS, Path::new("/home/dev/project")),
+        Some("ext4")
+    );
+    assert_eq!(
+        mount_filesystem_type(MOUNTS, Path::new("/mnt/cdrom")),
+        Some("ext4"),
+        "mount points match whole path components"
+    );
+}
+
+#[test]
+fn test_watcher_config_env_overrides_settings() {
+    let settings = WatcherSettings {
+        backend: Some("native".to_string()),
+        poll_interval_ms: Some(5000),
+    };
+
+    let from_settings = WatcherConfig::from_sources(None, None, &settings);
+    assert_eq!(from_settings.backend, WatcherBackend::Native);
+    assert_eq!(from_settings.poll_interval, Duration::from_millis(5000));
+
+    let from_env = WatcherConfig::from_sources(Some("poll"), Some("750"), &settings);
+    assert_eq!(from_env.backend, WatcherBackend::Poll);
+    assert_eq!(from_env.poll_interval, Duration::from_millis(750));
+
+    let invalid_env = WatcherConfig::from_sources(Some("fsevents"), Some("5"), &settings);
+    assert_eq!(invalid_env, from_settings, "invalid env values are ignored");
+
+    let defaults = WatcherConfig::from_sources(None, None, &WatcherSettings::default());
+    assert_eq!(defaults.backend, WatcherBackend::Auto);
+    assert_eq!(defaults.poll_interval, Duration::from_millis(2000));
+}
+
+#[test]
+fn test_poll_snapshot_reports_content_changes_only() {
+    let tmp = tempfile::tempdir().unwrap();
+    let root = tmp.path();
+    let extensions = build_supported_extensions();
+    fs::create_dir_all(root.join("src")).unwrap();
+    fs::write(root.join("src/lib.rs"), "pub fn one() {}\n").unwrap();
+    fs::write(root.join("src/keep.rs"), "pub fn keep() {}\n").unwrap();
+    fs::write(root.join("notes.txt"), "not source").unwrap();
+
+    let baseline = PollSnapshot::scan(root, &extensions, &PollSnapshot::default(), Duration::ZERO);
+    assert_eq!(baseline.len(), 2, "unsupported files are not tracked");
+
+    // Rewriting identical bytes bumps mtime but not the digest.
+    fs::write(root.join("src/keep.rs"), "pub fn keep() {}\n").unwrap();
+    fs::write(root.join("src/lib.rs"), "pub fn two() {}\n").unwrap();
+    fs::write(root.join("src/new.rs"), "pub fn new() {}\n").unwrap();
+    let next = PollSnapshot::scan(root, &extensions, &baseline, Duration::ZERO);
+    assert_eq!(
+        summarize(&next.changes_since(&baseline), root),
+        [
+            ("create", PathBuf::from("src/new.rs")),
+            ("modify", PathBuf::from("src/lib.rs")),
+        ]
+    );
+
+    fs::remove_file(root.join("src/lib.rs")).unwrap();
+    let after_delete = PollSnapshot::scan(root, &extensions, &next, Duration::ZERO);
+    assert_eq!(
+        summarize(&after_delete.changes_since(&next), root),
+        [("remove", PathBuf::from("src/lib.rs"))]
+    );
+}
+
+#[test]
+fn test_poll_snapshot_tracks_workspace_settings_file() {
+    let tmp = tempfile::tempdir().unwrap();
+    let root = tmp.path();
+    fs::create_dir_all(root.join(".julie")).unwrap();
+    fs::write(root.join(".julie/config.toml"), "[context]\n").unwrap();
+
+    let snapshot = PollSnapshot::scan(
+        root,
+        &build_supported_extensions(),
+        &PollSnapshot::default(),
+        Duration::ZERO,
+    );
+    let created = snapshot.changes_since(&PollSnapshot::default());
+    assert_eq!(
+        summarize(&created, root),
+        [("create", PathBuf::from(".julie/config.toml"))]
+    );
+}
+
+#[tokio::test]
+async fn test_polling_watcher_sends_events_after_baseline() {
+    let tmp = tempfile::tempdir().unwrap();
+    let root = tmp.path().to_path_buf();
+    fs::write(root.join("main.py"), "print('a')\n").unwrap();
+
+    let (tx, mut rx) = mpsc::unbounded_channel();
+    let watcher = PollingWatcher::start(
+        root.clone(),
+        build_supported_extensions(),
+        Duration::from_millis(250),
+        tx,
+    );
+    // Let the baseline scan run before editing.
+    tokio::time::sleep(Duration::from_millis(400)).await;
+    fs::write(root.join("main.py"), "print('b')\n").unwrap();
+
+    let event = tokio::time::timeout(Duration::from_secs(5), rx.recv())
+        .await
+        .expect("polling watcher reports the edit")
+        .expect("channel open")
+        .expect("no watcher error");
+    assert!(matches!(event.kind, EventKind::Modify(_)), "{event:?}");
+    assert_eq!(event.paths, [root.join("main.py")]);
+
+    drop(watcher);
+    let closed = tokio::time::timeout(Duration::from_secs(5), rx.recv())
+        .await
+        .expect("dropping the watcher closes the channel");
+    assert!(closed.is_none());
+}
@@ -632,3 +632,49 @@ fn test_cache_dir_gitignore_star_does_not_leak_globally() {
         "files inside cache dirs should still be rejected"
     );
 }
+
+#[test]
+fn test_build_gitignore_matcher_applies_settings_and_reloads() {
+    use crate::watcher::filtering::{is_workspace_settings_file, reload_workspace_settings};
+    use std::fs;
+    let tmp = tempfile::tempdir().unwrap();
+    let root = tmp.path();
+    let settings_file = root.join(".julie/config.toml");
+    fs::create_dir_all(root.join(".julie")).unwrap();
+    fs::write(&settings_file, "[index]\nignore = [\"generated/\"]\n").unwrap();
+    assert!(is_workspace_settings_file(&settings_file, root));
+
+    let matcher = build_gitignore_matcher(root).unwrap();
+    assert!(
+        matcher
+            .matched_path_or_any_parents("generated/api.rs", false)
+            .is_ignore(),
+        "index.ignore patterns should be in the watcher matcher"
+    );
+
+    fs::write(&settings_file, "[index]\nignore = [\"fixtures/\"]\n").unwrap();
+    let reloaded = reload_workspace_settings(root).expect("valid edit reloads");
+    assert!(
+        reloaded
+            .matched_path_or_any_parents("fixtures/data.rs", false)
+            .is_ignore()
+    );
+    assert!(
+        !reloaded
+            .matched_path_or_any_parents("generated/api.rs", false)
+            .is_ignore(),
+        "removed patterns stop applying"
+    );
+
+    fs::write(&settings_file, "[index]\nignore = [\"!fixtures/\"]\n").unwrap();
+    assert!(
+        reload_workspace_settings(root).is_none(),
+        "invalid edit is rejected"
+    );
+    let kept = build_gitignore_matcher(root).unwrap();
+    assert!(
+        kept.matched_path_or_any_parents("fixtures/data.rs", false)
+            .is_ignore(),
+        "previous settings stay in effect after an invalid edit"
+    );
+}
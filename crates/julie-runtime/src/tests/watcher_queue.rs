@@ -10,7 +10,7 @@ use std::fs;
 use std::sync::Arc;
 use std::sync::Mutex;
 use std::sync::atomic::{AtomicBool, Ordering};
-use std::time::SystemTime;
+use std::time::{Duration, SystemTime};
 use tokio::sync::Mutex as TokioMutex;
 
 fn rs_extensions() -> HashSet<String> {
@@ -262,24 +262,24 @@ async fn test_queue_overflow_drains_to_headroom() {
 #[tokio::test]
 async fn test_runtime_drops_recent_duplicates_and_processes_delete_and_rename() {
     use julie_core::database::SymbolDatabase;
-    use julie_extractors::ExtractorManager;
 
     let temp_dir = julie_test_support::unique_temp_dir("watcher_runtime_duplicate_policy");
     let workspace_root = temp_dir.path().canonicalize().unwrap();
     let db_path = workspace_root.join("test.db");
     let db = Arc::new(Mutex::new(SymbolDatabase::new(&db_path).unwrap()));
-    let extractor_manager = Arc::new(ExtractorManager::new());
     let shared_provider = Arc::new(std::sync::RwLock::new(None));
 
-    let indexer = IncrementalIndexer::new(
+    let mut indexer = IncrementalIndexer::new(
         workspace_root.clone(),
         db.clone(),
-        extractor_manager,
         None,
         shared_provider,
         julie_core::indexing_state::IndexingRuntimeState::shared(),
     )
     .unwrap();
+    // Deletes here must retire the file at once; the grace period has its
+    // own tests below.
+    indexer.set_soft_delete_grace(Duration::ZERO);
 
     // Duplicate Modified event for file_a should be dropped (not re-queued),
     // and should not block file_b processing in the same tick.
@@ -461,19 +461,16 @@ async fn test_overflow_repair_skips_unchanged_indexed_files() {
     use crate::workspace::mutation_gate::acquire_gate;
     use julie_core::database::SymbolDatabase;
     use julie_core::indexing_state::IndexingRepairReason;
-    use julie_extractors::ExtractorManager;
 
     let temp_dir = julie_test_support::unique_temp_dir("watcher_repair_skip_unchanged");
     let workspace_root = temp_dir.path().canonicalize().unwrap();
     let db_path = workspace_root.join("test.db");
     let db = Arc::new(Mutex::new(SymbolDatabase::new(&db_path).unwrap()));
-    let extractor_manager = Arc::new(ExtractorManager::new());
     let shared_provider = Arc::new(std::sync::RwLock::new(None));
 
     let indexer = IncrementalIndexer::new(
         workspace_root.clone(),
         db.clone(),
-        extractor_manager.clone(),
         None,
         shared_provider,
         julie_core::indexing_state::IndexingRuntimeState::shared(),
@@ -487,7 +484,6 @@ async fn test_overflow_repair_skips_unchanged_indexed_files() {
         handle_file_created_or_modified_static(
             stable_file.canonicalize().unwrap(),
             &db,
-            &extractor_manager,
             &workspace_root,
             None,
             &guard,
@@ -525,8 +521,6 @@ async fn test_overflow_repair_skips_unchanged_indexed_files() {
 async fn test_stop_with_pending_queue_does_not_wait_forever_when_gate_is_held() {
     use crate::workspace::mutation_gate::Registry as MutationGateRegistry;
     use julie_core::database::SymbolDatabase;
-    use julie_extractors::ExtractorManager;
-    use std::time::Duration;
 
     let temp_dir = julie_test_support::unique_temp_dir("watcher_stop_gate_held");
     let workspace_root = temp_dir.path().canonicalize().unwrap();
@@ -535,7 +529,6 @@ async fn test_stop_with_pending_queue_does_not_wait_forever_when_gate_is_held()
 
     let db_path = workspace_root.join("test.db");
     let db = Arc::new(Mutex::new(SymbolDatabase::new(&db_path).unwrap()));
-    let extractor_manager = Arc::new(ExtractorManager::new());
     let shared_provider = Arc::new(std::sync::RwLock::new(None));
     let mutation_gate_registry = Arc::new(MutationGateRegistry::new());
     let workspace_id =
@@ -545,7 +538,6 @@ async fn test_stop_with_pending_queue_does_not_wait_forever_when_gate_is_held()
     let mut indexer = IncrementalIndexer::new_with_mutation_gate_registry(
         workspace_root.clone(),
         db,
-        extractor_manager,
         None,
         shared_provider,
         julie_core::indexing_state::IndexingRuntimeState::shared(),
@@ -578,19 +570,16 @@ async fn test_overflow_repair_processes_changed_deleted_new_supported_and_text_o
     use crate::watcher::handlers::handle_file_created_or_modified_static;
     use crate::workspace::mutation_gate::acquire_gate;
     use julie_core::database::SymbolDatabase;
-    use julie_extractors::ExtractorManager;
 
     let temp_dir = julie_test_support::unique_temp_dir("watcher_repair_targeted_dispatch");
     let workspace_root = temp_dir.path().canonicalize().unwrap();
     let db_path = workspace_root.join("test.db");
     let db = Arc::new(Mutex::new(SymbolDatabase::new(&db_path).unwrap()));
-    let extractor_manager = Arc::new(ExtractorManager::new());
     let shared_provider = Arc::new(std::sync::RwLock::new(None));
 
     let indexer = IncrementalIndexer::new(
         workspace_root.clone(),
         db.clone(),
-        extractor_manager.clone(),
         None,
         shared_provider,
         julie_core::indexing_state::IndexingRuntimeState::shared(),
@@ -610,7 +599,6 @@ async fn test_overflow_repair_processes_changed_deleted_new_supported_and_text_o
             handle_file_created_or_modified_static(
                 file.canonicalize().unwrap(),
                 &db,
-                &extractor_manager,
                 &workspace_root,
                 None,
                 &guard,
@@ -698,13 +686,11 @@ async fn test_overflow_repair_processes_changed_deleted_new_supported_and_text_o
 #[tokio::test]
 async fn test_repair_retry_clears_unsupported_extensionless_and_unsupported_names() {
     use julie_core::database::SymbolDatabase;
-    use julie_extractors::ExtractorManager;
 
     let temp_dir = julie_test_support::unique_temp_dir("watcher_retry_unsupported_names");
     let workspace_root = temp_dir.path().canonicalize().unwrap();
     let db_path = workspace_root.join("test.db");
     let db = Arc::new(Mutex::new(SymbolDatabase::new(&db_path).unwrap()));
-    let extractor_manager = Arc::new(ExtractorManager::new());
     let shared_provider = Arc::new(std::sync::RwLock::new(None));
 
     fs::write(workspace_root.join(".dockerignore"), "target/\n").unwrap();
@@ -721,7 +707,6 @@ async fn test_repair_retry_clears_unsupported_extensionless_and_unsupported_name
     let indexer = IncrementalIndexer::new(
         workspace_root.clone(),
         db.clone(),
-        extractor_manager,
         None,
         shared_provider,
         julie_core::indexing_state::IndexingRuntimeState::shared(),
@@ -751,13 +736,11 @@ async fn test_repair_retry_keeps_supported_extractor_failures_due_for_retry() {
     use crate::watcher::handlers::handle_file_created_or_modified_static;
     use crate::workspace::mutation_gate::acquire_gate;
     use julie_core::database::SymbolDatabase;
-    use julie_extractors::ExtractorManager;
 
     let temp_dir = julie_test_support::unique_temp_dir("watcher_retry_supported_failure");
     let workspace_root = temp_dir.path().canonicalize().unwrap();
     let db_path = workspace_root.join("test.db");
     let db = Arc::new(Mutex::new(SymbolDatabase::new(&db_path).unwrap()));
-    let extractor_manager = Arc::new(ExtractorManager::new());
     let shared_provider = Arc::new(std::sync::RwLock::new(None));
 
     let rust_file = workspace_root.join("broken.rs");
@@ -767,7 +750,6 @@ async fn test_repair_retry_keeps_supported_extractor_failures_due_for_retry() {
         handle_file_created_or_modified_static(
             rust_file.canonicalize().unwrap(),
             &db,
-            &extractor_manager,
             &workspace_root,
             None,
             &guard,
@@ -785,7 +767,6 @@ async fn test_repair_retry_keeps_supported_extractor_failures_due_for_retry() {
     let indexer = IncrementalIndexer::new(
         workspace_root.clone(),
         db.clone(),
-        extractor_manager,
         None,
         shared_provider,
         julie_core::indexing_state::IndexingRuntimeState::shared(),
@@ -808,3 +789,120 @@ async fn test_repair_retry_keeps_supported_extractor_failures_due_for_retry() {
         "supported files with extractor failures should remain repair candidates",
     );
 }
+
+#[tokio::test]
+async fn test_deleted_file_stays_queryable_until_it_returns() {
+    use julie_core::database::SymbolDatabase;
+
+    let temp_dir = julie_test_support::unique_temp_dir("watcher_soft_delete_restore");
+    let workspace_root = temp_dir.path().canonicalize().unwrap();
+    let db = Arc::new(Mutex::new(
+        SymbolDatabase::new(workspace_root.join("test.db")).unwrap(),
+    ));
+    let mut indexer = IncrementalIndexer::new(
+        workspace_root.clone(),
+        db.clone(),
+        None,
+        Arc::new(std::sync::RwLock::new(None)),
+        julie_core::indexing_state::IndexingRuntimeState::shared(),
+    )
+    .unwrap();
+    indexer.set_soft_delete_grace(Duration::from_secs(600));
+
+    let file = workspace_root.join("branch_only.rs");
+    fs::write(&file, "fn branch_only() {}\n").unwrap();
+    let push = |change_type| {
+        let index_queue = Arc::clone(&indexer.index_queue);
+        let path = workspace_root.join("branch_only.rs");
+        async move {
+            index_queue.lock().await.push_back(FileChangeEvent {
+                path,
+                change_type,
+                timestamp: SystemTime::now(),
+            });
+        }
+    };
+    push(FileChangeType::Created).await;
+    indexer.process_pending_changes().await.unwrap();
+
+    fs::remove_file(&file).unwrap();
+    push(FileChangeType::Deleted).await;
+    indexer.process_pending_changes().await.unwrap();
+    {
+        let db_lock = db.lock().unwrap();
+        assert_eq!(
+            db_lock
+                .get_symbols_for_file("branch_only.rs")
+                .unwrap()
+                .len(),
+            1,
+            "symbols stay queryable during the grace period",
+        );
+        assert!(
+            db_lock
+                .soft_deleted_files()
+                .unwrap()
+                .contains_key("branch_only.rs")
+        );
+    }
+
+    fs::write(&file, "fn branch_only() {}\n").unwrap();
+    push(FileChangeType::Created).await;
+    indexer.process_pending_changes().await.unwrap();
+    assert!(
+        db.lock().unwrap().soft_deleted_files().unwrap().is_empty(),
+        "a file that comes back is no longer flagged as deleted",
+    );
+}
+
+#[tokio::test]
+async fn test_expired_soft_deletes_are_purged() {
+    use julie_core::database::SymbolDatabase;
+
+    let temp_dir = julie_test_support::unique_temp_dir("watcher_soft_delete_purge");
+    let workspace_root = temp_dir.path().canonicalize().unwrap();
+    let db = Arc::new(Mutex::new(
+        SymbolDatabase::new(workspace_root.join("test.db")).unwrap(),
+    ));
+    let mut indexer = IncrementalIndexer::new(
+        workspace_root.clone(),
+        db.clone(),
+        None,
+        Arc::new(std::sync::RwLock::new(None)),
+        julie_core::indexing_state::IndexingRuntimeState::shared(),
+    )
+    .unwrap();
+    indexer.set_soft_delete_grace(Duration::from_secs(600));
+
+    for name in ["gone.rs", "restored.rs"] {
+        fs::write(
+            workspace_root.join(name),
+            format!("fn {}() {{}}\n", &name[..name.len() - 3]),
+        )
+        .unwrap();
+        indexer.index_queue.lock().await.push_back(FileChangeEvent {
+            path: workspace_root.join(name),
+            change_type: FileChangeType::Created,
+            timestamp: SystemTime::now(),
+        });
+    }
+    indexer.process_pending_changes().await.unwrap();
+
+    // Both were deleted long ago; only `restored.rs` came back, without an
+    // event reaching the watcher.
+    fs::remove_file(workspace_root.join("gone.rs")).unwrap();
+    {
+        let db_lock = db.lock().unwrap();
+        db_lock.mark_file_soft_deleted("gone.rs", 1).unwrap();
+        db_lock.mark_file_soft_deleted("restored.rs", 1).unwrap();
+    }
+    indexer.process_pending_changes().await.unwrap();
+
+    let db_lock = db.lock().unwrap();
+    assert!(db_lock.get_symbols_for_file("gone.rs").unwrap().is_empty());
+    assert_eq!(
+        db_lock.get_symbols_for_file("restored.rs").unwrap().len(),
+        1
+    );
+    assert!(db_lock.soft_deleted_files().unwrap().is_empty());
+}
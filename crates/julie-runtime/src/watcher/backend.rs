@@ -0,0 +1,358 @@
+//! Watcher backend selection and the polling fallback.
+//!
+//! The native notify backend (inotify, FSEvents, ReadDirectoryChangesW) misses
+//! events on network filesystems: WSL2 `/mnt/c` mounts, NFS and SMB shares all
+//! accept the watch and then stay silent. The polling backend rescans the
+//! workspace on an interval instead and feeds the same event channel, so the
+//! rest of the watcher cannot tell the two apart.
+//!
+//! A file counts as changed only when its content digest changes. Metadata
+//! (mtime, size) decides when to re-hash: files whose metadata moved, plus
+//! files modified within the last few intervals, because coarse network
+//! timestamps can hide a second write in the same tick.
+//!
+//! `backend = "auto"` (the default) polls when the workspace sits on a known
+//! network filesystem or when the native backend fails to start.
+
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::{Duration, SystemTime};
+
+use julie_core::file_policy;
+use julie_core::walk::{WalkConfig, try_build_walker};
+use julie_core::workspace_settings::{self, WorkspaceSettingsRegistry};
+use notify::event::{CreateKind, DataChange, ModifyKind, RemoveKind};
+use notify::{Event, EventKind};
+use tokio::sync::mpsc::UnboundedSender;
+use tokio::task::JoinHandle;
+use tracing::{debug, warn};
+
+/// Env override for the watcher backend: `auto`, `native` or `poll`.
+const WATCHER_BACKEND_ENV: &str = "JULIE_WATCHER_BACKEND";
+/// Env override for the polling interval in milliseconds.
+const WATCHER_POLL_INTERVAL_ENV: &str = "JULIE_WATCHER_POLL_INTERVAL_MS";
+/// Default polling interval: a saved file shows up in search within a couple
+/// of seconds without rescanning large trees constantly.
+const DEFAULT_POLL_INTERVAL_MS: u64 = 2000;
+/// Files modified within this many intervals are re-hashed on every scan.
+const RECENT_WRITE_INTERVALS: u32 = 2;
+
+/// Filesystem types whose change notifications cannot be trusted.
+const NETWORK_FILESYSTEMS: &[&str] = &[
+    "nfs",
+    "nfs4",
+    "cifs",
+    "smb3",
+    "smbfs",
+    "9p",
+    "drvfs",
+    "fuse.sshfs",
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WatcherBackend {
+    /// Native events, polling on network filesystems or when native fails.
+    Auto,
+    Native,
+    Poll,
+}
+
+impl WatcherBackend {
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "auto" => Some(Self::Auto),
+            "native" => Some(Self::Native),
+            "poll" | "polling" => Some(Self::Poll),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WatcherConfig {
+    pub backend: WatcherBackend,
+    pub poll_interval: Duration,
+}
+
+impl WatcherConfig {
+    /// Resolve the backend and interval for `workspace_root`: env overrides
+    /// first, then `[watcher]` in `.julie/config.toml`, then defaults.
+    pub fn resolve(workspace_root: &Path) -> Self {
+        let settings = WorkspaceSettingsRegistry::global().get(workspace_root);
+        Self::from_sources(
+            std::env::var(WATCHER_BACKEND_ENV).ok().as_deref(),
+            std::env::var(WATCHER_POLL_INTERVAL_ENV).ok().as_deref(),
+            &settings.watcher,
+        )
+    }
+
+    pub fn from_sources(
+        backend_env: Option<&str>,
+        interval_env: Option<&str>,
+        settings: &workspace_settings::WatcherSettings,
+    ) -> Self {
+        let backend = backend_env
+            .and_then(|raw| {
+                let parsed = WatcherBackend::parse(raw);
+                if parsed.is_none() {
+                    warn!("Ignoring invalid {WATCHER_BACKEND_ENV}={raw:?}");
+                }
+                parsed
+            })
+            .or_else(|| settings.backend.as_deref().and_then(WatcherBackend::parse))
+            .unwrap_or(WatcherBackend::Auto);
+
+        let interval_ms = interval_env
+            .and_then(|raw| {
+                let parsed =
+                    raw.trim().parse::<u64>().ok().filter(|ms| {
+                        workspace_settings::WATCHER_POLL_INTERVAL_MS_RANGE.contains(ms)
+                    });
+                if parsed.is_none() {
+                    warn!("Ignoring invalid {WATCHER_POLL_INTERVAL_ENV}={raw:?}");
+                }
+                parsed
+            })
+            .or(settings.poll_interval_ms)
+            .unwrap_or(DEFAULT_POLL_INTERVAL_MS);
+
+        Self {
+            backend,
+            poll_interval: Duration::from_millis(interval_ms),
+        }
+    }
+}
+
+/// Filesystem type of the mount containing `path`, from `/proc/mounts`
+/// text. The longest matching mount point wins.
+pub fn mount_filesystem_type<'a>(mounts: &'a str, path: &Path) -> Option<&'a str> {
+    mounts
+        .lines()
+        .filter_map(|line| {
+            let mut fields = line.split_whitespace();
+            let _device = fields.next()?;
+            let mount_point = fields.next()?;
+            let fs_type = fields.next()?;
+            Some((unescape_mount_point(mount_point), fs_type))
+        })
+        .filter(|(mount_point, _)| path.starts_with(mount_point))
+        .max_by_key(|(mount_point, _)| mount_point.as_os_str().len())
+        .map(|(_, fs_type)| fs_type)
+}
+
+/// `/proc/mounts` escapes spaces, tabs, newlines and backslashes as octal.
+fn unescape_mount_point(raw: &str) -> PathBuf {
+    PathBuf::from(
+        raw.replace("\\040", " ")
+            .replace("\\011", "\t")
+            .replace("\\012", "\n")
+            .replace("\\134", "\\"),
+    )
+}
+
+/// The network filesystem `workspace_root` lives on, if any. Only Linux
+/// (including WSL) exposes mount types; elsewhere this is always `None`.
+pub fn network_filesystem(workspace_root: &Path) -> Option<String> {
+    let mounts = std::fs::read_to_string("/proc/mounts").ok()?;
+    let root = workspace_root
+        .canonicalize()
+        .unwrap_or_else(|_| workspace_root.to_path_buf());
+    mount_filesystem_type(&mounts, &root)
+        .filter(|fs_type| NETWORK_FILESYSTEMS.contains(fs_type))
+        .map(str::to_string)
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct FileStamp {
+    modified: Option<SystemTime>,
+    len: u64,
+    digest: String,
+}
+
+/// Content digests of every watched file, keyed by absolute path.
+#[derive(Debug, Clone, Default)]
+pub struct PollSnapshot {
+    files: HashMap<PathBuf, FileStamp>,
+}
+
+impl PollSnapshot {
+    /// Scan `workspace_root` with the indexing walk rules (gitignore,
+    /// `.julieignore`, blacklisted directories) plus the settings file.
+    /// Digests are reused from `previous` unless the file may have changed.
+    pub fn scan(
+        workspace_root: &Path,
+        supported_extensions: &HashSet<String>,
+        previous: &PollSnapshot,
+        recent_window: Duration,
+    ) -> Self {
+        let mut paths: Vec<PathBuf> =
+            match try_build_walker(workspace_root, &WalkConfig::full_index()) {
+                Ok(walker) => walker
+                    .filter_map(Result::ok)
+                    .filter(|entry| entry.file_type().is_some_and(|kind| kind.is_file()))
+                    .map(|entry| entry.into_path())
+                    .filter(|path| file_policy::should_watch_path(path, supported_extensions))
+                    .collect(),
+                Err(err) => {
+                    warn!("Polling watcher could not walk workspace: {}", err);
+                    return previous.clone();
+                }
+            };
+        // `.julie/` is skipped by the walk, but settings edits must still
+        // reach the event detector.
+        let settings = workspace_settings::settings_path(workspace_root);
+        if settings.is_file() {
+            paths.push(settings);
+        }
+
+        let now = SystemTime::now();
+        let mut files = HashMap::with_capacity(paths.len());
+        for path in paths {
+            let Ok(metadata) = std::fs::metadata(&path) else {
+                continue;
+            };
+            let modified = metadata.modified().ok();
+            let len = metadata.len();
+            let recently_written = modified
+                .and_then(|time| now.duration_since(time).ok())
+                .is_some_and(|age| age < recent_window);
+            let reusable = previous.files.get(&path).filter(|stamp| {
+                !recently_written && stamp.modified == modified && stamp.len == len
+            });
+            let digest = match reusable {
+                Some(stamp) => stamp.digest.clone(),
+                None => match julie_core::database::calculate_file_hash(&path) {
+                    Ok(digest) => digest,
+                    Err(err) => {
+                        debug!("Polling watcher skipped {}: {}", path.display(), err);
+                        continue;
+                    }
+                },
+            };
+            files.insert(
+                path,
+                FileStamp {
+                    modified,
+                    len,
+                    digest,
+                },
+            );
+        }
+        Self { files }
+    }
+
+    pub fn len(&self) -> usize {
+        self.files.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.files.is_empty()
+    }
+
+    /// Notify events turning `previous` into `self`: created, content-changed
+    /// and removed files, each sorted by path.
+    pub fn changes_since(&self, previous: &PollSnapshot) -> Vec<Event> {
+        let mut created = Vec::new();
+        let mut modified = Vec::new();
+        for (path, stamp) in &self.files {
+            match previous.files.get(path) {
+                None => created.push(path.clone()),
+                Some(old) if old.digest != stamp.digest => modified.push(path.clone()),
+                Some(_) => {}
+            }
+        }
+        let mut removed: Vec<PathBuf> = previous
+            .files
+            .keys()
+            .filter(|path| !self.files.contains_key(*path))
+            .cloned()
+            .collect();
+        created.sort();
+        modified.sort();
+        removed.sort();
+
+        let event = |kind: EventKind, path: PathBuf| Event::new(kind).add_path(path);
+        created
+            .into_iter()
+            .map(|path| event(EventKind::Create(CreateKind::File), path))
+            .chain(modified.into_iter().map(|path| {
+                event(
+                    EventKind::Modify(ModifyKind::Data(DataChange::Content)),
+                    path,
+                )
+            }))
+            .chain(
+                removed
+                    .into_iter()
+                    .map(|path| event(EventKind::Remove(RemoveKind::File), path)),
+            )
+            .collect()
+    }
+}
+
+/// Background rescan loop. Dropping it stops the loop and closes its event
+/// sender, just like dropping a native watcher.
+pub struct PollingWatcher {
+    task: JoinHandle<()>,
+}
+
+impl PollingWatcher {
+    pub fn start(
+        workspace_root: PathBuf,
+        supported_extensions: HashSet<String>,
+        poll_interval: Duration,
+        tx: UnboundedSender<notify::Result<Event>>,
+    ) -> Self {
+        let supported_extensions = Arc::new(supported_extensions);
+        let recent_window = poll_interval * RECENT_WRITE_INTERVALS;
+        let task = tokio::spawn(async move {
+            let mut snapshot = PollSnapshot::default();
+            let mut tick = tokio::time::interval(poll_interval);
+            tick.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+            let mut baseline = true;
+            loop {
+                tick.tick().await;
+                let root = workspace_root.clone();
+                let extensions = Arc::clone(&supported_extensions);
+                let previous = std::mem::take(&mut snapshot);
+                let scanned = tokio::task::spawn_blocking(move || {
+                    let next = PollSnapshot::scan(&root, &extensions, &previous, recent_window);
+                    let events = next.changes_since(&previous);
+                    (next, events)
+                })
+                .await;
+                let (next, events) = match scanned {
+                    Ok(result) => result,
+                    Err(err) => {
+                        // The previous snapshot went down with the scan;
+                        // re-baseline rather than report every file as new.
+                        warn!("Polling watcher scan failed: {}", err);
+                        baseline = true;
+                        continue;
+                    }
+                };
+                snapshot = next;
+                if baseline {
+                    // The initial scan only records state; startup indexing
+                    // already covered existing files.
+                    debug!("Polling watcher tracking {} files", snapshot.len());
+                    baseline = false;
+                    continue;
+                }
+                for event in events {
+                    if tx.send(Ok(event)).is_err() {
+                        return;
+                    }
+                }
+            }
+        });
+        Self { task }
+    }
+}
+
+impl Drop for PollingWatcher {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
@@ -6,10 +6,14 @@
 use anyhow::Result;
 use ignore::gitignore::{Gitignore, GitignoreBuilder};
 use julie_core::file_policy;
+use julie_core::generated_files::GeneratedOutputMatcher;
 use julie_core::shared::BLACKLISTED_DIRECTORIES;
+use julie_core::telemetry::Telemetry;
+use julie_core::walk::find_nested_repositories;
+use julie_core::workspace_settings::{self, WorkspaceSettingsRegistry};
 use std::collections::HashSet;
 use std::path::Path;
-use tracing::warn;
+use tracing::{info, warn};
 
 /// Build set of supported file extensions.
 ///
@@ -59,7 +63,9 @@ fn collect_gitignore_files(dir: &Path, max_depth: usize) -> Vec<std::path::PathB
 /// Build a gitignore-based matcher that layers:
 /// 1. `.gitignore` patterns from the workspace root and all subdirectories
 /// 2. `.julieignore` patterns (if present in workspace root)
-/// 3. Synthetic patterns for Julie's own directories and common noise
+/// 3. `index.ignore` patterns from `.julie/config.toml`, plus every nested
+///    git repository when `index.nested_repositories = false`
+/// 4. Synthetic patterns for Julie's own directories and common noise
 pub fn build_gitignore_matcher(workspace_root: &Path) -> Result<Gitignore> {
     let mut builder = GitignoreBuilder::new(workspace_root);
 
@@ -97,6 +103,22 @@ pub fn build_gitignore_matcher(workspace_root: &Path) -> Result<Gitignore> {
         }
     }
 
+    // Validated when the settings were loaded.
+    let settings = WorkspaceSettingsRegistry::global().get(workspace_root);
+    for pattern in &settings.index.ignore {
+        if let Err(err) = builder.add_line(None, pattern) {
+            warn!("Skipping index.ignore pattern '{}': {}", pattern, err);
+        }
+    }
+    if !settings.indexes_nested_repositories() {
+        for repository in find_nested_repositories(workspace_root) {
+            let pattern = format!("/{}/", repository.root);
+            if let Err(err) = builder.add_line(None, &pattern) {
+                warn!("Skipping nested repository '{}': {}", repository.root, err);
+            }
+        }
+    }
+
     let synthetics = [
         ".julie/",
         ".memories/",
@@ -115,6 +137,52 @@ pub fn build_gitignore_matcher(workspace_root: &Path) -> Result<Gitignore> {
         .map_err(|e| anyhow::anyhow!("Failed to build gitignore matcher: {}", e))
 }
 
+/// True if `path` is the workspace settings file. `.julie/` is otherwise
+/// ignored, so the event detector checks for this before filtering.
+pub fn is_workspace_settings_file(path: &Path, workspace_root: &Path) -> bool {
+    path == workspace_settings::settings_path(workspace_root)
+}
+
+/// Re-read `.julie/config.toml` after it changed and rebuild the matcher so
+/// new `index.ignore` patterns apply to later events. Returns `None` when the
+/// file is invalid: the error is logged and the previous settings stay in effect.
+///
+/// Changed ignore patterns do not remove already-indexed files; a
+/// `manage_workspace(operation="refresh")` does.
+pub fn reload_workspace_settings(workspace_root: &Path) -> Option<Gitignore> {
+    match WorkspaceSettingsRegistry::global().reload(workspace_root) {
+        Ok(settings) => {
+            Telemetry::global()
+                .configure_workspace(workspace_root, settings.telemetry_sink(workspace_root));
+            info!(
+                "Reloaded workspace settings for {} ({} index.ignore pattern(s))",
+                workspace_root.display(),
+                settings.index.ignore.len()
+            );
+            if settings.embeddings.provider.is_some()
+                || settings.embeddings.model.is_some()
+                || settings.semantic.mode.is_some()
+            {
+                info!("Embedding settings take effect when the embedding provider next starts");
+            }
+            match build_gitignore_matcher(workspace_root) {
+                Ok(matcher) => Some(matcher),
+                Err(err) => {
+                    warn!(
+                        "Failed to rebuild ignore matcher after settings reload: {}",
+                        err
+                    );
+                    None
+                }
+            }
+        }
+        Err(err) => {
+            warn!("{}; keeping previous workspace settings", err);
+            None
+        }
+    }
+}
+
 /// Check if any component of the path is a blacklisted directory name.
 ///
 /// This catches directories like `node_modules`, `.git`, `target`, `bin`, `obj`, etc.
@@ -189,8 +257,9 @@ fn is_under_configured_julie_home(path: &Path) -> bool {
 ///    state, etc. into the index)
 /// 3. Filename must not be blacklisted (lockfiles, etc.)
 /// 4. Extension must be in supported set
-/// 5. No path component may be a blacklisted directory
-/// 6. Must not match gitignore/julieignore/synthetic patterns
+/// 5. `[[generated]]` outputs are indexed regardless of 6 and 7
+/// 6. No path component may be a blacklisted directory
+/// 7. Must not match gitignore/julieignore/synthetic patterns
 pub fn should_index_file(
     path: &Path,
     supported_extensions: &HashSet<String>,
@@ -206,6 +275,9 @@ pub fn should_index_file(
     if !file_policy::should_watch_path(path, supported_extensions) {
         return false;
     }
+    if is_generated_output(path, workspace_root) {
+        return true;
+    }
     if contains_blacklisted_directory_relative(path, Some(workspace_root)) {
         return false;
     }
@@ -215,6 +287,22 @@ pub fn should_index_file(
     true
 }
 
+/// True when `path` is under a `[[generated]]` output directory of
+/// `.julie/config.toml`, which is indexed even when ignored.
+fn is_generated_output(path: &Path, workspace_root: &Path) -> bool {
+    let matcher = GeneratedOutputMatcher::for_workspace(workspace_root);
+    if matcher.is_empty() {
+        return false;
+    }
+    julie_core::paths::relative_within_workspace(path, workspace_root)
+        .and_then(|relative| {
+            relative
+                .to_str()
+                .map(|relative| relative.replace('\\', "/"))
+        })
+        .is_some_and(|relative| matcher.generated_file(&relative).is_some())
+}
+
 /// Check if a deletion event should be processed.
 ///
 /// Same filtering as `should_index_file` but:
@@ -232,6 +320,9 @@ pub fn should_process_deletion(
     if !file_policy::should_process_deleted_path(path, supported_extensions) {
         return false;
     }
+    if is_generated_output(path, workspace_root) {
+        return true;
+    }
     if contains_blacklisted_directory_relative(path, Some(workspace_root)) {
         return false;
     }
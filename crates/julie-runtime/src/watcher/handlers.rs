@@ -6,15 +6,22 @@
 use crate::watcher::extraction_write::WatcherExtractionWrite;
 use crate::workspace::mutation_gate::MutationGuard;
 use anyhow::{Context, Result};
-use julie_core::database::SymbolDatabase;
+use julie_core::database::{PARSE_SKIPPED_REASON, SymbolDatabase};
 use julie_core::file_policy::{
     ExtractionMode, detect_language_for_indexing_with_content, determine_extraction_mode,
+    parse_timeout, parser_skip_reason,
 };
 use julie_core::indexing_state::IndexingRepairReason;
-use julie_extractors::ExtractorManager;
 use julie_index::search::SearchIndex;
 use julie_pipeline::finalize::resolve_pending_relationships;
+use julie_pipeline::indexing_core::dispatch::dispatch_extractor;
+use julie_pipeline::indexing_core::generated_outputs::annotate_generated_symbols;
+use julie_pipeline::indexing_core::markdown_links::link_markdown_references;
 use julie_pipeline::indexing_core::normalized::normalize_extraction_results;
+use julie_pipeline::indexing_core::preprocessor::{
+    annotate_preprocessor_conditions, preprocessor_defines_for,
+};
+use julie_pipeline::indexing_core::symbol_options::{apply_symbol_options, symbol_options_for};
 use std::collections::HashSet;
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
@@ -86,7 +93,6 @@ fn persist_repair_state(
 pub async fn handle_file_created_or_modified_static(
     path: PathBuf,
     db: &Arc<std::sync::Mutex<SymbolDatabase>>,
-    extractor_manager: &Arc<ExtractorManager>,
     workspace_root: &Path,
     search_index: Option<&Arc<SearchIndex>>,
     _guard: &MutationGuard<'_>,
@@ -130,25 +136,29 @@ pub async fn handle_file_created_or_modified_static(
     let content_str = String::from_utf8_lossy(&content).into_owned();
     let language =
         detect_language_for_indexing_with_content(Path::new(&relative_path), &content_str);
-    let extraction_mode = determine_extraction_mode(&language, &content_str);
+    let mut extraction_mode = determine_extraction_mode(&language, &content_str);
+    let mut skip_reason = parser_skip_reason(&language, &content_str);
 
-    let results = match extraction_mode {
+    let mut results = match extraction_mode {
         ExtractionMode::ParserBacked => {
             let relative_path_clone = relative_path.clone();
             let content_clone = content_str.clone();
             let workspace_root_clone = workspace_root.to_path_buf();
-            let extractor_manager = Arc::clone(extractor_manager);
-            match tokio::task::spawn_blocking(move || {
-                extractor_manager.extract_all(
+            let host_language = language.clone();
+            let parse_timeout = parse_timeout();
+            let task = tokio::task::spawn_blocking(move || {
+                dispatch_extractor(
+                    &host_language,
                     &relative_path_clone,
                     &content_clone,
                     &workspace_root_clone,
                 )
-            })
-            .await
-            {
-                Ok(Ok(results)) => results,
-                Ok(Err(e)) => {
+            });
+            // Timeouts and crashes degrade the file to text-only (recorded as
+            // a parse skip) instead of holding the watcher queue.
+            match tokio::time::timeout(parse_timeout, task).await {
+                Ok(Ok(Ok(results))) => results,
+                Ok(Ok(Err(e))) => {
                     error!("Extraction failed for {}: {}", relative_path, e);
                     persist_repair_state(
                         db,
@@ -161,23 +171,38 @@ pub async fn handle_file_created_or_modified_static(
                         IndexingRepairReason::ExtractorFailure,
                     ));
                 }
-                Err(e) => {
+                Ok(Err(e)) => {
                     error!("Extraction task panicked for {}: {}", relative_path, e);
-                    persist_repair_state(
-                        db,
-                        &relative_path,
-                        IndexingRepairReason::ExtractorFailure,
-                        Some(&format!("spawn_blocking panic: {e}")),
+                    extraction_mode = ExtractionMode::TextOnly;
+                    skip_reason = Some(format!("extractor crashed: {e}"));
+                    julie_extractors::ExtractionResults::empty()
+                }
+                Err(_) => {
+                    warn!(
+                        "Watcher: parsing {} exceeded {:?}; indexing it text-only",
+                        relative_path, parse_timeout
                     );
-                    return Ok(FileIndexOutcome::repair_needed(
-                        true,
-                        IndexingRepairReason::ExtractorFailure,
-                    ));
+                    extraction_mode = ExtractionMode::TextOnly;
+                    skip_reason = Some(format!("parse timed out after {parse_timeout:?}"));
+                    julie_extractors::ExtractionResults::empty()
                 }
             }
         }
         ExtractionMode::TextOnly => julie_extractors::ExtractionResults::empty(),
     };
+    apply_symbol_options(&mut results, &symbol_options_for(workspace_root, &language));
+    annotate_preprocessor_conditions(
+        &mut results.symbols,
+        &content_str,
+        preprocessor_defines_for(workspace_root).as_ref(),
+    );
+    annotate_generated_symbols(
+        &mut results.symbols,
+        workspace_root,
+        &relative_path,
+        &content_str,
+    );
+    link_markdown_references(&mut results, &relative_path, &language, &content_str);
 
     info!(
         "Watcher: extracted {} symbols, {} identifiers, {} relationships from {} ({})",
@@ -189,7 +214,8 @@ pub async fn handle_file_created_or_modified_static(
     );
 
     let configs = julie_index::search::LanguageConfigs::load_embedded();
-    let normalized = normalize_extraction_results(results, &configs);
+    let normalized =
+        normalize_extraction_results(results, &relative_path, &language, &content_str, &configs);
     let pending_relationships = normalized.pending_relationships.clone();
     let structured_pending_relationships = normalized.structured_pending_relationships.clone();
     let parse_diagnostics = normalized.parse_diagnostics.clone();
@@ -313,6 +339,9 @@ pub async fn handle_file_created_or_modified_static(
         db_lock.update_file_hash(&relative_path, &new_hash_str)?;
         db_lock.store_file_parse_diagnostics(&relative_path, &parse_diagnostics)?;
         db_lock.clear_indexing_repair(&relative_path)?;
+        if let Some(reason) = skip_reason.as_deref() {
+            db_lock.record_indexing_repair(&relative_path, PARSE_SKIPPED_REASON, Some(reason))?;
+        }
     }
 
     #[cfg(test)]
@@ -523,7 +552,6 @@ pub(crate) async fn handle_file_renamed_static(
     from: PathBuf,
     to: PathBuf,
     db: &Arc<std::sync::Mutex<SymbolDatabase>>,
-    extractor_manager: &Arc<ExtractorManager>,
     workspace_root: &Path,
     search_index: Option<&Arc<SearchIndex>>,
     _guard: &MutationGuard<'_>,
@@ -536,15 +564,9 @@ pub(crate) async fn handle_file_renamed_static(
 
     // Create/update the destination first. If that fails, keep the source index
     // in place rather than deleting it and hoping for the best.
-    let outcome = handle_file_created_or_modified_static(
-        to,
-        db,
-        extractor_manager,
-        workspace_root,
-        search_index,
-        _guard,
-    )
-    .await?;
+    let outcome =
+        handle_file_created_or_modified_static(to, db, workspace_root, search_index, _guard)
+            .await?;
 
     if outcome.repair_reason == Some(IndexingRepairReason::ExtractorFailure) {
         return Ok(outcome);
@@ -7,10 +7,12 @@
 //!
 //! The watcher uses a 2-phase processing model:
 //! 1. **File System Events** -> Notify-rs detects changes and queues them
+//!    (or the polling backend, on filesystems without native events)
 //! 2. **Background Processing** -> Async task processes queue every second
 //!
 //! This separation prevents blocking on file I/O or database operations.
 
+pub mod backend;
 pub mod events;
 mod extraction_write;
 pub mod filtering; // Public for tests
@@ -18,6 +20,7 @@ pub mod handlers; // Public for tests
 pub mod observability; // INFO-level event observability helpers
 pub(crate) mod queue;
 mod runtime;
+pub mod soft_delete;
 pub mod types;
 
 use anyhow::{Context, Result};
@@ -28,15 +31,16 @@ use std::future::Future;
 use std::path::PathBuf;
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::{Arc, Mutex as StdMutex};
-use std::time::SystemTime;
+use std::time::{Duration, SystemTime};
 use tokio::sync::{Mutex as TokioMutex, mpsc};
 use tracing::{debug, error, info, warn};
 
 use crate::workspace::mutation_gate::MutationGuard;
 use crate::workspace::mutation_gate::Registry as MutationGateRegistry;
+use julie_core::background_tasks::{BackgroundTaskKind, BackgroundTaskRegistry};
 use julie_core::database::SymbolDatabase;
 use julie_core::indexing_state::{IndexingRepairReason, SharedIndexingRuntime};
-use julie_extractors::ExtractorManager;
+use julie_core::supervisor::{Subsystem, Supervisor, panic_message};
 
 pub use types::{FileChangeEvent, FileChangeType, IndexingStats};
 
@@ -55,7 +59,13 @@ where
         Ok(()) => true,
         Err(err) => {
             if err.is_panic() {
-                error!(task = task_name, error = %err, "Watcher background step panicked");
+                // The loop driving the step runs the next one, so the watcher recovers.
+                Supervisor::global().record_panic(
+                    Subsystem::Watcher,
+                    task_name,
+                    &panic_message(err.into_panic()),
+                    true,
+                );
             } else {
                 warn!(task = task_name, error = %err, "Watcher background step stopped");
             }
@@ -67,8 +77,9 @@ where
 /// Manages incremental indexing with real-time file watching
 pub struct IncrementalIndexer {
     watcher: Option<notify::RecommendedWatcher>,
+    /// Polling fallback, running instead of `watcher` on network filesystems.
+    poller: Option<backend::PollingWatcher>,
     db: Arc<StdMutex<SymbolDatabase>>,
-    extractor_manager: Arc<ExtractorManager>,
     search_index: Option<Arc<julie_index::search::SearchIndex>>,
 
     /// Embedding provider for incremental semantic updates.
@@ -112,6 +123,9 @@ pub struct IncrementalIndexer {
     indexing_runtime: SharedIndexingRuntime,
     mutation_gate_registry: Arc<MutationGateRegistry>,
 
+    /// How long deleted files stay queryable; see [`soft_delete`].
+    soft_delete_grace: Duration,
+
     /// Join handles for the event detector and queue processor tasks.
     /// Stored so stop() can join them for a clean, non-aborting shutdown (Fix D).
     event_task: Option<tokio::task::JoinHandle<()>>,
@@ -124,16 +138,20 @@ pub struct IncrementalIndexer {
 /// exists (atomic-save pattern). The caller should remove that path from its
 /// dedup map so the follow-up Create/Modify event is not suppressed (Fix F:
 /// replaces the old detached `tokio::spawn` callback approach).
+///
+/// With a non-zero `soft_delete_grace`, a DELETE only marks the file as
+/// soft-deleted; its rows are removed once the grace period passes (see
+/// [`soft_delete`]).
 pub(super) async fn dispatch_file_event(
     event: FileChangeEvent,
     db: &Arc<StdMutex<SymbolDatabase>>,
-    extractor_manager: &Arc<ExtractorManager>,
     search_index: &Option<Arc<julie_index::search::SearchIndex>>,
     embedding_provider: &Option<Arc<dyn julie_pipeline::embeddings::EmbeddingProvider>>,
     workspace_root: &std::path::Path,
     lang_configs: &Arc<julie_index::search::language_config::LanguageConfigs>,
     tantivy_dirty: &Arc<StdMutex<std::collections::HashSet<String>>>,
     indexing_runtime: &SharedIndexingRuntime,
+    soft_delete_grace: Duration,
     _guard: &MutationGuard<'_>,
 ) -> Option<PathBuf> {
     let relative_for_embed =
@@ -142,10 +160,14 @@ pub(super) async fn dispatch_file_event(
     match event.change_type {
         FileChangeType::Created | FileChangeType::Modified => {
             let rel_path = relative_for_embed.clone();
+            if let Some(ref rel) = rel_path {
+                if soft_delete::clear_mark(db, rel) {
+                    info!("{} reappeared within its soft-delete grace period", rel);
+                }
+            }
             match handlers::handle_file_created_or_modified_static(
                 event.path,
                 db,
-                extractor_manager,
                 workspace_root,
                 search_index.as_ref(),
                 _guard,
@@ -217,41 +239,47 @@ pub(super) async fn dispatch_file_event(
                 return Some(event.path);
             }
 
-            if let Some(ref rel) = relative_for_embed {
-                if let Ok(mut db_guard) = db.lock() {
-                    if let Err(e) = db_guard.delete_embeddings_for_file(rel) {
-                        warn!("Failed to delete embeddings for {}: {}", rel, e);
+            if !soft_delete_grace.is_zero() {
+                if let Some(ref rel) = relative_for_embed {
+                    let marked = db
+                        .lock()
+                        .unwrap_or_else(|poisoned| poisoned.into_inner())
+                        .mark_file_soft_deleted(rel, soft_delete::unix_now());
+                    match marked {
+                        Ok(()) => {
+                            info!(
+                                "Soft-deleted {}; symbols stay queryable for {}s",
+                                rel,
+                                soft_delete_grace.as_secs()
+                            );
+                            return None;
+                        }
+                        Err(e) => warn!("Failed to soft-delete {}, removing now: {}", rel, e),
                     }
                 }
             }
-            if let Err(e) = handlers::handle_file_deleted_static(
+
+            soft_delete::retire_file(
                 event.path,
+                relative_for_embed.as_deref(),
                 db,
                 workspace_root,
                 search_index.as_ref(),
+                tantivy_dirty,
                 _guard,
             )
-            .await
-            {
-                warn!("Failed to handle file deletion: {}", e);
-            }
-            // Clear dirty-retry entry: file is deleted, retrying Tantivy
-            // would recreate a phantom doc for a nonexistent file.
-            if let Some(ref rel) = relative_for_embed {
-                tantivy_dirty
-                    .lock()
-                    .unwrap_or_else(|p| p.into_inner())
-                    .remove(rel);
-            }
+            .await;
             None
         }
         FileChangeType::Renamed { from, to } => {
             let rel_from = julie_core::paths::to_relative_unix_style(&from, workspace_root).ok();
+            if let Ok(rel_to) = julie_core::paths::to_relative_unix_style(&to, workspace_root) {
+                soft_delete::clear_mark(db, &rel_to);
+            }
             match handlers::handle_file_renamed_static(
                 from,
                 to.clone(),
                 db,
-                extractor_manager,
                 workspace_root,
                 search_index.as_ref(),
                 _guard,
@@ -267,7 +295,8 @@ pub(super) async fn dispatch_file_event(
                     // Same post-commit hazard as Created/Modified: queue the rename
                     // target for Tantivy retry so a mid-handler error cannot leave
                     // the projection falsely Ready.
-                    if let Ok(rel_to) = julie_core::paths::to_relative_unix_style(&to, workspace_root)
+                    if let Ok(rel_to) =
+                        julie_core::paths::to_relative_unix_style(&to, workspace_root)
                     {
                         tantivy_dirty
                             .lock()
@@ -347,7 +376,6 @@ impl IncrementalIndexer {
     pub fn new(
         workspace_root: PathBuf,
         db: Arc<StdMutex<SymbolDatabase>>,
-        extractor_manager: Arc<ExtractorManager>,
         search_index: Option<Arc<julie_index::search::SearchIndex>>,
         embedding_provider: SharedEmbeddingProvider,
         indexing_runtime: SharedIndexingRuntime,
@@ -355,7 +383,6 @@ impl IncrementalIndexer {
         Self::new_with_mutation_gate_registry(
             workspace_root,
             db,
-            extractor_manager,
             search_index,
             embedding_provider,
             indexing_runtime,
@@ -366,7 +393,6 @@ impl IncrementalIndexer {
     pub fn new_with_mutation_gate_registry(
         workspace_root: PathBuf,
         db: Arc<StdMutex<SymbolDatabase>>,
-        extractor_manager: Arc<ExtractorManager>,
         search_index: Option<Arc<julie_index::search::SearchIndex>>,
         embedding_provider: SharedEmbeddingProvider,
         indexing_runtime: SharedIndexingRuntime,
@@ -386,8 +412,8 @@ impl IncrementalIndexer {
 
         Ok(Self {
             watcher: None,
+            poller: None,
             db,
-            extractor_manager,
             search_index,
             embedding_provider,
             lang_configs,
@@ -402,6 +428,7 @@ impl IncrementalIndexer {
             tantivy_dirty: Arc::new(StdMutex::new(std::collections::HashSet::new())),
             indexing_runtime,
             mutation_gate_registry,
+            soft_delete_grace: soft_delete::grace_period(),
             event_task: None,
             queue_task: None,
         })
@@ -419,6 +446,12 @@ impl IncrementalIndexer {
         *guard = provider;
     }
 
+    /// Override the soft-delete grace period read from the environment. A
+    /// running watcher keeps the period it started with.
+    pub fn set_soft_delete_grace(&mut self, grace: Duration) {
+        self.soft_delete_grace = grace;
+    }
+
     // Exposed for tests only — gated by cfg(test) or the "test-support" feature so
     // it is compiled into library builds only when the top-crate enables the feature
     // in its dev-dependencies (Cargo.toml: julie-runtime = { features = ["test-support"] }).
@@ -439,19 +472,50 @@ impl IncrementalIndexer {
 
         let (tx, mut rx) = mpsc::unbounded_channel::<notify::Result<notify::Event>>();
 
-        // Create the watcher
-        let mut watcher = notify::recommended_watcher(move |res| {
-            if let Err(e) = tx.send(res) {
-                error!("Failed to send file event: {}", e);
+        let config = backend::WatcherConfig::resolve(&self.workspace_root);
+        let use_native = match config.backend {
+            backend::WatcherBackend::Native => true,
+            backend::WatcherBackend::Poll => false,
+            backend::WatcherBackend::Auto => {
+                match backend::network_filesystem(&self.workspace_root) {
+                    Some(fs_type) => {
+                        info!(
+                            "Workspace is on a {} filesystem; native file events are unreliable there",
+                            fs_type
+                        );
+                        false
+                    }
+                    None => true,
+                }
             }
-        })?;
-
-        // Start watching the workspace
-        watcher
-            .watch(&self.workspace_root, notify::RecursiveMode::Recursive)
-            .context("Failed to start watching workspace")?;
-
-        self.watcher = Some(watcher);
+        };
+
+        if use_native {
+            match self.start_native_watcher(tx.clone()) {
+                Ok(watcher) => self.watcher = Some(watcher),
+                Err(err) if config.backend == backend::WatcherBackend::Auto => {
+                    warn!(
+                        "Native file watcher unavailable ({:#}); falling back to polling",
+                        err
+                    );
+                }
+                Err(err) => return Err(err),
+            }
+        }
+        if self.watcher.is_none() {
+            info!(
+                "Polling workspace for changes every {:?}",
+                config.poll_interval
+            );
+            self.poller = Some(backend::PollingWatcher::start(
+                self.workspace_root.clone(),
+                self.supported_extensions.clone(),
+                config.poll_interval,
+                tx,
+            ));
+        } else {
+            drop(tx);
+        }
 
         // Start the event processing task
         // Clone the necessary components that are Sync
@@ -462,8 +526,22 @@ impl IncrementalIndexer {
         let needs_rescan_for_events = self.needs_rescan.clone();
 
         let event_handle = tokio::spawn(async move {
+            // Rebuilt in place when `.julie/config.toml` changes.
+            let mut gitignore = gitignore;
             info!("File system event detector started");
             while let Some(event_result) = rx.recv().await {
+                if let Ok(event) = &event_result {
+                    if event.paths.iter().any(|path| {
+                        filtering::is_workspace_settings_file(path, &workspace_root_for_events)
+                    }) {
+                        if let Some(rebuilt) =
+                            filtering::reload_workspace_settings(&workspace_root_for_events)
+                        {
+                            gitignore = rebuilt;
+                        }
+                    }
+                }
+
                 let supported_extensions = supported_extensions.clone();
                 let gitignore = gitignore.clone();
                 let workspace_root_for_events = workspace_root_for_events.clone();
@@ -502,7 +580,6 @@ impl IncrementalIndexer {
         let cancel_flag_queue = self.cancel_flag.clone();
         let queue_runtime = runtime::QueueRuntime::new(
             Arc::clone(&self.db),
-            Arc::clone(&self.extractor_manager),
             self.search_index.as_ref().map(Arc::clone),
             Arc::clone(&self.embedding_provider),
             Arc::clone(&self.lang_configs),
@@ -516,6 +593,16 @@ impl IncrementalIndexer {
             Arc::clone(&self.tantivy_dirty),
             Arc::clone(&self.indexing_runtime),
             Arc::clone(&self.mutation_gate_registry),
+            self.soft_delete_grace,
+        );
+
+        // Lives as long as the queue processor; pausing it stops processing
+        // while the event detector keeps queueing.
+        let watcher_task = BackgroundTaskRegistry::global().register(
+            BackgroundTaskKind::Watcher,
+            self.workspace_id.clone(),
+            "file watcher queue",
+            true,
         );
 
         let queue_handle = tokio::spawn(async move {
@@ -533,8 +620,9 @@ impl IncrementalIndexer {
                 }
 
                 let queue_runtime = queue_runtime.clone();
+                let watcher_task = Arc::clone(&watcher_task);
                 let _ = run_guarded_task_step("queue-processor", async move {
-                    queue_runtime.run_cycle().await;
+                    queue_runtime.run_tracked_cycle(&watcher_task).await;
                 })
                 .await;
             }
@@ -545,6 +633,21 @@ impl IncrementalIndexer {
         Ok(())
     }
 
+    fn start_native_watcher(
+        &self,
+        tx: mpsc::UnboundedSender<notify::Result<notify::Event>>,
+    ) -> Result<notify::RecommendedWatcher> {
+        let mut watcher = notify::recommended_watcher(move |res| {
+            if let Err(e) = tx.send(res) {
+                error!("Failed to send file event: {}", e);
+            }
+        })?;
+        watcher
+            .watch(&self.workspace_root, notify::RecursiveMode::Recursive)
+            .context("Failed to start watching workspace")?;
+        Ok(watcher)
+    }
+
     /// Process any pending file changes from the queue
     pub async fn process_pending_changes(&self) -> Result<()> {
         runtime::QueueRuntime::from_indexer(self)
@@ -569,6 +672,9 @@ impl IncrementalIndexer {
         if let Some(watcher) = self.watcher.take() {
             drop(watcher);
         }
+        if let Some(poller) = self.poller.take() {
+            drop(poller);
+        }
 
         // Join event task (exits quickly once rx yields None).
         if let Some(handle) = self.event_task.take() {
@@ -589,7 +695,7 @@ impl IncrementalIndexer {
     // to keep it out of the production library.
     #[cfg(any(test, feature = "test-support"))]
     pub fn is_running_for_test(&self) -> bool {
-        self.watcher.is_some()
+        (self.watcher.is_some() || self.poller.is_some())
             && self.event_task.is_some()
             && self.queue_task.is_some()
             && !self.cancel_flag.load(Ordering::Acquire)
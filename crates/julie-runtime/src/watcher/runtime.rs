@@ -3,9 +3,9 @@ use crate::watcher::observability::timed_acquire_gate_with_registry_or_cancelled
 use crate::workspace::mutation_gate::{MutationGuard, Registry as MutationGateRegistry};
 use anyhow::Result;
 use ignore::gitignore::Gitignore;
+use julie_core::background_tasks::BackgroundTask;
 use julie_core::database::{ProjectionStatus, SymbolDatabase};
 use julie_core::indexing_state::{IndexingOperation, IndexingRepairReason, SharedIndexingRuntime};
-use julie_extractors::ExtractorManager;
 use julie_index::search::projection::TANTIVY_PROJECTION_NAME;
 use std::collections::{HashMap, HashSet, VecDeque};
 use std::path::{Path, PathBuf};
@@ -18,6 +18,7 @@ use tracing::{debug, error, info, warn};
 mod processing;
 mod projection;
 mod repairs;
+mod soft_deletes;
 
 const EXTRACTOR_REPAIR_RETRY_INTERVAL: Duration = Duration::from_secs(30);
 const DUPLICATE_DEBOUNCE_WINDOW: Duration = Duration::from_secs(1);
@@ -31,7 +32,6 @@ const MAX_TANTIVY_RETRY_ATTEMPTS: u32 = 10;
 #[derive(Clone)]
 pub(super) struct QueueRuntime {
     db: Arc<StdMutex<SymbolDatabase>>,
-    extractor_manager: Arc<ExtractorManager>,
     search_index: Option<Arc<julie_index::search::SearchIndex>>,
     embedding_provider: SharedEmbeddingProvider,
     lang_configs: Arc<julie_index::search::language_config::LanguageConfigs>,
@@ -51,6 +51,7 @@ pub(super) struct QueueRuntime {
     tantivy_failure_attempts: Arc<StdMutex<HashMap<String, u32>>>,
     indexing_runtime: SharedIndexingRuntime,
     mutation_gate_registry: Arc<MutationGateRegistry>,
+    soft_delete_grace: Duration,
     #[cfg(test)]
     fail_commit_for_test: bool,
 }
@@ -59,7 +60,6 @@ impl QueueRuntime {
     pub(super) fn from_indexer(indexer: &IncrementalIndexer) -> Self {
         Self {
             db: Arc::clone(&indexer.db),
-            extractor_manager: Arc::clone(&indexer.extractor_manager),
             search_index: indexer.search_index.as_ref().map(Arc::clone),
             embedding_provider: Arc::clone(&indexer.embedding_provider),
             lang_configs: Arc::clone(&indexer.lang_configs),
@@ -74,6 +74,7 @@ impl QueueRuntime {
             tantivy_failure_attempts: Arc::new(StdMutex::new(HashMap::new())),
             indexing_runtime: Arc::clone(&indexer.indexing_runtime),
             mutation_gate_registry: Arc::clone(&indexer.mutation_gate_registry),
+            soft_delete_grace: indexer.soft_delete_grace,
             #[cfg(test)]
             fail_commit_for_test: false,
         }
@@ -81,7 +82,6 @@ impl QueueRuntime {
 
     pub(super) fn new(
         db: Arc<StdMutex<SymbolDatabase>>,
-        extractor_manager: Arc<ExtractorManager>,
         search_index: Option<Arc<julie_index::search::SearchIndex>>,
         embedding_provider: SharedEmbeddingProvider,
         lang_configs: Arc<julie_index::search::language_config::LanguageConfigs>,
@@ -95,10 +95,10 @@ impl QueueRuntime {
         tantivy_dirty: Arc<StdMutex<HashSet<String>>>,
         indexing_runtime: SharedIndexingRuntime,
         mutation_gate_registry: Arc<MutationGateRegistry>,
+        soft_delete_grace: Duration,
     ) -> Self {
         Self {
             db,
-            extractor_manager,
             search_index,
             embedding_provider,
             lang_configs,
@@ -113,6 +113,7 @@ impl QueueRuntime {
             tantivy_failure_attempts: Arc::new(StdMutex::new(HashMap::new())),
             indexing_runtime,
             mutation_gate_registry,
+            soft_delete_grace,
             #[cfg(test)]
             fail_commit_for_test: false,
         }
@@ -154,11 +155,29 @@ impl QueueRuntime {
             .await;
     }
 
+    /// One queue tick reported on the watcher's background task. While the
+    /// task is paused, events keep queueing but are not processed; a backlog
+    /// past the overflow limit falls back to a rescan as usual.
+    pub(super) async fn run_tracked_cycle(&self, task: &BackgroundTask) {
+        let depth = self.index_queue.lock().await.len() as u64;
+        task.track_backlog(depth);
+        if task.is_paused() {
+            return;
+        }
+
+        self.run_cycle().await;
+
+        let remaining = self.index_queue.lock().await.len() as u64;
+        task.advance(depth.saturating_sub(remaining));
+    }
+
     async fn run_cycle_with_retry_age(&self, min_repair_age: Duration) {
         self.retry_dirty_tantivy().await;
 
         self.process_queue_batch().await;
 
+        self.purge_expired_soft_deletes().await;
+
         self.retry_persisted_repairs(min_repair_age).await;
 
         self.run_repair_scan_if_needed().await;
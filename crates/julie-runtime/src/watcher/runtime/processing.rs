@@ -27,13 +27,13 @@ impl QueueRuntime {
                     crate::watcher::dispatch_file_event(
                         event,
                         &self.db,
-                        &self.extractor_manager,
                         &self.search_index,
                         &provider_snapshot,
                         &self.workspace_root,
                         &self.lang_configs,
                         &self.tantivy_dirty,
                         &self.indexing_runtime,
+                        self.soft_delete_grace,
                         &guard,
                     )
                     .await;
@@ -144,13 +144,13 @@ impl QueueRuntime {
             let atomic_delete_path = crate::watcher::dispatch_file_event(
                 event,
                 &self.db,
-                &self.extractor_manager,
                 &self.search_index,
                 &provider_snapshot,
                 &self.workspace_root,
                 &self.lang_configs,
                 &self.tantivy_dirty,
                 &self.indexing_runtime,
+                self.soft_delete_grace,
                 &guard,
             )
             .await;
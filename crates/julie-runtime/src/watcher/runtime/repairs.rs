@@ -70,7 +70,8 @@ impl QueueRuntime {
         let mut affected_paths = HashSet::new();
         for repair in due_repairs {
             let repair_path = repair.path.clone();
-            let absolute_path = self.workspace_root.join(&repair_path);
+            let absolute_path =
+                julie_core::paths::stored_path_to_absolute(&self.workspace_root, &repair_path);
             if !absolute_path.is_file() {
                 let db_guard = self
                     .db
@@ -110,13 +111,13 @@ impl QueueRuntime {
                     timestamp: SystemTime::now(),
                 },
                 &self.db,
-                &self.extractor_manager,
                 &self.search_index,
                 &provider_snapshot,
                 &self.workspace_root,
                 &self.lang_configs,
                 &self.tantivy_dirty,
                 &self.indexing_runtime,
+                self.soft_delete_grace,
                 &guard,
             )
             .await;
@@ -301,13 +302,13 @@ impl QueueRuntime {
                         timestamp: SystemTime::now(),
                     },
                     &self.db,
-                    &self.extractor_manager,
                     &self.search_index,
                     &provider_snapshot,
                     &self.workspace_root,
                     &self.lang_configs,
                     &self.tantivy_dirty,
                     &self.indexing_runtime,
+                    self.soft_delete_grace,
                     &guard,
                 )
                 .await;
@@ -326,13 +327,13 @@ impl QueueRuntime {
                             timestamp: SystemTime::now(),
                         },
                         &self.db,
-                        &self.extractor_manager,
                         &self.search_index,
                         &provider_snapshot,
                         &self.workspace_root,
                         &self.lang_configs,
                         &self.tantivy_dirty,
                         &self.indexing_runtime,
+                        self.soft_delete_grace,
                         &guard,
                     )
                     .await;
@@ -372,13 +373,13 @@ impl QueueRuntime {
                     timestamp: SystemTime::now(),
                 },
                 &self.db,
-                &self.extractor_manager,
                 &self.search_index,
                 &provider_snapshot,
                 &self.workspace_root,
                 &self.lang_configs,
                 &self.tantivy_dirty,
                 &self.indexing_runtime,
+                self.soft_delete_grace,
                 &guard,
             )
             .await;
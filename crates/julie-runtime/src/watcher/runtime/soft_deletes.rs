@@ -0,0 +1,69 @@
+use super::*;
+use crate::watcher::soft_delete;
+
+impl QueueRuntime {
+    /// Remove the rows of files whose soft-delete grace period has passed.
+    ///
+    /// A file that is back on disk without a create event (the watcher missed
+    /// it) has its mark cleared and is queued as modified so it is
+    /// re-checked; everything else is retired for good.
+    pub(super) async fn purge_expired_soft_deletes(&self) -> usize {
+        let cutoff = soft_delete::unix_now() - self.soft_delete_grace.as_secs() as i64;
+        let expired = {
+            let db_guard = self
+                .db
+                .lock()
+                .unwrap_or_else(|poisoned| poisoned.into_inner());
+            match db_guard.soft_deletes_older_than(cutoff) {
+                Ok(expired) => expired,
+                Err(err) => {
+                    warn!("Failed to list expired soft deletes: {}", err);
+                    return 0;
+                }
+            }
+        };
+        if expired.is_empty() {
+            return 0;
+        }
+
+        let Some(guard) = self.acquire_gate_or_mark_rescan("soft delete purge").await else {
+            return 0;
+        };
+
+        let mut retired = 0usize;
+        let mut affected_paths = HashSet::new();
+        for rel_path in expired {
+            let abs_path = self.workspace_root.join(Path::new(&rel_path));
+            if abs_path.is_file() {
+                soft_delete::clear_mark(&self.db, &rel_path);
+                self.index_queue.lock().await.push_back(FileChangeEvent {
+                    path: abs_path,
+                    change_type: FileChangeType::Modified,
+                    timestamp: SystemTime::now(),
+                });
+                continue;
+            }
+
+            soft_delete::retire_file(
+                abs_path,
+                Some(&rel_path),
+                &self.db,
+                &self.workspace_root,
+                self.search_index.as_ref(),
+                &self.tantivy_dirty,
+                &guard,
+            )
+            .await;
+            soft_delete::clear_mark(&self.db, &rel_path);
+            affected_paths.insert(rel_path);
+            retired += 1;
+        }
+
+        if retired > 0 {
+            info!(retired, "Removed files past their soft-delete grace period");
+            self.commit_search_index("soft delete purge", &affected_paths)
+                .await;
+        }
+        retired
+    }
+}
@@ -0,0 +1,84 @@
+//! Grace period between a file's deletion and the removal of its index rows.
+//!
+//! A delete event marks the file in `soft_deleted_files` and leaves its
+//! symbols, relationships and search documents in place, so references an
+//! agent is following survive a branch switch or a stray `git clean`. Tools
+//! flag results from marked files. The queue processor retires marks older
+//! than the grace period (see `QueueRuntime::purge_expired_soft_deletes`);
+//! a create, modify or rename-to event for the path clears the mark instead.
+
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex as StdMutex};
+use std::time::Duration;
+
+use julie_core::database::SymbolDatabase;
+use tracing::warn;
+
+use super::handlers;
+use crate::workspace::mutation_gate::MutationGuard;
+
+/// Seconds a deleted file's rows stay queryable when
+/// `JULIE_SOFT_DELETE_GRACE_SECS` is not set.
+pub const DEFAULT_SOFT_DELETE_GRACE_SECS: u64 = 600;
+
+/// How long deleted files stay queryable before their rows are removed.
+///
+/// Override with `JULIE_SOFT_DELETE_GRACE_SECS`; `0` removes rows as soon as
+/// the delete event is processed.
+pub fn grace_period() -> Duration {
+    let secs = std::env::var("JULIE_SOFT_DELETE_GRACE_SECS")
+        .ok()
+        .and_then(|value| value.trim().parse::<u64>().ok())
+        .unwrap_or(DEFAULT_SOFT_DELETE_GRACE_SECS);
+    Duration::from_secs(secs)
+}
+
+pub(crate) fn unix_now() -> i64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|duration| duration.as_secs() as i64)
+        .unwrap_or(0)
+}
+
+/// Clear the deletion mark for a path. Returns whether the file was waiting
+/// out its grace period.
+pub(crate) fn clear_mark(db: &Arc<StdMutex<SymbolDatabase>>, relative_path: &str) -> bool {
+    let db = db.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+    db.clear_soft_delete(relative_path).unwrap_or_else(|e| {
+        warn!("Failed to clear soft delete for {}: {}", relative_path, e);
+        false
+    })
+}
+
+/// Remove every row derived from a deleted file: embeddings, symbols and
+/// relationships, search documents, and any pending Tantivy retry.
+pub(crate) async fn retire_file(
+    path: PathBuf,
+    relative_path: Option<&str>,
+    db: &Arc<StdMutex<SymbolDatabase>>,
+    workspace_root: &Path,
+    search_index: Option<&Arc<julie_index::search::SearchIndex>>,
+    tantivy_dirty: &Arc<StdMutex<std::collections::HashSet<String>>>,
+    guard: &MutationGuard<'_>,
+) {
+    if let Some(rel) = relative_path {
+        if let Ok(mut db_guard) = db.lock() {
+            if let Err(e) = db_guard.delete_embeddings_for_file(rel) {
+                warn!("Failed to delete embeddings for {}: {}", rel, e);
+            }
+        }
+    }
+    if let Err(e) =
+        handlers::handle_file_deleted_static(path, db, workspace_root, search_index, guard).await
+    {
+        warn!("Failed to handle file deletion: {}", e);
+    }
+    // Clear dirty-retry entry: file is deleted, retrying Tantivy
+    // would recreate a phantom doc for a nonexistent file.
+    if let Some(rel) = relative_path {
+        tantivy_dirty
+            .lock()
+            .unwrap_or_else(|p| p.into_inner())
+            .remove(rel);
+    }
+}
@@ -15,6 +15,10 @@ pub mod startup_hint;
 
 use anyhow::{Context, Result, anyhow};
 use julie_core::health_types::{EmbeddingState, ProjectionState, WatcherState};
+use julie_core::telemetry::Telemetry;
+use julie_core::workspace_settings::{
+    SEMANTIC_DISABLED_REASON, WorkspaceSettings, WorkspaceSettingsRegistry, settings_path,
+};
 use serde::{Deserialize, Serialize};
 use std::fs;
 use std::path::{Path, PathBuf};
@@ -166,6 +170,8 @@ impl JulieWorkspace {
             indexing_runtime: julie_core::indexing_state::IndexingRuntimeState::shared(),
         };
 
+        Self::load_settings(&workspace.root);
+
         // Initialize persistent components
         workspace.initialize_all_components().await?;
 
@@ -216,6 +222,8 @@ impl JulieWorkspace {
                 // Validate workspace structure
                 workspace.validate_structure()?;
 
+                Self::load_settings(&workspace.root);
+
                 // Initialize persistent components
                 workspace.initialize_all_components().await?;
 
@@ -605,6 +613,16 @@ impl JulieWorkspace {
         }
 
         let database = SqliteDB::new(&db_path)?;
+        // Rows written by absolute path before the root was known are stored
+        // relative like the rest of the tree.
+        match database.relativize_stored_paths(&self.root) {
+            Ok(0) => {}
+            Ok(changed) => info!(
+                "Stored {} absolute paths relative to the workspace root",
+                changed
+            ),
+            Err(e) => warn!("Failed to relativize stored paths: {e}"),
+        }
         self.db = Some(Arc::new(std::sync::Mutex::new(database)));
 
         info!("Database initialized successfully");
@@ -707,14 +725,10 @@ impl JulieWorkspace {
 
         info!("Initializing file watcher for: {}", self.root.display());
 
-        // Create placeholder extractor manager for now
-        let extractor_manager = Arc::new(julie_extractors::ExtractorManager::new());
-
         let shared_provider = Arc::new(std::sync::RwLock::new(self.embedding_provider.clone()));
         let file_watcher = IncrementalIndexer::new(
             self.root.clone(),
             self.db.as_ref().unwrap().clone(),
-            extractor_manager,
             self.search_index.clone(),
             shared_provider,
             Arc::clone(&self.indexing_runtime),
@@ -726,6 +740,29 @@ impl JulieWorkspace {
         Ok(())
     }
 
+    /// User settings from `.julie/config.toml`, as last (re)loaded.
+    pub fn settings(&self) -> Arc<WorkspaceSettings> {
+        WorkspaceSettingsRegistry::global().get(&self.root)
+    }
+
+    /// Load `.julie/config.toml` up front so a bad edit is reported at
+    /// startup rather than on first use. Invalid settings never fail the
+    /// workspace: defaults apply until the file is fixed.
+    fn load_settings(root: &Path) {
+        match WorkspaceSettingsRegistry::global().reload(root) {
+            Ok(settings) => {
+                Telemetry::global().configure_workspace(root, settings.telemetry_sink(root));
+                if *settings != WorkspaceSettings::default() {
+                    info!(
+                        "Loaded workspace settings from {}",
+                        settings_path(root).display()
+                    );
+                }
+            }
+            Err(err) => warn!("{}; using default workspace settings", err),
+        }
+    }
+
     /// Initialize all persistent components (database, search index, file watcher).
     ///
     /// Embedding provider initialization is intentionally deferred — it can take
@@ -754,9 +791,24 @@ impl JulieWorkspace {
     /// search and navigation become available immediately.
     ///
     /// If initialization fails, `embedding_provider` stays `None` and keyword
-    /// search continues to work without embeddings.
+    /// search continues to work without embeddings. A workspace configured
+    /// with `semantic.mode = "disabled"` never creates one.
     pub fn initialize_embedding_provider(&mut self) {
-        let (provider, runtime_status) = julie_pipeline::embeddings::create_embedding_provider();
+        let settings = self.settings();
+        let (provider, runtime_status) = if settings.semantic_disabled() {
+            info!("Embedding subsystem not started: {SEMANTIC_DISABLED_REASON}");
+            let status = julie_pipeline::embeddings::EmbeddingRuntimeStatus {
+                requested_backend: julie_pipeline::embeddings::EmbeddingBackend::Unresolved,
+                resolved_backend: julie_pipeline::embeddings::EmbeddingBackend::Unresolved,
+                accelerated: false,
+                degraded_reason: Some(SEMANTIC_DISABLED_REASON.to_string()),
+            };
+            (None, Some(status))
+        } else {
+            julie_pipeline::embeddings::create_embedding_provider_with_settings(
+                &settings.embeddings,
+            )
+        };
         self.embedding_provider = provider.clone();
         self.embedding_runtime_status = runtime_status;
         // Propagate to file watcher so incremental updates use the new provider
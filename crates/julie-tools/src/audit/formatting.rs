@@ -0,0 +1,74 @@
+use super::{AuditFinding, AuditReport, Severity};
+use julie_core::paths::display_stored_path;
+
+pub(super) fn format_report(report: &AuditReport) -> String {
+    if report.findings.is_empty() {
+        return format!(
+            "Security audit: no dangerous sinks found ({} candidate call sites checked)",
+            report.sites_scanned
+        );
+    }
+
+    let critical = report
+        .findings
+        .iter()
+        .filter(|f| f.rule.severity == Severity::Critical)
+        .count();
+    let mut lines = vec![format!(
+        "Security audit: {} findings ({} critical, {} high), callers walked {} hops",
+        report.total_findings,
+        critical,
+        report.findings.len() - critical,
+        report.max_depth
+    )];
+    if report.total_findings > report.findings.len() {
+        lines.push(format!(
+            "Showing top {} by priority; raise limit or filter by language/category for the rest",
+            report.findings.len()
+        ));
+    }
+
+    for (rank, finding) in report.findings.iter().enumerate() {
+        lines.push(String::new());
+        lines.extend(format_finding(rank + 1, finding));
+    }
+
+    lines.push(String::new());
+    lines.push(
+        "Heuristic: sinks are matched by callee name and raw SQL by string building on the call line; \
+         verify that untrusted input actually reaches each one."
+            .to_string(),
+    );
+    lines.join("\n")
+}
+
+fn format_finding(rank: usize, finding: &AuditFinding) -> Vec<String> {
+    let call = &finding.call;
+    let mut lines = vec![format!(
+        "{rank}. [{}] {}: {} at {}:{}",
+        finding.rule.severity.as_str(),
+        finding.rule.category.as_str(),
+        call.name,
+        display_stored_path(&call.file_path),
+        call.start_line
+    )];
+
+    match &finding.container {
+        Some(container) => lines.push(format!(
+            "   in {} ({}), {} caller{} reached",
+            container.name,
+            container.kind,
+            finding.callers_reached,
+            if finding.callers_reached == 1 {
+                ""
+            } else {
+                "s"
+            }
+        )),
+        None => lines.push("   at top level (no containing symbol)".to_string()),
+    }
+    for path in &finding.paths {
+        lines.push(format!("   path: {} -> {}", path.join(" -> "), call.name));
+    }
+    lines
+}
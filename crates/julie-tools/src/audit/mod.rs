@@ -0,0 +1,274 @@
+//! `fast_audit`: locate dangerous sinks and the call paths that reach them.
+//!
+//! Sinks come from a per-language catalog (`sinks`) matched against call
+//! identifiers; each hit's containing symbol seeds a reverse call walk
+//! (`paths`). Findings are ranked by sink severity, then by how many callers
+//! reach the sink. This is a triage aid, not a taint analysis: no data flow
+//! is tracked, so every finding needs a human read.
+
+mod formatting;
+pub mod paths;
+pub mod sinks;
+
+use std::collections::{HashMap, HashSet};
+
+use anyhow::{Result, anyhow};
+use julie_context::{ToolContext, WorkspaceTarget};
+use julie_core::database::{CallSite, SymbolDatabase};
+use julie_core::mcp_compat::{CallToolResult, CallToolResultExt, Content};
+use julie_extractors::Symbol;
+use julie_index::search::scoring::is_test_path;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+pub use self::sinks::{Severity, SinkCategory, SinkRule};
+
+/// Example call paths shown per finding.
+const PATHS_PER_FINDING: usize = 3;
+
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
+pub struct AuditTool {
+    /// Only report sinks in this language (e.g. `python`, `typescript`).
+    #[serde(default)]
+    pub language: Option<String>,
+    /// Only report one sink category: `command_exec`, `code_eval`,
+    /// `sql_injection` or `deserialization`.
+    #[serde(default)]
+    pub category: Option<SinkCategory>,
+    /// Caller hops to walk back from each sink (0-6).
+    #[serde(
+        default = "default_max_depth",
+        deserialize_with = "julie_core::serde_lenient::deserialize_u32_lenient"
+    )]
+    pub max_depth: u32,
+    /// Maximum findings in the report.
+    #[serde(
+        default = "default_limit",
+        deserialize_with = "julie_core::serde_lenient::deserialize_u32_lenient"
+    )]
+    pub limit: u32,
+    /// Include sinks in test files.
+    #[serde(
+        default,
+        deserialize_with = "julie_core::serde_lenient::deserialize_bool_lenient"
+    )]
+    pub include_tests: bool,
+    /// Workspace target. Use `primary` or a workspace id opened through `manage_workspace`.
+    #[serde(default)]
+    pub workspace: Option<String>,
+}
+
+impl Default for AuditTool {
+    fn default() -> Self {
+        Self {
+            language: None,
+            category: None,
+            max_depth: default_max_depth(),
+            limit: default_limit(),
+            include_tests: false,
+            workspace: None,
+        }
+    }
+}
+
+/// One sink call site with the callers that reach it.
+#[derive(Debug)]
+pub struct AuditFinding {
+    pub rule: &'static SinkRule,
+    pub call: CallSite,
+    pub container: Option<Symbol>,
+    pub callers_reached: usize,
+    /// Symbol names, outermost caller first, ending at the container.
+    pub paths: Vec<Vec<String>>,
+}
+
+impl AuditFinding {
+    /// Severity first, then reach: a sink many callers funnel into is a
+    /// bigger surface than one behind a single private helper.
+    fn priority(&self) -> u32 {
+        self.rule.severity.weight() * 1000 + self.callers_reached.min(999) as u32
+    }
+}
+
+#[derive(Debug)]
+pub struct AuditReport {
+    pub findings: Vec<AuditFinding>,
+    pub sites_scanned: usize,
+    pub total_findings: usize,
+    pub max_depth: u32,
+}
+
+impl AuditTool {
+    pub async fn call_tool(&self, handler: &dyn ToolContext) -> Result<CallToolResult> {
+        let workspace_target = handler
+            .resolve_workspace_target(self.workspace.as_deref())
+            .await?;
+        self.call_tool_with_target(handler, &workspace_target).await
+    }
+
+    pub async fn call_tool_with_target(
+        &self,
+        handler: &dyn ToolContext,
+        workspace_target: &WorkspaceTarget,
+    ) -> Result<CallToolResult> {
+        if self
+            .language
+            .as_deref()
+            .is_some_and(|l| l.trim().is_empty())
+        {
+            return Err(anyhow!("language must not be empty"));
+        }
+        let database = match workspace_target {
+            WorkspaceTarget::Primary => handler.primary_pooled_database().await?,
+            WorkspaceTarget::Target(workspace_id) => {
+                handler
+                    .get_pooled_database_for_workspace(workspace_id)
+                    .await?
+            }
+        };
+        let tool = self.clone();
+        let rendered = tokio::task::spawn_blocking(move || -> Result<String> {
+            let database = database.into_read_snapshot()?;
+            let report = tool.audit(&database)?;
+            Ok(formatting::format_report(&report))
+        })
+        .await
+        .map_err(|error| anyhow!("fast_audit task failed: {error}"))??;
+        Ok(CallToolResult::text_content(vec![Content::text(rendered)]))
+    }
+
+    /// Scan the workspace for sinks and rank them.
+    pub fn audit(&self, db: &SymbolDatabase) -> Result<AuditReport> {
+        let max_depth = self.max_depth.min(6);
+        let limit = self.limit.clamp(1, 200) as usize;
+
+        let sites = db.get_call_sites_by_callee_names(&sinks::catalog_callee_names())?;
+        let sites_scanned = sites.len();
+
+        let mut seen = HashSet::new();
+        let mut matched: Vec<(&'static SinkRule, CallSite)> = sites
+            .into_iter()
+            .filter(|site| {
+                self.language
+                    .as_deref()
+                    .is_none_or(|language| site.language.eq_ignore_ascii_case(language))
+            })
+            .filter(|site| self.include_tests || !is_test_path(&site.file_path))
+            .filter_map(|site| Some((sinks::match_rule(&site.language, &site.name)?, site)))
+            .filter(|(rule, _)| self.category.is_none_or(|c| c == rule.category))
+            .filter(|(_, site)| {
+                seen.insert((site.file_path.clone(), site.start_line, site.name.clone()))
+            })
+            .collect();
+
+        let lines = dynamic_string_lines(db, &matched)?;
+        matched.retain(|(rule, site)| {
+            !rule.requires_dynamic_string
+                || lines.contains(&(site.file_path.clone(), site.start_line))
+        });
+
+        let container_ids: Vec<String> = matched
+            .iter()
+            .filter_map(|(_, site)| site.containing_symbol_id.clone())
+            .collect::<HashSet<_>>()
+            .into_iter()
+            .collect();
+        let containers: HashMap<String, Symbol> = db
+            .get_symbols_by_ids(&container_ids)?
+            .into_iter()
+            .map(|symbol| (symbol.id.clone(), symbol))
+            .collect();
+
+        let mut trees = HashMap::new();
+        let mut findings = Vec::with_capacity(matched.len());
+        for (rule, call) in matched {
+            let container = call
+                .containing_symbol_id
+                .as_ref()
+                .and_then(|id| containers.get(id))
+                .cloned();
+            let (callers_reached, paths) = match &container {
+                Some(symbol) => {
+                    if !trees.contains_key(&symbol.id) {
+                        trees.insert(
+                            symbol.id.clone(),
+                            paths::walk_callers(db, symbol, max_depth)?,
+                        );
+                    }
+                    let tree = &trees[&symbol.id];
+                    let paths = tree
+                        .paths(&symbol.id, PATHS_PER_FINDING)
+                        .into_iter()
+                        .map(|path| path.into_iter().map(|s| s.name.clone()).collect())
+                        .collect();
+                    (tree.len(), paths)
+                }
+                None => (0, Vec::new()),
+            };
+            findings.push(AuditFinding {
+                rule,
+                call,
+                container,
+                callers_reached,
+                paths,
+            });
+        }
+
+        findings.sort_by(|a, b| {
+            b.priority()
+                .cmp(&a.priority())
+                .then_with(|| a.call.file_path.cmp(&b.call.file_path))
+                .then_with(|| a.call.start_line.cmp(&b.call.start_line))
+        });
+        let total_findings = findings.len();
+        findings.truncate(limit);
+
+        Ok(AuditReport {
+            findings,
+            sites_scanned,
+            total_findings,
+            max_depth,
+        })
+    }
+}
+
+/// `(file, line)` of the raw-SQL candidates whose source line builds a
+/// string dynamically.
+fn dynamic_string_lines(
+    db: &SymbolDatabase,
+    matched: &[(&'static SinkRule, CallSite)],
+) -> Result<HashSet<(String, u32)>> {
+    let paths: Vec<String> = matched
+        .iter()
+        .filter(|(rule, _)| rule.requires_dynamic_string)
+        .map(|(_, site)| site.file_path.clone())
+        .collect::<HashSet<_>>()
+        .into_iter()
+        .collect();
+    let contents = db.get_file_contents_by_paths(&paths)?;
+
+    Ok(matched
+        .iter()
+        .filter(|(rule, _)| rule.requires_dynamic_string)
+        .filter(|(_, site)| {
+            contents
+                .get(&site.file_path)
+                .and_then(|content| content.as_deref())
+                .and_then(|content| {
+                    content
+                        .lines()
+                        .nth(site.start_line.saturating_sub(1) as usize)
+                })
+                .is_some_and(sinks::has_dynamic_string)
+        })
+        .map(|(_, site)| (site.file_path.clone(), site.start_line))
+        .collect())
+}
+
+fn default_max_depth() -> u32 {
+    3
+}
+
+fn default_limit() -> u32 {
+    20
+}
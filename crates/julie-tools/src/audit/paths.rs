@@ -0,0 +1,117 @@
+//! Reverse call walk from a sink's containing symbol.
+//!
+//! Incoming `calls` relationships are merged with call identifiers (see
+//! `identifier_incoming_edges`), like the blast-radius walk, but every
+//! reached caller keeps a pointer to the callee it was reached through so
+//! whole call paths can be reported.
+
+use std::collections::{HashMap, HashSet};
+
+use anyhow::Result;
+use julie_core::database::SymbolDatabase;
+use julie_core::database::impact_graph::identifier_incoming_edges;
+use julie_extractors::{RelationshipKind, Symbol};
+
+/// Frontier cap per depth, so a sink inside a hot utility does not walk the
+/// whole workspace.
+const MAX_FRONTIER_PER_DEPTH: usize = 200;
+
+#[derive(Debug, Default)]
+pub struct CallerTree {
+    /// Caller id -> (callee id it was reached through, depth).
+    parents: HashMap<String, (String, u32)>,
+    symbols: HashMap<String, Symbol>,
+}
+
+impl CallerTree {
+    /// Number of distinct callers reached.
+    pub fn len(&self) -> usize {
+        self.parents.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.parents.is_empty()
+    }
+
+    /// Up to `limit` call paths, outermost caller first and ending at `root_id`.
+    /// The deepest callers with no caller of their own are preferred: they are
+    /// the closest thing to entry points the walk found.
+    pub fn paths(&self, root_id: &str, limit: usize) -> Vec<Vec<&Symbol>> {
+        let callees: HashSet<&str> = self
+            .parents
+            .values()
+            .map(|(callee, _)| callee.as_str())
+            .collect();
+        let mut leaves: Vec<(&str, u32)> = self
+            .parents
+            .iter()
+            .filter(|(id, _)| !callees.contains(id.as_str()))
+            .map(|(id, (_, depth))| (id.as_str(), *depth))
+            .collect();
+        leaves.sort_by(|(a_id, a_depth), (b_id, b_depth)| {
+            b_depth.cmp(a_depth).then_with(|| a_id.cmp(b_id))
+        });
+
+        leaves
+            .into_iter()
+            .take(limit)
+            .filter_map(|(leaf, _)| {
+                let mut path = Vec::new();
+                let mut current = leaf;
+                while current != root_id {
+                    path.push(self.symbols.get(current)?);
+                    current = self.parents.get(current)?.0.as_str();
+                }
+                path.push(self.symbols.get(root_id)?);
+                Some(path)
+            })
+            .collect()
+    }
+}
+
+/// Walk callers of `root` up to `max_depth` hops.
+pub fn walk_callers(db: &SymbolDatabase, root: &Symbol, max_depth: u32) -> Result<CallerTree> {
+    let mut tree = CallerTree::default();
+    tree.symbols.insert(root.id.clone(), root.clone());
+    let mut visited: HashSet<String> = HashSet::from([root.id.clone()]);
+    let mut frontier: Vec<Symbol> = vec![root.clone()];
+
+    for depth in 1..=max_depth {
+        if frontier.is_empty() {
+            break;
+        }
+        let frontier_ids: Vec<String> = frontier.iter().map(|s| s.id.clone()).collect();
+
+        // caller -> callee, first edge wins; relationships before identifiers.
+        let mut edges: Vec<(String, String)> = db
+            .get_relationships_to_symbols(&frontier_ids)?
+            .into_iter()
+            .filter(|rel| rel.kind == RelationshipKind::Calls)
+            .map(|rel| (rel.from_symbol_id, rel.to_symbol_id))
+            .collect();
+        edges.extend(
+            identifier_incoming_edges(db, &frontier, &visited)?
+                .into_iter()
+                .filter(|edge| edge.relationship_kind == RelationshipKind::Calls)
+                .filter_map(|edge| Some((edge.container_id, edge.target_symbol_id?))),
+        );
+
+        let mut next_ids = Vec::new();
+        for (caller, callee) in edges {
+            if next_ids.len() >= MAX_FRONTIER_PER_DEPTH {
+                break;
+            }
+            if visited.insert(caller.clone()) {
+                tree.parents.insert(caller.clone(), (callee, depth));
+                next_ids.push(caller);
+            }
+        }
+
+        frontier = db.get_symbols_by_ids(&next_ids)?;
+        for symbol in &frontier {
+            tree.symbols.insert(symbol.id.clone(), symbol.clone());
+        }
+    }
+
+    Ok(tree)
+}
@@ -0,0 +1,370 @@
+//! Catalog of dangerous sinks per language.
+//!
+//! Sinks are matched against call identifiers by callee name. A qualified
+//! entry ("pickle.loads") only matches calls written with that qualifier, so
+//! `json.loads` is not flagged; a bare entry ("eval") matches the bare call
+//! and any qualified form of it. Raw-SQL entries additionally require the
+//! call line to build its string by concatenation or interpolation, since
+//! `cursor.execute(query, params)` is the safe, parameterized form.
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Deserialize, Serialize, JsonSchema,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum SinkCategory {
+    CommandExec,
+    CodeEval,
+    SqlInjection,
+    Deserialization,
+}
+
+impl SinkCategory {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::CommandExec => "command_exec",
+            Self::CodeEval => "code_eval",
+            Self::SqlInjection => "sql_injection",
+            Self::Deserialization => "deserialization",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Severity {
+    High,
+    Critical,
+}
+
+impl Severity {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::High => "high",
+            Self::Critical => "critical",
+        }
+    }
+
+    pub(crate) fn weight(self) -> u32 {
+        match self {
+            Self::High => 2,
+            Self::Critical => 3,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct SinkRule {
+    pub category: SinkCategory,
+    pub severity: Severity,
+    pub languages: &'static [&'static str],
+    pub callees: &'static [&'static str],
+    /// Only flag calls whose line builds a string dynamically.
+    pub requires_dynamic_string: bool,
+}
+
+const JS_LIKE: &[&str] = &["javascript", "typescript"];
+const JVM: &[&str] = &["java", "kotlin"];
+const C_LIKE: &[&str] = &["c", "cpp"];
+
+pub const SINK_RULES: &[SinkRule] = &[
+    // Command execution
+    SinkRule {
+        category: SinkCategory::CommandExec,
+        severity: Severity::Critical,
+        languages: &["python"],
+        callees: &[
+            "os.system",
+            "os.popen",
+            "subprocess.call",
+            "subprocess.run",
+            "subprocess.Popen",
+            "subprocess.check_call",
+            "subprocess.check_output",
+        ],
+        requires_dynamic_string: false,
+    },
+    SinkRule {
+        category: SinkCategory::CommandExec,
+        severity: Severity::Critical,
+        languages: JS_LIKE,
+        callees: &[
+            "child_process.exec",
+            "execSync",
+            "child_process.spawn",
+            "spawnSync",
+        ],
+        requires_dynamic_string: false,
+    },
+    SinkRule {
+        category: SinkCategory::CommandExec,
+        severity: Severity::Critical,
+        languages: &["php"],
+        callees: &[
+            "exec",
+            "shell_exec",
+            "system",
+            "passthru",
+            "popen",
+            "proc_open",
+        ],
+        requires_dynamic_string: false,
+    },
+    SinkRule {
+        category: SinkCategory::CommandExec,
+        severity: Severity::Critical,
+        languages: &["ruby"],
+        callees: &[
+            "system",
+            "exec",
+            "IO.popen",
+            "Open3.capture2",
+            "Open3.capture3",
+        ],
+        requires_dynamic_string: false,
+    },
+    SinkRule {
+        category: SinkCategory::CommandExec,
+        severity: Severity::Critical,
+        languages: JVM,
+        callees: &["exec", "ProcessBuilder"],
+        requires_dynamic_string: false,
+    },
+    SinkRule {
+        category: SinkCategory::CommandExec,
+        severity: Severity::Critical,
+        languages: &["go"],
+        callees: &["exec.Command", "exec.CommandContext"],
+        requires_dynamic_string: false,
+    },
+    SinkRule {
+        category: SinkCategory::CommandExec,
+        severity: Severity::Critical,
+        languages: &["csharp"],
+        callees: &["Process.Start"],
+        requires_dynamic_string: false,
+    },
+    SinkRule {
+        category: SinkCategory::CommandExec,
+        severity: Severity::Critical,
+        languages: &["rust"],
+        callees: &["Command::new"],
+        requires_dynamic_string: false,
+    },
+    SinkRule {
+        category: SinkCategory::CommandExec,
+        severity: Severity::Critical,
+        languages: C_LIKE,
+        callees: &["system", "popen", "execl", "execlp", "execv", "execvp"],
+        requires_dynamic_string: false,
+    },
+    // Dynamic code evaluation
+    SinkRule {
+        category: SinkCategory::CodeEval,
+        severity: Severity::Critical,
+        languages: &["python"],
+        callees: &["eval", "exec"],
+        requires_dynamic_string: false,
+    },
+    SinkRule {
+        category: SinkCategory::CodeEval,
+        severity: Severity::Critical,
+        languages: JS_LIKE,
+        callees: &["eval", "vm.runInThisContext", "vm.runInNewContext"],
+        requires_dynamic_string: false,
+    },
+    SinkRule {
+        category: SinkCategory::CodeEval,
+        severity: Severity::Critical,
+        languages: &["php"],
+        callees: &["eval", "create_function"],
+        requires_dynamic_string: false,
+    },
+    SinkRule {
+        category: SinkCategory::CodeEval,
+        severity: Severity::Critical,
+        languages: &["ruby"],
+        callees: &["eval", "instance_eval", "class_eval"],
+        requires_dynamic_string: false,
+    },
+    // Raw SQL built from strings
+    SinkRule {
+        category: SinkCategory::SqlInjection,
+        severity: Severity::Critical,
+        languages: &["python"],
+        callees: &["execute", "executemany", "executescript", "raw"],
+        requires_dynamic_string: true,
+    },
+    SinkRule {
+        category: SinkCategory::SqlInjection,
+        severity: Severity::Critical,
+        languages: JS_LIKE,
+        callees: &["query", "raw", "$queryRawUnsafe", "$executeRawUnsafe"],
+        requires_dynamic_string: true,
+    },
+    SinkRule {
+        category: SinkCategory::SqlInjection,
+        severity: Severity::Critical,
+        languages: JVM,
+        callees: &[
+            "executeQuery",
+            "executeUpdate",
+            "execute",
+            "prepareStatement",
+            "createQuery",
+            "createNativeQuery",
+        ],
+        requires_dynamic_string: true,
+    },
+    SinkRule {
+        category: SinkCategory::SqlInjection,
+        severity: Severity::Critical,
+        languages: &["csharp"],
+        callees: &["ExecuteSqlRaw", "FromSqlRaw", "SqlCommand"],
+        requires_dynamic_string: true,
+    },
+    SinkRule {
+        category: SinkCategory::SqlInjection,
+        severity: Severity::Critical,
+        languages: &["php"],
+        callees: &["query", "mysqli_query", "pg_query"],
+        requires_dynamic_string: true,
+    },
+    SinkRule {
+        category: SinkCategory::SqlInjection,
+        severity: Severity::Critical,
+        languages: &["go"],
+        callees: &["Query", "QueryRow", "Exec", "QueryContext", "ExecContext"],
+        requires_dynamic_string: true,
+    },
+    SinkRule {
+        category: SinkCategory::SqlInjection,
+        severity: Severity::Critical,
+        languages: &["ruby"],
+        callees: &["find_by_sql", "execute"],
+        requires_dynamic_string: true,
+    },
+    SinkRule {
+        category: SinkCategory::SqlInjection,
+        severity: Severity::Critical,
+        languages: &["rust"],
+        callees: &["query", "execute", "sql_query"],
+        requires_dynamic_string: true,
+    },
+    // Unsafe deserialization
+    SinkRule {
+        category: SinkCategory::Deserialization,
+        severity: Severity::High,
+        languages: &["python"],
+        callees: &[
+            "pickle.load",
+            "pickle.loads",
+            "marshal.loads",
+            "yaml.load",
+            "dill.loads",
+            "shelve.open",
+        ],
+        requires_dynamic_string: false,
+    },
+    SinkRule {
+        category: SinkCategory::Deserialization,
+        severity: Severity::High,
+        languages: JVM,
+        callees: &["readObject", "readUnshared", "XMLDecoder"],
+        requires_dynamic_string: false,
+    },
+    SinkRule {
+        category: SinkCategory::Deserialization,
+        severity: Severity::High,
+        languages: &["php"],
+        callees: &["unserialize"],
+        requires_dynamic_string: false,
+    },
+    SinkRule {
+        category: SinkCategory::Deserialization,
+        severity: Severity::High,
+        languages: &["ruby"],
+        callees: &["Marshal.load", "YAML.load"],
+        requires_dynamic_string: false,
+    },
+    SinkRule {
+        category: SinkCategory::Deserialization,
+        severity: Severity::High,
+        languages: JS_LIKE,
+        callees: &["unserialize"],
+        requires_dynamic_string: false,
+    },
+    SinkRule {
+        category: SinkCategory::Deserialization,
+        severity: Severity::High,
+        languages: &["csharp"],
+        callees: &["BinaryFormatter"],
+        requires_dynamic_string: false,
+    },
+];
+
+/// Line fragments that indicate a string is assembled from pieces.
+const DYNAMIC_STRING_MARKERS: &[&str] = &[
+    "\" +",
+    "+ \"",
+    "' +",
+    "+ '",
+    "\" .",
+    ". \"",
+    "' .",
+    ". '",
+    "format!(",
+    ".format(",
+    "f\"",
+    "f'",
+    "\" %",
+    "' %",
+    "${",
+    "#{",
+    "Sprintf(",
+    "String.format(",
+    "$\"",
+    ".concat(",
+];
+
+/// Last segment of a possibly qualified callee (`os.system` → `system`).
+pub fn callee_last_segment(name: &str) -> &str {
+    let after_dot = name.rsplit('.').next().unwrap_or(name);
+    after_dot.rsplit("::").next().unwrap_or(after_dot)
+}
+
+/// Returns true if the call identifier `name` matches the catalog `entry`.
+pub fn callee_matches(entry: &str, name: &str) -> bool {
+    if callee_last_segment(entry) == entry {
+        return callee_last_segment(name) == entry;
+    }
+    name == entry || name.ends_with(&format!(".{entry}")) || name.ends_with(&format!("::{entry}"))
+}
+
+/// Find the rule that flags a call to `name` in `language`, if any.
+pub fn match_rule(language: &str, name: &str) -> Option<&'static SinkRule> {
+    SINK_RULES.iter().find(|rule| {
+        rule.languages.contains(&language)
+            && rule.callees.iter().any(|entry| callee_matches(entry, name))
+    })
+}
+
+/// Every distinct last segment in the catalog, for the identifier query.
+pub fn catalog_callee_names() -> Vec<String> {
+    let mut names: Vec<String> = SINK_RULES
+        .iter()
+        .flat_map(|rule| rule.callees.iter())
+        .map(|entry| callee_last_segment(entry).to_string())
+        .collect();
+    names.sort();
+    names.dedup();
+    names
+}
+
+/// Returns true if `line` appears to build a string dynamically.
+pub fn has_dynamic_string(line: &str) -> bool {
+    DYNAMIC_STRING_MARKERS
+        .iter()
+        .any(|marker| line.contains(marker))
+}
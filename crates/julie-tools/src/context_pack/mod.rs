@@ -0,0 +1,423 @@
+//! `get_context_pack`: everything needed to edit one symbol safely, in one
+//! token-budgeted bundle.
+//!
+//! The pack is the symbol's definition (signature, extracted type, doc
+//! comment and body), then its callers, its callees, and the types it sits in
+//! or uses. Sections are filled in that order until `max_tokens` is spent, so
+//! a tight budget drops distant context first and never the definition
+//! header. Within a section, calls come before other references and the
+//! symbol's own file before other files.
+//!
+//! The symbol is resolved like `fast_signature`: by id, then by name, with the
+//! ids listed when a name is shared.
+
+use std::collections::HashSet;
+
+use anyhow::{Result, anyhow};
+use julie_context::{ToolContext, WorkspaceTarget};
+use julie_core::database::SymbolDatabase;
+use julie_core::mcp_compat::{CallToolResult, CallToolResultExt, Content};
+use julie_core::token_estimation::TokenEstimator;
+use julie_extractors::base::{RelationshipKind, Symbol, SymbolKind};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::deep_dive::data::{RefEntry, build_symbol_context};
+use crate::navigation::formatting::format_type_info;
+use crate::signature::{FastSignatureTool, SignatureLookup};
+
+/// Budget when the call sets none.
+pub const DEFAULT_MAX_TOKENS: u32 = 3000;
+
+/// Accepted range for `max_tokens`.
+pub const MAX_TOKENS_RANGE: std::ops::RangeInclusive<u32> = 500..=20_000;
+
+/// Callers and callees gathered before budgeting.
+const REF_CAP: usize = 40;
+
+/// Type names looked up from the extracted type.
+const MAX_TYPE_NAMES: usize = 5;
+
+/// Share of the budget the definition body may take.
+const BODY_BUDGET_SHARE: f64 = 0.5;
+
+/// Estimated cost of the `Body:` label and the truncation marker.
+const BODY_FRAME_TOKENS: usize = 8;
+
+fn default_max_tokens() -> u32 {
+    DEFAULT_MAX_TOKENS
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
+pub struct GetContextPackTool {
+    /// Symbol id or name (qualified names like `Processor::process` work). If several symbols share the name, their ids are listed
+    #[serde(alias = "symbol_id")]
+    pub symbol: String,
+    /// Token budget for the whole pack (default 3000, 500-20000). The definition header is always included
+    #[serde(
+        default = "default_max_tokens",
+        deserialize_with = "julie_core::serde_lenient::deserialize_u32_lenient"
+    )]
+    pub max_tokens: u32,
+    /// Workspace target. Use `primary` or a workspace id opened through `manage_workspace`.
+    #[serde(default)]
+    pub workspace: Option<String>,
+}
+
+/// Everything gathered for a pack, before budgeting.
+#[derive(Debug, Clone)]
+pub struct ContextPack {
+    pub symbol: Symbol,
+    /// Extracted type, already formatted (`returns Promise<User>`).
+    pub type_line: Option<String>,
+    /// `kind name` of the enclosing symbol.
+    pub parent: Option<String>,
+    pub callers: Vec<Symbol>,
+    pub callees: Vec<Symbol>,
+    /// Enclosing symbol first, then the types the symbol uses.
+    pub types: Vec<Symbol>,
+}
+
+/// Outcome of a pack request.
+#[derive(Debug, Clone)]
+pub enum ContextPackLookup {
+    Found(Box<ContextPack>),
+    /// Not found or ambiguous; holds the `fast_signature` lookup to render.
+    Unresolved(SignatureLookup),
+}
+
+impl GetContextPackTool {
+    pub async fn call_tool(&self, handler: &dyn ToolContext) -> Result<CallToolResult> {
+        let workspace_target = handler
+            .resolve_workspace_target(self.workspace.as_deref())
+            .await?;
+        self.call_tool_with_target(handler, &workspace_target).await
+    }
+
+    pub async fn call_tool_with_target(
+        &self,
+        handler: &dyn ToolContext,
+        workspace_target: &WorkspaceTarget,
+    ) -> Result<CallToolResult> {
+        if self.symbol.trim().is_empty() {
+            return Err(anyhow!("symbol must not be empty"));
+        }
+        if !MAX_TOKENS_RANGE.contains(&self.max_tokens) {
+            return Err(anyhow!(
+                "max_tokens {} is out of range ({}-{})",
+                self.max_tokens,
+                MAX_TOKENS_RANGE.start(),
+                MAX_TOKENS_RANGE.end()
+            ));
+        }
+        let database = match workspace_target {
+            WorkspaceTarget::Primary => handler.primary_pooled_database().await?,
+            WorkspaceTarget::Target(workspace_id) => {
+                handler
+                    .get_pooled_database_for_workspace(workspace_id)
+                    .await?
+            }
+        };
+        let tool = self.clone();
+
+        let rendered = tokio::task::spawn_blocking(move || -> Result<String> {
+            let database = database.into_read_snapshot()?;
+            let lookup = tool.execute(&database)?;
+            Ok(tool.render(&lookup))
+        })
+        .await
+        .map_err(|error| anyhow!("get_context_pack task failed: {error}"))??;
+        Ok(CallToolResult::text_content(vec![Content::text(rendered)]))
+    }
+
+    /// Resolve the symbol and gather its neighborhood.
+    pub fn execute(&self, db: &SymbolDatabase) -> Result<ContextPackLookup> {
+        let signature = FastSignatureTool {
+            symbol_id: self.symbol.clone(),
+            workspace: None,
+        };
+        let report = match signature.execute(db)? {
+            SignatureLookup::Found(report) => report,
+            other => return Ok(ContextPackLookup::Unresolved(other)),
+        };
+        let context = build_symbol_context(db, &report.symbol, "context", REF_CAP, REF_CAP)?;
+        let symbol = context.symbol;
+        let type_line = report
+            .type_info
+            .as_ref()
+            .map(|info| format_type_info(&symbol.kind, info));
+
+        let callers = ranked_ref_symbols(&context.incoming, &symbol);
+        let mut callees = ranked_ref_symbols(&context.outgoing, &symbol);
+
+        // Types move out of the callee list into their own section.
+        let mut types = Vec::new();
+        if let Some(parent_id) = symbol.parent_id.as_deref() {
+            types.extend(db.get_symbol_by_id(parent_id)?);
+        }
+        let (used_types, calls): (Vec<Symbol>, Vec<Symbol>) = callees
+            .drain(..)
+            .partition(|callee| is_type_kind(&callee.kind));
+        callees = calls;
+        types.extend(used_types);
+        if let Some(info) = &report.type_info {
+            for name in type_names(&info.resolved_type) {
+                types.extend(
+                    db.find_symbols_by_name(&name)?
+                        .into_iter()
+                        .filter(|candidate| {
+                            is_type_kind(&candidate.kind) && candidate.language == symbol.language
+                        })
+                        .take(1),
+                );
+            }
+        }
+        let mut seen = HashSet::from([symbol.id.clone()]);
+        types.retain(|candidate| seen.insert(candidate.id.clone()));
+
+        Ok(ContextPackLookup::Found(Box::new(ContextPack {
+            parent: report.parent.clone(),
+            symbol,
+            type_line,
+            callers,
+            callees,
+            types,
+        })))
+    }
+
+    pub fn render(&self, lookup: &ContextPackLookup) -> String {
+        match lookup {
+            ContextPackLookup::Found(pack) => render_pack(pack, self.max_tokens as usize),
+            ContextPackLookup::Unresolved(unresolved) => FastSignatureTool {
+                symbol_id: self.symbol.clone(),
+                workspace: None,
+            }
+            .render(unresolved),
+        }
+    }
+}
+
+/// Symbols behind `refs`, deduplicated: calls first, then the symbol's own
+/// file, then the order the database returned.
+fn ranked_ref_symbols(refs: &[RefEntry], symbol: &Symbol) -> Vec<Symbol> {
+    let mut ranked: Vec<(bool, bool, usize, &Symbol)> = refs
+        .iter()
+        .enumerate()
+        .filter_map(|(index, entry)| {
+            let target = entry.symbol.as_ref()?;
+            Some((
+                !matches!(entry.kind, RelationshipKind::Calls),
+                target.file_path != symbol.file_path,
+                index,
+                target,
+            ))
+        })
+        .collect();
+    ranked.sort_by_key(|(not_call, other_file, index, _)| (*not_call, *other_file, *index));
+    let mut seen = HashSet::from([symbol.id.as_str()]);
+    ranked
+        .into_iter()
+        .filter(|(_, _, _, target)| seen.insert(target.id.as_str()))
+        .map(|(_, _, _, target)| target.clone())
+        .collect()
+}
+
+fn is_type_kind(kind: &SymbolKind) -> bool {
+    matches!(
+        kind,
+        SymbolKind::Class
+            | SymbolKind::Struct
+            | SymbolKind::Interface
+            | SymbolKind::Trait
+            | SymbolKind::Enum
+            | SymbolKind::Type
+    )
+}
+
+/// Capitalized identifiers in an extracted type (`Promise<User>` gives
+/// `Promise`, `User`); lowercase names are builtins or type parameters
+/// far more often than project types.
+fn type_names(resolved_type: &str) -> Vec<String> {
+    let mut names = Vec::new();
+    for word in resolved_type.split(|c: char| !(c.is_alphanumeric() || c == '_')) {
+        let project_like = word.len() > 1 && word.starts_with(|c: char| c.is_ascii_uppercase());
+        if project_like && !names.iter().any(|name| name == word) {
+            names.push(word.to_string());
+        }
+        if names.len() == MAX_TYPE_NAMES {
+            break;
+        }
+    }
+    names
+}
+
+/// Lines added until the budget runs out; later blocks are counted instead.
+struct Budget {
+    estimator: TokenEstimator,
+    remaining: usize,
+    lines: Vec<String>,
+}
+
+impl Budget {
+    fn push(&mut self, block: Vec<String>) -> bool {
+        let cost = self.estimator.estimate_string(&block.join("\n")) + 1;
+        if cost > self.remaining {
+            return false;
+        }
+        self.remaining -= cost;
+        self.lines.extend(block);
+        true
+    }
+}
+
+fn render_pack(pack: &ContextPack, max_tokens: usize) -> String {
+    let mut budget = Budget {
+        estimator: TokenEstimator::new(),
+        remaining: max_tokens,
+        lines: Vec::new(),
+    };
+    let symbol = &pack.symbol;
+
+    // The header is always kept, whatever it costs.
+    let header = definition_header(pack);
+    budget.remaining = budget
+        .remaining
+        .saturating_sub(budget.estimator.estimate_string(&header.join("\n")));
+    budget.lines.extend(header);
+
+    let mut omitted = Vec::new();
+    if let Some(body) = symbol
+        .code_context
+        .as_deref()
+        .filter(|body| !body.trim().is_empty())
+    {
+        // Room for the `Body:` label and the truncation marker comes off the top.
+        let allowance = (((max_tokens as f64) * BODY_BUDGET_SHARE) as usize)
+            .min(budget.remaining)
+            .saturating_sub(BODY_FRAME_TOKENS);
+        let mut kept = Vec::new();
+        let mut used = 0;
+        for line in body.lines() {
+            let cost = budget.estimator.estimate_string(line) + 1;
+            if used + cost > allowance {
+                break;
+            }
+            used += cost;
+            kept.push(format!("  {}", line.trim_end()));
+        }
+        if kept.is_empty() {
+            omitted.push("the body".to_string());
+        } else {
+            let cut = body.lines().count() - kept.len();
+            budget.remaining -= used + BODY_FRAME_TOKENS;
+            budget.lines.push(String::new());
+            budget.lines.push("Body:".to_string());
+            budget.lines.extend(kept);
+            if cut > 0 {
+                budget.lines.push(format!("  ... ({cut} more lines)"));
+            }
+        }
+    }
+
+    let sections = [
+        ("Callers", &pack.callers),
+        ("Callees", &pack.callees),
+        ("Related types", &pack.types),
+    ];
+    for (title, symbols) in sections {
+        if symbols.is_empty() {
+            continue;
+        }
+        if !budget.push(vec![String::new(), format!("{title} ({}):", symbols.len())]) {
+            omitted.push(format!("{} {}", symbols.len(), title.to_lowercase()));
+            continue;
+        }
+        let shown = symbols
+            .iter()
+            .take_while(|neighbor| budget.push(neighbor_block(neighbor)))
+            .count();
+        if shown < symbols.len() {
+            omitted.push(format!(
+                "{} {}",
+                symbols.len() - shown,
+                title.to_lowercase()
+            ));
+        }
+    }
+    if !omitted.is_empty() {
+        budget.lines.push(String::new());
+        budget.lines.push(format!(
+            "Budget of {max_tokens} tokens reached; left out: {}. Raise max_tokens or use deep_dive",
+            omitted.join(", ")
+        ));
+    }
+    budget.lines.join("\n")
+}
+
+/// Location, enclosing symbol, signature, extracted type and doc comment.
+fn definition_header(pack: &ContextPack) -> Vec<String> {
+    let symbol = &pack.symbol;
+    let mut lines = vec![format!(
+        "{} {} ({}) {}:{}-{}",
+        symbol.kind,
+        symbol.name,
+        symbol.language,
+        symbol.file_path,
+        symbol.start_line,
+        symbol.end_line
+    )];
+    if let Some(parent) = &pack.parent {
+        lines.push(format!("  in {parent}"));
+    }
+    if let Some(signature) = symbol
+        .signature
+        .as_deref()
+        .filter(|signature| !signature.trim().is_empty())
+    {
+        lines.extend(
+            signature
+                .lines()
+                .map(|line| format!("  {}", line.trim_end())),
+        );
+    }
+    if let Some(type_line) = &pack.type_line {
+        lines.push(format!("  {type_line}"));
+    }
+    if let Some(doc) = symbol
+        .doc_comment
+        .as_deref()
+        .filter(|doc| !doc.trim().is_empty())
+    {
+        lines.push(String::new());
+        lines.extend(
+            doc.trim()
+                .lines()
+                .map(|line| format!("  {}", line.trim_end())),
+        );
+    }
+    lines
+}
+
+/// One neighbor: location, first signature line, first doc line.
+fn neighbor_block(symbol: &Symbol) -> Vec<String> {
+    let mut block = vec![format!(
+        "  {} {}  {}:{}",
+        symbol.kind, symbol.name, symbol.file_path, symbol.start_line
+    )];
+    if let Some(signature) = symbol
+        .signature
+        .as_deref()
+        .and_then(|signature| signature.lines().next())
+        .filter(|line| !line.trim().is_empty())
+    {
+        block.push(format!("    {}", signature.trim()));
+    }
+    if let Some(doc) = symbol
+        .doc_comment
+        .as_deref()
+        .and_then(|doc| doc.trim().lines().next())
+    {
+        block.push(format!("    // {}", doc.trim()));
+    }
+    block
+}
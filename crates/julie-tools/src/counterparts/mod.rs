@@ -0,0 +1,180 @@
+//! `fast_counterparts`: symbols in other languages that model the same
+//! logical entity as a given symbol.
+//!
+//! For each definition of the symbol, candidates come from
+//! [`julie_index::analysis::cross_language_links::match_counterparts`]: DTOs,
+//! interfaces and tables with the same canonical name scored by field
+//! overlap, proto ↔ gRPC stub and OpenAPI operation ↔ handler/client links,
+//! and client calls ↔ the route handlers they reach. Each candidate lists the
+//! fields only one side has, so contract drift shows up as a diff instead of
+//! two files to compare by hand.
+
+use anyhow::{Result, anyhow};
+use julie_context::{ToolContext, WorkspaceTarget};
+use julie_core::database::SymbolDatabase;
+use julie_core::mcp_compat::{CallToolResult, CallToolResultExt, Content};
+use julie_extractors::Symbol;
+use julie_index::analysis::cross_language_links::{
+    CounterpartMatch, DEFAULT_MATCH_CONFIDENCE, match_counterparts,
+};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::deep_dive::data::find_symbol;
+
+/// Candidates listed per definition when `limit` is not given.
+const DEFAULT_LIMIT: u32 = 10;
+
+/// Definitions of an ambiguous name that are matched; the rest are counted.
+const MAX_DEFINITIONS: usize = 5;
+
+fn default_limit() -> u32 {
+    DEFAULT_LIMIT
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
+pub struct FastCounterpartsTool {
+    /// Symbol name of a type, table, message, operation or route handler
+    pub symbol: String,
+    /// Disambiguate when multiple symbols share a name (partial file path match)
+    #[serde(default)]
+    pub context_file: Option<String>,
+    /// Lowest confidence listed, 0.0-1.0 (default 0.3). Pairs below 0.5 are usually drifted or unrelated shapes
+    #[serde(default)]
+    pub min_confidence: Option<f32>,
+    /// Most candidates per definition (default 10)
+    #[serde(
+        default = "default_limit",
+        deserialize_with = "julie_core::serde_lenient::deserialize_u32_lenient"
+    )]
+    pub limit: u32,
+    /// Workspace target. Use `primary` or a workspace id opened through `manage_workspace`.
+    #[serde(default)]
+    pub workspace: Option<String>,
+}
+
+/// The counterparts of one definition of the queried name.
+#[derive(Debug, Clone)]
+pub struct CounterpartsReport {
+    pub definition: Symbol,
+    pub matches: Vec<CounterpartMatch>,
+}
+
+impl FastCounterpartsTool {
+    pub async fn call_tool(&self, handler: &dyn ToolContext) -> Result<CallToolResult> {
+        let workspace_target = handler
+            .resolve_workspace_target(self.workspace.as_deref())
+            .await?;
+        self.call_tool_with_target(handler, &workspace_target).await
+    }
+
+    pub async fn call_tool_with_target(
+        &self,
+        handler: &dyn ToolContext,
+        workspace_target: &WorkspaceTarget,
+    ) -> Result<CallToolResult> {
+        if self.symbol.trim().is_empty() {
+            return Err(anyhow!("symbol must not be empty"));
+        }
+        let database = match workspace_target {
+            WorkspaceTarget::Primary => handler.primary_pooled_database().await?,
+            WorkspaceTarget::Target(workspace_id) => {
+                handler
+                    .get_pooled_database_for_workspace(workspace_id)
+                    .await?
+            }
+        };
+        let tool = self.clone();
+
+        let rendered = tokio::task::spawn_blocking(move || -> Result<String> {
+            let database = database.into_read_snapshot()?;
+            let (reports, total) = tool.execute(&database)?;
+            Ok(tool.render(&reports, total))
+        })
+        .await
+        .map_err(|error| anyhow!("fast_counterparts task failed: {error}"))??;
+        Ok(CallToolResult::text_content(vec![Content::text(rendered)]))
+    }
+
+    /// Match up to [`MAX_DEFINITIONS`] definitions of the symbol. Returns the
+    /// reports and how many definitions the name has in total.
+    pub fn execute(&self, db: &SymbolDatabase) -> Result<(Vec<CounterpartsReport>, usize)> {
+        let min_confidence = self
+            .min_confidence
+            .unwrap_or(DEFAULT_MATCH_CONFIDENCE)
+            .clamp(0.0, 1.0);
+        let definitions = find_symbol(db, self.symbol.trim(), self.context_file.as_deref())?;
+        let total = definitions.len();
+        let reports = definitions
+            .into_iter()
+            .take(MAX_DEFINITIONS)
+            .map(|definition| {
+                let mut matches = match_counterparts(db, &definition.id, min_confidence)?;
+                matches.truncate(self.limit as usize);
+                Ok(CounterpartsReport {
+                    definition,
+                    matches,
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Ok((reports, total))
+    }
+
+    pub fn render(&self, reports: &[CounterpartsReport], total: usize) -> String {
+        if reports.is_empty() {
+            return format!("No definition found for '{}'", self.symbol.trim());
+        }
+        let mut lines = Vec::new();
+        for report in reports {
+            let definition = &report.definition;
+            if !lines.is_empty() {
+                lines.push(String::new());
+            }
+            lines.push(format!(
+                "{} {} ({}) {}:{}",
+                definition.kind,
+                definition.name,
+                definition.language,
+                definition.file_path,
+                definition.start_line
+            ));
+            if report.matches.is_empty() {
+                lines.push("  No counterparts in other languages".to_string());
+                continue;
+            }
+            for candidate in &report.matches {
+                let symbol = &candidate.symbol;
+                lines.push(format!(
+                    "  {:.2} {} {} ({}) {}:{} — {}",
+                    candidate.confidence,
+                    symbol.kind,
+                    symbol.name,
+                    symbol.language,
+                    symbol.file_path,
+                    symbol.start_line,
+                    candidate.reason
+                ));
+                if !candidate.missing_fields.is_empty() {
+                    lines.push(format!(
+                        "       missing there: {}",
+                        candidate.missing_fields.join(", ")
+                    ));
+                }
+                if !candidate.extra_fields.is_empty() {
+                    lines.push(format!(
+                        "       only there: {}",
+                        candidate.extra_fields.join(", ")
+                    ));
+                }
+            }
+        }
+        if total > reports.len() {
+            lines.push(format!(
+                "\n… {} more definitions of '{}' (narrow with context_file)",
+                total - reports.len(),
+                self.symbol.trim()
+            ));
+        }
+        lines.join("\n")
+    }
+}
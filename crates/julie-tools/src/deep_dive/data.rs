@@ -4,15 +4,19 @@
 //! All queries use existing indexed data — no new indexing required.
 
 use anyhow::Result;
+use serde::Serialize;
 use serde_json::Value;
 use std::collections::{HashMap, HashSet};
 use tracing::debug;
 
-use crate::navigation::resolution::{file_path_matches_suffix, parse_qualified_name};
+use crate::navigation::resolution::{
+    file_path_matches_suffix, parse_qualified_name, swift_symbols_in_module,
+};
 use julie_core::database::{IdentifierRef, SymbolDatabase};
 use julie_core::shared::NOISE_CALLEE_NAMES;
 use julie_extractors::base::{Relationship, RelationshipKind, Symbol, SymbolKind};
 use julie_index::search::scoring::is_test_path;
+use julie_pipeline::indexing_core::csharp_partials::PARTIAL_GROUP_METADATA_KEY;
 
 /// Aggregated context for a single symbol, ready for formatting
 #[derive(Debug)]
@@ -21,6 +25,8 @@ pub struct SymbolContext {
     pub symbol: Symbol,
     /// Extractor-provided structural complexity metric
     pub complexity: Option<julie_extractors::base::ComplexityMetric>,
+    /// Extracted type: return type of a callable, type of anything else
+    pub type_info: Option<julie_extractors::base::TypeInfo>,
     /// Incoming references: who calls/uses this symbol
     pub incoming: Vec<RefEntry>,
     /// Total incoming before capping
@@ -35,6 +41,18 @@ pub struct SymbolContext {
     pub outgoing_calls_total: usize,
     /// Child symbols (methods, fields) for struct/class/trait/enum
     pub children: Vec<Symbol>,
+    /// Other declaration sites of a partial type (C# `partial class`), of
+    /// an Ada unit's spec and body, or of a Kotlin `expect` declaration and
+    /// its `actual`s, excluding `symbol` itself. Children and refs above already include them.
+    pub declaration_sites: Vec<Symbol>,
+    /// Swift module (SwiftPM or Xcode target) the symbol is compiled into
+    pub module: Option<String>,
+    /// Monorepo project (package) the symbol's file belongs to
+    pub project: Option<String>,
+    /// Labels of the build targets (Make, CMake, Bazel) compiling the symbol's file
+    pub build_targets: Vec<String>,
+    /// Framework role the symbol plays, e.g. "Spring bean"
+    pub framework_role: Option<String>,
     /// Implementations of this trait/interface
     pub implementations: Vec<Symbol>,
     /// Test file references (populated at context and full depth)
@@ -61,7 +79,8 @@ pub struct RefEntry {
 /// Resolution order:
 /// 1. Try full name as-is (handles Elixir's "Phoenix.Router", Scala's "cats.Monad", etc.)
 /// 2. Try qualified parent/child parsing (handles Rust's "Struct::method", Python's "Class.method")
-/// 3. Fall back to full name without definition-kind filter
+/// 3. Try a Swift module qualifier (handles "Networking.Client", "Networking.Client.send")
+/// 4. Fall back to full name without definition-kind filter
 pub fn find_symbol(
     db: &SymbolDatabase,
     name: &str,
@@ -112,12 +131,162 @@ pub fn find_symbol(
         // Fall through if no parent match found (e.g. parent not yet indexed)
     }
 
-    // Step 3: Fall back to full name without definition-kind filter
+    // Step 3: Swift module qualifier — the leading segment names the SwiftPM or
+    // Xcode target, so resolve the rest and keep matches compiled into it.
+    if let Some((module, rest)) = name.split_once('.') {
+        let candidates = find_symbol(db, rest, context_file)?;
+        let in_module = swift_symbols_in_module(db, &candidates, module)?;
+        if !in_module.is_empty() {
+            return Ok(candidates
+                .into_iter()
+                .filter(|s| in_module.contains(&s.id))
+                .collect());
+        }
+    }
+
+    // Step 4: Fall back to full name without definition-kind filter
     let mut symbols = db.find_symbols_by_name(name)?;
     symbols.retain(|s| !is_lookup_stub(&s.kind));
     apply_context_file_filter(symbols, context_file)
 }
 
+/// Collapse partial type declarations that share a `partial_group` into the
+/// first declaration found, so a C# `partial class` split across files, an
+/// Ada spec and its body, or a Kotlin `expect` and its platform `actual`s,
+/// reads as one logical symbol instead of N ambiguous definitions.
+pub fn collapse_partial_declarations(symbols: Vec<Symbol>) -> Vec<Symbol> {
+    let mut seen_groups = HashSet::new();
+    symbols
+        .into_iter()
+        .filter(|symbol| match partial_group(symbol) {
+            Some(group) => seen_groups.insert(group.to_string()),
+            None => true,
+        })
+        .collect()
+}
+
+/// One definition behind an ambiguous name, with the context that tells it
+/// apart from the others.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct DisambiguationCandidate {
+    /// Selects this definition when passed back as `disambiguator`: its
+    /// `file:line`, or its symbol id when another candidate shares the line
+    pub disambiguator: String,
+    pub symbol_id: String,
+    pub file_path: String,
+    pub line: u32,
+    pub kind: String,
+    pub visibility: Option<String>,
+    /// Enclosing type, module or package
+    pub container: Option<String>,
+    /// First line of the signature
+    pub signature: Option<String>,
+    pub project: Option<String>,
+    /// Swift module (SwiftPM or Xcode target)
+    pub module: Option<String>,
+}
+
+/// Describe each of several same-named definitions for a disambiguation list.
+pub fn disambiguation_candidates(
+    db: &SymbolDatabase,
+    symbols: &[Symbol],
+) -> Result<Vec<DisambiguationCandidate>> {
+    let parent_ids: Vec<String> = symbols
+        .iter()
+        .filter_map(|s| s.parent_id.clone())
+        .collect::<HashSet<_>>()
+        .into_iter()
+        .collect();
+    let parents: HashMap<String, String> = db
+        .get_symbols_by_ids(&parent_ids)?
+        .into_iter()
+        .map(|parent| (parent.id, parent.name))
+        .collect();
+    let swift_paths: Vec<String> = symbols
+        .iter()
+        .filter(|s| s.language == "swift")
+        .map(|s| s.file_path.clone())
+        .collect();
+    let modules = db.swift_modules_for_files(&swift_paths)?;
+    let projects = crate::projects::projects_for_symbols(db, symbols)?;
+
+    let mut locations: HashMap<(&str, u32), usize> = HashMap::new();
+    for symbol in symbols {
+        *locations
+            .entry((symbol.file_path.as_str(), symbol.start_line))
+            .or_default() += 1;
+    }
+
+    Ok(symbols
+        .iter()
+        .map(|symbol| {
+            let location = format!("{}:{}", symbol.file_path, symbol.start_line);
+            let shared = locations[&(symbol.file_path.as_str(), symbol.start_line)] > 1;
+            DisambiguationCandidate {
+                disambiguator: if shared { symbol.id.clone() } else { location },
+                symbol_id: symbol.id.clone(),
+                file_path: symbol.file_path.clone(),
+                line: symbol.start_line,
+                kind: format!("{:?}", symbol.kind).to_lowercase(),
+                visibility: symbol
+                    .visibility
+                    .as_ref()
+                    .map(|vis| format!("{vis:?}").to_lowercase()),
+                container: symbol
+                    .parent_id
+                    .as_ref()
+                    .and_then(|id| parents.get(id).cloned())
+                    .or_else(|| impl_type_name(symbol).map(str::to_string)),
+                signature: symbol
+                    .signature
+                    .as_deref()
+                    .and_then(|sig| sig.lines().next())
+                    .map(|line| line.trim().to_string())
+                    .filter(|line| !line.is_empty()),
+                project: projects.get(&symbol.file_path).cloned(),
+                module: modules.get(&symbol.file_path).cloned(),
+            }
+        })
+        .collect())
+}
+
+/// Keep the definitions a `disambiguator` from an earlier candidate list
+/// names: a symbol id, or `file:line` where the path may be a suffix.
+pub fn select_disambiguated(symbols: Vec<Symbol>, disambiguator: &str) -> Vec<Symbol> {
+    let disambiguator = disambiguator.trim();
+    if let Some(symbol) = symbols.iter().find(|s| s.id == disambiguator) {
+        return vec![symbol.clone()];
+    }
+    let Some((file, line)) = disambiguator
+        .rsplit_once(':')
+        .and_then(|(file, line)| Some((file, line.parse::<u32>().ok()?)))
+    else {
+        return Vec::new();
+    };
+    symbols
+        .into_iter()
+        .filter(|s| s.start_line == line && context_file_matches(&s.file_path, file))
+        .collect()
+}
+
+fn partial_group(symbol: &Symbol) -> Option<&str> {
+    symbol
+        .metadata
+        .as_ref()
+        .and_then(|metadata| metadata.get(PARTIAL_GROUP_METADATA_KEY))
+        .and_then(Value::as_str)
+}
+
+/// All other declaration sites of `symbol` when it is a partial type.
+fn partial_declaration_sites(db: &SymbolDatabase, symbol: &Symbol) -> Result<Vec<Symbol>> {
+    let Some(group) = partial_group(symbol) else {
+        return Ok(Vec::new());
+    };
+    let mut sites = db.find_partial_declarations(group)?;
+    sites.retain(|site| site.id != symbol.id && site.language == symbol.language);
+    Ok(sites)
+}
+
 fn qualified_name_leaf(name: &str) -> &str {
     name.rsplit_once("::")
         .map(|(_, tail)| tail)
@@ -203,7 +372,11 @@ pub fn build_symbol_context(
     incoming_cap: usize,
     outgoing_cap: usize,
 ) -> Result<SymbolContext> {
-    let symbol_ids = vec![symbol.id.clone()];
+    // Partial types: every declaration site is the same logical symbol, so
+    // refs, outgoing edges, and children are gathered across all of them.
+    let declaration_sites = partial_declaration_sites(db, symbol)?;
+    let mut symbol_ids = vec![symbol.id.clone()];
+    symbol_ids.extend(declaration_sites.iter().map(|site| site.id.clone()));
     let needs_body_enrichment = depth != "overview";
 
     // === Incoming references (who references this symbol) ===
@@ -235,7 +408,10 @@ pub fn build_symbol_context(
     }
 
     // === Outgoing references (what this symbol calls/uses) ===
-    let raw_outgoing = db.get_outgoing_relationships(&symbol.id)?;
+    let mut raw_outgoing = Vec::new();
+    for id in &symbol_ids {
+        raw_outgoing.extend(db.get_outgoing_relationships(id)?);
+    }
     let outgoing_total = raw_outgoing.len();
     let outgoing_calls_total = raw_outgoing
         .iter()
@@ -290,7 +466,11 @@ pub fn build_symbol_context(
 
     // === Children (methods, fields for struct/class/trait/enum/module) ===
     let children = if is_container_kind(&symbol.kind) {
-        db.get_children_by_parent_id(&symbol.id)?
+        let mut children = Vec::new();
+        for id in &symbol_ids {
+            children.extend(db.get_children_by_parent_id(id)?);
+        }
+        children
     } else {
         vec![]
     };
@@ -311,6 +491,30 @@ pub fn build_symbol_context(
         symbol.clone()
     };
     let complexity = db.get_complexity_metric_for_symbol(&symbol.id)?;
+    let type_info = db
+        .get_type_infos_for_symbols(std::slice::from_ref(&symbol.id))?
+        .remove(&symbol.id);
+    let module = if symbol.language == "swift" {
+        db.swift_modules_for_files(std::slice::from_ref(&symbol.file_path))?
+            .remove(&symbol.file_path)
+    } else {
+        None
+    };
+    let project = db
+        .projects_for_files(std::slice::from_ref(&symbol.file_path))?
+        .remove(&symbol.file_path);
+    let build_targets = db
+        .build_targets_for_files(std::slice::from_ref(&symbol.file_path))?
+        .remove(&symbol.file_path)
+        .unwrap_or_default()
+        .into_iter()
+        .map(|target| target.label)
+        .collect();
+    let framework_role = db
+        .framework_roles_for_symbols(std::slice::from_ref(&symbol.id))?
+        .remove(&symbol.id)
+        .and_then(|row| julie_index::analysis::frameworks::framework_role(&row.role))
+        .map(|role| role.label.to_string());
 
     // === Test locations (context and full depth) ===
     let test_refs = if depth == "full" || depth == "context" {
@@ -329,6 +533,7 @@ pub fn build_symbol_context(
     Ok(SymbolContext {
         symbol,
         complexity,
+        type_info,
         incoming,
         incoming_total,
         incoming_calls_total,
@@ -336,6 +541,11 @@ pub fn build_symbol_context(
         outgoing_total,
         outgoing_calls_total,
         children,
+        declaration_sites,
+        module,
+        project,
+        build_targets,
+        framework_role,
         implementations,
         test_refs,
         similar,
@@ -6,15 +6,39 @@
 //! - Struct/Class: fields, methods, used by
 //! - Module/Namespace: public exports
 
+use std::collections::HashSet;
 use std::fmt::Write;
 
+use julie_core::paths::display_stored_path;
 use julie_core::token_estimation::TokenEstimator;
 use julie_extractors::base::{RelationshipKind, SymbolKind};
+use julie_pipeline::indexing_core::kotlin_multiplatform::KOTLIN_MULTIPLATFORM_METADATA_KEY;
 
 use super::data::{RefEntry, SimilarEntry, SymbolContext};
+use crate::deleted_files::format_deleted_files;
+use crate::navigation::formatting::format_type_info;
+
+/// Token budget of one symbol's context at each depth level.
+pub fn depth_token_limit(depth: &str) -> usize {
+    match depth {
+        "overview" => 300,
+        "context" => 800,
+        _ => 1800, // "full"
+    }
+}
 
 /// Format a SymbolContext for the given depth level.
 pub fn format_symbol_context(ctx: &SymbolContext, depth: &str) -> String {
+    format_symbol_context_within(ctx, depth, depth_token_limit(depth))
+}
+
+/// Format a SymbolContext, truncating callers, callees and code to fit
+/// `token_limit` instead of the depth level's own budget.
+pub fn format_symbol_context_within(
+    ctx: &SymbolContext,
+    depth: &str,
+    token_limit: usize,
+) -> String {
     let mut out = String::new();
 
     // === Header: location + kind + visibility + signature ===
@@ -48,12 +72,6 @@ pub fn format_symbol_context(ctx: &SymbolContext, depth: &str) -> String {
     // Render the similar section separately so it survives truncation.
     // The main body (callers, callees, code) gets truncated first;
     // similar symbols are appended after, within reserved budget.
-    let token_limit = match depth {
-        "overview" => 300,
-        "context" => 800,
-        _ => 1800, // "full"
-    };
-
     let mut similar_text = String::new();
     format_similar_section(&mut similar_text, &ctx.similar);
 
@@ -137,15 +155,172 @@ fn format_header(out: &mut String, ctx: &SymbolContext) {
 
     out.push_str(&format!(
         "{}:{} ({}{})\n",
-        s.file_path, s.start_line, kind, vis
+        display_stored_path(&s.file_path),
+        s.start_line,
+        kind,
+        vis
     ));
 
     if let Some(sig) = &s.signature {
         out.push_str(&format!("  {}\n", sig));
     }
+    if let Some(info) = &ctx.type_info {
+        out.push_str(&format!("  {}\n", format_type_info(&s.kind, info)));
+    }
+    if let Some(module) = &ctx.module {
+        out.push_str(&format!("  module: {}\n", module));
+    }
+    if let Some(project) = &ctx.project {
+        out.push_str(&format!("  project: {}\n", project));
+    }
+    if !ctx.build_targets.is_empty() {
+        out.push_str(&format!("  built by: {}\n", ctx.build_targets.join(", ")));
+    }
+    if let Some(role) = &ctx.framework_role {
+        out.push_str(&format!("  framework: {}\n", role));
+    }
 
     // Show test quality info when the symbol itself is a test
     format_test_quality_info(out, s);
+    format_macro_provenance(out, s);
+    format_manifest_dependency(out, s);
+    format_generated_provenance(out, s);
+    format_declaration_sites(out, ctx);
+}
+
+/// Link symbols generated by a C/C++ macro to the invocation that produced
+/// them, and list what an invocation site expands to.
+fn format_macro_provenance(out: &mut String, symbol: &julie_extractors::base::Symbol) {
+    let Some(expansion) = symbol
+        .metadata
+        .as_ref()
+        .and_then(|metadata| metadata.get("macro_expansion"))
+    else {
+        return;
+    };
+    let invocation = expansion
+        .get("invocation")
+        .and_then(|value| value.as_str())
+        .unwrap_or_default();
+    let line = expansion
+        .get("line")
+        .and_then(|value| value.as_u64())
+        .unwrap_or(symbol.start_line as u64);
+
+    match expansion
+        .get("generated")
+        .and_then(|value| value.as_array())
+    {
+        Some(generated) => {
+            let names: Vec<&str> = generated.iter().filter_map(|name| name.as_str()).collect();
+            if !names.is_empty() {
+                out.push_str(&format!(
+                    "  Macro invocation expands to: {}\n",
+                    names.join(", ")
+                ));
+            }
+        }
+        None => out.push_str(&format!(
+            "  Generated by macro: {} at {}:{}\n",
+            invocation,
+            display_stored_path(&symbol.file_path),
+            line
+        )),
+    }
+}
+
+/// Summarize a package-manifest dependency declaration.
+fn format_manifest_dependency(out: &mut String, symbol: &julie_extractors::base::Symbol) {
+    let Some(dependency) = symbol
+        .metadata
+        .as_ref()
+        .and_then(|metadata| metadata.get("dependency"))
+    else {
+        return;
+    };
+    let field = |key: &str| dependency.get(key).and_then(|value| value.as_str());
+    let package = field("package").unwrap_or(&symbol.name);
+    let mut line = format!(
+        "  Dependency: {} {}",
+        field("ecosystem").unwrap_or("package"),
+        package
+    );
+    if let Some(version) = field("version") {
+        line.push_str(&format!(" {}", version));
+    }
+    if let Some(section) = field("section") {
+        line.push_str(&format!(" ({})", section));
+    }
+    line.push('\n');
+    out.push_str(&line);
+}
+
+/// Point symbols from a `[[generated]]` output at the source they are
+/// generated from; edits belong there.
+fn format_generated_provenance(out: &mut String, symbol: &julie_extractors::base::Symbol) {
+    let Some(generated) = symbol
+        .metadata
+        .as_ref()
+        .and_then(|metadata| metadata.get("generated"))
+    else {
+        return;
+    };
+    match generated.get("source").and_then(|source| source.as_str()) {
+        Some(source) => out.push_str(&format!("  generated from: {} (read-only)\n", source)),
+        None => out.push_str("  generated (read-only)\n"),
+    }
+}
+
+/// List every other declaration site of a partial type, Ada unit or Kotlin
+/// `expect`/`actual` declaration.
+fn format_declaration_sites(out: &mut String, ctx: &SymbolContext) {
+    if ctx.declaration_sites.is_empty() {
+        return;
+    }
+    let heading = if multiplatform_role(&ctx.symbol).is_some() {
+        "Expect/actual declarations"
+    } else {
+        "Partial declarations"
+    };
+    out.push_str(&format!(
+        "\n{} ({}):\n",
+        heading,
+        ctx.declaration_sites.len() + 1
+    ));
+    for site in std::iter::once(&ctx.symbol).chain(&ctx.declaration_sites) {
+        let role = multiplatform_role(site)
+            .map(|role| format!(" ({role})"))
+            .unwrap_or_default();
+        out.push_str(&format!(
+            "  {}:{}{}\n",
+            display_stored_path(&site.file_path),
+            site.start_line,
+            role
+        ));
+    }
+}
+
+/// `expect` or `actual` for a Kotlin Multiplatform declaration.
+fn multiplatform_role(symbol: &julie_extractors::base::Symbol) -> Option<&str> {
+    symbol
+        .metadata
+        .as_ref()
+        .and_then(|metadata| metadata.get(KOTLIN_MULTIPLATFORM_METADATA_KEY))
+        .and_then(|value| value.as_str())
+}
+
+/// Line suffix for a child symbol. Children merged in from another partial
+/// declaration carry their file path so the location stays unambiguous.
+fn child_location(ctx: &SymbolContext, child: &julie_extractors::base::Symbol) -> String {
+    if child.file_path == ctx.symbol.file_path {
+        format!(":{}", child.start_line)
+    } else {
+        format!(
+            "{}:{}",
+            display_stored_path(&child.file_path),
+            child.start_line
+        )
+    }
 }
 
 fn format_body(out: &mut String, ctx: &SymbolContext, depth: &str) {
@@ -184,10 +359,17 @@ fn format_test_locations(out: &mut String, ctx: &SymbolContext, depth: &str) {
             let quality_tag = extract_quality_tier(&sym.metadata);
             out.push_str(&format!(
                 "  {}:{}  {}{}\n",
-                r.file_path, r.line_number, sym.name, quality_tag
+                display_stored_path(&r.file_path),
+                r.line_number,
+                sym.name,
+                quality_tag
             ));
         } else {
-            out.push_str(&format!("  {}:{}\n", r.file_path, r.line_number));
+            out.push_str(&format!(
+                "  {}:{}\n",
+                display_stored_path(&r.file_path),
+                r.line_number
+            ));
         }
     }
 }
@@ -337,10 +519,17 @@ fn format_callable(out: &mut String, ctx: &SymbolContext, depth: &str) {
                 let kind = sym.kind.to_string();
                 out.push_str(&format!(
                     "  {}  {}:{}  {}\n",
-                    sym.name, r.file_path, r.line_number, kind
+                    sym.name,
+                    display_stored_path(&r.file_path),
+                    r.line_number,
+                    kind
                 ));
             } else {
-                out.push_str(&format!("  {}:{}\n", r.file_path, r.line_number));
+                out.push_str(&format!(
+                    "  {}:{}\n",
+                    display_stored_path(&r.file_path),
+                    r.line_number
+                ));
             }
         }
     }
@@ -380,10 +569,11 @@ fn format_trait_or_interface(out: &mut String, ctx: &SymbolContext, depth: &str)
     if !methods.is_empty() {
         out.push_str(&format!("\nRequired methods ({}):\n", methods.len()));
         for m in &methods {
+            let location = child_location(ctx, m);
             if let Some(sig) = &m.signature {
-                out.push_str(&format!("  {}  :{}\n", sig, m.start_line));
+                out.push_str(&format!("  {}  {}\n", sig, location));
             } else {
-                out.push_str(&format!("  {}  :{}\n", m.name, m.start_line));
+                out.push_str(&format!("  {}  {}\n", m.name, location));
             }
         }
     }
@@ -397,7 +587,9 @@ fn format_trait_or_interface(out: &mut String, ctx: &SymbolContext, depth: &str)
         for imp in &ctx.implementations {
             out.push_str(&format!(
                 "  {}:{}  {}\n",
-                imp.file_path, imp.start_line, imp.name
+                display_stored_path(&imp.file_path),
+                imp.start_line,
+                imp.name
             ));
         }
     }
@@ -445,10 +637,11 @@ fn format_class_or_struct(out: &mut String, ctx: &SymbolContext, depth: &str) {
     if !methods.is_empty() {
         out.push_str(&format!("\nMethods ({}):\n", methods.len()));
         for m in &methods {
+            let location = child_location(ctx, m);
             if let Some(sig) = &m.signature {
-                out.push_str(&format!("  {}  :{}\n", sig, m.start_line));
+                out.push_str(&format!("  {}  {}\n", sig, location));
             } else {
-                out.push_str(&format!("  {}()  :{}\n", m.name, m.start_line));
+                out.push_str(&format!("  {}()  {}\n", m.name, location));
             }
         }
     }
@@ -471,10 +664,16 @@ fn format_class_or_struct(out: &mut String, ctx: &SymbolContext, depth: &str) {
             if let Some(sym) = &r.symbol {
                 out.push_str(&format!(
                     "  {}  {}:{}\n",
-                    sym.name, sym.file_path, sym.start_line
+                    sym.name,
+                    display_stored_path(&sym.file_path),
+                    sym.start_line
                 ));
             } else {
-                out.push_str(&format!("  {}:{}\n", r.file_path, r.line_number));
+                out.push_str(&format!(
+                    "  {}:{}\n",
+                    display_stored_path(&r.file_path),
+                    r.line_number
+                ));
             }
         }
     }
@@ -631,6 +830,29 @@ fn format_generic(out: &mut String, ctx: &SymbolContext, depth: &str) {
     format_body(out, ctx, depth);
 }
 
+/// Files among the symbol, its other declaration sites, implementations and
+/// references that were deleted from disk but are still inside their
+/// soft-delete grace period (see [`crate::deleted_files`]).
+pub fn format_deleted_context(ctx: &SymbolContext, deleted_files: &HashSet<String>) -> String {
+    let definitions = std::iter::once(&ctx.symbol)
+        .chain(&ctx.declaration_sites)
+        .map(|symbol| (symbol.file_path.as_str(), "definition"));
+    let implementations = ctx
+        .implementations
+        .iter()
+        .map(|symbol| (symbol.file_path.as_str(), "implementation"));
+    let references = ctx
+        .incoming
+        .iter()
+        .chain(&ctx.outgoing)
+        .chain(&ctx.test_refs)
+        .map(|r| (r.file_path.as_str(), "reference"));
+    format_deleted_files(
+        definitions.chain(implementations).chain(references),
+        deleted_files,
+    )
+}
+
 /// Format a section of references with depth-aware detail
 fn format_ref_section(
     out: &mut String,
@@ -654,7 +876,10 @@ fn format_ref_section(
     while index < refs.len() {
         let end = same_file_ref_run_end(refs, index);
         if end - index > 1 {
-            out.push_str(&format!("  {}:\n", refs[index].file_path));
+            out.push_str(&format!(
+                "  {}:\n",
+                display_stored_path(&refs[index].file_path)
+            ));
             for r in &refs[index..end] {
                 format_ref_entry(out, r, depth, true);
             }
@@ -680,7 +905,7 @@ fn format_ref_entry(out: &mut String, r: &RefEntry, depth: &str, grouped: bool)
     let location = if grouped {
         format!(":{}", r.line_number)
     } else {
-        format!("{}:{}", r.file_path, r.line_number)
+        format!("{}:{}", display_stored_path(&r.file_path), r.line_number)
     };
 
     match depth {
@@ -744,7 +969,7 @@ fn format_similar_section(out: &mut String, similar: &[SimilarEntry]) {
             "  {:<25} {:.2}  {}:{} ({})\n",
             entry.symbol.name,
             entry.score,
-            entry.symbol.file_path,
+            display_stored_path(&entry.symbol.file_path),
             entry.symbol.start_line,
             kind_vis,
         ));
@@ -2,6 +2,8 @@
 //!
 //! Given a symbol, returns everything an agent needs to understand it in a single call.
 //! Replaces the common 3-4 tool chain of fast_search → get_symbols → fast_refs → Read.
+//! Definitions and references from files deleted from disk but still inside
+//! their soft-delete grace period are listed after each symbol.
 
 pub mod data;
 pub mod formatting;
@@ -11,7 +13,9 @@ use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use tracing::debug;
 
+use crate::deleted_files::read_deleted_files;
 use crate::navigation::resolution::WorkspaceTarget;
+use crate::projects;
 use julie_context::ToolContext;
 use julie_core::mcp_compat::{CallToolResult, CallToolResultExt, Content};
 
@@ -65,6 +69,21 @@ pub struct DeepDiveTool {
     /// Workspace filter: "primary" (default) or workspace ID
     #[serde(default = "default_workspace")]
     pub workspace: Option<String>,
+
+    /// Disambiguate by monorepo project (package name from Cargo.toml, package.json, go.mod or a .NET project)
+    #[serde(default)]
+    pub project: Option<String>,
+
+    /// Select one of several same-named definitions: a `disambiguator` from a previous call's candidate list (`file:line` or symbol id)
+    #[serde(default)]
+    pub disambiguator: Option<String>,
+
+    /// Approximate token budget for each definition's context, replacing the depth's default. Callers, callees and code are cut first; similar symbols are kept
+    #[serde(
+        default,
+        deserialize_with = "julie_core::serde_lenient::deserialize_option_u32_lenient"
+    )]
+    pub max_tokens: Option<u32>,
 }
 
 /// Reference caps by depth level
@@ -88,8 +107,11 @@ impl DeepDiveTool {
 
         let symbol_name = self.symbol.clone();
         let context_file = self.context_file.clone();
+        let project = self.project.clone();
+        let disambiguator = self.disambiguator.clone();
         let depth_owned = depth.to_string();
         let (incoming_cap, outgoing_cap) = ref_caps(depth);
+        let max_tokens = self.max_tokens;
 
         match workspace_target {
             WorkspaceTarget::Target(target_workspace_id) => {
@@ -98,21 +120,24 @@ impl DeepDiveTool {
                     .get_pooled_database_for_workspace(&target_workspace_id)
                     .await?;
 
-                let result = tokio::task::spawn_blocking(move || -> Result<String> {
+                let output = tokio::task::spawn_blocking(move || -> Result<DeepDiveOutput> {
                     let pooled_db = pooled_db.into_read_snapshot()?;
-                    deep_dive_query(
+                    deep_dive_output(
                         &pooled_db,
                         &symbol_name,
                         context_file.as_deref(),
+                        project.as_deref(),
+                        disambiguator.as_deref(),
                         &depth_owned,
                         incoming_cap,
                         outgoing_cap,
+                        max_tokens,
                     )
                 })
                 .await
                 .map_err(|e| anyhow::anyhow!("spawn_blocking error: {}", e))??;
 
-                return Ok(CallToolResult::text_content(vec![Content::text(result)]));
+                return Ok(output.into_result(&self.symbol));
             }
             WorkspaceTarget::Primary => {
                 // Fall through to primary workspace logic below
@@ -123,21 +148,56 @@ impl DeepDiveTool {
         let pooled_db = handler.primary_pooled_database().await?;
 
         // All database work in spawn_blocking (SQLite is synchronous)
-        let result = tokio::task::spawn_blocking(move || -> Result<String> {
+        let output = tokio::task::spawn_blocking(move || -> Result<DeepDiveOutput> {
             let db = pooled_db.into_read_snapshot()?;
-            deep_dive_query(
+            deep_dive_output(
                 &db,
                 &symbol_name,
                 context_file.as_deref(),
+                project.as_deref(),
+                disambiguator.as_deref(),
                 &depth_owned,
                 incoming_cap,
                 outgoing_cap,
+                max_tokens,
             )
         })
         .await
         .map_err(|e| anyhow::anyhow!("spawn_blocking error: {}", e))??;
 
-        Ok(CallToolResult::text_content(vec![Content::text(result)]))
+        Ok(output.into_result(&self.symbol))
+    }
+}
+
+/// A deep dive's text, plus the candidate definitions when the name matched
+/// more than one, so a follow-up call can pick one with `disambiguator`.
+#[derive(Debug)]
+pub struct DeepDiveOutput {
+    pub text: String,
+    pub candidates: Vec<data::DisambiguationCandidate>,
+}
+
+impl DeepDiveOutput {
+    fn text(text: String) -> Self {
+        Self {
+            text,
+            candidates: Vec::new(),
+        }
+    }
+
+    /// Text content, with the candidates as structured content under
+    /// `disambiguation`.
+    pub fn into_result(self, symbol_name: &str) -> CallToolResult {
+        let mut result = CallToolResult::text_content(vec![Content::text(self.text)]);
+        if !self.candidates.is_empty() {
+            result.structured_content = Some(serde_json::json!({
+                "disambiguation": {
+                    "symbol": symbol_name,
+                    "candidates": self.candidates,
+                }
+            }));
+        }
+        result
     }
 }
 
@@ -150,18 +210,106 @@ pub fn deep_dive_query(
     incoming_cap: usize,
     outgoing_cap: usize,
 ) -> Result<String> {
+    deep_dive_query_in_project(
+        db,
+        symbol_name,
+        context_file,
+        None,
+        depth,
+        incoming_cap,
+        outgoing_cap,
+    )
+}
+
+/// [`deep_dive_query`] restricted to definitions inside a monorepo project.
+pub fn deep_dive_query_in_project(
+    db: &julie_core::database::SymbolDatabase,
+    symbol_name: &str,
+    context_file: Option<&str>,
+    project: Option<&str>,
+    depth: &str,
+    incoming_cap: usize,
+    outgoing_cap: usize,
+) -> Result<String> {
+    deep_dive_output(
+        db,
+        symbol_name,
+        context_file,
+        project,
+        None,
+        depth,
+        incoming_cap,
+        outgoing_cap,
+        None,
+    )
+    .map(|output| output.text)
+}
+
+/// [`deep_dive_query_in_project`] narrowed to the definition `disambiguator`
+/// names, returning the candidate list alongside the text. With `max_tokens`,
+/// each definition's context is cut to that budget instead of its depth's.
+#[allow(clippy::too_many_arguments)]
+pub fn deep_dive_output(
+    db: &julie_core::database::SymbolDatabase,
+    symbol_name: &str,
+    context_file: Option<&str>,
+    project: Option<&str>,
+    disambiguator: Option<&str>,
+    depth: &str,
+    incoming_cap: usize,
+    outgoing_cap: usize,
+    max_tokens: Option<u32>,
+) -> Result<DeepDiveOutput> {
+    let token_limit = max_tokens.map_or_else(
+        || formatting::depth_token_limit(depth),
+        |max_tokens| max_tokens as usize,
+    );
     // Step 1: Find the symbol
-    let symbols = data::find_symbol(db, symbol_name, context_file)?;
+    let mut symbols =
+        data::collapse_partial_declarations(data::find_symbol(db, symbol_name, context_file)?);
+    if let Some(requested) = project {
+        let project = projects::resolve_project(db, requested)?;
+        projects::retain_project_symbols(db, &mut symbols, &project)?;
+        if symbols.is_empty() {
+            return Ok(DeepDiveOutput::text(format!(
+                "No symbol found: '{}' in project '{}'\nTry deep_dive without project to see every definition.",
+                symbol_name, project
+            )));
+        }
+    }
 
     if symbols.is_empty() {
-        return Ok(format!(
+        return Ok(DeepDiveOutput::text(format!(
             "No symbol found: '{}'\nTry fast_search(query=\"{}\") for fuzzy matching.",
             symbol_name, symbol_name
-        ));
+        )));
+    }
+
+    if let Some(disambiguator) = disambiguator.filter(|d| !d.trim().is_empty()) {
+        let selected = data::select_disambiguated(symbols.clone(), disambiguator);
+        if selected.len() != 1 {
+            let candidates = data::disambiguation_candidates(db, &symbols)?;
+            let mut output = format!(
+                "No single definition of '{}' matches disambiguator '{}'. Candidates:\n\n",
+                symbol_name,
+                disambiguator.trim()
+            );
+            format_candidates(&mut output, &candidates);
+            return Ok(DeepDiveOutput {
+                text: output,
+                candidates,
+            });
+        }
+        symbols = selected;
     }
 
     // Step 2: Build context for each matching symbol
     let mut output = String::new();
+    let candidates = if symbols.len() > 1 {
+        data::disambiguation_candidates(db, &symbols)?
+    } else {
+        Vec::new()
+    };
 
     // Guard: too many matches → auto-select or return compact disambiguation list
     const DISAMBIGUATION_THRESHOLD: usize = 5;
@@ -171,51 +319,88 @@ pub fn deep_dive_query(
             let ctx = data::build_symbol_context(db, &selected, depth, incoming_cap, outgoing_cap)?;
             let kind = format!("{:?}", selected.kind).to_lowercase();
             output.push_str(&format!(
-                "Auto-selected {} from {} definitions in {} (prefer class/struct over overloads)\n\n",
+                "Auto-selected {} from {} definitions in {} (prefer class/struct over overloads; pass disambiguator for another)\n\n",
                 kind,
                 symbols.len(),
                 selected.file_path,
             ));
-            output.push_str(&formatting::format_symbol_context(&ctx, depth));
-            return Ok(output);
+            output.push_str(&formatting::format_symbol_context_within(
+                &ctx,
+                depth,
+                token_limit,
+            ));
+            output.push_str(&formatting::format_deleted_context(
+                &ctx,
+                &read_deleted_files(db),
+            ));
+            return Ok(DeepDiveOutput {
+                text: output,
+                candidates,
+            });
         }
 
         // Results span multiple files — return compact disambiguation list
         output.push_str(&format!(
-            "Found {} definitions of '{}'. Use context_file to disambiguate.\n\n",
+            "Found {} definitions of '{}'. Pass one of these as disambiguator (or narrow with context_file):\n\n",
             symbols.len(),
             symbol_name
         ));
-        for symbol in &symbols {
-            let kind = format!("{:?}", symbol.kind).to_lowercase();
-            let vis = format!("{:?}", symbol.visibility).to_lowercase();
-            output.push_str(&format!(
-                "  {}:{} ({}, {})\n",
-                symbol.file_path, symbol.start_line, kind, vis
-            ));
-        }
-        return Ok(output);
+        format_candidates(&mut output, &candidates);
+        return Ok(DeepDiveOutput {
+            text: output,
+            candidates,
+        });
     }
 
     if symbols.len() > 1 {
         output.push_str(&format!(
-            "Found {} definitions of '{}'. Use context_file to disambiguate.\n\n",
+            "Found {} definitions of '{}'. Pass one of these as disambiguator (or narrow with context_file):\n\n",
             symbols.len(),
             symbol_name
         ));
+        format_candidates(&mut output, &candidates);
+        output.push('\n');
     }
 
+    let deleted_files = read_deleted_files(db);
     for symbol in &symbols {
         let ctx = data::build_symbol_context(db, symbol, depth, incoming_cap, outgoing_cap)?;
-        let formatted = formatting::format_symbol_context(&ctx, depth);
+        let formatted = formatting::format_symbol_context_within(&ctx, depth, token_limit);
         output.push_str(&formatted);
+        output.push_str(&formatting::format_deleted_context(&ctx, &deleted_files));
 
         if symbols.len() > 1 {
             output.push_str("\n---\n\n");
         }
     }
 
-    Ok(output)
+    Ok(DeepDiveOutput {
+        text: output,
+        candidates,
+    })
+}
+
+/// One line per candidate, led by its disambiguator.
+fn format_candidates(output: &mut String, candidates: &[data::DisambiguationCandidate]) {
+    for candidate in candidates {
+        let mut details = vec![candidate.kind.clone()];
+        details.extend(candidate.visibility.clone());
+        if candidate.disambiguator == candidate.symbol_id {
+            details.push(format!("{}:{}", candidate.file_path, candidate.line));
+        }
+        details.extend(candidate.container.as_ref().map(|c| format!("in {c}")));
+        details.extend(candidate.module.as_ref().map(|m| format!("module {m}")));
+        details.extend(candidate.project.as_ref().map(|p| format!("project {p}")));
+        output.push_str(&format!(
+            "  {} ({})",
+            candidate.disambiguator,
+            details.join(", ")
+        ));
+        if let Some(signature) = &candidate.signature {
+            output.push_str(&format!(" — {signature}"));
+        }
+        output.push('\n');
+    }
 }
 
 /// When the disambiguation threshold is exceeded and all results are in the same file,
@@ -0,0 +1,98 @@
+//! Results from files deleted from disk, shared by the tools.
+//!
+//! A file deleted from disk keeps its symbols, relationships and search
+//! documents until its soft-delete grace period ends (see
+//! `julie_core::database::soft_deletes`). Tools still return those results
+//! but list the files they came from, so the agent knows the locations may
+//! not exist anymore.
+
+use std::collections::HashSet;
+
+use julie_context::ToolContext;
+use julie_core::database::SymbolDatabase;
+use julie_core::paths::display_stored_path;
+use tracing::debug;
+
+use crate::navigation::resolution::WorkspaceTarget;
+use crate::projects::project_database;
+
+/// Files of `workspace_target` deleted from disk and still inside their grace
+/// period. A pool error yields none, so nothing is flagged.
+pub async fn load_deleted_files(
+    handler: &dyn ToolContext,
+    workspace_target: &WorkspaceTarget,
+) -> HashSet<String> {
+    let Ok(db) = project_database(handler, workspace_target).await else {
+        return HashSet::new();
+    };
+    tokio::task::spawn_blocking(move || read_deleted_files(&db))
+        .await
+        .unwrap_or_default()
+}
+
+/// [`load_deleted_files`] for callers already holding the database.
+pub fn read_deleted_files(db: &SymbolDatabase) -> HashSet<String> {
+    match db.soft_deleted_files() {
+        Ok(files) => files.into_keys().collect(),
+        Err(e) => {
+            debug!("Soft-deleted files unavailable: {}", e);
+            HashSet::new()
+        }
+    }
+}
+
+/// List the deleted files among `entries`, each a result's file path and the
+/// singular noun it is counted under. Files keep the order they first appear
+/// in; nothing is written when none of them is deleted.
+///
+/// Output format:
+/// ```text
+/// Deleted from disk (kept until the grace period ends):
+///   src/legacy/users.rs  1 definition, 2 references
+/// ```
+pub fn format_deleted_files<'a>(
+    entries: impl IntoIterator<Item = (&'a str, &'static str)>,
+    deleted_files: &HashSet<String>,
+) -> String {
+    if deleted_files.is_empty() {
+        return String::new();
+    }
+    let mut counts: Vec<(&str, Vec<(&str, usize)>)> = Vec::new();
+    for (path, noun) in entries {
+        if !deleted_files.contains(path) {
+            continue;
+        }
+        let index = match counts.iter().position(|(file, _)| *file == path) {
+            Some(index) => index,
+            None => {
+                counts.push((path, Vec::new()));
+                counts.len() - 1
+            }
+        };
+        let nouns = &mut counts[index].1;
+        match nouns.iter_mut().find(|(counted, _)| *counted == noun) {
+            Some((_, count)) => *count += 1,
+            None => nouns.push((noun, 1)),
+        }
+    }
+    if counts.is_empty() {
+        return String::new();
+    }
+
+    let mut out = String::from("\n\nDeleted from disk (kept until the grace period ends):\n");
+    for (path, nouns) in counts {
+        let parts: Vec<String> = nouns
+            .into_iter()
+            .map(|(noun, count)| {
+                let plural = if count == 1 { "" } else { "s" };
+                format!("{count} {noun}{plural}")
+            })
+            .collect();
+        out.push_str(&format!(
+            "  {}  {}\n",
+            display_stored_path(path),
+            parts.join(", ")
+        ));
+    }
+    out.trim_end().to_string()
+}
@@ -14,10 +14,12 @@ use tracing::debug;
 use crate::navigation::resolution::WorkspaceTarget;
 use julie_context::ToolContext;
 use julie_core::file_utils::secure_path_resolution;
+use julie_core::generated_files::generated_file;
 use julie_core::mcp_compat::CallToolResultExt;
 use julie_core::mcp_compat::{CallToolResult, Content};
 
 use super::EditingTransaction;
+use super::journal::JournalRecorder;
 use super::validation::{
     check_bracket_balance, format_dry_run_diff, format_unified_diff, should_check_balance,
 };
@@ -56,7 +58,7 @@ struct EditApplication {
 
 #[derive(Debug)]
 pub struct PreparedEdit {
-    resolved_str: String,
+    resolved_path: std::path::PathBuf,
     original_content: String,
     application: EditApplication,
     diff: String,
@@ -528,7 +530,16 @@ impl EditFileTool {
 
         let workspace_root = self.resolve_workspace_root(handler).await?;
         let resolved_path = secure_path_resolution(&self.file_path, &workspace_root)?;
-        let resolved_str = resolved_path.to_string_lossy().to_string();
+        if let Some(generated) =
+            julie_core::paths::to_relative_unix_style(&resolved_path, &workspace_root)
+                .ok()
+                .and_then(|relative| generated_file(&workspace_root, &relative))
+        {
+            return Err(edit_file_error(
+                "generated_file",
+                generated.edit_refusal(&self.file_path),
+            ));
+        }
         let original_content = std::fs::read_to_string(&resolved_path)
             .map_err(|error| anyhow!("Cannot read file '{}': {}", self.file_path, error))?;
         let application = apply_edit_with_metrics(
@@ -550,7 +561,7 @@ impl EditFileTool {
         let changed_bytes = changed_region_bytes(&original_content, &application.modified_content);
 
         Ok(PreparedEdit {
-            resolved_str,
+            resolved_path,
             original_content,
             application,
             diff,
@@ -583,7 +594,11 @@ impl EditFileTool {
         metadata
     }
 
-    pub fn call_prepared(&self, prepared: PreparedEdit) -> Result<CallToolResult> {
+    pub async fn call_prepared(
+        &self,
+        handler: &dyn ToolContext,
+        prepared: PreparedEdit,
+    ) -> Result<CallToolResult> {
         if prepared.application.modified_content == prepared.original_content {
             let message = format!(
                 "No changes: edit to '{}' would not modify the file (old_text and new_text resolve to identical content).",
@@ -609,14 +624,28 @@ impl EditFileTool {
         // hash mismatch to trigger symbol re-extraction. Updating the hash here would
         // poison watcher change-detection and leave the index permanently stale.
         #[cfg(any(test, feature = "test-support"))]
-        run_before_commit_hook_for_test(std::path::Path::new(&prepared.resolved_str));
-        let txn = EditingTransaction::begin(&prepared.resolved_str)?;
+        run_before_commit_hook_for_test(&prepared.resolved_path);
+        let txn = EditingTransaction::begin(&prepared.resolved_path)?;
         txn.commit_if_unchanged(
             &prepared.application.modified_content,
             &prepared.original_content,
         )?;
 
         debug!("edit_file applied to {}", self.file_path);
+        let mut journal = JournalRecorder::default();
+        journal.record(
+            &self.file_path,
+            &prepared.original_content,
+            &prepared.application.modified_content,
+        );
+        journal
+            .commit(
+                handler,
+                self.workspace.as_deref(),
+                "edit_file",
+                &format!("edit_file {}", self.file_path),
+            )
+            .await;
         let mut msg = format!("Applied edit to {}:\n\n{}", self.file_path, prepared.diff);
         if let Some(warning) = prepared.balance_warning {
             msg.push_str(&format!("\n\n{}", warning));
@@ -626,7 +655,7 @@ impl EditFileTool {
 
     pub async fn call_tool(&self, handler: &dyn ToolContext) -> Result<CallToolResult> {
         let prepared = self.prepare_edit(handler).await?;
-        self.call_prepared(prepared)
+        self.call_prepared(handler, prepared).await
     }
 }
 
@@ -0,0 +1,111 @@
+//! Recording applied edits in the workspace undo log and restoring them.
+//!
+//! Editing tools gather the files they change in a [`JournalRecorder`] and
+//! store it as one operation once the edit is applied. A failure to record
+//! never fails the edit itself: the files are already written.
+//! [`restore_operation`] undoes an operation, refusing when a file has
+//! changed since so later work is never silently overwritten.
+
+use std::path::Path;
+
+use anyhow::{Result, anyhow};
+use julie_context::ToolContext;
+use julie_core::database::{JournalFile, JournalOperation};
+use julie_core::file_utils::secure_path_resolution;
+use tracing::{debug, warn};
+
+use super::MultiFileTransaction;
+use crate::navigation::resolution::WorkspaceTarget;
+
+/// Files changed by one operation, gathered as it writes them.
+#[derive(Debug, Default)]
+pub struct JournalRecorder {
+    files: Vec<JournalFile>,
+}
+
+impl JournalRecorder {
+    /// Note that `file_path` went from `before` to `after`. A file written
+    /// twice keeps its first `before` and its last `after`.
+    pub fn record(&mut self, file_path: &str, before: &str, after: &str) {
+        match self.files.iter_mut().find(|f| f.file_path == file_path) {
+            Some(file) => file.after = after.to_string(),
+            None => self.files.push(JournalFile {
+                file_path: file_path.to_string(),
+                before: before.to_string(),
+                after: after.to_string(),
+            }),
+        }
+    }
+
+    pub fn files(&self) -> &[JournalFile] {
+        &self.files
+    }
+
+    /// Store the gathered files as one operation in `workspace`'s journal.
+    pub async fn commit(
+        self,
+        handler: &dyn ToolContext,
+        workspace: Option<&str>,
+        kind: &str,
+        summary: &str,
+    ) {
+        if self.files.is_empty() {
+            return;
+        }
+        if let Err(error) = self.store(handler, workspace, kind, summary).await {
+            warn!("Failed to record {kind} in the operation journal: {error}");
+        }
+    }
+
+    async fn store(
+        self,
+        handler: &dyn ToolContext,
+        workspace: Option<&str>,
+        kind: &str,
+        summary: &str,
+    ) -> Result<()> {
+        let workspace_id = match handler.resolve_workspace_target(workspace).await? {
+            WorkspaceTarget::Primary => handler.require_primary_workspace_identity()?,
+            WorkspaceTarget::Target(workspace_id) => workspace_id,
+        };
+        let db = handler.get_database_for_workspace(&workspace_id).await?;
+        let (kind, summary) = (kind.to_string(), summary.to_string());
+        let id = tokio::task::spawn_blocking(move || {
+            let db = db.lock().map_err(|_| anyhow!("database lock poisoned"))?;
+            db.record_operation(&kind, &summary, &self.files)
+        })
+        .await??;
+        debug!("Recorded operation {id} in the journal of {workspace_id}");
+        Ok(())
+    }
+}
+
+/// Write every file of `operation` back to its `before` content, all or
+/// nothing. Fails without touching anything when a file no longer holds the
+/// operation's `after` content.
+pub fn restore_operation(workspace_root: &Path, operation: &JournalOperation) -> Result<()> {
+    let mut resolved = Vec::with_capacity(operation.files.len());
+    let mut changed_since = Vec::new();
+    for file in &operation.files {
+        let path = secure_path_resolution(&file.file_path, workspace_root)?;
+        let current = std::fs::read_to_string(&path).ok();
+        if current.as_deref() != Some(file.after.as_str()) {
+            changed_since.push(file.file_path.as_str());
+        }
+        resolved.push((path, file));
+    }
+    if !changed_since.is_empty() {
+        return Err(anyhow!(
+            "Cannot roll back '{}': modified since it was applied: {}",
+            operation.summary,
+            changed_since.join(", ")
+        ));
+    }
+
+    let mut transaction = MultiFileTransaction::new(&format!("rollback{}", operation.id))?;
+    for (path, file) in &resolved {
+        transaction.add_file(path)?;
+        transaction.set_content(path, &file.before)?;
+    }
+    transaction.commit_all()
+}
@@ -7,6 +7,7 @@
 //! These primitives ensure file safety across all editing tools in Julie.
 
 pub mod edit_file;
+pub mod journal;
 pub mod rewrite_symbol;
 pub mod validation;
 
@@ -110,8 +111,8 @@ pub struct EditingTransaction {
 
 impl EditingTransaction {
     /// Begin a new transaction for a file
-    pub fn begin(file_path: &str) -> Result<Self> {
-        let file_path = PathBuf::from(file_path);
+    pub fn begin(file_path: impl AsRef<Path>) -> Result<Self> {
+        let file_path = file_path.as_ref().to_path_buf();
 
         // Read original content if file exists
         let original_content = if file_path.exists() {
@@ -281,8 +282,8 @@ impl MultiFileTransaction {
     }
 
     /// Add a file to the transaction
-    pub fn add_file(&mut self, file_path: &str) -> Result<()> {
-        let path = PathBuf::from(file_path);
+    pub fn add_file(&mut self, file_path: impl AsRef<Path>) -> Result<()> {
+        let path = file_path.as_ref().to_path_buf();
 
         // Store None when the file doesn't exist so rollback can DELETE it instead
         // of writing an empty placeholder.
@@ -299,13 +300,13 @@ impl MultiFileTransaction {
     }
 
     /// Set new content for a file in the transaction
-    pub fn set_content(&mut self, file_path: &str, content: &str) -> Result<()> {
-        let path = PathBuf::from(file_path);
+    pub fn set_content(&mut self, file_path: impl AsRef<Path>, content: &str) -> Result<()> {
+        let path = file_path.as_ref().to_path_buf();
 
         if !self.files.contains_key(&path) {
             return Err(anyhow::anyhow!(
                 "File not added to transaction: {}",
-                file_path
+                path.display()
             ));
         }
 
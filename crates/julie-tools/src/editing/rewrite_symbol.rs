@@ -8,19 +8,22 @@ use anyhow::{Result, anyhow};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use serde_json::{Value, json};
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use tracing::debug;
 
 use crate::navigation::resolution::{WorkspaceTarget, file_path_matches_suffix};
 use julie_context::ToolContext;
 use julie_core::database::SymbolDatabase;
 use julie_core::file_utils::secure_path_resolution;
+use julie_core::generated_files::generated_file;
 use julie_core::mcp_compat::CallToolResultExt;
 use julie_core::mcp_compat::{CallToolResult, Content};
+use julie_core::parser_pool::ParserPool;
 use julie_extractors::{ExtractorManager, Symbol};
-use tree_sitter::{Node, Parser, Tree};
+use tree_sitter::{Node, Tree};
 
 use super::EditingTransaction;
+use super::journal::JournalRecorder;
 use super::validation::{
     check_bracket_balance, format_dry_run_diff, format_unified_diff, should_check_balance,
 };
@@ -138,7 +141,7 @@ struct LiveSymbolContext {
 
 pub struct PreparedRewrite {
     indexed_symbol: Symbol,
-    resolved_path: String,
+    resolved_path: PathBuf,
     original_content: String,
     modified_content: String,
     span_context: SpanContext,
@@ -293,9 +296,7 @@ fn insert_after_line(source: &str, byte_index: usize, new_content: &str) -> Resu
 fn parse_live_tree(file_path: &str, content: &str) -> Result<Tree> {
     let language = julie_extractors::language::detect_language_for_source(file_path, content)
         .ok_or_else(|| anyhow!("Could not detect language for '{}'", file_path))?;
-    let ts_language = julie_extractors::language::get_tree_sitter_language(&language)?;
-    let mut parser = Parser::new();
-    parser.set_language(&ts_language)?;
+    let mut parser = ParserPool::global().checkout(&language)?;
     parser
         .parse(content, None)
         .ok_or_else(|| anyhow!("Failed to parse {} file '{}'", language, file_path))
@@ -333,7 +334,9 @@ fn live_symbol_context(
     workspace_root: &Path,
 ) -> Result<LiveSymbolContext> {
     let extractor = ExtractorManager::new();
-    let live_symbols = extractor.extract_symbols(file_path, content, workspace_root)?;
+    let mut live_symbols = extractor.extract_symbols(file_path, content, workspace_root)?;
+    // The index stores stable IDs; derive the same ones for the live parse.
+    julie_core::symbol_ids::assign_stable_symbol_ids(&mut live_symbols);
     let live_symbol = if let Some(symbol) = live_symbols
         .iter()
         .find(|symbol| symbol.id == indexed_symbol.id)
@@ -726,9 +729,14 @@ impl RewriteSymbolTool {
         }
 
         let indexed_symbol = matches.into_iter().next().expect("one symbol");
+        if let Some(generated) = generated_file(&target.workspace_root, &indexed_symbol.file_path) {
+            return Err(rewrite_symbol_error(
+                "generated_file",
+                generated.edit_refusal(&indexed_symbol.file_path),
+            ));
+        }
         let resolved_path =
             secure_path_resolution(&indexed_symbol.file_path, &target.workspace_root)?;
-        let resolved_str = resolved_path.to_string_lossy().to_string();
 
         let original_content = std::fs::read_to_string(&resolved_path).map_err(|error| {
             anyhow!("Cannot read file '{}': {}", indexed_symbol.file_path, error)
@@ -820,7 +828,7 @@ impl RewriteSymbolTool {
             super::edit_file::changed_region_bytes(&original_content, &modified_content);
         Ok(PreparedRewrite {
             indexed_symbol,
-            resolved_path: resolved_str,
+            resolved_path,
             original_content,
             modified_content,
             span_context,
@@ -864,7 +872,11 @@ impl RewriteSymbolTool {
         metadata
     }
 
-    pub fn call_prepared(&self, application: PreparedRewrite) -> Result<CallToolResult> {
+    pub async fn call_prepared(
+        &self,
+        handler: &dyn ToolContext,
+        application: PreparedRewrite,
+    ) -> Result<CallToolResult> {
         if application.modified_content == application.original_content {
             let message = format!(
                 "No changes: {} with supplied content would not modify the file. Symbol '{}' at {}:{}-{} is already in the requested state.",
@@ -911,6 +923,23 @@ impl RewriteSymbolTool {
             "rewrite_symbol {} applied to {}",
             self.operation, application.indexed_symbol.file_path
         );
+        let mut journal = JournalRecorder::default();
+        journal.record(
+            &application.indexed_symbol.file_path,
+            &application.original_content,
+            &application.modified_content,
+        );
+        journal
+            .commit(
+                handler,
+                self.workspace.as_deref(),
+                "rewrite_symbol",
+                &format!(
+                    "rewrite_symbol {} '{}' in {}",
+                    self.operation, self.symbol, application.indexed_symbol.file_path
+                ),
+            )
+            .await;
         let mut message = format!(
             "Applied {} on '{}' in {}:\n\n{}",
             self.operation, self.symbol, application.indexed_symbol.file_path, application.diff
@@ -923,6 +952,6 @@ impl RewriteSymbolTool {
 
     pub async fn call_tool(&self, handler: &dyn ToolContext) -> Result<CallToolResult> {
         let application = self.prepare_rewrite(handler).await?;
-        self.call_prepared(application)
+        self.call_prepared(handler, application).await
     }
 }
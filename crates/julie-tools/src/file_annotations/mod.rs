@@ -0,0 +1,130 @@
+//! `get_file_annotations`: every symbol in one file with its range, kind and
+//! reference count, for editor extensions that decorate the open file.
+//!
+//! The data comes from a single joined query
+//! ([`SymbolDatabase::get_file_annotations`]), so a file with hundreds of
+//! symbols costs one round trip instead of one `fast_refs` call per symbol.
+
+use std::collections::HashMap;
+
+use anyhow::{Result, anyhow};
+use julie_context::{ToolContext, WorkspaceTarget};
+use julie_core::database::{FileAnnotations, SymbolDatabase};
+use julie_core::mcp_compat::{CallToolResult, CallToolResultExt, Content};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum FileAnnotationsFormat {
+    #[default]
+    Json,
+    Compact,
+}
+
+#[derive(Debug, Clone, Default, Deserialize, Serialize, JsonSchema)]
+pub struct GetFileAnnotationsTool {
+    /// File to annotate (workspace-relative or absolute).
+    pub file_path: String,
+    /// Workspace target. Use `primary` or a workspace id opened through `manage_workspace`.
+    #[serde(default)]
+    pub workspace: Option<String>,
+    /// `json` (default) for editor integrations, `compact` for a readable outline.
+    #[serde(default)]
+    pub format: FileAnnotationsFormat,
+}
+
+impl GetFileAnnotationsTool {
+    pub async fn call_tool(&self, handler: &dyn ToolContext) -> Result<CallToolResult> {
+        let workspace_target = handler
+            .resolve_workspace_target(self.workspace.as_deref())
+            .await?;
+        self.call_tool_with_target(handler, &workspace_target).await
+    }
+
+    pub async fn call_tool_with_target(
+        &self,
+        handler: &dyn ToolContext,
+        workspace_target: &WorkspaceTarget,
+    ) -> Result<CallToolResult> {
+        if self.file_path.trim().is_empty() {
+            return Err(anyhow!("file_path must not be empty"));
+        }
+        let (database, workspace_root) = match workspace_target {
+            WorkspaceTarget::Primary => (
+                handler.primary_pooled_database().await?,
+                handler.require_primary_workspace_root()?,
+            ),
+            WorkspaceTarget::Target(workspace_id) => (
+                handler
+                    .get_pooled_database_for_workspace(workspace_id)
+                    .await?,
+                handler.get_workspace_root_for_target(workspace_id).await?,
+            ),
+        };
+        let mut tool = self.clone();
+        tool.file_path =
+            julie_core::paths::resolve_workspace_file_input(&self.file_path, &workspace_root)?
+                .relative_query_path;
+
+        let rendered = tokio::task::spawn_blocking(move || -> Result<String> {
+            let database = database.into_read_snapshot()?;
+            let annotations = tool.execute(&database)?;
+            tool.render(&annotations)
+        })
+        .await
+        .map_err(|error| anyhow!("get_file_annotations task failed: {error}"))??;
+        Ok(CallToolResult::text_content(vec![Content::text(rendered)]))
+    }
+
+    /// Load the annotations. `file_path` must already be workspace-relative.
+    pub fn execute(&self, db: &SymbolDatabase) -> Result<FileAnnotations> {
+        db.get_file_annotations(&self.file_path)?
+            .ok_or_else(|| anyhow!("File not indexed: {}", self.file_path))
+    }
+
+    pub fn render(&self, annotations: &FileAnnotations) -> Result<String> {
+        match self.format {
+            FileAnnotationsFormat::Json => Ok(serde_json::to_string(&json!({
+                "schema_version": 1,
+                "file": annotations,
+            }))?),
+            FileAnnotationsFormat::Compact => {
+                let mut lines = vec![format!(
+                    "{} ({}, {} lines, modified {}, indexed {})",
+                    annotations.file_path,
+                    annotations.language,
+                    annotations.line_count,
+                    annotations.last_modified,
+                    annotations.last_indexed
+                )];
+                // Symbols arrive in source order, so parents precede children.
+                let mut depths: HashMap<&str, usize> = HashMap::new();
+                for symbol in &annotations.symbols {
+                    let depth = symbol
+                        .parent_id
+                        .as_deref()
+                        .and_then(|parent| depths.get(parent))
+                        .map_or(0, |depth| depth + 1);
+                    depths.insert(&symbol.id, depth);
+                    lines.push(format!(
+                        "{}{}:{}-{}:{} {} {} refs={}",
+                        "  ".repeat(depth + 1),
+                        symbol.start_line,
+                        symbol.start_column,
+                        symbol.end_line,
+                        symbol.end_column,
+                        symbol.kind,
+                        symbol.name,
+                        symbol.reference_count
+                    ));
+                }
+                if annotations.symbols.is_empty() {
+                    lines.push("  No symbols".to_string());
+                }
+                Ok(lines.join("\n"))
+            }
+        }
+    }
+}
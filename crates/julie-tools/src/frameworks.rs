@@ -0,0 +1,97 @@
+//! The `framework` filter shared by the tools.
+//!
+//! The post-index analysis pass detects each project's frameworks and labels
+//! the symbols playing a framework role (see
+//! `julie_index::analysis::frameworks`). With `framework` set, tools keep only
+//! the symbols with a matching role: either one role (`spring_bean`) or every
+//! role of one framework (`spring`).
+
+use std::collections::HashMap;
+
+use anyhow::{Result, bail};
+use julie_context::ToolContext;
+use julie_core::database::FrameworkSymbol;
+use julie_index::analysis::frameworks::{FRAMEWORK_ROLES, FRAMEWORKS};
+
+use crate::navigation::resolution::WorkspaceTarget;
+use crate::projects::project_database;
+use crate::search::trace::SearchHit;
+
+/// A resolved `framework` filter.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FrameworkFilter {
+    /// Every role of one framework, by framework key.
+    Framework(&'static str),
+    /// One role, by role key.
+    Role(&'static str),
+}
+
+impl FrameworkFilter {
+    pub fn matches(&self, symbol: &FrameworkSymbol) -> bool {
+        match self {
+            Self::Framework(key) => symbol.framework == *key,
+            Self::Role(key) => symbol.role == *key,
+        }
+    }
+}
+
+/// The framework or role named `requested`, accepting keys and display
+/// names alike ("spring_bean", "Spring bean", "ASP.NET"), or an error
+/// listing the valid values.
+pub fn resolve_framework_filter(requested: &str) -> Result<FrameworkFilter> {
+    let normalized: String = requested
+        .trim()
+        .to_ascii_lowercase()
+        .chars()
+        .filter(|c| *c != '.')
+        .map(|c| if c == ' ' || c == '-' { '_' } else { c })
+        .collect();
+    if let Some(framework) = FRAMEWORKS.iter().find(|f| f.key == normalized) {
+        return Ok(FrameworkFilter::Framework(framework.key));
+    }
+    if let Some(role) = FRAMEWORK_ROLES.iter().find(|r| r.key == normalized) {
+        return Ok(FrameworkFilter::Role(role.key));
+    }
+    let frameworks: Vec<&str> = FRAMEWORKS.iter().map(|f| f.key).collect();
+    let roles: Vec<&str> = FRAMEWORK_ROLES.iter().map(|r| r.key).collect();
+    bail!(
+        "Unknown framework '{}'. Frameworks: {}. Roles: {}",
+        requested.trim(),
+        frameworks.join(", "),
+        roles.join(", ")
+    )
+}
+
+/// Framework role of each hit's symbol, keyed by symbol id.
+pub async fn load_hit_roles(
+    hits: &[SearchHit],
+    workspace_target: &WorkspaceTarget,
+    handler: &dyn ToolContext,
+) -> Result<HashMap<String, FrameworkSymbol>> {
+    let ids: Vec<String> = hits
+        .iter()
+        .filter_map(|hit| hit.symbol_id.clone())
+        .collect();
+    if ids.is_empty() {
+        return Ok(HashMap::new());
+    }
+    let db = project_database(handler, workspace_target).await?;
+    tokio::task::spawn_blocking(move || db.framework_roles_for_symbols(&ids)).await?
+}
+
+/// Keep the hits whose symbol plays a role matching `filter`. File and line
+/// hits carry no symbol and are dropped. Returns how many were removed.
+pub fn retain_framework_hits(
+    hits: &mut Vec<SearchHit>,
+    roles: &HashMap<String, FrameworkSymbol>,
+    filter: FrameworkFilter,
+) -> usize {
+    let before = hits.len();
+    hits.retain(|hit| {
+        hit.symbol_id
+            .as_ref()
+            .and_then(|id| roles.get(id))
+            .is_some_and(|role| filter.matches(role))
+    });
+    before - hits.len()
+}
@@ -3,7 +3,12 @@
 //! Renders pivots (with code bodies or signatures), neighbors (with signatures or names),
 //! a file map, and centrality hints into a structured text response.
 
+use std::collections::HashSet;
+
+use julie_core::paths::display_stored_path;
+
 use super::allocation::{Allocation, NeighborMode};
+use crate::deleted_files::format_deleted_files;
 use crate::spillover::more_available_marker;
 
 /// All data needed to format a get_context response.
@@ -146,7 +151,10 @@ fn format_context_readable(data: &ContextData) -> String {
             .unwrap_or_default();
         out.push_str(&format!(
             "{}:{} ({}){}\n",
-            pivot.file_path, pivot.start_line, pivot.kind, quality_tag
+            display_stored_path(&pivot.file_path),
+            pivot.start_line,
+            pivot.kind,
+            quality_tag
         ));
         let label = centrality_label(pivot.reference_score);
         out.push_str(&format!("  Centrality: {}\n", label));
@@ -222,7 +230,11 @@ fn format_context_compact(data: &ContextData) -> String {
             .unwrap_or_default();
         out.push_str(&format!(
             "PIVOT {} {}:{} kind={}{}\n",
-            pivot.name, pivot.file_path, pivot.start_line, pivot.kind, quality_tag
+            pivot.name,
+            display_stored_path(&pivot.file_path),
+            pivot.start_line,
+            pivot.kind,
+            quality_tag
         ));
         for line in pivot.content.lines() {
             out.push_str("  ");
@@ -256,6 +268,29 @@ fn format_context_compact(data: &ContextData) -> String {
     out
 }
 
+/// Follow a formatted response with the files among its pivots and
+/// neighbors that were deleted from disk but are still inside their
+/// soft-delete grace period (see [`crate::deleted_files`]).
+pub fn with_deleted_files(
+    output: String,
+    data: &ContextData,
+    deleted_files: &HashSet<String>,
+) -> String {
+    let pivots = data
+        .pivots
+        .iter()
+        .map(|pivot| (pivot.file_path.as_str(), "pivot"));
+    let neighbors = data
+        .neighbors
+        .iter()
+        .map(|neighbor| (neighbor.file_path.as_str(), "neighbor"));
+    let section = format_deleted_files(pivots.chain(neighbors), deleted_files);
+    if section.is_empty() {
+        return output;
+    }
+    format!("{}{}\n", output.trim_end(), section)
+}
+
 pub fn format_neighbor_rows(entries: &[NeighborEntry], mode: &NeighborMode) -> Vec<String> {
     grouped_neighbor_rows(entries, mode, NeighborRowStyle::Readable)
 }
@@ -314,7 +349,10 @@ fn format_neighbor_row(
         (NeighborRowStyle::Readable, NeighborMode::SignatureAndDoc) => {
             let mut row = format!(
                 "{} {}:{} ({})",
-                neighbor.name, neighbor.file_path, neighbor.start_line, neighbor.kind
+                neighbor.name,
+                display_stored_path(&neighbor.file_path),
+                neighbor.start_line,
+                neighbor.kind
             );
             if let Some(signature) = &neighbor.signature {
                 row.push_str(&format!("\n   sig: {signature}"));
@@ -328,13 +366,20 @@ fn format_neighbor_row(
             let signature = neighbor.signature.as_deref().unwrap_or(&neighbor.name);
             format!(
                 "{} {}:{} ({})\n   sig: {}",
-                neighbor.name, neighbor.file_path, neighbor.start_line, neighbor.kind, signature
+                neighbor.name,
+                display_stored_path(&neighbor.file_path),
+                neighbor.start_line,
+                neighbor.kind,
+                signature
             )
         }
         (NeighborRowStyle::Readable, NeighborMode::NameAndLocation) => {
             format!(
                 "{} {}:{} ({})",
-                neighbor.name, neighbor.file_path, neighbor.start_line, neighbor.kind
+                neighbor.name,
+                display_stored_path(&neighbor.file_path),
+                neighbor.start_line,
+                neighbor.kind
             )
         }
         (NeighborRowStyle::Compact, NeighborMode::SignatureAndDoc)
@@ -342,13 +387,20 @@ fn format_neighbor_row(
             let sig = neighbor.signature.as_deref().unwrap_or(&neighbor.name);
             format!(
                 "NEIGHBOR {} {}:{} kind={} sig={}",
-                neighbor.name, neighbor.file_path, neighbor.start_line, neighbor.kind, sig
+                neighbor.name,
+                display_stored_path(&neighbor.file_path),
+                neighbor.start_line,
+                neighbor.kind,
+                sig
             )
         }
         (NeighborRowStyle::Compact, NeighborMode::NameAndLocation) => {
             format!(
                 "NEIGHBOR {} {}:{} kind={}",
-                neighbor.name, neighbor.file_path, neighbor.start_line, neighbor.kind
+                neighbor.name,
+                display_stored_path(&neighbor.file_path),
+                neighbor.start_line,
+                neighbor.kind
             )
         }
     }
@@ -359,7 +411,7 @@ fn format_neighbor_group(
     mode: &NeighborMode,
     style: NeighborRowStyle,
 ) -> String {
-    let file_path = &entries[0].file_path;
+    let file_path = display_stored_path(&entries[0].file_path);
     let mut row = match style {
         NeighborRowStyle::Readable => format!("{file_path}:"),
         NeighborRowStyle::Compact => format!("NEIGHBORS {file_path}"),
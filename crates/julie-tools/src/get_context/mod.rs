@@ -33,7 +33,7 @@ pub struct GetContextTool {
     /// Search query (text or pattern)
     pub query: String,
 
-    /// Token budget override (default: auto-scaled 2000-4000 based on result count). Set higher for broad exploration, lower for focused queries
+    /// Token budget override (default: `context.max_tokens` from .julie/config.toml, else auto-scaled 2000-4000 based on result count). Set higher for broad exploration, lower for focused queries
     #[serde(
         default,
         deserialize_with = "julie_core::serde_lenient::deserialize_option_u32_lenient"
@@ -91,6 +91,10 @@ pub struct GetContextTool {
         deserialize_with = "julie_core::serde_lenient::deserialize_option_bool_lenient"
     )]
     pub prefer_tests: Option<bool>,
+
+    /// Monorepo project (package name) to draw pivots from. Neighbors may still come from other projects.
+    #[serde(default)]
+    pub project: Option<String>,
 }
 
 impl GetContextTool {
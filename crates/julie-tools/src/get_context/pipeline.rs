@@ -14,10 +14,12 @@ use super::second_hop::{merge_expansions, select_second_hop_seeds, should_expand
 use super::task_signals::{
     TaskSignals, hydrate_failing_test_links, merge_task_signal_seed_results,
 };
+use crate::deleted_files::read_deleted_files;
 use crate::navigation::resolution::WorkspaceTarget;
 use crate::spillover::{SpilloverFormat, SpilloverStore};
 use julie_context::ToolContext;
 use julie_core::database::SymbolDatabase;
+use julie_core::workspace_settings::WorkspaceSettingsRegistry;
 
 /// Run the full get_context pipeline: search → rank → expand → allocate → format.
 pub fn run_pipeline(
@@ -70,7 +72,9 @@ pub fn run_pipeline_with_options(
 ) -> Result<String> {
     use super::allocation::TokenBudget;
     use super::entries::{build_neighbor_entries, build_pivot_entries};
-    use super::formatting::{ContextData, format_context_with_mode, format_neighbor_rows};
+    use super::formatting::{
+        ContextData, format_context_with_mode, format_neighbor_rows, with_deleted_files,
+    };
     use julie_index::search::index::SearchFilter;
 
     let mut resolved_signals = task_signals.cloned().unwrap_or_default();
@@ -81,6 +85,7 @@ pub fn run_pipeline_with_options(
         kind: None,
         file_pattern,
         exclude_tests: false,
+        include_generated: false,
     };
     let profile = julie_index::search::weights::SearchWeightProfile::get_context();
     // Prefer a precomputed embedding so the sidecar round-trip (up to 30 s)
@@ -88,16 +93,34 @@ pub fn run_pipeline_with_options(
     let effective_embedding = precomputed_embedding.or_else(|| {
         julie_index::search::hybrid::compute_query_embedding_for_hybrid(query, embedding_provider)
     });
+    // A project filter discards candidates after ranking; fetch extra.
+    let candidate_limit = if resolved_signals.project.is_some() {
+        120
+    } else {
+        30
+    };
     let mut search_results = julie_index::search::hybrid::hybrid_search_with_embedding(
         query,
         &filter,
-        30,
+        candidate_limit,
         search_index,
         db,
         effective_embedding,
         Some(profile),
     )?;
     merge_task_signal_seed_results(&mut search_results.results, db, &filter, &resolved_signals)?;
+    if let Some(requested) = resolved_signals.project.as_deref() {
+        let project = crate::projects::resolve_project(db, requested)?;
+        let paths: Vec<String> = search_results
+            .results
+            .iter()
+            .map(|result| result.file_path.clone())
+            .collect();
+        let projects = db.projects_for_files(&paths)?;
+        search_results
+            .results
+            .retain(|result| projects.get(&result.file_path) == Some(&project));
+    }
     let output_format = super::formatting::OutputFormat::from_option(format.as_deref());
 
     if search_results.results.is_empty() {
@@ -199,7 +222,11 @@ pub fn run_pipeline_with_options(
         spillover_handle,
     };
 
-    Ok(format_context_with_mode(&context_data, output_format))
+    Ok(with_deleted_files(
+        format_context_with_mode(&context_data, output_format),
+        &context_data,
+        &read_deleted_files(db),
+    ))
 }
 
 /// Handler entry point: extracts DB and SearchIndex from handler, delegates to run_pipeline.
@@ -210,6 +237,21 @@ pub async fn run(tool: &GetContextTool, handler: &dyn ToolContext) -> Result<Str
     run_with_target(tool, handler, workspace_target).await
 }
 
+/// `context.max_tokens` from the target workspace's `.julie/config.toml`.
+async fn configured_max_tokens(
+    handler: &dyn ToolContext,
+    workspace_target: &WorkspaceTarget,
+) -> Option<u32> {
+    let root = match workspace_target {
+        WorkspaceTarget::Primary => handler.require_primary_workspace_root().ok()?,
+        WorkspaceTarget::Target(id) => handler.get_workspace_root_for_target(id).await.ok()?,
+    };
+    WorkspaceSettingsRegistry::global()
+        .get(&root)
+        .context
+        .max_tokens
+}
+
 /// Same as `run`, but uses a workspace target the caller has already resolved.
 /// Tool wrappers in `src/handler/tools/` call this so the workspace is resolved
 /// exactly once per request (used for both metrics attribution and the actual
@@ -220,7 +262,10 @@ pub async fn run_with_target(
     workspace_target: WorkspaceTarget,
 ) -> Result<String> {
     let query = tool.query.clone();
-    let max_tokens = tool.max_tokens;
+    let max_tokens = match tool.max_tokens {
+        Some(max_tokens) => Some(max_tokens),
+        None => configured_max_tokens(handler, &workspace_target).await,
+    };
     let language = tool.language.clone();
     let file_pattern = tool.file_pattern.clone();
     let format = tool.format.clone();
@@ -19,6 +19,9 @@ pub struct TaskSignals {
     pub failing_test_linked_symbol_ids: HashSet<String>,
     pub max_hops: u32,
     pub prefer_tests: bool,
+    /// Monorepo project the pivots must come from. A filter, not a ranking
+    /// signal, so it does not count toward [`TaskSignals::is_empty`].
+    pub project: Option<String>,
 }
 
 impl TaskSignals {
@@ -44,6 +47,7 @@ impl TaskSignals {
             failing_test_linked_symbol_ids: HashSet::new(),
             max_hops: tool.max_hops.unwrap_or(1),
             prefer_tests: tool.prefer_tests.unwrap_or(false),
+            project: tool.project.clone(),
         }
     }
 
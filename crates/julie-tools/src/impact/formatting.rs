@@ -2,6 +2,7 @@ use crate::impact::LikelyTests;
 use crate::impact::ranking::RankedImpact;
 use crate::impact::seed::SeedContext;
 use crate::spillover::{SpilloverFormat, SpilloverStore, more_available_marker};
+use julie_core::paths::display_stored_path;
 
 /// Extra context that shapes the blast-radius header line.
 ///
@@ -191,12 +192,16 @@ fn same_file_impact_run_end(impacts: &[RankedImpact], start: usize) -> usize {
 fn format_impact_row(impact: &RankedImpact, rank: usize) -> String {
     format!(
         "{}. {}  {}:{}\n   why: {}",
-        rank, impact.symbol.name, impact.symbol.file_path, impact.symbol.start_line, impact.why
+        rank,
+        impact.symbol.name,
+        display_stored_path(&impact.symbol.file_path),
+        impact.symbol.start_line,
+        impact.why
     )
 }
 
 fn format_impact_group(impacts: &[RankedImpact], start_rank: usize) -> String {
-    let file_path = &impacts[0].symbol.file_path;
+    let file_path = display_stored_path(&impacts[0].symbol.file_path);
     let mut block = format!("{file_path}:");
     for (offset, impact) in impacts.iter().enumerate() {
         block.push_str(&format!(
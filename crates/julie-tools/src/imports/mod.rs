@@ -0,0 +1,249 @@
+//! `fast_imports`: where a module is imported and what it exports.
+//!
+//! The target is a workspace file or a library name. For a file, its exports
+//! (export symbols and public top-level declarations) are listed with every
+//! import statement that resolves to it; for a library, every import of it.
+//! Resolution follows each language's module rules (see
+//! [`julie_index::analysis::module_imports`]), so relative, aliased and
+//! wildcard imports are all found. Importers are grouped by the monorepo
+//! project that owns them.
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use anyhow::{Result, anyhow};
+use julie_context::{ToolContext, WorkspaceTarget};
+use julie_core::database::SymbolDatabase;
+use julie_core::mcp_compat::{CallToolResult, CallToolResultExt, Content};
+use julie_extractors::{SymbolKind, Visibility};
+use julie_index::analysis::module_imports::{ImportSite, ImportTarget, find_importers};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// Importers listed when `limit` is not given.
+const DEFAULT_LIMIT: u32 = 100;
+
+/// Heading for importers outside every detected project.
+const NO_PROJECT: &str = "(no project)";
+
+fn default_limit() -> u32 {
+    DEFAULT_LIMIT
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
+pub struct FastImportsTool {
+    /// File (workspace-relative or absolute) or library name (`lodash`,
+    /// `requests`, `github.com/gin-gonic/gin`).
+    pub target: String,
+    /// Workspace target. Use `primary` or a workspace id opened through `manage_workspace`.
+    #[serde(default)]
+    pub workspace: Option<String>,
+    /// Most import statements to list (default 100). The totals always count all of them.
+    #[serde(default = "default_limit")]
+    pub limit: u32,
+}
+
+/// One exported name of the target file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ModuleExport {
+    pub name: String,
+    pub kind: String,
+    pub line: u32,
+}
+
+/// Everything `fast_imports` reports about one target.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ImportsReport {
+    /// Workspace-relative file, or the library name as given.
+    pub target: String,
+    /// File language; `None` when the target is a library.
+    pub language: Option<String>,
+    pub exports: Vec<ModuleExport>,
+    /// Import sites by project name, each in file and line order.
+    pub importers: BTreeMap<String, Vec<ImportSite>>,
+}
+
+impl ImportsReport {
+    pub fn import_count(&self) -> usize {
+        self.importers.values().map(Vec::len).sum()
+    }
+
+    pub fn importing_files(&self) -> usize {
+        self.importers
+            .values()
+            .flatten()
+            .map(|site| site.file_path.as_str())
+            .collect::<BTreeSet<_>>()
+            .len()
+    }
+}
+
+impl FastImportsTool {
+    pub async fn call_tool(&self, handler: &dyn ToolContext) -> Result<CallToolResult> {
+        let workspace_target = handler
+            .resolve_workspace_target(self.workspace.as_deref())
+            .await?;
+        self.call_tool_with_target(handler, &workspace_target).await
+    }
+
+    pub async fn call_tool_with_target(
+        &self,
+        handler: &dyn ToolContext,
+        workspace_target: &WorkspaceTarget,
+    ) -> Result<CallToolResult> {
+        if self.target.trim().is_empty() {
+            return Err(anyhow!("target must not be empty"));
+        }
+        let (database, workspace_root) = match workspace_target {
+            WorkspaceTarget::Primary => (
+                handler.primary_pooled_database().await?,
+                handler.require_primary_workspace_root()?,
+            ),
+            WorkspaceTarget::Target(workspace_id) => (
+                handler
+                    .get_pooled_database_for_workspace(workspace_id)
+                    .await?,
+                handler.get_workspace_root_for_target(workspace_id).await?,
+            ),
+        };
+        // A target that is not a path inside the workspace is a library name.
+        let relative_path =
+            julie_core::paths::resolve_workspace_file_input(self.target.trim(), &workspace_root)
+                .ok()
+                .map(|resolution| resolution.relative_query_path);
+        let tool = self.clone();
+
+        let rendered = tokio::task::spawn_blocking(move || -> Result<String> {
+            let database = database.into_read_snapshot()?;
+            let report = tool.execute(&database, relative_path.as_deref())?;
+            Ok(tool.render(&report))
+        })
+        .await
+        .map_err(|error| anyhow!("fast_imports task failed: {error}"))??;
+        Ok(CallToolResult::text_content(vec![Content::text(rendered)]))
+    }
+
+    /// Build the report. `relative_path` is the target resolved against the
+    /// workspace root; it is used when that file is indexed, and the target
+    /// is otherwise taken for a library name.
+    pub fn execute(
+        &self,
+        db: &SymbolDatabase,
+        relative_path: Option<&str>,
+    ) -> Result<ImportsReport> {
+        let indexed = match relative_path {
+            Some(path) => db.get_file_hash(path)?.is_some().then_some(path),
+            None => None,
+        };
+        let (target, language, exports, sites) = match indexed {
+            Some(path) => {
+                let symbols = db.get_symbols_for_file(path)?;
+                let language = db
+                    .get_file_languages_by_paths(&[path])?
+                    .remove(path)
+                    .unwrap_or_else(|| "unknown".to_string());
+                let mut exports: Vec<ModuleExport> = symbols
+                    .iter()
+                    .filter(|symbol| match symbol.kind {
+                        SymbolKind::Export => true,
+                        SymbolKind::Import => false,
+                        _ => {
+                            symbol.parent_id.is_none()
+                                && matches!(symbol.visibility, Some(Visibility::Public))
+                        }
+                    })
+                    .map(|symbol| ModuleExport {
+                        name: symbol.name.clone(),
+                        kind: symbol.kind.to_string(),
+                        line: symbol.start_line,
+                    })
+                    .collect();
+                exports.sort_by(|a, b| a.line.cmp(&b.line).then_with(|| a.name.cmp(&b.name)));
+                let sites = find_importers(db, ImportTarget::File(path))?;
+                (path.to_string(), Some(language), exports, sites)
+            }
+            None => {
+                let name = self.target.trim();
+                let sites = find_importers(db, ImportTarget::Library(name))?;
+                (name.to_string(), None, Vec::new(), sites)
+            }
+        };
+
+        let files: Vec<String> = sites.iter().map(|site| site.file_path.clone()).collect();
+        let projects = db.projects_for_files(&files)?;
+        let mut importers: BTreeMap<String, Vec<ImportSite>> = BTreeMap::new();
+        for site in sites {
+            let project = projects
+                .get(&site.file_path)
+                .cloned()
+                .unwrap_or_else(|| NO_PROJECT.to_string());
+            importers.entry(project).or_default().push(site);
+        }
+        Ok(ImportsReport {
+            target,
+            language,
+            exports,
+            importers,
+        })
+    }
+
+    pub fn render(&self, report: &ImportsReport) -> String {
+        let mut lines = Vec::new();
+        match &report.language {
+            Some(language) => {
+                lines.push(format!("{} ({language})", report.target));
+                lines.push(format!("Exports ({}):", report.exports.len()));
+                for export in &report.exports {
+                    lines.push(format!(
+                        "  {} {} L{}",
+                        export.kind, export.name, export.line
+                    ));
+                }
+                if report.exports.is_empty() {
+                    lines.push("  No exported symbols".to_string());
+                }
+            }
+            None => lines.push(format!("{} (library)", report.target)),
+        }
+
+        let total = report.import_count();
+        if total == 0 {
+            lines.push("Not imported anywhere in this workspace".to_string());
+            return lines.join("\n");
+        }
+        lines.push(format!(
+            "Imported by {} files ({total} statements) in {} projects:",
+            report.importing_files(),
+            report.importers.len()
+        ));
+        let mut shown = 0;
+        let limit = self.limit as usize;
+        for (project, sites) in &report.importers {
+            if shown == limit {
+                break;
+            }
+            lines.push(format!("  {project} ({})", sites.len()));
+            for site in sites.iter().take(limit - shown) {
+                shown += 1;
+                let bindings = if site.bindings.is_empty() {
+                    String::new()
+                } else {
+                    format!(" [{}]", site.bindings.join(", "))
+                };
+                lines.push(format!(
+                    "    {}:{} {}{bindings}  {}",
+                    site.file_path,
+                    site.line,
+                    site.style.as_str(),
+                    site.statement
+                ));
+            }
+        }
+        if shown < total {
+            lines.push(format!(
+                "  … {} more (raise `limit` to list them)",
+                total - shown
+            ));
+        }
+        lines.join("\n")
+    }
+}
@@ -5,29 +5,50 @@
 //! All entry points take `&dyn julie_context::ToolContext`; no `JulieServerHandler`
 //! reference exists in this crate.
 
+pub mod audit;
+pub mod context_pack;
+pub mod counterparts;
 pub mod deep_dive;
+pub mod deleted_files;
 pub mod editing;
+pub mod file_annotations;
+pub mod frameworks;
 pub mod get_context;
 pub mod impact;
+pub mod imports;
 pub mod navigation;
 pub mod patterns;
+pub mod projects;
 pub mod refactoring;
 pub mod search;
 pub mod shared;
+pub mod signature;
 pub mod spillover;
+pub mod streaming;
 pub mod symbols;
+pub mod syntax_query;
+pub mod task_status;
+pub mod tool_limits;
 
 // Re-export the public tool types so the top-crate shim can re-export them.
+pub use audit::AuditTool;
+pub use context_pack::GetContextPackTool;
+pub use counterparts::FastCounterpartsTool;
 pub use deep_dive::{DeepDiveDepth, DeepDiveTool};
 pub use editing::EditingTransaction;
+pub use file_annotations::GetFileAnnotationsTool;
 pub use get_context::GetContextTool;
 pub use impact::BlastRadiusTool;
+pub use imports::FastImportsTool;
 pub use navigation::{CallPathTool, FastRefsTool};
 pub use patterns::{PatternsFormat, PatternsGroupBy, PatternsOperation, PatternsTool};
 pub use refactoring::RenameSymbolTool;
 pub use search::FastSearchTool;
+pub use signature::FastSignatureTool;
 pub use spillover::SpilloverGetTool;
 pub use symbols::GetSymbolsTool;
+pub use syntax_query::SyntaxQueryTool;
+pub use task_status::TaskStatusTool;
 
 pub use shared::{
     BLACKLISTED_DIRECTORIES, BLACKLISTED_EXTENSIONS, BLACKLISTED_FILENAMES, OptimizedResponse,
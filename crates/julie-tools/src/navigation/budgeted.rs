@@ -0,0 +1,109 @@
+//! fast_refs output under a `max_tokens` budget.
+//!
+//! Every definition, import, reference, documentation link and textual
+//! mention becomes one [`BudgetedEntry`], keyed by its file, so an oversized
+//! answer sheds type lines and mention excerpts first and then collapses to
+//! one line per file (see [`julie_core::response_budget`]).
+
+use std::collections::HashMap;
+
+use julie_core::paths::display_stored_path;
+use julie_core::response_budget::{BudgetedEntry, BudgetedResponse};
+use julie_extractors::base::TypeInfo;
+use julie_extractors::{Relationship, Symbol, SymbolKind};
+
+use super::formatting::{doc_anchor, format_type_info, truncate_signature};
+use crate::search::LineMatch;
+
+/// Format fast_refs results in the order of the lean output, shaped to fit
+/// `max_tokens`.
+///
+/// Output format (before any shaping):
+/// ```text
+/// 3 references to "UserService":
+///
+/// src/services/user.rs:15 (definition, struct) → pub struct UserService
+///   type UserService
+///
+/// src/api/auth.rs:42  handle_request (Calls)
+///
+/// config/routes.yaml:12 [textual]
+///   handler: "UserService.create"
+/// ```
+pub fn format_budgeted_refs_results(
+    symbol: &str,
+    definitions: &[Symbol],
+    references: &[Relationship],
+    source_names: &HashMap<String, String>,
+    types: &HashMap<String, TypeInfo>,
+    textual_mentions: &[LineMatch],
+    max_tokens: u32,
+) -> String {
+    let total = definitions.len() + references.len();
+    let mut response = BudgetedResponse::new(format!("{} references to \"{}\":", total, symbol))
+        .with_max_tokens(Some(max_tokens));
+
+    for def in definitions {
+        let role = if def.kind == SymbolKind::Import {
+            "import".to_string()
+        } else {
+            format!("definition, {:?}", def.kind).to_lowercase()
+        };
+        let mut header = format!(
+            "{}:{} ({})",
+            display_stored_path(&def.file_path),
+            def.start_line,
+            role
+        );
+        if let Some(sig) = def
+            .signature
+            .as_deref()
+            .filter(|sig| !sig.trim().is_empty())
+        {
+            header.push_str(&format!(" → {}", truncate_signature(sig, 60)));
+        }
+        let type_line = types
+            .get(&def.id)
+            .map(|info| format_type_info(&def.kind, info));
+        response
+            .push(BudgetedEntry::new(&def.file_path, header).with_context(type_line.as_deref()));
+    }
+
+    // Several links from one documentation section are listed once.
+    let mut doc_sections: Vec<String> = Vec::new();
+    for rel in references {
+        let location = display_stored_path(&rel.file_path);
+        let header = match doc_anchor(rel) {
+            Some(anchor) => {
+                let section = format!("{}#{}", location, anchor);
+                if doc_sections.contains(&section) {
+                    continue;
+                }
+                doc_sections.push(section.clone());
+                format!("{} (documented)", section)
+            }
+            None => match source_names.get(&rel.from_symbol_id) {
+                Some(name) => format!(
+                    "{}:{}  {} ({:?})",
+                    location, rel.line_number, name, rel.kind
+                ),
+                None => format!("{}:{} ({:?})", location, rel.line_number, rel.kind),
+            },
+        };
+        response.push(BudgetedEntry::new(&rel.file_path, header));
+    }
+
+    for mention in textual_mentions {
+        let header = format!(
+            "{}:{} [textual]",
+            display_stored_path(&mention.file_path),
+            mention.line_number
+        );
+        response.push(
+            BudgetedEntry::new(&mention.file_path, header)
+                .with_context(Some(mention.line_content.trim())),
+        );
+    }
+
+    response.render().text
+}
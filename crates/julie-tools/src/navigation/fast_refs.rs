@@ -5,6 +5,31 @@
 //! 2. Cross-language naming convention variants (snake_case, camelCase, etc.)
 //! 3. Relationships table for caller→callee connections
 //! 4. Identifiers table for usage sites (calls, type usages, member access, imports)
+//!
+//! Build-time aliases (see `julie_index::analysis::symbol_aliases`) join in at
+//! both ends: a generated name such as `getUserName` resolves to the field or
+//! function it was generated from, and call sites spelled with a definition's
+//! generated names count as references to it.
+//!
+//! A Swift qualifier may name a module rather than a parent type:
+//! `Networking.Client` keeps the `Client` compiled into the `Networking`
+//! target (see `julie_index::analysis::swift_modules`).
+//!
+//! Each definition shows its extracted type (return type of a callable, with
+//! generic parameters and constraints) when the extractor recorded one.
+//! Definitions are followed by their stored cross-language counterparts
+//! (see `julie_index::analysis::cross_language_links`), and calls into
+//! external libraries are grouped by library (see
+//! `julie_index::analysis::external_modules`). With `include_textual`, mentions
+//! in comments and string literals (config keys, reflection, prose) follow as
+//! a separate low-confidence section found through the full-text index.
+//!
+//! Given a [`ResultSink`], definitions and references are streamed as soon as
+//! they are resolved, ahead of the sections above.
+//!
+//! With `project`, only definitions inside that monorepo project count (see
+//! `julie_index::analysis::workspace_projects`), so references to a
+//! same-named symbol in another package drop out.
 
 use anyhow::Result;
 use julie_core::mcp_compat::{CallToolResult, CallToolResultExt, Content};
@@ -12,11 +37,24 @@ use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use tracing::debug;
 
-use super::formatting::format_lean_refs_results;
-use super::resolution::{WorkspaceTarget, parse_qualified_name};
+use super::budgeted::format_budgeted_refs_results;
+use super::formatting::{
+    format_cross_language_counterparts, format_deleted_files, format_external_usage,
+    format_lean_refs_results, format_textual_mentions,
+};
+use super::resolution::{WorkspaceTarget, parse_qualified_name, swift_symbols_in_module};
 use super::target_workspace;
+use crate::deleted_files::load_deleted_files;
+use crate::projects;
+use crate::search::LineMatch;
+use crate::search::line_mode::line_mode_matches_in_regions;
+use crate::search::regions::SourceRegionFilter;
+use crate::streaming::{self, ResultSink};
+use crate::tool_limits::configured_max_tokens;
 use julie_context::ToolContext;
 use julie_core::cross_language_intelligence::generate_naming_variants;
+use julie_core::database::{CrossLanguageLink, ExternalModuleUsage};
+use julie_extractors::base::{SourceRegionKind, TypeInfo};
 use julie_extractors::{Relationship, RelationshipKind, Symbol, SymbolKind};
 use std::collections::{HashMap, HashSet};
 
@@ -54,18 +92,74 @@ pub struct FastRefsTool {
     /// Narrow by reference kind: "call", "variable_ref", "type_usage", "member_access", "import". Omit to see all reference types
     #[serde(default)]
     pub reference_kind: Option<String>,
+    /// Also list mentions in comments and string literals (config keys, reflection), tagged as low-confidence textual matches (default: false)
+    #[serde(
+        default,
+        deserialize_with = "julie_core::serde_lenient::deserialize_bool_lenient"
+    )]
+    pub include_textual: bool,
+    /// Monorepo project (package) whose definitions to use, e.g. "billing" when several packages define the same name. References from any project to those definitions are kept
+    #[serde(default)]
+    pub project: Option<String>,
+    /// Approximate token budget for the response. When exceeded, type lines and textual mention excerpts are dropped first, then results collapse to one line per file; a footer reports what was omitted (default: `tools.fast_refs.max_tokens` or `tools.default.max_tokens` from .julie/config.toml, else unlimited)
+    #[serde(
+        default,
+        deserialize_with = "julie_core::serde_lenient::deserialize_option_u32_lenient"
+    )]
+    pub max_tokens: Option<u32>,
 }
 
 impl FastRefsTool {
-    /// Create lean text result for references
+    /// Create lean text result for references, shaped to `max_tokens` when
+    /// set.
+    #[allow(clippy::too_many_arguments)]
     fn create_result(
         &self,
         definitions: Vec<Symbol>,
         references: Vec<Relationship>,
         source_names: &HashMap<String, String>,
+        types: &HashMap<String, TypeInfo>,
+        counterparts: &(Vec<CrossLanguageLink>, HashMap<String, Symbol>),
+        external_usage: &[ExternalModuleUsage],
+        textual_mentions: &[LineMatch],
+        deleted_files: &HashSet<String>,
+        max_tokens: Option<u32>,
     ) -> Result<CallToolResult> {
-        let lean_output =
-            format_lean_refs_results(&self.symbol, &definitions, &references, source_names);
+        // With nothing resolved the lean output's suggestions are kept.
+        let budget = max_tokens.filter(|_| !definitions.is_empty() || !references.is_empty());
+        let mut lean_output = match budget {
+            Some(max_tokens) => format_budgeted_refs_results(
+                &self.symbol,
+                &definitions,
+                &references,
+                source_names,
+                types,
+                textual_mentions,
+                max_tokens,
+            ),
+            None => format_lean_refs_results(
+                &self.symbol,
+                &definitions,
+                &references,
+                source_names,
+                types,
+            ),
+        };
+        lean_output.push_str(&format_deleted_files(
+            &definitions,
+            &references,
+            deleted_files,
+        ));
+        lean_output.push_str(&format_cross_language_counterparts(
+            &definitions,
+            &counterparts.0,
+            &counterparts.1,
+        ));
+        lean_output.push_str(&format_external_usage(external_usage));
+        // Under a budget the mentions are shaped with the references.
+        if budget.is_none() {
+            lean_output.push_str(&format_textual_mentions(textual_mentions));
+        }
         Ok(CallToolResult::text_content(vec![Content::text(
             lean_output,
         )]))
@@ -156,20 +250,55 @@ impl FastRefsTool {
         &self,
         handler: &dyn ToolContext,
         workspace_target: &WorkspaceTarget,
+    ) -> Result<CallToolResult> {
+        self.call_tool_streaming(handler, workspace_target, None)
+            .await
+    }
+
+    /// Same as `call_tool_with_target`, also sending definitions and
+    /// references to `sink` as soon as they are resolved (see
+    /// [`crate::streaming`]).
+    pub async fn call_tool_streaming(
+        &self,
+        handler: &dyn ToolContext,
+        workspace_target: &WorkspaceTarget,
+        sink: Option<&dyn ResultSink>,
     ) -> Result<CallToolResult> {
         debug!("Finding references for: {}", self.symbol);
 
         // Find references (workspace resolution is handled by workspace_target)
-        let (definitions, references) = self
+        let (mut definitions, mut references) = self
             .find_references_and_definitions(handler, workspace_target.clone())
             .await?;
+        if let Some(project) = self.project.as_deref() {
+            self.retain_project(
+                handler,
+                workspace_target,
+                project,
+                &mut definitions,
+                &mut references,
+            )
+            .await?;
+        }
+        if let Some(sink) = sink {
+            self.stream_references(sink, &definitions, &references)
+                .await;
+        }
+
+        let external_usage = self.resolve_external_usage(handler, workspace_target).await;
+        let textual_mentions = self
+            .resolve_textual_mentions(handler, workspace_target, &definitions, &references)
+            .await;
 
         if definitions.is_empty() && references.is_empty() {
             // Attempt semantic fallback (works for both primary and explicit workspaces)
             let semantic_section = self.try_semantic_fallback(handler, workspace_target).await;
 
             let empty_names = HashMap::new();
-            let mut result_text = format_lean_refs_results(&self.symbol, &[], &[], &empty_names);
+            let mut result_text =
+                format_lean_refs_results(&self.symbol, &[], &[], &empty_names, &HashMap::new());
+            result_text.push_str(&format_external_usage(&external_usage));
+            result_text.push_str(&format_textual_mentions(&textual_mentions));
             result_text.push_str(&semantic_section);
             return Ok(CallToolResult::text_content(vec![Content::text(
                 result_text,
@@ -182,6 +311,18 @@ impl FastRefsTool {
             .resolve_source_names(handler, &references, workspace_target)
             .await;
 
+        let types = self
+            .resolve_definition_types(handler, &definitions, workspace_target)
+            .await;
+        let counterparts = self
+            .resolve_cross_language_counterparts(handler, &definitions, workspace_target)
+            .await;
+        let deleted_files = load_deleted_files(handler, workspace_target).await;
+        let max_tokens = match self.max_tokens {
+            Some(max_tokens) => Some(max_tokens),
+            None => configured_max_tokens(handler, workspace_target, "fast_refs").await,
+        };
+
         // Respect include_definition parameter
         let defs = if self.include_definition {
             definitions
@@ -189,7 +330,288 @@ impl FastRefsTool {
             vec![]
         };
 
-        self.create_result(defs, references, &source_names)
+        self.create_result(
+            defs,
+            references,
+            &source_names,
+            &types,
+            &counterparts,
+            &external_usage,
+            &textual_mentions,
+            &deleted_files,
+            max_tokens,
+        )
+    }
+
+    /// Send the definitions and references to `sink` before the slower
+    /// lookups: caller names, counterparts, external usage, textual mentions.
+    async fn stream_references(
+        &self,
+        sink: &dyn ResultSink,
+        definitions: &[Symbol],
+        references: &[Relationship],
+    ) {
+        let mut lines = Vec::new();
+        if self.include_definition {
+            lines.extend(definitions.iter().map(|d| {
+                let kind = format!("{:?}", d.kind).to_lowercase();
+                format!("{}:{} ({})", d.file_path, d.start_line, kind)
+            }));
+        }
+        lines.extend(
+            references
+                .iter()
+                .map(|r| format!("{}:{} ({:?})", r.file_path, r.line_number, r.kind)),
+        );
+        let heading = format!("fast_refs \"{}\": references", self.symbol);
+        streaming::stream_lines(sink, &heading, &lines, 0, Some(lines.len())).await;
+    }
+
+    /// Keep the definitions inside `project` and drop references that point at
+    /// a definition outside it.
+    async fn retain_project(
+        &self,
+        handler: &dyn ToolContext,
+        workspace_target: &WorkspaceTarget,
+        project: &str,
+        definitions: &mut Vec<Symbol>,
+        references: &mut Vec<Relationship>,
+    ) -> Result<()> {
+        let db = projects::project_database(handler, workspace_target).await?;
+        let requested = project.to_string();
+        let mut kept = std::mem::take(definitions);
+        let (kept, dropped_ids) = tokio::task::spawn_blocking(move || -> Result<_> {
+            let project = projects::resolve_project(&db, &requested)?;
+            let all_ids: Vec<String> = kept.iter().map(|d| d.id.clone()).collect();
+            projects::retain_project_symbols(&db, &mut kept, &project)?;
+            let kept_ids: HashSet<&str> = kept.iter().map(|d| d.id.as_str()).collect();
+            let dropped_ids: HashSet<String> = all_ids
+                .into_iter()
+                .filter(|id| !kept_ids.contains(id.as_str()))
+                .collect();
+            Ok((kept, dropped_ids))
+        })
+        .await??;
+        *definitions = kept;
+        references.retain(|r| !dropped_ids.contains(&r.to_symbol_id));
+        Ok(())
+    }
+
+    /// With `include_textual`, find lines in comments and string literals that
+    /// mention the (unqualified) symbol name, skipping lines already reported
+    /// as definitions or references.
+    ///
+    /// Uses the same full-text line search as `fast_search`, restricted to
+    /// comment/string regions. These are textual hits, not resolved
+    /// references; a missing index or search error simply yields no section.
+    async fn resolve_textual_mentions(
+        &self,
+        handler: &dyn ToolContext,
+        workspace_target: &WorkspaceTarget,
+        definitions: &[Symbol],
+        references: &[Relationship],
+    ) -> Vec<LineMatch> {
+        if !self.include_textual {
+            return Vec::new();
+        }
+
+        let name = parse_qualified_name(&self.symbol)
+            .map(|(_, child)| child)
+            .unwrap_or(&self.symbol);
+        let regions = SourceRegionFilter(vec![
+            SourceRegionKind::Comment,
+            SourceRegionKind::DocComment,
+            SourceRegionKind::StringLiteral,
+        ]);
+        let search = match line_mode_matches_in_regions(
+            name,
+            &None,
+            &None,
+            self.limit,
+            None,
+            workspace_target,
+            handler,
+            &regions,
+        )
+        .await
+        {
+            Ok(search) => search,
+            Err(e) => {
+                debug!("Textual mentions unavailable: {}", e);
+                return Vec::new();
+            }
+        };
+
+        let covered: HashSet<(&str, usize)> = definitions
+            .iter()
+            .map(|d| (d.file_path.as_str(), d.start_line as usize))
+            .chain(
+                references
+                    .iter()
+                    .map(|r| (r.file_path.as_str(), r.line_number as usize)),
+            )
+            .collect();
+        search
+            .matches
+            .into_iter()
+            .filter(|m| !covered.contains(&(m.file_path.as_str(), m.line_number)))
+            .collect()
+    }
+
+    /// Load calls attributed to external libraries that match the queried
+    /// name — either the called name (`get`, `_.debounce`) or the library
+    /// itself (`requests`).
+    ///
+    /// Attribution is precomputed by post-index analysis; a missing table or
+    /// pool error simply yields no section.
+    async fn resolve_external_usage(
+        &self,
+        handler: &dyn ToolContext,
+        workspace_target: &WorkspaceTarget,
+    ) -> Vec<ExternalModuleUsage> {
+        let mut names = vec![self.symbol.clone()];
+        if let Some((_, child)) = parse_qualified_name(&self.symbol) {
+            names.push(child.to_string());
+        }
+
+        // Pooled DB: read-only, no mutation gate required.
+        let pooled_db = match workspace_target {
+            WorkspaceTarget::Target(target_workspace_id) => {
+                match handler
+                    .get_pooled_database_for_workspace(target_workspace_id)
+                    .await
+                {
+                    Ok(db) => db,
+                    Err(_) => return Vec::new(),
+                }
+            }
+            WorkspaceTarget::Primary => match handler.primary_pooled_database().await {
+                Ok(db) => db,
+                Err(_) => return Vec::new(),
+            },
+        };
+
+        tokio::task::spawn_blocking(move || {
+            pooled_db
+                .external_module_usages_for_names(&names)
+                .unwrap_or_else(|e| {
+                    debug!("External usage unavailable: {}", e);
+                    Vec::new()
+                })
+        })
+        .await
+        .unwrap_or_default()
+    }
+
+    /// Load the extracted types of the (non-import) definitions, keyed by
+    /// symbol id. Symbols without a type row, or a pool error, yield none.
+    async fn resolve_definition_types(
+        &self,
+        handler: &dyn ToolContext,
+        definitions: &[Symbol],
+        workspace_target: &WorkspaceTarget,
+    ) -> HashMap<String, TypeInfo> {
+        let ids: Vec<String> = definitions
+            .iter()
+            .filter(|d| d.kind != SymbolKind::Import)
+            .map(|d| d.id.clone())
+            .collect();
+
+        if ids.is_empty() {
+            return HashMap::new();
+        }
+
+        // Pooled DB: read-only, no mutation gate required.
+        let pooled_db = match workspace_target {
+            WorkspaceTarget::Target(target_workspace_id) => {
+                match handler
+                    .get_pooled_database_for_workspace(target_workspace_id)
+                    .await
+                {
+                    Ok(db) => db,
+                    Err(_) => return HashMap::new(),
+                }
+            }
+            WorkspaceTarget::Primary => match handler.primary_pooled_database().await {
+                Ok(db) => db,
+                Err(_) => return HashMap::new(),
+            },
+        };
+
+        tokio::task::spawn_blocking(move || {
+            pooled_db
+                .get_type_infos_for_symbols(&ids)
+                .unwrap_or_else(|e| {
+                    debug!("Definition types unavailable: {}", e);
+                    HashMap::new()
+                })
+        })
+        .await
+        .unwrap_or_default()
+    }
+
+    /// Load the stored cross-language counterparts of the (non-import)
+    /// definitions, plus the counterpart symbols themselves for display.
+    ///
+    /// Links are precomputed by post-index analysis; a missing table or pool
+    /// error simply yields no section.
+    async fn resolve_cross_language_counterparts(
+        &self,
+        handler: &dyn ToolContext,
+        definitions: &[Symbol],
+        workspace_target: &WorkspaceTarget,
+    ) -> (Vec<CrossLanguageLink>, HashMap<String, Symbol>) {
+        let ids: Vec<String> = definitions
+            .iter()
+            .filter(|d| d.kind != SymbolKind::Import)
+            .map(|d| d.id.clone())
+            .collect();
+
+        if ids.is_empty() {
+            return Default::default();
+        }
+
+        // Pooled DB: read-only, no mutation gate required.
+        let pooled_db = match workspace_target {
+            WorkspaceTarget::Target(target_workspace_id) => {
+                match handler
+                    .get_pooled_database_for_workspace(target_workspace_id)
+                    .await
+                {
+                    Ok(db) => db,
+                    Err(_) => return Default::default(),
+                }
+            }
+            WorkspaceTarget::Primary => match handler.primary_pooled_database().await {
+                Ok(db) => db,
+                Err(_) => return Default::default(),
+            },
+        };
+
+        tokio::task::spawn_blocking(move || {
+            let links = match pooled_db.cross_language_links_for_symbols(&ids) {
+                Ok(links) => links,
+                Err(e) => {
+                    debug!("Cross-language counterparts unavailable: {}", e);
+                    return Default::default();
+                }
+            };
+            let counterpart_ids: Vec<String> = links
+                .iter()
+                .map(|link| link.to_symbol_id.clone())
+                .collect::<HashSet<_>>()
+                .into_iter()
+                .collect();
+            let symbols: HashMap<String, Symbol> = pooled_db
+                .get_symbols_by_ids(&counterpart_ids)
+                .unwrap_or_default()
+                .into_iter()
+                .map(|s| (s.id.clone(), s))
+                .collect();
+            (links, symbols)
+        })
+        .await
+        .unwrap_or_default()
     }
 
     /// Batch-resolve from_symbol_id values to symbol names for reference display.
@@ -289,6 +711,19 @@ impl FastRefsTool {
                 // Strategy 1: exact-name lookup via SQLite (O(log n))
                 let mut definitions = pooled_db.get_symbols_by_name(&symbol_owned)?;
 
+                // Build-time aliases: the queried name may only exist after
+                // macro expansion or codegen (napi, Lombok, source generators)
+                let alias_targets: Vec<String> = pooled_db
+                    .symbol_aliases_by_names(std::slice::from_ref(&symbol_owned))
+                    .unwrap_or_default()
+                    .into_iter()
+                    .map(|alias| alias.symbol_id)
+                    .collect();
+                if !alias_targets.is_empty() {
+                    debug!("🔗 Alias resolves to {} definitions", alias_targets.len());
+                    definitions.extend(pooled_db.get_symbols_by_ids(&alias_targets)?);
+                }
+
                 // Apply parent filter for qualified names like Foo::bar
                 if let Some(ref parent_name) = parent_filter_owned {
                     let parent_ids: Vec<String> = definitions
@@ -297,24 +732,21 @@ impl FastRefsTool {
                         .collect::<HashSet<_>>()
                         .into_iter()
                         .collect();
+                    let matching_parent_ids: HashSet<String> = pooled_db
+                        .get_symbols_by_ids(&parent_ids)?
+                        .into_iter()
+                        .filter(|p| p.name == *parent_name)
+                        .map(|p| p.id)
+                        .collect();
+                    // Swift: the qualifier may be a module (`Networking.Client`)
+                    let in_module = swift_symbols_in_module(&pooled_db, &definitions, parent_name)?;
 
-                    if !parent_ids.is_empty() {
-                        let parents = pooled_db.get_symbols_by_ids(&parent_ids)?;
-                        let matching_parent_ids: HashSet<String> = parents
-                            .into_iter()
-                            .filter(|p| p.name == *parent_name)
-                            .map(|p| p.id)
-                            .collect();
-
-                        definitions.retain(|s| {
-                            s.parent_id
-                                .as_deref()
-                                .map(|pid| matching_parent_ids.contains(pid))
-                                .unwrap_or(false)
-                        });
-                    } else {
-                        definitions.clear();
-                    }
+                    definitions.retain(|s| {
+                        s.parent_id
+                            .as_deref()
+                            .is_some_and(|pid| matching_parent_ids.contains(pid))
+                            || in_module.contains(&s.id)
+                    });
                 }
 
                 debug!("⚡ SQLite found {} exact matches", definitions.len());
@@ -391,6 +823,15 @@ impl FastRefsTool {
                         all_names.push(v.clone());
                     }
                 }
+                // Call sites may use a definition's generated names
+                for alias in pooled_db
+                    .symbol_aliases_for_symbols(&definition_ids)
+                    .unwrap_or_default()
+                {
+                    if !all_names.contains(&alias.alias) {
+                        all_names.push(alias.alias);
+                    }
+                }
 
                 let first_def_id = definitions
                     .first()
@@ -5,13 +5,31 @@
 //! - Familiar grep-style output
 //! - Zero parsing overhead
 
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
+use julie_core::database::{CrossLanguageLink, ExternalModuleUsage};
+use julie_core::paths::display_stored_path;
+use julie_extractors::base::TypeInfo;
 use julie_extractors::{Relationship, Symbol, SymbolKind};
 use julie_index::search::similarity::SimilarEntry;
+use julie_pipeline::indexing_core::markdown_links::DOC_ANCHOR_METADATA_KEY;
+
+use crate::search::LineMatch;
+
+/// Maximum counterparts listed under "Cross-language counterparts".
+const MAX_CROSS_LANGUAGE_COUNTERPARTS: usize = 10;
+
+/// Longest line excerpt shown for a textual mention before truncation.
+const MAX_TEXTUAL_LINE_CHARS: usize = 120;
+
+/// Maximum libraries listed under "External usage by library".
+const MAX_EXTERNAL_LIBRARIES: usize = 10;
+
+/// Maximum call sites shown per library; the count covers all of them.
+const MAX_EXTERNAL_SITES_PER_LIBRARY: usize = 3;
 
 /// Truncate a signature to `max_len` characters, appending "..." if trimmed.
-fn truncate_signature(sig: &str, max_len: usize) -> String {
+pub(super) fn truncate_signature(sig: &str, max_len: usize) -> String {
     let first_line = sig.lines().next().unwrap_or(sig).trim();
 
     if first_line.chars().count() <= max_len {
@@ -26,8 +44,37 @@ fn truncate_signature(sig: &str, max_len: usize) -> String {
     }
 }
 
+/// One-line summary of a symbol's extracted type: what a callable returns
+/// or what type anything else has, then its generic parameters, their
+/// constraints, and whether the type was inferred rather than written.
+///
+/// ```text
+/// returns Promise<T> <T> where T extends Entity (inferred)
+/// type Map<string, User>
+/// ```
+pub fn format_type_info(kind: &SymbolKind, info: &TypeInfo) -> String {
+    let label = match kind {
+        SymbolKind::Function | SymbolKind::Method | SymbolKind::Constructor => "returns",
+        _ => "type",
+    };
+    let mut out = format!("{label} {}", info.resolved_type);
+    if let Some(generics) = info.generic_params.as_ref().filter(|g| !g.is_empty()) {
+        out.push_str(&format!(" <{}>", generics.join(", ")));
+    }
+    if let Some(constraints) = info.constraints.as_ref().filter(|c| !c.is_empty()) {
+        out.push_str(&format!(" where {}", constraints.join(", ")));
+    }
+    if info.is_inferred {
+        out.push_str(" (inferred)");
+    }
+    out
+}
+
 /// Format references in lean text format for AI agents
 ///
+/// `types` holds the extracted type of definitions that have one, shown on
+/// the line below the definition.
+///
 /// Output format:
 /// ```text
 /// 5 references to "UserService":
@@ -44,12 +91,16 @@ fn truncate_signature(sig: &str, max_len: usize) -> String {
 ///   src/api/profile.rs:28  get_profile (Uses)
 ///   src/handlers/login.rs:55  login (Calls)
 ///   src/tests/user_test.rs:12  test_user (Uses)
+///
+/// Documented in (1):
+///   docs/architecture.md#user-service
 /// ```
 pub fn format_lean_refs_results(
     symbol: &str,
     definitions: &[Symbol],
     references: &[Relationship],
     source_names: &HashMap<String, String>,
+    types: &HashMap<String, TypeInfo>,
 ) -> String {
     let mut output = String::new();
     let total = definitions.len() + references.len();
@@ -88,14 +139,22 @@ pub fn format_lean_refs_results(
             if sig.is_empty() {
                 output.push_str(&format!(
                     "  {}:{} ({})\n",
-                    def.file_path, def.start_line, kind
+                    display_stored_path(&def.file_path),
+                    def.start_line,
+                    kind
                 ));
             } else {
                 output.push_str(&format!(
                     "  {}:{} ({}) → {}\n",
-                    def.file_path, def.start_line, kind, sig
+                    display_stored_path(&def.file_path),
+                    def.start_line,
+                    kind,
+                    sig
                 ));
             }
+            if let Some(info) = types.get(&def.id) {
+                output.push_str(&format!("    {}\n", format_type_info(&def.kind, info)));
+            }
         }
         output.push('\n');
     }
@@ -118,25 +177,33 @@ pub fn format_lean_refs_results(
             if sig.is_empty() {
                 output.push_str(&format!(
                     "  {}:{} (import)\n",
-                    def.file_path, def.start_line
+                    display_stored_path(&def.file_path),
+                    def.start_line
                 ));
             } else {
                 output.push_str(&format!(
                     "  {}:{} (import) → {}\n",
-                    def.file_path, def.start_line, sig
+                    display_stored_path(&def.file_path),
+                    def.start_line,
+                    sig
                 ));
             }
         }
         output.push('\n');
     }
 
+    // Links from documentation are listed by the section they come from,
+    // after the code references.
+    let (doc_references, references): (Vec<&Relationship>, Vec<&Relationship>) =
+        references.iter().partition(|rel| doc_anchor(rel).is_some());
+
     // References section — group by file when multiple refs share a file
     if !references.is_empty() {
         output.push_str(&format!("References ({}):\n", references.len()));
 
         // Group references by file path (preserving order of first appearance)
         let mut groups: Vec<(&str, Vec<&Relationship>)> = Vec::new();
-        for rel in references {
+        for &rel in &references {
             let path = rel.file_path.as_str();
             if let Some(group) = groups.iter_mut().find(|(p, _)| *p == path) {
                 group.1.push(rel);
@@ -146,6 +213,7 @@ pub fn format_lean_refs_results(
         }
 
         for (file_path, rels) in &groups {
+            let file_path = display_stored_path(file_path);
             if rels.len() == 1 {
                 // Single ref in file: keep inline format
                 let rel = rels[0];
@@ -176,9 +244,38 @@ pub fn format_lean_refs_results(
         }
     }
 
+    if !doc_references.is_empty() {
+        let mut sections: Vec<String> = Vec::new();
+        for rel in &doc_references {
+            let section = format!(
+                "{}#{}",
+                display_stored_path(&rel.file_path),
+                doc_anchor(rel).unwrap_or_default()
+            );
+            if !sections.contains(&section) {
+                sections.push(section);
+            }
+        }
+        if !references.is_empty() {
+            output.push('\n');
+        }
+        output.push_str(&format!("Documented in ({}):\n", sections.len()));
+        for section in &sections {
+            output.push_str(&format!("  {}\n", section));
+        }
+    }
+
     output.trim_end().to_string()
 }
 
+/// The documentation section anchor of a link resolved from a Markdown file.
+pub(super) fn doc_anchor(rel: &Relationship) -> Option<&str> {
+    rel.metadata
+        .as_ref()?
+        .get(DOC_ANCHOR_METADATA_KEY)?
+        .as_str()
+}
+
 /// Format semantic similarity results for the zero-ref fallback in fast_refs.
 pub fn format_semantic_fallback(symbol: &str, similar: &[SimilarEntry]) -> String {
     if similar.is_empty() {
@@ -205,7 +302,7 @@ pub fn format_semantic_fallback(symbol: &str, similar: &[SimilarEntry]) -> Strin
             "  {:<25} {:.2}  {}:{} ({})\n",
             entry.symbol.name,
             entry.score,
-            entry.symbol.file_path,
+            display_stored_path(&entry.symbol.file_path),
             entry.symbol.start_line,
             kind_vis,
         ));
@@ -218,3 +315,162 @@ pub fn format_semantic_fallback(symbol: &str, similar: &[SimilarEntry]) -> Strin
 
     out
 }
+
+/// Format stored cross-language counterpart links for fast_refs.
+///
+/// `links` are expected highest-confidence first (as returned by
+/// `cross_language_links_for_symbols`); `counterparts` resolves each link's
+/// `to_symbol_id`. A counterpart reached from several definitions is listed
+/// once, and symbols already shown as definitions are skipped.
+///
+/// Output format:
+/// ```text
+/// Cross-language counterparts:
+///   src/Dtos/UserDto.cs:5  UserDto (class, csharp) 0.90 — same name, 3/4 fields
+///   db/schema.sql:12  users (class, sql) 0.73 — normalized name, 2/6 fields
+/// ```
+pub fn format_cross_language_counterparts(
+    definitions: &[Symbol],
+    links: &[CrossLanguageLink],
+    counterparts: &HashMap<String, Symbol>,
+) -> String {
+    let mut seen: HashSet<&str> = definitions.iter().map(|def| def.id.as_str()).collect();
+    let mut lines = Vec::new();
+    for link in links {
+        if lines.len() >= MAX_CROSS_LANGUAGE_COUNTERPARTS {
+            break;
+        }
+        let Some(symbol) = counterparts.get(&link.to_symbol_id) else {
+            continue;
+        };
+        if !seen.insert(symbol.id.as_str()) {
+            continue;
+        }
+        lines.push(format!(
+            "  {}:{}  {} ({}, {}) {:.2} — {}\n",
+            display_stored_path(&symbol.file_path),
+            symbol.start_line,
+            symbol.name,
+            symbol.kind,
+            symbol.language,
+            link.confidence,
+            link.reason,
+        ));
+    }
+
+    if lines.is_empty() {
+        return String::new();
+    }
+    let mut out = String::from("\n\nCross-language counterparts:\n");
+    for line in lines {
+        out.push_str(&line);
+    }
+    out.trim_end().to_string()
+}
+
+/// Format calls attributed to external libraries for fast_refs, grouped by
+/// library with the most-used library first.
+///
+/// Output format:
+/// ```text
+/// External usage by library:
+///   requests (2 calls)
+///     src/api/client.py:14  requests.get
+///     src/api/client.py:31  get
+///   lodash (1 call)
+///     web/src/search.ts:8  _.debounce
+/// ```
+pub fn format_external_usage(usages: &[ExternalModuleUsage]) -> String {
+    let mut libraries: Vec<(&str, Vec<&ExternalModuleUsage>)> = Vec::new();
+    for usage in usages {
+        match libraries
+            .iter_mut()
+            .find(|(module, _)| *module == usage.module)
+        {
+            Some((_, sites)) => sites.push(usage),
+            None => libraries.push((usage.module.as_str(), vec![usage])),
+        }
+    }
+    if libraries.is_empty() {
+        return String::new();
+    }
+    libraries.sort_by(|a, b| b.1.len().cmp(&a.1.len()).then_with(|| a.0.cmp(b.0)));
+
+    let mut out = String::from("\n\nExternal usage by library:\n");
+    for (module, sites) in libraries.iter().take(MAX_EXTERNAL_LIBRARIES) {
+        let noun = if sites.len() == 1 { "call" } else { "calls" };
+        out.push_str(&format!("  {} ({} {})\n", module, sites.len(), noun));
+        for site in sites.iter().take(MAX_EXTERNAL_SITES_PER_LIBRARY) {
+            out.push_str(&format!(
+                "    {}:{}  {}\n",
+                display_stored_path(&site.file_path),
+                site.start_line,
+                site.name
+            ));
+        }
+    }
+    if libraries.len() > MAX_EXTERNAL_LIBRARIES {
+        out.push_str(&format!(
+            "  ... {} more libraries\n",
+            libraries.len() - MAX_EXTERNAL_LIBRARIES
+        ));
+    }
+    out.trim_end().to_string()
+}
+
+/// Format low-confidence textual mentions (comments, string literals) for
+/// fast_refs. Every line is tagged so it can't be mistaken for a resolved
+/// reference.
+///
+/// Output format:
+/// ```text
+/// Textual mentions (comments/strings, not resolved references):
+///   config/routes.yaml:12  [textual] handler: "UserService.create"
+///   src/app.ts:40  [textual] // UserService is wired up in bootstrap()
+/// ```
+pub fn format_textual_mentions(mentions: &[LineMatch]) -> String {
+    if mentions.is_empty() {
+        return String::new();
+    }
+    let mut out =
+        String::from("\n\nTextual mentions (comments/strings, not resolved references):\n");
+    for mention in mentions {
+        let line = mention.line_content.trim();
+        let line = match line.char_indices().nth(MAX_TEXTUAL_LINE_CHARS) {
+            Some((cut, _)) => format!("{}…", &line[..cut]),
+            None => line.to_string(),
+        };
+        out.push_str(&format!(
+            "  {}:{}  [textual] {}\n",
+            display_stored_path(&mention.file_path),
+            mention.line_number,
+            line
+        ));
+    }
+    out.trim_end().to_string()
+}
+
+/// Flag results that come from files deleted from disk but still inside
+/// their soft-delete grace period, so the agent knows those locations may
+/// not exist anymore.
+///
+/// Output format:
+/// ```text
+/// Deleted from disk (kept until the grace period ends):
+///   src/legacy/users.rs  1 definition, 2 references
+/// ```
+pub fn format_deleted_files(
+    definitions: &[Symbol],
+    references: &[Relationship],
+    deleted_files: &HashSet<String>,
+) -> String {
+    let paths = definitions
+        .iter()
+        .map(|definition| (definition.file_path.as_str(), "definition"))
+        .chain(
+            references
+                .iter()
+                .map(|reference| (reference.file_path.as_str(), "reference")),
+        );
+    crate::deleted_files::format_deleted_files(paths, deleted_files)
+}
@@ -9,6 +9,7 @@
 //! - Per-workspace database isolation
 //! - Cross-language support through naming convention variants
 
+pub mod budgeted;
 pub mod call_path;
 mod fast_refs;
 pub mod formatting;
@@ -4,6 +4,10 @@
 //! The handler-bound workspace-parameter resolver (`resolve_workspace_filter`)
 //! has moved to `src/handler/workspace_resolution.rs`.
 
+use anyhow::Result;
+use std::collections::HashSet;
+
+use julie_core::database::SymbolDatabase;
 use julie_extractors::Symbol;
 
 pub use julie_core::workspace_errors::{
@@ -39,6 +43,30 @@ pub fn parse_qualified_name(symbol: &str) -> Option<(&str, &str)> {
     None
 }
 
+/// IDs of the Swift `symbols` compiled into module `module`, so a qualifier
+/// such as `Networking` in `Networking.Client` can name a module rather than
+/// a parent type. Empty when none of them is Swift or the module is unknown.
+pub fn swift_symbols_in_module(
+    db: &SymbolDatabase,
+    symbols: &[Symbol],
+    module: &str,
+) -> Result<HashSet<String>> {
+    let swift_paths: Vec<String> = symbols
+        .iter()
+        .filter(|s| s.language == "swift")
+        .map(|s| s.file_path.clone())
+        .collect();
+    if swift_paths.is_empty() {
+        return Ok(HashSet::new());
+    }
+    let modules = db.swift_modules_for_files(&swift_paths)?;
+    Ok(symbols
+        .iter()
+        .filter(|s| modules.get(&s.file_path).is_some_and(|m| m == module))
+        .map(|s| s.id.clone())
+        .collect())
+}
+
 /// Priority ordering for symbol definitions by kind
 pub fn definition_priority(kind: &julie_extractors::SymbolKind) -> u8 {
     use julie_extractors::SymbolKind;
@@ -7,7 +7,7 @@ use anyhow::Result;
 use std::collections::HashSet;
 use tracing::debug;
 
-use super::resolution::parse_qualified_name;
+use super::resolution::{parse_qualified_name, swift_symbols_in_module};
 use julie_context::ToolContext;
 use julie_core::cross_language_intelligence::generate_naming_variants;
 use julie_extractors::{Relationship, RelationshipKind, Symbol, SymbolKind};
@@ -15,10 +15,11 @@ use julie_extractors::{Relationship, RelationshipKind, Symbol, SymbolKind};
 /// Find references in a target workspace using handler helpers for DB access.
 ///
 /// Supports the same strategies as the primary workspace path:
-/// 1. Exact name lookup
+/// 1. Exact name lookup, plus definitions the name is a build-time alias of
 /// 2. Cross-language naming variants
 /// 3. Relationship-based refs (optionally filtered by `reference_kind`)
-/// 4. Identifier-based refs (optionally filtered by `reference_kind`)
+/// 4. Identifier-based refs (optionally filtered by `reference_kind`),
+///    including call sites spelled with a definition's aliases
 ///
 /// Results are sorted by confidence (descending) then truncated to `limit`.
 pub async fn find_references_in_target_workspace(
@@ -53,6 +54,17 @@ pub async fn find_references_in_target_workspace(
         // Strategy 1: Find exact matches by name
         let mut defs = ref_db.get_symbols_by_name(&effective_symbol)?;
 
+        // Build-time aliases: the name may only exist after macro expansion or codegen
+        let alias_targets: Vec<String> = ref_db
+            .symbol_aliases_by_names(std::slice::from_ref(&effective_symbol))
+            .unwrap_or_default()
+            .into_iter()
+            .map(|alias| alias.symbol_id)
+            .collect();
+        if !alias_targets.is_empty() {
+            defs.extend(ref_db.get_symbols_by_ids(&alias_targets)?);
+        }
+
         debug!("Target workspace search found {} exact matches", defs.len());
 
         // Strategy 2: Cross-language Intelligence Layer - naming convention variants
@@ -84,24 +96,21 @@ pub async fn find_references_in_target_workspace(
                 .collect::<HashSet<_>>()
                 .into_iter()
                 .collect();
+            let matching_parent_ids: HashSet<String> = ref_db
+                .get_symbols_by_ids(&parent_ids)?
+                .into_iter()
+                .filter(|p| p.name == *parent_name)
+                .map(|p| p.id)
+                .collect();
+            // Swift: the qualifier may be a module (`Networking.Client`)
+            let in_module = swift_symbols_in_module(&ref_db, &defs, parent_name)?;
 
-            if !parent_ids.is_empty() {
-                let parents = ref_db.get_symbols_by_ids(&parent_ids)?;
-                let matching_parent_ids: HashSet<String> = parents
-                    .into_iter()
-                    .filter(|p| p.name == *parent_name)
-                    .map(|p| p.id)
-                    .collect();
-
-                defs.retain(|s| {
-                    s.parent_id
-                        .as_deref()
-                        .map(|pid| matching_parent_ids.contains(pid))
-                        .unwrap_or(false)
-                });
-            } else {
-                defs.clear();
-            }
+            defs.retain(|s| {
+                s.parent_id
+                    .as_deref()
+                    .is_some_and(|pid| matching_parent_ids.contains(pid))
+                    || in_module.contains(&s.id)
+            });
         }
 
         // Remove duplicates
@@ -159,6 +168,14 @@ pub async fn find_references_in_target_workspace(
                 all_names.push(v.clone());
             }
         }
+        for alias in ref_db
+            .symbol_aliases_for_symbols(&definition_ids)
+            .unwrap_or_default()
+        {
+            if !all_names.contains(&alias.alias) {
+                all_names.push(alias.alias);
+            }
+        }
 
         let first_def_id = defs.first().map(|d| d.id.clone()).unwrap_or_default();
         let resolved_definition_ids: HashSet<String> = defs.iter().map(|d| d.id.clone()).collect();
@@ -2,6 +2,7 @@ use std::collections::BTreeMap;
 use std::path::Path;
 
 use anyhow::Result;
+use julie_core::paths::display_stored_path;
 use julie_extractors::base::StructuralFact;
 use serde::Serialize;
 use serde_json::{Value, json};
@@ -136,7 +137,11 @@ pub(super) fn format_summary(
 fn compact_fact(fact: &StructuralFact) -> String {
     let mut line = format!(
         "{}:{} {} {} {}",
-        fact.file_path, fact.start_line, fact.capture_name, fact.pattern_id, fact.id
+        display_stored_path(&fact.file_path),
+        fact.start_line,
+        fact.capture_name,
+        fact.pattern_id,
+        fact.id
     );
     let metadata = sorted_metadata(fact);
     if !metadata.is_empty() {
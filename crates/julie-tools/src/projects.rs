@@ -0,0 +1,128 @@
+//! Monorepo project labels and the `project` filter shared by the tools.
+//!
+//! The post-index analysis pass maps every file to the package that owns it
+//! (see `julie_index::analysis::workspace_projects`). Tools label results with
+//! that project so two `UserService` definitions in different packages can be
+//! told apart, and with `project` set they keep only results inside it.
+
+use std::collections::HashMap;
+
+use anyhow::{Result, bail};
+use julie_context::ToolContext;
+use julie_core::database::SymbolDatabase;
+use julie_extractors::Symbol;
+
+use crate::navigation::resolution::WorkspaceTarget;
+use crate::search::trace::SearchHit;
+
+/// Projects listed in an unknown-project error; the rest are counted.
+const MAX_LISTED_PROJECTS: usize = 20;
+
+/// Pooled database of `workspace_target`: read-only, no mutation gate required.
+pub async fn project_database(
+    handler: &dyn ToolContext,
+    workspace_target: &WorkspaceTarget,
+) -> Result<SymbolDatabase> {
+    match workspace_target {
+        WorkspaceTarget::Primary => handler.primary_pooled_database().await,
+        WorkspaceTarget::Target(workspace_id) => {
+            handler
+                .get_pooled_database_for_workspace(workspace_id)
+                .await
+        }
+    }
+}
+
+/// The indexed project named `requested` (ASCII case-insensitive), or an
+/// error listing the projects that exist.
+pub fn resolve_project(db: &SymbolDatabase, requested: &str) -> Result<String> {
+    let requested = requested.trim();
+    let known = db.project_file_counts()?;
+    if let Some((name, _)) = known
+        .iter()
+        .find(|(name, _)| name.eq_ignore_ascii_case(requested))
+    {
+        return Ok(name.clone());
+    }
+    if known.is_empty() {
+        bail!(
+            "Unknown project '{requested}': no projects detected in this workspace \
+             (projects come from Cargo.toml, package.json, go.mod, .csproj and .sln manifests)"
+        );
+    }
+    let listed: Vec<&str> = known
+        .iter()
+        .take(MAX_LISTED_PROJECTS)
+        .map(|(name, _)| name.as_str())
+        .collect();
+    let more = known.len().saturating_sub(MAX_LISTED_PROJECTS);
+    let more = if more > 0 {
+        format!(" and {more} more")
+    } else {
+        String::new()
+    };
+    bail!(
+        "Unknown project '{requested}'. Known projects: {}{more}",
+        listed.join(", ")
+    )
+}
+
+/// Project of each symbol's file, keyed by file path.
+pub fn projects_for_symbols(
+    db: &SymbolDatabase,
+    symbols: &[Symbol],
+) -> Result<HashMap<String, String>> {
+    let paths: Vec<String> = symbols.iter().map(|s| s.file_path.clone()).collect();
+    db.projects_for_files(&paths)
+}
+
+/// Keep the symbols whose file belongs to `project` (already resolved by
+/// [`resolve_project`]). Returns how many were removed.
+pub fn retain_project_symbols(
+    db: &SymbolDatabase,
+    symbols: &mut Vec<Symbol>,
+    project: &str,
+) -> Result<usize> {
+    let projects = projects_for_symbols(db, symbols)?;
+    let before = symbols.len();
+    symbols.retain(|s| projects.get(&s.file_path).is_some_and(|p| p == project));
+    Ok(before - symbols.len())
+}
+
+/// ` [project: name]` suffix for a result line, or nothing.
+pub fn project_label(project: Option<&str>) -> String {
+    project
+        .map(|project| format!(" [project: {project}]"))
+        .unwrap_or_default()
+}
+
+/// Project of each hit's file, keyed by file path.
+pub async fn load_hit_projects(
+    hits: &[SearchHit],
+    workspace_target: &WorkspaceTarget,
+    handler: &dyn ToolContext,
+) -> Result<HashMap<String, String>> {
+    let paths: Vec<String> = hits.iter().map(|hit| hit.file.clone()).collect();
+    if paths.is_empty() {
+        return Ok(HashMap::new());
+    }
+    let db = project_database(handler, workspace_target).await?;
+    tokio::task::spawn_blocking(move || db.projects_for_files(&paths)).await?
+}
+
+/// Label each hit with its file's project and, with `project` set, drop the
+/// hits outside it. Returns how many were removed.
+pub fn label_and_retain_hits(
+    hits: &mut Vec<SearchHit>,
+    projects: &HashMap<String, String>,
+    project: Option<&str>,
+) -> usize {
+    for hit in hits.iter_mut() {
+        hit.project = projects.get(&hit.file).cloned();
+    }
+    let before = hits.len();
+    if let Some(project) = project {
+        hits.retain(|hit| hit.project.as_deref() == Some(project));
+    }
+    before - hits.len()
+}
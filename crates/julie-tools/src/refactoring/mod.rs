@@ -13,6 +13,8 @@ mod utils;
 
 use anyhow::Result;
 use julie_core::mcp_compat::{CallToolResult, CallToolResultExt, Content};
+use julie_core::parser_pool::ParserPool;
+use julie_core::paths::stored_path_to_absolute;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use std::collections::HashSet;
@@ -48,6 +50,7 @@ pub fn compute_line_changes(old_content: &str, new_content: &str) -> Vec<RenameC
 }
 
 use crate::editing::EditingTransaction;
+use crate::editing::journal::JournalRecorder;
 use julie_context::ToolContext;
 
 fn default_dry_run() -> bool {
@@ -263,12 +266,13 @@ impl SmartRefactorTool {
         old_name: &str,
         new_name: &str,
         allowed_lines: &[u32],
+        journal: &mut JournalRecorder,
     ) -> Result<Vec<RenameChange>> {
         // Resolve file path relative to workspace root
         let absolute_path = if Path::new(file_path).is_absolute() {
-            file_path.to_string()
+            PathBuf::from(file_path)
         } else {
-            workspace_root.join(file_path).to_string_lossy().to_string()
+            stored_path_to_absolute(workspace_root, file_path)
         };
 
         // Read file content
@@ -293,6 +297,7 @@ impl SmartRefactorTool {
         if !self.dry_run {
             let tx = EditingTransaction::begin(&absolute_path)?;
             tx.commit_if_unchanged(&updated_content, &content)?;
+            journal.record(file_path, &content, &updated_content);
         }
 
         Ok(compute_line_changes(&content, &updated_content))
@@ -336,8 +341,6 @@ impl SmartRefactorTool {
         file_path: &str,
         allowed_lines: Option<&HashSet<u32>>,
     ) -> Result<String> {
-        use tree_sitter::Parser;
-
         if old_name.is_empty() || old_name == new_name {
             return Ok(content.to_string());
         }
@@ -345,8 +348,8 @@ impl SmartRefactorTool {
         let language = julie_extractors::language::detect_language_for_source(file_path, content)
             .map(str::to_string)
             .unwrap_or_else(|| self.detect_language(file_path));
-        let ts_language = match self.get_tree_sitter_language(&language) {
-            Ok(lang) => lang,
+        let mut parser = match ParserPool::global().checkout(&language) {
+            Ok(parser) => parser,
             Err(_) => {
                 // No tree-sitter parser for this language (e.g. .env, .cfg, .ini files that
                 // Julie indexes but has no grammar for). Fall back to plain text replacement.
@@ -359,9 +362,6 @@ impl SmartRefactorTool {
             }
         };
 
-        let mut parser = Parser::new();
-        parser.set_language(&ts_language)?;
-
         let tree = parser
             .parse(content, None)
             .ok_or_else(|| anyhow::anyhow!("Failed to parse {} file", language))?;
@@ -455,9 +455,4 @@ impl SmartRefactorTool {
             );
         }
     }
-
-    /// Get tree-sitter language for file type (delegates to shared language module)
-    fn get_tree_sitter_language(&self, language: &str) -> Result<tree_sitter::Language> {
-        julie_extractors::language::get_tree_sitter_language(language)
-    }
 }
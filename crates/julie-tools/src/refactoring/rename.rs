@@ -1,12 +1,14 @@
 //! Rename symbol refactoring operations
 
 use anyhow::Result;
+use julie_core::generated_files::GeneratedOutputMatcher;
 use julie_core::mcp_compat::CallToolResult;
 use serde_json::Value as JsonValue;
 use std::collections::{HashMap, HashSet};
 use tracing::debug;
 
 use super::{RenameChange, RenameSymbolTool, SmartRefactorTool, compute_line_changes};
+use crate::editing::journal::JournalRecorder;
 use crate::navigation::FastRefsTool;
 use crate::navigation::resolution::parse_qualified_name;
 use julie_context::ToolContext;
@@ -34,6 +36,9 @@ impl RenameSymbolTool {
             limit: 1000,
             workspace: workspace.clone(),
             reference_kind: None,
+            include_textual: false,
+            project: None,
+            max_tokens: None,
         };
         let workspace_target = handler
             .resolve_workspace_target(refs_tool.workspace.as_deref())
@@ -53,6 +58,7 @@ impl RenameSymbolTool {
                 file_locations.retain(|path, _| path == &normalized_file_path);
             }
         }
+        remove_generated_files(&workspace_root, &mut file_locations);
 
         let engine = SmartRefactorTool {
             operation: "rename_symbol".to_string(),
@@ -66,7 +72,7 @@ impl RenameSymbolTool {
             let absolute_path = if std::path::Path::new(file_path).is_absolute() {
                 std::path::PathBuf::from(file_path)
             } else {
-                workspace_root.join(file_path)
+                julie_core::paths::stored_path_to_absolute(&workspace_root, file_path)
             };
             let content = std::fs::read_to_string(&absolute_path)?;
             let allowed_lines: HashSet<u32> = lines.iter().copied().collect();
@@ -156,6 +162,9 @@ impl SmartRefactorTool {
             limit: 1000, // High limit for comprehensive rename
             workspace: workspace.clone().or_else(|| Some("primary".to_string())),
             reference_kind: None, // No filtering - find all reference kinds
+            include_textual: false,
+            project: None,
+            max_tokens: None,
         };
 
         let workspace_target = handler
@@ -210,6 +219,21 @@ impl SmartRefactorTool {
             }
         }
 
+        let generated_files = remove_generated_files(&workspace_root, &mut file_locations);
+        if file_locations.is_empty() {
+            return self.create_result(
+                "rename_symbol",
+                false,
+                vec![],
+                0,
+                Some(format!(
+                    "rename_symbol: '{}' is only referenced in generated files ({}); rename it in their sources and regenerate",
+                    old_name,
+                    generated_files.join(", ")
+                )),
+            );
+        }
+
         debug!(
             "📍 Found {} references across {} files",
             file_locations
@@ -222,6 +246,7 @@ impl SmartRefactorTool {
         // Step 2: Apply renames file by file
         let mut renamed_files: Vec<(String, Vec<RenameChange>)> = Vec::new();
         let mut errors = Vec::new();
+        let mut journal = JournalRecorder::default();
 
         for (file_path, lines) in &file_locations {
             match self
@@ -231,6 +256,7 @@ impl SmartRefactorTool {
                     replacement_old_name,
                     new_name,
                     lines,
+                    &mut journal,
                 )
                 .await
             {
@@ -266,12 +292,29 @@ impl SmartRefactorTool {
         } else {
             None
         };
+        let generated_note = (!generated_files.is_empty()).then(|| {
+            format!(
+                "Note: skipped {} generated file(s) ({}); rename in their sources and regenerate.",
+                generated_files.len(),
+                generated_files.join(", ")
+            )
+        });
+        let import_unsupported_warning = match (import_unsupported_warning, generated_note) {
+            (Some(imports), Some(generated)) => Some(format!("{imports}\n{generated}")),
+            (imports, generated) => imports.or(generated),
+        };
 
         if update_imports && !renamed_files.is_empty() {
             debug!("Updating import statements for renamed symbol");
             let file_paths: Vec<String> = file_locations.keys().cloned().collect();
             match self
-                .update_import_statements_in_files(&workspace_root, &file_paths, old_name, new_name)
+                .update_import_statements_in_files(
+                    &workspace_root,
+                    &file_paths,
+                    old_name,
+                    new_name,
+                    &mut journal,
+                )
                 .await
             {
                 Ok(updated_files) => {
@@ -299,6 +342,17 @@ impl SmartRefactorTool {
             }
         }
 
+        // Journal whatever was written, partial failures included, so the
+        // rename can be rolled back as one operation.
+        journal
+            .commit(
+                handler,
+                workspace.as_deref(),
+                "rename_symbol",
+                &format!("rename_symbol '{}' → '{}'", old_name, new_name),
+            )
+            .await;
+
         // Step 3: Generate result summary
         let total_files = renamed_files.len();
         let total_changes: usize = renamed_files
@@ -393,12 +447,13 @@ impl SmartRefactorTool {
         file_paths: &[String],
         old_name: &str,
         new_name: &str,
+        journal: &mut JournalRecorder,
     ) -> Result<Vec<(String, usize)>> {
         let mut updated_files = Vec::new();
 
         for file_path in file_paths {
             match self
-                .update_imports_in_file(workspace_root, file_path, old_name, new_name)
+                .update_imports_in_file(workspace_root, file_path, old_name, new_name, journal)
                 .await
             {
                 Ok(changes) if changes > 0 => {
@@ -424,14 +479,15 @@ impl SmartRefactorTool {
         file_path: &str,
         old_name: &str,
         new_name: &str,
+        journal: &mut JournalRecorder,
     ) -> Result<usize> {
         use regex::Regex;
 
         // Resolve file path relative to workspace root
         let absolute_path = if std::path::Path::new(file_path).is_absolute() {
-            file_path.to_string()
+            std::path::PathBuf::from(file_path)
         } else {
-            workspace_root.join(file_path).to_string_lossy().to_string()
+            julie_core::paths::stored_path_to_absolute(workspace_root, file_path)
         };
 
         let content = std::fs::read_to_string(&absolute_path)?;
@@ -485,6 +541,7 @@ impl SmartRefactorTool {
             use crate::editing::EditingTransaction;
             let tx = EditingTransaction::begin(&absolute_path)?;
             tx.commit_if_unchanged(&modified_content, &content)?;
+            journal.record(file_path, &content, &modified_content);
         }
 
         Ok(changes)
@@ -514,6 +571,28 @@ fn normalize_scope_file_path(file_path: &str, workspace_root: &std::path::Path)
 }
 
 /// Used by rename to find all locations that need to be updated.
+/// Drop `[[generated]]` outputs from `file_locations`: they change when
+/// their source is regenerated. Returns the dropped paths, sorted.
+fn remove_generated_files(
+    workspace_root: &std::path::Path,
+    file_locations: &mut HashMap<String, Vec<u32>>,
+) -> Vec<String> {
+    let matcher = GeneratedOutputMatcher::for_workspace(workspace_root);
+    if matcher.is_empty() {
+        return Vec::new();
+    }
+    let mut generated: Vec<String> = file_locations
+        .keys()
+        .filter(|path| matcher.generated_file(path).is_some())
+        .cloned()
+        .collect();
+    generated.sort();
+    for path in &generated {
+        file_locations.remove(path);
+    }
+    generated
+}
+
 fn build_file_locations(
     definitions: &[Symbol],
     references: &[Relationship],
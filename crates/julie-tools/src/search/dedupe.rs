@@ -0,0 +1,75 @@
+//! Collapse identical copies of a symbol into one search hit.
+//!
+//! Vendored libraries repeat the same definitions in every copy, so a query
+//! for a common helper can fill the whole result page with one function. With
+//! `dedupe` on (the default), symbol hits that share a name, kind and body
+//! hash are folded into the best-ranked one, which lists where the other
+//! copies live. Copies outside the result page are listed too.
+
+use std::collections::{HashMap, HashSet};
+
+use anyhow::Result;
+use julie_context::ToolContext;
+use julie_core::database::SymbolCopy;
+
+use super::trace::SearchHit;
+use crate::navigation::resolution::WorkspaceTarget;
+
+/// Load the copy groups for the symbol hits in `hits` from the workspace
+/// database.
+pub async fn load_symbol_copies(
+    hits: &[SearchHit],
+    workspace_target: &WorkspaceTarget,
+    handler: &dyn ToolContext,
+) -> Result<HashMap<String, Vec<SymbolCopy>>> {
+    let symbol_ids: Vec<String> = hits
+        .iter()
+        .filter(|hit| hit.as_symbol().is_some())
+        .filter_map(|hit| hit.symbol_id.clone())
+        .collect();
+    if symbol_ids.is_empty() {
+        return Ok(HashMap::new());
+    }
+
+    // Pooled DB: read-only, no mutation gate required.
+    let db = match workspace_target {
+        WorkspaceTarget::Primary => handler.primary_pooled_database().await?,
+        WorkspaceTarget::Target(workspace_id) => {
+            handler
+                .get_pooled_database_for_workspace(workspace_id)
+                .await?
+        }
+    };
+    tokio::task::spawn_blocking(move || db.symbol_copies(&symbol_ids)).await?
+}
+
+/// Fold copies into the first (best-ranked) hit of each group, recording the
+/// other copies' locations on it. Returns how many hits were removed.
+pub fn collapse_symbol_copies(
+    hits: &mut Vec<SearchHit>,
+    copies: &HashMap<String, Vec<SymbolCopy>>,
+) -> usize {
+    let before = hits.len();
+    let mut folded: HashSet<&str> = HashSet::new();
+
+    hits.retain_mut(|hit| {
+        let Some(symbol_id) = hit.symbol_id.as_deref() else {
+            return true;
+        };
+        if folded.contains(symbol_id) {
+            return false;
+        }
+        let Some(group) = copies.get(symbol_id) else {
+            return true;
+        };
+        hit.copies = group
+            .iter()
+            .filter(|copy| copy.symbol_id != symbol_id)
+            .map(|copy| format!("{}:{}", copy.file_path, copy.start_line))
+            .collect();
+        folded.extend(group.iter().map(|copy| copy.symbol_id.as_str()));
+        true
+    });
+
+    before - hits.len()
+}
@@ -0,0 +1,71 @@
+//! Restrict search results to one C/C++ build configuration.
+//!
+//! Indexing records the `#if` condition guarding each C/C++ symbol (see
+//! `indexing_core::preprocessor`). With `defines` set, symbol hits whose
+//! condition is false under those macros are dropped, so a search for
+//! `open_file` with `defines="_WIN32"` returns only the Windows definition.
+//! Conditions that cannot be decided, and hits without a condition, are kept.
+
+use std::collections::HashMap;
+
+use anyhow::{Result, anyhow};
+use julie_context::ToolContext;
+use julie_core::preprocessor::{DefineSet, evaluate_condition};
+
+use super::trace::SearchHit;
+use crate::navigation::resolution::WorkspaceTarget;
+
+/// Parse the comma-separated `defines` parameter (`"DEBUG,LEVEL=3"`). An
+/// empty string is the configuration with nothing defined.
+pub fn parse_defines(defines: &str) -> Result<DefineSet> {
+    DefineSet::parse(
+        defines
+            .split(',')
+            .map(str::trim)
+            .filter(|entry| !entry.is_empty()),
+    )
+    .map_err(|message| anyhow!("Invalid defines: {message}"))
+}
+
+/// Load the preprocessor conditions of the symbol hits in `hits`.
+pub async fn load_conditions(
+    hits: &[SearchHit],
+    workspace_target: &WorkspaceTarget,
+    handler: &dyn ToolContext,
+) -> Result<HashMap<String, String>> {
+    let symbol_ids: Vec<String> = hits
+        .iter()
+        .filter(|hit| hit.as_symbol().is_some())
+        .filter_map(|hit| hit.symbol_id.clone())
+        .collect();
+    if symbol_ids.is_empty() {
+        return Ok(HashMap::new());
+    }
+
+    // Pooled DB: read-only, no mutation gate required.
+    let db = match workspace_target {
+        WorkspaceTarget::Primary => handler.primary_pooled_database().await?,
+        WorkspaceTarget::Target(workspace_id) => {
+            handler
+                .get_pooled_database_for_workspace(workspace_id)
+                .await?
+        }
+    };
+    tokio::task::spawn_blocking(move || db.preprocessor_conditions(&symbol_ids)).await?
+}
+
+/// Drop the hits compiled out under `defines`. Returns how many were removed.
+pub fn retain_configuration(
+    hits: &mut Vec<SearchHit>,
+    conditions: &HashMap<String, String>,
+    defines: &DefineSet,
+) -> usize {
+    let before = hits.len();
+    hits.retain(|hit| {
+        hit.symbol_id
+            .as_deref()
+            .and_then(|id| conditions.get(id))
+            .is_none_or(|condition| evaluate_condition(condition, defines) != Some(false))
+    });
+    before - hits.len()
+}
@@ -1,10 +1,14 @@
 use std::cmp::Ordering;
+use std::collections::HashMap;
 use std::sync::Arc;
 use std::time::Duration;
 
 use anyhow::Result;
+use tracing::debug;
 
+use julie_core::database::SymbolDatabase;
 use julie_extractors::{Symbol, SymbolKind};
+use julie_index::search::hybrid::SemanticLanguageScope;
 use julie_index::search::{SearchFilter, SymbolSearchResult};
 use julie_pipeline::embeddings::EmbeddingProvider;
 
@@ -14,6 +18,7 @@ use super::backend::{ResolvedSearchBackend, SearchBackend};
 use super::hint_formatter;
 use super::line_mode;
 use super::query;
+use super::spelling;
 use super::text_search;
 use super::trace::{
     FilePatternDiagnostic, SearchExecutionKind, SearchExecutionResult, SearchHit, ZeroHitReason,
@@ -26,7 +31,14 @@ pub struct SearchExecutionParams<'a> {
     pub limit: u32,
     pub context_lines: Option<u32>,
     pub exclude_tests: Option<bool>,
+    /// Rank generated code like hand-written code instead of below it.
+    pub include_generated: bool,
     pub backend: ResolvedSearchBackend,
+    /// Return near-miss symbols ranked by edit distance when nothing matches.
+    pub fuzzy: bool,
+    /// Languages to restrict and boost the semantic/hybrid KNN to. Lexical
+    /// passes ignore it.
+    pub language_scope: SemanticLanguageScope,
 }
 
 #[derive(Debug, Clone)]
@@ -69,7 +81,10 @@ pub async fn execute_search(
         limit: params.limit,
         context_lines: params.context_lines,
         exclude_tests: params.exclude_tests,
+        include_generated: params.include_generated,
         backend: params.backend,
+        fuzzy: params.fuzzy,
+        language_scope: params.language_scope,
     };
 
     // T8 cutover: all traffic routes through the unified path.
@@ -83,6 +98,85 @@ fn sort_hits_by_score_desc(hits: &mut [SearchHit]) {
     hits.sort_by(|a, b| b.score.partial_cmp(&a.score).unwrap_or(Ordering::Equal));
 }
 
+/// Incoming usage counts, keyed by `(workspace, symbol_id)`.
+pub(crate) type ReferenceCounts = HashMap<(String, String), i64>;
+
+/// Sort by score, breaking exact ties by how often each symbol is used, so a
+/// widely called definition lands above a rarely used one with the same text
+/// relevance. Hits without a count (lines, files) rank as unused.
+pub(crate) fn sort_hits_by_score_then_popularity(hits: &mut [SearchHit], counts: &ReferenceCounts) {
+    let popularity = |hit: &SearchHit| {
+        hit.symbol_id
+            .as_ref()
+            .and_then(|id| counts.get(&(hit.workspace.clone(), id.clone())))
+            .copied()
+            .unwrap_or(0)
+    };
+    hits.sort_by(|a, b| {
+        b.score
+            .partial_cmp(&a.score)
+            .unwrap_or(Ordering::Equal)
+            .then_with(|| popularity(b).cmp(&popularity(a)))
+    });
+}
+
+/// Label each symbol hit with its usage count, for the result badge.
+pub(crate) fn attach_usage_counts(hits: &mut [SearchHit], counts: &ReferenceCounts) {
+    for hit in hits {
+        hit.usages = hit
+            .symbol_id
+            .as_ref()
+            .and_then(|id| counts.get(&(hit.workspace.clone(), id.clone())))
+            .copied();
+    }
+}
+
+/// Usage counts for the symbol hits, read from each hit's workspace
+/// database. Best effort: a workspace whose counts can't be read keeps its
+/// ties in relevance order and shows no badges.
+async fn load_reference_counts(hits: &[SearchHit], handler: &dyn ToolContext) -> ReferenceCounts {
+    let mut ids_by_workspace: HashMap<&str, Vec<String>> = HashMap::new();
+    for hit in hits {
+        if let Some(id) = &hit.symbol_id {
+            ids_by_workspace
+                .entry(hit.workspace.as_str())
+                .or_default()
+                .push(id.clone());
+        }
+    }
+
+    let mut counts = ReferenceCounts::new();
+    for (workspace_id, ids) in ids_by_workspace {
+        let db = match handler
+            .get_pooled_database_for_workspace(workspace_id)
+            .await
+        {
+            Ok(db) => db,
+            Err(err) => {
+                debug!(workspace_id = %workspace_id, "Skipping reference counts: {err:#}");
+                continue;
+            }
+        };
+        match tokio::task::spawn_blocking(move || {
+            let ids: Vec<&str> = ids.iter().map(String::as_str).collect();
+            db.get_usage_counts(&ids)
+        })
+        .await
+        {
+            Ok(Ok(by_id)) => counts.extend(
+                by_id
+                    .into_iter()
+                    .map(|(id, count)| ((workspace_id.to_string(), id), count)),
+            ),
+            Ok(Err(err)) => {
+                debug!(workspace_id = %workspace_id, "Skipping reference counts: {err:#}")
+            }
+            Err(err) => debug!(workspace_id = %workspace_id, "Reference count task failed: {err}"),
+        }
+    }
+    counts
+}
+
 // ---------------------------------------------------------------------------
 // Phase 2 — unified execution path
 // ---------------------------------------------------------------------------
@@ -126,6 +220,7 @@ pub async fn execute_search_unified(
                     params.backend.value,
                     params.query,
                     params.language,
+                    &params.language_scope,
                     normalized_file_pattern.as_deref(),
                     params.limit,
                     effective_exclude_tests,
@@ -151,6 +246,7 @@ pub async fn execute_search_unified(
         normalized_file_pattern.as_deref(),
         params.limit,
         effective_exclude_tests,
+        params.include_generated,
         workspaces,
         handler,
     )
@@ -207,6 +303,7 @@ pub async fn execute_search_unified(
             None,
             params.limit,
             effective_exclude_tests,
+            params.include_generated,
             workspaces,
             handler,
         )
@@ -236,6 +333,7 @@ pub async fn execute_search_unified(
             SearchBackend::Semantic,
             params.query,
             params.language,
+            &params.language_scope,
             None,
             params.limit,
             effective_exclude_tests,
@@ -278,9 +376,156 @@ pub async fn execute_search_unified(
         execution.trace.hint_kind = Some(hint_kind);
     }
 
+    // Misspelled queries are the common zero-hit cause once no filter is
+    // to blame: offer corrections from the symbol-name dictionary, and with
+    // `fuzzy` return the closest names' symbols outright.
+    if execution.hits.is_empty()
+        && !matches!(
+            execution.trace.zero_hit_reason,
+            Some(ZeroHitReason::FilePatternFiltered | ZeroHitReason::TestFiltered)
+        )
+        && !query::looks_like_file_or_path_query(params.query)
+    {
+        let dictionaries =
+            load_name_dictionaries(params.language.as_deref(), workspaces, handler).await;
+        let dictionary: Vec<String> = dictionaries
+            .iter()
+            .flat_map(|(_, _, names)| names.iter().cloned())
+            .collect();
+        execution.trace.suggestions = spelling::suggest_corrections(params.query, &dictionary);
+
+        if params.fuzzy {
+            let hits = fuzzy_fallback_hits(
+                &params,
+                normalized_file_pattern.as_deref(),
+                effective_exclude_tests,
+                dictionaries,
+            )
+            .await;
+            if !hits.is_empty() {
+                execution.total_results = hits.len();
+                execution.hits = hits;
+                execution.trace.strategy_id = "fast_search_fuzzy_fallback".to_string();
+                execution.trace.zero_hit_reason = None;
+                execution.trace.file_pattern_diagnostic = None;
+                execution.trace.hint_kind = None;
+                execution.trace.target_hint = None;
+                execution.trace.refresh_hits(&execution.hits);
+            }
+        }
+    }
+
     Ok(execution)
 }
 
+/// Each searched workspace's symbol-name dictionary with its pooled
+/// database. Best effort: a workspace whose dictionary can't be read is
+/// skipped.
+async fn load_name_dictionaries(
+    language: Option<&str>,
+    workspaces: &[SearchExecutionWorkspace],
+    handler: &dyn ToolContext,
+) -> Vec<(String, SymbolDatabase, Vec<String>)> {
+    let mut dictionaries = Vec::new();
+    for workspace in workspaces {
+        let db = match handler
+            .get_pooled_database_for_workspace(&workspace.workspace_id)
+            .await
+        {
+            Ok(db) => db,
+            Err(err) => {
+                debug!(
+                    workspace_id = %workspace.workspace_id,
+                    "Skipping suggestion dictionary: {err:#}"
+                );
+                continue;
+            }
+        };
+        let language = language.map(str::to_string);
+        match tokio::task::spawn_blocking(move || {
+            let names = db.symbol_name_dictionary(language.as_deref(), spelling::DICTIONARY_LIMIT);
+            names.map(|names| (db, names))
+        })
+        .await
+        {
+            Ok(Ok((db, names))) => dictionaries.push((workspace.workspace_id.clone(), db, names)),
+            Ok(Err(err)) => debug!(
+                workspace_id = %workspace.workspace_id,
+                "Skipping suggestion dictionary: {err:#}"
+            ),
+            Err(err) => debug!(
+                workspace_id = %workspace.workspace_id,
+                "Suggestion dictionary task failed: {err}"
+            ),
+        }
+    }
+    dictionaries
+}
+
+/// Symbols named like the query within the spelling edit budget, closest
+/// first, with the caller's language, file and test filters applied. Imports
+/// are skipped: they repeat a definition's name without being one.
+async fn fuzzy_fallback_hits(
+    params: &SearchExecutionParams<'_>,
+    file_pattern: Option<&str>,
+    exclude_tests: bool,
+    dictionaries: Vec<(String, SymbolDatabase, Vec<String>)>,
+) -> Vec<SearchHit> {
+    let mut hits = Vec::new();
+    for (workspace_id, db, names) in dictionaries {
+        let near_misses: Vec<(usize, String)> = spelling::near_miss_names(params.query, &names)
+            .into_iter()
+            .take(params.limit.max(1) as usize)
+            .map(|(distance, name)| (distance, name.to_string()))
+            .collect();
+        if near_misses.is_empty() {
+            continue;
+        }
+        let lookup: Vec<String> = near_misses.iter().map(|(_, name)| name.clone()).collect();
+        let by_name = match tokio::task::spawn_blocking(move || {
+            db.find_symbols_by_names_batch(&lookup)
+        })
+        .await
+        {
+            Ok(Ok(by_name)) => by_name,
+            Ok(Err(err)) => {
+                debug!(workspace_id = %workspace_id, "Fuzzy fallback lookup failed: {err:#}");
+                continue;
+            }
+            Err(err) => {
+                debug!(workspace_id = %workspace_id, "Fuzzy fallback task failed: {err}");
+                continue;
+            }
+        };
+
+        for (distance, name) in near_misses {
+            for symbol in by_name.get(&name).into_iter().flatten() {
+                if symbol.kind == SymbolKind::Import
+                    || params
+                        .language
+                        .as_deref()
+                        .is_some_and(|language| symbol.language != language)
+                    || file_pattern.is_some_and(|pattern| {
+                        !crate::search::matches_glob_pattern(&symbol.file_path, pattern)
+                    })
+                    || (exclude_tests
+                        && julie_index::search::scoring::is_test_path(&symbol.file_path))
+                {
+                    continue;
+                }
+                let mut hit = SearchHit::from_symbol(symbol.clone(), workspace_id.clone());
+                hit.score = 1.0 / (1.0 + distance as f32);
+                hits.push(hit);
+            }
+        }
+    }
+
+    // Stable: equal distances keep dictionary (reference score) order.
+    sort_hits_by_score_desc(&mut hits);
+    hits.truncate(params.limit.max(1) as usize);
+    hits
+}
+
 fn should_try_semantic_zero_hit_fallback(
     params: &SearchExecutionParams<'_>,
     normalized_file_pattern: Option<&str>,
@@ -333,6 +578,7 @@ async fn run_symbol_backend_pass(
     backend: SearchBackend,
     query: &str,
     language: &Option<String>,
+    language_scope: &SemanticLanguageScope,
     file_pattern: Option<&str>,
     limit: u32,
     effective_exclude_tests: bool,
@@ -351,6 +597,7 @@ async fn run_symbol_backend_pass(
             kind: None,
             file_pattern: file_pattern.map(str::to_string),
             exclude_tests: effective_exclude_tests,
+            include_generated: false,
         };
         let db = handler
             .get_pooled_database_for_workspace(&workspace.workspace_id)
@@ -365,6 +612,7 @@ async fn run_symbol_backend_pass(
         let workspace_id = workspace.workspace_id.clone();
         let query = query.to_string();
         let provider = Arc::clone(&provider);
+        let language_scope = language_scope.clone();
 
         let (mut workspace_hits, workspace_relaxed, workspace_total) =
             tokio::task::spawn_blocking(move || -> Result<(Vec<SearchHit>, bool, usize)> {
@@ -372,6 +620,7 @@ async fn run_symbol_backend_pass(
                     SearchBackend::Semantic => run_semantic_symbol_search(
                         &query,
                         &filter,
+                        &language_scope,
                         limit_usize,
                         &db,
                         provider.as_ref(),
@@ -391,7 +640,7 @@ async fn run_symbol_backend_pass(
                                 Some(provider.as_ref()),
                             );
                         let index = si_arc;
-                        julie_index::search::hybrid::hybrid_search_with_embedding(
+                        julie_index::search::hybrid::hybrid_search_with_embedding_scoped(
                             &query,
                             &filter,
                             limit_usize,
@@ -399,6 +648,7 @@ async fn run_symbol_backend_pass(
                             &db,
                             precomputed_embedding,
                             Some(julie_index::search::weights::SearchWeightProfile::fast_search()),
+                            &language_scope,
                         )?
                     }
                     SearchBackend::Lexical => {
@@ -440,12 +690,21 @@ async fn run_symbol_backend_pass(
 fn run_semantic_symbol_search(
     query: &str,
     filter: &SearchFilter,
+    language_scope: &SemanticLanguageScope,
     limit: usize,
-    db: &julie_core::database::SymbolDatabase,
+    db: &SymbolDatabase,
     provider: &dyn EmbeddingProvider,
 ) -> Result<julie_index::search::SymbolSearchResults> {
     let query_vector = provider.embed_query(query)?;
-    let knn_hits = db.knn_search(&query_vector, limit.saturating_mul(4).max(limit))?;
+    let language_scope = language_scope
+        .clone()
+        .with_filter_language(filter.language.as_deref());
+    let knn_hits = julie_index::search::hybrid::scoped_knn_search(
+        db,
+        &query_vector,
+        limit.saturating_mul(4).max(limit),
+        &language_scope,
+    )?;
     let mut results: Vec<_> = julie_index::search::hybrid::knn_to_search_results(&knn_hits, db)?
         .into_iter()
         .filter(|result| filter.matches_symbol_result(result))
@@ -505,6 +764,7 @@ async fn run_unified_pass(
     file_pattern: Option<&str>,
     limit: u32,
     effective_exclude_tests: bool,
+    include_generated: bool,
     workspaces: &[SearchExecutionWorkspace],
     handler: &dyn ToolContext,
 ) -> Result<UnifiedPassResult> {
@@ -522,6 +782,7 @@ async fn run_unified_pass(
             kind: None,
             file_pattern: file_pattern.map(str::to_string),
             exclude_tests: effective_exclude_tests,
+            include_generated,
         };
 
         // Use `unified_search_hits` (returns raw UnifiedHit) rather than
@@ -576,6 +837,7 @@ async fn run_unified_pass(
                 kind: None,
                 file_pattern: None,
                 exclude_tests: false,
+                include_generated: false,
             };
             let diagnostic_fetch_limit = raw_fetch_limit.saturating_mul(20).max(1_000);
             let (diagnostic_hits, diagnostic_relaxed, _) = text_search::unified_search_hits(
@@ -606,8 +868,10 @@ async fn run_unified_pass(
         }
     }
 
-    sort_hits_by_score_desc(&mut hits);
+    let counts = load_reference_counts(&hits, handler).await;
+    sort_hits_by_score_then_popularity(&mut hits, &counts);
     hits.truncate(limit.max(1) as usize);
+    attach_usage_counts(&mut hits, &counts);
 
     Ok(UnifiedPassResult {
         hits,
@@ -2,9 +2,52 @@
 //!
 //! Provides formatting utilities for search tool responses.
 
+use std::collections::HashSet;
+
+use crate::deleted_files::format_deleted_files;
+use crate::projects::project_label;
 use crate::search::trace::{SearchHit, SearchHitBacking};
+use julie_core::paths::display_stored_path;
+use julie_core::response_budget::{BudgetedEntry, BudgetedResponse};
 use julie_core::shared::OptimizedResponse;
 
+/// Copy locations listed under a deduplicated hit; the rest are counted.
+const MAX_LISTED_COPIES: usize = 5;
+
+/// One-line summary of the identical copies folded into `hit` by `dedupe`,
+/// e.g. `+2 identical copies: vendor/a/util.rs:7, vendor/b/util.rs:3`.
+pub fn copies_summary(hit: &SearchHit) -> Option<String> {
+    let total = hit.copies.len();
+    if total == 0 {
+        return None;
+    }
+    let noun = if total == 1 { "copy" } else { "copies" };
+    let listed = hit
+        .copies
+        .iter()
+        .take(MAX_LISTED_COPIES)
+        .map(String::as_str)
+        .collect::<Vec<_>>()
+        .join(", ");
+    let mut summary = format!("+{total} identical {noun}: {listed}");
+    if total > MAX_LISTED_COPIES {
+        summary.push_str(&format!(
+            ", ... {} more (dedupe=false lists each copy)",
+            total - MAX_LISTED_COPIES
+        ));
+    }
+    Some(summary)
+}
+
+fn write_copies_summary(output: &mut String, hit: &SearchHit, indent: &str) {
+    if let Some(summary) = copies_summary(hit) {
+        output.push_str(&format!(
+            "{indent}{summary}
+"
+        ));
+    }
+}
+
 fn write_definition_other_match_snippet(output: &mut String, hit: &SearchHit, indent: &str) {
     if let Some(snippet) = hit
         .snippet
@@ -204,19 +247,63 @@ pub fn format_unified_locations(query: &str, hits: &[SearchHit], total_found: us
         ));
     }
     for hit in hits {
-        if let Some(symbol) = hit.as_symbol() {
-            let kind = symbol.kind.to_string();
-            output.push_str(&format!(
-                "  {}:{} ({})\n",
-                symbol.file_path, symbol.start_line, kind
-            ));
-        } else {
-            output.push_str(&format!("  {} (file)\n", hit.file));
+        output.push_str(&format!("  {}\n", format_hit_location(hit)));
+        if hit.as_symbol().is_some() {
+            write_copies_summary(&mut output, hit, "    ");
         }
     }
     output.trim_end().to_string()
 }
 
+/// Files among `hits` deleted from disk but still inside their soft-delete
+/// grace period (see [`crate::deleted_files`]).
+pub fn format_deleted_hits(hits: &[SearchHit], deleted_files: &HashSet<String>) -> String {
+    format_deleted_files(
+        hits.iter().map(|hit| (hit.file.as_str(), "result")),
+        deleted_files,
+    )
+}
+
+/// ` [N refs]` badge for a symbol hit's usage count, or nothing when the
+/// count is unknown or zero.
+pub fn usage_label(usages: Option<i64>) -> String {
+    usages
+        .filter(|count| *count > 0)
+        .map(|count| format!(" [{}]", usage_count_text(count)))
+        .unwrap_or_default()
+}
+
+/// ` [generated]` badge for a hit from generated code, or nothing.
+pub fn generated_label(hit: &SearchHit) -> &'static str {
+    if hit.generated { " [generated]" } else { "" }
+}
+
+/// `1 ref`, `12 refs`.
+pub fn usage_count_text(count: i64) -> String {
+    if count == 1 {
+        "1 ref".to_string()
+    } else {
+        format!("{count} refs")
+    }
+}
+
+/// One-line location of a hit: `path:line (kind)` for symbols, `path (file)`
+/// for file rows.
+pub fn format_hit_location(hit: &SearchHit) -> String {
+    match hit.as_symbol() {
+        Some(symbol) => format!(
+            "{}:{} ({}){}{}{}",
+            display_stored_path(&symbol.file_path),
+            symbol.start_line,
+            symbol.kind,
+            usage_label(hit.usages),
+            generated_label(hit),
+            project_label(hit.project.as_deref())
+        ),
+        None => format!("{} (file){}", hit.file, generated_label(hit)),
+    }
+}
+
 /// Lean-format fallback for the unified path: no exact-match promotion, every
 /// hit rendered in rank order grouped by file.
 fn format_unified_lean(query: &str, hits: &[SearchHit], total_found: usize) -> String {
@@ -250,11 +337,20 @@ fn write_unified_hits_grouped(output: &mut String, hits: &[&SearchHit]) {
     }
 
     for (file_path, group_hits) in &groups {
+        let file_path = display_stored_path(file_path);
         if group_hits.len() == 1 {
             let hit = group_hits[0];
             if let Some(symbol) = hit.as_symbol() {
-                output.push_str(&format!("{}:{}\n", file_path, symbol.start_line));
+                output.push_str(&format!(
+                    "{}:{}{}{}{}\n",
+                    file_path,
+                    symbol.start_line,
+                    usage_label(hit.usages),
+                    generated_label(hit),
+                    project_label(hit.project.as_deref())
+                ));
                 write_definition_other_match_snippet(output, hit, "  ");
+                write_copies_summary(output, hit, "  ");
             } else {
                 // File row: render as a single path line with file marker.
                 let lang = if hit.language.is_empty() {
@@ -262,14 +358,29 @@ fn write_unified_hits_grouped(output: &mut String, hits: &[&SearchHit]) {
                 } else {
                     format!(", {}", hit.language)
                 };
-                output.push_str(&format!("{} (file{})\n", file_path, lang));
+                output.push_str(&format!(
+                    "{} (file{}){}\n",
+                    file_path,
+                    lang,
+                    generated_label(hit)
+                ));
             }
         } else {
-            output.push_str(&format!("{}:\n", file_path));
+            output.push_str(&format!(
+                "{}:{}\n",
+                file_path,
+                project_label(group_hits[0].project.as_deref())
+            ));
             for hit in group_hits {
                 if let Some(symbol) = hit.as_symbol() {
-                    output.push_str(&format!("  :{}\n", symbol.start_line));
+                    output.push_str(&format!(
+                        "  :{}{}{}\n",
+                        symbol.start_line,
+                        usage_label(hit.usages),
+                        generated_label(hit)
+                    ));
                     write_definition_other_match_snippet(output, hit, "    ");
+                    write_copies_summary(output, hit, "    ");
                     output.push('\n');
                 } else {
                     let lang = if hit.language.is_empty() {
@@ -277,7 +388,7 @@ fn write_unified_hits_grouped(output: &mut String, hits: &[&SearchHit]) {
                     } else {
                         format!(", {}", hit.language)
                     };
-                    output.push_str(&format!("  (file{})\n", lang));
+                    output.push_str(&format!("  (file{}){}\n", lang, generated_label(hit)));
                 }
             }
         }
@@ -294,8 +405,14 @@ fn write_unified_hit_promoted(output: &mut String, hit: &SearchHit) {
             .map(|v| format!(", {}", v.to_string().to_lowercase()))
             .unwrap_or_default();
         output.push_str(&format!(
-            "  {}:{} ({}{})\n",
-            symbol.file_path, symbol.start_line, kind, vis
+            "  {}:{} ({}{}){}{}{}\n",
+            display_stored_path(&symbol.file_path),
+            symbol.start_line,
+            kind,
+            vis,
+            usage_label(hit.usages),
+            generated_label(hit),
+            project_label(hit.project.as_deref())
         ));
         if let Some(sig) = &symbol.signature {
             output.push_str(&format!("  {}\n", sig));
@@ -304,13 +421,19 @@ fn write_unified_hit_promoted(output: &mut String, hit: &SearchHit) {
                 output.push_str(&format!("  {}\n", first_line.trim()));
             }
         }
+        write_copies_summary(output, hit, "  ");
     } else {
         let lang = if hit.language.is_empty() {
             String::new()
         } else {
             format!(", {}", hit.language)
         };
-        output.push_str(&format!("  {} (file{})\n", hit.file, lang));
+        output.push_str(&format!(
+            "  {} (file{}){}\n",
+            hit.file,
+            lang,
+            generated_label(hit)
+        ));
     }
 }
 
@@ -332,3 +455,87 @@ fn file_hit_matches_query(hit: &SearchHit, query_compact: &str) -> bool {
         || compact_alnum_lc(stem) == query_compact
         || compact_alnum_lc(&hit.file) == query_compact
 }
+
+/// Format search hits in rank order under a `max_tokens` budget.
+///
+/// Each hit becomes a [`BudgetedEntry`]: location plus signature in the
+/// always-kept header, doc comment and code context as sheddable lines. The
+/// budget builder drops context, then docs, then collapses by file, and
+/// appends a footer describing what was omitted.
+pub fn format_budgeted_search_results(
+    query: &str,
+    hits: &[SearchHit],
+    total_found: usize,
+    max_tokens: u32,
+) -> String {
+    let count = hits.len();
+    let heading = if count == total_found {
+        format!("{} matches for \"{}\":", count, query)
+    } else {
+        format!(
+            "{} matches for \"{}\" (showing {} of {}):",
+            count, query, count, total_found
+        )
+    };
+    let mut response = BudgetedResponse::new(heading).with_max_tokens(Some(max_tokens));
+    for hit in hits {
+        response.push(budgeted_entry_for_hit(hit));
+    }
+    response.render().text
+}
+
+fn budgeted_entry_for_hit(hit: &SearchHit) -> BudgetedEntry {
+    match &hit.backing {
+        SearchHitBacking::Symbol(symbol) => {
+            let mut header = format!(
+                "{}:{} ({}){}{}{}",
+                display_stored_path(&symbol.file_path),
+                symbol.start_line,
+                symbol.kind,
+                usage_label(hit.usages),
+                generated_label(hit),
+                project_label(hit.project.as_deref())
+            );
+            if let Some(signature) = symbol
+                .signature
+                .as_deref()
+                .filter(|signature| !signature.trim().is_empty())
+            {
+                header.push_str(&format!("\n  {}", signature.trim()));
+            }
+            if let Some(summary) = copies_summary(hit) {
+                header.push_str(&format!("\n  {summary}"));
+            }
+            // Prefer the line-mode snippet (actual matching lines) over the
+            // stored code context when enrichment produced one.
+            let context = hit
+                .snippet
+                .as_deref()
+                .filter(|snippet| Some(*snippet) != symbol.signature.as_deref())
+                .or(symbol.code_context.as_deref());
+            BudgetedEntry::new(&symbol.file_path, header)
+                .with_doc(symbol.doc_comment.as_deref())
+                .with_context(context)
+        }
+        SearchHitBacking::LineMatch(line_match) => BudgetedEntry::new(
+            &hit.file,
+            format!(
+                "{}:{}",
+                display_stored_path(&line_match.file_path),
+                line_match.line_number
+            ),
+        )
+        .with_context(Some(line_match.line_content.trim())),
+        SearchHitBacking::File(_) => {
+            let lang = if hit.language.is_empty() {
+                String::new()
+            } else {
+                format!(", {}", hit.language)
+            };
+            BudgetedEntry::new(
+                &hit.file,
+                format!("{} (file{}){}", hit.file, lang, generated_label(hit)),
+            )
+        }
+    }
+}
@@ -404,6 +404,7 @@ fn run_line_mode_workspace_fetch(
         kind: None,
         file_pattern: None,
         exclude_tests: false,
+        include_generated: false,
     };
 
     let (matches, stage_counts, file_pattern_diagnostic) = run_line_mode_fetch_loop(
@@ -753,7 +754,7 @@ async fn line_mode_matches_with_regions(
     })
 }
 
-fn indexed_language_matches(indexed: &str, requested: &str) -> bool {
+pub(crate) fn indexed_language_matches(indexed: &str, requested: &str) -> bool {
     let indexed = indexed.to_ascii_lowercase();
     match requested.to_ascii_lowercase().as_str() {
         "c++" => indexed == "cpp",
@@ -4,7 +4,11 @@
 //! - Code-aware tokenization (CamelCase/snake_case splitting at index time)
 //! - Language and file pattern filtering
 //! - Line-level grep-style search
+//! - Regex search over indexed file contents
 //! - Per-workspace isolation
+//!
+//! Hits from files deleted from disk but still inside their soft-delete grace
+//! period are listed after the results (see [`crate::deleted_files`]).
 
 // Public API re-exports
 pub use self::backend::SearchBackend;
@@ -13,6 +17,7 @@ pub use self::query_preprocessor::{
     PreprocessedQuery, QueryType, detect_query_type, preprocess_query, sanitize_query,
     validate_query,
 };
+pub use self::regex_mode::SearchMode;
 pub use self::trace::{
     FilePatternDiagnostic, HintKind, LineEnrichmentStatus, SearchExecutionResult, SearchHit,
     SearchTrace, ZeroHitReason,
@@ -21,6 +26,8 @@ pub use self::types::{LineMatch, LineMatchStrategy};
 
 // Internal modules
 mod backend;
+pub mod dedupe;
+pub mod defines;
 pub mod execution;
 pub mod formatting; // Exposed for testing
 pub mod hint_formatter;
@@ -29,8 +36,12 @@ pub mod line_mode;
 pub mod nl_embeddings;
 pub mod query;
 pub mod query_preprocessor; // Public for testing
+pub mod regex_mode;
 pub mod regions;
+pub mod spelling;
 pub mod text_search;
+pub mod todo_mode;
+pub mod topics_mode;
 pub mod trace;
 mod types;
 
@@ -41,9 +52,16 @@ use serde::de::{Deserializer, Error as DeError, IntoDeserializer};
 use serde::{Deserialize, Serialize};
 use tracing::debug;
 
+use crate::deleted_files::load_deleted_files;
+use crate::frameworks::{self, FrameworkFilter};
 use crate::navigation::resolution::WorkspaceTarget;
+use crate::projects;
+use crate::streaming::{self, ResultSink};
+use crate::tool_limits::configured_max_tokens;
 use julie_core::health_types::SystemStatus;
 use julie_core::shared::OptimizedResponse;
+use julie_core::workspace_settings::{SEMANTIC_DISABLED_REASON, WorkspaceSettingsRegistry};
+use julie_index::search::hybrid::SemanticLanguageScope;
 
 use julie_context::ToolContext;
 
@@ -57,7 +75,7 @@ const MAX_LIMIT: u32 = 500;
 #[derive(Debug, Clone, Serialize, JsonSchema)]
 /// Search code and symbols using unified code-aware full-text search. Supports multi-word queries with AND/OR logic, exact symbol name matches, file-path fragments, and conceptual semantic search. Optional backend: omitted/default lexical returns mixed file+symbol hits and may show labeled semantic fallback candidates on identifier-like zero-hit queries when embeddings are ready; explicit "lexical" stays pure lexical; "semantic" and "hybrid" are symbol-only concept search. Use lexical for file/path queries.
 pub struct FastSearchTool {
-    /// Search query. Exact symbol names, file path fragments, and natural-language descriptions all work. Too many results? Add file_pattern or language filter. Zero lexical results may show labeled semantic fallback candidates for identifier-like queries when backend is omitted and embeddings are ready. Still zero? Run manage_workspace(operation="index")
+    /// Search query. Exact symbol names, file path fragments, and natural-language descriptions all work. Annotation terms (`@Deprecated`, `#[test]`, `[Authorize]`, or `annotation:pytest.fixture` in any language) keep only symbols carrying that annotation. Too many results? Add file_pattern or language filter. Zero lexical results may show labeled semantic fallback candidates for identifier-like queries when backend is omitted and embeddings are ready. Still zero? Run manage_workspace(operation="index")
     pub query: String,
     /// Language filter: "rust", "typescript", "javascript", "python", "java", "csharp", "vbnet", "php", "ruby", "swift", "kotlin", "scala", "go", "c", "cpp", "lua", "qml", "r", "sql", "html", "css", "vue", "bash", "gdscript", "dart", "zig"
     #[serde(default)]
@@ -85,6 +103,12 @@ pub struct FastSearchTool {
         deserialize_with = "julie_core::serde_lenient::deserialize_option_bool_lenient"
     )]
     pub exclude_tests: Option<bool>,
+    /// Rank generated code (protobuf stubs, `*.g.dart`, ORM migrations, files with a DO NOT EDIT header) like hand-written code (default: false; generated results rank lower and are labeled [generated])
+    #[serde(
+        default,
+        deserialize_with = "julie_core::serde_lenient::deserialize_bool_lenient"
+    )]
+    pub include_generated: bool,
     /// Search backend: omitted/default lexical uses BM25/full-text mixed file+symbol hits and may show labeled semantic fallback candidates on identifier-like zero-hit queries when embeddings are ready; explicit "lexical" stays pure lexical; "semantic" uses KNN symbol search; "hybrid" uses BM25+KNN symbol search. Semantic and hybrid are symbol-only; use lexical for file/path queries.
     #[serde(default)]
     pub backend: Option<SearchBackend>,
@@ -94,6 +118,45 @@ pub struct FastSearchTool {
     /// Return format: "full" (default, code context and rich summaries) or "locations" (file:line only)
     #[serde(default = "default_return_format")]
     pub return_format: String,
+    /// Approximate token budget for the response. When exceeded, context lines are dropped first, then doc comments, then results collapse to one line per file; a footer reports what was omitted (default: `tools.fast_search.max_tokens` or `tools.default.max_tokens` from .julie/config.toml, else unlimited).
+    #[serde(
+        default,
+        deserialize_with = "julie_core::serde_lenient::deserialize_option_u32_lenient"
+    )]
+    pub max_tokens: Option<u32>,
+    /// Collapse identical copies of a symbol (same name, kind and body, e.g. vendored libraries) into one result listing the other locations (default: true). Set false to see every copy as its own result.
+    #[serde(
+        default = "default_dedupe",
+        deserialize_with = "julie_core::serde_lenient::deserialize_bool_lenient"
+    )]
+    pub dedupe: bool,
+    /// Typo-tolerant fallback (default: false). When nothing matches, return the symbols whose names are closest to the query by edit distance (getUserAccont → getUserAccount), with "did you mean" suggestions.
+    #[serde(
+        default,
+        deserialize_with = "julie_core::serde_lenient::deserialize_bool_lenient"
+    )]
+    pub fuzzy: bool,
+    /// C/C++ build configuration as comma-separated macros, e.g. "_WIN32,LEVEL=3"; every other macro is undefined. Drops symbols inside #if/#ifdef branches that would not be compiled under it. Results from other languages are unaffected.
+    #[serde(default)]
+    pub defines: Option<String>,
+    /// Monorepo project (package) to search, as named by its Cargo.toml, package.json, go.mod or .NET project, e.g. "billing". Case-insensitive; an unknown name lists the detected projects. Results are labeled with their project either way.
+    #[serde(default)]
+    pub project: Option<String>,
+    /// Framework role to keep, e.g. "react_component", "spring_bean", "spring_endpoint", "rails_controller_action", "aspnet_controller_action", "angular_component", "flutter_widget", or a framework ("react", "angular", "spring", "rails", "aspnet", "flutter") for all of its roles. Roles are detected at index time from each project's manifests and imports; only symbol results with a matching role are kept.
+    #[serde(default)]
+    pub framework: Option<String>,
+    /// Semantic/hybrid backends: only search symbols in these languages, e.g. ["go"] or ["go", "proto"]. Applied inside the vector search, so the result count stays full.
+    #[serde(
+        default,
+        deserialize_with = "julie_core::serde_lenient::deserialize_option_vec_string_lenient"
+    )]
+    pub languages: Option<Vec<String>>,
+    /// Semantic/hybrid backends: rank symbols in these languages ahead of similar matches in other languages, without excluding anything, e.g. ["go"] while working on a Go service.
+    #[serde(
+        default,
+        deserialize_with = "julie_core::serde_lenient::deserialize_option_vec_string_lenient"
+    )]
+    pub boost_languages: Option<Vec<String>>,
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
@@ -103,6 +166,9 @@ pub struct FastSearchParams {
     /// Restrict line-level lexical matches to stored source-region kinds.
     #[serde(default)]
     pub regions: Option<String>,
+    /// "regex": treat query as a regular expression over indexed file contents; each matching line is labeled with its enclosing symbol. "todos": list TODO/FIXME/HACK comments grouped by file, with enclosing symbol, owner (TODO(name) or git blame) and age; query filters them by marker, text, assignee or path ("*" lists all). "topics": group the embedded declarations into semantic topics (k-means over the embeddings), each named by its most distinctive identifier words, with its directories and most central symbols; query filters topics by name, term or directory ("*" lists all) and limit caps how many are shown. All three honor language, file_pattern and project; regex and todos also honor exclude_tests, topics always leaves tests out.
+    #[serde(default)]
+    pub mode: Option<SearchMode>,
 }
 
 impl From<FastSearchTool> for FastSearchParams {
@@ -110,6 +176,7 @@ impl From<FastSearchTool> for FastSearchParams {
         Self {
             search,
             regions: None,
+            mode: None,
         }
     }
 }
@@ -133,12 +200,48 @@ struct FastSearchToolSerde {
         deserialize_with = "julie_core::serde_lenient::deserialize_option_bool_lenient"
     )]
     exclude_tests: Option<bool>,
+    #[serde(
+        default,
+        deserialize_with = "julie_core::serde_lenient::deserialize_bool_lenient"
+    )]
+    include_generated: bool,
     #[serde(default)]
     backend: Option<SearchBackend>,
     #[serde(default = "default_workspace")]
     workspace: Option<String>,
     #[serde(default = "default_return_format")]
     return_format: String,
+    #[serde(
+        default,
+        deserialize_with = "julie_core::serde_lenient::deserialize_option_u32_lenient"
+    )]
+    max_tokens: Option<u32>,
+    #[serde(
+        default = "default_dedupe",
+        deserialize_with = "julie_core::serde_lenient::deserialize_bool_lenient"
+    )]
+    dedupe: bool,
+    #[serde(
+        default,
+        deserialize_with = "julie_core::serde_lenient::deserialize_bool_lenient"
+    )]
+    fuzzy: bool,
+    #[serde(default)]
+    defines: Option<String>,
+    #[serde(default)]
+    project: Option<String>,
+    #[serde(default)]
+    framework: Option<String>,
+    #[serde(
+        default,
+        deserialize_with = "julie_core::serde_lenient::deserialize_option_vec_string_lenient"
+    )]
+    languages: Option<Vec<String>>,
+    #[serde(
+        default,
+        deserialize_with = "julie_core::serde_lenient::deserialize_option_vec_string_lenient"
+    )]
+    boost_languages: Option<Vec<String>>,
 }
 
 impl<'de> Deserialize<'de> for FastSearchTool {
@@ -159,9 +262,18 @@ impl<'de> Deserialize<'de> for FastSearchTool {
             limit: raw.limit,
             context_lines,
             exclude_tests: raw.exclude_tests,
+            include_generated: raw.include_generated,
             backend: raw.backend,
             workspace: raw.workspace,
             return_format: raw.return_format,
+            max_tokens: raw.max_tokens,
+            dedupe: raw.dedupe,
+            fuzzy: raw.fuzzy,
+            defines: raw.defines,
+            project: raw.project,
+            framework: raw.framework,
+            languages: raw.languages,
+            boost_languages: raw.boost_languages,
         })
     }
 }
@@ -191,6 +303,9 @@ fn default_context_lines() -> Option<u32> {
 fn default_return_format() -> String {
     "full".to_string()
 }
+fn default_dedupe() -> bool {
+    true
+}
 
 fn deserialize_presence_tracked_option_u32<'de, D>(
     deserializer: D,
@@ -220,9 +335,18 @@ impl Default for FastSearchTool {
             limit: default_limit(),
             context_lines: default_context_lines(),
             exclude_tests: None,
+            include_generated: false,
             backend: None,
             workspace: default_workspace(),
             return_format: default_return_format(),
+            max_tokens: None,
+            dedupe: default_dedupe(),
+            fuzzy: false,
+            defines: None,
+            project: None,
+            framework: None,
+            languages: None,
+            boost_languages: None,
         }
     }
 }
@@ -241,7 +365,7 @@ impl FastSearchParams {
         &self,
         handler: &dyn ToolContext,
     ) -> Result<FastSearchExecution> {
-        if self.regions.is_none() {
+        if self.regions.is_none() && self.mode.is_none() {
             return self.search.execute_with_trace(handler).await;
         }
         let workspace_target = self.search.resolve_workspace_filter(handler).await?;
@@ -254,10 +378,40 @@ impl FastSearchParams {
         handler: &dyn ToolContext,
         workspace_target: WorkspaceTarget,
     ) -> Result<FastSearchExecution> {
+        self.execute_with_trace_streaming(handler, workspace_target, None)
+            .await
+    }
+
+    /// Same as `execute_with_trace_with_target`, also sending the hits to
+    /// `sink` as soon as they are found (see [`crate::streaming`]).
+    pub async fn execute_with_trace_streaming(
+        &self,
+        handler: &dyn ToolContext,
+        workspace_target: WorkspaceTarget,
+        sink: Option<&dyn ResultSink>,
+    ) -> Result<FastSearchExecution> {
+        match self.mode {
+            Some(SearchMode::Regex) => {
+                return self
+                    .execute_regex_search(handler, workspace_target, sink)
+                    .await;
+            }
+            Some(SearchMode::Todos) => {
+                return self
+                    .execute_todo_listing(handler, workspace_target, sink)
+                    .await;
+            }
+            Some(SearchMode::Topics) => {
+                return self
+                    .execute_topic_map(handler, workspace_target, sink)
+                    .await;
+            }
+            None => {}
+        }
         let Some(regions) = self.regions.as_deref() else {
             return self
                 .search
-                .execute_with_trace_with_target(handler, workspace_target)
+                .execute_with_trace_streaming(handler, workspace_target, sink)
                 .await;
         };
         if matches!(
@@ -268,27 +422,29 @@ impl FastSearchParams {
                 "regions require lexical search; semantic and hybrid backends search symbols"
             );
         }
+        if self.search.framework_filter()?.is_some() {
+            anyhow::bail!("regions match source lines; the framework filter needs symbol results");
+        }
 
         let region_filter = regions::SourceRegionFilter::parse(regions)?;
+        let project = self
+            .search
+            .resolve_project_filter(handler, &workspace_target)
+            .await?;
         let line_result = line_mode::line_mode_matches_in_regions(
             &self.search.query,
             &self.search.language,
             &self.search.file_pattern,
-            self.search.effective_limit(),
+            self.search.fetch_limit(project.as_deref()),
             self.search.exclude_tests,
             &workspace_target,
             handler,
             &region_filter,
         )
         .await?;
-        let workspace_label = match &workspace_target {
-            WorkspaceTarget::Primary => handler
-                .require_primary_workspace_identity()
-                .unwrap_or_else(|_| "primary".to_string()),
-            WorkspaceTarget::Target(id) => id.clone(),
-        };
+        let workspace_label = workspace_label(handler, &workspace_target);
         let requested_language = self.search.language.clone();
-        let hits = line_result
+        let mut hits = line_result
             .matches
             .into_iter()
             .map(|line_match| {
@@ -304,6 +460,13 @@ impl FastSearchParams {
                 SearchHit::from_line_match(line_match, workspace_label.clone(), language, 0.0)
             })
             .collect::<Vec<_>>();
+        self.search
+            .apply_project_filter(&mut hits, project.as_deref(), &workspace_target, handler)
+            .await?;
+        hits.truncate(self.search.effective_limit() as usize);
+        if let Some(sink) = sink {
+            self.search.stream_hits(sink, &hits).await;
+        }
         let total_results = hits.len();
         let mut execution = SearchExecutionResult::new(
             hits,
@@ -326,6 +489,10 @@ impl FastSearchParams {
             execution.trace.scope_rescue_count = 1;
         }
 
+        let max_tokens = match self.search.max_tokens {
+            Some(max_tokens) => Some(max_tokens),
+            None => configured_max_tokens(handler, &workspace_target, "fast_search").await,
+        };
         let result = if execution.hits.is_empty() {
             CallToolResult::text_content(vec![Content::text(format!(
                 "No results found for '{}' inside source regions: {}",
@@ -335,6 +502,13 @@ impl FastSearchParams {
             let output = if self.search.return_format == "locations" {
                 let response = OptimizedResponse::with_total(execution.hits.clone(), total_results);
                 formatting::format_content_locations_only(&self.search.query, &response)
+            } else if let Some(max_tokens) = max_tokens {
+                formatting::format_budgeted_search_results(
+                    &self.search.query,
+                    &execution.hits,
+                    total_results,
+                    max_tokens,
+                )
             } else {
                 format_region_search_results(&self.search.query, &execution.hits)
             };
@@ -355,6 +529,8 @@ impl FastSearchParams {
             } else {
                 output
             };
+            let deleted_files = load_deleted_files(handler, &workspace_target).await;
+            let output = output + &formatting::format_deleted_hits(&execution.hits, &deleted_files);
             CallToolResult::text_content(vec![Content::text(output)])
         };
 
@@ -365,13 +541,24 @@ impl FastSearchParams {
     }
 }
 
+/// The workspace name content hits are labeled with.
+fn workspace_label(handler: &dyn ToolContext, workspace_target: &WorkspaceTarget) -> String {
+    match workspace_target {
+        WorkspaceTarget::Primary => handler
+            .require_primary_workspace_identity()
+            .unwrap_or_else(|_| "primary".to_string()),
+        WorkspaceTarget::Target(id) => id.clone(),
+    }
+}
+
 fn format_region_search_results(query: &str, hits: &[SearchHit]) -> String {
     let mut output = format!("{} matches for \"{}\":\n", hits.len(), query);
     for hit in hits {
         output.push_str(&format!(
-            "{}:{}\n  {}\n",
+            "{}:{}{}\n  {}\n",
             hit.file,
             hit.line.unwrap_or_default(),
+            projects::project_label(hit.project.as_deref()),
             hit.snippet.as_deref().unwrap_or_default().trim()
         ));
     }
@@ -383,6 +570,90 @@ impl FastSearchTool {
         clamp_limit(self.limit)
     }
 
+    /// Send `hits` to `sink` as location lines, in batches.
+    async fn stream_hits(&self, sink: &dyn ResultSink, hits: &[SearchHit]) {
+        let lines: Vec<String> = hits.iter().map(formatting::format_hit_location).collect();
+        let heading = format!("fast_search \"{}\": results", self.query);
+        streaming::stream_lines(sink, &heading, &lines, 0, Some(lines.len())).await;
+    }
+
+    /// Candidates to fetch: `project` and `framework` filters discard hits
+    /// after ranking, so fetch extra to still fill the limit.
+    fn fetch_limit(&self, project: Option<&str>) -> u32 {
+        if project.is_some() || self.framework.is_some() {
+            self.effective_limit().saturating_mul(4).min(MAX_LIMIT)
+        } else {
+            self.effective_limit()
+        }
+    }
+
+    /// The `languages` / `boost_languages` parameters for the KNN side.
+    fn semantic_language_scope(&self) -> SemanticLanguageScope {
+        SemanticLanguageScope::new(
+            self.languages.as_deref().unwrap_or_default(),
+            self.boost_languages.as_deref().unwrap_or_default(),
+        )
+    }
+
+    /// The `project` parameter resolved to an indexed project name.
+    async fn resolve_project_filter(
+        &self,
+        handler: &dyn ToolContext,
+        workspace_target: &WorkspaceTarget,
+    ) -> Result<Option<String>> {
+        let Some(requested) = self.project.as_deref().filter(|p| !p.trim().is_empty()) else {
+            return Ok(None);
+        };
+        let db = projects::project_database(handler, workspace_target).await?;
+        let requested = requested.to_string();
+        let project =
+            tokio::task::spawn_blocking(move || projects::resolve_project(&db, &requested))
+                .await??;
+        Ok(Some(project))
+    }
+
+    /// The `framework` parameter resolved to a framework or role.
+    fn framework_filter(&self) -> Result<Option<FrameworkFilter>> {
+        self.framework
+            .as_deref()
+            .filter(|f| !f.trim().is_empty())
+            .map(frameworks::resolve_framework_filter)
+            .transpose()
+    }
+
+    /// Keep only the hits whose symbol plays a role matching `filter`.
+    /// Returns how many hits were dropped.
+    async fn apply_framework_filter(
+        &self,
+        hits: &mut Vec<SearchHit>,
+        filter: FrameworkFilter,
+        workspace_target: &WorkspaceTarget,
+        handler: &dyn ToolContext,
+    ) -> Result<usize> {
+        let roles = frameworks::load_hit_roles(hits, workspace_target, handler).await?;
+        Ok(frameworks::retain_framework_hits(hits, &roles, filter))
+    }
+
+    /// Label `hits` with their project and keep only `project`'s, if set.
+    /// Returns how many hits were dropped. Labels are best-effort; the filter
+    /// is not.
+    async fn apply_project_filter(
+        &self,
+        hits: &mut Vec<SearchHit>,
+        project: Option<&str>,
+        workspace_target: &WorkspaceTarget,
+        handler: &dyn ToolContext,
+    ) -> Result<usize> {
+        match projects::load_hit_projects(hits, workspace_target, handler).await {
+            Ok(map) => Ok(projects::label_and_retain_hits(hits, &map, project)),
+            Err(err) if project.is_none() => {
+                debug!("Skipping project labels: {}", err);
+                Ok(0)
+            }
+            Err(err) => Err(err),
+        }
+    }
+
     fn with_backend_fallback_note(
         &self,
         text: String,
@@ -394,6 +665,14 @@ impl FastSearchTool {
             );
         }
 
+        if execution.trace.strategy_id == "fast_search_fuzzy_fallback" {
+            return format!(
+                "NOTE: No exact matches for '{}'. Showing the closest symbol names.{}\n\n{text}",
+                self.query,
+                spelling::format_suggestions(&execution.trace.suggestions)
+            );
+        }
+
         if !execution.trace.backend_fallback {
             return text;
         }
@@ -433,6 +712,19 @@ impl FastSearchTool {
         &self,
         handler: &dyn ToolContext,
         workspace_target: WorkspaceTarget,
+    ) -> Result<FastSearchExecution> {
+        self.execute_with_trace_streaming(handler, workspace_target, None)
+            .await
+    }
+
+    /// Same as `execute_with_trace_with_target`, also sending the ranked hits
+    /// to `sink` before snippet enrichment and formatting (see
+    /// [`crate::streaming`]).
+    pub async fn execute_with_trace_streaming(
+        &self,
+        handler: &dyn ToolContext,
+        workspace_target: WorkspaceTarget,
+        sink: Option<&dyn ResultSink>,
     ) -> Result<FastSearchExecution> {
         debug!("🔍 Fast search (unified): {}", self.query);
 
@@ -446,6 +738,20 @@ impl FastSearchTool {
         }
 
         let effective_limit = self.effective_limit();
+        let configuration = self
+            .defines
+            .as_deref()
+            .map(defines::parse_defines)
+            .transpose()?;
+
+        if let Some(backend @ (SearchBackend::Semantic | SearchBackend::Hybrid)) = self.backend {
+            if semantic_disabled_for(handler, &workspace_target).await {
+                anyhow::bail!(
+                    "backend={} is unavailable: {SEMANTIC_DISABLED_REASON}. Use backend=\"lexical\"; keyword search, references and call paths are unaffected.",
+                    backend.as_str()
+                );
+            }
+        }
 
         if let WorkspaceTarget::Target(target_workspace_id) = &workspace_target {
             if let Some(index_error) = handler
@@ -622,21 +928,81 @@ impl FastSearchTool {
             }
         }
 
+        let project = self
+            .resolve_project_filter(handler, &workspace_target)
+            .await?;
+        let framework = self.framework_filter()?;
         let mut execution = execution::execute_search_unified(
             execution::SearchExecutionParams {
                 query: &self.query,
                 language: &self.language,
                 file_pattern: &self.file_pattern,
-                limit: effective_limit,
+                limit: self.fetch_limit(project.as_deref()),
                 context_lines: self.context_lines,
                 exclude_tests: self.exclude_tests,
+                include_generated: self.include_generated,
                 backend: SearchBackend::resolve(self.backend),
+                fuzzy: self.fuzzy,
+                language_scope: self.semantic_language_scope(),
             },
             &execution_workspaces,
             handler,
         )
         .await?;
 
+        let mut dropped = self
+            .apply_project_filter(
+                &mut execution.hits,
+                project.as_deref(),
+                &workspace_target,
+                handler,
+            )
+            .await?;
+        if let Some(filter) = framework {
+            dropped += self
+                .apply_framework_filter(&mut execution.hits, filter, &workspace_target, handler)
+                .await?;
+        }
+        if project.is_some() || framework.is_some() {
+            execution.total_results = execution.total_results.saturating_sub(dropped);
+            execution.hits.truncate(effective_limit as usize);
+            execution.trace.refresh_hits(&execution.hits);
+        }
+
+        if let Some(configuration) = &configuration {
+            match defines::load_conditions(&execution.hits, &workspace_target, handler).await {
+                Ok(conditions) => {
+                    let dropped = defines::retain_configuration(
+                        &mut execution.hits,
+                        &conditions,
+                        configuration,
+                    );
+                    if dropped > 0 {
+                        execution.total_results = execution.total_results.saturating_sub(dropped);
+                        execution.trace.refresh_hits(&execution.hits);
+                    }
+                }
+                Err(err) => debug!("Skipping preprocessor filter: {}", err),
+            }
+        }
+
+        if self.dedupe {
+            match dedupe::load_symbol_copies(&execution.hits, &workspace_target, handler).await {
+                Ok(copies) => {
+                    let collapsed = dedupe::collapse_symbol_copies(&mut execution.hits, &copies);
+                    if collapsed > 0 {
+                        execution.total_results = execution.total_results.saturating_sub(collapsed);
+                        execution.trace.refresh_hits(&execution.hits);
+                    }
+                }
+                Err(err) => debug!("Skipping duplicate collapse: {}", err),
+            }
+        }
+
+        if let Some(sink) = sink {
+            self.stream_hits(sink, &execution.hits).await;
+        }
+
         // T12 fix: the unified search returns mixed file+symbol hits.  Pulling
         // only `definition_symbols()` silently drops file rows, which is what
         // caused the Phase 2 file/path-search regression (Eros bakeoff −46).
@@ -653,7 +1019,10 @@ impl FastSearchTool {
         execution.trace.definition_exact_match = has_exact_name_match;
         let symbol_backend_active = matches!(
             execution.trace.strategy_id.as_str(),
-            "fast_search_semantic" | "fast_search_hybrid" | "fast_search_semantic_fallback"
+            "fast_search_semantic"
+                | "fast_search_hybrid"
+                | "fast_search_semantic_fallback"
+                | "fast_search_fuzzy_fallback"
         );
 
         if execution.hits.is_empty() {
@@ -679,12 +1048,17 @@ impl FastSearchTool {
                     self.query
                 )
             };
+            let message = format!(
+                "{message}{}",
+                spelling::format_suggestions(&execution.trace.suggestions)
+            );
             let message = self.with_backend_fallback_note(message, &execution);
             return Ok(FastSearchExecution {
                 result: CallToolResult::text_content(vec![Content::text(message)]),
                 execution: Some(execution),
             });
         }
+        let deleted_files = load_deleted_files(handler, &workspace_target).await;
 
         if self.return_format != "locations" && !has_exact_name_match && !symbol_backend_active {
             if let Err(err) = self
@@ -723,7 +1097,8 @@ impl FastSearchTool {
                         } else {
                             locations_text
                         };
-                        let final_text = self.with_backend_fallback_note(final_text, &execution);
+                        let final_text = self.with_backend_fallback_note(final_text, &execution)
+                            + &formatting::format_deleted_hits(&execution.hits, &deleted_files);
                         return Ok(FastSearchExecution {
                             result: CallToolResult::text_content(vec![Content::text(final_text)]),
                             execution: Some(execution),
@@ -751,6 +1126,10 @@ impl FastSearchTool {
             }
             locations_output = with_scope_rescue_header(locations_output, &execution);
             locations_output = self.with_backend_fallback_note(locations_output, &execution);
+            locations_output.push_str(&formatting::format_deleted_hits(
+                &execution.hits,
+                &deleted_files,
+            ));
             return Ok(FastSearchExecution {
                 result: CallToolResult::text_content(vec![Content::text(locations_output)]),
                 execution: Some(execution),
@@ -761,11 +1140,23 @@ impl FastSearchTool {
         // (kind == "file") appear in the output alongside symbol rows.  Without
         // this, path-shaped queries silently dropped their target file row at
         // the formatter boundary, causing the Phase 2 file/path-search regression.
-        let lean_output = formatting::format_unified_search_results(
-            &self.query,
-            &execution.hits,
-            execution.total_results,
-        );
+        let max_tokens = match self.max_tokens {
+            Some(max_tokens) => Some(max_tokens),
+            None => configured_max_tokens(handler, &workspace_target, "fast_search").await,
+        };
+        let lean_output = match max_tokens {
+            Some(max_tokens) => formatting::format_budgeted_search_results(
+                &self.query,
+                &execution.hits,
+                execution.total_results,
+                max_tokens,
+            ),
+            None => formatting::format_unified_search_results(
+                &self.query,
+                &execution.hits,
+                execution.total_results,
+            ),
+        };
 
         // Prepend relaxed-match indicator when OR fallback was used
         let lean_output = if execution.relaxed {
@@ -798,7 +1189,8 @@ impl FastSearchTool {
         } else {
             lean_output
         };
-        let lean_output = self.with_backend_fallback_note(lean_output, &execution);
+        let lean_output = self.with_backend_fallback_note(lean_output, &execution)
+            + &formatting::format_deleted_hits(&execution.hits, &deleted_files);
 
         debug!(
             "✅ Returning unified search results ({} chars, {} results, relaxed: {})",
@@ -859,12 +1251,7 @@ impl FastSearchTool {
             return Ok(None);
         }
 
-        let workspace_label = match workspace_target {
-            WorkspaceTarget::Primary => handler
-                .require_primary_workspace_identity()
-                .unwrap_or_else(|_| "primary".to_string()),
-            WorkspaceTarget::Target(id) => id.clone(),
-        };
+        let workspace_label = workspace_label(handler, workspace_target);
 
         let scope_rescue_header = line_result
             .scope_relaxed
@@ -939,6 +1326,11 @@ impl FastSearchTool {
         if has_exact_name_match || symbol_backend_active || execution.trace.scope_relaxed {
             return false;
         }
+        // Line scanning knows nothing of projects or framework roles; keep
+        // the filtered hits.
+        if self.project.is_some() || self.framework.is_some() {
+            return false;
+        }
         if query::looks_like_file_or_path_query(&self.query)
             || looks_like_structured_lookup(&self.query)
         {
@@ -1077,6 +1469,31 @@ fn looks_like_structured_lookup(query: &str) -> bool {
     }
 }
 
+/// Root directory of the target workspace, when it can be resolved.
+async fn workspace_root(
+    handler: &dyn ToolContext,
+    workspace_target: &WorkspaceTarget,
+) -> Option<std::path::PathBuf> {
+    match workspace_target {
+        WorkspaceTarget::Primary => handler.require_primary_workspace_root().ok(),
+        WorkspaceTarget::Target(id) => handler.get_workspace_root_for_target(id).await.ok(),
+    }
+}
+
+/// `semantic.mode = "disabled"` in the target workspace's `.julie/config.toml`.
+async fn semantic_disabled_for(
+    handler: &dyn ToolContext,
+    workspace_target: &WorkspaceTarget,
+) -> bool {
+    workspace_root(handler, workspace_target)
+        .await
+        .is_some_and(|root| {
+            WorkspaceSettingsRegistry::global()
+                .get(&root)
+                .semantic_disabled()
+        })
+}
+
 fn missing_index_message(workspace_id: Option<&str>) -> String {
     match workspace_id {
         Some(id) => format!(
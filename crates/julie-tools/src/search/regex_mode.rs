@@ -0,0 +1,419 @@
+//! Regex search over indexed file contents (`mode: "regex"`).
+//!
+//! Scans the content stored for every indexed file, so the ignore rules
+//! applied at index time hold, and labels each matching line with the
+//! innermost symbol that encloses it. The `regex` crate matches in linear
+//! time; pattern length, compiled program size, scanned bytes and wall time
+//! are capped on top of that so one query cannot stall the server.
+
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+use anyhow::{Result, anyhow, bail};
+use julie_core::database::SymbolDatabase;
+use julie_core::mcp_compat::{CallToolResult, CallToolResultExt, Content};
+use julie_core::shared::OptimizedResponse;
+use julie_extractors::base::{Symbol, SymbolKind};
+use julie_index::search::scoring::is_test_path;
+use regex::{Regex, RegexBuilder};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use julie_context::ToolContext;
+
+use super::line_mode::indexed_language_matches;
+use super::query::matches_glob_pattern;
+use super::trace::{self, SearchExecutionResult, SearchHit, ZeroHitReason};
+use super::types::LineMatch;
+use super::{FastSearchExecution, FastSearchParams, SearchBackend, formatting, workspace_label};
+use crate::navigation::resolution::WorkspaceTarget;
+use crate::projects;
+use crate::streaming::ResultSink;
+
+/// Longest accepted pattern, in bytes.
+pub const MAX_PATTERN_LEN: usize = 1_000;
+/// Compiled program size limit (`RegexBuilder::size_limit`).
+const REGEX_SIZE_LIMIT: usize = 1 << 20;
+/// Lazy DFA cache limit (`RegexBuilder::dfa_size_limit`).
+const REGEX_DFA_SIZE_LIMIT: usize = 4 << 20;
+/// Longest line shown as a snippet, in characters.
+const MAX_SNIPPET_CHARS: usize = 240;
+
+/// How `query` is interpreted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum SearchMode {
+    /// `query` is a regular expression matched against indexed file contents.
+    Regex,
+    /// List TODO / FIXME / HACK comments; `query` filters them (see
+    /// [`super::todo_mode`]).
+    Todos,
+    /// Map the workspace's semantic topics; `query` filters them (see
+    /// [`super::topics_mode`]).
+    Topics,
+}
+
+/// Caps on one regex scan.
+#[derive(Debug, Clone, Copy)]
+pub struct RegexScanBudget {
+    /// File content bytes read before the scan stops.
+    pub max_bytes: usize,
+    /// Wall time before the scan stops.
+    pub deadline: Duration,
+}
+
+impl Default for RegexScanBudget {
+    fn default() -> Self {
+        Self {
+            max_bytes: 256 << 20,
+            deadline: Duration::from_secs(5),
+        }
+    }
+}
+
+/// Which files a regex scan reads.
+#[derive(Debug, Clone, Default)]
+pub struct RegexScanFilters {
+    pub language: Option<String>,
+    pub file_pattern: Option<String>,
+    pub exclude_tests: bool,
+}
+
+/// Why a scan stopped before reading every file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RegexScanStop {
+    /// Enough matching lines were collected.
+    Limit,
+    /// The byte budget ran out.
+    ByteBudget,
+    /// The time budget ran out.
+    Deadline,
+}
+
+/// A matching line and its innermost enclosing symbol.
+#[derive(Debug, Clone)]
+pub struct RegexMatch {
+    pub line_match: LineMatch,
+    pub language: String,
+    pub enclosing: Option<Symbol>,
+}
+
+#[derive(Debug, Default)]
+pub struct RegexScan {
+    pub matches: Vec<RegexMatch>,
+    pub files_scanned: usize,
+    pub bytes_scanned: usize,
+    pub stopped: Option<RegexScanStop>,
+}
+
+/// Compile `pattern` under the size limits. Patterns that match the empty
+/// string are refused: they match every line.
+pub fn compile_pattern(pattern: &str) -> Result<Regex> {
+    if pattern.trim().is_empty() {
+        bail!("regex mode needs a non-empty pattern");
+    }
+    if pattern.len() > MAX_PATTERN_LEN {
+        bail!(
+            "regex pattern is {} bytes; the limit is {MAX_PATTERN_LEN}",
+            pattern.len()
+        );
+    }
+    let regex = RegexBuilder::new(pattern)
+        .multi_line(true)
+        .size_limit(REGEX_SIZE_LIMIT)
+        .dfa_size_limit(REGEX_DFA_SIZE_LIMIT)
+        .build()
+        .map_err(|err| anyhow!("invalid regex pattern: {err}"))?;
+    if regex.is_match("") {
+        bail!("regex pattern '{pattern}' matches the empty string, so it would match every line");
+    }
+    Ok(regex)
+}
+
+/// Match `regex` against every indexed file that passes `filters`, one hit
+/// per matching line, until `limit` lines are found or `budget` runs out.
+pub fn scan_indexed_files(
+    db: &SymbolDatabase,
+    regex: &Regex,
+    filters: &RegexScanFilters,
+    limit: usize,
+    budget: RegexScanBudget,
+) -> Result<RegexScan> {
+    let started = Instant::now();
+    let mut scan = RegexScan::default();
+    db.for_each_file_content(|path, language, content| {
+        if filters
+            .file_pattern
+            .as_deref()
+            .is_some_and(|pattern| !matches_glob_pattern(path, pattern))
+            || filters
+                .language
+                .as_deref()
+                .is_some_and(|requested| !indexed_language_matches(language, requested))
+            || (filters.exclude_tests && is_test_path(path))
+        {
+            return true;
+        }
+        if scan.bytes_scanned + content.len() > budget.max_bytes {
+            scan.stopped = Some(RegexScanStop::ByteBudget);
+            return false;
+        }
+        scan.files_scanned += 1;
+        scan.bytes_scanned += content.len();
+        for line_match in matching_lines(regex, path, content, limit - scan.matches.len()) {
+            scan.matches.push(RegexMatch {
+                line_match,
+                language: language.to_string(),
+                enclosing: None,
+            });
+        }
+        if scan.matches.len() >= limit {
+            scan.stopped = Some(RegexScanStop::Limit);
+            return false;
+        }
+        if started.elapsed() >= budget.deadline {
+            scan.stopped = Some(RegexScanStop::Deadline);
+            return false;
+        }
+        true
+    })?;
+
+    let mut symbols_by_file: HashMap<String, Vec<Symbol>> = HashMap::new();
+    for regex_match in &mut scan.matches {
+        let path = &regex_match.line_match.file_path;
+        if !symbols_by_file.contains_key(path) {
+            symbols_by_file.insert(path.clone(), db.get_symbols_for_file(path)?);
+        }
+        regex_match.enclosing = enclosing_symbol(
+            &symbols_by_file[path],
+            regex_match.line_match.line_number as u32,
+        )
+        .cloned();
+    }
+    Ok(scan)
+}
+
+/// The first `max` lines of `content` that `regex` matches. A match spanning
+/// lines is reported on the line where it starts.
+pub fn matching_lines(regex: &Regex, path: &str, content: &str, max: usize) -> Vec<LineMatch> {
+    let mut lines = Vec::new();
+    let mut line_number = 1;
+    let mut counted_to = 0;
+    let mut start = 0;
+    while lines.len() < max
+        && start <= content.len()
+        && let Some(found) = regex.find_at(content, start)
+    {
+        line_number += content[counted_to..found.start()]
+            .bytes()
+            .filter(|byte| *byte == b'\n')
+            .count();
+        let line_start = content[..found.start()].rfind('\n').map_or(0, |i| i + 1);
+        let line_end = content[found.start()..]
+            .find('\n')
+            .map_or(content.len(), |i| found.start() + i);
+        lines.push(LineMatch {
+            file_path: path.to_string(),
+            line_number,
+            line_content: snippet(&content[line_start..line_end]),
+        });
+        counted_to = found.start();
+        start = line_end + 1;
+    }
+    lines
+}
+
+fn snippet(line: &str) -> String {
+    let line = line.trim_end_matches('\r');
+    match line.char_indices().nth(MAX_SNIPPET_CHARS) {
+        Some((cut, _)) => format!("{}…", &line[..cut]),
+        None => line.to_string(),
+    }
+}
+
+/// The innermost container symbol (function, type, module) whose line range
+/// covers `line`.
+pub fn enclosing_symbol(symbols: &[Symbol], line: u32) -> Option<&Symbol> {
+    symbols
+        .iter()
+        .filter(|symbol| {
+            matches!(
+                symbol.kind,
+                SymbolKind::Function
+                    | SymbolKind::Method
+                    | SymbolKind::Constructor
+                    | SymbolKind::Class
+                    | SymbolKind::Struct
+                    | SymbolKind::Interface
+                    | SymbolKind::Trait
+                    | SymbolKind::Enum
+                    | SymbolKind::Module
+                    | SymbolKind::Namespace
+            ) && symbol.start_line <= line
+                && line <= symbol.end_line
+        })
+        .min_by_key(|symbol| {
+            (
+                symbol.end_line - symbol.start_line,
+                u32::MAX - symbol.start_line,
+            )
+        })
+}
+
+impl FastSearchParams {
+    pub(super) async fn execute_regex_search(
+        &self,
+        handler: &dyn ToolContext,
+        workspace_target: WorkspaceTarget,
+        sink: Option<&dyn ResultSink>,
+    ) -> Result<FastSearchExecution> {
+        if matches!(
+            self.search.backend,
+            Some(SearchBackend::Semantic | SearchBackend::Hybrid)
+        ) {
+            bail!("regex mode matches file contents; semantic and hybrid backends search symbols");
+        }
+        if self.regions.is_some() {
+            bail!("regex mode cannot be combined with regions");
+        }
+        if self.search.framework_filter()?.is_some() {
+            bail!("regex mode matches source lines; the framework filter needs symbol results");
+        }
+
+        let regex = compile_pattern(&self.search.query)?;
+        let project = self
+            .search
+            .resolve_project_filter(handler, &workspace_target)
+            .await?;
+        let filters = RegexScanFilters {
+            language: self.search.language.clone(),
+            file_pattern: self.search.file_pattern.clone(),
+            exclude_tests: self.search.exclude_tests.unwrap_or(false),
+        };
+        let limit = self.search.fetch_limit(project.as_deref()) as usize;
+        let db = projects::project_database(handler, &workspace_target).await?;
+        let scan = tokio::task::spawn_blocking(move || {
+            scan_indexed_files(&db, &regex, &filters, limit, RegexScanBudget::default())
+        })
+        .await??;
+
+        let workspace_label = workspace_label(handler, &workspace_target);
+        let mut enclosing_labels = HashMap::new();
+        let mut hits = scan
+            .matches
+            .into_iter()
+            .map(|regex_match| {
+                let mut hit = SearchHit::from_line_match(
+                    regex_match.line_match,
+                    workspace_label.clone(),
+                    regex_match.language,
+                    0.0,
+                );
+                if let Some(symbol) = regex_match.enclosing {
+                    enclosing_labels.insert(
+                        symbol.id.clone(),
+                        format!("{} {}", symbol.kind, symbol.name),
+                    );
+                    hit.symbol_id = Some(symbol.id);
+                }
+                hit
+            })
+            .collect::<Vec<_>>();
+        self.search
+            .apply_project_filter(&mut hits, project.as_deref(), &workspace_target, handler)
+            .await?;
+        hits.truncate(self.search.effective_limit() as usize);
+        if let Some(sink) = sink {
+            self.search.stream_hits(sink, &hits).await;
+        }
+        let total_results = hits.len();
+        let partial = matches!(
+            scan.stopped,
+            Some(RegexScanStop::ByteBudget | RegexScanStop::Deadline)
+        );
+        let mut execution = SearchExecutionResult::new(
+            hits,
+            false,
+            total_results,
+            if partial {
+                "fast_search_regex_partial"
+            } else {
+                "fast_search_regex"
+            },
+            trace::SearchExecutionKind::Content {
+                workspace_label: Some(workspace_label),
+                file_level: false,
+            },
+        );
+        execution.trace.line_match_strategy = Some("regex".to_string());
+        if execution.hits.is_empty() {
+            execution.trace.zero_hit_reason = Some(ZeroHitReason::LineMatchMiss);
+        }
+
+        let stop_note = match scan.stopped {
+            Some(RegexScanStop::ByteBudget) => Some(format!(
+                "Scan stopped at the {} MB content budget after {} files; narrow it with file_pattern or language for complete results.",
+                RegexScanBudget::default().max_bytes >> 20,
+                scan.files_scanned
+            )),
+            Some(RegexScanStop::Deadline) => Some(format!(
+                "Scan stopped at the {}s time budget after {} files; narrow it with file_pattern or language for complete results.",
+                RegexScanBudget::default().deadline.as_secs(),
+                scan.files_scanned
+            )),
+            Some(RegexScanStop::Limit) | None => None,
+        };
+        let output = if execution.hits.is_empty() {
+            format!(
+                "No lines match regex '{}' in {} indexed files",
+                self.search.query, scan.files_scanned
+            )
+        } else if self.search.return_format == "locations" {
+            let response = OptimizedResponse::with_total(execution.hits.clone(), total_results);
+            formatting::format_content_locations_only(&self.search.query, &response)
+        } else if let Some(max_tokens) = self.search.max_tokens {
+            formatting::format_budgeted_search_results(
+                &self.search.query,
+                &execution.hits,
+                total_results,
+                max_tokens,
+            )
+        } else {
+            format_regex_search_results(&self.search.query, &execution.hits, &enclosing_labels)
+        };
+        let output = match stop_note {
+            Some(note) => format!("{output}\n\n{note}"),
+            None => output,
+        };
+
+        Ok(FastSearchExecution {
+            result: CallToolResult::text_content(vec![Content::text(output)]),
+            execution: Some(execution),
+        })
+    }
+}
+
+fn format_regex_search_results(
+    pattern: &str,
+    hits: &[SearchHit],
+    enclosing_labels: &HashMap<String, String>,
+) -> String {
+    let mut output = format!("{} lines match regex '{}':\n", hits.len(), pattern);
+    for hit in hits {
+        let enclosing = hit
+            .symbol_id
+            .as_ref()
+            .and_then(|id| enclosing_labels.get(id))
+            .map(|label| format!(" (in {label})"))
+            .unwrap_or_default();
+        output.push_str(&format!(
+            "{}:{}{}{}\n  {}\n",
+            hit.file,
+            hit.line.unwrap_or_default(),
+            enclosing,
+            projects::project_label(hit.project.as_deref()),
+            hit.snippet.as_deref().unwrap_or_default().trim()
+        ));
+    }
+    output.trim_end().to_string()
+}
@@ -0,0 +1,166 @@
+//! "Did you mean" suggestions for zero-hit searches.
+//!
+//! When a search comes back empty, the query is checked against the
+//! workspace's symbol-name dictionary (most-referenced names first). Two
+//! kinds of suggestion come out of it:
+//!
+//! - a respelled query, built by replacing each query token the dictionary
+//!   has never seen with the closest identifier token it has
+//!   (`usr servic` → `user service`);
+//! - whole symbol names whose lowercased, separator-free form is within a
+//!   small edit distance of the query's (`usr servic` → `UserService`).
+//!
+//! Suggestions are advisory: they land on `SearchTrace.suggestions` and in
+//! the zero-hit message so agents can retry without a human in the loop.
+//! With `fuzzy=true`, the near-miss names also become results: their symbols
+//! are returned ranked by edit distance instead of an empty page.
+
+use std::collections::{HashMap, HashSet};
+
+use julie_core::string_similarity::levenshtein_distance;
+use julie_index::search::tokenizer::split_camel_case;
+
+/// Maximum suggestions returned for one query.
+pub const MAX_SUGGESTIONS: usize = 5;
+
+/// Names loaded from each workspace's dictionary. Edit distance runs over
+/// every entry, so this bounds the zero-hit cost on very large workspaces.
+pub const DICTIONARY_LIMIT: usize = 20_000;
+
+/// Suggest corrections for `query` from `dictionary`, best first.
+///
+/// `dictionary` is expected most-relevant first (as returned by
+/// `symbol_name_dictionary`); ties in edit distance keep that order.
+pub fn suggest_corrections(query: &str, dictionary: &[String]) -> Vec<String> {
+    let query = query.trim();
+    let query_tokens = identifier_tokens(query);
+    if query_tokens.is_empty() {
+        return Vec::new();
+    }
+
+    let mut suggestions = Vec::new();
+    if let Some(respelled) = respell_query(&query_tokens, dictionary) {
+        suggestions.push(respelled);
+    }
+
+    for (_, name) in near_miss_names(query, dictionary) {
+        if suggestions.len() >= MAX_SUGGESTIONS {
+            break;
+        }
+        if !suggestions
+            .iter()
+            .any(|existing| existing.eq_ignore_ascii_case(name))
+        {
+            suggestions.push(name.to_string());
+        }
+    }
+    suggestions
+}
+
+/// Distinct dictionary names within a small edit distance of `query`,
+/// compared lowercased and without separators, closest first. Ties keep
+/// dictionary order. The query itself is never returned.
+pub fn near_miss_names<'a>(query: &str, dictionary: &'a [String]) -> Vec<(usize, &'a str)> {
+    let query = query.trim();
+    let compact_query = compact(query);
+    let query_len = compact_query.chars().count();
+    let budget = max_edits(query_len);
+    let mut seen_names = HashSet::new();
+    let mut close_names: Vec<(usize, usize, &str)> = Vec::new();
+    for (rank, name) in dictionary.iter().enumerate() {
+        if name == query || !seen_names.insert(name.as_str()) {
+            continue;
+        }
+        let compact_name = compact(name);
+        if compact_name.chars().count().abs_diff(query_len) > budget {
+            continue;
+        }
+        let distance = levenshtein_distance(&compact_query, &compact_name);
+        if distance <= budget {
+            close_names.push((distance, rank, name.as_str()));
+        }
+    }
+    close_names.sort_by_key(|&(distance, rank, _)| (distance, rank));
+    close_names
+        .into_iter()
+        .map(|(distance, _, name)| (distance, name))
+        .collect()
+}
+
+/// Render suggestions as the trailing line of a zero-hit message, or an
+/// empty string when there are none.
+pub fn format_suggestions(suggestions: &[String]) -> String {
+    if suggestions.is_empty() {
+        return String::new();
+    }
+    format!("\nDid you mean: {}?", suggestions.join(", "))
+}
+
+/// Replace unknown query tokens with their closest dictionary token. Returns
+/// `None` when nothing changed.
+fn respell_query(query_tokens: &[String], dictionary: &[String]) -> Option<String> {
+    // Token → rank of the first (most relevant) name it appears in.
+    let mut vocabulary: HashMap<String, usize> = HashMap::new();
+    for (rank, name) in dictionary.iter().enumerate() {
+        for token in identifier_tokens(name) {
+            vocabulary.entry(token).or_insert(rank);
+        }
+    }
+
+    let mut changed = false;
+    let respelled: Vec<&str> = query_tokens
+        .iter()
+        .map(|token| {
+            if vocabulary.contains_key(token) {
+                return token.as_str();
+            }
+            let budget = max_edits(token.chars().count());
+            let closest = vocabulary
+                .iter()
+                .filter(|(candidate, _)| {
+                    candidate.chars().count().abs_diff(token.chars().count()) <= budget
+                })
+                .map(|(candidate, rank)| (levenshtein_distance(token, candidate), *rank, candidate))
+                .filter(|(distance, _, _)| *distance <= budget)
+                .min();
+            match closest {
+                Some((_, _, candidate)) => {
+                    changed = true;
+                    candidate.as_str()
+                }
+                None => token.as_str(),
+            }
+        })
+        .collect();
+
+    changed.then(|| respelled.join(" "))
+}
+
+/// Lowercased identifier tokens: split on non-alphanumerics, then on
+/// camelCase boundaries.
+fn identifier_tokens(text: &str) -> Vec<String> {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|part| !part.is_empty())
+        .flat_map(split_camel_case)
+        .map(str::to_lowercase)
+        .collect()
+}
+
+/// Lowercase and drop separators so `user_service`, `UserService` and
+/// `user service` compare equal.
+fn compact(text: &str) -> String {
+    text.chars()
+        .filter(|c| c.is_alphanumeric())
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+/// Edits tolerated for a word of `len` characters.
+fn max_edits(len: usize) -> usize {
+    match len {
+        0..=2 => 0,
+        3..=5 => 1,
+        6..=9 => 2,
+        _ => 3,
+    }
+}
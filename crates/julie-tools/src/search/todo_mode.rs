@@ -0,0 +1,351 @@
+//! Task comment listing (`mode: "todos"`).
+//!
+//! Lists the TODO / FIXME / HACK symbols the indexer derives from comments
+//! (`julie_pipeline::indexing_core::task_comments`), grouped by file, with the
+//! symbol each sits in, its owner and its age. Owner and age come from
+//! `git blame` of the task's line; a `TODO(name)` assignee takes precedence
+//! over the blamed author. Without git history both are reported as unknown.
+
+use std::collections::{BTreeMap, HashMap};
+use std::path::Path;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use anyhow::{Result, bail};
+use julie_core::database::SymbolDatabase;
+use julie_core::git_history::{LineBlame, blame_lines};
+use julie_core::mcp_compat::{CallToolResult, CallToolResultExt, Content};
+use julie_extractors::base::Symbol;
+use julie_index::search::scoring::is_test_path;
+use julie_pipeline::indexing_core::task_comments::{TASK_SEMANTIC_GROUP, task_info};
+use tracing::debug;
+
+use julie_context::ToolContext;
+
+use super::line_mode::indexed_language_matches;
+use super::query::matches_glob_pattern;
+use super::regex_mode::RegexScanFilters;
+use super::trace::{self, SearchExecutionResult, SearchHit};
+use super::{
+    FastSearchExecution, FastSearchParams, SearchBackend, workspace_label, workspace_root,
+};
+use crate::navigation::resolution::WorkspaceTarget;
+use crate::projects;
+use crate::streaming::ResultSink;
+
+const DAY_SECS: i64 = 24 * 60 * 60;
+
+/// Age buckets of the summary: upper bound in days and label.
+const AGE_BUCKETS: &[(i64, &str)] = &[
+    (30, "under 1 month"),
+    (182, "1-6 months"),
+    (365, "6-12 months"),
+    (i64::MAX, "over 1 year"),
+];
+
+/// One task comment and what the listing shows about it.
+#[derive(Debug, Clone)]
+pub struct TaskListing {
+    pub symbol: Symbol,
+    pub marker: String,
+    pub text: String,
+    pub assignee: Option<String>,
+    /// `kind name` of the symbol the comment sits in.
+    pub enclosing: Option<String>,
+    pub owner: Option<String>,
+    /// Seconds since the line was last changed, when blame knows.
+    pub age_secs: Option<i64>,
+}
+
+impl TaskListing {
+    fn owner_label(&self) -> &str {
+        self.assignee
+            .as_deref()
+            .or(self.owner.as_deref())
+            .unwrap_or("unknown")
+    }
+
+    fn age_label(&self) -> &'static str {
+        self.age_secs.map_or("unknown age", |age| {
+            AGE_BUCKETS
+                .iter()
+                .find(|(days, _)| age / DAY_SECS < *days)
+                .map_or("over 1 year", |(_, label)| label)
+        })
+    }
+}
+
+/// Task symbols in files that pass `filters`, whose marker, text, assignee
+/// or path contains `query` (ignoring case; `*` keeps every task), ordered
+/// by file and line.
+pub fn collect_tasks(
+    db: &SymbolDatabase,
+    filters: &RegexScanFilters,
+    query: &str,
+) -> Result<Vec<TaskListing>> {
+    let query = query.trim().to_lowercase();
+    let mut tasks: Vec<TaskListing> = db
+        .get_symbols_by_semantic_group(TASK_SEMANTIC_GROUP)?
+        .into_iter()
+        .filter(|symbol| {
+            !(filters
+                .file_pattern
+                .as_deref()
+                .is_some_and(|pattern| !matches_glob_pattern(&symbol.file_path, pattern))
+                || filters.language.as_deref().is_some_and(|requested| {
+                    !indexed_language_matches(&symbol.language, requested)
+                })
+                || (filters.exclude_tests && is_test_path(&symbol.file_path)))
+        })
+        .filter_map(|symbol| {
+            let (marker, text, assignee) = task_info(&symbol)?;
+            let (marker, text, assignee) = (
+                marker.to_string(),
+                text.to_string(),
+                assignee.map(str::to_string),
+            );
+            Some(TaskListing {
+                symbol,
+                marker,
+                text,
+                assignee,
+                enclosing: None,
+                owner: None,
+                age_secs: None,
+            })
+        })
+        .filter(|task| {
+            query.is_empty()
+                || query == "*"
+                || [
+                    Some(task.marker.as_str()),
+                    Some(task.text.as_str()),
+                    task.assignee.as_deref(),
+                    Some(task.symbol.file_path.as_str()),
+                ]
+                .into_iter()
+                .flatten()
+                .any(|field| field.to_lowercase().contains(&query))
+        })
+        .collect();
+    tasks.sort_by(|a, b| {
+        (&a.symbol.file_path, a.symbol.start_line).cmp(&(&b.symbol.file_path, b.symbol.start_line))
+    });
+
+    let parent_ids: Vec<String> = tasks
+        .iter()
+        .filter_map(|task| task.symbol.parent_id.clone())
+        .collect();
+    let parents: HashMap<String, Symbol> = db
+        .get_symbols_by_ids(&parent_ids)?
+        .into_iter()
+        .map(|symbol| (symbol.id.clone(), symbol))
+        .collect();
+    for task in &mut tasks {
+        task.enclosing = task
+            .symbol
+            .parent_id
+            .as_ref()
+            .and_then(|id| parents.get(id))
+            .map(|parent| format!("{} {}", parent.kind, parent.name));
+    }
+    Ok(tasks)
+}
+
+/// Fill in owner and age of `tasks` from `git blame` in `root`. Files git
+/// cannot blame (untracked, outside a repository, changed since indexing)
+/// keep an unknown owner.
+pub fn blame_tasks(root: &Path, tasks: &mut [TaskListing], now: SystemTime) {
+    let now = now
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |elapsed| elapsed.as_secs() as i64);
+    let mut lines_by_file: BTreeMap<&str, Vec<u32>> = BTreeMap::new();
+    for task in tasks.iter() {
+        lines_by_file
+            .entry(task.symbol.file_path.as_str())
+            .or_default()
+            .push(task.symbol.start_line);
+    }
+    let blame: HashMap<String, HashMap<u32, LineBlame>> = lines_by_file
+        .into_iter()
+        .filter_map(|(file, lines)| match blame_lines(root, file, &lines) {
+            Ok(blame) => Some((file.to_string(), blame)),
+            Err(err) => {
+                debug!("No blame for task comments in {file}: {err:#}");
+                None
+            }
+        })
+        .collect();
+    for task in tasks {
+        if let Some(line) = blame
+            .get(&task.symbol.file_path)
+            .and_then(|lines| lines.get(&task.symbol.start_line))
+        {
+            task.owner = Some(line.author.clone());
+            task.age_secs = Some((now - line.author_time).max(0));
+        }
+    }
+}
+
+/// The listing: counts per marker, owner and age, then the tasks grouped by
+/// file. `total` counts the matching tasks before `tasks` was truncated.
+pub fn format_task_listing(tasks: &[TaskListing], total: usize) -> String {
+    let mut by_marker: BTreeMap<&str, usize> = BTreeMap::new();
+    let mut by_owner: BTreeMap<&str, usize> = BTreeMap::new();
+    let mut by_age: HashMap<&str, usize> = HashMap::new();
+    for task in tasks {
+        *by_marker.entry(task.marker.as_str()).or_default() += 1;
+        *by_owner.entry(task.owner_label()).or_default() += 1;
+        *by_age.entry(task.age_label()).or_default() += 1;
+    }
+    let counts = |entries: Vec<(&str, usize)>| {
+        entries
+            .iter()
+            .map(|(label, count)| format!("{label} {count}"))
+            .collect::<Vec<_>>()
+            .join(", ")
+    };
+    let mut owners: Vec<(&str, usize)> = by_owner.into_iter().collect();
+    owners.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(b.0)));
+    let ages: Vec<(&str, usize)> = AGE_BUCKETS
+        .iter()
+        .map(|(_, label)| *label)
+        .chain(["unknown age"])
+        .filter_map(|label| by_age.get(label).map(|count| (label, *count)))
+        .collect();
+
+    let mut output = if tasks.len() < total {
+        format!("Showing {} of {total} tasks", tasks.len())
+    } else {
+        format!("{total} tasks")
+    };
+    output.push_str(&format!(
+        " ({})\nBy owner: {}\nBy age: {}\n",
+        counts(by_marker.into_iter().collect()),
+        counts(owners),
+        counts(ages)
+    ));
+    let mut current_file = None;
+    for task in tasks {
+        if current_file != Some(task.symbol.file_path.as_str()) {
+            current_file = Some(task.symbol.file_path.as_str());
+            output.push_str(&format!("\n{}\n", task.symbol.file_path));
+        }
+        let enclosing = task
+            .enclosing
+            .as_ref()
+            .map(|label| format!(" (in {label})"))
+            .unwrap_or_default();
+        let age = task
+            .age_secs
+            .map(|age| format!(", {}", format_age(age)))
+            .unwrap_or_default();
+        output.push_str(&format!(
+            "  {}: {}{} [{}{}] {}\n",
+            task.symbol.start_line,
+            task.marker,
+            enclosing,
+            task.owner_label(),
+            age,
+            task.text
+        ));
+    }
+    output.trim_end().to_string()
+}
+
+fn format_age(age_secs: i64) -> String {
+    match age_secs / DAY_SECS {
+        0 => "today".to_string(),
+        1 => "1 day".to_string(),
+        days if days < 60 => format!("{days} days"),
+        days if days < 730 => format!("{} months", days / 30),
+        days => format!("{} years", days / 365),
+    }
+}
+
+impl FastSearchParams {
+    pub(super) async fn execute_todo_listing(
+        &self,
+        handler: &dyn ToolContext,
+        workspace_target: WorkspaceTarget,
+        sink: Option<&dyn ResultSink>,
+    ) -> Result<FastSearchExecution> {
+        if matches!(
+            self.search.backend,
+            Some(SearchBackend::Semantic | SearchBackend::Hybrid)
+        ) {
+            bail!(
+                "todos mode lists indexed task comments; semantic and hybrid backends do not apply"
+            );
+        }
+        if self.regions.is_some() {
+            bail!("todos mode cannot be combined with regions");
+        }
+        if self.search.framework_filter()?.is_some() {
+            bail!("todos mode lists comments; the framework filter needs symbol results");
+        }
+
+        let project = self
+            .search
+            .resolve_project_filter(handler, &workspace_target)
+            .await?;
+        let filters = RegexScanFilters {
+            language: self.search.language.clone(),
+            file_pattern: self.search.file_pattern.clone(),
+            exclude_tests: self.search.exclude_tests.unwrap_or(false),
+        };
+        let query = self.search.query.clone();
+        let db = projects::project_database(handler, &workspace_target).await?;
+        let tasks =
+            tokio::task::spawn_blocking(move || collect_tasks(&db, &filters, &query)).await??;
+
+        let workspace_label = workspace_label(handler, &workspace_target);
+        let mut tasks_by_id: HashMap<String, TaskListing> = HashMap::new();
+        let mut hits = tasks
+            .into_iter()
+            .map(|task| {
+                let hit = SearchHit::from_symbol(task.symbol.clone(), workspace_label.clone());
+                tasks_by_id.insert(task.symbol.id.clone(), task);
+                hit
+            })
+            .collect::<Vec<_>>();
+        self.search
+            .apply_project_filter(&mut hits, project.as_deref(), &workspace_target, handler)
+            .await?;
+        let total_results = hits.len();
+        hits.truncate(self.search.effective_limit() as usize);
+        if let Some(sink) = sink {
+            self.search.stream_hits(sink, &hits).await;
+        }
+
+        let mut listed: Vec<TaskListing> = hits
+            .iter()
+            .filter_map(|hit| hit.symbol_id.as_ref())
+            .filter_map(|id| tasks_by_id.remove(id))
+            .collect();
+        if let Some(root) = workspace_root(handler, &workspace_target).await {
+            listed = tokio::task::spawn_blocking(move || {
+                blame_tasks(&root, &mut listed, SystemTime::now());
+                listed
+            })
+            .await?;
+        }
+
+        let execution = SearchExecutionResult::new(
+            hits,
+            false,
+            total_results,
+            "fast_search_todos",
+            trace::SearchExecutionKind::Definitions,
+        );
+        let output = if listed.is_empty() {
+            "No TODO, FIXME or HACK comments match".to_string()
+        } else {
+            format_task_listing(&listed, total_results)
+        };
+
+        Ok(FastSearchExecution {
+            result: CallToolResult::text_content(vec![Content::text(output)]),
+            execution: Some(execution),
+        })
+    }
+}
@@ -0,0 +1,224 @@
+//! Topic map listing (`mode: "topics"`).
+//!
+//! Groups the workspace's embedded declarations into semantic topics
+//! (`julie_index::analysis::topics`) and lists each topic with its name,
+//! size, main directories and the symbols closest to its center: a map of
+//! what the codebase is about. `query` keeps the topics whose name, terms or
+//! directories contain it (`*` lists all). Test files are always left out.
+
+use std::collections::HashSet;
+
+use anyhow::{Result, bail};
+use julie_core::database::{SymbolDatabase, TopicCandidate};
+use julie_core::mcp_compat::{CallToolResult, CallToolResultExt, Content};
+use julie_index::analysis::{Topic, TopicMapOptions, TopicMapReport, build_topic_map};
+
+use julie_context::ToolContext;
+
+use super::line_mode::indexed_language_matches;
+use super::query::matches_glob_pattern;
+use super::regex_mode::RegexScanFilters;
+use super::trace::{self, SearchExecutionResult, SearchHit};
+use super::{FastSearchExecution, FastSearchParams, SearchBackend, workspace_label};
+use crate::navigation::resolution::WorkspaceTarget;
+use crate::projects;
+use crate::streaming::ResultSink;
+
+/// Topic map candidates in files that pass `filters` and, when `project` is
+/// set, belong to it.
+pub fn filter_topic_candidates(
+    db: &SymbolDatabase,
+    candidates: Vec<TopicCandidate>,
+    filters: &RegexScanFilters,
+    project: Option<&str>,
+) -> Result<Vec<TopicCandidate>> {
+    let mut candidates: Vec<TopicCandidate> = candidates
+        .into_iter()
+        .filter(|candidate| {
+            !(filters
+                .file_pattern
+                .as_deref()
+                .is_some_and(|pattern| !matches_glob_pattern(&candidate.file_path, pattern))
+                || filters.language.as_deref().is_some_and(|requested| {
+                    !indexed_language_matches(&candidate.language, requested)
+                }))
+        })
+        .collect();
+    if let Some(project) = project {
+        let paths: Vec<String> = candidates
+            .iter()
+            .map(|candidate| candidate.file_path.clone())
+            .collect::<HashSet<_>>()
+            .into_iter()
+            .collect();
+        let projects = db.projects_for_files(&paths)?;
+        candidates.retain(|candidate| {
+            projects
+                .get(&candidate.file_path)
+                .is_some_and(|p| p == project)
+        });
+    }
+    Ok(candidates)
+}
+
+/// Whether the name, a term or a directory of `topic` contains `query`
+/// (ignoring case; `*` matches every topic).
+pub fn topic_matches(topic: &Topic, query: &str) -> bool {
+    let query = query.trim().to_lowercase();
+    query.is_empty()
+        || query == "*"
+        || topic.label.to_lowercase().contains(&query)
+        || topic.terms.iter().any(|term| term.contains(&query))
+        || topic
+            .directories
+            .iter()
+            .any(|(dir, _)| dir.to_lowercase().contains(&query))
+}
+
+/// The map: a summary line, then each of `topics` with its size,
+/// directories and central symbols. `total` counts the matching topics
+/// before `topics` was truncated.
+pub fn format_topic_map(report: &TopicMapReport, topics: &[Topic], total: usize) -> String {
+    let mut output = if topics.len() < total {
+        format!("Showing {} of {total} topics", topics.len())
+    } else {
+        format!("{total} topics")
+    };
+    output.push_str(&format!(" over {} embedded symbols", report.clustered));
+    if report.clustered < report.candidates {
+        output.push_str(&format!(" (an even sample of {})", report.candidates));
+    }
+    if report.unassigned > 0 {
+        output.push_str(&format!(
+            "; {} in groups too small to be a topic",
+            report.unassigned
+        ));
+    }
+    output.push('\n');
+
+    for (rank, topic) in topics.iter().enumerate() {
+        output.push_str(&format!(
+            "\n{}. {} ({} symbols)\n",
+            rank + 1,
+            topic.label,
+            topic.size
+        ));
+        if !topic.directories.is_empty() {
+            let directories: Vec<String> = topic
+                .directories
+                .iter()
+                .map(|(dir, count)| format!("{dir} ({count})"))
+                .collect();
+            output.push_str(&format!("   in {}\n", directories.join(", ")));
+        }
+        for symbol in &topic.symbols {
+            output.push_str(&format!(
+                "   {} {}  {}:{}\n",
+                symbol.kind, symbol.name, symbol.file_path, symbol.line
+            ));
+        }
+    }
+    output.trim_end().to_string()
+}
+
+impl FastSearchParams {
+    pub(super) async fn execute_topic_map(
+        &self,
+        handler: &dyn ToolContext,
+        workspace_target: WorkspaceTarget,
+        sink: Option<&dyn ResultSink>,
+    ) -> Result<FastSearchExecution> {
+        if matches!(
+            self.search.backend,
+            Some(SearchBackend::Semantic | SearchBackend::Hybrid)
+        ) {
+            bail!("topics mode already works from the embeddings; leave backend unset");
+        }
+        if self.regions.is_some() {
+            bail!("topics mode cannot be combined with regions");
+        }
+        if self.search.framework_filter()?.is_some() {
+            bail!("topics mode maps the whole workspace; the framework filter does not apply");
+        }
+
+        let project = self
+            .search
+            .resolve_project_filter(handler, &workspace_target)
+            .await?;
+        let filters = RegexScanFilters {
+            language: self.search.language.clone(),
+            file_pattern: self.search.file_pattern.clone(),
+            exclude_tests: true,
+        };
+        let db = projects::project_database(handler, &workspace_target).await?;
+        let (report, symbols) = tokio::task::spawn_blocking(move || {
+            let candidates = db.get_topic_candidates(None)?;
+            let candidates =
+                filter_topic_candidates(&db, candidates, &filters, project.as_deref())?;
+            let report = build_topic_map(&db, candidates, &TopicMapOptions::default())?;
+            let ids: Vec<String> = report
+                .topics
+                .iter()
+                .flat_map(|topic| topic.symbols.iter().map(|symbol| symbol.id.clone()))
+                .collect();
+            let symbols = db.get_symbols_by_ids(&ids)?;
+            Ok::<_, anyhow::Error>((report, symbols))
+        })
+        .await??;
+
+        let query = self.search.query.clone();
+        let matching: Vec<Topic> = report
+            .topics
+            .iter()
+            .filter(|topic| topic_matches(topic, &query))
+            .cloned()
+            .collect();
+        let total_topics = matching.len();
+        let shown: Vec<Topic> = matching
+            .into_iter()
+            .take(self.search.effective_limit() as usize)
+            .collect();
+
+        let workspace_label = workspace_label(handler, &workspace_target);
+        let shown_ids: HashSet<&str> = shown
+            .iter()
+            .flat_map(|topic| topic.symbols.iter().map(|symbol| symbol.id.as_str()))
+            .collect();
+        let hits: Vec<SearchHit> = symbols
+            .into_iter()
+            .filter(|symbol| shown_ids.contains(symbol.id.as_str()))
+            .map(|symbol| SearchHit::from_symbol(symbol, workspace_label.clone()))
+            .collect();
+        if let Some(sink) = sink {
+            self.search.stream_hits(sink, &hits).await;
+        }
+
+        let execution = SearchExecutionResult::new(
+            hits,
+            false,
+            total_topics,
+            "fast_search_topics",
+            trace::SearchExecutionKind::Definitions,
+        );
+        let output = if report.clustered == 0 {
+            "No embedded symbols to map. Topics are built from the semantic embeddings; \
+             check task_status for a running embedding job, or enable semantic search \
+             and re-index."
+                .to_string()
+        } else if shown.is_empty() && report.topics.is_empty() {
+            format!(
+                "{} embedded symbols are too few to form topics",
+                report.clustered
+            )
+        } else if shown.is_empty() {
+            format!("No topics match '{}'", query.trim())
+        } else {
+            format_topic_map(&report, &shown, total_topics)
+        };
+
+        Ok(FastSearchExecution {
+            result: CallToolResult::text_content(vec![Content::text(output)]),
+            execution: Some(execution),
+        })
+    }
+}
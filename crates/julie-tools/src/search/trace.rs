@@ -2,6 +2,7 @@ use serde::Serialize;
 
 use julie_extractors::Symbol;
 use julie_index::search::index::{FileSearchResult, UnifiedHit};
+use julie_index::search::scoring::classify_role;
 
 use super::types::LineMatch;
 
@@ -23,6 +24,19 @@ pub struct SearchHit {
     pub snippet: Option<String>,
     pub workspace: String,
     pub symbol_id: Option<String>,
+    /// `file:line` of identical copies folded into this hit by `dedupe`.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub copies: Vec<String>,
+    /// Monorepo project (package) of `file`, when one is known.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub project: Option<String>,
+    /// How often the symbol is used (incoming references), when known.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub usages: Option<i64>,
+    /// The hit comes from generated code (protobuf stubs, ORM migrations,
+    /// files marked DO NOT EDIT); search ranks it below hand-written code.
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub generated: bool,
     #[serde(skip_serializing)]
     pub backing: SearchHitBacking,
 }
@@ -41,6 +55,12 @@ impl SearchHit {
         let file = symbol.file_path.clone();
         let kind = symbol.kind.to_string();
         let language = symbol.language.clone();
+        let generated = symbol
+            .metadata
+            .as_ref()
+            .and_then(|metadata| metadata.get("is_generated"))
+            .and_then(|value| value.as_bool())
+            .unwrap_or_else(|| classify_role(&file, &language) == "generated");
 
         Self {
             name,
@@ -52,6 +72,10 @@ impl SearchHit {
             snippet,
             workspace,
             symbol_id,
+            copies: Vec::new(),
+            project: None,
+            usages: None,
+            generated,
             backing: SearchHitBacking::Symbol(symbol),
         }
     }
@@ -82,6 +106,10 @@ impl SearchHit {
             snippet,
             workspace,
             symbol_id: None,
+            copies: Vec::new(),
+            project: None,
+            usages: None,
+            generated: false,
             backing: SearchHitBacking::LineMatch(line_match),
         }
     }
@@ -107,6 +135,10 @@ impl SearchHit {
             snippet: None,
             workspace,
             symbol_id: None,
+            copies: Vec::new(),
+            project: None,
+            usages: None,
+            generated: false,
             backing: SearchHitBacking::File(file_result),
         }
     }
@@ -143,6 +175,11 @@ impl SearchHit {
             None
         };
 
+        let generated = if hit.role.is_empty() {
+            classify_role(&hit.file_path, &hit.language) == "generated"
+        } else {
+            hit.role == "generated"
+        };
         let symbol_id = if is_file { None } else { Some(hit.id.clone()) };
         let line = if is_file { None } else { Some(hit.start_line) };
 
@@ -203,6 +240,10 @@ impl SearchHit {
             snippet,
             workspace,
             symbol_id,
+            copies: Vec::new(),
+            project: None,
+            usages: None,
+            generated,
             backing,
         }
     }
@@ -356,6 +397,9 @@ pub fn target_hint_label(hint_kind: &HintKind) -> Option<&'static str> {
 ///   from the wider codebase.
 /// - `scope_rescue_count` is the number of workspace searches that triggered
 ///   that rescue path. It is not a result count.
+/// - `suggestions` holds "did you mean" corrections built from the symbol-name
+///   dictionary. It is only filled for zero-hit runs whose candidates were not
+///   removed by a filter.
 #[derive(Debug, Clone, Serialize)]
 pub struct SearchTrace {
     pub strategy_id: String,
@@ -378,6 +422,7 @@ pub struct SearchTrace {
     pub scope_rescue_count: usize,
     pub or_disjunction_detected: bool,
     pub backend_fallback: bool,
+    pub suggestions: Vec<String>,
 }
 
 impl SearchTrace {
@@ -406,6 +451,7 @@ impl SearchTrace {
             scope_rescue_count: 0,
             or_disjunction_detected: false,
             backend_fallback: false,
+            suggestions: Vec::new(),
         }
     }
 
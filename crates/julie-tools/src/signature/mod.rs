@@ -0,0 +1,210 @@
+//! `fast_signature`: the full typed signature and documentation of one
+//! symbol, looked up by id.
+//!
+//! The cheap follow-up to a search or reference listing: one indexed lookup,
+//! no reference walk. Shows the untruncated signature, the extracted type
+//! (see [`format_type_info`]), the enclosing symbol and the doc comment.
+//! A signature capped at indexing time is read back in full from the file.
+//!
+//! A value that is not a symbol id is looked up as a name; when several
+//! symbols share it, their ids are listed to pass back.
+
+use anyhow::{Result, anyhow};
+use julie_context::{ToolContext, WorkspaceTarget};
+use julie_core::database::SymbolDatabase;
+use julie_core::mcp_compat::{CallToolResult, CallToolResultExt, Content};
+use julie_extractors::Symbol;
+use julie_extractors::base::TypeInfo;
+use julie_pipeline::indexing_core::signatures::{is_signature_truncated, recover_signature};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::deep_dive::data::find_symbol;
+use crate::navigation::formatting::format_type_info;
+
+/// Same-named symbols listed when a name is ambiguous.
+const MAX_CANDIDATES: usize = 10;
+
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
+pub struct FastSignatureTool {
+    /// Symbol id (deep_dive lists them for ambiguous names). A symbol name also works; if several symbols share it, their ids are listed
+    pub symbol_id: String,
+    /// Workspace target. Use `primary` or a workspace id opened through `manage_workspace`.
+    #[serde(default)]
+    pub workspace: Option<String>,
+}
+
+/// Outcome of a lookup.
+#[derive(Debug, Clone)]
+pub enum SignatureLookup {
+    Found(Box<SignatureReport>),
+    /// The value was a name shared by these symbols.
+    Ambiguous(Vec<Symbol>),
+    NotFound,
+}
+
+/// What `fast_signature` reports about one symbol.
+#[derive(Debug, Clone)]
+pub struct SignatureReport {
+    pub symbol: Symbol,
+    pub type_info: Option<TypeInfo>,
+    /// `kind name` of the enclosing symbol.
+    pub parent: Option<String>,
+    /// The full declaration, when the stored signature was capped at
+    /// indexing time and the file still matches the symbol's span.
+    pub full_signature: Option<String>,
+}
+
+impl FastSignatureTool {
+    pub async fn call_tool(&self, handler: &dyn ToolContext) -> Result<CallToolResult> {
+        let workspace_target = handler
+            .resolve_workspace_target(self.workspace.as_deref())
+            .await?;
+        self.call_tool_with_target(handler, &workspace_target).await
+    }
+
+    pub async fn call_tool_with_target(
+        &self,
+        handler: &dyn ToolContext,
+        workspace_target: &WorkspaceTarget,
+    ) -> Result<CallToolResult> {
+        if self.symbol_id.trim().is_empty() {
+            return Err(anyhow!("symbol_id must not be empty"));
+        }
+        let database = match workspace_target {
+            WorkspaceTarget::Primary => handler.primary_pooled_database().await?,
+            WorkspaceTarget::Target(workspace_id) => {
+                handler
+                    .get_pooled_database_for_workspace(workspace_id)
+                    .await?
+            }
+        };
+        let tool = self.clone();
+
+        let rendered = tokio::task::spawn_blocking(move || -> Result<String> {
+            let database = database.into_read_snapshot()?;
+            let lookup = tool.execute(&database)?;
+            Ok(tool.render(&lookup))
+        })
+        .await
+        .map_err(|error| anyhow!("fast_signature task failed: {error}"))??;
+        Ok(CallToolResult::text_content(vec![Content::text(rendered)]))
+    }
+
+    /// Look the symbol up by id, then by name.
+    pub fn execute(&self, db: &SymbolDatabase) -> Result<SignatureLookup> {
+        let key = self.symbol_id.trim();
+        let symbol = match db.get_symbol_by_id(key)? {
+            Some(symbol) => symbol,
+            None => {
+                let mut named = find_symbol(db, key, None)?;
+                match named.len() {
+                    0 => return Ok(SignatureLookup::NotFound),
+                    1 => named.remove(0),
+                    _ => return Ok(SignatureLookup::Ambiguous(named)),
+                }
+            }
+        };
+        let type_info = db
+            .get_type_infos_for_symbols(std::slice::from_ref(&symbol.id))?
+            .remove(&symbol.id);
+        let parent = match symbol.parent_id.as_deref() {
+            Some(parent_id) => db
+                .get_symbol_by_id(parent_id)?
+                .map(|parent| format!("{} {}", parent.kind, parent.name)),
+            None => None,
+        };
+        let full_signature = if is_signature_truncated(&symbol) {
+            db.get_file_content(&symbol.file_path)?
+                .and_then(|content| recover_signature(&symbol, &content))
+        } else {
+            None
+        };
+        Ok(SignatureLookup::Found(Box::new(SignatureReport {
+            symbol,
+            type_info,
+            parent,
+            full_signature,
+        })))
+    }
+
+    pub fn render(&self, lookup: &SignatureLookup) -> String {
+        let key = self.symbol_id.trim();
+        match lookup {
+            SignatureLookup::Found(report) => render_report(report),
+            SignatureLookup::NotFound => format!("No symbol with id or name '{key}'"),
+            SignatureLookup::Ambiguous(symbols) => {
+                let mut lines = vec![format!(
+                    "{} symbols are named '{key}'. Pass one of these ids as symbol_id:",
+                    symbols.len()
+                )];
+                for symbol in symbols.iter().take(MAX_CANDIDATES) {
+                    lines.push(format!(
+                        "  {}  {} {} ({}) {}:{}",
+                        symbol.id,
+                        symbol.kind,
+                        symbol.name,
+                        symbol.language,
+                        symbol.file_path,
+                        symbol.start_line
+                    ));
+                }
+                if symbols.len() > MAX_CANDIDATES {
+                    lines.push(format!("  … {} more", symbols.len() - MAX_CANDIDATES));
+                }
+                lines.join("\n")
+            }
+        }
+    }
+}
+
+/// Location header, parent, signature, type and doc comment, one block.
+fn render_report(report: &SignatureReport) -> String {
+    let symbol = &report.symbol;
+    let visibility = symbol
+        .visibility
+        .as_ref()
+        .map(|visibility| format!(", {}", visibility.to_string().to_lowercase()))
+        .unwrap_or_default();
+    let mut lines = vec![format!(
+        "{} {} ({}{}) {}:{}-{}",
+        symbol.kind,
+        symbol.name,
+        symbol.language,
+        visibility,
+        symbol.file_path,
+        symbol.start_line,
+        symbol.end_line
+    )];
+    if let Some(parent) = &report.parent {
+        lines.push(format!("  in {parent}"));
+    }
+    if let Some(signature) = report
+        .full_signature
+        .as_deref()
+        .or(symbol.signature.as_deref())
+        .filter(|signature| !signature.trim().is_empty())
+    {
+        lines.extend(
+            signature
+                .lines()
+                .map(|line| format!("  {}", line.trim_end())),
+        );
+    }
+    if let Some(info) = &report.type_info {
+        lines.push(format!("  {}", format_type_info(&symbol.kind, info)));
+    }
+    if let Some(doc) = symbol
+        .doc_comment
+        .as_deref()
+        .filter(|doc| !doc.trim().is_empty())
+    {
+        lines.push(String::new());
+        lines.extend(
+            doc.trim()
+                .lines()
+                .map(|line| format!("  {}", line.trim_end())),
+        );
+    }
+    lines.join("\n")
+}
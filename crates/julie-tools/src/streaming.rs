@@ -0,0 +1,51 @@
+//! Partial results for long-running tools.
+//!
+//! `fast_search` and `fast_refs` can hand their results over in batches while
+//! they are still working: search hits once ranking and filtering finish (before
+//! snippet enrichment and formatting), references as soon as they are resolved
+//! (before callers, counterparts and textual mentions are looked up). Tools push
+//! batches into a [`ResultSink`]; the MCP handler turns them into progress
+//! notifications for clients that sent a progress token. The final tool result
+//! is unchanged, so a client that ignores the batches loses nothing.
+
+use async_trait::async_trait;
+
+/// Results per streamed batch.
+pub const STREAM_BATCH_SIZE: usize = 10;
+
+/// Receives result batches while a tool is still running.
+#[async_trait]
+pub trait ResultSink: Send + Sync {
+    /// Deliver one batch. `sent` counts the results delivered so far,
+    /// including this batch; `total` is the expected count when known.
+    async fn send_batch(&self, text: String, sent: usize, total: Option<usize>);
+}
+
+/// Send `lines` (one result each) in [`STREAM_BATCH_SIZE`] batches, each
+/// headed by `heading` and the range it covers. `offset` is the number of
+/// results already sent in earlier calls; returns the new running count.
+pub async fn stream_lines(
+    sink: &dyn ResultSink,
+    heading: &str,
+    lines: &[String],
+    offset: usize,
+    total: Option<usize>,
+) -> usize {
+    let mut sent = offset;
+    for batch in lines.chunks(STREAM_BATCH_SIZE) {
+        let first = sent + 1;
+        sent += batch.len();
+        let mut text = match total {
+            Some(total) => format!("{heading} {first}-{sent} of {total}:\n"),
+            None => format!("{heading} {first}-{sent}:\n"),
+        };
+        for line in batch {
+            text.push_str("  ");
+            text.push_str(line);
+            text.push('\n');
+        }
+        sink.send_batch(text.trim_end().to_string(), sent, total)
+            .await;
+    }
+    sent
+}
@@ -2,9 +2,16 @@
 //!
 //! Handles formatting symbol data into structured responses for MCP clients.
 
+use std::collections::{HashMap, HashSet};
+
 use julie_core::mcp_compat::{CallToolResult, CallToolResultExt, Content};
+use julie_core::paths::display_stored_path;
+use julie_core::response_budget::{BudgetedEntry, BudgetedResponse};
 use tracing::debug;
 
+use crate::deleted_files::format_deleted_files;
+use crate::search::formatting::usage_count_text;
+
 use julie_extractors::base::Symbol;
 
 /// Format raw code output - just the source code, no metadata wrapper
@@ -13,11 +20,14 @@ use julie_extractors::base::Symbol;
 /// Returns code bodies separated by blank lines with a minimal file header.
 const FORMAT_CODE_CHAR_LIMIT: usize = 50_000;
 
-fn format_code_output(file_path: &str, symbols: &[Symbol]) -> CallToolResult {
+fn format_code_output(file_path: &str, symbols: &[Symbol]) -> String {
     let mut output = String::new();
 
     // Minimal file header
-    output.push_str(&format!("// === {} ===\n\n", file_path));
+    output.push_str(&format!(
+        "// === {} ===\n\n",
+        display_stored_path(file_path)
+    ));
 
     // Extract code from each symbol, stopping at the character cap
     let mut truncated = false;
@@ -42,13 +52,11 @@ fn format_code_output(file_path: &str, symbols: &[Symbol]) -> CallToolResult {
     }
 
     // Trim trailing whitespace but ensure single newline at end
-    let output = if truncated {
+    if truncated {
         output + "\n"
     } else {
         output.trim_end().to_string() + "\n"
-    };
-
-    CallToolResult::text_content(vec![Content::text(output)])
+    }
 }
 
 /// Map a SymbolKind to its source-code keyword (with trailing space).
@@ -76,15 +84,26 @@ fn kind_keyword(kind: &julie_extractors::SymbolKind) -> Option<&'static str> {
 /// ```text
 /// src/foo.rs — 12 symbols
 ///
-///   struct Foo (10-25, public)
-///     fn new() -> Self (12-15, public)
-///     fn process(&self, data: &[u8]) (17-24, public)
-///   fn helper(x: i32) -> bool (30-45, private)
+///   struct Foo (10-25, 4 refs)
+///     fn new() -> Self (12-15, 3 refs)
+///     fn process(&self, data: &[u8]) (17-24)
+///   fn helper(x: i32) -> bool (30-45, private, 1 ref)
 /// ```
-fn format_lean_symbols(file_path: &str, symbols: &[Symbol]) -> CallToolResult {
+///
+/// `usages` holds each symbol's usage count; symbols without one (or with
+/// none) get no badge.
+fn format_lean_symbols(
+    file_path: &str,
+    symbols: &[Symbol],
+    usages: &HashMap<String, i64>,
+) -> String {
     let mut output = String::new();
 
-    output.push_str(&format!("{} — {} symbols\n", file_path, symbols.len()));
+    output.push_str(&format!(
+        "{} — {} symbols\n",
+        display_stored_path(file_path),
+        symbols.len()
+    ));
 
     for symbol in symbols {
         let indent = if symbol.parent_id.is_some() {
@@ -92,56 +111,129 @@ fn format_lean_symbols(file_path: &str, symbols: &[Symbol]) -> CallToolResult {
         } else {
             "  "
         };
-        let kind = symbol.kind.to_string();
+        output.push_str(&format!("{}{}\n", indent, lean_symbol_line(symbol, usages)));
+    }
 
-        // Use signature if available, otherwise just name
-        let name_display = if let Some(sig) = &symbol.signature {
-            // Signature often includes the kind keyword, use as-is
-            sig.clone()
-        } else {
-            symbol.name.clone()
-        };
+    output.trim_end().to_string()
+}
 
-        let vis = symbol
-            .visibility
-            .as_ref()
-            .map(|v| format!("{:?}", v).to_lowercase())
-            .unwrap_or_default();
+/// One outline line: signature (or kind and name), line range, visibility
+/// when not public, and usage count.
+fn lean_symbol_line(symbol: &Symbol, usages: &HashMap<String, i64>) -> String {
+    let kind = symbol.kind.to_string();
 
-        let vis_str = if vis.is_empty() || vis == "public" {
-            String::new()
-        } else {
-            format!(", {}", vis)
-        };
+    // Use signature if available, otherwise just name
+    let name_display = if let Some(sig) = &symbol.signature {
+        // Signature often includes the kind keyword, use as-is
+        sig.clone()
+    } else {
+        symbol.name.clone()
+    };
 
-        // Skip the kind prefix when the signature already contains the kind
-        // keyword, e.g. "pub struct Foo" already signals its kind.
-        let signature_has_kind = kind_keyword(&symbol.kind)
-            .map(|kw| name_display.contains(kw))
-            .unwrap_or(false);
-
-        if signature_has_kind {
-            output.push_str(&format!(
-                "{}{} ({}-{}{})\n",
-                indent, name_display, symbol.start_line, symbol.end_line, vis_str,
-            ));
-        } else {
-            output.push_str(&format!(
-                "{}{} {} ({}-{}{})\n",
-                indent, kind, name_display, symbol.start_line, symbol.end_line, vis_str,
-            ));
+    let vis = symbol
+        .visibility
+        .as_ref()
+        .map(|v| format!("{:?}", v).to_lowercase())
+        .unwrap_or_default();
+
+    let vis_str = if vis.is_empty() || vis == "public" {
+        String::new()
+    } else {
+        format!(", {}", vis)
+    };
+    let usage_str = usages
+        .get(&symbol.id)
+        .filter(|count| **count > 0)
+        .map(|count| format!(", {}", usage_count_text(*count)))
+        .unwrap_or_default();
+
+    // Skip the kind prefix when the signature already contains the kind
+    // keyword, e.g. "pub struct Foo" already signals its kind.
+    let signature_has_kind = kind_keyword(&symbol.kind)
+        .map(|kw| name_display.contains(kw))
+        .unwrap_or(false);
+
+    if signature_has_kind {
+        format!(
+            "{} ({}-{}{}{})",
+            name_display, symbol.start_line, symbol.end_line, vis_str, usage_str,
+        )
+    } else {
+        format!(
+            "{} {} ({}-{}{}{})",
+            kind, name_display, symbol.start_line, symbol.end_line, vis_str, usage_str,
+        )
+    }
+}
+
+/// Format symbols under a `max_tokens` budget.
+///
+/// Each symbol is one [`BudgetedEntry`]: its outline line is always kept,
+/// its code body and then its doc comment are shed first. Entries are grouped
+/// under their top-level symbol, so when even the outline does not fit, each
+/// top-level symbol is listed with the number of symbols it holds.
+pub fn format_budgeted_symbol_response(
+    file_path: &str,
+    symbols: Vec<Symbol>,
+    usages: &HashMap<String, i64>,
+    deleted_files: &HashSet<String>,
+    max_tokens: u32,
+) -> anyhow::Result<CallToolResult> {
+    let deleted = format_deleted_files(
+        symbols
+            .iter()
+            .map(|symbol| (symbol.file_path.as_str(), "symbol")),
+        deleted_files,
+    );
+    let by_id: HashMap<&str, &Symbol> = symbols.iter().map(|s| (s.id.as_str(), s)).collect();
+    let mut response = BudgetedResponse::new(format!(
+        "{} — {} symbols",
+        display_stored_path(file_path),
+        symbols.len()
+    ))
+    .with_max_tokens(Some(max_tokens));
+
+    for symbol in &symbols {
+        let mut top = symbol;
+        while let Some(parent) = top
+            .parent_id
+            .as_deref()
+            .and_then(|id| by_id.get(id).copied())
+        {
+            top = parent;
         }
+        let indent = if symbol.parent_id.is_some() { "  " } else { "" };
+        response.push(
+            BudgetedEntry::new(
+                format!("{} {}", top.kind, top.name),
+                format!("{}{}", indent, lean_symbol_line(symbol, usages)),
+            )
+            .with_doc(symbol.doc_comment.as_deref())
+            .with_context(symbol.code_context.as_deref()),
+        );
     }
 
-    CallToolResult::text_content(vec![Content::text(output.trim_end().to_string())])
+    let output = response.render().text + &deleted;
+    Ok(CallToolResult::text_content(vec![Content::text(output)]))
 }
 
 /// Format symbol query response with structured content
+///
+/// When the file is among `deleted_files` (deleted from disk, still inside
+/// its soft-delete grace period) the symbols are followed by that note.
 pub fn format_symbol_response(
     file_path: &str,
     symbols: Vec<Symbol>,
     target: Option<&str>,
+    usages: &HashMap<String, i64>,
+    deleted_files: &HashSet<String>,
 ) -> anyhow::Result<CallToolResult> {
+    let deleted = format_deleted_files(
+        symbols
+            .iter()
+            .map(|symbol| (symbol.file_path.as_str(), "symbol")),
+        deleted_files,
+    );
     // Auto-select format: "code" when code bodies are available, "lean" otherwise
     let has_code_bodies = symbols.iter().any(|s| s.code_context.is_some());
     let effective_format = if has_code_bodies { "code" } else { "lean" };
@@ -153,7 +245,10 @@ pub fn format_symbol_response(
             symbols.len(),
             target
         );
-        return Ok(format_code_output(file_path, &symbols));
+        // The code output already ends in a newline.
+        let output = format_code_output(file_path, &symbols)
+            + deleted.strip_prefix('\n').unwrap_or(&deleted);
+        return Ok(CallToolResult::text_content(vec![Content::text(output)]));
     }
 
     // Everything else (including "lean", unknown formats) → lean text overview
@@ -162,5 +257,6 @@ pub fn format_symbol_response(
         symbols.len(),
         target
     );
-    Ok(format_lean_symbols(file_path, &symbols))
+    let output = format_lean_symbols(file_path, &symbols, usages) + &deleted;
+    Ok(CallToolResult::text_content(vec![Content::text(output)]))
 }
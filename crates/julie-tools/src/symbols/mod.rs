@@ -22,6 +22,7 @@ use serde::{Deserialize, Serialize};
 use tracing::debug;
 
 use crate::navigation::resolution::WorkspaceTarget;
+use crate::tool_limits::configured_max_tokens;
 use julie_context::ToolContext;
 
 fn default_max_depth() -> u32 {
@@ -100,6 +101,12 @@ pub struct GetSymbolsTool {
     /// Workspace filter: "primary" (default) or workspace ID
     #[serde(default = "default_workspace")]
     pub workspace: Option<String>,
+    /// Approximate token budget for the response. When exceeded, code bodies are dropped first, then doc comments, then symbols collapse to one line per top-level symbol; a footer reports what was omitted (default: `tools.get_symbols.max_tokens` or `tools.default.max_tokens` from .julie/config.toml, else unlimited)
+    #[serde(
+        default,
+        deserialize_with = "julie_core::serde_lenient::deserialize_option_u32_lenient"
+    )]
+    pub max_tokens: Option<u32>,
 }
 
 impl GetSymbolsTool {
@@ -110,6 +117,10 @@ impl GetSymbolsTool {
         let workspace_target = handler
             .resolve_workspace_target(self.workspace.as_deref())
             .await?;
+        let max_tokens = match self.max_tokens {
+            Some(max_tokens) => Some(max_tokens),
+            None => configured_max_tokens(handler, &workspace_target, "get_symbols").await,
+        };
 
         match workspace_target {
             WorkspaceTarget::Target(target_workspace_id) => {
@@ -121,6 +132,7 @@ impl GetSymbolsTool {
                     self.target.as_deref(),
                     self.limit,
                     mode,
+                    max_tokens,
                     target_workspace_id,
                 )
                 .await
@@ -134,6 +146,7 @@ impl GetSymbolsTool {
                     self.target.as_deref(),
                     self.limit,
                     mode,
+                    max_tokens,
                 )
                 .await
             }
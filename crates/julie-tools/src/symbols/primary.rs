@@ -2,13 +2,16 @@
 //!
 //! Handles getting symbols from the primary (active) workspace.
 
+use std::collections::HashSet;
+
 use anyhow::{Result, bail};
 use julie_core::mcp_compat::{CallToolResult, CallToolResultExt, Content};
 use tracing::{debug, info};
 
 use super::body_extraction::extract_code_bodies;
 use super::filtering::apply_all_filters;
-use super::formatting::format_symbol_response;
+use super::formatting::{format_budgeted_symbol_response, format_symbol_response};
+use crate::deleted_files::read_deleted_files;
 use julie_context::ToolContext;
 
 /// Get symbols from the primary workspace
@@ -19,6 +22,7 @@ pub async fn get_symbols_from_primary(
     target: Option<&str>,
     limit: Option<u32>,
     mode: &str,
+    max_tokens: Option<u32>,
 ) -> Result<CallToolResult> {
     info!(
         "📋 Getting symbols for file: {} (depth: {})",
@@ -51,10 +55,18 @@ pub async fn get_symbols_from_primary(
     );
     debug!("🔍 Workspace root: '{}'", current_workspace_root.display());
 
-    // Check if file exists before querying database
-    if !std::path::Path::new(&absolute_path).exists() {
-        bail!(super::file_not_found_message(file_path, target));
-    }
+    // Check if file exists before querying database. A file deleted from
+    // disk keeps its symbols through the soft-delete grace period; those are
+    // listed, without bodies, and flagged as deleted.
+    let deleted_files = if std::path::Path::new(&absolute_path).exists() {
+        HashSet::new()
+    } else {
+        let deleted_files = read_deleted_files(&db);
+        if !deleted_files.contains(&query_path) {
+            bail!(super::file_not_found_message(file_path, target));
+        }
+        deleted_files
+    };
 
     // Query symbols for this file using relative Unix-style path.
     // In structure mode, use lightweight query that skips expensive columns
@@ -91,6 +103,28 @@ pub async fn get_symbols_from_primary(
     };
     let symbols_to_return = extract_code_bodies(symbols_to_return, &absolute_path, body_mode)?;
 
+    // Usage badges are best effort: an outline without them still orients.
+    let ids: Vec<&str> = symbols_to_return.iter().map(|s| s.id.as_str()).collect();
+    let usages = db.get_usage_counts(&ids).unwrap_or_else(|e| {
+        debug!("Skipping usage counts for {}: {}", query_path, e);
+        Default::default()
+    });
+
     // Format and return the response
-    format_symbol_response(file_path, symbols_to_return, target)
+    match max_tokens {
+        Some(max_tokens) => format_budgeted_symbol_response(
+            file_path,
+            symbols_to_return,
+            &usages,
+            &deleted_files,
+            max_tokens,
+        ),
+        None => format_symbol_response(
+            file_path,
+            symbols_to_return,
+            target,
+            &usages,
+            &deleted_files,
+        ),
+    }
 }
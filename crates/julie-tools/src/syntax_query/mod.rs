@@ -0,0 +1,298 @@
+//! `syntax_query`: run a user-supplied tree-sitter query over indexed files.
+//!
+//! For one-off structural questions that no extractor answers. Sources come
+//! from the indexed file contents, so results match what the other tools see
+//! and work for any opened workspace. Queries are compiled once per language;
+//! a pattern that is valid for one grammar may not compile for another, in
+//! which case that language's files are skipped and reported.
+
+pub mod query;
+
+use std::collections::BTreeMap;
+
+use anyhow::{Result, anyhow};
+use julie_context::{ToolContext, WorkspaceTarget};
+use julie_core::database::SymbolDatabase;
+use julie_core::glob::matches_glob_pattern;
+use julie_core::mcp_compat::{CallToolResult, CallToolResultExt, Content};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+use self::query::{SyntaxCapture, compile_query, run_query};
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum SyntaxQueryFormat {
+    #[default]
+    Compact,
+    Json,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
+pub struct SyntaxQueryTool {
+    /// Tree-sitter query (S-expression), e.g. `(call_expression function: (identifier) @fn)`.
+    pub query: String,
+    /// Run against one file (workspace-relative or absolute).
+    #[serde(default)]
+    pub file_path: Option<String>,
+    /// Run against indexed files matching this glob, e.g. `src/**/*.rs`.
+    #[serde(default)]
+    pub file_pattern: Option<String>,
+    /// Only query files of this language. Recommended with `file_pattern`,
+    /// since node names differ between grammars.
+    #[serde(default)]
+    pub language: Option<String>,
+    /// Maximum captures returned across all files.
+    #[serde(
+        default = "default_limit",
+        deserialize_with = "julie_core::serde_lenient::deserialize_u32_lenient"
+    )]
+    pub limit: u32,
+    /// Maximum files scanned for a `file_pattern`.
+    #[serde(
+        default = "default_max_files",
+        deserialize_with = "julie_core::serde_lenient::deserialize_u32_lenient"
+    )]
+    pub max_files: u32,
+    /// Workspace target. Use `primary` or a workspace id opened through `manage_workspace`.
+    #[serde(default)]
+    pub workspace: Option<String>,
+    #[serde(default)]
+    pub format: SyntaxQueryFormat,
+}
+
+impl Default for SyntaxQueryTool {
+    fn default() -> Self {
+        Self {
+            query: String::new(),
+            file_path: None,
+            file_pattern: None,
+            language: None,
+            limit: default_limit(),
+            max_files: default_max_files(),
+            workspace: None,
+            format: SyntaxQueryFormat::Compact,
+        }
+    }
+}
+
+/// Captures plus what was skipped to produce them.
+#[derive(Debug, Default)]
+pub struct SyntaxQueryOutcome {
+    pub captures: Vec<SyntaxCapture>,
+    pub files_scanned: usize,
+    /// Files matched by the pattern but not scanned because of `max_files`.
+    pub files_skipped: usize,
+    /// True when `limit` cut the results short.
+    pub truncated: bool,
+    /// Languages whose grammar rejected the query, with the compile error.
+    pub language_errors: BTreeMap<String, String>,
+}
+
+impl SyntaxQueryTool {
+    pub async fn call_tool(&self, handler: &dyn ToolContext) -> Result<CallToolResult> {
+        let workspace_target = handler
+            .resolve_workspace_target(self.workspace.as_deref())
+            .await?;
+        self.call_tool_with_target(handler, &workspace_target).await
+    }
+
+    pub async fn call_tool_with_target(
+        &self,
+        handler: &dyn ToolContext,
+        workspace_target: &WorkspaceTarget,
+    ) -> Result<CallToolResult> {
+        self.validate()?;
+        let (database, workspace_root) = match workspace_target {
+            WorkspaceTarget::Primary => (
+                handler.primary_pooled_database().await?,
+                handler.require_primary_workspace_root()?,
+            ),
+            WorkspaceTarget::Target(workspace_id) => (
+                handler
+                    .get_pooled_database_for_workspace(workspace_id)
+                    .await?,
+                handler.get_workspace_root_for_target(workspace_id).await?,
+            ),
+        };
+        let mut tool = self.clone();
+        if let Some(file_path) = tool.file_path.as_deref() {
+            tool.file_path = Some(
+                julie_core::paths::resolve_workspace_file_input(file_path, &workspace_root)?
+                    .relative_query_path,
+            );
+        }
+
+        let rendered = tokio::task::spawn_blocking(move || -> Result<String> {
+            let database = database.into_read_snapshot()?;
+            let outcome = tool.execute(&database)?;
+            tool.render(&outcome)
+        })
+        .await
+        .map_err(|error| anyhow!("syntax_query task failed: {error}"))??;
+        Ok(CallToolResult::text_content(vec![Content::text(rendered)]))
+    }
+
+    fn validate(&self) -> Result<()> {
+        if self.query.trim().is_empty() {
+            return Err(anyhow!("query must not be empty"));
+        }
+        match (self.file_path.as_deref(), self.file_pattern.as_deref()) {
+            (Some(_), Some(_)) => Err(anyhow!("pass either file_path or file_pattern, not both")),
+            (None, None) => Err(anyhow!("syntax_query requires file_path or file_pattern")),
+            (Some(value), None) | (None, Some(value)) if value.trim().is_empty() => {
+                Err(anyhow!("file_path and file_pattern must not be empty"))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Run the query over the selected files. `file_path`, when set, must
+    /// already be workspace-relative.
+    pub fn execute(&self, db: &SymbolDatabase) -> Result<SyntaxQueryOutcome> {
+        let limit = self.limit.clamp(1, 1000) as usize;
+        let max_files = self.max_files.clamp(1, 2000) as usize;
+
+        let mut paths: Vec<String> = match (&self.file_path, &self.file_pattern) {
+            (Some(path), _) => vec![path.clone()],
+            (None, Some(pattern)) => db
+                .get_all_indexed_files()?
+                .into_iter()
+                .filter(|path| matches_glob_pattern(path, pattern))
+                .collect(),
+            (None, None) => Vec::new(),
+        };
+        paths.sort();
+
+        let path_refs: Vec<&str> = paths.iter().map(String::as_str).collect();
+        let languages = db.get_file_languages_by_paths(&path_refs)?;
+        if let Some(path) = self
+            .file_path
+            .as_ref()
+            .filter(|p| !languages.contains_key(*p))
+        {
+            return Err(anyhow!("File not indexed: {path}"));
+        }
+        paths.retain(|path| {
+            self.language.as_deref().is_none_or(|wanted| {
+                languages
+                    .get(path)
+                    .is_some_and(|language| language.eq_ignore_ascii_case(wanted))
+            })
+        });
+
+        let mut outcome = SyntaxQueryOutcome {
+            files_skipped: paths.len().saturating_sub(max_files),
+            ..SyntaxQueryOutcome::default()
+        };
+        paths.truncate(max_files);
+        let contents = db.get_file_contents_by_paths(&paths)?;
+
+        let mut queries = BTreeMap::new();
+        for path in &paths {
+            let Some(language) = languages.get(path) else {
+                continue;
+            };
+            let Some(Some(content)) = contents.get(path) else {
+                continue;
+            };
+            let compiled = queries
+                .entry(language.clone())
+                .or_insert_with(|| compile_query(language, &self.query));
+            let query = match compiled {
+                Ok(query) => query,
+                Err(error) => {
+                    outcome
+                        .language_errors
+                        .entry(language.clone())
+                        .or_insert_with(|| format!("{error:#}"));
+                    continue;
+                }
+            };
+
+            let remaining = limit - outcome.captures.len();
+            // One extra capture tells a full page apart from a cut-off one.
+            let mut captures = run_query(query, language, path, content, remaining + 1)?;
+            outcome.files_scanned += 1;
+            if captures.len() > remaining {
+                captures.truncate(remaining);
+                outcome.captures.extend(captures);
+                outcome.truncated = true;
+                break;
+            }
+            outcome.captures.extend(captures);
+        }
+
+        if outcome.files_scanned == 0 && !outcome.language_errors.is_empty() {
+            let errors: Vec<String> = outcome.language_errors.values().cloned().collect();
+            return Err(anyhow!(errors.join("\n")));
+        }
+        Ok(outcome)
+    }
+
+    fn render(&self, outcome: &SyntaxQueryOutcome) -> Result<String> {
+        match self.format {
+            SyntaxQueryFormat::Json => Ok(serde_json::to_string(&json!({
+                "schema_version": 1,
+                "captures": outcome.captures,
+                "files_scanned": outcome.files_scanned,
+                "files_skipped": outcome.files_skipped,
+                "truncated": outcome.truncated,
+                "language_errors": outcome.language_errors,
+            }))?),
+            SyntaxQueryFormat::Compact => {
+                let mut lines: Vec<String> = outcome
+                    .captures
+                    .iter()
+                    .map(|c| {
+                        format!(
+                            "{}:{}:{}-{}:{} @{} ({}) {}",
+                            c.file_path,
+                            c.start_line,
+                            c.start_column,
+                            c.end_line,
+                            c.end_column,
+                            c.capture,
+                            c.node_kind,
+                            c.text
+                        )
+                    })
+                    .collect();
+                if lines.is_empty() {
+                    lines.push("No captures".to_string());
+                }
+                lines.push(format!(
+                    "{} captures from {} files{}{}",
+                    outcome.captures.len(),
+                    outcome.files_scanned,
+                    if outcome.truncated {
+                        "; limit reached"
+                    } else {
+                        ""
+                    },
+                    if outcome.files_skipped > 0 {
+                        format!(
+                            "; {} files over max_files not scanned",
+                            outcome.files_skipped
+                        )
+                    } else {
+                        String::new()
+                    }
+                ));
+                for (language, error) in &outcome.language_errors {
+                    lines.push(format!("skipped {language} files: {error}"));
+                }
+                Ok(lines.join("\n"))
+            }
+        }
+    }
+}
+
+fn default_limit() -> u32 {
+    100
+}
+
+fn default_max_files() -> u32 {
+    200
+}
@@ -0,0 +1,91 @@
+//! Run one tree-sitter query against one file's source.
+
+use anyhow::{Result, anyhow};
+use julie_core::parser_pool::ParserPool;
+use serde::Serialize;
+use tree_sitter::{Query, QueryCursor, StreamingIterator};
+
+/// Characters of captured text kept per capture.
+const CAPTURE_TEXT_CHARS: usize = 120;
+
+/// Upper bound on in-progress matches, so a pathological pattern such as
+/// `(_ (_) @a (_) @b)` cannot blow up memory on a large file.
+const MATCH_LIMIT: u32 = 4096;
+
+/// One capture. Lines and columns are 1-based.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct SyntaxCapture {
+    pub file_path: String,
+    pub capture: String,
+    pub node_kind: String,
+    pub start_line: usize,
+    pub start_column: usize,
+    pub end_line: usize,
+    pub end_column: usize,
+    pub text: String,
+}
+
+/// Compile `source` for `language`, reporting the error position on failure.
+pub fn compile_query(language: &str, source: &str) -> Result<Query> {
+    let ts_language = julie_extractors::language::get_tree_sitter_language(language)?;
+    Query::new(&ts_language, source).map_err(|error| {
+        anyhow!(
+            "invalid {language} query at row {}, column {}: {}",
+            error.row + 1,
+            error.column + 1,
+            error.message
+        )
+    })
+}
+
+/// Parse `content` and collect up to `limit` captures of `query`.
+pub fn run_query(
+    query: &Query,
+    language: &str,
+    file_path: &str,
+    content: &str,
+    limit: usize,
+) -> Result<Vec<SyntaxCapture>> {
+    let mut parser = ParserPool::global().checkout(language)?;
+    let tree = parser
+        .parse(content, None)
+        .ok_or_else(|| anyhow!("Failed to parse {language} file '{file_path}'"))?;
+
+    let capture_names = query.capture_names();
+    let mut cursor = QueryCursor::new();
+    cursor.set_match_limit(MATCH_LIMIT);
+    let mut matches = cursor.matches(query, tree.root_node(), content.as_bytes());
+
+    let mut captures = Vec::new();
+    while let Some(query_match) = matches.next() {
+        for capture in query_match.captures {
+            if captures.len() >= limit {
+                return Ok(captures);
+            }
+            let node = capture.node;
+            let start = node.start_position();
+            let end = node.end_position();
+            captures.push(SyntaxCapture {
+                file_path: file_path.to_string(),
+                capture: capture_names[capture.index as usize].to_string(),
+                node_kind: node.kind().to_string(),
+                start_line: start.row + 1,
+                start_column: start.column + 1,
+                end_line: end.row + 1,
+                end_column: end.column + 1,
+                text: capture_text(&content[node.byte_range()]),
+            });
+        }
+    }
+    Ok(captures)
+}
+
+/// First line of the captured text, shortened for display.
+fn capture_text(text: &str) -> String {
+    let first_line = text.lines().next().unwrap_or_default().trim_end();
+    let mut shortened: String = first_line.chars().take(CAPTURE_TEXT_CHARS).collect();
+    if shortened.len() < first_line.len() || text.contains('\n') {
+        shortened.push_str(" …");
+    }
+    shortened
+}
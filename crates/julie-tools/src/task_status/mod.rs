@@ -0,0 +1,133 @@
+//! `task_status`: report and control julie's background work.
+//!
+//! Lists the tasks registered in `julie_core::background_tasks` (indexing,
+//! embedding, maintenance commands and each file watcher's queue) with their
+//! progress and an ETA, and pauses or resumes the pausable ones. Only tasks of
+//! the serving process are visible.
+
+use std::time::Duration;
+
+use anyhow::{Result, anyhow};
+use julie_context::ToolContext;
+use julie_core::background_tasks::{
+    BackgroundTaskKind, BackgroundTaskRegistry, BackgroundTaskSnapshot,
+};
+use julie_core::mcp_compat::{CallToolResult, CallToolResultExt, Content};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum TaskStatusOperation {
+    #[default]
+    List,
+    Pause,
+    Resume,
+}
+
+#[derive(Debug, Clone, Default, Deserialize, Serialize, JsonSchema)]
+pub struct TaskStatusTool {
+    /// `list` (default) reports active background tasks; `pause` and `resume` control one task.
+    #[serde(default)]
+    pub operation: TaskStatusOperation,
+    /// Task id from `list`. Required for `pause` and `resume`.
+    #[serde(
+        default,
+        deserialize_with = "julie_core::serde_lenient::deserialize_option_u32_lenient"
+    )]
+    pub task_id: Option<u32>,
+    /// Only list tasks of this workspace id.
+    #[serde(default)]
+    pub workspace_id: Option<String>,
+}
+
+impl TaskStatusTool {
+    pub async fn call_tool(&self, _handler: &dyn ToolContext) -> Result<CallToolResult> {
+        let output = self.execute(BackgroundTaskRegistry::global())?;
+        Ok(CallToolResult::text_content(vec![Content::text(output)]))
+    }
+
+    /// Run the operation against `registry` and render the result.
+    pub fn execute(&self, registry: &BackgroundTaskRegistry) -> Result<String> {
+        match self.operation {
+            TaskStatusOperation::List => Ok(self.render_list(&registry.snapshot())),
+            TaskStatusOperation::Pause | TaskStatusOperation::Resume => {
+                let paused = self.operation == TaskStatusOperation::Pause;
+                let task_id = self
+                    .task_id
+                    .ok_or_else(|| anyhow!("task_id is required for pause and resume"))?;
+                let task = registry.set_paused(u64::from(task_id), paused)?;
+                let verb = if paused { "Paused" } else { "Resumed" };
+                Ok(format!("{verb} task:\n{}", format_task(&task)))
+            }
+        }
+    }
+
+    fn render_list(&self, tasks: &[BackgroundTaskSnapshot]) -> String {
+        let tasks: Vec<&BackgroundTaskSnapshot> = tasks
+            .iter()
+            .filter(|task| {
+                self.workspace_id
+                    .as_deref()
+                    .is_none_or(|workspace_id| task.workspace_id == workspace_id)
+            })
+            .collect();
+        if tasks.is_empty() {
+            return "No background tasks running.".to_string();
+        }
+
+        let mut output = format!("{} background task(s):", tasks.len());
+        for task in tasks {
+            output.push('\n');
+            output.push_str(&format_task(task));
+        }
+        output
+    }
+}
+
+/// One line per task: identity, then progress, then timing and pause state.
+fn format_task(task: &BackgroundTaskSnapshot) -> String {
+    let mut parts = Vec::new();
+    if let Some(detail) = &task.detail {
+        parts.push(detail.clone());
+    }
+    let remaining = task.total.map(|total| total.saturating_sub(task.completed));
+    match (task.kind, task.total) {
+        (BackgroundTaskKind::Watcher, _) => {
+            parts.push(format!("backlog {}", remaining.unwrap_or(0)));
+        }
+        (_, Some(total)) => match task.percent() {
+            Some(percent) => parts.push(format!("{}/{total} ({percent:.0}%)", task.completed)),
+            None => parts.push(format!("{}/{total}", task.completed)),
+        },
+        (_, None) => parts.push(format!("{} done", task.completed)),
+    }
+    parts.push(format!("elapsed {}", format_duration(task.elapsed)));
+    if task.paused {
+        parts.push("PAUSED".to_string());
+    } else if let Some(eta) = task.eta.filter(|_| remaining.unwrap_or(0) > 0) {
+        parts.push(format!("ETA {}", format_duration(eta)));
+    }
+    if !task.pausable {
+        parts.push("not pausable".to_string());
+    }
+
+    format!(
+        "#{} {} {} [{}]: {}",
+        task.id,
+        task.kind,
+        task.label,
+        task.workspace_id,
+        parts.join("; ")
+    )
+}
+
+fn format_duration(duration: Duration) -> String {
+    let secs = duration.as_secs();
+    match secs {
+        0 => "<1s".to_string(),
+        1..=59 => format!("{secs}s"),
+        60..=3599 => format!("{}m {:02}s", secs / 60, secs % 60),
+        _ => format!("{}h {:02}m", secs / 3600, (secs % 3600) / 60),
+    }
+}
@@ -0,0 +1,213 @@
+//! Tests for the fast_audit sink scan and caller walk.
+
+#[cfg(test)]
+mod audit_tests {
+    use tempfile::TempDir;
+
+    use crate::audit::sinks::{callee_matches, has_dynamic_string, match_rule};
+    use crate::audit::{AuditTool, SinkCategory};
+    use julie_core::database::{FileInfo, SymbolDatabase};
+    use julie_extractors::base::{Relationship, RelationshipKind, Symbol, SymbolKind};
+    use julie_test_support::db::identifier_builder;
+
+    const APP_PY: &str = "\
+def run_tool(name):
+    os.system(\"convert \" + name)
+
+def lookup(cur, user):
+    cur.execute(\"SELECT * FROM users WHERE name = '\" + user + \"'\")
+    cur.execute(\"SELECT * FROM users WHERE name = ?\", (user,))
+
+def load(blob):
+    return json.loads(blob)
+";
+
+    fn make_symbol(id: &str, name: &str, file: &str, line: u32) -> Symbol {
+        Symbol {
+            id: id.to_string(),
+            name: name.to_string(),
+            kind: SymbolKind::Function,
+            language: "python".to_string(),
+            file_path: file.to_string(),
+            start_line: line,
+            end_line: line + 2,
+            start_column: 0,
+            end_column: 0,
+            start_byte: 0,
+            end_byte: 100,
+            parent_id: None,
+            signature: None,
+            doc_comment: None,
+            visibility: None,
+            metadata: None,
+            semantic_group: None,
+            confidence: Some(0.9),
+            code_context: None,
+            content_type: None,
+            body_span: None,
+            body_hash: None,
+            annotations: Vec::new(),
+        }
+    }
+
+    fn make_call(id: &str, from: &str, to: &str, line: u32) -> Relationship {
+        Relationship {
+            id: id.to_string(),
+            from_symbol_id: from.to_string(),
+            to_symbol_id: to.to_string(),
+            kind: RelationshipKind::Calls,
+            file_path: "src/app.py".to_string(),
+            line_number: line,
+            confidence: 0.9,
+            metadata: None,
+        }
+    }
+
+    fn setup_db() -> (TempDir, SymbolDatabase) {
+        let temp_dir = TempDir::new().unwrap();
+        let mut db = SymbolDatabase::new(temp_dir.path().join("test.db")).unwrap();
+
+        for (path, content) in [
+            ("src/app.py", APP_PY),
+            ("tests/test_app.py", "os.system(cmd)\n"),
+        ] {
+            db.store_file_info(&FileInfo {
+                path: path.to_string(),
+                language: "python".to_string(),
+                hash: format!("hash_{path}"),
+                size: content.len() as i64,
+                last_modified: 0,
+                last_indexed: 0,
+                symbol_count: 0,
+                line_count: 0,
+                content: Some(content.to_string()),
+            })
+            .unwrap();
+        }
+
+        db.store_symbols(&[
+            make_symbol("sym_run_tool", "run_tool", "src/app.py", 1),
+            make_symbol("sym_lookup", "lookup", "src/app.py", 4),
+            make_symbol("sym_load", "load", "src/app.py", 8),
+            make_symbol("sym_convert", "convert", "src/app.py", 20),
+            make_symbol("sym_upload", "handle_upload", "src/app.py", 30),
+            make_symbol("sym_test", "test_run", "tests/test_app.py", 1),
+        ])
+        .unwrap();
+        db.store_relationships(&[
+            make_call("r1", "sym_convert", "sym_run_tool", 21),
+            make_call("r2", "sym_upload", "sym_convert", 31),
+        ])
+        .unwrap();
+
+        let calls = [
+            ("i1", "os.system", "src/app.py", 2, "sym_run_tool"),
+            ("i2", "cur.execute", "src/app.py", 5, "sym_lookup"),
+            ("i3", "cur.execute", "src/app.py", 6, "sym_lookup"),
+            ("i4", "json.loads", "src/app.py", 9, "sym_load"),
+            ("i5", "os.system", "tests/test_app.py", 1, "sym_test"),
+        ];
+        let identifiers: Vec<_> = calls
+            .iter()
+            .map(|(id, name, file, line, container)| {
+                identifier_builder(*id, *name, *file)
+                    .language("python")
+                    .line(*line)
+                    .column(0, 10)
+                    .bytes(0, 10)
+                    .containing_symbol_id(*container)
+                    .confidence(1.0)
+                    .build()
+            })
+            .collect();
+        db.bulk_store_identifiers(&identifiers, "").unwrap();
+
+        (temp_dir, db)
+    }
+
+    #[test]
+    fn test_qualified_sinks_only_match_their_qualifier() {
+        assert!(callee_matches("pickle.loads", "pickle.loads"));
+        assert!(!callee_matches("pickle.loads", "json.loads"));
+        assert!(!callee_matches("pickle.loads", "loads"));
+        assert!(callee_matches("eval", "eval"));
+        assert!(callee_matches("eval", "builtins.eval"));
+        assert!(callee_matches("Command::new", "std::process::Command::new"));
+        assert!(match_rule("javascript", "regex.exec").is_none());
+        assert!(
+            match_rule("rust", "eval").is_none(),
+            "rules are per language"
+        );
+    }
+
+    #[test]
+    fn test_dynamic_string_detection() {
+        assert!(has_dynamic_string("cur.execute(\"SELECT \" + name)"));
+        assert!(has_dynamic_string("cur.execute(f\"SELECT {name}\")"));
+        assert!(has_dynamic_string("db.query(`SELECT ${id}`)"));
+        assert!(!has_dynamic_string("cur.execute(\"SELECT ?\", (name,))"));
+    }
+
+    #[test]
+    fn test_audit_ranks_sinks_and_reports_caller_paths() {
+        let (_tmp, db) = setup_db();
+        let report = AuditTool::default().audit(&db).unwrap();
+
+        let found: Vec<(SinkCategory, &str, u32)> = report
+            .findings
+            .iter()
+            .map(|f| {
+                (
+                    f.rule.category,
+                    f.call.file_path.as_str(),
+                    f.call.start_line,
+                )
+            })
+            .collect();
+        assert_eq!(
+            found,
+            vec![
+                (SinkCategory::CommandExec, "src/app.py", 2),
+                (SinkCategory::SqlInjection, "src/app.py", 5),
+            ],
+            "parameterized SQL, json.loads and test files are not flagged; reach breaks the severity tie"
+        );
+
+        let command = &report.findings[0];
+        assert_eq!(command.callers_reached, 2);
+        assert_eq!(
+            command.paths,
+            vec![vec![
+                "handle_upload".to_string(),
+                "convert".to_string(),
+                "run_tool".to_string()
+            ]]
+        );
+    }
+
+    #[test]
+    fn test_audit_filters_and_depth() {
+        let (_tmp, db) = setup_db();
+
+        let tool = AuditTool {
+            category: Some(SinkCategory::CommandExec),
+            include_tests: true,
+            max_depth: 1,
+            ..AuditTool::default()
+        };
+        let report = tool.audit(&db).unwrap();
+        assert_eq!(report.findings.len(), 2);
+        let app = report
+            .findings
+            .iter()
+            .find(|f| f.call.file_path == "src/app.py")
+            .unwrap();
+        assert_eq!(app.callers_reached, 1, "depth 1 stops at the direct caller");
+
+        let tool = AuditTool {
+            language: Some("ruby".to_string()),
+            ..AuditTool::default()
+        };
+        assert!(tool.audit(&db).unwrap().findings.is_empty());
+    }
+}
@@ -0,0 +1,192 @@
+//! Tests for `get_context_pack` gathering and budgeting.
+
+#[cfg(test)]
+mod context_pack_tests {
+    use julie_core::database::SymbolDatabase;
+    use julie_extractors::SymbolKind;
+    use julie_extractors::base::{RelationshipKind, TypeInfo};
+    use julie_test_support::db::{file_info_builder, relationship_builder, symbol_builder};
+    use tempfile::TempDir;
+
+    use crate::context_pack::{ContextPackLookup, DEFAULT_MAX_TOKENS, GetContextPackTool};
+
+    fn tool(symbol: &str, max_tokens: u32) -> GetContextPackTool {
+        GetContextPackTool {
+            symbol: symbol.to_string(),
+            max_tokens,
+            workspace: None,
+        }
+    }
+
+    fn seeded_db() -> (TempDir, SymbolDatabase) {
+        let temp_dir = TempDir::new().unwrap();
+        let mut db = SymbolDatabase::new(temp_dir.path().join("test.db")).unwrap();
+        for path in ["src/user.ts", "src/form.ts", "src/types.ts"] {
+            db.store_file_info(&file_info_builder(path).language("typescript").build())
+                .unwrap();
+        }
+        db.store_symbols(&[
+            symbol_builder("user_service", "UserService", "src/user.ts")
+                .kind(SymbolKind::Class)
+                .language("typescript")
+                .span(1, 0, 60, 1)
+                .doc_comment("Reads and writes users.")
+                .build(),
+            symbol_builder("save", "save", "src/user.ts")
+                .kind(SymbolKind::Method)
+                .language("typescript")
+                .span(10, 2, 14, 3)
+                .parent_id("user_service")
+                .signature("async save(user: User): Promise<User>")
+                .doc_comment("Persist a user.")
+                .code_context(
+                    "async save(user: User): Promise<User> {\n  \
+                     this.validate(user);\n  \
+                     return this.store.put(user);\n}",
+                )
+                .build(),
+            symbol_builder("validate", "validate", "src/user.ts")
+                .kind(SymbolKind::Method)
+                .language("typescript")
+                .span(20, 2, 24, 3)
+                .parent_id("user_service")
+                .signature("validate(user: User): void")
+                .build(),
+            symbol_builder("persist_all", "persistAll", "src/user.ts")
+                .kind(SymbolKind::Function)
+                .language("typescript")
+                .span(40, 0, 44, 1)
+                .build(),
+            symbol_builder("handle_submit", "handleSubmit", "src/form.ts")
+                .kind(SymbolKind::Function)
+                .language("typescript")
+                .span(3, 0, 9, 1)
+                .doc_comment("Submit handler of the signup form.\nSecond line is dropped.")
+                .build(),
+            symbol_builder("user", "User", "src/types.ts")
+                .kind(SymbolKind::Interface)
+                .language("typescript")
+                .span(4, 0, 8, 1)
+                .signature("interface User")
+                .build(),
+        ])
+        .unwrap();
+        db.store_relationships(&[
+            relationship_builder("r_form", "handle_submit", "save")
+                .file_path("src/form.ts")
+                .line_number(5)
+                .build(),
+            relationship_builder("r_all", "persist_all", "save")
+                .file_path("src/user.ts")
+                .line_number(42)
+                .build(),
+            relationship_builder("r_validate", "save", "validate")
+                .file_path("src/user.ts")
+                .line_number(11)
+                .build(),
+            relationship_builder("r_user", "save", "user")
+                .kind(RelationshipKind::Uses)
+                .file_path("src/user.ts")
+                .line_number(10)
+                .build(),
+        ])
+        .unwrap();
+        db.bulk_store_types(
+            &[TypeInfo {
+                symbol_id: "save".to_string(),
+                resolved_type: "Promise<User>".to_string(),
+                generic_params: None,
+                constraints: None,
+                is_inferred: false,
+                language: "typescript".to_string(),
+                metadata: None,
+            }],
+            "ws",
+        )
+        .unwrap();
+        (temp_dir, db)
+    }
+
+    #[test]
+    fn pack_orders_definition_callers_callees_and_types() {
+        let (_temp_dir, db) = seeded_db();
+        let tool = tool("save", DEFAULT_MAX_TOKENS);
+        let ContextPackLookup::Found(pack) = tool.execute(&db).unwrap() else {
+            panic!("save is unique");
+        };
+
+        let names = |symbols: &[julie_extractors::Symbol]| {
+            symbols
+                .iter()
+                .map(|symbol| symbol.name.clone())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(
+            names(&pack.callers),
+            ["persistAll", "handleSubmit"],
+            "callers in the symbol's own file come first"
+        );
+        assert_eq!(names(&pack.callees), ["validate"]);
+        assert_eq!(
+            names(&pack.types),
+            ["UserService", "User"],
+            "enclosing type first; the used interface is listed once"
+        );
+
+        let rendered = tool.render(&ContextPackLookup::Found(pack));
+        assert!(rendered.starts_with(
+            "method save (typescript) src/user.ts:10-14\n  \
+             in class UserService\n  \
+             async save(user: User): Promise<User>\n  \
+             returns Promise<User>\n\n  \
+             Persist a user.\n\n\
+             Body:\n  \
+             async save(user: User): Promise<User> {"
+        ));
+        let order: Vec<usize> = ["Callers (2):", "Callees (1):", "Related types (2):"]
+            .iter()
+            .map(|title| rendered.find(title).expect(title))
+            .collect();
+        assert!(order.windows(2).all(|pair| pair[0] < pair[1]));
+        assert!(rendered.contains(
+            "  function handleSubmit  src/form.ts:3\n    // Submit handler of the signup form."
+        ));
+        assert!(!rendered.contains("Second line is dropped"));
+        assert!(!rendered.contains("Budget of"));
+    }
+
+    #[test]
+    fn tight_budget_keeps_the_header_and_reports_what_was_left_out() {
+        let (_temp_dir, db) = seeded_db();
+        let tool = tool("save", 20);
+        let lookup = tool.execute(&db).unwrap();
+        let rendered = tool.render(&lookup);
+
+        assert!(rendered.starts_with("method save (typescript) src/user.ts:10-14"));
+        assert!(rendered.contains("Persist a user."));
+        assert!(!rendered.contains("Callers ("));
+        assert!(rendered.ends_with(
+            "Budget of 20 tokens reached; left out: the body, 2 callers, 1 callees, \
+             2 related types. Raise max_tokens or use deep_dive"
+        ));
+    }
+
+    #[test]
+    fn unresolved_names_render_like_fast_signature() {
+        let (_temp_dir, db) = seeded_db();
+        let missing = tool("nope", DEFAULT_MAX_TOKENS);
+        let lookup = missing.execute(&db).unwrap();
+        assert!(matches!(lookup, ContextPackLookup::Unresolved(_)));
+        assert_eq!(missing.render(&lookup), "No symbol with id or name 'nope'");
+    }
+
+    #[test]
+    fn max_tokens_defaults_and_accepts_strings() {
+        let parsed: GetContextPackTool = serde_json::from_str(r#"{"symbol": "save"}"#).unwrap();
+        assert_eq!(parsed.max_tokens, DEFAULT_MAX_TOKENS);
+        let parsed: GetContextPackTool =
+            serde_json::from_str(r#"{"symbol_id": "save", "max_tokens": "1200"}"#).unwrap();
+        assert_eq!(parsed.symbol, "save");
+        assert_eq!(parsed.max_tokens, 1200);
+    }
+}
@@ -0,0 +1,107 @@
+//! Tests for `fast_counterparts` reports and rendering.
+
+#[cfg(test)]
+mod counterparts_tests {
+    use julie_core::database::SymbolDatabase;
+    use julie_extractors::{Symbol, SymbolKind};
+    use julie_test_support::db::{file_info_builder, symbol_builder};
+    use tempfile::TempDir;
+
+    use crate::counterparts::FastCounterpartsTool;
+
+    fn tool(symbol: &str, context_file: Option<&str>) -> FastCounterpartsTool {
+        FastCounterpartsTool {
+            symbol: symbol.to_string(),
+            context_file: context_file.map(str::to_string),
+            min_confidence: None,
+            limit: 10,
+            workspace: None,
+        }
+    }
+
+    fn entity(id: &str, name: &str, language: &str, file_path: &str) -> Symbol {
+        symbol_builder(id, name, file_path)
+            .kind(SymbolKind::Class)
+            .language(language)
+            .span(1, 0, 20, 1)
+            .build()
+    }
+
+    fn field(parent: &Symbol, name: &str, line: u32) -> Symbol {
+        symbol_builder(format!("{}_{name}", parent.id), name, &parent.file_path)
+            .kind(SymbolKind::Property)
+            .language(&parent.language)
+            .span(line, 4, line, 30)
+            .parent_id(&parent.id)
+            .build()
+    }
+
+    fn seeded_db() -> (TempDir, SymbolDatabase) {
+        let temp_dir = TempDir::new().unwrap();
+        let mut db = SymbolDatabase::new(temp_dir.path().join("test.db")).unwrap();
+        for (path, language) in [
+            ("web/src/invoice.ts", "typescript"),
+            ("api/Dtos/InvoiceDto.cs", "csharp"),
+            ("api/Models/Invoice.cs", "csharp"),
+        ] {
+            db.store_file_info(&file_info_builder(path).language(language).build())
+                .unwrap();
+        }
+        let ts = entity("ts_invoice", "Invoice", "typescript", "web/src/invoice.ts");
+        let dto = entity("cs_dto", "InvoiceDto", "csharp", "api/Dtos/InvoiceDto.cs");
+        let model = entity("cs_model", "Invoice", "csharp", "api/Models/Invoice.cs");
+        let fields = vec![
+            field(&ts, "id", 2),
+            field(&ts, "total", 3),
+            field(&ts, "dueDate", 4),
+            field(&dto, "Id", 2),
+            field(&dto, "Total", 3),
+            field(&dto, "DueOn", 4),
+            field(&model, "Id", 2),
+            field(&model, "Total", 3),
+            field(&model, "DueDate", 4),
+        ];
+        let mut symbols = vec![ts, dto, model];
+        symbols.extend(fields);
+        db.store_symbols(&symbols).unwrap();
+        (temp_dir, db)
+    }
+
+    #[test]
+    fn lists_ranked_counterparts_with_the_fields_that_drifted() {
+        let (_temp_dir, db) = seeded_db();
+        let tool = tool("Invoice", Some("invoice.ts"));
+        let (reports, total) = tool.execute(&db).unwrap();
+        assert_eq!(total, 1, "context_file narrows the definitions");
+
+        assert_eq!(
+            tool.render(&reports, total),
+            "class Invoice (typescript) web/src/invoice.ts:1\n  \
+             1.00 class Invoice (csharp) api/Models/Invoice.cs:1 — same name, 3/3 fields\n  \
+             0.70 class InvoiceDto (csharp) api/Dtos/InvoiceDto.cs:1 — normalized name, 2/4 fields\n       \
+             missing there: dueDate\n       \
+             only there: DueOn"
+        );
+    }
+
+    #[test]
+    fn ambiguous_names_match_each_definition_and_same_language_is_skipped() {
+        let (_temp_dir, db) = seeded_db();
+        let tool = tool("Invoice", None);
+        let (reports, total) = tool.execute(&db).unwrap();
+
+        assert_eq!(total, 2);
+        let csharp = reports
+            .iter()
+            .find(|report| report.definition.id == "cs_model")
+            .unwrap();
+        let ids: Vec<&str> = csharp
+            .matches
+            .iter()
+            .map(|m| m.symbol.id.as_str())
+            .collect();
+        assert_eq!(ids, ["ts_invoice"], "the C# DTO is the same language");
+
+        assert_eq!(tool.render(&[], 0), "No definition found for 'Invoice'");
+    }
+}
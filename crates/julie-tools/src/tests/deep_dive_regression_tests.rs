@@ -112,6 +112,7 @@ fn empty_context(symbol: Symbol) -> SymbolContext {
     SymbolContext {
         symbol,
         complexity: None,
+        type_info: None,
         incoming: vec![],
         incoming_total: 0,
         incoming_calls_total: 0,
@@ -122,6 +123,11 @@ fn empty_context(symbol: Symbol) -> SymbolContext {
         implementations: vec![],
         test_refs: vec![],
         similar: vec![],
+        declaration_sites: vec![],
+        module: None,
+        project: None,
+        build_targets: vec![],
+        framework_role: None,
     }
 }
 
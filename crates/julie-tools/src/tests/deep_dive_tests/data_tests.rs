@@ -98,5 +98,8 @@ fn make_rel(
 }
 
 mod build_context;
+mod disambiguation;
 mod find_symbol;
 mod identifiers_query_similarity;
+mod partial_types;
+mod swift_modules;
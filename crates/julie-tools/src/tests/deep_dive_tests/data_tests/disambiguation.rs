@@ -0,0 +1,217 @@
+use super::*;
+use crate::deep_dive::data::{disambiguation_candidates, select_disambiguated};
+use crate::deep_dive::{DeepDiveOutput, deep_dive_output};
+
+// === Disambiguation of same-named definitions ===
+
+/// `process` as a method of `Engine`, of `Handler`, and as a free function.
+fn store_overloaded_fixture(db: &mut SymbolDatabase) {
+    db.store_symbols(&[
+        make_symbol(
+            "engine",
+            "Engine",
+            SymbolKind::Struct,
+            "src/engine.rs",
+            1,
+            None,
+            Some("pub struct Engine"),
+            Some(Visibility::Public),
+            None,
+        ),
+        make_symbol(
+            "handler",
+            "Handler",
+            SymbolKind::Struct,
+            "src/handler.rs",
+            1,
+            None,
+            Some("pub struct Handler"),
+            Some(Visibility::Public),
+            None,
+        ),
+        make_symbol(
+            "engine-process",
+            "process",
+            SymbolKind::Method,
+            "src/engine.rs",
+            20,
+            Some("engine"),
+            Some("pub fn process(&self, input: &str) -> Output\n    where Self: Sized"),
+            Some(Visibility::Public),
+            None,
+        ),
+        make_symbol(
+            "handler-process",
+            "process",
+            SymbolKind::Method,
+            "src/handler.rs",
+            40,
+            Some("handler"),
+            Some("fn process(&mut self)"),
+            Some(Visibility::Private),
+            None,
+        ),
+        make_symbol(
+            "main-process",
+            "process",
+            SymbolKind::Function,
+            "src/main.rs",
+            5,
+            None,
+            Some("fn process()"),
+            None,
+            None,
+        ),
+    ])
+    .unwrap();
+}
+
+fn dive(db: &SymbolDatabase, disambiguator: Option<&str>) -> DeepDiveOutput {
+    deep_dive_output(
+        db,
+        "process",
+        None,
+        None,
+        disambiguator,
+        "overview",
+        10,
+        10,
+        None,
+    )
+    .unwrap()
+}
+
+#[test]
+fn test_ambiguous_name_lists_candidates_with_context() {
+    let (_tmp, mut db) = setup_db();
+    store_overloaded_fixture(&mut db);
+
+    let output = dive(&db, None);
+    let candidates = &output.candidates;
+    assert_eq!(candidates.len(), 3);
+
+    let engine = candidates
+        .iter()
+        .find(|c| c.symbol_id == "engine-process")
+        .unwrap();
+    assert_eq!(engine.disambiguator, "src/engine.rs:20");
+    assert_eq!(engine.kind, "method");
+    assert_eq!(engine.visibility.as_deref(), Some("public"));
+    assert_eq!(engine.container.as_deref(), Some("Engine"));
+    assert_eq!(
+        engine.signature.as_deref(),
+        Some("pub fn process(&self, input: &str) -> Output")
+    );
+    let free = candidates
+        .iter()
+        .find(|c| c.symbol_id == "main-process")
+        .unwrap();
+    assert_eq!(free.container, None);
+
+    assert!(
+        output.text.contains("Found 3 definitions of 'process'"),
+        "{}",
+        output.text
+    );
+    assert!(
+        output
+            .text
+            .contains("  src/handler.rs:40 (method, private, in Handler) — fn process(&mut self)"),
+        "{}",
+        output.text
+    );
+}
+
+#[test]
+fn test_disambiguator_selects_one_definition() {
+    let (_tmp, mut db) = setup_db();
+    store_overloaded_fixture(&mut db);
+
+    for disambiguator in ["src/handler.rs:40", "handler.rs:40", "handler-process"] {
+        let output = dive(&db, Some(disambiguator));
+        assert!(output.candidates.is_empty(), "{disambiguator}");
+        assert!(
+            output.text.contains("src/handler.rs:40"),
+            "{disambiguator}: {}",
+            output.text
+        );
+        assert!(
+            !output.text.contains("src/engine.rs"),
+            "{disambiguator}: {}",
+            output.text
+        );
+    }
+
+    let missed = dive(&db, Some("src/handler.rs:41"));
+    assert!(
+        missed
+            .text
+            .starts_with("No single definition of 'process' matches disambiguator"),
+        "{}",
+        missed.text
+    );
+    assert_eq!(missed.candidates.len(), 3);
+}
+
+#[test]
+fn test_shared_location_falls_back_to_symbol_id() {
+    let (_tmp, db) = setup_db();
+    let symbols = [
+        make_symbol(
+            "first",
+            "handle",
+            SymbolKind::Function,
+            "src/main.rs",
+            7,
+            None,
+            None,
+            None,
+            None,
+        ),
+        make_symbol(
+            "second",
+            "handle",
+            SymbolKind::Function,
+            "src/main.rs",
+            7,
+            None,
+            None,
+            None,
+            None,
+        ),
+    ];
+
+    let candidates = disambiguation_candidates(&db, &symbols).unwrap();
+    assert_eq!(candidates[0].disambiguator, "first");
+    assert_eq!(candidates[1].disambiguator, "second");
+
+    let selected = select_disambiguated(symbols.to_vec(), "second");
+    assert_eq!(selected.len(), 1);
+    assert_eq!(selected[0].id, "second");
+    assert_eq!(
+        select_disambiguated(symbols.to_vec(), "src/main.rs:7").len(),
+        2
+    );
+}
+
+#[test]
+fn test_candidates_are_structured_content() {
+    let (_tmp, mut db) = setup_db();
+    store_overloaded_fixture(&mut db);
+
+    let result = dive(&db, None).into_result("process");
+    let payload = result.structured_content.expect("structured candidates");
+    assert_eq!(payload["disambiguation"]["symbol"], "process");
+    let candidates = payload["disambiguation"]["candidates"].as_array().unwrap();
+    assert_eq!(candidates.len(), 3);
+    assert!(
+        candidates
+            .iter()
+            .any(|c| c["disambiguator"] == "src/main.rs:5"
+                && c["container"].is_null()
+                && c["kind"] == "function")
+    );
+
+    let single = dive(&db, Some("src/main.rs:5")).into_result("process");
+    assert!(single.structured_content.is_none());
+}
@@ -0,0 +1,143 @@
+use super::*;
+
+// === C# partial type tests ===
+
+fn partial_class(id: &str, file: &str, line: u32) -> Symbol {
+    let mut symbol = make_symbol(
+        id,
+        "OrderService",
+        SymbolKind::Class,
+        file,
+        line,
+        None,
+        Some("public partial class OrderService"),
+        Some(Visibility::Public),
+        None,
+    );
+    symbol.language = "csharp".to_string();
+    symbol.metadata = Some(HashMap::from([(
+        "partial_group".to_string(),
+        serde_json::Value::String("Shop.OrderService".to_string()),
+    )]));
+    symbol
+}
+
+fn partial_member(id: &str, name: &str, parent: &str, file: &str, line: u32) -> Symbol {
+    let mut symbol = make_symbol(
+        id,
+        name,
+        SymbolKind::Method,
+        file,
+        line,
+        Some(parent),
+        Some(&format!("public void {name}()")),
+        Some(Visibility::Public),
+        None,
+    );
+    symbol.language = "csharp".to_string();
+    symbol
+}
+
+fn store_partial_fixture(db: &mut SymbolDatabase) {
+    db.store_symbols(&[
+        partial_class("cls-a", "src/engine.rs", 3),
+        partial_class("cls-b", "src/handler.rs", 7),
+        partial_member("m-a", "PlaceOrder", "cls-a", "src/engine.rs", 5),
+        partial_member("m-b", "OnGenerated", "cls-b", "src/handler.rs", 9),
+    ])
+    .unwrap();
+}
+
+#[test]
+fn test_partial_declarations_collapse_into_one_logical_symbol() {
+    let (_tmp, mut db) = setup_db();
+    store_partial_fixture(&mut db);
+
+    let found = find_symbol(&db, "OrderService", None).unwrap();
+    assert_eq!(found.len(), 2, "both declarations are stored separately");
+
+    let output = deep_dive_query(&db, "OrderService", None, "overview", 10, 10).unwrap();
+    assert!(
+        !output.contains("Found 2 definitions"),
+        "partial declarations should not be reported as ambiguous: {output}"
+    );
+    assert!(output.contains("Partial declarations (2):"), "{output}");
+    assert!(output.contains("src/engine.rs:3"), "{output}");
+    assert!(output.contains("src/handler.rs:7"), "{output}");
+}
+
+#[test]
+fn test_partial_declaration_children_are_merged() {
+    let (_tmp, mut db) = setup_db();
+    store_partial_fixture(&mut db);
+
+    let primary = db.get_symbol_by_id("cls-a").unwrap().unwrap();
+    let ctx = build_symbol_context(&db, &primary, "overview", 10, 10).unwrap();
+
+    assert_eq!(ctx.declaration_sites.len(), 1);
+    assert_eq!(ctx.declaration_sites[0].id, "cls-b");
+    let child_names: Vec<&str> = ctx.children.iter().map(|c| c.name.as_str()).collect();
+    assert!(child_names.contains(&"PlaceOrder"));
+    assert!(child_names.contains(&"OnGenerated"));
+}
+
+// === Kotlin Multiplatform expect/actual tests ===
+
+fn multiplatform_function(id: &str, file: &str, role: &str) -> Symbol {
+    let mut symbol = make_symbol(
+        id,
+        "platformName",
+        SymbolKind::Function,
+        file,
+        3,
+        None,
+        Some(&format!("{role} fun platformName(): String")),
+        Some(Visibility::Public),
+        None,
+    );
+    symbol.language = "kotlin".to_string();
+    symbol.metadata = Some(HashMap::from([
+        (
+            "kotlin_multiplatform".to_string(),
+            serde_json::Value::String(role.to_string()),
+        ),
+        (
+            "partial_group".to_string(),
+            serde_json::Value::String("kotlin:app.platformName/0".to_string()),
+        ),
+    ]));
+    symbol
+}
+
+#[test]
+fn test_expect_and_actual_declarations_are_offered_together() {
+    let (_tmp, mut db) = setup_db();
+    db.store_symbols(&[
+        multiplatform_function("expect", "src/commonMain/kotlin/Platform.kt", "expect"),
+        multiplatform_function("jvm", "src/jvmMain/kotlin/Platform.kt", "actual"),
+        multiplatform_function("ios", "src/iosMain/kotlin/Platform.kt", "actual"),
+    ])
+    .unwrap();
+
+    let output = deep_dive_query(&db, "platformName", None, "overview", 10, 10).unwrap();
+    assert!(
+        !output.contains("Found 3 definitions"),
+        "expect and actuals are one logical symbol: {output}"
+    );
+    assert!(
+        output.contains("Expect/actual declarations (3):"),
+        "{output}"
+    );
+    assert!(
+        output.contains("src/commonMain/kotlin/Platform.kt:3 (expect)"),
+        "{output}"
+    );
+    assert!(
+        output.contains("src/jvmMain/kotlin/Platform.kt:3 (actual)"),
+        "{output}"
+    );
+    assert!(
+        output.contains("src/iosMain/kotlin/Platform.kt:3 (actual)"),
+        "{output}"
+    );
+}
@@ -0,0 +1,70 @@
+use super::*;
+use julie_core::database::SwiftModuleFile;
+
+// === Swift module qualification tests ===
+
+fn swift_type(id: &str, file: &str) -> Symbol {
+    let mut symbol = make_symbol(
+        id,
+        "Client",
+        SymbolKind::Class,
+        file,
+        3,
+        None,
+        Some("public final class Client"),
+        Some(Visibility::Public),
+        None,
+    );
+    symbol.language = "swift".to_string();
+    symbol
+}
+
+/// Two same-named `Client` types, one per module.
+fn store_two_module_fixture(db: &mut SymbolDatabase) {
+    db.store_symbols(&[
+        swift_type("net-client", "src/engine.rs"),
+        swift_type("ui-client", "src/handler.rs"),
+    ])
+    .unwrap();
+    db.replace_all_swift_module_files(&[
+        SwiftModuleFile {
+            file_path: "src/engine.rs".to_string(),
+            module: "Networking".to_string(),
+            manifest_path: "Package.swift".to_string(),
+        },
+        SwiftModuleFile {
+            file_path: "src/handler.rs".to_string(),
+            module: "UI".to_string(),
+            manifest_path: "Package.swift".to_string(),
+        },
+    ])
+    .unwrap();
+}
+
+#[test]
+fn test_module_qualified_name_selects_the_module_definition() {
+    let (_tmp, mut db) = setup_db();
+    store_two_module_fixture(&mut db);
+
+    assert_eq!(find_symbol(&db, "Client", None).unwrap().len(), 2);
+
+    let found = find_symbol(&db, "Networking.Client", None).unwrap();
+    assert_eq!(found.len(), 1);
+    assert_eq!(found[0].id, "net-client");
+
+    assert!(
+        find_symbol(&db, "Storage.Client", None).unwrap().is_empty(),
+        "an unknown module matches nothing"
+    );
+}
+
+#[test]
+fn test_swift_definitions_are_labelled_with_their_module() {
+    let (_tmp, mut db) = setup_db();
+    store_two_module_fixture(&mut db);
+
+    let output = deep_dive_query(&db, "UI.Client", None, "overview", 10, 10).unwrap();
+    assert!(output.contains("src/handler.rs:3"), "{output}");
+    assert!(output.contains("module: UI"), "{output}");
+    assert!(!output.contains("src/engine.rs"), "{output}");
+}
@@ -15,3 +15,20 @@ fn test_deep_dive_accepts_canonical_symbol_field() {
     let tool: DeepDiveTool = serde_json::from_str(json).unwrap();
     assert_eq!(tool.symbol, "MyFunction");
 }
+
+#[test]
+fn test_deep_dive_accepts_disambiguator() {
+    let json = r#"{"symbol": "process", "disambiguator": "src/engine.rs:20"}"#;
+    let tool: DeepDiveTool = serde_json::from_str(json).unwrap();
+    assert_eq!(tool.disambiguator.as_deref(), Some("src/engine.rs:20"));
+}
+
+#[test]
+fn test_deep_dive_accepts_max_tokens_as_string() {
+    let json = r#"{"symbol": "process", "max_tokens": "2500"}"#;
+    let tool: DeepDiveTool = serde_json::from_str(json).unwrap();
+    assert_eq!(tool.max_tokens, Some(2500));
+
+    let tool: DeepDiveTool = serde_json::from_str(r#"{"symbol": "process"}"#).unwrap();
+    assert_eq!(tool.max_tokens, None);
+}
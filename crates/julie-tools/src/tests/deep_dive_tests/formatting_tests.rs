@@ -1,5 +1,7 @@
 use crate::deep_dive::data::{RefEntry, SimilarEntry, SymbolContext};
-use crate::deep_dive::formatting::format_symbol_context;
+use crate::deep_dive::formatting::{
+    format_deleted_context, format_symbol_context, format_symbol_context_within,
+};
 use julie_extractors::base::{RelationshipKind, Symbol, SymbolKind, Visibility};
 
 fn make_symbol(
@@ -51,6 +53,7 @@ fn empty_context(symbol: Symbol) -> SymbolContext {
     SymbolContext {
         symbol,
         complexity: None,
+        type_info: None,
         incoming: vec![],
         incoming_total: 0,
         incoming_calls_total: 0,
@@ -61,6 +64,11 @@ fn empty_context(symbol: Symbol) -> SymbolContext {
         implementations: vec![],
         test_refs: vec![],
         similar: vec![],
+        declaration_sites: vec![],
+        module: None,
+        project: None,
+        build_targets: vec![],
+        framework_role: None,
     }
 }
 
@@ -565,3 +565,91 @@ fn test_module_shows_dependencies() {
     assert!(output.contains("src/models/money.rs"));
     assert!(output.contains("Money"));
 }
+
+// === C/C++ macro provenance ===
+
+#[test]
+fn test_macro_generated_symbol_links_to_invocation() {
+    let mut sym = make_symbol(
+        "Foo_handler",
+        SymbolKind::Function,
+        "src/handlers.c",
+        7,
+        Some("DEFINE_HANDLER(Foo)"),
+        None,
+        None,
+    );
+    sym.metadata = Some(std::collections::HashMap::from([(
+        "macro_expansion".to_string(),
+        serde_json::json!({
+            "macro": "DEFINE_HANDLER",
+            "invocation": "DEFINE_HANDLER(Foo)",
+            "line": 7,
+        }),
+    )]));
+
+    let output = format_symbol_context(&empty_context(sym), "overview");
+    assert!(
+        output.contains("Generated by macro: DEFINE_HANDLER(Foo) at src/handlers.c:7"),
+        "got: {output}"
+    );
+}
+
+#[test]
+fn test_macro_invocation_site_lists_generated_symbols() {
+    let mut sym = make_symbol(
+        "DEFINE_HANDLER",
+        SymbolKind::Function,
+        "src/handlers.c",
+        7,
+        Some("DEFINE_HANDLER(Foo)"),
+        None,
+        None,
+    );
+    sym.metadata = Some(std::collections::HashMap::from([(
+        "macro_expansion".to_string(),
+        serde_json::json!({
+            "macro": "DEFINE_HANDLER",
+            "invocation": "DEFINE_HANDLER(Foo)",
+            "line": 7,
+            "generated": ["Foo_handler", "Foo_calls"],
+        }),
+    )]));
+
+    let output = format_symbol_context(&empty_context(sym), "overview");
+    assert!(
+        output.contains("Macro invocation expands to: Foo_handler, Foo_calls"),
+        "got: {output}"
+    );
+    assert!(!output.contains("Generated by macro"));
+}
+
+// === Manifest dependencies ===
+
+#[test]
+fn test_manifest_dependency_shows_package_and_version() {
+    let mut sym = make_symbol(
+        "lodash",
+        SymbolKind::Import,
+        "package.json",
+        12,
+        Some("\"lodash\": \"^4.17.21\""),
+        None,
+        None,
+    );
+    sym.metadata = Some(std::collections::HashMap::from([(
+        "dependency".to_string(),
+        serde_json::json!({
+            "ecosystem": "npm",
+            "package": "lodash",
+            "section": "devDependencies",
+            "version": "^4.17.21",
+        }),
+    )]));
+
+    let output = format_symbol_context(&empty_context(sym), "overview");
+    assert!(
+        output.contains("Dependency: npm lodash ^4.17.21 (devDependencies)"),
+        "got: {output}"
+    );
+}
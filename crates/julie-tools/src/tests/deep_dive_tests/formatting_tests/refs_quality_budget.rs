@@ -601,6 +601,7 @@ fn test_full_depth_output_under_token_budget() {
     let ctx = SymbolContext {
         symbol: sym,
         complexity: None,
+        type_info: None,
         incoming,
         incoming_total: 50,
         incoming_calls_total: 50,
@@ -611,6 +612,11 @@ fn test_full_depth_output_under_token_budget() {
         implementations: vec![],
         test_refs: vec![],
         similar: vec![],
+        declaration_sites: vec![],
+        module: None,
+        project: None,
+        build_targets: vec![],
+        framework_role: None,
     };
 
     let output = format_symbol_context(&ctx, "full");
@@ -629,6 +635,20 @@ fn test_full_depth_output_under_token_budget() {
         "output should contain truncation notice when budget is exceeded, got:\n{}",
         &output[output.len().saturating_sub(200)..]
     );
+
+    // max_tokens replaces the depth's budget in either direction.
+    let tight = format_symbol_context_within(&ctx, "full", 500);
+    assert!(estimator.estimate_string(&tight) <= 500, "{tight}");
+    assert!(
+        tight.contains("(truncated to ~500 token budget)"),
+        "{tight}"
+    );
+    let roomy = format_symbol_context_within(&ctx, "full", 100_000);
+    assert!(
+        !roomy.contains("truncated to"),
+        "roomy budget should keep everything"
+    );
+    assert!(roomy.len() > output.len());
 }
 
 #[test]
@@ -668,6 +688,7 @@ fn test_overview_depth_under_token_budget() {
     let ctx = SymbolContext {
         symbol: sym,
         complexity: None,
+        type_info: None,
         incoming,
         incoming_total: 20,
         incoming_calls_total: 20,
@@ -678,6 +699,11 @@ fn test_overview_depth_under_token_budget() {
         implementations: vec![],
         test_refs: vec![],
         similar: vec![],
+        declaration_sites: vec![],
+        module: None,
+        project: None,
+        build_targets: vec![],
+        framework_role: None,
     };
 
     let output = format_symbol_context(&ctx, "overview");
@@ -690,3 +716,45 @@ fn test_overview_depth_under_token_budget() {
         token_count
     );
 }
+
+// === Results from files deleted from disk ===
+
+#[test]
+fn test_deleted_files_are_listed_with_their_counts() {
+    let sym = make_symbol(
+        "load_users",
+        SymbolKind::Function,
+        "src/legacy/users.rs",
+        10,
+        Some("pub fn load_users()"),
+        None,
+        None,
+    );
+    let mut ctx = empty_context(sym);
+    ctx.incoming = vec![
+        make_ref(RelationshipKind::Calls, "src/legacy/admin.rs", 4, None),
+        make_ref(RelationshipKind::Calls, "src/main.rs", 12, None),
+        make_ref(RelationshipKind::Calls, "src/legacy/admin.rs", 30, None),
+    ];
+    ctx.outgoing = vec![make_ref(
+        RelationshipKind::Calls,
+        "src/legacy/users.rs",
+        14,
+        None,
+    )];
+
+    let deleted = std::collections::HashSet::from([
+        "src/legacy/users.rs".to_string(),
+        "src/legacy/admin.rs".to_string(),
+    ]);
+    assert_eq!(
+        format_deleted_context(&ctx, &deleted),
+        "\n\nDeleted from disk (kept until the grace period ends):\n  \
+         src/legacy/users.rs  1 definition, 1 reference\n  \
+         src/legacy/admin.rs  2 references"
+    );
+    assert_eq!(
+        format_deleted_context(&ctx, &std::collections::HashSet::new()),
+        ""
+    );
+}
@@ -0,0 +1,88 @@
+//! Tests for recording edits in the operation journal and restoring them.
+
+#[cfg(test)]
+mod journal_tests {
+    use std::fs;
+
+    use julie_core::database::{JournalFile, JournalOperation};
+
+    use crate::editing::journal::{JournalRecorder, restore_operation};
+
+    fn operation(files: &[(&str, &str, &str)]) -> JournalOperation {
+        JournalOperation {
+            id: 7,
+            kind: "rename_symbol".to_string(),
+            summary: "rename_symbol 'old' → 'new'".to_string(),
+            created_at: 0,
+            files: files
+                .iter()
+                .map(|(file_path, before, after)| JournalFile {
+                    file_path: file_path.to_string(),
+                    before: before.to_string(),
+                    after: after.to_string(),
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn test_recorder_keeps_first_before_and_last_after() {
+        let mut journal = JournalRecorder::default();
+        journal.record("src/a.rs", "old()", "new()");
+        journal.record("src/b.rs", "old", "new");
+        journal.record("src/a.rs", "new()", "use x::new; new()");
+
+        assert_eq!(journal.files().len(), 2);
+        assert_eq!(journal.files()[0].before, "old()");
+        assert_eq!(journal.files()[0].after, "use x::new; new()");
+    }
+
+    #[test]
+    fn test_restore_operation_writes_every_file_back() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        fs::write(temp_dir.path().join("a.rs"), "new()").unwrap();
+        fs::write(temp_dir.path().join("b.rs"), "fn new() {}").unwrap();
+
+        restore_operation(
+            temp_dir.path(),
+            &operation(&[
+                ("a.rs", "old()", "new()"),
+                ("b.rs", "fn old() {}", "fn new() {}"),
+            ]),
+        )
+        .unwrap();
+
+        assert_eq!(
+            fs::read_to_string(temp_dir.path().join("a.rs")).unwrap(),
+            "old()"
+        );
+        assert_eq!(
+            fs::read_to_string(temp_dir.path().join("b.rs")).unwrap(),
+            "fn old() {}"
+        );
+    }
+
+    #[test]
+    fn test_restore_operation_refuses_files_changed_since() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        fs::write(temp_dir.path().join("a.rs"), "new()").unwrap();
+        fs::write(temp_dir.path().join("b.rs"), "fn new() { later_edit() }").unwrap();
+
+        let err = restore_operation(
+            temp_dir.path(),
+            &operation(&[
+                ("a.rs", "old()", "new()"),
+                ("b.rs", "fn old() {}", "fn new() {}"),
+            ]),
+        )
+        .unwrap_err()
+        .to_string();
+
+        assert!(err.contains("modified since it was applied: b.rs"), "{err}");
+        assert_eq!(
+            fs::read_to_string(temp_dir.path().join("a.rs")).unwrap(),
+            "new()",
+            "nothing is restored when any file conflicts"
+        );
+    }
+}
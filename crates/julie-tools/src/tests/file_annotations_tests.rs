@@ -0,0 +1,110 @@
+//! Tests for the get_file_annotations tool.
+
+#[cfg(test)]
+mod file_annotations_tests {
+    use tempfile::TempDir;
+
+    use crate::file_annotations::{FileAnnotationsFormat, GetFileAnnotationsTool};
+    use julie_core::database::{FileInfo, SymbolDatabase};
+    use julie_extractors::{Symbol, SymbolKind};
+
+    fn symbol(id: &str, name: &str, kind: SymbolKind, lines: (u32, u32)) -> Symbol {
+        Symbol {
+            id: id.to_string(),
+            name: name.to_string(),
+            kind,
+            language: "rust".to_string(),
+            file_path: "src/lib.rs".to_string(),
+            start_line: lines.0,
+            start_column: 0,
+            end_line: lines.1,
+            end_column: 1,
+            start_byte: 0,
+            end_byte: 0,
+            signature: None,
+            doc_comment: None,
+            visibility: None,
+            parent_id: None,
+            metadata: None,
+            semantic_group: None,
+            confidence: None,
+            code_context: None,
+            content_type: None,
+            body_span: None,
+            body_hash: None,
+            annotations: Vec::new(),
+        }
+    }
+
+    fn setup_db() -> (TempDir, SymbolDatabase) {
+        let temp_dir = TempDir::new().unwrap();
+        let mut db = SymbolDatabase::new(temp_dir.path().join("test.db")).unwrap();
+        db.store_file_info(&FileInfo {
+            path: "src/lib.rs".to_string(),
+            language: "rust".to_string(),
+            hash: "hash_lib".to_string(),
+            size: 120,
+            last_modified: 1_700_000_000,
+            last_indexed: 1_700_000_050,
+            symbol_count: 2,
+            line_count: 12,
+            content: None,
+        })
+        .unwrap();
+        let mut method = symbol("m", "run", SymbolKind::Method, (3, 5));
+        method.parent_id = Some("s".to_string());
+        db.store_symbols(&[symbol("s", "Runner", SymbolKind::Struct, (1, 10)), method])
+            .unwrap();
+        (temp_dir, db)
+    }
+
+    fn tool(format: FileAnnotationsFormat) -> GetFileAnnotationsTool {
+        GetFileAnnotationsTool {
+            file_path: "src/lib.rs".to_string(),
+            format,
+            ..GetFileAnnotationsTool::default()
+        }
+    }
+
+    #[test]
+    fn test_json_format_includes_file_metadata_and_symbols() {
+        let (_temp_dir, db) = setup_db();
+        let tool = tool(FileAnnotationsFormat::Json);
+        let rendered = tool.render(&tool.execute(&db).unwrap()).unwrap();
+        let value: serde_json::Value = serde_json::from_str(&rendered).unwrap();
+
+        assert_eq!(value["schema_version"], 1);
+        assert_eq!(value["file"]["last_modified"], 1_700_000_000);
+        assert_eq!(value["file"]["line_count"], 12);
+        let symbols = value["file"]["symbols"].as_array().unwrap();
+        assert_eq!(symbols.len(), 2);
+        assert_eq!(symbols[1]["name"], "run");
+        assert_eq!(symbols[1]["kind"], "method");
+        assert_eq!(symbols[1]["start_line"], 3);
+        assert_eq!(symbols[1]["reference_count"], 0);
+    }
+
+    #[test]
+    fn test_compact_format_indents_children() {
+        let (_temp_dir, db) = setup_db();
+        let tool = tool(FileAnnotationsFormat::Compact);
+        let rendered = tool.render(&tool.execute(&db).unwrap()).unwrap();
+
+        assert_eq!(
+            rendered,
+            "src/lib.rs (rust, 12 lines, modified 1700000000, indexed 1700000050)\n  \
+             1:0-10:1 struct Runner refs=0\n    3:0-5:1 method run refs=0"
+        );
+    }
+
+    #[test]
+    fn test_unindexed_file_is_an_error() {
+        let (_temp_dir, db) = setup_db();
+        let tool = GetFileAnnotationsTool {
+            file_path: "src/missing.rs".to_string(),
+            ..GetFileAnnotationsTool::default()
+        };
+        let error = tool.execute(&db).unwrap_err().to_string();
+        assert_eq!(error, "File not indexed: src/missing.rs");
+    }
+}
@@ -1,12 +1,19 @@
 //! Tests for navigation output formatting (format_lean_refs_results)
 
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
-use crate::navigation::formatting::{format_lean_refs_results, format_semantic_fallback};
+use crate::navigation::budgeted::format_budgeted_refs_results;
+use crate::navigation::formatting::{
+    format_cross_language_counterparts, format_deleted_files, format_external_usage,
+    format_lean_refs_results, format_semantic_fallback, format_textual_mentions,
+};
 use crate::navigation::resolution::parse_qualified_name;
-use julie_extractors::base::{RelationshipKind, SymbolKind, Visibility};
+use crate::search::LineMatch;
+use julie_core::database::{CrossLanguageLink, ExternalModuleUsage};
+use julie_extractors::base::{RelationshipKind, SymbolKind, TypeInfo, Visibility};
 use julie_extractors::{Relationship, Symbol};
 use julie_index::search::similarity::SimilarEntry;
+use julie_pipeline::indexing_core::markdown_links::DOC_ANCHOR_METADATA_KEY;
 
 fn make_test_symbol(file_path: &str, line: u32, kind: SymbolKind, sig: Option<&str>) -> Symbol {
     Symbol {
@@ -62,7 +69,13 @@ fn test_lean_refs_single_definition() {
         make_test_relationship("src/handler.rs", 55, RelationshipKind::Uses),
     ];
 
-    let output = format_lean_refs_results("UserService", &defs, &refs, &HashMap::new());
+    let output = format_lean_refs_results(
+        "UserService",
+        &defs,
+        &refs,
+        &HashMap::new(),
+        &HashMap::new(),
+    );
 
     assert!(output.contains("3 references to \"UserService\":"));
     assert!(output.contains("Definition:"));
@@ -75,7 +88,7 @@ fn test_lean_refs_single_definition() {
 
 #[test]
 fn test_lean_refs_no_results() {
-    let output = format_lean_refs_results("Unknown", &[], &[], &HashMap::new());
+    let output = format_lean_refs_results("Unknown", &[], &[], &HashMap::new(), &HashMap::new());
     assert!(
         output.contains("No references found for \"Unknown\""),
         "Should contain 'No references found' message, got: {}",
@@ -88,6 +101,86 @@ fn test_lean_refs_no_results() {
     );
 }
 
+#[test]
+fn test_lean_refs_lists_documentation_sections() {
+    let defs = vec![make_test_symbol(
+        "src/auth.rs",
+        10,
+        SymbolKind::Function,
+        None,
+    )];
+    let doc_link = |line: u32, anchor: &str| {
+        let mut rel =
+            make_test_relationship("docs/architecture.md", line, RelationshipKind::References);
+        rel.metadata = Some(HashMap::from([(
+            DOC_ANCHOR_METADATA_KEY.to_string(),
+            serde_json::json!(anchor),
+        )]));
+        rel
+    };
+    let refs = vec![
+        make_test_relationship("src/api.rs", 42, RelationshipKind::Calls),
+        doc_link(7, "auth-flow"),
+        doc_link(9, "auth-flow"),
+        doc_link(30, "sessions"),
+    ];
+
+    let output = format_lean_refs_results(
+        "authenticate",
+        &defs,
+        &refs,
+        &HashMap::new(),
+        &HashMap::new(),
+    );
+
+    assert!(output.contains("5 references to \"authenticate\":"));
+    assert!(
+        output.contains(
+            "References (1):\n  src/api.rs:42 (Calls)\n\nDocumented in (2):\n  docs/architecture.md#auth-flow\n  docs/architecture.md#sessions"
+        ),
+        "doc links should be listed once per section after the code references. Got:\n{}",
+        output
+    );
+}
+
+#[test]
+fn test_lean_refs_shows_definition_type() {
+    let defs = vec![
+        make_test_symbol(
+            "src/user.rs",
+            15,
+            SymbolKind::Function,
+            Some("fn load(id: u64)"),
+        ),
+        make_test_symbol("src/user.rs", 40, SymbolKind::Function, Some("fn save()")),
+    ];
+    let types = HashMap::from([(
+        defs[0].id.clone(),
+        TypeInfo {
+            symbol_id: defs[0].id.clone(),
+            resolved_type: "Result<User>".to_string(),
+            generic_params: None,
+            constraints: None,
+            is_inferred: true,
+            language: "rust".to_string(),
+            metadata: None,
+        },
+    )]);
+
+    let output = format_lean_refs_results("load", &defs, &[], &HashMap::new(), &types);
+
+    assert!(
+        output.contains("→ fn load(id: u64)\n    returns Result<User> (inferred)\n"),
+        "type line should follow its definition. Got:\n{}",
+        output
+    );
+    assert_eq!(
+        output.matches("returns").count(),
+        1,
+        "definitions without a type get no type line"
+    );
+}
+
 #[test]
 fn test_truncate_signature() {
     // truncate_signature is private, so we test it indirectly via format_lean_refs_results
@@ -99,7 +192,7 @@ fn test_truncate_signature() {
         SymbolKind::Function,
         Some(long_sig),
     )];
-    let output = format_lean_refs_results("test_fn", &defs, &[], &HashMap::new());
+    let output = format_lean_refs_results("test_fn", &defs, &[], &HashMap::new(), &HashMap::new());
     // The output should contain the definition but signature should be truncated
     assert!(output.contains("src/lib.rs:1 (function)"));
     assert!(output.contains("→ "));
@@ -115,7 +208,7 @@ fn test_lean_refs_uses_first_signature_line_only() {
         Some(multiline_sig),
     )];
 
-    let output = format_lean_refs_results("example", &defs, &[], &HashMap::new());
+    let output = format_lean_refs_results("example", &defs, &[], &HashMap::new(), &HashMap::new());
 
     assert!(output.contains("→ fn example("));
     assert!(
@@ -136,7 +229,7 @@ fn test_truncate_signature_keeps_total_length_within_limit() {
         Some(long_sig),
     )];
 
-    let output = format_lean_refs_results("test_fn", &defs, &[], &HashMap::new());
+    let output = format_lean_refs_results("test_fn", &defs, &[], &HashMap::new(), &HashMap::new());
     let signature = output
         .lines()
         .find_map(|line| line.split("→ ").nth(1))
@@ -165,7 +258,7 @@ fn test_truncate_signature_handles_unicode_without_panic() {
     )];
 
     let output = std::panic::catch_unwind(|| {
-        format_lean_refs_results("test_fn", &defs, &[], &HashMap::new())
+        format_lean_refs_results("test_fn", &defs, &[], &HashMap::new(), &HashMap::new())
     })
     .expect("format_lean_refs_results should not panic on non-ASCII signatures");
 
@@ -204,7 +297,13 @@ fn test_lean_refs_separates_imports_from_definitions() {
         RelationshipKind::Calls,
     )];
 
-    let output = format_lean_refs_results("UserService", &defs, &refs, &HashMap::new());
+    let output = format_lean_refs_results(
+        "UserService",
+        &defs,
+        &refs,
+        &HashMap::new(),
+        &HashMap::new(),
+    );
 
     // Total should include all definitions + references
     assert!(
@@ -248,7 +347,8 @@ fn test_lean_refs_single_import_uses_singular() {
     let import = make_test_symbol("src/api/auth.rs", 3, SymbolKind::Import, None);
     let defs = vec![import];
 
-    let output = format_lean_refs_results("UserService", &defs, &[], &HashMap::new());
+    let output =
+        format_lean_refs_results("UserService", &defs, &[], &HashMap::new(), &HashMap::new());
 
     assert!(
         output.contains("Import:\n"),
@@ -283,7 +383,8 @@ fn test_lean_refs_includes_source_symbol_names() {
     let mut source_names = HashMap::new();
     source_names.insert("caller_123".to_string(), "handle_request".to_string());
 
-    let output = format_lean_refs_results("UserService", &defs, &refs, &source_names);
+    let output =
+        format_lean_refs_results("UserService", &defs, &refs, &source_names, &HashMap::new());
 
     // Unified format: file:line  name (Kind)
     assert!(
@@ -302,7 +403,7 @@ fn test_lean_refs_graceful_without_source_names() {
     )];
 
     // Empty source names — should fall back to file:line (Kind) without name
-    let output = format_lean_refs_results("Foo", &[], &refs, &HashMap::new());
+    let output = format_lean_refs_results("Foo", &[], &refs, &HashMap::new(), &HashMap::new());
 
     assert!(
         output.contains("src/api.rs:42 (Uses)"),
@@ -337,7 +438,8 @@ fn test_lean_refs_groups_same_file_references() {
     source_names.insert("caller_b".to_string(), "validate_token".to_string());
     source_names.insert("caller_c".to_string(), "login".to_string());
 
-    let output = format_lean_refs_results("UserService", &defs, &refs, &source_names);
+    let output =
+        format_lean_refs_results("UserService", &defs, &refs, &source_names, &HashMap::new());
 
     // The grouped file should appear only once as a header
     let auth_occurrences = output.matches("src/api/auth.rs").count();
@@ -369,6 +471,58 @@ fn test_lean_refs_groups_same_file_references() {
     );
 }
 
+#[test]
+fn test_budgeted_refs_collapse_by_file_with_omission_footer() {
+    let defs = vec![make_test_symbol(
+        "src/user.rs",
+        15,
+        SymbolKind::Struct,
+        Some("pub struct UserService"),
+    )];
+    let refs: Vec<Relationship> = (0..30)
+        .map(|i| {
+            make_test_relationship(
+                &format!("src/file_{}.rs", i % 3),
+                i + 1,
+                RelationshipKind::Calls,
+            )
+        })
+        .collect();
+    let mentions = vec![LineMatch {
+        file_path: "config/app.yaml".to_string(),
+        line_number: 4,
+        line_content: "  service: UserService".to_string(),
+    }];
+
+    let budgeted = |max_tokens| {
+        format_budgeted_refs_results(
+            "UserService",
+            &defs,
+            &refs,
+            &HashMap::new(),
+            &HashMap::new(),
+            &mentions,
+            max_tokens,
+        )
+    };
+
+    let unbounded = budgeted(100_000);
+    assert!(unbounded.contains("31 references to \"UserService\":"));
+    assert!(unbounded.contains("src/user.rs:15 (definition, struct) → pub struct UserService"));
+    assert!(unbounded.contains("src/file_0.rs:1 (Calls)"));
+    assert!(unbounded.contains("config/app.yaml:4 [textual]\n  service: UserService"));
+    assert!(!unbounded.contains("[budget]"));
+
+    let shaped = budgeted(120);
+    assert!(
+        shaped.contains("src/file_0.rs (10 results)"),
+        "tight budget should collapse references by file: {shaped}"
+    );
+    assert!(shaped.contains("src/user.rs (1 result)"));
+    assert!(!shaped.contains("src/file_0.rs:1 (Calls)"));
+    assert!(shaped.contains("[budget] Omitted to fit max_tokens=120"));
+}
+
 // --- Qualified name parsing tests ---
 
 #[test]
@@ -483,3 +637,162 @@ fn test_format_semantic_fallback_empty() {
         "Should return empty string for no results"
     );
 }
+
+fn make_link(from: &str, to: &str, confidence: f32, reason: &str) -> CrossLanguageLink {
+    CrossLanguageLink {
+        from_symbol_id: from.to_string(),
+        to_symbol_id: to.to_string(),
+        confidence,
+        reason: reason.to_string(),
+    }
+}
+
+#[test]
+fn test_format_cross_language_counterparts() {
+    let def = make_test_symbol("web/src/user.ts", 3, SymbolKind::Interface, None);
+    let mut dto = make_test_symbol("api/Dtos/UserDto.cs", 5, SymbolKind::Class, None);
+    dto.name = "UserDto".to_string();
+    dto.language = "csharp".to_string();
+    let mut table = make_test_symbol("db/schema.sql", 12, SymbolKind::Class, None);
+    table.name = "users".to_string();
+    table.language = "sql".to_string();
+
+    let links = vec![
+        make_link(&def.id, &dto.id, 0.9, "normalized name, 3/3 fields"),
+        make_link(&def.id, &table.id, 0.7, "normalized name, 2/4 fields"),
+        // Reached again from a second definition: listed once.
+        make_link("other_def", &dto.id, 0.6, "normalized name"),
+        // Already shown as a definition: skipped.
+        make_link(&dto.id, &def.id, 0.9, "normalized name, 3/3 fields"),
+    ];
+    let counterparts: HashMap<String, Symbol> = [def.clone(), dto.clone(), table.clone()]
+        .into_iter()
+        .map(|s| (s.id.clone(), s))
+        .collect();
+
+    let output = format_cross_language_counterparts(&[def], &links, &counterparts);
+    assert_eq!(
+        output,
+        "\n\nCross-language counterparts:\n\
+         \x20 api/Dtos/UserDto.cs:5  UserDto (class, csharp) 0.90 — normalized name, 3/3 fields\n\
+         \x20 db/schema.sql:12  users (class, sql) 0.70 — normalized name, 2/4 fields"
+    );
+}
+
+#[test]
+fn test_format_cross_language_counterparts_empty() {
+    let def = make_test_symbol("web/src/user.ts", 3, SymbolKind::Interface, None);
+    let output = format_cross_language_counterparts(&[def], &[], &HashMap::new());
+    assert!(output.is_empty());
+}
+
+fn make_external_usage(
+    module: &str,
+    name: &str,
+    file_path: &str,
+    line: u32,
+) -> ExternalModuleUsage {
+    ExternalModuleUsage {
+        module: module.to_string(),
+        name: name.to_string(),
+        file_path: file_path.to_string(),
+        start_line: line,
+        containing_symbol_id: None,
+    }
+}
+
+#[test]
+fn test_format_external_usage_groups_by_library() {
+    let usages = vec![
+        make_external_usage("lodash", "_.debounce", "web/src/search.ts", 8),
+        make_external_usage("requests", "requests.get", "app/client.py", 14),
+        make_external_usage("requests", "get", "app/client.py", 31),
+        make_external_usage("requests", "requests.post", "app/sync.py", 5),
+        make_external_usage("requests", "get", "app/sync.py", 9),
+    ];
+
+    let output = format_external_usage(&usages);
+    assert_eq!(
+        output,
+        "\n\nExternal usage by library:\n\
+         \x20 requests (4 calls)\n\
+         \x20   app/client.py:14  requests.get\n\
+         \x20   app/client.py:31  get\n\
+         \x20   app/sync.py:5  requests.post\n\
+         \x20 lodash (1 call)\n\
+         \x20   web/src/search.ts:8  _.debounce"
+    );
+    assert!(format_external_usage(&[]).is_empty());
+}
+
+#[test]
+fn test_format_textual_mentions_tags_each_line() {
+    let long_comment = format!("// {}", "x".repeat(200));
+    let mentions = vec![
+        LineMatch {
+            file_path: "config/routes.yaml".to_string(),
+            line_number: 12,
+            line_content: "    handler: \"UserService.create\"".to_string(),
+        },
+        LineMatch {
+            file_path: "src/app.ts".to_string(),
+            line_number: 40,
+            line_content: long_comment,
+        },
+    ];
+
+    let output = format_textual_mentions(&mentions);
+    let lines: Vec<&str> = output.lines().collect();
+    assert_eq!(
+        lines[2],
+        "Textual mentions (comments/strings, not resolved references):"
+    );
+    assert_eq!(
+        lines[3],
+        "  config/routes.yaml:12  [textual] handler: \"UserService.create\""
+    );
+    assert!(lines[4].starts_with("  src/app.ts:40  [textual] // xxx"));
+    assert!(lines[4].ends_with('…'), "long lines are truncated");
+    assert!(format_textual_mentions(&[]).is_empty());
+}
+
+#[test]
+fn test_format_deleted_files_counts_results_per_deleted_file() {
+    let defs = vec![make_test_symbol(
+        "src/legacy/users.rs",
+        3,
+        SymbolKind::Struct,
+        None,
+    )];
+    let refs = vec![
+        make_test_relationship("src/legacy/users.rs", 40, RelationshipKind::Uses),
+        make_test_relationship("src/api.rs", 12, RelationshipKind::Calls),
+        make_test_relationship("src/old_api.rs", 7, RelationshipKind::Calls),
+        make_test_relationship("src/old_api.rs", 9, RelationshipKind::Calls),
+    ];
+    let deleted = HashSet::from([
+        "src/legacy/users.rs".to_string(),
+        "src/old_api.rs".to_string(),
+        "src/unrelated.rs".to_string(),
+    ]);
+
+    assert_eq!(
+        format_deleted_files(&defs, &refs, &deleted),
+        "\n\nDeleted from disk (kept until the grace period ends):\n  \
+         src/legacy/users.rs  1 definition, 1 reference\n  \
+         src/old_api.rs  2 references"
+    );
+    assert!(
+        format_deleted_files(
+            &defs,
+            &refs[1..2],
+            &HashSet::from(["src/api.rs".to_string()])
+        )
+        .contains("src/api.rs  1 reference")
+    );
+    assert!(format_deleted_files(&defs, &refs, &HashSet::new()).is_empty());
+    assert!(
+        format_deleted_files(&[], &refs[1..2], &deleted).is_empty(),
+        "no section when no result is in a deleted file"
+    );
+}
@@ -7,7 +7,7 @@ mod formatting_tests {
     use crate::get_context::allocation::{Allocation, NeighborMode, PivotMode};
     use crate::get_context::formatting::{
         ContextData, NeighborEntry, OutputFormat, PivotEntry, format_context,
-        format_context_with_mode,
+        format_context_with_mode, with_deleted_files,
     };
 
     /// Helper: build an Allocation with specific modes (token counts don't matter for formatting).
@@ -995,4 +995,42 @@ mod formatting_tests {
             "readable no-results should use === borders"
         );
     }
+
+    #[test]
+    fn test_deleted_files_follow_the_context() {
+        let data = ContextData {
+            query: "load users".to_string(),
+            pivots: vec![
+                make_pivot(
+                    "load_users",
+                    "src/legacy/users.rs",
+                    10,
+                    3.0,
+                    "fn load_users()",
+                ),
+                make_pivot("save_users", "src/store.rs", 40, 1.0, "fn save_users()"),
+            ],
+            neighbors: vec![
+                make_neighbor("parse_user", "src/legacy/users.rs", 30, None, None),
+                make_neighbor("User", "src/model.rs", 5, None, None),
+            ],
+            allocation: make_allocation(PivotMode::SignatureOnly, NeighborMode::NameAndLocation),
+            spillover_handle: None,
+        };
+        let output = format_context_with_mode(&data, OutputFormat::Compact);
+
+        let deleted = std::collections::HashSet::from(["src/legacy/users.rs".to_string()]);
+        let flagged = with_deleted_files(output.clone(), &data, &deleted);
+        assert!(
+            flagged.ends_with(
+                "\n\nDeleted from disk (kept until the grace period ends):\n  \
+                 src/legacy/users.rs  1 pivot, 1 neighbor\n"
+            ),
+            "{flagged}"
+        );
+        assert!(flagged.starts_with(output.trim_end()));
+
+        let unflagged = with_deleted_files(output.clone(), &data, &Default::default());
+        assert_eq!(unflagged, output);
+    }
 }
@@ -0,0 +1,129 @@
+/// Per-query language filtering and boosting for semantic search.
+///
+/// `languages` is applied inside the vector query (the top-k is filled from
+/// matching symbols only); `boost_languages` scales the distances of symbols
+/// in those languages without excluding anything.
+#[cfg(test)]
+mod language_scope_tests {
+    use julie_core::database::SymbolDatabase;
+    use julie_extractors::SymbolKind;
+    use julie_index::search::hybrid::{SemanticLanguageScope, scoped_knn_search};
+    use julie_test_support::db::{file_info_builder, store_file_info_if_missing, symbol_builder};
+    use tempfile::TempDir;
+
+    use crate::search::FastSearchTool;
+
+    /// 384-dim unit vector at `angle` radians from axis 0.
+    fn unit_vector(angle: f32) -> Vec<f32> {
+        let mut v = vec![0.0_f32; 384];
+        v[0] = angle.cos();
+        v[1] = angle.sin();
+        v
+    }
+
+    /// A Rust handler nearest the query, a Go handler just behind it, a far
+    /// Go helper and a Python script in between.
+    fn seeded_db() -> (SymbolDatabase, TempDir) {
+        let dir = tempfile::tempdir().unwrap();
+        let mut db = SymbolDatabase::new(dir.path().join("test.db")).unwrap();
+        let symbols = [
+            ("rs_handler", "src/handler.rs", "rust", 0.30),
+            ("go_handler", "svc/handler.go", "go", 0.35),
+            ("py_script", "tools/script.py", "python", 0.60),
+            ("go_helper", "svc/helper.go", "go", 1.50),
+        ];
+        let mut embeddings = Vec::new();
+        for (id, file_path, language, angle) in symbols {
+            store_file_info_if_missing(
+                &db,
+                &file_info_builder(file_path).language(language).build(),
+            )
+            .unwrap();
+            db.store_symbols(&[symbol_builder(id, id, file_path)
+                .kind(SymbolKind::Function)
+                .language(language)
+                .span(1, 0, 10, 1)
+                .build()])
+                .unwrap();
+            embeddings.push((id.to_string(), unit_vector(angle)));
+        }
+        db.store_embeddings(&embeddings).unwrap();
+        (db, dir)
+    }
+
+    fn ids(hits: &[(String, f64)]) -> Vec<&str> {
+        hits.iter().map(|(id, _)| id.as_str()).collect()
+    }
+
+    fn langs(names: &[&str]) -> Vec<String> {
+        names.iter().map(|name| name.to_string()).collect()
+    }
+
+    #[test]
+    fn test_languages_filter_fills_limit_from_matching_symbols() {
+        let (db, _dir) = seeded_db();
+        let query = unit_vector(0.0);
+
+        let unscoped =
+            scoped_knn_search(&db, &query, 2, &SemanticLanguageScope::default()).unwrap();
+        assert_eq!(ids(&unscoped), ["rs_handler", "go_handler"]);
+
+        let go_only = SemanticLanguageScope::new(&langs(&["go"]), &[]);
+        let hits = scoped_knn_search(&db, &query, 2, &go_only).unwrap();
+        assert_eq!(ids(&hits), ["go_handler", "go_helper"]);
+    }
+
+    #[test]
+    fn test_boost_languages_rank_first_without_excluding_others() {
+        let (db, _dir) = seeded_db();
+        let query = unit_vector(0.0);
+
+        let boost_go = SemanticLanguageScope::new(&[], &langs(&["go"]));
+        let hits = scoped_knn_search(&db, &query, 4, &boost_go).unwrap();
+        assert_eq!(
+            ids(&hits),
+            ["go_handler", "rs_handler", "py_script", "go_helper"],
+            "a boost reorders close matches but cannot lift a far one past them"
+        );
+
+        // Boosts outside the `languages` filter are ignored.
+        let scoped = SemanticLanguageScope::new(&langs(&["rust", "python"]), &langs(&["go"]));
+        let hits = scoped_knn_search(&db, &query, 4, &scoped).unwrap();
+        assert_eq!(ids(&hits), ["rs_handler", "py_script"]);
+    }
+
+    #[test]
+    fn test_scope_normalizes_names_and_folds_in_language_filter() {
+        let scope = SemanticLanguageScope::new(&langs(&[" Go", "go", ""]), &langs(&["RUST"]));
+        assert_eq!(scope.languages, ["go"]);
+        assert_eq!(scope.boost_languages, ["rust"]);
+        assert!(scope.allows("go"));
+        assert!(!scope.allows("rust"));
+
+        let folded = SemanticLanguageScope::default().with_filter_language(Some("python"));
+        assert_eq!(folded.languages, ["python"]);
+        assert!(SemanticLanguageScope::default().is_unscoped());
+        assert_eq!(
+            scope.clone().with_filter_language(Some("python")).languages,
+            ["go"],
+            "an explicit languages list wins over the single-language filter"
+        );
+    }
+
+    #[test]
+    fn test_fast_search_accepts_language_lists() {
+        let tool: FastSearchTool = serde_json::from_value(serde_json::json!({
+            "query": "request handler",
+            "backend": "semantic",
+            "languages": ["go"],
+            "boost_languages": "[\"go\", \"proto\"]"
+        }))
+        .unwrap();
+        assert_eq!(tool.languages.as_deref(), Some(&langs(&["go"])[..]));
+        assert_eq!(
+            tool.boost_languages.as_deref(),
+            Some(&langs(&["go", "proto"])[..])
+        );
+        assert_eq!(FastSearchTool::default().languages, None);
+    }
+}
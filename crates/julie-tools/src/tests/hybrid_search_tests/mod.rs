@@ -1,6 +1,7 @@
 //! Hybrid search test modules.
 
 mod knn_conversion;
+mod language_scope;
 mod lock_free_embed;
 mod orchestrator;
 mod rrf_merge;
@@ -318,6 +318,7 @@ mod orchestrator_tests {
             kind: None,
             file_pattern: Some("src/**/*.rs".to_string()),
             exclude_tests: false,
+            include_generated: false,
         };
 
         let provider = StaticProvider;
@@ -0,0 +1,150 @@
+//! Tests for `fast_imports` reports and rendering.
+
+#[cfg(test)]
+mod imports_tests {
+    use julie_core::database::{ProjectFile, SymbolDatabase};
+    use julie_extractors::{SymbolKind, Visibility};
+    use julie_test_support::db::{file_info_builder, symbol_builder};
+    use tempfile::TempDir;
+
+    use crate::imports::FastImportsTool;
+
+    const TARGET: &str = "web/src/util/format.ts";
+
+    fn tool(target: &str, limit: u32) -> FastImportsTool {
+        FastImportsTool {
+            target: target.to_string(),
+            workspace: None,
+            limit,
+        }
+    }
+
+    fn seeded_db() -> (TempDir, SymbolDatabase) {
+        let temp_dir = TempDir::new().unwrap();
+        let mut db = SymbolDatabase::new(temp_dir.path().join("test.db")).unwrap();
+        for path in [
+            TARGET,
+            "web/src/pages/home.tsx",
+            "admin/src/report.ts",
+            "scripts/build.ts",
+        ] {
+            db.store_file_info(&file_info_builder(path).language("typescript").build())
+                .unwrap();
+        }
+        let import = |id: &str, name: &str, file: &str, line: u32, statement: &str| {
+            symbol_builder(id, name, file)
+                .kind(SymbolKind::Import)
+                .language("typescript")
+                .span(line, 0, line, 60)
+                .signature(statement)
+                .build()
+        };
+        db.store_symbols(&[
+            symbol_builder("format_date", "formatDate", TARGET)
+                .kind(SymbolKind::Function)
+                .language("typescript")
+                .span(3, 0, 5, 1)
+                .visibility(Visibility::Public)
+                .build(),
+            symbol_builder("pad", "pad", TARGET)
+                .kind(SymbolKind::Function)
+                .language("typescript")
+                .span(7, 0, 9, 1)
+                .visibility(Visibility::Private)
+                .build(),
+            symbol_builder("default_export", "default", TARGET)
+                .kind(SymbolKind::Export)
+                .language("typescript")
+                .span(11, 0, 11, 30)
+                .build(),
+            import(
+                "home_format",
+                "formatDate",
+                "web/src/pages/home.tsx",
+                1,
+                "import { formatDate } from '../util/format'",
+            ),
+            import(
+                "report_format",
+                "fmt",
+                "admin/src/report.ts",
+                2,
+                "import * as fmt from '@/util/format'",
+            ),
+            import(
+                "build_lodash",
+                "_",
+                "scripts/build.ts",
+                1,
+                "import _ from 'lodash'",
+            ),
+        ])
+        .unwrap();
+        db.replace_all_project_files(&[ProjectFile {
+            file_path: "web/src/pages/home.tsx".to_string(),
+            project: "web".to_string(),
+            manifest_path: "web/package.json".to_string(),
+        }])
+        .unwrap();
+        (temp_dir, db)
+    }
+
+    #[test]
+    fn test_file_target_lists_exports_and_importers_by_project() {
+        let (_temp_dir, db) = seeded_db();
+        let tool = tool(TARGET, 100);
+        let report = tool.execute(&db, Some(TARGET)).unwrap();
+
+        assert_eq!(report.language.as_deref(), Some("typescript"));
+        let exports: Vec<&str> = report.exports.iter().map(|e| e.name.as_str()).collect();
+        assert_eq!(exports, ["formatDate", "default"]);
+        assert_eq!(report.import_count(), 2);
+        assert_eq!(report.importing_files(), 2);
+        let projects: Vec<&str> = report.importers.keys().map(String::as_str).collect();
+        assert_eq!(projects, ["(no project)", "web"]);
+
+        let rendered = tool.render(&report);
+        assert!(rendered.starts_with("web/src/util/format.ts (typescript)"));
+        assert!(rendered.contains("Imported by 2 files (2 statements) in 2 projects:"));
+        assert!(rendered.contains(
+            "web/src/pages/home.tsx:1 named [formatDate]  import { formatDate } from '../util/format'"
+        ));
+        assert!(rendered.contains("admin/src/report.ts:2 namespace [fmt]"));
+        assert!(!rendered.contains("lodash"));
+    }
+
+    #[test]
+    fn test_unindexed_target_is_treated_as_a_library() {
+        let (_temp_dir, db) = seeded_db();
+        let tool = tool("lodash", 100);
+        let report = tool.execute(&db, Some("lodash")).unwrap();
+
+        assert_eq!(report.language, None);
+        assert!(report.exports.is_empty());
+        assert_eq!(report.import_count(), 1);
+        let rendered = tool.render(&report);
+        assert!(rendered.starts_with("lodash (library)"));
+        assert!(rendered.contains("scripts/build.ts:1"));
+    }
+
+    #[test]
+    fn test_limit_truncates_listing_but_not_totals() {
+        let (_temp_dir, db) = seeded_db();
+        let tool = tool(TARGET, 1);
+        let rendered = tool.render(&tool.execute(&db, Some(TARGET)).unwrap());
+
+        assert!(rendered.contains("(2 statements)"));
+        assert!(rendered.contains("… 1 more (raise `limit` to list them)"));
+    }
+
+    #[test]
+    fn test_library_without_importers() {
+        let (_temp_dir, db) = seeded_db();
+        let tool = tool("left-pad", 100);
+        let rendered = tool.render(&tool.execute(&db, None).unwrap());
+        assert_eq!(
+            rendered,
+            "left-pad (library)\nNot imported anywhere in this workspace"
+        );
+    }
+}
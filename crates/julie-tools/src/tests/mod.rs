@@ -11,6 +11,30 @@ pub mod hybrid_search_tests;
 pub mod phase4_token_savings;
 pub mod query_classification_tests;
 
+// Security audit
+pub mod audit_tests;
+
+// File annotations
+pub mod file_annotations_tests;
+
+// Module imports
+pub mod imports_tests;
+
+// Cross-language counterparts
+pub mod counterparts_tests;
+
+// Typed signature lookup
+pub mod signature_tests;
+
+// Symbol context packs
+pub mod context_pack_tests;
+
+// Syntax query
+pub mod syntax_query_tests;
+
+// Background task status
+pub mod task_status_tests;
+
 // Deep dive (T2b.6)
 pub mod deep_dive_regression_tests;
 pub mod deep_dive_tests;
@@ -29,6 +53,7 @@ pub mod get_context_tests;
 pub mod get_context_token_budget_tests;
 
 // Editing (T2b.6)
+pub mod editing_journal_tests;
 pub mod editing_markdown_section_tests;
 pub mod editing_security_tests;
 pub mod editing_transactional_editing_tests;
@@ -47,12 +72,23 @@ pub mod metrics_tool_calls_db_tests;
 
 // Search (T2b.6)
 pub mod search_annotation_search_tests;
+pub mod search_dedupe_tests;
+pub mod search_defines_tests;
+pub mod search_deleted_files_tests;
+pub mod search_frameworks_tests;
 pub mod search_lean_format_tests;
 pub mod search_line_match_strategy_tests;
 pub mod search_nl_path_prior_pipeline_tests;
 pub mod search_nl_symbol_query_latency_tests;
+pub mod search_popularity_tests;
 pub mod search_pretokenized_emit_test;
+pub mod search_projects_tests;
+pub mod search_regex_mode_tests;
+pub mod search_todo_mode_tests;
+pub mod search_topics_mode_tests;
 pub mod search_promotion_tests;
+pub mod search_spelling_tests;
+pub mod search_streaming_tests;
 pub mod search_title_exact_boost_tests;
 pub mod search_zero_hit_reason_tests;
 pub mod tantivy_index_tests;
@@ -307,3 +307,55 @@ fn annotation_text_search_hydrates_hits_without_sqlite_prepend_pollution() {
         Some("fn plain_handler() {}")
     );
 }
+
+#[test]
+fn annotation_filter_prefix_matches_bare_and_native_names() {
+    let symbols = vec![
+        symbol(
+            "deprecated",
+            "old_api",
+            SymbolKind::Function,
+            "src/api.rs",
+            None,
+            vec![marker("Deprecated", "deprecated", "Deprecated")],
+        ),
+        symbol(
+            "fixture",
+            "db_session",
+            SymbolKind::Function,
+            "tests/conftest.py",
+            None,
+            vec![marker("pytest.fixture", "pytest.fixture", "pytest.fixture")],
+        ),
+        symbol(
+            "route",
+            "show_user",
+            SymbolKind::Function,
+            "src/routes.rs",
+            None,
+            vec![marker("Route", "route", "Route(\"/users\")")],
+        ),
+        symbol(
+            "name-only",
+            "annotation_helper",
+            SymbolKind::Function,
+            "src/helpers.rs",
+            None,
+            Vec::new(),
+        ),
+    ];
+    let (_db_dir, _index_dir, index) = projected_index(&symbols);
+
+    assert_eq!(
+        result_names(&index, "annotation:Deprecated"),
+        vec!["old_api"]
+    );
+    assert_eq!(
+        result_names(&index, "annotation:pytest.fixture"),
+        vec!["db_session"]
+    );
+    assert_eq!(
+        result_names(&index, "annotation:#[Route]"),
+        vec!["show_user"]
+    );
+}
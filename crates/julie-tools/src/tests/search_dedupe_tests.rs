@@ -0,0 +1,101 @@
+//! Tests for collapsing identical symbol copies in search results.
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use julie_core::database::SymbolCopy;
+    use julie_test_support::db::symbol_builder;
+
+    use crate::search::FastSearchTool;
+    use crate::search::dedupe::collapse_symbol_copies;
+    use crate::search::formatting::{copies_summary, format_unified_search_results};
+    use crate::search::trace::SearchHit;
+
+    fn symbol_hit(id: &str, name: &str, file_path: &str, line: u32) -> SearchHit {
+        let symbol = symbol_builder(id, name, file_path)
+            .span(line, 0, line + 3, 1)
+            .signature(format!("fn {name}(input: &str)"))
+            .build();
+        SearchHit::from_symbol(symbol, "primary".to_string())
+    }
+
+    fn copy(id: &str, file_path: &str, line: u32) -> SymbolCopy {
+        SymbolCopy {
+            symbol_id: id.to_string(),
+            file_path: file_path.to_string(),
+            start_line: line,
+        }
+    }
+
+    fn parse_copies() -> HashMap<String, Vec<SymbolCopy>> {
+        let group = vec![
+            copy("main", "src/util.rs", 10),
+            copy("vendor-a", "vendor/a/util.rs", 7),
+            copy("vendor-b", "vendor/b/util.rs", 3),
+        ];
+        ["main", "vendor-a", "vendor-b"]
+            .into_iter()
+            .map(|id| (id.to_string(), group.clone()))
+            .collect()
+    }
+
+    #[test]
+    fn test_collapse_keeps_best_ranked_copy_and_lists_the_rest() {
+        let mut hits = vec![
+            symbol_hit("vendor-b", "parse", "vendor/b/util.rs", 3),
+            symbol_hit("other", "parse_args", "src/cli.rs", 5),
+            symbol_hit("main", "parse", "src/util.rs", 10),
+            symbol_hit("vendor-a", "parse", "vendor/a/util.rs", 7),
+        ];
+
+        let collapsed = collapse_symbol_copies(&mut hits, &parse_copies());
+
+        assert_eq!(collapsed, 2);
+        let ids: Vec<_> = hits
+            .iter()
+            .map(|hit| hit.symbol_id.as_deref().unwrap())
+            .collect();
+        assert_eq!(ids, ["vendor-b", "other"], "rank order is preserved");
+        assert_eq!(hits[0].copies, ["src/util.rs:10", "vendor/a/util.rs:7"]);
+        assert!(hits[1].copies.is_empty());
+    }
+
+    #[test]
+    fn test_copies_render_under_the_kept_hit() {
+        let mut hits = vec![
+            symbol_hit("main", "parse", "src/util.rs", 10),
+            symbol_hit("vendor-a", "parse", "vendor/a/util.rs", 7),
+        ];
+        collapse_symbol_copies(&mut hits, &parse_copies());
+
+        let output = format_unified_search_results("parse", &hits, hits.len());
+        assert!(
+            output.contains("+2 identical copies: vendor/a/util.rs:7, vendor/b/util.rs:3"),
+            "{output}"
+        );
+
+        let mut many = symbol_hit("main", "parse", "src/util.rs", 10);
+        many.copies = (1..=8).map(|i| format!("vendor/{i}/util.rs:3")).collect();
+        let summary = copies_summary(&many).unwrap();
+        assert!(summary.starts_with("+8 identical copies: vendor/1/util.rs:3"));
+        assert!(summary.ends_with("... 3 more (dedupe=false lists each copy)"));
+    }
+
+    #[test]
+    fn test_dedupe_defaults_on_and_can_be_disabled() {
+        let default: FastSearchTool = serde_json::from_value(serde_json::json!({
+            "query": "parse"
+        }))
+        .unwrap();
+        assert!(default.dedupe);
+        assert!(FastSearchTool::default().dedupe);
+
+        let disabled: FastSearchTool = serde_json::from_value(serde_json::json!({
+            "query": "parse",
+            "dedupe": "false"
+        }))
+        .unwrap();
+        assert!(!disabled.dedupe);
+    }
+}
@@ -0,0 +1,57 @@
+//! Tests for restricting search results to one C/C++ build configuration.
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use julie_test_support::db::symbol_builder;
+
+    use crate::search::defines::{parse_defines, retain_configuration};
+    use crate::search::trace::SearchHit;
+
+    fn symbol_hit(id: &str, file_path: &str) -> SearchHit {
+        let symbol = symbol_builder(id, "open_file", file_path).build();
+        SearchHit::from_symbol(symbol, "primary".to_string())
+    }
+
+    #[test]
+    fn test_parse_defines_accepts_names_values_and_blanks() {
+        let defines = parse_defines("DEBUG, LEVEL=3,,").unwrap();
+        assert!(defines.is_defined("DEBUG"));
+        assert_eq!(defines.value("LEVEL"), Some("3"));
+        assert!(parse_defines("").unwrap().is_empty());
+        assert!(parse_defines("1BAD").is_err());
+    }
+
+    #[test]
+    fn test_retain_configuration_drops_only_compiled_out_hits() {
+        let mut hits = vec![
+            symbol_hit("win", "src/io.c"),
+            symbol_hit("posix", "src/io.c"),
+            symbol_hit("plugin", "src/io.c"),
+            symbol_hit("plain", "src/util.c"),
+        ];
+        let conditions: HashMap<String, String> = [
+            ("win", "defined(_WIN32)"),
+            ("posix", "!defined(_WIN32)"),
+            ("plugin", "HAS_FEATURE(plugins)"),
+        ]
+        .into_iter()
+        .map(|(id, condition)| (id.to_string(), condition.to_string()))
+        .collect();
+
+        let removed =
+            retain_configuration(&mut hits, &conditions, &parse_defines("_WIN32").unwrap());
+
+        assert_eq!(removed, 1);
+        let ids: Vec<_> = hits
+            .iter()
+            .map(|hit| hit.symbol_id.as_deref().unwrap())
+            .collect();
+        assert_eq!(
+            ids,
+            ["win", "plugin", "plain"],
+            "undecidable and unconditional hits are kept"
+        );
+    }
+}
@@ -0,0 +1,49 @@
+//! Tests for flagging search hits from files deleted from disk.
+
+#[cfg(test)]
+mod tests {
+    use julie_core::database::SymbolDatabase;
+    use julie_extractors::SymbolKind;
+    use julie_test_support::db::symbol_builder;
+    use tempfile::TempDir;
+
+    use crate::deleted_files::read_deleted_files;
+    use crate::search::formatting::{format_deleted_hits, format_unified_locations};
+    use crate::search::trace::SearchHit;
+
+    fn symbol_hit(id: &str, file_path: &str, line: u32) -> SearchHit {
+        let symbol = symbol_builder(id, "load_users", file_path)
+            .kind(SymbolKind::Function)
+            .span(line, 0, line + 5, 1)
+            .signature("pub fn load_users()")
+            .build();
+        SearchHit::from_symbol(symbol, "primary".to_string())
+    }
+
+    #[test]
+    fn test_hits_from_soft_deleted_files_are_listed() {
+        let temp_dir = TempDir::new().unwrap();
+        let db = SymbolDatabase::new(temp_dir.path().join("deleted.db")).unwrap();
+        db.mark_file_soft_deleted("src/legacy/users.rs", 100)
+            .unwrap();
+        let deleted = read_deleted_files(&db);
+
+        let hits = vec![
+            symbol_hit("a", "src/legacy/users.rs", 3),
+            symbol_hit("b", "src/users.rs", 8),
+            symbol_hit("c", "src/legacy/users.rs", 40),
+        ];
+        let output = format_unified_locations("load_users", &hits, hits.len())
+            + &format_deleted_hits(&hits, &deleted);
+        assert!(
+            output.ends_with(
+                "\n\nDeleted from disk (kept until the grace period ends):\n  \
+                 src/legacy/users.rs  2 results"
+            ),
+            "{output}"
+        );
+
+        db.clear_soft_delete("src/legacy/users.rs").unwrap();
+        assert_eq!(format_deleted_hits(&hits, &read_deleted_files(&db)), "");
+    }
+}
@@ -0,0 +1,98 @@
+//! Tests for the `framework` filter.
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use julie_core::database::FrameworkSymbol;
+    use julie_extractors::SymbolKind;
+    use julie_test_support::db::symbol_builder;
+
+    use crate::frameworks::{FrameworkFilter, resolve_framework_filter, retain_framework_hits};
+    use crate::search::FastSearchTool;
+    use crate::search::trace::SearchHit;
+
+    fn symbol_hit(id: &str, name: &str) -> SearchHit {
+        let symbol = symbol_builder(id, name, "api/src/Users.java")
+            .kind(SymbolKind::Class)
+            .span(3, 0, 20, 1)
+            .build();
+        SearchHit::from_symbol(symbol, "primary".to_string())
+    }
+
+    fn roles() -> HashMap<String, FrameworkSymbol> {
+        [
+            ("service", "spring", "spring_bean"),
+            ("list", "spring", "spring_endpoint"),
+        ]
+        .into_iter()
+        .map(|(id, framework, role)| {
+            (
+                id.to_string(),
+                FrameworkSymbol {
+                    symbol_id: id.to_string(),
+                    framework: framework.to_string(),
+                    role: role.to_string(),
+                },
+            )
+        })
+        .collect()
+    }
+
+    #[test]
+    fn test_framework_filter_accepts_keys_and_labels() {
+        assert_eq!(
+            resolve_framework_filter("spring").unwrap(),
+            FrameworkFilter::Framework("spring")
+        );
+        assert_eq!(
+            resolve_framework_filter(" ASP.NET ").unwrap(),
+            FrameworkFilter::Framework("aspnet")
+        );
+        assert_eq!(
+            resolve_framework_filter("Spring bean").unwrap(),
+            FrameworkFilter::Role("spring_bean")
+        );
+        assert_eq!(
+            resolve_framework_filter("react-component").unwrap(),
+            FrameworkFilter::Role("react_component")
+        );
+
+        let err = resolve_framework_filter("django").unwrap_err().to_string();
+        assert!(err.starts_with("Unknown framework 'django'"), "{err}");
+        assert!(err.contains("rails_controller_action"), "{err}");
+    }
+
+    #[test]
+    fn test_hits_without_a_matching_role_are_dropped() {
+        let hits = vec![
+            symbol_hit("service", "UserService"),
+            symbol_hit("list", "listUsers"),
+            symbol_hit("dto", "UserDto"),
+        ];
+
+        let mut beans = hits.clone();
+        let dropped =
+            retain_framework_hits(&mut beans, &roles(), FrameworkFilter::Role("spring_bean"));
+        assert_eq!(dropped, 2);
+        assert_eq!(beans[0].name, "UserService");
+
+        let mut spring = hits;
+        let dropped =
+            retain_framework_hits(&mut spring, &roles(), FrameworkFilter::Framework("spring"));
+        assert_eq!(dropped, 1);
+        let names: Vec<_> = spring.iter().map(|hit| hit.name.as_str()).collect();
+        assert_eq!(names, ["UserService", "listUsers"]);
+    }
+
+    #[test]
+    fn test_framework_parameter_defaults_to_unset() {
+        assert_eq!(FastSearchTool::default().framework, None);
+        let tool: FastSearchTool = serde_json::from_value(serde_json::json!({
+            "query": "User",
+            "framework": "spring_bean"
+        }))
+        .unwrap();
+        assert_eq!(tool.framework.as_deref(), Some("spring_bean"));
+    }
+}
@@ -3,7 +3,9 @@
 #[cfg(test)]
 mod tests {
     use crate::search::LineMatch;
-    use crate::search::formatting::format_content_locations_only;
+    use crate::search::formatting::{
+        format_budgeted_search_results, format_content_locations_only,
+    };
     use crate::search::trace::SearchHit;
     use crate::shared::OptimizedResponse;
 
@@ -53,4 +55,30 @@ mod tests {
         assert!(!output.contains("fn sample_file_hit"));
         assert!(!output.contains("tool: \"edit_file\""));
     }
+
+    #[test]
+    fn test_budgeted_search_results_collapse_by_file_with_omission_footer() {
+        let hits: Vec<SearchHit> = (0..20)
+            .map(|i| {
+                make_line_hit(
+                    &format!("src/module_{}.rs", i % 4),
+                    i * 10 + 1,
+                    "let value = compute_the_answer_with_a_rather_long_line(input, options);",
+                )
+            })
+            .collect();
+
+        let unbounded = format_budgeted_search_results("compute", &hits, 20, 100_000);
+        assert!(unbounded.contains("compute_the_answer_with_a_rather_long_line"));
+        assert!(!unbounded.contains("[budget]"));
+
+        let shaped = format_budgeted_search_results("compute", &hits, 20, 80);
+        assert!(
+            shaped.contains("src/module_0.rs (5 results)"),
+            "tight budget should collapse hits by file: {shaped}"
+        );
+        assert!(!shaped.contains("compute_the_answer_with_a_rather_long_line"));
+        assert!(shaped.contains("[budget] Omitted to fit max_tokens=80"));
+        assert!(shaped.contains("20 context lines"));
+    }
 }
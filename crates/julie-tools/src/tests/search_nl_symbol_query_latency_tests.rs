@@ -226,6 +226,7 @@ fn nl_three_token_definition_search_completes_within_latency_bound() -> Result<(
         kind: None,
         file_pattern: None,
         exclude_tests: false,
+        include_generated: false,
     };
 
     // Warm up: one ignored call to open any lazy internal Tantivy readers.
@@ -361,6 +362,7 @@ fn and_or_fallback_does_not_blow_up_latency() -> Result<()> {
         kind: None,
         file_pattern: None,
         exclude_tests: false,
+        include_generated: false,
     };
 
     // "alpha bravo" — each term exists in separate symbols, so AND returns zero,
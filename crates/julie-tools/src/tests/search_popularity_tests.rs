@@ -0,0 +1,93 @@
+//! Tests for breaking search score ties by symbol popularity and for the
+//! usage badges on search results.
+
+#[cfg(test)]
+mod tests {
+    use julie_test_support::db::symbol_builder;
+
+    use crate::search::execution::{
+        ReferenceCounts, attach_usage_counts, sort_hits_by_score_then_popularity,
+    };
+    use crate::search::formatting::format_hit_location;
+    use crate::search::trace::SearchHit;
+
+    fn hit(id: &str, workspace: &str, score: f32) -> SearchHit {
+        let mut hit = SearchHit::from_symbol(
+            symbol_builder(id, "parse", "src/lib.rs").build(),
+            workspace.to_string(),
+        );
+        hit.score = score;
+        hit
+    }
+
+    fn counts(rows: &[(&str, &str, i64)]) -> ReferenceCounts {
+        rows.iter()
+            .map(|(workspace, id, count)| ((workspace.to_string(), id.to_string()), *count))
+            .collect()
+    }
+
+    fn order(hits: &[SearchHit]) -> Vec<&str> {
+        hits.iter()
+            .filter_map(|hit| hit.symbol_id.as_deref())
+            .collect()
+    }
+
+    #[test]
+    fn tied_scores_rank_the_more_referenced_symbol_first() {
+        let mut hits = vec![hit("rare", "primary", 2.0), hit("popular", "primary", 2.0)];
+        let counts = counts(&[("primary", "rare", 1), ("primary", "popular", 40)]);
+
+        sort_hits_by_score_then_popularity(&mut hits, &counts);
+
+        assert_eq!(order(&hits), ["popular", "rare"]);
+    }
+
+    #[test]
+    fn popularity_never_outranks_relevance() {
+        let mut hits = vec![
+            hit("popular", "primary", 1.0),
+            hit("relevant", "primary", 3.0),
+        ];
+        let counts = counts(&[("primary", "popular", 500)]);
+
+        sort_hits_by_score_then_popularity(&mut hits, &counts);
+
+        assert_eq!(order(&hits), ["relevant", "popular"]);
+    }
+
+    #[test]
+    fn counts_are_looked_up_per_workspace() {
+        let mut hits = vec![hit("same", "primary", 1.0), hit("same", "reference", 1.0)];
+        let counts = counts(&[("reference", "same", 3)]);
+
+        sort_hits_by_score_then_popularity(&mut hits, &counts);
+
+        let workspaces: Vec<&str> = hits.iter().map(|hit| hit.workspace.as_str()).collect();
+        assert_eq!(workspaces, ["reference", "primary"]);
+    }
+
+    #[test]
+    fn symbol_hits_carry_a_usage_badge() {
+        let mut hits = vec![
+            hit("popular", "primary", 1.0),
+            hit("single", "primary", 1.0),
+            hit("unused", "primary", 1.0),
+            hit("unknown", "primary", 1.0),
+        ];
+        let counts = counts(&[
+            ("primary", "popular", 40),
+            ("primary", "single", 1),
+            ("primary", "unused", 0),
+        ]);
+
+        attach_usage_counts(&mut hits, &counts);
+
+        let usages: Vec<Option<i64>> = hits.iter().map(|hit| hit.usages).collect();
+        assert_eq!(usages, [Some(40), Some(1), Some(0), None]);
+        let lines: Vec<String> = hits.iter().map(format_hit_location).collect();
+        assert!(lines[0].ends_with(" [40 refs]"), "{}", lines[0]);
+        assert!(lines[1].ends_with(" [1 ref]"), "{}", lines[1]);
+        assert!(!lines[2].contains("ref"), "{}", lines[2]);
+        assert!(!lines[3].contains("ref"), "{}", lines[3]);
+    }
+}
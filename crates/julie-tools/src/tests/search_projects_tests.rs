@@ -0,0 +1,123 @@
+//! Tests for monorepo project labels and the `project` filter.
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use julie_core::database::{ProjectFile, SymbolDatabase};
+    use julie_extractors::SymbolKind;
+    use julie_test_support::db::symbol_builder;
+    use tempfile::TempDir;
+
+    use crate::projects::{label_and_retain_hits, resolve_project, retain_project_symbols};
+    use crate::search::FastSearchTool;
+    use crate::search::formatting::{format_budgeted_search_results, format_unified_locations};
+    use crate::search::trace::SearchHit;
+
+    fn symbol_hit(id: &str, file_path: &str) -> SearchHit {
+        let symbol = symbol_builder(id, "UserService", file_path)
+            .kind(SymbolKind::Struct)
+            .span(3, 0, 20, 1)
+            .signature("pub struct UserService")
+            .build();
+        SearchHit::from_symbol(symbol, "primary".to_string())
+    }
+
+    fn project_map() -> HashMap<String, String> {
+        [
+            ("crates/auth/src/lib.rs", "auth"),
+            ("crates/billing/src/lib.rs", "billing"),
+        ]
+        .into_iter()
+        .map(|(file, project)| (file.to_string(), project.to_string()))
+        .collect()
+    }
+
+    fn project_db(temp_dir: &TempDir) -> SymbolDatabase {
+        let db = SymbolDatabase::new(temp_dir.path().join("projects.db")).unwrap();
+        let rows: Vec<ProjectFile> = project_map()
+            .into_iter()
+            .map(|(file_path, project)| ProjectFile {
+                manifest_path: format!("crates/{project}/Cargo.toml"),
+                file_path,
+                project,
+            })
+            .collect();
+        db.replace_all_project_files(&rows).unwrap();
+        db
+    }
+
+    #[test]
+    fn test_hits_are_labeled_and_filtered_by_project() {
+        let hits = vec![
+            symbol_hit("auth", "crates/auth/src/lib.rs"),
+            symbol_hit("billing", "crates/billing/src/lib.rs"),
+            symbol_hit("script", "scripts/user_service.rs"),
+        ];
+
+        let mut labeled = hits.clone();
+        assert_eq!(label_and_retain_hits(&mut labeled, &project_map(), None), 0);
+        let projects: Vec<_> = labeled.iter().map(|hit| hit.project.as_deref()).collect();
+        assert_eq!(projects, [Some("auth"), Some("billing"), None]);
+
+        let output = format_unified_locations("UserService", &labeled, labeled.len());
+        assert!(
+            output.contains("crates/billing/src/lib.rs:3 (struct) [project: billing]"),
+            "{output}"
+        );
+        assert!(output.contains("scripts/user_service.rs:3 (struct)\n"));
+
+        let mut filtered = hits;
+        let dropped = label_and_retain_hits(&mut filtered, &project_map(), Some("billing"));
+        assert_eq!(dropped, 2);
+        assert_eq!(filtered.len(), 1);
+        let output = format_budgeted_search_results("UserService", &filtered, 1, 500);
+        assert!(output.contains("[project: billing]"), "{output}");
+    }
+
+    #[test]
+    fn test_project_names_resolve_case_insensitively() {
+        let temp_dir = TempDir::new().unwrap();
+        let db = project_db(&temp_dir);
+
+        assert_eq!(resolve_project(&db, " Billing ").unwrap(), "billing");
+        let err = resolve_project(&db, "payments").unwrap_err().to_string();
+        assert_eq!(
+            err,
+            "Unknown project 'payments'. Known projects: auth, billing"
+        );
+
+        let empty_dir = TempDir::new().unwrap();
+        let empty = SymbolDatabase::new(empty_dir.path().join("empty.db")).unwrap();
+        let err = resolve_project(&empty, "auth").unwrap_err().to_string();
+        assert!(err.contains("no projects detected"), "{err}");
+    }
+
+    #[test]
+    fn test_symbols_outside_the_project_are_dropped() {
+        let temp_dir = TempDir::new().unwrap();
+        let db = project_db(&temp_dir);
+        let mut symbols = vec![
+            symbol_builder("auth", "UserService", "crates/auth/src/lib.rs").build(),
+            symbol_builder("billing", "UserService", "crates/billing/src/lib.rs").build(),
+            symbol_builder("script", "UserService", "scripts/user_service.rs").build(),
+        ];
+
+        let removed = retain_project_symbols(&db, &mut symbols, "auth").unwrap();
+
+        assert_eq!(removed, 2);
+        let ids: Vec<_> = symbols.iter().map(|s| s.id.as_str()).collect();
+        assert_eq!(ids, ["auth"]);
+    }
+
+    #[test]
+    fn test_project_parameter_defaults_to_unset() {
+        assert_eq!(FastSearchTool::default().project, None);
+        let tool: FastSearchTool = serde_json::from_value(serde_json::json!({
+            "query": "UserService",
+            "project": "billing"
+        }))
+        .unwrap();
+        assert_eq!(tool.project.as_deref(), Some("billing"));
+    }
+}
@@ -0,0 +1,147 @@
+//! Tests for `fast_search` regex mode over indexed file contents.
+
+#[cfg(test)]
+mod tests {
+    use julie_core::database::SymbolDatabase;
+    use julie_extractors::SymbolKind;
+    use julie_test_support::db::symbol_builder;
+    use tempfile::TempDir;
+
+    use crate::search::regex_mode::{
+        MAX_PATTERN_LEN, RegexScanBudget, RegexScanFilters, RegexScanStop, compile_pattern,
+        scan_indexed_files,
+    };
+
+    const SERVICE: &str = "struct Service;\n\nimpl Service {\n    fn load(&self) {\n        let data = read().unwrap();\n    }\n}\n\nfn main() { run().unwrap(); }\n";
+
+    fn seeded_db(temp_dir: &TempDir) -> SymbolDatabase {
+        let mut db = SymbolDatabase::new(temp_dir.path().join("regex.db")).unwrap();
+        for (path, language, content) in [
+            ("src/service.rs", "rust", SERVICE),
+            ("tests/service_test.rs", "rust", "fn t() { x.unwrap(); }\n"),
+            ("web/app.ts", "typescript", "const v = maybe.unwrap();\n"),
+        ] {
+            db.store_file_with_content(path, language, path, 10, 1, content, "ws")
+                .unwrap();
+        }
+        let symbols = [
+            ("impl", "Service", SymbolKind::Class, (3, 7)),
+            ("load", "load", SymbolKind::Method, (4, 6)),
+            ("data", "data", SymbolKind::Variable, (5, 5)),
+            ("main", "main", SymbolKind::Function, (9, 9)),
+        ]
+        .into_iter()
+        .map(|(id, name, kind, (start, end))| {
+            symbol_builder(id, name, "src/service.rs")
+                .kind(kind)
+                .span(start, 0, end, 1)
+                .build()
+        })
+        .collect::<Vec<_>>();
+        db.store_symbols(&symbols).unwrap();
+        db
+    }
+
+    #[test]
+    fn test_compile_pattern_rejects_costly_or_degenerate_patterns() {
+        for (pattern, expected) in [
+            ("  ", "non-empty"),
+            ("(", "invalid regex"),
+            ("a*|b", "empty string"),
+            (r"\w{1000}{1000}", "size limit"),
+        ] {
+            let err = compile_pattern(pattern).unwrap_err().to_string();
+            assert!(err.contains(expected), "{pattern}: {err}");
+        }
+        let long = "a".repeat(MAX_PATTERN_LEN + 1);
+        assert!(
+            compile_pattern(&long)
+                .unwrap_err()
+                .to_string()
+                .contains("limit")
+        );
+        assert!(compile_pattern(r"^fn \w+").is_ok());
+    }
+
+    #[test]
+    fn test_scan_labels_lines_with_innermost_enclosing_symbol() {
+        let temp_dir = TempDir::new().unwrap();
+        let db = seeded_db(&temp_dir);
+        let regex = compile_pattern(r"\.unwrap\(\)").unwrap();
+
+        let scan = scan_indexed_files(
+            &db,
+            &regex,
+            &RegexScanFilters {
+                language: Some("rust".to_string()),
+                exclude_tests: true,
+                ..Default::default()
+            },
+            10,
+            RegexScanBudget::default(),
+        )
+        .unwrap();
+
+        let hits: Vec<(&str, usize, Option<&str>)> = scan
+            .matches
+            .iter()
+            .map(|m| {
+                (
+                    m.line_match.file_path.as_str(),
+                    m.line_match.line_number,
+                    m.enclosing.as_ref().map(|symbol| symbol.id.as_str()),
+                )
+            })
+            .collect();
+        assert_eq!(
+            hits,
+            [
+                ("src/service.rs", 5, Some("load")),
+                ("src/service.rs", 9, Some("main")),
+            ],
+            "variables are not containers; tests and other languages are filtered"
+        );
+        assert_eq!(
+            scan.matches[0].line_match.line_content,
+            "        let data = read().unwrap();"
+        );
+        assert_eq!(scan.files_scanned, 1);
+        assert_eq!(scan.stopped, None);
+    }
+
+    #[test]
+    fn test_scan_stops_at_limit_and_byte_budget() {
+        let temp_dir = TempDir::new().unwrap();
+        let db = seeded_db(&temp_dir);
+        let regex = compile_pattern("unwrap").unwrap();
+
+        let limited = scan_indexed_files(
+            &db,
+            &regex,
+            &RegexScanFilters::default(),
+            1,
+            RegexScanBudget::default(),
+        )
+        .unwrap();
+        assert_eq!(limited.matches.len(), 1);
+        assert_eq!(limited.stopped, Some(RegexScanStop::Limit));
+
+        let budgeted = scan_indexed_files(
+            &db,
+            &regex,
+            &RegexScanFilters {
+                file_pattern: Some("src/**".to_string()),
+                ..Default::default()
+            },
+            10,
+            RegexScanBudget {
+                max_bytes: SERVICE.len() - 1,
+                ..Default::default()
+            },
+        )
+        .unwrap();
+        assert!(budgeted.matches.is_empty());
+        assert_eq!(budgeted.files_scanned, 0);
+        assert_eq!(budgeted.stopped, Some(RegexScanStop::ByteBudget));
+    }
+}
@@ -0,0 +1,100 @@
+//! `suggest_corrections` builds the "did you mean" list attached to zero-hit
+//! searches. These tests pin both suggestion kinds (respelled query and
+//! close symbol names) and the ordering rules between them.
+
+#[cfg(test)]
+mod tests {
+    use crate::search::spelling::{
+        MAX_SUGGESTIONS, format_suggestions, near_miss_names, suggest_corrections,
+    };
+
+    fn dictionary(names: &[&str]) -> Vec<String> {
+        names.iter().map(|name| name.to_string()).collect()
+    }
+
+    /// Unknown query tokens are replaced by the closest identifier token, and
+    /// the separator-free query also matches whole symbol names.
+    #[test]
+    fn typo_query_is_respelled_and_matched_to_symbol_names() {
+        let names = dictionary(&["UserService", "user_service", "OrderRepository"]);
+        let suggestions = suggest_corrections("usr servic", &names);
+        assert_eq!(
+            suggestions,
+            vec!["user service", "UserService", "user_service"],
+            "respelled query first, then close names in dictionary order"
+        );
+    }
+
+    /// A camelCase typo splits into tokens before respelling.
+    #[test]
+    fn camel_case_query_tokens_are_respelled_individually() {
+        let names = dictionary(&["getUserById", "parseConfig"]);
+        let suggestions = suggest_corrections("getUsrById", &names);
+        assert_eq!(
+            suggestions.first().map(String::as_str),
+            Some("get user by id")
+        );
+        assert!(suggestions.contains(&"getUserById".to_string()));
+    }
+
+    /// Among equally close names the dictionary's relevance order wins.
+    #[test]
+    fn equally_close_names_keep_dictionary_order() {
+        let names = dictionary(&["parse_config", "parse_confid", "ParseConfig"]);
+        let suggestions = suggest_corrections("parsconfig", &names);
+        assert!(
+            suggestions.iter().position(|s| s == "parse_config")
+                < suggestions.iter().position(|s| s == "parse_confid"),
+            "closer name must rank first: {suggestions:?}"
+        );
+    }
+
+    /// Short tokens tolerate no edits, so two-letter queries stay quiet, and
+    /// the list never exceeds its cap.
+    #[test]
+    fn short_queries_get_no_suggestions_and_lists_are_capped() {
+        assert!(suggest_corrections("ab", &dictionary(&["ac", "ad"])).is_empty());
+
+        let many = dictionary(&[
+            "handler1", "handler2", "handler3", "handler4", "handler5", "handler6", "handler7",
+        ]);
+        assert!(suggest_corrections("handlerx", &many).len() <= MAX_SUGGESTIONS);
+    }
+
+    /// The exact query is never suggested back to the caller.
+    #[test]
+    fn exact_query_is_not_suggested() {
+        let names = dictionary(&["AccountManager", "AccountManagers"]);
+        let suggestions = suggest_corrections("AccountManager", &names);
+        assert_eq!(suggestions, vec!["AccountManagers"]);
+    }
+
+    #[test]
+    fn format_suggestions_renders_a_did_you_mean_line() {
+        assert_eq!(format_suggestions(&[]), "");
+        assert_eq!(
+            format_suggestions(&["user service".to_string(), "UserService".to_string()]),
+            "\nDid you mean: user service, UserService?"
+        );
+    }
+
+    /// The fuzzy fallback ranks near misses by edit distance, closest first,
+    /// and keeps dictionary order between equal distances.
+    #[test]
+    fn near_miss_names_rank_by_edit_distance() {
+        let names = dictionary(&[
+            "getUserAccounts",
+            "getUserAccount",
+            "get_user_account",
+            "getOrderAccount",
+        ]);
+        assert_eq!(
+            near_miss_names("getUserAccont", &names),
+            vec![
+                (1, "getUserAccount"),
+                (1, "get_user_account"),
+                (2, "getUserAccounts"),
+            ]
+        );
+    }
+}
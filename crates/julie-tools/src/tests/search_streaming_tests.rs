@@ -0,0 +1,92 @@
+//! Tests for streaming result batches to a `ResultSink`.
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+
+    use async_trait::async_trait;
+    use julie_extractors::SymbolKind;
+    use julie_test_support::db::symbol_builder;
+
+    use crate::search::formatting::format_hit_location;
+    use crate::search::trace::SearchHit;
+    use crate::streaming::{ResultSink, STREAM_BATCH_SIZE, stream_lines};
+
+    #[derive(Default)]
+    struct CollectingSink {
+        batches: Mutex<Vec<(String, usize, Option<usize>)>>,
+    }
+
+    #[async_trait]
+    impl ResultSink for CollectingSink {
+        async fn send_batch(&self, text: String, sent: usize, total: Option<usize>) {
+            self.batches.lock().unwrap().push((text, sent, total));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_lines_are_sent_in_counted_batches() {
+        let sink = CollectingSink::default();
+        let lines: Vec<String> = (1..=STREAM_BATCH_SIZE + 2)
+            .map(|line| format!("src/lib.rs:{line} (function)"))
+            .collect();
+
+        let sent = stream_lines(&sink, "fast_search \"parse\": results", &lines, 0, Some(12)).await;
+
+        assert_eq!(sent, 12);
+        let batches = sink.batches.lock().unwrap();
+        let counts: Vec<_> = batches
+            .iter()
+            .map(|(_, sent, total)| (*sent, *total))
+            .collect();
+        assert_eq!(counts, [(10, Some(12)), (12, Some(12))]);
+        assert!(
+            batches[0].0.starts_with(
+                "fast_search \"parse\": results 1-10 of 12:\n  src/lib.rs:1 (function)"
+            )
+        );
+        assert_eq!(
+            batches[1].0,
+            "fast_search \"parse\": results 11-12 of 12:\n  src/lib.rs:11 (function)\n  src/lib.rs:12 (function)"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_nothing_is_sent_without_results() {
+        let sink = CollectingSink::default();
+
+        let sent = stream_lines(&sink, "fast_refs \"parse\": references", &[], 3, None).await;
+
+        assert_eq!(sent, 3);
+        assert!(sink.batches.lock().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_hit_location_lines() {
+        let symbol = symbol_builder("parse", "parse", "src/lib.rs")
+            .kind(SymbolKind::Function)
+            .span(7, 0, 9, 1)
+            .build();
+        let mut hit = SearchHit::from_symbol(symbol, "primary".to_string());
+        assert_eq!(format_hit_location(&hit), "src/lib.rs:7 (function)");
+        hit.project = Some("core".to_string());
+        assert_eq!(
+            format_hit_location(&hit),
+            "src/lib.rs:7 (function) [project: core]"
+        );
+    }
+
+    #[test]
+    fn test_hit_location_labels_generated_code() {
+        let symbol = symbol_builder("msg", "UserRequest", "api/user_pb2.py")
+            .kind(SymbolKind::Class)
+            .span(12, 0, 30, 1)
+            .build();
+        let hit = SearchHit::from_symbol(symbol, "primary".to_string());
+        assert!(hit.generated);
+        assert_eq!(
+            format_hit_location(&hit),
+            "api/user_pb2.py:12 (class) [generated]"
+        );
+    }
+}
@@ -0,0 +1,237 @@
+//! Tests for `fast_search` todos mode over indexed task comment symbols.
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use std::time::{Duration, UNIX_EPOCH};
+
+    use julie_core::database::SymbolDatabase;
+    use julie_extractors::SymbolKind;
+    use julie_extractors::base::Symbol;
+    use julie_pipeline::indexing_core::task_comments::{TASK_METADATA_KEY, TASK_SEMANTIC_GROUP};
+    use julie_test_support::db::symbol_builder;
+    use serde_json::json;
+    use tempfile::TempDir;
+
+    use crate::search::regex_mode::RegexScanFilters;
+    use crate::search::todo_mode::{blame_tasks, collect_tasks, format_task_listing};
+
+    fn task(
+        id: &str,
+        file: &str,
+        line: u32,
+        marker: &str,
+        text: &str,
+        assignee: Option<&str>,
+        parent: Option<&str>,
+    ) -> Symbol {
+        let mut builder = symbol_builder(id, marker, file)
+            .kind(SymbolKind::Variable)
+            .span(line, 3, line, 40)
+            .metadata(HashMap::from([(
+                TASK_METADATA_KEY.to_string(),
+                json!({ "marker": marker, "text": text, "assignee": assignee }),
+            )]));
+        if let Some(parent) = parent {
+            builder = builder.parent_id(parent);
+        }
+        let mut symbol = builder.build();
+        symbol.semantic_group = Some(TASK_SEMANTIC_GROUP.to_string());
+        symbol
+    }
+
+    fn seeded_db(temp_dir: &TempDir) -> SymbolDatabase {
+        let mut db = SymbolDatabase::new(temp_dir.path().join("todos.db")).unwrap();
+        for (path, language) in [
+            ("src/billing.rs", "rust"),
+            ("src/api.rs", "rust"),
+            ("tests/billing_test.rs", "rust"),
+            ("web/app.ts", "typescript"),
+        ] {
+            db.store_file_with_content(path, language, path, 10, 1, "", "ws")
+                .unwrap();
+        }
+        let charge = symbol_builder("charge", "charge", "src/billing.rs")
+            .kind(SymbolKind::Function)
+            .span(2, 0, 9, 1)
+            .build();
+        let mut app_task = task("t5", "web/app.ts", 1, "TODO", "lazy load", None, None);
+        app_task.language = "typescript".to_string();
+        db.store_symbols(&[
+            charge,
+            task(
+                "t1",
+                "src/billing.rs",
+                4,
+                "FIXME",
+                "retry on 503",
+                Some("ana"),
+                Some("charge"),
+            ),
+            task(
+                "t2",
+                "src/billing.rs",
+                1,
+                "TODO",
+                "split module",
+                None,
+                None,
+            ),
+            task(
+                "t3",
+                "src/api.rs",
+                7,
+                "HACK",
+                "skip auth in dev",
+                None,
+                None,
+            ),
+            task(
+                "t4",
+                "tests/billing_test.rs",
+                2,
+                "TODO",
+                "flaky",
+                None,
+                None,
+            ),
+            app_task,
+        ])
+        .unwrap();
+        db
+    }
+
+    #[test]
+    fn test_collect_tasks_filters_and_orders_by_file_and_line() {
+        let temp_dir = TempDir::new().unwrap();
+        let db = seeded_db(&temp_dir);
+
+        let all = collect_tasks(
+            &db,
+            &RegexScanFilters {
+                language: Some("rust".to_string()),
+                exclude_tests: true,
+                ..Default::default()
+            },
+            "*",
+        )
+        .unwrap();
+        let listed: Vec<(&str, u32, Option<&str>)> = all
+            .iter()
+            .map(|task| {
+                (
+                    task.symbol.file_path.as_str(),
+                    task.symbol.start_line,
+                    task.enclosing.as_deref(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            listed,
+            [
+                ("src/api.rs", 7, None),
+                ("src/billing.rs", 1, None),
+                ("src/billing.rs", 4, Some("function charge")),
+            ]
+        );
+        assert_eq!(all[2].assignee.as_deref(), Some("ana"));
+
+        let matching = |query: &str| {
+            collect_tasks(&db, &RegexScanFilters::default(), query)
+                .unwrap()
+                .into_iter()
+                .map(|task| task.symbol.id)
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(matching("fixme"), ["t1"]);
+        assert_eq!(matching("ANA"), ["t1"]);
+        assert_eq!(matching("billing"), ["t2", "t1", "t4"]);
+        assert!(matching("nothing like this").is_empty());
+    }
+
+    #[test]
+    fn test_listing_groups_by_file_with_owner_and_age_counts() {
+        let temp_dir = TempDir::new().unwrap();
+        let db = seeded_db(&temp_dir);
+        let mut tasks = collect_tasks(
+            &db,
+            &RegexScanFilters {
+                file_pattern: Some("src/**".to_string()),
+                ..Default::default()
+            },
+            "*",
+        )
+        .unwrap();
+        tasks[0].owner = Some("bob".to_string());
+        tasks[0].age_secs = Some(400 * 24 * 60 * 60);
+        tasks[1].owner = Some("bob".to_string());
+        tasks[1].age_secs = Some(3 * 24 * 60 * 60);
+
+        assert_eq!(
+            format_task_listing(&tasks, 4),
+            "Showing 3 of 4 tasks (FIXME 1, HACK 1, TODO 1)\n\
+             By owner: bob 2, ana 1\n\
+             By age: under 1 month 1, over 1 year 1, unknown age 1\n\
+             \n\
+             src/api.rs\n  \
+             7: HACK [bob, 13 months] skip auth in dev\n\
+             \n\
+             src/billing.rs\n  \
+             1: TODO [bob, 3 days] split module\n  \
+             4: FIXME (in function charge) [ana] retry on 503"
+        );
+    }
+
+    #[test]
+    fn test_blame_tasks_reads_owner_and_age_from_git() {
+        use std::process::Command;
+
+        let temp_dir = TempDir::new().unwrap();
+        let db = seeded_db(&temp_dir);
+        let root = temp_dir.path().join("repo");
+        std::fs::create_dir_all(root.join("src")).unwrap();
+        let git = |args: &[&str]| {
+            let status = Command::new("git")
+                .arg("-C")
+                .arg(&root)
+                .args([
+                    "-c",
+                    "user.name=Grace",
+                    "-c",
+                    "user.email=grace@example.com",
+                ])
+                .args(args)
+                .env("GIT_AUTHOR_DATE", "1700000000 +0000")
+                .status()
+                .expect("git must be installed to run this test");
+            assert!(status.success(), "git {args:?} failed");
+        };
+        git(&["init", "-q"]);
+        std::fs::write(
+            root.join("src/api.rs"),
+            "\n\n\n\n\n\n// HACK: skip auth in dev\n",
+        )
+        .unwrap();
+        git(&["add", "."]);
+        git(&["commit", "-q", "-m", "initial"]);
+
+        let mut tasks = collect_tasks(
+            &db,
+            &RegexScanFilters {
+                file_pattern: Some("src/**".to_string()),
+                ..Default::default()
+            },
+            "*",
+        )
+        .unwrap();
+        let now = UNIX_EPOCH + Duration::from_secs(1_700_000_000 + 10 * 24 * 60 * 60);
+        blame_tasks(&root, &mut tasks, now);
+
+        assert_eq!(tasks[0].owner.as_deref(), Some("Grace"));
+        assert_eq!(tasks[0].age_secs, Some(10 * 24 * 60 * 60));
+        assert_eq!(
+            tasks[1].owner, None,
+            "src/billing.rs is not in the repository"
+        );
+    }
+}
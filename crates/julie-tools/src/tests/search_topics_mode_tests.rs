@@ -0,0 +1,123 @@
+//! Tests for `fast_search` topics mode: candidate filtering, topic matching
+//! and the rendered map.
+
+#[cfg(test)]
+mod tests {
+    use julie_core::database::{SymbolDatabase, TopicCandidate};
+    use julie_index::analysis::{Topic, TopicMapReport, TopicSymbol};
+    use tempfile::TempDir;
+
+    use crate::search::regex_mode::RegexScanFilters;
+    use crate::search::topics_mode::{filter_topic_candidates, format_topic_map, topic_matches};
+
+    fn candidate(id: &str, file_path: &str, language: &str) -> TopicCandidate {
+        TopicCandidate {
+            id: id.to_string(),
+            name: id.to_string(),
+            kind: "function".to_string(),
+            language: language.to_string(),
+            file_path: file_path.to_string(),
+            start_line: 1,
+        }
+    }
+
+    fn topic(label: &str, size: usize, directory: &str, symbols: &[(&str, u32)]) -> Topic {
+        Topic {
+            label: label.to_string(),
+            terms: label.split(" / ").map(str::to_string).collect(),
+            size,
+            directories: vec![(directory.to_string(), size)],
+            symbols: symbols
+                .iter()
+                .map(|&(name, line)| TopicSymbol {
+                    id: name.to_string(),
+                    name: name.to_string(),
+                    kind: "function".to_string(),
+                    file_path: format!("{directory}/mod.rs"),
+                    line,
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn test_candidates_are_filtered_by_language_and_file_pattern() {
+        let temp_dir = TempDir::new().unwrap();
+        let db = SymbolDatabase::new(temp_dir.path().join("topics.db")).unwrap();
+        let candidates = vec![
+            candidate("login", "src/auth/login.rs", "rust"),
+            candidate("invoice", "src/billing/invoice.rs", "rust"),
+            candidate("checkout", "web/checkout.ts", "typescript"),
+        ];
+        let ids = |filters: RegexScanFilters| {
+            filter_topic_candidates(&db, candidates.clone(), &filters, None)
+                .unwrap()
+                .into_iter()
+                .map(|candidate| candidate.id)
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            ids(RegexScanFilters::default()),
+            ["login", "invoice", "checkout"]
+        );
+        assert_eq!(
+            ids(RegexScanFilters {
+                language: Some("rust".to_string()),
+                ..Default::default()
+            }),
+            ["login", "invoice"]
+        );
+        assert_eq!(
+            ids(RegexScanFilters {
+                file_pattern: Some("src/billing/**".to_string()),
+                ..Default::default()
+            }),
+            ["invoice"]
+        );
+    }
+
+    #[test]
+    fn test_query_matches_label_terms_and_directories() {
+        let auth = topic("session / token", 4, "src/auth", &[]);
+        assert!(topic_matches(&auth, "*"));
+        assert!(topic_matches(&auth, ""));
+        assert!(topic_matches(&auth, "Token"));
+        assert!(topic_matches(&auth, "src/auth"));
+        assert!(!topic_matches(&auth, "billing"));
+    }
+
+    #[test]
+    fn test_map_lists_topics_with_directories_and_symbols() {
+        let topics = vec![
+            topic(
+                "invoice / payment",
+                12,
+                "src/billing",
+                &[("createInvoice", 3), ("refundPayment", 40)],
+            ),
+            topic("session / token", 9, "src/auth", &[("validateSession", 7)]),
+        ];
+        let report = TopicMapReport {
+            focus_path: None,
+            candidates: 30,
+            clustered: 25,
+            unassigned: 4,
+            topics: topics.clone(),
+        };
+
+        assert_eq!(
+            format_topic_map(&report, &topics[..1], 2),
+            "Showing 1 of 2 topics over 25 embedded symbols (an even sample of 30); \
+             4 in groups too small to be a topic\n\
+             \n\
+             1. invoice / payment (12 symbols)\n   \
+             in src/billing (12)\n   \
+             function createInvoice  src/billing/mod.rs:3\n   \
+             function refundPayment  src/billing/mod.rs:40"
+        );
+        assert!(
+            format_topic_map(&report, &topics, 2).starts_with("2 topics over 25 embedded symbols")
+        );
+    }
+}
@@ -0,0 +1,160 @@
+//! Tests for `fast_signature` lookups and rendering.
+
+#[cfg(test)]
+mod signature_tests {
+    use julie_core::database::SymbolDatabase;
+    use julie_extractors::base::TypeInfo;
+    use julie_extractors::{SymbolKind, Visibility};
+    use julie_test_support::db::{file_info_builder, symbol_builder};
+    use tempfile::TempDir;
+
+    use crate::signature::{FastSignatureTool, SignatureLookup};
+    use julie_pipeline::indexing_core::signatures::SIGNATURE_METADATA_KEY;
+
+    fn tool(symbol_id: &str) -> FastSignatureTool {
+        FastSignatureTool {
+            symbol_id: symbol_id.to_string(),
+            workspace: None,
+        }
+    }
+
+    fn seeded_db() -> (TempDir, SymbolDatabase) {
+        let temp_dir = TempDir::new().unwrap();
+        let mut db = SymbolDatabase::new(temp_dir.path().join("test.db")).unwrap();
+        for path in ["src/api/users.ts", "src/api/orders.ts"] {
+            db.store_file_info(&file_info_builder(path).language("typescript").build())
+                .unwrap();
+        }
+        db.store_symbols(&[
+            symbol_builder("users_api", "UsersApi", "src/api/users.ts")
+                .kind(SymbolKind::Class)
+                .language("typescript")
+                .span(3, 0, 40, 1)
+                .build(),
+            symbol_builder("fetch_user", "fetchUser", "src/api/users.ts")
+                .kind(SymbolKind::Method)
+                .language("typescript")
+                .span(12, 2, 30, 3)
+                .visibility(Visibility::Public)
+                .parent_id("users_api")
+                .signature("async fetchUser<T extends User>(\n  id: string,\n): Promise<T>")
+                .doc_comment("Load one user.\nThrows when the id is unknown.")
+                .build(),
+            symbol_builder("fetch_orders", "fetch", "src/api/orders.ts")
+                .kind(SymbolKind::Function)
+                .language("typescript")
+                .span(5, 0, 9, 1)
+                .build(),
+            symbol_builder("fetch_users", "fetch", "src/api/users.ts")
+                .kind(SymbolKind::Function)
+                .language("typescript")
+                .span(42, 0, 48, 1)
+                .build(),
+        ])
+        .unwrap();
+        db.bulk_store_types(
+            &[TypeInfo {
+                symbol_id: "fetch_user".to_string(),
+                resolved_type: "Promise<T>".to_string(),
+                generic_params: Some(vec!["T".to_string()]),
+                constraints: Some(vec!["T extends User".to_string()]),
+                is_inferred: false,
+                language: "typescript".to_string(),
+                metadata: None,
+            }],
+            "ws",
+        )
+        .unwrap();
+        (temp_dir, db)
+    }
+
+    #[test]
+    fn renders_signature_type_parent_and_doc_by_id() {
+        let (_temp_dir, db) = seeded_db();
+        let tool = tool("fetch_user");
+        let lookup = tool.execute(&db).unwrap();
+        assert!(matches!(lookup, SignatureLookup::Found(_)));
+
+        assert_eq!(
+            tool.render(&lookup),
+            "method fetchUser (typescript, public) src/api/users.ts:12-30\n  \
+             in class UsersApi\n  \
+             async fetchUser<T extends User>(\n    \
+             id: string,\n  \
+             ): Promise<T>\n  \
+             returns Promise<T> <T> where T extends User\n\n  \
+             Load one user.\n  \
+             Throws when the id is unknown."
+        );
+    }
+
+    #[test]
+    fn names_resolve_when_unique_and_list_ids_when_shared() {
+        let (_temp_dir, db) = seeded_db();
+
+        let by_name = tool("UsersApi");
+        let lookup = by_name.execute(&db).unwrap();
+        assert_eq!(
+            by_name.render(&lookup),
+            "class UsersApi (typescript) src/api/users.ts:3-40",
+            "no signature, type or doc lines when none were extracted"
+        );
+
+        let shared = tool("fetch");
+        let SignatureLookup::Ambiguous(symbols) = shared.execute(&db).unwrap() else {
+            panic!("two symbols are named fetch");
+        };
+        assert_eq!(symbols.len(), 2);
+        let rendered = shared.render(&SignatureLookup::Ambiguous(symbols));
+        assert!(rendered.starts_with("2 symbols are named 'fetch'. Pass one of these ids"));
+        assert!(
+            rendered.contains("  fetch_orders  function fetch (typescript) src/api/orders.ts:5")
+        );
+        assert!(
+            rendered.contains("  fetch_users  function fetch (typescript) src/api/users.ts:42")
+        );
+
+        let missing = tool("nope");
+        let lookup = missing.execute(&db).unwrap();
+        assert!(matches!(lookup, SignatureLookup::NotFound));
+        assert_eq!(missing.render(&lookup), "No symbol with id or name 'nope'");
+    }
+
+    #[test]
+    fn capped_signatures_are_shown_in_full_from_the_file() {
+        let temp_dir = TempDir::new().unwrap();
+        let mut db = SymbolDatabase::new(temp_dir.path().join("test.db")).unwrap();
+        let header = format!("function render(props={{{}}})", "a:1,".repeat(150));
+        let content = format!("{header}{{return props}}\n");
+        db.store_file_info(
+            &file_info_builder("dist/app.min.js")
+                .language("javascript")
+                .content(content.clone())
+                .build(),
+        )
+        .unwrap();
+        db.store_symbols(&[symbol_builder("render", "render", "dist/app.min.js")
+            .kind(SymbolKind::Function)
+            .language("javascript")
+            .span(1, 0, 1, content.len() as u32 - 1)
+            .bytes(0, content.len() as u32 - 1)
+            .signature("function render(props={a:1, …")
+            .metadata(
+                [(
+                    SIGNATURE_METADATA_KEY.to_string(),
+                    serde_json::json!({ "truncated": true, "chars": header.len() }),
+                )]
+                .into(),
+            )
+            .build()])
+            .unwrap();
+
+        let tool = tool("render");
+        let lookup = tool.execute(&db).unwrap();
+        assert_eq!(
+            tool.render(&lookup),
+            format!("function render (javascript) dist/app.min.js:1-1\n  {header}{{return props}}"),
+            "the stored signature was capped; the file has the whole declaration"
+        );
+    }
+}
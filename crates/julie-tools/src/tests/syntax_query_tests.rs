@@ -0,0 +1,147 @@
+//! Tests for the syntax_query tool.
+
+#[cfg(test)]
+mod syntax_query_tests {
+    use tempfile::TempDir;
+
+    use crate::syntax_query::SyntaxQueryTool;
+    use crate::syntax_query::query::{compile_query, run_query};
+    use julie_core::database::{FileInfo, SymbolDatabase};
+
+    const LIB_RS: &str = "\
+fn alpha() {
+    beta();
+    gamma(1);
+}
+
+fn beta() {}
+";
+
+    fn setup_db() -> (TempDir, SymbolDatabase) {
+        let temp_dir = TempDir::new().unwrap();
+        let db = SymbolDatabase::new(temp_dir.path().join("test.db")).unwrap();
+        for (path, language, content) in [
+            ("src/lib.rs", "rust", LIB_RS),
+            ("src/other.rs", "rust", "fn delta() { alpha(); }\n"),
+            ("web/app.py", "python", "def alpha():\n    beta()\n"),
+        ] {
+            db.store_file_info(&FileInfo {
+                path: path.to_string(),
+                language: language.to_string(),
+                hash: format!("hash_{path}"),
+                size: content.len() as i64,
+                last_modified: 0,
+                last_indexed: 0,
+                symbol_count: 0,
+                line_count: 0,
+                content: Some(content.to_string()),
+            })
+            .unwrap();
+        }
+        (temp_dir, db)
+    }
+
+    fn rust_calls_tool() -> SyntaxQueryTool {
+        SyntaxQueryTool {
+            query: "(call_expression function: (identifier) @callee)".to_string(),
+            file_pattern: Some("src/**/*.rs".to_string()),
+            ..SyntaxQueryTool::default()
+        }
+    }
+
+    #[test]
+    fn test_run_query_reports_one_based_ranges() {
+        let query = compile_query("rust", "(function_item name: (identifier) @name)").unwrap();
+        let captures = run_query(&query, "rust", "src/lib.rs", LIB_RS, 10).unwrap();
+
+        let names: Vec<&str> = captures.iter().map(|c| c.text.as_str()).collect();
+        assert_eq!(names, vec!["alpha", "beta"]);
+        let alpha = &captures[0];
+        assert_eq!(alpha.capture, "name");
+        assert_eq!(alpha.node_kind, "identifier");
+        assert_eq!((alpha.start_line, alpha.start_column), (1, 4));
+        assert_eq!((alpha.end_line, alpha.end_column), (1, 9));
+    }
+
+    #[test]
+    fn test_invalid_query_reports_position() {
+        let error = compile_query("rust", "(function_item name: (identifier) @name")
+            .unwrap_err()
+            .to_string();
+        assert!(error.starts_with("invalid rust query at row 1"), "{error}");
+    }
+
+    #[test]
+    fn test_execute_over_glob_respects_limit() {
+        let (_tmp, db) = setup_db();
+
+        let outcome = rust_calls_tool().execute(&db).unwrap();
+        let callees: Vec<(&str, &str)> = outcome
+            .captures
+            .iter()
+            .map(|c| (c.file_path.as_str(), c.text.as_str()))
+            .collect();
+        assert_eq!(
+            callees,
+            vec![
+                ("src/lib.rs", "beta"),
+                ("src/lib.rs", "gamma"),
+                ("src/other.rs", "alpha")
+            ]
+        );
+        assert_eq!(outcome.files_scanned, 2);
+        assert!(!outcome.truncated);
+
+        let limited = SyntaxQueryTool {
+            limit: 2,
+            ..rust_calls_tool()
+        }
+        .execute(&db)
+        .unwrap();
+        assert_eq!(limited.captures.len(), 2);
+        assert!(limited.truncated);
+
+        let one_file = SyntaxQueryTool {
+            max_files: 1,
+            ..rust_calls_tool()
+        }
+        .execute(&db)
+        .unwrap();
+        assert_eq!(one_file.files_scanned, 1);
+        assert_eq!(one_file.files_skipped, 1);
+    }
+
+    #[test]
+    fn test_execute_skips_languages_whose_grammar_rejects_the_query() {
+        let (_tmp, db) = setup_db();
+
+        let tool = SyntaxQueryTool {
+            file_pattern: Some("**/*".to_string()),
+            ..rust_calls_tool()
+        };
+        let outcome = tool.execute(&db).unwrap();
+        assert_eq!(outcome.files_scanned, 2);
+        assert!(outcome.language_errors.contains_key("python"));
+
+        let python_only = SyntaxQueryTool {
+            language: Some("python".to_string()),
+            ..tool
+        };
+        assert!(
+            python_only.execute(&db).is_err(),
+            "no grammar accepted the query"
+        );
+    }
+
+    #[test]
+    fn test_execute_rejects_unindexed_file() {
+        let (_tmp, db) = setup_db();
+        let tool = SyntaxQueryTool {
+            file_path: Some("src/missing.rs".to_string()),
+            file_pattern: None,
+            ..rust_calls_tool()
+        };
+        let error = tool.execute(&db).unwrap_err().to_string();
+        assert!(error.contains("File not indexed"), "{error}");
+    }
+}
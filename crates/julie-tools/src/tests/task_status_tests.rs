@@ -0,0 +1,121 @@
+//! Tests for the task_status tool.
+
+#[cfg(test)]
+mod task_status_tests {
+    use julie_core::background_tasks::{BackgroundTaskKind, BackgroundTaskRegistry};
+
+    use crate::task_status::{TaskStatusOperation, TaskStatusTool};
+
+    fn tool(operation: TaskStatusOperation, task_id: Option<u32>) -> TaskStatusTool {
+        TaskStatusTool {
+            operation,
+            task_id,
+            workspace_id: None,
+        }
+    }
+
+    #[test]
+    fn list_reports_progress_and_backlog() {
+        let registry = BackgroundTaskRegistry::new();
+        let embedding = registry.register(
+            BackgroundTaskKind::Embedding,
+            "ws_a",
+            "workspace embedding",
+            true,
+        );
+        embedding.set_total(400);
+        embedding.advance(100);
+        embedding.set_detail("embedding symbols");
+        let watcher = registry.register(
+            BackgroundTaskKind::Watcher,
+            "ws_a",
+            "file watcher queue",
+            true,
+        );
+        watcher.track_backlog(7);
+
+        let output = tool(TaskStatusOperation::List, None)
+            .execute(&registry)
+            .unwrap();
+
+        assert!(output.starts_with("2 background task(s):"), "{output}");
+        assert!(
+            output.contains(
+                "#1 embedding workspace embedding [ws_a]: embedding symbols; 100/400 (25%)"
+            ),
+            "{output}"
+        );
+        assert!(output.contains("ETA "), "{output}");
+        assert!(
+            output.contains("#2 watcher file watcher queue [ws_a]: backlog 7"),
+            "{output}"
+        );
+    }
+
+    #[test]
+    fn list_filters_by_workspace_and_reports_idle() {
+        let registry = BackgroundTaskRegistry::new();
+        let _indexing = registry.register(BackgroundTaskKind::Indexing, "ws_a", "indexing", false);
+
+        let mut filtered = tool(TaskStatusOperation::List, None);
+        filtered.workspace_id = Some("ws_b".to_string());
+        assert_eq!(
+            filtered.execute(&registry).unwrap(),
+            "No background tasks running."
+        );
+
+        filtered.workspace_id = Some("ws_a".to_string());
+        let output = filtered.execute(&registry).unwrap();
+        assert!(output.contains("not pausable"), "{output}");
+    }
+
+    #[test]
+    fn pause_and_resume_by_task_id() {
+        let registry = BackgroundTaskRegistry::new();
+        let task = registry.register(BackgroundTaskKind::Embedding, "ws", "embedding", true);
+        let task_id = u32::try_from(task.id()).unwrap();
+
+        let output = tool(TaskStatusOperation::Pause, Some(task_id))
+            .execute(&registry)
+            .unwrap();
+        assert!(output.starts_with("Paused task:"), "{output}");
+        assert!(output.contains("PAUSED"), "{output}");
+        assert!(task.is_paused());
+
+        tool(TaskStatusOperation::Resume, Some(task_id))
+            .execute(&registry)
+            .unwrap();
+        assert!(!task.is_paused());
+    }
+
+    #[test]
+    fn pause_errors_are_reported() {
+        let registry = BackgroundTaskRegistry::new();
+        let task = registry.register(BackgroundTaskKind::Indexing, "ws", "indexing", false);
+
+        let missing_id = tool(TaskStatusOperation::Pause, None).execute(&registry);
+        assert!(
+            missing_id
+                .unwrap_err()
+                .to_string()
+                .contains("task_id is required")
+        );
+
+        let unpausable =
+            tool(TaskStatusOperation::Pause, Some(task.id() as u32)).execute(&registry);
+        assert!(
+            unpausable
+                .unwrap_err()
+                .to_string()
+                .contains("cannot be paused")
+        );
+
+        let unknown = tool(TaskStatusOperation::Resume, Some(42)).execute(&registry);
+        assert!(
+            unknown
+                .unwrap_err()
+                .to_string()
+                .contains("no active background task")
+        );
+    }
+}
@@ -0,0 +1,25 @@
+//! Output limits from the `[tools]` tables of a workspace's
+//! `.julie/config.toml`, applied when a call sets none of its own.
+
+use julie_context::ToolContext;
+use julie_core::workspace_settings::WorkspaceSettingsRegistry;
+
+use crate::navigation::resolution::WorkspaceTarget;
+
+/// `tools.<tool>.max_tokens` of the target workspace, else
+/// `tools.default.max_tokens`. `None` when neither is set or the workspace
+/// root cannot be resolved.
+pub async fn configured_max_tokens(
+    handler: &dyn ToolContext,
+    workspace_target: &WorkspaceTarget,
+    tool: &str,
+) -> Option<u32> {
+    let root = match workspace_target {
+        WorkspaceTarget::Primary => handler.require_primary_workspace_root().ok()?,
+        WorkspaceTarget::Target(id) => handler.get_workspace_root_for_target(id).await.ok()?,
+    };
+    WorkspaceSettingsRegistry::global()
+        .get(&root)
+        .tool_limits(tool)
+        .max_tokens
+}
@@ -2,8 +2,9 @@ use clap::{Parser, Subcommand};
 use std::path::PathBuf;
 
 use crate::cli_tools::subcommands::{
-    BlastRadiusArgs, CallPathArgs, ContextArgs, GenericToolArgs, GlobalToolFlags, PatternsArgs,
-    RefsArgs, SearchArgs, SignalsArgs, SymbolsArgs, WorkspaceArgs,
+    AnnotationsArgs, ApiArgs, BlastRadiusArgs, CallPathArgs, CheckArgs, ContextArgs, DepsArgs,
+    DriftArgs, GenericToolArgs, GlobalToolFlags, PatternsArgs, RefsArgs, SearchArgs, SignalsArgs,
+    SymbolsArgs, WorkspaceArgs,
 };
 use crate::external_extract::ExternalExtractRawArgs;
 use crate::workspace::startup_hint::{WorkspaceStartupHint, WorkspaceStartupSource};
@@ -31,6 +32,11 @@ pub enum Command {
     // -- Lifecycle commands --------------------------------------------------
     /// Open the dashboard in the default browser
     Dashboard,
+    /// Serve search, goto, refs and outline over gRPC (token from JULIE_GRPC_TOKEN)
+    #[cfg(feature = "grpc")]
+    Grpc(GrpcArgs),
+    /// Serve definition, references, hover and symbols over LSP on stdio
+    Lsp,
 
     // -- Tool commands (named wrappers) --------------------------------------
     /// Search code, symbols, or file paths
@@ -52,6 +58,16 @@ pub enum Command {
     Workspace(WorkspaceArgs),
     /// Generate early warning signals report
     Signals(SignalsArgs),
+    /// Per-symbol editor annotations (reference counts, tests, deprecation) for a file
+    Annotations(AnnotationsArgs),
+    /// Public/exported API surface grouped by module
+    Api(ApiArgs),
+    /// Naming drift: similar functions named with different verbs
+    Drift(DriftArgs),
+    /// Module dependency matrix with upward (layer-violating) dependencies
+    Deps(DepsArgs),
+    /// CI quality gate: compare analyses against a base git ref
+    Check(CheckArgs),
     /// Extract parser data into a caller-owned SQLite database
     Extract(ExternalExtractRawArgs),
 
@@ -60,6 +76,15 @@ pub enum Command {
     Tool(GenericToolArgs),
 }
 
+/// Options for `julie-server grpc`.
+#[cfg(feature = "grpc")]
+#[derive(Debug, Clone, Parser)]
+pub struct GrpcArgs {
+    /// Address to listen on; bind a non-loopback address to serve other hosts
+    #[arg(long, default_value = crate::grpc::DEFAULT_LISTEN)]
+    pub listen: std::net::SocketAddr,
+}
+
 /// Resolve the workspace root path from CLI arg, env var, or current directory.
 ///
 /// Priority order:
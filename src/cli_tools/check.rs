@@ -0,0 +1,123 @@
+//! `julie check`: CI quality gate against a base git ref.
+//!
+//! Both sides are indexed from scratch into throwaway databases with the same
+//! pipeline — the working tree as head, a detached `git worktree` of the base
+//! ref as base — so the comparison never depends on a developer's local
+//! `.julie` index being fresh or on how either side was indexed.
+
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use anyhow::{Context, Result, bail};
+use tracing::warn;
+
+use crate::analysis::{QualityGateReport, QualitySnapshot};
+use crate::cli::resolve_workspace_root;
+use crate::database::SymbolDatabase;
+use crate::external_extract::normalize_external_root;
+use crate::external_extract::operations::group_files_by_language;
+use crate::indexing_core::analysis::run_sqlite_analysis;
+use crate::indexing_core::discovery::discover_external_files;
+use crate::indexing_core::extraction::extract_files_for_indexing_with_records;
+use crate::indexing_core::persistence::persist_force_rebuild;
+use crate::tools::workspace::indexing::finalize::resolve_pending_relationships_in_db;
+
+use super::subcommands::CheckArgs;
+
+/// Workspace id recorded in the throwaway databases.
+const QUALITY_GATE_WORKSPACE_ID: &str = "quality-gate";
+
+/// Index the working tree and `args.base`, and evaluate the selected checks.
+pub async fn run_quality_gate(
+    args: &CheckArgs,
+    cli_workspace: Option<PathBuf>,
+) -> Result<QualityGateReport> {
+    let start = std::time::Instant::now();
+    let workspace_root = resolve_workspace_root(cli_workspace);
+    eprintln!("Mode: standalone | Workspace: {:?}", workspace_root);
+
+    let scratch = tempfile::tempdir().context("failed to create scratch directory")?;
+
+    eprintln!("Indexing working tree...");
+    let head = index_snapshot(&workspace_root, &scratch.path().join("head.db")).await?;
+
+    eprintln!("Indexing base {}...", args.base);
+    let worktree = BaseWorktree::add(&workspace_root, &args.base, &scratch.path().join("base"))?;
+    let base = index_snapshot(worktree.path(), &scratch.path().join("base.db")).await?;
+    drop(worktree);
+
+    let report = crate::analysis::evaluate_quality_gate(&args.base, &base, &head, &args.checks);
+
+    eprintln!("Elapsed: {:.2?}", start.elapsed());
+    Ok(report)
+}
+
+/// Full extraction, cross-file resolution and post-index analysis of `root`
+/// into a fresh database at `db_path`.
+async fn index_snapshot(root: &Path, db_path: &Path) -> Result<QualitySnapshot> {
+    let root = normalize_external_root(root)?;
+    let files = discover_external_files(&root, &[])?;
+    let (batch, _records) =
+        extract_files_for_indexing_with_records(group_files_by_language(files), &root).await?;
+
+    let mut db = SymbolDatabase::new(db_path)?;
+    persist_force_rebuild(&mut db, QUALITY_GATE_WORKSPACE_ID, &batch)?;
+    resolve_pending_relationships_in_db(
+        &mut db,
+        &batch.all_pending_relationships,
+        &batch.all_structured_pending_relationships,
+    );
+    run_sqlite_analysis(&db)?;
+    crate::analysis::capture_quality_snapshot(&db)
+}
+
+/// A detached `git worktree` checkout of the base ref, removed on drop.
+struct BaseWorktree {
+    repo: PathBuf,
+    path: PathBuf,
+}
+
+impl BaseWorktree {
+    fn add(repo: &Path, base_ref: &str, path: &Path) -> Result<Self> {
+        let output = Command::new("git")
+            .arg("-C")
+            .arg(repo)
+            .args(["worktree", "add", "--detach", "--quiet"])
+            .arg(path)
+            .arg(base_ref)
+            .output()
+            .context("failed to run git; `julie check` needs git to check out the base ref")?;
+        if !output.status.success() {
+            bail!(
+                "git worktree add for base '{}' failed: {}",
+                base_ref,
+                String::from_utf8_lossy(&output.stderr).trim()
+            );
+        }
+        Ok(Self {
+            repo: repo.to_path_buf(),
+            path: path.to_path_buf(),
+        })
+    }
+
+    fn path(&self) -> &Path {
+        &self.path
+    }
+}
+
+impl Drop for BaseWorktree {
+    fn drop(&mut self) {
+        let removed = Command::new("git")
+            .arg("-C")
+            .arg(&self.repo)
+            .args(["worktree", "remove", "--force"])
+            .arg(&self.path)
+            .status();
+        if !matches!(removed, Ok(status) if status.success()) {
+            warn!(
+                "Failed to remove base worktree {}; run `git worktree prune`",
+                self.path.display()
+            );
+        }
+    }
+}
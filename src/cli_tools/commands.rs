@@ -139,15 +139,43 @@ impl CliToolCommand for SearchArgs {
         if self.exclude_tests {
             args["exclude_tests"] = Value::Bool(true);
         }
+        if self.include_generated {
+            args["include_generated"] = Value::Bool(true);
+        }
         if let Some(ref regions) = self.regions {
             args["regions"] = Value::String(regions.clone());
         }
+        if self.regex {
+            args["mode"] = Value::String("regex".to_string());
+        } else if self.todos {
+            args["mode"] = Value::String("todos".to_string());
+        } else if self.topics {
+            args["mode"] = Value::String("topics".to_string());
+        }
+        if let Some(budget) = self.budget {
+            args["max_tokens"] = Value::Number(budget.into());
+        }
+        if self.no_dedupe {
+            args["dedupe"] = Value::Bool(false);
+        }
+        if self.fuzzy {
+            args["fuzzy"] = Value::Bool(true);
+        }
+        if let Some(ref defines) = self.defines {
+            args["defines"] = Value::String(defines.clone());
+        }
+        if let Some(ref project) = self.project {
+            args["project"] = Value::String(project.clone());
+        }
+        if let Some(ref framework) = self.framework {
+            args["framework"] = Value::String(framework.clone());
+        }
 
         Ok(args)
     }
 
     async fn call_standalone(&self, handler: &JulieServerHandler) -> Result<CallToolResult> {
-        use crate::tools::search::{FastSearchParams, FastSearchTool};
+        use crate::tools::search::{FastSearchParams, FastSearchTool, SearchMode};
 
         let tool = FastSearchParams {
             search: FastSearchTool {
@@ -157,9 +185,23 @@ impl CliToolCommand for SearchArgs {
                 file_pattern: self.file_pattern.clone(),
                 context_lines: self.context_lines,
                 exclude_tests: if self.exclude_tests { Some(true) } else { None },
+                include_generated: self.include_generated,
+                max_tokens: self.budget,
+                dedupe: !self.no_dedupe,
+                fuzzy: self.fuzzy,
+                defines: self.defines.clone(),
+                project: self.project.clone(),
+                framework: self.framework.clone(),
                 ..Default::default()
             },
             regions: self.regions.clone(),
+            mode: if self.regex {
+                Some(SearchMode::Regex)
+            } else if self.todos {
+                Some(SearchMode::Todos)
+            } else {
+                self.topics.then_some(SearchMode::Topics)
+            },
         };
         tool.call_tool(handler).await
     }
@@ -227,6 +269,15 @@ impl CliToolCommand for RefsArgs {
         if let Some(ref kind) = self.kind {
             args["reference_kind"] = Value::String(kind.clone());
         }
+        if self.textual {
+            args["include_textual"] = Value::Bool(true);
+        }
+        if let Some(ref project) = self.project {
+            args["project"] = Value::String(project.clone());
+        }
+        if let Some(budget) = self.budget {
+            args["max_tokens"] = Value::Number(budget.into());
+        }
 
         Ok(args)
     }
@@ -260,6 +311,9 @@ impl CliToolCommand for SymbolsArgs {
         if let Some(ref target) = self.target {
             args["target"] = Value::String(target.clone());
         }
+        if let Some(budget) = self.budget {
+            args["max_tokens"] = Value::Number(budget.into());
+        }
 
         Ok(args)
     }
@@ -274,6 +328,7 @@ impl CliToolCommand for SymbolsArgs {
             limit: Some(self.limit),
             mode: Some(self.mode.clone()),
             workspace: None,
+            max_tokens: self.budget,
         };
         tool.call_tool(handler).await
     }
@@ -307,6 +362,9 @@ impl CliToolCommand for ContextArgs {
         if self.prefer_tests {
             args["prefer_tests"] = Value::Bool(true);
         }
+        if let Some(ref project) = self.project {
+            args["project"] = Value::String(project.clone());
+        }
 
         Ok(args)
     }
@@ -327,6 +385,7 @@ impl CliToolCommand for ContextArgs {
             failing_test: None,
             max_hops: self.max_hops,
             prefer_tests: if self.prefer_tests { Some(true) } else { None },
+            project: self.project.clone(),
         };
         tool.call_tool(handler).await
     }
@@ -454,6 +513,9 @@ impl CliToolCommand for WorkspaceArgs {
             name: self.name.clone(),
             workspace_id: None,
             detailed: None,
+            from_generation: None,
+            to_generation: None,
+            dry_run: None,
         };
         tool.call_tool(handler).await
     }
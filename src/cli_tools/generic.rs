@@ -1,7 +1,7 @@
 //! Generic tool dispatcher for the `julie-server tool <name>` subcommand.
 //!
 //! Maps tool names to their struct types, deserializes JSON params via serde,
-//! and calls the tool through the shared `.call_tool(&handler)` path. All 13
+//! and calls the tool through the shared `.call_tool(&handler)` path. All 22
 //! public MCP tools are reachable through this dispatcher.
 
 use anyhow::Result;
@@ -16,15 +16,24 @@ pub const AVAILABLE_TOOLS: &[&str] = &[
     "call_path",
     "deep_dive",
     "edit_file",
+    "fast_audit",
+    "fast_counterparts",
+    "fast_imports",
     "fast_refs",
     "fast_search",
+    "fast_signature",
     "get_context",
+    "get_context_pack",
+    "get_file_annotations",
     "get_symbols",
     "manage_workspace",
     "patterns",
     "rename_symbol",
     "rewrite_symbol",
+    "session_context",
     "spillover_get",
+    "syntax_query",
+    "task_status",
 ];
 
 /// Dispatch a tool call by name, deserializing JSON params into the correct
@@ -42,6 +51,22 @@ pub async fn dispatch_generic_tool(
             let tool: crate::tools::search::FastSearchParams = deserialize_params(name, params)?;
             tool.call_tool(handler).await
         }
+        "fast_audit" => {
+            let tool: crate::tools::AuditTool = deserialize_params(name, params)?;
+            tool.call_tool(handler).await
+        }
+        "fast_counterparts" => {
+            let tool: crate::tools::FastCounterpartsTool = deserialize_params(name, params)?;
+            tool.call_tool(handler).await
+        }
+        "fast_imports" => {
+            let tool: crate::tools::FastImportsTool = deserialize_params(name, params)?;
+            tool.call_tool(handler).await
+        }
+        "fast_signature" => {
+            let tool: crate::tools::FastSignatureTool = deserialize_params(name, params)?;
+            tool.call_tool(handler).await
+        }
         "fast_refs" => {
             let tool: crate::tools::FastRefsTool = deserialize_params(name, params)?;
             tool.call_tool(handler).await
@@ -58,6 +83,14 @@ pub async fn dispatch_generic_tool(
             let tool: crate::tools::GetContextTool = deserialize_params(name, params)?;
             tool.call_tool(handler).await
         }
+        "get_context_pack" => {
+            let tool: crate::tools::GetContextPackTool = deserialize_params(name, params)?;
+            tool.call_tool(handler).await
+        }
+        "get_file_annotations" => {
+            let tool: crate::tools::GetFileAnnotationsTool = deserialize_params(name, params)?;
+            tool.call_tool(handler).await
+        }
         "blast_radius" => {
             let tool: crate::tools::BlastRadiusTool = deserialize_params(name, params)?;
             tool.call_tool(handler).await
@@ -70,6 +103,18 @@ pub async fn dispatch_generic_tool(
             let tool: crate::tools::SpilloverGetTool = deserialize_params(name, params)?;
             tool.call_tool(handler).await
         }
+        "syntax_query" => {
+            let tool: crate::tools::SyntaxQueryTool = deserialize_params(name, params)?;
+            tool.call_tool(handler).await
+        }
+        "session_context" => {
+            let tool: crate::tools::SessionContextTool = deserialize_params(name, params)?;
+            tool.call_tool(handler).await
+        }
+        "task_status" => {
+            let tool: crate::tools::TaskStatusTool = deserialize_params(name, params)?;
+            tool.call_tool(handler).await
+        }
         "rename_symbol" => {
             let tool: crate::tools::RenameSymbolTool = deserialize_params(name, params)?;
             tool.call_tool(handler).await
@@ -117,7 +162,7 @@ mod tests {
 
     #[test]
     fn test_available_tools_count() {
-        assert_eq!(AVAILABLE_TOOLS.len(), 13, "All 13 MCP tools must be listed");
+        assert_eq!(AVAILABLE_TOOLS.len(), 22, "All 22 MCP tools must be listed");
     }
 
     #[test]
@@ -10,6 +10,7 @@
 //! It runs every tool in standalone mode: creates a local handler, indexes
 //! the workspace in-process, and executes the tool.
 
+pub mod check;
 pub mod commands;
 pub mod generic;
 pub mod output;
@@ -213,6 +214,9 @@ pub async fn bootstrap_standalone_handler(
             name: None,
             workspace_id: None,
             detailed: None,
+            from_generation: None,
+            to_generation: None,
+            dry_run: None,
         };
         index_tool
             .call_tool_with_options(&handler, true)
@@ -292,6 +296,166 @@ pub async fn run_signals_report(
     Ok(report)
 }
 
+// ---------------------------------------------------------------------------
+// Annotations report (standalone-only, not an MCP tool)
+// ---------------------------------------------------------------------------
+
+/// Generate per-symbol editor annotations for one file.
+pub async fn run_annotations_report(
+    args: &subcommands::AnnotationsArgs,
+    cli_workspace: Option<PathBuf>,
+) -> Result<crate::analysis::FileAnnotationReport> {
+    let start = std::time::Instant::now();
+    let workspace_root = resolve_workspace_root(cli_workspace);
+    eprintln!("Mode: standalone | Workspace: {:?}", workspace_root);
+
+    let file_path = stored_relative_path(&args.file, &workspace_root)?;
+
+    let handler = bootstrap_standalone_handler(&workspace_root).await?;
+    let db_arc = handler.primary_database().await?;
+    let db = db_arc
+        .lock()
+        .map_err(|e| anyhow::anyhow!("Database lock: {e}"))?;
+
+    let report = crate::analysis::generate_file_annotations(&db, &file_path)?;
+
+    eprintln!("Elapsed: {:.2?}", start.elapsed());
+    Ok(report)
+}
+
+/// Editors pass absolute buffer paths; the index stores workspace-relative
+/// Unix-style paths.
+fn stored_relative_path(file: &str, workspace_root: &std::path::Path) -> Result<String> {
+    let path = std::path::Path::new(file);
+    if path.is_absolute() {
+        return crate::utils::paths::to_relative_unix_style(path, workspace_root);
+    }
+    Ok(file.trim_start_matches("./").replace('\\', "/"))
+}
+
+// ---------------------------------------------------------------------------
+// API surface report (standalone-only, not an MCP tool)
+// ---------------------------------------------------------------------------
+
+/// List the public/exported API surface of the workspace or a focus path.
+pub async fn run_api_surface_report(
+    args: &subcommands::ApiArgs,
+    cli_workspace: Option<PathBuf>,
+) -> Result<crate::analysis::ApiSurfaceReport> {
+    let start = std::time::Instant::now();
+    let workspace_root = resolve_workspace_root(cli_workspace);
+    eprintln!("Mode: standalone | Workspace: {:?}", workspace_root);
+
+    let focus_path = args
+        .path
+        .as_deref()
+        .map(|path| stored_relative_path(path, &workspace_root))
+        .transpose()?
+        .filter(|path| !path.is_empty() && path != ".");
+
+    let handler = bootstrap_standalone_handler(&workspace_root).await?;
+    let db_arc = handler.primary_database().await?;
+    let db = db_arc
+        .lock()
+        .map_err(|e| anyhow::anyhow!("Database lock: {e}"))?;
+
+    let report = crate::analysis::generate_api_surface(&db, focus_path.as_deref())?;
+
+    eprintln!("Elapsed: {:.2?}", start.elapsed());
+    Ok(report)
+}
+
+// ---------------------------------------------------------------------------
+// Naming drift report (standalone-only, not an MCP tool)
+// ---------------------------------------------------------------------------
+
+/// Find similar functions/methods named with inconsistent verbs.
+pub async fn run_naming_drift_report(
+    args: &subcommands::DriftArgs,
+    cli_workspace: Option<PathBuf>,
+) -> Result<crate::analysis::NamingDriftReport> {
+    let start = std::time::Instant::now();
+    let workspace_root = resolve_workspace_root(cli_workspace);
+    eprintln!("Mode: standalone | Workspace: {:?}", workspace_root);
+
+    let mut options = crate::analysis::NamingDriftOptions {
+        focus_path: args
+            .path
+            .as_deref()
+            .map(|path| stored_relative_path(path, &workspace_root))
+            .transpose()?
+            .filter(|path| !path.is_empty() && path != "."),
+        ..Default::default()
+    };
+    if let Some(min_similarity) = args.min_similarity {
+        if !(0.0..=1.0).contains(&min_similarity) {
+            anyhow::bail!("--min-similarity must be between 0.0 and 1.0");
+        }
+        options.clustering.min_score = min_similarity;
+    }
+
+    let handler = bootstrap_standalone_handler(&workspace_root).await?;
+    let db_arc = handler.primary_database().await?;
+    let db = db_arc
+        .lock()
+        .map_err(|e| anyhow::anyhow!("Database lock: {e}"))?;
+
+    let report = crate::analysis::generate_naming_drift_report(&db, &options)?;
+
+    eprintln!("Elapsed: {:.2?}", start.elapsed());
+    Ok(report)
+}
+
+// ---------------------------------------------------------------------------
+// Dependency matrix report (standalone-only, not an MCP tool)
+// ---------------------------------------------------------------------------
+
+/// Roll cross-file relationships up into a module dependency matrix.
+pub async fn run_dependency_matrix_report(
+    args: &subcommands::DepsArgs,
+    cli_workspace: Option<PathBuf>,
+) -> Result<crate::analysis::DependencyMatrixReport> {
+    let start = std::time::Instant::now();
+    let workspace_root = resolve_workspace_root(cli_workspace);
+    eprintln!("Mode: standalone | Workspace: {:?}", workspace_root);
+
+    let mut options = crate::analysis::DependencyMatrixOptions {
+        focus_path: args
+            .path
+            .as_deref()
+            .map(|path| stored_relative_path(path, &workspace_root))
+            .transpose()?
+            .filter(|path| !path.is_empty() && path != "."),
+        layers: args
+            .layers
+            .iter()
+            .map(|layer| stored_relative_path(layer, &workspace_root))
+            .map(|layer| layer.map(|layer| layer.trim_end_matches('/').to_string()))
+            .collect::<Result<_>>()?,
+        ..Default::default()
+    };
+    if let Some(depth) = args.depth {
+        if depth == 0 {
+            anyhow::bail!("--depth must be at least 1");
+        }
+        options.depth = depth;
+    }
+    if let Some(top) = args.top {
+        options.top = top;
+    }
+
+    let handler = bootstrap_standalone_handler(&workspace_root).await?;
+    let db_arc = handler.primary_database().await?;
+    let db = db_arc
+        .lock()
+        .map_err(|e| anyhow::anyhow!("Database lock: {e}"))?;
+
+    let report = crate::analysis::generate_dependency_matrix(&db, &options)?;
+
+    eprintln!("Elapsed: {:.2?}", start.elapsed());
+    Ok(report)
+}
+
 // ---------------------------------------------------------------------------
 // Helpers
 // ---------------------------------------------------------------------------
@@ -256,6 +256,426 @@ fn format_signals_markdown(report: &crate::analysis::EarlyWarningReport) -> Stri
     out
 }
 
+// ---------------------------------------------------------------------------
+// Annotations report formatter
+// ---------------------------------------------------------------------------
+
+/// Format a per-file editor annotations report for CLI output.
+pub fn format_annotations_report(
+    report: &crate::analysis::FileAnnotationReport,
+    format: OutputFormat,
+) -> String {
+    match format {
+        OutputFormat::Json => serde_json::to_string_pretty(report).unwrap_or_default(),
+        OutputFormat::Text => format_annotations_text(report),
+        OutputFormat::Markdown => format_annotations_markdown(report),
+    }
+}
+
+fn annotation_tests_label(symbol: &crate::analysis::SymbolAnnotation) -> String {
+    match (&symbol.best_test_tier, symbol.has_tests()) {
+        (Some(tier), true) => format!("{} ({})", symbol.test_count, tier),
+        (None, true) => symbol.test_count.to_string(),
+        _ => "none".to_string(),
+    }
+}
+
+fn format_annotations_text(report: &crate::analysis::FileAnnotationReport) -> String {
+    let mut out = format!(
+        "Annotations for {}  ({} symbols)\n",
+        report.file_path,
+        report.symbols.len()
+    );
+    for symbol in &report.symbols {
+        out.push_str(&format!(
+            "  {}:{} {} {}  refs: {}  tests: {}",
+            symbol.start_line,
+            symbol.start_column,
+            symbol.kind,
+            symbol.name,
+            symbol.reference_count,
+            annotation_tests_label(symbol)
+        ));
+        if let Some(reason) = &symbol.deprecated {
+            if reason.is_empty() {
+                out.push_str("  deprecated");
+            } else {
+                out.push_str(&format!("  deprecated: {}", reason));
+            }
+        }
+        out.push('\n');
+    }
+    out
+}
+
+fn format_annotations_markdown(report: &crate::analysis::FileAnnotationReport) -> String {
+    let mut out = format!("# Annotations: {}\n\n", report.file_path);
+    out.push_str("| Line | Symbol | Kind | References | Tests | Deprecated |\n|------|--------|------|------------|-------|------------|\n");
+    for symbol in &report.symbols {
+        out.push_str(&format!(
+            "| {} | {} | {} | {} | {} | {} |\n",
+            symbol.start_line,
+            symbol.name,
+            symbol.kind,
+            symbol.reference_count,
+            annotation_tests_label(symbol),
+            match &symbol.deprecated {
+                Some(reason) if !reason.is_empty() => reason.as_str(),
+                Some(_) => "yes",
+                None => "",
+            }
+        ));
+    }
+    out
+}
+
+// ---------------------------------------------------------------------------
+// API surface report formatter
+// ---------------------------------------------------------------------------
+
+/// Format a public API surface report for CLI output.
+pub fn format_api_surface_report(
+    report: &crate::analysis::ApiSurfaceReport,
+    format: OutputFormat,
+) -> String {
+    match format {
+        OutputFormat::Json => serde_json::to_string_pretty(report).unwrap_or_default(),
+        OutputFormat::Text => format_api_surface_text(report),
+        OutputFormat::Markdown => format_api_surface_markdown(report),
+    }
+}
+
+fn api_surface_scope(report: &crate::analysis::ApiSurfaceReport) -> &str {
+    report.focus_path.as_deref().unwrap_or("workspace")
+}
+
+fn format_api_surface_text(report: &crate::analysis::ApiSurfaceReport) -> String {
+    let mut out = format!(
+        "API surface of {}  ({} symbols in {} modules)\n",
+        api_surface_scope(report),
+        report.total_symbols,
+        report.modules.len()
+    );
+    for module in &report.modules {
+        out.push_str(&format!("\n{} ({})\n", module.module, module.language));
+        for symbol in &module.symbols {
+            match &symbol.signature {
+                Some(signature) => out.push_str(&format!(
+                    "  :{} {} {}  {}\n",
+                    symbol.line, symbol.kind, symbol.name, signature
+                )),
+                None => out.push_str(&format!(
+                    "  :{} {} {}\n",
+                    symbol.line, symbol.kind, symbol.name
+                )),
+            }
+        }
+    }
+    out
+}
+
+fn format_api_surface_markdown(report: &crate::analysis::ApiSurfaceReport) -> String {
+    let mut out = format!(
+        "# API surface: {}\n\n{} symbols in {} modules\n",
+        api_surface_scope(report),
+        report.total_symbols,
+        report.modules.len()
+    );
+    for module in &report.modules {
+        out.push_str(&format!("\n## {} ({})\n\n", module.module, module.language));
+        out.push_str(
+            "| Line | Kind | Symbol | Signature |\n|------|------|--------|-----------|\n",
+        );
+        for symbol in &module.symbols {
+            out.push_str(&format!(
+                "| {} | {} | {} | {} |\n",
+                symbol.line,
+                symbol.kind,
+                symbol.name,
+                symbol
+                    .signature
+                    .as_deref()
+                    .map(|signature| format!("`{}`", signature.replace('|', "\\|")))
+                    .unwrap_or_default()
+            ));
+        }
+    }
+    out
+}
+
+pub fn format_naming_drift_report(
+    report: &crate::analysis::NamingDriftReport,
+    format: OutputFormat,
+) -> String {
+    match format {
+        OutputFormat::Json => serde_json::to_string_pretty(report).unwrap_or_default(),
+        OutputFormat::Text => format_naming_drift_text(report),
+        OutputFormat::Markdown => format_naming_drift_markdown(report),
+    }
+}
+
+fn naming_drift_variants(cluster: &crate::analysis::NamingDriftCluster) -> String {
+    cluster
+        .variants
+        .iter()
+        .map(|variant| format!("{} ×{}", variant.verb, variant.count))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+fn format_naming_drift_text(report: &crate::analysis::NamingDriftReport) -> String {
+    let mut out = format!(
+        "Naming drift in {}  ({} groups from {} embedded functions/methods)\n",
+        report.focus_path.as_deref().unwrap_or("workspace"),
+        report.clusters.len(),
+        report.candidates
+    );
+    if report.candidates == 0 {
+        out.push_str("No embedded symbols found; run the embedding pipeline first.\n");
+    }
+    for cluster in &report.clusters {
+        out.push_str(&format!(
+            "\n{}: {}  → suggest {}\n",
+            cluster.subject,
+            naming_drift_variants(cluster),
+            cluster.suggested_verb
+        ));
+        for symbol in &cluster.symbols {
+            out.push_str(&format!(
+                "  {}:{} {} ({})\n",
+                symbol.file_path, symbol.line, symbol.name, symbol.kind
+            ));
+        }
+    }
+    out
+}
+
+fn format_naming_drift_markdown(report: &crate::analysis::NamingDriftReport) -> String {
+    let mut out = format!(
+        "# Naming drift: {}\n\n{} groups from {} embedded functions/methods\n",
+        report.focus_path.as_deref().unwrap_or("workspace"),
+        report.clusters.len(),
+        report.candidates
+    );
+    for cluster in &report.clusters {
+        out.push_str(&format!(
+            "\n## {} → `{}`\n\nVariants: {}\n\n",
+            cluster.subject,
+            cluster.suggested_verb,
+            naming_drift_variants(cluster)
+        ));
+        out.push_str("| Symbol | Kind | Location |\n|--------|------|----------|\n");
+        for symbol in &cluster.symbols {
+            out.push_str(&format!(
+                "| {} | {} | {}:{} |\n",
+                symbol.name, symbol.kind, symbol.file_path, symbol.line
+            ));
+        }
+    }
+    out
+}
+
+pub fn format_dependency_matrix_report(
+    report: &crate::analysis::DependencyMatrixReport,
+    format: OutputFormat,
+) -> String {
+    match format {
+        OutputFormat::Json => serde_json::to_string_pretty(report).unwrap_or_default(),
+        OutputFormat::Text => format_dependency_matrix_text(report),
+        OutputFormat::Markdown => format_dependency_matrix_markdown(report),
+    }
+}
+
+fn format_dependency_matrix_text(report: &crate::analysis::DependencyMatrixReport) -> String {
+    let mut out = format!(
+        "Module dependencies in {}  ({} of {} modules, {} cross-module relationships)\n",
+        report.focus_path.as_deref().unwrap_or("workspace"),
+        report.modules.len(),
+        report.total_modules,
+        report.total_dependencies
+    );
+    if report.modules.is_empty() {
+        out.push_str("No cross-module relationships found.\n");
+        return out;
+    }
+
+    out.push('\n');
+    for (index, module) in report.modules.iter().enumerate() {
+        out.push_str(&format!("  [{}] {}\n", index + 1, module));
+    }
+
+    // Rows depend on columns; cells are padded to the widest count.
+    let width = report
+        .matrix
+        .iter()
+        .flatten()
+        .map(|count| count.to_string().len())
+        .chain(std::iter::once(report.modules.len().to_string().len() + 2))
+        .max()
+        .unwrap_or(1);
+    out.push_str(&format!("\n{:>width$}", "", width = width + 2));
+    for index in 1..=report.modules.len() {
+        out.push_str(&format!(" {:>width$}", format!("[{index}]")));
+    }
+    out.push('\n');
+    for (index, row) in report.matrix.iter().enumerate() {
+        out.push_str(&format!(
+            "{:>width$}",
+            format!("[{}]", index + 1),
+            width = width + 2
+        ));
+        for (column, count) in row.iter().enumerate() {
+            let cell = if column == index {
+                "-".to_string()
+            } else if *count == 0 {
+                ".".to_string()
+            } else {
+                count.to_string()
+            };
+            out.push_str(&format!(" {cell:>width$}"));
+        }
+        out.push('\n');
+    }
+
+    if report.upward.is_empty() {
+        out.push_str("\nNo upward dependencies.\n");
+    } else {
+        out.push_str(&format!(
+            "\nUpward dependencies ({}):\n",
+            report.upward.len()
+        ));
+        for upward in &report.upward {
+            out.push_str(&format!(
+                "  {} → {}  ({} relationships; {})\n",
+                upward.from, upward.to, upward.relationships, upward.reason
+            ));
+        }
+    }
+    out
+}
+
+fn format_dependency_matrix_markdown(report: &crate::analysis::DependencyMatrixReport) -> String {
+    let mut out = format!(
+        "# Module dependencies: {}\n\n{} of {} modules, {} cross-module relationships (rows depend on columns)\n",
+        report.focus_path.as_deref().unwrap_or("workspace"),
+        report.modules.len(),
+        report.total_modules,
+        report.total_dependencies
+    );
+    if !report.modules.is_empty() {
+        out.push_str("\n| Module |");
+        for index in 1..=report.modules.len() {
+            out.push_str(&format!(" {index} |"));
+        }
+        out.push_str("\n|--------|");
+        out.push_str(&"---|".repeat(report.modules.len()));
+        out.push('\n');
+        for (index, (module, row)) in report.modules.iter().zip(&report.matrix).enumerate() {
+            out.push_str(&format!("| {} `{}` |", index + 1, module));
+            for (column, count) in row.iter().enumerate() {
+                if column == index {
+                    out.push_str(" - |");
+                } else {
+                    out.push_str(&format!(" {count} |"));
+                }
+            }
+            out.push('\n');
+        }
+    }
+    if !report.upward.is_empty() {
+        out.push_str("\n## Upward dependencies\n\n| From | To | Relationships | Why |\n|------|----|---------------|-----|\n");
+        for upward in &report.upward {
+            out.push_str(&format!(
+                "| `{}` | `{}` | {} | {} |\n",
+                upward.from, upward.to, upward.relationships, upward.reason
+            ));
+        }
+    }
+    out
+}
+
+pub fn format_quality_gate_report(
+    report: &crate::analysis::QualityGateReport,
+    format: OutputFormat,
+) -> String {
+    match format {
+        OutputFormat::Json => serde_json::to_string_pretty(report).unwrap_or_default(),
+        OutputFormat::Text => format_quality_gate_text(report),
+        OutputFormat::Markdown => format_quality_gate_markdown(report),
+    }
+}
+
+fn quality_gate_verdict(passed: bool) -> &'static str {
+    if passed { "PASS" } else { "FAIL" }
+}
+
+/// The entries that explain a check's verdict: new dead code, removed API.
+/// API additions are listed too, as information.
+fn quality_gate_details(result: &crate::analysis::QualityCheckResult) -> Vec<String> {
+    use crate::analysis::QualityCheck;
+    match result.check {
+        QualityCheck::DeadCode => result.added.iter().map(|e| format!("+ {e}")).collect(),
+        QualityCheck::ApiSurface => result
+            .removed
+            .iter()
+            .map(|e| format!("- {e}"))
+            .chain(result.added.iter().map(|e| format!("+ {e}")))
+            .collect(),
+        QualityCheck::DeprecatedUsage => Vec::new(),
+    }
+}
+
+fn format_quality_gate_text(report: &crate::analysis::QualityGateReport) -> String {
+    let mut out = format!(
+        "Quality gate vs {}: {}\n",
+        report.base_ref,
+        quality_gate_verdict(report.passed)
+    );
+    for result in &report.checks {
+        out.push_str(&format!(
+            "\n{} {}  base={} head={}\n",
+            quality_gate_verdict(result.passed),
+            result.check,
+            result.base,
+            result.head
+        ));
+        for line in quality_gate_details(result) {
+            out.push_str(&format!("  {line}\n"));
+        }
+    }
+    out
+}
+
+fn format_quality_gate_markdown(report: &crate::analysis::QualityGateReport) -> String {
+    let mut out = format!(
+        "# Quality gate vs `{}`: {}\n\n| Check | Result | Base | Head |\n|-------|--------|------|------|\n",
+        report.base_ref,
+        quality_gate_verdict(report.passed)
+    );
+    for result in &report.checks {
+        out.push_str(&format!(
+            "| {} | {} | {} | {} |\n",
+            result.check,
+            quality_gate_verdict(result.passed),
+            result.base,
+            result.head
+        ));
+    }
+    for result in &report.checks {
+        let details = quality_gate_details(result);
+        if details.is_empty() {
+            continue;
+        }
+        out.push_str(&format!("\n## {}\n\n```diff\n", result.check));
+        for line in details {
+            out.push_str(&line);
+            out.push('\n');
+        }
+        out.push_str("```\n");
+    }
+    out
+}
+
 // ---------------------------------------------------------------------------
 // Shared helpers
 // ---------------------------------------------------------------------------
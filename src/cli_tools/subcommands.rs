@@ -71,6 +71,9 @@ impl GlobalToolFlags {
 ///   julie-server search "FastSearchTool"
 ///   julie-server search "parse" --language rust
 ///   julie-server search "browser_client.rs"
+///   julie-server search 'unwrap\(\)' --regex --language rust
+///   julie-server search '*' --todos --file-pattern 'src/billing/**'
+///   julie-server search '*' --topics
 #[derive(Debug, Clone, Parser)]
 pub struct SearchArgs {
     /// Search query
@@ -96,10 +99,51 @@ pub struct SearchArgs {
     #[arg(short = 'T', long)]
     pub exclude_tests: bool,
 
+    /// Rank generated code (protobuf stubs, ORM migrations, DO NOT EDIT files) like hand-written code
+    #[arg(long)]
+    pub include_generated: bool,
+
     /// Restrict content matches to stored source-region kinds.
     #[arg(long)]
     pub regions: Option<String>,
 
+    /// Treat the query as a regular expression over indexed file contents
+    #[arg(short = 'E', long)]
+    pub regex: bool,
+
+    /// List TODO/FIXME/HACK comments with owner and age; the query filters them ("*" lists all)
+    #[arg(long, conflicts_with = "regex")]
+    pub todos: bool,
+
+    /// Map the semantic topics of the embedded symbols; the query filters them ("*" lists all)
+    #[arg(long, conflicts_with_all = ["regex", "todos"])]
+    pub topics: bool,
+
+    /// Approximate token budget for the response (drops context, then docs, then collapses by file)
+    #[arg(short = 'b', long)]
+    pub budget: Option<u32>,
+
+    /// List every identical copy of a symbol (e.g. vendored code) as its own result
+    #[arg(long)]
+    pub no_dedupe: bool,
+
+    /// When nothing matches, return the symbols with the closest names (typo-tolerant)
+    #[arg(long)]
+    pub fuzzy: bool,
+
+    /// C/C++ build configuration: comma-separated macros (e.g. "_WIN32,LEVEL=3")
+    #[arg(long)]
+    pub defines: Option<String>,
+
+    /// Only search this monorepo project (Cargo, npm, Go or .NET package name)
+    #[arg(long)]
+    pub project: Option<String>,
+
+    /// Only keep symbols playing this framework role (e.g. spring_bean) or
+    /// any role of this framework (e.g. react)
+    #[arg(long)]
+    pub framework: Option<String>,
+
     /// Deprecated and accepted as a no-op since T8 unified-search cutover.
     /// Older harnesses (e.g. the eros bakeoff comparator) still pass
     /// `--target definitions|files|content`; we keep the flag so they can run
@@ -144,6 +188,18 @@ pub struct RefsArgs {
     /// Narrow by reference kind: call, variable_ref, type_usage, member_access, import
     #[arg(short = 'k', long)]
     pub kind: Option<String>,
+
+    /// Also list low-confidence mentions in comments and string literals
+    #[arg(long)]
+    pub textual: bool,
+
+    /// Only use definitions from this monorepo project (package name)
+    #[arg(long)]
+    pub project: Option<String>,
+
+    /// Approximate token budget for the response (drops type lines, then collapses by file)
+    #[arg(short = 'b', long)]
+    pub budget: Option<u32>,
 }
 
 // ---------------------------------------------------------------------------
@@ -175,6 +231,10 @@ pub struct SymbolsArgs {
     /// Maximum nesting depth (0=top-level, 1=include methods, 2+=deeper)
     #[arg(short = 'd', long, default_value = "1")]
     pub max_depth: u32,
+
+    /// Approximate token budget for the response (drops code, then docs, then collapses by parent)
+    #[arg(short = 'b', long)]
+    pub budget: Option<u32>,
 }
 
 // ---------------------------------------------------------------------------
@@ -206,6 +266,10 @@ pub struct ContextArgs {
     /// Include test-linked symbols in neighbor slots
     #[arg(long)]
     pub prefer_tests: bool,
+
+    /// Only take pivots from this monorepo project (package name)
+    #[arg(long)]
+    pub project: Option<String>,
 }
 
 // ---------------------------------------------------------------------------
@@ -330,6 +394,7 @@ pub struct PatternsArgs {
 ///   julie-server workspace index
 ///   julie-server workspace stats
 ///   julie-server workspace health --force
+///   julie-server workspace capabilities
 ///   julie-server workspace register --path /code/myproject --name "My Project"
 ///
 /// Note: `open`, `register`, `remove`, `refresh`, `stats`, and `dashboard`
@@ -337,7 +402,7 @@ pub struct PatternsArgs {
 /// point, not the one-shot standalone workspace wrapper.
 #[derive(Debug, Clone, Parser)]
 pub struct WorkspaceArgs {
-    /// Operation: index, list, register, remove, stats, clean, refresh, open, health
+    /// Operation: index, list, register, remove, stats, clean, refresh, open, health, capabilities
     pub operation: String,
 
     /// Path to workspace (used by: index, register, open)
@@ -381,6 +446,117 @@ pub struct SignalsArgs {
     pub limit: Option<usize>,
 }
 
+// ---------------------------------------------------------------------------
+// annotations (editor inlay hints)
+// ---------------------------------------------------------------------------
+
+/// Per-symbol annotations for one file: reference counts, test linkage, and
+/// deprecation markers, for rendering as editor inlay hints or decorations.
+///
+/// Examples:
+///   julie-server annotations src/handler.rs --standalone
+///   julie-server annotations src/handler.rs --standalone --json
+#[derive(Debug, Clone, Parser)]
+pub struct AnnotationsArgs {
+    /// File to annotate (workspace-relative or absolute)
+    pub file: String,
+}
+
+// ---------------------------------------------------------------------------
+// api (public API surface)
+// ---------------------------------------------------------------------------
+
+/// List the public/exported API surface: public declarations with signatures,
+/// grouped by module. Diff the output between releases to catch accidental
+/// API growth.
+///
+/// Examples:
+///   julie-server api --standalone
+///   julie-server api --path src/client --standalone --format markdown
+#[derive(Debug, Clone, Parser)]
+pub struct ApiArgs {
+    /// Limit the report to a file or directory (workspace-relative or absolute)
+    #[arg(long)]
+    pub path: Option<String>,
+}
+
+// ---------------------------------------------------------------------------
+// drift (naming drift)
+// ---------------------------------------------------------------------------
+
+/// Find functions and methods that do the same thing but are named
+/// inconsistently (`getUser` / `fetchUser` / `retrieveUser`), with a suggested
+/// standard verb per group. Needs embeddings; run after the embedding
+/// pipeline has finished.
+///
+/// Examples:
+///   julie-server drift --standalone
+///   julie-server drift --path src/api --min-similarity 0.9 --standalone --json
+#[derive(Debug, Clone, Parser)]
+pub struct DriftArgs {
+    /// Limit the report to a file or directory (workspace-relative or absolute)
+    #[arg(long)]
+    pub path: Option<String>,
+
+    /// Minimum embedding similarity (0.0-1.0) for two symbols to be grouped
+    #[arg(long)]
+    pub min_similarity: Option<f32>,
+}
+
+// ---------------------------------------------------------------------------
+// deps (module dependency matrix)
+// ---------------------------------------------------------------------------
+
+/// Directory-to-directory dependency matrix for the most connected modules,
+/// with upward dependencies flagged. Pass `--layers` (highest layer first) to
+/// check a layered architecture; without it, the weaker direction of any two
+/// modules that depend on each other is reported.
+///
+/// Examples:
+///   julie-server deps --standalone
+///   julie-server deps --depth 1 --layers src/handler,src/tools,crates --standalone --format markdown
+#[derive(Debug, Clone, Parser)]
+pub struct DepsArgs {
+    /// Limit the report to a directory (workspace-relative or absolute)
+    #[arg(long)]
+    pub path: Option<String>,
+
+    /// Directory components below the path that name a module (default: 2)
+    #[arg(long)]
+    pub depth: Option<usize>,
+
+    /// Number of modules shown in the matrix (default: 10)
+    #[arg(long)]
+    pub top: Option<usize>,
+
+    /// Layer directories, comma-separated, highest layer first
+    #[arg(long, value_delimiter = ',')]
+    pub layers: Vec<String>,
+}
+
+// ---------------------------------------------------------------------------
+// check (CI quality gate)
+// ---------------------------------------------------------------------------
+
+/// CI quality gate: index the working tree and a base git ref, compare the
+/// selected analyses, and exit non-zero on regressions (new dead code, removed
+/// public API, more deprecated usages).
+///
+/// Examples:
+///   julie-server check --base origin/main
+///   julie-server check --base origin/main --only dead-code,api-surface --json
+#[derive(Debug, Clone, Parser)]
+pub struct CheckArgs {
+    /// Git ref to compare against (branch, tag or commit)
+    #[arg(long)]
+    pub base: String,
+
+    /// Checks to run, comma-separated: dead-code, api-surface,
+    /// deprecated-usage (default: all)
+    #[arg(long = "only", value_delimiter = ',')]
+    pub checks: Vec<crate::analysis::QualityCheck>,
+}
+
 // ---------------------------------------------------------------------------
 // tool (generic)
 // ---------------------------------------------------------------------------
@@ -3,17 +3,27 @@
 use std::collections::HashMap;
 use std::f64::consts::PI;
 
-use axum::extract::{Path, State};
+use axum::extract::{Path, Query, State};
 use axum::http::StatusCode;
 use axum::response::Html;
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 use tera::Context;
+use tracing::debug;
 
 use crate::dashboard::AppState;
 use crate::dashboard::render_template;
 use crate::database::SymbolDatabase;
 use crate::database::analytics::{AggregateStats, CentralitySymbol, FileHotspot};
 
+/// Longest git history window the hotspots table accepts.
+const MAX_CHURN_MONTHS: u32 = 120;
+
+#[derive(Deserialize)]
+pub struct IntelligenceParams {
+    /// Rank hotspots by complexity times commits over this many months.
+    pub churn_months: Option<u32>,
+}
+
 /// SVG donut chart circumference: 2 * pi * r where r = 0.7.
 const CIRCUMFERENCE: f64 = 2.0 * PI * 0.7;
 
@@ -241,12 +251,26 @@ pub(crate) fn open_workspace_db(
 pub async fn index(
     State(state): State<AppState>,
     Path(workspace_id): Path<String>,
+    Query(params): Query<IntelligenceParams>,
 ) -> Result<Html<String>, StatusCode> {
     let db = open_workspace_db(&state, &workspace_id)?;
 
+    let churn_months = params
+        .churn_months
+        .filter(|months| *months > 0)
+        .map(|months| months.min(MAX_CHURN_MONTHS));
+    let commit_counts = match churn_months {
+        Some(months) => workspace_commit_counts(&state, &workspace_id, months).await,
+        None => None,
+    };
+
     let (top_symbols, hotspots, stats, by_kind, lang_counts) = {
         let top_symbols = db.get_top_symbols_by_centrality(15).unwrap_or_default();
-        let hotspots = db.get_file_hotspots(10).unwrap_or_default();
+        let hotspots = match &commit_counts {
+            Some(counts) => db.get_file_hotspots_with_churn(10, counts),
+            None => db.get_file_hotspots(10),
+        }
+        .unwrap_or_default();
         let stats = db.get_aggregate_stats().unwrap_or_default();
         let (by_kind, _by_language) = db.get_symbol_statistics().unwrap_or_default();
         let lang_counts = db.count_files_by_language().unwrap_or_default();
@@ -275,15 +299,41 @@ pub async fn index(
     context.insert("donut_segments", &donut_segments);
     context.insert("index_duration", &index_duration_str);
 
-    let max_hotspot_score = hotspots
-        .first()
-        .map(|h| h.line_count as f64 + h.symbol_count as f64 * 10.0)
-        .unwrap_or(1.0);
+    let max_hotspot_score = hotspots.first().map(FileHotspot::risk_score).unwrap_or(1.0);
     context.insert("max_hotspot_score", &max_hotspot_score);
+    // Only claim churn ranking when git history was actually read.
+    let churn_months = churn_months.filter(|_| commit_counts.is_some());
+    context.insert("churn_months", &churn_months);
 
     render_template(&state, "intelligence.html", context).await
 }
 
+/// Commits per file over the last `months` months of the workspace's git
+/// history, or `None` when the workspace is not in a git repository.
+async fn workspace_commit_counts(
+    state: &AppState,
+    workspace_id: &str,
+    months: u32,
+) -> Option<HashMap<String, usize>> {
+    let workspace = state
+        .dashboard
+        .daemon_db()
+        .and_then(|daemon_db| daemon_db.get_workspace(workspace_id).ok().flatten())?;
+    let root = julie_core::paths::decode_stored_path(&workspace.path);
+    let counts = tokio::task::spawn_blocking(move || {
+        julie_core::git_history::commit_counts_since(&root, months)
+    })
+    .await
+    .ok()?;
+    match counts {
+        Ok(counts) => Some(counts),
+        Err(error) => {
+            debug!("No git churn for workspace {workspace_id}: {error:#}");
+            None
+        }
+    }
+}
+
 /// Lazy-loaded story cards partial for a workspace.
 pub async fn story_cards(
     State(state): State<AppState>,
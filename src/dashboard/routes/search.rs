@@ -162,7 +162,10 @@ async fn run_search(
             limit: limit as u32,
             context_lines: None,
             exclude_tests: None,
+            include_generated: false,
             backend: crate::tools::search::SearchBackend::resolve(None),
+            fuzzy: false,
+            language_scope: Default::default(),
         },
         &execution_workspaces,
         &handler,
@@ -6,6 +6,7 @@ pub use julie_pipeline::embeddings::*;
 // Re-export submodules so `crate::embeddings::factory::*` etc. remain valid
 pub use julie_pipeline::embeddings::factory;
 pub use julie_pipeline::embeddings::init;
+pub use julie_pipeline::embeddings::job;
 pub use julie_pipeline::embeddings::metadata;
 pub use julie_pipeline::embeddings::pipeline;
 #[cfg(feature = "embeddings-sidecar")]
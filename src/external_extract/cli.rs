@@ -3,6 +3,8 @@ use std::path::PathBuf;
 
 use clap::{ArgAction, Args, CommandFactory, Parser, Subcommand};
 
+use crate::indexing_core::shards::ShardSpec;
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct ExternalExtractArgs {
     pub db: PathBuf,
@@ -27,11 +29,17 @@ impl ExternalExtractArgs {
 
 impl ExternalExtractRawArgs {
     pub fn validate(self) -> Result<ExternalExtractArgs, clap::Error> {
-        let Some(db) = self.db else {
-            return Err(clap::Error::raw(
-                clap::error::ErrorKind::MissingRequiredArgument,
-                "the following required arguments were not provided: --db",
-            ));
+        // Shard workers and exports stream to stdout/--output and never open a
+        // database.
+        let db = match self.db {
+            Some(db) => db,
+            None if !self.command.requires_db() => PathBuf::new(),
+            None => {
+                return Err(clap::Error::raw(
+                    clap::error::ErrorKind::MissingRequiredArgument,
+                    "the following required arguments were not provided: --db",
+                ));
+            }
         };
 
         if self.command.requires_root() && self.root.is_none() {
@@ -110,6 +118,42 @@ pub enum ExternalExtractCommand {
     },
     Analyze,
     Info,
+    /// Distributed worker: extract one shard and stream it as JSON lines.
+    Shard {
+        /// Shard to extract, as INDEX/COUNT (zero-based), e.g. `2/8`.
+        #[arg(long)]
+        shard: ShardSpec,
+        /// Write the stream here instead of stdout.
+        #[arg(long)]
+        output: Option<PathBuf>,
+    },
+    /// Distributed coordinator: collect every shard and merge into --db.
+    Merge {
+        /// Spawn this many shard workers and merge their output.
+        #[arg(long, conflicts_with = "inputs")]
+        workers: Option<u32>,
+        /// Worker command, given after `--` one argument per word; `{shard}`,
+        /// `{index}` and `{count}` are substituted in each argument. Defaults
+        /// to running this binary locally.
+        #[arg(last = true, requires = "workers", value_name = "WORKER_COMMAND")]
+        worker_command: Vec<String>,
+        /// Merge previously captured shard streams instead of spawning workers.
+        #[arg(long = "input", required_unless_present = "workers")]
+        inputs: Vec<PathBuf>,
+    },
+    /// Export symbols, identifiers and relationships as JSON lines without
+    /// touching a database.
+    Export {
+        /// Only extract files of this language (repeatable).
+        #[arg(long = "language")]
+        languages: Vec<String>,
+        /// Only export symbols of this kind, e.g. `function` (repeatable).
+        #[arg(long = "kind")]
+        kinds: Vec<String>,
+        /// Write the export here instead of stdout.
+        #[arg(long)]
+        output: Option<PathBuf>,
+    },
 }
 
 impl ExternalExtractCommand {
@@ -120,10 +164,26 @@ impl ExternalExtractCommand {
             Self::Delete { .. } => "delete",
             Self::Analyze => "analyze",
             Self::Info => "info",
+            Self::Shard { .. } => "shard",
+            Self::Merge { .. } => "merge",
+            Self::Export { .. } => "export",
         }
     }
 
     fn requires_root(&self) -> bool {
         !matches!(self, Self::Analyze | Self::Info)
     }
+
+    fn requires_db(&self) -> bool {
+        !matches!(self, Self::Shard { .. } | Self::Export { .. })
+    }
+
+    /// Whether this command writes its primary output (a shard or export
+    /// stream) to stdout, so the report must go to stderr instead.
+    pub fn streams_to_stdout(&self) -> bool {
+        matches!(
+            self,
+            Self::Shard { output: None, .. } | Self::Export { output: None, .. }
+        )
+    }
 }
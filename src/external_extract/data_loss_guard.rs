@@ -10,6 +10,42 @@ pub(crate) fn ensure_batch_preserves_known_good_symbols(
     db: &SymbolDatabase,
     batch: &ExtractedBatch,
     records: &[ExtractedFileRecord],
+) -> Result<()> {
+    ensure_records_preserve_symbols(batch, records, |path| existing_symbol_count(db, path))
+}
+
+/// Symbol counts per file, read before a streamed rebuild deletes the rows
+/// the guard compares against.
+pub(crate) struct KnownSymbolCounts(HashMap<String, i64>);
+
+impl KnownSymbolCounts {
+    pub(crate) fn load(db: &SymbolDatabase) -> Result<Self> {
+        let mut stmt = db
+            .conn
+            .prepare("SELECT file_path, COUNT(*) FROM symbols GROUP BY file_path")?;
+        let counts = stmt
+            .query_map([], |row| Ok((row.get(0)?, row.get(1)?)))?
+            .collect::<rusqlite::Result<_>>()?;
+        Ok(Self(counts))
+    }
+
+    /// [`ensure_batch_preserves_known_good_symbols`] for one part of a
+    /// streamed rebuild.
+    pub(crate) fn ensure_batch_preserves(
+        &self,
+        batch: &ExtractedBatch,
+        records: &[ExtractedFileRecord],
+    ) -> Result<()> {
+        ensure_records_preserve_symbols(batch, records, |path| {
+            Ok(self.0.get(path).copied().unwrap_or(0))
+        })
+    }
+}
+
+fn ensure_records_preserve_symbols(
+    batch: &ExtractedBatch,
+    records: &[ExtractedFileRecord],
+    existing_symbol_count: impl Fn(&str) -> Result<i64>,
 ) -> Result<()> {
     let mut new_symbol_counts: HashMap<&str, usize> = HashMap::new();
     for symbol in &batch.all_symbols {
@@ -34,7 +70,7 @@ pub(crate) fn ensure_batch_preserves_known_good_symbols(
         {
             continue;
         }
-        let existing_symbols = existing_symbol_count(db, &record.relative_path)?;
+        let existing_symbols = existing_symbol_count(&record.relative_path)?;
         if existing_symbols == 0 {
             continue;
         }
@@ -43,7 +79,9 @@ pub(crate) fn ensure_batch_preserves_known_good_symbols(
                 format!("parser failed: {detail}")
             }
             ExtractedFileDisposition::Parsed => "parser returned zero symbols".to_string(),
-            ExtractedFileDisposition::TextOnly => unreachable!("text-only records are skipped"),
+            ExtractedFileDisposition::TextOnly | ExtractedFileDisposition::Skipped { .. } => {
+                unreachable!("text-only records are skipped")
+            }
         };
         return Err(anyhow!(
             "extraction for '{}' would remove existing symbols ({existing_symbols}); {detail}",
@@ -0,0 +1,399 @@
+//! Distributed extraction: `extract shard` workers and the `extract merge`
+//! coordinator.
+//!
+//! Workers only parse — they never open the database — so any number can run
+//! in parallel, locally or on other machines sharing the checkout. The
+//! coordinator persists each streamed chunk as it arrives, all in one
+//! force-rebuild transaction, then runs the same pending-relationship
+//! resolution and analysis as a single-process `scan --force`, so cross-shard
+//! references resolve exactly as they would have in one pass.
+
+use std::fs::File;
+use std::io::{BufRead, BufReader, BufWriter, Write};
+use std::path::{Path, PathBuf};
+use std::process::{Child, Command, Stdio};
+use std::sync::mpsc::{self, Receiver, SyncSender};
+use std::thread::JoinHandle;
+
+use anyhow::{Context, Result, anyhow, bail};
+
+use crate::external_extract::data_loss_guard::KnownSymbolCounts;
+use crate::external_extract::operations::{
+    ReportCounts, group_files_by_language, maybe_run_analysis, success_report,
+};
+use crate::external_extract::{
+    ExternalExtractArgs, ExternalExtractCommand, ExternalExtractReport, ExternalExtractStatus,
+    ensure_external_extract_metadata_with_root_policy, normalize_external_root,
+    open_external_extract_database_for_operation,
+};
+use crate::indexing_core::batch::ExtractedBatch;
+use crate::indexing_core::discovery::discover_external_files;
+use crate::indexing_core::extraction::{
+    ExtractedFileRecord, extract_files_for_indexing_with_records,
+};
+use crate::indexing_core::persistence::persist_force_rebuild_streamed;
+use crate::indexing_core::shards::{
+    DEFAULT_SHARD_CHUNK_FILES, ShardMerger, ShardSpec, ShardStreamReader, ShardStreamWriter,
+};
+use crate::tools::workspace::indexing::engine_version::SEMANTIC_INDEX_ENGINE_VERSION;
+use crate::tools::workspace::indexing::finalize::resolve_pending_relationships_in_db;
+
+/// Chunks queued between the stream readers and the database writer. A chunk
+/// holds at most [`DEFAULT_SHARD_CHUNK_FILES`] files, so this bounds what the
+/// coordinator holds however many workers run.
+const MERGE_QUEUE_CHUNKS: usize = 4;
+
+pub async fn run_external_shard(args: &ExternalExtractArgs) -> Result<ExternalExtractReport> {
+    let (shard, output) = match &args.command {
+        ExternalExtractCommand::Shard { shard, output } => (*shard, output.as_ref()),
+        _ => return Err(anyhow!("run_external_shard requires a shard command")),
+    };
+
+    let root_arg = args
+        .root
+        .as_ref()
+        .context("external shard requires a root path")?;
+    let root = normalize_external_root(root_arg)?;
+
+    let mut shard_files = Vec::new();
+    for file_path in discover_external_files(&root, &args.ignore_files)? {
+        let relative_path = crate::utils::paths::to_relative_unix_style(&file_path, &root)?;
+        if shard.contains(&relative_path) {
+            shard_files.push(file_path);
+        }
+    }
+    let files_scanned = shard_files.len() as u64;
+
+    let out: Box<dyn Write + Send> = match output {
+        Some(path) => Box::new(BufWriter::new(File::create(path).with_context(|| {
+            format!("failed to create shard output {}", path.display())
+        })?)),
+        None => Box::new(BufWriter::new(std::io::stdout())),
+    };
+    let mut writer =
+        ShardStreamWriter::start(out, shard, SEMANTIC_INDEX_ENGINE_VERSION, shard_files.len())?;
+    for chunk in shard_files.chunks(DEFAULT_SHARD_CHUNK_FILES) {
+        let (batch, records) =
+            extract_files_for_indexing_with_records(group_files_by_language(chunk.to_vec()), &root)
+                .await?;
+        writer.write_chunk(batch, records)?;
+    }
+    let (files_updated, symbols_extracted) = writer.finish()?;
+
+    success_report(
+        args,
+        ExternalExtractStatus::Scanned,
+        "shard",
+        Some(root),
+        None,
+        ReportCounts {
+            files_scanned,
+            files_updated: files_updated as u64,
+            files_deleted: 0,
+            symbols_extracted: symbols_extracted as u64,
+        },
+        None,
+    )
+}
+
+pub async fn run_external_merge(args: &ExternalExtractArgs) -> Result<ExternalExtractReport> {
+    let (workers, worker_command, inputs) = match &args.command {
+        ExternalExtractCommand::Merge {
+            workers,
+            worker_command,
+            inputs,
+        } => (*workers, worker_command, inputs),
+        _ => return Err(anyhow!("run_external_merge requires a merge command")),
+    };
+
+    let root_arg = args
+        .root
+        .as_ref()
+        .context("external merge requires a root path")?;
+    let root = normalize_external_root(root_arg)?;
+
+    let mut operation = open_external_extract_database_for_operation(&args.db, args.strict_schema)?;
+    let (workspace_id, has_metadata) = match args.workspace_id.clone() {
+        Some(requested_workspace_id) => (requested_workspace_id, false),
+        None => {
+            let metadata = ensure_external_extract_metadata_with_root_policy(
+                operation.db(),
+                &root,
+                None,
+                true,
+            )?;
+            (metadata.workspace_id.clone(), true)
+        }
+    };
+    let known_symbols = KnownSymbolCounts::load(operation.db())?;
+
+    let mut streams = match workers {
+        Some(count) => ShardStreams::spawn_workers(
+            &ShardSpec::all(count)?,
+            &root,
+            &args.ignore_files,
+            worker_command,
+        )?,
+        None => ShardStreams::open_inputs(inputs)?,
+    };
+
+    // Each chunk is written as it arrives; only the pending cross-file
+    // references are kept until every shard is in, so references between
+    // shards resolve exactly as in a single-process scan.
+    let mut pending = ExtractedBatch::new();
+    let mut counts = ReportCounts {
+        files_scanned: 0,
+        files_updated: 0,
+        files_deleted: 0,
+        symbols_extracted: 0,
+    };
+    let persisted = persist_force_rebuild_streamed(operation.db_mut(), &workspace_id, || {
+        let Some((mut batch, records)) = streams.next_chunk()? else {
+            return Ok(None);
+        };
+        known_symbols.ensure_batch_preserves(&batch, &records)?;
+        counts.files_scanned += records.len() as u64;
+        counts.files_updated += batch.files_processed as u64;
+        counts.symbols_extracted += batch.all_symbols.len() as u64;
+        pending
+            .all_pending_relationships
+            .append(&mut batch.all_pending_relationships);
+        pending
+            .all_structured_pending_relationships
+            .append(&mut batch.all_structured_pending_relationships);
+        Ok(Some(batch))
+    });
+    if let Err(error) = persisted {
+        streams.abort();
+        return Err(error);
+    }
+    resolve_pending_relationships_in_db(
+        operation.db_mut(),
+        &pending.all_pending_relationships,
+        &pending.all_structured_pending_relationships,
+    );
+
+    if !has_metadata {
+        ensure_external_extract_metadata_with_root_policy(
+            operation.db(),
+            &root,
+            Some(&workspace_id),
+            true,
+        )?;
+    }
+
+    maybe_run_analysis(operation.db_mut(), &workspace_id, args.analyze)?;
+
+    success_report(
+        args,
+        ExternalExtractStatus::Rebuilt,
+        "merge",
+        Some(root),
+        Some(workspace_id),
+        counts,
+        Some(operation.db()),
+    )
+}
+
+/// What a stream reader thread hands to the coordinator.
+enum StreamEvent {
+    Opened {
+        source: String,
+        shard: ShardSpec,
+    },
+    Chunk {
+        source: String,
+        batch: ExtractedBatch,
+        records: Vec<ExtractedFileRecord>,
+    },
+}
+
+/// Every shard stream of one merge. Each stream is read on its own thread and
+/// its chunks are queued for the coordinator, which persists them one at a
+/// time. The queue is bounded, so a slow database write stalls the readers
+/// (and, through their pipes, the workers) instead of buffering shards.
+struct ShardStreams {
+    events: Receiver<Result<StreamEvent>>,
+    sender: Option<SyncSender<Result<StreamEvent>>>,
+    readers: Vec<(String, JoinHandle<()>)>,
+    workers: Vec<(String, Child)>,
+    merger: ShardMerger,
+}
+
+impl ShardStreams {
+    fn new() -> Self {
+        let (sender, events) = mpsc::sync_channel(MERGE_QUEUE_CHUNKS);
+        Self {
+            events,
+            sender: Some(sender),
+            readers: Vec::new(),
+            workers: Vec::new(),
+            merger: ShardMerger::new(),
+        }
+    }
+
+    fn open_inputs(inputs: &[PathBuf]) -> Result<Self> {
+        let mut streams = Self::new();
+        for path in inputs {
+            let source = path.display().to_string();
+            let file = File::open(path)
+                .with_context(|| format!("failed to open shard stream {source}"))?;
+            streams.read(source, BufReader::new(file));
+        }
+        streams.sender = None;
+        Ok(streams)
+    }
+
+    /// Spawn one worker per shard and read their streams as they run.
+    fn spawn_workers(
+        shards: &[ShardSpec],
+        root: &Path,
+        ignore_files: &[PathBuf],
+        worker_command: &[String],
+    ) -> Result<Self> {
+        let mut streams = Self::new();
+        for shard in shards {
+            let mut command = build_worker_command(*shard, root, ignore_files, worker_command)?;
+            let source = format!("worker {shard}");
+            let spawned = command
+                .stdin(Stdio::null())
+                .stdout(Stdio::piped())
+                .stderr(Stdio::inherit())
+                .spawn()
+                .with_context(|| format!("failed to spawn {source}: {command:?}"));
+            let mut child = match spawned {
+                Ok(child) => child,
+                Err(error) => {
+                    streams.abort();
+                    return Err(error);
+                }
+            };
+            let stdout = child.stdout.take();
+            streams.workers.push((source.clone(), child));
+            let Some(stdout) = stdout else {
+                streams.abort();
+                bail!("{source}: stdout was not captured");
+            };
+            streams.read(source, BufReader::new(stdout));
+        }
+        streams.sender = None;
+        Ok(streams)
+    }
+
+    fn read<R: BufRead + Send + 'static>(&mut self, source: String, reader: R) {
+        let Some(sender) = self.sender.clone() else {
+            return;
+        };
+        let thread_source = source.clone();
+        let handle = std::thread::spawn(move || {
+            let source = thread_source;
+            let result = (|| {
+                let mut stream =
+                    ShardStreamReader::open(reader, SEMANTIC_INDEX_ENGINE_VERSION, &source)?;
+                let opened = StreamEvent::Opened {
+                    source: source.clone(),
+                    shard: stream.shard(),
+                };
+                if sender.send(Ok(opened)).is_err() {
+                    return Ok(());
+                }
+                while let Some((batch, records)) = stream.next_chunk()? {
+                    let chunk = StreamEvent::Chunk {
+                        source: source.clone(),
+                        batch,
+                        records,
+                    };
+                    if sender.send(Ok(chunk)).is_err() {
+                        // The coordinator gave up; nobody is listening.
+                        return Ok(());
+                    }
+                }
+                Ok(())
+            })();
+            if let Err(error) = result {
+                let _ = sender.send(Err(error));
+            }
+        });
+        self.readers.push((source, handle));
+    }
+
+    /// The next chunk, checked against the split. `None` once every stream
+    /// has ended, every shard is accounted for and every worker exited
+    /// cleanly; a worker that exits non-zero fails the merge even if its
+    /// stream looked complete.
+    fn next_chunk(&mut self) -> Result<Option<(ExtractedBatch, Vec<ExtractedFileRecord>)>> {
+        while let Ok(event) = self.events.recv() {
+            match event? {
+                StreamEvent::Opened { source, shard } => self.merger.add_shard(shard, &source)?,
+                StreamEvent::Chunk {
+                    source,
+                    batch,
+                    records,
+                } => {
+                    self.merger.claim_files(&batch, &source)?;
+                    return Ok(Some((batch, records)));
+                }
+            }
+        }
+
+        let mut failures = Vec::new();
+        for (source, reader) in self.readers.drain(..) {
+            if reader.join().is_err() {
+                failures.push(format!("{source}: stream reader panicked"));
+            }
+        }
+        for (source, mut child) in self.workers.drain(..) {
+            let status = child
+                .wait()
+                .with_context(|| format!("failed to wait for {source}"))?;
+            if !status.success() {
+                failures.push(format!("{source} exited with {status}"));
+            }
+        }
+        if !failures.is_empty() {
+            bail!("distributed extraction failed: {}", failures.join("; "));
+        }
+        self.merger.finish()?;
+        Ok(None)
+    }
+
+    /// Stop the workers of a failed merge. Their readers exit once the queue
+    /// is dropped with `self`.
+    fn abort(&mut self) {
+        for (_, mut child) in self.workers.drain(..) {
+            let _ = child.kill();
+            let _ = child.wait();
+        }
+    }
+}
+
+/// Build the command for one worker. Without a command the current binary is
+/// re-run locally. A command (e.g. `ssh build-{index} julie extract --root
+/// /src shard --shard {shard}`) is taken as argv, one argument per element,
+/// with `{shard}`, `{index}` and `{count}` substituted per worker.
+fn build_worker_command(
+    shard: ShardSpec,
+    root: &Path,
+    ignore_files: &[PathBuf],
+    worker_command: &[String],
+) -> Result<Command> {
+    if let Some((program, args)) = worker_command.split_first() {
+        let substitute = |arg: &String| {
+            arg.replace("{shard}", &shard.to_string())
+                .replace("{index}", &shard.index.to_string())
+                .replace("{count}", &shard.count.to_string())
+        };
+        let mut command = Command::new(substitute(program));
+        command.args(args.iter().map(substitute));
+        return Ok(command);
+    }
+
+    let exe = std::env::current_exe().context("failed to locate the julie executable")?;
+    let mut command = Command::new(exe);
+    command.arg("extract").arg("--root").arg(root);
+    for ignore_file in ignore_files {
+        command.arg("--ignore-file").arg(ignore_file);
+    }
+    command.arg("shard").arg("--shard").arg(shard.to_string());
+    Ok(command)
+}
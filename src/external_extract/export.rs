@@ -0,0 +1,81 @@
+//! `extract export`: stream a directory's symbols, identifiers and
+//! relationships as JSON lines for external analytics pipelines.
+//!
+//! Like a shard worker, an export only parses — it never opens the database —
+//! so it can run against any checkout. Files are extracted in parallel, in
+//! bounded chunks, and written as they complete.
+
+use std::fs::File;
+use std::io::{BufWriter, Write};
+
+use anyhow::{Context, Result, anyhow};
+
+use crate::external_extract::operations::{ReportCounts, group_files_by_language, success_report};
+use crate::external_extract::{
+    ExternalExtractArgs, ExternalExtractCommand, ExternalExtractReport, ExternalExtractStatus,
+    normalize_external_root,
+};
+use crate::indexing_core::discovery::discover_external_files;
+use crate::indexing_core::export::{ExportFilter, ExportStreamWriter};
+use crate::indexing_core::extraction::extract_files_for_indexing_with_records;
+use crate::indexing_core::shards::DEFAULT_SHARD_CHUNK_FILES;
+
+pub async fn run_external_export(args: &ExternalExtractArgs) -> Result<ExternalExtractReport> {
+    let (languages, kinds, output) = match &args.command {
+        ExternalExtractCommand::Export {
+            languages,
+            kinds,
+            output,
+        } => (languages, kinds, output.as_ref()),
+        _ => return Err(anyhow!("run_external_export requires an export command")),
+    };
+
+    let root_arg = args
+        .root
+        .as_ref()
+        .context("external export requires a root path")?;
+    let root = normalize_external_root(root_arg)?;
+
+    let mut files_by_language =
+        group_files_by_language(discover_external_files(&root, &args.ignore_files)?);
+    if !languages.is_empty() {
+        let languages: Vec<String> = languages
+            .iter()
+            .map(|language| language.trim().to_ascii_lowercase())
+            .collect();
+        files_by_language.retain(|language, _| languages.contains(language));
+    }
+    let mut files: Vec<_> = files_by_language.into_values().flatten().collect();
+    files.sort();
+    let files_scanned = files.len() as u64;
+
+    let out: Box<dyn Write + Send> = match output {
+        Some(path) => Box::new(BufWriter::new(File::create(path).with_context(|| {
+            format!("failed to create export output {}", path.display())
+        })?)),
+        None => Box::new(BufWriter::new(std::io::stdout())),
+    };
+    let mut writer = ExportStreamWriter::new(out, ExportFilter::new(kinds));
+    for chunk in files.chunks(DEFAULT_SHARD_CHUNK_FILES) {
+        let (batch, _records) =
+            extract_files_for_indexing_with_records(group_files_by_language(chunk.to_vec()), &root)
+                .await?;
+        writer.write_batch(&batch)?;
+    }
+    let counts = writer.finish()?;
+
+    success_report(
+        args,
+        ExternalExtractStatus::Scanned,
+        "export",
+        Some(root),
+        None,
+        ReportCounts {
+            files_scanned,
+            files_updated: counts.files_processed as u64,
+            files_deleted: 0,
+            symbols_extracted: counts.symbols as u64,
+        },
+        None,
+    )
+}
@@ -92,8 +92,10 @@ fn open_read_only(db_path: &Path) -> Result<Connection> {
     let flags = OpenFlags::SQLITE_OPEN_READ_ONLY
         | OpenFlags::SQLITE_OPEN_URI
         | OpenFlags::SQLITE_OPEN_NO_MUTEX;
-    Connection::open_with_flags(db_path, flags)
-        .map_err(|error| anyhow!("failed to open database read-only: {error}"))
+    let conn = Connection::open_with_flags(db_path, flags)
+        .map_err(|error| anyhow!("failed to open database read-only: {error}"))?;
+    julie_core::database::encryption::apply_index_key(&conn)?;
+    Ok(conn)
 }
 
 fn read_schema_version_from_conn(conn: &Connection) -> Result<Option<i32>> {
@@ -1,5 +1,7 @@
 pub mod cli;
 mod data_loss_guard;
+mod distributed;
+mod export;
 pub mod info;
 pub mod lock;
 pub mod metadata;
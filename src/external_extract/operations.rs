@@ -5,6 +5,8 @@ use anyhow::{Context, Result, anyhow};
 
 use crate::database::{SymbolDatabase, calculate_file_hash};
 use crate::external_extract::data_loss_guard::ensure_batch_preserves_known_good_symbols;
+use crate::external_extract::distributed::{run_external_merge, run_external_shard};
+use crate::external_extract::export::run_external_export;
 use crate::external_extract::metadata::EXTRACT_HASH_ALGORITHM;
 use crate::external_extract::{
     EXTRACT_CONTRACT_VERSION, ExternalExtractArgs, ExternalExtractCommand, ExternalExtractReport,
@@ -30,6 +32,9 @@ pub async fn run_external_extract(args: &ExternalExtractArgs) -> Result<External
         ExternalExtractCommand::Delete { .. } => run_external_delete(args).await,
         ExternalExtractCommand::Analyze => run_external_analyze(args).await,
         ExternalExtractCommand::Info => run_external_info(args),
+        ExternalExtractCommand::Shard { .. } => run_external_shard(args).await,
+        ExternalExtractCommand::Merge { .. } => run_external_merge(args).await,
+        ExternalExtractCommand::Export { .. } => run_external_export(args).await,
     }
 }
 
@@ -375,7 +380,7 @@ fn filter_scan_delta(
     Ok((files_to_extract, orphaned_files))
 }
 
-fn group_files_by_language(files: Vec<PathBuf>) -> HashMap<String, Vec<PathBuf>> {
+pub(crate) fn group_files_by_language(files: Vec<PathBuf>) -> HashMap<String, Vec<PathBuf>> {
     let mut files_by_language: HashMap<String, Vec<PathBuf>> = HashMap::new();
     for file_path in files {
         files_by_language
@@ -386,7 +391,11 @@ fn group_files_by_language(files: Vec<PathBuf>) -> HashMap<String, Vec<PathBuf>>
     files_by_language
 }
 
-fn maybe_run_analysis(db: &mut SymbolDatabase, workspace_id: &str, analyze: bool) -> Result<()> {
+pub(crate) fn maybe_run_analysis(
+    db: &mut SymbolDatabase,
+    workspace_id: &str,
+    analyze: bool,
+) -> Result<()> {
     if analyze {
         run_and_mark_analysis_current(db, workspace_id)?;
     }
@@ -400,14 +409,14 @@ fn run_and_mark_analysis_current(db: &mut SymbolDatabase, workspace_id: &str) ->
     Ok(())
 }
 
-struct ReportCounts {
-    files_scanned: u64,
-    files_updated: u64,
-    files_deleted: u64,
-    symbols_extracted: u64,
+pub(crate) struct ReportCounts {
+    pub(crate) files_scanned: u64,
+    pub(crate) files_updated: u64,
+    pub(crate) files_deleted: u64,
+    pub(crate) symbols_extracted: u64,
 }
 
-fn success_report(
+pub(crate) fn success_report(
     args: &ExternalExtractArgs,
     status: ExternalExtractStatus,
     operation: &str,
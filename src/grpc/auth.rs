@@ -0,0 +1,81 @@
+//! Shared-token authentication for the gRPC API.
+//!
+//! The token comes from `JULIE_GRPC_TOKEN` rather than a flag, so it never
+//! shows up in process listings. Every call must carry it as
+//! `authorization: Bearer <token>`.
+
+use std::sync::Arc;
+
+use anyhow::Result;
+use tonic::service::Interceptor;
+use tonic::{Request, Status};
+
+/// Environment variable holding the shared token.
+pub const TOKEN_ENV: &str = "JULIE_GRPC_TOKEN";
+
+/// Shorter tokens are refused at startup.
+const MIN_TOKEN_LEN: usize = 16;
+
+/// Read and validate the shared token from the environment.
+pub fn token_from_env() -> Result<String> {
+    let token = std::env::var(TOKEN_ENV).unwrap_or_default();
+    validate_token(token.trim())
+}
+
+/// The trimmed token, or why it can't be used.
+pub fn validate_token(token: &str) -> Result<String> {
+    if token.is_empty() {
+        anyhow::bail!(
+            "Set {TOKEN_ENV} to the token gRPC clients must send before starting the server"
+        );
+    }
+    if token.len() < MIN_TOKEN_LEN {
+        anyhow::bail!("{TOKEN_ENV} must be at least {MIN_TOKEN_LEN} characters");
+    }
+    Ok(token.to_string())
+}
+
+/// Rejects calls whose bearer token doesn't match the server's.
+#[derive(Clone)]
+pub struct TokenAuth {
+    token: Arc<str>,
+}
+
+impl TokenAuth {
+    pub fn new(token: impl Into<Arc<str>>) -> Self {
+        Self {
+            token: token.into(),
+        }
+    }
+}
+
+impl Interceptor for TokenAuth {
+    fn call(&mut self, request: Request<()>) -> Result<Request<()>, Status> {
+        let header = request
+            .metadata()
+            .get("authorization")
+            .and_then(|value| value.to_str().ok());
+        if bearer_matches(header, &self.token) {
+            Ok(request)
+        } else {
+            Err(Status::unauthenticated("missing or invalid bearer token"))
+        }
+    }
+}
+
+/// Whether an `authorization` header carries `Bearer <token>`.
+pub fn bearer_matches(header: Option<&str>, token: &str) -> bool {
+    let Some((scheme, presented)) = header.and_then(|header| header.trim().split_once(' ')) else {
+        return false;
+    };
+    scheme.eq_ignore_ascii_case("bearer") && constant_time_eq(presented.trim(), token)
+}
+
+/// Compare without returning early on the first differing byte.
+fn constant_time_eq(a: &str, b: &str) -> bool {
+    a.len() == b.len()
+        && a.bytes()
+            .zip(b.bytes())
+            .fold(0u8, |diff, (x, y)| diff | (x ^ y))
+            == 0
+}
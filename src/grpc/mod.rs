@@ -0,0 +1,174 @@
+//! Optional gRPC API for integrations that don't speak MCP (`--features grpc`).
+//!
+//! `julie-server grpc` indexes the workspace like the CLI tool commands, keeps
+//! it current with the file watcher, and serves `proto/julie.proto`. Each RPC
+//! runs the same tool struct as its MCP tool against one shared handler, and
+//! replies with the tool's text and structured content.
+
+pub mod auth;
+
+pub mod proto {
+    tonic::include_proto!("julie.v1");
+}
+
+use std::net::SocketAddr;
+use std::path::PathBuf;
+
+use anyhow::{Context, Result};
+use serde_json::{Value, json};
+use tonic::{Request, Response, Status};
+
+use crate::cli::resolve_workspace_root;
+use crate::cli_tools::bootstrap_standalone_handler;
+use crate::handler::JulieServerHandler;
+use crate::mcp_compat::CallToolResult;
+use crate::tools::search::{FastSearchParams, FastSearchTool};
+use crate::tools::{DeepDiveTool, FastRefsTool, GetSymbolsTool};
+use auth::TokenAuth;
+use proto::julie_server::{Julie, JulieServer};
+use proto::{GotoRequest, OutlineRequest, RefsRequest, SearchRequest, ToolReply};
+
+/// Default listen address: loopback only, so exposing the API is a choice.
+pub const DEFAULT_LISTEN: &str = "127.0.0.1:50051";
+
+/// Serve the gRPC API for a workspace until Ctrl-C.
+pub async fn serve_grpc(listen: SocketAddr, cli_workspace: Option<PathBuf>) -> Result<()> {
+    let token = auth::token_from_env()?;
+    let workspace_root = resolve_workspace_root(cli_workspace);
+    let handler = bootstrap_standalone_handler(&workspace_root).await?;
+
+    eprintln!(
+        "julie: gRPC API for {} listening on {}",
+        workspace_root.display(),
+        listen
+    );
+    tonic::transport::Server::builder()
+        .add_service(JulieServer::with_interceptor(
+            JulieGrpc::new(handler),
+            TokenAuth::new(token),
+        ))
+        .serve_with_shutdown(listen, async {
+            let _ = tokio::signal::ctrl_c().await;
+        })
+        .await
+        .context("gRPC server failed")
+}
+
+/// The `Julie` service, backed by one handler.
+pub struct JulieGrpc {
+    handler: JulieServerHandler,
+}
+
+impl JulieGrpc {
+    pub fn new(handler: JulieServerHandler) -> Self {
+        Self { handler }
+    }
+}
+
+#[tonic::async_trait]
+impl Julie for JulieGrpc {
+    async fn search(&self, request: Request<SearchRequest>) -> Result<Response<ToolReply>, Status> {
+        let request = request.into_inner();
+        require("query", &request.query)?;
+        let mut search = FastSearchTool {
+            query: request.query,
+            language: request.language,
+            file_pattern: request.file_pattern,
+            project: request.project,
+            framework: request.framework,
+            ..Default::default()
+        };
+        if request.limit > 0 {
+            search.limit = request.limit;
+        }
+        let tool = FastSearchParams {
+            search,
+            regions: None,
+            mode: None,
+        };
+        reply(tool.call_tool(&self.handler).await)
+    }
+
+    async fn goto(&self, request: Request<GotoRequest>) -> Result<Response<ToolReply>, Status> {
+        let request = request.into_inner();
+        require("symbol", &request.symbol)?;
+        let tool: DeepDiveTool = from_params(json!({
+            "symbol": request.symbol,
+            "depth": "overview",
+            "context_file": request.context_file,
+            "disambiguator": request.disambiguator,
+            "project": request.project,
+        }))?;
+        reply(tool.call_tool(&self.handler).await)
+    }
+
+    async fn refs(&self, request: Request<RefsRequest>) -> Result<Response<ToolReply>, Status> {
+        let request = request.into_inner();
+        require("symbol", &request.symbol)?;
+        let mut params = json!({
+            "symbol": request.symbol,
+            "include_definition": request.include_definition,
+            "reference_kind": request.reference_kind,
+            "project": request.project,
+        });
+        if request.limit > 0 {
+            params["limit"] = json!(request.limit);
+        }
+        let tool: FastRefsTool = from_params(params)?;
+        reply(tool.call_tool(&self.handler).await)
+    }
+
+    async fn outline(
+        &self,
+        request: Request<OutlineRequest>,
+    ) -> Result<Response<ToolReply>, Status> {
+        let request = request.into_inner();
+        require("file_path", &request.file_path)?;
+        let mut params = json!({
+            "file_path": request.file_path,
+            "target": request.target,
+        });
+        if let Some(max_depth) = request.max_depth {
+            params["max_depth"] = json!(max_depth);
+        }
+        let tool: GetSymbolsTool = from_params(params)?;
+        reply(tool.call_tool(&self.handler).await)
+    }
+}
+
+fn require(field: &str, value: &str) -> Result<(), Status> {
+    if value.trim().is_empty() {
+        return Err(Status::invalid_argument(format!("{field} is required")));
+    }
+    Ok(())
+}
+
+/// Build a tool from JSON params so omitted fields take the tool's defaults.
+fn from_params<T: serde::de::DeserializeOwned>(params: Value) -> Result<T, Status> {
+    serde_json::from_value(params).map_err(|e| Status::invalid_argument(e.to_string()))
+}
+
+fn reply(result: Result<CallToolResult>) -> Result<Response<ToolReply>, Status> {
+    result
+        .map(|result| Response::new(tool_reply(result)))
+        .map_err(|e| Status::internal(format!("{e:#}")))
+}
+
+/// Flatten a tool result into the reply message.
+pub fn tool_reply(result: CallToolResult) -> ToolReply {
+    let text = result
+        .content
+        .iter()
+        .filter_map(|content| content.as_text())
+        .map(|text| text.text.as_str())
+        .collect::<Vec<_>>()
+        .join("\n");
+    ToolReply {
+        text,
+        structured_json: result
+            .structured_content
+            .map(|structured| structured.to_string())
+            .unwrap_or_default(),
+        is_error: result.is_error.unwrap_or(false),
+    }
+}
@@ -1,8 +1,18 @@
 #[path = "handler/embedding_init.rs"]
 pub(crate) mod embedding_init;
+#[path = "handler/progress.rs"]
+pub(crate) mod progress;
+#[path = "handler/query_cache.rs"]
+pub(crate) mod query_cache;
+#[path = "handler/ref_db_cache.rs"]
+pub(crate) mod ref_db_cache;
+#[path = "handler/resources.rs"]
+pub(crate) mod resources;
 #[path = "handler/search_telemetry.rs"]
 pub(crate) mod search_telemetry;
 pub mod session_workspace;
+#[path = "handler/tool_admission.rs"]
+pub(crate) mod tool_admission;
 #[path = "handler/tool_context_impl.rs"]
 mod tool_context_impl;
 #[path = "handler/tool_metrics.rs"]
@@ -13,7 +23,7 @@ pub(crate) mod tools;
 #[path = "handler/workspace_resolution.rs"]
 pub(crate) mod workspace_resolution;
 
-use std::collections::{HashMap, HashSet};
+use std::collections::HashSet;
 
 use anyhow::Result;
 use rmcp::{
@@ -21,7 +31,8 @@ use rmcp::{
     handler::server::tool::{ToolCallContext, ToolRouter},
     model::{
         CallToolRequestParams, CallToolResult, Implementation, InitializeRequestParams,
-        ListToolsResult, ServerCapabilities, ServerInfo, Tool,
+        ListResourceTemplatesResult, ListResourcesResult, ListToolsResult, PaginatedRequestParams,
+        ReadResourceRequestParams, ReadResourceResult, ServerCapabilities, ServerInfo, Tool,
     },
     service::{NotificationContext, Peer, RequestContext},
 };
@@ -46,6 +57,7 @@ use crate::workspace::startup_hint::WorkspaceStartupSource;
 use tokio::sync::RwLock;
 
 use self::tool_metrics::{MetricsTask, run_metrics_writer};
+use self::tools::error::{ToolErrorCode, coded_internal_error, tool_error_code, with_error_code};
 use crate::tools::metrics::session::{SessionMetrics, extract_source_paths};
 use crate::tools::workspace::commands::ManageWorkspaceOperation;
 
@@ -211,6 +223,10 @@ pub struct JulieServerHandler {
     pub session_metrics: Arc<SessionMetrics>,
     /// In-memory spillover pages for graph-heavy tool outputs.
     pub(crate) spillover_store: Arc<crate::tools::spillover::store::SpilloverStore>,
+    /// LRU cache of read-only tool results, invalidated by index generation.
+    pub(crate) query_cache: Arc<query_cache::QueryCache>,
+    /// Per-session concurrency cap for tool calls. None when disabled.
+    pub(crate) tool_admission: Option<Arc<tool_admission::ToolAdmission>>,
     /// Per-workspace embedding pipeline: cancellation flag + task handle.
     /// Keyed by workspace_id so concurrent workspaces don't cancel each other.
     pub(crate) embedding_tasks: Arc<
@@ -269,8 +285,13 @@ pub struct JulieServerHandler {
     metrics_tx: tokio::sync::mpsc::Sender<MetricsTask>,
     /// Cache for non-primary workspace DB connections, keyed by workspace_id with
     /// the resolved physical db path so root-anchor changes in stdio do not reuse
-    /// stale handles across different `.julie/indexes/...` trees.
-    ref_db_cache: Arc<RwLock<HashMap<String, (PathBuf, Arc<std::sync::Mutex<SymbolDatabase>>)>>>,
+    /// stale handles across different `.julie/indexes/...` trees. Idle entries
+    /// are closed, also by a background sweep (see `ref_db_cache`).
+    ref_db_cache: Arc<RwLock<ref_db_cache::RefDbCache>>,
+    /// Same cache for Tantivy indexes opened through
+    /// `get_search_index_for_workspace`, keyed by the index directory.
+    /// Evicting an entry releases its readers and segment mmaps.
+    ref_index_cache: Arc<RwLock<ref_db_cache::RefIndexCache>>,
     /// Broadcast sender for dashboard live-feed events. None in stdio/test mode.
     dashboard_tx: Option<broadcast::Sender<DashboardEvent>>,
     /// Mutation-gate registry used by workspace writer paths in this handler.
@@ -730,11 +751,15 @@ impl JulieServerHandler {
 
         match tool_name {
             "fast_search" | "fast_refs" | "call_path" | "get_symbols" | "deep_dive"
-            | "get_context" | "blast_radius" | "rename_symbol" | "edit_file" => {
-                workspace_is_primary
-            }
+            | "get_context" | "blast_radius" | "fast_audit" | "syntax_query" | "rename_symbol"
+            | "edit_file" => workspace_is_primary,
             "manage_workspace" => Self::manage_workspace_request_targets_primary(arguments),
-            "rewrite_symbol" => workspace_is_primary,
+            "rewrite_symbol"
+            | "get_file_annotations"
+            | "fast_imports"
+            | "fast_counterparts"
+            | "fast_signature"
+            | "get_context_pack" => workspace_is_primary,
             _ => false,
         }
     }
@@ -796,6 +821,8 @@ impl JulieServerHandler {
             indexing_status: Arc::new(IndexingStatus::new()),
             session_metrics: Arc::new(SessionMetrics::new()),
             spillover_store: Arc::new(crate::tools::spillover::store::SpilloverStore::default()),
+            query_cache: Arc::new(query_cache::QueryCache::default()),
+            tool_admission: tool_admission::ToolAdmission::from_env().map(Arc::new),
             embedding_tasks: Arc::new(tokio::sync::Mutex::new(std::collections::HashMap::new())),
             tool_router: Self::tool_router(),
             project_log: None,
@@ -809,7 +836,8 @@ impl JulieServerHandler {
             suppress_workspace_file_writes: Arc::new(AtomicBool::new(false)),
             session_lifecycle: None,
             metrics_tx,
-            ref_db_cache: Arc::new(RwLock::new(HashMap::new())),
+            ref_db_cache: ref_db_cache::session_workspace_cache(),
+            ref_index_cache: ref_db_cache::session_workspace_cache(),
             dashboard_tx: None,
             mutation_gate_registry: Arc::clone(MutationGateRegistry::global()),
             leadership: Arc::new(LeadershipState::none()),
@@ -906,6 +934,8 @@ impl JulieServerHandler {
             indexing_status: Arc::new(IndexingStatus::new()),
             session_metrics: Arc::new(SessionMetrics::new()),
             spillover_store: Arc::new(crate::tools::spillover::store::SpilloverStore::default()),
+            query_cache: Arc::new(query_cache::QueryCache::default()),
+            tool_admission: tool_admission::ToolAdmission::from_env().map(Arc::new),
             embedding_tasks: Arc::new(tokio::sync::Mutex::new(std::collections::HashMap::new())),
             tool_router: Self::tool_router(),
             project_log,
@@ -919,7 +949,8 @@ impl JulieServerHandler {
             suppress_workspace_file_writes: Arc::new(AtomicBool::new(false)),
             session_lifecycle: None,
             metrics_tx,
-            ref_db_cache: Arc::new(RwLock::new(HashMap::new())),
+            ref_db_cache: ref_db_cache::session_workspace_cache(),
+            ref_index_cache: ref_db_cache::session_workspace_cache(),
             dashboard_tx,
             mutation_gate_registry: Arc::clone(MutationGateRegistry::global()),
             leadership: Arc::new(LeadershipState::none()),
@@ -996,6 +1027,8 @@ impl JulieServerHandler {
             indexing_status: Arc::new(IndexingStatus::new()),
             session_metrics: Arc::new(SessionMetrics::new()),
             spillover_store: Arc::new(crate::tools::spillover::store::SpilloverStore::default()),
+            query_cache: Arc::new(query_cache::QueryCache::default()),
+            tool_admission: tool_admission::ToolAdmission::from_env().map(Arc::new),
             embedding_tasks: Arc::new(tokio::sync::Mutex::new(std::collections::HashMap::new())),
             tool_router: Self::tool_router(),
             project_log: if enable_project_writes {
@@ -1015,7 +1048,8 @@ impl JulieServerHandler {
             suppress_workspace_file_writes: Arc::new(AtomicBool::new(!enable_project_writes)),
             session_lifecycle: None,
             metrics_tx,
-            ref_db_cache: Arc::new(RwLock::new(HashMap::new())),
+            ref_db_cache: ref_db_cache::session_workspace_cache(),
+            ref_index_cache: ref_db_cache::session_workspace_cache(),
             dashboard_tx,
             mutation_gate_registry: Arc::clone(MutationGateRegistry::global()),
             leadership: Arc::new(LeadershipState::none()),
@@ -1485,6 +1519,10 @@ impl JulieServerHandler {
     pub(crate) async fn embedding_provider(
         &self,
     ) -> Option<Arc<dyn crate::embeddings::EmbeddingProvider>> {
+        // `semantic.mode = "disabled"` wins over every shared provider.
+        if self.semantic_disabled() {
+            return None;
+        }
         // In-process mode: injected provider takes priority.
         if let Some(ref p) = self.injected_embedding_provider {
             return Some(Arc::clone(p));
@@ -1502,6 +1540,16 @@ impl JulieServerHandler {
     pub(crate) async fn embedding_runtime_status(
         &self,
     ) -> Option<crate::embeddings::EmbeddingRuntimeStatus> {
+        if self.semantic_disabled() {
+            return Some(crate::embeddings::EmbeddingRuntimeStatus {
+                requested_backend: crate::embeddings::EmbeddingBackend::Unresolved,
+                resolved_backend: crate::embeddings::EmbeddingBackend::Unresolved,
+                accelerated: false,
+                degraded_reason: Some(
+                    julie_core::workspace_settings::SEMANTIC_DISABLED_REASON.to_string(),
+                ),
+            });
+        }
         if let Some(ref service) = self.embedding_service {
             return service.runtime_status();
         }
@@ -1510,6 +1558,14 @@ impl JulieServerHandler {
             .and_then(|ws| ws.embedding_runtime_status.clone())
     }
 
+    /// True when the current workspace's `.julie/config.toml` sets
+    /// `semantic.mode = "disabled"`.
+    pub(crate) fn semantic_disabled(&self) -> bool {
+        julie_core::workspace_settings::WorkspaceSettingsRegistry::global()
+            .get(&self.current_workspace_root())
+            .semantic_disabled()
+    }
+
     /// Mark embedding initialization as skipped for standalone (single-shot CLI) mode.
     ///
     /// Standalone mode is a single-shot CLI invocation. Launching the Python
@@ -1932,9 +1988,10 @@ impl JulieServerHandler {
     async fn run_auto_indexing(&self) {
         use crate::startup::run_primary_workspace_repair;
 
-        // Concurrent catch-up calls serialize through mutation_gate::acquire_gate(workspace_id)
-        // inside run_primary_workspace_repair. Redundant invocations are cheap because
-        // filter_changed_files short-circuits when nothing has changed.
+        // Concurrent catch-up calls for the same root share one in-flight repair
+        // inside run_primary_workspace_repair; later ones serialize through
+        // mutation_gate::acquire_gate(workspace_id). Redundant invocations are cheap
+        // because filter_changed_files short-circuits when nothing has changed.
         info!("🔍 Starting background auto-indexing check...");
 
         match run_primary_workspace_repair(self).await {
@@ -2003,13 +2060,11 @@ impl JulieServerHandler {
             ));
         }
 
-        let database = {
-            let cache = self.ref_db_cache.read().await;
-            cache
-                .get(&binding.workspace_id)
-                .filter(|(cached_path, _)| *cached_path == db_path)
-                .map(|(_, db)| Arc::clone(db))
-        };
+        let database = self.ref_db_cache.write().await.get(
+            &binding.workspace_id,
+            &db_path,
+            std::time::Instant::now(),
+        );
 
         let database = if let Some(database) = database {
             database
@@ -2021,10 +2076,11 @@ impl JulieServerHandler {
             })
             .await??;
 
-            let mut cache = self.ref_db_cache.write().await;
-            cache.insert(
-                binding.workspace_id.clone(),
-                (db_path.clone(), Arc::clone(&database)),
+            self.ref_db_cache.write().await.insert(
+                &binding.workspace_id,
+                db_path.clone(),
+                Arc::clone(&database),
+                std::time::Instant::now(),
             );
             database
         };
@@ -2032,7 +2088,7 @@ impl JulieServerHandler {
         let tantivy_path = self
             .workspace_tantivy_dir_for(&binding.workspace_id)
             .await?;
-        let search_index = if tantivy_path.join("meta.json").exists() {
+        let search_index = if SearchIndex::exists(&tantivy_path) {
             let workspace_id = binding.workspace_id.clone();
             let database_for_projection = Arc::clone(&database);
             let indexing_status = Arc::clone(&self.indexing_status);
@@ -2382,13 +2438,13 @@ impl JulieServerHandler {
         let db_path = self.workspace_db_file_path_for(workspace_id).await?;
 
         // Fast path: return cached connection for this session (M22).
-        {
-            let cache = self.ref_db_cache.read().await;
-            if let Some((cached_path, db)) = cache.get(workspace_id) {
-                if *cached_path == db_path {
-                    return Ok(Arc::clone(db));
-                }
-            }
+        let cached =
+            self.ref_db_cache
+                .write()
+                .await
+                .get(workspace_id, &db_path, std::time::Instant::now());
+        if let Some(db) = cached {
+            return Ok(db);
         }
 
         // In daemon mode, index_root_override points to ~/.julie/indexes/{primary_id}.
@@ -2409,10 +2465,12 @@ impl JulieServerHandler {
         .await??;
 
         // Populate cache for subsequent calls within this session.
-        {
-            let mut cache = self.ref_db_cache.write().await;
-            cache.insert(workspace_id.to_string(), (db_path.clone(), Arc::clone(&db)));
-        }
+        self.ref_db_cache.write().await.insert(
+            workspace_id,
+            db_path.clone(),
+            Arc::clone(&db),
+            std::time::Instant::now(),
+        );
 
         Ok(db)
     }
@@ -2421,7 +2479,8 @@ impl JulieServerHandler {
     ///
     /// In stdio mode: looks in `{project}/.julie/indexes/{workspace_id}/tantivy/`.
     /// In daemon mode: looks in `~/.julie/indexes/{workspace_id}/tantivy/`.
-    /// Returns `Ok(None)` if the index directory doesn't exist yet.
+    /// Returns `Ok(None)` if the index directory doesn't exist yet. The opened
+    /// index is cached for this session until it goes idle (see `ref_db_cache`).
     pub async fn get_search_index_for_workspace(
         &self,
         workspace_id: &str,
@@ -2429,10 +2488,19 @@ impl JulieServerHandler {
         self.ensure_primary_pool_membership_for(workspace_id)
             .await?;
         let tantivy_path = self.workspace_tantivy_dir_for(workspace_id).await?;
-        if !tantivy_path.join("meta.json").exists() {
+        if !SearchIndex::exists(&tantivy_path) {
             return Ok(None);
         }
 
+        let cached = self.ref_index_cache.write().await.get(
+            workspace_id,
+            &tantivy_path,
+            std::time::Instant::now(),
+        );
+        if let Some(index) = cached {
+            return Ok(Some(index));
+        }
+
         let db_path = self.workspace_db_file_path_for(workspace_id).await?;
 
         // T7 single-writer gate (codex 3c.3 pre-merge): recreated-open repair is a
@@ -2440,8 +2508,11 @@ impl JulieServerHandler {
         // rebuild; the follower serves the (empty) opened index read-only and picks
         // up the leader's rebuild via the poll-reload.
         let may_repair = self.may_repair_recreated_projection();
-        let workspace_id = workspace_id.to_string();
-        tokio::task::spawn_blocking(move || {
+        let workspace_id_for_open = workspace_id.to_string();
+        let tantivy_path_for_open = tantivy_path.clone();
+        let index = tokio::task::spawn_blocking(move || {
+            let workspace_id = workspace_id_for_open;
+            let tantivy_path = tantivy_path_for_open;
             let configs = crate::search::LanguageConfigs::load_embedded();
             let open_outcome =
                 SearchIndex::open_with_language_configs_outcome(&tantivy_path, &configs)?;
@@ -2458,6 +2529,9 @@ impl JulieServerHandler {
                 let mut db = SymbolDatabase::new(&db_path)?;
                 let projection = SearchProjection::tantivy(workspace_id.clone());
                 projection.repair_recreated_open_if_needed(&mut db, &index, repair_required, None)?;
+                // The index stays cached for reads; do not keep the writer
+                // lock the leader needs for later updates.
+                index.release_writer()?;
             } else if repair_required {
                 debug!(
                     "Tantivy index for workspace '{}' at {} was recreated empty during open; in-process follower skipping projection repair (leader owns the rebuild)",
@@ -2466,9 +2540,17 @@ impl JulieServerHandler {
                 );
             }
 
-            Ok(Some(Arc::new(index)))
+            Ok::<_, anyhow::Error>(Arc::new(index))
         })
-        .await?
+        .await??;
+
+        self.ref_index_cache.write().await.insert(
+            workspace_id,
+            tantivy_path,
+            Arc::clone(&index),
+            std::time::Instant::now(),
+        );
+        Ok(Some(index))
     }
 
     /// Get the root path on disk for a specific workspace by ID.
@@ -2532,15 +2614,24 @@ impl JulieServerHandler {
     pub fn tool_router() -> rmcp::handler::server::router::tool::ToolRouter<Self> {
         Self::tool_router_fast_search()
             + Self::tool_router_fast_refs()
+            + Self::tool_router_fast_imports()
+            + Self::tool_router_fast_counterparts()
+            + Self::tool_router_fast_signature()
             + Self::tool_router_call_path()
             + Self::tool_router_get_symbols()
             + Self::tool_router_deep_dive()
             + Self::tool_router_get_context()
+            + Self::tool_router_get_context_pack()
+            + Self::tool_router_get_file_annotations()
             + Self::tool_router_blast_radius()
             + Self::tool_router_spillover_get()
             + Self::tool_router_rename_symbol()
             + Self::tool_router_manage_workspace()
             + Self::tool_router_patterns()
+            + Self::tool_router_fast_audit()
+            + Self::tool_router_syntax_query()
+            + Self::tool_router_task_status()
+            + Self::tool_router_session_context()
             + Self::tool_router_edit_file()
             + Self::tool_router_rewrite_symbol()
     }
@@ -2581,7 +2672,8 @@ pub(crate) fn parse_request_timeout(raw: Option<String>) -> Option<Duration> {
 /// workspace state.
 ///
 /// For `manage_workspace` the exemption is operation-aware:
-/// - **Exempt (mutating / long-running):** Index, Register, Remove, Clean, Refresh, Open.
+/// - **Exempt (mutating / long-running):** Index, Register, Remove, Clean, Refresh, Open,
+///   RollbackLastOperation (restores edited files).
 /// - **Deadline-bounded (read-only):** List, Stats, Health, Dashboard — these
 ///   have no write-safety concern, so they must not escape the hang guard.
 /// - **Unparseable operation:** not exempt (safely bounded; no mutation risk).
@@ -2606,6 +2698,7 @@ pub(crate) fn is_write_exempt(
                     | ManageWorkspaceOperation::Clean
                     | ManageWorkspaceOperation::Refresh
                     | ManageWorkspaceOperation::Open
+                    | ManageWorkspaceOperation::RollbackLastOperation
             )
         );
     }
@@ -2632,14 +2725,14 @@ pub(crate) async fn dispatch_with_deadline(
     let d = deadline.unwrap();
     match tokio::time::timeout(d, fut).await {
         Ok(result) => result,
-        Err(_elapsed) => Err(McpError::internal_error(
+        Err(_elapsed) => Err(coded_internal_error(
+            ToolErrorCode::Timeout,
             format!(
                 "tool '{}' did not respond within {}s; \
                  request timed out to prevent session hang",
                 tool_name,
                 d.as_secs()
             ),
-            None,
         )),
     }
 }
@@ -2650,8 +2743,13 @@ impl ServerHandler for JulieServerHandler {
         let server_info = Implementation::new("Julie", env!("CARGO_PKG_VERSION"))
             .with_title("Julie - Code Intelligence Server");
 
-        let mut info = ServerInfo::new(ServerCapabilities::builder().enable_tools().build())
-            .with_server_info(server_info);
+        let mut info = ServerInfo::new(
+            ServerCapabilities::builder()
+                .enable_tools()
+                .enable_resources()
+                .build(),
+        )
+        .with_server_info(server_info);
 
         if let Some(instructions) = self.load_agent_instructions() {
             info = info.with_instructions(instructions);
@@ -2681,6 +2779,8 @@ impl ServerHandler for JulieServerHandler {
         // are moved into the in-process bounded future below.
         let tool_name = request.name.as_ref().to_string();
         let exempt = is_write_exempt(&tool_name, request.arguments.as_ref());
+        // Held until the call returns; see `tool_admission`.
+        let _admission = self.admit_tool_call(&tool_name, exempt).await?;
 
         if Self::tool_request_targets_primary(request.name.as_ref(), request.arguments.as_ref()) {
             let complete_deferred_auto_index = !(request.name.as_ref() == "manage_workspace"
@@ -2735,28 +2835,32 @@ impl ServerHandler for JulieServerHandler {
                     handler
                         .ensure_primary_workspace_for_request(&context.peer, false)
                         .await
-                        .map_err(|e| McpError::internal_error(e.to_string(), None))?;
+                        .map_err(|e| {
+                            let code = tool_error_code(&e);
+                            coded_internal_error(code, e.to_string())
+                        })?;
                     handler
                         .tool_router
                         .call(ToolCallContext::new(&handler, request, context))
                         .await
                 };
 
-                return match deadline {
+                let result = match deadline {
                     Some(d) => match tokio::time::timeout(d, bounded_fut).await {
                         Ok(result) => result,
-                        Err(_elapsed) => Err(McpError::internal_error(
+                        Err(_elapsed) => Err(coded_internal_error(
+                            ToolErrorCode::WorkspaceNotReady,
                             format!(
                                 "in-process workspace not ready within {s}s; \
                                  indexing in progress — retry shortly \
                                  (tool: '{tool_name}')",
                                 s = d.as_secs()
                             ),
-                            None,
                         )),
                     },
                     None => bounded_fut.await,
                 };
+                return result.map(with_error_code);
             }
 
             // Non-in-process path OR in-process write path: existing behavior
@@ -2764,14 +2868,19 @@ impl ServerHandler for JulieServerHandler {
             // exemption); daemon/stdio take this path for all tools.
             self.ensure_primary_workspace_for_request(&context.peer, complete_deferred_auto_index)
                 .await
-                .map_err(|err| McpError::internal_error(err.to_string(), None))?;
+                .map_err(|err| {
+                    let code = tool_error_code(&err);
+                    coded_internal_error(code, err.to_string())
+                })?;
         }
 
         let deadline = parse_request_timeout(std::env::var(REQUEST_TIMEOUT_ENV).ok());
         let fut = self
             .tool_router
             .call(ToolCallContext::new(self, request, context));
-        dispatch_with_deadline(&tool_name, exempt, fut, deadline).await
+        dispatch_with_deadline(&tool_name, exempt, fut, deadline)
+            .await
+            .map(with_error_code)
     }
 
     async fn list_tools(
@@ -2786,6 +2895,63 @@ impl ServerHandler for JulieServerHandler {
         self.tool_router.get(name).cloned()
     }
 
+    async fn list_resources(
+        &self,
+        request: Option<PaginatedRequestParams>,
+        context: RequestContext<RoleServer>,
+    ) -> Result<ListResourcesResult, McpError> {
+        // Resource reads never run the deferred auto-index inline; they serve
+        // whatever the index holds right now.
+        if self
+            .ensure_primary_workspace_for_request(&context.peer, false)
+            .await
+            .is_err()
+        {
+            // No primary workspace yet: nothing indexed to offer.
+            return Ok(ListResourcesResult::default());
+        }
+        let cursor = request
+            .as_ref()
+            .and_then(|request| request.cursor.as_deref());
+        self.list_file_resources(cursor)
+            .await
+            .map_err(|e| McpError::invalid_params(e.to_string(), None))
+    }
+
+    async fn list_resource_templates(
+        &self,
+        _request: Option<PaginatedRequestParams>,
+        _context: RequestContext<RoleServer>,
+    ) -> Result<ListResourceTemplatesResult, McpError> {
+        Ok(ListResourceTemplatesResult::with_all_items(
+            resources::resource_templates(),
+        ))
+    }
+
+    async fn read_resource(
+        &self,
+        request: ReadResourceRequestParams,
+        context: RequestContext<RoleServer>,
+    ) -> Result<ReadResourceResult, McpError> {
+        let Some(target) = resources::parse_resource_uri(&request.uri) else {
+            return Err(McpError::invalid_params(
+                format!("Unsupported resource URI '{}'", request.uri),
+                None,
+            ));
+        };
+        self.ensure_primary_workspace_for_request(&context.peer, false)
+            .await
+            .map_err(|e| McpError::internal_error(e.to_string(), None))?;
+        match self.read_julie_resource(&target, &request.uri).await {
+            Ok(Some(result)) => Ok(result),
+            Ok(None) => Err(McpError::resource_not_found(
+                format!("No indexed resource at '{}'", request.uri),
+                None,
+            )),
+            Err(e) => Err(McpError::internal_error(e.to_string(), None)),
+        }
+    }
+
     async fn on_initialized(&self, context: NotificationContext<RoleServer>) {
         info!("MCP connection established - client initialized");
 
@@ -57,6 +57,11 @@ pub(crate) async fn wait_for_embedding_provider_settled(
     handler: &JulieServerHandler,
     daemon_timeout: Duration,
 ) -> Option<Arc<dyn EmbeddingProvider>> {
+    // `semantic.mode = "disabled"`: never wait for, or start, a provider.
+    if handler.semantic_disabled() {
+        return None;
+    }
+
     // If a provider is already available (daemon shared service or workspace),
     // return it immediately.
     if let Some(provider) = handler.embedding_provider().await {
@@ -0,0 +1,47 @@
+//! MCP progress notifications carrying streamed tool results.
+//!
+//! A client opts in by sending a progress token (`_meta.progressToken`) with
+//! `fast_search` or `fast_refs`. Each result batch then arrives as a
+//! `notifications/progress` message whose `message` holds the batch text and
+//! whose `progress` / `total` count results. The tool result that follows is
+//! the usual complete response.
+
+use async_trait::async_trait;
+use rmcp::RoleServer;
+use rmcp::model::{ProgressNotificationParam, ProgressToken};
+use rmcp::service::{Peer, RequestContext};
+use tracing::debug;
+
+use crate::tools::streaming::ResultSink;
+
+pub(crate) struct ProgressResultSink {
+    peer: Peer<RoleServer>,
+    token: ProgressToken,
+}
+
+impl ProgressResultSink {
+    /// A sink for this request, when the client asked for progress.
+    pub(crate) fn for_request(context: &RequestContext<RoleServer>) -> Option<Self> {
+        let token = context.meta.get_progress_token()?;
+        Some(Self {
+            peer: context.peer.clone(),
+            token,
+        })
+    }
+}
+
+#[async_trait]
+impl ResultSink for ProgressResultSink {
+    async fn send_batch(&self, text: String, sent: usize, total: Option<usize>) {
+        let mut param =
+            ProgressNotificationParam::new(self.token.clone(), sent as f64).with_message(text);
+        if let Some(total) = total {
+            param = param.with_total(total as f64);
+        }
+        // Batches are a preview of the final result; a client that went away
+        // or rejects the notification still gets (or ignores) the full result.
+        if let Err(err) = self.peer.notify_progress(param).await {
+            debug!("Dropping streamed result batch: {}", err);
+        }
+    }
+}
@@ -0,0 +1,162 @@
+//! In-memory LRU cache for read-only tool results.
+//!
+//! Agents repeat the same `fast_search` / `fast_refs` calls many times in a
+//! session. Results are cached per `(tool, workspace, params, generation)`,
+//! where the generation is the workspace's mutation-gate counter (see
+//! [`MutationGateRegistry::generation`]). Every writer — watcher batches,
+//! catch-up and force re-index, registry refresh — holds the gate, so any
+//! committed change moves the generation and orphans older entries; they are
+//! purged on the next insert for that workspace.
+//!
+//! Only the primary workspace of a process that owns its writers is cached:
+//! an in-process follower, or a reference workspace another process may be
+//! indexing, would never see the generation move.
+//!
+//! [`MutationGateRegistry::generation`]: julie_core::workspace::mutation_gate::Registry::generation
+
+use std::collections::HashMap;
+use std::sync::Mutex as StdMutex;
+
+use rmcp::model::CallToolResult;
+use serde::Serialize;
+use tracing::debug;
+
+use crate::handler::JulieServerHandler;
+use crate::tools::search::trace::SearchExecutionResult;
+use julie_context::WorkspaceTarget;
+
+/// Entries kept per handler. Results are small formatted text, so this bounds
+/// memory to a few MB even for large `limit` values.
+pub(crate) const DEFAULT_QUERY_CACHE_CAPACITY: usize = 256;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub(crate) struct QueryCacheKey {
+    pub(crate) tool: &'static str,
+    pub(crate) workspace_id: String,
+    /// Canonical JSON of the tool parameters.
+    pub(crate) params: String,
+    pub(crate) generation: u64,
+}
+
+/// A cached tool response plus the search trace needed to record telemetry
+/// for a cache hit exactly as for a fresh call.
+#[derive(Debug, Clone)]
+pub(crate) struct CachedToolOutput {
+    pub(crate) result: CallToolResult,
+    pub(crate) search_execution: Option<SearchExecutionResult>,
+}
+
+struct CacheEntry<V> {
+    value: V,
+    last_used: u64,
+}
+
+struct CacheState<V> {
+    entries: HashMap<QueryCacheKey, CacheEntry<V>>,
+    tick: u64,
+}
+
+pub(crate) struct QueryCache<V = CachedToolOutput> {
+    capacity: usize,
+    state: StdMutex<CacheState<V>>,
+}
+
+impl<V: Clone> QueryCache<V> {
+    pub(crate) fn new(capacity: usize) -> Self {
+        Self {
+            capacity: capacity.max(1),
+            state: StdMutex::new(CacheState {
+                entries: HashMap::new(),
+                tick: 0,
+            }),
+        }
+    }
+
+    pub(crate) fn get(&self, key: &QueryCacheKey) -> Option<V> {
+        let mut state = self.state.lock().unwrap_or_else(|p| p.into_inner());
+        state.tick += 1;
+        let tick = state.tick;
+        let entry = state.entries.get_mut(key)?;
+        entry.last_used = tick;
+        Some(entry.value.clone())
+    }
+
+    pub(crate) fn insert(&self, key: QueryCacheKey, value: V) {
+        let mut state = self.state.lock().unwrap_or_else(|p| p.into_inner());
+        state.tick += 1;
+        let tick = state.tick;
+
+        // Entries from an older generation of this workspace can never be hit
+        // again; drop them before considering LRU eviction.
+        state.entries.retain(|existing, _| {
+            existing.workspace_id != key.workspace_id || existing.generation >= key.generation
+        });
+
+        if !state.entries.contains_key(&key) && state.entries.len() >= self.capacity {
+            if let Some(oldest) = state
+                .entries
+                .iter()
+                .min_by_key(|(_, entry)| entry.last_used)
+                .map(|(key, _)| key.clone())
+            {
+                state.entries.remove(&oldest);
+            }
+        }
+
+        state.entries.insert(
+            key,
+            CacheEntry {
+                value,
+                last_used: tick,
+            },
+        );
+    }
+
+    #[cfg_attr(not(test), allow(dead_code))]
+    pub(crate) fn len(&self) -> usize {
+        self.state
+            .lock()
+            .unwrap_or_else(|p| p.into_inner())
+            .entries
+            .len()
+    }
+}
+
+impl Default for QueryCache {
+    fn default() -> Self {
+        Self::new(DEFAULT_QUERY_CACHE_CAPACITY)
+    }
+}
+
+impl JulieServerHandler {
+    /// Build the cache key for a read-only tool call, or `None` when the call
+    /// must not be cached (follower process, reference workspace, write in
+    /// flight, or unserializable params).
+    ///
+    /// The generation is read *before* the tool runs, so a result computed
+    /// while a write lands is stored under a generation that has already
+    /// moved on and is never served.
+    pub(crate) fn query_cache_key<P: Serialize>(
+        &self,
+        tool: &'static str,
+        target: &WorkspaceTarget,
+        params: &P,
+    ) -> Option<QueryCacheKey> {
+        if self.is_in_process_follower() || !matches!(target, WorkspaceTarget::Primary) {
+            return None;
+        }
+        let workspace_id = self.current_workspace_id()?;
+        let generation = self.mutation_gate_registry.generation(&workspace_id);
+        if generation % 2 == 1 {
+            debug!(tool, %workspace_id, "query cache bypassed: write in flight");
+            return None;
+        }
+        let params = serde_json::to_string(params).ok()?;
+        Some(QueryCacheKey {
+            tool,
+            workspace_id,
+            params,
+            generation,
+        })
+    }
+}
@@ -0,0 +1,177 @@
+//! Open databases and search indexes for non-primary workspaces, closed once
+//! idle.
+//!
+//! A long-running session can touch many workspaces: reference workspaces
+//! queried with `workspace="<id>"`, and primary bindings reopened from their
+//! index paths. Each workspace's SQLite database and Tantivy index are opened
+//! lazily on first use and cached so later calls skip the open. Without
+//! eviction the session kept every workspace it had ever touched open until
+//! it exited, so memory grew with the number of repos an editor had visited.
+//!
+//! An entry unused for [`workspace_idle_timeout`] is dropped on the next
+//! cache access, or by the sweep [`session_workspace_cache`] runs in the
+//! background once the session goes quiet. Dropping it closes the connection
+//! or the index readers as soon as no in-flight call still holds a clone; a
+//! later call for that workspace reopens it. Embedding vectors live in the
+//! workspace database and the model runs in the host shared per
+//! `$JULIE_HOME`, so closing the database is what releases a workspace's
+//! embeddings.
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex as StdMutex};
+use std::time::{Duration, Instant};
+
+use tokio::sync::RwLock;
+use tokio::task::JoinHandle;
+use tokio::time::MissedTickBehavior;
+use tracing::info;
+
+use crate::database::SymbolDatabase;
+use crate::search::SearchIndex;
+
+/// Env override for the workspace idle timeout (seconds). `0` keeps every
+/// opened workspace for the life of the session.
+const WORKSPACE_IDLE_TIMEOUT_ENV: &str = "JULIE_WORKSPACE_IDLE_TIMEOUT_SECS";
+/// Default: long enough that a workspace consulted every few minutes stays
+/// warm, short enough that a repo visited once does not stay open all day.
+const DEFAULT_WORKSPACE_IDLE_TIMEOUT_SECS: u64 = 900;
+/// Longest wait between two background sweeps. A shorter idle timeout is
+/// swept that often instead.
+const MAX_SWEEP_INTERVAL: Duration = Duration::from_secs(60);
+
+/// Resolve the workspace idle timeout, honoring the env override. `None`
+/// disables eviction.
+pub(crate) fn workspace_idle_timeout() -> Option<Duration> {
+    let secs = std::env::var(WORKSPACE_IDLE_TIMEOUT_ENV)
+        .ok()
+        .and_then(|raw| raw.trim().parse::<u64>().ok())
+        .unwrap_or(DEFAULT_WORKSPACE_IDLE_TIMEOUT_SECS);
+    (secs > 0).then(|| Duration::from_secs(secs))
+}
+
+/// A handler cache, swept in the background so a workspace closes on time
+/// even when the session makes no further call.
+pub(crate) fn session_workspace_cache<T>() -> Arc<RwLock<IdleWorkspaceCache<T>>>
+where
+    T: Send + Sync + 'static,
+{
+    let idle_timeout = workspace_idle_timeout();
+    let cache = Arc::new(RwLock::new(IdleWorkspaceCache::new(idle_timeout)));
+    if let Some(idle_timeout) = idle_timeout {
+        spawn_idle_sweep(&cache, idle_timeout.min(MAX_SWEEP_INTERVAL));
+    }
+    cache
+}
+
+/// Evict idle entries of `cache` every `interval`. The task holds the cache
+/// weakly and ends once the handler owning it is dropped.
+pub(crate) fn spawn_idle_sweep<T>(
+    cache: &Arc<RwLock<IdleWorkspaceCache<T>>>,
+    interval: Duration,
+) -> JoinHandle<()>
+where
+    T: Send + Sync + 'static,
+{
+    let cache = Arc::downgrade(cache);
+    tokio::spawn(async move {
+        let mut ticker = tokio::time::interval(interval);
+        ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+        // The first tick completes immediately; nothing is idle yet.
+        ticker.tick().await;
+        loop {
+            ticker.tick().await;
+            let Some(cache) = cache.upgrade() else {
+                break;
+            };
+            cache.write().await.evict_idle(Instant::now());
+        }
+    })
+}
+
+/// Database connections of non-primary workspaces.
+pub(crate) type RefDbCache = IdleWorkspaceCache<StdMutex<SymbolDatabase>>;
+/// Tantivy indexes of non-primary workspaces, keyed by their index directory.
+pub(crate) type RefIndexCache = IdleWorkspaceCache<SearchIndex>;
+
+struct CacheEntry<T> {
+    path: PathBuf,
+    handle: Arc<T>,
+    last_used: Instant,
+}
+
+/// Cached handles keyed by workspace id. The resolved on-disk path is part of
+/// the key so root-anchor changes in stdio do not reuse a stale handle from a
+/// different `.julie/indexes/...` tree.
+pub(crate) struct IdleWorkspaceCache<T> {
+    entries: HashMap<String, CacheEntry<T>>,
+    idle_timeout: Option<Duration>,
+}
+
+impl<T> IdleWorkspaceCache<T> {
+    pub(crate) fn new(idle_timeout: Option<Duration>) -> Self {
+        Self {
+            entries: HashMap::new(),
+            idle_timeout,
+        }
+    }
+
+    /// The cached handle for `workspace_id` at `path`, marking it used.
+    /// Evicts idle entries first.
+    pub(crate) fn get(&mut self, workspace_id: &str, path: &Path, now: Instant) -> Option<Arc<T>> {
+        self.evict_idle(now);
+        let entry = self
+            .entries
+            .get_mut(workspace_id)
+            .filter(|entry| entry.path == path)?;
+        entry.last_used = now;
+        Some(Arc::clone(&entry.handle))
+    }
+
+    /// Cache a freshly opened handle, replacing any handle for another path.
+    /// Evicts idle entries first.
+    pub(crate) fn insert(
+        &mut self,
+        workspace_id: &str,
+        path: PathBuf,
+        handle: Arc<T>,
+        now: Instant,
+    ) {
+        self.evict_idle(now);
+        self.entries.insert(
+            workspace_id.to_string(),
+            CacheEntry {
+                path,
+                handle,
+                last_used: now,
+            },
+        );
+    }
+
+    /// Drop entries unused for longer than the idle timeout. Returns the
+    /// evicted workspace ids, sorted.
+    pub(crate) fn evict_idle(&mut self, now: Instant) -> Vec<String> {
+        let Some(idle_timeout) = self.idle_timeout else {
+            return Vec::new();
+        };
+        let mut evicted: Vec<String> = self
+            .entries
+            .iter()
+            .filter(|(_, entry)| now.saturating_duration_since(entry.last_used) >= idle_timeout)
+            .map(|(workspace_id, _)| workspace_id.clone())
+            .collect();
+        evicted.sort();
+        for workspace_id in &evicted {
+            self.entries.remove(workspace_id);
+            info!(
+                "Released workspace '{}' handle after {:?} idle",
+                workspace_id, idle_timeout
+            );
+        }
+        evicted
+    }
+
+    pub(crate) fn len(&self) -> usize {
+        self.entries.len()
+    }
+}
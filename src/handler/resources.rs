@@ -0,0 +1,221 @@
+//! MCP resources for the primary workspace.
+//!
+//! Indexed files are listed as `julie://file/<path>` and every symbol can be
+//! read as `julie://symbol/<id>`: a small document holding its location,
+//! signature, doc comment and body. Clients that support resource mentions can
+//! attach these straight to a prompt instead of calling a tool first. Symbols
+//! are not listed (there are too many); clients reach them through the
+//! resource template or through ids returned by the search tools.
+
+use anyhow::{Result, anyhow};
+use rmcp::model::{
+    AnnotateAble, ListResourcesResult, RawResource, RawResourceTemplate, ReadResourceResult,
+    ResourceContents, ResourceTemplate,
+};
+
+use super::JulieServerHandler;
+use crate::database::SymbolDatabase;
+use crate::extractors::Symbol;
+use crate::utils::paths::{display_stored_path, stored_path_to_absolute};
+
+pub(crate) const FILE_URI_PREFIX: &str = "julie://file/";
+pub(crate) const SYMBOL_URI_PREFIX: &str = "julie://symbol/";
+
+/// Files returned per `resources/list` page. The cursor is the offset of the
+/// next page in the sorted path list.
+pub(crate) const RESOURCE_PAGE_SIZE: usize = 500;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum ResourceTarget {
+    File(String),
+    Symbol(String),
+}
+
+pub(crate) fn file_uri(path: &str) -> String {
+    format!("{FILE_URI_PREFIX}{}", encode_uri_path(path))
+}
+
+/// Parse a `julie://` URI. Returns `None` for other schemes, unknown kinds,
+/// empty targets and malformed percent escapes.
+pub(crate) fn parse_resource_uri(uri: &str) -> Option<ResourceTarget> {
+    if let Some(path) = uri.strip_prefix(FILE_URI_PREFIX) {
+        return decode_uri_path(path)
+            .filter(|path| !path.is_empty())
+            .map(ResourceTarget::File);
+    }
+    if let Some(id) = uri.strip_prefix(SYMBOL_URI_PREFIX) {
+        return decode_uri_path(id)
+            .filter(|id| !id.is_empty())
+            .map(ResourceTarget::Symbol);
+    }
+    None
+}
+
+/// Percent-encode everything except unreserved characters and `/`, so
+/// workspace-relative paths stay readable in the URI.
+fn encode_uri_path(value: &str) -> String {
+    let mut encoded = String::with_capacity(value.len());
+    for byte in value.bytes() {
+        if byte.is_ascii_alphanumeric() || matches!(byte, b'-' | b'.' | b'_' | b'~' | b'/') {
+            encoded.push(byte as char);
+        } else {
+            encoded.push_str(&format!("%{byte:02X}"));
+        }
+    }
+    encoded
+}
+
+fn decode_uri_path(value: &str) -> Option<String> {
+    let bytes = value.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] == b'%' {
+            let hex = value.get(i + 1..i + 3)?;
+            decoded.push(u8::from_str_radix(hex, 16).ok()?);
+            i += 3;
+        } else {
+            decoded.push(bytes[i]);
+            i += 1;
+        }
+    }
+    String::from_utf8(decoded).ok()
+}
+
+/// Source text of `symbol` within its file. Uses the byte range when the
+/// extractor recorded one and falls back to the line range otherwise.
+pub(crate) fn symbol_body<'a>(symbol: &Symbol, content: &'a str) -> Option<&'a str> {
+    let (start, end) = (symbol.start_byte as usize, symbol.end_byte as usize);
+    if end > start {
+        return content.get(start..end);
+    }
+    if symbol.start_line == 0 {
+        return None;
+    }
+    let mut offset = 0;
+    let (mut from, mut to) = (None, None);
+    for (index, line) in content.split_inclusive('\n').enumerate() {
+        let number = index as u32 + 1;
+        if number == symbol.start_line {
+            from = Some(offset);
+        }
+        offset += line.len();
+        if number == symbol.end_line.max(symbol.start_line) {
+            to = Some(offset);
+            break;
+        }
+    }
+    content
+        .get(from?..to?)
+        .map(|body| body.trim_end_matches('\n'))
+}
+
+/// Render the document served for `julie://symbol/<id>`.
+pub(crate) fn render_symbol_document(symbol: &Symbol, body: Option<&str>) -> String {
+    let kind = format!("{:?}", symbol.kind).to_lowercase();
+    let mut document = format!(
+        "# {} ({kind})\n{}:{}-{}\n",
+        symbol.name,
+        display_stored_path(&symbol.file_path),
+        symbol.start_line,
+        symbol.end_line
+    );
+    if let Some(signature) = symbol.signature.as_deref().filter(|s| !s.trim().is_empty()) {
+        document.push_str(&format!("\n{}\n", signature.trim()));
+    }
+    if let Some(doc) = symbol
+        .doc_comment
+        .as_deref()
+        .filter(|d| !d.trim().is_empty())
+    {
+        document.push_str(&format!("\n{}\n", doc.trim()));
+    }
+    if let Some(body) = body {
+        document.push_str(&format!("\n```{}\n{}\n```\n", symbol.language, body));
+    }
+    document
+}
+
+pub(crate) fn resource_templates() -> Vec<ResourceTemplate> {
+    let mut file = RawResourceTemplate::new("julie://file/{path}", "Indexed file");
+    file.description = Some("Content of a file in the primary workspace index".to_string());
+    let mut symbol = RawResourceTemplate::new("julie://symbol/{id}", "Symbol document");
+    symbol.description =
+        Some("Location, signature, doc comment and body of an indexed symbol".to_string());
+    symbol.mime_type = Some("text/markdown".to_string());
+    vec![file.no_annotation(), symbol.no_annotation()]
+}
+
+/// One page of the sorted file list starting at `cursor`.
+pub(crate) fn file_resource_page(
+    mut paths: Vec<String>,
+    cursor: Option<&str>,
+) -> Result<ListResourcesResult> {
+    let start = match cursor {
+        Some(cursor) => cursor
+            .parse::<usize>()
+            .map_err(|_| anyhow!("Invalid resource cursor '{cursor}'"))?,
+        None => 0,
+    };
+    paths.sort();
+    let end = paths.len().min(start.saturating_add(RESOURCE_PAGE_SIZE));
+    let resources = paths
+        .get(start..end)
+        .unwrap_or_default()
+        .iter()
+        .map(|path| RawResource::new(file_uri(path), display_stored_path(path)).no_annotation())
+        .collect();
+    let mut result = ListResourcesResult::with_all_items(resources);
+    if end < paths.len() {
+        result.next_cursor = Some(end.to_string());
+    }
+    Ok(result)
+}
+
+impl JulieServerHandler {
+    pub(crate) async fn list_file_resources(
+        &self,
+        cursor: Option<&str>,
+    ) -> Result<ListResourcesResult> {
+        let db = self.primary_pooled_database().await?;
+        file_resource_page(db.get_all_indexed_files()?, cursor)
+    }
+
+    /// Read a `julie://` resource from the primary workspace. `Ok(None)` means
+    /// the URI is well-formed but names nothing in the index.
+    pub(crate) async fn read_julie_resource(
+        &self,
+        target: &ResourceTarget,
+        uri: &str,
+    ) -> Result<Option<ReadResourceResult>> {
+        let db = self.primary_pooled_database().await?;
+        let text = match target {
+            ResourceTarget::File(path) => self.indexed_file_content(&db, path)?,
+            ResourceTarget::Symbol(id) => match db.get_symbol_by_id(id)? {
+                Some(symbol) => {
+                    let content = self.indexed_file_content(&db, &symbol.file_path)?;
+                    let body = content
+                        .as_deref()
+                        .and_then(|content| symbol_body(&symbol, content));
+                    Some(render_symbol_document(&symbol, body))
+                }
+                None => None,
+            },
+        };
+        Ok(text.map(|text| ReadResourceResult::new(vec![ResourceContents::text(text, uri)])))
+    }
+
+    /// File content as stored by the indexer, falling back to disk for rows
+    /// indexed without content. Paths not in the index are never read, so a
+    /// URI cannot reach outside the workspace.
+    fn indexed_file_content(&self, db: &SymbolDatabase, path: &str) -> Result<Option<String>> {
+        if db.get_file_hash(path)?.is_none() {
+            return Ok(None);
+        }
+        if let Some(content) = db.get_file_content(path)? {
+            return Ok(Some(content));
+        }
+        let root = self.require_primary_workspace_binding()?.workspace_root;
+        Ok(std::fs::read_to_string(stored_path_to_absolute(&root, path)).ok())
+    }
+}
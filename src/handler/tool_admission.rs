@@ -0,0 +1,198 @@
+//! Per-session admission control for tool calls.
+//!
+//! A misbehaving agent can fire dozens of heavy queries at once. Each one
+//! holds a blocking thread and takes the workspace database lock in turn, so
+//! an indexing run behind them waits on every query in the pile. Every tool
+//! call in a session therefore takes permits from one semaphore before it
+//! runs:
+//!
+//! - an ordinary read takes one permit, and the graph walks (`blast_radius`,
+//!   `call_path`, `fast_audit`, `fast_imports`, `get_context`, `patterns`)
+//!   take two;
+//! - while a writer holds the workspace's mutation gate, every call takes
+//!   twice as many, so half as many queries run alongside indexing;
+//! - Tokio's semaphore is FIFO, so a heavy call waiting for two permits is not
+//!   starved by a stream of light ones.
+//!
+//! A call waits at most the queue timeout, and when too many calls are
+//! already waiting it is refused straight away. Either way the agent gets a
+//! retryable `rate_limited` error instead of a pile-up. Edits and index
+//! operations (see [`is_write_exempt`]), `manage_workspace` and the cheap
+//! `task_status` / `spillover_get` lookups are never held back: they are
+//! either what the queries are waiting for or how an agent finds out why.
+//!
+//! [`is_write_exempt`]: crate::handler::is_write_exempt
+
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Duration;
+
+use rmcp::ErrorData as McpError;
+use tokio::sync::{OwnedSemaphorePermit, Semaphore};
+use tracing::{debug, warn};
+
+use crate::handler::JulieServerHandler;
+use crate::handler::tools::error::{ToolErrorCode, coded_internal_error};
+
+/// Permits per session. Set to `0` to disable admission control.
+const MAX_CONCURRENT_ENV: &str = "JULIE_MAX_CONCURRENT_TOOL_CALLS";
+const DEFAULT_MAX_CONCURRENT: u32 = 4;
+
+/// Longest a call waits for permits before it is refused.
+const QUEUE_TIMEOUT_ENV: &str = "JULIE_TOOL_QUEUE_TIMEOUT_SECS";
+const DEFAULT_QUEUE_TIMEOUT_SECS: u64 = 30;
+
+/// Calls allowed to wait per permit; more are refused without queueing.
+const WAITING_PER_PERMIT: usize = 4;
+
+/// Tools that walk the relationship graph or scan the whole index.
+const HEAVY_TOOLS: &[&str] = &[
+    "blast_radius",
+    "call_path",
+    "fast_audit",
+    "fast_imports",
+    "get_context",
+    "patterns",
+];
+
+/// Tools that are never held back (besides write-exempt calls).
+const UNGATED_TOOLS: &[&str] = &["manage_workspace", "task_status", "spillover_get"];
+
+/// Why a call was not admitted.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum AdmissionRejected {
+    /// Too many calls were already waiting.
+    QueueFull { waiting: usize },
+    /// Permits did not free up within the queue timeout.
+    TimedOut(Duration),
+}
+
+/// The session's tool call semaphore.
+pub(crate) struct ToolAdmission {
+    permits: Arc<Semaphore>,
+    max_permits: u32,
+    queue_timeout: Duration,
+    max_waiting: usize,
+    waiting: AtomicUsize,
+}
+
+impl ToolAdmission {
+    pub(crate) fn new(max_permits: u32, queue_timeout: Duration) -> Self {
+        let max_permits = max_permits.max(1);
+        Self {
+            permits: Arc::new(Semaphore::new(max_permits as usize)),
+            max_permits,
+            queue_timeout,
+            max_waiting: max_permits as usize * WAITING_PER_PERMIT,
+            waiting: AtomicUsize::new(0),
+        }
+    }
+
+    /// Admission control as configured by the environment, or `None` when
+    /// `JULIE_MAX_CONCURRENT_TOOL_CALLS=0` turns it off.
+    pub(crate) fn from_env() -> Option<Self> {
+        let max_permits = std::env::var(MAX_CONCURRENT_ENV)
+            .ok()
+            .and_then(|raw| raw.trim().parse::<u32>().ok())
+            .unwrap_or(DEFAULT_MAX_CONCURRENT);
+        let queue_timeout = std::env::var(QUEUE_TIMEOUT_ENV)
+            .ok()
+            .and_then(|raw| raw.trim().parse::<u64>().ok())
+            .filter(|secs| *secs > 0)
+            .unwrap_or(DEFAULT_QUEUE_TIMEOUT_SECS);
+        (max_permits > 0).then(|| Self::new(max_permits, Duration::from_secs(queue_timeout)))
+    }
+
+    /// Wait for `cost` permits (capped at the session total). The call runs
+    /// while the returned permit is alive.
+    pub(crate) async fn admit(&self, cost: u32) -> Result<OwnedSemaphorePermit, AdmissionRejected> {
+        let cost = cost.clamp(1, self.max_permits);
+        if let Ok(permit) = Arc::clone(&self.permits).try_acquire_many_owned(cost) {
+            return Ok(permit);
+        }
+
+        let waiting = self.waiting.fetch_add(1, Ordering::AcqRel);
+        let _waiting = WaitingGuard(&self.waiting);
+        if waiting >= self.max_waiting {
+            return Err(AdmissionRejected::QueueFull { waiting });
+        }
+        match tokio::time::timeout(
+            self.queue_timeout,
+            Arc::clone(&self.permits).acquire_many_owned(cost),
+        )
+        .await
+        {
+            Ok(Ok(permit)) => Ok(permit),
+            // The semaphore is never closed, so only the timeout ends a wait.
+            Ok(Err(_)) | Err(_) => Err(AdmissionRejected::TimedOut(self.queue_timeout)),
+        }
+    }
+}
+
+struct WaitingGuard<'a>(&'a AtomicUsize);
+
+impl Drop for WaitingGuard<'_> {
+    fn drop(&mut self) {
+        self.0.fetch_sub(1, Ordering::AcqRel);
+    }
+}
+
+/// Whether `tool_name` skips admission control. `write_exempt` is
+/// [`is_write_exempt`](crate::handler::is_write_exempt) for the call.
+pub(crate) fn bypasses_admission(tool_name: &str, write_exempt: bool) -> bool {
+    write_exempt || UNGATED_TOOLS.contains(&tool_name)
+}
+
+/// Permits a call to `tool_name` takes; doubled while a writer is active.
+pub(crate) fn tool_call_cost(tool_name: &str, writer_active: bool) -> u32 {
+    let base = if HEAVY_TOOLS.contains(&tool_name) {
+        2
+    } else {
+        1
+    };
+    if writer_active { base * 2 } else { base }
+}
+
+impl JulieServerHandler {
+    /// Admit a tool call, returning the permit to hold while it runs (`None`
+    /// when the call is not gated) or a retryable `rate_limited` error.
+    pub(crate) async fn admit_tool_call(
+        &self,
+        tool_name: &str,
+        write_exempt: bool,
+    ) -> Result<Option<OwnedSemaphorePermit>, McpError> {
+        let Some(admission) = self.tool_admission.as_ref() else {
+            return Ok(None);
+        };
+        if bypasses_admission(tool_name, write_exempt) {
+            return Ok(None);
+        }
+        // An odd generation means a writer holds the mutation gate.
+        let writer_active = self
+            .current_workspace_id()
+            .is_some_and(|id| self.mutation_gate_registry.generation(&id) % 2 == 1);
+        let cost = tool_call_cost(tool_name, writer_active);
+
+        debug!(tool_name, cost, writer_active, "admitting tool call");
+        match admission.admit(cost).await {
+            Ok(permit) => Ok(Some(permit)),
+            Err(rejected) => {
+                let message = match rejected {
+                    AdmissionRejected::QueueFull { waiting } => format!(
+                        "too many concurrent tool calls ({waiting} already waiting); \
+                         '{tool_name}' was not started — wait for earlier calls to \
+                         finish and retry"
+                    ),
+                    AdmissionRejected::TimedOut(timeout) => format!(
+                        "'{tool_name}' waited {}s behind other tool calls{} and was not \
+                         started — retry shortly",
+                        timeout.as_secs(),
+                        if writer_active { " while indexing" } else { "" }
+                    ),
+                };
+                warn!("Tool call refused: {message}");
+                Err(coded_internal_error(ToolErrorCode::RateLimited, message))
+            }
+        }
+    }
+}
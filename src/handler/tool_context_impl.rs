@@ -157,6 +157,9 @@ impl ToolContext for JulieServerHandler {
             workspace_id: None,
             force: Some(false),
             detailed: None,
+            from_generation: None,
+            to_generation: None,
+            dry_run: None,
         };
         let result = index_tool.call_tool_with_options(self, true).await?;
         if result.is_error.unwrap_or(false) {
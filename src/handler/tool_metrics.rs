@@ -1,6 +1,7 @@
 use std::sync::Arc;
 use std::sync::atomic::Ordering;
 
+use julie_core::telemetry::Telemetry;
 use serde_json::json;
 use tokio::sync::RwLock;
 use tracing::warn;
@@ -126,10 +127,15 @@ impl JulieServerHandler {
         let workspace_id = workspace_snapshot
             .map(|binding| binding.workspace_id.clone())
             .or_else(|| self.current_workspace_id());
+        Telemetry::global().record_tool_call(tool_name, duration, success);
         if let Some(kind) = ToolKind::from_name(tool_name) {
             self.session_metrics
                 .record(kind, duration_us, 0, output_bytes);
         }
+        if success {
+            self.session_metrics
+                .record_target(tool_name, &report.metadata);
+        }
         if let Some(ref log) = self.project_log {
             log.tool_call(tool_name, duration.as_secs_f64() * 1000.0, output_bytes);
         }
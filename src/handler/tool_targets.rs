@@ -1,12 +1,22 @@
 use serde_json::{Value, json};
 
+use crate::tools::audit::AuditTool;
+use crate::tools::context_pack::GetContextPackTool;
+use crate::tools::counterparts::FastCounterpartsTool;
 use crate::tools::editing::edit_file::EditFileTool;
 use crate::tools::editing::rewrite_symbol::RewriteSymbolTool;
+use crate::tools::file_annotations::GetFileAnnotationsTool;
 use crate::tools::get_context::GetContextTool;
+use crate::tools::imports::FastImportsTool;
 use crate::tools::navigation::{CallPathTool, FastRefsTool};
 use crate::tools::patterns::PatternsTool;
+use crate::tools::signature::FastSignatureTool;
 use crate::tools::spillover::SpilloverGetTool;
-use crate::tools::{BlastRadiusTool, DeepDiveTool, GetSymbolsTool, RenameSymbolTool};
+use crate::tools::syntax_query::SyntaxQueryTool;
+use crate::tools::task_status::TaskStatusTool;
+use crate::tools::{
+    BlastRadiusTool, DeepDiveTool, GetSymbolsTool, RenameSymbolTool, SessionContextTool,
+};
 
 fn target_metadata(symbol_name: Option<&str>, file_path: Option<&str>, line: Option<u32>) -> Value {
     json!({
@@ -37,11 +47,51 @@ pub(crate) fn fast_refs_metadata(params: &FastRefsTool) -> Value {
         "include_definition": params.include_definition,
         "limit": params.limit,
         "reference_kind": params.reference_kind,
+        "include_textual": params.include_textual,
+        "project": params.project,
+        "max_tokens": params.max_tokens,
         "workspace": params.workspace,
         "target": target_metadata(Some(&params.symbol), None, None),
     })
 }
 
+pub(crate) fn fast_counterparts_metadata(params: &FastCounterpartsTool) -> Value {
+    json!({
+        "symbol": params.symbol,
+        "context_file": params.context_file,
+        "min_confidence": params.min_confidence,
+        "limit": params.limit,
+        "workspace": params.workspace,
+        "target": target_metadata(Some(&params.symbol), params.context_file.as_deref(), None),
+    })
+}
+
+pub(crate) fn fast_signature_metadata(params: &FastSignatureTool) -> Value {
+    json!({
+        "symbol_id": params.symbol_id,
+        "workspace": params.workspace,
+        "target": target_metadata(Some(&params.symbol_id), None, None),
+    })
+}
+
+pub(crate) fn get_context_pack_metadata(params: &GetContextPackTool) -> Value {
+    json!({
+        "symbol": params.symbol,
+        "max_tokens": params.max_tokens,
+        "workspace": params.workspace,
+        "target": target_metadata(Some(&params.symbol), None, None),
+    })
+}
+
+pub(crate) fn fast_imports_metadata(params: &FastImportsTool) -> Value {
+    json!({
+        "target_module": params.target,
+        "limit": params.limit,
+        "workspace": params.workspace,
+        "target": target_metadata(None, Some(&params.target), None),
+    })
+}
+
 pub(crate) fn call_path_metadata(params: &CallPathTool) -> Value {
     json!({
         "from": params.from,
@@ -59,6 +109,7 @@ pub(crate) fn get_symbols_metadata(params: &GetSymbolsTool) -> Value {
         "file": params.file_path,
         "mode": params.mode,
         "target_filter": params.target,
+        "max_tokens": params.max_tokens,
         "workspace": params.workspace,
         "target": target_metadata(params.target.as_deref(), Some(&params.file_path), None),
     })
@@ -70,6 +121,9 @@ pub(crate) fn deep_dive_metadata(params: &DeepDiveTool) -> Value {
         "depth": params.depth,
         "context_file": params.context_file,
         "workspace": params.workspace,
+        "project": params.project,
+        "disambiguator": params.disambiguator,
+        "max_tokens": params.max_tokens,
         "target": target_metadata(Some(&params.symbol), params.context_file.as_deref(), None),
     })
 }
@@ -91,6 +145,41 @@ pub(crate) fn patterns_metadata(params: &PatternsTool) -> Value {
     })
 }
 
+pub(crate) fn fast_audit_metadata(params: &AuditTool) -> Value {
+    json!({
+        "language": params.language,
+        "category": params.category,
+        "max_depth": params.max_depth,
+        "limit": params.limit,
+        "include_tests": params.include_tests,
+        "workspace": params.workspace,
+        "target": target_metadata(None, None, None),
+    })
+}
+
+pub(crate) fn syntax_query_metadata(params: &SyntaxQueryTool) -> Value {
+    json!({
+        "query": params.query,
+        "file_path": params.file_path,
+        "file_pattern": params.file_pattern,
+        "language": params.language,
+        "limit": params.limit,
+        "max_files": params.max_files,
+        "workspace": params.workspace,
+        "format": params.format,
+        "target": target_metadata(None, params.file_path.as_deref(), None),
+    })
+}
+
+pub(crate) fn get_file_annotations_metadata(params: &GetFileAnnotationsTool) -> Value {
+    json!({
+        "file_path": params.file_path,
+        "workspace": params.workspace,
+        "format": params.format,
+        "target": target_metadata(None, Some(&params.file_path), None),
+    })
+}
+
 pub(crate) fn get_context_metadata(params: &GetContextTool) -> Value {
     json!({
         "query": params.query,
@@ -104,6 +193,7 @@ pub(crate) fn get_context_metadata(params: &GetContextTool) -> Value {
         "failing_test": params.failing_test,
         "max_hops": params.max_hops,
         "prefer_tests": params.prefer_tests,
+        "project": params.project,
         "workspace": params.workspace,
         "target": target_metadata(None, None, None),
     })
@@ -118,6 +208,22 @@ pub(crate) fn spillover_get_metadata(params: &SpilloverGetTool) -> Value {
     })
 }
 
+pub(crate) fn session_context_metadata(params: &SessionContextTool) -> Value {
+    json!({
+        "limit": params.limit,
+        "target": target_metadata(None, None, None),
+    })
+}
+
+pub(crate) fn task_status_metadata(params: &TaskStatusTool) -> Value {
+    json!({
+        "operation": params.operation,
+        "task_id": params.task_id,
+        "workspace_id": params.workspace_id,
+        "target": target_metadata(None, None, None),
+    })
+}
+
 pub(crate) fn blast_radius_metadata(params: &BlastRadiusTool) -> Value {
     json!({
         "symbol_ids": params.symbol_ids,
@@ -76,7 +76,7 @@ impl JulieServerHandler {
             }),
         );
         let input_bytes = Self::input_bytes_from_metadata(&metadata);
-        let result = match params.call_prepared(prepared) {
+        let result = match params.call_prepared(self, prepared).await {
             Ok(result) => result,
             Err(e) => {
                 let metadata = tool_targets::with_failure_kind(
@@ -5,10 +5,168 @@
 //! parameter failures as `invalid_params` (vs the default `internal_error`)
 //! lets MCP clients tell the difference between user mistakes (bad
 //! `workspace_id`) and genuine server errors.
+//!
+//! Every failure also carries a stable [`ToolErrorCode`] so agents can branch
+//! on the cause without parsing prose: `{"code": "workspace_not_indexed",
+//! "retryable": false}` in the `McpError` data, and under `"error"` in the
+//! structured content of tool results flagged `isError`.
 
 use rmcp::ErrorData as McpError;
+use rmcp::model::CallToolResult;
+use serde_json::{Value, json};
+
+use crate::tools::navigation::resolution::{
+    WorkspaceResolutionFailureKind, workspace_resolution_failure_kind,
+};
+
+/// Stable, client-facing error codes. The string forms are part of the tool
+/// contract: add new variants rather than renaming existing ones.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum ToolErrorCode {
+    /// The `workspace` parameter names no known workspace.
+    WorkspaceNotFound,
+    /// The workspace is known but still indexing or activating.
+    WorkspaceNotReady,
+    /// The primary workspace is being swapped.
+    WorkspaceBusy,
+    /// A known workspace could not be opened (root moved, unreadable).
+    WorkspaceActivationFailed,
+    /// The workspace has no index yet; run `manage_workspace index`.
+    WorkspaceNotIndexed,
+    /// A file path resolved outside the workspace root.
+    FileOutsideWorkspace,
+    /// The file is not on disk or not in the index.
+    FileNotFound,
+    /// No indexed symbol matches the requested name.
+    SymbolNotFound,
+    /// The file changed since it was indexed.
+    StaleIndex,
+    /// Parameters were rejected (empty match text, ambiguous target, ...).
+    InvalidInput,
+    /// SQLite reported the database busy or locked.
+    DatabaseLocked,
+    /// The request exceeded its deadline.
+    Timeout,
+    /// Too many tool calls are running or waiting in this session.
+    RateLimited,
+    /// Anything not classified above.
+    Internal,
+}
+
+impl ToolErrorCode {
+    pub(crate) fn as_str(self) -> &'static str {
+        match self {
+            Self::WorkspaceNotFound => "workspace_not_found",
+            Self::WorkspaceNotReady => "workspace_not_ready",
+            Self::WorkspaceBusy => "workspace_busy",
+            Self::WorkspaceActivationFailed => "workspace_activation_failed",
+            Self::WorkspaceNotIndexed => "workspace_not_indexed",
+            Self::FileOutsideWorkspace => "file_outside_workspace",
+            Self::FileNotFound => "file_not_found",
+            Self::SymbolNotFound => "symbol_not_found",
+            Self::StaleIndex => "stale_index",
+            Self::InvalidInput => "invalid_input",
+            Self::DatabaseLocked => "database_locked",
+            Self::Timeout => "timeout",
+            Self::RateLimited => "rate_limited",
+            Self::Internal => "internal",
+        }
+    }
+
+    /// Whether the same call can succeed later without the caller changing
+    /// anything.
+    pub(crate) fn is_retryable(self) -> bool {
+        matches!(
+            self,
+            Self::WorkspaceNotReady
+                | Self::WorkspaceBusy
+                | Self::DatabaseLocked
+                | Self::Timeout
+                | Self::RateLimited
+        )
+    }
+
+    /// The JSON object attached to errors and error results.
+    pub(crate) fn to_data(self) -> Value {
+        json!({ "code": self.as_str(), "retryable": self.is_retryable() })
+    }
+}
 
-use crate::tools::navigation::resolution::workspace_resolution_failure_kind;
+/// Classify a tool failure: typed errors first (workspace resolution, the
+/// editing tools' failure kinds, SQLite busy/locked anywhere in the chain),
+/// then the wording of the well-known messages.
+pub(crate) fn tool_error_code(err: &anyhow::Error) -> ToolErrorCode {
+    if let Some(kind) = workspace_resolution_failure_kind(err) {
+        return match kind {
+            WorkspaceResolutionFailureKind::UnknownWorkspace => ToolErrorCode::WorkspaceNotFound,
+            WorkspaceResolutionFailureKind::WorkspaceNotReady => ToolErrorCode::WorkspaceNotReady,
+            WorkspaceResolutionFailureKind::PrimarySwapInProgress => ToolErrorCode::WorkspaceBusy,
+            WorkspaceResolutionFailureKind::AutoActivationFailed => {
+                ToolErrorCode::WorkspaceActivationFailed
+            }
+            WorkspaceResolutionFailureKind::FileOutsideWorkspace => {
+                ToolErrorCode::FileOutsideWorkspace
+            }
+        };
+    }
+
+    let editing_kind = [
+        crate::tools::editing::edit_file::failure_kind(err),
+        crate::tools::editing::rewrite_symbol::failure_kind(err),
+        crate::tools::refactoring::failure_kind(err),
+    ]
+    .into_iter()
+    .find(|kind| *kind != "execution_error");
+    match editing_kind {
+        Some("symbol_not_found") => return ToolErrorCode::SymbolNotFound,
+        Some("stale_index") => return ToolErrorCode::StaleIndex,
+        Some(_) => return ToolErrorCode::InvalidInput,
+        None => {}
+    }
+
+    let locked = err.chain().any(|cause| {
+        cause
+            .downcast_ref::<rusqlite::Error>()
+            .and_then(rusqlite::Error::sqlite_error_code)
+            .is_some_and(|code| {
+                matches!(
+                    code,
+                    rusqlite::ErrorCode::DatabaseBusy | rusqlite::ErrorCode::DatabaseLocked
+                )
+            })
+    });
+    if locked {
+        return ToolErrorCode::DatabaseLocked;
+    }
+
+    error_code_from_message(&format!("{err:#}"))
+}
+
+/// Classify by the wording of the messages tools return for common
+/// failures. Used for errors without a typed cause and for `isError` results,
+/// which only carry text.
+pub(crate) fn error_code_from_message(message: &str) -> ToolErrorCode {
+    let message = message.to_lowercase();
+    let has = |needle: &str| message.contains(needle);
+    if has("database is locked") || has("database is busy") {
+        ToolErrorCode::DatabaseLocked
+    } else if has("not indexed yet")
+        || has("database not available")
+        || has("database not found for workspace")
+    {
+        ToolErrorCode::WorkspaceNotIndexed
+    } else if has("file not found") || has("file not indexed") {
+        ToolErrorCode::FileNotFound
+    } else if has("symbol") && has("not found") {
+        ToolErrorCode::SymbolNotFound
+    } else if has("workspace") && has("not found") {
+        ToolErrorCode::WorkspaceNotFound
+    } else if has("timed out") || has("did not respond within") {
+        ToolErrorCode::Timeout
+    } else {
+        ToolErrorCode::Internal
+    }
+}
 
 /// Map a tool failure to the appropriate `McpError`.
 ///
@@ -18,12 +176,38 @@ use crate::tools::navigation::resolution::workspace_resolution_failure_kind;
 /// * Everything else becomes `internal_error`.
 ///
 /// The error message is prefixed with `"<tool_name> failed: "` so callers can
-/// pass the same string to both metrics and the `McpError`.
+/// pass the same string to both metrics and the `McpError`. The data carries
+/// the [`ToolErrorCode`].
 pub(crate) fn classify_tool_failure(tool_name: &str, err: &anyhow::Error) -> McpError {
     let message = format!("{} failed: {}", tool_name, err);
+    let data = Some(tool_error_code(err).to_data());
     if workspace_resolution_failure_kind(err).is_some() {
-        McpError::invalid_params(message, None)
+        McpError::invalid_params(message, data)
     } else {
-        McpError::internal_error(message, None)
+        McpError::internal_error(message, data)
+    }
+}
+
+/// An `internal_error` carrying `code`, for failures raised by the handler
+/// itself rather than a tool (deadlines, primary workspace setup).
+pub(crate) fn coded_internal_error(code: ToolErrorCode, message: String) -> McpError {
+    McpError::internal_error(message, Some(code.to_data()))
+}
+
+/// Attach an error code to the structured content of a result flagged
+/// `isError`, classified from its text. Results that already carry structured
+/// content are left alone.
+pub(crate) fn with_error_code(mut result: CallToolResult) -> CallToolResult {
+    if result.is_error == Some(true) && result.structured_content.is_none() {
+        let text: String = result
+            .content
+            .iter()
+            .filter_map(|content| content.as_text())
+            .map(|text| text.text.as_str())
+            .collect::<Vec<_>>()
+            .join("\n");
+        result.structured_content =
+            Some(json!({ "error": error_code_from_message(&text).to_data() }));
     }
+    result
 }
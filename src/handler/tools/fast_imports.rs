@@ -0,0 +1,87 @@
+//! `fast_imports` MCP tool.
+
+use rmcp::{
+    ErrorData as McpError, handler::server::wrapper::Parameters, model::CallToolResult, tool,
+    tool_router,
+};
+use tracing::debug;
+
+use crate::handler::tools::error::classify_tool_failure;
+use crate::handler::workspace_resolution::resolve_workspace_filter;
+use crate::handler::{JulieServerHandler, tool_targets};
+use crate::tools::FastImportsTool;
+use crate::tools::metrics::session::ToolCallReport;
+
+#[tool_router(router = tool_router_fast_imports, vis = "pub(crate)")]
+impl JulieServerHandler {
+    #[tool(
+        name = "fast_imports",
+        description = "List what a module exports and every place it is imported (named, aliased, namespace and wildcard imports), grouped by importing project. Target a workspace file or a library name",
+        annotations(
+            title = "Find Module Imports",
+            read_only_hint = true,
+            destructive_hint = false,
+            idempotent_hint = true,
+            open_world_hint = false
+        )
+    )]
+    async fn fast_imports(
+        &self,
+        Parameters(params): Parameters<FastImportsTool>,
+    ) -> Result<CallToolResult, McpError> {
+        debug!("Fast imports: {:?}", params);
+        let start = std::time::Instant::now();
+        let metadata = tool_targets::fast_imports_metadata(&params);
+        let workspace_target =
+            match resolve_workspace_filter(params.workspace.as_deref(), self).await {
+                Ok(target) => target,
+                Err(error) => {
+                    let message = format!("fast_imports failed: {error}");
+                    self.record_tool_failure(
+                        "fast_imports",
+                        start.elapsed(),
+                        None,
+                        metadata.clone(),
+                        Vec::new(),
+                        Self::input_bytes_from_metadata(&metadata),
+                        &message,
+                    );
+                    return Err(classify_tool_failure("fast_imports", &error));
+                }
+            };
+        let workspace_snapshot = self
+            .metrics_workspace_binding_for_target(&workspace_target)
+            .await;
+        let result = match params.call_tool_with_target(self, &workspace_target).await {
+            Ok(result) => result,
+            Err(error) => {
+                let message = format!("fast_imports failed: {error}");
+                self.record_tool_failure(
+                    "fast_imports",
+                    start.elapsed(),
+                    workspace_snapshot.as_ref(),
+                    metadata.clone(),
+                    Vec::new(),
+                    Self::input_bytes_from_metadata(&metadata),
+                    &message,
+                );
+                return Err(classify_tool_failure("fast_imports", &error));
+            }
+        };
+        let report = ToolCallReport {
+            result_count: None,
+            input_bytes: Self::input_bytes_from_metadata(&metadata),
+            source_bytes: None,
+            output_bytes: Self::output_bytes_from_result(&result),
+            metadata,
+            source_file_paths: Self::extract_paths_from_result(&result),
+        };
+        self.record_tool_call(
+            "fast_imports",
+            start.elapsed(),
+            &report,
+            workspace_snapshot.as_ref(),
+        );
+        Ok(result)
+    }
+}
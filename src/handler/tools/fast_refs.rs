@@ -1,22 +1,25 @@
 //! `fast_refs` MCP tool.
 
 use rmcp::{
-    ErrorData as McpError, handler::server::wrapper::Parameters, model::CallToolResult, tool,
-    tool_router,
+    ErrorData as McpError, RoleServer, handler::server::wrapper::Parameters, model::CallToolResult,
+    service::RequestContext, tool, tool_router,
 };
 use tracing::debug;
 
+use crate::handler::progress::ProgressResultSink;
+use crate::handler::query_cache::CachedToolOutput;
 use crate::handler::tools::error::classify_tool_failure;
 use crate::handler::workspace_resolution::resolve_workspace_filter;
 use crate::handler::{JulieServerHandler, tool_targets};
 use crate::tools::FastRefsTool;
 use crate::tools::metrics::session::ToolCallReport;
+use crate::tools::streaming::ResultSink;
 
 #[tool_router(router = tool_router_fast_refs, vis = "pub(crate)")]
 impl JulieServerHandler {
     #[tool(
         name = "fast_refs",
-        description = "Find all references to a symbol across the codebase. Required before modifying any symbol. Use `reference_kind` to filter (calls, type-uses, etc.). For a broader view including definition and callers in one call, use deep_dive instead.",
+        description = "Find all references to a symbol across the codebase. Required before modifying any symbol. Use `reference_kind` to filter (calls, type-uses, etc.). Set `include_textual` to also list comment/string mentions (config keys, reflection) as low-confidence textual matches. For a broader view including definition and callers in one call, use deep_dive instead.",
         annotations(
             title = "Find References",
             read_only_hint = true,
@@ -28,6 +31,7 @@ impl JulieServerHandler {
     async fn fast_refs(
         &self,
         Parameters(params): Parameters<FastRefsTool>,
+        context: RequestContext<RoleServer>,
     ) -> Result<CallToolResult, McpError> {
         debug!("⚡ Fast find references: {:?}", params);
         let start = std::time::Instant::now();
@@ -57,20 +61,46 @@ impl JulieServerHandler {
         let workspace_snapshot = self
             .metrics_workspace_binding_for_target(&workspace_target)
             .await;
-        let result = match params.call_tool_with_target(self, &workspace_target).await {
-            Ok(result) => result,
-            Err(e) => {
-                let message = format!("fast_refs failed: {}", e);
-                self.record_tool_failure(
-                    "fast_refs",
-                    start.elapsed(),
-                    workspace_snapshot.as_ref(),
-                    metadata.clone(),
-                    Vec::new(),
-                    Self::input_bytes_from_metadata(&metadata),
-                    &message,
-                );
-                return Err(classify_tool_failure("fast_refs", &e));
+        let cache_key = self.query_cache_key("fast_refs", &workspace_target, &params);
+        let cached = cache_key.as_ref().and_then(|key| self.query_cache.get(key));
+        let result = if let Some(cached) = cached {
+            debug!("fast_refs served from query cache");
+            cached.result
+        } else {
+            let sink = ProgressResultSink::for_request(&context);
+            match params
+                .call_tool_streaming(
+                    self,
+                    &workspace_target,
+                    sink.as_ref().map(|sink| sink as &dyn ResultSink),
+                )
+                .await
+            {
+                Ok(result) => {
+                    if let Some(key) = cache_key {
+                        self.query_cache.insert(
+                            key,
+                            CachedToolOutput {
+                                result: result.clone(),
+                                search_execution: None,
+                            },
+                        );
+                    }
+                    result
+                }
+                Err(e) => {
+                    let message = format!("fast_refs failed: {}", e);
+                    self.record_tool_failure(
+                        "fast_refs",
+                        start.elapsed(),
+                        workspace_snapshot.as_ref(),
+                        metadata.clone(),
+                        Vec::new(),
+                        Self::input_bytes_from_metadata(&metadata),
+                        &message,
+                    );
+                    return Err(classify_tool_failure("fast_refs", &e));
+                }
             }
         };
         let output_bytes = Self::output_bytes_from_result(&result);
@@ -1,22 +1,25 @@
 //! `fast_search` MCP tool.
 
 use rmcp::{
-    ErrorData as McpError, handler::server::wrapper::Parameters, model::CallToolResult, tool,
-    tool_router,
+    ErrorData as McpError, RoleServer, handler::server::wrapper::Parameters, model::CallToolResult,
+    service::RequestContext, tool, tool_router,
 };
 use tracing::debug;
 
+use crate::handler::progress::ProgressResultSink;
+use crate::handler::query_cache::CachedToolOutput;
 use crate::handler::tools::error::classify_tool_failure;
 use crate::handler::workspace_resolution::resolve_workspace_filter;
 use crate::handler::{JulieServerHandler, search_telemetry};
 use crate::tools::metrics::session::ToolCallReport;
-use crate::tools::search::FastSearchParams;
+use crate::tools::search::{FastSearchExecution, FastSearchParams, SearchBackend};
+use crate::tools::streaming::ResultSink;
 
 #[tool_router(router = tool_router_fast_search, vis = "pub(crate)")]
 impl JulieServerHandler {
     #[tool(
         name = "fast_search",
-        description = "Search code and symbols using unified code-aware full-text search. Supports multi-word queries with AND/OR logic, exact symbol name matches, file-path fragments, and conceptual semantic search. Optional `regions` restricts lexical line matches to stored comment, doc_comment/docstring, string_literal, or embedded spans. Optional `backend`: omitted/default lexical returns mixed file+symbol hits and may show labeled semantic fallback candidates on identifier-like zero-hit queries when embeddings are ready; explicit `lexical` stays pure lexical; `semantic` and `hybrid` are symbol-only concept search. Use lexical for file/path or region queries.",
+        description = "Search code and symbols using unified code-aware full-text search. Supports multi-word queries with AND/OR logic, exact symbol name matches, file-path fragments, and conceptual semantic search. Optional `regions` restricts lexical line matches to stored comment, doc_comment/docstring, string_literal, or embedded spans. Optional `backend`: omitted/default lexical returns mixed file+symbol hits and may show labeled semantic fallback candidates on identifier-like zero-hit queries when embeddings are ready; explicit `lexical` stays pure lexical; `semantic` and `hybrid` are symbol-only concept search. Use lexical for file/path or region queries. Optional `mode: \"regex\"` matches `query` as a regular expression against indexed file contents and labels each matching line with its enclosing symbol; `mode: \"todos\"` lists TODO/FIXME/HACK comments grouped by file with their enclosing symbol, owner and age, filtered by `query` (`*` for all); `mode: \"topics\"` clusters embedded symbols into named semantic topics (auth, billing, parsing...) and lists each with its size, directories and central symbols, filtered by `query` (`*` for all).",
         annotations(
             title = "Fast Code Search",
             read_only_hint = true,
@@ -28,6 +31,7 @@ impl JulieServerHandler {
     async fn fast_search(
         &self,
         Parameters(params): Parameters<FastSearchParams>,
+        context: RequestContext<RoleServer>,
     ) -> Result<CallToolResult, McpError> {
         debug!("⚡ Fast search: {:?}", params);
         let start = std::time::Instant::now();
@@ -61,28 +65,56 @@ impl JulieServerHandler {
         let workspace_snapshot = self
             .metrics_workspace_binding_for_target(&workspace_target)
             .await;
-        let executed = match params
-            .execute_with_trace_with_target(self, workspace_target)
-            .await
-        {
-            Ok(executed) => executed,
-            Err(e) => {
-                let metadata = search_telemetry::fast_search_metadata_with_regions(
-                    &params.search,
-                    params.regions.as_deref(),
-                    None,
-                );
-                let message = format!("fast_search failed: {}", e);
-                self.record_tool_failure(
-                    "fast_search",
-                    start.elapsed(),
-                    workspace_snapshot.as_ref(),
-                    metadata.clone(),
-                    Vec::new(),
-                    Self::input_bytes_from_metadata(&metadata),
-                    &message,
-                );
-                return Err(classify_tool_failure("fast_search", &e));
+        let cache_key = self.query_cache_key("fast_search", &workspace_target, &params);
+        let cached = cache_key.as_ref().and_then(|key| self.query_cache.get(key));
+        let executed = if let Some(cached) = cached {
+            debug!("fast_search served from query cache");
+            FastSearchExecution {
+                result: cached.result,
+                execution: cached.search_execution,
+            }
+        } else {
+            let sink = ProgressResultSink::for_request(&context);
+            match params
+                .execute_with_trace_streaming(
+                    self,
+                    workspace_target,
+                    sink.as_ref().map(|sink| sink as &dyn ResultSink),
+                )
+                .await
+            {
+                Ok(executed) => {
+                    if let Some(key) = cache_key
+                        && is_cacheable(&params, &executed)
+                    {
+                        self.query_cache.insert(
+                            key,
+                            CachedToolOutput {
+                                result: executed.result.clone(),
+                                search_execution: executed.execution.clone(),
+                            },
+                        );
+                    }
+                    executed
+                }
+                Err(e) => {
+                    let metadata = search_telemetry::fast_search_metadata_with_regions(
+                        &params.search,
+                        params.regions.as_deref(),
+                        None,
+                    );
+                    let message = format!("fast_search failed: {}", e);
+                    self.record_tool_failure(
+                        "fast_search",
+                        start.elapsed(),
+                        workspace_snapshot.as_ref(),
+                        metadata.clone(),
+                        Vec::new(),
+                        Self::input_bytes_from_metadata(&metadata),
+                        &message,
+                    );
+                    return Err(classify_tool_failure("fast_search", &e));
+                }
             }
         };
         let metadata = search_telemetry::fast_search_metadata_with_regions(
@@ -114,3 +146,24 @@ impl JulieServerHandler {
         Ok(result)
     }
 }
+
+/// Zero-hit and semantic results depend on embedding readiness, which changes
+/// without a write to the index, so only settled lexical hits are cached.
+fn is_cacheable(params: &FastSearchParams, executed: &FastSearchExecution) -> bool {
+    if matches!(
+        params.search.backend,
+        Some(SearchBackend::Semantic | SearchBackend::Hybrid)
+    ) {
+        return false;
+    }
+    executed.execution.as_ref().is_some_and(|execution| {
+        !execution.hits.is_empty()
+            && !execution.trace.strategy_id.contains("semantic")
+            // A regex scan cut short by its time budget may differ next run.
+            && execution.trace.strategy_id != "fast_search_regex_partial"
+            // Task owners and ages come from git, which commits change.
+            && execution.trace.strategy_id != "fast_search_todos"
+            // Topics come from embeddings, which change without an index write.
+            && execution.trace.strategy_id != "fast_search_topics"
+    })
+}
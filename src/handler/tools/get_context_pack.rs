@@ -0,0 +1,87 @@
+//! `get_context_pack` MCP tool.
+
+use rmcp::{
+    ErrorData as McpError, handler::server::wrapper::Parameters, model::CallToolResult, tool,
+    tool_router,
+};
+use tracing::debug;
+
+use crate::handler::tools::error::classify_tool_failure;
+use crate::handler::workspace_resolution::resolve_workspace_filter;
+use crate::handler::{JulieServerHandler, tool_targets};
+use crate::tools::GetContextPackTool;
+use crate::tools::metrics::session::ToolCallReport;
+
+#[tool_router(router = tool_router_get_context_pack, vis = "pub(crate)")]
+impl JulieServerHandler {
+    #[tool(
+        name = "get_context_pack",
+        description = "Everything needed to edit one symbol, in one token-budgeted bundle: its definition (signature, type, doc comment, body), then its callers, callees and related types, most relevant first. Sections are dropped from the end when max_tokens runs out. Use before editing a function instead of chaining deep_dive, fast_refs and fast_signature",
+        annotations(
+            title = "Get Symbol Context Pack",
+            read_only_hint = true,
+            destructive_hint = false,
+            idempotent_hint = true,
+            open_world_hint = false
+        )
+    )]
+    async fn get_context_pack(
+        &self,
+        Parameters(params): Parameters<GetContextPackTool>,
+    ) -> Result<CallToolResult, McpError> {
+        debug!("Get context pack: {:?}", params);
+        let start = std::time::Instant::now();
+        let metadata = tool_targets::get_context_pack_metadata(&params);
+        let workspace_target =
+            match resolve_workspace_filter(params.workspace.as_deref(), self).await {
+                Ok(target) => target,
+                Err(error) => {
+                    let message = format!("get_context_pack failed: {error}");
+                    self.record_tool_failure(
+                        "get_context_pack",
+                        start.elapsed(),
+                        None,
+                        metadata.clone(),
+                        Vec::new(),
+                        Self::input_bytes_from_metadata(&metadata),
+                        &message,
+                    );
+                    return Err(classify_tool_failure("get_context_pack", &error));
+                }
+            };
+        let workspace_snapshot = self
+            .metrics_workspace_binding_for_target(&workspace_target)
+            .await;
+        let result = match params.call_tool_with_target(self, &workspace_target).await {
+            Ok(result) => result,
+            Err(error) => {
+                let message = format!("get_context_pack failed: {error}");
+                self.record_tool_failure(
+                    "get_context_pack",
+                    start.elapsed(),
+                    workspace_snapshot.as_ref(),
+                    metadata.clone(),
+                    Vec::new(),
+                    Self::input_bytes_from_metadata(&metadata),
+                    &message,
+                );
+                return Err(classify_tool_failure("get_context_pack", &error));
+            }
+        };
+        let report = ToolCallReport {
+            result_count: None,
+            input_bytes: Self::input_bytes_from_metadata(&metadata),
+            source_bytes: None,
+            output_bytes: Self::output_bytes_from_result(&result),
+            metadata,
+            source_file_paths: Self::extract_paths_from_result(&result),
+        };
+        self.record_tool_call(
+            "get_context_pack",
+            start.elapsed(),
+            &report,
+            workspace_snapshot.as_ref(),
+        );
+        Ok(result)
+    }
+}
@@ -15,7 +15,7 @@ use crate::tools::metrics::session::ToolCallReport;
 impl JulieServerHandler {
     #[tool(
         name = "manage_workspace",
-        description = "Manage workspaces: index, open, register metadata, remove, list, refresh, stats, and health-check. For cross-workspace work, call open first, then pass the workspace_id to other tools.",
+        description = "Manage workspaces: index, open, register metadata, remove, list, refresh, stats, health-check, report capabilities (what was indexed from source and what needs a build), and roll back the last applied edit_file/rewrite_symbol/rename_symbol. For cross-workspace work, call open first, then pass the workspace_id to other tools.",
         annotations(
             title = "Manage Workspace",
             read_only_hint = false,
@@ -12,12 +12,21 @@ pub(crate) mod call_path;
 pub(crate) mod deep_dive;
 pub(crate) mod edit_file;
 pub(crate) mod error;
+pub(crate) mod fast_audit;
+pub(crate) mod fast_counterparts;
+pub(crate) mod fast_imports;
 pub(crate) mod fast_refs;
 pub(crate) mod fast_search;
+pub(crate) mod fast_signature;
 pub(crate) mod get_context;
+pub(crate) mod get_context_pack;
+pub(crate) mod get_file_annotations;
 pub(crate) mod get_symbols;
 pub(crate) mod manage_workspace;
 pub(crate) mod patterns;
 pub(crate) mod rename_symbol;
 pub(crate) mod rewrite_symbol;
+pub(crate) mod session_context;
 pub(crate) mod spillover_get;
+pub(crate) mod syntax_query;
+pub(crate) mod task_status;
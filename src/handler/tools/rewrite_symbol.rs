@@ -74,7 +74,7 @@ impl JulieServerHandler {
             .and_then(serde_json::Value::as_str)
             .map(|path| vec![path.to_string()])
             .unwrap_or_else(|| params.file_path.clone().into_iter().collect::<Vec<_>>());
-        let result = match params.call_prepared(prepared) {
+        let result = match params.call_prepared(self, prepared).await {
             Ok(result) => result,
             Err(e) => {
                 let metadata = tool_targets::with_failure_kind(
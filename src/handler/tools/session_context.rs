@@ -0,0 +1,67 @@
+//! `session_context` MCP tool.
+
+use rmcp::{
+    ErrorData as McpError, handler::server::wrapper::Parameters, model::CallToolResult, tool,
+    tool_router,
+};
+use tracing::debug;
+
+use crate::handler::tools::error::classify_tool_failure;
+use crate::handler::{JulieServerHandler, tool_targets};
+use crate::tools::SessionContextTool;
+use crate::tools::metrics::session::ToolCallReport;
+
+#[tool_router(router = tool_router_session_context, vis = "pub(crate)")]
+impl JulieServerHandler {
+    #[tool(
+        name = "session_context",
+        description = "List the symbols and files this session's tool calls targeted most, with call counts and the tools used, plus the last symbols touched. Call it after your context was truncated to re-orient without re-searching",
+        annotations(
+            title = "Session Hot Set",
+            read_only_hint = true,
+            destructive_hint = false,
+            idempotent_hint = true,
+            open_world_hint = false
+        )
+    )]
+    async fn session_context(
+        &self,
+        Parameters(params): Parameters<SessionContextTool>,
+    ) -> Result<CallToolResult, McpError> {
+        debug!("Session context: {:?}", params);
+        let start = std::time::Instant::now();
+        let workspace_snapshot = self.require_primary_workspace_binding().ok();
+        let metadata = tool_targets::session_context_metadata(&params);
+        let result = match params.call_tool(self).await {
+            Ok(result) => result,
+            Err(e) => {
+                let message = format!("session_context failed: {}", e);
+                self.record_tool_failure(
+                    "session_context",
+                    start.elapsed(),
+                    workspace_snapshot.as_ref(),
+                    metadata.clone(),
+                    Vec::new(),
+                    Self::input_bytes_from_metadata(&metadata),
+                    &message,
+                );
+                return Err(classify_tool_failure("session_context", &e));
+            }
+        };
+        let report = ToolCallReport {
+            result_count: None,
+            input_bytes: Self::input_bytes_from_metadata(&metadata),
+            source_bytes: None,
+            output_bytes: Self::output_bytes_from_result(&result),
+            metadata,
+            source_file_paths: Vec::new(),
+        };
+        self.record_tool_call(
+            "session_context",
+            start.elapsed(),
+            &report,
+            workspace_snapshot.as_ref(),
+        );
+        Ok(result)
+    }
+}
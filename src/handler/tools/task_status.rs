@@ -0,0 +1,67 @@
+//! `task_status` MCP tool.
+
+use rmcp::{
+    ErrorData as McpError, handler::server::wrapper::Parameters, model::CallToolResult, tool,
+    tool_router,
+};
+use tracing::debug;
+
+use crate::handler::tools::error::classify_tool_failure;
+use crate::handler::{JulieServerHandler, tool_targets};
+use crate::tools::TaskStatusTool;
+use crate::tools::metrics::session::ToolCallReport;
+
+#[tool_router(router = tool_router_task_status, vis = "pub(crate)")]
+impl JulieServerHandler {
+    #[tool(
+        name = "task_status",
+        description = "Show what julie is doing in the background (indexing, embedding, maintenance, file-watcher backlog) with per-task progress and ETA; pause or resume a task by id.",
+        annotations(
+            title = "Background Task Status",
+            read_only_hint = false,
+            destructive_hint = false,
+            idempotent_hint = true,
+            open_world_hint = false
+        )
+    )]
+    async fn task_status(
+        &self,
+        Parameters(params): Parameters<TaskStatusTool>,
+    ) -> Result<CallToolResult, McpError> {
+        debug!("Task status: {:?}", params);
+        let start = std::time::Instant::now();
+        let workspace_snapshot = self.require_primary_workspace_binding().ok();
+        let metadata = tool_targets::task_status_metadata(&params);
+        let result = match params.call_tool(self).await {
+            Ok(result) => result,
+            Err(e) => {
+                let message = format!("task_status failed: {}", e);
+                self.record_tool_failure(
+                    "task_status",
+                    start.elapsed(),
+                    workspace_snapshot.as_ref(),
+                    metadata.clone(),
+                    Vec::new(),
+                    Self::input_bytes_from_metadata(&metadata),
+                    &message,
+                );
+                return Err(classify_tool_failure("task_status", &e));
+            }
+        };
+        let report = ToolCallReport {
+            result_count: None,
+            input_bytes: Self::input_bytes_from_metadata(&metadata),
+            source_bytes: None,
+            output_bytes: Self::output_bytes_from_result(&result),
+            metadata,
+            source_file_paths: Vec::new(),
+        };
+        self.record_tool_call(
+            "task_status",
+            start.elapsed(),
+            &report,
+            workspace_snapshot.as_ref(),
+        );
+        Ok(result)
+    }
+}
@@ -3,10 +3,11 @@ use crate::handler::session_workspace::PrimaryWorkspaceBinding;
 use crate::search::projection::TANTIVY_PROJECTION_NAME;
 use anyhow::Result;
 
-use super::evaluation::{overall_from_planes, readiness_from_data_plane};
+use super::evaluation::{overall_from_levels, overall_from_planes, readiness_from_data_plane};
 use super::{
-    ControlPlaneHealth, DaemonLifecycleState, HealthLevel, ProjectionFreshness, RuntimePlaneHealth,
-    SystemHealthSnapshot, SystemStatus, WatcherState, build_data_plane, project_embedding_runtime,
+    ControlPlaneHealth, DaemonLifecycleState, GrammarHealth, HealthLevel, MemoryHealth,
+    ProjectionFreshness, RecoveryHealth, RuntimePlaneHealth, SystemHealthSnapshot, SystemStatus,
+    WatcherState, build_data_plane, project_embedding_runtime,
 };
 
 /// Centralized health checker used by all tools.
@@ -82,12 +83,17 @@ impl HealthChecker {
         let data_plane = build_data_plane(handler, &primary).await?;
         let runtime_plane = Self::build_runtime_plane(handler).await?;
         let readiness = readiness_from_data_plane(&data_plane);
-        let overall = overall_from_planes(
-            control_plane.level,
-            data_plane.level,
-            runtime_plane.level,
-            handler.embedding_service.is_some(),
-        );
+        let overall = overall_from_levels(&[
+            overall_from_planes(
+                control_plane.level,
+                data_plane.level,
+                runtime_plane.level,
+                handler.embedding_service.is_some(),
+            ),
+            runtime_plane.grammars.level,
+            runtime_plane.recovery.level,
+            runtime_plane.memory.level,
+        ]);
 
         Ok(SystemHealthSnapshot {
             overall,
@@ -298,6 +304,15 @@ impl HealthChecker {
         Ok(RuntimePlaneHealth {
             level: embeddings.level,
             embeddings,
+            grammars: GrammarHealth::from_report(
+                julie_core::grammar_compat::grammar_compatibility(),
+            ),
+            recovery: RecoveryHealth::from_snapshot(
+                &julie_core::supervisor::Supervisor::global().snapshot(),
+            ),
+            memory: MemoryHealth::from_usage(
+                &julie_core::memory_budget::MemoryBudget::global().usage(),
+            ),
         })
     }
 }
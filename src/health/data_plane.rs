@@ -1,4 +1,5 @@
 use anyhow::Result;
+use julie_pipeline::indexing_core::partial_parse::describe_parse_diagnostics;
 use julie_pipeline::indexing_core::web_edges::WEB_EDGES_PROJECTION_NAME;
 
 use crate::{handler::JulieServerHandler, search::projection::TANTIVY_PROJECTION_NAME};
@@ -48,6 +49,8 @@ pub(crate) async fn build_data_plane(
                 repair_needed: false,
                 repair_issue_count: 0,
                 repair_reasons: Vec::new(),
+                parse_skipped_files: Vec::new(),
+                partially_indexed_files: Vec::new(),
                 detail: "No indexing runtime is attached because no primary workspace is bound"
                     .to_string(),
             };
@@ -170,7 +173,24 @@ pub(crate) async fn build_data_plane(
                     .collect(),
             };
 
-            let indexing = indexing_health(state.indexing_runtime.as_ref());
+            let mut indexing = indexing_health(state.indexing_runtime.as_ref());
+            indexing.parse_skipped_files = pooled_db
+                .as_ref()
+                .and_then(|db| db.list_parse_skips().ok())
+                .unwrap_or_default()
+                .into_iter()
+                .map(|skip| format!("{}: {}", skip.path, skip.detail.unwrap_or_default()))
+                .collect();
+            indexing.partially_indexed_files = pooled_db
+                .as_ref()
+                .and_then(|db| db.list_files_with_parse_diagnostics().ok())
+                .unwrap_or_default()
+                .into_iter()
+                .filter_map(|(path, diagnostics)| {
+                    describe_parse_diagnostics(&diagnostics)
+                        .map(|detail| format!("{path}: {detail}"))
+                })
+                .collect();
             let mut levels = vec![canonical_store.level, indexing.level];
             levels.extend(projections.iter().map(|projection| projection.level));
 
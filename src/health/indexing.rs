@@ -15,6 +15,8 @@ pub(crate) fn indexing_health(
             repair_needed: false,
             repair_issue_count: 0,
             repair_reasons: Vec::new(),
+            parse_skipped_files: Vec::new(),
+            partially_indexed_files: Vec::new(),
             detail: "Indexing idle".to_string(),
         };
     };
@@ -89,6 +91,8 @@ pub(crate) fn indexing_health(
         repair_needed,
         repair_issue_count,
         repair_reasons,
+        parse_skipped_files: Vec::new(),
+        partially_indexed_files: Vec::new(),
         detail,
     }
 }
@@ -21,7 +21,7 @@ pub(crate) use indexing::indexing_health;
 pub(crate) use projection::{ProjectionPolicy, projection_health_for_workspace};
 pub use types::{
     CanonicalStoreHealth, ControlPlaneHealth, DaemonLifecycleState, DataPlaneHealth,
-    EmbeddingRuntimeHealth, EmbeddingState, HealthLevel, IndexingHealth, ProjectionFreshness,
-    ProjectionHealth, ProjectionState, RuntimePlaneHealth, SystemHealthSnapshot, SystemStatus,
-    WatcherState,
+    EmbeddingRuntimeHealth, EmbeddingState, GrammarHealth, HealthLevel, IndexingHealth,
+    MemoryHealth, ProjectionFreshness, ProjectionHealth, ProjectionState, RecoveryHealth,
+    RuntimePlaneHealth, SystemHealthSnapshot, SystemStatus, WatcherState,
 };
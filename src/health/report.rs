@@ -1,6 +1,9 @@
 use super::ProjectionFreshness;
 use super::{HealthLevel, ProjectionState, SystemHealthSnapshot, SystemStatus};
 
+/// Parse-skipped files listed by path in the health report; the count covers all.
+const MAX_PARSE_SKIPS_LISTED: usize = 10;
+
 impl SystemHealthSnapshot {
     pub fn render_report(&self, detailed: bool) -> String {
         let mut report = String::from("JULIE SYSTEM HEALTH REPORT\n\n");
@@ -186,6 +189,36 @@ impl SystemHealthSnapshot {
                 self.data_plane.indexing.repair_reasons.join(", ")
             }
         ));
+        report.push_str(&format!(
+            "Parse-Skipped Files: {}\n",
+            self.data_plane.indexing.parse_skipped_files.len()
+        ));
+        for skipped in self
+            .data_plane
+            .indexing
+            .parse_skipped_files
+            .iter()
+            .take(MAX_PARSE_SKIPS_LISTED)
+        {
+            report.push_str(&format!("  {}\n", skipped));
+        }
+        if self.data_plane.indexing.parse_skipped_files.len() > MAX_PARSE_SKIPS_LISTED {
+            report.push_str(&format!(
+                "  ... {} more\n",
+                self.data_plane.indexing.parse_skipped_files.len() - MAX_PARSE_SKIPS_LISTED
+            ));
+        }
+        let partial = &self.data_plane.indexing.partially_indexed_files;
+        report.push_str(&format!("Partially Indexed Files: {}\n", partial.len()));
+        for file in partial.iter().take(MAX_PARSE_SKIPS_LISTED) {
+            report.push_str(&format!("  {}\n", file));
+        }
+        if partial.len() > MAX_PARSE_SKIPS_LISTED {
+            report.push_str(&format!(
+                "  ... {} more\n",
+                partial.len() - MAX_PARSE_SKIPS_LISTED
+            ));
+        }
         report.push_str(&format!(
             "Indexing Detail: {}\n",
             self.data_plane.indexing.detail
@@ -256,6 +289,43 @@ impl SystemHealthSnapshot {
             "Query Fallback: {}\n",
             self.runtime_plane.embeddings.query_fallback
         ));
+        report.push_str("Grammar Compatibility\n");
+        report.push_str(&format!(
+            "Grammar Status: {} ({} languages checked)\n",
+            self.runtime_plane.grammars.level.label(),
+            self.runtime_plane.grammars.checked_languages
+        ));
+        for warning in &self.runtime_plane.grammars.warnings {
+            report.push_str(&format!("⚠️ {warning}\n"));
+        }
+        report.push_str("Background Recovery\n");
+        report.push_str(&format!(
+            "Recovery Status: {} ({} panics, {} restarts)\n",
+            self.runtime_plane.recovery.level.label(),
+            self.runtime_plane.recovery.panics,
+            self.runtime_plane.recovery.restarts
+        ));
+        for subsystem in &self.runtime_plane.recovery.subsystems {
+            report.push_str(&format!("⚠️ {subsystem}\n"));
+        }
+        let memory = &self.runtime_plane.memory;
+        report.push_str("Memory Budget\n");
+        report.push_str(&format!(
+            "Memory Status: {} ({:.1} MB used of {}, peak {:.1} MB)\n",
+            memory.level.label(),
+            memory.used_mb,
+            memory
+                .budget_mb
+                .map(|budget| format!("{budget:.1} MB"))
+                .unwrap_or_else(|| "no budget".to_string()),
+            memory.peak_mb
+        ));
+        for component in &memory.components {
+            report.push_str(&format!("  {component}\n"));
+        }
+        if memory.shrunk_batches > 0 {
+            report.push_str(&format!("Batches Shrunk: {}\n", memory.shrunk_batches));
+        }
 
         report
     }
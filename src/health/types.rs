@@ -72,6 +72,12 @@ pub struct IndexingHealth {
     pub repair_needed: bool,
     pub repair_issue_count: usize,
     pub repair_reasons: Vec<String>,
+    /// Files indexed text-only because parsing was skipped, as
+    /// `path: reason`. Informational; does not affect the level.
+    pub parse_skipped_files: Vec<String>,
+    /// Files with syntax errors, indexed from the code around them, as
+    /// `path: N syntax errors, first at line L`. Informational.
+    pub partially_indexed_files: Vec<String>,
     pub detail: String,
 }
 
@@ -104,10 +110,117 @@ pub struct EmbeddingRuntimeHealth {
     pub query_fallback: String,
 }
 
+/// Tree-sitter grammars checked against the node kinds extraction relies on
+/// (`julie_core::grammar_compat`).
+#[derive(Debug, Clone, Serialize)]
+pub struct GrammarHealth {
+    pub level: HealthLevel,
+    pub checked_languages: usize,
+    pub warnings: Vec<String>,
+}
+
+impl GrammarHealth {
+    pub fn from_report(report: &julie_core::grammar_compat::GrammarCompatReport) -> Self {
+        Self {
+            level: if report.is_compatible() {
+                HealthLevel::Ready
+            } else {
+                HealthLevel::Degraded
+            },
+            checked_languages: report.checked,
+            warnings: report.issues.iter().map(ToString::to_string).collect(),
+        }
+    }
+}
+
+/// Background panics caught by `julie_core::supervisor` since startup.
+#[derive(Debug, Clone, Serialize)]
+pub struct RecoveryHealth {
+    /// Degraded once a subsystem panicked and stayed down.
+    pub level: HealthLevel,
+    pub panics: u64,
+    pub restarts: u64,
+    /// One line per subsystem that panicked, with its most recent panic.
+    pub subsystems: Vec<String>,
+}
+
+impl RecoveryHealth {
+    pub fn from_snapshot(snapshot: &[julie_core::supervisor::SubsystemRecovery]) -> Self {
+        Self {
+            level: if snapshot.iter().any(|entry| entry.unrecovered > 0) {
+                HealthLevel::Degraded
+            } else {
+                HealthLevel::Ready
+            },
+            panics: snapshot.iter().map(|entry| entry.panics).sum(),
+            restarts: snapshot.iter().map(|entry| entry.restarts).sum(),
+            subsystems: snapshot
+                .iter()
+                .map(|entry| {
+                    format!(
+                        "{}: {} panics, {} restarts, {} unrecovered (last in {}: {})",
+                        entry.subsystem,
+                        entry.panics,
+                        entry.restarts,
+                        entry.unrecovered,
+                        entry.last_panic.task,
+                        entry.last_panic.message
+                    )
+                })
+                .collect(),
+        }
+    }
+}
+
+/// Approximate memory of the buffers tracked by `julie_core::memory_budget`.
+#[derive(Debug, Clone, Serialize)]
+pub struct MemoryHealth {
+    /// Degraded while usage is at or over the budget.
+    pub level: HealthLevel,
+    pub budget_mb: Option<f64>,
+    pub used_mb: f64,
+    pub peak_mb: f64,
+    /// One line per tracked component with its current size.
+    pub components: Vec<String>,
+    /// Batches shrunk to stay within the budget since startup.
+    pub shrunk_batches: u64,
+}
+
+impl MemoryHealth {
+    pub fn from_usage(usage: &julie_core::memory_budget::MemoryUsage) -> Self {
+        let mb = |bytes: u64| bytes as f64 / (1024.0 * 1024.0);
+        Self {
+            level: if usage.over_budget() {
+                HealthLevel::Degraded
+            } else {
+                HealthLevel::Ready
+            },
+            budget_mb: usage.limit_bytes.map(mb),
+            used_mb: mb(usage.used_bytes()),
+            peak_mb: mb(usage.peak_bytes),
+            components: [
+                ("parser pool", usage.parser_pool_bytes),
+                ("batch buffers", usage.batch_buffer_bytes),
+                ("embedding batches", usage.embedding_batch_bytes),
+            ]
+            .into_iter()
+            .map(|(component, bytes)| format!("{component}: {:.1} MB", mb(bytes)))
+            .collect(),
+            shrunk_batches: usage.shrunk_batches,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize)]
 pub struct RuntimePlaneHealth {
+    /// Embedding runtime level. Grammar, recovery and memory problems are
+    /// reported separately in `grammars`, `recovery` and `memory` and only
+    /// count toward the overall level.
     pub level: HealthLevel,
     pub embeddings: EmbeddingRuntimeHealth,
+    pub grammars: GrammarHealth,
+    pub recovery: RecoveryHealth,
+    pub memory: MemoryHealth,
 }
 
 #[derive(Debug, Clone, Serialize)]
@@ -165,4 +278,59 @@ mod tests {
         let store = store_with(HealthLevel::Unavailable, 0, 0.0);
         assert_eq!(store.sqlite_status_label(), "NOT CONNECTED");
     }
+
+    #[test]
+    fn recovery_health_degrades_only_for_unrecovered_panics() {
+        use julie_core::supervisor::{Subsystem, Supervisor};
+
+        let supervisor = Supervisor::new();
+        assert_eq!(
+            RecoveryHealth::from_snapshot(&supervisor.snapshot()).level,
+            HealthLevel::Ready
+        );
+
+        supervisor.record_panic(Subsystem::Watcher, "events", "boom", true);
+        supervisor.record_panic(Subsystem::Watcher, "events", "boom again", true);
+        let recovered = RecoveryHealth::from_snapshot(&supervisor.snapshot());
+        assert_eq!(recovered.level, HealthLevel::Ready);
+        assert_eq!((recovered.panics, recovered.restarts), (2, 2));
+        assert_eq!(
+            recovered.subsystems,
+            ["watcher: 2 panics, 2 restarts, 0 unrecovered (last in events: boom again)"]
+        );
+
+        supervisor.record_panic(Subsystem::Embeddings, "primary", "oom", false);
+        let degraded = RecoveryHealth::from_snapshot(&supervisor.snapshot());
+        assert_eq!(degraded.level, HealthLevel::Degraded);
+        assert_eq!(degraded.panics, 3);
+        assert!(degraded.subsystems[0].starts_with("embeddings:"));
+    }
+
+    #[test]
+    fn memory_health_degrades_only_over_budget() {
+        use julie_core::memory_budget::{MemoryBudget, MemoryComponent};
+
+        const MB: u64 = 1024 * 1024;
+        let budget = MemoryBudget::new(Some(64 * MB));
+        let _parsers = budget.charge(MemoryComponent::ParserPool, 2 * MB);
+        let mut batch = budget.charge(MemoryComponent::BatchBuffers, 30 * MB);
+
+        let health = MemoryHealth::from_usage(&budget.usage());
+        assert_eq!(health.level, HealthLevel::Ready);
+        assert_eq!(health.budget_mb, Some(64.0));
+        assert_eq!(health.used_mb, 32.0);
+        assert_eq!(
+            health.components,
+            [
+                "parser pool: 2.0 MB",
+                "batch buffers: 30.0 MB",
+                "embedding batches: 0.0 MB"
+            ]
+        );
+
+        batch.resize(70 * MB);
+        let health = MemoryHealth::from_usage(&budget.usage());
+        assert_eq!(health.level, HealthLevel::Degraded);
+        assert_eq!(health.peak_mb, 72.0);
+    }
 }
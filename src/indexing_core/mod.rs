@@ -1,2 +1,4 @@
 //! Indexing pipeline modules — relocated to `julie_pipeline::indexing_core`.
-pub use julie_pipeline::indexing_core::{analysis, batch, discovery, extraction, persistence};
+pub use julie_pipeline::indexing_core::{
+    analysis, batch, checkpoint, discovery, export, extraction, persistence, shards,
+};
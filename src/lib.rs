@@ -10,12 +10,15 @@ pub use julie_core::database;
 pub mod embeddings;
 pub mod external_extract;
 pub mod extractors;
+#[cfg(feature = "grpc")]
+pub mod grpc;
 pub mod handler;
 pub mod health;
 pub mod indexing_core;
 pub mod language;
 pub mod leadership;
 pub mod logging;
+pub mod lsp;
 pub mod mcp_compat;
 pub use julie_index::search;
 pub mod startup;
@@ -0,0 +1,165 @@
+//! Conversions between the index and LSP: file URIs, UTF-16 positions and
+//! symbol kinds.
+//!
+//! The index stores workspace-relative paths with `/` separators, 1-based
+//! lines and byte columns; LSP speaks `file://` URIs, 0-based lines and
+//! UTF-16 columns.
+
+use std::path::{Path, PathBuf};
+
+/// `file://` URI of a path.
+pub fn path_to_uri(path: &Path) -> String {
+    let path = path.to_string_lossy().replace('\\', "/");
+    let mut uri = String::from("file://");
+    if !path.starts_with('/') {
+        // Windows drive path: file:///C:/...
+        uri.push('/');
+    }
+    for byte in path.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' | b'/' | b':' => {
+                uri.push(byte as char)
+            }
+            _ => uri.push_str(&format!("%{byte:02X}")),
+        }
+    }
+    uri
+}
+
+/// Path of a `file://` URI; `None` for other schemes.
+pub fn uri_to_path(uri: &str) -> Option<PathBuf> {
+    let rest = uri.strip_prefix("file://")?;
+    // Skip an authority (normally empty, `localhost` at most).
+    let path = &rest[rest.find('/')?..];
+    let mut bytes = Vec::with_capacity(path.len());
+    let mut iter = path.bytes();
+    while let Some(byte) = iter.next() {
+        if byte == b'%' {
+            let hex = [iter.next()?, iter.next()?];
+            bytes.push(u8::from_str_radix(std::str::from_utf8(&hex).ok()?, 16).ok()?);
+        } else {
+            bytes.push(byte);
+        }
+    }
+    let decoded = String::from_utf8(bytes).ok()?;
+    // `/C:/dir` is a Windows drive path.
+    let decoded = match decoded.as_bytes() {
+        [b'/', drive, b':', ..] if drive.is_ascii_alphabetic() => decoded[1..].to_string(),
+        _ => decoded,
+    };
+    Some(PathBuf::from(decoded))
+}
+
+/// Workspace-relative index path of `path`, when it lies in `root`.
+pub fn relative_path(root: &Path, path: &Path) -> Option<String> {
+    let relative = match path.strip_prefix(root) {
+        Ok(relative) => relative.to_path_buf(),
+        // The editor may name the file through a symlink.
+        Err(_) => path
+            .canonicalize()
+            .ok()?
+            .strip_prefix(root)
+            .ok()?
+            .to_path_buf(),
+    };
+    let relative = relative.to_string_lossy().replace('\\', "/");
+    (!relative.is_empty()).then_some(relative)
+}
+
+/// Byte offset in `line` of the UTF-16 column `character` (clamped to the
+/// line).
+pub fn utf16_to_byte(line: &str, character: u32) -> usize {
+    let mut units = 0;
+    for (index, ch) in line.char_indices() {
+        if units >= character as usize {
+            return index;
+        }
+        units += ch.len_utf16();
+    }
+    line.len()
+}
+
+/// UTF-16 column of the byte offset `byte` in `line` (clamped to the line).
+pub fn byte_to_utf16(line: &str, byte: usize) -> u32 {
+    let mut byte = byte.min(line.len());
+    while !line.is_char_boundary(byte) {
+        byte -= 1;
+    }
+    line[..byte].chars().map(char::len_utf16).sum::<usize>() as u32
+}
+
+fn is_identifier_char(ch: char) -> bool {
+    ch.is_alphanumeric() || ch == '_' || ch == '$'
+}
+
+/// The identifier under (or just before) the cursor at 0-based `line` and
+/// UTF-16 `character`.
+pub fn identifier_at(text: &str, line: u32, character: u32) -> Option<String> {
+    let line = text.lines().nth(line as usize)?;
+    let cursor = utf16_to_byte(line, character);
+    let start = line[..cursor]
+        .char_indices()
+        .rev()
+        .take_while(|&(_, ch)| is_identifier_char(ch))
+        .last()
+        .map_or(cursor, |(index, _)| index);
+    let end = line[cursor..]
+        .char_indices()
+        .find(|&(_, ch)| !is_identifier_char(ch))
+        .map_or(line.len(), |(offset, _)| cursor + offset);
+    let word = &line[start..end];
+    (!word.is_empty() && !word.starts_with(|ch: char| ch.is_ascii_digit()))
+        .then(|| word.to_string())
+}
+
+/// UTF-16 start and end columns of `name` as a whole word in `line`: the
+/// first occurrence at or after byte `from`, else the first in the line.
+pub fn name_columns(line: &str, name: &str, from: usize) -> Option<(u32, u32)> {
+    if name.is_empty() {
+        return None;
+    }
+    let whole_word = |index: usize| {
+        let before = line[..index].chars().next_back();
+        let after = line[index + name.len()..].chars().next();
+        !before.is_some_and(is_identifier_char) && !after.is_some_and(is_identifier_char)
+    };
+    let occurrences: Vec<usize> = line
+        .match_indices(name)
+        .map(|(index, _)| index)
+        .filter(|&index| whole_word(index))
+        .collect();
+    let start = occurrences
+        .iter()
+        .copied()
+        .find(|&index| index >= from)
+        .or_else(|| occurrences.first().copied())?;
+    Some((
+        byte_to_utf16(line, start),
+        byte_to_utf16(line, start + name.len()),
+    ))
+}
+
+/// LSP `SymbolKind` of an index symbol kind.
+pub fn symbol_kind(kind: &str) -> u32 {
+    match kind {
+        "file" => 1,
+        "module" | "import" | "export" => 2,
+        "namespace" => 3,
+        "package" => 4,
+        "class" => 5,
+        "method" => 6,
+        "property" => 7,
+        "field" => 8,
+        "constructor" | "destructor" => 9,
+        "enum" => 10,
+        "interface" | "trait" => 11,
+        "function" | "delegate" => 12,
+        "constant" => 14,
+        "enum_member" => 22,
+        "struct" | "union" => 23,
+        "event" => 24,
+        "operator" => 25,
+        "type" => 26,
+        _ => 13,
+    }
+}
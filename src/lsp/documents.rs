@@ -0,0 +1,100 @@
+//! The files the gateway answers about: the editor's open buffers, else the
+//! file on disk, addressed by workspace-relative path.
+
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+use serde_json::{Value, json};
+
+use super::convert::{byte_to_utf16, name_columns, path_to_uri, relative_path, uri_to_path};
+
+/// A name at an index position: 1-based `line`, byte `column` where the
+/// search for `name` on that line starts.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Place {
+    pub file_path: String,
+    pub line: u32,
+    pub column: u32,
+    pub name: String,
+}
+
+pub struct Documents {
+    root: PathBuf,
+    /// Open buffers by workspace-relative path.
+    open: HashMap<String, String>,
+}
+
+impl Documents {
+    pub fn new(root: PathBuf) -> Self {
+        Self {
+            root,
+            open: HashMap::new(),
+        }
+    }
+
+    /// Workspace-relative path of a document URI; `None` outside the
+    /// workspace.
+    pub fn relative(&self, uri: &str) -> Option<String> {
+        relative_path(&self.root, &uri_to_path(uri)?)
+    }
+
+    pub fn uri(&self, file_path: &str) -> String {
+        path_to_uri(&self.root.join(file_path))
+    }
+
+    pub fn open(&mut self, uri: &str, text: String) {
+        if let Some(path) = self.relative(uri) {
+            self.open.insert(path, text);
+        }
+    }
+
+    pub fn close(&mut self, uri: &str) {
+        if let Some(path) = self.relative(uri) {
+            self.open.remove(&path);
+        }
+    }
+
+    /// The open buffer of `file_path`, else its contents on disk.
+    pub fn text(&self, file_path: &str) -> Option<String> {
+        match self.open.get(file_path) {
+            Some(text) => Some(text.clone()),
+            None => std::fs::read_to_string(self.root.join(file_path)).ok(),
+        }
+    }
+
+    /// LSP `Location`s of `places`, each ranging over its name when the
+    /// name is found on its line, else empty at the column.
+    pub fn locations(&self, places: &[Place]) -> Vec<Value> {
+        let mut texts: HashMap<&str, Option<String>> = HashMap::new();
+        places
+            .iter()
+            .map(|place| {
+                let text = texts
+                    .entry(place.file_path.as_str())
+                    .or_insert_with(|| self.text(&place.file_path));
+                let line = place.line.saturating_sub(1);
+                let line_text = text
+                    .as_deref()
+                    .and_then(|text| text.lines().nth(line as usize))
+                    .unwrap_or("");
+                let (start, end) = name_columns(line_text, &place.name, place.column as usize)
+                    .unwrap_or_else(|| {
+                        let column = byte_to_utf16(line_text, place.column as usize);
+                        (column, column)
+                    });
+                json!({
+                    "uri": self.uri(&place.file_path),
+                    "range": range(line, start, line, end),
+                })
+            })
+            .collect()
+    }
+}
+
+/// LSP `Range` from 0-based lines and UTF-16 columns.
+pub fn range(start_line: u32, start: u32, end_line: u32, end: u32) -> Value {
+    json!({
+        "start": { "line": start_line, "character": start },
+        "end": { "line": end_line, "character": end },
+    })
+}
@@ -0,0 +1,312 @@
+//! The LSP requests, each answered by the tool logic MCP clients use:
+//! definitions and references come from `fast_refs`, workspace symbols from
+//! `fast_search`, document symbols from the file's indexed symbols.
+
+use std::collections::{HashMap, HashSet};
+
+use anyhow::Result;
+use julie_context::ToolContext;
+use serde_json::{Value, json};
+
+use super::convert::{byte_to_utf16, identifier_at, name_columns, symbol_kind, utf16_to_byte};
+use super::documents::{Documents, Place, range};
+use crate::extractors::{Relationship, Symbol, SymbolKind};
+use crate::tools::FastRefsTool;
+use crate::tools::navigation::resolution::{
+    WorkspaceTarget, compare_symbols_by_priority_and_context,
+};
+use crate::tools::search::FastSearchTool;
+
+/// Most references returned for one name.
+const MAX_REFERENCES: u32 = 500;
+/// Most workspace symbols returned for one query.
+const MAX_WORKSPACE_SYMBOLS: u32 = 100;
+
+/// A cursor: the document's workspace-relative path and the identifier
+/// under the position.
+pub struct Cursor {
+    pub file_path: String,
+    pub name: String,
+    /// 0-based line and UTF-16 range of the identifier.
+    pub line: u32,
+    pub start: u32,
+    pub end: u32,
+}
+
+impl Cursor {
+    /// The identifier at `position` in `file_path`, if there is one.
+    pub fn at(documents: &Documents, file_path: &str, position: &Value) -> Option<Self> {
+        let line = position.get("line")?.as_u64()? as u32;
+        let character = position.get("character")?.as_u64()? as u32;
+        let text = documents.text(file_path)?;
+        let name = identifier_at(&text, line, character)?;
+        let line_text = text.lines().nth(line as usize)?;
+        // The occurrence touching the cursor, not the first on the line.
+        let cursor = utf16_to_byte(line_text, character);
+        let from = cursor.saturating_sub(name.len());
+        let (start, end) = name_columns(line_text, &name, from)?;
+        Some(Self {
+            file_path: file_path.to_string(),
+            name,
+            line,
+            start,
+            end,
+        })
+    }
+}
+
+/// Definitions and references of the cursor's name, via `fast_refs`;
+/// definitions best first for a cursor in `cursor.file_path`.
+async fn lookup(
+    handler: &dyn ToolContext,
+    cursor: &Cursor,
+) -> Result<(Vec<Symbol>, Vec<Relationship>)> {
+    let tool = FastRefsTool {
+        symbol: cursor.name.clone(),
+        include_definition: true,
+        limit: MAX_REFERENCES,
+        workspace: None,
+        reference_kind: None,
+        include_textual: false,
+        project: None,
+        max_tokens: None,
+    };
+    let (mut definitions, references) = tool
+        .find_references_and_definitions(handler, WorkspaceTarget::Primary)
+        .await?;
+    definitions.sort_by(|a, b| {
+        compare_symbols_by_priority_and_context(a, b, Some(&cursor.file_path))
+            .then_with(|| a.file_path.cmp(&b.file_path))
+            .then_with(|| a.start_line.cmp(&b.start_line))
+    });
+    Ok((definitions, references))
+}
+
+fn definition_place(symbol: &Symbol) -> Place {
+    Place {
+        file_path: symbol.file_path.clone(),
+        line: symbol.start_line,
+        column: symbol.start_column,
+        name: symbol.name.clone(),
+    }
+}
+
+/// `textDocument/definition`: the definitions of the name under the cursor.
+pub async fn definition(
+    handler: &dyn ToolContext,
+    documents: &Documents,
+    cursor: &Cursor,
+) -> Result<Value> {
+    let (definitions, _) = lookup(handler, cursor).await?;
+    let places: Vec<Place> = definitions.iter().map(definition_place).collect();
+    Ok(Value::Array(documents.locations(&places)))
+}
+
+/// `textDocument/references`: every reference to the name under the cursor,
+/// in file and line order, with its definitions when `include_declaration`.
+pub async fn references(
+    handler: &dyn ToolContext,
+    documents: &Documents,
+    cursor: &Cursor,
+    include_declaration: bool,
+) -> Result<Value> {
+    let (definitions, references) = lookup(handler, cursor).await?;
+    let mut places: Vec<Place> = references
+        .iter()
+        .map(|reference| Place {
+            file_path: reference.file_path.clone(),
+            line: reference.line_number,
+            column: 0,
+            name: cursor.name.clone(),
+        })
+        .collect();
+    if include_declaration {
+        places.extend(definitions.iter().map(definition_place));
+    }
+    places.sort_by(|a, b| (&a.file_path, a.line).cmp(&(&b.file_path, b.line)));
+    let mut seen = HashSet::new();
+    places.retain(|place| seen.insert((place.file_path.clone(), place.line)));
+    Ok(Value::Array(documents.locations(&places)))
+}
+
+/// `textDocument/hover`: signature, doc comment and location of the best
+/// definition of the name under the cursor.
+pub async fn hover(handler: &dyn ToolContext, cursor: &Cursor) -> Result<Value> {
+    let (definitions, _) = lookup(handler, cursor).await?;
+    let Some(markdown) = hover_markdown(&definitions) else {
+        return Ok(Value::Null);
+    };
+    Ok(json!({
+        "contents": { "kind": "markdown", "value": markdown },
+        "range": range(cursor.line, cursor.start, cursor.line, cursor.end),
+    }))
+}
+
+/// Hover text for the first of `definitions`.
+pub fn hover_markdown(definitions: &[Symbol]) -> Option<String> {
+    let symbol = definitions.first()?;
+    let kind = symbol.kind.to_string();
+    let signature = symbol
+        .signature
+        .as_deref()
+        .filter(|signature| !signature.trim().is_empty())
+        .map_or_else(|| format!("{kind} {}", symbol.name), str::to_string);
+    let mut markdown = format!("```{}\n{signature}\n```", symbol.language);
+    if let Some(doc) = symbol
+        .doc_comment
+        .as_deref()
+        .map(str::trim)
+        .filter(|doc| !doc.is_empty())
+    {
+        markdown.push_str(&format!("\n\n{doc}"));
+    }
+    markdown.push_str(&format!(
+        "\n\n*{kind}* in `{}:{}`",
+        symbol.file_path, symbol.start_line
+    ));
+    if definitions.len() > 1 {
+        markdown.push_str(&format!(" (+{} more definitions)", definitions.len() - 1));
+    }
+    Some(markdown)
+}
+
+/// `textDocument/documentSymbol`: the file's indexed symbols as a tree.
+pub async fn document_symbols(
+    handler: &dyn ToolContext,
+    documents: &Documents,
+    file_path: &str,
+) -> Result<Value> {
+    let db = handler.primary_pooled_database().await?;
+    let path = file_path.to_string();
+    let symbols = tokio::task::spawn_blocking(move || db.get_symbols_for_file(&path)).await??;
+    let text = documents.text(file_path).unwrap_or_default();
+    Ok(Value::Array(document_symbol_tree(&symbols, &text)))
+}
+
+/// LSP `DocumentSymbol`s of a file's `symbols`, nested by parent. Imports
+/// are left out; a symbol whose parent is not in the file is top-level.
+pub fn document_symbol_tree(symbols: &[Symbol], text: &str) -> Vec<Value> {
+    let symbols: Vec<&Symbol> = symbols
+        .iter()
+        .filter(|symbol| symbol.kind != SymbolKind::Import)
+        .collect();
+    let ids: HashSet<&str> = symbols.iter().map(|symbol| symbol.id.as_str()).collect();
+    let mut children: HashMap<Option<&str>, Vec<&Symbol>> = HashMap::new();
+    for &symbol in &symbols {
+        let parent = symbol
+            .parent_id
+            .as_deref()
+            .filter(|parent| ids.contains(parent));
+        children.entry(parent).or_default().push(symbol);
+    }
+    for siblings in children.values_mut() {
+        siblings.sort_by_key(|symbol| (symbol.start_line, symbol.start_column));
+    }
+    let lines: Vec<&str> = text.lines().collect();
+    build_level(None, &children, &lines)
+}
+
+fn build_level(
+    parent: Option<&str>,
+    children: &HashMap<Option<&str>, Vec<&Symbol>>,
+    lines: &[&str],
+) -> Vec<Value> {
+    let Some(symbols) = children.get(&parent) else {
+        return Vec::new();
+    };
+    symbols
+        .iter()
+        .map(|symbol| {
+            let line_text = |line: u32| lines.get(line as usize).copied().unwrap_or("");
+            let start_line = symbol.start_line.saturating_sub(1);
+            let end_line = symbol.end_line.saturating_sub(1).max(start_line);
+            let start = byte_to_utf16(line_text(start_line), symbol.start_column as usize);
+            let end = byte_to_utf16(line_text(end_line), symbol.end_column as usize);
+            let end = if end_line == start_line {
+                end.max(start)
+            } else {
+                end
+            };
+            // The name within the declaration, else its start.
+            let (name_start, name_end) = name_columns(
+                line_text(start_line),
+                &symbol.name,
+                symbol.start_column as usize,
+            )
+            .filter(|&(name_start, name_end)| {
+                name_start >= start && (end_line > start_line || name_end <= end)
+            })
+            .unwrap_or((start, start));
+
+            let mut document_symbol = json!({
+                "name": symbol.name,
+                "kind": symbol_kind(&symbol.kind.to_string()),
+                "range": range(start_line, start, end_line, end),
+                "selectionRange": range(start_line, name_start, start_line, name_end),
+            });
+            if let Some(detail) = symbol
+                .signature
+                .as_deref()
+                .and_then(|signature| signature.lines().next())
+                .map(str::trim)
+                .filter(|detail| !detail.is_empty())
+            {
+                document_symbol["detail"] = json!(detail);
+            }
+            let nested = build_level(Some(symbol.id.as_str()), children, lines);
+            if !nested.is_empty() {
+                document_symbol["children"] = Value::Array(nested);
+            }
+            document_symbol
+        })
+        .collect()
+}
+
+/// `workspace/symbol`: the symbol hits of a `fast_search` for `query`.
+pub async fn workspace_symbols(
+    handler: &dyn ToolContext,
+    documents: &Documents,
+    query: &str,
+) -> Result<Value> {
+    if query.trim().is_empty() {
+        return Ok(json!([]));
+    }
+    let tool = FastSearchTool {
+        query: query.to_string(),
+        limit: MAX_WORKSPACE_SYMBOLS,
+        return_format: "locations".to_string(),
+        ..Default::default()
+    };
+    let hits = tool
+        .execute_with_trace(handler)
+        .await?
+        .execution
+        .map(|execution| execution.hits)
+        .unwrap_or_default();
+    let hits: Vec<_> = hits
+        .into_iter()
+        .filter(|hit| hit.symbol_id.is_some())
+        .collect();
+    let places: Vec<Place> = hits
+        .iter()
+        .map(|hit| Place {
+            file_path: hit.file.clone(),
+            line: hit.line.unwrap_or(1),
+            column: 0,
+            name: hit.name.clone(),
+        })
+        .collect();
+    let symbols = hits
+        .iter()
+        .zip(documents.locations(&places))
+        .map(|(hit, location)| {
+            json!({
+                "name": hit.name,
+                "kind": symbol_kind(&hit.kind),
+                "location": location,
+                "containerName": hit.file,
+            })
+        })
+        .collect();
+    Ok(Value::Array(symbols))
+}
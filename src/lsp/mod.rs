@@ -0,0 +1,239 @@
+//! Optional LSP gateway for editors that don't speak MCP.
+//!
+//! `julie-server lsp` indexes the workspace like the CLI tool commands, keeps
+//! it current with the file watcher, and speaks the Language Server Protocol
+//! over stdio. Definition, references, hover, document symbols and workspace
+//! symbols are translated to the same tool logic MCP clients use
+//! (`fast_refs`, `fast_search`, the file's indexed symbols) against one
+//! shared handler, so both protocols answer from one index. Answers follow
+//! the index: a buffer's unsaved edits count for the word under the cursor,
+//! and for everything else once the file is saved.
+
+pub mod convert;
+pub mod documents;
+pub mod features;
+pub mod protocol;
+
+use std::path::PathBuf;
+
+use anyhow::{Result, bail};
+use julie_context::ToolContext;
+use serde_json::{Value, json};
+use tokio::io::{AsyncBufRead, AsyncWrite, BufReader};
+
+use crate::cli::resolve_workspace_root;
+use crate::cli_tools::bootstrap_standalone_handler;
+use crate::handler::JulieServerHandler;
+use documents::Documents;
+use features::Cursor;
+use protocol::{
+    INTERNAL_ERROR, INVALID_PARAMS, INVALID_REQUEST, METHOD_NOT_FOUND, ResponseError,
+    SERVER_NOT_INITIALIZED,
+};
+
+/// Serve LSP on stdin/stdout until the client sends `exit`.
+pub async fn serve_lsp(cli_workspace: Option<PathBuf>) -> Result<()> {
+    let workspace_root = resolve_workspace_root(cli_workspace);
+    let handler = bootstrap_standalone_handler(&workspace_root).await?;
+
+    // stdout carries the protocol; diagnostics go to stderr.
+    eprintln!(
+        "julie: LSP gateway for {} on stdio",
+        workspace_root.display()
+    );
+    let mut server = LspServer::new(handler, workspace_root);
+    let mut reader = BufReader::new(tokio::io::stdin());
+    let mut writer = tokio::io::stdout();
+    server.run(&mut reader, &mut writer).await
+}
+
+/// One LSP session over a handler.
+pub struct LspServer {
+    handler: JulieServerHandler,
+    documents: Documents,
+    initialized: bool,
+    shutdown: bool,
+    exited: bool,
+}
+
+impl LspServer {
+    pub fn new(handler: JulieServerHandler, workspace_root: PathBuf) -> Self {
+        let root = workspace_root.canonicalize().unwrap_or(workspace_root);
+        Self {
+            handler,
+            documents: Documents::new(root),
+            initialized: false,
+            shutdown: false,
+            exited: false,
+        }
+    }
+
+    /// Answer messages until `exit` or the end of the input. Fails when the
+    /// client exits without asking for a shutdown first.
+    pub async fn run<R, W>(&mut self, reader: &mut R, writer: &mut W) -> Result<()>
+    where
+        R: AsyncBufRead + Unpin,
+        W: AsyncWrite + Unpin,
+    {
+        while let Some(message) = protocol::read_message(reader).await? {
+            if let Some(reply) = self.handle(message).await {
+                protocol::write_message(writer, &reply).await?;
+            }
+            if self.exited {
+                break;
+            }
+        }
+        if !self.shutdown {
+            bail!("LSP client exited without a shutdown request");
+        }
+        Ok(())
+    }
+
+    /// Handle one message; the reply for a request, `None` for a
+    /// notification.
+    pub async fn handle(&mut self, message: Value) -> Option<Value> {
+        // Replies to server requests are not expected: the gateway sends none.
+        let method = message.get("method")?.as_str()?.to_string();
+        let params = message.get("params").cloned().unwrap_or(Value::Null);
+        let Some(id) = message.get("id").cloned() else {
+            self.notification(&method, &params);
+            return None;
+        };
+        Some(match self.request(&method, &params).await {
+            Ok(result) => protocol::response(id, result),
+            Err(error) => protocol::error_response(id, error),
+        })
+    }
+
+    fn notification(&mut self, method: &str, params: &Value) {
+        let uri = params
+            .pointer("/textDocument/uri")
+            .and_then(Value::as_str)
+            .unwrap_or_default();
+        match method {
+            "exit" => self.exited = true,
+            "textDocument/didOpen" => {
+                if let Some(text) = params.pointer("/textDocument/text").and_then(Value::as_str) {
+                    self.documents.open(uri, text.to_string());
+                }
+            }
+            "textDocument/didChange" => {
+                // Full sync: the last change carries the whole text.
+                if let Some(text) = params
+                    .get("contentChanges")
+                    .and_then(Value::as_array)
+                    .and_then(|changes| changes.last())
+                    .and_then(|change| change.get("text"))
+                    .and_then(Value::as_str)
+                {
+                    self.documents.open(uri, text.to_string());
+                }
+            }
+            "textDocument/didClose" => self.documents.close(uri),
+            // `initialized`, `didSave`, `$/cancelRequest` and the rest need
+            // no action.
+            _ => {}
+        }
+    }
+
+    async fn request(&mut self, method: &str, params: &Value) -> Result<Value, ResponseError> {
+        if self.shutdown {
+            return Err(ResponseError::new(INVALID_REQUEST, "server is shut down"));
+        }
+        match method {
+            "initialize" => {
+                self.initialized = true;
+                return Ok(capabilities());
+            }
+            _ if !self.initialized => {
+                return Err(ResponseError::new(
+                    SERVER_NOT_INITIALIZED,
+                    "initialize first",
+                ));
+            }
+            "shutdown" => {
+                self.shutdown = true;
+                return Ok(Value::Null);
+            }
+            _ => {}
+        }
+
+        let handler: &dyn ToolContext = &self.handler;
+        let documents = &self.documents;
+        let result = match method {
+            "textDocument/definition" => match self.cursor(params)? {
+                Some(cursor) => features::definition(handler, documents, &cursor).await,
+                None => Ok(Value::Null),
+            },
+            "textDocument/references" => match self.cursor(params)? {
+                Some(cursor) => {
+                    let include_declaration = params
+                        .pointer("/context/includeDeclaration")
+                        .and_then(Value::as_bool)
+                        .unwrap_or(false);
+                    features::references(handler, documents, &cursor, include_declaration).await
+                }
+                None => Ok(Value::Null),
+            },
+            "textDocument/hover" => match self.cursor(params)? {
+                Some(cursor) => features::hover(handler, &cursor).await,
+                None => Ok(Value::Null),
+            },
+            "textDocument/documentSymbol" => match self.document_path(params)? {
+                Some(file_path) => features::document_symbols(handler, documents, &file_path).await,
+                None => Ok(Value::Null),
+            },
+            "workspace/symbol" => {
+                let query = params
+                    .get("query")
+                    .and_then(Value::as_str)
+                    .unwrap_or_default();
+                features::workspace_symbols(handler, documents, query).await
+            }
+            _ => {
+                return Err(ResponseError::new(
+                    METHOD_NOT_FOUND,
+                    format!("unsupported method {method}"),
+                ));
+            }
+        };
+        result.map_err(|e| ResponseError::new(INTERNAL_ERROR, format!("{e:#}")))
+    }
+
+    /// Workspace-relative path of the request's document; `None` outside
+    /// the workspace.
+    fn document_path(&self, params: &Value) -> Result<Option<String>, ResponseError> {
+        let uri = params
+            .pointer("/textDocument/uri")
+            .and_then(Value::as_str)
+            .ok_or_else(|| ResponseError::new(INVALID_PARAMS, "textDocument.uri is required"))?;
+        Ok(self.documents.relative(uri))
+    }
+
+    /// The identifier at the request's position; `None` when the document
+    /// is outside the workspace or no identifier is there.
+    fn cursor(&self, params: &Value) -> Result<Option<Cursor>, ResponseError> {
+        let position = params
+            .get("position")
+            .ok_or_else(|| ResponseError::new(INVALID_PARAMS, "position is required"))?;
+        Ok(self
+            .document_path(params)?
+            .and_then(|file_path| Cursor::at(&self.documents, &file_path, position)))
+    }
+}
+
+/// The `initialize` result: full document sync and the five requests.
+pub fn capabilities() -> Value {
+    json!({
+        "capabilities": {
+            "positionEncoding": "utf-16",
+            "textDocumentSync": { "openClose": true, "change": 1 },
+            "definitionProvider": true,
+            "referencesProvider": true,
+            "hoverProvider": true,
+            "documentSymbolProvider": true,
+            "workspaceSymbolProvider": true,
+        },
+        "serverInfo": { "name": "julie", "version": env!("CARGO_PKG_VERSION") },
+    })
+}
@@ -8,6 +8,8 @@
 //! Argv dispatch:
 //!   - no args                 → in-process MCP server (run_in_process_server)
 //!   - `dashboard`             → serve standalone read-only dashboard
+//!   - `grpc`                  → serve the gRPC API (`grpc` feature)
+//!   - `lsp`                   → serve the LSP gateway on stdio
 //!   - tool subcommands        → run_cli_tool (standalone, in-process)
 
 use clap::Parser;
@@ -25,6 +27,13 @@ async fn main() -> anyhow::Result<()> {
         Some(Command::Dashboard) => {
             julie::dashboard::standalone::serve_dashboard_forever().await?;
         }
+        #[cfg(feature = "grpc")]
+        Some(Command::Grpc(args)) => {
+            julie::grpc::serve_grpc(args.listen, cli.workspace).await?;
+        }
+        Some(Command::Lsp) => {
+            julie::lsp::serve_lsp(cli.workspace).await?;
+        }
         // Tool commands: routed through the CLI execution core
         Some(Command::Search(args)) => {
             run_tool_command(&args, &cli.tool_flags, cli.workspace).await?;
@@ -56,6 +65,21 @@ async fn main() -> anyhow::Result<()> {
         Some(Command::Signals(args)) => {
             run_signals_command(&args, &cli.tool_flags, cli.workspace).await?;
         }
+        Some(Command::Annotations(args)) => {
+            run_annotations_command(&args, &cli.tool_flags, cli.workspace).await?;
+        }
+        Some(Command::Api(args)) => {
+            run_api_command(&args, &cli.tool_flags, cli.workspace).await?;
+        }
+        Some(Command::Drift(args)) => {
+            run_drift_command(&args, &cli.tool_flags, cli.workspace).await?;
+        }
+        Some(Command::Deps(args)) => {
+            run_deps_command(&args, &cli.tool_flags, cli.workspace).await?;
+        }
+        Some(Command::Check(args)) => {
+            run_check_command(&args, &cli.tool_flags, cli.workspace).await?;
+        }
         Some(Command::Extract(raw_args)) => {
             run_extract_command(raw_args, &cli.tool_flags).await?;
         }
@@ -105,6 +129,76 @@ async fn run_signals_command(
     Ok(())
 }
 
+/// Run the per-file editor annotations report (standalone-only, not an MCP tool).
+async fn run_annotations_command(
+    args: &julie::cli_tools::subcommands::AnnotationsArgs,
+    flags: &julie::cli_tools::GlobalToolFlags,
+    cli_workspace: Option<std::path::PathBuf>,
+) -> anyhow::Result<()> {
+    let output = julie::cli_tools::run_annotations_report(args, cli_workspace).await?;
+    let formatted =
+        julie::cli_tools::output::format_annotations_report(&output, flags.effective_format());
+    println!("{}", formatted);
+    Ok(())
+}
+
+/// Run the public API surface report (standalone-only, not an MCP tool).
+async fn run_api_command(
+    args: &julie::cli_tools::subcommands::ApiArgs,
+    flags: &julie::cli_tools::GlobalToolFlags,
+    cli_workspace: Option<std::path::PathBuf>,
+) -> anyhow::Result<()> {
+    let output = julie::cli_tools::run_api_surface_report(args, cli_workspace).await?;
+    let formatted =
+        julie::cli_tools::output::format_api_surface_report(&output, flags.effective_format());
+    println!("{}", formatted);
+    Ok(())
+}
+
+/// Run the naming drift report (standalone-only, not an MCP tool).
+async fn run_drift_command(
+    args: &julie::cli_tools::subcommands::DriftArgs,
+    flags: &julie::cli_tools::GlobalToolFlags,
+    cli_workspace: Option<std::path::PathBuf>,
+) -> anyhow::Result<()> {
+    let output = julie::cli_tools::run_naming_drift_report(args, cli_workspace).await?;
+    let formatted =
+        julie::cli_tools::output::format_naming_drift_report(&output, flags.effective_format());
+    println!("{}", formatted);
+    Ok(())
+}
+
+/// Run the module dependency matrix report (standalone-only, not an MCP tool).
+async fn run_deps_command(
+    args: &julie::cli_tools::subcommands::DepsArgs,
+    flags: &julie::cli_tools::GlobalToolFlags,
+    cli_workspace: Option<std::path::PathBuf>,
+) -> anyhow::Result<()> {
+    let output = julie::cli_tools::run_dependency_matrix_report(args, cli_workspace).await?;
+    let formatted = julie::cli_tools::output::format_dependency_matrix_report(
+        &output,
+        flags.effective_format(),
+    );
+    println!("{}", formatted);
+    Ok(())
+}
+
+/// Run the CI quality gate; exits with status 1 when any check regressed.
+async fn run_check_command(
+    args: &julie::cli_tools::subcommands::CheckArgs,
+    flags: &julie::cli_tools::GlobalToolFlags,
+    cli_workspace: Option<std::path::PathBuf>,
+) -> anyhow::Result<()> {
+    let report = julie::cli_tools::check::run_quality_gate(args, cli_workspace).await?;
+    let formatted =
+        julie::cli_tools::output::format_quality_gate_report(&report, flags.effective_format());
+    println!("{}", formatted);
+    if !report.passed {
+        std::process::exit(1);
+    }
+    Ok(())
+}
+
 /// Run external extraction against a caller-owned SQLite database.
 async fn run_extract_command(
     raw_args: julie::external_extract::ExternalExtractRawArgs,
@@ -119,14 +213,23 @@ async fn run_extract_command(
                 &report,
                 flags.effective_format(),
             )?;
-            println!("{}", formatted);
+            if args.command.streams_to_stdout() {
+                eprintln!("{}", formatted);
+            } else {
+                println!("{}", formatted);
+            }
             std::process::exit(1);
         }
     };
 
     let formatted =
         julie::external_extract::format_external_extract_report(&report, flags.effective_format())?;
-    println!("{}", formatted);
+    if args.command.streams_to_stdout() {
+        // stdout carries the shard stream; keep it parseable.
+        eprintln!("{}", formatted);
+    } else {
+        println!("{}", formatted);
+    }
     Ok(())
 }
 
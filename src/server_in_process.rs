@@ -232,6 +232,10 @@ pub async fn run_in_process_server(
         }
     };
 
+    // Verify the linked tree-sitter grammars still define the node kinds the
+    // extractors match on; problems are logged here and shown by `health`.
+    julie_core::grammar_compat::grammar_compatibility();
+
     // 5. Acquire embedding provider with a bounded timeout (Part D).
     //    A cold host can take up to 180 s to spawn — we must NOT block serve()
     //    on startup.  On timeout the session degrades to keyword-only; the
@@ -241,21 +245,34 @@ pub async fn run_in_process_server(
         .ok()
         .and_then(|v| v.parse().ok())
         .unwrap_or(5);
-    let embedding_provider = match tokio::time::timeout(
-        std::time::Duration::from_secs(embed_wait_secs),
-        acquire_in_process_embedding_provider(&paths),
-    )
-    .await
-    {
-        Ok(provider) => provider,
-        Err(_elapsed) => {
-            warn!(
-                timeout_secs = embed_wait_secs,
-                "Embedding provider not ready within timeout — serving without \
-                 semantic search (keyword-only). The host continues warming in \
-                 the background for later sessions."
-            );
-            None
+    //    `semantic.mode = "disabled"` skips the host entirely, so no model is
+    //    downloaded and no ONNX runtime is loaded.
+    let semantic_disabled = julie_core::workspace_settings::WorkspaceSettingsRegistry::global()
+        .get(&canonical_path)
+        .semantic_disabled();
+    let embedding_provider = if semantic_disabled {
+        info!(
+            "In-process embedding disabled via .julie/config.toml semantic.mode; \
+             degrading to keyword-only"
+        );
+        None
+    } else {
+        match tokio::time::timeout(
+            std::time::Duration::from_secs(embed_wait_secs),
+            acquire_in_process_embedding_provider(&paths),
+        )
+        .await
+        {
+            Ok(provider) => provider,
+            Err(_elapsed) => {
+                warn!(
+                    timeout_secs = embed_wait_secs,
+                    "Embedding provider not ready within timeout — serving without \
+                     semantic search (keyword-only). The host continues warming in \
+                     the background for later sessions."
+                );
+                None
+            }
         }
     };
 
@@ -5,15 +5,18 @@
 
 use crate::handler::JulieServerHandler;
 use crate::tools::workspace::ManageWorkspaceTool;
+use crate::tools::workspace::indexing::branch_switch::GIT_HEAD_COMPONENT;
 use crate::tools::workspace::indexing::engine_version::{
     SEMANTIC_INDEX_ENGINE_COMPONENT, SEMANTIC_INDEX_ENGINE_VERSION,
 };
 use crate::tools::workspace::indexing::state::IndexingRepairReason;
+use crate::workspace::init_flight::InitializationLock;
 use crate::workspace::mutation_gate::MutationGuard;
 use crate::workspace::startup_hint::WorkspaceStartupSource;
 use anyhow::{Context, Result};
 use std::collections::HashSet;
 use std::path::Path;
+use std::sync::OnceLock;
 use std::time::Duration;
 use std::time::SystemTime;
 use tracing::{debug, info, warn};
@@ -67,13 +70,23 @@ pub async fn check_if_indexing_needed(handler: &JulieServerHandler) -> Result<bo
     Ok(plan_primary_workspace_repair(handler).await?.is_some())
 }
 
+/// Single-flight for startup repair, keyed by canonical workspace root.
+fn primary_repair_flights() -> &'static InitializationLock<Option<PrimaryWorkspaceRepairPlan>> {
+    static FLIGHTS: OnceLock<InitializationLock<Option<PrimaryWorkspaceRepairPlan>>> =
+        OnceLock::new();
+    FLIGHTS.get_or_init(InitializationLock::new)
+}
+
 /// Acquire the mutation gate for the primary workspace, then run the repair pass.
 ///
-/// This is the public entry point. It serializes concurrent catch-up scans by
-/// holding the workspace mutation gate for the duration of the repair. If no
-/// workspace is bound yet (empty-database first-run path), the gate cannot be
-/// keyed by workspace_id and the repair runs ungated — there is no concurrent
-/// writer to contend with in that case.
+/// This is the public entry point. Concurrent callers for the same workspace
+/// root (e.g. two MCP connections completing their handshake together) share
+/// one run: the first becomes the leader and the rest await its outcome
+/// instead of starting a second indexing pipeline. The leader holds the
+/// workspace mutation gate for the duration of the repair. If no workspace is
+/// bound yet (empty-database first-run path), the gate cannot be keyed by
+/// workspace_id and the repair runs ungated; the single-flight is what keeps
+/// two first-run repairs from racing on the registry in that case.
 pub(crate) async fn run_primary_workspace_repair(
     handler: &JulieServerHandler,
 ) -> Result<Option<PrimaryWorkspaceRepairPlan>> {
@@ -87,20 +100,38 @@ pub(crate) async fn run_primary_workspace_repair(
         return Ok(None);
     }
 
-    match handler.require_primary_workspace_identity() {
-        Ok(workspace_id) => {
-            let guard = handler.acquire_mutation_gate(&workspace_id).await;
-            run_primary_workspace_repair_inner(&guard, handler).await
-        }
-        Err(_) => {
-            // No workspace bound yet (first-run / empty-database path).
-            // There is no concurrent writer to serialize against, so run
-            // the repair directly without a gate. The downstream index call
-            // will acquire its own gate when it discovers the workspace
-            // identity.
-            run_primary_workspace_repair_body(handler, None).await
-        }
+    let root = JulieServerHandler::canonicalize_workspace_path(handler.current_workspace_root());
+    let key = root.to_string_lossy();
+    let mut led = false;
+    let led_flag = &mut led;
+    let outcome = primary_repair_flights()
+        .run(&key, move || {
+            *led_flag = true;
+            async move {
+                match handler.require_primary_workspace_identity() {
+                    Ok(workspace_id) => {
+                        let guard = handler.acquire_mutation_gate(&workspace_id).await;
+                        run_primary_workspace_repair_inner(&guard, handler).await
+                    }
+                    Err(_) => {
+                        // No workspace bound yet (first-run / empty-database path).
+                        // Other callers for this root are parked on the flight
+                        // above, so run the repair directly without a gate. The
+                        // downstream index call will acquire its own gate when it
+                        // discovers the workspace identity.
+                        run_primary_workspace_repair_body(handler, None).await
+                    }
+                }
+            }
+        })
+        .await;
+
+    // The leader's index command flags its own handler; a follower that
+    // shared a successful run must flag itself the same way.
+    if !led && outcome.is_ok() {
+        *handler.is_indexed.write().await = true;
     }
+    outcome
 }
 
 /// Inner repair implementation. Takes a `&MutationGuard<'_>` as a proof token
@@ -164,14 +195,18 @@ async fn run_primary_workspace_repair_body(
                     workspace_id: None,
                     force: Some(false),
                     detailed: None,
+                    from_generation: None,
+                    to_generation: None,
+                    dry_run: None,
                 };
 
                 // `repair_rebuilds_embedding_inputs` covers reasons that
                 // require running the embedding pipeline after repair:
                 //   - Reasons that rebuild symbol text (EmptyDatabase,
                 //     StaleFiles, NewFiles, DeletedFiles, ExtractorFailure,
-                //     WatcherOverflow, SemanticVersionChanged) — embedding
-                //     inputs changed and existing vectors are now stale.
+                //     WatcherOverflow, SemanticVersionChanged,
+                //     BranchSwitched) — embedding inputs changed and
+                //     existing vectors are now stale.
                 //   - MissingEmbeddings — symbols are intact but no
                 //     vectors exist. The index path does not rebuild
                 //     symbols here, but the "no files changed but
@@ -191,6 +226,7 @@ async fn run_primary_workspace_repair_body(
                             | IndexingRepairReason::ExtractorFailure
                             | IndexingRepairReason::WatcherOverflow
                             | IndexingRepairReason::SemanticVersionChanged
+                            | IndexingRepairReason::BranchSwitched
                             | IndexingRepairReason::MissingEmbeddings
                     )
                 });
@@ -419,7 +455,13 @@ pub(crate) async fn plan_primary_workspace_repair(
         }
     };
 
-    let (has_symbols_result, semantic_version_matches, indexed_files_raw, stored_repairs) = {
+    let (
+        has_symbols_result,
+        semantic_version_matches,
+        indexed_files_raw,
+        stored_repairs,
+        recorded_git_head,
+    ) = {
         // Keep the SQLite mutex scoped to database reads. Filesystem scans below
         // can be slow on large workspaces, and holding this lock makes first
         // health checks report a false SQLite BUSY state while catch-up is only
@@ -446,9 +488,16 @@ pub(crate) async fn plan_primary_workspace_repair(
                 )?),
                 db.get_all_indexed_files()?,
                 db.list_indexing_repairs()?,
+                db.get_index_engine_state(&route.workspace_id, GIT_HEAD_COMPONENT)?,
             ),
-            Ok(false) => (Ok(false), None, Vec::new(), db.list_indexing_repairs()?),
-            Err(err) => (Err(err), None, Vec::new(), Vec::new()),
+            Ok(false) => (
+                Ok(false),
+                None,
+                Vec::new(),
+                db.list_indexing_repairs()?,
+                None,
+            ),
+            Err(err) => (Err(err), None, Vec::new(), Vec::new(), None),
         }
     };
 
@@ -474,20 +523,34 @@ pub(crate) async fn plan_primary_workspace_repair(
                 reasons.push(IndexingRepairReason::SemanticVersionChanged);
             }
 
-            // Check if index is stale by comparing file modification times with database timestamp
-            let db_mtime = get_database_mtime(&db_path)?;
-            let max_file_mtime = get_max_file_mtime_in_workspace(&current_primary_root)?;
+            // A moved HEAD already calls for a repair run, which re-checks
+            // the files git lists as changed; the mtime scan would add nothing.
+            let head_moved = recorded_git_head.is_some_and(|(recorded_head, _)| {
+                julie_core::git_history::head_commit(&current_primary_root)
+                    .is_ok_and(|head| head != recorded_head)
+            });
 
-            debug!(
-                "Staleness check: db_mtime={:?}, max_file_mtime={:?}, stale={}",
-                db_mtime,
-                max_file_mtime,
-                max_file_mtime > db_mtime
-            );
+            if head_moved {
+                info!("📊 HEAD moved since last index - differential indexing needed");
+                reasons.push(IndexingRepairReason::BranchSwitched);
+            } else {
+                // Check if index is stale by comparing file modification times with database timestamp
+                let db_mtime = get_database_mtime(&db_path)?;
+                let max_file_mtime = get_max_file_mtime_in_workspace(&current_primary_root)?;
+
+                debug!(
+                    "Staleness check: db_mtime={:?}, max_file_mtime={:?}, stale={}",
+                    db_mtime,
+                    max_file_mtime,
+                    max_file_mtime > db_mtime
+                );
 
-            if max_file_mtime > db_mtime {
-                info!("📊 Database is stale (files modified after last index) - indexing needed");
-                reasons.push(IndexingRepairReason::StaleFiles);
+                if max_file_mtime > db_mtime {
+                    info!(
+                        "📊 Database is stale (files modified after last index) - indexing needed"
+                    );
+                    reasons.push(IndexingRepairReason::StaleFiles);
+                }
             }
 
             // Database stores relative Unix-style paths per CLAUDE.md Path Handling Contract
@@ -654,5 +717,11 @@ fn get_max_file_mtime_in_workspace(workspace_root: &Path) -> Result<SystemTime>
         }
     }
 
+    for path in julie_core::generated_files::discover_generated_files(workspace_root) {
+        if let Ok(mtime) = std::fs::metadata(&path).and_then(|metadata| metadata.modified()) {
+            max_mtime = max_mtime.max(mtime);
+        }
+    }
+
     Ok(max_mtime)
 }
@@ -74,8 +74,18 @@ fn test_search_args_tool_name() {
         file_pattern: None,
         context_lines: None,
         exclude_tests: false,
+        include_generated: false,
         regions: None,
+        regex: false,
+        todos: false,
+        topics: false,
         target: None,
+        budget: None,
+        no_dedupe: false,
+        fuzzy: false,
+        defines: None,
+        project: None,
+        framework: None,
     };
     assert_eq!(args.tool_name(), "fast_search");
 }
@@ -88,6 +98,9 @@ fn test_refs_args_tool_name() {
         workspace: None,
         kind: None,
         limit: 10,
+        textual: false,
+        project: None,
+        budget: None,
     };
     assert_eq!(args.tool_name(), "fast_refs");
 }
@@ -100,6 +113,7 @@ fn test_symbols_args_tool_name() {
         target: None,
         limit: 50,
         max_depth: 1,
+        budget: None,
     };
     assert_eq!(args.tool_name(), "get_symbols");
 }
@@ -112,6 +126,7 @@ fn test_context_args_tool_name() {
         max_hops: None,
         entry_symbols: None,
         prefer_tests: false,
+        project: None,
     };
     assert_eq!(args.tool_name(), "get_context");
 }
@@ -173,8 +188,18 @@ fn test_search_to_tool_args_minimal() {
         file_pattern: None,
         context_lines: None,
         exclude_tests: false,
+        include_generated: false,
         regions: None,
+        regex: false,
+        todos: false,
+        topics: false,
         target: None,
+        budget: None,
+        no_dedupe: false,
+        fuzzy: false,
+        defines: None,
+        project: None,
+        framework: None,
     };
     let json = args.to_tool_args().unwrap();
     assert_eq!(json["query"], "hello");
@@ -185,6 +210,8 @@ fn test_search_to_tool_args_minimal() {
     assert!(json.get("file_pattern").is_none());
     assert!(json.get("context_lines").is_none());
     assert!(json.get("exclude_tests").is_none());
+    assert!(json.get("include_generated").is_none());
+    assert!(json.get("mode").is_none());
 }
 
 #[test]
@@ -196,8 +223,18 @@ fn test_search_to_tool_args_full() {
         file_pattern: Some("src/**/*.rs".into()),
         context_lines: Some(3),
         exclude_tests: true,
+        include_generated: true,
         regions: Some("comment,docstring".into()),
+        regex: true,
+        todos: false,
+        topics: false,
         target: None,
+        budget: None,
+        no_dedupe: false,
+        fuzzy: false,
+        defines: None,
+        project: None,
+        framework: None,
     };
     let json = args.to_tool_args().unwrap();
     assert_eq!(json["query"], "parse");
@@ -208,7 +245,9 @@ fn test_search_to_tool_args_full() {
     assert_eq!(json["file_pattern"], "src/**/*.rs");
     assert_eq!(json["context_lines"], 3);
     assert_eq!(json["exclude_tests"], true);
+    assert_eq!(json["include_generated"], true);
     assert_eq!(json["regions"], "comment,docstring");
+    assert_eq!(json["mode"], "regex");
 }
 
 #[test]
@@ -219,10 +258,15 @@ fn test_refs_to_tool_args_with_filters() {
         workspace: None,
         kind: Some("call".into()),
         limit: 25,
+        textual: true,
+        project: None,
+        budget: Some(800),
     };
     let json = args.to_tool_args().unwrap();
     assert_eq!(json["symbol"], "Command");
     assert_eq!(json["reference_kind"], "call");
+    assert_eq!(json["include_textual"], true);
+    assert_eq!(json["max_tokens"], 800);
     assert!(json.get("file_path").is_none());
     assert!(json.get("file_pattern").is_none());
     assert_eq!(json["limit"], 25);
@@ -236,6 +280,7 @@ fn test_symbols_to_tool_args_with_target() {
         target: Some("new".into()),
         limit: 5,
         max_depth: 2,
+        budget: Some(400),
     };
     let json = args.to_tool_args().unwrap();
     assert_eq!(json["file_path"], "src/handler.rs");
@@ -243,6 +288,7 @@ fn test_symbols_to_tool_args_with_target() {
     assert_eq!(json["target"], "new");
     assert_eq!(json["limit"], 5);
     assert_eq!(json["max_depth"], 2);
+    assert_eq!(json["max_tokens"], 400);
 }
 
 #[test]
@@ -253,6 +299,7 @@ fn test_context_to_tool_args_full() {
         max_hops: Some(2),
         entry_symbols: Some(vec!["FastSearchTool".into(), "Command".into()]),
         prefer_tests: true,
+        project: None,
     };
     let json = args.to_tool_args().unwrap();
     assert_eq!(json["query"], "search scoring");
@@ -518,8 +565,18 @@ async fn test_run_cli_tool_standalone_definition_search_uses_bootstrapped_index(
         file_pattern: None,
         context_lines: None,
         exclude_tests: false,
+        include_generated: false,
         regions: None,
+        regex: false,
+        todos: false,
+        topics: false,
         target: None,
+        budget: None,
+        no_dedupe: false,
+        fuzzy: false,
+        defines: None,
+        project: None,
+        framework: None,
     };
 
     let output = run_cli_tool(&args, Some(temp.path().to_path_buf()), true)
@@ -562,8 +619,18 @@ async fn test_run_cli_tool_standalone_missing_workspace() {
         file_pattern: None,
         context_lines: None,
         exclude_tests: false,
+        include_generated: false,
         regions: None,
+        regex: false,
+        todos: false,
+        topics: false,
         target: None,
+        budget: None,
+        no_dedupe: false,
+        fuzzy: false,
+        defines: None,
+        project: None,
+        framework: None,
     };
 
     let result = run_cli_tool(
@@ -695,6 +762,9 @@ fn test_refs_to_tool_args_no_file_filters() {
         workspace: None,
         kind: None,
         limit: 10,
+        textual: false,
+        project: None,
+        budget: None,
     };
     let json = args.to_tool_args().unwrap();
     assert!(
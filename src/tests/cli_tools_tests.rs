@@ -659,6 +659,107 @@ fn test_signals_all_flags() {
     assert_eq!(args.limit, Some(50));
 }
 
+#[test]
+fn test_annotations_takes_file_argument() {
+    use crate::cli::{Cli, Command};
+    let cli = Cli::try_parse_from(["julie-server", "annotations", "src/lib.rs", "--json"]).unwrap();
+    assert!(cli.tool_flags.json);
+    let Command::Annotations(args) = cli.command.unwrap() else {
+        panic!("expected Annotations");
+    };
+    assert_eq!(args.file, "src/lib.rs");
+    assert!(Cli::try_parse_from(["julie-server", "annotations"]).is_err());
+}
+
+#[test]
+fn test_api_focus_path_is_optional() {
+    use crate::cli::{Cli, Command};
+    let cli = Cli::try_parse_from(["julie-server", "api"]).unwrap();
+    let Command::Api(args) = cli.command.unwrap() else {
+        panic!("expected Api");
+    };
+    assert_eq!(args.path, None);
+
+    let cli = Cli::try_parse_from(["julie-server", "api", "--path", "src/client"]).unwrap();
+    let Command::Api(args) = cli.command.unwrap() else {
+        panic!("expected Api");
+    };
+    assert_eq!(args.path.as_deref(), Some("src/client"));
+}
+
+#[test]
+fn test_drift_parses_path_and_min_similarity() {
+    use crate::cli::{Cli, Command};
+    let cli = Cli::try_parse_from([
+        "julie-server",
+        "drift",
+        "--path",
+        "src/api",
+        "--min-similarity",
+        "0.9",
+    ])
+    .unwrap();
+    let Command::Drift(args) = cli.command.unwrap() else {
+        panic!("expected Drift");
+    };
+    assert_eq!(args.path.as_deref(), Some("src/api"));
+    assert_eq!(args.min_similarity, Some(0.9));
+}
+
+#[test]
+fn test_deps_parses_depth_top_and_layer_list() {
+    use crate::cli::{Cli, Command};
+    let cli = Cli::try_parse_from([
+        "julie-server",
+        "deps",
+        "--depth",
+        "1",
+        "--top",
+        "5",
+        "--layers",
+        "src/handler,src/tools,crates",
+    ])
+    .unwrap();
+    let Command::Deps(args) = cli.command.unwrap() else {
+        panic!("expected Deps");
+    };
+    assert_eq!(args.path, None);
+    assert_eq!(args.depth, Some(1));
+    assert_eq!(args.top, Some(5));
+    assert_eq!(args.layers, ["src/handler", "src/tools", "crates"]);
+}
+
+#[test]
+fn test_check_requires_base_and_parses_only_list() {
+    use crate::analysis::QualityCheck;
+    use crate::cli::{Cli, Command};
+
+    assert!(Cli::try_parse_from(["julie-server", "check"]).is_err());
+
+    let cli = Cli::try_parse_from([
+        "julie-server",
+        "check",
+        "--base",
+        "origin/main",
+        "--only",
+        "dead-code,api",
+    ])
+    .unwrap();
+    let Command::Check(args) = cli.command.unwrap() else {
+        panic!("expected Check");
+    };
+    assert_eq!(args.base, "origin/main");
+    assert_eq!(
+        args.checks,
+        [QualityCheck::DeadCode, QualityCheck::ApiSurface]
+    );
+
+    assert!(
+        Cli::try_parse_from(["julie-server", "check", "--base", "main", "--only", "speed"])
+            .is_err()
+    );
+}
+
 #[test]
 fn test_agent_instructions_recommend_standalone_for_quick_dogfood_checks() {
     let instructions_path =
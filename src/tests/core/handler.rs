@@ -10,6 +10,7 @@ pub(crate) use common::*;
 
 mod deadline;
 mod editing_metrics;
+mod error_codes;
 mod fa_pin_hint;
 mod follower_repair_gate;
 mod inprocess_ctor;
@@ -19,6 +20,10 @@ mod loser_refuses;
 mod metrics_recording;
 mod path_helpers;
 mod public_surface;
+mod query_cache;
+mod ref_db_cache;
+mod resources;
 mod startup_checkpoint;
 mod t9_bounded_read;
+mod tool_admission;
 mod workspace_binding_metrics;
@@ -90,6 +90,15 @@ fn test_is_write_exempt_manage_workspace_index_is_exempt() {
     );
 }
 
+#[test]
+fn test_is_write_exempt_manage_workspace_rollback_is_exempt() {
+    let a = args("rollback_last_operation");
+    assert!(
+        is_write_exempt("manage_workspace", Some(&a)),
+        "manage_workspace rollback_last_operation restores files and must be exempt"
+    );
+}
+
 #[test]
 fn test_is_write_exempt_manage_workspace_stats_is_not_exempt() {
     let a = args("stats");
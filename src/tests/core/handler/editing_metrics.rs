@@ -20,6 +20,9 @@ async fn test_edit_file_metrics_attribute_root_file_source_bytes() -> Result<()>
         name: None,
         force: Some(false),
         detailed: None,
+        from_generation: None,
+        to_generation: None,
+        dry_run: None,
     };
     index_tool.call_tool(&handler).await?;
 
@@ -94,6 +97,9 @@ async fn test_edit_file_validation_errors_are_recorded_as_failures() -> Result<(
         name: None,
         force: Some(false),
         detailed: None,
+        from_generation: None,
+        to_generation: None,
+        dry_run: None,
     };
     index_tool.call_tool(&handler).await?;
 
@@ -151,6 +157,9 @@ async fn test_edit_file_empty_old_text_validation_precedes_file_io() -> Result<(
         name: None,
         force: Some(false),
         detailed: None,
+        from_generation: None,
+        to_generation: None,
+        dry_run: None,
     }
     .call_tool(&handler)
     .await?;
@@ -193,6 +202,9 @@ async fn test_edit_file_metrics_include_input_and_edit_outcome() -> Result<()> {
         name: None,
         force: Some(false),
         detailed: None,
+        from_generation: None,
+        to_generation: None,
+        dry_run: None,
     }
     .call_tool(&handler)
     .await?;
@@ -244,6 +256,9 @@ async fn test_edit_file_apply_metrics_record_conversion_outcome() -> Result<()>
         name: None,
         force: Some(false),
         detailed: None,
+        from_generation: None,
+        to_generation: None,
+        dry_run: None,
     }
     .call_tool(&handler)
     .await?;
@@ -289,6 +304,9 @@ async fn test_edit_file_failed_apply_metrics_record_applied_false() -> Result<()
         name: None,
         force: Some(false),
         detailed: None,
+        from_generation: None,
+        to_generation: None,
+        dry_run: None,
     }
     .call_tool(&handler)
     .await?;
@@ -339,6 +357,9 @@ async fn test_rewrite_symbol_metrics_include_symbol_span_and_failure_kind() -> R
         name: None,
         force: Some(false),
         detailed: None,
+        from_generation: None,
+        to_generation: None,
+        dry_run: None,
     }
     .call_tool(&handler)
     .await?;
@@ -424,6 +445,9 @@ async fn test_rewrite_symbol_failed_apply_metrics_record_applied_false() -> Resu
         name: None,
         force: Some(false),
         detailed: None,
+        from_generation: None,
+        to_generation: None,
+        dry_run: None,
     }
     .call_tool(&handler)
     .await?;
@@ -476,6 +500,9 @@ async fn test_rename_symbol_metrics_include_reference_and_change_counts() -> Res
         name: None,
         force: Some(false),
         detailed: None,
+        from_generation: None,
+        to_generation: None,
+        dry_run: None,
     }
     .call_tool(&handler)
     .await?;
@@ -0,0 +1,95 @@
+use crate::handler::dispatch_with_deadline;
+use crate::handler::tools::error::{
+    ToolErrorCode, classify_tool_failure, tool_error_code, with_error_code,
+};
+use crate::tools::navigation::resolution::{
+    WorkspaceResolutionFailure, WorkspaceResolutionFailureKind,
+};
+use rmcp::ErrorData as McpError;
+use rmcp::model::{CallToolResult, Content, ErrorCode};
+use std::time::Duration;
+
+#[test]
+fn test_tool_failure_carries_stable_code_in_error_data() {
+    let err = anyhow::Error::new(WorkspaceResolutionFailure::new(
+        WorkspaceResolutionFailureKind::PrimarySwapInProgress,
+        "Primary workspace swap in progress",
+    ));
+    let error = classify_tool_failure("fast_refs", &err);
+    assert_eq!(error.code, ErrorCode::INVALID_PARAMS);
+    assert_eq!(
+        error.data,
+        Some(serde_json::json!({ "code": "workspace_busy", "retryable": true }))
+    );
+
+    let err = anyhow::anyhow!("File not indexed: src/lib.rs");
+    let error = classify_tool_failure("syntax_query", &err);
+    assert_eq!(error.code, ErrorCode::INTERNAL_ERROR);
+    assert_eq!(error.data.unwrap()["code"], "file_not_found");
+}
+
+#[test]
+fn test_tool_error_code_distinguishes_common_failures() {
+    let cases = [
+        (
+            "Workspace not indexed yet. Run manage_workspace(operation=\"index\") first.",
+            ToolErrorCode::WorkspaceNotIndexed,
+        ),
+        (
+            "Database not available. Run manage_workspace(operation=\"index\") first.",
+            ToolErrorCode::WorkspaceNotIndexed,
+        ),
+        (
+            "Workspace not found: lib_abc123",
+            ToolErrorCode::WorkspaceNotFound,
+        ),
+        (
+            "Symbol 'parse' for 'from' was not found. Use fast_search or deep_dive to verify the name.",
+            ToolErrorCode::SymbolNotFound,
+        ),
+        ("unexpected end of input", ToolErrorCode::Internal),
+    ];
+    for (message, expected) in cases {
+        assert_eq!(
+            tool_error_code(&anyhow::anyhow!(message)),
+            expected,
+            "{message}"
+        );
+    }
+
+    let busy =
+        rusqlite::Error::SqliteFailure(rusqlite::ffi::Error::new(rusqlite::ffi::SQLITE_BUSY), None);
+    let err = anyhow::Error::new(busy).context("Failed to query symbols");
+    assert_eq!(tool_error_code(&err), ToolErrorCode::DatabaseLocked);
+    assert!(ToolErrorCode::DatabaseLocked.is_retryable());
+}
+
+#[test]
+fn test_error_results_get_structured_error_code() {
+    let result = with_error_code(CallToolResult::error(vec![Content::text(
+        "Workspace not found: lib_abc123",
+    )]));
+    assert_eq!(
+        result.structured_content,
+        Some(serde_json::json!({
+            "error": { "code": "workspace_not_found", "retryable": false }
+        }))
+    );
+
+    let ok = with_error_code(CallToolResult::success(vec![Content::text(
+        "No symbol found",
+    )]));
+    assert!(ok.structured_content.is_none(), "successes are untouched");
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_deadline_error_carries_timeout_code() {
+    let stalled = async {
+        tokio::time::sleep(Duration::from_secs(300)).await;
+        Ok::<CallToolResult, McpError>(CallToolResult::success(vec![]))
+    };
+    let error = dispatch_with_deadline("fast_search", false, stalled, Some(Duration::from_secs(1)))
+        .await
+        .expect_err("stalled tool must time out");
+    assert_eq!(error.data.unwrap()["code"], "timeout");
+}
@@ -67,6 +67,9 @@ async fn test_loser_refuses_manage_workspace_index() {
         workspace_id: None,
         name: None,
         detailed: None,
+        from_generation: None,
+        to_generation: None,
+        dry_run: None,
     };
     // call_tool returns Ok(...) — refusal is a tool-level error, not a Rust Err.
     let result = tool
@@ -124,6 +127,9 @@ async fn test_loser_allows_reads_stats() {
         workspace_id: None,
         name: None,
         detailed: None,
+        from_generation: None,
+        to_generation: None,
+        dry_run: None,
     };
     // Must not panic and must not return the refusal message.
     let result = tool
@@ -158,6 +164,9 @@ async fn test_leader_not_refused_index() {
         workspace_id: None,
         name: None,
         detailed: None,
+        from_generation: None,
+        to_generation: None,
+        dry_run: None,
     };
     // Leader may succeed or fail for unrelated reasons (no source files, etc.),
     // but it must NOT return the follower refusal message.
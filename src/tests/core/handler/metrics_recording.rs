@@ -19,6 +19,9 @@ async fn test_tool_failure_metrics_records_failed_handler_call() -> Result<()> {
         name: None,
         force: Some(false),
         detailed: None,
+        from_generation: None,
+        to_generation: None,
+        dry_run: None,
     };
     index_tool.call_tool(&handler).await?;
 
@@ -178,6 +181,9 @@ async fn test_deep_dive_failure_metrics_records_failed_handler_call() -> Result<
         name: None,
         force: Some(false),
         detailed: None,
+        from_generation: None,
+        to_generation: None,
+        dry_run: None,
     };
     index_tool.call_tool(&handler).await?;
 
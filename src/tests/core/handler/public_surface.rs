@@ -91,6 +91,69 @@ async fn test_tool_list_matches_public_surface() -> Result<()> {
             .any(|tool| tool.name.as_ref() == "spillover_get"),
         "spillover_get should appear in the public tool list"
     );
+    assert!(
+        tools
+            .tools
+            .iter()
+            .any(|tool| tool.name.as_ref() == "fast_audit"),
+        "fast_audit should appear in the public tool list"
+    );
+    assert!(
+        tools
+            .tools
+            .iter()
+            .any(|tool| tool.name.as_ref() == "syntax_query"),
+        "syntax_query should appear in the public tool list"
+    );
+    assert!(
+        tools
+            .tools
+            .iter()
+            .any(|tool| tool.name.as_ref() == "get_file_annotations"),
+        "get_file_annotations should appear in the public tool list"
+    );
+    assert!(
+        tools
+            .tools
+            .iter()
+            .any(|tool| tool.name.as_ref() == "fast_imports"),
+        "fast_imports should appear in the public tool list"
+    );
+    assert!(
+        tools
+            .tools
+            .iter()
+            .any(|tool| tool.name.as_ref() == "fast_counterparts"),
+        "fast_counterparts should appear in the public tool list"
+    );
+    assert!(
+        tools
+            .tools
+            .iter()
+            .any(|tool| tool.name.as_ref() == "fast_signature"),
+        "fast_signature should appear in the public tool list"
+    );
+    assert!(
+        tools
+            .tools
+            .iter()
+            .any(|tool| tool.name.as_ref() == "get_context_pack"),
+        "get_context_pack should appear in the public tool list"
+    );
+    assert!(
+        tools
+            .tools
+            .iter()
+            .any(|tool| tool.name.as_ref() == "session_context"),
+        "session_context should appear in the public tool list"
+    );
+    assert!(
+        tools
+            .tools
+            .iter()
+            .any(|tool| tool.name.as_ref() == "task_status"),
+        "task_status should appear in the public tool list"
+    );
 
     let _ = service.cancel().await;
     Ok(())
@@ -0,0 +1,95 @@
+use super::*;
+use crate::handler::query_cache::{QueryCache, QueryCacheKey};
+use julie_context::WorkspaceTarget;
+
+fn key(workspace_id: &str, params: &str, generation: u64) -> QueryCacheKey {
+    QueryCacheKey {
+        tool: "fast_search",
+        workspace_id: workspace_id.to_string(),
+        params: params.to_string(),
+        generation,
+    }
+}
+
+#[test]
+fn test_query_cache_evicts_least_recently_used() {
+    let cache: QueryCache<u32> = QueryCache::new(2);
+    cache.insert(key("ws", "a", 0), 1);
+    cache.insert(key("ws", "b", 0), 2);
+    assert_eq!(cache.get(&key("ws", "a", 0)), Some(1), "touch a");
+
+    cache.insert(key("ws", "c", 0), 3);
+    assert_eq!(cache.len(), 2);
+    assert_eq!(
+        cache.get(&key("ws", "b", 0)),
+        None,
+        "b was least recently used"
+    );
+    assert_eq!(cache.get(&key("ws", "a", 0)), Some(1));
+    assert_eq!(cache.get(&key("ws", "c", 0)), Some(3));
+}
+
+#[test]
+fn test_query_cache_purges_older_generations_of_same_workspace() {
+    let cache: QueryCache<u32> = QueryCache::new(16);
+    cache.insert(key("ws", "a", 0), 1);
+    cache.insert(key("other", "a", 0), 2);
+
+    cache.insert(key("ws", "b", 2), 3);
+    assert_eq!(
+        cache.get(&key("ws", "a", 0)),
+        None,
+        "stale generation purged"
+    );
+    assert_eq!(
+        cache.get(&key("other", "a", 0)),
+        Some(2),
+        "other workspaces keep their entries"
+    );
+    assert_eq!(
+        cache.get(&key("ws", "a", 2)),
+        None,
+        "generation is part of the key"
+    );
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_query_cache_key_follows_mutation_gate_generation() -> Result<()> {
+    let temp_dir = TempDir::new()?;
+    let handler = JulieServerHandler::new(temp_dir.path().to_path_buf()).await?;
+    handler.initialize_workspace(None).await?;
+    let workspace_id = handler
+        .current_workspace_id()
+        .expect("primary workspace should be bound");
+    let params = serde_json::json!({ "query": "hi" });
+
+    let before = handler
+        .query_cache_key("fast_search", &WorkspaceTarget::Primary, &params)
+        .expect("primary reads are cacheable");
+    assert!(
+        handler
+            .query_cache_key(
+                "fast_search",
+                &WorkspaceTarget::Target("other_ws".to_string()),
+                &params
+            )
+            .is_none(),
+        "reference workspaces are never cached"
+    );
+
+    let guard = handler.acquire_mutation_gate(&workspace_id).await;
+    assert!(
+        handler
+            .query_cache_key("fast_search", &WorkspaceTarget::Primary, &params)
+            .is_none(),
+        "no caching while a write is in flight"
+    );
+    drop(guard);
+
+    let after = handler
+        .query_cache_key("fast_search", &WorkspaceTarget::Primary, &params)
+        .expect("cacheable again once the write lands");
+    assert!(after.generation > before.generation);
+    assert_ne!(before, after);
+    Ok(())
+}
@@ -0,0 +1,117 @@
+use crate::database::SymbolDatabase;
+use crate::handler::ref_db_cache::{RefDbCache, RefIndexCache, spawn_idle_sweep};
+use crate::search::SearchIndex;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use tempfile::TempDir;
+
+fn open_db(dir: &TempDir, name: &str) -> (std::path::PathBuf, Arc<Mutex<SymbolDatabase>>) {
+    let path = dir.path().join(name);
+    let db = SymbolDatabase::new(&path).unwrap();
+    (path, Arc::new(Mutex::new(db)))
+}
+
+#[test]
+fn test_ref_db_cache_closes_workspaces_idle_past_timeout() {
+    let dir = TempDir::new().unwrap();
+    let mut cache = RefDbCache::new(Some(Duration::from_secs(60)));
+    let start = Instant::now();
+    let (lib_path, lib_db) = open_db(&dir, "lib.db");
+    let (docs_path, docs_db) = open_db(&dir, "docs.db");
+    cache.insert("lib", lib_path.clone(), Arc::clone(&lib_db), start);
+    cache.insert("docs", docs_path.clone(), Arc::clone(&docs_db), start);
+
+    // Touching `lib` resets its idle clock; `docs` keeps aging.
+    let later = start + Duration::from_secs(40);
+    assert!(cache.get("lib", &lib_path, later).is_some());
+
+    let evicted = cache.evict_idle(start + Duration::from_secs(70));
+    assert_eq!(evicted, ["docs"]);
+    assert_eq!(cache.len(), 1);
+    assert_eq!(
+        Arc::strong_count(&docs_db),
+        1,
+        "the cache released its handle, so the connection closes with ours"
+    );
+    assert!(
+        cache
+            .get("docs", &docs_path, start + Duration::from_secs(70))
+            .is_none(),
+        "an evicted workspace is reopened by the caller"
+    );
+
+    assert!(
+        cache
+            .get("lib", &lib_path, later + Duration::from_secs(60))
+            .is_none(),
+        "get evicts before looking up"
+    );
+    assert_eq!(cache.len(), 0);
+}
+
+#[test]
+fn test_ref_db_cache_ignores_handles_for_another_path_and_can_disable_eviction() {
+    let dir = TempDir::new().unwrap();
+    let mut cache = RefDbCache::new(None);
+    let start = Instant::now();
+    let (path, db) = open_db(&dir, "ws.db");
+    cache.insert("ws", path.clone(), db, start);
+
+    assert!(
+        cache
+            .get("ws", &dir.path().join("moved.db"), start)
+            .is_none(),
+        "a root-anchor change must not reuse the old handle"
+    );
+    let much_later = start + Duration::from_secs(365 * 24 * 3600);
+    assert!(cache.evict_idle(much_later).is_empty());
+    assert!(cache.get("ws", &path, much_later).is_some());
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_ref_db_cache_sweep_closes_idle_workspaces_without_further_calls() {
+    let dir = TempDir::new().unwrap();
+    let timeout = Duration::from_secs(60);
+    let cache = Arc::new(tokio::sync::RwLock::new(RefDbCache::new(Some(timeout))));
+    let (path, db) = open_db(&dir, "ws.db");
+    let last_used = Instant::now()
+        .checked_sub(timeout * 2)
+        .expect("the clock is past two idle timeouts");
+    cache.write().await.insert("ws", path, db, last_used);
+
+    let sweep = spawn_idle_sweep(&cache, Duration::from_secs(10));
+    tokio::time::sleep(Duration::from_secs(11)).await;
+    assert_eq!(
+        cache.read().await.len(),
+        0,
+        "the sweep evicted the idle entry"
+    );
+
+    drop(cache);
+    tokio::time::sleep(Duration::from_secs(11)).await;
+    assert!(sweep.is_finished(), "the sweep ends with its cache");
+}
+
+#[test]
+fn test_ref_index_cache_releases_idle_search_indexes() {
+    let dir = TempDir::new().unwrap();
+    let mut cache = RefIndexCache::new(Some(Duration::from_secs(60)));
+    let start = Instant::now();
+    let tantivy_path = dir.path().join("tantivy");
+    let index = Arc::new(SearchIndex::open_or_create(&tantivy_path).unwrap());
+    cache.insert("lib", tantivy_path.clone(), Arc::clone(&index), start);
+
+    let hit = cache
+        .get("lib", &tantivy_path, start + Duration::from_secs(30))
+        .expect("a warm index is served from the cache");
+    assert!(Arc::ptr_eq(&hit, &index));
+    drop(hit);
+
+    let evicted = cache.evict_idle(start + Duration::from_secs(90));
+    assert_eq!(evicted, ["lib"]);
+    assert_eq!(
+        Arc::strong_count(&index),
+        1,
+        "the cache released the index, so its readers close with ours"
+    );
+}
@@ -0,0 +1,124 @@
+use super::*;
+use crate::handler::resources::{
+    FILE_URI_PREFIX, RESOURCE_PAGE_SIZE, ResourceTarget, file_resource_page, file_uri,
+    parse_resource_uri,
+};
+use crate::tests::helpers::workspace::mark_workspace_root;
+use rmcp::model::{ReadResourceRequestParams, ResourceContents};
+
+#[test]
+fn resource_uris_round_trip_paths_and_reject_foreign_schemes() {
+    let uri = file_uri("src/my file#1.rs");
+    assert_eq!(uri, "julie://file/src/my%20file%231.rs");
+    assert_eq!(
+        parse_resource_uri(&uri),
+        Some(ResourceTarget::File("src/my file#1.rs".to_string()))
+    );
+    assert_eq!(
+        parse_resource_uri("julie://symbol/abc123"),
+        Some(ResourceTarget::Symbol("abc123".to_string()))
+    );
+    assert_eq!(parse_resource_uri("julie://symbol/"), None);
+    assert_eq!(parse_resource_uri("julie://file/bad%zz"), None);
+    assert_eq!(parse_resource_uri("file:///etc/passwd"), None);
+}
+
+#[test]
+fn file_resource_pages_are_sorted_and_cursored_by_offset() -> Result<()> {
+    let paths: Vec<String> = (0..RESOURCE_PAGE_SIZE + 3)
+        .rev()
+        .map(|i| format!("src/f{i:04}.rs"))
+        .collect();
+
+    let first = file_resource_page(paths.clone(), None)?;
+    assert_eq!(first.resources.len(), RESOURCE_PAGE_SIZE);
+    assert_eq!(
+        first.resources[0].raw.uri,
+        format!("{FILE_URI_PREFIX}src/f0000.rs")
+    );
+    assert_eq!(first.next_cursor.as_deref(), Some("500"));
+
+    let second = file_resource_page(paths.clone(), first.next_cursor.as_deref())?;
+    assert_eq!(second.resources.len(), 3);
+    assert_eq!(second.next_cursor, None);
+
+    assert!(file_resource_page(paths, Some("not-a-number")).is_err());
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn read_resource_serves_indexed_files_and_symbol_documents() -> Result<()> {
+    use crate::tools::workspace::ManageWorkspaceTool;
+
+    let temp_dir = TempDir::new()?;
+    mark_workspace_root(temp_dir.path());
+    std::fs::create_dir_all(temp_dir.path().join("src"))?;
+    let source = "/// Greets the caller.\npub fn greet(name: &str) -> String {\n    format!(\"hi {name}\")\n}\n";
+    std::fs::write(temp_dir.path().join("src/lib.rs"), source)?;
+
+    let handler = JulieServerHandler::new(temp_dir.path().to_path_buf()).await?;
+    ManageWorkspaceTool {
+        operation: "index".to_string(),
+        workspace_id: None,
+        path: Some(temp_dir.path().to_string_lossy().to_string()),
+        name: None,
+        force: Some(false),
+        detailed: None,
+        from_generation: None,
+        to_generation: None,
+        dry_run: None,
+    }
+    .call_tool(&handler)
+    .await?;
+
+    let greet_id = {
+        let db = handler.primary_database().await?;
+        let db = db.lock().expect("workspace db should lock");
+        db.get_symbols_for_file("src/lib.rs")?
+            .into_iter()
+            .find(|symbol| symbol.name == "greet")
+            .expect("greet should be indexed")
+            .id
+    };
+
+    let (server_transport, client_transport) = tokio::io::duplex(64);
+    drop(client_transport);
+    let service =
+        serve_directly::<rmcp::RoleServer, _, _, _, _>(handler.clone(), server_transport, None);
+    let read = |uri: String, id: i64| {
+        let handler = handler.clone();
+        let peer = service.peer().clone();
+        async move {
+            let request: ReadResourceRequestParams =
+                serde_json::from_value(serde_json::json!({ "uri": uri }))?;
+            let result = <JulieServerHandler as ServerHandler>::read_resource(
+                &handler,
+                request,
+                RequestContext::new(NumberOrString::Number(id), peer),
+            )
+            .await?;
+            match result.contents.into_iter().next() {
+                Some(ResourceContents::TextResourceContents { text, .. }) => Ok(text),
+                other => Err(anyhow::anyhow!("expected text contents, got {other:?}")),
+            }
+        }
+    };
+
+    assert_eq!(read(file_uri("src/lib.rs"), 1).await?, source);
+
+    let document = read(format!("julie://symbol/{greet_id}"), 2).await?;
+    assert!(document.starts_with("# greet (function)\nsrc/lib.rs:"));
+    assert!(document.contains("Greets the caller."));
+    assert!(document.contains("```rust\n"));
+    assert!(document.contains("pub fn greet(name: &str) -> String {"));
+
+    assert!(read(file_uri("src/missing.rs"), 3).await.is_err());
+    assert!(
+        read("julie://symbol/no-such-id".to_string(), 4)
+            .await
+            .is_err()
+    );
+
+    let _ = service.cancel().await;
+    Ok(())
+}
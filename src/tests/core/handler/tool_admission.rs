@@ -0,0 +1,64 @@
+use crate::handler::tool_admission::{
+    AdmissionRejected, ToolAdmission, bypasses_admission, tool_call_cost,
+};
+use std::time::Duration;
+
+#[test]
+fn test_tool_call_cost_weights_heavy_tools_and_active_writers() {
+    assert_eq!(tool_call_cost("fast_search", false), 1);
+    assert_eq!(tool_call_cost("blast_radius", false), 2);
+    assert_eq!(tool_call_cost("fast_search", true), 2);
+    assert_eq!(tool_call_cost("get_context", true), 4);
+}
+
+#[test]
+fn test_writes_and_bookkeeping_tools_bypass_admission() {
+    assert!(bypasses_admission("edit_file", true));
+    assert!(bypasses_admission("manage_workspace", false));
+    assert!(bypasses_admission("task_status", false));
+    assert!(!bypasses_admission("fast_refs", false));
+}
+
+#[tokio::test]
+async fn test_admission_caps_concurrent_calls_and_times_out() {
+    let admission = ToolAdmission::new(2, Duration::from_millis(50));
+    let first = admission.admit(1).await.expect("first call admitted");
+    let _second = admission.admit(1).await.expect("second call admitted");
+
+    assert_eq!(
+        admission.admit(1).await.unwrap_err(),
+        AdmissionRejected::TimedOut(Duration::from_millis(50))
+    );
+
+    drop(first);
+    assert!(admission.admit(1).await.is_ok(), "freed permit is reusable");
+}
+
+#[tokio::test]
+async fn test_admission_caps_cost_at_session_total() {
+    let admission = ToolAdmission::new(2, Duration::from_millis(50));
+    let permit = admission.admit(4).await.expect("oversized cost is capped");
+    assert_eq!(permit.num_permits(), 2);
+}
+
+#[tokio::test]
+async fn test_admission_refuses_when_queue_is_full() {
+    let admission = std::sync::Arc::new(ToolAdmission::new(1, Duration::from_secs(30)));
+    let _running = admission.admit(1).await.unwrap();
+
+    let waiters: Vec<_> = (0..4)
+        .map(|_| {
+            let admission = std::sync::Arc::clone(&admission);
+            tokio::spawn(async move { admission.admit(1).await.map(drop) })
+        })
+        .collect();
+    tokio::time::sleep(Duration::from_millis(20)).await;
+
+    assert_eq!(
+        admission.admit(1).await.unwrap_err(),
+        AdmissionRejected::QueueFull { waiting: 4 }
+    );
+    for waiter in waiters {
+        waiter.abort();
+    }
+}
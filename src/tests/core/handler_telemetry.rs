@@ -91,6 +91,9 @@ async fn seed_workspace(files: &[(&str, &str)]) -> (TempDir, JulieServerHandler)
         name: None,
         workspace_id: None,
         detailed: None,
+        from_generation: None,
+        to_generation: None,
+        dry_run: None,
     };
     index_tool
         .call_tool(&handler)
@@ -109,9 +112,18 @@ fn search_tool(query: &str, _search_target: &str) -> FastSearchTool {
         limit: 10,
         context_lines: Some(0),
         exclude_tests: None,
+        include_generated: false,
         backend: None,
         workspace: Some("primary".to_string()),
         return_format: "full".to_string(),
+        max_tokens: None,
+        dedupe: true,
+        fuzzy: false,
+        defines: None,
+        project: None,
+        framework: None,
+        languages: None,
+        boost_languages: None,
     }
 }
 
@@ -268,6 +280,9 @@ fn test_fast_refs_metadata_captures_result_shaping_fields() {
         limit: 25,
         workspace: Some("target-workspace".to_string()),
         reference_kind: Some("call".to_string()),
+        include_textual: false,
+        project: None,
+        max_tokens: None,
     };
 
     let metadata = tool_targets::fast_refs_metadata(&params);
@@ -720,6 +735,7 @@ fn test_get_symbols_metadata_prefers_file_target_with_symbol_filter() {
         limit: Some(10),
         mode: Some("minimal".to_string()),
         workspace: Some("primary".to_string()),
+        max_tokens: None,
     };
 
     let metadata = tool_targets::get_symbols_metadata(&params);
@@ -738,6 +754,9 @@ fn test_deep_dive_metadata_carries_symbol_and_context_file_target() {
         depth: DeepDiveDepth::Context,
         context_file: Some("src/dashboard/routes/search.rs".to_string()),
         workspace: Some("primary".to_string()),
+        project: None,
+        disambiguator: None,
+        max_tokens: None,
     };
 
     let metadata = tool_targets::deep_dive_metadata(&params);
@@ -779,6 +798,7 @@ fn test_get_context_metadata_carries_format_and_task_inputs() {
         failing_test: Some("get_context_metadata".to_string()),
         max_hops: Some(2),
         prefer_tests: Some(true),
+        project: None,
     };
 
     let metadata = tool_targets::get_context_metadata(&params);
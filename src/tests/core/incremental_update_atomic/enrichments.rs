@@ -766,3 +766,44 @@ fn test_replace_workspace_data_atomic_clears_stale_literals() {
         "only the rebuilt batch's literal may survive"
     );
 }
+
+#[test]
+fn test_parse_skips_persist_as_informational_repair_rows() {
+    let tmp = TempDir::new().unwrap();
+    let db_path = tmp.path().join("test.db");
+    let mut db = SymbolDatabase::new(&db_path).unwrap();
+
+    let files = vec![make_file("dist/app.min.js")];
+    let write_set = CanonicalWriteSet {
+        files: &files,
+        ..Default::default()
+    };
+    let parse_skips = vec![(
+        "dist/app.min.js".to_string(),
+        "minified or generated content".to_string(),
+    )];
+    db.incremental_update_atomic_with_metadata(
+        &[],
+        &write_set,
+        "ws_test",
+        AtomicPersistenceMetadata {
+            parse_skips: &parse_skips,
+            ..Default::default()
+        },
+    )
+    .expect("persisting a parse skip should succeed");
+    db.record_indexing_repair("src/lib.rs", "extractor_failure", Some("boom"))
+        .expect("record_indexing_repair should succeed");
+
+    let skips = db.list_parse_skips().expect("list_parse_skips");
+    assert_eq!(
+        skips.len(),
+        1,
+        "only parse_skipped rows are listed: {skips:?}"
+    );
+    assert_eq!(skips[0].path, "dist/app.min.js");
+    assert_eq!(
+        skips[0].detail.as_deref(),
+        Some("minified or generated content")
+    );
+}
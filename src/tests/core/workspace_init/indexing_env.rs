@@ -47,6 +47,9 @@ async fn test_incremental_indexing_respects_env_var() {
         name: None,
         workspace_id: None,
         detailed: None,
+        from_generation: None,
+        to_generation: None,
+        dry_run: None,
     };
 
     // Call the tool - this should index target_workspace, not different_cwd
@@ -112,6 +115,9 @@ fn test_resolve_workspace_path_respects_env_var() {
         name: None,
         workspace_id: None,
         detailed: None,
+        from_generation: None,
+        to_generation: None,
+        dry_run: None,
     };
 
     // Without handler_root, env var should be used (third priority)
@@ -41,6 +41,9 @@ fn test_find_workspace_root_rejects_home_julie_dir() {
         name: None,
         workspace_id: None,
         detailed: None,
+        from_generation: None,
+        to_generation: None,
+        dry_run: None,
     };
 
     let result = tool
@@ -112,6 +115,9 @@ fn test_find_workspace_root_does_not_let_parent_julie_capture_unmarked_explicit_
         name: None,
         workspace_id: None,
         detailed: None,
+        from_generation: None,
+        to_generation: None,
+        dry_run: None,
     };
 
     let result = tool
@@ -282,6 +288,9 @@ fn test_paths_find_workspace_root_recognizes_hg_root() {
         name: None,
         workspace_id: None,
         detailed: None,
+        from_generation: None,
+        to_generation: None,
+        dry_run: None,
     };
 
     let result = tool
@@ -346,6 +346,44 @@ fn test_get_file_hotspots_null_line_count_treated_as_zero() {
     );
 }
 
+#[test]
+fn test_get_file_hotspots_with_churn_ranks_by_complexity_times_commits() {
+    let (_tmp, mut db) = test_db();
+
+    // big.rs: 100 lines + 1 symbol = 110, 1 commit  -> risk 110
+    // busy.rs: 20 lines + 1 symbol = 30, 5 commits  -> risk 150
+    // stale.rs: 500 lines + 1 symbol, no commits    -> left out
+    for (path, lines) in [
+        ("src/big.rs", 100),
+        ("src/busy.rs", 20),
+        ("src/stale.rs", 500),
+    ] {
+        db.store_file_info(&make_file(path, "rust", lines, lines * 10))
+            .unwrap();
+        store_function_symbol(&mut db, path, "f", "rust", path, None, 0.0);
+    }
+    let commit_counts = HashMap::from([
+        ("src/big.rs".to_string(), 1),
+        ("src/busy.rs".to_string(), 5),
+        ("src/deleted.rs".to_string(), 9),
+    ]);
+
+    let result = db.get_file_hotspots_with_churn(10, &commit_counts).unwrap();
+    let ranked: Vec<(&str, Option<i64>)> = result
+        .iter()
+        .map(|hotspot| (hotspot.path.as_str(), hotspot.commit_count))
+        .collect();
+    assert_eq!(
+        ranked,
+        vec![("src/busy.rs", Some(5)), ("src/big.rs", Some(1))],
+        "frequently changed files outrank bigger ones that rarely change"
+    );
+    assert_eq!(result[0].risk_score(), 150.0);
+
+    let top = db.get_file_hotspots_with_churn(1, &commit_counts).unwrap();
+    assert_eq!(top.len(), 1);
+}
+
 // --- get_aggregate_stats ---
 
 #[test]
@@ -563,6 +601,7 @@ fn test_generate_story_cards_produces_expected_cards() {
         line_count: 800,
         size: 20000,
         symbol_count: 60,
+        commit_count: None,
     }];
 
     let mut by_kind = HashMap::new();
@@ -644,6 +683,7 @@ fn test_generate_story_cards_skips_refs_card_when_low() {
         line_count: 100,
         size: 1000,
         symbol_count: 5,
+        commit_count: None,
     }];
 
     let mut by_kind = HashMap::new();
@@ -77,6 +77,86 @@ fn external_extract_args_parse_scan_update_delete_analyze_info() {
     assert!(!analyze.analyze);
 }
 
+#[test]
+fn external_extract_args_parse_shard_and_merge() {
+    let shard = ExternalExtractArgs::try_parse_from([
+        "extract", "--root", "/repo", "shard", "--shard", "1/4",
+    ])
+    .expect("shard parses without --db");
+
+    assert_eq!(shard.command.as_str(), "shard");
+    assert!(shard.command.streams_to_stdout());
+    match shard.command {
+        ExternalExtractCommand::Shard { shard, output } => {
+            assert_eq!((shard.index, shard.count), (1, 4));
+            assert_eq!(output, None);
+        }
+        other => panic!("unexpected command: {other:?}"),
+    }
+
+    assert!(
+        ExternalExtractArgs::try_parse_from([
+            "extract", "--root", "/repo", "shard", "--shard", "4/4",
+        ])
+        .is_err()
+    );
+
+    let merge = ExternalExtractArgs::try_parse_from([
+        "extract",
+        "--db",
+        "external.sqlite",
+        "--root",
+        "/repo",
+        "merge",
+        "--workers",
+        "2",
+    ])
+    .expect("merge --workers parses");
+    assert!(!merge.command.streams_to_stdout());
+    match merge.command {
+        ExternalExtractCommand::Merge {
+            workers: Some(2),
+            worker_command,
+            ..
+        } => assert!(worker_command.is_empty()),
+        other => panic!("unexpected command: {other:?}"),
+    }
+
+    // The worker command is argv, so quoted arguments keep their spaces.
+    let remote = ExternalExtractArgs::try_parse_from([
+        "extract",
+        "--db",
+        "external.sqlite",
+        "--root",
+        "/repo",
+        "merge",
+        "--workers",
+        "2",
+        "--",
+        "ssh",
+        "build-{index}",
+        "julie-server extract --root '/src/my repo' shard --shard {shard}",
+    ])
+    .expect("merge with a worker command parses");
+    match remote.command {
+        ExternalExtractCommand::Merge { worker_command, .. } => assert_eq!(
+            worker_command,
+            [
+                "ssh",
+                "build-{index}",
+                "julie-server extract --root '/src/my repo' shard --shard {shard}",
+            ]
+        ),
+        other => panic!("unexpected command: {other:?}"),
+    }
+
+    let missing_db = ExternalExtractArgs::try_parse_from([
+        "extract", "--root", "/repo", "merge", "--input", "a.jsonl",
+    ])
+    .unwrap_err();
+    assert!(missing_db.to_string().contains("--db"));
+}
+
 #[test]
 fn external_extract_args_update_delete_require_file() {
     for command in ["update", "delete"] {
@@ -333,3 +413,45 @@ fn external_extract_failed_report_includes_hash_algorithm_in_all_formats() {
         .expect("markdown report formats");
     assert!(markdown.contains("| Hash Algorithm | blake3 |"));
 }
+
+#[test]
+fn external_extract_args_parse_export_filters() {
+    let export = ExternalExtractArgs::try_parse_from([
+        "extract",
+        "--root",
+        "/repo",
+        "export",
+        "--language",
+        "rust",
+        "--language",
+        "python",
+        "--kind",
+        "function",
+    ])
+    .expect("export parses without --db");
+
+    assert_eq!(export.command.as_str(), "export");
+    assert!(export.command.streams_to_stdout());
+    assert_eq!(
+        export.command,
+        ExternalExtractCommand::Export {
+            languages: vec!["rust".to_string(), "python".to_string()],
+            kinds: vec!["function".to_string()],
+            output: None,
+        }
+    );
+
+    let to_file = ExternalExtractArgs::try_parse_from([
+        "extract",
+        "--root",
+        "/repo",
+        "export",
+        "--output",
+        "symbols.jsonl",
+    ])
+    .expect("export --output parses");
+    assert!(!to_file.command.streams_to_stdout());
+
+    let missing_root = ExternalExtractArgs::try_parse_from(["extract", "export"]).unwrap_err();
+    assert!(missing_root.to_string().contains("--root"));
+}
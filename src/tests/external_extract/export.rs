@@ -0,0 +1,114 @@
+use std::fs;
+
+use serde_json::Value;
+use tempfile::TempDir;
+
+use crate::external_extract::{ExternalExtractArgs, ExternalExtractCommand, run_external_extract};
+
+fn export_args(
+    root: std::path::PathBuf,
+    kinds: &[&str],
+    output: std::path::PathBuf,
+) -> ExternalExtractArgs {
+    ExternalExtractArgs {
+        db: std::path::PathBuf::new(),
+        root: Some(root),
+        strict_schema: false,
+        ignore_files: Vec::new(),
+        workspace_id: None,
+        analyze: false,
+        command: ExternalExtractCommand::Export {
+            languages: vec!["rust".to_string()],
+            kinds: kinds.iter().map(|kind| kind.to_string()).collect(),
+            output: Some(output),
+        },
+    }
+}
+
+fn write_sources(root: &std::path::Path) {
+    fs::write(
+        root.join("lib.rs"),
+        r#"
+pub struct Parser;
+
+pub fn tokenize() -> Vec<String> {
+    Vec::new()
+}
+
+pub fn parse() -> Parser {
+    tokenize();
+    Parser
+}
+"#,
+    )
+    .expect("write rust source");
+    fs::write(root.join("tool.py"), "def helper():\n    return 1\n").expect("write python source");
+}
+
+fn read_records(path: &std::path::Path) -> Vec<Value> {
+    fs::read_to_string(path)
+        .expect("read export")
+        .lines()
+        .map(|line| serde_json::from_str(line).expect("each line is one JSON record"))
+        .collect()
+}
+
+fn names_of(records: &[Value], record_type: &str) -> Vec<String> {
+    records
+        .iter()
+        .filter(|record| record["type"] == record_type)
+        .map(|record| record["name"].as_str().unwrap_or_default().to_string())
+        .collect()
+}
+
+#[tokio::test]
+async fn extract_export_streams_tagged_jsonl_for_selected_languages() {
+    let temp_dir = TempDir::new().expect("temp dir");
+    let root = temp_dir.path().join("repo");
+    fs::create_dir(&root).expect("create root");
+    write_sources(&root);
+    let output = temp_dir.path().join("export.jsonl");
+
+    let report = run_external_extract(&export_args(root, &[], output.clone()))
+        .await
+        .expect("export succeeds");
+    assert_eq!(report.operation, "export");
+    assert_eq!(report.files_scanned, 1, "python file filtered out");
+
+    let records = read_records(&output);
+    let symbols = names_of(&records, "symbol");
+    assert!(symbols.contains(&"Parser".to_string()), "{symbols:?}");
+    assert!(symbols.contains(&"parse".to_string()), "{symbols:?}");
+    assert!(!symbols.contains(&"helper".to_string()), "{symbols:?}");
+    assert_eq!(report.symbols_extracted, symbols.len() as u64);
+    assert!(
+        names_of(&records, "identifier").contains(&"tokenize".to_string()),
+        "call identifiers are exported"
+    );
+    assert!(
+        records.iter().all(|record| record["file_path"]
+            .as_str()
+            .is_none_or(|path| path == "lib.rs")),
+        "paths are workspace-relative"
+    );
+}
+
+#[tokio::test]
+async fn extract_export_kind_filter_keeps_only_owned_records() {
+    let temp_dir = TempDir::new().expect("temp dir");
+    let root = temp_dir.path().join("repo");
+    fs::create_dir(&root).expect("create root");
+    write_sources(&root);
+    let output = temp_dir.path().join("export.jsonl");
+
+    run_external_extract(&export_args(root, &["Struct"], output.clone()))
+        .await
+        .expect("export succeeds");
+
+    let records = read_records(&output);
+    assert_eq!(names_of(&records, "symbol"), ["Parser"]);
+    assert!(
+        names_of(&records, "identifier").is_empty(),
+        "the tokenize() call belongs to parse, which was filtered out"
+    );
+}
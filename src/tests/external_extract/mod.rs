@@ -1,5 +1,7 @@
 pub mod cli;
 
+pub mod export;
+
 pub mod info;
 
 pub mod locking;
@@ -18,7 +18,8 @@ use crate::extractors::{Identifier, IdentifierKind, Symbol, SymbolKind};
 use crate::indexing_core::batch::ExtractedBatch;
 use crate::indexing_core::extraction::extract_files_for_indexing;
 use crate::indexing_core::persistence::{
-    persist_force_rebuild, persist_incremental_scan, persist_single_file_delete,
+    persist_force_rebuild, persist_force_rebuild_streamed, persist_incremental_scan,
+    persist_single_file_delete,
 };
 use crate::tests::helpers::db::{file_info_builder, identifier_builder, symbol_builder};
 
@@ -321,6 +322,78 @@ fn extract_force_rebuild_is_atomic_after_extraction_success() {
     assert_eq!(changes[0].file_path, "new.rs");
 }
 
+#[test]
+fn extract_streamed_force_rebuild_writes_every_part_in_one_revision() {
+    let tmp = TempDir::new().expect("temp dir");
+    let db_path = tmp.path().join("external.db");
+    let mut db = SymbolDatabase::new(&db_path).expect("db");
+
+    let old_batch = batch_for(
+        vec![make_file("old.rs", "old_hash")],
+        vec![make_symbol("old_symbol", "old_entry", "old.rs")],
+    );
+    persist_force_rebuild(&mut db, "external_ws", &old_batch).expect("seed force rebuild");
+
+    let mut parts = vec![
+        batch_for(
+            vec![make_file("a.rs", "a_hash")],
+            vec![make_symbol("a_symbol", "a_entry", "a.rs")],
+        ),
+        batch_for(
+            vec![make_file("b.rs", "b_hash")],
+            vec![make_symbol("b_symbol", "b_entry", "b.rs")],
+        ),
+    ]
+    .into_iter();
+    let revision = persist_force_rebuild_streamed(&mut db, "external_ws", || Ok(parts.next()))
+        .expect("streamed force rebuild");
+
+    assert_eq!(revision, Some(2));
+    assert_eq!(count_rows(&db, "files"), 2);
+    assert_eq!(count_rows(&db, "symbols"), 2);
+    assert!(db.get_file_hash("old.rs").expect("old hash").is_none());
+
+    let mut changed: Vec<_> = db
+        .get_revision_file_changes_between("external_ws", 1, 2)
+        .expect("revision changes")
+        .into_iter()
+        .map(|change| (change.revision, change.file_path))
+        .collect();
+    changed.sort();
+    assert_eq!(changed, [(2, "a.rs".to_string()), (2, "b.rs".to_string())]);
+}
+
+#[test]
+fn extract_streamed_force_rebuild_keeps_previous_index_when_a_part_fails() {
+    let tmp = TempDir::new().expect("temp dir");
+    let db_path = tmp.path().join("external.db");
+    let mut db = SymbolDatabase::new(&db_path).expect("db");
+
+    let old_batch = batch_for(
+        vec![make_file("old.rs", "old_hash")],
+        vec![make_symbol("old_symbol", "old_entry", "old.rs")],
+    );
+    persist_force_rebuild(&mut db, "external_ws", &old_batch).expect("seed force rebuild");
+
+    let mut first = Some(batch_for(
+        vec![make_file("a.rs", "a_hash")],
+        vec![make_symbol("a_symbol", "a_entry", "a.rs")],
+    ));
+    let error = persist_force_rebuild_streamed(&mut db, "external_ws", || match first.take() {
+        Some(batch) => Ok(Some(batch)),
+        None => Err(anyhow::anyhow!("shard stream truncated")),
+    })
+    .unwrap_err();
+
+    assert!(error.to_string().contains("truncated"));
+    assert_eq!(count_rows(&db, "files"), 1);
+    assert_eq!(
+        db.get_file_hash("old.rs").expect("old hash"),
+        Some("old_hash".to_string())
+    );
+    assert!(db.get_file_hash("a.rs").expect("a hash").is_none());
+}
+
 #[test]
 fn extract_mixed_scan_records_single_revision() {
     let tmp = TempDir::new().expect("temp dir");
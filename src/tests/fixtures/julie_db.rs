@@ -62,6 +62,9 @@ impl JulieTestFixture {
             name: None,
             workspace_id: None,
             detailed: None,
+            from_generation: None,
+            to_generation: None,
+            dry_run: None,
         };
 
         index_tool.call_tool(&handler).await?;
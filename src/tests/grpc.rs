@@ -0,0 +1,80 @@
+//! Tests for the optional gRPC API: token auth, CLI args and reply shape.
+
+use crate::cli::{Cli, Command};
+use crate::grpc::auth::{TokenAuth, bearer_matches, validate_token};
+use crate::grpc::tool_reply;
+use crate::mcp_compat::{CallToolResult, Content};
+use clap::Parser;
+use tonic::Request;
+use tonic::service::Interceptor;
+
+const TOKEN: &str = "0123456789abcdef-review-bot";
+
+#[test]
+fn test_token_must_be_set_and_long_enough() {
+    assert!(validate_token("").is_err());
+    assert!(validate_token("short").is_err());
+    assert_eq!(validate_token(TOKEN).unwrap(), TOKEN);
+}
+
+#[test]
+fn test_bearer_header_must_carry_the_token() {
+    assert!(bearer_matches(Some(&format!("Bearer {TOKEN}")), TOKEN));
+    assert!(bearer_matches(Some(&format!("bearer  {TOKEN} ")), TOKEN));
+    assert!(!bearer_matches(None, TOKEN));
+    assert!(!bearer_matches(Some(TOKEN), TOKEN));
+    assert!(!bearer_matches(Some(&format!("Basic {TOKEN}")), TOKEN));
+    assert!(!bearer_matches(Some(&format!("Bearer {TOKEN}x")), TOKEN));
+    assert!(!bearer_matches(Some("Bearer "), TOKEN));
+}
+
+#[test]
+fn test_interceptor_rejects_calls_without_the_token() {
+    let mut auth = TokenAuth::new(TOKEN);
+
+    let status = auth.call(Request::new(())).unwrap_err();
+    assert_eq!(status.code(), tonic::Code::Unauthenticated);
+
+    let mut request = Request::new(());
+    request
+        .metadata_mut()
+        .insert("authorization", format!("Bearer {TOKEN}").parse().unwrap());
+    assert!(auth.call(request).is_ok());
+}
+
+#[test]
+fn test_grpc_command_defaults_to_loopback() {
+    let cli = Cli::try_parse_from(["julie-server", "grpc"]).unwrap();
+    let Some(Command::Grpc(args)) = cli.command else {
+        panic!("expected grpc command");
+    };
+    assert_eq!(args.listen.to_string(), "127.0.0.1:50051");
+
+    let cli = Cli::try_parse_from(["julie-server", "grpc", "--listen", "0.0.0.0:7000"]).unwrap();
+    let Some(Command::Grpc(args)) = cli.command else {
+        panic!("expected grpc command");
+    };
+    assert_eq!(args.listen.port(), 7000);
+}
+
+#[test]
+fn test_tool_reply_carries_text_and_structured_content() {
+    let mut result = CallToolResult::success(vec![
+        Content::text("Found 2 definitions of 'process'."),
+        Content::text("  src/a.rs:3"),
+    ]);
+    result.structured_content = Some(serde_json::json!({"disambiguation": {"symbol": "process"}}));
+
+    let reply = tool_reply(result);
+    assert_eq!(
+        reply.text,
+        "Found 2 definitions of 'process'.\n  src/a.rs:3"
+    );
+    let structured: serde_json::Value = serde_json::from_str(&reply.structured_json).unwrap();
+    assert_eq!(structured["disambiguation"]["symbol"], "process");
+    assert!(!reply.is_error);
+
+    let reply = tool_reply(CallToolResult::error(vec![Content::text("No symbol")]));
+    assert!(reply.is_error);
+    assert!(reply.structured_json.is_empty());
+}
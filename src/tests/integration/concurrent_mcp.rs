@@ -182,6 +182,9 @@ mod tests {
                 workspace_id: None,
                 force: Some(true),
                 detailed: None,
+                from_generation: None,
+                to_generation: None,
+                dry_run: None,
             }
             .call_tool(&handler),
         )
@@ -228,6 +231,7 @@ mod tests {
                     limit: Some(50),
                     mode: Some("structure".to_string()),
                     workspace: None,
+                    max_tokens: None,
                 }
                 .call_tool(h.as_ref())
                 .await?;
@@ -242,6 +246,9 @@ mod tests {
                     depth: DeepDiveDepth::Overview,
                     context_file: None,
                     workspace: None,
+                    project: None,
+                    disambiguator: None,
+                    max_tokens: None,
                 }
                 .call_tool(h.as_ref())
                 .await?;
@@ -284,6 +291,7 @@ mod tests {
                     failing_test: None,
                     max_hops: Some(1),
                     prefer_tests: Some(false),
+                    project: None,
                 }
                 .call_tool(h.as_ref())
                 .await?;
@@ -305,9 +313,18 @@ mod tests {
                     limit: 5,
                     context_lines: Some(0),
                     exclude_tests: None,
+                    include_generated: false,
                     backend: None,
                     workspace: None,
                     return_format: "locations".to_string(),
+                    max_tokens: None,
+                    dedupe: true,
+                    fuzzy: false,
+                    defines: None,
+                    project: None,
+                    framework: None,
+                    languages: None,
+                    boost_languages: None,
                 }
                 .call_tool(h.as_ref())
                 .await?;
@@ -451,6 +468,7 @@ mod tests {
                     limit: Some(50),
                     mode: Some("structure".to_string()),
                     workspace: Some(ws),
+                    max_tokens: None,
                 }
                 .call_tool(h.as_ref())
                 .await?;
@@ -468,6 +486,9 @@ mod tests {
                     depth: DeepDiveDepth::Overview,
                     context_file: None,
                     workspace: Some(ws),
+                    project: None,
+                    disambiguator: None,
+                    max_tokens: None,
                 }
                 .call_tool(h.as_ref())
                 .await?;
@@ -486,6 +507,9 @@ mod tests {
                     limit: 10,
                     workspace: Some(ws),
                     reference_kind: None,
+                    include_textual: false,
+                    project: None,
+                    max_tokens: None,
                 }
                 .call_tool(h.as_ref())
                 .await?;
@@ -530,6 +554,9 @@ mod tests {
                     workspace_id: None,
                     force: Some(false),
                     detailed: None,
+                    from_generation: None,
+                    to_generation: None,
+                    dry_run: None,
                 }
                 .call_tool(&h)
                 .await?;
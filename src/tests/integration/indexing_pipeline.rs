@@ -27,6 +27,9 @@ fn workspace_tool() -> ManageWorkspaceTool {
         name: None,
         workspace_id: None,
         detailed: None,
+        from_generation: None,
+        to_generation: None,
+        dry_run: None,
     }
 }
 
@@ -42,6 +42,9 @@ async fn index_workspace(
         name: None,
         workspace_id: None,
         detailed: None,
+        from_generation: None,
+        to_generation: None,
+        dry_run: None,
     };
 
     index_tool.call_tool(handler).await?;
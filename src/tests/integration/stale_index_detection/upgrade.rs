@@ -107,6 +107,9 @@ async fn test_v690_upgrade_preserves_existing_tantivy_docs_on_first_edit() -> Re
         name: None,
         workspace_id: None,
         detailed: None,
+        from_generation: None,
+        to_generation: None,
+        dry_run: None,
     };
     non_force_index.call_tool(&upgraded).await?;
 
@@ -130,6 +130,9 @@ mod tests {
             name: None,
             workspace_id: None,
             detailed: None,
+            from_generation: None,
+            to_generation: None,
+            dry_run: None,
         }
         .call_tool(&handler)
         .await?;
@@ -54,6 +54,9 @@ mod target_workspace_tests {
             name: None,
             workspace_id: None,
             detailed: None,
+            from_generation: None,
+            to_generation: None,
+            dry_run: None,
         };
         index_primary.call_tool(handler).await?;
         mark_index_ready(handler).await;
@@ -75,6 +78,9 @@ mod target_workspace_tests {
                 name: None,
                 workspace_id: None,
                 detailed: None,
+                from_generation: None,
+                to_generation: None,
+                dry_run: None,
             };
             index_target.call_tool(handler).await?;
             mark_index_ready(handler).await;
@@ -296,6 +302,9 @@ mod target_workspace_tests {
             name: None,
             workspace_id: None,
             detailed: None,
+            from_generation: None,
+            to_generation: None,
+            dry_run: None,
         };
         index_primary.call_tool(&handler).await?;
         mark_index_ready(&handler).await;
@@ -309,6 +318,9 @@ mod target_workspace_tests {
                 name: None,
                 workspace_id: None,
                 detailed: None,
+                from_generation: None,
+                to_generation: None,
+                dry_run: None,
             };
             index_reference.call_tool(&handler).await?;
             mark_index_ready(&handler).await;
@@ -421,6 +433,9 @@ mod target_workspace_tests {
             name: None,
             workspace_id: None,
             detailed: None,
+            from_generation: None,
+            to_generation: None,
+            dry_run: None,
         };
 
         let reindex_result = reindex_tool.call_tool(&handler).await?;
@@ -63,6 +63,9 @@ mod workspace_isolation_smoke_tests {
             name: None,
             workspace_id: None,
             detailed: None,
+            from_generation: None,
+            to_generation: None,
+            dry_run: None,
         };
         index_primary.call_tool(&handler).await?;
         mark_index_ready(&handler).await;
@@ -76,6 +79,9 @@ mod workspace_isolation_smoke_tests {
                 name: None,
                 workspace_id: None,
                 detailed: None,
+                from_generation: None,
+                to_generation: None,
+                dry_run: None,
             };
             index_reference.call_tool(&handler).await?;
             mark_index_ready(&handler).await;
@@ -199,6 +205,9 @@ mod workspace_isolation_smoke_tests {
             name: None,
             workspace_id: None,
             detailed: None,
+            from_generation: None,
+            to_generation: None,
+            dry_run: None,
         };
         index_primary.call_tool(&handler).await?;
         mark_index_ready(&handler).await;
@@ -212,6 +221,9 @@ mod workspace_isolation_smoke_tests {
                 name: None,
                 workspace_id: None,
                 detailed: None,
+                from_generation: None,
+                to_generation: None,
+                dry_run: None,
             };
             index_reference.call_tool(&handler).await?;
             mark_index_ready(&handler).await;
@@ -287,6 +299,9 @@ mod workspace_isolation_smoke_tests {
             name: None,
             workspace_id: None,
             detailed: None,
+            from_generation: None,
+            to_generation: None,
+            dry_run: None,
         };
         index_primary.call_tool(&handler).await?;
         mark_index_ready(&handler).await;
@@ -190,6 +190,7 @@ fn replay_content_zero_hits_and_classify() -> Result<()> {
             kind: None,
             file_pattern: entry.file_pattern.clone(),
             exclude_tests: entry.exclude_tests.unwrap_or(false),
+            include_generated: false,
         };
         let limit = entry.limit_param.unwrap_or(10).max(1);
         let result = index
@@ -146,6 +146,9 @@ async fn bootstrap_handler() -> Result<(TempDir, JulieServerHandler)> {
         name: None,
         workspace_id: None,
         detailed: None,
+        from_generation: None,
+        to_generation: None,
+        dry_run: None,
     };
     index_tool
         .call_tool(&handler)
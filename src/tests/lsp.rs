@@ -0,0 +1,373 @@
+//! Tests for the LSP gateway: framing, position and URI conversion, and the
+//! requests against an indexed workspace.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::Result;
+use serde_json::{Value, json};
+use tempfile::TempDir;
+use tokio::io::BufReader;
+
+use crate::cli::{Cli, Command};
+use crate::handler::JulieServerHandler;
+use crate::lsp::LspServer;
+use crate::lsp::convert::{
+    byte_to_utf16, identifier_at, name_columns, path_to_uri, symbol_kind, uri_to_path,
+    utf16_to_byte,
+};
+use crate::lsp::protocol::{read_message, write_message};
+use crate::tools::workspace::ManageWorkspaceTool;
+use clap::Parser;
+
+const LIB_RS: &str = "/// Adds two numbers.
+pub fn add_numbers(a: i32, b: i32) -> i32 {
+    a + b
+}
+
+pub struct Calculator {
+    total: i32,
+}
+
+impl Calculator {
+    pub fn accumulate(&mut self, value: i32) {
+        self.total = add_numbers(self.total, value);
+    }
+}
+";
+
+#[tokio::test]
+async fn test_messages_round_trip_through_the_framing() -> Result<()> {
+    let mut wire = Vec::new();
+    let message = json!({"jsonrpc": "2.0", "id": 1, "method": "initialize", "params": {"é": 1}});
+    write_message(&mut wire, &message).await?;
+    write_message(&mut wire, &json!({"jsonrpc": "2.0", "method": "exit"})).await?;
+    let body_len = serde_json::to_vec(&message)?.len();
+    assert!(
+        String::from_utf8_lossy(&wire).starts_with(&format!("Content-Length: {body_len}\r\n\r\n"))
+    );
+
+    let mut reader = BufReader::new(wire.as_slice());
+    assert_eq!(read_message(&mut reader).await?, Some(message));
+    assert_eq!(read_message(&mut reader).await?.unwrap()["method"], "exit");
+    assert_eq!(read_message(&mut reader).await?, None, "end of input");
+
+    let mut reader = BufReader::new(&b"Content-Type: x\r\n\r\n{}"[..]);
+    assert!(read_message(&mut reader).await.is_err());
+    Ok(())
+}
+
+#[test]
+fn test_uris_round_trip_with_escapes_and_drive_letters() {
+    let uri = path_to_uri(Path::new("/work/my repo/src/a#b.rs"));
+    assert_eq!(uri, "file:///work/my%20repo/src/a%23b.rs");
+    assert_eq!(
+        uri_to_path(&uri),
+        Some(PathBuf::from("/work/my repo/src/a#b.rs"))
+    );
+    assert_eq!(
+        uri_to_path("file:///C:/Users/dev/a%20b.rs"),
+        Some(PathBuf::from("C:/Users/dev/a b.rs"))
+    );
+    assert_eq!(
+        path_to_uri(Path::new("C:\\Users\\dev\\a.rs")),
+        "file:///C:/Users/dev/a.rs"
+    );
+    assert_eq!(uri_to_path("untitled:Untitled-1"), None);
+}
+
+#[test]
+fn test_columns_convert_between_utf16_and_bytes() {
+    // `é` is two bytes and one UTF-16 unit; `𝄞` is four bytes and two units.
+    let line = "é𝄞 = total;";
+    assert_eq!(utf16_to_byte(line, 0), 0);
+    assert_eq!(utf16_to_byte(line, 1), 2);
+    assert_eq!(utf16_to_byte(line, 3), 6);
+    assert_eq!(utf16_to_byte(line, 99), line.len());
+    assert_eq!(byte_to_utf16(line, 6), 3);
+    assert_eq!(byte_to_utf16(line, 4), 1, "inside a character rounds down");
+}
+
+#[test]
+fn test_identifier_under_the_cursor() {
+    let text = "fn main() {\n    let total = compute_sum(a, b);\n}";
+    assert_eq!(identifier_at(text, 1, 20).as_deref(), Some("compute_sum"));
+    assert_eq!(
+        identifier_at(text, 1, 27).as_deref(),
+        Some("compute_sum"),
+        "just after the word still counts"
+    );
+    assert_eq!(identifier_at(text, 1, 15), None, "between words");
+    assert_eq!(identifier_at(text, 7, 0), None, "past the end");
+
+    let line = "fn sum(summary: u32) -> sum";
+    assert_eq!(name_columns(line, "sum", 0), Some((3, 6)));
+    assert_eq!(
+        name_columns(line, "sum", 7),
+        Some((24, 27)),
+        "whole words only"
+    );
+    assert_eq!(name_columns(line, "missing", 0), None);
+}
+
+#[test]
+fn test_symbol_kinds_map_to_lsp_kinds() {
+    assert_eq!(symbol_kind("function"), 12);
+    assert_eq!(symbol_kind("method"), 6);
+    assert_eq!(symbol_kind("struct"), 23);
+    assert_eq!(symbol_kind("trait"), 11);
+    assert_eq!(symbol_kind("enum_member"), 22);
+    assert_eq!(symbol_kind("something_new"), 13);
+}
+
+#[test]
+fn test_lsp_command_parses() {
+    let cli = Cli::try_parse_from(["julie-server", "lsp", "--workspace", "/repo"]).unwrap();
+    assert!(matches!(cli.command, Some(Command::Lsp)));
+    assert_eq!(cli.workspace, Some(PathBuf::from("/repo")));
+}
+
+#[tokio::test]
+async fn test_session_needs_initialize_and_shutdown() -> Result<()> {
+    let handler = JulieServerHandler::new_for_test().await?;
+    let temp_dir = TempDir::new()?;
+    let mut server = LspServer::new(handler, temp_dir.path().to_path_buf());
+
+    let reply = server
+        .handle(json!({"jsonrpc": "2.0", "id": 1, "method": "workspace/symbol", "params": {"query": "x"}}))
+        .await
+        .unwrap();
+    assert_eq!(reply["error"]["code"], -32002);
+
+    let mut input = Vec::new();
+    for message in [
+        json!({"jsonrpc": "2.0", "id": 2, "method": "initialize", "params": {}}),
+        json!({"jsonrpc": "2.0", "method": "initialized", "params": {}}),
+        json!({"jsonrpc": "2.0", "id": 3, "method": "textDocument/formatting", "params": {}}),
+        json!({"jsonrpc": "2.0", "id": 4, "method": "shutdown"}),
+        json!({"jsonrpc": "2.0", "id": 5, "method": "workspace/symbol", "params": {"query": "x"}}),
+        json!({"jsonrpc": "2.0", "method": "exit"}),
+    ] {
+        write_message(&mut input, &message).await?;
+    }
+    let mut output = Vec::new();
+    server
+        .run(&mut BufReader::new(input.as_slice()), &mut output)
+        .await?;
+
+    let mut reader = BufReader::new(output.as_slice());
+    let mut replies = Vec::new();
+    while let Some(reply) = read_message(&mut reader).await? {
+        replies.push(reply);
+    }
+    let ids: Vec<&Value> = replies.iter().map(|reply| &reply["id"]).collect();
+    assert_eq!(ids, [&json!(2), &json!(3), &json!(4), &json!(5)]);
+    assert_eq!(
+        replies[0]["result"]["capabilities"]["definitionProvider"],
+        true
+    );
+    assert_eq!(replies[1]["error"]["code"], -32601);
+    assert_eq!(replies[2]["result"], Value::Null);
+    assert_eq!(replies[3]["error"]["code"], -32600, "shut down");
+
+    let handler = JulieServerHandler::new_for_test().await?;
+    let mut server = LspServer::new(handler, temp_dir.path().to_path_buf());
+    let mut input = Vec::new();
+    write_message(&mut input, &json!({"jsonrpc": "2.0", "method": "exit"})).await?;
+    assert!(
+        server
+            .run(&mut BufReader::new(input.as_slice()), &mut Vec::new())
+            .await
+            .is_err(),
+        "exit without shutdown"
+    );
+    Ok(())
+}
+
+async fn indexed_server(temp_dir: &TempDir) -> Result<LspServer> {
+    fs::create_dir(temp_dir.path().join("src"))?;
+    fs::write(temp_dir.path().join("src/lib.rs"), LIB_RS)?;
+
+    let handler = JulieServerHandler::new_for_test().await?;
+    handler
+        .initialize_workspace_with_force(Some(temp_dir.path().to_string_lossy().to_string()), true)
+        .await?;
+    ManageWorkspaceTool {
+        operation: "index".to_string(),
+        path: Some(temp_dir.path().to_string_lossy().to_string()),
+        force: Some(true),
+        name: None,
+        workspace_id: None,
+        detailed: None,
+        from_generation: None,
+        to_generation: None,
+        dry_run: None,
+    }
+    .call_tool(&handler)
+    .await?;
+
+    let mut server = LspServer::new(handler, temp_dir.path().to_path_buf());
+    server
+        .handle(json!({"jsonrpc": "2.0", "id": 0, "method": "initialize", "params": {}}))
+        .await;
+    Ok(server)
+}
+
+async fn request(server: &mut LspServer, method: &str, params: Value) -> Value {
+    let reply = server
+        .handle(json!({"jsonrpc": "2.0", "id": 1, "method": method, "params": params}))
+        .await
+        .unwrap();
+    assert!(reply.get("error").is_none(), "{method} failed: {reply}");
+    reply["result"].clone()
+}
+
+/// 0-based start line of each location.
+fn lines(locations: &Value) -> Vec<u64> {
+    locations
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|location| location["range"]["start"]["line"].as_u64().unwrap())
+        .collect()
+}
+
+/// Names of `symbols` and their children, depth first.
+fn names(symbols: &Value) -> Vec<String> {
+    let mut all = Vec::new();
+    for symbol in symbols.as_array().unwrap() {
+        all.push(symbol["name"].as_str().unwrap().to_string());
+        if let Some(children) = symbol.get("children") {
+            all.extend(names(children));
+        }
+    }
+    all
+}
+
+#[tokio::test]
+async fn test_requests_answer_from_the_index() -> Result<()> {
+    let temp_dir = TempDir::new()?;
+    let mut server = indexed_server(&temp_dir).await?;
+    let uri = path_to_uri(&temp_dir.path().canonicalize()?.join("src/lib.rs"));
+    // The call `add_numbers(` on line 11.
+    let at_call = json!({
+        "textDocument": {"uri": uri},
+        "position": {"line": 11, "character": 22},
+    });
+
+    let definitions = request(&mut server, "textDocument/definition", at_call.clone()).await;
+    assert_eq!(lines(&definitions), [1]);
+    assert_eq!(definitions[0]["uri"], uri.as_str());
+    assert_eq!(definitions[0]["range"]["start"]["character"], 7);
+    assert_eq!(definitions[0]["range"]["end"]["character"], 18);
+
+    let mut params = at_call.clone();
+    params["context"] = json!({"includeDeclaration": true});
+    let references = request(&mut server, "textDocument/references", params).await;
+    assert_eq!(lines(&references), [1, 11]);
+    params = at_call.clone();
+    params["context"] = json!({"includeDeclaration": false});
+    let references = request(&mut server, "textDocument/references", params).await;
+    assert_eq!(lines(&references), [11]);
+
+    let hover = request(&mut server, "textDocument/hover", at_call).await;
+    let markdown = hover["contents"]["value"].as_str().unwrap();
+    assert!(markdown.contains("add_numbers"), "{markdown}");
+    assert!(markdown.contains("Adds two numbers"), "{markdown}");
+    assert_eq!(hover["range"]["start"]["line"], 11);
+
+    let on_space = json!({
+        "textDocument": {"uri": uri},
+        "position": {"line": 2, "character": 0},
+    });
+    assert_eq!(
+        request(&mut server, "textDocument/definition", on_space).await,
+        Value::Null
+    );
+
+    let symbols = request(
+        &mut server,
+        "textDocument/documentSymbol",
+        json!({"textDocument": {"uri": uri}}),
+    )
+    .await;
+    let all = names(&symbols);
+    for name in ["add_numbers", "Calculator", "accumulate"] {
+        assert!(all.iter().any(|n| n == name), "{name} missing from {all:?}");
+    }
+    let add = symbols
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|symbol| symbol["name"] == "add_numbers")
+        .unwrap();
+    assert_eq!(add["kind"], 12);
+    assert_eq!(add["selectionRange"]["start"]["character"], 7);
+
+    let found = request(
+        &mut server,
+        "workspace/symbol",
+        json!({"query": "Calculator"}),
+    )
+    .await;
+    let calculator = found
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|symbol| symbol["name"] == "Calculator" && symbol["kind"] == 23)
+        .expect("the Calculator struct in workspace symbols");
+    assert_eq!(calculator["location"]["uri"], uri.as_str());
+    assert_eq!(calculator["location"]["range"]["start"]["line"], 5);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_open_buffers_decide_the_word_under_the_cursor() -> Result<()> {
+    let temp_dir = TempDir::new()?;
+    let mut server = indexed_server(&temp_dir).await?;
+    let uri = path_to_uri(&temp_dir.path().canonicalize()?.join("src/main.rs"));
+
+    // An unsaved buffer calling into the indexed file.
+    server
+        .handle(json!({
+            "jsonrpc": "2.0",
+            "method": "textDocument/didOpen",
+            "params": {"textDocument": {
+                "uri": uri, "languageId": "rust", "version": 1,
+                "text": "fn main() {\n    let sum = add_numbers(1, 2);\n}\n",
+            }},
+        }))
+        .await;
+    let definitions = request(
+        &mut server,
+        "textDocument/definition",
+        json!({"textDocument": {"uri": uri}, "position": {"line": 1, "character": 16}}),
+    )
+    .await;
+    assert_eq!(lines(&definitions), [1]);
+    assert!(
+        definitions[0]["uri"]
+            .as_str()
+            .unwrap()
+            .ends_with("/src/lib.rs")
+    );
+
+    server
+        .handle(json!({
+            "jsonrpc": "2.0",
+            "method": "textDocument/didClose",
+            "params": {"textDocument": {"uri": uri}},
+        }))
+        .await;
+    assert_eq!(
+        request(
+            &mut server,
+            "textDocument/definition",
+            json!({"textDocument": {"uri": uri}, "position": {"line": 1, "character": 16}}),
+        )
+        .await,
+        Value::Null,
+        "closed and never saved"
+    );
+    Ok(())
+}
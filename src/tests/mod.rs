@@ -25,6 +25,9 @@ pub mod cli_execution_tests; // CLI execution core (daemon/standalone mode, hand
 pub mod cli_tests; // CLI argument parsing (clap) and workspace resolution tests
 pub mod cli_tools_tests; // CLI tool subcommand parsing (search, refs, symbols, etc.)
 pub mod external_extract;
+#[cfg(feature = "grpc")]
+pub mod grpc; // gRPC API auth, CLI args and reply shape
+pub mod lsp; // LSP gateway framing, conversions and requests
 
 // ============================================================================
 // CORE SYSTEM TESTS - Database, handlers, language support
@@ -78,6 +81,9 @@ pub mod tools {
     pub mod refactoring; // Refactoring tool tests (SmartRefactorTool with SOURCE/CONTROL)
 
     pub mod workspace {
+        pub mod backup; // Backup destination rules for manage_workspace backup
+        pub mod branch_switch; // Branch-switch detection and differential indexing report
+        pub mod capabilities; // Bare-clone (no-build) capabilities report tests
         pub mod deferred_open; // Deferred-session workspace open path tests
         pub mod discovery; // Vendor pattern detection and .julieignore auto-generation tests
         pub mod embedding_deferred; // Deferred-embedding non-blocking semantics (Task 2)
@@ -90,10 +96,14 @@ pub mod tools {
         pub mod mod_tests; // Workspace module functionality tests
         pub mod processor; // Indexing processor parser-failure handling tests
         pub mod refresh_routing; // Primary force-refresh should reuse full index path
+        pub mod remote; // Remote workspace specs, sync commands and origin records
+        pub mod repair; // Index drift detection for the repair operation
+        pub mod rollback; // rollback_last_operation restores and reindexes journaled edits
         // registry.rs relocated to crates/julie-runtime/src/tests/ (T2c.3 — tests julie-runtime's workspace::registry)
         pub mod resolver; // Cross-file relationship resolution tests
         // root_safety.rs relocated to crates/julie-runtime/src/tests/ (T2c.3 — tests julie-runtime's workspace::root_safety)
         pub mod utils; // Workspace utilities tests // Registry service tests
+        pub mod warm_up; // Warm-up batch selection for large index runs
     }
 
     // phase4_token_savings relocated to crates/julie-tools/src/tests/ (T2b.6)
@@ -213,6 +213,9 @@ async fn test_fast_search_reference_definitions_first_request_succeeds_without_p
         name: None,
         workspace_id: None,
         detailed: None,
+        from_generation: None,
+        to_generation: None,
+        dry_run: None,
     }
     .call_tool(&prep_handler)
     .await?;
@@ -351,6 +354,9 @@ async fn test_fast_search_startup_workspace_id_first_request_succeeds_without_pr
         name: None,
         workspace_id: None,
         detailed: None,
+        from_generation: None,
+        to_generation: None,
+        dry_run: None,
     }
     .call_tool(&prep_handler)
     .await?;
@@ -35,6 +35,9 @@ async fn setup_multi_file_workspace(
         name: None,
         force: Some(false),
         detailed: None,
+        from_generation: None,
+        to_generation: None,
+        dry_run: None,
     };
     index_tool.call_tool(&handler).await?;
 
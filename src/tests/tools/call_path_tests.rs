@@ -37,6 +37,9 @@ async fn setup_indexed_workspace_files(
         name: None,
         force: Some(false),
         detailed: None,
+        from_generation: None,
+        to_generation: None,
+        dry_run: None,
     };
     index_tool.call_tool(&handler).await?;
 
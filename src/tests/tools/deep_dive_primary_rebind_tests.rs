@@ -124,6 +124,9 @@ async fn test_deep_dive_primary_uses_rebound_current_primary_store() -> Result<(
         depth: DeepDiveDepth::Overview,
         context_file: None,
         workspace: Some("primary".to_string()),
+        project: None,
+        disambiguator: None,
+        max_tokens: None,
     }
     .call_tool(&handler)
     .await?;
@@ -147,6 +150,9 @@ async fn test_deep_dive_primary_rejects_swap_gap() -> Result<()> {
         depth: DeepDiveDepth::Overview,
         context_file: None,
         workspace: Some("primary".to_string()),
+        project: None,
+        disambiguator: None,
+        max_tokens: None,
     }
     .call_tool(&handler)
     .await
@@ -336,6 +336,9 @@ async fn test_edit_file_apply_rejects_changed_target_before_commit() -> Result<(
         name: None,
         force: Some(false),
         detailed: None,
+        from_generation: None,
+        to_generation: None,
+        dry_run: None,
     };
     index_tool.call_tool(&handler).await?;
     let tool = EditFileTool {
@@ -363,6 +366,57 @@ async fn test_edit_file_apply_rejects_changed_target_before_commit() -> Result<(
     Ok(())
 }
 
+#[tokio::test]
+async fn test_edit_file_refuses_generated_outputs() -> Result<()> {
+    let temp_dir = TempDir::new()?;
+    mark_workspace_root(temp_dir.path());
+    fs::create_dir_all(temp_dir.path().join(".julie"))?;
+    fs::write(
+        temp_dir.path().join(".julie/config.toml"),
+        "[[generated]]\nroot = \"gen\"\nsource = \"proto/{dir}/{stem}.proto\"\n",
+    )?;
+    fs::write(temp_dir.path().join(".gitignore"), "gen/\n")?;
+    fs::create_dir_all(temp_dir.path().join("gen/user"))?;
+    let generated = temp_dir.path().join("gen/user/user.pb.go");
+    let original = "package user\n\ntype User struct{}\n";
+    fs::write(&generated, original)?;
+
+    let handler = JulieServerHandler::new(temp_dir.path().to_path_buf()).await?;
+    let index_tool = ManageWorkspaceTool {
+        operation: "index".to_string(),
+        workspace_id: None,
+        path: Some(temp_dir.path().to_string_lossy().to_string()),
+        name: None,
+        force: Some(false),
+        detailed: None,
+        from_generation: None,
+        to_generation: None,
+        dry_run: None,
+    };
+    index_tool.call_tool(&handler).await?;
+    let tool = EditFileTool {
+        file_path: "gen/user/user.pb.go".to_string(),
+        old_text: "type User struct{}".to_string(),
+        new_text: "type User struct{ ID string }".to_string(),
+        workspace: Some("primary".to_string()),
+        dry_run: false,
+        occurrence: EditOccurrence::First,
+    };
+
+    let err = tool
+        .call_tool(&handler)
+        .await
+        .expect_err("generated outputs are read-only");
+    assert!(
+        err.to_string()
+            .contains("generated from 'proto/user/user.proto'"),
+        "error should point at the source, got: {err}"
+    );
+    assert_eq!(fs::read_to_string(&generated)?, original);
+
+    Ok(())
+}
+
 #[tokio::test(flavor = "multi_thread")]
 async fn test_edit_file_routes_to_target_workspace() -> Result<()> {
     let temp_dir = TempDir::new()?;
@@ -461,6 +515,9 @@ async fn test_edit_file_dry_run_truncates_large_diff_preview() -> Result<()> {
         name: None,
         force: Some(false),
         detailed: None,
+        from_generation: None,
+        to_generation: None,
+        dry_run: None,
     };
     index_tool.call_tool(&handler).await?;
 
@@ -529,6 +586,9 @@ async fn test_prepared_edit_drives_metrics_and_rejects_changed_target() -> Resul
         name: None,
         force: Some(false),
         detailed: None,
+        from_generation: None,
+        to_generation: None,
+        dry_run: None,
     }
     .call_tool(&handler)
     .await?;
@@ -552,7 +612,8 @@ async fn test_prepared_edit_drives_metrics_and_rejects_changed_target() -> Resul
 
     fs::write(&file_path, intervening)?;
     let err = tool
-        .call_prepared(prepared)
+        .call_prepared(&handler, prepared)
+        .await
         .expect_err("prepared apply must reject a file changed after preparation")
         .to_string();
 
@@ -583,6 +644,9 @@ async fn test_prepared_edit_dry_run_matches_direct_output() -> Result<()> {
         name: None,
         force: Some(false),
         detailed: None,
+        from_generation: None,
+        to_generation: None,
+        dry_run: None,
     }
     .call_tool(&handler)
     .await?;
@@ -596,7 +660,9 @@ async fn test_prepared_edit_dry_run_matches_direct_output() -> Result<()> {
         occurrence: EditOccurrence::First,
     };
 
-    let prepared_result = tool.call_prepared(tool.prepare_edit(&handler).await?)?;
+    let prepared_result = tool
+        .call_prepared(&handler, tool.prepare_edit(&handler).await?)
+        .await?;
     let direct_result = tool.call_tool(&handler).await?;
 
     assert_eq!(extract_text(&prepared_result), extract_text(&direct_result));
@@ -38,6 +38,9 @@ async fn setup_workspace(files: &[(&str, &str)]) -> Result<(TempDir, JulieServer
         name: None,
         force: Some(false),
         detailed: None,
+        from_generation: None,
+        to_generation: None,
+        dry_run: None,
     };
     index_tool.call_tool(&handler).await?;
 
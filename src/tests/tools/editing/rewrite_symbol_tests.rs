@@ -41,6 +41,9 @@ async fn setup_indexed_workspace(content: &str) -> Result<(TempDir, JulieServerH
         name: None,
         force: Some(false),
         detailed: None,
+        from_generation: None,
+        to_generation: None,
+        dry_run: None,
     };
     index_tool.call_tool(&handler).await?;
 
@@ -70,6 +73,9 @@ async fn setup_indexed_workspace_with_files(
         name: None,
         force: Some(false),
         detailed: None,
+        from_generation: None,
+        to_generation: None,
+        dry_run: None,
     };
     index_tool.call_tool(&handler).await?;
 
@@ -838,4 +844,81 @@ async fn test_rewrite_symbol_file_path_bogus_filter_returns_not_found() -> Resul
     Ok(())
 }
 
+/// A Latin-1 file name is not valid UTF-8. It is stored escaped in the index,
+/// and rewriting one of its symbols must decode that back to the real file.
+#[cfg(unix)]
+#[tokio::test(flavor = "multi_thread")]
+async fn test_rewrite_symbol_edits_a_file_with_a_non_utf8_name() -> Result<()> {
+    use std::ffi::OsStr;
+    use std::os::unix::ffi::OsStrExt;
+
+    let temp_dir = TempDir::new()?;
+    let workspace_path = temp_dir.path().to_path_buf();
+    mark_workspace_root(workspace_path.as_path());
+    let src_dir = workspace_path.join("src");
+    fs::create_dir_all(&src_dir)?;
+    let file_name = OsStr::from_bytes(b"caf\xe9.rs");
+    let file_path = src_dir.join(file_name);
+    if fs::write(&file_path, "pub fn brew() {\n    println!(\"old\");\n}\n").is_err() {
+        // Some filesystems (e.g. on macOS) only accept UTF-8 names.
+        return Ok(());
+    }
+
+    let handler = JulieServerHandler::new(workspace_path.clone()).await?;
+    ManageWorkspaceTool {
+        operation: "index".to_string(),
+        workspace_id: None,
+        path: Some(workspace_path.to_string_lossy().to_string()),
+        name: None,
+        force: Some(false),
+        detailed: None,
+        from_generation: None,
+        to_generation: None,
+        dry_run: None,
+    }
+    .call_tool(&handler)
+    .await?;
+
+    let stored = format!(
+        "src/{}",
+        crate::utils::paths::encode_path_lossless(file_name)
+    );
+    {
+        let workspace_id = handler.require_primary_workspace_identity()?;
+        let db = handler.get_database_for_workspace(&workspace_id).await?;
+        let db = db.lock().unwrap();
+        let symbols = db.get_symbols_for_file(&stored)?;
+        assert!(
+            symbols.iter().any(|symbol| symbol.name == "brew"),
+            "the file should be indexed under its escaped path {stored:?}"
+        );
+    }
+
+    let tool = crate::tools::editing::rewrite_symbol::RewriteSymbolTool {
+        symbol: "brew".to_string(),
+        operation: "replace_body".to_string(),
+        content: "{\n    println!(\"new\");\n}".to_string(),
+        file_path: None,
+        workspace: Some("primary".to_string()),
+        dry_run: false,
+    };
+    let text = extract_text(&tool.call_tool(&handler).await?);
+    assert!(
+        text.contains("Applied replace_body"),
+        "Expected replace_body success message, got: {text}"
+    );
+
+    let on_disk = fs::read_to_string(&file_path)?;
+    assert!(
+        on_disk.contains("println!(\"new\")"),
+        "the non-UTF-8 file itself should be edited, got: {on_disk}"
+    );
+    assert_eq!(
+        fs::read_dir(&src_dir)?.count(),
+        1,
+        "no lossy-named copy should be written next to it"
+    );
+    Ok(())
+}
+
 mod stateful;
@@ -289,7 +289,7 @@ async fn test_prepared_rewrite_drives_metrics_and_dry_run_output() -> Result<()>
     assert!(metadata["diff_bytes"].as_u64().unwrap() > 0);
     assert!(metadata["changed_bytes"].as_u64().unwrap() > 0);
 
-    let prepared_result = tool.call_prepared(prepared)?;
+    let prepared_result = tool.call_prepared(&handler, prepared).await?;
     let direct_result = tool.call_tool(&handler).await?;
 
     assert_eq!(extract_text(&prepared_result), extract_text(&direct_result));
@@ -316,7 +316,8 @@ async fn test_prepared_rewrite_rejects_changed_target_before_commit() -> Result<
     let prepared = tool.prepare_rewrite(&handler).await?;
     fs::write(&file_path, intervening)?;
     let err = tool
-        .call_prepared(prepared)
+        .call_prepared(&handler, prepared)
+        .await
         .expect_err("prepared apply must reject a file changed after preparation")
         .to_string();
 
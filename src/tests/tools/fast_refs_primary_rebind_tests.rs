@@ -321,6 +321,9 @@ async fn test_fast_refs_primary_uses_rebound_current_primary_store() -> Result<(
         limit: 10,
         workspace: Some("primary".to_string()),
         reference_kind: None,
+        include_textual: false,
+        project: None,
+        max_tokens: None,
     }
     .call_tool(&handler)
     .await?;
@@ -345,6 +348,9 @@ async fn test_fast_refs_primary_rejects_swap_gap() -> Result<()> {
         limit: 10,
         workspace: Some("primary".to_string()),
         reference_kind: None,
+        include_textual: false,
+        project: None,
+        max_tokens: None,
     }
     .call_tool(&handler)
     .await
@@ -369,6 +375,9 @@ async fn test_fast_refs_primary_keeps_rebound_source_name_resolution_after_rebin
         limit: 10,
         workspace: Some("primary".to_string()),
         reference_kind: Some("call".to_string()),
+        include_textual: false,
+        project: None,
+        max_tokens: None,
     }
     .call_tool(&handler)
     .await?;
@@ -524,6 +533,9 @@ async fn test_fast_refs_primary_qualified_identifier_fallback_respects_parent_fi
         limit: 10,
         workspace: Some("primary".to_string()),
         reference_kind: None,
+        include_textual: false,
+        project: None,
+        max_tokens: None,
     }
     .call_tool(&handler)
     .await?;
@@ -639,6 +651,9 @@ async fn test_fast_refs_primary_identifier_fallback_dedupes_within_batch() -> Re
         limit: 2,
         workspace: Some("primary".to_string()),
         reference_kind: None,
+        include_textual: false,
+        project: None,
+        max_tokens: None,
     }
     .call_tool(&handler)
     .await?;
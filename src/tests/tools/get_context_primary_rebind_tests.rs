@@ -82,6 +82,9 @@ async fn setup_rebound_primary_get_context_handler()
         name: None,
         workspace_id: None,
         detailed: None,
+        from_generation: None,
+        to_generation: None,
+        dry_run: None,
     }
     .call_tool(&seed_handler)
     .await?;
@@ -111,6 +114,7 @@ async fn test_get_context_primary_uses_rebound_current_primary_store() -> Result
         failing_test: None,
         max_hops: None,
         prefer_tests: None,
+        project: None,
     }
     .call_tool(&handler)
     .await?;
@@ -142,6 +146,7 @@ async fn test_get_context_primary_rejects_swap_gap() -> Result<()> {
         failing_test: None,
         max_hops: None,
         prefer_tests: None,
+        project: None,
     }
     .call_tool(&handler)
     .await
@@ -110,6 +110,9 @@ async fn test_get_context_target_workspace_uses_requested_binding_for_metrics_at
         name: None,
         workspace_id: None,
         detailed: None,
+        from_generation: None,
+        to_generation: None,
+        dry_run: None,
     }
     .call_tool(&seed_handler)
     .await?;
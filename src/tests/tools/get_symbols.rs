@@ -3,13 +3,14 @@
 //! TDD: Write failing tests first, then fix the implementation
 
 use anyhow::Result;
+use std::collections::{HashMap, HashSet};
 use std::fs;
 use tempfile::TempDir;
 
 use crate::extractors::{Symbol, SymbolKind};
 use crate::handler::JulieServerHandler;
 use crate::tests::helpers::mcp::call_tool_result_text;
-use crate::tools::symbols::formatting::format_symbol_response;
+use crate::tools::symbols::formatting::{format_budgeted_symbol_response, format_symbol_response};
 use crate::tools::{GetSymbolsTool, ManageWorkspaceTool};
 
 #[tokio::test]
@@ -61,6 +62,9 @@ pub const MAX_USERS: usize = 100;
         name: None,
         workspace_id: None,
         detailed: None,
+        from_generation: None,
+        to_generation: None,
+        dry_run: None,
     };
     let index_result = index_tool.call_tool(&handler).await?;
     println!(
@@ -98,6 +102,7 @@ pub const MAX_USERS: usize = 100;
         limit: None,
         mode: None,
         workspace: None,
+        max_tokens: None,
     };
 
     let result = tool.call_tool(&handler).await?;
@@ -158,6 +163,9 @@ pub fn process_data(input: &str) -> String {
         name: None,
         workspace_id: None,
         detailed: None,
+        from_generation: None,
+        to_generation: None,
+        dry_run: None,
     };
     index_tool.call_tool(&handler).await?;
 
@@ -172,6 +180,7 @@ pub fn process_data(input: &str) -> String {
         limit: None,
         mode: None,
         workspace: None,
+        max_tokens: None,
     };
 
     let result = tool.call_tool(&handler).await?;
@@ -215,6 +224,9 @@ async fn test_get_symbols_normalizes_various_path_formats() -> Result<()> {
         name: None,
         workspace_id: None,
         detailed: None,
+        from_generation: None,
+        to_generation: None,
+        dry_run: None,
     };
     index_tool.call_tool(&handler).await?;
 
@@ -235,6 +247,7 @@ async fn test_get_symbols_normalizes_various_path_formats() -> Result<()> {
             limit: None,
             mode: None,
             workspace: None,
+            max_tokens: None,
         };
 
         let result = tool.call_tool(&handler).await?;
@@ -287,6 +300,9 @@ async fn test_get_symbols_with_limit_parameter() -> Result<()> {
         name: None,
         workspace_id: None,
         detailed: None,
+        from_generation: None,
+        to_generation: None,
+        dry_run: None,
     };
     index_tool.call_tool(&handler).await?;
 
@@ -300,6 +316,7 @@ async fn test_get_symbols_with_limit_parameter() -> Result<()> {
         limit: None,
         mode: None, // Default → "structure" → lean overview
         workspace: None,
+        max_tokens: None,
     };
 
     let result_no_limit = tool_no_limit.call_tool(&handler).await?;
@@ -327,6 +344,7 @@ async fn test_get_symbols_with_limit_parameter() -> Result<()> {
         limit: Some(5),
         mode: None,
         workspace: None,
+        max_tokens: None,
     };
 
     let result_with_limit = tool_with_limit.call_tool(&handler).await?;
@@ -375,6 +393,9 @@ async fn test_get_symbols_file_not_found_error() -> Result<()> {
         name: None,
         workspace_id: None,
         detailed: None,
+        from_generation: None,
+        to_generation: None,
+        dry_run: None,
     };
     index_tool.call_tool(&handler).await?;
 
@@ -388,6 +409,7 @@ async fn test_get_symbols_file_not_found_error() -> Result<()> {
         limit: None,
         mode: None,
         workspace: None,
+        max_tokens: None,
     };
 
     let result_not_found = tool_not_found.call_tool(&handler).await;
@@ -419,6 +441,7 @@ async fn test_get_symbols_file_not_found_error() -> Result<()> {
         limit: None,
         mode: None,
         workspace: None,
+        max_tokens: None,
     };
 
     let result_exists = tool_exists.call_tool(&handler).await?;
@@ -452,6 +475,7 @@ async fn test_get_symbols_file_not_found_error() -> Result<()> {
         limit: None,
         mode: None,
         workspace: None,
+        max_tokens: None,
     };
 
     let result_empty = tool_empty.call_tool(&handler).await?;
@@ -516,6 +540,9 @@ pub fn get_user(id: &str) -> User {
         name: None,
         workspace_id: None,
         detailed: None,
+        from_generation: None,
+        to_generation: None,
+        dry_run: None,
     };
     index_tool.call_tool(&handler).await?;
     tokio::time::sleep(tokio::time::Duration::from_millis(500)).await;
@@ -528,6 +555,7 @@ pub fn get_user(id: &str) -> User {
         limit: None,
         mode: Some("minimal".to_string()), // minimal mode provides code bodies → code format
         workspace: None,
+        max_tokens: None,
     };
 
     let result = tool.call_tool(&handler).await?;
@@ -655,8 +683,14 @@ fn test_lean_format_skips_redundant_kind_prefix() {
         annotations: Vec::new(),
     };
 
-    let result = format_symbol_response("src/foo.rs", vec![struct_sym, fn_sym], None)
-        .expect("format_symbol_response should not fail");
+    let result = format_symbol_response(
+        "src/foo.rs",
+        vec![struct_sym, fn_sym],
+        None,
+        &HashMap::new(),
+        &HashSet::new(),
+    )
+    .expect("format_symbol_response should not fail");
 
     let text = call_tool_result_text(&result);
 
@@ -684,3 +718,207 @@ fn test_lean_format_skips_redundant_kind_prefix() {
         text
     );
 }
+
+/// Lean output badges each symbol with its usage count so the used one of
+/// several similar helpers stands out; unused symbols get no badge.
+#[test]
+fn test_lean_format_shows_usage_badges() {
+    let helper = |id: &str, name: &str, line: u32| Symbol {
+        id: id.to_string(),
+        name: name.to_string(),
+        kind: SymbolKind::Function,
+        language: "rust".to_string(),
+        file_path: "src/parse.rs".to_string(),
+        start_line: line,
+        start_column: 0,
+        end_line: line + 2,
+        end_column: 0,
+        start_byte: 0,
+        end_byte: 50,
+        signature: Some(format!("pub fn {name}()")),
+        doc_comment: None,
+        visibility: None,
+        parent_id: None,
+        metadata: None,
+        semantic_group: None,
+        confidence: None,
+        code_context: None,
+        content_type: None,
+        body_span: None,
+        body_hash: None,
+        annotations: Vec::new(),
+    };
+    let usages = HashMap::from([("used".to_string(), 12), ("once".to_string(), 1)]);
+
+    let result = format_symbol_response(
+        "src/parse.rs",
+        vec![
+            helper("used", "parse_config", 1),
+            helper("once", "parse_config_v2", 5),
+            helper("unused", "parse_config_old", 9),
+        ],
+        None,
+        &usages,
+        &HashSet::new(),
+    )
+    .expect("format_symbol_response should not fail");
+    let text = call_tool_result_text(&result);
+
+    assert!(
+        text.contains("pub fn parse_config() (1-3, 12 refs)"),
+        "{text}"
+    );
+    assert!(
+        text.contains("pub fn parse_config_v2() (5-7, 1 ref)"),
+        "{text}"
+    );
+    assert!(text.contains("pub fn parse_config_old() (9-11)"), "{text}");
+}
+
+/// A file deleted from disk but inside its soft-delete grace period still
+/// lists its symbols, flagged as deleted.
+#[test]
+fn test_symbols_of_deleted_file_are_flagged() {
+    let symbol = |id: &str, name: &str, line: u32| Symbol {
+        id: id.to_string(),
+        name: name.to_string(),
+        kind: SymbolKind::Function,
+        language: "rust".to_string(),
+        file_path: "src/legacy.rs".to_string(),
+        start_line: line,
+        start_column: 0,
+        end_line: line + 2,
+        end_column: 0,
+        start_byte: 0,
+        end_byte: 50,
+        signature: Some(format!("pub fn {name}()")),
+        doc_comment: None,
+        visibility: None,
+        parent_id: None,
+        metadata: None,
+        semantic_group: None,
+        confidence: None,
+        code_context: None,
+        content_type: None,
+        body_span: None,
+        body_hash: None,
+        annotations: Vec::new(),
+    };
+    let symbols = vec![symbol("a", "load", 1), symbol("b", "save", 5)];
+
+    let deleted = HashSet::from(["src/legacy.rs".to_string()]);
+    let result = format_symbol_response(
+        "src/legacy.rs",
+        symbols.clone(),
+        None,
+        &HashMap::new(),
+        &deleted,
+    )
+    .expect("format_symbol_response should not fail");
+    let text = call_tool_result_text(&result);
+    assert!(text.contains("pub fn save() (5-7)"), "{text}");
+    assert!(
+        text.ends_with(
+            "\n\nDeleted from disk (kept until the grace period ends):\n  src/legacy.rs  2 symbols"
+        ),
+        "{text}"
+    );
+
+    let result = format_symbol_response(
+        "src/legacy.rs",
+        symbols,
+        None,
+        &HashMap::new(),
+        &HashSet::new(),
+    )
+    .expect("format_symbol_response should not fail");
+    assert!(!call_tool_result_text(&result).contains("Deleted from disk"));
+}
+
+/// Under `max_tokens` the outline survives: code bodies and docs are shed
+/// first, and as a last resort children collapse under their top-level symbol.
+#[test]
+fn test_budgeted_symbols_shed_bodies_then_collapse_by_parent() {
+    let symbol = |id: &str, name: &str, line: u32, parent: Option<&str>| Symbol {
+        id: id.to_string(),
+        name: name.to_string(),
+        kind: if parent.is_some() {
+            SymbolKind::Method
+        } else {
+            SymbolKind::Struct
+        },
+        language: "rust".to_string(),
+        file_path: "src/foo.rs".to_string(),
+        start_line: line,
+        start_column: 0,
+        end_line: line + 4,
+        end_column: 0,
+        start_byte: 0,
+        end_byte: 200,
+        signature: Some(if parent.is_some() {
+            format!("pub fn {name}(&self)")
+        } else {
+            format!("pub struct {name}")
+        }),
+        doc_comment: Some(format!("/// Doc for {name}.")),
+        visibility: None,
+        parent_id: parent.map(str::to_string),
+        metadata: None,
+        semantic_group: None,
+        confidence: None,
+        code_context: Some(format!(
+            "pub fn {name}(&self) {{\n    let first = self.compute_first_value();\n    let second = self.compute_second_value(first);\n    self.combine_values(first, second)\n}}"
+        )),
+        content_type: None,
+        body_span: None,
+        body_hash: None,
+        annotations: Vec::new(),
+    };
+    let mut symbols = vec![symbol("foo", "Foo", 1, None)];
+    for i in 0..6 {
+        symbols.push(symbol(
+            &format!("m{i}"),
+            &format!("method_{i}"),
+            3 + i * 5,
+            Some("foo"),
+        ));
+    }
+    let render = |max_tokens| {
+        let result = format_budgeted_symbol_response(
+            "src/foo.rs",
+            symbols.clone(),
+            &HashMap::new(),
+            &HashSet::new(),
+            max_tokens,
+        )
+        .expect("format_budgeted_symbol_response should not fail");
+        call_tool_result_text(&result)
+    };
+
+    let unbounded = render(100_000);
+    assert!(
+        unbounded.starts_with("src/foo.rs — 7 symbols"),
+        "{unbounded}"
+    );
+    assert!(
+        unbounded.contains("pub fn method_3(&self) (18-22)"),
+        "{unbounded}"
+    );
+    assert!(unbounded.contains("compute_second_value"), "{unbounded}");
+    assert!(!unbounded.contains("[budget]"), "{unbounded}");
+
+    let outline = render(150);
+    assert!(
+        outline.contains("pub fn method_3(&self) (18-22)"),
+        "{outline}"
+    );
+    assert!(!outline.contains("compute_second_value"), "{outline}");
+    assert!(
+        outline.contains("[budget] Omitted to fit max_tokens=150"),
+        "{outline}"
+    );
+
+    let collapsed = render(20);
+    assert!(collapsed.contains("Foo (7 results)"), "{collapsed}");
+    assert!(!collapsed.contains("method_3"), "{collapsed}");
+}
@@ -56,6 +56,9 @@ async fn test_get_symbols_with_relative_path() -> Result<()> {
         name: None,
         workspace_id: None,
         detailed: None,
+        from_generation: None,
+        to_generation: None,
+        dry_run: None,
     };
     index_tool.call_tool(&handler).await?;
 
@@ -67,6 +70,7 @@ async fn test_get_symbols_with_relative_path() -> Result<()> {
         limit: None,
         target: None,
         workspace: None,
+        max_tokens: None,
     };
 
     let result = tool.call_tool(&handler).await?;
@@ -122,6 +126,9 @@ async fn test_get_symbols_with_absolute_path() -> Result<()> {
         name: None,
         workspace_id: None,
         detailed: None,
+        from_generation: None,
+        to_generation: None,
+        dry_run: None,
     };
     index_tool.call_tool(&handler).await?;
 
@@ -134,6 +141,7 @@ async fn test_get_symbols_with_absolute_path() -> Result<()> {
         limit: None,
         target: None,
         workspace: None,
+        max_tokens: None,
     };
 
     let result = tool.call_tool(&handler).await?;
@@ -250,6 +258,7 @@ async fn test_get_symbols_relative_path_uses_rebound_current_primary_root() -> R
         limit: None,
         target: None,
         workspace: Some(rebound_id),
+        max_tokens: None,
     };
 
     let result = tool.call_tool(&handler).await?;
@@ -364,6 +373,7 @@ async fn test_get_symbols_primary_uses_rebound_current_primary_root() -> Result<
         limit: None,
         target: None,
         workspace: Some("primary".to_string()),
+        max_tokens: None,
     };
 
     let result = tool.call_tool(&handler).await?;
@@ -537,6 +547,9 @@ async fn test_database_stores_relative_unix_paths() -> Result<()> {
         name: None,
         workspace_id: None,
         detailed: None,
+        from_generation: None,
+        to_generation: None,
+        dry_run: None,
     };
     index_tool.call_tool(&handler).await?;
 
@@ -68,6 +68,9 @@ async fn test_default_behavior_strips_context() -> Result<()> {
         name: None,
         workspace_id: None,
         detailed: None,
+        from_generation: None,
+        to_generation: None,
+        dry_run: None,
     };
     index_tool.call_tool(&handler).await?;
     tokio::time::sleep(tokio::time::Duration::from_millis(500)).await;
@@ -80,6 +83,7 @@ async fn test_default_behavior_strips_context() -> Result<()> {
         limit: None,
         mode: None, // Default = "structure" → lean overview (no code bodies)
         workspace: None,
+        max_tokens: None,
     };
 
     let result = tool.call_tool(&handler).await?;
@@ -116,6 +120,9 @@ async fn test_invalid_mode_returns_error() -> Result<()> {
         name: None,
         workspace_id: None,
         detailed: None,
+        from_generation: None,
+        to_generation: None,
+        dry_run: None,
     };
     index_tool.call_tool(&handler).await?;
     tokio::time::sleep(tokio::time::Duration::from_millis(500)).await;
@@ -127,6 +134,7 @@ async fn test_invalid_mode_returns_error() -> Result<()> {
         limit: None,
         mode: Some("minmal".to_string()),
         workspace: None,
+        max_tokens: None,
     };
 
     let error = tool
@@ -161,6 +169,9 @@ async fn test_structure_mode_strips_context() -> Result<()> {
         name: None,
         workspace_id: None,
         detailed: None,
+        from_generation: None,
+        to_generation: None,
+        dry_run: None,
     };
     index_tool.call_tool(&handler).await?;
     tokio::time::sleep(tokio::time::Duration::from_millis(500)).await;
@@ -173,6 +184,7 @@ async fn test_structure_mode_strips_context() -> Result<()> {
         limit: None,
         mode: Some("structure".to_string()),
         workspace: None,
+        max_tokens: None,
         // lean format (structure mode has no code bodies)
     };
 
@@ -208,6 +220,9 @@ async fn test_mode_structure_always_strips() -> Result<()> {
         name: None,
         workspace_id: None,
         detailed: None,
+        from_generation: None,
+        to_generation: None,
+        dry_run: None,
     };
     index_tool.call_tool(&handler).await?;
     tokio::time::sleep(tokio::time::Duration::from_millis(500)).await;
@@ -220,6 +235,7 @@ async fn test_mode_structure_always_strips() -> Result<()> {
         limit: None,
         mode: Some("structure".to_string()),
         workspace: None,
+        max_tokens: None,
         // lean format (structure mode has no code bodies)
     };
 
@@ -255,6 +271,9 @@ async fn test_mode_minimal_top_level_only() -> Result<()> {
         name: None,
         workspace_id: None,
         detailed: None,
+        from_generation: None,
+        to_generation: None,
+        dry_run: None,
     };
     index_tool.call_tool(&handler).await?;
     tokio::time::sleep(tokio::time::Duration::from_millis(500)).await;
@@ -266,6 +285,7 @@ async fn test_mode_minimal_top_level_only() -> Result<()> {
         limit: None,
         mode: Some("minimal".to_string()),
         workspace: None,
+        max_tokens: None,
         // Default → "code" format (since minimal provides code bodies)
     };
 
@@ -307,6 +327,9 @@ async fn test_mode_full_all_symbols() -> Result<()> {
         name: None,
         workspace_id: None,
         detailed: None,
+        from_generation: None,
+        to_generation: None,
+        dry_run: None,
     };
     index_tool.call_tool(&handler).await?;
     tokio::time::sleep(tokio::time::Duration::from_millis(500)).await;
@@ -318,6 +341,7 @@ async fn test_mode_full_all_symbols() -> Result<()> {
         limit: None,
         mode: Some("full".to_string()),
         workspace: None,
+        max_tokens: None,
         // Default → "code" format (since full provides code bodies)
     };
 
@@ -365,6 +389,9 @@ async fn test_target_with_minimal_mode() -> Result<()> {
         name: None,
         workspace_id: None,
         detailed: None,
+        from_generation: None,
+        to_generation: None,
+        dry_run: None,
     };
     index_tool.call_tool(&handler).await?;
     tokio::time::sleep(tokio::time::Duration::from_millis(500)).await;
@@ -376,6 +403,7 @@ async fn test_target_with_minimal_mode() -> Result<()> {
         limit: None,
         mode: Some("minimal".to_string()),
         workspace: None,
+        max_tokens: None,
         // Default → "code" format (since minimal provides code bodies)
     };
 
@@ -418,6 +446,9 @@ async fn test_file_read_error_handling() -> Result<()> {
         name: None,
         workspace_id: None,
         detailed: None,
+        from_generation: None,
+        to_generation: None,
+        dry_run: None,
     };
     index_tool.call_tool(&handler).await?;
     tokio::time::sleep(tokio::time::Duration::from_millis(500)).await;
@@ -430,6 +461,7 @@ async fn test_file_read_error_handling() -> Result<()> {
         limit: None,
         mode: Some("minimal".to_string()),
         workspace: None,
+        max_tokens: None,
     };
 
     let result = tool.call_tool(&handler).await;
@@ -471,6 +503,9 @@ async fn test_utf8_decode_error_handling() -> Result<()> {
         name: None,
         workspace_id: None,
         detailed: None,
+        from_generation: None,
+        to_generation: None,
+        dry_run: None,
     };
     index_tool.call_tool(&handler).await?;
     tokio::time::sleep(tokio::time::Duration::from_millis(500)).await;
@@ -484,6 +519,7 @@ async fn test_utf8_decode_error_handling() -> Result<()> {
         limit: None,
         mode: Some("minimal".to_string()),
         workspace: None,
+        max_tokens: None,
         // Default → "code" format (since minimal provides code bodies)
     };
 
@@ -564,6 +600,9 @@ async fn test_vue_target_minimal_extracts_code_body() -> Result<()> {
         name: None,
         workspace_id: None,
         detailed: None,
+        from_generation: None,
+        to_generation: None,
+        dry_run: None,
     };
     index_tool.call_tool(&handler).await?;
     tokio::time::sleep(tokio::time::Duration::from_millis(500)).await;
@@ -576,6 +615,7 @@ async fn test_vue_target_minimal_extracts_code_body() -> Result<()> {
         limit: None,
         mode: Some("minimal".to_string()),
         workspace: None,
+        max_tokens: None,
     };
 
     let result = tool.call_tool(&handler).await?;
@@ -601,6 +641,7 @@ async fn test_vue_target_minimal_extracts_code_body() -> Result<()> {
         limit: None,
         mode: Some("structure".to_string()),
         workspace: None,
+        max_tokens: None,
     };
 
     let structure_result = structure_tool.call_tool(&handler).await?;
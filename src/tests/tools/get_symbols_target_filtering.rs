@@ -35,6 +35,9 @@ mod tests {
             name: None,
             force: Some(true),
             detailed: None,
+            from_generation: None,
+            to_generation: None,
+            dry_run: None,
         };
         index_tool.call_tool(&handler).await?;
 
@@ -46,6 +49,7 @@ mod tests {
             limit: None,
             mode: None,
             workspace: None,
+            max_tokens: None,
         };
 
         let result = tool.call_tool(&handler).await?;
@@ -103,6 +107,9 @@ mod tests {
             name: None,
             force: Some(true),
             detailed: None,
+            from_generation: None,
+            to_generation: None,
+            dry_run: None,
         };
         index_tool.call_tool(&handler).await?;
 
@@ -114,6 +121,7 @@ mod tests {
             limit: None,
             mode: None,
             workspace: None,
+            max_tokens: None,
         };
 
         let result = tool.call_tool(&handler).await?;
@@ -163,6 +171,9 @@ mod tests {
             name: None,
             force: Some(true),
             detailed: None,
+            from_generation: None,
+            to_generation: None,
+            dry_run: None,
         };
         index_tool.call_tool(&handler).await?;
 
@@ -174,6 +185,7 @@ mod tests {
             limit: None,
             mode: None,
             workspace: None,
+            max_tokens: None,
         };
 
         let result = tool.call_tool(&handler).await?;
@@ -215,6 +227,9 @@ mod tests {
             name: None,
             force: Some(true),
             detailed: None,
+            from_generation: None,
+            to_generation: None,
+            dry_run: None,
         };
         index_tool.call_tool(&handler).await?;
 
@@ -226,6 +241,7 @@ mod tests {
             limit: None,
             mode: None,
             workspace: None,
+            max_tokens: None,
         };
 
         let result = tool.call_tool(&handler).await?;
@@ -29,6 +29,9 @@ mod tests {
             name: None,
             force: Some(true),
             detailed: None,
+            from_generation: None,
+            to_generation: None,
+            dry_run: None,
         };
         index_tool.call_tool(&handler).await?;
 
@@ -42,6 +45,7 @@ mod tests {
             limit: None,
             mode: Some("minimal".to_string()),
             workspace: None,
+            max_tokens: None,
         };
 
         let result = tool.call_tool(&handler).await?;
@@ -79,6 +79,9 @@ async fn test_get_symbols_target_workspace() -> Result<()> {
         name: None,
         workspace_id: None,
         detailed: None,
+        from_generation: None,
+        to_generation: None,
+        dry_run: None,
     };
     index_tool.call_tool(&handler).await?;
 
@@ -161,6 +164,7 @@ async fn test_get_symbols_target_workspace() -> Result<()> {
         limit: None,
         mode: None,
         workspace: Some(workspace_id.clone()),
+        max_tokens: None,
     };
 
     let result = get_symbols_tool.call_tool(&handler).await?;
@@ -240,6 +244,9 @@ pub struct Another { pub field: String }
         name: None,
         workspace_id: None,
         detailed: None,
+        from_generation: None,
+        to_generation: None,
+        dry_run: None,
     };
     index_tool.call_tool(&handler).await?;
 
@@ -398,6 +405,7 @@ pub struct Another { pub field: String }
         limit: None,
         mode: None,
         workspace: Some(workspace_id.clone()),
+        max_tokens: None,
     };
 
     let result_all = get_all.call_tool(&handler).await?;
@@ -427,6 +435,7 @@ pub struct Another { pub field: String }
         limit: None,
         mode: None,
         workspace: Some(workspace_id.clone()),
+        max_tokens: None,
     };
 
     let result_depth_0 = get_depth_0.call_tool(&handler).await?;
@@ -445,6 +454,7 @@ pub struct Another { pub field: String }
         limit: None,
         mode: None,
         workspace: Some(workspace_id.clone()),
+        max_tokens: None,
     };
 
     let result_target = get_target.call_tool(&handler).await?;
@@ -470,6 +480,7 @@ pub struct Another { pub field: String }
         limit: Some(2),
         mode: None,
         workspace: Some(workspace_id.clone()),
+        max_tokens: None,
     };
 
     let result_limit = get_limit.call_tool(&handler).await?;
@@ -604,6 +615,7 @@ async fn test_get_symbols_target_workspace_relative_paths_after_primary_rebind()
         limit: None,
         mode: Some("full".to_string()),
         workspace: Some(target_workspace_id.clone()),
+        max_tokens: None,
     };
 
     let result = get_symbols_tool.call_tool(&handler).await?;
@@ -628,6 +640,7 @@ async fn test_get_symbols_target_workspace_relative_paths_after_primary_rebind()
             limit: None,
             mode: Some("full".to_string()),
             workspace: Some(target_workspace_id.clone()),
+            max_tokens: None,
         };
 
         let result = get_symbols_tool.call_tool(&handler).await?;
@@ -138,3 +138,113 @@ async fn test_handler_has_session_metrics() {
     assert_eq!(handler.session_metrics.total_calls(), 0);
     assert!(!handler.session_metrics.session_id.is_empty());
 }
+
+fn target(symbol: Option<&str>, file: Option<&str>) -> serde_json::Value {
+    serde_json::json!({
+        "limit": 10,
+        "target": {
+            "target_symbol_name": symbol,
+            "target_file_path": file,
+            "target_line": null,
+        },
+    })
+}
+
+#[test]
+fn test_heatmap_ranks_symbols_by_calls_then_recency() {
+    let metrics = SessionMetrics::new();
+    metrics.record_target("fast_search", &target(None, None));
+    metrics.record_target("deep_dive", &target(Some("UserService"), None));
+    metrics.record_target("fast_refs", &target(Some("login"), Some("auth.rs")));
+    metrics.record_target(
+        "fast_refs",
+        &target(Some("UserService"), Some("src/user.rs")),
+    );
+    metrics.record_target("deep_dive", &target(Some("UserService"), None));
+    metrics.record_target("get_symbols", &target(None, Some("src/auth.rs")));
+    metrics.record_target("rename_symbol", &target(Some("logout"), None));
+
+    let heatmap = metrics.heatmap();
+    let hot_symbols = heatmap.hot_symbols(10);
+    let hot: Vec<(&str, u64)> = hot_symbols
+        .iter()
+        .map(|heat| (heat.target.as_str(), heat.calls))
+        .collect();
+    assert_eq!(hot, [("UserService", 3), ("logout", 1), ("login", 1)]);
+
+    let user_service = &hot_symbols[0];
+    assert_eq!(user_service.tools, ["deep_dive", "fast_refs"]);
+    assert_eq!(
+        user_service.file_path.as_deref(),
+        Some("src/user.rs"),
+        "a later call without a file keeps the hint"
+    );
+    let recent: Vec<String> = heatmap
+        .recent_symbols(2)
+        .into_iter()
+        .map(|heat| heat.target)
+        .collect();
+    assert_eq!(recent, ["logout", "UserService"]);
+
+    let files = heatmap.hot_files(10);
+    assert_eq!(files.len(), 1, "calls naming a symbol count for the symbol");
+    assert_eq!(files[0].target, "src/auth.rs");
+}
+
+#[test]
+fn test_heatmap_forgets_the_coldest_target_past_the_cap() {
+    use crate::tools::metrics::session::MAX_TRACKED_TARGETS;
+
+    let metrics = SessionMetrics::new();
+    metrics.record_target("deep_dive", &target(Some("hot"), None));
+    metrics.record_target("deep_dive", &target(Some("hot"), None));
+    for i in 0..MAX_TRACKED_TARGETS {
+        metrics.record_target("fast_refs", &target(Some(&format!("sym{i}")), None));
+    }
+
+    let heatmap = metrics.heatmap();
+    assert_eq!(heatmap.symbol_count(), MAX_TRACKED_TARGETS);
+    let all: Vec<String> = heatmap
+        .hot_symbols(MAX_TRACKED_TARGETS)
+        .into_iter()
+        .map(|heat| heat.target)
+        .collect();
+    assert_eq!(all[0], "hot");
+    assert!(
+        !all.contains(&"sym0".to_string()),
+        "the oldest single-call symbol made room"
+    );
+}
+
+#[test]
+fn test_session_context_renders_the_hot_set() {
+    use crate::tools::SessionContextTool;
+
+    let metrics = SessionMetrics::new();
+    let tool = SessionContextTool { limit: 10 };
+    assert_eq!(
+        tool.render(&metrics),
+        "Session: 0 tool calls over 0m\n\
+         No symbol or file has been looked up yet. Start with fast_search or get_context"
+    );
+
+    metrics.record_target(
+        "fast_refs",
+        &target(Some("UserService"), Some("src/user.rs")),
+    );
+    metrics.record_target("deep_dive", &target(Some("UserService"), None));
+    metrics.record_target("fast_refs", &target(Some("login"), None));
+    metrics.record_target("get_symbols", &target(None, Some("src/auth.rs")));
+
+    assert_eq!(
+        SessionContextTool { limit: 1 }.render(&metrics),
+        "Session: 0 tool calls over 0m\n\
+         \n\
+         Hot symbols (1 of 2):\n  \
+         UserService (src/user.rs) ×2 via fast_refs, deep_dive\n\
+         Last touched: login, UserService\n\
+         \n\
+         Hot files (1 of 1):\n  \
+         src/auth.rs ×1 via get_symbols"
+    );
+}
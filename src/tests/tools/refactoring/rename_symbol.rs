@@ -50,6 +50,9 @@ async fn test_rename_symbol_basic() -> Result<()> {
         name: None,
         workspace_id: None,
         detailed: None,
+        from_generation: None,
+        to_generation: None,
+        dry_run: None,
     };
     index_tool.call_tool(&handler).await?;
 
@@ -123,6 +126,9 @@ public:
         name: None,
         workspace_id: None,
         detailed: None,
+        from_generation: None,
+        to_generation: None,
+        dry_run: None,
     }
     .call_tool(&handler)
     .await?;
@@ -178,6 +184,9 @@ async fn test_rename_symbol_reports_parse_error_without_modifying_file() -> Resu
         name: None,
         workspace_id: None,
         detailed: None,
+        from_generation: None,
+        to_generation: None,
+        dry_run: None,
     };
     index_tool.call_tool(&handler).await?;
 
@@ -314,6 +323,9 @@ async fn test_rename_symbol_dry_run() -> Result<()> {
         name: None,
         workspace_id: None,
         detailed: None,
+        from_generation: None,
+        to_generation: None,
+        dry_run: None,
     };
     index_tool.call_tool(&handler).await?;
 
@@ -374,6 +386,9 @@ async fn test_rename_symbol_file_scope_accepts_absolute_path() -> Result<()> {
         name: None,
         workspace_id: None,
         detailed: None,
+        from_generation: None,
+        to_generation: None,
+        dry_run: None,
     };
     index_tool.call_tool(&handler).await?;
 
@@ -443,6 +458,9 @@ fn caller(target: Target, other: Other) {
         name: None,
         workspace_id: None,
         detailed: None,
+        from_generation: None,
+        to_generation: None,
+        dry_run: None,
     };
     index_tool.call_tool(&handler).await?;
 
@@ -505,6 +523,9 @@ async fn test_rename_symbol_multiple_files() -> Result<()> {
         name: None,
         workspace_id: None,
         detailed: None,
+        from_generation: None,
+        to_generation: None,
+        dry_run: None,
     };
     index_tool.call_tool(&handler).await?;
 
@@ -607,6 +628,9 @@ async fn test_rename_symbol_primary_resolves_rebound_current_primary_root() -> R
         name: None,
         workspace_id: None,
         detailed: None,
+        from_generation: None,
+        to_generation: None,
+        dry_run: None,
     }
     .call_tool(&seed_handler)
     .await?;
@@ -41,6 +41,9 @@ async fn index_workspace(workspace_path: &std::path::Path) -> Result<JulieServer
         name: None,
         workspace_id: None,
         detailed: None,
+        from_generation: None,
+        to_generation: None,
+        dry_run: None,
     }
     .call_tool(&handler)
     .await?;
@@ -45,6 +45,9 @@ async fn content_hits_have_nonzero_scores_sorted_by_score() {
         name: None,
         workspace_id: None,
         detailed: None,
+        from_generation: None,
+        to_generation: None,
+        dry_run: None,
     };
     index_tool
         .call_tool(&handler)
@@ -60,9 +63,18 @@ async fn content_hits_have_nonzero_scores_sorted_by_score() {
         limit: 50,
         context_lines: Some(0),
         exclude_tests: None,
+        include_generated: false,
         backend: None,
         workspace: Some("primary".to_string()),
         return_format: "full".to_string(),
+        max_tokens: None,
+        dedupe: true,
+        fuzzy: false,
+        defines: None,
+        project: None,
+        framework: None,
+        languages: None,
+        boost_languages: None,
     };
 
     let execution = tool
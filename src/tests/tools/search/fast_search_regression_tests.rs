@@ -65,6 +65,9 @@ async fn index_workspace(workspace_path: &std::path::Path) -> Result<JulieServer
         name: None,
         workspace_id: None,
         detailed: None,
+        from_generation: None,
+        to_generation: None,
+        dry_run: None,
     }
     .call_tool(&handler)
     .await?;
@@ -203,6 +206,7 @@ fn tantivy_indexed_qualified_name_found_by_partial_token() -> Result<()> {
         kind: None,
         file_pattern: None,
         exclude_tests: false,
+        include_generated: false,
     };
     let (symbols, _relaxed, total) =
         definition_search_with_index_for_test("Router", &filter, 5, &index, Some(&db))?;
@@ -472,6 +476,7 @@ fn definition_test_intent_uses_metadata_for_inline_test_helpers_before_centralit
         kind: None,
         file_pattern: None,
         exclude_tests: false,
+        include_generated: false,
     };
     let (symbols, _relaxed, _total) = definition_search_with_index_for_test(
         "test helper refresh",
@@ -46,6 +46,9 @@ async fn initialize_indexed_handler(workspace_path: &Path) -> JulieServerHandler
         name: None,
         workspace_id: None,
         detailed: None,
+        from_generation: None,
+        to_generation: None,
+        dry_run: None,
     }
     .call_tool(&handler)
     .await
@@ -153,6 +156,9 @@ async fn fast_search_unified_returns_file_hits_for_filename_query() {
         name: None,
         workspace_id: None,
         detailed: None,
+        from_generation: None,
+        to_generation: None,
+        dry_run: None,
     }
     .call_tool(&handler)
     .await
@@ -168,9 +174,18 @@ async fn fast_search_unified_returns_file_hits_for_filename_query() {
         limit: 10,
         context_lines: None,
         exclude_tests: None,
+        include_generated: false,
         backend: None,
         workspace: Some("primary".to_string()),
         return_format: "full".to_string(),
+        max_tokens: None,
+        dedupe: true,
+        fuzzy: false,
+        defines: None,
+        project: None,
+        framework: None,
+        languages: None,
+        boost_languages: None,
     }
     .execute_with_trace(&handler)
     .await
@@ -214,9 +229,18 @@ async fn fast_search_file_pattern_scopes_results() {
         limit: 5,
         context_lines: None,
         exclude_tests: None,
+        include_generated: false,
         backend: None,
         workspace: Some("primary".to_string()),
         return_format: "locations".to_string(),
+        max_tokens: None,
+        dedupe: true,
+        fuzzy: false,
+        defines: None,
+        project: None,
+        framework: None,
+        languages: None,
+        boost_languages: None,
     }
     .execute_with_trace(&handler)
     .await
@@ -265,9 +289,18 @@ async fn filename_locations_preserve_exact_file_rank_instead_of_line_mode_mentio
         limit: 5,
         context_lines: None,
         exclude_tests: None,
+        include_generated: false,
         backend: None,
         workspace: Some("primary".to_string()),
         return_format: "locations".to_string(),
+        max_tokens: None,
+        dedupe: true,
+        fuzzy: false,
+        defines: None,
+        project: None,
+        framework: None,
+        languages: None,
+        boost_languages: None,
     }
     .execute_with_trace(&handler)
     .await
@@ -309,9 +342,18 @@ async fn locations_scope_rescue_labels_out_of_scope_results() {
         limit: 5,
         context_lines: None,
         exclude_tests: None,
+        include_generated: false,
         backend: None,
         workspace: Some("primary".to_string()),
         return_format: "locations".to_string(),
+        max_tokens: None,
+        dedupe: true,
+        fuzzy: false,
+        defines: None,
+        project: None,
+        framework: None,
+        languages: None,
+        boost_languages: None,
     }
     .execute_with_trace(&handler)
     .await
@@ -353,9 +395,18 @@ async fn request_level_whitespace_separated_globs_return_syntax_hint() {
         limit: 10,
         context_lines: None,
         exclude_tests: None,
+        include_generated: false,
         backend: None,
         workspace: Some("primary".to_string()),
         return_format: "full".to_string(),
+        max_tokens: None,
+        dedupe: true,
+        fuzzy: false,
+        defines: None,
+        project: None,
+        framework: None,
+        languages: None,
+        boost_languages: None,
     }
     .execute_with_trace(&handler)
     .await
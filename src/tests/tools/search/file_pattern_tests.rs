@@ -238,9 +238,18 @@ mod boundary_normalization {
             limit: 20,
             context_lines: Some(0),
             exclude_tests: None,
+            include_generated: false,
             backend: None,
             workspace: Some("primary".to_string()),
             return_format: "full".to_string(),
+            max_tokens: None,
+            dedupe: true,
+            fuzzy: false,
+            defines: None,
+            project: None,
+            framework: None,
+            languages: None,
+            boost_languages: None,
         };
 
         let execution = tool
@@ -314,6 +323,9 @@ mod boundary_normalization {
             name: None,
             workspace_id: None,
             detailed: None,
+            from_generation: None,
+            to_generation: None,
+            dry_run: None,
         };
         index_tool
             .call_tool(&handler)
@@ -361,9 +373,18 @@ mod boundary_normalization {
             limit: 20,
             context_lines: Some(0),
             exclude_tests: None,
+            include_generated: false,
             backend: None,
             workspace: Some("primary".to_string()),
             return_format: "full".to_string(),
+            max_tokens: None,
+            dedupe: true,
+            fuzzy: false,
+            defines: None,
+            project: None,
+            framework: None,
+            languages: None,
+            boost_languages: None,
         };
 
         let run = tool
@@ -421,9 +442,18 @@ mod boundary_normalization {
             limit: 20,
             context_lines: None,
             exclude_tests: None,
+            include_generated: false,
             backend: None,
             workspace: Some("primary".to_string()),
             return_format: "full".to_string(),
+            max_tokens: None,
+            dedupe: true,
+            fuzzy: false,
+            defines: None,
+            project: None,
+            framework: None,
+            languages: None,
+            boost_languages: None,
         };
 
         let run = tool
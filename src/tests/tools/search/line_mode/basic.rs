@@ -50,6 +50,9 @@ println!("Processing payment");
         name: None,
         workspace_id: None,
         detailed: None,
+        from_generation: None,
+        to_generation: None,
+        dry_run: None,
     };
     index_tool.call_tool(&handler).await?;
 
@@ -134,6 +137,9 @@ async fn test_fast_search_line_mode_respects_workspace_filter() -> Result<()> {
         name: None,
         workspace_id: None,
         detailed: None,
+        from_generation: None,
+        to_generation: None,
+        dry_run: None,
     };
     index_tool.call_tool(&handler).await?;
     sleep(Duration::from_millis(500)).await;
@@ -240,6 +246,9 @@ User { name: "test" }
         name: None,
         workspace_id: None,
         detailed: None,
+        from_generation: None,
+        to_generation: None,
+        dry_run: None,
     };
     index_tool.call_tool(&handler).await?;
 
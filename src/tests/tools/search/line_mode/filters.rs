@@ -44,6 +44,9 @@ fn user_preferences_dashboard() {}
         name: None,
         workspace_id: None,
         detailed: None,
+        from_generation: None,
+        to_generation: None,
+        dry_run: None,
     };
     index_tool.call_tool(&handler).await?;
     sleep(Duration::from_millis(500)).await;
@@ -136,6 +139,9 @@ async fn test_fast_search_line_mode_language_filter() -> Result<()> {
         name: None,
         workspace_id: None,
         detailed: None,
+        from_generation: None,
+        to_generation: None,
+        dry_run: None,
     };
     index_tool.call_tool(&handler).await?;
     sleep(Duration::from_millis(500)).await;
@@ -235,6 +241,9 @@ async fn test_fast_search_line_mode_file_pattern_filter() -> Result<()> {
         name: None,
         workspace_id: None,
         detailed: None,
+        from_generation: None,
+        to_generation: None,
+        dry_run: None,
     };
     index_tool.call_tool(&handler).await?;
     sleep(Duration::from_secs(2)).await; // Increased wait for FTS content indexing
@@ -351,6 +360,9 @@ assert!(authenticate_user("admin", "secret"));
         name: None,
         workspace_id: None,
         detailed: None,
+        from_generation: None,
+        to_generation: None,
+        dry_run: None,
     };
     index_tool.call_tool(&handler).await?;
     sleep(Duration::from_secs(2)).await;
@@ -450,6 +462,9 @@ async fn test_fast_search_line_mode_combined_filters() -> Result<()> {
         name: None,
         workspace_id: None,
         detailed: None,
+        from_generation: None,
+        to_generation: None,
+        dry_run: None,
     };
     index_tool.call_tool(&handler).await?;
     sleep(Duration::from_secs(2)).await; // Increased wait for FTS content indexing
@@ -63,6 +63,9 @@ async fn setup_loaded_primary_without_tantivy() -> Result<(TempDir, JulieServerH
         name: None,
         workspace_id: None,
         detailed: None,
+        from_generation: None,
+        to_generation: None,
+        dry_run: None,
     };
     index_tool.call_tool(&handler).await?;
 
@@ -88,6 +88,9 @@ async fn test_fast_search_line_mode_primary_uses_rebound_session_primary() -> Re
         name: None,
         workspace_id: None,
         detailed: None,
+        from_generation: None,
+        to_generation: None,
+        dry_run: None,
     }
     .call_tool(&seed_handler)
     .await?;
@@ -316,6 +319,9 @@ async fn test_fast_search_reference_indexing_uses_rebound_primary_storage_root()
         name: None,
         workspace_id: None,
         detailed: None,
+        from_generation: None,
+        to_generation: None,
+        dry_run: None,
     };
     index_tool.call_tool(&handler).await?;
 
@@ -371,6 +377,9 @@ async fn test_fast_search_primary_rejects_neutral_gap_without_primary_identity()
         name: None,
         workspace_id: None,
         detailed: None,
+        from_generation: None,
+        to_generation: None,
+        dry_run: None,
     };
     index_tool.call_tool(&handler).await?;
 
@@ -463,6 +472,9 @@ async fn test_fast_search_primary_rejects_swap_in_progress_after_partial_publish
         name: None,
         workspace_id: None,
         detailed: None,
+        from_generation: None,
+        to_generation: None,
+        dry_run: None,
     };
     index_tool.call_tool(&handler).await?;
 
@@ -186,6 +186,9 @@ mod line_mode_stage_counts {
             name: None,
             workspace_id: None,
             detailed: None,
+            from_generation: None,
+            to_generation: None,
+            dry_run: None,
         };
         index_tool.call_tool(&handler).await.expect("index");
         mark_index_ready(&handler).await;
@@ -91,6 +91,9 @@ mod tests {
             name: None,
             workspace_id: None,
             detailed: None,
+            from_generation: None,
+            to_generation: None,
+            dry_run: None,
         };
         index_tool.call_tool(&handler).await.expect("index");
         sleep(Duration::from_millis(500)).await;
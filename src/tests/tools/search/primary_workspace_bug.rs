@@ -50,6 +50,9 @@ async fn test_fast_search_recognizes_indexed_primary_workspace() -> Result<()> {
         name: None,
         force: Some(false),
         detailed: None,
+        from_generation: None,
+        to_generation: None,
+        dry_run: None,
     };
 
     index_tool.call_tool(&handler).await?;
@@ -137,6 +140,9 @@ async fn test_fast_search_with_explicit_workspace_id() -> Result<()> {
         name: None,
         force: Some(false),
         detailed: None,
+        from_generation: None,
+        to_generation: None,
+        dry_run: None,
     };
 
     index_tool.call_tool(&handler).await?;
@@ -243,6 +249,9 @@ async fn test_text_search_definitions_explicit_rebound_workspace_uses_current_pr
         name: None,
         force: Some(true),
         detailed: None,
+        from_generation: None,
+        to_generation: None,
+        dry_run: None,
     };
     index_tool.call_tool(&seed_handler).await?;
 
@@ -531,16 +531,18 @@ mod search_integration_tests {
 
     #[test]
     fn test_zero_results_fallback_suggestions() {
-        // TDD RED: When search returns zero results, should suggest alternatives
+        // When search returns zero results, suggest alternatives.
         // This prevents the "agent searches 5 times and gets zero results" problem
+        use crate::tools::search::spelling::{format_suggestions, suggest_corrections};
 
         let query = "usr servic"; // Typo - likely should be "user service"
+        let dictionary = vec!["UserService".to_string(), "user_service".to_string()];
 
-        // After implementation, should suggest:
-        // "Did you mean: user service, UserService, user_service?"
-        // Currently will return zero results with no suggestions
-
-        println!("Query '{}' should suggest corrections", query);
+        let suggestions = suggest_corrections(query, &dictionary);
+        assert_eq!(
+            format_suggestions(&suggestions),
+            "\nDid you mean: user service, UserService, user_service?"
+        );
     }
 }
 
@@ -248,6 +248,9 @@ pub fn helper_function() {}
             name: None,
             workspace_id: None,
             detailed: None,
+            from_generation: None,
+            to_generation: None,
+            dry_run: None,
         };
         index_tool.call_tool(&handler).await?;
 
@@ -284,6 +287,7 @@ pub fn helper_function() {}
                 limit: None,
                 mode: None,
                 workspace: None,
+                max_tokens: None,
             };
 
             let get_symbols_extra = GetSymbolsTool {
@@ -293,6 +297,7 @@ pub fn helper_function() {}
                 limit: None,
                 mode: None,
                 workspace: None,
+                max_tokens: None,
             };
 
             let handler_a = handler.clone();
@@ -364,6 +369,9 @@ pub fn embedding_vector_semantic() {}
             name: None,
             workspace_id: None,
             detailed: None,
+            from_generation: None,
+            to_generation: None,
+            dry_run: None,
         };
         index_tool.call_tool(&handler).await?;
         tokio::time::sleep(Duration::from_millis(500)).await;
@@ -461,6 +469,9 @@ pub fn embedding_vector_semantic() {}
             workspace_id: None,
             force: Some(false),
             detailed: None,
+            from_generation: None,
+            to_generation: None,
+            dry_run: None,
         };
         println!("🐛 TEST TRACE 5: Calling index_tool.call_tool");
         let index_result = timeout(Duration::from_secs(90), index_tool.call_tool(&handler)).await;
@@ -483,6 +494,9 @@ pub fn embedding_vector_semantic() {}
                 workspace_id: None,
                 force: None,
                 detailed: None,
+                from_generation: None,
+                to_generation: None,
+                dry_run: None,
             };
             index_ref.call_tool(&handler).await?;
         }
@@ -569,6 +583,9 @@ pub fn embedding_vector_semantic() {}
             workspace_id: None,
             force: Some(false),
             detailed: None,
+            from_generation: None,
+            to_generation: None,
+            dry_run: None,
         };
         index_primary.call_tool(&handler).await?;
 
@@ -581,6 +598,9 @@ pub fn embedding_vector_semantic() {}
                 workspace_id: None,
                 force: None,
                 detailed: None,
+                from_generation: None,
+                to_generation: None,
+                dry_run: None,
             };
             index_ref.call_tool(&handler).await?;
         }
@@ -596,6 +616,9 @@ pub fn embedding_vector_semantic() {}
             workspace_id: Some(workspace_id.clone()),
             force: Some(false),
             detailed: None,
+            from_generation: None,
+            to_generation: None,
+            dry_run: None,
         };
 
         // Use timeout to surface hangs caused by LockBusy deadlocks
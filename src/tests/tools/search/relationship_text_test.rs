@@ -474,6 +474,9 @@ mod relationship_text_test {
             name: None,
             workspace_id: None,
             detailed: None,
+            from_generation: None,
+            to_generation: None,
+            dry_run: None,
         };
 
         run_indexing_pipeline(
@@ -501,7 +504,6 @@ mod relationship_text_test {
     async fn watcher_reprojects_partners_created_by_pending_resolution() {
         use std::sync::{Arc, Mutex};
 
-        use crate::extractors::ExtractorManager;
         use crate::watcher::handlers::handle_file_created_or_modified_static;
         use crate::workspace::mutation_gate::acquire_gate;
 
@@ -520,14 +522,12 @@ mod relationship_text_test {
             SymbolDatabase::new(&workspace_root.join("watcher.db")).unwrap(),
         ));
         let search_index = Arc::new(make_index(&dir));
-        let extractor_manager = Arc::new(ExtractorManager::new());
 
         {
             let guard = acquire_gate("relationship_text_watcher_callee").await;
             handle_file_created_or_modified_static(
                 callee.canonicalize().unwrap(),
                 &db,
-                &extractor_manager,
                 &workspace_root,
                 Some(&search_index),
                 &guard,
@@ -542,7 +542,6 @@ mod relationship_text_test {
             handle_file_created_or_modified_static(
                 caller.canonicalize().unwrap(),
                 &db,
-                &extractor_manager,
                 &workspace_root,
                 Some(&search_index),
                 &guard,
@@ -566,7 +565,6 @@ mod relationship_text_test {
     async fn dirty_tantivy_retry_reprojects_relationship_partners() {
         use std::sync::{Arc, Mutex, RwLock};
 
-        use crate::extractors::ExtractorManager;
         use crate::tools::workspace::indexing::state::IndexingRuntimeState;
         use crate::watcher::IncrementalIndexer;
 
@@ -613,7 +611,6 @@ mod relationship_text_test {
         let indexer = IncrementalIndexer::new(
             workspace_root,
             Arc::clone(&db),
-            Arc::new(ExtractorManager::new()),
             Some(Arc::clone(&search_index)),
             Arc::new(RwLock::new(None)),
             IndexingRuntimeState::shared(),
@@ -144,6 +144,7 @@ async fn fast_search_regions_returns_only_matching_source_region_lines() -> Resu
             ..Default::default()
         },
         regions: Some("comment".into()),
+        mode: None,
     }
     .call_tool(&fixture.context)
     .await?;
@@ -175,6 +176,7 @@ async fn fast_search_regions_rejects_unknown_region_and_symbol_backends() -> Res
             ..Default::default()
         },
         regions: Some("unknown".into()),
+        mode: None,
     }
     .call_tool(&fixture.context)
     .await
@@ -193,6 +195,7 @@ async fn fast_search_regions_rejects_unknown_region_and_symbol_backends() -> Res
                 ..Default::default()
             },
             regions: Some("comment,doc_comment,docstring,string_literal,embedded".into()),
+            mode: None,
         }
         .call_tool(&fixture.context)
         .await
@@ -221,6 +224,7 @@ async fn fast_search_regions_respects_target_workspace() -> Result<()> {
             ..Default::default()
         },
         regions: Some("comment".into()),
+        mode: None,
     }
     .call_tool(&context)
     .await?;
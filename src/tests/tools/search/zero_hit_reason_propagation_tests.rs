@@ -80,6 +80,9 @@ async fn seed_workspace(files: &[(&str, &str)]) -> (TempDir, JulieServerHandler)
         name: None,
         workspace_id: None,
         detailed: None,
+        from_generation: None,
+        to_generation: None,
+        dry_run: None,
     };
     index_tool.call_tool(&handler).await.expect("index");
     ensure_primary_projection_current(&handler).await;
@@ -95,9 +98,18 @@ fn content_search(query: &str, file_pattern: Option<&str>) -> FastSearchTool {
         limit: 10,
         context_lines: Some(0),
         exclude_tests: None,
+        include_generated: false,
         backend: None,
         workspace: Some("primary".to_string()),
         return_format: "full".to_string(),
+        max_tokens: None,
+        dedupe: true,
+        fuzzy: false,
+        defines: None,
+        project: None,
+        framework: None,
+        languages: None,
+        boost_languages: None,
     }
 }
 
@@ -429,3 +441,79 @@ async fn trace_or_disjunction_detected_flows_from_execute_content_search() {
         "execute_content_search should stamp clean OR disjunction detection on the trace",
     );
 }
+
+/// A misspelled identifier with no index candidates carries "did you mean"
+/// suggestions from the symbol-name dictionary on both the trace and the
+/// response text.
+#[tokio::test(flavor = "multi_thread")]
+async fn trace_suggestions_offer_close_symbol_names_on_zero_hits() {
+    let (_dir, handler) = seed_workspace(&[(
+        "src/accounts.rs",
+        "pub struct AccountManager;\nimpl AccountManager { pub fn open() {} }\n",
+    )])
+    .await;
+
+    let run = content_search("acountmanagr", None)
+        .execute_with_trace(&handler)
+        .await
+        .expect("search should not error");
+    let execution = run
+        .execution
+        .expect("execute_with_trace populates execution for zero-hit search");
+    let text = extract_text_from_result(&run.result);
+
+    assert!(execution.hits.is_empty(), "misspelling should not match");
+    assert_eq!(
+        execution.trace.suggestions.first().map(String::as_str),
+        Some("AccountManager"),
+        "closest symbol name should lead the suggestions; got {:?}",
+        execution.trace.suggestions,
+    );
+    assert!(
+        text.contains("Did you mean: AccountManager"),
+        "zero-hit text should carry the suggestions, got: {}",
+        text,
+    );
+}
+
+/// With `fuzzy`, the same misspelling returns the closest symbols as hits
+/// instead of an empty page, and still carries the suggestions.
+#[tokio::test(flavor = "multi_thread")]
+async fn fuzzy_search_returns_near_miss_symbols_on_zero_hits() {
+    let (_dir, handler) = seed_workspace(&[(
+        "src/accounts.rs",
+        "pub struct AccountManager;\nimpl AccountManager { pub fn open() {} }\n",
+    )])
+    .await;
+
+    let mut tool = content_search("acountmanagr", None);
+    tool.fuzzy = true;
+    let run = tool
+        .execute_with_trace(&handler)
+        .await
+        .expect("search should not error");
+    let execution = run
+        .execution
+        .expect("execute_with_trace populates execution");
+    let text = extract_text_from_result(&run.result);
+
+    assert_eq!(execution.trace.strategy_id, "fast_search_fuzzy_fallback");
+    assert_eq!(execution.trace.zero_hit_reason, None);
+    assert!(
+        execution
+            .hits
+            .iter()
+            .any(|hit| hit.name == "AccountManager"),
+        "near-miss symbol should be returned; got {:?}",
+        execution
+            .hits
+            .iter()
+            .map(|hit| &hit.name)
+            .collect::<Vec<_>>(),
+    );
+    assert!(
+        text.contains("No exact matches") && text.contains("Did you mean: AccountManager"),
+        "fuzzy text should explain the fallback, got: {}",
+        text,
+    );
+}
@@ -52,6 +52,9 @@ async fn index_workspace_for_search(
         name: None,
         workspace_id: None,
         detailed: None,
+        from_generation: None,
+        to_generation: None,
+        dry_run: None,
     }
     .call_tool(handler)
     .await?;
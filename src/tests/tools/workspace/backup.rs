@@ -0,0 +1,66 @@
+use crate::tools::workspace::commands::backup::backup_target_error;
+use std::path::Path;
+use tempfile::TempDir;
+
+#[test]
+fn backup_paths_must_be_absolute_and_outside_the_workspace() {
+    let dir = TempDir::new().unwrap();
+    let workspace = dir.path().join("repo");
+    std::fs::create_dir_all(workspace.join("src")).unwrap();
+
+    let relative = backup_target_error(Path::new("backups/repo"), &workspace);
+    assert!(
+        relative
+            .as_deref()
+            .unwrap()
+            .starts_with("Backup path must be absolute"),
+        "{relative:?}"
+    );
+
+    let escaping = backup_target_error(&dir.path().join("elsewhere/../repo/bak"), &workspace);
+    assert!(
+        escaping
+            .as_deref()
+            .unwrap()
+            .contains("must not contain '..'"),
+        "{escaping:?}"
+    );
+
+    for inside in [
+        workspace.clone(),
+        workspace.join("src/backup"),
+        workspace.join("a/b"),
+    ] {
+        let error = backup_target_error(&inside, &workspace);
+        assert!(
+            error
+                .as_deref()
+                .unwrap()
+                .starts_with("Backup path must be outside the workspace"),
+            "{} was accepted",
+            inside.display()
+        );
+    }
+
+    assert_eq!(
+        backup_target_error(&dir.path().join("backups/repo"), &workspace),
+        None
+    );
+    assert_eq!(
+        backup_target_error(&dir.path().join("repo-backup"), &workspace),
+        None,
+        "a sibling sharing the name prefix is outside the workspace"
+    );
+}
+
+#[cfg(unix)]
+#[test]
+fn backup_paths_through_a_symlink_into_the_workspace_are_rejected() {
+    let dir = TempDir::new().unwrap();
+    let workspace = dir.path().join("repo");
+    std::fs::create_dir_all(&workspace).unwrap();
+    let link = dir.path().join("link");
+    std::os::unix::fs::symlink(&workspace, &link).unwrap();
+
+    assert!(backup_target_error(&link.join("bak/today"), &workspace).is_some());
+}
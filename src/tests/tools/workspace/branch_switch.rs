@@ -0,0 +1,126 @@
+//! Branch-switch detection and the differential indexing report.
+
+use crate::tools::workspace::indexing::branch_switch::{
+    BranchSwitchReport, SymbolDiff, SymbolSnapshot, detect_branch_switch, diff_symbols,
+};
+use std::path::Path;
+use std::process::Command;
+use tempfile::TempDir;
+
+fn git(root: &Path, args: &[&str]) -> String {
+    let output = Command::new("git")
+        .arg("-C")
+        .arg(root)
+        .args(["-c", "user.name=Test", "-c", "user.email=test@example.com"])
+        .args(args)
+        .output()
+        .expect("git must be installed to run branch switch tests");
+    assert!(
+        output.status.success(),
+        "git {:?} failed: {}",
+        args,
+        String::from_utf8_lossy(&output.stderr)
+    );
+    String::from_utf8_lossy(&output.stdout).trim().to_string()
+}
+
+fn snapshot(entries: &[(&str, &str, &str, usize)]) -> SymbolSnapshot {
+    entries
+        .iter()
+        .map(|(file, name, signature, count)| {
+            (
+                (
+                    file.to_string(),
+                    "function".to_string(),
+                    name.to_string(),
+                    signature.to_string(),
+                ),
+                *count,
+            )
+        })
+        .collect()
+}
+
+#[test]
+fn test_symbol_diff_counts_signature_changes_as_replacements() {
+    let before = snapshot(&[
+        ("src/auth.rs", "login", "fn login()", 1),
+        ("src/auth.rs", "logout", "fn logout()", 1),
+        ("src/auth.rs", "helper", "fn helper()", 2),
+        ("src/legacy.rs", "old", "fn old()", 1),
+    ]);
+    let after = snapshot(&[
+        ("src/auth.rs", "login", "fn login(user: &User)", 1),
+        ("src/auth.rs", "logout", "fn logout()", 1),
+        ("src/auth.rs", "helper", "fn helper()", 1),
+        ("src/auth.rs", "refresh", "fn refresh()", 1),
+    ]);
+
+    assert_eq!(
+        diff_symbols(&before, &after),
+        SymbolDiff {
+            added: 2,
+            removed: 4,
+            unchanged: 2,
+        }
+    );
+    assert_eq!(diff_symbols(&after, &after).unchanged, 4);
+}
+
+#[test]
+fn test_branch_switch_report_summary() {
+    let report = BranchSwitchReport {
+        previous_head: "a1b2c3d4e5f6".to_string(),
+        head: "0123456789ab".to_string(),
+        git_changed_files: 12,
+        files_reindexed: 9,
+        files_removed: 2,
+        symbols: SymbolDiff {
+            added: 30,
+            removed: 18,
+            unchanged: 140,
+        },
+    };
+    assert_eq!(
+        report.summary(),
+        "Branch switch a1b2c3d -> 0123456: 12 files changed in git, 9 reindexed, 2 removed; \
+         symbols: 30 added, 18 removed, 140 unchanged"
+    );
+}
+
+#[test]
+fn test_branch_switch_lists_files_changed_since_indexed_commit() {
+    let temp_dir = TempDir::new().unwrap();
+    let root = temp_dir.path();
+    git(root, &["init", "-q", "-b", "main"]);
+    std::fs::write(root.join("stable.rs"), "fn stable() {}\n").unwrap();
+    std::fs::write(root.join("feature.rs"), "fn feature() {}\n").unwrap();
+    git(root, &["add", "."]);
+    git(root, &["commit", "-q", "-m", "initial"]);
+    let indexed = git(root, &["rev-parse", "HEAD"]);
+
+    // Nothing recorded, or HEAD unchanged: no switch.
+    assert!(detect_branch_switch(None, root).is_none());
+    assert!(detect_branch_switch(Some((indexed.clone(), 0)), root).is_none());
+
+    git(root, &["checkout", "-q", "-b", "topic"]);
+    std::fs::write(root.join("feature.rs"), "fn feature(flag: bool) {}\n").unwrap();
+    std::fs::write(root.join("added.rs"), "fn added() {}\n").unwrap();
+    git(root, &["add", "."]);
+    git(root, &["commit", "-q", "-m", "topic"]);
+    let head = git(root, &["rev-parse", "HEAD"]);
+
+    // Recorded in the future, so only the git diff decides.
+    let switch = detect_branch_switch(Some((indexed.clone(), i64::MAX)), root).unwrap();
+    assert_eq!(switch.previous_head, indexed);
+    assert_eq!(switch.head, head);
+    let mut changed: Vec<_> = switch.changed_paths.iter().cloned().collect();
+    changed.sort();
+    assert_eq!(changed, ["added.rs", "feature.rs"]);
+    assert!(switch.needs_check("feature.rs", &root.join("feature.rs")));
+    assert!(!switch.needs_check("stable.rs", &root.join("stable.rs")));
+
+    // Files modified after the recording are rechecked even if git is clean.
+    let switch = detect_branch_switch(Some((indexed, 0)), root).unwrap();
+    assert!(switch.needs_check("stable.rs", &root.join("stable.rs")));
+}
@@ -0,0 +1,95 @@
+use crate::analysis::grpc_links::GrpcStubCoverage;
+use crate::tools::workspace::commands::capabilities::WorkspaceCapabilities;
+use julie_core::workspace::build_state::{ArtifactState, EcosystemBuildState};
+
+fn ecosystem(
+    ecosystem: &'static str,
+    manifest: &str,
+    dependencies: ArtifactState,
+    build_outputs: ArtifactState,
+) -> EcosystemBuildState {
+    EcosystemBuildState {
+        ecosystem,
+        manifest: manifest.to_string(),
+        dependencies,
+        build_outputs,
+        setup_hint: "install and build",
+    }
+}
+
+#[test]
+fn bare_clone_report_lists_source_features_and_build_gated_gaps() {
+    let capabilities = WorkspaceCapabilities {
+        workspace_id: "repo_1234".to_string(),
+        build_state: vec![ecosystem(
+            "node",
+            "package.json",
+            ArtifactState::Missing,
+            ArtifactState::Missing,
+        )],
+        files: 12,
+        symbols: 340,
+        relationships_by_kind: vec![("calls".to_string(), 90), ("imports".to_string(), 12)],
+        dependency_declarations: 7,
+        external_library_calls: 25,
+        cross_language_links: 0,
+        grpc: GrpcStubCoverage {
+            proto_services: 2,
+            generated_stub_files: 0,
+        },
+        semantic_disabled: true,
+    };
+
+    let report = capabilities.render();
+    assert!(report.contains("Mode: no-build"), "{report}");
+    assert!(
+        report.contains("  node (package.json): dependencies missing, build outputs missing"),
+        "{report}"
+    );
+    assert!(
+        report.contains("  Relationships: calls 90, imports 12"),
+        "{report}"
+    );
+    assert!(
+        report.contains("  gRPC stub links: 2 proto service(s), no generated stubs"),
+        "{report}"
+    );
+    assert!(report.contains("  node: install and build"), "{report}");
+    assert!(
+        report.contains("Disabled by workspace settings:\n  Semantic and hybrid search"),
+        "{report}"
+    );
+}
+
+#[test]
+fn built_workspace_report_has_no_build_gated_gaps() {
+    let capabilities = WorkspaceCapabilities {
+        workspace_id: "repo_1234".to_string(),
+        build_state: vec![ecosystem(
+            "rust",
+            "Cargo.toml",
+            ArtifactState::NotLocal,
+            ArtifactState::Present,
+        )],
+        grpc: GrpcStubCoverage {
+            proto_services: 1,
+            generated_stub_files: 3,
+        },
+        ..Default::default()
+    };
+
+    let report = capabilities.render();
+    assert!(report.contains("Mode: built"), "{report}");
+    assert!(
+        report.contains("Unavailable until build or dependency install:\n  none"),
+        "{report}"
+    );
+    assert!(
+        !report.contains("To enable build-dependent features"),
+        "{report}"
+    );
+    assert!(
+        !report.contains("Disabled by workspace settings"),
+        "{report}"
+    );
+}
@@ -74,6 +74,9 @@ async fn test_manage_workspace_open_path_succeeds_without_bound_primary_in_defer
         name: None,
         workspace_id: None,
         detailed: None,
+        from_generation: None,
+        to_generation: None,
+        dry_run: None,
     }
     .call_tool(&handler)
     .await
@@ -26,6 +26,9 @@ fn create_tool() -> ManageWorkspaceTool {
         name: None,
         workspace_id: None,
         detailed: None,
+        from_generation: None,
+        to_generation: None,
+        dry_run: None,
     }
 }
 
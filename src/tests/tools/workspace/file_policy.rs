@@ -1,7 +1,7 @@
 use crate::watcher::filtering::build_supported_extensions;
 use julie_core::file_policy::{
-    ExtractionMode, detect_language_for_indexing_with_content, determine_extraction_mode,
-    should_watch_path,
+    ExtractionMode, SqlDialect, detect_language_for_indexing_with_content,
+    determine_extraction_mode, parser_skip_reason, should_watch_path,
 };
 use std::fs;
 
@@ -47,6 +47,210 @@ fn test_determine_extraction_mode_text_language_is_text_only() {
     assert_eq!(mode, ExtractionMode::TextOnly);
 }
 
+#[test]
+fn test_notebooks_are_parser_backed_without_minified_check() {
+    let notebook = std::path::Path::new("notebooks/Analysis.ipynb");
+    assert_eq!(
+        detect_language_for_indexing_with_content(notebook, "{}"),
+        "jupyter"
+    );
+    assert_eq!(
+        detect_language_for_indexing_with_content(std::path::Path::new("report.Rmd"), "# Report\n"),
+        "rmarkdown"
+    );
+
+    let with_image_output = format!(
+        "{{\"cells\": [], \"data\": \"{}\"}}\n",
+        "iVBORw0KGgo".repeat(3_000)
+    );
+    assert_eq!(
+        determine_extraction_mode("jupyter", &with_image_output),
+        ExtractionMode::ParserBacked,
+        "embedded outputs are not minified code"
+    );
+    assert_eq!(parser_skip_reason("jupyter", &with_image_output), None);
+}
+
+#[test]
+fn test_verilog_sources_are_parser_backed() {
+    for (path, language) in [
+        ("rtl/fifo.v", "verilog"),
+        ("rtl/defs.vh", "verilog"),
+        ("rtl/top.sv", "systemverilog"),
+        ("rtl/pkg.SVH", "systemverilog"),
+    ] {
+        assert_eq!(
+            detect_language_for_indexing_with_content(std::path::Path::new(path), "module m;\n"),
+            language,
+            "{path}"
+        );
+        assert_eq!(
+            determine_extraction_mode(language, "module m;\nendmodule\n"),
+            ExtractionMode::ParserBacked
+        );
+    }
+}
+
+#[test]
+fn test_groovy_sources_and_build_scripts_are_parser_backed() {
+    for path in [
+        "src/main/groovy/Widget.groovy",
+        "build.gradle",
+        "gradle/publishing.GRADLE",
+        "scripts/release.gsh",
+        "Jenkinsfile",
+        "ci/Jenkinsfile.nightly",
+    ] {
+        assert_eq!(
+            detect_language_for_indexing_with_content(std::path::Path::new(path), "println 'hi'\n"),
+            "groovy",
+            "{path}"
+        );
+    }
+    assert_eq!(
+        determine_extraction_mode("groovy", "task check {}\n"),
+        ExtractionMode::ParserBacked
+    );
+}
+
+#[test]
+fn test_fortran_sources_are_parser_backed() {
+    for path in [
+        "src/solver.f90",
+        "src/legacy.F95",
+        "src/geometry.f03",
+        "src/coarrays.F08",
+    ] {
+        assert_eq!(
+            detect_language_for_indexing_with_content(std::path::Path::new(path), "end\n"),
+            "fortran",
+            "{path}"
+        );
+    }
+    assert_eq!(
+        determine_extraction_mode("fortran", "module m\nend module m\n"),
+        ExtractionMode::ParserBacked
+    );
+}
+
+#[test]
+fn test_ada_sources_are_parser_backed() {
+    for path in ["src/flight.ads", "src/flight.ADB", "legacy/main.ada"] {
+        assert_eq!(
+            detect_language_for_indexing_with_content(std::path::Path::new(path), "end;\n"),
+            "ada",
+            "{path}"
+        );
+    }
+    assert_eq!(
+        determine_extraction_mode("ada", "package P is\nend P;\n"),
+        ExtractionMode::ParserBacked
+    );
+}
+
+#[test]
+fn test_sql_dialect_sources_are_sql() {
+    for (path, dialect) in [
+        ("db/emp_pkg.pks", Some(SqlDialect::PlSql)),
+        ("db/emp_pkg.PKB", Some(SqlDialect::PlSql)),
+        ("db/audit.trg", Some(SqlDialect::PlSql)),
+        ("db/orders.tsql", Some(SqlDialect::TSql)),
+        ("db/schema.sql", None),
+    ] {
+        assert_eq!(
+            detect_language_for_indexing_with_content(std::path::Path::new(path), "END;\n"),
+            "sql",
+            "{path}"
+        );
+        assert_eq!(
+            SqlDialect::from_extension(std::path::Path::new(path)),
+            dialect
+        );
+    }
+    assert_eq!(SqlDialect::parse("Oracle"), Some(SqlDialect::PlSql));
+    assert_eq!(SqlDialect::parse("T-SQL"), Some(SqlDialect::TSql));
+    assert_eq!(SqlDialect::parse("postgres"), None);
+}
+
+#[test]
+fn test_build_files_are_parser_backed() {
+    for (path, language) in [
+        ("Makefile", "makefile"),
+        ("tools/GNUmakefile", "makefile"),
+        ("mk/rules.mk", "makefile"),
+        ("CMakeLists.txt", "cmake"),
+        ("cmake/deps.cmake", "cmake"),
+        ("BUILD", "bazel"),
+        ("pkg/BUILD.bazel", "bazel"),
+    ] {
+        assert_eq!(
+            detect_language_for_indexing_with_content(std::path::Path::new(path), "all:\n"),
+            language,
+            "{path}"
+        );
+    }
+    assert_ne!(
+        detect_language_for_indexing_with_content(
+            std::path::Path::new("scripts/build"),
+            "#!/bin/sh\n"
+        ),
+        "bazel"
+    );
+    assert_eq!(
+        determine_extraction_mode("bazel", "cc_library(name = \"core\")\n"),
+        ExtractionMode::ParserBacked
+    );
+}
+
+#[test]
+fn test_godot_scenes_are_parser_backed() {
+    assert_eq!(
+        detect_language_for_indexing_with_content(
+            std::path::Path::new("game/scenes/Main.TSCN"),
+            "[gd_scene format=3]\n"
+        ),
+        "godot_scene"
+    );
+    assert_eq!(
+        determine_extraction_mode("godot_scene", "[gd_scene format=3]\n"),
+        ExtractionMode::ParserBacked
+    );
+}
+
+#[test]
+fn test_terraform_sources_are_parser_backed() {
+    for path in ["infra/main.tf", "infra/VARIABLES.TF", "live/network.tofu"] {
+        assert_eq!(
+            detect_language_for_indexing_with_content(std::path::Path::new(path), "locals {}\n"),
+            "terraform",
+            "{path}"
+        );
+    }
+    assert_eq!(
+        determine_extraction_mode("terraform", "variable \"region\" {}\n"),
+        ExtractionMode::ParserBacked
+    );
+}
+
+#[test]
+fn test_data_files_are_detected_and_indexed_as_text() {
+    for (path, language) in [
+        ("data/orders.csv", "csv"),
+        ("exports/REPORT.CSV", "csv"),
+        ("samples/genes.tsv", "tsv"),
+    ] {
+        assert_eq!(
+            detect_language_for_indexing_with_content(std::path::Path::new(path), "id,name\n"),
+            language,
+            "{path}"
+        );
+    }
+    assert_eq!(
+        determine_extraction_mode("csv", "id,name\n1,a\n"),
+        ExtractionMode::TextOnly
+    );
+}
+
 #[test]
 fn test_determine_extraction_mode_oversized_parser_file_falls_back_to_text_only() {
     let oversized = "a".repeat(5_000_001);
@@ -61,6 +265,26 @@ fn test_determine_extraction_mode_minified_parser_file_falls_back_to_text_only()
     assert_eq!(mode, ExtractionMode::TextOnly);
 }
 
+#[test]
+fn test_parser_skip_reason_explains_oversized_and_minified_files() {
+    let oversized = "a".repeat(5_000_001);
+    let reason = parser_skip_reason("rust", &oversized).unwrap();
+    assert!(reason.contains("too large"), "{reason}");
+
+    let minified = format!("function x(){{return 1;}}{}\n", "a".repeat(25_000));
+    assert_eq!(
+        parser_skip_reason("javascript", &minified).as_deref(),
+        Some("minified or generated content")
+    );
+
+    assert_eq!(parser_skip_reason("rust", "fn main() {}\n"), None);
+    assert_eq!(
+        parser_skip_reason("text", &oversized),
+        None,
+        "languages without a grammar are text-only by design, not skipped"
+    );
+}
+
 #[test]
 fn test_determine_extraction_mode_markdown_long_lines_stays_parser_backed() {
     let mut content = String::from("# Heading\n\n");
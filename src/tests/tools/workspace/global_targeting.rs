@@ -92,6 +92,9 @@ async fn setup_known_reference_search_workspace() -> (tempfile::TempDir, JulieSe
         name: None,
         workspace_id: None,
         detailed: None,
+        from_generation: None,
+        to_generation: None,
+        dry_run: None,
     }
     .call_tool(&seed_handler)
     .await
@@ -111,6 +114,9 @@ async fn setup_known_reference_search_workspace() -> (tempfile::TempDir, JulieSe
         name: None,
         workspace_id: None,
         detailed: None,
+        from_generation: None,
+        to_generation: None,
+        dry_run: None,
     }
     .call_tool(&seed_handler)
     .await
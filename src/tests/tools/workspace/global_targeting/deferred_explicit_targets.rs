@@ -54,6 +54,9 @@ async fn test_manage_workspace_stats_explicit_target_succeeds_without_bound_prim
         name: None,
         workspace_id: Some(target_id.clone()),
         detailed: None,
+        from_generation: None,
+        to_generation: None,
+        dry_run: None,
     }
     .call_tool(&handler)
     .await
@@ -126,6 +129,9 @@ async fn test_manage_workspace_refresh_by_workspace_id_succeeds_without_bound_pr
         name: None,
         workspace_id: Some(target_id.clone()),
         detailed: None,
+        from_generation: None,
+        to_generation: None,
+        dry_run: None,
     }
     .call_tool(&handler)
     .await
@@ -198,6 +204,9 @@ async fn test_manage_workspace_open_by_workspace_id_succeeds_without_bound_prima
         name: None,
         workspace_id: Some(target_id.clone()),
         detailed: None,
+        from_generation: None,
+        to_generation: None,
+        dry_run: None,
     }
     .call_tool(&handler)
     .await
@@ -79,6 +79,9 @@ async fn test_manage_workspace_list_succeeds_in_deferred_session_without_primary
         name: None,
         workspace_id: None,
         detailed: None,
+        from_generation: None,
+        to_generation: None,
+        dry_run: None,
     }
     .call_tool(&handler)
     .await
@@ -126,6 +129,9 @@ async fn test_manage_workspace_remove_succeeds_in_deferred_session_without_prima
         name: None,
         workspace_id: Some(removable_id.clone()),
         detailed: None,
+        from_generation: None,
+        to_generation: None,
+        dry_run: None,
     }
     .call_tool(&handler)
     .await
@@ -178,6 +184,9 @@ async fn test_manage_workspace_register_succeeds_in_deferred_session_without_pri
         name: None,
         workspace_id: None,
         detailed: None,
+        from_generation: None,
+        to_generation: None,
+        dry_run: None,
     }
     .call_tool(&handler)
     .await
@@ -230,6 +239,9 @@ async fn test_manage_workspace_add_is_rejected_as_unknown_operation() {
         name: None,
         workspace_id: None,
         detailed: None,
+        from_generation: None,
+        to_generation: None,
+        dry_run: None,
     }
     .call_tool(&handler)
     .await;
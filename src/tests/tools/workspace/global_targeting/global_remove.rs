@@ -75,6 +75,9 @@ async fn test_remove_workspace_uses_global_index_dir_shape() {
         name: None,
         workspace_id: Some(target_id.clone()),
         detailed: None,
+        from_generation: None,
+        to_generation: None,
+        dry_run: None,
     }
     .call_tool(&handler)
     .await
@@ -178,6 +181,9 @@ async fn test_remove_current_primary_workspace_is_blocked_in_process() {
         name: None,
         workspace_id: Some(primary_id.clone()),
         detailed: None,
+        from_generation: None,
+        to_generation: None,
+        dry_run: None,
     }
     .call_tool(&handler)
     .await
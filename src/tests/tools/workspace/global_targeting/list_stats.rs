@@ -43,6 +43,9 @@ async fn test_manage_workspace_list_includes_loaded_primary_without_explicit_reg
         name: None,
         workspace_id: None,
         detailed: None,
+        from_generation: None,
+        to_generation: None,
+        dry_run: None,
     }
     .call_tool(&handler)
     .await
@@ -123,6 +126,9 @@ async fn test_manage_workspace_list_labels_current_active_and_known_workspaces()
         name: None,
         workspace_id: None,
         detailed: None,
+        from_generation: None,
+        to_generation: None,
+        dry_run: None,
     }
     .call_tool(&handler)
     .await
@@ -229,6 +235,9 @@ async fn test_manage_workspace_list_uses_session_primary_binding_for_current_lab
         name: None,
         workspace_id: None,
         detailed: None,
+        from_generation: None,
+        to_generation: None,
+        dry_run: None,
     }
     .call_tool(&handler)
     .await
@@ -408,6 +417,9 @@ async fn test_manage_workspace_stats_include_all_known_workspaces() {
         name: None,
         workspace_id: None,
         detailed: None,
+        from_generation: None,
+        to_generation: None,
+        dry_run: None,
     }
     .call_tool(&handler)
     .await
@@ -479,6 +491,9 @@ async fn test_manage_workspace_stats_neutral_gap_returns_registry_summary_withou
         name: None,
         workspace_id: None,
         detailed: None,
+        from_generation: None,
+        to_generation: None,
+        dry_run: None,
     }
     .call_tool(&handler)
     .await
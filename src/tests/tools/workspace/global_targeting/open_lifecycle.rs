@@ -43,6 +43,9 @@ async fn test_manage_workspace_open_registers_missing_workspace_and_returns_work
         name: None,
         workspace_id: None,
         detailed: None,
+        from_generation: None,
+        to_generation: None,
+        dry_run: None,
     };
 
     let result = tool.call_tool(&handler).await.unwrap();
@@ -139,6 +142,9 @@ async fn test_manage_workspace_register_does_not_mutate_primary_binding_during_r
         name: None,
         workspace_id: None,
         detailed: None,
+        from_generation: None,
+        to_generation: None,
+        dry_run: None,
     }
     .call_tool(&handler)
     .await
@@ -213,6 +219,9 @@ async fn test_manage_workspace_open_by_workspace_id_marks_known_workspace_active
         name: None,
         workspace_id: Some(target_id.clone()),
         detailed: None,
+        from_generation: None,
+        to_generation: None,
+        dry_run: None,
     };
 
     let result = tool.call_tool(&handler).await.unwrap();
@@ -284,6 +293,9 @@ async fn test_manage_workspace_open_does_not_activate_workspace_when_refresh_fai
         name: None,
         workspace_id: Some(target_id.clone()),
         detailed: None,
+        from_generation: None,
+        to_generation: None,
+        dry_run: None,
     };
 
     let result = tool.call_tool(&handler).await.unwrap();
@@ -346,6 +358,9 @@ async fn test_manage_workspace_open_is_idempotent_for_active_workspace() {
         name: None,
         workspace_id: Some(target_id.clone()),
         detailed: None,
+        from_generation: None,
+        to_generation: None,
+        dry_run: None,
     };
 
     tool.call_tool(&handler).await.unwrap();
@@ -416,6 +431,9 @@ async fn test_manage_workspace_open_short_circuits_when_active() {
         name: None,
         workspace_id: Some(target_id.clone()),
         detailed: None,
+        from_generation: None,
+        to_generation: None,
+        dry_run: None,
     };
 
     let first = tool.call_tool(&handler).await.unwrap();
@@ -485,6 +503,9 @@ async fn test_manage_workspace_open_force_active_workspace_runs_refresh() {
         name: None,
         workspace_id: Some(target_id.clone()),
         detailed: None,
+        from_generation: None,
+        to_generation: None,
+        dry_run: None,
     };
 
     let initial = open_tool.call_tool(&handler).await.unwrap();
@@ -502,6 +523,9 @@ async fn test_manage_workspace_open_force_active_workspace_runs_refresh() {
         name: None,
         workspace_id: Some(target_id.clone()),
         detailed: None,
+        from_generation: None,
+        to_generation: None,
+        dry_run: None,
     };
 
     let forced = force_open_tool.call_tool(&handler).await.unwrap();
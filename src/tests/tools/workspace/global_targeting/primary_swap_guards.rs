@@ -91,6 +91,9 @@ async fn test_manage_workspace_open_refuses_while_primary_swap_in_progress() {
         name: None,
         workspace_id: Some(target_id.clone()),
         detailed: None,
+        from_generation: None,
+        to_generation: None,
+        dry_run: None,
     }
     .call_tool(&handler)
     .await;
@@ -125,6 +128,9 @@ async fn test_manage_workspace_refresh_refuses_primary_mutation_while_swap_in_pr
         name: None,
         workspace_id: Some(primary_id.clone()),
         detailed: None,
+        from_generation: None,
+        to_generation: None,
+        dry_run: None,
     }
     .call_tool(&handler)
     .await;
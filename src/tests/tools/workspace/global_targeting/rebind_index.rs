@@ -49,6 +49,9 @@ async fn test_manage_workspace_open_uses_session_primary_binding_over_legacy_wor
         name: None,
         workspace_id: None,
         detailed: None,
+        from_generation: None,
+        to_generation: None,
+        dry_run: None,
     };
     let stats_result = stats_tool.call_tool(&handler).await.unwrap();
     let stats_text = extract_text_from_result(&stats_result);
@@ -67,6 +70,9 @@ async fn test_manage_workspace_open_uses_session_primary_binding_over_legacy_wor
         name: None,
         workspace_id: Some(target_id.clone()),
         detailed: None,
+        from_generation: None,
+        to_generation: None,
+        dry_run: None,
     };
     let open_result = open_tool.call_tool(&handler).await.unwrap();
     let open_text = extract_text_from_result(&open_result);
@@ -131,6 +137,9 @@ async fn test_manage_workspace_index_path_rebind_updates_daemon_stats_for_new_pr
         name: None,
         workspace_id: None,
         detailed: None,
+        from_generation: None,
+        to_generation: None,
+        dry_run: None,
     }
     .call_tool(&handler)
     .await
@@ -207,6 +216,9 @@ async fn test_manage_workspace_index_path_succeeds_without_bound_primary_in_defe
         name: None,
         workspace_id: None,
         detailed: None,
+        from_generation: None,
+        to_generation: None,
+        dry_run: None,
     }
     .call_tool(&handler)
     .await
@@ -272,6 +284,9 @@ async fn test_manage_workspace_index_path_succeeds_without_bound_primary_in_defe
         name: None,
         workspace_id: None,
         detailed: None,
+        from_generation: None,
+        to_generation: None,
+        dry_run: None,
     }
     .call_tool(&handler)
     .await
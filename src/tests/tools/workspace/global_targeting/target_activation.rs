@@ -132,6 +132,9 @@ async fn test_opened_workspace_routes_fast_search_by_workspace_id() {
         name: None,
         workspace_id: Some(target_id.clone()),
         detailed: None,
+        from_generation: None,
+        to_generation: None,
+        dry_run: None,
     }
     .call_tool(&handler)
     .await
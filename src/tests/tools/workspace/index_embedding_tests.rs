@@ -409,3 +409,105 @@ fn test_pipeline_cancel_after_batch_stops_before_next_batch() {
         "first batch should have been stored before cancel was detected"
     );
 }
+
+/// A cancelled run checkpoints what it embedded, and the next run resumes the
+/// job instead of re-embedding it. Classes are always re-embedded for fresh
+/// enrichment, so only the job's done list lets the second run skip them.
+#[test]
+fn test_pipeline_resumes_cancelled_job_from_checkpoint() {
+    use crate::database::EmbeddingJobStatus;
+    use crate::embeddings::pipeline::run_embedding_pipeline_cancellable;
+    use crate::embeddings::{DeviceInfo, EmbeddingProvider};
+    use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+    use std::sync::{Arc, Mutex};
+
+    let (mut db, _dir) = create_test_db();
+    for i in 0..300_usize {
+        let file_path = format!("src/file_{i}.rs");
+        let symbol_id = format!("class_{i}");
+        db.store_file_info(
+            &file_info_builder(&file_path)
+                .language("rust")
+                .hash("deadbeef")
+                .size(100)
+                .last_modified(0)
+                .last_indexed(0)
+                .build(),
+        )
+        .unwrap();
+        db.store_symbols(
+            &[symbol_builder(&symbol_id, format!("Class{i}"), &file_path)
+                .kind(SymbolKind::Class)
+                .language("rust")
+                .span(1, 0, 10, 0)
+                .bytes(0, 0)
+                .build()],
+        )
+        .unwrap();
+        set_symbol_reference_scores(&db, &[(&symbol_id, 1.0)]).unwrap();
+    }
+    let db_arc = Arc::new(Mutex::new(db));
+
+    // Raises `cancel` during its first batch, when one is given.
+    struct CountingProvider {
+        cancel: Option<Arc<AtomicBool>>,
+        texts: AtomicUsize,
+    }
+    impl EmbeddingProvider for CountingProvider {
+        fn embed_query(&self, _: &str) -> anyhow::Result<Vec<f32>> {
+            Ok(vec![0.0; 4])
+        }
+        fn embed_batch(&self, texts: &[String]) -> anyhow::Result<Vec<Vec<f32>>> {
+            self.texts.fetch_add(texts.len(), Ordering::Relaxed);
+            if let Some(cancel) = &self.cancel {
+                cancel.store(true, Ordering::Release);
+            }
+            Ok(texts.iter().map(|_| vec![0.1_f32; 4]).collect())
+        }
+        fn dimensions(&self) -> usize {
+            4
+        }
+        fn device_info(&self) -> DeviceInfo {
+            DeviceInfo {
+                runtime: "test".to_string(),
+                device: "cpu".to_string(),
+                model_name: "resume-model".to_string(),
+                dimensions: 4,
+            }
+        }
+    }
+
+    let cancel = Arc::new(AtomicBool::new(false));
+    let first = CountingProvider {
+        cancel: Some(cancel.clone()),
+        texts: AtomicUsize::new(0),
+    };
+    let stats = run_embedding_pipeline_cancellable(&db_arc, &first, None, Some(&cancel)).unwrap();
+    assert_eq!(stats.symbols_embedded, 250, "one batch before the cancel");
+    {
+        let db = db_arc.lock().unwrap();
+        assert_eq!(db.embedding_count().unwrap(), 250);
+        let job = db.embedding_job().unwrap().unwrap();
+        assert_eq!(job.status, EmbeddingJobStatus::Cancelled);
+        assert_eq!((job.completed, job.total), (250, 300));
+    }
+
+    let second = CountingProvider {
+        cancel: None,
+        texts: AtomicUsize::new(0),
+    };
+    let stats = run_embedding_pipeline_cancellable(&db_arc, &second, None, None).unwrap();
+    assert_eq!(
+        second.texts.load(Ordering::Relaxed),
+        50,
+        "the resumed job must embed only what the cancelled one left"
+    );
+    assert_eq!(stats.symbols_embedded, 50);
+
+    let db = db_arc.lock().unwrap();
+    assert_eq!(db.embedding_count().unwrap(), 300);
+    let job = db.embedding_job().unwrap().unwrap();
+    assert_eq!(job.status, EmbeddingJobStatus::Complete);
+    assert_eq!((job.completed, job.total), (300, 300));
+    assert!(db.unfinished_embedding_job_ids().unwrap().is_empty());
+}
@@ -152,6 +152,76 @@ fn manage_workspace_request_parses_valid_operations_with_live_fields() {
 
     let request = request_from_json(json!({ "operation": "dashboard" })).unwrap();
     assert!(matches!(request, ManageWorkspaceRequest::Dashboard));
+
+    let request = request_from_json(json!({
+        "operation": "backup",
+        "path": "/backups/repo"
+    }))
+    .unwrap();
+    assert!(matches!(
+        request,
+        ManageWorkspaceRequest::Backup { workspace_id, path }
+            if workspace_id.is_none() && path.as_deref() == Some("/backups/repo")
+    ));
+
+    let request = request_from_json(json!({ "operation": "capabilities" })).unwrap();
+    assert!(matches!(
+        request,
+        ManageWorkspaceRequest::Capabilities { workspace_id } if workspace_id.is_none()
+    ));
+
+    let request = request_from_json(json!({
+        "operation": "rollback_last_operation",
+        "workspace_id": "workspace-1"
+    }))
+    .unwrap();
+    assert!(matches!(
+        request,
+        ManageWorkspaceRequest::RollbackLastOperation { workspace_id }
+            if workspace_id.as_deref() == Some("workspace-1")
+    ));
+
+    let request = request_from_json(json!({
+        "operation": "compare_generations",
+        "from_generation": "12"
+    }))
+    .unwrap();
+    assert!(matches!(
+        request,
+        ManageWorkspaceRequest::CompareGenerations {
+            workspace_id: None,
+            from_generation: Some(12),
+            to_generation: None,
+        }
+    ));
+
+    let request = request_from_json(json!({ "operation": "cancel_embeddings" })).unwrap();
+    assert!(matches!(
+        request,
+        ManageWorkspaceRequest::CancelEmbeddings { workspace_id } if workspace_id.is_none()
+    ));
+
+    let request = request_from_json(json!({ "operation": "repair" })).unwrap();
+    assert_eq!(
+        request,
+        ManageWorkspaceRequest::Repair {
+            workspace_id: None,
+            dry_run: true,
+        }
+    );
+
+    let request = request_from_json(json!({
+        "operation": "repair",
+        "dry_run": "false"
+    }))
+    .unwrap();
+    assert_eq!(
+        request,
+        ManageWorkspaceRequest::Repair {
+            workspace_id: None,
+            dry_run: false,
+        }
+    );
 }
 
 #[test]
@@ -171,7 +241,7 @@ fn manage_workspace_request_rejects_missing_required_fields_and_unknown_operatio
         ),
         (
             json!({ "operation": "add" }),
-            "Unknown operation: 'add'. Valid operations: index, list, register, remove, stats, clean, refresh, open, health, dashboard",
+            "Unknown operation: 'add'. Valid operations: index, list, register, remove, stats, clean, refresh, open, health, dashboard, backup, capabilities, rollback_last_operation, compare_generations, cancel_embeddings, repair",
         ),
     ];
 
@@ -207,6 +277,28 @@ fn manage_workspace_preflight_classification_uses_shared_operation_parser() {
         "operation": "stats",
         "workspace_id": "workspace-1"
     })));
+    assert!(request_targets_primary(json!({ "operation": "backup" })));
+    assert!(!request_targets_primary(json!({
+        "operation": "backup",
+        "workspace_id": "workspace-1"
+    })));
+    assert!(request_targets_primary(
+        json!({ "operation": "capabilities" })
+    ));
+    assert!(!request_targets_primary(json!({
+        "operation": "capabilities",
+        "workspace_id": "workspace-1"
+    })));
+    assert!(request_targets_primary(
+        json!({ "operation": "rollback_last_operation" })
+    ));
+    assert!(request_targets_primary(
+        json!({ "operation": "compare_generations" })
+    ));
+    assert!(request_targets_primary(
+        json!({ "operation": "cancel_embeddings" })
+    ));
+    assert!(request_targets_primary(json!({ "operation": "repair" })));
 
     assert!(request_targets_primary(json!({ "operation": "index" })));
     assert!(request_targets_primary(json!({
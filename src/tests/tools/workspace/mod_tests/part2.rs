@@ -62,6 +62,9 @@ async fn test_workspace_index_records_parse_diagnostics_for_recovered_file() ->
         name: None,
         workspace_id: None,
         detailed: None,
+        from_generation: None,
+        to_generation: None,
+        dry_run: None,
     };
     index_tool.call_tool(&handler).await?;
 
@@ -175,6 +178,9 @@ async fn test_health_snapshot_classifies_plane_states_for_local_workspace() {
         name: None,
         workspace_id: None,
         detailed: None,
+        from_generation: None,
+        to_generation: None,
+        dry_run: None,
     }
     .call_tool(&handler)
     .await
@@ -287,6 +293,9 @@ async fn test_manage_workspace_health_reports_control_data_and_runtime_planes()
         name: None,
         workspace_id: None,
         detailed: None,
+        from_generation: None,
+        to_generation: None,
+        dry_run: None,
     }
     .call_tool(&handler)
     .await
@@ -326,6 +335,9 @@ async fn test_manage_workspace_health_reports_control_data_and_runtime_planes()
         name: None,
         workspace_id: None,
         detailed: Some(false),
+        from_generation: None,
+        to_generation: None,
+        dry_run: None,
     }
     .call_tool(&handler)
     .await
@@ -392,6 +404,9 @@ async fn test_manage_workspace_health_surfaces_embedding_runtime_status() {
         name: None,
         workspace_id: None,
         detailed: Some(false),
+        from_generation: None,
+        to_generation: None,
+        dry_run: None,
     };
 
     let result = tool.call_tool(&handler).await.unwrap();
@@ -430,6 +445,18 @@ async fn test_manage_workspace_health_surfaces_embedding_runtime_status() {
         health.contains("Query Fallback: semantic"),
         "health output should describe the query fallback mode when embeddings are available: {health}"
     );
+    assert!(
+        health.contains("Grammar Status: READY"),
+        "health output should report pinned grammar compatibility: {health}"
+    );
+    assert!(
+        health.contains("Recovery Status: "),
+        "health output should report background task recovery: {health}"
+    );
+    assert!(
+        health.contains("Memory Status: "),
+        "health output should report memory budget usage: {health}"
+    );
 }
 
 #[tokio::test]
@@ -467,6 +494,9 @@ async fn test_manage_workspace_health_reports_unavailable_when_provider_missing(
         name: None,
         workspace_id: None,
         detailed: Some(false),
+        from_generation: None,
+        to_generation: None,
+        dry_run: None,
     };
 
     let result = tool.call_tool(&handler).await.unwrap();
@@ -524,6 +554,9 @@ async fn test_manage_workspace_health_reports_not_initialized_when_runtime_statu
         name: None,
         workspace_id: None,
         detailed: Some(false),
+        from_generation: None,
+        to_generation: None,
+        dry_run: None,
     };
 
     let result = tool.call_tool(&handler).await.unwrap();
@@ -577,6 +610,9 @@ async fn test_manage_workspace_health_reports_initialized_when_not_degraded() {
         name: None,
         workspace_id: None,
         detailed: Some(false),
+        from_generation: None,
+        to_generation: None,
+        dry_run: None,
     };
 
     let result = tool.call_tool(&handler).await.unwrap();
@@ -125,6 +125,9 @@ async fn test_manage_workspace_health_uses_rebound_session_primary() {
         name: None,
         workspace_id: None,
         detailed: Some(false),
+        from_generation: None,
+        to_generation: None,
+        dry_run: None,
     };
 
     let result = tool.call_tool(&handler).await.unwrap();
@@ -450,6 +453,9 @@ async fn test_manage_workspace_health_loaded_primary_without_tantivy_is_sqlite_o
         name: None,
         workspace_id: None,
         detailed: None,
+        from_generation: None,
+        to_generation: None,
+        dry_run: None,
     }
     .call_tool(&handler)
     .await
@@ -504,6 +510,9 @@ async fn test_manage_workspace_health_rejects_neutral_gap_without_primary_identi
         name: None,
         workspace_id: None,
         detailed: None,
+        from_generation: None,
+        to_generation: None,
+        dry_run: None,
     }
     .call_tool(&handler)
     .await
@@ -518,6 +527,9 @@ async fn test_manage_workspace_health_rejects_neutral_gap_without_primary_identi
         name: None,
         workspace_id: None,
         detailed: Some(false),
+        from_generation: None,
+        to_generation: None,
+        dry_run: None,
     };
 
     let err = tool
@@ -543,6 +555,9 @@ async fn test_manage_workspace_health_cold_start_returns_index_first_guidance()
         name: None,
         workspace_id: None,
         detailed: Some(false),
+        from_generation: None,
+        to_generation: None,
+        dry_run: None,
     }
     .call_tool(&handler)
     .await
@@ -589,6 +604,9 @@ async fn test_manage_workspace_health_true_swap_gap_uses_swap_gap_classification
         name: None,
         workspace_id: None,
         detailed: None,
+        from_generation: None,
+        to_generation: None,
+        dry_run: None,
     }
     .call_tool(&handler)
     .await
@@ -605,6 +623,9 @@ async fn test_manage_workspace_health_true_swap_gap_uses_swap_gap_classification
         name: None,
         workspace_id: None,
         detailed: Some(false),
+        from_generation: None,
+        to_generation: None,
+        dry_run: None,
     }
     .call_tool(&handler)
     .await
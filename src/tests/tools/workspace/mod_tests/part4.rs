@@ -168,6 +168,9 @@ async fn test_manage_workspace_index_rejects_neutral_gap_without_primary_identit
         name: None,
         workspace_id: None,
         detailed: None,
+        from_generation: None,
+        to_generation: None,
+        dry_run: None,
     }
     .call_tool(&handler)
     .await
@@ -182,6 +185,9 @@ async fn test_manage_workspace_index_rejects_neutral_gap_without_primary_identit
         name: None,
         workspace_id: None,
         detailed: None,
+        from_generation: None,
+        to_generation: None,
+        dry_run: None,
     };
 
     let err = tool
@@ -224,6 +230,9 @@ async fn test_manage_workspace_index_rejects_neutral_gap_without_primary_identit
         name: None,
         workspace_id: None,
         detailed: None,
+        from_generation: None,
+        to_generation: None,
+        dry_run: None,
     }
     .call_tool(&handler)
     .await
@@ -240,6 +249,9 @@ async fn test_manage_workspace_index_rejects_neutral_gap_without_primary_identit
         name: None,
         workspace_id: None,
         detailed: None,
+        from_generation: None,
+        to_generation: None,
+        dry_run: None,
     }
     .call_tool(&handler)
     .await
@@ -278,6 +290,9 @@ async fn test_concurrent_manage_workspace_index_does_not_lock_search_index() {
             name: None,
             workspace_id: None,
             detailed: None,
+            from_generation: None,
+            to_generation: None,
+            dry_run: None,
         };
 
         tool.call_tool(&handler)
@@ -330,6 +345,9 @@ async fn test_primary_index_schedules_embedding_when_provider_available() {
         name: None,
         workspace_id: None,
         detailed: None,
+        from_generation: None,
+        to_generation: None,
+        dry_run: None,
     };
 
     let result = tool.call_tool(&handler).await.unwrap();
@@ -387,6 +405,9 @@ fn test_function() {
         name: None,
         workspace_id: None,
         detailed: None,
+        from_generation: None,
+        to_generation: None,
+        dry_run: None,
     };
 
     let result = tool.call_tool(&handler).await.unwrap();
@@ -488,6 +509,9 @@ fn test_function() {
         name: None,
         workspace_id: None,
         detailed: None,
+        from_generation: None,
+        to_generation: None,
+        dry_run: None,
     };
 
     let result = tool.call_tool(&handler).await.unwrap();
@@ -566,6 +590,9 @@ fn test_function() {
         name: None,
         workspace_id: None,
         detailed: None,
+        from_generation: None,
+        to_generation: None,
+        dry_run: None,
     };
 
     let result = tool_no_force.call_tool(&handler).await.unwrap();
@@ -584,6 +611,9 @@ fn test_function() {
         name: None,
         workspace_id: None,
         detailed: None,
+        from_generation: None,
+        to_generation: None,
+        dry_run: None,
     };
 
     let result = tool_force.call_tool(&handler).await.unwrap();
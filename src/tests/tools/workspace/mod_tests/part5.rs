@@ -58,6 +58,9 @@ fn function_two() {
         name: None,
         workspace_id: None,
         detailed: None,
+        from_generation: None,
+        to_generation: None,
+        dry_run: None,
     };
 
     let result = tool.call_tool(&handler).await.unwrap();
@@ -157,6 +160,9 @@ async fn test_incremental_indexing_forces_reindex_when_index_engine_version_is_s
         name: None,
         workspace_id: None,
         detailed: None,
+        from_generation: None,
+        to_generation: None,
+        dry_run: None,
     };
 
     let result = tool.call_tool(&handler).await.unwrap();
@@ -228,6 +234,9 @@ async fn test_incremental_indexing_forces_reindex_when_index_engine_version_is_s
         name: None,
         workspace_id: None,
         detailed: None,
+        from_generation: None,
+        to_generation: None,
+        dry_run: None,
     };
 
     let result = incremental_tool.call_tool(&handler).await.unwrap();
@@ -345,6 +354,9 @@ async fn test_startup_stale_file_repair_refreshes_embeddings_for_changed_file()
         name: None,
         workspace_id: None,
         detailed: None,
+        from_generation: None,
+        to_generation: None,
+        dry_run: None,
     };
     index_tool.call_tool(&handler).await.unwrap();
     wait_for_embedding_tasks_to_finish(&handler).await;
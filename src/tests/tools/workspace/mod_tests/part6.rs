@@ -42,6 +42,9 @@ async fn test_startup_noop_repair_does_not_mark_catchup_active_while_planning()
         name: None,
         workspace_id: None,
         detailed: None,
+        from_generation: None,
+        to_generation: None,
+        dry_run: None,
     };
     index_tool.call_tool(&handler).await.unwrap();
     wait_for_embedding_tasks_to_finish(&handler).await;
@@ -120,6 +123,9 @@ async fn test_startup_semantic_repair_runs_embeddings_after_full_reindex() {
         name: None,
         workspace_id: None,
         detailed: None,
+        from_generation: None,
+        to_generation: None,
+        dry_run: None,
     };
     let result = index_tool.call_tool(&handler).await.unwrap();
     let message = extract_text_from_result(&result);
@@ -213,6 +219,9 @@ async fn test_startup_repair_schedules_embeddings_when_workspace_has_symbols_but
         name: None,
         workspace_id: None,
         detailed: None,
+        from_generation: None,
+        to_generation: None,
+        dry_run: None,
     };
     index_tool.call_tool(&handler).await.unwrap();
     wait_for_embedding_tasks_to_finish(&handler).await;
@@ -305,6 +314,9 @@ async fn test_startup_missing_embeddings_only_repair_reconciles_web_edges() {
         name: None,
         workspace_id: None,
         detailed: None,
+        from_generation: None,
+        to_generation: None,
+        dry_run: None,
     };
     index_tool.call_tool(&handler).await.unwrap();
     wait_for_embedding_tasks_to_finish(&handler).await;
@@ -422,6 +434,9 @@ async fn test_startup_repair_does_not_schedule_missing_embeddings_when_task_alre
         name: None,
         workspace_id: None,
         detailed: None,
+        from_generation: None,
+        to_generation: None,
+        dry_run: None,
     };
     index_tool.call_tool(&handler).await.unwrap();
     wait_for_embedding_tasks_to_finish(&handler).await;
@@ -43,6 +43,9 @@ async fn test_refresh_treats_semantic_version_drift_as_full_reindex() {
         name: None,
         workspace_id: None,
         detailed: None,
+        from_generation: None,
+        to_generation: None,
+        dry_run: None,
     };
     let result = index_tool.call_tool(&handler).await.unwrap();
     let message = extract_text_from_result(&result);
@@ -76,6 +79,9 @@ async fn test_refresh_treats_semantic_version_drift_as_full_reindex() {
         name: None,
         workspace_id: Some(workspace_id.clone()),
         detailed: None,
+        from_generation: None,
+        to_generation: None,
+        dry_run: None,
     };
     let result = refresh_tool.call_tool(&handler).await.unwrap();
     let message = extract_text_from_result(&result);
@@ -127,6 +133,9 @@ async fn test_force_reindex_cancels_embedding_task_when_explicit_path_resolves_t
         name: None,
         workspace_id: None,
         detailed: None,
+        from_generation: None,
+        to_generation: None,
+        dry_run: None,
     };
     let result = index_tool
         .call_tool_with_options(&handler, true)
@@ -255,6 +264,9 @@ async fn test_refresh_no_changes_skips_embedding_pipeline() {
         name: None,
         workspace_id: None,
         detailed: None,
+        from_generation: None,
+        to_generation: None,
+        dry_run: None,
     };
     let result = index_tool.call_tool(&handler).await.unwrap();
     let msg = extract_text_from_result(&result);
@@ -288,6 +300,9 @@ async fn test_refresh_no_changes_skips_embedding_pipeline() {
         name: None,
         workspace_id: Some(workspace_id.clone()),
         detailed: None,
+        from_generation: None,
+        to_generation: None,
+        dry_run: None,
     };
     let result = refresh_tool.call_tool(&handler).await.unwrap();
     let msg = extract_text_from_result(&result);
@@ -331,6 +346,9 @@ async fn test_incremental_index_triggers_catch_up_embedding_when_none_exist() {
         name: None,
         workspace_id: None,
         detailed: None,
+        from_generation: None,
+        to_generation: None,
+        dry_run: None,
     };
     let _ = tool.call_tool(&handler).await.unwrap();
 
@@ -374,6 +392,9 @@ async fn test_incremental_index_triggers_catch_up_embedding_when_none_exist() {
         name: None,
         workspace_id: None,
         detailed: None,
+        from_generation: None,
+        to_generation: None,
+        dry_run: None,
     };
     let result = incremental_tool.call_tool(&handler).await.unwrap();
     let message = extract_text_from_result(&result);
@@ -1,7 +1,9 @@
 use crate::database::SymbolDatabase;
 use crate::extractors::ExtractionResults;
 use crate::extractors::base::{ParseDiagnostic, ParseDiagnosticKind};
-use crate::indexing_core::extraction::extract_files_for_indexing_with_records;
+use crate::indexing_core::extraction::{
+    extract_files_for_indexing_with_records, process_file_with_parser_with_timeout_for_test,
+};
 use crate::tools::workspace::ManageWorkspaceTool;
 use crate::tools::workspace::indexing::state::{
     IndexedFileDisposition, IndexingBatchState, IndexingStage,
@@ -19,6 +21,9 @@ fn workspace_tool() -> ManageWorkspaceTool {
         name: None,
         workspace_id: None,
         detailed: None,
+        from_generation: None,
+        to_generation: None,
+        dry_run: None,
     }
 }
 
@@ -318,8 +323,105 @@ async fn test_process_file_with_parser_keeps_file_info_for_degraded_parse_result
     assert_eq!(stats.total_symbols, 0);
     assert_eq!(
         db.get_file_parse_diagnostics(&stored_path).unwrap(),
-        vec![expected_diagnostic]
+        vec![expected_diagnostic.clone()]
+    );
+    assert_eq!(
+        db.list_files_with_parse_diagnostics().unwrap(),
+        vec![(stored_path, vec![expected_diagnostic])]
+    );
+}
+
+#[tokio::test]
+async fn test_process_file_with_parser_degrades_to_text_only_on_parse_timeout() {
+    let temp_dir = TempDir::new().unwrap();
+    let workspace_root = temp_dir.path().canonicalize().unwrap();
+    let file_path = workspace_root.join("slow.rs");
+    let content = "fn slow() {}\n";
+    fs::write(&file_path, content).unwrap();
+
+    let result = process_file_with_parser_with_timeout_for_test(
+        &file_path,
+        "rust",
+        &workspace_root,
+        std::time::Duration::from_millis(50),
+        |_relative_path, _content, _workspace_root| {
+            std::thread::sleep(std::time::Duration::from_millis(500));
+            Ok(ExtractionResults::empty())
+        },
+    )
+    .await
+    .expect("a timed-out parse must not fail the file");
+
+    let reason = result.skip_reason.expect("timeout is recorded as a skip");
+    assert!(reason.contains("timed out"), "{reason}");
+    assert!(result.normalized.symbols.is_empty());
+    assert_eq!(result.file_info.path, "slow.rs");
+    assert_eq!(
+        result.file_info.content.as_deref(),
+        Some(content),
+        "content stays searchable"
+    );
+}
+
+#[tokio::test]
+async fn test_process_file_with_parser_degrades_to_text_only_on_extractor_panic() {
+    let temp_dir = TempDir::new().unwrap();
+    let workspace_root = temp_dir.path().canonicalize().unwrap();
+    let file_path = workspace_root.join("crash.rs");
+    fs::write(&file_path, "fn crash() {}\n").unwrap();
+
+    let result = workspace_tool()
+        .process_file_with_parser_for_test(
+            &file_path,
+            "rust",
+            &workspace_root,
+            |_relative_path, _content, _workspace_root| panic!("grammar blew up"),
+        )
+        .await
+        .expect("a crashing extractor must not fail the file");
+
+    let reason = result.skip_reason.expect("crash is recorded as a skip");
+    assert!(reason.contains("extractor crashed"), "{reason}");
+    assert_eq!(result.file_info.path, "crash.rs");
+    let parser = julie_core::supervisor::Supervisor::global()
+        .snapshot()
+        .into_iter()
+        .find(|entry| entry.subsystem == julie_core::supervisor::Subsystem::Parser)
+        .expect("the crash is counted in health diagnostics");
+    assert!(parser.restarts >= 1);
+}
+
+#[tokio::test]
+async fn test_extract_files_for_indexing_records_parse_skip_for_minified_file() {
+    let temp_dir = TempDir::new().unwrap();
+    let workspace_root = temp_dir.path().canonicalize().unwrap();
+    let file_path = workspace_root.join("bundle.min.js");
+    fs::write(
+        &file_path,
+        format!("function x(){{return 1;}}{}\n", "a".repeat(25_000)),
+    )
+    .unwrap();
+
+    let mut files_by_language = HashMap::new();
+    files_by_language.insert("javascript".to_string(), vec![file_path]);
+    let (batch, records) =
+        extract_files_for_indexing_with_records(files_by_language, &workspace_root)
+            .await
+            .unwrap();
+
+    assert_eq!(
+        batch.parse_skips,
+        vec![(
+            "bundle.min.js".to_string(),
+            "minified or generated content".to_string()
+        )]
     );
+    assert!(batch.repair_entries.is_empty(), "skips are not repairs");
+    assert_eq!(batch.all_file_infos.len(), 1, "the file is still indexed");
+    assert!(matches!(
+        records[0].disposition,
+        crate::indexing_core::extraction::ExtractedFileDisposition::Skipped { .. }
+    ));
 }
 
 #[tokio::test]
@@ -73,6 +73,9 @@ async fn test_primary_force_refresh_uses_full_index_path() {
         name: None,
         workspace_id: Some(primary_id),
         detailed: None,
+        from_generation: None,
+        to_generation: None,
+        dry_run: None,
     }
     .call_tool(&handler)
     .await
@@ -155,6 +158,9 @@ async fn test_manage_workspace_refresh_force_uses_rebound_session_primary_root()
         name: None,
         workspace_id: Some(rebound_primary_id.clone()),
         detailed: None,
+        from_generation: None,
+        to_generation: None,
+        dry_run: None,
     }
     .call_tool(&handler)
     .await
@@ -390,6 +396,9 @@ async fn test_manage_workspace_refresh_force_reference_keeps_reference_snapshot_
         name: None,
         workspace_id: Some(reference_id.clone()),
         detailed: None,
+        from_generation: None,
+        to_generation: None,
+        dry_run: None,
     }
     .call_tool(&handler)
     .await
@@ -568,6 +577,9 @@ async fn test_manage_workspace_index_non_force_uses_rebound_session_primary_root
         name: None,
         workspace_id: None,
         detailed: None,
+        from_generation: None,
+        to_generation: None,
+        dry_run: None,
     }
     .call_tool(&handler)
     .await
@@ -623,6 +635,9 @@ async fn test_manage_workspace_index_subdirectory_under_current_root_stays_prima
         name: None,
         workspace_id: None,
         detailed: None,
+        from_generation: None,
+        to_generation: None,
+        dry_run: None,
     }
     .call_tool(&handler)
     .await
@@ -708,6 +723,9 @@ async fn test_manage_workspace_implicit_index_from_subdir_resolves_to_project_ro
         name: None,
         workspace_id: None,
         detailed: None,
+        from_generation: None,
+        to_generation: None,
+        dry_run: None,
     }
     .call_tool(&handler)
     .await
@@ -795,6 +813,9 @@ async fn test_manage_workspace_refresh_non_force_uses_rebound_session_primary_ro
         name: None,
         workspace_id: Some(rebound_primary_id),
         detailed: None,
+        from_generation: None,
+        to_generation: None,
+        dry_run: None,
     }
     .call_tool(&handler)
     .await
@@ -0,0 +1,176 @@
+use crate::tools::workspace::commands::registry::remote::{
+    RemoteOrigin, RemoteSource, read_origin, remove_remote_copy, write_origin,
+};
+use std::path::Path;
+use tempfile::TempDir;
+
+fn source(user: Option<&str>, host: &str, port: Option<u16>, path: &str) -> RemoteSource {
+    RemoteSource {
+        user: user.map(str::to_string),
+        host: host.to_string(),
+        port,
+        path: path.to_string(),
+    }
+}
+
+#[test]
+fn remote_specs_parse_in_ssh_and_scp_form() {
+    let cases = [
+        (
+            "ssh://dev@build-01:2222/srv/monorepo/",
+            source(Some("dev"), "build-01", Some(2222), "/srv/monorepo"),
+        ),
+        (
+            "ssh://build-01/~/src/app",
+            source(None, "build-01", None, "src/app"),
+        ),
+        (
+            "dev@build-01.corp:/srv/monorepo",
+            source(Some("dev"), "build-01.corp", None, "/srv/monorepo"),
+        ),
+        (
+            "build-01:~/src/app",
+            source(None, "build-01", None, "src/app"),
+        ),
+    ];
+    for (spec, expected) in cases {
+        assert_eq!(RemoteSource::parse(spec), Some(expected), "{spec}");
+    }
+}
+
+#[test]
+fn local_paths_are_not_remote_specs() {
+    for spec in [
+        "/home/dev/project",
+        "./src:generated",
+        r"C:\src\project",
+        "C:/src/project",
+        "https://example.com/repo",
+        "build-01:",
+        ":/srv",
+        "@build-01:/srv",
+        "ssh://build-01",
+        "ssh://build-01:port/srv",
+    ] {
+        assert_eq!(RemoteSource::parse(spec), None, "{spec}");
+    }
+}
+
+#[test]
+fn users_and_hosts_that_could_become_ssh_options_are_rejected() {
+    for spec in [
+        "-oProxyCommand=sh -c 'touch /tmp/pwned'@host:/x",
+        "ssh://-oProxyCommand=sh -c 'touch /tmp/pwned'@host/x",
+        "-oProxyCommand=id:/x",
+        "dev@-oProxyCommand=id:/x",
+        "d$ev@build-01:/srv",
+        "dev@build 01:/srv",
+    ] {
+        assert_eq!(RemoteSource::parse(spec), None, "{spec}");
+    }
+}
+
+#[test]
+fn remote_sources_display_as_a_spec_that_parses_back() {
+    for spec in [
+        "dev@build-01:/srv/monorepo",
+        "build-01:src/app",
+        "ssh://dev@build-01:2222/srv/monorepo",
+        "ssh://build-01:2222/~/src/app",
+    ] {
+        let parsed = RemoteSource::parse(spec).unwrap();
+        assert_eq!(parsed.to_string(), spec);
+        assert_eq!(RemoteSource::parse(&parsed.to_string()), Some(parsed));
+    }
+}
+
+#[test]
+fn each_remote_source_gets_its_own_cache_dir() {
+    let root = Path::new("/cache/remote");
+    let monorepo = RemoteSource::parse("build-01:/srv/monorepo").unwrap();
+    let dir = monorepo.cache_dir(root);
+    let name = dir.file_name().unwrap().to_string_lossy().to_string();
+    assert_eq!(dir.parent(), Some(root));
+    assert!(name.starts_with("build-01-monorepo-"), "{name}");
+    assert_eq!(name.len(), "build-01-monorepo-".len() + 8);
+
+    assert_eq!(
+        RemoteSource::parse("build-01:/srv/monorepo/")
+            .unwrap()
+            .cache_dir(root),
+        dir
+    );
+    assert_ne!(
+        RemoteSource::parse("build-01:/home/dev/monorepo")
+            .unwrap()
+            .cache_dir(root),
+        dir,
+        "same directory name in another place"
+    );
+}
+
+#[test]
+fn sync_commands_skip_git_and_julie_state() {
+    let remote = RemoteSource::parse("ssh://dev@build-01:2222/srv/it's here").unwrap();
+    assert_eq!(
+        remote.rsync_args(Path::new("/cache/copy")),
+        [
+            "-az",
+            "--delete",
+            "--protect-args",
+            "--filter=:- .gitignore",
+            "--exclude=/.git/",
+            "--exclude=/.julie/",
+            "-e",
+            "ssh -o BatchMode=yes -o ConnectTimeout=15 -p 2222",
+            "--",
+            "dev@build-01:/srv/it's here/",
+            "/cache/copy/",
+        ]
+    );
+    assert_eq!(
+        remote.tar_over_ssh_args(),
+        [
+            "-o",
+            "BatchMode=yes",
+            "-o",
+            "ConnectTimeout=15",
+            "-p",
+            "2222",
+            "--",
+            "dev@build-01",
+            r"tar -C '/srv/it'\''s here' --exclude=./.git --exclude=./.julie -cf - .",
+        ]
+    );
+}
+
+#[test]
+fn origin_records_round_trip_and_go_with_the_copy() {
+    let cache = TempDir::new().unwrap();
+    let copy = cache.path().join("build-01-monorepo-0123abcd");
+    std::fs::create_dir_all(copy.join("src")).unwrap();
+    assert_eq!(read_origin(&copy), None);
+
+    let origin = RemoteOrigin {
+        source: "build-01:/srv/monorepo".to_string(),
+        method: "rsync".to_string(),
+        synced_at: 1_792_236_600,
+    };
+    write_origin(&copy, &origin).unwrap();
+    assert_eq!(read_origin(&copy), Some(origin.clone()));
+    assert_eq!(
+        origin.label(),
+        "build-01:/srv/monorepo (synced 2026-10-17 11:30 UTC via rsync)"
+    );
+
+    remove_remote_copy(&copy);
+    assert!(!copy.exists());
+    assert_eq!(std::fs::read_dir(cache.path()).unwrap().count(), 0);
+}
+
+#[test]
+fn removing_a_local_workspace_leaves_it_alone() {
+    let dir = TempDir::new().unwrap();
+    remove_remote_copy(dir.path());
+    assert!(dir.path().exists());
+}
@@ -0,0 +1,89 @@
+use crate::database::{SymbolDatabase, calculate_file_hash};
+use crate::tools::workspace::commands::repair::plan_repair;
+use julie_extractors::SymbolKind;
+use julie_test_support::db::{file_info_builder, relationship_builder, symbol_builder};
+use tempfile::TempDir;
+
+fn index_file(db: &SymbolDatabase, root: &std::path::Path, path: &str) {
+    let hash = calculate_file_hash(root.join(path)).unwrap();
+    db.store_file_info(&file_info_builder(path).language("rust").hash(hash).build())
+        .unwrap();
+}
+
+#[test]
+fn repair_plan_reports_each_kind_of_drift() {
+    let workspace = TempDir::new().unwrap();
+    let root = workspace.path();
+    std::fs::create_dir_all(root.join("src")).unwrap();
+    for file in ["lib.rs", "changed.rs", "removed.rs", "moved.rs"] {
+        std::fs::write(root.join("src").join(file), format!("// {file}\n")).unwrap();
+    }
+
+    let db_dir = TempDir::new().unwrap();
+    let mut db = SymbolDatabase::new(db_dir.path().join("symbols.db")).unwrap();
+    for file in ["lib.rs", "changed.rs", "removed.rs", "moved.rs"] {
+        index_file(&db, root, &format!("src/{file}"));
+    }
+    db.store_symbols(&[
+        symbol_builder("run", "run", "src/lib.rs")
+            .kind(SymbolKind::Function)
+            .build(),
+        symbol_builder("helper", "helper", "src/lib.rs")
+            .kind(SymbolKind::Function)
+            .build(),
+    ])
+    .unwrap();
+    db.store_relationships(&[relationship_builder("rel", "run", "helper")
+        .file_path("src/lib.rs")
+        .build()])
+        .unwrap();
+
+    std::fs::write(root.join("src/changed.rs"), "// edited\n").unwrap();
+    std::fs::write(root.join("src/added.rs"), "// new\n").unwrap();
+    std::fs::remove_file(root.join("src/removed.rs")).unwrap();
+    // A file in its soft-delete grace period is kept on purpose.
+    std::fs::remove_file(root.join("src/moved.rs")).unwrap();
+    db.mark_file_soft_deleted("src/moved.rs", 0).unwrap();
+    db.conn.execute_batch("PRAGMA foreign_keys = OFF").unwrap();
+    db.conn
+        .execute("DELETE FROM symbols WHERE id = 'helper'", [])
+        .unwrap();
+    db.conn.execute_batch("PRAGMA foreign_keys = ON").unwrap();
+
+    let plan = plan_repair(&db, root, true, false).unwrap();
+    assert_eq!(plan.modified_files, ["src/changed.rs"]);
+    assert_eq!(plan.new_files, ["src/added.rs"]);
+    assert_eq!(plan.deleted_files, ["src/removed.rs"]);
+    assert_eq!(plan.orphaned_relationships, 1);
+    assert_eq!(plan.orphaned_vectors, 0);
+    assert_eq!(
+        plan.missing_vectors.as_deref(),
+        Some("no vectors for 1 symbols")
+    );
+
+    // An embedding run in flight is not reported as missing vectors, and
+    // vectors are not checked at all when semantic search is off.
+    assert!(
+        plan_repair(&db, root, true, true)
+            .unwrap()
+            .missing_vectors
+            .is_none()
+    );
+    let without_vectors = plan_repair(&db, root, false, false).unwrap();
+    assert!(without_vectors.missing_vectors.is_none());
+    assert!(!without_vectors.is_clean());
+}
+
+#[test]
+fn repair_plan_is_clean_for_a_current_index() {
+    let workspace = TempDir::new().unwrap();
+    let root = workspace.path();
+    std::fs::write(root.join("main.rs"), "fn main() {}\n").unwrap();
+
+    let db_dir = TempDir::new().unwrap();
+    let db = SymbolDatabase::new(db_dir.path().join("symbols.db")).unwrap();
+    index_file(&db, root, "main.rs");
+
+    let plan = plan_repair(&db, root, true, false).unwrap();
+    assert!(plan.is_clean(), "{plan:?}");
+}
@@ -0,0 +1,97 @@
+//! End-to-end tests for `manage_workspace rollback_last_operation`.
+
+use crate::handler::JulieServerHandler;
+use crate::mcp_compat::CallToolResult;
+use crate::tests::helpers::workspace::mark_workspace_root;
+use crate::tools::editing::edit_file::{EditFileTool, EditOccurrence};
+use crate::tools::workspace::ManageWorkspaceTool;
+use anyhow::Result;
+use std::fs;
+use tempfile::TempDir;
+
+fn manage(operation: &str, path: Option<String>) -> ManageWorkspaceTool {
+    ManageWorkspaceTool {
+        operation: operation.to_string(),
+        workspace_id: None,
+        path,
+        name: None,
+        force: Some(false),
+        detailed: None,
+        from_generation: None,
+        to_generation: None,
+        dry_run: None,
+    }
+}
+
+fn extract_text(result: &CallToolResult) -> String {
+    result
+        .content
+        .iter()
+        .filter_map(|block| {
+            serde_json::to_value(block).ok().and_then(|json| {
+                json.get("text")
+                    .and_then(|value| value.as_str())
+                    .map(|text| text.to_string())
+            })
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+async fn symbol_names(handler: &JulieServerHandler, file_path: &str) -> Result<Vec<String>> {
+    let workspace_id = handler.require_primary_workspace_identity()?;
+    let db = handler.get_database_for_workspace(&workspace_id).await?;
+    let db = db.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+    let mut names: Vec<_> = db
+        .get_symbols_for_file(file_path)?
+        .into_iter()
+        .map(|symbol| symbol.name)
+        .collect();
+    names.sort();
+    Ok(names)
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn rollback_restores_the_file_and_reindexes_it_before_returning() -> Result<()> {
+    let temp_dir = TempDir::new()?;
+    mark_workspace_root(temp_dir.path());
+    fs::create_dir_all(temp_dir.path().join("src"))?;
+    let file_path = temp_dir.path().join("src/lib.rs");
+    let original = "pub fn before_edit() {}\n";
+    fs::write(&file_path, original)?;
+
+    let handler = JulieServerHandler::new(temp_dir.path().to_path_buf()).await?;
+    manage("index", Some(temp_dir.path().to_string_lossy().to_string()))
+        .call_tool(&handler)
+        .await?;
+    EditFileTool {
+        file_path: "src/lib.rs".to_string(),
+        old_text: "before_edit".to_string(),
+        new_text: "after_edit".to_string(),
+        workspace: Some("primary".to_string()),
+        dry_run: false,
+        occurrence: EditOccurrence::First,
+    }
+    .call_tool(&handler)
+    .await?;
+    manage("refresh", None).call_tool(&handler).await?;
+    assert_eq!(symbol_names(&handler, "src/lib.rs").await?, ["after_edit"]);
+
+    let result = manage("rollback_last_operation", None)
+        .call_tool(&handler)
+        .await?;
+    let text = extract_text(&result);
+
+    assert_eq!(fs::read_to_string(&file_path)?, original);
+    assert!(
+        text.contains("Reindexed them:"),
+        "rollback should report the reindexed symbol delta, got: {text}"
+    );
+    assert_eq!(symbol_names(&handler, "src/lib.rs").await?, ["before_edit"]);
+
+    let again = manage("rollback_last_operation", None)
+        .call_tool(&handler)
+        .await?;
+    assert!(extract_text(&again).contains("Nothing to roll back"));
+    Ok(())
+}
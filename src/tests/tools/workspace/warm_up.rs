@@ -0,0 +1,59 @@
+//! Warm-up batch selection for large index runs.
+
+use crate::tools::workspace::indexing::warm_up::{
+    WARM_UP_MIN_FILES, WarmUpCandidate, pick_warm_up, split_warm_up,
+};
+use std::path::PathBuf;
+use std::time::{Duration, SystemTime};
+use tempfile::TempDir;
+
+fn candidate(path: &str, age_secs: Option<u64>) -> WarmUpCandidate {
+    WarmUpCandidate {
+        relative_path: path.to_string(),
+        modified: age_secs.map(|age| SystemTime::UNIX_EPOCH + Duration::from_secs(10_000 - age)),
+    }
+}
+
+#[test]
+fn pick_warm_up_prefers_uncommitted_then_newest_files() {
+    let candidates = vec![
+        candidate("src/old.rs", Some(900)),
+        candidate("src/newest.rs", Some(1)),
+        candidate("src/edited.rs", Some(500)),
+        candidate("src/no_mtime.rs", None),
+        candidate("src/newer.rs", Some(10)),
+    ];
+    let uncommitted = vec!["src/edited.rs".to_string(), "deleted.rs".to_string()];
+
+    let chosen = pick_warm_up(&candidates, &uncommitted, 3);
+
+    let mut chosen: Vec<_> = chosen.into_iter().collect();
+    chosen.sort_unstable();
+    assert_eq!(chosen, vec![1, 2, 4]);
+}
+
+#[test]
+fn pick_warm_up_respects_the_limit_within_uncommitted_files() {
+    let candidates = vec![
+        candidate("a.rs", Some(1)),
+        candidate("b.rs", Some(2)),
+        candidate("c.rs", Some(3)),
+    ];
+    let uncommitted = vec!["c.rs".to_string(), "b.rs".to_string()];
+
+    let chosen = pick_warm_up(&candidates, &uncommitted, 1);
+
+    assert_eq!(chosen.into_iter().collect::<Vec<_>>(), vec![2]);
+}
+
+#[test]
+fn split_warm_up_leaves_small_runs_whole() {
+    let temp = TempDir::new().unwrap();
+    let files: Vec<PathBuf> = (0..WARM_UP_MIN_FILES - 1)
+        .map(|idx| temp.path().join(format!("f{idx}.rs")))
+        .collect();
+
+    let result = split_warm_up(temp.path(), files.clone());
+
+    assert_eq!(result, Err(files));
+}
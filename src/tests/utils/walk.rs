@@ -3,12 +3,18 @@
 // Verifies that build_walker correctly handles:
 // - .gitignore parsing (including nested)
 // - .julieignore support
+// - index.ignore from .julie/config.toml
+// - nested git repositories (index.nested_repositories)
 // - BLACKLISTED_DIRECTORIES filtering
 // - .git exclusion (always, even with hidden(false))
 // - Dotfile inclusion (e.g., .editorconfig)
 // - Non-git workspaces
 
-use crate::utils::walk::{WalkConfig, build_walker, try_build_single_path_walker};
+use crate::database::NestedRepository;
+use crate::utils::walk::{
+    WalkConfig, build_walker, find_nested_repositories, try_build_single_path_walker,
+};
+use julie_core::workspace_settings::WorkspaceSettingsRegistry;
 use std::fs;
 use tempfile::TempDir;
 
@@ -74,6 +80,96 @@ fn test_walk_respects_julieignore() {
     assert!(files.iter().any(|f| f.contains("app.rs")));
 }
 
+#[test]
+fn test_walk_respects_settings_index_ignore() {
+    let dir = TempDir::new().unwrap();
+    let root = dir.path();
+    fs::create_dir_all(root.join(".git")).unwrap();
+    fs::create_dir_all(root.join(".julie")).unwrap();
+    fs::write(
+        root.join(".julie/config.toml"),
+        "[index]\nignore = [\"generated/\", \"*.pb.rs\"]\n",
+    )
+    .unwrap();
+    fs::create_dir_all(root.join("generated")).unwrap();
+    fs::write(root.join("generated/api.rs"), "// generated").unwrap();
+    fs::create_dir_all(root.join("src")).unwrap();
+    fs::write(root.join("src/app.rs"), "fn main() {}").unwrap();
+    fs::write(root.join("src/messages.pb.rs"), "// protobuf").unwrap();
+
+    let files = collect_walked_files(root, &WalkConfig::full_index());
+    assert!(
+        !files
+            .iter()
+            .any(|f| f.contains("generated") || f.ends_with(".pb.rs")),
+        "index.ignore patterns should be excluded, got {files:?}"
+    );
+    assert!(files.iter().any(|f| f.contains("app.rs")));
+
+    let vendor_scan = collect_walked_files(root, &WalkConfig::vendor_scan());
+    assert!(
+        vendor_scan.iter().any(|f| f.contains("generated")),
+        "vendor scan does not apply user exclusions"
+    );
+}
+
+#[test]
+fn test_walk_finds_and_optionally_skips_nested_repositories() {
+    let dir = TempDir::new().unwrap();
+    let root = dir.path();
+    fs::create_dir_all(root.join(".git")).unwrap();
+    fs::write(root.join("app.rs"), "fn main() {}").unwrap();
+    // A submodule's `.git` is a file; a plain clone's is a directory.
+    fs::create_dir_all(root.join("libs/parser/src")).unwrap();
+    fs::write(
+        root.join("libs/parser/.git"),
+        "gitdir: ../../.git/modules/parser\n",
+    )
+    .unwrap();
+    fs::write(root.join("libs/parser/src/lib.rs"), "pub fn parse() {}").unwrap();
+    fs::create_dir_all(root.join("tools/linter/.git")).unwrap();
+    fs::write(root.join("tools/linter/lint.py"), "pass").unwrap();
+
+    assert_eq!(
+        find_nested_repositories(root),
+        [
+            NestedRepository {
+                root: "libs/parser".to_string(),
+                submodule: true,
+            },
+            NestedRepository {
+                root: "tools/linter".to_string(),
+                submodule: false,
+            },
+        ]
+    );
+    let files = collect_walked_files(root, &WalkConfig::full_index());
+    assert!(
+        files.iter().any(|f| f == "libs/parser/src/lib.rs"),
+        "nested repositories are indexed by default, got {files:?}"
+    );
+
+    fs::create_dir_all(root.join(".julie")).unwrap();
+    fs::write(
+        root.join(".julie/config.toml"),
+        "[index]\nnested_repositories = false\n",
+    )
+    .unwrap();
+    WorkspaceSettingsRegistry::global().reload(root).unwrap();
+    let files = collect_walked_files(root, &WalkConfig::full_index());
+    assert_eq!(files, ["app.rs"], "nested repositories are skipped");
+    assert!(!single_path_walker_includes(
+        root,
+        &root.join("libs/parser/src/lib.rs"),
+        &WalkConfig::full_index()
+    ));
+    assert!(single_path_walker_includes(
+        root,
+        &root.join("app.rs"),
+        &WalkConfig::full_index()
+    ));
+}
+
 #[test]
 fn test_walk_vendor_scan_skips_gitignored_but_not_blacklisted() {
     let dir = TempDir::new().unwrap();
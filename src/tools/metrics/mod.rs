@@ -1,3 +1,4 @@
 //! Metrics helpers for dashboard rendering and session tracking.
 
 pub mod session;
+pub mod session_context;
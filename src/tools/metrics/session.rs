@@ -2,8 +2,14 @@
 //!
 //! Pre-allocated at handler construction, zero-allocation on the hot path.
 //! Indexed by ToolKind ordinal for O(1) per-tool counter access.
+//!
+//! The symbols and files that tool calls targeted are counted separately in a
+//! [`SessionHeatmap`] behind a mutex, so `session_context` can list what the
+//! session has been working on after an agent's context is truncated.
 
+use std::collections::HashMap;
 use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Mutex, MutexGuard};
 use std::time::Instant;
 
 /// Per-tool atomic counters. Default-initialized to zero.
@@ -148,6 +154,116 @@ pub fn extract_source_paths(text: &str) -> Vec<String> {
     paths
 }
 
+/// Distinct symbols (and files) tracked per session. Past the cap, the
+/// coldest, least recent target is forgotten to make room.
+pub const MAX_TRACKED_TARGETS: usize = 512;
+
+/// How often one symbol or file was the target of a successful tool call.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TargetHeat {
+    pub target: String,
+    /// For symbols: the file hint of the latest call that gave one.
+    pub file_path: Option<String>,
+    pub calls: u64,
+    /// Tools that targeted it, in first-use order.
+    pub tools: Vec<String>,
+    /// Sequence number of the latest call; higher is more recent.
+    pub last_seen: u64,
+}
+
+/// Symbol and file targets of a session's tool calls, read from the
+/// `target` object of each call's metadata (see `handler::tool_targets`).
+/// A call naming a symbol counts for the symbol; a call naming only a file
+/// counts for the file.
+#[derive(Debug, Default)]
+pub struct SessionHeatmap {
+    symbols: HashMap<String, TargetHeat>,
+    files: HashMap<String, TargetHeat>,
+    sequence: u64,
+}
+
+impl SessionHeatmap {
+    pub fn record(&mut self, tool_name: &str, target: &serde_json::Value) {
+        let field = |key: &str| {
+            target
+                .get(key)
+                .and_then(serde_json::Value::as_str)
+                .map(str::trim)
+                .filter(|value| !value.is_empty())
+        };
+        let symbol = field("target_symbol_name");
+        let file = field("target_file_path");
+        let (map, key, file_path) = match (symbol, file) {
+            (Some(symbol), file) => (&mut self.symbols, symbol, file),
+            (None, Some(file)) => (&mut self.files, file, None),
+            (None, None) => return,
+        };
+        self.sequence += 1;
+        let sequence = self.sequence;
+        if !map.contains_key(key) && map.len() >= MAX_TRACKED_TARGETS {
+            let coldest = map
+                .values()
+                .min_by_key(|heat| (heat.calls, heat.last_seen))
+                .map(|heat| heat.target.clone());
+            if let Some(coldest) = coldest {
+                map.remove(&coldest);
+            }
+        }
+        let heat = map.entry(key.to_string()).or_insert_with(|| TargetHeat {
+            target: key.to_string(),
+            file_path: None,
+            calls: 0,
+            tools: Vec::new(),
+            last_seen: 0,
+        });
+        heat.calls += 1;
+        heat.last_seen = sequence;
+        if let Some(file_path) = file_path {
+            heat.file_path = Some(file_path.to_string());
+        }
+        if !heat.tools.iter().any(|tool| tool == tool_name) {
+            heat.tools.push(tool_name.to_string());
+        }
+    }
+
+    /// Most-targeted symbols, most calls first, ties broken by recency.
+    pub fn hot_symbols(&self, limit: usize) -> Vec<TargetHeat> {
+        hottest(&self.symbols, limit)
+    }
+
+    /// Most-targeted files that were not looked at through a symbol.
+    pub fn hot_files(&self, limit: usize) -> Vec<TargetHeat> {
+        hottest(&self.files, limit)
+    }
+
+    /// Symbols by their latest call, newest first.
+    pub fn recent_symbols(&self, limit: usize) -> Vec<TargetHeat> {
+        let mut recent: Vec<TargetHeat> = self.symbols.values().cloned().collect();
+        recent.sort_by(|a, b| b.last_seen.cmp(&a.last_seen));
+        recent.truncate(limit);
+        recent
+    }
+
+    pub fn symbol_count(&self) -> usize {
+        self.symbols.len()
+    }
+
+    pub fn file_count(&self) -> usize {
+        self.files.len()
+    }
+}
+
+fn hottest(map: &HashMap<String, TargetHeat>, limit: usize) -> Vec<TargetHeat> {
+    let mut hot: Vec<TargetHeat> = map.values().cloned().collect();
+    hot.sort_by(|a, b| {
+        b.calls
+            .cmp(&a.calls)
+            .then_with(|| b.last_seen.cmp(&a.last_seen))
+    });
+    hot.truncate(limit);
+    hot
+}
+
 /// Session-wide metrics. Wrapped in Arc on the handler.
 pub struct SessionMetrics {
     pub session_id: String,
@@ -157,6 +273,7 @@ pub struct SessionMetrics {
     pub total_source_bytes: AtomicU64,
     pub total_output_bytes: AtomicU64,
     pub per_tool: [ToolCounters; ToolKind::COUNT],
+    heatmap: Mutex<SessionHeatmap>,
 }
 
 impl SessionMetrics {
@@ -169,6 +286,7 @@ impl SessionMetrics {
             total_source_bytes: AtomicU64::new(0),
             total_output_bytes: AtomicU64::new(0),
             per_tool: std::array::from_fn(|_| ToolCounters::default()),
+            heatmap: Mutex::new(SessionHeatmap::default()),
         }
     }
 
@@ -191,6 +309,19 @@ impl SessionMetrics {
             .fetch_add(output_bytes, Ordering::Relaxed);
     }
 
+    /// Count the symbol or file in `metadata["target"]` of a tool call.
+    pub fn record_target(&self, tool_name: &str, metadata: &serde_json::Value) {
+        if let Some(target) = metadata.get("target") {
+            self.heatmap().record(tool_name, target);
+        }
+    }
+
+    pub fn heatmap(&self) -> MutexGuard<'_, SessionHeatmap> {
+        self.heatmap
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
     pub fn total_calls(&self) -> u64 {
         self.total_calls.load(Ordering::Relaxed)
     }
@@ -0,0 +1,101 @@
+//! `session_context`: the symbols and files this session's tool calls kept
+//! coming back to.
+//!
+//! After an agent's context is truncated it has lost track of what it was
+//! working on. The hot set from [`SessionHeatmap`] answers that in one call,
+//! without re-running the searches that found those symbols.
+
+use anyhow::Result;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::handler::JulieServerHandler;
+use crate::mcp_compat::{CallToolResult, CallToolResultExt, Content};
+use crate::tools::metrics::session::{SessionHeatmap, SessionMetrics, TargetHeat};
+
+/// Symbols and files listed when `limit` is not given.
+const DEFAULT_LIMIT: u32 = 10;
+
+/// Symbols named on the "Last touched" line.
+const RECENT_SYMBOLS: usize = 5;
+
+fn default_limit() -> u32 {
+    DEFAULT_LIMIT
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
+pub struct SessionContextTool {
+    /// Most symbols and most files listed (default 10)
+    #[serde(
+        default = "default_limit",
+        deserialize_with = "crate::utils::serde_lenient::deserialize_u32_lenient"
+    )]
+    pub limit: u32,
+}
+
+impl SessionContextTool {
+    pub async fn call_tool(&self, handler: &JulieServerHandler) -> Result<CallToolResult> {
+        let text = self.render(&handler.session_metrics);
+        Ok(CallToolResult::text_content(vec![Content::text(text)]))
+    }
+
+    pub fn render(&self, metrics: &SessionMetrics) -> String {
+        let heatmap = metrics.heatmap();
+        let minutes = metrics.session_start.elapsed().as_secs() / 60;
+        let header = format!(
+            "Session: {} tool calls over {minutes}m",
+            metrics.total_calls()
+        );
+        if heatmap.symbol_count() == 0 && heatmap.file_count() == 0 {
+            return format!(
+                "{header}\nNo symbol or file has been looked up yet. Start with fast_search or get_context"
+            );
+        }
+        render_heatmap(&header, &heatmap, self.limit as usize)
+    }
+}
+
+fn render_heatmap(header: &str, heatmap: &SessionHeatmap, limit: usize) -> String {
+    let mut lines = vec![header.to_string()];
+    let symbols = heatmap.hot_symbols(limit);
+    if !symbols.is_empty() {
+        lines.push(String::new());
+        lines.push(format!(
+            "Hot symbols ({} of {}):",
+            symbols.len(),
+            heatmap.symbol_count()
+        ));
+        lines.extend(symbols.iter().map(heat_line));
+        let recent: Vec<String> = heatmap
+            .recent_symbols(RECENT_SYMBOLS)
+            .into_iter()
+            .map(|heat| heat.target)
+            .collect();
+        lines.push(format!("Last touched: {}", recent.join(", ")));
+    }
+    let files = heatmap.hot_files(limit);
+    if !files.is_empty() {
+        lines.push(String::new());
+        lines.push(format!(
+            "Hot files ({} of {}):",
+            files.len(),
+            heatmap.file_count()
+        ));
+        lines.extend(files.iter().map(heat_line));
+    }
+    lines.join("\n")
+}
+
+fn heat_line(heat: &TargetHeat) -> String {
+    let file = heat
+        .file_path
+        .as_deref()
+        .map(|file| format!(" ({file})"))
+        .unwrap_or_default();
+    format!(
+        "  {}{file} ×{} via {}",
+        heat.target,
+        heat.calls,
+        heat.tools.join(", ")
+    )
+}
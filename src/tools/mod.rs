@@ -11,28 +11,46 @@ pub mod workspace; // Workspace management and indexing
 // The 9 extracted tool modules — re-exported from julie_tools so sub-paths work:
 //   crate::tools::search::FastSearchTool  →  julie_tools::search::FastSearchTool
 //   crate::tools::navigation::resolution::WorkspaceTarget  →  (and so on)
+pub use julie_tools::audit;
+pub use julie_tools::context_pack;
+pub use julie_tools::counterparts;
 pub use julie_tools::deep_dive;
 pub use julie_tools::editing;
+pub use julie_tools::file_annotations;
 pub use julie_tools::get_context;
 pub use julie_tools::impact;
+pub use julie_tools::imports;
 pub use julie_tools::navigation;
 pub use julie_tools::patterns;
 pub use julie_tools::refactoring;
 pub use julie_tools::search;
 pub use julie_tools::shared;
+pub use julie_tools::signature;
 pub use julie_tools::spillover;
+pub use julie_tools::streaming;
 pub use julie_tools::symbols;
+pub use julie_tools::syntax_query;
+pub use julie_tools::task_status;
 
 // Re-export all tools for external use (backward compat)
+pub use audit::AuditTool;
+pub use context_pack::GetContextPackTool;
+pub use counterparts::FastCounterpartsTool;
 pub use deep_dive::{DeepDiveDepth, DeepDiveTool};
+pub use file_annotations::GetFileAnnotationsTool;
 pub use get_context::GetContextTool;
 pub use impact::BlastRadiusTool;
+pub use imports::FastImportsTool;
 pub use navigation::{CallPathTool, FastRefsTool};
 pub use patterns::{PatternsFormat, PatternsGroupBy, PatternsOperation, PatternsTool};
 pub use refactoring::RenameSymbolTool;
 pub use search::FastSearchTool;
+pub use signature::FastSignatureTool;
 pub use spillover::SpilloverGetTool;
 pub use symbols::GetSymbolsTool;
+pub use syntax_query::SyntaxQueryTool;
+pub use task_status::TaskStatusTool;
+pub use metrics::session_context::SessionContextTool;
 pub use workspace::ManageWorkspaceTool;
 
 // Re-export shared types and helpers
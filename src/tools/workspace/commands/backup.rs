@@ -0,0 +1,193 @@
+use std::path::{Component, Path, PathBuf};
+
+use super::ManageWorkspaceTool;
+use crate::handler::JulieServerHandler;
+use crate::mcp_compat::{CallToolResult, CallToolResultExt, Content};
+use crate::paths::RegistryPaths;
+use anyhow::{Context, Result};
+use julie_core::background_tasks::{BackgroundTaskKind, BackgroundTaskRegistry};
+use tracing::info;
+
+impl ManageWorkspaceTool {
+    /// Snapshot a workspace's SQLite database (symbols, relationships and
+    /// embeddings) and its Tantivy index while the server keeps serving.
+    ///
+    /// The snapshot mirrors the index directory layout (`db/symbols.db`,
+    /// `tantivy/`), so restoring is copying it back over
+    /// `indexes/<workspace_id>/` with the server stopped. With an index key
+    /// set the database copy stays encrypted and the Tantivy index, which
+    /// then only lives in memory, is left out and rebuilt on restore.
+    ///
+    /// Backups go under `$JULIE_HOME/backups/<workspace_id>/` unless `path`
+    /// names another absolute directory outside the workspace tree.
+    pub(crate) async fn handle_backup_command(
+        &self,
+        handler: &JulieServerHandler,
+        workspace_id: Option<String>,
+        path: Option<String>,
+    ) -> Result<CallToolResult> {
+        let workspace_id = match workspace_id.filter(|id| id != "primary") {
+            Some(id) => id,
+            None => match handler.require_primary_workspace_identity() {
+                Ok(id) => id,
+                Err(e) => {
+                    return Ok(CallToolResult::error(vec![Content::text(e.to_string())]));
+                }
+            },
+        };
+        info!("Backing up workspace index: {}", workspace_id);
+
+        let target = match path {
+            Some(path) => {
+                let target = PathBuf::from(path);
+                let workspace_root =
+                    match handler.get_workspace_root_for_target(&workspace_id).await {
+                        Ok(root) => root,
+                        Err(e) => {
+                            return Ok(CallToolResult::error(vec![Content::text(e.to_string())]));
+                        }
+                    };
+                if let Some(message) = backup_target_error(&target, &workspace_root) {
+                    return Ok(CallToolResult::error(vec![Content::text(message)]));
+                }
+                target
+            }
+            None => default_backup_dir(&RegistryPaths::try_new()?.julie_home(), &workspace_id),
+        };
+        if target.exists() {
+            let message = format!("Backup target already exists: {}", target.display());
+            return Ok(CallToolResult::error(vec![Content::text(message)]));
+        }
+
+        let db = match handler
+            .get_pooled_database_for_workspace(&workspace_id)
+            .await
+        {
+            Ok(db) => db,
+            Err(e) => {
+                return Ok(CallToolResult::error(vec![Content::text(e.to_string())]));
+            }
+        };
+        let encrypted = julie_core::database::encryption::index_key_configured();
+        let search_index = if encrypted {
+            None
+        } else {
+            handler
+                .get_search_index_for_workspace(&workspace_id)
+                .await?
+        };
+
+        // Database first: the projection state it records can then only lag
+        // the Tantivy copy, and catch-up re-projection is idempotent. The
+        // reverse order could claim documents the Tantivy copy lacks.
+        let task = BackgroundTaskRegistry::global().register(
+            BackgroundTaskKind::Maintenance,
+            workspace_id.clone(),
+            "index backup",
+            false,
+        );
+        task.set_total(2);
+        let target_for_copy = target.clone();
+        let (db_bytes, tantivy_bytes) = tokio::task::spawn_blocking(move || {
+            let db_dir = target_for_copy.join("db");
+            std::fs::create_dir_all(&db_dir)
+                .with_context(|| format!("failed to create {}", db_dir.display()))?;
+            task.set_detail("copying database");
+            let db_bytes = db.backup_to(&db_dir.join("symbols.db"))?;
+            task.advance(1);
+
+            task.set_detail("copying search index");
+            let tantivy_bytes = match search_index {
+                Some(index) => Some(index.snapshot_to(&target_for_copy.join("tantivy"))?),
+                None => None,
+            };
+            Ok::<_, anyhow::Error>((db_bytes, tantivy_bytes))
+        })
+        .await??;
+
+        let tantivy_line = match tantivy_bytes {
+            Some(bytes) => format!("Search index: {}", format_megabytes(bytes)),
+            None if encrypted => {
+                "Search index: kept in memory while encrypted (skipped, rebuilt on restore)"
+                    .to_string()
+            }
+            None => "Search index: not built yet (skipped)".to_string(),
+        };
+        let message = format!(
+            "Backup complete: {}\n\n\
+             Location: {}\n\
+             Database (incl. embeddings): {}\n\
+             {}",
+            workspace_id,
+            target.display(),
+            format_megabytes(db_bytes),
+            tantivy_line,
+        );
+        Ok(CallToolResult::text_content(vec![Content::text(message)]))
+    }
+}
+
+/// Why `target` cannot hold a backup of the workspace at `workspace_root`:
+/// a relative path would resolve against wherever the server was started,
+/// and a path inside the workspace would be watched and indexed as source.
+pub(crate) fn backup_target_error(target: &Path, workspace_root: &Path) -> Option<String> {
+    if !target.is_absolute() {
+        return Some(format!(
+            "Backup path must be absolute: {}",
+            target.display()
+        ));
+    }
+    if target
+        .components()
+        .any(|component| component == Component::ParentDir)
+    {
+        return Some(format!(
+            "Backup path must not contain '..': {}",
+            target.display()
+        ));
+    }
+    let workspace_root = workspace_root
+        .canonicalize()
+        .unwrap_or_else(|_| workspace_root.to_path_buf());
+    if resolve_existing_prefix(target).starts_with(&workspace_root) {
+        return Some(format!(
+            "Backup path must be outside the workspace ({}): {}",
+            workspace_root.display(),
+            target.display()
+        ));
+    }
+    None
+}
+
+/// `path` with its longest existing prefix canonicalized, so a symlinked
+/// parent cannot hide that a not-yet-created target lies in the workspace.
+fn resolve_existing_prefix(path: &Path) -> PathBuf {
+    let mut existing = path;
+    let mut missing = Vec::new();
+    while !existing.exists() {
+        match (existing.parent(), existing.file_name()) {
+            (Some(parent), Some(name)) => {
+                missing.push(name);
+                existing = parent;
+            }
+            _ => break,
+        }
+    }
+    let mut resolved = existing
+        .canonicalize()
+        .unwrap_or_else(|_| existing.to_path_buf());
+    resolved.extend(missing.iter().rev());
+    resolved
+}
+
+/// `<julie home>/backups/<workspace_id>/<UTC timestamp>`.
+fn default_backup_dir(julie_home: &Path, workspace_id: &str) -> PathBuf {
+    julie_home
+        .join("backups")
+        .join(workspace_id)
+        .join(chrono::Utc::now().format("%Y%m%dT%H%M%SZ").to_string())
+}
+
+fn format_megabytes(bytes: u64) -> String {
+    format!("{:.1} MB", bytes as f64 / (1024.0 * 1024.0))
+}
@@ -0,0 +1,206 @@
+use super::ManageWorkspaceTool;
+use crate::analysis::grpc_links::{GrpcStubCoverage, grpc_stub_coverage};
+use crate::handler::JulieServerHandler;
+use crate::mcp_compat::{CallToolResult, CallToolResultExt, Content};
+use anyhow::Result;
+use julie_core::workspace::build_state::{
+    ArtifactState, EcosystemBuildState, is_bare_clone, probe_build_state,
+};
+use julie_core::workspace_settings::{SEMANTIC_DISABLED_REASON, WorkspaceSettingsRegistry};
+use julie_pipeline::indexing_core::manifest_dependencies::DEPENDENCY_SEMANTIC_GROUP;
+use tracing::info;
+
+/// What an indexed workspace can answer, and what needs a build first.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct WorkspaceCapabilities {
+    pub workspace_id: String,
+    pub build_state: Vec<EcosystemBuildState>,
+    pub files: i64,
+    pub symbols: i64,
+    pub relationships_by_kind: Vec<(String, i64)>,
+    pub dependency_declarations: usize,
+    pub external_library_calls: usize,
+    pub cross_language_links: i64,
+    pub grpc: GrpcStubCoverage,
+    pub semantic_disabled: bool,
+}
+
+impl ManageWorkspaceTool {
+    /// Report which relationship types a workspace provides from source alone
+    /// and which need a build or dependency install (bare-clone onboarding).
+    pub(crate) async fn handle_capabilities_command(
+        &self,
+        handler: &JulieServerHandler,
+        workspace_id: Option<String>,
+    ) -> Result<CallToolResult> {
+        let workspace_id = match workspace_id.filter(|id| id != "primary") {
+            Some(id) => id,
+            None => match handler.require_primary_workspace_identity() {
+                Ok(id) => id,
+                Err(e) => {
+                    return Ok(CallToolResult::error(vec![Content::text(e.to_string())]));
+                }
+            },
+        };
+        info!("Reporting workspace capabilities: {}", workspace_id);
+
+        let root = handler.get_workspace_root_for_target(&workspace_id).await?;
+        let semantic_disabled = WorkspaceSettingsRegistry::global()
+            .get(&root)
+            .semantic_disabled();
+        let db = match handler
+            .get_pooled_database_for_workspace(&workspace_id)
+            .await
+        {
+            Ok(db) => db,
+            Err(e) => {
+                return Ok(CallToolResult::error(vec![Content::text(e.to_string())]));
+            }
+        };
+
+        let capabilities = tokio::task::spawn_blocking(move || {
+            let files = db
+                .count_files_by_language()?
+                .iter()
+                .map(|(_, count)| count)
+                .sum();
+            Ok::<_, anyhow::Error>(WorkspaceCapabilities {
+                workspace_id,
+                build_state: probe_build_state(&root),
+                files,
+                symbols: db.get_symbol_count_for_workspace()?,
+                relationships_by_kind: db.count_relationships_by_kind()?,
+                dependency_declarations: db
+                    .get_symbols_by_semantic_group(DEPENDENCY_SEMANTIC_GROUP)?
+                    .len(),
+                external_library_calls: db
+                    .external_module_call_counts()?
+                    .iter()
+                    .map(|count| count.calls)
+                    .sum(),
+                cross_language_links: db.cross_language_link_count()?,
+                grpc: grpc_stub_coverage(&db)?,
+                semantic_disabled,
+            })
+        })
+        .await??;
+
+        Ok(CallToolResult::text_content(vec![Content::text(
+            capabilities.render(),
+        )]))
+    }
+}
+
+impl WorkspaceCapabilities {
+    pub(crate) fn render(&self) -> String {
+        let bare = is_bare_clone(&self.build_state);
+        let mode = if bare {
+            "no-build (bare clone: no dependency install or build output found)"
+        } else if self
+            .build_state
+            .iter()
+            .any(EcosystemBuildState::has_local_artifacts)
+        {
+            "built (dependency install or build output present)"
+        } else {
+            "source-only (no local build artifacts to check)"
+        };
+        let mut out = format!(
+            "Workspace capabilities: {}\nMode: {}\n",
+            self.workspace_id, mode
+        );
+
+        if !self.build_state.is_empty() {
+            out.push_str("\nBuild state:\n");
+            for state in &self.build_state {
+                out.push_str(&format!(
+                    "  {} ({}): dependencies {}, build outputs {}\n",
+                    state.ecosystem,
+                    state.manifest,
+                    state.dependencies.as_str(),
+                    state.build_outputs.as_str()
+                ));
+            }
+        }
+
+        out.push_str("\nAvailable from source (no build needed):\n");
+        out.push_str(&format!("  Files indexed: {}\n", self.files));
+        out.push_str(&format!("  Symbols: {}\n", self.symbols));
+        let relationships = if self.relationships_by_kind.is_empty() {
+            "none".to_string()
+        } else {
+            self.relationships_by_kind
+                .iter()
+                .map(|(kind, count)| format!("{kind} {count}"))
+                .collect::<Vec<_>>()
+                .join(", ")
+        };
+        out.push_str(&format!("  Relationships: {relationships}\n"));
+        out.push_str(&format!(
+            "  Manifest dependencies: {}\n",
+            self.dependency_declarations
+        ));
+        out.push_str(&format!(
+            "  External library calls (attributed from imports): {}\n",
+            self.external_library_calls
+        ));
+        out.push_str(&format!(
+            "  Cross-language links: {}\n",
+            self.cross_language_links
+        ));
+
+        let mut unavailable = Vec::new();
+        if self.grpc.proto_services > 0 && self.grpc.generated_stub_files == 0 {
+            unavailable.push(format!(
+                "gRPC stub links: {} proto service(s), no generated stubs in the source tree. \
+                 Run code generation (protoc / buf generate) into the source tree, then refresh",
+                self.grpc.proto_services
+            ));
+        }
+        if bare {
+            unavailable.push(
+                "Symbols in generated code (go generate, protoc, GraphQL/OpenAPI codegen): \
+                 indexed once generation writes them inside the source tree"
+                    .to_string(),
+            );
+        }
+        out.push_str("\nUnavailable until build or dependency install:\n");
+        if unavailable.is_empty() {
+            out.push_str("  none\n");
+        }
+        for line in unavailable {
+            out.push_str(&format!("  {line}\n"));
+        }
+
+        if self.semantic_disabled {
+            out.push_str(&format!(
+                "\nDisabled by workspace settings:\n  \
+                 Semantic and hybrid search, embeddings: {SEMANTIC_DISABLED_REASON}\n"
+            ));
+        }
+
+        out.push_str(
+            "\nNever indexed (with or without a build):\n  \
+             Dependency sources (node_modules, vendor, virtualenvs) and build output \
+             directories are excluded. Calls into libraries are attributed by import, \
+             not resolved to definitions\n",
+        );
+
+        let setup: Vec<&EcosystemBuildState> = self
+            .build_state
+            .iter()
+            .filter(|state| {
+                state.dependencies == ArtifactState::Missing
+                    || state.build_outputs == ArtifactState::Missing
+            })
+            .collect();
+        if !setup.is_empty() {
+            out.push_str("\nTo enable build-dependent features:\n");
+            for state in setup {
+                out.push_str(&format!("  {}: {}\n", state.ecosystem, state.setup_hint));
+            }
+        }
+
+        out.trim_end().to_string()
+    }
+}
@@ -0,0 +1,81 @@
+use super::ManageWorkspaceTool;
+use crate::handler::JulieServerHandler;
+use crate::mcp_compat::{CallToolResult, CallToolResultExt, Content};
+use anyhow::{Result, anyhow};
+use julie_core::database::{EmbeddingJobRecord, EmbeddingJobStatus};
+use std::sync::atomic::Ordering;
+use tracing::info;
+
+impl ManageWorkspaceTool {
+    /// Stop the workspace's background embedding run at its next batch
+    /// boundary.
+    ///
+    /// The pipeline publishes what it embedded so far and records the job as
+    /// cancelled; the next `index` or `refresh` resumes from that checkpoint
+    /// instead of starting over. Search keeps working meanwhile, with
+    /// semantic results limited to the symbols already embedded.
+    pub(crate) async fn handle_cancel_embeddings_command(
+        &self,
+        handler: &JulieServerHandler,
+        workspace_id: Option<String>,
+    ) -> Result<CallToolResult> {
+        let workspace_id = match workspace_id.filter(|id| id != "primary") {
+            Some(id) => id,
+            None => match handler.require_primary_workspace_identity() {
+                Ok(id) => id,
+                Err(e) => {
+                    return Ok(CallToolResult::error(vec![Content::text(e.to_string())]));
+                }
+            },
+        };
+
+        // Only raise the flag: the pipeline checks it between batches and
+        // checkpoints before returning, which aborting the handle would skip.
+        let signalled = {
+            let tasks = handler.embedding_tasks.lock().await;
+            match tasks.get(&workspace_id) {
+                Some((cancel_flag, handle)) if !handle.is_finished() => {
+                    cancel_flag.store(true, Ordering::Release);
+                    true
+                }
+                _ => false,
+            }
+        };
+
+        let db = handler.get_database_for_workspace(&workspace_id).await?;
+        let job = tokio::task::spawn_blocking(move || {
+            let db = db.lock().map_err(|_| anyhow!("database lock poisoned"))?;
+            db.embedding_job()
+        })
+        .await??;
+
+        let message = if signalled {
+            info!("Cancelling embedding pipeline for workspace {workspace_id}");
+            format!(
+                "Cancelling embedding for {workspace_id}; it stops after the current batch.\n{}\n\
+                 The next index or refresh resumes from the last checkpoint.",
+                describe_job(job.as_ref()),
+            )
+        } else {
+            format!(
+                "No embedding run is active for {workspace_id}.\n{}",
+                describe_job(job.as_ref()),
+            )
+        };
+        Ok(CallToolResult::text_content(vec![Content::text(message)]))
+    }
+}
+
+fn describe_job(job: Option<&EmbeddingJobRecord>) -> String {
+    let Some(job) = job else {
+        return "No embedding job has run in this workspace yet.".to_string();
+    };
+    let progress = format!("{}/{} symbols", job.completed, job.total);
+    match job.status {
+        EmbeddingJobStatus::Running => format!("Progress: {progress} embedded."),
+        EmbeddingJobStatus::Complete => format!("Last job: complete ({progress})."),
+        status => {
+            format!("Last job: {status} at {progress}; the next index or refresh resumes it.")
+        }
+    }
+}
@@ -0,0 +1,104 @@
+use super::ManageWorkspaceTool;
+use crate::database::{GenerationDiff, RevisionSymbolChange};
+use crate::handler::JulieServerHandler;
+use crate::mcp_compat::{CallToolResult, CallToolResultExt, Content};
+use anyhow::{Result, anyhow};
+
+/// Symbols listed per section before the rest are summarized as a count.
+const MAX_LISTED_CHANGES: usize = 25;
+
+impl ManageWorkspaceTool {
+    /// Diff the symbol sets of two index generations (canonical revisions).
+    ///
+    /// `to_generation` defaults to the current one and `from_generation` to
+    /// the one before it, so a bare call answers "what did the last index
+    /// write change?".
+    pub(crate) async fn handle_compare_generations_command(
+        &self,
+        handler: &JulieServerHandler,
+        workspace_id: Option<String>,
+        from_generation: Option<i64>,
+        to_generation: Option<i64>,
+    ) -> Result<CallToolResult> {
+        let workspace_id = match workspace_id.filter(|id| id != "primary") {
+            Some(id) => id,
+            None => match handler.require_primary_workspace_identity() {
+                Ok(id) => id,
+                Err(e) => {
+                    return Ok(CallToolResult::error(vec![Content::text(e.to_string())]));
+                }
+            },
+        };
+        let db = handler.get_database_for_workspace(&workspace_id).await?;
+
+        let query_workspace_id = workspace_id.clone();
+        let outcome = tokio::task::spawn_blocking(move || {
+            let db = db.lock().map_err(|_| anyhow!("database lock poisoned"))?;
+            let Some(latest) = db.get_current_canonical_revision(&query_workspace_id)? else {
+                return Ok(None);
+            };
+            let oldest = db
+                .oldest_comparable_generation(&query_workspace_id)?
+                .unwrap_or(latest);
+            let to = to_generation.unwrap_or(latest);
+            let from = from_generation.unwrap_or_else(|| (to - 1).max(oldest));
+            let diff = db.compare_generations(&query_workspace_id, from, to);
+            Ok::<_, anyhow::Error>(Some((diff, oldest, latest)))
+        })
+        .await??;
+
+        let Some((diff, oldest, latest)) = outcome else {
+            let message = format!("No index generations recorded for {workspace_id} yet");
+            return Ok(CallToolResult::text_content(vec![Content::text(message)]));
+        };
+        let diff = match diff {
+            Ok(diff) => diff,
+            Err(e) => {
+                let message = format!("{e}\nComparable generations: {oldest}..={latest}");
+                return Ok(CallToolResult::error(vec![Content::text(message)]));
+            }
+        };
+
+        let message = format_generation_diff(&diff, oldest, latest);
+        Ok(CallToolResult::text_content(vec![Content::text(message)]))
+    }
+}
+
+fn format_generation_diff(diff: &GenerationDiff, oldest: i64, latest: i64) -> String {
+    let mut out = format!(
+        "Symbol changes from generation {} to {}: {} added, {} removed, {} modified\n\
+         Comparable generations: {}..={}",
+        diff.from_revision,
+        diff.to_revision,
+        diff.added.len(),
+        diff.removed.len(),
+        diff.modified.len(),
+        oldest,
+        latest,
+    );
+    for (label, changes) in [
+        ("Added", &diff.added),
+        ("Removed", &diff.removed),
+        ("Modified", &diff.modified),
+    ] {
+        if changes.is_empty() {
+            continue;
+        }
+        out.push_str(&format!("\n\n{label}:"));
+        let mut sorted: Vec<&RevisionSymbolChange> = changes.iter().collect();
+        sorted.sort_by(|a, b| (&a.file_path, &a.name).cmp(&(&b.file_path, &b.name)));
+        for change in sorted.iter().take(MAX_LISTED_CHANGES) {
+            out.push_str(&format!(
+                "\n  {} ({}) {}",
+                change.name, change.kind, change.file_path
+            ));
+        }
+        if sorted.len() > MAX_LISTED_CHANGES {
+            out.push_str(&format!(
+                "\n  ... and {} more",
+                sorted.len() - MAX_LISTED_CHANGES
+            ));
+        }
+    }
+    out
+}
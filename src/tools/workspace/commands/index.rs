@@ -381,6 +381,10 @@ impl ManageWorkspaceTool {
                 if let Some(canonical_revision) = result.canonical_revision {
                     message.push_str(&format!("\nCanonical revision: {}", canonical_revision));
                 }
+                if let Some(report) = &result.branch_switch {
+                    message.push('\n');
+                    message.push_str(&report.summary());
+                }
                 if let Some(ws_id) = indexed_workspace_id {
                     let skip_embedding_pipeline = skip_embeddings && !effective_force_reindex;
                     if skip_embedding_pipeline {
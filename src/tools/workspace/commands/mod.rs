@@ -6,10 +6,16 @@ use tracing::info;
 
 use crate::handler::JulieServerHandler;
 
+pub(crate) mod backup;
+pub(crate) mod capabilities;
 mod dashboard;
+mod embeddings;
 pub(crate) mod force_safeguards;
+mod generations;
 mod index;
 pub(crate) mod registry;
+pub(crate) mod repair;
+mod rollback;
 
 //******************//
 // Workspace Management Commands //
@@ -27,6 +33,12 @@ pub(crate) enum ManageWorkspaceOperation {
     Stats,
     Health,
     Dashboard,
+    Backup,
+    Capabilities,
+    RollbackLastOperation,
+    CompareGenerations,
+    CancelEmbeddings,
+    Repair,
 }
 
 impl ManageWorkspaceOperation {
@@ -44,6 +56,12 @@ impl ManageWorkspaceOperation {
         ("open", Self::Open),
         ("health", Self::Health),
         ("dashboard", Self::Dashboard),
+        ("backup", Self::Backup),
+        ("capabilities", Self::Capabilities),
+        ("rollback_last_operation", Self::RollbackLastOperation),
+        ("compare_generations", Self::CompareGenerations),
+        ("cancel_embeddings", Self::CancelEmbeddings),
+        ("repair", Self::Repair),
     ];
 
     pub(crate) fn parse(operation: &str) -> Result<Self> {
@@ -95,7 +113,15 @@ impl ManageWorkspaceOperation {
             // body resolves the target path without treating the request as a
             // primary-targeting operation.
             Some(Self::List | Self::Remove | Self::Health) => true,
-            Some(Self::Stats) => arguments
+            Some(
+                Self::Stats
+                | Self::Backup
+                | Self::Capabilities
+                | Self::RollbackLastOperation
+                | Self::CompareGenerations
+                | Self::CancelEmbeddings
+                | Self::Repair,
+            ) => arguments
                 .get("workspace_id")
                 .and_then(serde_json::Value::as_str)
                 .is_none_or(|workspace_id| workspace_id == "primary"),
@@ -145,6 +171,28 @@ pub(crate) enum ManageWorkspaceRequest {
         detailed: bool,
     },
     Dashboard,
+    Backup {
+        workspace_id: Option<String>,
+        path: Option<String>,
+    },
+    Capabilities {
+        workspace_id: Option<String>,
+    },
+    RollbackLastOperation {
+        workspace_id: Option<String>,
+    },
+    CompareGenerations {
+        workspace_id: Option<String>,
+        from_generation: Option<i64>,
+        to_generation: Option<i64>,
+    },
+    CancelEmbeddings {
+        workspace_id: Option<String>,
+    },
+    Repair {
+        workspace_id: Option<String>,
+        dry_run: bool,
+    },
 }
 
 impl TryFrom<&ManageWorkspaceTool> for ManageWorkspaceRequest {
@@ -199,13 +247,35 @@ impl TryFrom<&ManageWorkspaceTool> for ManageWorkspaceRequest {
                 detailed: tool.detailed.unwrap_or(false),
             }),
             ManageWorkspaceOperation::Dashboard => Ok(Self::Dashboard),
+            ManageWorkspaceOperation::Backup => Ok(Self::Backup {
+                workspace_id: tool.workspace_id.clone(),
+                path: tool.path.clone(),
+            }),
+            ManageWorkspaceOperation::Capabilities => Ok(Self::Capabilities {
+                workspace_id: tool.workspace_id.clone(),
+            }),
+            ManageWorkspaceOperation::RollbackLastOperation => Ok(Self::RollbackLastOperation {
+                workspace_id: tool.workspace_id.clone(),
+            }),
+            ManageWorkspaceOperation::CompareGenerations => Ok(Self::CompareGenerations {
+                workspace_id: tool.workspace_id.clone(),
+                from_generation: tool.from_generation,
+                to_generation: tool.to_generation,
+            }),
+            ManageWorkspaceOperation::CancelEmbeddings => Ok(Self::CancelEmbeddings {
+                workspace_id: tool.workspace_id.clone(),
+            }),
+            ManageWorkspaceOperation::Repair => Ok(Self::Repair {
+                workspace_id: tool.workspace_id.clone(),
+                dry_run: tool.dry_run.unwrap_or(true),
+            }),
         }
     }
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 pub struct ManageWorkspaceTool {
-    /// Operation to perform: "index", "list", "register", "remove", "stats", "clean", "refresh", "open", "health", "dashboard"
+    /// Operation to perform: "index", "list", "register", "remove", "stats", "clean", "refresh", "open", "health", "dashboard", "backup", "capabilities", "rollback_last_operation", "compare_generations", "cancel_embeddings", "repair"
     ///
     /// EXAMPLES:
     /// Index workspace:      {"operation": "index", "path": null, "force": false}
@@ -219,10 +289,18 @@ pub struct ManageWorkspaceTool {
     /// Open and force sync:   {"operation": "open", "workspace_id": "workspace-id", "force": true}
     /// Health check:         {"operation": "health", "detailed": true}
     /// Launch dashboard:      {"operation": "dashboard"}
+    /// Back up index:        {"operation": "backup", "workspace_id": null, "path": null}
+    /// Capabilities:         {"operation": "capabilities", "workspace_id": null}
+    /// Undo last edit:       {"operation": "rollback_last_operation", "workspace_id": null}
+    /// Diff generations:     {"operation": "compare_generations", "from_generation": 12, "to_generation": null}
+    /// Cancel embedding:     {"operation": "cancel_embeddings", "workspace_id": null}
+    /// Find index drift:     {"operation": "repair", "workspace_id": null}
+    /// Fix index drift:      {"operation": "repair", "dry_run": false}
     pub operation: String,
 
     // Optional parameters used by various operations
-    /// Path to workspace (used by: index, register, open)
+    /// Path to workspace (used by: index, register, open; `open` also takes `user@host:/path` to
+    /// index a copy of a remote directory over SSH), or backup destination, an absolute directory outside the workspace (used by: backup)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub path: Option<String>,
 
@@ -238,7 +316,7 @@ pub struct ManageWorkspaceTool {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub name: Option<String>,
 
-    /// Workspace ID (used by: remove, refresh, open, stats)
+    /// Workspace ID (used by: remove, refresh, open, stats, backup, capabilities, rollback_last_operation, compare_generations, cancel_embeddings, repair)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub workspace_id: Option<String>,
 
@@ -249,6 +327,30 @@ pub struct ManageWorkspaceTool {
         deserialize_with = "crate::utils::serde_lenient::deserialize_option_bool_lenient"
     )]
     pub detailed: Option<bool>,
+
+    /// Older index generation to diff from; defaults to the one before `to_generation` (used by: compare_generations)
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        default,
+        deserialize_with = "crate::utils::serde_lenient::deserialize_option_i64_lenient"
+    )]
+    pub from_generation: Option<i64>,
+
+    /// Newer index generation to diff to; defaults to the current one (used by: compare_generations)
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        default,
+        deserialize_with = "crate::utils::serde_lenient::deserialize_option_i64_lenient"
+    )]
+    pub to_generation: Option<i64>,
+
+    /// Report index drift without fixing it (default: true). Pass false to apply the fixes (used by: repair)
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        default,
+        deserialize_with = "crate::utils::serde_lenient::deserialize_option_bool_lenient"
+    )]
+    pub dry_run: Option<bool>,
 }
 
 impl ManageWorkspaceTool {
@@ -311,6 +413,41 @@ impl ManageWorkspaceTool {
                 self.handle_health_command(handler, detailed).await
             }
             ManageWorkspaceRequest::Dashboard => self.handle_dashboard_command().await,
+            ManageWorkspaceRequest::Backup { workspace_id, path } => {
+                self.handle_backup_command(handler, workspace_id, path)
+                    .await
+            }
+            ManageWorkspaceRequest::Capabilities { workspace_id } => {
+                self.handle_capabilities_command(handler, workspace_id)
+                    .await
+            }
+            ManageWorkspaceRequest::RollbackLastOperation { workspace_id } => {
+                self.handle_rollback_command(handler, workspace_id).await
+            }
+            ManageWorkspaceRequest::CompareGenerations {
+                workspace_id,
+                from_generation,
+                to_generation,
+            } => {
+                self.handle_compare_generations_command(
+                    handler,
+                    workspace_id,
+                    from_generation,
+                    to_generation,
+                )
+                .await
+            }
+            ManageWorkspaceRequest::CancelEmbeddings { workspace_id } => {
+                self.handle_cancel_embeddings_command(handler, workspace_id)
+                    .await
+            }
+            ManageWorkspaceRequest::Repair {
+                workspace_id,
+                dry_run,
+            } => {
+                self.handle_repair_command(handler, workspace_id, dry_run)
+                    .await
+            }
         }
     }
 }
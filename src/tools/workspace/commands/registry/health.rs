@@ -26,9 +26,13 @@ impl ManageWorkspaceTool {
             return Ok(CallToolResult::text_content(vec![Content::text(message)]));
         }
 
-        let report = HealthChecker::system_snapshot(handler)
-            .await?
-            .render_report(detailed);
+        let snapshot = HealthChecker::system_snapshot(handler).await?;
+        let mut report = snapshot.render_report(detailed);
+        if snapshot.data_plane.indexing.repair_needed {
+            report.push_str(
+                "\nRun manage_workspace(operation=\"repair\") to see the index drift and how to fix it.\n",
+            );
+        }
 
         Ok(CallToolResult::text_content(vec![Content::text(report)]))
     }
@@ -1,8 +1,10 @@
 use super::cleanup::run_cleanup_sweep;
+use super::remote::read_origin;
 use super::{ManageWorkspaceTool, cleanup_activity_for_handler, registry_store_for_handler};
 use crate::handler::JulieServerHandler;
 use crate::mcp_compat::{CallToolResult, CallToolResultExt, Content};
 use anyhow::Result;
+use julie_core::background_tasks::{BackgroundTaskKind, BackgroundTaskRegistry};
 use tracing::{info, warn};
 
 impl ManageWorkspaceTool {
@@ -59,11 +61,14 @@ impl ManageWorkspaceTool {
                 } else {
                     labels.push("KNOWN");
                 }
+                let remote = read_origin(std::path::Path::new(&ws.path))
+                    .map(|origin| format!("Remote: {}\n", origin.label()))
+                    .unwrap_or_default();
                 output.push_str(&format!(
                     "{} ({}) [{}]\n\
                      Path: {}\n\
                     Status: {} | Sessions: {}\n\
-                     Files: {} | Symbols: {}\n\n",
+                     Files: {} | Symbols: {}\n{}\n",
                     ws.workspace_id
                         .split('_')
                         .next()
@@ -75,6 +80,7 @@ impl ManageWorkspaceTool {
                     ws.session_count,
                     ws.file_count.unwrap_or(0),
                     ws.symbol_count.unwrap_or(0),
+                    remote,
                 ));
             }
 
@@ -94,6 +100,12 @@ impl ManageWorkspaceTool {
         info!("Cleaning workspaces");
 
         if let Some(registry_store) = registry_store_for_handler(handler)? {
+            let _task = BackgroundTaskRegistry::global().register(
+                BackgroundTaskKind::Maintenance,
+                "all",
+                "workspace cleanup sweep",
+                false,
+            );
             let cleanup_activity = cleanup_activity_for_handler(handler).await;
             let summary = match run_cleanup_sweep(&registry_store, &cleanup_activity).await {
                 Ok(summary) => summary,
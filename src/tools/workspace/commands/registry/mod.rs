@@ -7,6 +7,7 @@
 // - list_clean: workspace listing and cleanup operations
 // - refresh_stats: workspace re-indexing and statistics
 // - health: comprehensive system health checks
+// - remote: workspaces mirrored from another machine over SSH
 
 pub use super::ManageWorkspaceTool;
 
@@ -60,3 +61,4 @@ mod list_clean;
 mod open;
 mod refresh_stats;
 mod register_remove;
+pub(crate) mod remote;
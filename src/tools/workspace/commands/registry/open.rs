@@ -8,6 +8,7 @@ use super::cleanup::{
     WorkspaceDeleteOutcome, delete_workspace_if_allowed, inspect_workspace_cleanup_state,
 };
 use super::refresh_stats::RefreshWorkspaceOutcome;
+use super::remote::{RemoteSource, read_origin, remote_cache_root, sync_remote};
 use super::{ManageWorkspaceTool, cleanup_activity_for_handler, registry_store_for_handler};
 use crate::handler::JulieServerHandler;
 use crate::mcp_compat::{CallToolResult, CallToolResultExt, Content};
@@ -23,10 +24,13 @@ struct OpenTarget {
 
 impl ManageWorkspaceTool {
     fn opened_message(target: &OpenTarget) -> CallToolResult {
-        let message = format!(
+        let mut message = format!(
             "Workspace Opened\nWorkspace ID: {}\nPath: {}",
             target.workspace_id, target.canonical_path,
         );
+        if let Some(origin) = read_origin(&target.workspace_path) {
+            message.push_str(&format!("\nRemote: {}", origin.label()));
+        }
         CallToolResult::text_content(vec![Content::text(message)])
     }
 
@@ -61,8 +65,23 @@ impl ManageWorkspaceTool {
                 ));
             }
 
-            let expanded = shellexpand::tilde(path).to_string();
-            let canonical_path = PathBuf::from(expanded)
+            let mut expanded = PathBuf::from(shellexpand::tilde(path).to_string());
+            if !expanded.exists()
+                && let Some(source) = RemoteSource::parse(path)
+            {
+                expanded = source.cache_dir(&remote_cache_root()?);
+                if !expanded.exists() {
+                    info!(
+                        "Copying remote workspace {} into {}",
+                        source,
+                        expanded.display()
+                    );
+                    sync_remote(&source, &expanded).await.map_err(|e| {
+                        anyhow!("Failed to copy remote workspace '{}': {e:#}", path)
+                    })?;
+                }
+            }
+            let canonical_path = expanded
                 .canonicalize()
                 .map_err(|e| anyhow!("Failed to canonicalize workspace path '{}': {e}", path))?;
             crate::workspace::root_safety::reject_sensitive_workspace_root(&canonical_path)?;
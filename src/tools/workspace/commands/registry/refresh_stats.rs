@@ -2,6 +2,7 @@ use super::super::force_safeguards::{
     cancel_embedding_tasks, refresh_workspace_ids_for_force_reindex,
 };
 use super::ManageWorkspaceTool;
+use super::remote::resync_if_remote;
 use crate::handler::JulieServerHandler;
 use crate::mcp_compat::{CallToolResult, CallToolResultExt, Content};
 use anyhow::Result;
@@ -42,6 +43,22 @@ impl ManageWorkspaceTool {
             Ok(Some(ws_row)) => {
                 let workspace_path = std::path::PathBuf::from(&ws_row.path);
 
+                // A copy of a remote workspace pulls the remote tree first, so
+                // the re-index below picks up what changed there.
+                let synced_from = match resync_if_remote(&workspace_path).await {
+                    Ok(source) => source,
+                    Err(e) => {
+                        let message = format!(
+                            "Workspace Refresh Failed\n\
+                            Workspace: {}\n\
+                            Error: {:#}\n\
+                            Check that the host is reachable over SSH without a password prompt",
+                            workspace_id, e,
+                        );
+                        return Ok(RefreshWorkspaceOutcome::Failure(message));
+                    }
+                };
+
                 // Acquire the shared mutation gate before touching workspace state.
                 let mutation_guard = handler.acquire_mutation_gate(workspace_id).await;
                 info!("Starting re-indexing of workspace: {}", workspace_id);
@@ -145,6 +162,9 @@ impl ManageWorkspaceTool {
                         } else {
                             format!("{} changed files re-indexed.", result.files_processed)
                         };
+                        if let Some(source) = synced_from {
+                            status = format!("Synced from {source}. {status}");
+                        }
                         if let Some(canonical_revision) = result.canonical_revision {
                             status
                                 .push_str(&format!(" Canonical revision: {}.", canonical_revision));
@@ -214,6 +234,12 @@ impl ManageWorkspaceTool {
         }
 
         if force && handler.current_workspace_id().as_deref() == Some(workspace_id) {
+            let workspace_root = handler.get_workspace_root_for_target(workspace_id).await?;
+            if let Err(e) = resync_if_remote(&workspace_root).await {
+                let message =
+                    format!("Workspace Refresh Failed\nWorkspace: {workspace_id}\nError: {e:#}");
+                return Ok(CallToolResult::error(vec![Content::text(message)]));
+            }
             return self.handle_index_command(handler, None, force, false).await;
         }
 
@@ -281,6 +307,7 @@ impl ManageWorkspaceTool {
             match workspace_id {
                 Some(ref id) => match db.get_workspace(id) {
                     Ok(Some(ws)) => {
+                        let context_storage = context_storage_line(handler, id).await;
                         let message = format!(
                             "Workspace Statistics: {}\n\n\
                                 {} ({})\n\
@@ -289,7 +316,7 @@ impl ManageWorkspaceTool {
                                 Files: {} | Symbols: {}\n\
                                 Sessions: {}\n\
                                 Last Indexed: {}\n\
-                                Vector Count: {}",
+                                Vector Count: {}{}",
                             ws.workspace_id,
                             ws.workspace_id
                                 .split('_')
@@ -305,6 +332,7 @@ impl ManageWorkspaceTool {
                                 .map(|t| t.to_string())
                                 .unwrap_or_else(|| "never".to_string()),
                             ws.vector_count.unwrap_or(0),
+                            context_storage,
                         );
                         return Ok(CallToolResult::text_content(vec![Content::text(message)]));
                     }
@@ -363,3 +391,29 @@ impl ManageWorkspaceTool {
         Ok(CallToolResult::error(vec![Content::text(message)]))
     }
 }
+
+/// The "Context Storage" stats line, or an empty string when the workspace
+/// database cannot be read. Stats are best-effort and never fail the command.
+async fn context_storage_line(handler: &JulieServerHandler, workspace_id: &str) -> String {
+    let stats = match handler
+        .get_pooled_database_for_workspace(workspace_id)
+        .await
+    {
+        Ok(db) => tokio::task::spawn_blocking(move || db.get_context_storage_stats())
+            .await
+            .ok()
+            .and_then(|stats| stats.ok()),
+        Err(_) => None,
+    };
+    match stats {
+        Some(stats) if stats.symbols > 0 => format!(
+            "\nContext Storage: {} symbols in {} blobs, {:.1} MB raw -> {:.1} MB stored (saved {:.0}%)",
+            stats.symbols,
+            stats.blobs,
+            stats.raw_bytes as f64 / (1024.0 * 1024.0),
+            stats.stored_bytes as f64 / (1024.0 * 1024.0),
+            stats.saved_percent()
+        ),
+        _ => String::new(),
+    }
+}
@@ -2,6 +2,7 @@ use super::cleanup::{
     CLEANUP_ACTION_MANUAL_DELETE, CLEANUP_REASON_USER_REQUEST, WorkspaceDeleteOutcome,
     delete_workspace_if_allowed, prune_missing_workspaces,
 };
+use super::remote::remove_remote_copy;
 use super::{ManageWorkspaceTool, cleanup_activity_for_handler, registry_store_for_handler};
 use crate::handler::JulieServerHandler;
 use crate::mcp_compat::{CallToolResult, CallToolResultExt, Content};
@@ -187,6 +188,7 @@ impl ManageWorkspaceTool {
                 .await?
                 {
                     WorkspaceDeleteOutcome::Deleted { workspace_id, path } => {
+                        remove_remote_copy(std::path::Path::new(&path));
                         CallToolResult::text_content(vec![Content::text(format!(
                             "Workspace Removed Successfully\nWorkspace: {}\nPath: {}\nAll associated index data removed.",
                             workspace_id, path
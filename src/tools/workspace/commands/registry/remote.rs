@@ -0,0 +1,390 @@
+//! Remote workspaces: a directory on another machine, mirrored over SSH.
+//!
+//! `open` with an `ssh://[user@]host[:port]/path` or `[user@]host:path` path
+//! copies the remote tree into a local cache and indexes the copy like any
+//! other workspace. rsync is used when it is installed locally, honouring
+//! each directory's `.gitignore`; otherwise the tree is streamed as a tar
+//! archive over `ssh`. `.git/` and `.julie/` are never copied.
+//!
+//! The origin is recorded next to the copy (`<cache>.origin.json`), so
+//! `list`, `open` and `refresh` show where a workspace came from, and
+//! `refresh` pulls the remote tree again before re-indexing what changed.
+//! Removing the workspace deletes the copy.
+//!
+//! SSH runs in batch mode: authentication has to work without a prompt (an
+//! agent or an unencrypted key), since the server has no terminal to ask on.
+
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+
+use anyhow::{Context, Result, anyhow, bail};
+use serde::{Deserialize, Serialize};
+use tracing::{info, warn};
+
+/// Overrides where remote workspaces are copied to. The default is
+/// `<user cache dir>/julie/remote`, outside `JULIE_HOME`, whose contents
+/// are never indexed.
+pub(crate) const REMOTE_CACHE_ENV: &str = "JULIE_REMOTE_CACHE";
+
+/// Seconds `ssh` waits for the remote host before giving up.
+const SSH_CONNECT_TIMEOUT_SECS: u32 = 15;
+
+/// Top-level directories left out of the copy.
+const EXCLUDED_DIRS: &[&str] = &[".git", ".julie"];
+
+/// A directory on a remote host, as given to `open`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct RemoteSource {
+    pub user: Option<String>,
+    pub host: String,
+    pub port: Option<u16>,
+    /// Path on the host; relative paths are relative to the login directory.
+    pub path: String,
+}
+
+impl RemoteSource {
+    /// Parse `ssh://[user@]host[:port]/path` or scp-style `[user@]host:path`.
+    ///
+    /// Anything else is a local path: a Windows drive (`C:\src`), a path
+    /// with a `/` before the first `:`, or a spec with an empty host or path.
+    /// A user or host that starts with `-` or has characters outside
+    /// `[A-Za-z0-9._-]` is rejected too, so neither can reach `ssh` as an
+    /// option.
+    pub(crate) fn parse(spec: &str) -> Option<Self> {
+        let spec = spec.trim();
+        if let Some(rest) = spec.strip_prefix("ssh://") {
+            let (authority, path) = rest.split_once('/')?;
+            let (user, host_port) = split_user(authority);
+            let (host, port) = match host_port.rsplit_once(':') {
+                Some((host, port)) => (host, Some(port.parse().ok()?)),
+                None => (host_port, None),
+            };
+            // `ssh://host/~/src` is relative to the login directory, while
+            // `ssh://host/srv/src` is absolute.
+            let path = match path.strip_prefix('~') {
+                Some(relative) => relative.trim_start_matches('/').to_string(),
+                None => format!("/{path}"),
+            };
+            return Self::new(user, host, port, path);
+        }
+
+        let (authority, path) = spec.split_once(':')?;
+        if authority.contains(['/', '\\']) || authority.len() == 1 || path.starts_with("//") {
+            return None;
+        }
+        let (user, host) = split_user(authority);
+        let path = path.strip_prefix("~/").unwrap_or(path);
+        Self::new(user, host, None, path.to_string())
+    }
+
+    fn new(user: Option<&str>, host: &str, port: Option<u16>, path: String) -> Option<Self> {
+        let path = path.trim_end_matches('/').to_string();
+        (is_safe_name(host) && !path.is_empty() && user.is_none_or(is_safe_name)).then(|| Self {
+            user: user.map(str::to_string),
+            host: host.to_string(),
+            port,
+            path,
+        })
+    }
+
+    /// `user@host`, or `host` when no user was given.
+    fn destination(&self) -> String {
+        match &self.user {
+            Some(user) => format!("{user}@{}", self.host),
+            None => self.host.clone(),
+        }
+    }
+
+    /// The local directory this source is copied to under `cache_root`:
+    /// host and directory name, plus a hash of the whole spec so two
+    /// checkouts of the same name stay apart.
+    pub(crate) fn cache_dir(&self, cache_root: &Path) -> PathBuf {
+        let name = self
+            .path
+            .rsplit('/')
+            .find(|segment| !segment.is_empty())
+            .unwrap_or("home");
+        let hash = blake3::hash(self.to_string().as_bytes()).to_hex();
+        cache_root.join(format!("{}-{}-{}", self.host, name, &hash[..8]))
+    }
+
+    fn ssh_args(&self) -> Vec<String> {
+        let mut args = vec![
+            "-o".to_string(),
+            "BatchMode=yes".to_string(),
+            "-o".to_string(),
+            format!("ConnectTimeout={SSH_CONNECT_TIMEOUT_SECS}"),
+        ];
+        if let Some(port) = self.port {
+            args.push("-p".to_string());
+            args.push(port.to_string());
+        }
+        args
+    }
+
+    /// Arguments of the `rsync` run mirroring this source into `local`.
+    pub(crate) fn rsync_args(&self, local: &Path) -> Vec<String> {
+        let mut args = vec![
+            "-az".to_string(),
+            "--delete".to_string(),
+            "--protect-args".to_string(),
+            "--filter=:- .gitignore".to_string(),
+        ];
+        // Excluded paths are also protected from `--delete`, so the local
+        // `.julie/` state of the copy survives.
+        args.extend(EXCLUDED_DIRS.iter().map(|dir| format!("--exclude=/{dir}/")));
+        args.push("-e".to_string());
+        args.push(format!("ssh {}", self.ssh_args().join(" ")));
+        args.push("--".to_string());
+        args.push(format!("{}:{}/", self.destination(), self.path));
+        args.push(format!("{}/", local.display()));
+        args
+    }
+
+    /// Arguments of the `ssh` run that streams this source as a tar archive.
+    pub(crate) fn tar_over_ssh_args(&self) -> Vec<String> {
+        let excludes: Vec<String> = EXCLUDED_DIRS
+            .iter()
+            .map(|dir| format!("--exclude=./{dir}"))
+            .collect();
+        let mut args = self.ssh_args();
+        args.push("--".to_string());
+        args.push(self.destination());
+        args.push(format!(
+            "tar -C {} {} -cf - .",
+            shell_quote(&self.path),
+            excludes.join(" ")
+        ));
+        args
+    }
+}
+
+impl fmt::Display for RemoteSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.port {
+            Some(port) => {
+                let path = match self.path.strip_prefix('/') {
+                    Some(absolute) => absolute.to_string(),
+                    None => format!("~/{}", self.path),
+                };
+                write!(f, "ssh://{}:{}/{}", self.destination(), port, path)
+            }
+            None => write!(f, "{}:{}", self.destination(), self.path),
+        }
+    }
+}
+
+/// A user or host name that `ssh` cannot mistake for an option.
+fn is_safe_name(name: &str) -> bool {
+    !name.is_empty()
+        && !name.starts_with('-')
+        && name
+            .chars()
+            .all(|ch| ch.is_ascii_alphanumeric() || matches!(ch, '.' | '-' | '_'))
+}
+
+fn split_user(authority: &str) -> (Option<&str>, &str) {
+    match authority.rsplit_once('@') {
+        Some((user, host)) => (Some(user), host),
+        None => (None, authority),
+    }
+}
+
+/// Quote `value` for a POSIX shell.
+fn shell_quote(value: &str) -> String {
+    format!("'{}'", value.replace('\'', r"'\''"))
+}
+
+/// Where a local copy came from and when it was last pulled.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub(crate) struct RemoteOrigin {
+    /// The source spec, as shown by [`RemoteSource`]'s `Display`.
+    pub source: String,
+    /// `rsync` or `tar`.
+    pub method: String,
+    /// Unix seconds of the last successful sync.
+    pub synced_at: i64,
+}
+
+impl RemoteOrigin {
+    /// `host:path (synced 2026-10-17 09:30 UTC via rsync)`.
+    pub(crate) fn label(&self) -> String {
+        let synced = chrono::DateTime::from_timestamp(self.synced_at, 0)
+            .map(|time| time.format("%Y-%m-%d %H:%M UTC").to_string())
+            .unwrap_or_else(|| self.synced_at.to_string());
+        format!("{} (synced {} via {})", self.source, synced, self.method)
+    }
+}
+
+/// The directory remote workspaces are copied to.
+pub(crate) fn remote_cache_root() -> Result<PathBuf> {
+    if let Some(root) = std::env::var_os(REMOTE_CACHE_ENV).filter(|root| !root.is_empty()) {
+        return Ok(PathBuf::from(root));
+    }
+    let cache = dirs::cache_dir().ok_or_else(|| {
+        anyhow!("No user cache directory for remote workspaces; set {REMOTE_CACHE_ENV}")
+    })?;
+    Ok(cache.join("julie").join("remote"))
+}
+
+/// A file beside the copy at `local`, named after it plus `suffix`. Host
+/// names contain dots, so `Path::with_extension` would cut the name short.
+fn sibling(local: &Path, suffix: &str) -> PathBuf {
+    let mut name = local.file_name().unwrap_or_default().to_os_string();
+    name.push(suffix);
+    local.with_file_name(name)
+}
+
+/// The origin record kept beside the copy at `local`.
+fn origin_file(local: &Path) -> PathBuf {
+    sibling(local, ".origin.json")
+}
+
+/// The origin of `local` when it is a copy of a remote workspace.
+pub(crate) fn read_origin(local: &Path) -> Option<RemoteOrigin> {
+    let text = std::fs::read_to_string(origin_file(local)).ok()?;
+    serde_json::from_str(&text)
+        .inspect_err(|e| warn!("Unreadable remote origin for {}: {}", local.display(), e))
+        .ok()
+}
+
+pub(crate) fn write_origin(local: &Path, origin: &RemoteOrigin) -> Result<()> {
+    let path = origin_file(local);
+    std::fs::write(&path, serde_json::to_vec_pretty(origin)?)
+        .with_context(|| format!("failed to write {}", path.display()))
+}
+
+/// Delete the copy at `local` and its origin record, if it is one.
+pub(crate) fn remove_remote_copy(local: &Path) {
+    if read_origin(local).is_none() {
+        return;
+    }
+    if let Err(e) = std::fs::remove_dir_all(local) {
+        warn!("Failed to delete remote copy {}: {}", local.display(), e);
+    }
+    let _ = std::fs::remove_file(origin_file(local));
+}
+
+/// Pull `source` into `local` and record the origin. Only the files that
+/// changed are transferred when rsync is available.
+pub(crate) async fn sync_remote(source: &RemoteSource, local: &Path) -> Result<RemoteOrigin> {
+    let source = source.clone();
+    let local = local.to_path_buf();
+    tokio::task::spawn_blocking(move || {
+        let started = std::time::Instant::now();
+        std::fs::create_dir_all(&local)
+            .with_context(|| format!("failed to create {}", local.display()))?;
+        let method = if rsync_available() {
+            run(
+                Command::new("rsync").args(source.rsync_args(&local)),
+                "rsync",
+            )?;
+            "rsync"
+        } else {
+            sync_with_tar(&source, &local)?;
+            "tar"
+        };
+        let origin = RemoteOrigin {
+            source: source.to_string(),
+            method: method.to_string(),
+            synced_at: chrono::Utc::now().timestamp(),
+        };
+        write_origin(&local, &origin)?;
+        info!(
+            "Synced remote workspace {} into {} via {} in {:?}",
+            source,
+            local.display(),
+            method,
+            started.elapsed()
+        );
+        Ok(origin)
+    })
+    .await?
+}
+
+/// Pull the remote tree again when `local` is a copy of one. Returns the
+/// source that was synced, or `None` for an ordinary local workspace.
+pub(crate) async fn resync_if_remote(local: &Path) -> Result<Option<RemoteSource>> {
+    let Some(origin) = read_origin(local) else {
+        return Ok(None);
+    };
+    let source = RemoteSource::parse(&origin.source)
+        .ok_or_else(|| anyhow!("unrecognized remote origin '{}'", origin.source))?;
+    sync_remote(&source, local)
+        .await
+        .with_context(|| format!("failed to sync from {source}"))?;
+    Ok(Some(source))
+}
+
+fn rsync_available() -> bool {
+    Command::new("rsync")
+        .arg("--version")
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .status()
+        .is_ok_and(|status| status.success())
+}
+
+/// Stream the remote tree into a staging directory, then swap it in for the
+/// old copy, carrying the copy's `.julie/` state over.
+fn sync_with_tar(source: &RemoteSource, local: &Path) -> Result<()> {
+    let staging = sibling(local, ".partial");
+    if staging.exists() {
+        std::fs::remove_dir_all(&staging)?;
+    }
+    std::fs::create_dir_all(&staging)?;
+
+    let mut ssh = Command::new("ssh")
+        .args(source.tar_over_ssh_args())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .context("failed to start ssh")?;
+    let archive = ssh.stdout.take().expect("ssh stdout is piped");
+    let untar = Command::new("tar")
+        .arg("-xf")
+        .arg("-")
+        .arg("-C")
+        .arg(&staging)
+        .stdin(archive)
+        .output()
+        .context("failed to start tar")?;
+    let ssh = ssh.wait_with_output()?;
+    if !ssh.status.success() {
+        let _ = std::fs::remove_dir_all(&staging);
+        bail!(
+            "ssh failed: {}",
+            String::from_utf8_lossy(&ssh.stderr).trim()
+        );
+    }
+    if !untar.status.success() {
+        let _ = std::fs::remove_dir_all(&staging);
+        bail!(
+            "tar failed: {}",
+            String::from_utf8_lossy(&untar.stderr).trim()
+        );
+    }
+
+    let state = local.join(".julie");
+    if state.exists() {
+        std::fs::rename(&state, staging.join(".julie"))?;
+    }
+    std::fs::remove_dir_all(local)?;
+    std::fs::rename(&staging, local)?;
+    Ok(())
+}
+
+fn run(command: &mut Command, name: &str) -> Result<()> {
+    let output = command
+        .output()
+        .with_context(|| format!("failed to start {name}"))?;
+    if !output.status.success() {
+        bail!(
+            "{name} failed ({}): {}",
+            output.status,
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+    }
+    Ok(())
+}
@@ -0,0 +1,272 @@
+use super::ManageWorkspaceTool;
+use crate::database::{EmbeddingJobStatus, SymbolDatabase, calculate_file_hash};
+use crate::handler::JulieServerHandler;
+use crate::mcp_compat::{CallToolResult, CallToolResultExt, Content};
+use crate::tools::workspace::indexing::embeddings::spawn_workspace_embedding;
+use anyhow::{Result, anyhow};
+use std::collections::HashSet;
+use std::path::Path;
+use tracing::info;
+
+/// Files listed per drift category before the report summarizes the rest.
+const LISTED_FILES: usize = 10;
+
+/// Index drift found by `repair`, grouped by the fix each kind needs.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub(crate) struct RepairPlan {
+    /// Indexed files whose content changed on disk.
+    pub modified_files: Vec<String>,
+    /// Files on disk that were never indexed.
+    pub new_files: Vec<String>,
+    /// Indexed files gone from disk, excluding those still in their
+    /// soft-delete grace period.
+    pub deleted_files: Vec<String>,
+    pub orphaned_relationships: usize,
+    /// Vectors whose symbol no longer exists.
+    pub orphaned_vectors: usize,
+    /// Why symbols are missing vectors, when they are.
+    pub missing_vectors: Option<String>,
+}
+
+impl RepairPlan {
+    pub(crate) fn has_file_drift(&self) -> bool {
+        !self.modified_files.is_empty()
+            || !self.new_files.is_empty()
+            || !self.deleted_files.is_empty()
+    }
+
+    pub(crate) fn is_clean(&self) -> bool {
+        !self.has_file_drift()
+            && self.orphaned_relationships == 0
+            && self.orphaned_vectors == 0
+            && self.missing_vectors.is_none()
+    }
+
+    fn render(&self, output: &mut String) {
+        write_files(output, "changed since indexed", &self.modified_files);
+        write_files(output, "not indexed yet", &self.new_files);
+        write_files(output, "deleted but still indexed", &self.deleted_files);
+        if self.has_file_drift() {
+            output.push_str("  fix: reindex these files\n");
+        }
+        if self.orphaned_relationships > 0 {
+            output.push_str(&format!(
+                "- {} relationship(s) pointing at missing symbols\n  fix: delete them\n",
+                self.orphaned_relationships
+            ));
+        }
+        if self.orphaned_vectors > 0 {
+            output.push_str(&format!(
+                "- {} vector(s) for missing symbols\n  fix: delete them\n",
+                self.orphaned_vectors
+            ));
+        }
+        if let Some(reason) = &self.missing_vectors {
+            output.push_str(&format!(
+                "- missing vectors: {reason}\n  fix: embed the missing symbols (resumes from the last checkpoint)\n"
+            ));
+        }
+    }
+}
+
+fn write_files(output: &mut String, label: &str, files: &[String]) {
+    if files.is_empty() {
+        return;
+    }
+    output.push_str(&format!("- {} file(s) {label}:\n", files.len()));
+    for file in files.iter().take(LISTED_FILES) {
+        output.push_str(&format!("    {file}\n"));
+    }
+    if files.len() > LISTED_FILES {
+        output.push_str(&format!(
+            "    ... and {} more\n",
+            files.len() - LISTED_FILES
+        ));
+    }
+}
+
+/// Compare the index in `db` against `workspace_root` on disk.
+///
+/// Files are compared by content hash, like incremental indexing, so a
+/// touched-but-unchanged file is not drift. `embedding_active` says whether an
+/// embedding run is in flight, in which case a running job is not reported as
+/// missing vectors. Pass `check_vectors = false` when semantic search is off.
+pub(crate) fn plan_repair(
+    db: &SymbolDatabase,
+    workspace_root: &Path,
+    check_vectors: bool,
+    embedding_active: bool,
+) -> Result<RepairPlan> {
+    let indexed = db.get_file_hashes_for_workspace()?;
+    let soft_deleted = db.soft_deleted_files()?;
+    let on_disk = julie_core::workspace_scan::scan_workspace_files(workspace_root)?;
+
+    let mut plan = RepairPlan::default();
+    for path in &on_disk {
+        match indexed.get(path) {
+            Some(stored_hash) => {
+                let changed = !calculate_file_hash(workspace_root.join(path))
+                    .is_ok_and(|hash| &hash == stored_hash);
+                if changed {
+                    plan.modified_files.push(path.clone());
+                }
+            }
+            None => plan.new_files.push(path.clone()),
+        }
+    }
+    let on_disk: HashSet<&String> = on_disk.iter().collect();
+    plan.deleted_files = indexed
+        .keys()
+        .filter(|path| !on_disk.contains(path) && !soft_deleted.contains_key(*path))
+        .cloned()
+        .collect();
+    plan.modified_files.sort();
+    plan.new_files.sort();
+    plan.deleted_files.sort();
+
+    plan.orphaned_relationships = db.count_orphaned_relationships()?;
+    if check_vectors {
+        plan.orphaned_vectors = db.count_orphan_embeddings()?;
+        plan.missing_vectors = match db.embedding_job()? {
+            Some(job) if job.status == EmbeddingJobStatus::Running && embedding_active => None,
+            Some(job) if job.status != EmbeddingJobStatus::Complete => Some(format!(
+                "embedding job {} at {}/{} symbols",
+                job.status, job.completed, job.total
+            )),
+            Some(_) => None,
+            None => {
+                let symbols = db.count_symbols_for_workspace()?;
+                (symbols > 0 && db.embedding_count()? == 0 && !embedding_active)
+                    .then(|| format!("no vectors for {symbols} symbols"))
+            }
+        };
+    }
+    Ok(plan)
+}
+
+impl ManageWorkspaceTool {
+    /// Find index drift and, unless `dry_run`, fix it with targeted repairs:
+    /// reindex changed files, delete orphaned rows, embed missing vectors.
+    ///
+    /// The default is a dry run that only reports what would change, so a
+    /// caller sees the plan before anything is rewritten.
+    pub(crate) async fn handle_repair_command(
+        &self,
+        handler: &JulieServerHandler,
+        workspace_id: Option<String>,
+        dry_run: bool,
+    ) -> Result<CallToolResult> {
+        let workspace_id = match workspace_id.filter(|id| id != "primary") {
+            Some(id) => id,
+            None => match handler.require_primary_workspace_identity() {
+                Ok(id) => id,
+                Err(e) => {
+                    return Ok(CallToolResult::error(vec![Content::text(e.to_string())]));
+                }
+            },
+        };
+        if !dry_run && handler.is_in_process_follower() {
+            let message =
+                "another session owns writes for this workspace; this is a read-only follower";
+            return Ok(CallToolResult::error(vec![Content::text(message)]));
+        }
+
+        let plan = self.repair_plan(handler, &workspace_id).await?;
+        if plan.is_clean() {
+            let message = format!("No index drift found in {workspace_id}; nothing to repair.");
+            return Ok(CallToolResult::text_content(vec![Content::text(message)]));
+        }
+
+        let mut message = format!("Index drift in {workspace_id}:\n");
+        plan.render(&mut message);
+        if dry_run {
+            message.push_str(
+                "\nDry run: nothing was changed. Run repair with dry_run=false to apply these fixes.",
+            );
+            return Ok(CallToolResult::text_content(vec![Content::text(message)]));
+        }
+
+        info!("Repairing index drift in {workspace_id}: {plan:?}");
+        let mut applied = Vec::new();
+        if plan.has_file_drift() {
+            let result = self
+                .handle_refresh_command(handler, &workspace_id, false)
+                .await?;
+            if result.is_error == Some(true) {
+                return Ok(result);
+            }
+            applied.push(format!(
+                "reindexed {} changed, {} new and {} deleted file(s)",
+                plan.modified_files.len(),
+                plan.new_files.len(),
+                plan.deleted_files.len()
+            ));
+        }
+
+        if plan.orphaned_relationships > 0 || plan.orphaned_vectors > 0 {
+            let _guard = handler.acquire_mutation_gate(&workspace_id).await;
+            let db = handler.get_database_for_workspace(&workspace_id).await?;
+            let (relationships, vectors) = tokio::task::spawn_blocking(move || {
+                let mut db = db.lock().map_err(|_| anyhow!("database lock poisoned"))?;
+                let relationships = db.delete_orphaned_relationships()?;
+                let vectors = db.delete_orphan_embeddings()?;
+                Ok::<_, anyhow::Error>((relationships, vectors))
+            })
+            .await??;
+            if relationships > 0 {
+                applied.push(format!("deleted {relationships} orphaned relationship(s)"));
+            }
+            if vectors > 0 {
+                applied.push(format!("deleted {vectors} orphaned vector(s)"));
+            }
+        }
+
+        // Reindexing may already have started an embedding run.
+        if plan.missing_vectors.is_some() && !embedding_active(handler, &workspace_id).await {
+            let outcome = spawn_workspace_embedding(handler, workspace_id.clone()).await;
+            if outcome.deferred {
+                applied.push("queued embedding until the provider is ready".to_string());
+            } else if outcome.symbols > 0 {
+                applied.push(format!(
+                    "embedding missing vectors for {} symbols in background",
+                    outcome.symbols
+                ));
+            } else {
+                applied.push("could not embed: no embedding provider is available".to_string());
+            }
+        }
+
+        message.push_str("\nApplied:\n");
+        for action in &applied {
+            message.push_str(&format!("- {action}\n"));
+        }
+        Ok(CallToolResult::text_content(vec![Content::text(
+            message.trim_end().to_string(),
+        )]))
+    }
+
+    async fn repair_plan(
+        &self,
+        handler: &JulieServerHandler,
+        workspace_id: &str,
+    ) -> Result<RepairPlan> {
+        let workspace_root = handler.get_workspace_root_for_target(workspace_id).await?;
+        let db = handler.get_database_for_workspace(workspace_id).await?;
+        let check_vectors = !handler.semantic_disabled();
+        let embedding_active = embedding_active(handler, workspace_id).await;
+        tokio::task::spawn_blocking(move || {
+            let db = db.lock().map_err(|_| anyhow!("database lock poisoned"))?;
+            plan_repair(&db, &workspace_root, check_vectors, embedding_active)
+        })
+        .await?
+    }
+}
+
+async fn embedding_active(handler: &JulieServerHandler, workspace_id: &str) -> bool {
+    handler
+        .embedding_tasks
+        .lock()
+        .await
+        .get(workspace_id)
+        .is_some_and(|(_, handle)| !handle.is_finished())
+}
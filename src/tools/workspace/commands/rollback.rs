@@ -0,0 +1,141 @@
+use super::ManageWorkspaceTool;
+use crate::database::RevisionSymbolChange;
+use crate::handler::JulieServerHandler;
+use crate::mcp_compat::{CallToolResult, CallToolResultExt, Content};
+use crate::tools::editing::journal::restore_operation;
+use crate::utils::paths::normalize_stored_path;
+use anyhow::{Result, anyhow};
+use std::collections::HashSet;
+use std::sync::Arc;
+use tracing::{debug, info, warn};
+
+impl ManageWorkspaceTool {
+    /// Undo the newest journaled edit (`edit_file`, `rewrite_symbol`,
+    /// `rename_symbol`) that has not been rolled back yet.
+    ///
+    /// The files are restored, then reindexed right away so the symbol,
+    /// identifier and relationship rows match them before this returns; the
+    /// report lists the symbol changes that reindex made to those files.
+    pub(crate) async fn handle_rollback_command(
+        &self,
+        handler: &JulieServerHandler,
+        workspace_id: Option<String>,
+    ) -> Result<CallToolResult> {
+        if handler.is_in_process_follower() {
+            let message =
+                "another session owns writes for this workspace; this is a read-only follower";
+            return Ok(CallToolResult::error(vec![Content::text(message)]));
+        }
+        let workspace_id = match workspace_id.filter(|id| id != "primary") {
+            Some(id) => id,
+            None => match handler.require_primary_workspace_identity() {
+                Ok(id) => id,
+                Err(e) => {
+                    return Ok(CallToolResult::error(vec![Content::text(e.to_string())]));
+                }
+            },
+        };
+        let workspace_root = handler.get_workspace_root_for_target(&workspace_id).await?;
+        let db = handler.get_database_for_workspace(&workspace_id).await?;
+
+        let blocking_workspace_id = workspace_id.clone();
+        let blocking_db = Arc::clone(&db);
+        let outcome = tokio::task::spawn_blocking(move || {
+            let db = blocking_db
+                .lock()
+                .map_err(|_| anyhow!("database lock poisoned"))?;
+            let Some(operation) = db.last_operation()? else {
+                return Ok(None);
+            };
+            let revision = db.get_current_canonical_revision(&blocking_workspace_id)?;
+            let restored = restore_operation(&workspace_root, &operation);
+            if restored.is_ok() {
+                db.mark_operation_rolled_back(operation.id)?;
+            }
+            Ok::<_, anyhow::Error>(Some((operation, revision, restored)))
+        })
+        .await??;
+
+        let Some((operation, revision_before, restored)) = outcome else {
+            let message =
+                format!("Nothing to roll back: no journaled operations in {workspace_id}");
+            return Ok(CallToolResult::text_content(vec![Content::text(message)]));
+        };
+        if let Err(e) = restored {
+            return Ok(CallToolResult::error(vec![Content::text(e.to_string())]));
+        }
+        info!(
+            "Rolled back operation {} in {}: {}",
+            operation.id, workspace_id, operation.summary
+        );
+
+        let files = operation
+            .files
+            .iter()
+            .map(|file| format!("  {}", file.file_path))
+            .collect::<Vec<_>>()
+            .join("\n");
+        let mut message = format!(
+            "Rolled back: {}\n\nRestored {} file(s):\n{}\n\n",
+            operation.summary,
+            operation.files.len(),
+            files,
+        );
+
+        let reindex = self
+            .handle_refresh_command(handler, &workspace_id, false)
+            .await;
+        if !matches!(&reindex, Ok(result) if result.is_error != Some(true)) {
+            warn!(
+                "Reindex after rolling back operation {} failed",
+                operation.id
+            );
+            message.push_str(
+                "Reindexing the restored files failed; the watcher or a \
+                 `refresh` will bring the index up to date.",
+            );
+            return Ok(CallToolResult::text_content(vec![Content::text(message)]));
+        }
+
+        let restored_paths: HashSet<String> = operation
+            .files
+            .iter()
+            .map(|file| normalize_stored_path(&file.file_path).into_owned())
+            .collect();
+        let diff = tokio::task::spawn_blocking(move || {
+            let db = db.lock().map_err(|_| anyhow!("database lock poisoned"))?;
+            let Some(revision_after) = db.get_current_canonical_revision(&workspace_id)? else {
+                return Ok(None);
+            };
+            let revision_before = revision_before.unwrap_or(0);
+            if revision_after <= revision_before {
+                return Ok(None);
+            }
+            db.compare_generations(&workspace_id, revision_before, revision_after)
+                .map(Some)
+        })
+        .await?;
+        match diff {
+            Ok(Some(diff)) => {
+                let count = |changes: &[RevisionSymbolChange]| {
+                    changes
+                        .iter()
+                        .filter(|change| restored_paths.contains(&change.file_path))
+                        .count()
+                };
+                message.push_str(&format!(
+                    "Reindexed them: {} symbol(s) added, {} removed, {} modified.",
+                    count(&diff.added),
+                    count(&diff.removed),
+                    count(&diff.modified),
+                ));
+            }
+            Ok(None) => message.push_str("Reindexed them: the index already matched."),
+            Err(e) => {
+                debug!("No symbol delta for rollback {}: {e:#}", operation.id);
+                message.push_str("Reindexed them.");
+            }
+        }
+        Ok(CallToolResult::text_content(vec![Content::text(message)]))
+    }
+}
@@ -86,6 +86,17 @@ impl ManageWorkspaceTool {
             }
         }
 
+        // `[[generated]]` output directories are indexed even when ignored.
+        let mut seen: HashSet<PathBuf> = indexable_files.iter().cloned().collect();
+        for path in julie_core::generated_files::discover_generated_files(workspace_path) {
+            if !seen.contains(&path)
+                && self.should_index_file(&path, &blacklisted_exts, max_file_size, false)?
+            {
+                seen.insert(path.clone());
+                indexable_files.push(path);
+            }
+        }
+
         debug!(
             "📊 File discovery: {} indexable files found",
             indexable_files.len()
@@ -0,0 +1,179 @@
+//! Branch-switch detection and the differential indexing report.
+//!
+//! Every index run records the commit it indexed (`git_head` in
+//! `index_engine_state`). When HEAD has moved since, incremental indexing
+//! re-hashes only the files `git diff` lists against the recorded commit, plus
+//! files modified after the recording (stash pops, edits the watcher already
+//! indexed, tools that rewrite files behind git's back). New files are still
+//! found by path and deleted ones by orphan cleanup. The symbols of every
+//! reindexed or removed file are compared before and after the run, and the
+//! totals are reported with the index result.
+
+use std::collections::{HashMap, HashSet};
+use std::path::Path;
+use std::time::UNIX_EPOCH;
+
+use anyhow::Result;
+use julie_core::git_history::{changed_files_since, head_commit};
+use tracing::{debug, warn};
+
+use crate::database::SymbolDatabase;
+
+/// `index_engine_state` component holding the last indexed commit.
+pub(crate) const GIT_HEAD_COMPONENT: &str = "git_head";
+
+/// HEAD moved since the last index run.
+#[derive(Debug, Clone)]
+pub(crate) struct BranchSwitch {
+    pub previous_head: String,
+    pub head: String,
+    /// Paths git reports as different from `previous_head`.
+    pub changed_paths: HashSet<String>,
+    /// Unix seconds when `previous_head` was recorded.
+    pub recorded_at: i64,
+}
+
+impl BranchSwitch {
+    /// Whether an already-indexed file has to be re-hashed.
+    pub fn needs_check(&self, relative_path: &str, absolute_path: &Path) -> bool {
+        self.changed_paths.contains(relative_path)
+            || modified_since(absolute_path, self.recorded_at)
+    }
+}
+
+/// Compare the recorded commit (`(head, recorded_at)` from
+/// `index_engine_state`) with the one checked out at `workspace_root`.
+///
+/// Returns `None` when nothing was recorded, HEAD has not moved, or git cannot
+/// answer; callers then fall back to hashing every file.
+pub(crate) fn detect_branch_switch(
+    recorded: Option<(String, i64)>,
+    workspace_root: &Path,
+) -> Option<BranchSwitch> {
+    let (previous_head, recorded_at) = recorded?;
+    let head = head_commit(workspace_root).ok()?;
+    if head == previous_head {
+        return None;
+    }
+    match changed_files_since(workspace_root, &previous_head) {
+        Ok(paths) => Some(BranchSwitch {
+            previous_head,
+            head,
+            changed_paths: paths.into_iter().collect(),
+            recorded_at,
+        }),
+        Err(err) => {
+            // The recorded commit may be gone (gc after a rebase); a full
+            // hash scan is still correct.
+            warn!("HEAD moved but git diff failed, checking every file: {err:#}");
+            None
+        }
+    }
+}
+
+/// Record the commit checked out at `workspace_root` as indexed. Workspaces
+/// outside git record nothing.
+pub(crate) fn record_git_head(
+    db: &SymbolDatabase,
+    workspace_id: &str,
+    workspace_root: &Path,
+) -> Result<()> {
+    match head_commit(workspace_root) {
+        Ok(head) => db.set_index_engine_version(workspace_id, GIT_HEAD_COMPONENT, &head),
+        Err(err) => {
+            debug!("Not recording git HEAD: {err:#}");
+            Ok(())
+        }
+    }
+}
+
+fn modified_since(path: &Path, recorded_at: i64) -> bool {
+    let Ok(modified) = std::fs::metadata(path).and_then(|m| m.modified()) else {
+        return true;
+    };
+    let seconds = modified
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs() as i64)
+        .unwrap_or(0);
+    // Whole seconds: a file written in the recording second is rechecked.
+    seconds >= recorded_at
+}
+
+/// Symbols of a set of files, counted by `(file, kind, name, signature)`.
+pub(crate) type SymbolSnapshot = HashMap<(String, String, String, String), usize>;
+
+/// Snapshot the symbols currently stored for `paths`.
+pub(crate) fn snapshot_symbols(db: &SymbolDatabase, paths: &[String]) -> Result<SymbolSnapshot> {
+    let mut snapshot = SymbolSnapshot::new();
+    for path in paths {
+        for symbol in db.get_symbols_for_file_lightweight(path)? {
+            let key = (
+                symbol.file_path,
+                symbol.kind.to_string(),
+                symbol.name,
+                symbol.signature.unwrap_or_default(),
+            );
+            *snapshot.entry(key).or_insert(0) += 1;
+        }
+    }
+    Ok(snapshot)
+}
+
+/// How the symbols of the reindexed files changed.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub(crate) struct SymbolDiff {
+    pub added: usize,
+    pub removed: usize,
+    pub unchanged: usize,
+}
+
+/// Compare two snapshots. A symbol whose signature changed counts as one
+/// removed and one added.
+pub(crate) fn diff_symbols(before: &SymbolSnapshot, after: &SymbolSnapshot) -> SymbolDiff {
+    let mut diff = SymbolDiff::default();
+    for (key, &old) in before {
+        let new = after.get(key).copied().unwrap_or(0);
+        diff.unchanged += old.min(new);
+        diff.removed += old.saturating_sub(new);
+    }
+    for (key, &new) in after {
+        let old = before.get(key).copied().unwrap_or(0);
+        diff.added += new.saturating_sub(old);
+    }
+    diff
+}
+
+/// What an index run after a branch switch changed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct BranchSwitchReport {
+    pub previous_head: String,
+    pub head: String,
+    /// Paths `git diff` listed against the previous commit.
+    pub git_changed_files: usize,
+    /// Files whose content changed and were extracted again.
+    pub files_reindexed: usize,
+    /// Files removed from the index because they no longer exist.
+    pub files_removed: usize,
+    pub symbols: SymbolDiff,
+}
+
+impl BranchSwitchReport {
+    pub fn summary(&self) -> String {
+        format!(
+            "Branch switch {} -> {}: {} files changed in git, {} reindexed, {} removed; \
+             symbols: {} added, {} removed, {} unchanged",
+            short_commit(&self.previous_head),
+            short_commit(&self.head),
+            self.git_changed_files,
+            self.files_reindexed,
+            self.files_removed,
+            self.symbols.added,
+            self.symbols.removed,
+            self.symbols.unchanged,
+        )
+    }
+}
+
+fn short_commit(commit: &str) -> &str {
+    commit.get(..7).unwrap_or(commit)
+}
@@ -5,13 +5,19 @@
 //! connection.
 
 use std::sync::{Arc, Mutex};
+use std::time::Duration;
 
 use tracing::{debug, info, warn};
 
 use crate::database::SymbolDatabase;
 use crate::embeddings::EmbeddingProvider;
-use crate::embeddings::pipeline::run_embedding_pipeline_cancellable;
+use crate::embeddings::pipeline::run_embedding_pipeline_tracked;
 use crate::handler::JulieServerHandler;
+use julie_core::background_tasks::{BackgroundTaskKind, BackgroundTaskRegistry};
+use julie_core::supervisor::{RestartPolicy, Subsystem, Supervisor, SupervisorExit};
+
+/// Restarts of a panicking workspace embedding run before it is abandoned.
+const EMBEDDING_RESTART_POLICY: RestartPolicy = RestartPolicy::new(2, Duration::from_secs(1));
 
 /// Outcome of `spawn_workspace_embedding`.
 ///
@@ -40,7 +46,7 @@ impl EmbeddingOutcome {
 /// Returns an [`EmbeddingOutcome`] so the caller can include the symbol count
 /// (and whether the run was deferred behind a still-initializing provider) in
 /// response messages. Returns `symbols: 0` if embedding is skipped (no
-/// provider, no workspace, etc.).
+/// provider, no workspace, `semantic.mode = "disabled"`, etc.).
 ///
 /// If the embedding provider has not been initialized yet (deferred from
 /// workspace startup to avoid blocking indexing), this function either
@@ -51,6 +57,11 @@ pub(crate) async fn spawn_workspace_embedding(
     handler: &JulieServerHandler,
     workspace_id: String,
 ) -> EmbeddingOutcome {
+    if handler.semantic_disabled() {
+        debug!("semantic.mode = \"disabled\"; skipping workspace embedding");
+        return EmbeddingOutcome::skipped();
+    }
+
     // Fast path: check handler (daemon shared service or workspace provider)
     let provider = if let Some(p) = handler.embedding_provider().await {
         p
@@ -270,19 +281,46 @@ async fn run_pipeline_body(
     total_symbols: usize,
 ) {
     info!("Starting workspace embedding for {workspace_id} ({total_symbols} symbols)...");
-    let db_clone = db_arc.clone();
-    let lang_configs = crate::search::language_config::LanguageConfigs::load_embedded();
-    // Capture model name before provider is moved into spawn_blocking
+    let lang_configs = Arc::new(crate::search::language_config::LanguageConfigs::load_embedded());
+    // Capture model name for the daemon.db update after the run
     let model_name = provider.device_info().model_name.clone();
-    let result = tokio::task::spawn_blocking(move || {
-        run_embedding_pipeline_cancellable(
-            &db_clone,
-            provider.as_ref(),
-            Some(&lang_configs),
-            Some(&cancel_for_pipeline),
+    // Dropped once the supervised pipeline settles, which removes it from
+    // status output.
+    let task = BackgroundTaskRegistry::global().register(
+        BackgroundTaskKind::Embedding,
+        workspace_id.clone(),
+        "workspace embedding",
+        true,
+    );
+    // A panicking pipeline is restarted from its last committed batch; the
+    // pipeline skips symbols that already have vectors.
+    let result = Supervisor::global()
+        .supervise(
+            Subsystem::Embeddings,
+            &workspace_id,
+            EMBEDDING_RESTART_POLICY,
+            || {
+                // A panic while holding the DB lock poisons it; the pipeline
+                // treats a poisoned lock as fatal, so a restart clears it.
+                db_arc.clear_poison();
+                let db_clone = db_arc.clone();
+                let provider = provider.clone();
+                let lang_configs = lang_configs.clone();
+                let cancel_for_pipeline = cancel_for_pipeline.clone();
+                let task = task.clone();
+                tokio::task::spawn_blocking(move || {
+                    run_embedding_pipeline_tracked(
+                        &db_clone,
+                        provider.as_ref(),
+                        Some(&lang_configs),
+                        Some(&cancel_for_pipeline),
+                        Some(task.as_ref()),
+                    )
+                })
+            },
         )
-    })
-    .await;
+        .await;
+    drop(task);
 
     match result {
         Ok(Ok(stats)) => {
@@ -294,12 +332,11 @@ async fn run_pipeline_body(
         Ok(Err(e)) => {
             warn!("Workspace {workspace_id} embedding failed: {e:#}");
         }
-        Err(e) => {
-            if e.is_cancelled() {
-                info!("Workspace {workspace_id} embedding task cancelled");
-            } else {
-                warn!("Workspace {workspace_id} embedding task panicked: {e}");
-            }
+        Err(SupervisorExit::Cancelled) => {
+            info!("Workspace {workspace_id} embedding task cancelled");
+        }
+        Err(e @ SupervisorExit::GaveUp(_)) => {
+            warn!("Workspace {workspace_id} embedding stopped: {e}");
         }
     }
 
@@ -13,4 +13,7 @@ pub const SEMANTIC_INDEX_ENGINE_COMPONENT: &str = "semantic_index_engine";
 /// triggers a stored-index mismatch. Keep this literal in lockstep with
 /// `julie_extractors::EXTRACTION_CONTRACT_VERSION`; the regression test in
 /// `src/tests/core/engine_version.rs` enforces the link.
-pub const SEMANTIC_INDEX_ENGINE_VERSION: &str = "extractors=2026-06-30.ecmascript-swift-shape-v3.source-regions-v1.structural-facts-v1.complexity-metrics-v1.file-derived-component-symbols-v1.framework-route-facts-v1.react-nextjs-route-facts-v1.nuxt-route-facts-v1.web-route-facts-v3.http-boundary-facts-v1.containing-symbol-binding-v2.backend-http-boundary-v1.backend-http-boundary-v2.sql-tsql-facts-v1+consumer-enrichments-v1+schema=2026-05-05.reference-identifier-v3+web-edges-v1";
+///
+/// Changes to what Julie itself extracts or enriches bump the
+/// `consumer-enrichments` number; they do not append a marker per feature.
+pub const SEMANTIC_INDEX_ENGINE_VERSION: &str = "extractors=2026-06-30.ecmascript-swift-shape-v3.source-regions-v1.structural-facts-v1.complexity-metrics-v1.file-derived-component-symbols-v1.framework-route-facts-v1.react-nextjs-route-facts-v1.nuxt-route-facts-v1.web-route-facts-v3.http-boundary-facts-v1.containing-symbol-binding-v2.backend-http-boundary-v1.backend-http-boundary-v2.sql-tsql-facts-v1+consumer-enrichments-v2+schema=2026-05-05.reference-identifier-v3+web-edges-v1";
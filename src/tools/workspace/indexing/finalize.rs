@@ -1,11 +1,15 @@
 use anyhow::Result;
+use julie_core::walk::find_nested_repositories;
+use julie_core::workspace_settings::WorkspaceSettingsRegistry;
 use tracing::{info, warn};
 
 use super::route::IndexRoute;
 use crate::handler::JulieServerHandler;
 
 // resolve_pending_relationships relocated to julie_pipeline::finalize
-pub(crate) use julie_pipeline::finalize::resolve_pending_relationships;
+pub(crate) use julie_pipeline::finalize::{
+    resolve_pending_relationships, resolve_pending_relationships_in_db,
+};
 
 pub(crate) fn analyze_batch(
     handler: &JulieServerHandler,
@@ -67,6 +71,160 @@ pub(crate) fn analyze_batch(
         t.elapsed().as_secs_f64()
     );
 
+    let t = std::time::Instant::now();
+    {
+        let db_lock = match db.lock() {
+            Ok(guard) => guard,
+            Err(poisoned) => {
+                warn!("Database mutex poisoned during cross-language linking, recovering");
+                poisoned.into_inner()
+            }
+        };
+        if let Err(e) = crate::analysis::compute_cross_language_links(&db_lock) {
+            warn!("Failed to compute cross-language links: {}", e);
+        }
+    }
+    info!(
+        "⏱️  compute_cross_language_links: {:.2}s",
+        t.elapsed().as_secs_f64()
+    );
+
+    let t = std::time::Instant::now();
+    {
+        let db_lock = match db.lock() {
+            Ok(guard) => guard,
+            Err(poisoned) => {
+                warn!("Database mutex poisoned during external module attribution, recovering");
+                poisoned.into_inner()
+            }
+        };
+        if let Err(e) = crate::analysis::compute_external_call_modules(&db_lock) {
+            warn!("Failed to compute external call modules: {}", e);
+        }
+    }
+    info!(
+        "⏱️  compute_external_call_modules: {:.2}s",
+        t.elapsed().as_secs_f64()
+    );
+
+    let t = std::time::Instant::now();
+    {
+        let db_lock = match db.lock() {
+            Ok(guard) => guard,
+            Err(poisoned) => {
+                warn!("Database mutex poisoned during symbol alias inference, recovering");
+                poisoned.into_inner()
+            }
+        };
+        if let Err(e) = crate::analysis::compute_symbol_aliases(&db_lock) {
+            warn!("Failed to compute symbol aliases: {}", e);
+        }
+    }
+    info!(
+        "⏱️  compute_symbol_aliases: {:.2}s",
+        t.elapsed().as_secs_f64()
+    );
+
+    let t = std::time::Instant::now();
+    {
+        let db_lock = match db.lock() {
+            Ok(guard) => guard,
+            Err(poisoned) => {
+                warn!("Database mutex poisoned during expect/actual linking, recovering");
+                poisoned.into_inner()
+            }
+        };
+        if let Err(e) = crate::analysis::compute_expect_actual_links(&db_lock) {
+            warn!("Failed to compute expect/actual links: {}", e);
+        }
+    }
+    info!(
+        "⏱️  compute_expect_actual_links: {:.2}s",
+        t.elapsed().as_secs_f64()
+    );
+
+    let t = std::time::Instant::now();
+    {
+        let db_lock = match db.lock() {
+            Ok(guard) => guard,
+            Err(poisoned) => {
+                warn!("Database mutex poisoned during Swift module mapping, recovering");
+                poisoned.into_inner()
+            }
+        };
+        if let Err(e) = crate::analysis::compute_swift_modules(&db_lock) {
+            warn!("Failed to compute Swift modules: {}", e);
+        }
+    }
+    info!(
+        "⏱️  compute_swift_modules: {:.2}s",
+        t.elapsed().as_secs_f64()
+    );
+
+    // Nested repositories are found on disk (their `.git` is never indexed)
+    // and become projects in the project map below.
+    let repositories = if WorkspaceSettingsRegistry::global()
+        .get(&route.workspace_root)
+        .indexes_nested_repositories()
+    {
+        find_nested_repositories(&route.workspace_root)
+    } else {
+        Vec::new()
+    };
+    let t = std::time::Instant::now();
+    {
+        let db_lock = match db.lock() {
+            Ok(guard) => guard,
+            Err(poisoned) => {
+                warn!("Database mutex poisoned during project mapping, recovering");
+                poisoned.into_inner()
+            }
+        };
+        if let Err(e) = db_lock.replace_nested_repositories(&repositories) {
+            warn!("Failed to record nested repositories: {}", e);
+        }
+        if let Err(e) = crate::analysis::compute_workspace_projects(&db_lock) {
+            warn!("Failed to compute workspace projects: {}", e);
+        }
+    }
+    info!(
+        "⏱️  compute_workspace_projects: {:.2}s",
+        t.elapsed().as_secs_f64()
+    );
+
+    let t = std::time::Instant::now();
+    {
+        let db_lock = match db.lock() {
+            Ok(guard) => guard,
+            Err(poisoned) => {
+                warn!("Database mutex poisoned during build target mapping, recovering");
+                poisoned.into_inner()
+            }
+        };
+        if let Err(e) = crate::analysis::compute_build_target_files(&db_lock) {
+            warn!("Failed to compute build target files: {}", e);
+        }
+    }
+    info!(
+        "⏱️  compute_build_target_files: {:.2}s",
+        t.elapsed().as_secs_f64()
+    );
+
+    let t = std::time::Instant::now();
+    {
+        let db_lock = match db.lock() {
+            Ok(guard) => guard,
+            Err(poisoned) => {
+                warn!("Database mutex poisoned during framework detection, recovering");
+                poisoned.into_inner()
+            }
+        };
+        if let Err(e) = crate::analysis::compute_frameworks(&db_lock) {
+            warn!("Failed to compute frameworks: {}", e);
+        }
+    }
+    info!("⏱️  compute_frameworks: {:.2}s", t.elapsed().as_secs_f64());
+
     if let Some(ref daemon_db) = handler.daemon_db {
         let current_primary_id = if route.is_primary {
             handler
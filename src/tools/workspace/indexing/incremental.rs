@@ -2,6 +2,7 @@
 //! Handles efficient re-indexing by detecting changed files
 //! Removes database entries for deleted files
 
+use super::branch_switch::BranchSwitch;
 use super::route::IndexRoute;
 use crate::database::ProjectionStatus;
 use crate::handler::JulieServerHandler;
@@ -23,6 +24,20 @@ impl ManageWorkspaceTool {
         handler: &JulieServerHandler,
         all_files: Vec<PathBuf>,
         route: &IndexRoute,
+    ) -> Result<(Vec<PathBuf>, usize)> {
+        self.filter_changed_files_after_switch(handler, all_files, route, None)
+            .await
+    }
+
+    /// [`Self::filter_changed_files`], narrowed by a branch switch: indexed
+    /// files that git does not list as changed, and that were not modified
+    /// since the last run, are skipped without hashing.
+    pub(crate) async fn filter_changed_files_after_switch(
+        &self,
+        handler: &JulieServerHandler,
+        all_files: Vec<PathBuf>,
+        route: &IndexRoute,
+        branch_switch: Option<&BranchSwitch>,
     ) -> Result<(Vec<PathBuf>, usize)> {
         let workspace_id = route.workspace_id.clone();
 
@@ -99,6 +114,14 @@ impl ManageWorkspaceTool {
                     }
                 };
 
+            if let Some(switch) = branch_switch
+                && existing_file_hashes.contains_key(&file_path_relative)
+                && !switch.needs_check(&file_path_relative, file_path)
+            {
+                unchanged_count += 1;
+                continue;
+            }
+
             // Calculate current file hash
             let current_hash = match crate::database::calculate_file_hash(file_path) {
                 Ok(hash) => hash,
@@ -1,10 +1,14 @@
 //! Main workspace indexing orchestration
 //! Coordinates file discovery, processing, and Tantivy search indexing
 
+use super::branch_switch::{
+    BranchSwitch, BranchSwitchReport, GIT_HEAD_COMPONENT, SymbolSnapshot, detect_branch_switch,
+    diff_symbols, record_git_head, snapshot_symbols,
+};
 use super::engine_version::{SEMANTIC_INDEX_ENGINE_COMPONENT, SEMANTIC_INDEX_ENGINE_VERSION};
-use super::pipeline::run_indexing_pipeline;
 use super::route::{IndexRoute, IndexRouteRepairReason};
 use super::state::{IndexingOperation, IndexingRepairReason};
+use super::warm_up::run_indexing_pipeline_warm_first;
 use crate::handler::JulieServerHandler;
 use crate::tools::workspace::commands::ManageWorkspaceTool;
 use anyhow::{Context, Result};
@@ -29,6 +33,8 @@ pub(crate) struct IndexResult {
     pub relationships_total: usize,
     /// Total indexing duration in milliseconds
     pub duration_ms: u64,
+    /// What changed, when HEAD moved since the previous run
+    pub branch_switch: Option<BranchSwitchReport>,
 }
 
 impl ManageWorkspaceTool {
@@ -132,6 +138,27 @@ impl ManageWorkspaceTool {
         }
         let effective_force_reindex = force_reindex || semantic_engine_refresh_needed;
 
+        // 🔀 BRANCH SWITCH: When HEAD moved since the last run, only the files
+        // git reports as changed (plus files modified since) are re-hashed.
+        let branch_switch = if effective_force_reindex {
+            None
+        } else {
+            detect_route_branch_switch(handler, &route).await?
+        };
+        let mut switch_paths = Vec::new();
+        let mut symbols_before = SymbolSnapshot::new();
+        if let Some(switch) = &branch_switch {
+            info!(
+                previous_head = %switch.previous_head,
+                head = %switch.head,
+                changed_files = switch.changed_paths.len(),
+                "🔀 HEAD moved since last index; checking files changed in git"
+            );
+            switch_paths = switch.changed_paths.iter().cloned().collect();
+            switch_paths.sort();
+            symbols_before = snapshot_route_symbols(handler, &route, &switch_paths).await?;
+        }
+
         // 🚀 INCREMENTAL UPDATE: Filter files that need re-indexing based on hash changes
         debug!(
             "🐛 [INDEX TRACE E] About to filter files, force_reindex={}",
@@ -147,7 +174,12 @@ impl ManageWorkspaceTool {
         } else {
             debug!("🐛 [INDEX TRACE E2] Calling filter_changed_files");
             let (files, orphans) = self
-                .filter_changed_files(handler, all_discovered_files, &route)
+                .filter_changed_files_after_switch(
+                    handler,
+                    all_discovered_files,
+                    &route,
+                    branch_switch.as_ref(),
+                )
                 .await?;
             debug!(
                 "🐛 [INDEX TRACE E3] filter_changed_files returned {} files, {} orphans cleaned",
@@ -216,7 +248,19 @@ impl ManageWorkspaceTool {
             release_result.context("releasing Tantivy writer after startup projection backfill")?;
         }
 
+        let files_to_index_count = files_to_index.len();
         if !effective_force_reindex && files_to_index.is_empty() && orphans_cleaned == 0 {
+            record_indexed_git_head(handler, &route).await?;
+            let branch_switch = branch_switch_report(
+                handler,
+                &route,
+                branch_switch,
+                &switch_paths,
+                &symbols_before,
+                0,
+                0,
+            )
+            .await?;
             let (total_symbols, total_files_in_db, total_relationships, canonical_revision) =
                 current_index_totals(handler, &route).await?;
             handler
@@ -236,6 +280,7 @@ impl ManageWorkspaceTool {
                 symbols_total: total_symbols,
                 relationships_total: total_relationships,
                 duration_ms: index_start.elapsed().as_millis() as u64,
+                branch_switch,
             });
         }
 
@@ -265,10 +310,15 @@ impl ManageWorkspaceTool {
                     IndexingOperation::Incremental
                 }
             });
-        let pipeline_result =
-            run_indexing_pipeline(self, handler, files_to_index, &route, indexing_operation)
-                .await
-                .context("running indexing pipeline after projection backfill")?;
+        let pipeline_result = run_indexing_pipeline_warm_first(
+            self,
+            handler,
+            files_to_index,
+            &route,
+            indexing_operation,
+        )
+        .await
+        .context("running indexing pipeline after projection backfill")?;
         let total_files = pipeline_result.files_processed;
         if pipeline_result.state.repair_needed() {
             warn!(
@@ -281,6 +331,17 @@ impl ManageWorkspaceTool {
         debug!("🐛 [INDEX TRACE T] run_indexing_pipeline completed");
 
         record_current_index_engine_version(handler, &route).await?;
+        record_indexed_git_head(handler, &route).await?;
+        let branch_switch = branch_switch_report(
+            handler,
+            &route,
+            branch_switch,
+            &switch_paths,
+            &symbols_before,
+            files_to_index_count,
+            orphans_cleaned,
+        )
+        .await?;
 
         // 🚀 NEW ARCHITECTURE: Get final counts from DATABASE, not memory!
         // 🔴 CRITICAL FIX: Query the correct database for target vs primary workspaces.
@@ -303,6 +364,7 @@ impl ManageWorkspaceTool {
             symbols_total: total_symbols,
             relationships_total: total_relationships,
             duration_ms,
+            branch_switch,
         })
     }
 
@@ -409,6 +471,83 @@ async fn record_current_index_engine_version(
     Ok(())
 }
 
+async fn detect_route_branch_switch(
+    handler: &JulieServerHandler,
+    route: &IndexRoute,
+) -> Result<Option<BranchSwitch>> {
+    let Some(db_arc) = route.database_for_read(handler).await? else {
+        return Ok(None);
+    };
+    let recorded = {
+        let db = db_arc
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        db.get_index_engine_state(&route.workspace_id, GIT_HEAD_COMPONENT)?
+    };
+    if recorded.is_none() {
+        return Ok(None);
+    }
+    let workspace_root = route.workspace_root.clone();
+    tokio::task::spawn_blocking(move || detect_branch_switch(recorded, &workspace_root))
+        .await
+        .map_err(|e| anyhow::anyhow!("Branch switch detection task panicked: {}", e))
+}
+
+async fn snapshot_route_symbols(
+    handler: &JulieServerHandler,
+    route: &IndexRoute,
+    paths: &[String],
+) -> Result<SymbolSnapshot> {
+    let Some(db_arc) = route.database_for_read(handler).await? else {
+        return Ok(SymbolSnapshot::new());
+    };
+    let db = db_arc
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    snapshot_symbols(&db, paths)
+}
+
+async fn record_indexed_git_head(handler: &JulieServerHandler, route: &IndexRoute) -> Result<()> {
+    let Some(db_arc) = route.database_for_write(handler).await? else {
+        return Ok(());
+    };
+    let workspace_id = route.workspace_id.clone();
+    let workspace_root = route.workspace_root.clone();
+    tokio::task::spawn_blocking(move || {
+        let db = db_arc
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        record_git_head(&db, &workspace_id, &workspace_root)
+    })
+    .await
+    .map_err(|e| anyhow::anyhow!("Recording git HEAD task panicked: {}", e))?
+}
+
+async fn branch_switch_report(
+    handler: &JulieServerHandler,
+    route: &IndexRoute,
+    branch_switch: Option<BranchSwitch>,
+    switch_paths: &[String],
+    symbols_before: &SymbolSnapshot,
+    files_reindexed: usize,
+    files_removed: usize,
+) -> Result<Option<BranchSwitchReport>> {
+    let Some(switch) = branch_switch else {
+        return Ok(None);
+    };
+    let symbols_after = snapshot_route_symbols(handler, route, switch_paths).await?;
+    let report = BranchSwitchReport {
+        previous_head: switch.previous_head,
+        head: switch.head,
+        git_changed_files: switch_paths.len(),
+        files_reindexed,
+        files_removed,
+        symbols: diff_symbols(symbols_before, &symbols_after),
+    };
+    info!("🔀 {}", report.summary());
+    Ok(Some(report))
+}
+
 async fn current_index_totals(
     handler: &JulieServerHandler,
     route: &IndexRoute,
@@ -9,7 +9,10 @@
 //! - **processor**: File processing logic - handles parsing and symbol extraction
 //! - **extractor**: Symbol extraction from ASTs - all 26 language extractors
 //! - **incremental**: Incremental updates - detects changed files and orphan cleanup
+//! - **branch_switch**: Branch-switch detection via git diff and the differential report
+//! - **warm_up**: Large runs index recently edited files first
 
+pub(crate) mod branch_switch;
 pub(crate) mod embeddings;
 pub(crate) mod engine_version;
 pub(crate) mod extractor;
@@ -22,3 +25,4 @@ pub(crate) mod processor;
 pub(crate) mod resolver;
 pub(crate) mod route;
 pub(crate) mod state;
+pub(crate) mod warm_up;
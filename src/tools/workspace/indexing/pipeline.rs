@@ -6,18 +6,28 @@ use anyhow::Context;
 use anyhow::Result;
 use tracing::{debug, info, warn};
 
+use super::engine_version::SEMANTIC_INDEX_ENGINE_VERSION;
 use super::finalize::{analyze_batch, resolve_pending_relationships};
 use super::route::IndexRoute;
 use super::state::{IndexedFileDisposition, IndexingBatchState, IndexingOperation, IndexingStage};
 use crate::extractors::Symbol;
 use crate::handler::JulieServerHandler;
 use crate::indexing_core::batch::ExtractedBatch;
+use crate::indexing_core::checkpoint::{
+    DEFAULT_CHECKPOINT_CHUNK_FILES, ExtractionCheckpoints, extract_files_with_checkpoints,
+};
 use crate::indexing_core::extraction::{
     ExtractedFileDisposition, ExtractedFileRecord, extract_files_for_indexing_with_records,
 };
 use crate::tools::workspace::commands::ManageWorkspaceTool;
+use julie_core::background_tasks::{BackgroundTask, BackgroundTaskKind, BackgroundTaskRegistry};
+use julie_core::memory_budget::{MemoryBudget, MemoryComponent};
 use julie_pipeline::indexing_core::web_edges::rebuild_web_edges_for_workspace;
 
+/// Stages between `Queued` and `Completed`; each one finished is one unit of
+/// progress on the pipeline's background task.
+const PIPELINE_STAGE_COUNT: u64 = 6;
+
 pub(crate) struct IndexingPipelineResult {
     pub state: IndexingBatchState,
     pub files_processed: usize,
@@ -36,17 +46,48 @@ pub(crate) async fn run_indexing_pipeline(
     operation: IndexingOperation,
 ) -> Result<IndexingPipelineResult> {
     let mut state = IndexingBatchState::new(route.workspace_id.clone());
+    // Not pausable: later stages run under the workspace mutation gate.
+    let task = BackgroundTaskRegistry::global().register(
+        BackgroundTaskKind::Indexing,
+        route.workspace_id.clone(),
+        format!("{operation} indexing of {} files", files_to_index.len()),
+        false,
+    );
+    task.set_total(PIPELINE_STAGE_COUNT);
     update_runtime_begin(route, operation);
-    transition_stage(&mut state, route, IndexingStage::Grouped);
-
+    transition_stage(&mut state, route, &task, IndexingStage::Grouped);
+
+    // Runs larger than one chunk (typically the first index of a big repo)
+    // checkpoint their extraction so a killed server resumes instead of
+    // starting over.
+    let checkpoints = (files_to_index.len() > DEFAULT_CHECKPOINT_CHUNK_FILES)
+        .then(|| extraction_checkpoints(route))
+        .flatten();
     let files_by_language = group_files_by_language(tool, files_to_index);
     info!("🚀 Processing {} languages", files_by_language.len());
 
-    transition_stage(&mut state, route, IndexingStage::Extracting);
-    let (batch, extracted_records) =
-        extract_files_for_indexing_with_records(files_by_language, &route.workspace_root).await?;
+    transition_stage(&mut state, route, &task, IndexingStage::Extracting);
+    let (batch, extracted_records) = match &checkpoints {
+        Some(checkpoints) => {
+            let extraction = extract_files_with_checkpoints(
+                files_by_language,
+                &route.workspace_root,
+                checkpoints,
+                DEFAULT_CHECKPOINT_CHUNK_FILES,
+            )
+            .await?;
+            (extraction.batch, extraction.records)
+        }
+        None => {
+            extract_files_for_indexing_with_records(files_by_language, &route.workspace_root)
+                .await?
+        }
+    };
     record_extracted_file_records(&mut state, extracted_records);
     let files_processed = batch.files_processed;
+    // Held until the batch is consumed by projection.
+    let batch_charge =
+        MemoryBudget::global().charge(MemoryComponent::BatchBuffers, batch.approximate_bytes());
 
     // Test-role classification (and literal carrier gating) now happens inside
     // the shared chokepoint `extract_files_for_indexing_with_records` above, so
@@ -55,7 +96,8 @@ pub(crate) async fn run_indexing_pipeline(
     // which is exactly why the extract DB Miller reads lacked `test_role`.)
 
     let Some(db) = route.database_for_write(handler).await? else {
-        transition_stage(&mut state, route, IndexingStage::Completed);
+        clear_extraction_checkpoints(checkpoints.as_ref());
+        transition_stage(&mut state, route, &task, IndexingStage::Completed);
         update_runtime_finish(route, &state);
         return Ok(IndexingPipelineResult {
             state,
@@ -64,17 +106,18 @@ pub(crate) async fn run_indexing_pipeline(
         });
     };
 
-    transition_stage(&mut state, route, IndexingStage::Persisting);
+    transition_stage(&mut state, route, &task, IndexingStage::Persisting);
     let persist_result = persist_batch(&db, route, operation, &batch)?;
+    clear_extraction_checkpoints(checkpoints.as_ref());
 
-    transition_stage(&mut state, route, IndexingStage::Resolving);
+    transition_stage(&mut state, route, &task, IndexingStage::Resolving);
     resolve_pending_relationships(
         &db,
         &batch.all_pending_relationships,
         &batch.all_structured_pending_relationships,
     );
 
-    transition_stage(&mut state, route, IndexingStage::Projecting);
+    transition_stage(&mut state, route, &task, IndexingStage::Projecting);
     project_batch(
         &db,
         route,
@@ -83,8 +126,9 @@ pub(crate) async fn run_indexing_pipeline(
         persist_result.canonical_revision,
     )
     .await?;
+    drop(batch_charge);
 
-    transition_stage(&mut state, route, IndexingStage::Analyzing);
+    transition_stage(&mut state, route, &task, IndexingStage::Analyzing);
     analyze_batch(handler, route, &db)?;
 
     if !state.repair_needed() {
@@ -106,7 +150,7 @@ pub(crate) async fn run_indexing_pipeline(
         );
     }
 
-    transition_stage(&mut state, route, IndexingStage::Completed);
+    transition_stage(&mut state, route, &task, IndexingStage::Completed);
     update_runtime_finish(route, &state);
     if state.repair_needed() {
         warn!(
@@ -123,6 +167,28 @@ pub(crate) async fn run_indexing_pipeline(
     })
 }
 
+/// Checkpoints live beside `db/` and `tantivy/` in the workspace index dir.
+/// They hold extracted source in plain files, so none are written while an
+/// index key is set.
+fn extraction_checkpoints(route: &IndexRoute) -> Option<ExtractionCheckpoints> {
+    if julie_core::database::encryption::index_key_configured() {
+        return None;
+    }
+    let index_dir = route.db_path.parent()?.parent()?;
+    Some(ExtractionCheckpoints::new(
+        index_dir.join("extraction-checkpoints"),
+        SEMANTIC_INDEX_ENGINE_VERSION,
+    ))
+}
+
+fn clear_extraction_checkpoints(checkpoints: Option<&ExtractionCheckpoints>) {
+    if let Some(checkpoints) = checkpoints
+        && let Err(error) = checkpoints.clear()
+    {
+        warn!("Failed to clear extraction checkpoints: {:#}", error);
+    }
+}
+
 fn group_files_by_language(
     tool: &ManageWorkspaceTool,
     files_to_index: Vec<PathBuf>,
@@ -162,6 +228,14 @@ fn record_extracted_file_records(
                     None,
                 );
             }
+            ExtractedFileDisposition::Skipped { reason } => {
+                state.record_file(
+                    record.relative_path,
+                    record.language,
+                    IndexedFileDisposition::TextOnly,
+                    Some(reason),
+                );
+            }
             ExtractedFileDisposition::RepairNeeded { detail } => {
                 state.record_file(
                     record.relative_path,
@@ -174,7 +248,16 @@ fn record_extracted_file_records(
     }
 }
 
-fn transition_stage(state: &mut IndexingBatchState, route: &IndexRoute, stage: IndexingStage) {
+fn transition_stage(
+    state: &mut IndexingBatchState,
+    route: &IndexRoute,
+    task: &BackgroundTask,
+    stage: IndexingStage,
+) {
+    if state.current_stage != IndexingStage::Queued {
+        task.advance(1);
+    }
+    task.set_detail(stage.as_str());
     state.transition_to(stage);
     if let Some(runtime) = route.indexing_runtime.as_ref() {
         runtime
@@ -268,6 +351,13 @@ fn persist_batch(
                 Some(detail),
             )?;
         }
+        for (path, reason) in &batch.parse_skips {
+            db_lock.record_indexing_repair(
+                path,
+                crate::database::PARSE_SKIPPED_REASON,
+                Some(reason),
+            )?;
+        }
         log_documentation_symbol_count(&batch.all_symbols);
 
         info!(
@@ -316,6 +406,13 @@ fn persist_batch(
                 Some(detail),
             )?;
         }
+        for (path, reason) in &batch.parse_skips {
+            db_lock.record_indexing_repair(
+                path,
+                crate::database::PARSE_SKIPPED_REASON,
+                Some(reason),
+            )?;
+        }
         log_documentation_symbol_count(&batch.all_symbols);
 
         info!(
@@ -114,6 +114,14 @@ fn record_extracted_file_records(
                     None,
                 );
             }
+            ExtractedFileDisposition::Skipped { reason } => {
+                state.record_file(
+                    record.relative_path,
+                    record.language,
+                    IndexedFileDisposition::TextOnly,
+                    Some(reason),
+                );
+            }
             ExtractedFileDisposition::RepairNeeded { detail } => {
                 state.record_file(
                     record.relative_path,
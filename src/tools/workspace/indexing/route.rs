@@ -128,8 +128,7 @@ impl IndexRoute {
         &self,
         create_if_missing: bool,
     ) -> Result<Option<Arc<SearchIndex>>> {
-        let meta_path = self.tantivy_path.join("meta.json");
-        if !create_if_missing && !meta_path.exists() {
+        if !create_if_missing && !SearchIndex::exists(&self.tantivy_path) {
             return Ok(None);
         }
 
@@ -139,7 +138,7 @@ impl IndexRoute {
         let search_index = tokio::task::spawn_blocking(move || {
             if create_if_missing {
                 std::fs::create_dir_all(&tantivy_path)?;
-            } else if !tantivy_path.join("meta.json").exists() {
+            } else if !SearchIndex::exists(&tantivy_path) {
                 return Ok::<_, anyhow::Error>(None);
             }
 
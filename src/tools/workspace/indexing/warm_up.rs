@@ -0,0 +1,153 @@
+//! Warm-up ordering for large index runs.
+//!
+//! The first index of a big repository takes minutes, and until it is
+//! persisted no query can succeed. The files the user is working on right now
+//! are the ones interactive questions are about, so a large run is split in
+//! two: a warm-up batch of recently edited files — uncommitted work from git,
+//! then the most recently modified files on disk — is indexed and made
+//! searchable first, and the rest of the repository follows.
+
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+use anyhow::{Context, Result};
+use tracing::{debug, info};
+
+use super::pipeline::{IndexingPipelineResult, run_indexing_pipeline};
+use super::route::IndexRoute;
+use super::state::IndexingOperation;
+use crate::handler::JulieServerHandler;
+use crate::tools::workspace::commands::ManageWorkspaceTool;
+use crate::utils::paths::to_relative_unix_style;
+use julie_core::git_history::uncommitted_files;
+
+/// Runs with fewer files than this index quickly enough as one batch.
+pub(crate) const WARM_UP_MIN_FILES: usize = 2_000;
+
+/// Most files in a warm-up batch.
+pub(crate) const WARM_UP_FILE_LIMIT: usize = 500;
+
+/// Split `files` into a warm-up batch and the rest, preserving the original
+/// order within each. Hands `files` back when the run is too small to split.
+pub(crate) fn split_warm_up(
+    workspace_root: &Path,
+    files: Vec<PathBuf>,
+) -> std::result::Result<(Vec<PathBuf>, Vec<PathBuf>), Vec<PathBuf>> {
+    if files.len() < WARM_UP_MIN_FILES {
+        return Err(files);
+    }
+    let uncommitted = match uncommitted_files(workspace_root) {
+        Ok(paths) => paths,
+        Err(err) => {
+            debug!("Warm-up ordering without git status: {err:#}");
+            Vec::new()
+        }
+    };
+    let candidates: Vec<WarmUpCandidate> = files
+        .iter()
+        .map(|path| WarmUpCandidate {
+            relative_path: to_relative_unix_style(path, workspace_root).unwrap_or_default(),
+            modified: std::fs::metadata(path)
+                .and_then(|metadata| metadata.modified())
+                .ok(),
+        })
+        .collect();
+    let chosen = pick_warm_up(&candidates, &uncommitted, WARM_UP_FILE_LIMIT);
+
+    let (warm, rest): (Vec<_>, Vec<_>) = files
+        .into_iter()
+        .enumerate()
+        .partition(|(idx, _)| chosen.contains(idx));
+    Ok((
+        warm.into_iter().map(|(_, path)| path).collect(),
+        rest.into_iter().map(|(_, path)| path).collect(),
+    ))
+}
+
+/// One file of a run, as warm-up ranking sees it.
+#[derive(Debug, Clone)]
+pub(crate) struct WarmUpCandidate {
+    pub relative_path: String,
+    pub modified: Option<SystemTime>,
+}
+
+/// Indexes into `candidates` of at most `limit` files: those listed in
+/// `uncommitted` (in git's order), then the most recently modified.
+pub(crate) fn pick_warm_up(
+    candidates: &[WarmUpCandidate],
+    uncommitted: &[String],
+    limit: usize,
+) -> HashSet<usize> {
+    let mut chosen = HashSet::new();
+    let by_path: HashMap<&str, usize> = candidates
+        .iter()
+        .enumerate()
+        .map(|(idx, candidate)| (candidate.relative_path.as_str(), idx))
+        .collect();
+    for path in uncommitted {
+        if chosen.len() >= limit {
+            return chosen;
+        }
+        if let Some(&idx) = by_path.get(path.as_str()) {
+            chosen.insert(idx);
+        }
+    }
+
+    let mut recent: Vec<(SystemTime, usize)> = candidates
+        .iter()
+        .enumerate()
+        .filter(|(idx, _)| !chosen.contains(idx))
+        .filter_map(|(idx, candidate)| candidate.modified.map(|time| (time, idx)))
+        .collect();
+    recent.sort_unstable_by(|a, b| b.cmp(a));
+    let room = limit.saturating_sub(chosen.len());
+    chosen.extend(recent.into_iter().take(room).map(|(_, idx)| idx));
+    chosen
+}
+
+/// Run the indexing pipeline over `files`, warm-up batch first when the run
+/// is large enough to split. The result covers both batches.
+pub(crate) async fn run_indexing_pipeline_warm_first(
+    tool: &ManageWorkspaceTool,
+    handler: &JulieServerHandler,
+    files: Vec<PathBuf>,
+    route: &IndexRoute,
+    operation: IndexingOperation,
+) -> Result<IndexingPipelineResult> {
+    let workspace_root = route.workspace_root.clone();
+    let (warm, rest) =
+        tokio::task::spawn_blocking(move || match split_warm_up(&workspace_root, files) {
+            Ok(split) => split,
+            Err(files) => (Vec::new(), files),
+        })
+        .await
+        .context("warm-up ordering task failed")?;
+    if warm.is_empty() {
+        return run_indexing_pipeline(tool, handler, rest, route, operation).await;
+    }
+
+    info!(
+        "🔥 Indexing {} recently edited files first, then the other {}",
+        warm.len(),
+        rest.len()
+    );
+    let warm_result = run_indexing_pipeline(tool, handler, warm, route, operation)
+        .await
+        .context("indexing warm-up batch")?;
+    info!(
+        "🔥 Warm-up batch searchable ({} files); indexing the rest of the workspace",
+        warm_result.files_processed
+    );
+
+    // A full run already cleared the old index before the warm-up batch; the
+    // rest must be added to it, not replace it.
+    let rest_operation = match operation {
+        IndexingOperation::Full => IndexingOperation::Incremental,
+        other => other,
+    };
+    let mut result = run_indexing_pipeline(tool, handler, rest, route, rest_operation).await?;
+    result.files_processed += warm_result.files_processed;
+    result.state.absorb_earlier(warm_result.state);
+    Ok(result)
+}
@@ -9,8 +9,9 @@ use std::path::{Path, PathBuf};
 // and relative_within_workspace live in julie-core; re-exported publicly so all
 // crate::utils::paths::* callers compile unchanged.
 pub use julie_core::paths::{
-    WorkspaceFileInputResolution, relative_within_workspace, resolve_workspace_file_input,
-    to_relative_unix_style,
+    WorkspaceFileInputResolution, decode_stored_path, display_stored_path, encode_path_lossless,
+    normalize_stored_path, relative_within_workspace, resolve_workspace_file_input,
+    stored_path_to_absolute, to_relative_unix_style,
 };
 
 /// Convert a path to a user-friendly display string.
@@ -330,6 +330,8 @@ async fn execute_baseline_case(
             context_lines: None,
             exclude_tests: case.exclude_tests,
             backend: SearchBackend::resolve(None),
+            fuzzy: false,
+            language_scope: Default::default(),
         },
         &[SearchExecutionWorkspace::primary(
             workspace.workspace_id.clone(),